@@ -57,6 +57,25 @@ pub enum CliOptionsCommand {
     /// Computes the 64 bits BLAKE2 hash of a string payload and prints the hexadecimal-encoded hash.
     #[command(name = "blake2-64bits-hash")]
     Blake264BitsHash(CliOptionsBlake264Hash),
+    /// Writes a range of finalized blocks of the local database to a file.
+    #[command(name = "export-blocks")]
+    ExportBlocks(CliOptionsExportBlocks),
+    /// Verifies the blocks found in a file and inserts them in the local database.
+    #[command(name = "import-blocks")]
+    ImportBlocks(CliOptionsImportBlocks),
+    /// Writes the storage of the latest finalized block of the local database to a file.
+    #[command(name = "export-snapshot")]
+    ExportSnapshot(CliOptionsExportSnapshot),
+    /// Verifies a state snapshot and creates the local database from it.
+    #[command(name = "import-snapshot")]
+    ImportSnapshot(CliOptionsImportSnapshot),
+    /// Verifies the consistency of the local database and prints the problems found.
+    #[command(name = "check-database")]
+    CheckDatabase(CliOptionsCheckDatabase),
+    /// Rehearses the upgrade to a new runtime on top of a block of the local database and prints
+    /// a report.
+    #[command(name = "dry-run-runtime-upgrade")]
+    DryRunRuntimeUpgrade(CliOptionsDryRunRuntimeUpgrade),
 }
 
 #[derive(Debug, clap::Parser)]
@@ -95,6 +114,10 @@ pub struct CliOptionsRun {
     /// Do not load or store anything on disk.
     #[arg(long)]
     pub tmp: bool,
+    /// Directory where to cache compiled runtimes. Can be shared between multiple nodes.
+    /// Defaults to a directory within the data directory, unless `--tmp` is passed.
+    #[arg(long)]
+    pub runtime_cache: Option<PathBuf>,
     /// Maximum size of the cache used by the database.
     #[arg(long, default_value = "256M", value_parser = parse_max_bytes)]
     pub database_cache_size: MaxBytes,
@@ -102,6 +125,18 @@ pub struct CliOptionsRun {
     /// chain is not a parachain.
     #[arg(long, default_value = "256M", value_parser = parse_max_bytes)]
     pub relay_chain_database_cache_size: MaxBytes,
+    /// Maintain an index of the extrinsics of the stored blocks by hash. Building the index of
+    /// an existing database can take a long time.
+    #[arg(long)]
+    pub extrinsics_index: bool,
+    /// Run the off-chain worker of the runtime after each new best block. The HTTP requests of
+    /// off-chain workers are only supported over plain-text `http://`, not `https://`.
+    #[arg(long)]
+    pub offchain_workers: bool,
+    /// Execute each block a second time with both the interpreter and the JIT, and report the
+    /// divergences between the two in the logs. Considerably slows down syncing.
+    #[arg(long)]
+    pub differential_execution: bool,
 }
 
 #[derive(Debug, clap::Parser)]
@@ -110,6 +145,104 @@ pub struct CliOptionsBlake264Hash {
     pub payload: String,
 }
 
+#[derive(Debug, clap::Parser)]
+pub struct CliOptionsExportBlocks {
+    /// Chain whose blocks to export ("Polkadot", "Kusama", "Westend", or a file path).
+    #[arg(long, default_value = "polkadot")]
+    pub chain: CliChain,
+    /// Number of the first block to export.
+    #[arg(long, default_value = "1")]
+    pub from: u64,
+    /// Number of the last block to export. Defaults to the latest finalized block.
+    #[arg(long)]
+    pub to: Option<u64>,
+    /// Format of the output file.
+    #[arg(long, default_value = "scale")]
+    pub format: BlocksFileFormat,
+    /// Maximum size of the cache used by the database.
+    #[arg(long, default_value = "256M", value_parser = parse_max_bytes)]
+    pub database_cache_size: MaxBytes,
+    /// File to write the blocks to. Defaults to stdout.
+    pub output: Option<PathBuf>,
+}
+
+#[derive(Debug, clap::Parser)]
+pub struct CliOptionsImportBlocks {
+    /// Chain whose blocks to import ("Polkadot", "Kusama", "Westend", or a file path).
+    #[arg(long, default_value = "polkadot")]
+    pub chain: CliChain,
+    /// Format of the input file.
+    #[arg(long, default_value = "scale")]
+    pub format: BlocksFileFormat,
+    /// Do not load or store anything on disk. Useful in order to only verify the blocks.
+    #[arg(long)]
+    pub tmp: bool,
+    /// Maximum size of the cache used by the database.
+    #[arg(long, default_value = "256M", value_parser = parse_max_bytes)]
+    pub database_cache_size: MaxBytes,
+    /// File to read the blocks from. Defaults to stdin.
+    pub input: Option<PathBuf>,
+}
+
+#[derive(Debug, clap::Parser)]
+pub struct CliOptionsExportSnapshot {
+    /// Chain whose storage to export ("Polkadot", "Kusama", "Westend", or a file path).
+    #[arg(long, default_value = "polkadot")]
+    pub chain: CliChain,
+    /// Maximum size of the cache used by the database.
+    #[arg(long, default_value = "256M", value_parser = parse_max_bytes)]
+    pub database_cache_size: MaxBytes,
+    /// File to write the snapshot to. Defaults to stdout.
+    pub output: Option<PathBuf>,
+}
+
+#[derive(Debug, clap::Parser)]
+pub struct CliOptionsImportSnapshot {
+    /// Chain whose storage to import ("Polkadot", "Kusama", "Westend", or a file path).
+    #[arg(long, default_value = "polkadot")]
+    pub chain: CliChain,
+    /// Maximum size of the cache used by the database.
+    #[arg(long, default_value = "256M", value_parser = parse_max_bytes)]
+    pub database_cache_size: MaxBytes,
+    /// File to read the snapshot from. Defaults to stdin.
+    pub input: Option<PathBuf>,
+}
+
+#[derive(Debug, clap::Parser)]
+pub struct CliOptionsCheckDatabase {
+    /// Chain whose database to check ("Polkadot", "Kusama", "Westend", or a file path).
+    #[arg(long, default_value = "polkadot")]
+    pub chain: CliChain,
+    /// Maximum size of the cache used by the database.
+    #[arg(long, default_value = "256M", value_parser = parse_max_bytes)]
+    pub database_cache_size: MaxBytes,
+}
+
+#[derive(Debug, clap::Parser)]
+pub struct CliOptionsDryRunRuntimeUpgrade {
+    /// Chain whose database to use ("Polkadot", "Kusama", "Westend", or a file path).
+    #[arg(long, default_value = "polkadot")]
+    pub chain: CliChain,
+    /// Hash of the block to perform the upgrade on. Defaults to the latest finalized block.
+    #[arg(long, value_parser = decode_block_hash)]
+    pub block: Option<[u8; 32]>,
+    /// Number of descendants of the block to execute after the upgrade.
+    #[arg(long, default_value = "3")]
+    pub num_blocks: u32,
+    /// Do not run the pre- and post-upgrade checks and the `try_state` hooks of the runtime.
+    #[arg(long)]
+    pub no_try_runtime_checks: bool,
+    /// Level of the logs of the runtime: off, error, warn, info, debug, trace.
+    #[arg(long, default_value = "info")]
+    pub runtime_log_level: LogLevel,
+    /// Maximum size of the cache used by the database.
+    #[arg(long, default_value = "256M", value_parser = parse_max_bytes)]
+    pub database_cache_size: MaxBytes,
+    /// Wasm file of the runtime to upgrade to. Must have been compiled with the `try-runtime`
+    /// feature.
+    pub runtime: PathBuf,
+}
+
 #[derive(Debug, Clone)]
 pub enum CliChain {
     Polkadot,
@@ -209,6 +342,12 @@ pub enum Output {
     LogsJson,
 }
 
+#[derive(Debug, Clone, clap::ValueEnum)]
+pub enum BlocksFileFormat {
+    Scale,
+    Json,
+}
+
 #[derive(Debug, Clone)]
 pub struct JsonRpcAddress(pub Option<SocketAddr>);
 
@@ -299,6 +438,10 @@ fn decode_ed25519_private_key(phrase: &str) -> Result<[u8; 32], String> {
 fn decode_sr25519_private_key(phrase: &str) -> Result<[u8; 64], String> {
     seed_phrase::decode_sr25519_private_key(phrase).map_err(|err| err.to_string())
 }
+fn decode_block_hash(hash: &str) -> Result<[u8; 32], String> {
+    let bytes = hex::decode(hash.trim_start_matches("0x")).map_err(|err| err.to_string())?;
+    <[u8; 32]>::try_from(bytes).map_err(|_| "Block hash must be 32 bytes".to_string())
+}
 fn decode_multiaddr(addr: &str) -> Result<Multiaddr, String> {
     addr.parse::<Multiaddr>().map_err(|err| err.to_string())
 }
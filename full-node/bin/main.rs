@@ -42,6 +42,401 @@ async fn async_main() {
             let hash = blake2_rfc::blake2b::blake2b(8, &[], opt.payload.as_bytes());
             println!("0x{}", hex::encode(hash));
         }
+        cli::CliOptionsCommand::ExportBlocks(opt) => export_blocks(opt),
+        cli::CliOptionsCommand::ImportBlocks(opt) => import_blocks(opt).await,
+        cli::CliOptionsCommand::ExportSnapshot(opt) => export_snapshot(opt),
+        cli::CliOptionsCommand::ImportSnapshot(opt) => import_snapshot(opt),
+        cli::CliOptionsCommand::CheckDatabase(opt) => check_database(opt),
+        cli::CliOptionsCommand::DryRunRuntimeUpgrade(opt) => dry_run_runtime_upgrade(opt),
+    }
+}
+
+fn export_blocks(cli_options: cli::CliOptionsExportBlocks) {
+    let chain_spec = load_chain_spec(&cli_options.chain);
+    let parsed_chain_spec = smoldot::chain_spec::ChainSpec::from_json_bytes(&chain_spec)
+        .expect("Failed to decode chain specification");
+
+    let Some(base) = directories::ProjectDirs::from("io", "smoldot", "smoldot") else {
+        eprintln!("Failed to fetch $HOME directory");
+        std::process::exit(1)
+    };
+    let sqlite_database_path = base
+        .data_dir()
+        .join(parsed_chain_spec.id())
+        .join("database");
+
+    let output: Box<dyn io::Write> = match &cli_options.output {
+        Some(path) => Box::new(io::BufWriter::new(
+            fs::File::create(path).expect("Failed to create output file"),
+        )),
+        None => Box::new(io::BufWriter::new(io::stdout().lock())),
+    };
+
+    let result = smoldot_full_node::export_blocks(smoldot_full_node::ExportBlocksConfig {
+        chain_spec,
+        sqlite_database_path,
+        sqlite_cache_size: cli_options.database_cache_size.0,
+        from: cli_options.from,
+        to: cli_options.to,
+        format: match cli_options.format {
+            cli::BlocksFileFormat::Scale => smoldot_full_node::BlocksFileFormat::Scale,
+            cli::BlocksFileFormat::Json => smoldot_full_node::BlocksFileFormat::Json,
+        },
+        output,
+    });
+
+    match result {
+        Ok(num_blocks) => eprintln!("Exported {num_blocks} blocks"),
+        Err(err) => {
+            eprintln!("{err}");
+            std::process::exit(1)
+        }
+    }
+}
+
+async fn import_blocks(cli_options: cli::CliOptionsImportBlocks) {
+    let chain_spec = load_chain_spec(&cli_options.chain);
+    let parsed_chain_spec = smoldot::chain_spec::ChainSpec::from_json_bytes(&chain_spec)
+        .expect("Failed to decode chain specification");
+
+    let sqlite_database_path = if cli_options.tmp {
+        None
+    } else if let Some(base) = directories::ProjectDirs::from("io", "smoldot", "smoldot") {
+        Some(
+            base.data_dir()
+                .join(parsed_chain_spec.id())
+                .join("database"),
+        )
+    } else {
+        eprintln!(
+            "Failed to fetch $HOME directory. Please pass the `--tmp` flag in order to import \
+            blocks in memory."
+        );
+        std::process::exit(1)
+    };
+
+    let input: Box<dyn io::Read> = match &cli_options.input {
+        Some(path) => Box::new(io::BufReader::new(
+            fs::File::open(path).expect("Failed to open input file"),
+        )),
+        None => Box::new(io::BufReader::new(io::stdin().lock())),
+    };
+
+    let result = smoldot_full_node::import_blocks(smoldot_full_node::ImportBlocksConfig {
+        chain_spec,
+        sqlite_database_path,
+        sqlite_cache_size: cli_options.database_cache_size.0,
+        format: match cli_options.format {
+            cli::BlocksFileFormat::Scale => smoldot_full_node::BlocksFileFormat::Scale,
+            cli::BlocksFileFormat::Json => smoldot_full_node::BlocksFileFormat::Json,
+        },
+        input,
+        log_callback: Arc::new(|_level, _message| {}),
+    })
+    .await;
+
+    match result {
+        Ok(outcome) => eprintln!(
+            "Imported {} blocks; best block: #{}; finalized block: #{}",
+            outcome.num_imported, outcome.best_block_number, outcome.finalized_block_number
+        ),
+        Err(err) => {
+            eprintln!("{err}");
+            std::process::exit(1)
+        }
+    }
+}
+
+fn export_snapshot(cli_options: cli::CliOptionsExportSnapshot) {
+    let chain_spec = load_chain_spec(&cli_options.chain);
+    let parsed_chain_spec = smoldot::chain_spec::ChainSpec::from_json_bytes(&chain_spec)
+        .expect("Failed to decode chain specification");
+
+    let Some(base) = directories::ProjectDirs::from("io", "smoldot", "smoldot") else {
+        eprintln!("Failed to fetch $HOME directory");
+        std::process::exit(1)
+    };
+    let sqlite_database_path = base
+        .data_dir()
+        .join(parsed_chain_spec.id())
+        .join("database");
+
+    let output: Box<dyn io::Write> = match &cli_options.output {
+        Some(path) => Box::new(io::BufWriter::new(
+            fs::File::create(path).expect("Failed to create output file"),
+        )),
+        None => Box::new(io::BufWriter::new(io::stdout().lock())),
+    };
+
+    let result = smoldot_full_node::export_snapshot(smoldot_full_node::ExportSnapshotConfig {
+        chain_spec,
+        sqlite_database_path,
+        sqlite_cache_size: cli_options.database_cache_size.0,
+        output,
+    });
+
+    match result {
+        Ok(outcome) => eprintln!(
+            "Exported {} storage entries of block #{} ({})",
+            outcome.num_entries,
+            outcome.block_number,
+            smoldot::informant::HashDisplay(&outcome.block_hash)
+        ),
+        Err(err) => {
+            eprintln!("{err}");
+            std::process::exit(1)
+        }
+    }
+}
+
+fn import_snapshot(cli_options: cli::CliOptionsImportSnapshot) {
+    let chain_spec = load_chain_spec(&cli_options.chain);
+    let parsed_chain_spec = smoldot::chain_spec::ChainSpec::from_json_bytes(&chain_spec)
+        .expect("Failed to decode chain specification");
+
+    let Some(base) = directories::ProjectDirs::from("io", "smoldot", "smoldot") else {
+        eprintln!("Failed to fetch $HOME directory");
+        std::process::exit(1)
+    };
+    let sqlite_database_path = base
+        .data_dir()
+        .join(parsed_chain_spec.id())
+        .join("database");
+
+    let input: Box<dyn io::Read> = match &cli_options.input {
+        Some(path) => Box::new(io::BufReader::new(
+            fs::File::open(path).expect("Failed to open input file"),
+        )),
+        None => Box::new(io::BufReader::new(io::stdin().lock())),
+    };
+
+    let result = smoldot_full_node::import_snapshot(smoldot_full_node::ImportSnapshotConfig {
+        chain_spec,
+        sqlite_database_path,
+        sqlite_cache_size: cli_options.database_cache_size.0,
+        input,
+    });
+
+    match result {
+        Ok(outcome) => eprintln!(
+            "Imported {} storage entries; finalized block: #{} ({})",
+            outcome.num_entries,
+            outcome.block_number,
+            smoldot::informant::HashDisplay(&outcome.block_hash)
+        ),
+        Err(err) => {
+            eprintln!("{err}");
+            std::process::exit(1)
+        }
+    }
+}
+
+fn check_database(cli_options: cli::CliOptionsCheckDatabase) {
+    let chain_spec = load_chain_spec(&cli_options.chain);
+    let parsed_chain_spec = smoldot::chain_spec::ChainSpec::from_json_bytes(&chain_spec)
+        .expect("Failed to decode chain specification");
+
+    let Some(base) = directories::ProjectDirs::from("io", "smoldot", "smoldot") else {
+        eprintln!("Failed to fetch $HOME directory");
+        std::process::exit(1)
+    };
+    let sqlite_database_path = base
+        .data_dir()
+        .join(parsed_chain_spec.id())
+        .join("database");
+
+    let database =
+        match smoldot::database::full_sqlite::open(smoldot::database::full_sqlite::Config {
+            block_number_bytes: usize::from(parsed_chain_spec.block_number_bytes()),
+            genesis_block_hash: None,
+            extrinsics_index: false,
+            cache_size: cli_options.database_cache_size.0,
+            ty: smoldot::database::full_sqlite::ConfigTy::Disk {
+                path: &sqlite_database_path,
+                memory_map_size: 1000000000, // TODO: make configurable
+            },
+        }) {
+            Ok(smoldot::database::full_sqlite::DatabaseOpen::Open(database)) => database,
+            Ok(smoldot::database::full_sqlite::DatabaseOpen::Empty(_)) => {
+                eprintln!("Database at {} is empty", sqlite_database_path.display());
+                std::process::exit(1)
+            }
+            Err(err) => {
+                eprintln!("Failed to open database: {err}");
+                std::process::exit(1)
+            }
+        };
+
+    match database.check_integrity() {
+        Ok(issues) if issues.is_empty() => eprintln!("No problem found"),
+        Ok(issues) => {
+            for issue in &issues {
+                println!("{issue}");
+            }
+            eprintln!("Found {} problem(s)", issues.len());
+            std::process::exit(1)
+        }
+        Err(err) => {
+            eprintln!("Failed to access database: {err}");
+            std::process::exit(1)
+        }
+    }
+}
+
+fn dry_run_runtime_upgrade(cli_options: cli::CliOptionsDryRunRuntimeUpgrade) {
+    let chain_spec = load_chain_spec(&cli_options.chain);
+    let parsed_chain_spec = smoldot::chain_spec::ChainSpec::from_json_bytes(&chain_spec)
+        .expect("Failed to decode chain specification");
+
+    let Some(base) = directories::ProjectDirs::from("io", "smoldot", "smoldot") else {
+        eprintln!("Failed to fetch $HOME directory");
+        std::process::exit(1)
+    };
+    let sqlite_database_path = base
+        .data_dir()
+        .join(parsed_chain_spec.id())
+        .join("database");
+
+    let runtime_code = fs::read(&cli_options.runtime).expect("Failed to read runtime file");
+
+    let result =
+        smoldot_full_node::dry_run_runtime_upgrade(smoldot_full_node::RuntimeUpgradeDryRunConfig {
+            chain_spec,
+            sqlite_database_path,
+            sqlite_cache_size: cli_options.database_cache_size.0,
+            runtime_code: runtime_code.into(),
+            block_hash: cli_options.block,
+            num_blocks: cli_options.num_blocks,
+            try_runtime_checks: !cli_options.no_try_runtime_checks,
+            max_log_level: match cli_options.runtime_log_level {
+                cli::LogLevel::Off => 0,
+                cli::LogLevel::Error => 1,
+                cli::LogLevel::Warn => 2,
+                cli::LogLevel::Info => 3,
+                cli::LogLevel::Debug => 4,
+                cli::LogLevel::Trace => 5,
+            },
+        });
+
+    let report = match result {
+        Ok(report) => report,
+        Err(err) => {
+            eprintln!("{err}");
+            std::process::exit(1)
+        }
+    };
+
+    let runtime_version = report.runtime_version.decode();
+    println!(
+        "Upgrade of block #{} ({}) to {} version {}",
+        report.block_number,
+        smoldot::informant::HashDisplay(&report.block_hash),
+        runtime_version.spec_name,
+        runtime_version.spec_version
+    );
+
+    let mut success = true;
+
+    println!();
+    println!("Migrations:");
+    match &report.migration.result {
+        Ok(weight) => println!(
+            "  weight: ref_time={} proof_size={} (max block: ref_time={} proof_size={})",
+            weight.consumed.ref_time,
+            weight.consumed.proof_size,
+            weight.max_block.ref_time,
+            weight.max_block.proof_size
+        ),
+        Err(err) => {
+            success = false;
+            println!("  error: {err}");
+        }
+    }
+    print_storage_diff(&report.migration.storage_diff);
+    print_runtime_logs(&report.migration.logs);
+
+    for block in &report.blocks {
+        println!();
+        println!(
+            "Block #{} ({}):",
+            block.number,
+            smoldot::informant::HashDisplay(&block.hash)
+        );
+        for (index, extrinsic) in block.extrinsics.iter().enumerate() {
+            match extrinsic {
+                smoldot_full_node::ExtrinsicOutcome::Success => {
+                    println!("  extrinsic #{index}: success")
+                }
+                smoldot_full_node::ExtrinsicOutcome::DispatchError(error) => {
+                    println!(
+                        "  extrinsic #{index}: dispatch error 0x{}",
+                        hex::encode(error)
+                    )
+                }
+                smoldot_full_node::ExtrinsicOutcome::Invalid(error) => {
+                    println!("  extrinsic #{index}: invalid 0x{}", hex::encode(error))
+                }
+            }
+        }
+        if let Some(weight) = &block.weight {
+            println!(
+                "  weight: normal={}/{} operational={}/{} mandatory={}/{}",
+                weight.normal.ref_time,
+                weight.normal.proof_size,
+                weight.operational.ref_time,
+                weight.operational.proof_size,
+                weight.mandatory.ref_time,
+                weight.mandatory.proof_size
+            );
+        }
+        if let Some(err) = &block.error {
+            success = false;
+            println!("  error: {err}");
+        }
+        print_storage_diff(&block.storage_diff);
+        print_runtime_logs(&block.logs);
+    }
+
+    if !success {
+        std::process::exit(1)
+    }
+}
+
+/// Prints the storage diff of a report of [`smoldot_full_node::dry_run_runtime_upgrade`].
+fn print_storage_diff(storage_diff: &[smoldot_full_node::StorageDiffEntry]) {
+    println!("  storage changes: {}", storage_diff.len());
+    for entry in storage_diff {
+        let display = |value: &Option<Vec<u8>>| match value {
+            Some(value) => format!("0x{}", hex::encode(value)),
+            None => "<none>".to_owned(),
+        };
+        println!(
+            "    0x{}: {} -> {}",
+            hex::encode(&entry.key),
+            display(&entry.old_value),
+            display(&entry.new_value)
+        );
+    }
+}
+
+/// Prints the runtime logs of a report of [`smoldot_full_node::dry_run_runtime_upgrade`].
+fn print_runtime_logs(logs: &str) {
+    for line in logs.lines() {
+        println!("  | {line}");
+    }
+}
+
+/// Returns the content of the chain specification designated by the CLI.
+fn load_chain_spec(chain: &cli::CliChain) -> Cow<'static, [u8]> {
+    match chain {
+        cli::CliChain::Polkadot => {
+            (&include_bytes!("../../demo-chain-specs/polkadot.json")[..]).into()
+        }
+        cli::CliChain::Kusama => (&include_bytes!("../../demo-chain-specs/kusama.json")[..]).into(),
+        cli::CliChain::Westend => {
+            (&include_bytes!("../../demo-chain-specs/westend.json")[..]).into()
+        }
+        cli::CliChain::Custom(path) => fs::read(path).expect("Failed to read chain specs").into(),
     }
 }
 
@@ -186,16 +581,7 @@ async fn run(cli_options: cli::CliOptionsRun) {
         cli::Output::Auto => unreachable!(), // Handled above.
     };
 
-    let chain_spec: Cow<[u8]> = match &cli_options.chain {
-        cli::CliChain::Polkadot => {
-            (&include_bytes!("../../demo-chain-specs/polkadot.json")[..]).into()
-        }
-        cli::CliChain::Kusama => (&include_bytes!("../../demo-chain-specs/kusama.json")[..]).into(),
-        cli::CliChain::Westend => {
-            (&include_bytes!("../../demo-chain-specs/westend.json")[..]).into()
-        }
-        cli::CliChain::Custom(path) => fs::read(path).expect("Failed to read chain specs").into(),
-    };
+    let chain_spec = load_chain_spec(&cli_options.chain);
 
     let parsed_chain_spec = {
         smoldot::chain_spec::ChainSpec::from_json_bytes(&chain_spec)
@@ -227,6 +613,13 @@ async fn run(cli_options: cli::CliOptionsRun) {
     let keystore_path = base_storage_directory
         .as_ref()
         .map(|path| path.join(parsed_chain_spec.id()).join("keys"));
+    // Directory where compiled runtimes are cached. Compiled runtimes don't depend on the chain
+    // and are thus shared between all chains.
+    let runtime_cache_path = cli_options.runtime_cache.clone().or_else(|| {
+        base_storage_directory
+            .as_ref()
+            .map(|path| path.join("runtimes-cache"))
+    });
 
     // Build the relay chain information if relevant.
     let (relay_chain, relay_chain_name) =
@@ -256,13 +649,18 @@ async fn run(cli_options: cli::CliOptionsRun) {
                 chain_spec: spec_json,
                 additional_bootnodes: Vec::new(),
                 keystore_memory: Vec::new(),
-                sqlite_database_path: base_storage_directory
-                    .as_ref()
-                    .map(|d| d.join(parsed_relay_spec.id()).join("database")),
-                sqlite_cache_size: cli_options.relay_chain_database_cache_size.0,
+                database_backend: smoldot_full_node::DatabaseBackend::Sqlite {
+                    path: base_storage_directory
+                        .as_ref()
+                        .map(|d| d.join(parsed_relay_spec.id()).join("database")),
+                    cache_size: cli_options.relay_chain_database_cache_size.0,
+                },
+                extrinsics_index: false,
                 keystore_path: base_storage_directory
                     .as_ref()
                     .map(|path| path.join(parsed_relay_spec.id()).join("keys")),
+                offchain_workers: false,
+                differential_execution: cli_options.differential_execution,
             };
 
             (Some(cfg), Some(relay_chain_name.to_owned()))
@@ -361,9 +759,14 @@ async fn run(cli_options: cli::CliOptionsRun) {
                 .map(|cli::Bootnode { address, peer_id }| (peer_id.clone(), address.clone()))
                 .collect(),
             keystore_memory: cli_options.keystore_memory,
-            sqlite_database_path,
-            sqlite_cache_size: cli_options.database_cache_size.0,
+            database_backend: smoldot_full_node::DatabaseBackend::Sqlite {
+                path: sqlite_database_path,
+                cache_size: cli_options.database_cache_size.0,
+            },
+            extrinsics_index: cli_options.extrinsics_index,
             keystore_path,
+            offchain_workers: cli_options.offchain_workers,
+            differential_execution: cli_options.differential_execution,
         },
         relay_chain,
         libp2p_key,
@@ -375,6 +778,7 @@ async fn run(cli_options: cli::CliOptionsRun) {
         },
         log_callback: log_callback.clone(),
         jaeger_agent: cli_options.jaeger,
+        runtime_cache_path,
         show_informant: matches!(cli_output, cli::Output::Informant),
     })
     .await;
@@ -0,0 +1,1035 @@
+// Smoldot
+// Copyright (C) 2023  Pierre Krieger
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Exporting blocks from the database to a file, and importing blocks from such a file.
+//!
+//! Two file formats are supported:
+//!
+//! - [`BlocksFileFormat::Scale`]: a little endian `u64` containing the number of blocks,
+//!   followed with each block SCALE-encoded one after the other. Each block consists in its
+//!   header, its body, and an optional list of justifications. This is the same format as the
+//!   one used by Substrate's `export-blocks` and `import-blocks` commands.
+//! - [`BlocksFileFormat::Json`]: a JSON array where each item is an object containing the
+//!   hexadecimal-encoded `header`, `body` (list of extrinsics), and `justifications` (list of
+//!   objects containing an `engineId` and a `justification`) of the block.
+//!
+//! Importing blocks is done by feeding the blocks of the file to the syncing state machine, in
+//! the same way as if they had been downloaded from a peer. In other words, every block is fully
+//! verified before being inserted in the database.
+
+use crate::{consensus_service, database_backend, util, LogCallback, LogLevel};
+use smoldot::{
+    chain::blocks_tree,
+    chain_spec,
+    database::full_sqlite,
+    executor, header,
+    informant::HashDisplay,
+    sync::all::{self, TrieEntryVersion},
+    trie,
+    util::decode_scale_compact_usize,
+};
+use std::{
+    borrow::Cow,
+    cmp,
+    collections::VecDeque,
+    io, iter,
+    num::{NonZeroU32, NonZeroU64},
+    path::PathBuf,
+    sync::{Arc, Mutex},
+    time::SystemTime,
+};
+
+/// Format of a file containing blocks.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum BlocksFileFormat {
+    /// Binary format, compatible with Substrate.
+    Scale,
+    /// JSON format, for human readability.
+    Json,
+}
+
+/// Configuration for [`export_blocks`].
+pub struct ExportBlocksConfig<'a> {
+    /// Specification of the chain.
+    pub chain_spec: Cow<'a, [u8]>,
+    /// Path to the SQLite database to read the blocks from.
+    pub sqlite_database_path: PathBuf,
+    /// Maximum size, in bytes, of the cache SQLite uses.
+    pub sqlite_cache_size: usize,
+    /// Number of the first block to export.
+    pub from: u64,
+    /// Number of the last block to export. If `None`, the current finalized block of the
+    /// database.
+    pub to: Option<u64>,
+    /// Format of the output.
+    pub format: BlocksFileFormat,
+    /// Where to write the blocks to.
+    pub output: Box<dyn io::Write + 'a>,
+}
+
+/// Writes the finalized blocks of the database whose number is within the requested range to
+/// the given output.
+///
+/// Returns the number of blocks that have been written.
+pub fn export_blocks(mut config: ExportBlocksConfig) -> Result<u64, ExportBlocksError> {
+    let chain_spec = chain_spec::ChainSpec::from_json_bytes(&config.chain_spec)
+        .map_err(ExportBlocksError::InvalidChainSpec)?;
+    let block_number_bytes = usize::from(chain_spec.block_number_bytes());
+
+    let database = match full_sqlite::open(full_sqlite::Config {
+        block_number_bytes,
+        genesis_block_hash: None,
+        extrinsics_index: false,
+        cache_size: config.sqlite_cache_size,
+        ty: full_sqlite::ConfigTy::Disk {
+            path: &config.sqlite_database_path,
+            memory_map_size: 1000000000, // TODO: make configurable
+        },
+    })
+    .map_err(ExportBlocksError::DatabaseOpen)?
+    {
+        full_sqlite::DatabaseOpen::Open(database) => database,
+        full_sqlite::DatabaseOpen::Empty(_) => return Err(ExportBlocksError::EmptyDatabase),
+    };
+
+    let finalized_block_number = {
+        let hash = database.finalized_block_hash()?;
+        let header = database
+            .block_scale_encoded_header(&hash)?
+            .ok_or(ExportBlocksError::MissingBlock(hash))?;
+        header::decode(&header, block_number_bytes)
+            .map_err(ExportBlocksError::InvalidHeader)?
+            .number
+    };
+
+    let to = config.to.unwrap_or(finalized_block_number);
+    if to > finalized_block_number {
+        return Err(ExportBlocksError::NotFinalized {
+            requested: to,
+            finalized: finalized_block_number,
+        });
+    }
+    if config.from > to {
+        return Err(ExportBlocksError::EmptyRange);
+    }
+
+    let num_blocks = to - config.from + 1;
+    match config.format {
+        BlocksFileFormat::Scale => config.output.write_all(&num_blocks.to_le_bytes())?,
+        BlocksFileFormat::Json => config.output.write_all(b"[\n")?,
+    }
+
+    for number in config.from..=to {
+        // The database only contains one block per height on the finalized chain.
+        let mut hashes = database.block_hash_by_number(number)?;
+        let (Some(hash), 0) = (hashes.next(), hashes.len()) else {
+            return Err(ExportBlocksError::MissingBlockNumber(number));
+        };
+
+        let block = FileBlock {
+            scale_encoded_header: database
+                .block_scale_encoded_header(&hash)?
+                .ok_or(ExportBlocksError::MissingBlock(hash))?,
+            scale_encoded_extrinsics: database
+                .block_extrinsics(&hash)?
+                .ok_or(ExportBlocksError::MissingBlock(hash))?
+                .collect(),
+            // The database only ever stores GrandPa justifications.
+            justifications: database
+                .block_justification(&hash)?
+                .map(|justification| all::Justification {
+                    engine_id: *b"FRNK",
+                    justification,
+                })
+                .into_iter()
+                .collect(),
+        };
+
+        match config.format {
+            BlocksFileFormat::Scale => {
+                for buffer in block.scale_encode() {
+                    config.output.write_all(buffer.as_ref())?;
+                }
+            }
+            BlocksFileFormat::Json => {
+                if number != config.from {
+                    config.output.write_all(b",\n")?;
+                }
+                serde_json::to_writer(&mut config.output, &JsonBlock::from(block))
+                    .map_err(io::Error::from)?;
+            }
+        }
+    }
+
+    if let BlocksFileFormat::Json = config.format {
+        config.output.write_all(b"\n]\n")?;
+    }
+    config.output.flush()?;
+
+    Ok(num_blocks)
+}
+
+/// Error potentially returned by [`export_blocks`].
+#[derive(Debug, derive_more::Display)]
+pub enum ExportBlocksError {
+    /// Failed to parse the chain specification.
+    #[display(fmt = "Failed to decode chain specification: {_0}")]
+    InvalidChainSpec(chain_spec::ParseError),
+    /// Failed to open the database.
+    #[display(fmt = "Failed to open database: {_0}")]
+    DatabaseOpen(full_sqlite::OpenError),
+    /// The database doesn't exist or is empty.
+    #[display(fmt = "Database is empty")]
+    EmptyDatabase,
+    /// Error while accessing the database.
+    #[display(fmt = "Error while accessing the database: {_0}")]
+    DatabaseAccess(full_sqlite::AccessError),
+    /// A header found in the database couldn't be decoded.
+    #[display(fmt = "Invalid header in the database: {_0}")]
+    InvalidHeader(header::Error),
+    /// A block of the finalized chain is missing from the database.
+    #[display(fmt = "Block {} is missing from the database", "HashDisplay(_0)")]
+    MissingBlock([u8; 32]),
+    /// No single finalized block could be found in the database at the given height.
+    #[display(fmt = "No finalized block with number {_0} in the database")]
+    MissingBlockNumber(u64),
+    /// Requested range of blocks goes past the finalized block.
+    #[display(fmt = "Block #{requested} isn't finalized (latest finalized block is #{finalized})")]
+    NotFinalized { requested: u64, finalized: u64 },
+    /// Start of the requested range is after its end.
+    #[display(fmt = "Range of blocks to export is empty")]
+    EmptyRange,
+    /// Error while writing to the output.
+    #[display(fmt = "Failed to write output: {_0}")]
+    Io(io::Error),
+}
+
+impl From<full_sqlite::AccessError> for ExportBlocksError {
+    fn from(err: full_sqlite::AccessError) -> Self {
+        ExportBlocksError::DatabaseAccess(err)
+    }
+}
+
+impl From<io::Error> for ExportBlocksError {
+    fn from(err: io::Error) -> Self {
+        ExportBlocksError::Io(err)
+    }
+}
+
+/// Configuration for [`import_blocks`].
+pub struct ImportBlocksConfig<'a> {
+    /// Specification of the chain.
+    pub chain_spec: Cow<'a, [u8]>,
+    /// Path to the SQLite database to import the blocks into. If `None`, the database is opened
+    /// in memory, which is mostly useful in order to verify the blocks.
+    ///
+    /// The database is created and initialized with the genesis block of the chain if it doesn't
+    /// exist yet.
+    pub sqlite_database_path: Option<PathBuf>,
+    /// Maximum size, in bytes, of the cache SQLite uses.
+    pub sqlite_cache_size: usize,
+    /// Format of the input.
+    pub format: BlocksFileFormat,
+    /// Where to read the blocks from.
+    pub input: Box<dyn io::Read + 'a>,
+    /// Function called whenever the import wants to notify of something.
+    pub log_callback: Arc<dyn LogCallback + Send + Sync>,
+}
+
+/// Outcome of a successful [`import_blocks`].
+#[derive(Debug)]
+pub struct ImportBlocksOutcome {
+    /// Number of blocks that have been verified and inserted in the database.
+    pub num_imported: u64,
+    /// Number of the best block of the database after the import.
+    pub best_block_number: u64,
+    /// Number of the finalized block of the database after the import.
+    pub finalized_block_number: u64,
+}
+
+/// Number of blocks read from the input ahead of their verification.
+const IMPORT_QUEUE_SIZE: usize = 512;
+
+/// Reads blocks from the given input, verifies them, and inserts them in the database.
+///
+/// The blocks of the input must be ordered by increasing number and must form a chain. Blocks
+/// whose number is inferior or equal to the finalized block of the database are ignored.
+///
+/// Blocks that are accompanied with a justification are finalized in the database. The other
+/// blocks are inserted as non-finalized blocks.
+///
+/// The process stops at the first block that fails to verify.
+///
+/// > **Note**: The JSON format is loaded in memory in its entirety before being processed.
+///
+pub async fn import_blocks(
+    config: ImportBlocksConfig<'_>,
+) -> Result<ImportBlocksOutcome, ImportBlocksError> {
+    let chain_spec = chain_spec::ChainSpec::from_json_bytes(&config.chain_spec)
+        .map_err(ImportBlocksError::InvalidChainSpec)?;
+    let block_number_bytes = usize::from(chain_spec.block_number_bytes());
+
+    let genesis_chain_information = chain_spec
+        .to_chain_information()
+        .map_err(ImportBlocksError::InvalidGenesis)?
+        .0;
+
+    let (mut database, _) = crate::open_database(
+        &chain_spec,
+        genesis_chain_information.as_ref(),
+        crate::DatabaseBackend::Sqlite {
+            path: config.sqlite_database_path,
+            cache_size: config.sqlite_cache_size,
+        },
+        false,
+        false,
+    )
+    .await
+    .map_err(ImportBlocksError::DatabaseOpen)?;
+
+    let mut blocks = match config.format {
+        BlocksFileFormat::Scale => {
+            either::Left(ScaleReader::new(config.input, block_number_bytes)?)
+        }
+        BlocksFileFormat::Json => {
+            let blocks: Vec<JsonBlock> = serde_json::from_reader(config.input)
+                .map_err(|err| ImportBlocksError::InvalidFile(err.to_string()))?;
+            either::Right(blocks.into_iter().map(FileBlock::try_from))
+        }
+    };
+
+    // Load the information about the finalized block of the database, which is where the
+    // import starts from.
+    let finalized_block_hash = database.finalized_block_hash()?;
+    let finalized_block_number = header::decode(
+        &database
+            .block_scale_encoded_header(&finalized_block_hash)?
+            .ok_or(ImportBlocksError::CorruptedDatabase)?,
+        block_number_bytes,
+    )
+    .map_err(|_| ImportBlocksError::CorruptedDatabase)?
+    .number;
+
+    let mut finalized_runtime = {
+        let code = database
+            .block_storage_get(
+                &finalized_block_hash,
+                &[],
+                &trie::bytes_to_nibbles(b":code".iter().copied())
+                    .map(u8::from)
+                    .collect::<Vec<_>>(),
+            )
+            .map_err(|_| ImportBlocksError::CorruptedDatabase)?
+            .ok_or(ImportBlocksError::CorruptedDatabase)?
+            .0;
+        let heap_pages = database
+            .block_storage_get(
+                &finalized_block_hash,
+                &[],
+                &trie::bytes_to_nibbles(b":heappages".iter().copied())
+                    .map(u8::from)
+                    .collect::<Vec<_>>(),
+            )
+            .map_err(|_| ImportBlocksError::CorruptedDatabase)?
+            .map(|(hp, _)| hp);
+        // Assumed to always be valid, otherwise the block wouldn't have been saved in the
+        // database.
+        let runtime = executor::host::HostVmPrototype::new(executor::host::Config {
+            module: code,
+            heap_pages: executor::storage_heap_pages_to_value(heap_pages.as_deref())
+                .map_err(|_| ImportBlocksError::CorruptedDatabase)?,
+            exec_hint: executor::vm::ExecHint::CompileAheadOfTime,
+            compilation_cache: None,
+            allow_unresolved_imports: false,
+        })
+        .map_err(|_| ImportBlocksError::CorruptedDatabase)?;
+        Arc::new(Mutex::new(Some(runtime)))
+    };
+
+    let mut sync = all::AllSync::<
+        (),
+        (),
+        Option<Arc<Mutex<Option<executor::host::HostVmPrototype>>>>,
+    >::new(all::Config {
+        chain_information: database
+            .to_chain_information(&finalized_block_hash)
+            .map_err(|_| ImportBlocksError::CorruptedDatabase)?,
+        block_number_bytes,
+        allow_unknown_consensus_engines: false,
+        sources_capacity: 1,
+        blocks_capacity: IMPORT_QUEUE_SIZE,
+        max_disjoint_headers: 0,
+        max_requests_per_block: NonZeroU32::new(1).unwrap(),
+        download_ahead_blocks: NonZeroU32::new(u32::try_from(IMPORT_QUEUE_SIZE).unwrap()).unwrap(),
+        full_mode: true,
+    });
+
+    // The file is presented to the syncing state machine as a source that knows all the blocks
+    // that have been read from the file so far.
+    let source_id = sync.add_source((), finalized_block_number, finalized_block_hash);
+
+    // Blocks read from the file and not verified yet. The first block of the list is always
+    // the child of the best block of `sync`.
+    let mut queue = VecDeque::<FileBlock>::with_capacity(IMPORT_QUEUE_SIZE);
+    let mut input_finished = false;
+    let mut num_imported = 0;
+
+    loop {
+        let mut progress = false;
+
+        // Fill the queue with blocks from the file.
+        let mut announce = None;
+        while !input_finished && queue.len() < IMPORT_QUEUE_SIZE {
+            let Some(block) = blocks.next().transpose()? else {
+                input_finished = true;
+                break;
+            };
+
+            let number = header::decode(&block.scale_encoded_header, block_number_bytes)
+                .map_err(ImportBlocksError::InvalidHeader)?
+                .number;
+            if number <= finalized_block_number {
+                continue;
+            }
+
+            let expected = sync.best_block_number() + 1 + u64::try_from(queue.len()).unwrap();
+            if number != expected {
+                return Err(ImportBlocksError::NonSequentialBlock {
+                    expected,
+                    found: number,
+                });
+            }
+
+            announce = Some(block.scale_encoded_header.clone());
+            queue.push_back(block);
+        }
+        if let Some(announce) = announce {
+            let _ = sync.block_announce(source_id, announce, true);
+        }
+
+        // Answer the requests that the syncing state machine wants to perform using the queue.
+        loop {
+            let request = sync.desired_requests().map(|(_, _, rq)| rq).next();
+            let Some(mut request) = request else { break };
+            request.num_blocks_clamp(NonZeroU64::new(64).unwrap());
+
+            let all::DesiredRequest::BlocksRequest {
+                first_block_height,
+                ascending: true,
+                num_blocks,
+                ..
+            } = request
+            else {
+                unreachable!()
+            };
+
+            let start = first_block_height
+                .checked_sub(sync.best_block_number() + 1)
+                .and_then(|n| usize::try_from(n).ok())
+                .filter(|n| *n < queue.len())
+                .ok_or(ImportBlocksError::Stalled {
+                    block_number: first_block_height,
+                })?;
+            let end = cmp::min(
+                queue.len(),
+                start.saturating_add(usize::try_from(num_blocks.get()).unwrap_or(usize::MAX)),
+            );
+
+            let request_id = sync.add_request(source_id, request.into(), ());
+            let _ = sync.blocks_request_response(
+                request_id,
+                Ok(queue
+                    .range(start..end)
+                    .map(|block| all::BlockRequestSuccessBlock {
+                        scale_encoded_header: block.scale_encoded_header.clone(),
+                        scale_encoded_extrinsics: block.scale_encoded_extrinsics.clone(),
+                        scale_encoded_justifications: block.justifications.clone(),
+                        user_data: None,
+                    })
+                    // Collecting in order to release the borrow on `sync`.
+                    .collect::<Vec<_>>()
+                    .into_iter()),
+            );
+            progress = true;
+        }
+
+        // Verify the blocks.
+        loop {
+            let unix_time = SystemTime::now()
+                .duration_since(SystemTime::UNIX_EPOCH)
+                .unwrap();
+
+            match sync.process_one() {
+                all::ProcessOne::AllSync(idle) => {
+                    sync = idle;
+                    break;
+                }
+                all::ProcessOne::VerifyWarpSyncFragment(_)
+                | all::ProcessOne::WarpSyncBuildRuntime(_)
+                | all::ProcessOne::WarpSyncBuildChainInformation(_)
+                | all::ProcessOne::WarpSyncFinished { .. } => unreachable!(),
+                all::ProcessOne::VerifyHeader(verify) => {
+                    let hash_to_verify = verify.hash();
+                    match verify.perform(unix_time, None) {
+                        all::HeaderVerifyOutcome::Success { sync: sync_out, .. } => sync = sync_out,
+                        all::HeaderVerifyOutcome::Error { error, .. } => {
+                            return Err(ImportBlocksError::HeaderVerification {
+                                hash: hash_to_verify,
+                                error,
+                            })
+                        }
+                    }
+                }
+                all::ProcessOne::VerifyBodyHeader(verify) => {
+                    let hash_to_verify = verify.hash();
+                    let height_to_verify = verify.height();
+                    let parent_hash = verify.parent_hash();
+                    let parent_runtime_arc = verify
+                        .parent_user_data()
+                        .map(|rt| rt.clone().unwrap())
+                        .unwrap_or_else(|| finalized_runtime.clone());
+                    let parent_runtime = parent_runtime_arc.lock().unwrap().take().unwrap();
+                    let scale_encoded_header = verify.scale_encoded_header().to_owned();
+                    let scale_encoded_extrinsics = verify.scale_encoded_extrinsics().to_vec();
+
+                    let mut verify = verify.start(unix_time, parent_runtime, None);
+                    loop {
+                        match verify {
+                            all::BlockVerification::Error { error, .. } => {
+                                return Err(ImportBlocksError::BlockVerification {
+                                    hash: hash_to_verify,
+                                    error,
+                                });
+                            }
+                            all::BlockVerification::Success {
+                                is_new_best,
+                                sync: mut sync_out,
+                                storage_changes,
+                                state_trie_version,
+                                parent_runtime,
+                                new_runtime,
+                                ..
+                            } => {
+                                match database.insert(
+                                    &scale_encoded_header,
+                                    is_new_best,
+                                    &scale_encoded_extrinsics,
+                                    &mut consensus_service::insert_trie_nodes(&storage_changes),
+                                    u8::from(state_trie_version),
+                                ) {
+                                    Ok(()) => {}
+                                    // A previous import might have been interrupted before
+                                    // finalizing the blocks it has inserted.
+                                    Err(database_backend::InsertError::Duplicate) => {}
+                                    Err(err) => return Err(ImportBlocksError::DatabaseInsert(err)),
+                                }
+
+                                *parent_runtime_arc.lock().unwrap() = Some(parent_runtime);
+                                sync_out[(height_to_verify, &hash_to_verify)] =
+                                    Some(if let Some(new_runtime) = new_runtime {
+                                        Arc::new(Mutex::new(Some(new_runtime)))
+                                    } else {
+                                        parent_runtime_arc
+                                    });
+                                sync = sync_out;
+
+                                queue.pop_front();
+                                num_imported += 1;
+
+                                config.log_callback.log(
+                                    LogLevel::Debug,
+                                    format!(
+                                        "import-block; hash={}; height={}",
+                                        HashDisplay(&hash_to_verify),
+                                        height_to_verify
+                                    ),
+                                );
+                                break;
+                            }
+                            all::BlockVerification::ParentStorageGet(req) => {
+                                let parent_paths = database_backend::child_trie_parent_paths(
+                                    req.child_trie().as_ref().map(|c| c.as_ref()),
+                                );
+                                let value = database
+                                    .block_storage_get(
+                                        &parent_hash,
+                                        &parent_paths,
+                                        &trie::bytes_to_nibbles(req.key().as_ref().iter().copied())
+                                            .map(u8::from)
+                                            .collect::<Vec<_>>(),
+                                    )
+                                    .map_err(ImportBlocksError::StorageAccess)?;
+                                let value = match &value {
+                                    Some((val, vers)) => Some((
+                                        &val[..],
+                                        TrieEntryVersion::try_from(*vers)
+                                            .map_err(|_| ImportBlocksError::CorruptedDatabase)?,
+                                    )),
+                                    None => None,
+                                };
+                                verify = req.inject_value(value);
+                            }
+                            all::BlockVerification::ParentStorageMerkleValue(req) => {
+                                let parent_paths = database_backend::child_trie_parent_paths(
+                                    req.child_trie().as_ref().map(|c| c.as_ref()),
+                                );
+                                let merkle_value = database
+                                    .block_storage_closest_descendant_merkle_value(
+                                        &parent_hash,
+                                        &parent_paths,
+                                        &req.key().map(u8::from).collect::<Vec<_>>(),
+                                    )
+                                    .map_err(ImportBlocksError::StorageAccess)?;
+                                verify =
+                                    req.inject_merkle_value(merkle_value.as_ref().map(|v| &v[..]));
+                            }
+                            all::BlockVerification::ParentStorageNextKey(req) => {
+                                let parent_paths = database_backend::child_trie_parent_paths(
+                                    req.child_trie().as_ref().map(|c| c.as_ref()),
+                                );
+                                let next_key = database
+                                    .block_storage_next_key(
+                                        &parent_hash,
+                                        &parent_paths,
+                                        &req.key()
+                                            .map(u8::from)
+                                            .chain(if req.or_equal() { None } else { Some(0u8) })
+                                            .collect::<Vec<_>>(),
+                                        &req.prefix().map(u8::from).collect::<Vec<_>>(),
+                                        req.branch_nodes(),
+                                    )
+                                    .map_err(ImportBlocksError::StorageAccess)?;
+                                verify = req.inject_key(next_key.map(|k| {
+                                    k.into_iter().map(|b| trie::Nibble::try_from(b).unwrap())
+                                }));
+                            }
+                            all::BlockVerification::RuntimeCompilation(rt) => {
+                                verify = rt.build(None);
+                            }
+                        }
+                    }
+                }
+                all::ProcessOne::VerifyFinalityProof(verify) => {
+                    match verify.perform(rand::random()) {
+                        (
+                            sync_out,
+                            all::FinalityProofVerifyOutcome::NewFinalized {
+                                mut finalized_blocks,
+                                ..
+                            },
+                        ) => {
+                            sync = sync_out;
+                            // The order of `finalized_blocks` depends on the syncing strategy.
+                            finalized_blocks.sort_unstable_by_key(|b| b.header.number);
+                            let justifications = finalized_blocks
+                                .iter()
+                                .flat_map(|block| {
+                                    let hash = block.header.hash(sync.block_number_bytes());
+                                    block
+                                        .justifications
+                                        .iter()
+                                        .filter(|(engine_id, _)| engine_id == b"FRNK")
+                                        .map(move |(_, justification)| {
+                                            (hash, justification.clone())
+                                        })
+                                })
+                                .collect::<Vec<_>>();
+                            let finalized_block = finalized_blocks.pop().unwrap();
+                            finalized_runtime = finalized_block.user_data.unwrap();
+                            database
+                                .set_finalized(
+                                    &finalized_block.header.hash(sync.block_number_bytes()),
+                                )
+                                .map_err(ImportBlocksError::SetFinalized)?;
+                            for (hash, justification) in justifications {
+                                database
+                                    .set_block_justification(&hash, &justification)
+                                    .map_err(ImportBlocksError::SetJustification)?;
+                            }
+                        }
+                        (sync_out, all::FinalityProofVerifyOutcome::GrandpaCommitPending)
+                        | (sync_out, all::FinalityProofVerifyOutcome::AlreadyFinalized) => {
+                            sync = sync_out
+                        }
+                        (_, all::FinalityProofVerifyOutcome::GrandpaCommitError(_)) => {
+                            // Commits are never provided to the syncing.
+                            unreachable!()
+                        }
+                        (_, all::FinalityProofVerifyOutcome::JustificationError(error)) => {
+                            return Err(ImportBlocksError::JustificationVerification(error))
+                        }
+                    }
+                }
+            }
+
+            progress = true;
+        }
+
+        if !progress {
+            if !queue.is_empty() {
+                return Err(ImportBlocksError::Stalled {
+                    block_number: sync.best_block_number() + 1,
+                });
+            }
+
+            debug_assert!(input_finished);
+            break;
+        }
+    }
+
+    Ok(ImportBlocksOutcome {
+        num_imported,
+        best_block_number: sync.best_block_number(),
+        finalized_block_number: sync.finalized_block_header().number,
+    })
+}
+
+/// Error potentially returned by [`import_blocks`].
+#[derive(Debug, derive_more::Display)]
+pub enum ImportBlocksError {
+    /// Failed to parse the chain specification.
+    #[display(fmt = "Failed to decode chain specification: {_0}")]
+    InvalidChainSpec(chain_spec::ParseError),
+    /// Failed to build the chain information of the genesis block from the chain specification.
+    #[display(fmt = "Invalid genesis storage: {_0}")]
+    InvalidGenesis(chain_spec::FromGenesisStorageError),
+    /// Failed to open the database.
+    #[display(fmt = "Failed to open database: {_0}")]
+    DatabaseOpen(full_sqlite::OpenError),
+    /// Error while reading the input.
+    #[display(fmt = "Failed to read input: {_0}")]
+    Io(io::Error),
+    /// The input isn't a valid blocks file.
+    #[display(fmt = "Invalid blocks file: {_0}")]
+    InvalidFile(String),
+    /// Failed to decode the header of a block of the input.
+    #[display(fmt = "Invalid block header: {_0}")]
+    InvalidHeader(header::Error),
+    /// Blocks of the input aren't consecutive, or don't start right after the finalized block
+    /// of the database.
+    #[display(fmt = "Expected block #{expected} in the input, found block #{found} instead")]
+    NonSequentialBlock { expected: u64, found: u64 },
+    /// Error while accessing the database.
+    #[display(fmt = "Error while accessing the database: {_0}")]
+    DatabaseAccess(database_backend::AccessError),
+    /// Error while accessing the storage of a block in the database.
+    #[display(fmt = "Error while accessing the database: {_0}")]
+    StorageAccess(database_backend::StorageAccessError),
+    /// The content of the database is invalid.
+    #[display(fmt = "Database is corrupted")]
+    CorruptedDatabase,
+    /// Error while inserting a block in the database.
+    #[display(fmt = "Failed to insert block in the database: {_0}")]
+    DatabaseInsert(database_backend::InsertError),
+    /// Error while finalizing a block in the database.
+    #[display(fmt = "Failed to finalize block in the database: {_0}")]
+    SetFinalized(database_backend::SetFinalizedError),
+    /// Error while storing a justification in the database.
+    #[display(fmt = "Failed to store justification in the database: {_0}")]
+    SetJustification(database_backend::SetJustificationError),
+    /// A block header of the input has failed to verify.
+    #[display(fmt = "Failed to verify block {}: {error}", "HashDisplay(hash)")]
+    HeaderVerification {
+        hash: [u8; 32],
+        error: all::HeaderVerifyError,
+    },
+    /// A block of the input has failed to verify.
+    #[display(fmt = "Failed to verify block {}: {error}", "HashDisplay(hash)")]
+    BlockVerification {
+        hash: [u8; 32],
+        error: all::BlockVerificationError,
+    },
+    /// A justification of the input has failed to verify.
+    #[display(fmt = "Failed to verify justification: {_0}")]
+    JustificationVerification(blocks_tree::JustificationVerifyError),
+    /// The syncing state machine didn't accept a block of the input.
+    #[display(fmt = "Failed to import block #{block_number}")]
+    Stalled { block_number: u64 },
+}
+
+impl From<database_backend::AccessError> for ImportBlocksError {
+    fn from(err: database_backend::AccessError) -> Self {
+        ImportBlocksError::DatabaseAccess(err)
+    }
+}
+
+impl From<io::Error> for ImportBlocksError {
+    fn from(err: io::Error) -> Self {
+        ImportBlocksError::Io(err)
+    }
+}
+
+/// Block found in a blocks file.
+struct FileBlock {
+    scale_encoded_header: Vec<u8>,
+    /// List of SCALE-encoded extrinsics. Each extrinsic includes its length prefix.
+    scale_encoded_extrinsics: Vec<Vec<u8>>,
+    justifications: Vec<all::Justification>,
+}
+
+impl FileBlock {
+    /// Returns a list of buffers that, when concatenated together, form the SCALE encoding of
+    /// the block.
+    fn scale_encode(&'_ self) -> impl Iterator<Item = impl AsRef<[u8]> + '_> + '_ {
+        let body = iter::once(either::Left(util::encode_compact(
+            self.scale_encoded_extrinsics.len(),
+        )))
+        .chain(
+            self.scale_encoded_extrinsics
+                .iter()
+                .map(|e| either::Right(&e[..])),
+        );
+
+        let justifications = if self.justifications.is_empty() {
+            either::Left(iter::once(either::Right(&[0u8][..])))
+        } else {
+            either::Right(
+                iter::once(either::Right(&[1u8][..]))
+                    .chain(iter::once(either::Left(util::encode_compact(
+                        self.justifications.len(),
+                    ))))
+                    .chain(self.justifications.iter().flat_map(|j| {
+                        [
+                            either::Right(&j.engine_id[..]),
+                            either::Left(util::encode_compact(j.justification.len())),
+                            either::Right(&j.justification[..]),
+                        ]
+                    })),
+            )
+        };
+
+        iter::once(either::Right(&self.scale_encoded_header[..]))
+            .chain(body)
+            .chain(justifications)
+    }
+
+    /// Decodes a SCALE-encoded block found at the start of `bytes`. Returns the block and the
+    /// number of bytes that it occupies.
+    fn scale_decode(bytes: &[u8], block_number_bytes: usize) -> Result<(Self, usize), String> {
+        let (_, after_header) =
+            header::decode_partial(bytes, block_number_bytes).map_err(|err| err.to_string())?;
+        let scale_encoded_header = bytes[..bytes.len() - after_header.len()].to_vec();
+
+        let (num_extrinsics, mut remain) = decode_scale_compact_usize(after_header)
+            .ok_or_else(|| "Invalid number of extrinsics".to_string())?;
+        let mut scale_encoded_extrinsics = Vec::with_capacity(cmp::min(num_extrinsics, 1024));
+        for _ in 0..num_extrinsics {
+            let (len, after_len) = decode_scale_compact_usize(remain)
+                .ok_or_else(|| "Invalid extrinsic length".to_string())?;
+            let total_len = (remain.len() - after_len.len())
+                .checked_add(len)
+                .filter(|l| *l <= remain.len())
+                .ok_or_else(|| "Truncated extrinsic".to_string())?;
+            scale_encoded_extrinsics.push(remain[..total_len].to_vec());
+            remain = &remain[total_len..];
+        }
+
+        let mut justifications = Vec::new();
+        match remain.first() {
+            Some(0) => remain = &remain[1..],
+            Some(1) => {
+                let (num_justifications, after_num) = decode_scale_compact_usize(&remain[1..])
+                    .ok_or_else(|| "Invalid number of justifications".to_string())?;
+                remain = after_num;
+                for _ in 0..num_justifications {
+                    let engine_id = <[u8; 4]>::try_from(
+                        remain
+                            .get(..4)
+                            .ok_or_else(|| "Truncated justification".to_string())?,
+                    )
+                    .unwrap();
+                    let (len, after_len) = decode_scale_compact_usize(&remain[4..])
+                        .ok_or_else(|| "Invalid justification length".to_string())?;
+                    let justification = after_len
+                        .get(..len)
+                        .ok_or_else(|| "Truncated justification".to_string())?
+                        .to_vec();
+                    remain = &after_len[len..];
+                    justifications.push(all::Justification {
+                        engine_id,
+                        justification,
+                    });
+                }
+            }
+            _ => return Err("Invalid justifications".to_string()),
+        }
+
+        let block = FileBlock {
+            scale_encoded_header,
+            scale_encoded_extrinsics,
+            justifications,
+        };
+        Ok((block, bytes.len() - remain.len()))
+    }
+}
+
+/// Iterator over the blocks of a file in [`BlocksFileFormat::Scale`].
+struct ScaleReader<R> {
+    inner: R,
+    block_number_bytes: usize,
+    /// Number of blocks remaining to be read, as indicated at the start of the file.
+    remaining_blocks: u64,
+    /// Data read from `inner` but not decoded yet.
+    buffer: Vec<u8>,
+    /// `true` if `inner` has reached its end.
+    eof: bool,
+}
+
+impl<R: io::Read> ScaleReader<R> {
+    fn new(mut inner: R, block_number_bytes: usize) -> Result<Self, ImportBlocksError> {
+        let mut num_blocks = [0; 8];
+        inner.read_exact(&mut num_blocks)?;
+        Ok(ScaleReader {
+            inner,
+            block_number_bytes,
+            remaining_blocks: u64::from_le_bytes(num_blocks),
+            buffer: Vec::new(),
+            eof: false,
+        })
+    }
+}
+
+impl<R: io::Read> Iterator for ScaleReader<R> {
+    type Item = Result<FileBlock, ImportBlocksError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining_blocks == 0 {
+            return None;
+        }
+
+        // Since the size of a block can only be known by decoding it, we try to decode the
+        // content of the buffer and read more data from the input as long as this fails.
+        loop {
+            let error = match FileBlock::scale_decode(&self.buffer, self.block_number_bytes) {
+                Ok((block, len)) => {
+                    self.buffer.drain(..len);
+                    self.remaining_blocks -= 1;
+                    return Some(Ok(block));
+                }
+                Err(error) => error,
+            };
+
+            if self.eof {
+                self.remaining_blocks = 0;
+                return Some(Err(ImportBlocksError::InvalidFile(error)));
+            }
+
+            // Read at least as many bytes as there already are in the buffer, in order to avoid
+            // decoding large blocks too many times.
+            let previous_len = self.buffer.len();
+            self.buffer
+                .resize(previous_len + cmp::max(previous_len, 64 * 1024), 0);
+            match self.inner.read(&mut self.buffer[previous_len..]) {
+                Ok(0) => {
+                    self.buffer.truncate(previous_len);
+                    self.eof = true;
+                }
+                Ok(n) => self.buffer.truncate(previous_len + n),
+                Err(err) if err.kind() == io::ErrorKind::Interrupted => {
+                    self.buffer.truncate(previous_len);
+                }
+                Err(err) => {
+                    self.remaining_blocks = 0;
+                    return Some(Err(ImportBlocksError::Io(err)));
+                }
+            }
+        }
+    }
+}
+
+/// Block in the [`BlocksFileFormat::Json`] format.
+#[derive(serde::Serialize, serde::Deserialize)]
+struct JsonBlock {
+    header: HexString,
+    body: Vec<HexString>,
+    #[serde(default)]
+    justifications: Vec<JsonJustification>,
+}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+struct JsonJustification {
+    #[serde(rename = "engineId")]
+    engine_id: HexString,
+    justification: HexString,
+}
+
+impl From<FileBlock> for JsonBlock {
+    fn from(block: FileBlock) -> JsonBlock {
+        JsonBlock {
+            header: HexString(block.scale_encoded_header),
+            body: block
+                .scale_encoded_extrinsics
+                .into_iter()
+                .map(HexString)
+                .collect(),
+            justifications: block
+                .justifications
+                .into_iter()
+                .map(|j| JsonJustification {
+                    engine_id: HexString(j.engine_id.to_vec()),
+                    justification: HexString(j.justification),
+                })
+                .collect(),
+        }
+    }
+}
+
+impl TryFrom<JsonBlock> for FileBlock {
+    type Error = ImportBlocksError;
+
+    fn try_from(block: JsonBlock) -> Result<FileBlock, ImportBlocksError> {
+        Ok(FileBlock {
+            scale_encoded_header: block.header.0,
+            scale_encoded_extrinsics: block.body.into_iter().map(|e| e.0).collect(),
+            justifications: block
+                .justifications
+                .into_iter()
+                .map(|j| {
+                    Ok::<_, ImportBlocksError>(all::Justification {
+                        engine_id: <[u8; 4]>::try_from(j.engine_id.0).map_err(|_| {
+                            ImportBlocksError::InvalidFile("Invalid engine id".to_string())
+                        })?,
+                        justification: j.justification.0,
+                    })
+                })
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+/// Hexadecimal-encoded bytes, prefixed with `0x`.
+struct HexString(Vec<u8>);
+
+impl serde::Serialize for HexString {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        format!("0x{}", hex::encode(&self.0)).serialize(serializer)
+    }
+}
+
+impl<'a> serde::Deserialize<'a> for HexString {
+    fn deserialize<D: serde::Deserializer<'a>>(deserializer: D) -> Result<Self, D::Error> {
+        let string = String::deserialize(deserializer)?;
+        let Some(hex) = string.strip_prefix("0x") else {
+            return Err(serde::de::Error::custom(
+                "hexadecimal string doesn't start with 0x",
+            ));
+        };
+        hex::decode(hex)
+            .map(HexString)
+            .map_err(|err| serde::de::Error::custom(err.to_string()))
+    }
+}
@@ -24,7 +24,10 @@
 // TODO: doc
 // TODO: re-review this once finished
 
-use crate::{database_thread, jaeger_service, network_service, LogCallback, LogLevel};
+use crate::{
+    database_backend, database_thread, differential_execution, jaeger_service, network_service,
+    offchain_worker, runtime_cache, LogCallback, LogLevel,
+};
 
 use core::num::NonZeroU32;
 use futures_channel::{mpsc, oneshot};
@@ -34,8 +37,7 @@ use smol::lock::Mutex;
 use smoldot::{
     author,
     chain::chain_information,
-    database::full_sqlite,
-    executor, header,
+    database, executor, header,
     identity::keystore,
     informant::HashDisplay,
     libp2p,
@@ -48,10 +50,15 @@ use std::{
     borrow::Cow,
     iter, mem,
     num::NonZeroU64,
-    sync::Arc,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
     time::{Duration, Instant, SystemTime, UNIX_EPOCH},
 };
 
+mod tests;
+
 /// Configuration for a [`ConsensusService`].
 pub struct Config {
     /// Closure that spawns background tasks.
@@ -103,6 +110,23 @@ pub struct Config {
     /// Note that this value doesn't determine the moment when creating the block has ended, but
     /// the moment when creating the block should start its final phase.
     pub slot_duration_author_ratio: u16,
+
+    /// If `true`, the off-chain worker of the runtime is executed after each new best block.
+    pub offchain_workers: bool,
+
+    /// If `true`, each verified block is executed a second time with both the interpreter and
+    /// the JIT, and the two executions are compared. Ignored if the JIT isn't available on the
+    /// current platform.
+    pub differential_execution: bool,
+
+    /// Cache where compiled runtimes are loaded from and stored to. If `None`, runtimes are
+    /// always compiled.
+    pub runtime_cache: Option<Arc<runtime_cache::RuntimeCache>>,
+
+    /// List of runtime codes that replace the on-chain runtime code, alongside with the block
+    /// number they are registered at.
+    /// See [`smoldot::chain_spec::ChainSpec::code_substitutes`].
+    pub code_substitutes: Vec<(u64, Vec<u8>)>,
 }
 
 /// Identifier for a blocks request to be performed.
@@ -173,8 +197,10 @@ impl ConsensusService {
                     let finalized_code = database
                         .block_storage_get(
                             &finalized_block_hash,
-                            iter::empty::<iter::Empty<_>>(),
-                            trie::bytes_to_nibbles(b":code".iter().copied()).map(u8::from),
+                            &[],
+                            &trie::bytes_to_nibbles(b":code".iter().copied())
+                                .map(u8::from)
+                                .collect::<Vec<_>>(),
                         )
                         .unwrap()
                         .unwrap() // TODO: better error?
@@ -182,8 +208,10 @@ impl ConsensusService {
                     let finalized_heap_pages = database
                         .block_storage_get(
                             &finalized_block_hash,
-                            iter::empty::<iter::Empty<_>>(),
-                            trie::bytes_to_nibbles(b":heappages".iter().copied()).map(u8::from),
+                            &[],
+                            &trie::bytes_to_nibbles(b":heappages".iter().copied())
+                                .map(u8::from)
+                                .collect::<Vec<_>>(),
                         )
                         .unwrap() // TODO: better error?
                         .map(|(hp, _)| hp);
@@ -252,15 +280,55 @@ impl ConsensusService {
                 module: finalized_code,
                 heap_pages,
                 exec_hint: executor::vm::ExecHint::CompileAheadOfTime, // TODO: probably should be decided by the optimisticsync
+                compilation_cache: config
+                    .runtime_cache
+                    .as_deref()
+                    .map(|c| c as &dyn executor::vm::CompilationCache),
                 allow_unresolved_imports: false,
             })
             .unwrap() // TODO: better error message?
         };
 
+        // The `spec_version` of each code substitute is calculated the first time it is needed.
+        let mut code_substitutes = config
+            .code_substitutes
+            .into_iter()
+            .map(|(block_number, code)| CodeSubstitute {
+                block_number,
+                code,
+                spec_version: None,
+            })
+            .collect::<Vec<_>>();
+
+        // The runtime found in the database is the on-chain runtime, and might have to be
+        // replaced with a code substitute.
+        let finalized_runtime = build_code_substitute(
+            &mut code_substitutes,
+            finalized_block_number,
+            finalized_runtime.runtime_version().decode().spec_version,
+            finalized_runtime.heap_pages(),
+            config.runtime_cache.as_deref(),
+            &*config.log_callback,
+        )
+        .unwrap_or(finalized_runtime);
+
         let block_author_sync_source = sync.add_source(None, best_block_number, best_block_hash);
 
         let (block_requests_finished_tx, block_requests_finished_rx) = mpsc::channel(0);
         let (to_background_tx, to_background_rx) = mpsc::channel(4);
+        let (transactions_tx, transactions_rx) = mpsc::channel(64);
+
+        let differential_execution = if !config.differential_execution {
+            None
+        } else if differential_execution::is_supported() {
+            Some(Default::default())
+        } else {
+            config.log_callback.log(
+                LogLevel::Warn,
+                "differential-execution-unsupported; reason=jit-unavailable".to_string(),
+            );
+            None
+        };
 
         let background_sync = SyncBackground {
             sync,
@@ -281,6 +349,13 @@ impl ConsensusService {
             block_requests_finished_tx,
             block_requests_finished_rx,
             jaeger_service: config.jaeger_service,
+            offchain_workers: config.offchain_workers,
+            differential_execution,
+            runtime_cache: config.runtime_cache,
+            code_substitutes,
+            offchain_worker_running: Arc::new(AtomicBool::new(false)),
+            transactions_tx,
+            transactions_rx,
         };
 
         background_sync.start();
@@ -407,6 +482,31 @@ struct SyncBackground {
 
     /// How to report events about blocks.
     jaeger_service: Arc<jaeger_service::JaegerService>,
+
+    /// See [`Config::offchain_workers`].
+    offchain_workers: bool,
+
+    /// If `Some`, differential execution is enabled. See [`Config::differential_execution`].
+    differential_execution: Option<differential_execution::RuntimesCache>,
+
+    /// See [`Config::runtime_cache`].
+    runtime_cache: Option<Arc<runtime_cache::RuntimeCache>>,
+
+    /// See [`Config::code_substitutes`].
+    code_substitutes: Vec<CodeSubstitute>,
+
+    /// `true` if an off-chain worker is currently running. Shared with the task running the
+    /// off-chain worker.
+    offchain_worker_running: Arc<AtomicBool>,
+
+    /// Sending side of [`SyncBackground::transactions_rx`]. Cloned and passed to the off-chain
+    /// workers.
+    transactions_tx: mpsc::Sender<Vec<u8>>,
+
+    /// Transactions submitted by the off-chain workers and waiting to be included in a block
+    /// authored locally. These transactions have been validated and announced to the
+    /// peer-to-peer network by the off-chain workers.
+    transactions_rx: mpsc::Receiver<Vec<u8>>,
 }
 
 #[derive(Clone)]
@@ -730,7 +830,6 @@ impl SyncBackground {
         // Most parts of the block authorship can't be accelerated, in particular the
         // initialization and the signing at the end. This end of authoring threshold is only
         // checked when deciding whether to continue including more transactions in the block.
-        // TODO: use this
         // TODO: Substrate nodes increase the time available for authoring if it detects that slots have been skipped, in order to account for the possibility that the initialization of a block or the inclusion of an extrinsic takes too long
         let authoring_end = {
             let start = authoring_start.slot_start_from_unix_epoch();
@@ -837,8 +936,10 @@ impl SyncBackground {
                     // Part of the block production consists in adding transactions to the block.
                     // These transactions are extracted from the transactions pool.
                     author::build::BuilderAuthoring::ApplyExtrinsic(apply) => {
-                        // TODO: actually implement including transactions in the blocks
-                        block_authoring = apply.finish();
+                        block_authoring = match self.next_transaction_to_include(authoring_end) {
+                            Some(transaction) => apply.add_extrinsic(transaction),
+                            None => apply.finish(),
+                        };
                     }
                     author::build::BuilderAuthoring::ApplyExtrinsicResult { result, resume } => {
                         if let Err(error) = result {
@@ -852,14 +953,17 @@ impl SyncBackground {
                             );
                         }
 
-                        // TODO: actually implement including transactions in the blocks
-                        block_authoring = resume.finish();
+                        // Transactions that fail to be included are simply discarded.
+                        block_authoring = match self.next_transaction_to_include(authoring_end) {
+                            Some(transaction) => resume.add_extrinsic(transaction),
+                            None => resume.finish(),
+                        };
                     }
 
                     // Access to the best block storage.
                     author::build::BuilderAuthoring::StorageGet(req) => {
                         let parent_paths = req.child_trie().map(|child_trie| {
-                            trie::bytes_to_nibbles(b":child_storage:default:".iter().copied())
+                            trie::bytes_to_nibbles(trie::CHILD_STORAGE_PREFIX.iter().copied())
                                 .chain(trie::bytes_to_nibbles(child_trie.as_ref().iter().copied()))
                                 .map(u8::from)
                                 .collect::<Vec<_>>()
@@ -872,8 +976,8 @@ impl SyncBackground {
                             .with_database(move |db| {
                                 db.block_storage_get(
                                     &parent_hash,
-                                    parent_paths.into_iter().map(|p| p.into_iter()),
-                                    key.iter().copied(),
+                                    &parent_paths.into_iter().collect::<Vec<_>>(),
+                                    &key,
                                 )
                             })
                             .await
@@ -888,7 +992,7 @@ impl SyncBackground {
                     }
                     author::build::BuilderAuthoring::ClosestDescendantMerkleValue(req) => {
                         let parent_paths = req.child_trie().map(|child_trie| {
-                            trie::bytes_to_nibbles(b":child_storage:default:".iter().copied())
+                            trie::bytes_to_nibbles(trie::CHILD_STORAGE_PREFIX.iter().copied())
                                 .chain(trie::bytes_to_nibbles(child_trie.as_ref().iter().copied()))
                                 .map(u8::from)
                                 .collect::<Vec<_>>()
@@ -900,8 +1004,8 @@ impl SyncBackground {
                             .with_database(move |db| {
                                 db.block_storage_closest_descendant_merkle_value(
                                     &parent_hash,
-                                    parent_paths.into_iter().map(|p| p.into_iter()),
-                                    key_nibbles.iter().copied(),
+                                    &parent_paths.into_iter().collect::<Vec<_>>(),
+                                    &key_nibbles,
                                 )
                             })
                             .await
@@ -912,7 +1016,7 @@ impl SyncBackground {
                     }
                     author::build::BuilderAuthoring::NextKey(req) => {
                         let parent_paths = req.child_trie().map(|child_trie| {
-                            trie::bytes_to_nibbles(b":child_storage:default:".iter().copied())
+                            trie::bytes_to_nibbles(trie::CHILD_STORAGE_PREFIX.iter().copied())
                                 .chain(trie::bytes_to_nibbles(child_trie.as_ref().iter().copied()))
                                 .map(u8::from)
                                 .collect::<Vec<_>>()
@@ -930,9 +1034,9 @@ impl SyncBackground {
                             .with_database(move |db| {
                                 db.block_storage_next_key(
                                     &parent_hash,
-                                    parent_paths.into_iter().map(|p| p.into_iter()),
-                                    key_nibbles.iter().copied(),
-                                    prefix_nibbles.iter().copied(),
+                                    &parent_paths.into_iter().collect::<Vec<_>>(),
+                                    &key_nibbles,
+                                    &prefix_nibbles,
                                     branch_nodes,
                                 )
                             })
@@ -1011,6 +1115,63 @@ impl SyncBackground {
         ));
     }
 
+    /// Spawns a task that runs the off-chain worker of the given block, unless the off-chain
+    /// worker of a previous block is still running. This avoids piling up off-chain workers when
+    /// blocks are imported quickly, for example while the node is syncing.
+    fn start_offchain_worker(
+        &mut self,
+        runtime: &executor::host::HostVmPrototype,
+        block_number: u64,
+        scale_encoded_header: Vec<u8>,
+    ) {
+        if !offchain_worker::is_supported(runtime)
+            || self.offchain_worker_running.swap(true, Ordering::AcqRel)
+        {
+            return;
+        }
+
+        // Code substitutes only apply if their `spec_version` is the same as the one of the
+        // on-chain runtime, and thus the same as the one of `runtime`.
+        let code_substitute = find_code_substitute(
+            &self.code_substitutes,
+            block_number,
+            runtime.runtime_version().decode().spec_version,
+        )
+        .map(|substitute| substitute.code.clone());
+
+        let config = offchain_worker::Config {
+            runtime: runtime.clone(),
+            code_substitute,
+            scale_encoded_header,
+            block_number_bytes: self.sync.block_number_bytes(),
+            database: self.database.clone(),
+            keystore: self.keystore.clone(),
+            local_peer_id: self.network_service.local_peer_id().clone(),
+            network_service: (self.network_service.clone(), self.network_chain_index),
+            transactions_tx: self.transactions_tx.clone(),
+            log_callback: self.log_callback.clone(),
+        };
+
+        // The flag is reset when the task is destroyed rather than when the off-chain worker
+        // finishes, in order to also cover the situation where the task is cancelled.
+        let offchain_worker_running =
+            OffchainWorkerRunningGuard(self.offchain_worker_running.clone());
+        (self.tasks_executor)(Box::pin(async move {
+            offchain_worker::run(config).await;
+            drop(offchain_worker_running);
+        }));
+    }
+
+    /// Returns the next transaction to include in the block being authored, or `None` if no more
+    /// transactions should be included.
+    fn next_transaction_to_include(&mut self, authoring_end: SystemTime) -> Option<Vec<u8>> {
+        if SystemTime::now() >= authoring_end {
+            return None;
+        }
+
+        self.transactions_rx.try_next().ok().flatten()
+    }
+
     /// Starts all the new network requests that should be started.
     // TODO: handle obsolete requests
     async fn start_network_requests(&mut self) {
@@ -1201,6 +1362,7 @@ impl SyncBackground {
                     .unwrap_or_else(|| self.finalized_runtime.clone());
                 let parent_runtime = parent_runtime_arc.try_lock().unwrap().take().unwrap();
                 let scale_encoded_header_to_verify = verify.scale_encoded_header().to_owned(); // TODO: copy :-/
+                let scale_encoded_extrinsics_to_verify = verify.scale_encoded_extrinsics().to_vec(); // TODO: copy :-/
 
                 let _jaeger_span = self.jaeger_service.block_body_verify_span(&hash_to_verify);
 
@@ -1243,67 +1405,42 @@ impl SyncBackground {
                             new_runtime,
                             ..
                         } => {
+                            // The block is executed a second time before being inserted in the
+                            // database, as the insertion takes ownership of its body.
+                            if let Some(runtimes_cache) = &mut self.differential_execution {
+                                differential_execution::check_block(
+                                    differential_execution::Config {
+                                        scale_encoded_header: &scale_encoded_header_to_verify,
+                                        scale_encoded_extrinsics:
+                                            &scale_encoded_extrinsics_to_verify,
+                                        block_number_bytes: sync_out.block_number_bytes(),
+                                        database: &self.database,
+                                        runtimes_cache,
+                                        log_callback: &*self.log_callback,
+                                    },
+                                )
+                                .await;
+                            }
+
                             // Insert the block in the database.
                             let when_database_access_started = Instant::now();
                             self.database
                                 .with_database_detached({
-                                    let scale_encoded_header_to_verify = scale_encoded_header_to_verify.clone();
+                                    let scale_encoded_header_to_verify =
+                                        scale_encoded_header_to_verify.clone();
                                     move |database| {
                                         // TODO: overhead for building the SCALE encoding of the header
                                         let result = database.insert(
                                             &scale_encoded_header_to_verify,
                                             is_new_best,
-                                            iter::empty::<Vec<u8>>(), // TODO:,no /!\
-                                            storage_changes.trie_changes_iter_ordered().filter_map(
-                                                |(_child_trie, key, change)| {
-                                                    let all::TrieChange::InsertUpdate {
-                                                        new_merkle_value,
-                                                        partial_key,
-                                                        children_merkle_values,
-                                                        new_storage_value
-                                                    } = &change
-                                                        else { return None };
-
-                                                    // TODO: this punches through abstraction layers; maybe add some code to runtime_host to indicate this?
-                                                    let references_merkle_value = key.iter().copied()
-                                                        .zip(trie::bytes_to_nibbles(b":child_storage:".iter().copied()))
-                                                        .all(|(a, b)| a == b);
-
-                                                    Some(full_sqlite::InsertTrieNode {
-                                                        merkle_value: (&new_merkle_value[..]).into(),
-                                                        children_merkle_values: array::from_fn(|n| {
-                                                            children_merkle_values[n]
-                                                                .as_ref()
-                                                                .map(|v| From::from(&v[..]))
-                                                        }),
-                                                        storage_value: match new_storage_value {
-                                                            all::TrieChangeStorageValue::Modified {
-                                                                new_value: Some(value),
-                                                            } => full_sqlite::InsertTrieNodeStorageValue::Value {
-                                                                value: Cow::Borrowed(value),
-                                                                references_merkle_value,
-                                                            },
-                                                            all::TrieChangeStorageValue::Modified {
-                                                                new_value: None,
-                                                            } => full_sqlite::InsertTrieNodeStorageValue::NoValue,
-                                                            all::TrieChangeStorageValue::Unmodified => {
-                                                                full_sqlite::InsertTrieNodeStorageValue::SameAsParent
-                                                            }
-                                                        },
-                                                        partial_key_nibbles: partial_key
-                                                            .iter()
-                                                            .map(|n| u8::from(*n))
-                                                            .collect::<Vec<_>>()
-                                                            .into(),
-                                                    })
-                                                },
-                                            ),
+                                            &scale_encoded_extrinsics_to_verify,
+                                            &mut insert_trie_nodes(&storage_changes),
                                             u8::from(state_trie_version),
                                         );
 
                                         match result {
                                             Ok(()) => {}
-                                            Err(full_sqlite::InsertError::Duplicate) => {} // TODO: this should be an error ; right now we silence them because non-finalized blocks aren't loaded from the database at startup, resulting in them being downloaded again
+                                            Err(database_backend::InsertError::Duplicate) => {} // TODO: this should be an error ; right now we silence them because non-finalized blocks aren't loaded from the database at startup, resulting in them being downloaded again
                                             Err(err) => panic!("{}", err),
                                         }
                                     }
@@ -1328,6 +1465,88 @@ impl SyncBackground {
 
                             // Processing has made a step forward.
 
+                            // Code substitutes apply starting from the block they are
+                            // registered at, and as long as the runtime isn't upgraded on chain.
+                            let new_runtime = match new_runtime {
+                                Some(new_runtime) => Some(
+                                    build_code_substitute(
+                                        &mut self.code_substitutes,
+                                        height_to_verify,
+                                        new_runtime.runtime_version().decode().spec_version,
+                                        new_runtime.heap_pages(),
+                                        self.runtime_cache.as_deref(),
+                                        &*self.log_callback,
+                                    )
+                                    .unwrap_or(new_runtime),
+                                ),
+                                None if self
+                                    .code_substitutes
+                                    .iter()
+                                    .any(|s| s.block_number == height_to_verify) =>
+                                {
+                                    // `parent_runtime` might itself have been built from a code
+                                    // substitute. The `spec_version` of the on-chain runtime,
+                                    // which is what determines whether a substitute applies, is
+                                    // instead obtained from the `:code` and `:heappages` of the
+                                    // block.
+                                    let (on_chain_code, on_chain_heap_pages) = self
+                                        .database
+                                        .with_database(move |database| {
+                                            let get = |key: &[u8]| {
+                                                database.block_storage_get(
+                                                    &hash_to_verify,
+                                                    &[],
+                                                    &trie::bytes_to_nibbles(key.iter().copied())
+                                                        .map(u8::from)
+                                                        .collect::<Vec<_>>(),
+                                                )
+                                            };
+                                            (get(b":code"), get(b":heappages"))
+                                        })
+                                        .await;
+
+                                    let on_chain_runtime =
+                                        match (on_chain_code, on_chain_heap_pages) {
+                                            (Ok(Some((code, _))), Ok(heap_pages)) => {
+                                                executor::storage_heap_pages_to_value(
+                                                    heap_pages.as_ref().map(|(hp, _)| &hp[..]),
+                                                )
+                                                .ok()
+                                                .and_then(|heap_pages| {
+                                                    let spec_version =
+                                                        runtime_spec_version(&code, heap_pages)
+                                                            .ok()?;
+                                                    Some((spec_version, heap_pages))
+                                                })
+                                            }
+                                            _ => None,
+                                        };
+
+                                    if let Some((on_chain_spec_version, on_chain_heap_pages)) =
+                                        on_chain_runtime
+                                    {
+                                        build_code_substitute(
+                                            &mut self.code_substitutes,
+                                            height_to_verify,
+                                            on_chain_spec_version,
+                                            on_chain_heap_pages,
+                                            self.runtime_cache.as_deref(),
+                                            &*self.log_callback,
+                                        )
+                                    } else {
+                                        self.log_callback.log(
+                                            LogLevel::Warn,
+                                            format!(
+                                                "code-substitute-on-chain-runtime-error; block={}",
+                                                height_to_verify
+                                            ),
+                                        );
+                                        None
+                                    }
+                                }
+                                None => None,
+                            };
+
                             *parent_runtime_arc.try_lock().unwrap() = Some(parent_runtime);
 
                             // Store the storage of the children.
@@ -1356,6 +1575,18 @@ impl SyncBackground {
 
                             self.sync = sync_out;
 
+                            if is_new_best && self.offchain_workers {
+                                let NonFinalizedBlock::Verified { runtime } =
+                                    &self.sync[(height_to_verify, &hash_to_verify)]
+                                    else { unreachable!() };
+                                let runtime = runtime.clone();
+                                self.start_offchain_worker(
+                                    runtime.try_lock().unwrap().as_ref().unwrap(),
+                                    height_to_verify,
+                                    scale_encoded_header_to_verify.clone(),
+                                );
+                            }
+
                             // Announce the newly-verified block to all the sources that might
                             // not be aware of it. We can never be guaranteed that a certain
                             // source does *not* know about a block, however it is not a big
@@ -1417,7 +1648,7 @@ impl SyncBackground {
                         all::BlockVerification::ParentStorageGet(req) => {
                             let when_database_access_started = Instant::now();
                             let parent_paths = req.child_trie().map(|child_trie| {
-                                trie::bytes_to_nibbles(b":child_storage:default:".iter().copied())
+                                trie::bytes_to_nibbles(trie::CHILD_STORAGE_PREFIX.iter().copied())
                                     .chain(trie::bytes_to_nibbles(
                                         child_trie.as_ref().iter().copied(),
                                     ))
@@ -1432,8 +1663,8 @@ impl SyncBackground {
                                 .with_database(move |db| {
                                     db.block_storage_get(
                                         &parent_hash,
-                                        parent_paths.into_iter().map(|p| p.into_iter()),
-                                        key.iter().copied(),
+                                        &parent_paths.into_iter().collect::<Vec<_>>(),
+                                        &key,
                                     )
                                 })
                                 .await
@@ -1452,7 +1683,7 @@ impl SyncBackground {
                             let when_database_access_started = Instant::now();
 
                             let parent_paths = req.child_trie().map(|child_trie| {
-                                trie::bytes_to_nibbles(b":child_storage:default:".iter().copied())
+                                trie::bytes_to_nibbles(trie::CHILD_STORAGE_PREFIX.iter().copied())
                                     .chain(trie::bytes_to_nibbles(
                                         child_trie.as_ref().iter().copied(),
                                     ))
@@ -1466,8 +1697,8 @@ impl SyncBackground {
                                 .with_database(move |db| {
                                     db.block_storage_closest_descendant_merkle_value(
                                         &parent_hash,
-                                        parent_paths.into_iter().map(|p| p.into_iter()),
-                                        key_nibbles.iter().copied(),
+                                        &parent_paths.into_iter().collect::<Vec<_>>(),
+                                        &key_nibbles,
                                     )
                                 })
                                 .await
@@ -1480,7 +1711,7 @@ impl SyncBackground {
                             let when_database_access_started = Instant::now();
 
                             let parent_paths = req.child_trie().map(|child_trie| {
-                                trie::bytes_to_nibbles(b":child_storage:default:".iter().copied())
+                                trie::bytes_to_nibbles(trie::CHILD_STORAGE_PREFIX.iter().copied())
                                     .chain(trie::bytes_to_nibbles(
                                         child_trie.as_ref().iter().copied(),
                                     ))
@@ -1500,9 +1731,9 @@ impl SyncBackground {
                                 .with_database(move |db| {
                                     db.block_storage_next_key(
                                         &parent_hash,
-                                        parent_paths.into_iter().map(|p| p.into_iter()),
-                                        key_nibbles.iter().copied(),
-                                        prefix_nibbles.iter().copied(),
+                                        &parent_paths.into_iter().collect::<Vec<_>>(),
+                                        &key_nibbles,
+                                        &prefix_nibbles,
                                         branch_nodes,
                                     )
                                 })
@@ -1516,7 +1747,11 @@ impl SyncBackground {
                         }
                         all::BlockVerification::RuntimeCompilation(rt) => {
                             let before_runtime_build = Instant::now();
-                            let outcome = rt.build();
+                            let outcome = rt.build(
+                                self.runtime_cache
+                                    .as_deref()
+                                    .map(|c| c as &dyn executor::vm::CompilationCache),
+                            );
                             runtime_build_duration += before_runtime_build.elapsed();
                             verify = outcome;
                         }
@@ -1552,6 +1787,23 @@ impl SyncBackground {
                             self.block_authoring = None;
                         }
 
+                        // The order of `finalized_blocks` depends on the syncing strategy.
+                        finalized_blocks.sort_unstable_by_key(|b| b.header.number);
+
+                        // Justifications of the newly-finalized blocks are persisted in the
+                        // database so that they can be served to other nodes.
+                        let justifications = finalized_blocks
+                            .iter()
+                            .flat_map(|block| {
+                                let hash = block.header.hash(self.sync.block_number_bytes());
+                                block
+                                    .justifications
+                                    .iter()
+                                    .filter(|(engine_id, _)| engine_id == b"FRNK")
+                                    .map(move |(_, justification)| (hash, justification.clone()))
+                            })
+                            .collect::<Vec<_>>();
+
                         let finalized_block = finalized_blocks.pop().unwrap();
                         let NonFinalizedBlock::Verified { runtime } = finalized_block.user_data else { unreachable!() };
                         self.finalized_runtime = runtime;
@@ -1561,6 +1813,11 @@ impl SyncBackground {
                         self.database
                             .with_database_detached(move |database| {
                                 database.set_finalized(&new_finalized_hash).unwrap();
+                                for (hash, justification) in justifications {
+                                    database
+                                        .set_block_justification(&hash, &justification)
+                                        .unwrap();
+                                }
                             })
                             .await;
                         (self, true)
@@ -1643,3 +1900,185 @@ impl SyncBackground {
         }
     }
 }
+
+/// Turns the changes to the storage performed by a block into the list of trie nodes to pass to
+/// [`database_backend::FullDatabase::insert`].
+pub(crate) fn insert_trie_nodes(
+    storage_changes: &all::StorageChanges,
+) -> impl Iterator<Item = database::InsertTrieNode<'_>> {
+    storage_changes
+        .trie_changes_iter_ordered()
+        .filter_map(|(child_trie, key, change)| {
+            let all::TrieChange::InsertUpdate {
+                new_merkle_value,
+                partial_key,
+                children_merkle_values,
+                new_storage_value,
+            } = change
+                else { return None };
+
+            // Entries of the main trie under `:child_storage:default:` contain the root of a
+            // child trie, whose nodes are also part of the changes.
+            // TODO: this punches through abstraction layers; maybe add some code to runtime_host to indicate this?
+            let references_merkle_value = child_trie.is_none()
+                && key.len() > trie::CHILD_STORAGE_PREFIX.len() * 2
+                && key
+                    .iter()
+                    .copied()
+                    .zip(trie::bytes_to_nibbles(
+                        trie::CHILD_STORAGE_PREFIX.iter().copied(),
+                    ))
+                    .all(|(a, b)| a == b);
+
+            Some(database::InsertTrieNode {
+                merkle_value: Cow::Borrowed(new_merkle_value),
+                children_merkle_values: array::from_fn(|n| {
+                    children_merkle_values[n].map(Cow::Borrowed)
+                }),
+                storage_value: match new_storage_value {
+                    all::TrieChangeStorageValue::Modified {
+                        new_value: Some(value),
+                    } => database::InsertTrieNodeStorageValue::Value {
+                        value: Cow::Borrowed(value),
+                        references_merkle_value,
+                    },
+                    all::TrieChangeStorageValue::Modified { new_value: None } => {
+                        database::InsertTrieNodeStorageValue::NoValue
+                    }
+                    all::TrieChangeStorageValue::Unmodified => {
+                        database::InsertTrieNodeStorageValue::SameAsParent
+                    }
+                },
+                partial_key_nibbles: partial_key
+                    .iter()
+                    .map(|n| u8::from(*n))
+                    .collect::<Vec<_>>()
+                    .into(),
+            })
+        })
+}
+
+/// Resets [`SyncBackground::offchain_worker_running`] to `false` when destroyed.
+struct OffchainWorkerRunningGuard(Arc<AtomicBool>);
+
+impl Drop for OffchainWorkerRunningGuard {
+    fn drop(&mut self) {
+        self.0.store(false, Ordering::Release);
+    }
+}
+
+/// Entry in [`SyncBackground::code_substitutes`].
+struct CodeSubstitute {
+    /// Number of the block starting from which the substitute applies.
+    block_number: u64,
+
+    /// Runtime code of the substitute, in the same format as the `:code` storage item.
+    code: Vec<u8>,
+
+    /// `spec_version` of the runtime of the substitute. Calculated the first time it is needed,
+    /// using the heap pages of the block the substitute is checked against. Contains `Some(None)`
+    /// if the compilation has failed, in which case the substitute is never used.
+    spec_version: Option<Option<u32>>,
+}
+
+/// Returns the `spec_version` of the given runtime code.
+fn runtime_spec_version(
+    code: &[u8],
+    heap_pages: executor::host::HeapPages,
+) -> Result<u32, executor::host::NewErr> {
+    let vm = executor::host::HostVmPrototype::new(executor::host::Config {
+        module: code,
+        heap_pages,
+        exec_hint: executor::vm::ExecHint::Oneshot,
+        compilation_cache: None,
+        allow_unresolved_imports: false,
+    })?;
+    Ok(vm.runtime_version().decode().spec_version)
+}
+
+/// Returns the code substitute that applies to the block whose number is `block_number` and
+/// whose on-chain runtime has the given `spec_version`, or `None` if no substitute applies.
+///
+/// A code substitute applies starting from the block it is registered at, and as long as the
+/// on-chain runtime keeps the same `spec_version` as the substitute. If multiple substitutes
+/// apply, the one registered the most recently is picked.
+fn find_code_substitute(
+    code_substitutes: &[CodeSubstitute],
+    block_number: u64,
+    on_chain_spec_version: u32,
+) -> Option<&CodeSubstitute> {
+    code_substitutes
+        .iter()
+        .filter(|s| {
+            s.block_number <= block_number && s.spec_version == Some(Some(on_chain_spec_version))
+        })
+        .max_by_key(|s| s.block_number)
+}
+
+/// Compiles the code substitute that must be used in place of the on-chain runtime for the block
+/// whose number is `block_number`. Returns `None` if no code substitute applies to this block, or
+/// if the compilation has failed.
+///
+/// `on_chain_spec_version` must be the `spec_version` of the runtime found in the storage of the
+/// block in question, and `heap_pages` the number of heap pages found in the storage of this
+/// block, at the key `:heappages`.
+fn build_code_substitute(
+    code_substitutes: &mut [CodeSubstitute],
+    block_number: u64,
+    on_chain_spec_version: u32,
+    heap_pages: executor::host::HeapPages,
+    runtime_cache: Option<&runtime_cache::RuntimeCache>,
+    log_callback: &(dyn LogCallback + Send + Sync),
+) -> Option<executor::host::HostVmPrototype> {
+    // The `spec_version` of the substitutes that might apply is calculated if it isn't known yet.
+    for substitute in code_substitutes.iter_mut() {
+        if substitute.block_number > block_number || substitute.spec_version.is_some() {
+            continue;
+        }
+
+        substitute.spec_version = match runtime_spec_version(&substitute.code, heap_pages) {
+            Ok(spec_version) => Some(Some(spec_version)),
+            Err(error) => {
+                log_callback.log(
+                    LogLevel::Warn,
+                    format!(
+                        "code-substitute-build-error; block={}; error={}",
+                        substitute.block_number, error
+                    ),
+                );
+                Some(None)
+            }
+        };
+    }
+
+    let substitute = find_code_substitute(code_substitutes, block_number, on_chain_spec_version)?;
+
+    match executor::host::HostVmPrototype::new(executor::host::Config {
+        module: &substitute.code,
+        heap_pages,
+        exec_hint: executor::vm::ExecHint::CompileAheadOfTime,
+        compilation_cache: runtime_cache.map(|c| c as &dyn executor::vm::CompilationCache),
+        allow_unresolved_imports: false,
+    }) {
+        Ok(vm) => {
+            log_callback.log(
+                LogLevel::Info,
+                format!(
+                    "code-substitute-applied; block={}; substitute_block={}; spec_version={}",
+                    block_number, substitute.block_number, on_chain_spec_version
+                ),
+            );
+            Some(vm)
+        }
+        Err(error) => {
+            log_callback.log(
+                LogLevel::Warn,
+                format!(
+                    "code-substitute-build-error; block={}; error={}",
+                    substitute.block_number, error
+                ),
+            );
+            None
+        }
+    }
+}
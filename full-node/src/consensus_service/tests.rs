@@ -0,0 +1,80 @@
+// Smoldot
+// Copyright (C) 2023  Pierre Krieger
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <http://www.gnu.org/licenses/>.
+
+#![cfg(test)]
+
+use super::{find_code_substitute, CodeSubstitute};
+
+fn substitute(block_number: u64, spec_version: u32) -> CodeSubstitute {
+    CodeSubstitute {
+        block_number,
+        code: vec![u8::try_from(block_number % 256).unwrap()],
+        spec_version: Some(Some(spec_version)),
+    }
+}
+
+fn find(code_substitutes: &[CodeSubstitute], block_number: u64, spec_version: u32) -> Option<u64> {
+    find_code_substitute(code_substitutes, block_number, spec_version).map(|s| s.block_number)
+}
+
+#[test]
+fn applies_starting_from_activation_height() {
+    let substitutes = [substitute(100, 5)];
+    assert_eq!(find(&substitutes, 99, 5), None);
+    assert_eq!(find(&substitutes, 100, 5), Some(100));
+    assert_eq!(find(&substitutes, 101, 5), Some(100));
+}
+
+#[test]
+fn spec_version_bump_ends_substitute() {
+    let substitutes = [substitute(100, 5)];
+    assert_eq!(find(&substitutes, 150, 5), Some(100));
+    // The on-chain runtime has been upgraded.
+    assert_eq!(find(&substitutes, 151, 6), None);
+}
+
+#[test]
+fn fork_below_activation_height() {
+    let substitutes = [substitute(100, 5)];
+
+    // A fork that branches off at block #90 keeps the same on-chain runtime. Its blocks below
+    // the activation height don't use the substitute, while the ones above do.
+    assert_eq!(find(&substitutes, 95, 5), None);
+    assert_eq!(find(&substitutes, 100, 5), Some(100));
+
+    // Another fork that branches off at block #90 upgrades its runtime at block #95. The
+    // substitute doesn't apply to it, even at the activation height.
+    assert_eq!(find(&substitutes, 100, 6), None);
+    assert_eq!(find(&substitutes, 105, 6), None);
+}
+
+#[test]
+fn most_recent_substitute_picked() {
+    let substitutes = [substitute(100, 5), substitute(150, 6), substitute(200, 5)];
+    assert_eq!(find(&substitutes, 120, 5), Some(100));
+    assert_eq!(find(&substitutes, 160, 6), Some(150));
+    assert_eq!(find(&substitutes, 160, 5), Some(100));
+    assert_eq!(find(&substitutes, 200, 5), Some(200));
+}
+
+#[test]
+fn failed_or_unknown_substitutes_ignored() {
+    let mut substitutes = [substitute(100, 5), substitute(150, 5)];
+    substitutes[0].spec_version = None;
+    substitutes[1].spec_version = Some(None);
+    assert_eq!(find(&substitutes, 200, 5), None);
+}
@@ -0,0 +1,763 @@
+// Smoldot
+// Copyright (C) 2023  Pierre Krieger
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Abstraction over the storage backend of the database of the full node.
+//!
+//! The rest of the full node accesses the database exclusively through the [`FullDatabase`]
+//! trait, which is implemented on [`full_sqlite::SqliteFullDatabase`] and
+//! [`full_memory::MemoryFullDatabase`].
+//!
+//! All the methods of this trait use synchronous I/O. For this reason, the full node only ever
+//! accesses the database from a dedicated thread.
+
+use smoldot::{
+    chain::chain_information,
+    database::{full_memory, full_sqlite, InsertTrieNode},
+    header, trie,
+};
+
+/// Hash of a block and index of an extrinsic within the body of that block.
+pub type ExtrinsicLocation = ([u8; 32], usize);
+
+/// Builds the `parent_tries_paths_nibbles` parameter of the [`FullDatabase`] storage access
+/// methods corresponding to the given child trie, or to the main trie if `None`.
+pub fn child_trie_parent_paths(child_trie: Option<&[u8]>) -> Vec<Vec<u8>> {
+    child_trie
+        .map(|child_trie| {
+            trie::bytes_to_nibbles(trie::CHILD_STORAGE_PREFIX.iter().copied())
+                .chain(trie::bytes_to_nibbles(child_trie.iter().copied()))
+                .map(u8::from)
+                .collect::<Vec<_>>()
+        })
+        .into_iter()
+        .collect()
+}
+
+/// Returns the value associated with `key` in the storage of the given block, alongside with
+/// its trie entry version. `child_trie` designates the child trie to search in, or `None` for
+/// the main trie.
+pub fn storage_get(
+    database: &dyn FullDatabase,
+    block_hash: &[u8; 32],
+    child_trie: Option<&[u8]>,
+    key: &[u8],
+) -> Result<Option<(Vec<u8>, u8)>, StorageAccessError> {
+    database.block_storage_get(
+        block_hash,
+        &child_trie_parent_paths(child_trie),
+        &trie::bytes_to_nibbles(key.iter().copied())
+            .map(u8::from)
+            .collect::<Vec<_>>(),
+    )
+}
+
+/// Storage backend of a full node database.
+///
+/// Contains a list of blocks, the latest finalized block being the root of the tree formed by
+/// these blocks, and the storage of these blocks.
+pub trait FullDatabase: Send {
+    /// Returns the hash of the block in the database whose storage is currently accessible.
+    fn best_block_hash(&self) -> Result<[u8; 32], AccessError>;
+
+    /// Returns the hash of the finalized block in the database.
+    fn finalized_block_hash(&self) -> Result<[u8; 32], AccessError>;
+
+    /// Returns the SCALE-encoded header of the given block, or `None` if the block is unknown.
+    fn block_scale_encoded_header(
+        &self,
+        block_hash: &[u8; 32],
+    ) -> Result<Option<Vec<u8>>, AccessError>;
+
+    /// Returns the list of extrinsics of the given block, or `None` if the block is unknown.
+    fn block_extrinsics(&self, block_hash: &[u8; 32]) -> Result<Option<Vec<Vec<u8>>>, AccessError>;
+
+    /// Returns the list of blocks containing an extrinsic with the given hash, and the index of
+    /// the extrinsic within each of these blocks.
+    ///
+    /// Returns `None` if the database doesn't maintain an index of the extrinsics.
+    fn extrinsic_locations(
+        &self,
+        extrinsic_hash: &[u8; 32],
+    ) -> Result<Option<Vec<ExtrinsicLocation>>, AccessError>;
+
+    /// Returns the justification of the given block, or `None` if the block is unknown or has no
+    /// justification.
+    fn block_justification(&self, block_hash: &[u8; 32]) -> Result<Option<Vec<u8>>, AccessError>;
+
+    /// Stores the justification of the given block, which must be finalized.
+    fn set_block_justification(
+        &mut self,
+        block_hash: &[u8; 32],
+        justification: &[u8],
+    ) -> Result<(), SetJustificationError>;
+
+    /// Returns the hashes of the finalized blocks whose number is strictly superior to
+    /// `after_block_number` and that have a justification, ordered by increasing number.
+    fn finalized_justified_blocks(
+        &self,
+        after_block_number: u64,
+    ) -> Result<Vec<[u8; 32]>, AccessError>;
+
+    /// Returns the hashes of the finalized blocks whose number is strictly superior to
+    /// `after_block_number` and whose header schedules or forces a change in the list of GrandPa
+    /// authorities, ordered by increasing number.
+    fn finalized_grandpa_authorities_changes(
+        &self,
+        after_block_number: u64,
+    ) -> Result<Vec<[u8; 32]>, AccessError>;
+
+    /// Returns the hash of the finalized block with the highest number strictly between
+    /// `after_block_number` and `before_block_number` that has a justification.
+    fn latest_finalized_justified_block(
+        &self,
+        after_block_number: u64,
+        before_block_number: u64,
+    ) -> Result<Option<[u8; 32]>, AccessError>;
+
+    /// Returns the hashes of the blocks with the given number.
+    fn block_hash_by_number(&self, block_number: u64) -> Result<Vec<[u8; 32]>, AccessError>;
+
+    /// Returns the value associated to the given key in the storage of the off-chain workers.
+    fn offchain_storage_get(&self, key: &[u8]) -> Result<Option<Vec<u8>>, AccessError>;
+
+    /// Sets or removes the value associated to the given key in the storage of the off-chain
+    /// workers, only if the current value matches `old_value` when it is `Some`.
+    ///
+    /// Returns `true` if the modification has been performed.
+    ///
+    /// See [`full_sqlite::SqliteFullDatabase::offchain_storage_compare_and_set`].
+    fn offchain_storage_compare_and_set(
+        &mut self,
+        key: &[u8],
+        old_value: Option<Option<&[u8]>>,
+        new_value: Option<&[u8]>,
+    ) -> Result<bool, AccessError>;
+
+    /// Returns the chain information of the given block, which must be the finalized block.
+    fn to_chain_information(
+        &self,
+        finalized_block_hash: &[u8; 32],
+    ) -> Result<chain_information::ValidChainInformation, StorageAccessError>;
+
+    /// Insert a new block in the database.
+    ///
+    /// Must pass the header and body of the block, and the changes to the storage that this block
+    /// performs relative to its parent.
+    fn insert<'a>(
+        &mut self,
+        scale_encoded_header: &[u8],
+        is_new_best: bool,
+        body: &[Vec<u8>],
+        new_trie_nodes: &mut dyn Iterator<Item = InsertTrieNode<'a>>,
+        trie_entries_version: u8,
+    ) -> Result<(), InsertError>;
+
+    /// Changes the finalized block to the given one.
+    ///
+    /// The block must be a descendant of the current finalized block.
+    fn set_finalized(
+        &mut self,
+        new_finalized_block_hash: &[u8; 32],
+    ) -> Result<(), SetFinalizedError>;
+
+    /// Returns the value associated with a node of the trie of the given block.
+    ///
+    /// `parent_tries_paths_nibbles` is a list of keys to follow in order to find the root of the
+    /// trie into which `key_nibbles` should be searched.
+    ///
+    /// All the values of `parent_tries_paths_nibbles` and `key_nibbles` must be nibbles, in other
+    /// words values strictly inferior to 16.
+    fn block_storage_get(
+        &self,
+        block_hash: &[u8; 32],
+        parent_tries_paths_nibbles: &[Vec<u8>],
+        key_nibbles: &[u8],
+    ) -> Result<Option<(Vec<u8>, u8)>, StorageAccessError>;
+
+    /// Returns the key in the storage of the given block that immediately follows or is equal
+    /// to `key_nibbles` and starts with `prefix_nibbles`.
+    ///
+    /// See [`full_sqlite::SqliteFullDatabase::block_storage_next_key`].
+    fn block_storage_next_key(
+        &self,
+        block_hash: &[u8; 32],
+        parent_tries_paths_nibbles: &[Vec<u8>],
+        key_nibbles: &[u8],
+        prefix_nibbles: &[u8],
+        branch_nodes: bool,
+    ) -> Result<Option<Vec<u8>>, StorageAccessError>;
+
+    /// Returns the Merkle value of the trie node in the storage of the given block that is the
+    /// closest descendant of the provided key.
+    ///
+    /// See [`full_sqlite::SqliteFullDatabase::block_storage_closest_descendant_merkle_value`].
+    fn block_storage_closest_descendant_merkle_value(
+        &self,
+        block_hash: &[u8; 32],
+        parent_tries_paths_nibbles: &[Vec<u8>],
+        key_nibbles: &[u8],
+    ) -> Result<Option<Vec<u8>>, StorageAccessError>;
+}
+
+impl FullDatabase for full_sqlite::SqliteFullDatabase {
+    fn best_block_hash(&self) -> Result<[u8; 32], AccessError> {
+        Ok(full_sqlite::SqliteFullDatabase::best_block_hash(self)?)
+    }
+
+    fn finalized_block_hash(&self) -> Result<[u8; 32], AccessError> {
+        Ok(full_sqlite::SqliteFullDatabase::finalized_block_hash(self)?)
+    }
+
+    fn block_scale_encoded_header(
+        &self,
+        block_hash: &[u8; 32],
+    ) -> Result<Option<Vec<u8>>, AccessError> {
+        Ok(full_sqlite::SqliteFullDatabase::block_scale_encoded_header(
+            self, block_hash,
+        )?)
+    }
+
+    fn block_extrinsics(&self, block_hash: &[u8; 32]) -> Result<Option<Vec<Vec<u8>>>, AccessError> {
+        Ok(
+            full_sqlite::SqliteFullDatabase::block_extrinsics(self, block_hash)?
+                .map(|extrinsics| extrinsics.collect()),
+        )
+    }
+
+    fn extrinsic_locations(
+        &self,
+        extrinsic_hash: &[u8; 32],
+    ) -> Result<Option<Vec<ExtrinsicLocation>>, AccessError> {
+        Ok(full_sqlite::SqliteFullDatabase::extrinsic_locations(
+            self,
+            extrinsic_hash,
+        )?)
+    }
+
+    fn block_justification(&self, block_hash: &[u8; 32]) -> Result<Option<Vec<u8>>, AccessError> {
+        Ok(full_sqlite::SqliteFullDatabase::block_justification(
+            self, block_hash,
+        )?)
+    }
+
+    fn set_block_justification(
+        &mut self,
+        block_hash: &[u8; 32],
+        justification: &[u8],
+    ) -> Result<(), SetJustificationError> {
+        Ok(full_sqlite::SqliteFullDatabase::set_block_justification(
+            self,
+            block_hash,
+            justification,
+        )?)
+    }
+
+    fn finalized_justified_blocks(
+        &self,
+        after_block_number: u64,
+    ) -> Result<Vec<[u8; 32]>, AccessError> {
+        Ok(full_sqlite::SqliteFullDatabase::finalized_justified_blocks(
+            self,
+            after_block_number,
+        )?)
+    }
+
+    fn finalized_grandpa_authorities_changes(
+        &self,
+        after_block_number: u64,
+    ) -> Result<Vec<[u8; 32]>, AccessError> {
+        Ok(
+            full_sqlite::SqliteFullDatabase::finalized_grandpa_authorities_changes(
+                self,
+                after_block_number,
+            )?,
+        )
+    }
+
+    fn latest_finalized_justified_block(
+        &self,
+        after_block_number: u64,
+        before_block_number: u64,
+    ) -> Result<Option<[u8; 32]>, AccessError> {
+        Ok(
+            full_sqlite::SqliteFullDatabase::latest_finalized_justified_block(
+                self,
+                after_block_number,
+                before_block_number,
+            )?,
+        )
+    }
+
+    fn block_hash_by_number(&self, block_number: u64) -> Result<Vec<[u8; 32]>, AccessError> {
+        Ok(full_sqlite::SqliteFullDatabase::block_hash_by_number(self, block_number)?.collect())
+    }
+
+    fn offchain_storage_get(&self, key: &[u8]) -> Result<Option<Vec<u8>>, AccessError> {
+        Ok(full_sqlite::SqliteFullDatabase::offchain_storage_get(
+            self, key,
+        )?)
+    }
+
+    fn offchain_storage_compare_and_set(
+        &mut self,
+        key: &[u8],
+        old_value: Option<Option<&[u8]>>,
+        new_value: Option<&[u8]>,
+    ) -> Result<bool, AccessError> {
+        Ok(
+            full_sqlite::SqliteFullDatabase::offchain_storage_compare_and_set(
+                self, key, old_value, new_value,
+            )?,
+        )
+    }
+
+    fn to_chain_information(
+        &self,
+        finalized_block_hash: &[u8; 32],
+    ) -> Result<chain_information::ValidChainInformation, StorageAccessError> {
+        Ok(full_sqlite::SqliteFullDatabase::to_chain_information(
+            self,
+            finalized_block_hash,
+        )?)
+    }
+
+    fn insert<'a>(
+        &mut self,
+        scale_encoded_header: &[u8],
+        is_new_best: bool,
+        body: &[Vec<u8>],
+        new_trie_nodes: &mut dyn Iterator<Item = InsertTrieNode<'a>>,
+        trie_entries_version: u8,
+    ) -> Result<(), InsertError> {
+        Ok(full_sqlite::SqliteFullDatabase::insert(
+            self,
+            scale_encoded_header,
+            is_new_best,
+            body.iter(),
+            new_trie_nodes,
+            trie_entries_version,
+        )?)
+    }
+
+    fn set_finalized(
+        &mut self,
+        new_finalized_block_hash: &[u8; 32],
+    ) -> Result<(), SetFinalizedError> {
+        Ok(full_sqlite::SqliteFullDatabase::set_finalized(
+            self,
+            new_finalized_block_hash,
+        )?)
+    }
+
+    fn block_storage_get(
+        &self,
+        block_hash: &[u8; 32],
+        parent_tries_paths_nibbles: &[Vec<u8>],
+        key_nibbles: &[u8],
+    ) -> Result<Option<(Vec<u8>, u8)>, StorageAccessError> {
+        Ok(full_sqlite::SqliteFullDatabase::block_storage_get(
+            self,
+            block_hash,
+            parent_tries_paths_nibbles.iter().map(|p| p.iter().copied()),
+            key_nibbles.iter().copied(),
+        )?)
+    }
+
+    fn block_storage_next_key(
+        &self,
+        block_hash: &[u8; 32],
+        parent_tries_paths_nibbles: &[Vec<u8>],
+        key_nibbles: &[u8],
+        prefix_nibbles: &[u8],
+        branch_nodes: bool,
+    ) -> Result<Option<Vec<u8>>, StorageAccessError> {
+        Ok(full_sqlite::SqliteFullDatabase::block_storage_next_key(
+            self,
+            block_hash,
+            parent_tries_paths_nibbles.iter().map(|p| p.iter().copied()),
+            key_nibbles.iter().copied(),
+            prefix_nibbles.iter().copied(),
+            branch_nodes,
+        )?)
+    }
+
+    fn block_storage_closest_descendant_merkle_value(
+        &self,
+        block_hash: &[u8; 32],
+        parent_tries_paths_nibbles: &[Vec<u8>],
+        key_nibbles: &[u8],
+    ) -> Result<Option<Vec<u8>>, StorageAccessError> {
+        Ok(
+            full_sqlite::SqliteFullDatabase::block_storage_closest_descendant_merkle_value(
+                self,
+                block_hash,
+                parent_tries_paths_nibbles.iter().map(|p| p.iter().copied()),
+                key_nibbles.iter().copied(),
+            )?,
+        )
+    }
+}
+
+impl FullDatabase for full_memory::MemoryFullDatabase {
+    fn best_block_hash(&self) -> Result<[u8; 32], AccessError> {
+        Ok(full_memory::MemoryFullDatabase::best_block_hash(self))
+    }
+
+    fn finalized_block_hash(&self) -> Result<[u8; 32], AccessError> {
+        Ok(full_memory::MemoryFullDatabase::finalized_block_hash(self))
+    }
+
+    fn block_scale_encoded_header(
+        &self,
+        block_hash: &[u8; 32],
+    ) -> Result<Option<Vec<u8>>, AccessError> {
+        Ok(
+            full_memory::MemoryFullDatabase::block_scale_encoded_header(self, block_hash)
+                .map(|header| header.to_vec()),
+        )
+    }
+
+    fn block_extrinsics(&self, block_hash: &[u8; 32]) -> Result<Option<Vec<Vec<u8>>>, AccessError> {
+        Ok(
+            full_memory::MemoryFullDatabase::block_extrinsics(self, block_hash)
+                .map(|extrinsics| extrinsics.map(|ext| ext.to_vec()).collect()),
+        )
+    }
+
+    fn extrinsic_locations(
+        &self,
+        extrinsic_hash: &[u8; 32],
+    ) -> Result<Option<Vec<ExtrinsicLocation>>, AccessError> {
+        Ok(full_memory::MemoryFullDatabase::extrinsic_locations(
+            self,
+            extrinsic_hash,
+        ))
+    }
+
+    fn block_justification(&self, block_hash: &[u8; 32]) -> Result<Option<Vec<u8>>, AccessError> {
+        Ok(
+            full_memory::MemoryFullDatabase::block_justification(self, block_hash)
+                .map(|justification| justification.to_vec()),
+        )
+    }
+
+    fn set_block_justification(
+        &mut self,
+        block_hash: &[u8; 32],
+        justification: &[u8],
+    ) -> Result<(), SetJustificationError> {
+        Ok(full_memory::MemoryFullDatabase::set_block_justification(
+            self,
+            block_hash,
+            justification,
+        )?)
+    }
+
+    fn finalized_justified_blocks(
+        &self,
+        after_block_number: u64,
+    ) -> Result<Vec<[u8; 32]>, AccessError> {
+        Ok(full_memory::MemoryFullDatabase::finalized_justified_blocks(
+            self,
+            after_block_number,
+        ))
+    }
+
+    fn finalized_grandpa_authorities_changes(
+        &self,
+        after_block_number: u64,
+    ) -> Result<Vec<[u8; 32]>, AccessError> {
+        Ok(
+            full_memory::MemoryFullDatabase::finalized_grandpa_authorities_changes(
+                self,
+                after_block_number,
+            ),
+        )
+    }
+
+    fn latest_finalized_justified_block(
+        &self,
+        after_block_number: u64,
+        before_block_number: u64,
+    ) -> Result<Option<[u8; 32]>, AccessError> {
+        Ok(
+            full_memory::MemoryFullDatabase::latest_finalized_justified_block(
+                self,
+                after_block_number,
+                before_block_number,
+            ),
+        )
+    }
+
+    fn block_hash_by_number(&self, block_number: u64) -> Result<Vec<[u8; 32]>, AccessError> {
+        Ok(full_memory::MemoryFullDatabase::block_hash_by_number(self, block_number).collect())
+    }
+
+    fn offchain_storage_get(&self, key: &[u8]) -> Result<Option<Vec<u8>>, AccessError> {
+        Ok(full_memory::MemoryFullDatabase::offchain_storage_get(self, key).map(|v| v.to_vec()))
+    }
+
+    fn offchain_storage_compare_and_set(
+        &mut self,
+        key: &[u8],
+        old_value: Option<Option<&[u8]>>,
+        new_value: Option<&[u8]>,
+    ) -> Result<bool, AccessError> {
+        Ok(
+            full_memory::MemoryFullDatabase::offchain_storage_compare_and_set(
+                self, key, old_value, new_value,
+            ),
+        )
+    }
+
+    fn to_chain_information(
+        &self,
+        finalized_block_hash: &[u8; 32],
+    ) -> Result<chain_information::ValidChainInformation, StorageAccessError> {
+        Ok(full_memory::MemoryFullDatabase::to_chain_information(
+            self,
+            finalized_block_hash,
+        )?)
+    }
+
+    fn insert<'a>(
+        &mut self,
+        scale_encoded_header: &[u8],
+        is_new_best: bool,
+        body: &[Vec<u8>],
+        new_trie_nodes: &mut dyn Iterator<Item = InsertTrieNode<'a>>,
+        trie_entries_version: u8,
+    ) -> Result<(), InsertError> {
+        Ok(full_memory::MemoryFullDatabase::insert(
+            self,
+            scale_encoded_header,
+            is_new_best,
+            body.iter(),
+            new_trie_nodes,
+            trie_entries_version,
+        )?)
+    }
+
+    fn set_finalized(
+        &mut self,
+        new_finalized_block_hash: &[u8; 32],
+    ) -> Result<(), SetFinalizedError> {
+        Ok(full_memory::MemoryFullDatabase::set_finalized(
+            self,
+            new_finalized_block_hash,
+        )?)
+    }
+
+    fn block_storage_get(
+        &self,
+        block_hash: &[u8; 32],
+        parent_tries_paths_nibbles: &[Vec<u8>],
+        key_nibbles: &[u8],
+    ) -> Result<Option<(Vec<u8>, u8)>, StorageAccessError> {
+        Ok(full_memory::MemoryFullDatabase::block_storage_get(
+            self,
+            block_hash,
+            parent_tries_paths_nibbles.iter().map(|p| p.iter().copied()),
+            key_nibbles.iter().copied(),
+        )?
+        .map(|(value, version)| (value.to_vec(), version)))
+    }
+
+    fn block_storage_next_key(
+        &self,
+        block_hash: &[u8; 32],
+        parent_tries_paths_nibbles: &[Vec<u8>],
+        key_nibbles: &[u8],
+        prefix_nibbles: &[u8],
+        branch_nodes: bool,
+    ) -> Result<Option<Vec<u8>>, StorageAccessError> {
+        Ok(full_memory::MemoryFullDatabase::block_storage_next_key(
+            self,
+            block_hash,
+            parent_tries_paths_nibbles.iter().map(|p| p.iter().copied()),
+            key_nibbles.iter().copied(),
+            prefix_nibbles.iter().copied(),
+            branch_nodes,
+        )?)
+    }
+
+    fn block_storage_closest_descendant_merkle_value(
+        &self,
+        block_hash: &[u8; 32],
+        parent_tries_paths_nibbles: &[Vec<u8>],
+        key_nibbles: &[u8],
+    ) -> Result<Option<Vec<u8>>, StorageAccessError> {
+        Ok(
+            full_memory::MemoryFullDatabase::block_storage_closest_descendant_merkle_value(
+                self,
+                block_hash,
+                parent_tries_paths_nibbles.iter().map(|p| p.iter().copied()),
+                key_nibbles.iter().copied(),
+            )?
+            .map(|merkle_value| merkle_value.to_vec()),
+        )
+    }
+}
+
+/// Error while accessing some information.
+#[derive(Debug, derive_more::Display, derive_more::From)]
+pub enum AccessError {
+    /// Error while accessing the SQLite database.
+    #[display(fmt = "{_0}")]
+    Sqlite(full_sqlite::AccessError),
+    /// The header of a block in the database has failed to decode.
+    #[display(fmt = "Corrupted block header: {_0}")]
+    BlockHeaderCorrupted(header::Error),
+    /// The header of the finalized block, or of one of its ancestors, couldn't be found in the
+    /// database.
+    MissingFinalizedBlockHeader,
+}
+
+/// Error while calling [`FullDatabase::insert`].
+#[derive(Debug, derive_more::Display, derive_more::From)]
+pub enum InsertError {
+    /// Error accessing the database.
+    #[display(fmt = "{_0}")]
+    Access(AccessError),
+    /// Block was already in the database.
+    Duplicate,
+    /// Error when decoding the header to import.
+    #[display(fmt = "Failed to decode header: {_0}")]
+    BadHeader(header::Error),
+    /// Parent of the block to insert isn't in the database.
+    MissingParent,
+    /// Block isn't a descendant of the latest finalized block.
+    FinalizedNephew,
+}
+
+impl From<full_sqlite::InsertError> for InsertError {
+    fn from(err: full_sqlite::InsertError) -> Self {
+        match err {
+            full_sqlite::InsertError::Access(err) => InsertError::Access(err.into()),
+            full_sqlite::InsertError::Duplicate => InsertError::Duplicate,
+            full_sqlite::InsertError::BadHeader(err) => InsertError::BadHeader(err),
+            full_sqlite::InsertError::MissingParent => InsertError::MissingParent,
+            full_sqlite::InsertError::FinalizedNephew => InsertError::FinalizedNephew,
+        }
+    }
+}
+
+impl From<full_memory::InsertError> for InsertError {
+    fn from(err: full_memory::InsertError) -> Self {
+        match err {
+            full_memory::InsertError::Duplicate => InsertError::Duplicate,
+            full_memory::InsertError::BadHeader(err) => InsertError::BadHeader(err),
+            full_memory::InsertError::MissingParent => InsertError::MissingParent,
+            full_memory::InsertError::FinalizedNephew => InsertError::FinalizedNephew,
+        }
+    }
+}
+
+/// Error while calling [`FullDatabase::set_finalized`].
+#[derive(Debug, derive_more::Display, derive_more::From)]
+pub enum SetFinalizedError {
+    /// Error accessing the database.
+    #[display(fmt = "{_0}")]
+    Access(AccessError),
+    /// New finalized block isn't in the database.
+    UnknownBlock,
+    /// New finalized block must be a child of the previous finalized block.
+    RevertForbidden,
+}
+
+impl From<full_sqlite::SetFinalizedError> for SetFinalizedError {
+    fn from(err: full_sqlite::SetFinalizedError) -> Self {
+        match err {
+            full_sqlite::SetFinalizedError::Access(err) => SetFinalizedError::Access(err.into()),
+            full_sqlite::SetFinalizedError::UnknownBlock => SetFinalizedError::UnknownBlock,
+            full_sqlite::SetFinalizedError::RevertForbidden => SetFinalizedError::RevertForbidden,
+        }
+    }
+}
+
+impl From<full_memory::SetFinalizedError> for SetFinalizedError {
+    fn from(err: full_memory::SetFinalizedError) -> Self {
+        match err {
+            full_memory::SetFinalizedError::UnknownBlock => SetFinalizedError::UnknownBlock,
+            full_memory::SetFinalizedError::RevertForbidden => SetFinalizedError::RevertForbidden,
+        }
+    }
+}
+
+/// Error while calling [`FullDatabase::set_block_justification`].
+#[derive(Debug, derive_more::Display, derive_more::From)]
+pub enum SetJustificationError {
+    /// Error accessing the database.
+    #[display(fmt = "{_0}")]
+    Access(AccessError),
+    /// Block isn't in the database.
+    UnknownBlock,
+    /// Justifications can only be stored for finalized blocks.
+    NotFinalized,
+}
+
+impl From<full_sqlite::SetJustificationError> for SetJustificationError {
+    fn from(err: full_sqlite::SetJustificationError) -> Self {
+        match err {
+            full_sqlite::SetJustificationError::Access(err) => {
+                SetJustificationError::Access(err.into())
+            }
+            full_sqlite::SetJustificationError::UnknownBlock => SetJustificationError::UnknownBlock,
+            full_sqlite::SetJustificationError::NotFinalized => SetJustificationError::NotFinalized,
+        }
+    }
+}
+
+impl From<full_memory::SetJustificationError> for SetJustificationError {
+    fn from(err: full_memory::SetJustificationError) -> Self {
+        match err {
+            full_memory::SetJustificationError::UnknownBlock => SetJustificationError::UnknownBlock,
+            full_memory::SetJustificationError::NotFinalized => SetJustificationError::NotFinalized,
+        }
+    }
+}
+
+/// Error while accessing the storage of a block.
+#[derive(Debug, derive_more::Display, derive_more::From)]
+pub enum StorageAccessError {
+    /// Error accessing the database.
+    #[display(fmt = "{_0}")]
+    Access(AccessError),
+    /// Storage of the block hash passed as parameter is no longer in the database.
+    Pruned,
+    /// Requested block couldn't be found in the database.
+    UnknownBlock,
+}
+
+impl From<full_sqlite::StorageAccessError> for StorageAccessError {
+    fn from(err: full_sqlite::StorageAccessError) -> Self {
+        match err {
+            full_sqlite::StorageAccessError::Access(err) => StorageAccessError::Access(err.into()),
+            full_sqlite::StorageAccessError::Pruned => StorageAccessError::Pruned,
+            full_sqlite::StorageAccessError::UnknownBlock => StorageAccessError::UnknownBlock,
+        }
+    }
+}
+
+impl From<full_memory::StorageAccessError> for StorageAccessError {
+    fn from(err: full_memory::StorageAccessError) -> Self {
+        match err {
+            full_memory::StorageAccessError::Pruned => StorageAccessError::Pruned,
+            full_memory::StorageAccessError::UnknownBlock => StorageAccessError::UnknownBlock,
+        }
+    }
+}
@@ -18,9 +18,10 @@
 //! As explained in the documentation of smoldot, the database uses synchronous I/O operations.
 //! For this reason, it is undesirable to access it from an asynchronous context.
 
+use crate::database_backend::FullDatabase;
+
 use futures_channel::oneshot;
 use smol::{channel, lock::Mutex, stream::StreamExt as _};
-use smoldot::database::full_sqlite::SqliteFullDatabase;
 use std::thread;
 
 /// Handle to the thread were the database accesses are performed.
@@ -32,14 +33,14 @@ pub struct DatabaseThread {
     sender: Mutex<channel::Sender<Exec>>,
 }
 
-type Exec = Box<dyn FnOnce(&SqliteFullDatabase) + Send>;
+type Exec = Box<dyn FnOnce(&mut dyn FullDatabase) + Send>;
 
 impl DatabaseThread {
     /// Sends a closure to the database thread, executes it, then returns the value that the
     /// closure returned.
     pub async fn with_database<T: Send + 'static>(
         &self,
-        closure: impl FnOnce(&SqliteFullDatabase) -> T + Send + 'static,
+        closure: impl FnOnce(&mut dyn FullDatabase) -> T + Send + 'static,
     ) -> T {
         let (tx, rx) = oneshot::channel();
         self.sender
@@ -57,7 +58,7 @@ impl DatabaseThread {
     /// is slightly more optimized for this use case.
     pub async fn with_database_detached(
         &self,
-        closure: impl FnOnce(&SqliteFullDatabase) + Send + 'static,
+        closure: impl FnOnce(&mut dyn FullDatabase) + Send + 'static,
     ) {
         self.sender
             .lock()
@@ -70,17 +71,17 @@ impl DatabaseThread {
     }
 }
 
-impl From<SqliteFullDatabase> for DatabaseThread {
-    fn from(db: SqliteFullDatabase) -> DatabaseThread {
-        let (sender, mut rx) = channel::bounded::<Box<dyn FnOnce(&SqliteFullDatabase) + Send>>(256);
+impl From<Box<dyn FullDatabase>> for DatabaseThread {
+    fn from(mut db: Box<dyn FullDatabase>) -> DatabaseThread {
+        let (sender, mut rx) = channel::bounded::<Exec>(256);
 
         thread::Builder::new()
-            .name("sqlite-database".into())
+            .name("database".into())
             .spawn(move || {
                 // When the `DatabaseThread` is dropped, the sender will close, `rx.next()`
                 // will return `None`, and the closure here will finish, ending the thread.
                 while let Some(closure) = smol::block_on(rx.next()) {
-                    closure(&db)
+                    closure(&mut *db)
                 }
             })
             .unwrap();
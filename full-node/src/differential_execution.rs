@@ -0,0 +1,363 @@
+// Smoldot
+// Copyright (C) 2023  Pierre Krieger
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Differential execution of blocks.
+//!
+//! When enabled, each block that has been verified is executed a second time on top of the
+//! storage of its parent, simultaneously with the interpreter and with the JIT, and the two
+//! executions are compared. See [`smoldot::executor::differential`].
+//!
+//! This is a debugging tool meant to detect discrepancies between the execution engines on real
+//! chains. Divergences are reported through the logs and don't affect the import of the block.
+
+use crate::{database_thread, util, LogCallback, LogLevel};
+
+use smoldot::{
+    executor::{self, differential, host, vm},
+    header,
+    informant::HashDisplay,
+    trie,
+};
+use std::{iter, time::Instant};
+
+/// Runtimes compiled by [`check_block`], kept between blocks in order to avoid compiling the
+/// same runtime multiple times.
+#[derive(Default)]
+pub struct RuntimesCache {
+    cached: Option<CachedRuntimes>,
+}
+
+struct CachedRuntimes {
+    /// Value of the `:code` storage item the runtimes have been compiled from.
+    code: Vec<u8>,
+    /// Value of the `:heappages` storage item the runtimes have been compiled with.
+    heap_pages: Option<Vec<u8>>,
+    /// Runtime compiled with the interpreter.
+    reference: host::HostVmPrototype,
+    /// Runtime compiled with the JIT.
+    candidate: host::HostVmPrototype,
+}
+
+/// Returns `true` if differential execution is supported on the current platform, in other
+/// words if the JIT is available.
+pub fn is_supported() -> bool {
+    vm::ExecHint::force_wasmtime_if_available().is_some()
+}
+
+/// Configuration for [`check_block`].
+pub struct Config<'a> {
+    /// SCALE-encoded header of the block to execute. Its parent must be in the database.
+    pub scale_encoded_header: &'a [u8],
+
+    /// SCALE-encoded extrinsics of the block to execute.
+    pub scale_encoded_extrinsics: &'a [Vec<u8>],
+
+    /// Number of bytes used to encode the block number in the header.
+    pub block_number_bytes: usize,
+
+    /// Database containing the storage of the parent of the block.
+    pub database: &'a database_thread::DatabaseThread,
+
+    /// Runtimes compiled during previous calls.
+    pub runtimes_cache: &'a mut RuntimesCache,
+
+    /// Function called in order to notify of something.
+    pub log_callback: &'a (dyn LogCallback + Send + Sync),
+}
+
+/// Executes the given block on both execution engines and logs the outcome of the comparison.
+pub async fn check_block(config: Config<'_>) {
+    let when_started = Instant::now();
+    let block_hash = header::hash_from_scale_encoded_header(config.scale_encoded_header);
+
+    // The header has already been verified.
+    let Ok(mut unsealed_header) =
+        header::decode(config.scale_encoded_header, config.block_number_bytes)
+        else { return };
+    let parent_hash = *unsealed_header.parent_hash;
+    let block_number = unsealed_header.number;
+
+    // Consensus engines add a seal at the end of the digest logs, which must be removed before
+    // executing the block.
+    let _ = unsealed_header.digest.pop_seal();
+    let execute_block_parameter = unsealed_header
+        .scale_encoding(config.block_number_bytes)
+        .map(either::Left)
+        .chain(iter::once(either::Right(util::encode_compact(
+            config.scale_encoded_extrinsics.len(),
+        ))))
+        .fold(Vec::with_capacity(8192), |mut a, b| {
+            a.extend_from_slice(AsRef::<[u8]>::as_ref(&b));
+            a
+        });
+    let execute_block_parameter = iter::once(&execute_block_parameter[..])
+        .chain(config.scale_encoded_extrinsics.iter().map(|e| &e[..]));
+
+    let (code, heap_pages) = config
+        .database
+        .with_database(move |db| {
+            let code = db.block_storage_get(
+                &parent_hash,
+                &[],
+                &trie::bytes_to_nibbles(b":code".iter().copied())
+                    .map(u8::from)
+                    .collect::<Vec<_>>(),
+            );
+            let heap_pages = db.block_storage_get(
+                &parent_hash,
+                &[],
+                &trie::bytes_to_nibbles(b":heappages".iter().copied())
+                    .map(u8::from)
+                    .collect::<Vec<_>>(),
+            );
+            (code, heap_pages)
+        })
+        .await;
+    let (Ok(Some((code, _))), Ok(heap_pages)) = (code, heap_pages)
+    else {
+        config.log_callback.log(
+            LogLevel::Warn,
+            format!(
+                "differential-execution-runtime-code-unavailable; hash={}",
+                HashDisplay(&block_hash)
+            ),
+        );
+        return;
+    };
+    let heap_pages = heap_pages.map(|(value, _)| value);
+
+    let runtimes = match config.runtimes_cache.cached.take() {
+        Some(cached) if cached.code == code && cached.heap_pages == heap_pages => cached,
+        _ => match compile_runtimes(code, heap_pages) {
+            Ok(runtimes) => runtimes,
+            Err(error) => {
+                config.log_callback.log(
+                    LogLevel::Warn,
+                    format!(
+                        "differential-execution-runtime-build-error; hash={}; error={}",
+                        HashDisplay(&block_hash),
+                        error
+                    ),
+                );
+                return;
+            }
+        },
+    };
+
+    let CachedRuntimes {
+        code,
+        heap_pages,
+        reference,
+        candidate,
+    } = runtimes;
+
+    let mut execution = differential::run(differential::Config {
+        reference,
+        candidate,
+        function_to_call: "Core_execute_block",
+        parameter: execute_block_parameter,
+        storage_main_trie_changes: Default::default(),
+        max_log_level: 0,
+    });
+
+    let (reference, candidate) = loop {
+        match execution {
+            differential::DifferentialRuntimeHostVm::Finished(Ok(success)) => {
+                match &success.outcome {
+                    Ok(_) => config.log_callback.log(
+                        LogLevel::Debug,
+                        format!(
+                            "differential-execution-identical; hash={}; height={}; duration={:?}",
+                            HashDisplay(&block_hash),
+                            block_number,
+                            when_started.elapsed()
+                        ),
+                    ),
+                    Err(error) => config.log_callback.log(
+                        LogLevel::Debug,
+                        format!(
+                            "differential-execution-identical-failure; hash={}; height={}; \
+                            duration={:?}; error={}",
+                            HashDisplay(&block_hash),
+                            block_number,
+                            when_started.elapsed(),
+                            error
+                        ),
+                    ),
+                }
+                break success.into_prototypes();
+            }
+            differential::DifferentialRuntimeHostVm::Finished(Err(divergence)) => {
+                // `divergence` is last because it's quite big.
+                config.log_callback.log(
+                    LogLevel::Warn,
+                    format!(
+                        "differential-execution-divergence; hash={}; height={}; divergence={}",
+                        HashDisplay(&block_hash),
+                        block_number,
+                        divergence
+                    ),
+                );
+                break (
+                    divergence.reference_prototype,
+                    divergence.candidate_prototype,
+                );
+            }
+
+            differential::DifferentialRuntimeHostVm::StorageGet(req) => {
+                let parent_paths = req.child_trie().map(|child_trie| {
+                    trie::bytes_to_nibbles(trie::CHILD_STORAGE_PREFIX.iter().copied())
+                        .chain(trie::bytes_to_nibbles(child_trie.as_ref().iter().copied()))
+                        .map(u8::from)
+                        .collect::<Vec<_>>()
+                });
+                let key = trie::bytes_to_nibbles(req.key().as_ref().iter().copied())
+                    .map(u8::from)
+                    .collect::<Vec<_>>();
+                let value = config
+                    .database
+                    .with_database(move |db| {
+                        db.block_storage_get(
+                            &parent_hash,
+                            &parent_paths.into_iter().collect::<Vec<_>>(),
+                            &key,
+                        )
+                    })
+                    .await
+                    .expect("database access error");
+
+                execution = req.inject_value(value.as_ref().map(|(val, vers)| {
+                    (
+                        iter::once(&val[..]),
+                        differential::TrieEntryVersion::try_from(*vers)
+                            .expect("corrupted database"),
+                    )
+                }));
+            }
+            differential::DifferentialRuntimeHostVm::ClosestDescendantMerkleValue(req) => {
+                let parent_paths = req.child_trie().map(|child_trie| {
+                    trie::bytes_to_nibbles(trie::CHILD_STORAGE_PREFIX.iter().copied())
+                        .chain(trie::bytes_to_nibbles(child_trie.as_ref().iter().copied()))
+                        .map(u8::from)
+                        .collect::<Vec<_>>()
+                });
+                let key_nibbles = req.key().map(u8::from).collect::<Vec<_>>();
+
+                let merkle_value = config
+                    .database
+                    .with_database(move |db| {
+                        db.block_storage_closest_descendant_merkle_value(
+                            &parent_hash,
+                            &parent_paths.into_iter().collect::<Vec<_>>(),
+                            &key_nibbles,
+                        )
+                    })
+                    .await
+                    .expect("database access error");
+
+                execution = req.inject_merkle_value(merkle_value.as_ref().map(|v| &v[..]));
+            }
+            differential::DifferentialRuntimeHostVm::NextKey(req) => {
+                let parent_paths = req.child_trie().map(|child_trie| {
+                    trie::bytes_to_nibbles(trie::CHILD_STORAGE_PREFIX.iter().copied())
+                        .chain(trie::bytes_to_nibbles(child_trie.as_ref().iter().copied()))
+                        .map(u8::from)
+                        .collect::<Vec<_>>()
+                });
+                let key_nibbles = req
+                    .key()
+                    .map(u8::from)
+                    .chain(if req.or_equal() { None } else { Some(0u8) })
+                    .collect::<Vec<_>>();
+                let prefix_nibbles = req.prefix().map(u8::from).collect::<Vec<_>>();
+
+                let branch_nodes = req.branch_nodes();
+                let next_key = config
+                    .database
+                    .with_database(move |db| {
+                        db.block_storage_next_key(
+                            &parent_hash,
+                            &parent_paths.into_iter().collect::<Vec<_>>(),
+                            &key_nibbles,
+                            &prefix_nibbles,
+                            branch_nodes,
+                        )
+                    })
+                    .await
+                    .expect("database access error");
+
+                execution = req.inject_key(
+                    next_key.map(|k| k.into_iter().map(|b| trie::Nibble::try_from(b).unwrap())),
+                );
+            }
+            differential::DifferentialRuntimeHostVm::SignatureVerification(req) => {
+                execution = req.verify_and_resume();
+            }
+        }
+    };
+
+    config.runtimes_cache.cached = Some(CachedRuntimes {
+        code,
+        heap_pages,
+        reference,
+        candidate,
+    });
+}
+
+/// Compiles the given runtime with both the interpreter and the JIT.
+fn compile_runtimes(
+    code: Vec<u8>,
+    heap_pages: Option<Vec<u8>>,
+) -> Result<CachedRuntimes, CompileError> {
+    let decoded_heap_pages = executor::storage_heap_pages_to_value(heap_pages.as_deref())
+        .map_err(CompileError::InvalidHeapPages)?;
+    let jit = vm::ExecHint::force_wasmtime_if_available().ok_or(CompileError::JitUnavailable)?;
+
+    let build = |exec_hint| {
+        host::HostVmPrototype::new(host::Config {
+            module: &code,
+            heap_pages: decoded_heap_pages,
+            exec_hint,
+            allow_unresolved_imports: false,
+            compilation_cache: None,
+        })
+        .map_err(CompileError::VmInitialization)
+    };
+
+    let reference = build(vm::ExecHint::ForceWasmi)?;
+    let candidate = build(jit)?;
+
+    Ok(CachedRuntimes {
+        code,
+        heap_pages,
+        reference,
+        candidate,
+    })
+}
+
+/// Error potentially returned by [`compile_runtimes`].
+#[derive(Debug, derive_more::Display)]
+enum CompileError {
+    /// Invalid value of the `:heappages` storage item.
+    #[display(fmt = "Invalid heap pages: {_0}")]
+    InvalidHeapPages(executor::InvalidHeapPagesError),
+    /// The JIT isn't available on this platform.
+    JitUnavailable,
+    /// Failed to compile the runtime.
+    #[display(fmt = "{_0}")]
+    VmInitialization(host::NewErr),
+}
@@ -15,21 +15,31 @@
 // You should have received a copy of the GNU General Public License
 // along with this program.  If not, see <http://www.gnu.org/licenses/>.
 
-use crate::{LogCallback, LogLevel};
+use crate::{database_backend, database_thread, LogCallback, LogLevel};
+use futures_channel::{mpsc, oneshot};
+use futures_util::{SinkExt as _, StreamExt as _};
 use smol::future;
 use smoldot::json_rpc::{self, methods, websocket_server};
-use std::{future::Future, io, net::SocketAddr, pin::Pin, sync::Arc};
+use std::{future::Future, io, mem, net::SocketAddr, pin::Pin, sync::Arc};
+
+mod trace_block;
 
 /// Configuration for a [`JsonRpcService`].
-pub struct Config<'a> {
+pub struct Config {
     /// Closure that spawns background tasks.
-    pub tasks_executor: &'a mut dyn FnMut(Pin<Box<dyn Future<Output = ()> + Send>>),
+    pub tasks_executor: Box<dyn FnMut(Pin<Box<dyn Future<Output = ()> + Send>>) + Send>,
 
     /// Function called in order to notify of something.
     pub log_callback: Arc<dyn LogCallback + Send + Sync>,
 
     /// Where to bind the WebSocket server.
     pub bind_address: SocketAddr,
+
+    /// Database to access in order to answer requests.
+    pub database: Arc<database_thread::DatabaseThread>,
+
+    /// Number of bytes used to encode the block number in the headers of the chain.
+    pub block_number_bytes: usize,
 }
 
 /// Running JSON-RPC service. Holds a server open for as long as it is alive.
@@ -46,7 +56,7 @@ impl Drop for JsonRpcService {
 
 impl JsonRpcService {
     /// Initializes a new [`JsonRpcService`].
-    pub async fn new(config: Config<'_>) -> Result<Self, InitError> {
+    pub async fn new(config: Config) -> Result<Self, InitError> {
         let server = {
             let result = websocket_server::WsServer::new(websocket_server::Config {
                 bind_address: config.bind_address,
@@ -70,13 +80,20 @@ impl JsonRpcService {
         let service_dropped = event_listener::Event::new();
         let on_service_dropped = service_dropped.listen();
 
+        let (responses_tx, responses_rx) = mpsc::channel(16);
+
         let background = JsonRpcBackground {
             server,
             on_service_dropped,
+            tasks_executor: config.tasks_executor,
+            responses_tx,
+            responses_rx,
             log_callback: config.log_callback,
+            database: config.database,
+            block_number_bytes: config.block_number_bytes,
         };
 
-        (config.tasks_executor)(Box::pin(async move { background.run().await }));
+        background.start();
         Ok(JsonRpcService { service_dropped })
     }
 }
@@ -101,18 +118,60 @@ struct JsonRpcBackground {
     /// Event notified when the frontend is dropped.
     on_service_dropped: event_listener::EventListener,
 
+    /// See [`Config::tasks_executor`].
+    tasks_executor: Box<dyn FnMut(Pin<Box<dyn Future<Output = ()> + Send>>) + Send>,
+
+    /// Sending side of [`JsonRpcBackground::responses_rx`]. Cloned and passed to the tasks that
+    /// answer requests in the background.
+    responses_tx: mpsc::Sender<(websocket_server::ConnectionId, String)>,
+
+    /// Responses to requests that have been answered by a background task, and that must be sent
+    /// to the given connection.
+    responses_rx: mpsc::Receiver<(websocket_server::ConnectionId, String)>,
+
     /// See [`Config::log_callback`].
     log_callback: Arc<dyn LogCallback + Send + Sync>,
+
+    /// See [`Config::database`].
+    database: Arc<database_thread::DatabaseThread>,
+
+    /// See [`Config::block_number_bytes`].
+    block_number_bytes: usize,
 }
 
 impl JsonRpcBackground {
+    fn start(mut self) {
+        // Same trick as in the consensus service: the executor is stored within the background
+        // while the background is spawned using said executor.
+        let mut actual_executor =
+            mem::replace(&mut self.tasks_executor, Box::new(|_| unreachable!()));
+        let (tx, rx) = oneshot::channel();
+        actual_executor(Box::pin(async move {
+            let actual_executor = rx.await.unwrap();
+            self.tasks_executor = actual_executor;
+            self.run().await;
+        }));
+        tx.send(actual_executor).unwrap_or_else(|_| panic!());
+    }
+
     async fn run(mut self) {
         loop {
             let Some(event) = future::or(
                 async { (&mut self.on_service_dropped).await; None },
-                async { Some(self.server.next_event().await) }
+                future::or(
+                    async { Some(either::Right(self.responses_rx.next().await.unwrap())) },
+                    async { Some(either::Left(self.server.next_event().await)) },
+                )
             ).await else { return };
 
+            let event = match event {
+                either::Left(event) => event,
+                either::Right((connection_id, response)) => {
+                    self.server.queue_send(connection_id, response);
+                    continue;
+                }
+            };
+
             let (connection_id, message) = match event {
                 websocket_server::Event::ConnectionOpen { address, .. } => {
                     self.log_callback.log(
@@ -138,7 +197,7 @@ impl JsonRpcBackground {
                 } => (connection_id, message),
             };
 
-            let (request_id, _method) = match methods::parse_json_call(&message) {
+            let (request_id, method) = match methods::parse_json_call(&message) {
                 Ok(v) => v,
                 Err(error) => {
                     self.log_callback.log(
@@ -152,12 +211,60 @@ impl JsonRpcBackground {
 
             self.log_callback.log(
                 LogLevel::Debug,
-                format!("request; request_id={:?}; method={:?}", request_id, _method),
+                format!("request; request_id={:?}; method={:?}", request_id, method),
             );
 
-            self.server.queue_send(
-                connection_id,
-                json_rpc::parse::build_error_response(
+            let response = match method {
+                methods::MethodCall::chain_unstable_extrinsicLocations { extrinsic_hash } => {
+                    // The database might have to scan block bodies, which can take a long time.
+                    // The request is answered from a separate task in order to not block the
+                    // other requests.
+                    let database = self.database.clone();
+                    let request_id = request_id.to_owned();
+                    let mut responses_tx = self.responses_tx.clone();
+                    (self.tasks_executor)(Box::pin(async move {
+                        let result = database
+                            .with_database(move |database| {
+                                database.extrinsic_locations(&extrinsic_hash.0)
+                            })
+                            .await;
+                        let response = extrinsic_locations_response(&request_id, result);
+                        let _ = responses_tx.send((connection_id, response)).await;
+                    }));
+                    continue;
+                }
+                methods::MethodCall::state_traceBlock {
+                    block,
+                    targets,
+                    storage_keys,
+                    methods: methods_filter,
+                } => {
+                    // Re-executing a block takes a long time. The request is answered from a
+                    // separate task in order to not block the other requests.
+                    let database = self.database.clone();
+                    let block_number_bytes = self.block_number_bytes;
+                    let request_id = request_id.to_owned();
+                    let targets = targets.map(|t| t.into_owned());
+                    let storage_keys = storage_keys.map(|k| k.into_owned());
+                    let methods_filter = methods_filter.map(|m| m.into_owned());
+                    let mut responses_tx = self.responses_tx.clone();
+                    (self.tasks_executor)(Box::pin(async move {
+                        let response = trace_block::trace_block(
+                            &database,
+                            block_number_bytes,
+                            block.0,
+                            targets.as_deref(),
+                            storage_keys.as_deref(),
+                            methods_filter.as_deref(),
+                        )
+                        .await;
+                        let response = methods::Response::state_traceBlock(response)
+                            .to_json_response(&request_id);
+                        let _ = responses_tx.send((connection_id, response)).await;
+                    }));
+                    continue;
+                }
+                _ => json_rpc::parse::build_error_response(
                     request_id,
                     json_rpc::parse::ErrorResponse::ServerError(
                         -32000,
@@ -165,7 +272,38 @@ impl JsonRpcBackground {
                     ),
                     None,
                 ),
-            );
+            };
+
+            self.server.queue_send(connection_id, response);
         }
     }
 }
+
+/// Builds the JSON-RPC response to a `chain_unstable_extrinsicLocations` request.
+fn extrinsic_locations_response(
+    request_id: &str,
+    result: Result<Option<Vec<database_backend::ExtrinsicLocation>>, database_backend::AccessError>,
+) -> String {
+    match result {
+        Ok(Some(locations)) => methods::Response::chain_unstable_extrinsicLocations(
+            locations
+                .into_iter()
+                .map(|(block_hash, index)| methods::ExtrinsicLocation {
+                    block_hash: methods::HashHexString(block_hash),
+                    index: u32::try_from(index).unwrap(),
+                })
+                .collect(),
+        )
+        .to_json_response(request_id),
+        Ok(None) => json_rpc::parse::build_error_response(
+            request_id,
+            json_rpc::parse::ErrorResponse::ServerError(-32000, "The extrinsics index is disabled"),
+            None,
+        ),
+        Err(error) => json_rpc::parse::build_error_response(
+            request_id,
+            json_rpc::parse::ErrorResponse::ServerError(-32000, &error.to_string()),
+            None,
+        ),
+    }
+}
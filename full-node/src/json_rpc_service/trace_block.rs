@@ -0,0 +1,465 @@
+// Smoldot
+// Copyright (C) 2023  Pierre Krieger
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Implementation of the `state_traceBlock` JSON-RPC function.
+//!
+//! The block is executed again on top of the storage of its parent, with tracing enabled. The
+//! storage of the parent must still be in the database.
+//!
+//! The `targets`, `storage_keys` and `methods` parameters are comma-separated lists that filter
+//! the content of the trace:
+//!
+//! - `targets` contains the log targets whose log entries are kept. A log entry is kept if its
+//!   target starts with one of the items of the list.
+//! - `storage_keys` contains hexadecimal key prefixes. Storage events are kept only if their key
+//!   starts with one of the items of the list.
+//! - `methods` contains names of host functions. Only calls to these host functions, and the
+//!   events caused by these calls, are kept.
+//!
+//! An empty or missing list means that nothing is filtered out.
+
+use crate::{database_backend, database_thread, util};
+
+use smoldot::{
+    executor::{self, host, runtime_host, trace, vm},
+    header,
+    json_rpc::methods,
+    trie,
+};
+use std::iter;
+
+/// Executes the given block with tracing enabled and returns the trace.
+pub async fn trace_block(
+    database: &database_thread::DatabaseThread,
+    block_number_bytes: usize,
+    block_hash: [u8; 32],
+    targets: Option<&str>,
+    storage_keys: Option<&str>,
+    methods: Option<&str>,
+) -> methods::TraceBlockResponse {
+    match trace_block_inner(database, block_number_bytes, block_hash).await {
+        Ok((parent_hash, trace, execution_error)) => {
+            let mut block_trace = methods::BlockTrace {
+                block_hash: methods::HashHexString(block_hash),
+                parent_hash: methods::HashHexString(parent_hash),
+                tracing_targets: targets.unwrap_or("").to_owned(),
+                storage_keys: storage_keys.unwrap_or("").to_owned(),
+                methods: methods.unwrap_or("").to_owned(),
+                host_function_calls: Vec::new(),
+                events: Vec::new(),
+                execution_error,
+            };
+            fill_block_trace(&mut block_trace, trace);
+            methods::TraceBlockResponse::BlockTrace(block_trace)
+        }
+        Err(error) => methods::TraceBlockResponse::TraceError {
+            error: error.to_string(),
+        },
+    }
+}
+
+/// Error potentially returned by [`trace_block_inner`].
+#[derive(Debug, derive_more::Display)]
+enum TraceBlockError {
+    /// Error while accessing the database.
+    #[display(fmt = "{_0}")]
+    Access(database_backend::AccessError),
+    /// Error while accessing the storage of the parent of the block.
+    #[display(fmt = "Failed to access the storage of the parent block: {_0}")]
+    ParentStorageAccess(database_backend::StorageAccessError),
+    /// Block couldn't be found in the database.
+    UnknownBlock,
+    /// Failed to decode the header of the block.
+    #[display(fmt = "Failed to decode block header: {_0}")]
+    InvalidHeader(header::Error),
+    /// Storage of the parent block doesn't contain any runtime code.
+    ParentCodeMissing,
+    /// Storage of the parent block contains an invalid `:heappages` value.
+    #[display(fmt = "Invalid `:heappages` in the parent block: {_0}")]
+    HeapPages(executor::InvalidHeapPagesError),
+    /// Failed to compile the runtime of the parent block.
+    #[display(fmt = "Failed to compile the runtime of the parent block: {_0}")]
+    RuntimeCompilation(host::NewErr),
+    /// Failed to start the execution of the block.
+    #[display(fmt = "Failed to start the execution of the block: {_0}")]
+    ExecutionStart(host::StartErr),
+}
+
+/// Executes the block with tracing enabled.
+///
+/// On success, returns the hash of the parent of the block, the trace, and the error that
+/// happened during the execution, if any.
+async fn trace_block_inner(
+    database: &database_thread::DatabaseThread,
+    block_number_bytes: usize,
+    block_hash: [u8; 32],
+) -> Result<([u8; 32], trace::Trace, Option<String>), TraceBlockError> {
+    let (scale_encoded_header, block_body) = database
+        .with_database(move |db| {
+            let header = db.block_scale_encoded_header(&block_hash)?;
+            let body = db.block_extrinsics(&block_hash)?;
+            Ok::<_, database_backend::AccessError>(header.zip(body))
+        })
+        .await
+        .map_err(TraceBlockError::Access)?
+        .ok_or(TraceBlockError::UnknownBlock)?;
+
+    let mut decoded_header = header::decode(&scale_encoded_header, block_number_bytes)
+        .map_err(TraceBlockError::InvalidHeader)?;
+    let parent_hash = *decoded_header.parent_hash;
+
+    // The runtime of the block is found in the storage of its parent.
+    let runtime = {
+        let (code, _) = storage_get(database, parent_hash, None, b":code")
+            .await
+            .map_err(TraceBlockError::ParentStorageAccess)?
+            .ok_or(TraceBlockError::ParentCodeMissing)?;
+        let heap_pages = storage_get(database, parent_hash, None, b":heappages")
+            .await
+            .map_err(TraceBlockError::ParentStorageAccess)?
+            .map(|(value, _)| value);
+        let heap_pages = executor::storage_heap_pages_to_value(heap_pages.as_deref())
+            .map_err(TraceBlockError::HeapPages)?;
+        host::HostVmPrototype::new(host::Config {
+            module: &code,
+            heap_pages,
+            exec_hint: vm::ExecHint::Oneshot,
+            compilation_cache: None,
+            allow_unresolved_imports: true,
+        })
+        .map_err(TraceBlockError::RuntimeCompilation)?
+    };
+
+    // The parameter of `Core_execute_block` is the SCALE-encoded `(header, body)`, where the
+    // header doesn't include its seal.
+    let parameter = {
+        let _seal_log = decoded_header.digest.pop_seal();
+        let mut parameter = decoded_header.scale_encoding(block_number_bytes).fold(
+            Vec::with_capacity(8192),
+            |mut a, b| {
+                a.extend_from_slice(b.as_ref());
+                a
+            },
+        );
+        parameter.extend_from_slice(&util::encode_compact(block_body.len()));
+        for extrinsic in &block_body {
+            parameter.extend_from_slice(extrinsic);
+        }
+        parameter
+    };
+
+    let mut call = runtime_host::run(runtime_host::Config {
+        virtual_machine: runtime,
+        function_to_call: "Core_execute_block",
+        parameter: iter::once(parameter),
+        storage_main_trie_changes: Default::default(),
+        offchain_storage_changes: Default::default(),
+        max_log_level: 5,
+        trace: true,
+        fuel_budget: None,
+        max_pending_changes_size: None,
+    })
+    .map_err(|(error, _)| TraceBlockError::ExecutionStart(error))?;
+
+    loop {
+        match call {
+            runtime_host::RuntimeHostVm::Finished(Ok(success)) => {
+                return Ok((parent_hash, success.trace.unwrap(), None));
+            }
+            runtime_host::RuntimeHostVm::Finished(Err(error)) => {
+                let detail = error.detail.to_string();
+                return Ok((parent_hash, error.trace.unwrap(), Some(detail)));
+            }
+
+            runtime_host::RuntimeHostVm::StorageGet(req) => {
+                let child_trie = req.child_trie().map(|ct| ct.as_ref().to_vec());
+                let key = req.key().as_ref().to_vec();
+                let value = storage_get(database, parent_hash, child_trie.as_deref(), &key)
+                    .await
+                    .map_err(TraceBlockError::ParentStorageAccess)?;
+
+                call = req.inject_value(value.as_ref().map(|(val, vers)| {
+                    (
+                        iter::once(&val[..]),
+                        runtime_host::TrieEntryVersion::try_from(*vers)
+                            .expect("corrupted database"),
+                    )
+                }));
+            }
+            runtime_host::RuntimeHostVm::ClosestDescendantMerkleValue(req) => {
+                let parent_paths = database_backend::child_trie_parent_paths(
+                    req.child_trie().as_ref().map(|ct| ct.as_ref()),
+                );
+                let key_nibbles = req.key().map(u8::from).collect::<Vec<_>>();
+
+                let merkle_value = database
+                    .with_database(move |db| {
+                        db.block_storage_closest_descendant_merkle_value(
+                            &parent_hash,
+                            &parent_paths,
+                            &key_nibbles,
+                        )
+                    })
+                    .await
+                    .map_err(TraceBlockError::ParentStorageAccess)?;
+
+                call = req.inject_merkle_value(merkle_value.as_ref().map(|v| &v[..]));
+            }
+            runtime_host::RuntimeHostVm::NextKey(req) => {
+                let parent_paths = database_backend::child_trie_parent_paths(
+                    req.child_trie().as_ref().map(|ct| ct.as_ref()),
+                );
+                let key_nibbles = req
+                    .key()
+                    .map(u8::from)
+                    .chain(if req.or_equal() { None } else { Some(0u8) })
+                    .collect::<Vec<_>>();
+                let prefix_nibbles = req.prefix().map(u8::from).collect::<Vec<_>>();
+
+                let branch_nodes = req.branch_nodes();
+                let next_key = database
+                    .with_database(move |db| {
+                        db.block_storage_next_key(
+                            &parent_hash,
+                            &parent_paths,
+                            &key_nibbles,
+                            &prefix_nibbles,
+                            branch_nodes,
+                        )
+                    })
+                    .await
+                    .map_err(TraceBlockError::ParentStorageAccess)?;
+
+                call = req.inject_key(
+                    next_key.map(|k| k.into_iter().map(|b| trie::Nibble::try_from(b).unwrap())),
+                );
+            }
+            runtime_host::RuntimeHostVm::SignatureVerification(req) => {
+                call = req.verify_and_resume();
+            }
+
+            // Executing a block never has access to the keystore or to off-chain-worker-related
+            // features.
+            runtime_host::RuntimeHostVm::KeystorePublicKeys(req) => {
+                call = req.resume(iter::empty::<&[u8]>());
+            }
+            runtime_host::RuntimeHostVm::KeystoreGenerate(req) => {
+                call = req.resume(None);
+            }
+            runtime_host::RuntimeHostVm::SignRequest(req) => {
+                call = req.resume(None);
+            }
+            runtime_host::RuntimeHostVm::Offchain(ctx) => {
+                call = ctx.reject();
+            }
+        }
+    }
+}
+
+/// Reads a storage value of the given block. Returns the value and its trie entry version.
+async fn storage_get(
+    database: &database_thread::DatabaseThread,
+    block_hash: [u8; 32],
+    child_trie: Option<&[u8]>,
+    key: &[u8],
+) -> Result<Option<(Vec<u8>, u8)>, database_backend::StorageAccessError> {
+    let child_trie = child_trie.map(|c| c.to_vec());
+    let key = key.to_vec();
+    database
+        .with_database(move |db| {
+            database_backend::storage_get(db, &block_hash, child_trie.as_deref(), &key)
+        })
+        .await
+}
+
+/// Converts `trace` to the JSON-RPC format and pushes its content to `block_trace`, applying
+/// the filters found in `block_trace`.
+fn fill_block_trace(block_trace: &mut methods::BlockTrace, trace: trace::Trace) {
+    let targets = split_filter(&block_trace.tracing_targets).collect::<Vec<_>>();
+    let storage_keys = split_filter(&block_trace.storage_keys)
+        .map(|key| hex::decode(key.trim_start_matches("0x")).unwrap_or_default())
+        .collect::<Vec<_>>();
+    let host_functions = split_filter(&block_trace.methods).collect::<Vec<_>>();
+
+    // Since host function calls can be filtered out, the indices in the JSON-RPC trace don't
+    // necessarily match the ones in `trace`.
+    let mut new_indices = Vec::with_capacity(trace.host_function_calls.len());
+    for call in trace.host_function_calls {
+        if !host_functions.is_empty() && !host_functions.contains(&call.function_name) {
+            new_indices.push(None);
+            continue;
+        }
+
+        new_indices.push(Some(
+            u32::try_from(block_trace.host_function_calls.len()).unwrap(),
+        ));
+        block_trace
+            .host_function_calls
+            .push(methods::TraceHostFunctionCall {
+                name: call.function_name.to_owned(),
+                parameters: call
+                    .parameters
+                    .into_iter()
+                    .map(convert_wasm_value)
+                    .collect(),
+                return_value: call.return_value.map(convert_wasm_value),
+            });
+    }
+
+    let storage_key_matches = |key: &[u8]| {
+        storage_keys.is_empty() || storage_keys.iter().any(|prefix| key.starts_with(prefix))
+    };
+
+    for event in trace.events {
+        let Some(Some(host_function_call)) = new_indices.get(event.host_function_call).copied()
+        else {
+            continue;
+        };
+
+        let detail = match event.detail {
+            trace::EventDetail::StorageRead {
+                child_trie,
+                key,
+                value,
+            } => {
+                if !storage_key_matches(&key) {
+                    continue;
+                }
+                methods::TraceEventDetail::StorageRead {
+                    child_trie: child_trie.map(methods::HexString),
+                    key: methods::HexString(key),
+                    value: value.map(methods::HexString),
+                }
+            }
+            trace::EventDetail::StorageWrite {
+                child_trie,
+                key,
+                value,
+            } => {
+                if !storage_key_matches(&key) {
+                    continue;
+                }
+                methods::TraceEventDetail::StorageWrite {
+                    child_trie: child_trie.map(methods::HexString),
+                    key: methods::HexString(key),
+                    value: value.map(methods::HexString),
+                }
+            }
+            trace::EventDetail::StorageAppend {
+                child_trie,
+                key,
+                value,
+            } => {
+                if !storage_key_matches(&key) {
+                    continue;
+                }
+                methods::TraceEventDetail::StorageAppend {
+                    child_trie: child_trie.map(methods::HexString),
+                    key: methods::HexString(key),
+                    value: methods::HexString(value),
+                }
+            }
+            trace::EventDetail::StorageClearPrefix {
+                child_trie,
+                prefix,
+                keys_removed,
+                some_keys_remain,
+            } => {
+                // The event is kept if the prefix overlaps with one of the filtered keys.
+                if !storage_keys.is_empty()
+                    && !storage_keys
+                        .iter()
+                        .any(|k| prefix.starts_with(k) || k.starts_with(&prefix))
+                {
+                    continue;
+                }
+                methods::TraceEventDetail::StorageClearPrefix {
+                    child_trie: child_trie.map(methods::HexString),
+                    prefix: methods::HexString(prefix),
+                    keys_removed,
+                    some_keys_remain,
+                }
+            }
+            trace::EventDetail::StorageNextKey {
+                child_trie,
+                key,
+                next_key,
+            } => {
+                if !storage_key_matches(&key) {
+                    continue;
+                }
+                methods::TraceEventDetail::StorageNextKey {
+                    child_trie: child_trie.map(methods::HexString),
+                    key: methods::HexString(key),
+                    next_key: next_key.map(methods::HexString),
+                }
+            }
+            trace::EventDetail::StorageRoot { child_trie, hash } => {
+                methods::TraceEventDetail::StorageRoot {
+                    child_trie: child_trie.map(methods::HexString),
+                    hash: methods::HashHexString(hash),
+                }
+            }
+            trace::EventDetail::StorageTransactionStart => {
+                methods::TraceEventDetail::StorageTransactionStart
+            }
+            trace::EventDetail::StorageTransactionEnd { rollback } => {
+                methods::TraceEventDetail::StorageTransactionEnd { rollback }
+            }
+            trace::EventDetail::Log {
+                level,
+                target,
+                message,
+            } => {
+                if !targets.is_empty()
+                    && !targets.iter().any(|t| {
+                        target
+                            .as_deref()
+                            .is_some_and(|target| target.starts_with(t))
+                    })
+                {
+                    continue;
+                }
+                methods::TraceEventDetail::Log {
+                    level,
+                    target,
+                    message,
+                }
+            }
+        };
+
+        block_trace.events.push(methods::TraceEvent {
+            host_function_call,
+            detail,
+        });
+    }
+}
+
+/// Splits a comma-separated filter into its non-empty items.
+fn split_filter(filter: &str) -> impl Iterator<Item = &str> {
+    filter
+        .split(',')
+        .map(str::trim)
+        .filter(|item| !item.is_empty())
+}
+
+fn convert_wasm_value(value: vm::WasmValue) -> methods::TraceWasmValue {
+    match value {
+        vm::WasmValue::I32(v) => methods::TraceWasmValue::I32(v),
+        vm::WasmValue::I64(v) => methods::TraceWasmValue::I64(v),
+    }
+}
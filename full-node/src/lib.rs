@@ -23,7 +23,7 @@ use futures_util::{future, stream, FutureExt as _, StreamExt as _};
 use smol::lock::Mutex;
 use smoldot::{
     chain, chain_spec,
-    database::full_sqlite,
+    database::{self, full_memory, full_sqlite},
     executor, header,
     identity::keystore,
     informant::HashDisplay,
@@ -37,13 +37,34 @@ use std::{
     array, borrow::Cow, iter, net::SocketAddr, path::PathBuf, sync::Arc, thread, time::Duration,
 };
 
+mod blocks_file;
 mod consensus_service;
+pub mod database_backend;
 mod database_thread;
+mod differential_execution;
 mod jaeger_service;
 mod json_rpc_service;
 mod network_service;
+mod offchain_worker;
+mod runtime_cache;
+mod runtime_upgrade_dry_run;
+mod snapshot_file;
 mod util;
 
+pub use blocks_file::{
+    export_blocks, import_blocks, BlocksFileFormat, ExportBlocksConfig, ExportBlocksError,
+    ImportBlocksConfig, ImportBlocksError, ImportBlocksOutcome,
+};
+pub use runtime_upgrade_dry_run::{
+    dry_run_runtime_upgrade, BlockOutcome, BlockWeight, ExtrinsicOutcome, MigrationOutcome,
+    MigrationWeight, RuntimeCallError, RuntimeUpgradeDryRunConfig, RuntimeUpgradeDryRunError,
+    RuntimeUpgradeDryRunReport, StorageDiffEntry, Weight,
+};
+pub use snapshot_file::{
+    export_snapshot, import_snapshot, ExportSnapshotConfig, ExportSnapshotError,
+    ExportSnapshotOutcome, ImportSnapshotConfig, ImportSnapshotError, ImportSnapshotOutcome,
+};
+
 pub struct Config<'a> {
     /// Chain to connect to.
     pub chain: ChainConfig<'a>,
@@ -64,6 +85,11 @@ pub struct Config<'a> {
     pub log_callback: Arc<dyn LogCallback + Send + Sync>,
     /// Address of a Jaeger agent to send traces to. If `None`, do not send Jaeger traces.
     pub jaeger_agent: Option<SocketAddr>,
+    /// Path to the directory where compiled runtimes are cached, in order to not have to compile
+    /// them again after a restart. Can be shared between multiple nodes and chains.
+    ///
+    /// If `None`, runtimes are compiled every time they are needed.
+    pub runtime_cache_path: Option<PathBuf>,
     // TODO: option is a bit weird
     pub show_informant: bool,
 }
@@ -103,14 +129,41 @@ pub struct ChainConfig<'a> {
     /// List of secret phrases to insert in the keystore of the node. Used to author blocks.
     // TODO: also automatically add the same keys through ed25519?
     pub keystore_memory: Vec<[u8; 64]>,
-    /// Path to the SQLite database. If `None`, the database is opened in memory.
-    pub sqlite_database_path: Option<PathBuf>,
-    /// Maximum size, in bytes, of the cache SQLite uses.
-    pub sqlite_cache_size: usize,
+    /// Storage backend of the database of the chain.
+    pub database_backend: DatabaseBackend,
+    /// If `true`, the database maintains an index of the extrinsics of the stored blocks by
+    /// hash, which can be queried through the `chain_unstable_extrinsicLocations` JSON-RPC
+    /// function.
+    pub extrinsics_index: bool,
     /// Path to the directory where cryptographic keys are stored on disk.
     ///
     /// If `None`, no keys are stored in disk.
     pub keystore_path: Option<PathBuf>,
+    /// If `true`, the off-chain worker of the runtime is executed after each new best block.
+    ///
+    /// > **Note**: The HTTP requests of off-chain workers are only supported over plain-text
+    /// >           `http://`. `https://` requests fail, as TLS isn't supported.
+    pub offchain_workers: bool,
+    /// If `true`, each verified block is executed a second time with both the interpreter and
+    /// the JIT, and divergences between the two are reported in the logs.
+    pub differential_execution: bool,
+}
+
+/// Storage backend of the database of a chain. See [`ChainConfig::database_backend`].
+#[derive(Debug, Clone)]
+pub enum DatabaseBackend {
+    /// Store the database in SQLite.
+    Sqlite {
+        /// Path to the SQLite database. If `None`, the database is opened in memory.
+        path: Option<PathBuf>,
+        /// Maximum size, in bytes, of the cache SQLite uses.
+        cache_size: usize,
+    },
+    /// Store the database in memory, in plain data structures. The content of the database is
+    /// lost when the node shuts down.
+    ///
+    /// Faster than an in-memory SQLite database. Mostly useful for testing purposes.
+    Memory,
 }
 
 /// Running client. As long as this object is alive, the client reads/writes the database and has
@@ -188,11 +241,12 @@ pub async fn start(config: Config<'_>) -> Client {
         let (db, existed) = open_database(
             &chain_spec,
             genesis_chain_information.as_ref(),
-            config.chain.sqlite_database_path,
-            config.chain.sqlite_cache_size,
+            config.chain.database_backend,
+            config.chain.extrinsics_index,
             config.show_informant,
         )
-        .await;
+        .await
+        .unwrap_or_else(|err| panic!("Failed to open database: {err}"));
 
         (Arc::new(database_thread::DatabaseThread::from(db)), existed)
     };
@@ -202,11 +256,12 @@ pub async fn start(config: Config<'_>) -> Client {
             open_database(
                 relay_chain_spec.as_ref().unwrap(),
                 relay_genesis_chain_information.as_ref().unwrap().as_ref(),
-                relay_chain.sqlite_database_path.clone(),
-                relay_chain.sqlite_cache_size,
+                relay_chain.database_backend.clone(),
+                relay_chain.extrinsics_index,
                 config.show_informant,
             )
             .await
+            .unwrap_or_else(|err| panic!("Failed to open database: {err}"))
             .0,
         )))
     } else {
@@ -378,6 +433,21 @@ pub async fn start(config: Config<'_>) -> Client {
         keystore
     });
 
+    let runtime_cache = if let Some(path) = config.runtime_cache_path {
+        match runtime_cache::RuntimeCache::open(path) {
+            Ok(cache) => Some(Arc::new(cache)),
+            Err(err) => {
+                config.log_callback.log(
+                    LogLevel::Warn,
+                    format!("runtime-cache-open-error; err={err}"),
+                );
+                None
+            }
+        }
+    } else {
+        None
+    };
+
     let consensus_service = consensus_service::ConsensusService::new(consensus_service::Config {
         tasks_executor: {
             let executor = config.tasks_executor.clone();
@@ -387,11 +457,18 @@ pub async fn start(config: Config<'_>) -> Client {
         genesis_block_hash,
         network_events_receiver: network_events_receivers.next().unwrap(),
         network_service: (network_service.clone(), 0),
-        database,
+        database: database.clone(),
         block_number_bytes: usize::from(chain_spec.block_number_bytes()),
         keystore,
         jaeger_service: jaeger_service.clone(),
         slot_duration_author_ratio: 43691_u16,
+        offchain_workers: config.chain.offchain_workers,
+        differential_execution: config.chain.differential_execution,
+        runtime_cache: runtime_cache.clone(),
+        code_substitutes: chain_spec
+            .code_substitutes()
+            .map(|(block_number, code)| (block_number, code.to_vec()))
+            .collect(),
     })
     .await;
 
@@ -431,6 +508,19 @@ pub async fn start(config: Config<'_>) -> Client {
                 }),
                 jaeger_service, // TODO: consider passing a different jaeger service with a different service name
                 slot_duration_author_ratio: 43691_u16,
+                offchain_workers: config.relay_chain.as_ref().unwrap().offchain_workers,
+                differential_execution: config
+                    .relay_chain
+                    .as_ref()
+                    .unwrap()
+                    .differential_execution,
+                runtime_cache,
+                code_substitutes: relay_chain_spec
+                    .as_ref()
+                    .unwrap()
+                    .code_substitutes()
+                    .map(|(block_number, code)| (block_number, code.to_vec()))
+                    .collect(),
             })
             .await,
         )
@@ -447,9 +537,14 @@ pub async fn start(config: Config<'_>) -> Client {
     // something else.
     let json_rpc_service = if let Some(bind_address) = config.json_rpc_address {
         let result = json_rpc_service::JsonRpcService::new(json_rpc_service::Config {
-            tasks_executor: { &mut |task| (config.tasks_executor)(task) },
+            tasks_executor: {
+                let executor = config.tasks_executor.clone();
+                Box::new(move |task| executor(task))
+            },
             log_callback: config.log_callback.clone(),
             bind_address,
+            database: database.clone(),
+            block_number_bytes: usize::from(chain_spec.block_number_bytes()),
         })
         .await;
 
@@ -535,199 +630,62 @@ pub async fn start(config: Config<'_>) -> Client {
 
 /// Opens the database from the file system, or create a new database if none is found.
 ///
-/// If `db_path` is `None`, open the database in memory instead.
-///
 /// The returned boolean is `true` if the database existed before.
 ///
 /// # Panic
 ///
-/// Panics if the database can't be open. This function is expected to be called from the `main`
-/// function.
+/// Panics if the genesis storage of the chain specification is invalid.
 ///
 // TODO: `show_progress` option should be moved to the CLI
 async fn open_database(
     chain_spec: &chain_spec::ChainSpec,
     genesis_chain_information: chain::chain_information::ChainInformationRef<'_>,
-    db_path: Option<PathBuf>,
-    sqlite_cache_size: usize,
+    database_backend: DatabaseBackend,
+    extrinsics_index: bool,
     show_progress: bool,
-) -> (full_sqlite::SqliteFullDatabase, bool) {
-    // The `unwrap()` here can panic for example in case of access denied.
+) -> Result<(Box<dyn database_backend::FullDatabase>, bool), full_sqlite::OpenError> {
+    let (sqlite_database_path, sqlite_cache_size) = match database_backend {
+        DatabaseBackend::Sqlite { path, cache_size } => (path, cache_size),
+        DatabaseBackend::Memory => {
+            let (genesis_storage_full_trie, state_version) = genesis_trie_nodes(chain_spec);
+
+            // The finalized block is the genesis block. As such, it has an empty body and
+            // no justification.
+            let database = full_memory::MemoryFullDatabase::new(
+                full_memory::Config {
+                    block_number_bytes: chain_spec.block_number_bytes().into(),
+                    extrinsics_index,
+                },
+                genesis_chain_information,
+                iter::empty(),
+                None,
+                genesis_storage_full_trie.into_iter(),
+                state_version,
+            );
+            return Ok((Box::new(database), false));
+        }
+    };
+
+    // This can fail for example in case of access denied, or if the database belongs to a
+    // different chain.
     match background_open_database(
-        db_path.clone(),
+        sqlite_database_path,
         chain_spec.block_number_bytes().into(),
+        genesis_chain_information
+            .finalized_block_header
+            .hash(chain_spec.block_number_bytes().into()),
         sqlite_cache_size,
+        extrinsics_index,
         show_progress,
     )
-    .await
-    .unwrap()
+    .await?
     {
         // Database already exists and contains data.
-        full_sqlite::DatabaseOpen::Open(database) => {
-            if database.block_hash_by_number(0).unwrap().next().unwrap()
-                != genesis_chain_information
-                    .finalized_block_header
-                    .hash(chain_spec.block_number_bytes().into())
-            {
-                panic!("Mismatch between database and chain specification. Shutting down node.");
-            }
-
-            (database, true)
-        }
+        full_sqlite::DatabaseOpen::Open(database) => Ok((Box::new(database), true)),
 
         // The database doesn't exist or is empty.
         full_sqlite::DatabaseOpen::Empty(empty) => {
-            let genesis_storage = chain_spec.genesis_storage().into_genesis_items().unwrap(); // TODO: return error instead
-
-            // In order to determine the state_version of the genesis block, we need to compile
-            // the runtime.
-            // TODO: return errors instead of panicking
-            // TODO: consider not throwing away the runtime
-            let state_version = executor::host::HostVmPrototype::new(executor::host::Config {
-                module: genesis_storage.value(b":code").unwrap(),
-                heap_pages: executor::storage_heap_pages_to_value(
-                    genesis_storage.value(b":heappages"),
-                )
-                .unwrap(),
-                exec_hint: executor::vm::ExecHint::Oneshot,
-                allow_unresolved_imports: true,
-            })
-            .unwrap()
-            .runtime_version()
-            .decode()
-            .state_version
-            .map(u8::from)
-            .unwrap_or(0);
-
-            // The chain specification only contains trie nodes that have a storage value attached
-            // to them, while the database needs to know all trie nodes (including branch nodes).
-            // The good news is that we can determine the latter from the former, which we do
-            // here.
-            // TODO: consider moving this block to the chain spec module
-            // TODO: poorly optimized
-            let mut trie_structure = {
-                let mut trie_structure = trie::trie_structure::TrieStructure::new();
-                for (key, value) in genesis_storage.iter() {
-                    match trie_structure.node(trie::bytes_to_nibbles(key.iter().copied())) {
-                        trie::trie_structure::Entry::Vacant(e) => {
-                            e.insert_storage_value().insert(
-                                (Some(value), None::<trie::trie_node::MerkleValueOutput>),
-                                (None, None),
-                            );
-                        }
-                        trie::trie_structure::Entry::Occupied(
-                            trie::trie_structure::NodeAccess::Branch(mut e),
-                        ) => {
-                            *e.user_data() = (Some(value), None);
-                            e.insert_storage_value();
-                        }
-                        trie::trie_structure::Entry::Occupied(
-                            trie::trie_structure::NodeAccess::Storage(_),
-                        ) => {
-                            // Duplicate entry.
-                            panic!() // TODO: don't panic?
-                        }
-                    }
-                }
-
-                // Calculate the Merkle values of the nodes.
-                for node_index in trie_structure
-                    .iter_ordered()
-                    .collect::<Vec<_>>()
-                    .into_iter()
-                    .rev()
-                {
-                    let mut node_access = trie_structure.node_by_index(node_index).unwrap();
-
-                    let children = core::array::from_fn::<_, 16, _>(|n| {
-                        node_access
-                            .child(trie::Nibble::try_from(u8::try_from(n).unwrap()).unwrap())
-                            .map(|mut child| child.user_data().1.as_ref().unwrap().clone())
-                    });
-
-                    let is_root_node = node_access.is_root_node();
-                    let partial_key = node_access.partial_key().collect::<Vec<_>>().into_iter();
-
-                    // We have to hash the storage value ahead of time if necessary due to borrow
-                    // checking difficulties.
-                    let storage_value_hashed =
-                        match (node_access.user_data().0.as_ref(), state_version) {
-                            (Some(v), 1) => {
-                                if v.len() >= 33 {
-                                    Some(blake2_rfc::blake2b::blake2b(32, &[], v))
-                                } else {
-                                    None
-                                }
-                            }
-                            _ => None,
-                        };
-                    let storage_value = match (
-                        node_access.user_data().0.as_ref(),
-                        storage_value_hashed.as_ref(),
-                    ) {
-                        (_, Some(storage_value_hashed)) => trie::trie_node::StorageValue::Hashed(
-                            <&[u8; 32]>::try_from(storage_value_hashed.as_bytes()).unwrap(),
-                        ),
-                        (Some(v), None) => trie::trie_node::StorageValue::Unhashed(&v[..]),
-                        (None, _) => trie::trie_node::StorageValue::None,
-                    };
-
-                    let merkle_value = trie::trie_node::calculate_merkle_value(
-                        trie::trie_node::Decoded {
-                            children,
-                            partial_key,
-                            storage_value,
-                        },
-                        is_root_node,
-                    )
-                    .unwrap();
-
-                    node_access.into_user_data().1 = Some(merkle_value);
-                }
-
-                trie_structure
-            };
-
-            // Build the iterator of trie nodes.
-            let genesis_storage_full_trie = trie_structure
-                .iter_unordered()
-                .collect::<Vec<_>>()
-                .into_iter()
-                .map(|node_index| {
-                    let (storage_value, Some(merkle_value)) = &trie_structure[node_index]
-                        else { unreachable!() };
-                    // Cloning to solve borrow checker restriction. // TODO: optimize?
-                    let storage_value = if let Some(storage_value) = storage_value {
-                        // TODO: child tries support?
-                        full_sqlite::InsertTrieNodeStorageValue::Value {
-                            value: Cow::Owned(storage_value.to_vec()),
-                            references_merkle_value: false,
-                        }
-                    } else {
-                        full_sqlite::InsertTrieNodeStorageValue::NoValue
-                    };
-                    let merkle_value = merkle_value.as_ref().to_owned();
-                    let mut node_access = trie_structure.node_by_index(node_index).unwrap();
-
-                    full_sqlite::InsertTrieNode {
-                        storage_value,
-                        merkle_value: Cow::Owned(merkle_value),
-                        children_merkle_values: array::from_fn::<_, 16, _>(|n| {
-                            let child_index =
-                                trie::Nibble::try_from(u8::try_from(n).unwrap()).unwrap();
-                            if let Some(mut child) = node_access.child(child_index) {
-                                Some(Cow::Owned(
-                                    child.user_data().1.as_ref().unwrap().as_ref().to_vec(),
-                                ))
-                            } else {
-                                None
-                            }
-                        }),
-                        partial_key_nibbles: Cow::Owned(
-                            node_access.partial_key().map(u8::from).collect::<Vec<_>>(),
-                        ),
-                    }
-                });
+            let (genesis_storage_full_trie, state_version) = genesis_trie_nodes(chain_spec);
 
             // The finalized block is the genesis block. As such, it has an empty body and
             // no justification.
@@ -736,15 +694,235 @@ async fn open_database(
                     genesis_chain_information,
                     iter::empty(),
                     None,
-                    genesis_storage_full_trie,
+                    genesis_storage_full_trie.into_iter(),
                     state_version,
                 )
-                .unwrap();
-            (database, false)
+                .map_err(full_sqlite::OpenError::Access)?;
+            Ok((Box::new(database), false))
         }
     }
 }
 
+/// Builds the list of all the trie nodes of the storage of the genesis block of the given chain,
+/// and returns it alongside with the state version of the genesis block.
+///
+/// # Panic
+///
+/// Panics if the genesis storage of the chain specification is invalid.
+///
+fn genesis_trie_nodes(
+    chain_spec: &chain_spec::ChainSpec,
+) -> (Vec<database::InsertTrieNode<'static>>, u8) {
+    let genesis_storage = chain_spec.genesis_storage().into_genesis_items().unwrap(); // TODO: return error instead
+
+    // In order to determine the state_version of the genesis block, we need to compile
+    // the runtime.
+    // TODO: return errors instead of panicking
+    // TODO: consider not throwing away the runtime
+    let state_version = executor::host::HostVmPrototype::new(executor::host::Config {
+        module: genesis_storage.value(b":code").unwrap(),
+        heap_pages: executor::storage_heap_pages_to_value(genesis_storage.value(b":heappages"))
+            .unwrap(),
+        exec_hint: executor::vm::ExecHint::Oneshot,
+        compilation_cache: None,
+        allow_unresolved_imports: true,
+    })
+    .unwrap()
+    .runtime_version()
+    .decode()
+    .state_version
+    .map(u8::from)
+    .unwrap_or(0);
+
+    // The main trie contains, in addition to the entries found in the chain specification, one
+    // entry per child trie whose value is the root of this child trie.
+    let trie_entry_version = trie::TrieEntryVersion::try_from(state_version).unwrap(); // TODO: return error instead
+    let child_tries_roots = genesis_storage
+        .child_tries()
+        .map(|child_trie| {
+            let key = trie::CHILD_STORAGE_PREFIX
+                .iter()
+                .chain(child_trie)
+                .copied()
+                .collect::<Vec<_>>();
+            let root = genesis_storage
+                .child_trie_root_hash(child_trie, trie_entry_version)
+                .unwrap();
+            (key, root)
+        })
+        .collect::<Vec<_>>();
+
+    let mut genesis_storage_full_trie = build_trie_nodes(
+        genesis_storage
+            .iter()
+            .chain(
+                child_tries_roots
+                    .iter()
+                    .map(|(key, root)| (&key[..], &root[..])),
+            )
+            .map(|(key, value)| (key, value, state_version)),
+        true,
+    );
+
+    for child_trie in genesis_storage.child_tries() {
+        genesis_storage_full_trie.extend(build_trie_nodes(
+            genesis_storage
+                .child_trie_iter(child_trie)
+                .map(|(key, value)| (key, value, state_version)),
+            false,
+        ));
+    }
+
+    (genesis_storage_full_trie, state_version)
+}
+
+/// Builds the list of all the trie nodes of a trie from the list of its storage entries. Each
+/// entry consists of a key, a value, and the trie entry version to use when calculating the
+/// Merkle value of the node of this entry.
+///
+/// If `child_tries_references` is `true`, the values of the entries whose key starts with
+/// `:child_storage:default:` are considered as referencing the root of a child trie whose nodes
+/// are inserted in the database alongside with these nodes.
+///
+/// # Panic
+///
+/// Panics if the same key is found multiple times.
+///
+fn build_trie_nodes<'a>(
+    entries: impl Iterator<Item = (&'a [u8], &'a [u8], u8)>,
+    child_tries_references: bool,
+) -> Vec<database::InsertTrieNode<'static>> {
+    // The list of entries only contains trie nodes that have a storage value attached to
+    // them, while the database needs to know all trie nodes (including branch nodes).
+    // The good news is that we can determine the latter from the former, which we do
+    // here.
+    // TODO: poorly optimized
+    let mut trie_structure = {
+        let mut trie_structure = trie::trie_structure::TrieStructure::new();
+        for (key, value, version) in entries {
+            let references_merkle_value =
+                child_tries_references && key.starts_with(trie::CHILD_STORAGE_PREFIX);
+            match trie_structure.node(trie::bytes_to_nibbles(key.iter().copied())) {
+                trie::trie_structure::Entry::Vacant(e) => {
+                    e.insert_storage_value().insert(
+                        (
+                            Some((value, version, references_merkle_value)),
+                            None::<trie::trie_node::MerkleValueOutput>,
+                        ),
+                        (None, None),
+                    );
+                }
+                trie::trie_structure::Entry::Occupied(
+                    trie::trie_structure::NodeAccess::Branch(mut e),
+                ) => {
+                    *e.user_data() = (Some((value, version, references_merkle_value)), None);
+                    e.insert_storage_value();
+                }
+                trie::trie_structure::Entry::Occupied(
+                    trie::trie_structure::NodeAccess::Storage(_),
+                ) => {
+                    // Duplicate entry.
+                    panic!() // TODO: don't panic?
+                }
+            }
+        }
+
+        // Calculate the Merkle values of the nodes.
+        for node_index in trie_structure
+            .iter_ordered()
+            .collect::<Vec<_>>()
+            .into_iter()
+            .rev()
+        {
+            let mut node_access = trie_structure.node_by_index(node_index).unwrap();
+
+            let children = core::array::from_fn::<_, 16, _>(|n| {
+                node_access
+                    .child(trie::Nibble::try_from(u8::try_from(n).unwrap()).unwrap())
+                    .map(|mut child| child.user_data().1.as_ref().unwrap().clone())
+            });
+
+            let is_root_node = node_access.is_root_node();
+            let partial_key = node_access.partial_key().collect::<Vec<_>>().into_iter();
+
+            // We have to hash the storage value ahead of time if necessary due to borrow
+            // checking difficulties.
+            let storage_value_hashed = match node_access.user_data().0 {
+                Some((v, 1, _)) => {
+                    if v.len() >= 33 {
+                        Some(blake2_rfc::blake2b::blake2b(32, &[], v))
+                    } else {
+                        None
+                    }
+                }
+                _ => None,
+            };
+            let storage_value = match (
+                node_access.user_data().0.as_ref(),
+                storage_value_hashed.as_ref(),
+            ) {
+                (_, Some(storage_value_hashed)) => trie::trie_node::StorageValue::Hashed(
+                    <&[u8; 32]>::try_from(storage_value_hashed.as_bytes()).unwrap(),
+                ),
+                (Some((v, _, _)), None) => trie::trie_node::StorageValue::Unhashed(v),
+                (None, _) => trie::trie_node::StorageValue::None,
+            };
+
+            let merkle_value = trie::trie_node::calculate_merkle_value(
+                trie::trie_node::Decoded {
+                    children,
+                    partial_key,
+                    storage_value,
+                },
+                is_root_node,
+            )
+            .unwrap();
+
+            node_access.into_user_data().1 = Some(merkle_value);
+        }
+
+        trie_structure
+    };
+
+    // Build the iterator of trie nodes.
+    trie_structure
+        .iter_unordered()
+        .collect::<Vec<_>>()
+        .into_iter()
+        .map(|node_index| {
+            let (storage_value, Some(merkle_value)) = &trie_structure[node_index] else {
+                unreachable!()
+            };
+            // Cloning to solve borrow checker restriction. // TODO: optimize?
+            let storage_value =
+                if let Some((storage_value, _, references_merkle_value)) = storage_value {
+                    database::InsertTrieNodeStorageValue::Value {
+                        value: Cow::Owned(storage_value.to_vec()),
+                        references_merkle_value: *references_merkle_value,
+                    }
+                } else {
+                    database::InsertTrieNodeStorageValue::NoValue
+                };
+            let merkle_value = merkle_value.as_ref().to_owned();
+            let mut node_access = trie_structure.node_by_index(node_index).unwrap();
+
+            database::InsertTrieNode {
+                storage_value,
+                merkle_value: Cow::Owned(merkle_value),
+                children_merkle_values: array::from_fn::<_, 16, _>(|n| {
+                    let child_index = trie::Nibble::try_from(u8::try_from(n).unwrap()).unwrap();
+                    node_access.child(child_index).map(|mut child| {
+                        Cow::Owned(child.user_data().1.as_ref().unwrap().as_ref().to_vec())
+                    })
+                }),
+                partial_key_nibbles: Cow::Owned(
+                    node_access.partial_key().map(u8::from).collect::<Vec<_>>(),
+                ),
+            }
+        })
+        .collect::<Vec<_>>()
+}
+
 /// Since opening the database can take a long time, this utility function performs this operation
 /// in the background while showing a small progress bar to the user.
 ///
@@ -752,9 +930,11 @@ async fn open_database(
 async fn background_open_database(
     path: Option<PathBuf>,
     block_number_bytes: usize,
+    genesis_block_hash: [u8; 32],
     sqlite_cache_size: usize,
+    extrinsics_index: bool,
     show_progress: bool,
-) -> Result<full_sqlite::DatabaseOpen, full_sqlite::InternalError> {
+) -> Result<full_sqlite::DatabaseOpen, full_sqlite::OpenError> {
     let (tx, rx) = oneshot::channel();
     let mut rx = rx.fuse();
 
@@ -763,6 +943,8 @@ async fn background_open_database(
         move || {
             let result = full_sqlite::open(full_sqlite::Config {
                 block_number_bytes,
+                genesis_block_hash: Some(genesis_block_hash),
+                extrinsics_index,
                 cache_size: sqlite_cache_size,
                 ty: if let Some(path) = &path {
                     full_sqlite::ConfigTy::Disk {
@@ -781,6 +963,8 @@ async fn background_open_database(
     if thread_spawn_result.is_err() {
         return full_sqlite::open(full_sqlite::Config {
             block_number_bytes,
+            genesis_block_hash: Some(genesis_block_hash),
+            extrinsics_index,
             cache_size: sqlite_cache_size,
             ty: if let Some(path) = &path {
                 full_sqlite::ConfigTy::Disk {
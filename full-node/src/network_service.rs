@@ -27,11 +27,11 @@
 // TODO: doc
 // TODO: re-review this once finished
 
-use crate::{database_thread, jaeger_service, util, LogCallback, LogLevel};
+use crate::{database_backend, database_thread, jaeger_service, util, LogCallback, LogLevel};
 
 use core::{cmp, future::Future, mem, pin::Pin, task::Poll, time::Duration};
 use futures_channel::oneshot;
-use hashbrown::HashMap;
+use hashbrown::{HashMap, HashSet};
 use smol::{
     channel, future,
     lock::Mutex,
@@ -39,7 +39,6 @@ use smol::{
     stream::{Stream, StreamExt as _},
 };
 use smoldot::{
-    database::full_sqlite,
     header,
     informant::HashDisplay,
     libp2p::{
@@ -59,6 +58,7 @@ use std::{
 };
 
 mod tasks;
+mod tests;
 
 /// Configuration for a [`NetworkService`].
 pub struct Config {
@@ -182,6 +182,11 @@ enum ToBackground {
         is_best: bool,
         result_tx: oneshot::Sender<Result<(), QueueNotificationError>>,
     },
+    ForegroundAnnounceTransaction {
+        chain_index: usize,
+        transaction: Vec<u8>,
+        result_tx: oneshot::Sender<Vec<PeerId>>,
+    },
     ForegroundSetLocalBestBlock {
         chain_index: usize,
         best_hash: [u8; 32],
@@ -201,6 +206,11 @@ enum ToBackground {
         result_tx: oneshot::Sender<usize>,
     },
     ForegroundShutdown,
+    GrandpaWarpSyncResponse {
+        request_id: service::InRequestId,
+        /// `None` if the request can't be answered.
+        response: Option<WarpSyncResponse>,
+    },
 }
 struct Inner {
     /// Value provided through [`Config::identify_agent_version`].
@@ -268,6 +278,10 @@ struct Inner {
     /// List of Kademlia discovery operations that have been started but not finished yet.
     kademlia_discovery_operations:
         HashMap<service::KademliaOperationId, usize, fnv::FnvBuildHasher>,
+
+    /// List of incoming GrandPa warp sync requests whose response is being built in a separate
+    /// task and that haven't been cancelled by the remote.
+    pending_grandpa_warp_sync_requests: HashSet<service::InRequestId, fnv::FnvBuildHasher>,
 }
 
 impl NetworkService {
@@ -302,6 +316,7 @@ impl NetworkService {
                     None
                 },
                 allow_inbound_block_requests: true,
+                allow_inbound_grandpa_warp_sync_requests: chain.has_grandpa_protocol,
             });
 
             databases.push(chain.database.clone());
@@ -361,6 +376,10 @@ impl NetworkService {
                 4,
                 Default::default(),
             ),
+            pending_grandpa_warp_sync_requests: hashbrown::HashSet::with_capacity_and_hasher(
+                4,
+                Default::default(),
+            ),
             jaeger_service: config.jaeger_service.clone(),
         };
 
@@ -536,6 +555,11 @@ impl NetworkService {
         Ok((network_service, receivers))
     }
 
+    /// Returns the identity of the local node.
+    pub fn local_peer_id(&self) -> &PeerId {
+        &self.local_peer_id
+    }
+
     /// Returns the number of established TCP connections, both incoming and outgoing.
     pub async fn num_established_connections(&self) -> usize {
         let (result_tx, result_rx) = oneshot::channel();
@@ -610,6 +634,33 @@ impl NetworkService {
         result_rx.await.unwrap()
     }
 
+    /// Sends a transaction to all the peers we are connected to on the given chain.
+    ///
+    /// Returns the list of peers the transaction has been sent to.
+    ///
+    /// Note that the remotes don't confirm that they have received the transaction, and that
+    /// successfully sending a transaction to a peer doesn't guarantee that it will be received.
+    pub async fn announce_transaction(
+        self: Arc<Self>,
+        chain_index: usize,
+        transaction: &[u8],
+    ) -> Vec<PeerId> {
+        let (result_tx, result_rx) = oneshot::channel();
+
+        let _ = self
+            .to_background_tx
+            .lock()
+            .await
+            .send(ToBackground::ForegroundAnnounceTransaction {
+                chain_index,
+                transaction: transaction.to_vec(),
+                result_tx,
+            })
+            .await;
+
+        result_rx.await.unwrap()
+    }
+
     /// Sends a blocks request to the given peer.
     // TODO: more docs
     // TODO: proper error type
@@ -927,9 +978,11 @@ async fn background_task(mut inner: Inner) {
                         // We never start a request of any other kind.
                         unreachable!()
                     }
-                    service::Event::RequestInCancel { .. } => {
-                        // Requests are answered immediately, and thus cancelling events can't happen.
-                        unreachable!()
+                    service::Event::RequestInCancel { request_id } => {
+                        // Only GrandPa warp sync requests aren't answered immediately, and thus
+                        // cancelling events can only concern them.
+                        let _was_in = inner.pending_grandpa_warp_sync_requests.remove(&request_id);
+                        debug_assert!(_was_in);
                     }
                     service::Event::KademliaDiscoveryResult {
                         operation_id,
@@ -1038,6 +1091,58 @@ async fn background_task(mut inner: Inner) {
                             },
                         );
                     }
+                    service::Event::GrandpaWarpSyncRequestIn {
+                        peer_id,
+                        chain_index,
+                        begin_hash,
+                        request_id,
+                    } => {
+                        inner.log_callback.log(
+                            LogLevel::Debug,
+                            format!(
+                                "incoming-warp-sync-request; peer_id={}; chain_index={}; begin_hash={}",
+                                peer_id,
+                                chain_index,
+                                HashDisplay(&begin_hash)
+                            ),
+                        );
+
+                        // Building the response requires reading from the database, which is
+                        // done in a separate task in order to not block the networking.
+                        inner.pending_grandpa_warp_sync_requests.insert(request_id);
+                        let database = inner.databases[chain_index].clone();
+                        let block_number_bytes = inner.network.block_number_bytes(chain_index);
+                        let log_callback = inner.log_callback.clone();
+                        let to_background_tx = inner.to_background_tx.clone();
+                        (inner.tasks_executor)(Box::pin(async move {
+                            let response = match grandpa_warp_sync_request_response(
+                                &database,
+                                block_number_bytes,
+                                begin_hash,
+                            )
+                            .await
+                            {
+                                Ok(response) => response,
+                                Err(error) => {
+                                    log_callback.log(
+                                        LogLevel::Warn,
+                                        format!(
+                                            "incoming-warp-sync-request-error; error={}",
+                                            error
+                                        ),
+                                    );
+                                    None
+                                }
+                            };
+
+                            let _ = to_background_tx
+                                .send(ToBackground::GrandpaWarpSyncResponse {
+                                    request_id,
+                                    response,
+                                })
+                                .await;
+                        }));
+                    }
                     service::Event::GrandpaNeighborPacket {
                         chain_index,
                         peer_id,
@@ -1326,6 +1431,30 @@ async fn background_task(mut inner: Inner) {
 
                 let _ = result_tx.send(result);
             }
+            ToBackground::ForegroundAnnounceTransaction {
+                chain_index,
+                transaction,
+                result_tx,
+            } => {
+                // TODO: don't send the same transaction multiple times to the same peer
+                let mut sent_peers = Vec::new();
+                for peer in inner
+                    .network
+                    .opened_transactions_substream(chain_index)
+                    .cloned()
+                    .collect::<Vec<_>>()
+                {
+                    if inner
+                        .network
+                        .announce_transaction(&peer, chain_index, &transaction)
+                        .is_ok()
+                    {
+                        sent_peers.push(peer);
+                    }
+                }
+
+                let _ = result_tx.send(sent_peers);
+            }
             ToBackground::ForegroundSetLocalBestBlock {
                 chain_index,
                 best_hash,
@@ -1366,6 +1495,34 @@ async fn background_task(mut inner: Inner) {
             } => {
                 let _ = result_tx.send(inner.network.num_peers(chain_index));
             }
+            ToBackground::GrandpaWarpSyncResponse {
+                request_id,
+                response,
+            } => {
+                // The request might have been cancelled while the response was being built.
+                if !inner.pending_grandpa_warp_sync_requests.remove(&request_id) {
+                    continue;
+                }
+
+                inner.network.respond_grandpa_warp_sync(
+                    request_id,
+                    response.as_ref().map(|(fragments, is_finished)| {
+                        protocol::GrandpaWarpSyncResponse {
+                            fragments: fragments
+                                .iter()
+                                .map(|(header, justification)| {
+                                    protocol::GrandpaWarpSyncResponseFragment {
+                                        scale_encoded_header: header,
+                                        scale_encoded_justification: justification,
+                                    }
+                                })
+                                .collect(),
+                            is_finished: *is_finished,
+                        }
+                    }),
+                );
+                inner.process_network_service_events = true;
+            }
         }
     }
 }
@@ -1392,7 +1549,7 @@ async fn blocks_request_response(
     database: &database_thread::DatabaseThread,
     block_number_bytes: usize,
     config: protocol::BlocksRequestConfig,
-) -> Result<Vec<protocol::BlockData>, full_sqlite::AccessError> {
+) -> Result<Vec<protocol::BlockData>, database_backend::AccessError> {
     database
         .with_database(move |database| {
             let num_blocks = cmp::min(
@@ -1412,7 +1569,7 @@ async fn blocks_request_response(
                     protocol::BlocksRequestConfigStart::Hash(hash) => hash,
                     protocol::BlocksRequestConfigStart::Number(number) => {
                         // TODO: naive block selection ; should choose the best chain instead
-                        match database.block_hash_by_number(number)?.next() {
+                        match database.block_hash_by_number(number)?.into_iter().next() {
                             Some(h) => h,
                             None => break,
                         }
@@ -1446,15 +1603,24 @@ async fn blocks_request_response(
                     },
                     body: if config.fields.body {
                         Some(match database.block_extrinsics(&hash)? {
-                            Some(body) => body.collect(),
+                            Some(body) => body,
                             None => break,
                         })
                     } else {
                         None
                     },
                     justifications: if config.fields.justifications {
-                        // TODO: justifications aren't saved in database at the moment
-                        Some(Vec::new())
+                        // The database only ever stores GrandPa justifications.
+                        Some(
+                            database
+                                .block_justification(&hash)?
+                                .map(|justification| protocol::Justification {
+                                    engine_id: *b"FRNK",
+                                    justification,
+                                })
+                                .into_iter()
+                                .collect(),
+                        )
                     } else {
                         None
                     },
@@ -1465,3 +1631,146 @@ async fn blocks_request_response(
         })
         .await
 }
+
+/// List of fragments forming a GrandPa warp sync proof, and whether the proof is complete.
+type WarpSyncResponse = (Vec<WarpSyncFragment>, bool);
+
+/// SCALE-encoded header and justification of a block of a GrandPa warp sync proof.
+type WarpSyncFragment = (Vec<u8>, Vec<u8>);
+
+/// Builds the fragments of a GrandPa warp sync proof starting at the given block, and whether
+/// the proof is complete.
+///
+/// Returns `Ok(None)` if `begin_hash` isn't a finalized block of the database.
+async fn grandpa_warp_sync_request_response(
+    database: &database_thread::DatabaseThread,
+    block_number_bytes: usize,
+    begin_hash: [u8; 32],
+) -> Result<Option<WarpSyncResponse>, database_backend::AccessError> {
+    database
+        .with_database(move |database| {
+            build_grandpa_warp_sync_response(database, block_number_bytes, begin_hash)
+        })
+        .await
+}
+
+/// Synchronous implementation of [`grandpa_warp_sync_request_response`].
+fn build_grandpa_warp_sync_response(
+    database: &dyn database_backend::FullDatabase,
+    block_number_bytes: usize,
+    begin_hash: [u8; 32],
+) -> Result<Option<WarpSyncResponse>, database_backend::AccessError> {
+    // Maximum size of the justifications and headers in a response. The response is cut when
+    // this limit is reached, in which case the requester is expected to send a follow-up request.
+    const MAX_RESPONSE_SIZE: usize = 8 * 1024 * 1024;
+
+    let begin_number = match database.block_scale_encoded_header(&begin_hash)? {
+        Some(header) => {
+            header::decode(&header, block_number_bytes)
+                .map_err(database_backend::AccessError::BlockHeaderCorrupted)?
+                .number
+        }
+        None => return Ok(None),
+    };
+
+    // The starting block must be finalized. Since the database only keeps the finalized chain
+    // below the finalized block, comparing numbers is enough.
+    let finalized_number = {
+        let finalized_hash = database.finalized_block_hash()?;
+        let finalized_header = database
+            .block_scale_encoded_header(&finalized_hash)?
+            .ok_or(database_backend::AccessError::MissingFinalizedBlockHeader)?;
+        header::decode(&finalized_header, block_number_bytes)
+            .map_err(database_backend::AccessError::BlockHeaderCorrupted)?
+            .number
+    };
+    if begin_number > finalized_number {
+        return Ok(None);
+    }
+
+    // Every block that changes the list of GrandPa authorities must be part of the proof,
+    // otherwise the requester can't verify the justifications that follow. These blocks are
+    // found through an index of the database rather than by inspecting every header.
+    let mut fragments = Vec::new();
+    let mut response_size = 0;
+    // Number of the block of the last element of `fragments`, or `begin_number` if empty.
+    let mut last_fragment_number = begin_number;
+
+    for hash in database.finalized_grandpa_authorities_changes(begin_number)? {
+        let scale_encoded_header = database
+            .block_scale_encoded_header(&hash)?
+            .ok_or(database_backend::AccessError::MissingFinalizedBlockHeader)?;
+        let number = header::decode(&scale_encoded_header, block_number_bytes)
+            .map_err(database_backend::AccessError::BlockHeaderCorrupted)?
+            .number;
+
+        // The proof can't go past a change in the list of authorities whose justification
+        // is unknown. The proof is stopped at the latest justified block instead, and the
+        // requester is expected to download the missing justification from a different peer.
+        let Some(justification) = database.block_justification(&hash)? else {
+            if let Some((fragment, _)) = latest_justified_fragment(
+                database,
+                block_number_bytes,
+                last_fragment_number,
+                number,
+            )? {
+                response_size += fragment.0.len() + fragment.1.len();
+                if response_size <= MAX_RESPONSE_SIZE || fragments.is_empty() {
+                    fragments.push(fragment);
+                }
+            }
+            return Ok(Some((fragments, false)));
+        };
+
+        response_size += scale_encoded_header.len() + justification.len();
+        if response_size > MAX_RESPONSE_SIZE && !fragments.is_empty() {
+            return Ok(Some((fragments, false)));
+        }
+
+        fragments.push((scale_encoded_header, justification));
+        last_fragment_number = number;
+    }
+
+    // The latest justified block is always included in order to prove the finality of the most
+    // recent block possible.
+    if let Some((fragment, number)) = latest_justified_fragment(
+        database,
+        block_number_bytes,
+        last_fragment_number,
+        finalized_number.saturating_add(1),
+    )? {
+        response_size += fragment.0.len() + fragment.1.len();
+        if response_size > MAX_RESPONSE_SIZE && !fragments.is_empty() {
+            return Ok(Some((fragments, false)));
+        }
+        fragments.push(fragment);
+        last_fragment_number = number;
+    }
+
+    // The proof is complete only if it reaches the finalized block. If the finalized block has
+    // no justification, the requester is expected to ask a different peer.
+    Ok(Some((fragments, last_fragment_number == finalized_number)))
+}
+
+/// Returns the SCALE-encoded header and justification of the finalized block with the highest
+/// number strictly between `after_block_number` and `before_block_number` that has a
+/// justification, and its number.
+fn latest_justified_fragment(
+    database: &dyn database_backend::FullDatabase,
+    block_number_bytes: usize,
+    after_block_number: u64,
+    before_block_number: u64,
+) -> Result<Option<(WarpSyncFragment, u64)>, database_backend::AccessError> {
+    let Some(hash) =
+        database.latest_finalized_justified_block(after_block_number, before_block_number)?
+        else { return Ok(None) };
+    let scale_encoded_header = database
+        .block_scale_encoded_header(&hash)?
+        .ok_or(database_backend::AccessError::MissingFinalizedBlockHeader)?;
+    let number = header::decode(&scale_encoded_header, block_number_bytes)
+        .map_err(database_backend::AccessError::BlockHeaderCorrupted)?
+        .number;
+    let Some(justification) = database.block_justification(&hash)?
+        else { return Ok(None) };
+    Ok(Some(((scale_encoded_header, justification), number)))
+}
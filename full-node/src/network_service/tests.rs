@@ -0,0 +1,336 @@
+// Smoldot
+// Copyright (C) 2023  Pierre Krieger
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <http://www.gnu.org/licenses/>.
+
+#![cfg(test)]
+
+use super::build_grandpa_warp_sync_response;
+
+use core::{iter, num::NonZeroU64};
+use smoldot::{chain::chain_information, database::full_memory, header, sync::warp_sync, trie};
+
+/// Chain made of a genesis block followed with blocks whose GrandPa authorities are each made
+/// of a single authority.
+struct TestChain {
+    database: full_memory::MemoryFullDatabase,
+    genesis_chain_information: chain_information::ValidChainInformation,
+    /// Signing key of the authority of each authorities set, indexed by set id.
+    authorities: Vec<ed25519_zebra::SigningKey>,
+    /// Hashes of the non-genesis blocks, ordered by increasing number.
+    blocks: Vec<[u8; 32]>,
+}
+
+impl TestChain {
+    fn new(num_authorities_sets: u8) -> Self {
+        let authorities = (0..num_authorities_sets)
+            .map(|n| ed25519_zebra::SigningKey::from([n + 1; 32]))
+            .collect::<Vec<_>>();
+
+        let genesis_chain_information = chain_information::ChainInformation {
+            finalized_block_header: Box::new(header::Header {
+                parent_hash: [0; 32],
+                number: 0,
+                state_root: trie::EMPTY_TRIE_MERKLE_VALUE,
+                extrinsics_root: header::extrinsics_root(&[] as &[Vec<u8>]),
+                digest: header::DigestRef::empty().into(),
+            }),
+            consensus: chain_information::ChainInformationConsensus::Aura {
+                finalized_authorities_list: Vec::new(),
+                slot_duration: NonZeroU64::new(6000).unwrap(),
+            },
+            finality: chain_information::ChainInformationFinality::Grandpa {
+                after_finalized_block_authorities_set_id: 0,
+                finalized_triggered_authorities: vec![header::GrandpaAuthority {
+                    public_key: public_key(&authorities[0]),
+                    weight: NonZeroU64::new(1).unwrap(),
+                }],
+                finalized_scheduled_change: None,
+            },
+        };
+
+        let database = full_memory::MemoryFullDatabase::new(
+            full_memory::Config {
+                block_number_bytes: 4,
+                extrinsics_index: false,
+            },
+            &genesis_chain_information,
+            iter::empty(),
+            None,
+            iter::empty(),
+            0,
+        );
+
+        TestChain {
+            database,
+            genesis_chain_information: genesis_chain_information.try_into().unwrap(),
+            authorities,
+            blocks: Vec::new(),
+        }
+    }
+
+    /// Adds a block to the chain and finalizes it.
+    ///
+    /// If `new_authorities_set` is `Some`, the block switches the GrandPa authorities to the
+    /// authority of the given set id.
+    fn push_block(&mut self, new_authorities_set: Option<usize>) {
+        let parent_hash = self.blocks.last().copied().unwrap_or_else(|| {
+            self.genesis_chain_information
+                .as_ref()
+                .finalized_block_header
+                .hash(4)
+        });
+        let number = u8::try_from(self.blocks.len() + 1).unwrap();
+
+        let mut scale_encoded_header = parent_hash.to_vec();
+        scale_encoded_header.push(number << 2);
+        scale_encoded_header.extend_from_slice(&trie::EMPTY_TRIE_MERKLE_VALUE);
+        scale_encoded_header.extend_from_slice(&header::extrinsics_root(&[] as &[Vec<u8>]));
+        match new_authorities_set {
+            Some(set_id) => {
+                // Digest containing a single `ScheduledChange` with a delay of 0.
+                let mut change = vec![1, 1 << 2];
+                change.extend_from_slice(&public_key(&self.authorities[set_id]));
+                change.extend_from_slice(&1u64.to_le_bytes());
+                change.extend_from_slice(&0u32.to_le_bytes());
+
+                scale_encoded_header.push(1 << 2);
+                scale_encoded_header.push(4);
+                scale_encoded_header.extend_from_slice(b"FRNK");
+                scale_encoded_header.push(u8::try_from(change.len()).unwrap() << 2);
+                scale_encoded_header.extend_from_slice(&change);
+            }
+            None => scale_encoded_header.push(0),
+        }
+
+        let hash = header::hash_from_scale_encoded_header(&scale_encoded_header);
+        self.database
+            .insert(
+                &scale_encoded_header,
+                true,
+                iter::empty::<Vec<u8>>(),
+                iter::empty(),
+                0,
+            )
+            .unwrap();
+        self.database.set_finalized(&hash).unwrap();
+        self.blocks.push(hash);
+    }
+
+    /// Stores in the database a justification of the block with the given number signed by the
+    /// authority of the given set id.
+    fn justify(&mut self, block_number: usize, set_id: usize) {
+        let block_hash = self.blocks[block_number - 1];
+        let block_number = u32::try_from(block_number).unwrap();
+        let round = 1u64;
+
+        let mut message = vec![1];
+        message.extend_from_slice(&block_hash);
+        message.extend_from_slice(&block_number.to_le_bytes());
+        message.extend_from_slice(&round.to_le_bytes());
+        message.extend_from_slice(&u64::try_from(set_id).unwrap().to_le_bytes());
+        let signature = <[u8; 64]>::from(self.authorities[set_id].sign(&message));
+
+        let mut justification = round.to_le_bytes().to_vec();
+        justification.extend_from_slice(&block_hash);
+        justification.extend_from_slice(&block_number.to_le_bytes());
+        justification.push(1 << 2);
+        justification.extend_from_slice(&block_hash);
+        justification.extend_from_slice(&block_number.to_le_bytes());
+        justification.extend_from_slice(&signature);
+        justification.extend_from_slice(&public_key(&self.authorities[set_id]));
+        justification.push(0);
+
+        self.database
+            .set_block_justification(&block_hash, &justification)
+            .unwrap();
+    }
+
+    /// Warp syncs from the genesis block using proofs built from the database. Returns the
+    /// number of fragments of the proof and the hash of the block that has been warp synced to.
+    fn warp_sync(&self) -> (usize, [u8; 32]) {
+        let mut sync = warp_sync::start_warp_sync::<(), ()>(warp_sync::Config {
+            start_chain_information: self.genesis_chain_information.clone(),
+            block_number_bytes: 4,
+            sources_capacity: 1,
+            requests_capacity: 1,
+        })
+        .map_err(|_| ())
+        .unwrap();
+        let source_id = sync.add_source(());
+
+        let mut num_fragments = 0;
+        loop {
+            match sync.status() {
+                warp_sync::Status::Fragments { .. } => {}
+                warp_sync::Status::ChainInformation {
+                    finalized_block_hash,
+                    ..
+                } => return (num_fragments, finalized_block_hash),
+            }
+
+            let Some((_, _, warp_sync::DesiredRequest::WarpSyncRequest { block_hash })) =
+                sync.desired_requests().next()
+                else { panic!() };
+            let request_id = sync.add_request(
+                source_id,
+                (),
+                warp_sync::RequestDetail::WarpSyncRequest { block_hash },
+            );
+
+            let (fragments, is_complete) =
+                build_grandpa_warp_sync_response(&self.database, 4, block_hash)
+                    .unwrap()
+                    .unwrap();
+            num_fragments += fragments.len();
+            sync.warp_sync_request_success(
+                request_id,
+                fragments
+                    .into_iter()
+                    .map(|(scale_encoded_header, scale_encoded_justification)| {
+                        warp_sync::WarpSyncFragment {
+                            scale_encoded_header,
+                            scale_encoded_justification,
+                        }
+                    })
+                    .collect(),
+                is_complete,
+            );
+
+            loop {
+                match sync.process_one() {
+                    warp_sync::ProcessOne::VerifyWarpSyncFragment(verify) => {
+                        let (new_sync, error) = verify.verify([0; 32]);
+                        assert!(error.is_none(), "{error:?}");
+                        sync = new_sync;
+                    }
+                    warp_sync::ProcessOne::Idle(new_sync) => {
+                        sync = new_sync;
+                        break;
+                    }
+                    _ => panic!(),
+                }
+            }
+        }
+    }
+}
+
+fn public_key(signing_key: &ed25519_zebra::SigningKey) -> [u8; 32] {
+    <[u8; 32]>::from(ed25519_zebra::VerificationKey::from(signing_key))
+}
+
+#[test]
+fn multiple_authorities_sets() {
+    let mut chain = TestChain::new(3);
+    chain.push_block(Some(1));
+    chain.justify(1, 0);
+    chain.push_block(None);
+    chain.push_block(Some(2));
+    chain.justify(3, 1);
+    chain.push_block(None);
+    chain.push_block(None);
+    chain.justify(5, 2);
+
+    let (num_fragments, warp_synced_block) = chain.warp_sync();
+    assert_eq!(num_fragments, 3);
+    assert_eq!(warp_synced_block, chain.blocks[4]);
+}
+
+#[test]
+fn stops_at_unjustified_authorities_change() {
+    let mut chain = TestChain::new(3);
+    chain.push_block(Some(1));
+    chain.justify(1, 0);
+    chain.push_block(None);
+    chain.justify(2, 1);
+    // The block that switches to the third authorities set doesn't have any justification.
+    chain.push_block(Some(2));
+    chain.push_block(None);
+    chain.justify(4, 2);
+
+    let genesis_hash = chain
+        .genesis_chain_information
+        .as_ref()
+        .finalized_block_header
+        .hash(4);
+    let (fragments, is_finished) =
+        build_grandpa_warp_sync_response(&chain.database, 4, genesis_hash)
+            .unwrap()
+            .unwrap();
+    assert_eq!(fragments.len(), 2);
+    assert_eq!(
+        header::hash_from_scale_encoded_header(&fragments[1].0),
+        chain.blocks[1]
+    );
+    assert!(!is_finished);
+
+    // A follow-up request can't go any further.
+    let (fragments, is_finished) =
+        build_grandpa_warp_sync_response(&chain.database, 4, chain.blocks[1])
+            .unwrap()
+            .unwrap();
+    assert!(fragments.is_empty());
+    assert!(!is_finished);
+}
+
+#[test]
+fn not_finished_if_finalized_block_unjustified() {
+    let mut chain = TestChain::new(2);
+    chain.push_block(Some(1));
+    chain.justify(1, 0);
+    chain.push_block(None);
+    chain.push_block(None);
+    chain.justify(3, 1);
+    chain.push_block(None);
+
+    let genesis_hash = chain
+        .genesis_chain_information
+        .as_ref()
+        .finalized_block_header
+        .hash(4);
+    let (fragments, is_finished) =
+        build_grandpa_warp_sync_response(&chain.database, 4, genesis_hash)
+            .unwrap()
+            .unwrap();
+    assert_eq!(fragments.len(), 2);
+    assert_eq!(
+        header::hash_from_scale_encoded_header(&fragments[1].0),
+        chain.blocks[2]
+    );
+    assert!(!is_finished);
+
+    // Once the finalized block is justified, the proof reaches it.
+    chain.justify(4, 1);
+    let (fragments, is_finished) =
+        build_grandpa_warp_sync_response(&chain.database, 4, genesis_hash)
+            .unwrap()
+            .unwrap();
+    assert_eq!(fragments.len(), 2);
+    assert_eq!(
+        header::hash_from_scale_encoded_header(&fragments[1].0),
+        chain.blocks[3]
+    );
+    assert!(is_finished);
+}
+
+#[test]
+fn unknown_or_non_finalized_begin_block() {
+    let chain = TestChain::new(1);
+    assert!(
+        build_grandpa_warp_sync_response(&chain.database, 4, [0xff; 32])
+            .unwrap()
+            .is_none()
+    );
+}
@@ -0,0 +1,702 @@
+// Smoldot
+// Copyright (C) 2023  Pierre Krieger
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Execution of off-chain workers.
+//!
+//! Off-chain workers consist in calling the `OffchainWorkerApi_offchain_worker` runtime function
+//! on top of a block. Contrary to other runtime calls, off-chain workers are allowed to perform
+//! HTTP requests, to access the off-chain storage, and to submit transactions.
+//!
+//! The modifications to the storage of the block that the off-chain worker performs are
+//! discarded.
+//!
+//! The transactions submitted by the off-chain worker are validated against the block on top of
+//! which the off-chain worker runs. Valid transactions are announced to the peer-to-peer network
+//! and, if the node has keys and thus might author blocks, queued for inclusion in the blocks
+//! authored locally.
+//!
+//! The off-chain worker runs with a copy of the runtime compiled with fuel metering, and is
+//! interrupted after a certain number of instructions, so that a runtime that never returns
+//! can't prevent later off-chain workers from running.
+
+use crate::{database_backend, database_thread, network_service, LogCallback, LogLevel};
+
+use futures_channel::mpsc;
+use smoldot::{
+    executor::{self, host, runtime_host, vm},
+    header,
+    identity::keystore,
+    informant::HashDisplay,
+    libp2p::PeerId,
+    transactions::validate,
+    trie,
+};
+use std::{
+    iter,
+    sync::Arc,
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
+
+mod http;
+
+/// Maximum number of WebAssembly instructions that an off-chain worker is allowed to execute.
+///
+/// The value is chosen to be comfortably above the cost of any legitimate off-chain worker.
+const FUEL_BUDGET: u64 = 20_000_000_000;
+
+/// Configuration for an off-chain worker execution.
+pub struct Config {
+    /// Runtime of the block on top of which to run the off-chain worker.
+    pub runtime: host::HostVmPrototype,
+
+    /// Code of the code substitute that [`Config::runtime`] has been built from, or `None` if
+    /// it has been built from the `:code` found in the storage of the block.
+    pub code_substitute: Option<Vec<u8>>,
+
+    /// SCALE-encoded header of the block on top of which to run the off-chain worker.
+    pub scale_encoded_header: Vec<u8>,
+
+    /// Number of bytes used to encode the block number in the header.
+    pub block_number_bytes: usize,
+
+    /// Database containing the storage of the block.
+    pub database: Arc<database_thread::DatabaseThread>,
+
+    /// Keystore used to answer the keystore-related requests of the runtime.
+    pub keystore: Arc<keystore::Keystore>,
+
+    /// Identity of the local node on the peer-to-peer network.
+    pub local_peer_id: PeerId,
+
+    /// Network service used to announce the transactions submitted by the off-chain worker, and
+    /// index of the chain within this network service.
+    pub network_service: (Arc<network_service::NetworkService>, usize),
+
+    /// Channel where the valid transactions submitted by the off-chain worker are sent, in order
+    /// to be included in the blocks authored locally.
+    ///
+    /// Nothing is sent on this channel if the keystore doesn't contain any key.
+    pub transactions_tx: mpsc::Sender<Vec<u8>>,
+
+    /// Function called in order to notify of something.
+    pub log_callback: Arc<dyn LogCallback + Send + Sync>,
+}
+
+/// Runs the off-chain worker of the given block until it finishes.
+///
+/// Does nothing if the runtime doesn't support off-chain workers.
+pub async fn run(config: Config) {
+    let block_hash = header::hash_from_scale_encoded_header(&config.scale_encoded_header);
+    let log_callback = config.log_callback.clone();
+
+    if let Err(error) = run_inner(config, block_hash).await {
+        log_callback.log(
+            LogLevel::Warn,
+            format!(
+                "offchain-worker-database-error; hash={}; error={}",
+                HashDisplay(&block_hash),
+                error
+            ),
+        );
+    }
+}
+
+/// Actual implementation of [`run`]. Returns an error if the database couldn't be accessed, in
+/// which case the off-chain worker is interrupted.
+async fn run_inner(config: Config, block_hash: [u8; 32]) -> Result<(), Error> {
+    let when_started = Instant::now();
+
+    // Version 1 of the API accepts the block number as parameter, while later versions accept
+    // the block header.
+    let parameter = match config
+        .runtime
+        .runtime_version()
+        .decode()
+        .apis
+        .find_version("OffchainWorkerApi")
+    {
+        None => return Ok(()),
+        Some(1) => {
+            let Ok(decoded_header) =
+                header::decode(&config.scale_encoded_header, config.block_number_bytes)
+                else { return Ok(()) };
+            decoded_header.number.to_le_bytes()[..config.block_number_bytes].to_vec()
+        }
+        Some(_) => config.scale_encoded_header.clone(),
+    };
+
+    // The runtime is compiled again with fuel metering, which is only supported when the code
+    // is interpreted. The original runtime is used to validate the transactions submitted by
+    // the off-chain worker.
+    let metered_runtime = {
+        let code = match config.code_substitute {
+            Some(code) => code,
+            None => match storage_get(&config.database, block_hash, None, b":code").await? {
+                Some((code, _)) => code,
+                None => return Err(Error::CorruptedDatabase),
+            },
+        };
+        host::HostVmPrototype::new(host::Config {
+            module: &code,
+            heap_pages: config.runtime.heap_pages(),
+            exec_hint: vm::ExecHint::Untrusted,
+            compilation_cache: None,
+            allow_unresolved_imports: true,
+        })
+    };
+    let metered_runtime = match metered_runtime {
+        Ok(runtime) => runtime,
+        Err(error) => {
+            config.log_callback.log(
+                LogLevel::Warn,
+                format!(
+                    "offchain-worker-compilation-error; hash={}; error={}",
+                    HashDisplay(&block_hash),
+                    error
+                ),
+            );
+            return Ok(());
+        }
+    };
+
+    let mut validation_runtime = Some(config.runtime);
+
+    let mut call = match runtime_host::run(runtime_host::Config {
+        virtual_machine: metered_runtime,
+        function_to_call: "OffchainWorkerApi_offchain_worker",
+        parameter: iter::once(parameter),
+        storage_main_trie_changes: Default::default(),
+        offchain_storage_changes: Default::default(),
+        max_log_level: 0,
+        trace: false,
+        fuel_budget: Some(FUEL_BUDGET),
+        max_pending_changes_size: None,
+    }) {
+        Ok(call) => call,
+        Err((error, _)) => {
+            config.log_callback.log(
+                LogLevel::Warn,
+                format!(
+                    "offchain-worker-start-error; hash={}; error={}",
+                    HashDisplay(&block_hash),
+                    error
+                ),
+            );
+            return Ok(());
+        }
+    };
+
+    let mut transactions_tx = config.transactions_tx;
+    let mut http_requests = http::Requests::new();
+
+    loop {
+        match call {
+            runtime_host::RuntimeHostVm::Finished(Ok(_)) => {
+                config.log_callback.log(
+                    LogLevel::Debug,
+                    format!(
+                        "offchain-worker-finished; hash={}; duration={:?}",
+                        HashDisplay(&block_hash),
+                        when_started.elapsed()
+                    ),
+                );
+                return Ok(());
+            }
+            runtime_host::RuntimeHostVm::Finished(Err(error)) => {
+                config.log_callback.log(
+                    LogLevel::Warn,
+                    format!(
+                        "offchain-worker-error; hash={}; error={}",
+                        HashDisplay(&block_hash),
+                        error.detail
+                    ),
+                );
+                return Ok(());
+            }
+
+            runtime_host::RuntimeHostVm::StorageGet(req) => {
+                let value = storage_get(
+                    &config.database,
+                    block_hash,
+                    req.child_trie().as_ref().map(|c| c.as_ref()),
+                    req.key().as_ref(),
+                )
+                .await?;
+                call = req.inject_value(
+                    value
+                        .as_ref()
+                        .map(|(value, version)| (iter::once(&value[..]), *version)),
+                );
+            }
+            runtime_host::RuntimeHostVm::ClosestDescendantMerkleValue(req) => {
+                let merkle_value = closest_descendant_merkle_value(
+                    &config.database,
+                    block_hash,
+                    req.child_trie().as_ref().map(|c| c.as_ref()),
+                    req.key().map(u8::from).collect(),
+                )
+                .await?;
+                call = req.inject_merkle_value(merkle_value.as_deref());
+            }
+            runtime_host::RuntimeHostVm::NextKey(req) => {
+                let next_key = next_key(
+                    &config.database,
+                    block_hash,
+                    req.child_trie().as_ref().map(|c| c.as_ref()),
+                    req.key()
+                        .map(u8::from)
+                        .chain(if req.or_equal() { None } else { Some(0u8) })
+                        .collect(),
+                    req.prefix().map(u8::from).collect(),
+                    req.branch_nodes(),
+                )
+                .await?;
+                call = req.inject_key(
+                    next_key.map(|k| k.into_iter().map(|b| trie::Nibble::try_from(b).unwrap())),
+                );
+            }
+            runtime_host::RuntimeHostVm::SignatureVerification(req) => {
+                call = req.verify_and_resume();
+            }
+
+            runtime_host::RuntimeHostVm::KeystorePublicKeys(req) => {
+                let public_keys = match keystore::KeyNamespace::from_key_type_id(req.key_type_id())
+                {
+                    // The keystore doesn't distinguish between Ed25519 and Sr25519 keys.
+                    Some(namespace) => match req.algorithm() {
+                        host::KeyAlgorithm::Ed25519 | host::KeyAlgorithm::Sr25519 => config
+                            .keystore
+                            .keys()
+                            .await
+                            .filter(|(n, _)| *n == namespace)
+                            .map(|(_, key)| key.to_vec())
+                            .collect::<Vec<_>>(),
+                        host::KeyAlgorithm::Ecdsa => config
+                            .keystore
+                            .ecdsa_keys()
+                            .await
+                            .filter(|(n, _)| *n == namespace)
+                            .map(|(_, key)| key.to_vec())
+                            .collect::<Vec<_>>(),
+                    },
+                    None => Vec::new(),
+                };
+
+                call = req.resume(public_keys.iter());
+            }
+            runtime_host::RuntimeHostVm::KeystoreGenerate(req) => {
+                let public_key = match (
+                    keystore::KeyNamespace::from_key_type_id(req.key_type_id()),
+                    req.seed(),
+                ) {
+                    // Deriving keys from a secret URI isn't supported.
+                    (Some(_), Some(_)) | (None, _) => None,
+                    (Some(namespace), None) => match req.algorithm() {
+                        host::KeyAlgorithm::Ed25519 => config
+                            .keystore
+                            .generate_ed25519(namespace, true)
+                            .await
+                            .ok()
+                            .map(|key| key.to_vec()),
+                        host::KeyAlgorithm::Sr25519 => config
+                            .keystore
+                            .generate_sr25519(namespace, true)
+                            .await
+                            .ok()
+                            .map(|key| key.to_vec()),
+                        host::KeyAlgorithm::Ecdsa => config
+                            .keystore
+                            .generate_ecdsa(namespace, true)
+                            .await
+                            .ok()
+                            .map(|key| key.to_vec()),
+                    },
+                };
+
+                call = req.resume(public_key.as_deref());
+            }
+            runtime_host::RuntimeHostVm::SignRequest(req) => {
+                let signature = match keystore::KeyNamespace::from_key_type_id(req.key_type_id()) {
+                    Some(namespace) => match req.algorithm() {
+                        host::KeyAlgorithm::Ed25519 | host::KeyAlgorithm::Sr25519 => {
+                            match <[u8; 32]>::try_from(req.public_key().as_ref()) {
+                                Ok(public_key) => config
+                                    .keystore
+                                    .sign(namespace, &public_key, req.message().as_ref())
+                                    .await
+                                    .ok()
+                                    .map(|signature| signature.to_vec()),
+                                Err(_) => None,
+                            }
+                        }
+                        host::KeyAlgorithm::Ecdsa => {
+                            match (
+                                <[u8; 33]>::try_from(req.public_key().as_ref()),
+                                <[u8; 32]>::try_from(req.message().as_ref()),
+                            ) {
+                                (Ok(public_key), Ok(message_hash)) => config
+                                    .keystore
+                                    .sign_ecdsa_prehashed(namespace, &public_key, &message_hash)
+                                    .await
+                                    .ok()
+                                    .map(|signature| signature.to_vec()),
+                                _ => None,
+                            }
+                        }
+                    },
+                    None => None,
+                };
+
+                call = req.resume(signature.as_deref());
+            }
+
+            runtime_host::RuntimeHostVm::Offchain(ctx) => match ctx {
+                runtime_host::OffchainContext::StorageGet(req) => {
+                    let value = match req.kind() {
+                        host::OffchainStorageKind::Persistent => {
+                            let key = req.key().as_ref().to_vec();
+                            config
+                                .database
+                                .with_database(move |db| db.offchain_storage_get(&key))
+                                .await
+                                .map_err(database_backend::StorageAccessError::from)?
+                        }
+                        // Similar to Substrate, the local storage isn't supported and is
+                        // always empty.
+                        host::OffchainStorageKind::Local => None,
+                    };
+
+                    call = req.inject_value(value.as_deref());
+                }
+                runtime_host::OffchainContext::StorageSet(req) => {
+                    let replaced = match req.kind() {
+                        host::OffchainStorageKind::Persistent => {
+                            let key = req.key().as_ref().to_vec();
+                            let old_value = req.old_value().map(|v| v.map(|v| v.to_vec()));
+                            let new_value = req.value().map(|v| v.as_ref().to_vec());
+                            config
+                                .database
+                                .with_database(move |db| {
+                                    db.offchain_storage_compare_and_set(
+                                        &key,
+                                        old_value.as_ref().map(|v| v.as_deref()),
+                                        new_value.as_deref(),
+                                    )
+                                })
+                                .await
+                                .map_err(database_backend::StorageAccessError::from)?
+                        }
+                        host::OffchainStorageKind::Local => false,
+                    };
+
+                    call = req.resume(replaced);
+                }
+                runtime_host::OffchainContext::Timestamp(req) => {
+                    let now = SystemTime::now()
+                        .duration_since(UNIX_EPOCH)
+                        .unwrap_or(Duration::new(0, 0));
+                    call = req.inject_timestamp(u64::try_from(now.as_millis()).unwrap_or(u64::MAX));
+                }
+                runtime_host::OffchainContext::SleepUntil(req) => {
+                    let delay = (UNIX_EPOCH + Duration::from_millis(req.deadline()))
+                        .duration_since(SystemTime::now())
+                        .unwrap_or(Duration::new(0, 0));
+                    smol::Timer::after(delay).await;
+                    call = req.resume();
+                }
+                runtime_host::OffchainContext::RandomSeed(req) => {
+                    call = req.inject_random_seed(&rand::random());
+                }
+                runtime_host::OffchainContext::SubmitTransaction(req) => {
+                    let transaction = req.transaction().as_ref().to_vec();
+                    let (runtime, validity) = validate_transaction(
+                        &config.database,
+                        validation_runtime.take().unwrap(),
+                        &config.scale_encoded_header,
+                        config.block_number_bytes,
+                        &transaction,
+                    )
+                    .await?;
+                    validation_runtime = Some(runtime);
+
+                    let success = match validity {
+                        Ok(()) => {
+                            let peers = config
+                                .network_service
+                                .0
+                                .clone()
+                                .announce_transaction(config.network_service.1, &transaction)
+                                .await;
+                            config.log_callback.log(
+                                LogLevel::Debug,
+                                format!(
+                                    "offchain-worker-transaction-announced; hash={}; num_peers={}",
+                                    HashDisplay(&block_hash),
+                                    peers.len()
+                                ),
+                            );
+
+                            // Transactions are only queued for local inclusion if the node
+                            // might author blocks, as the queue would otherwise never be
+                            // emptied. They are dropped if the queue is full, rather than
+                            // waiting, in order to not stall the off-chain worker.
+                            if config.keystore.keys().await.next().is_some() {
+                                let _ = transactions_tx.try_send(transaction);
+                            }
+
+                            true
+                        }
+                        Err(error) => {
+                            config.log_callback.log(
+                                LogLevel::Debug,
+                                format!(
+                                    "offchain-worker-transaction-invalid; hash={}; error={}",
+                                    HashDisplay(&block_hash),
+                                    error
+                                ),
+                            );
+                            false
+                        }
+                    };
+
+                    call = req.resume(success);
+                }
+                runtime_host::OffchainContext::IsValidator(req) => {
+                    let is_validator = config.keystore.keys().await.next().is_some();
+                    call = req.resume(is_validator);
+                }
+                runtime_host::OffchainContext::NetworkState(req) => {
+                    // The external addresses of the node aren't known.
+                    call = req.resume(Some((
+                        config.local_peer_id.as_bytes(),
+                        iter::empty::<Vec<u8>>(),
+                    )));
+                }
+                runtime_host::OffchainContext::HttpRequestStart(req) => {
+                    let request_id = http_requests.start(req.method(), req.uri());
+                    if request_id.is_none() && req.uri().starts_with("https://") {
+                        config.log_callback.log(
+                            LogLevel::Warn,
+                            format!(
+                                "offchain-worker-https-unsupported; hash={}; uri={}",
+                                HashDisplay(&block_hash),
+                                req.uri()
+                            ),
+                        );
+                    }
+                    call = req.resume(request_id);
+                }
+                runtime_host::OffchainContext::HttpRequestAddHeader(req) => {
+                    let success =
+                        http_requests.add_header(req.request_id(), req.name(), req.value());
+                    call = req.resume(success);
+                }
+                runtime_host::OffchainContext::HttpRequestWriteBody(req) => {
+                    let result = http_requests.write_body(req.request_id(), req.chunk().as_ref());
+                    call = req.resume(result);
+                }
+                runtime_host::OffchainContext::HttpResponseWait(req) => {
+                    let statuses = http_requests.wait(req.request_ids(), req.deadline()).await;
+                    call = req.resume(&statuses);
+                }
+                runtime_host::OffchainContext::HttpResponseHeaders(req) => {
+                    let headers = http_requests
+                        .response_headers(req.request_id())
+                        .iter()
+                        .map(|(name, value)| (name.clone(), value.clone()))
+                        .collect::<Vec<_>>();
+                    call = req.resume(headers.into_iter());
+                }
+                runtime_host::OffchainContext::HttpResponseReadBody(req) => {
+                    let result = http_requests
+                        .read_body(req.request_id(), req.max_size(), req.deadline())
+                        .await;
+                    call = req.resume(result.as_deref().map_err(|err| *err));
+                }
+            },
+        }
+    }
+}
+
+/// Validates the given transaction against the given block, using `runtime`, which must be the
+/// runtime of this block.
+///
+/// Returns the runtime back, alongside with an error message if the transaction is invalid or
+/// couldn't be validated.
+async fn validate_transaction(
+    database: &database_thread::DatabaseThread,
+    runtime: host::HostVmPrototype,
+    scale_encoded_header: &[u8],
+    block_number_bytes: usize,
+    transaction: &[u8],
+) -> Result<(host::HostVmPrototype, Result<(), String>), Error> {
+    let block_hash = header::hash_from_scale_encoded_header(scale_encoded_header);
+
+    let mut validation = validate::validate_transaction(validate::Config {
+        runtime,
+        scale_encoded_header,
+        block_number_bytes,
+        scale_encoded_transaction: iter::once(transaction),
+        source: validate::TransactionSource::Local,
+        max_log_level: 0,
+    });
+
+    loop {
+        match validation {
+            validate::Query::Finished {
+                result: Ok(Ok(_)),
+                virtual_machine,
+            } => return Ok((virtual_machine, Ok(()))),
+            validate::Query::Finished {
+                result: Ok(Err(error)),
+                virtual_machine,
+            } => return Ok((virtual_machine, Err(error.to_string()))),
+            validate::Query::Finished {
+                result: Err(error),
+                virtual_machine,
+            } => return Ok((virtual_machine, Err(error.to_string()))),
+            validate::Query::StorageGet(req) => {
+                let value = storage_get(
+                    database,
+                    block_hash,
+                    req.child_trie().as_ref().map(|c| c.as_ref()),
+                    req.key().as_ref(),
+                )
+                .await?;
+                validation = req.inject_value(
+                    value
+                        .as_ref()
+                        .map(|(value, version)| (iter::once(&value[..]), *version)),
+                );
+            }
+            validate::Query::ClosestDescendantMerkleValue(req) => {
+                let merkle_value = closest_descendant_merkle_value(
+                    database,
+                    block_hash,
+                    req.child_trie().as_ref().map(|c| c.as_ref()),
+                    req.key().map(u8::from).collect(),
+                )
+                .await?;
+                validation = req.inject_merkle_value(merkle_value.as_deref());
+            }
+            validate::Query::NextKey(req) => {
+                let next_key = next_key(
+                    database,
+                    block_hash,
+                    req.child_trie().as_ref().map(|c| c.as_ref()),
+                    req.key()
+                        .map(u8::from)
+                        .chain(if req.or_equal() { None } else { Some(0u8) })
+                        .collect(),
+                    req.prefix().map(u8::from).collect(),
+                    req.branch_nodes(),
+                )
+                .await?;
+                validation = req.inject_key(
+                    next_key.map(|k| k.into_iter().map(|b| trie::Nibble::try_from(b).unwrap())),
+                );
+            }
+        }
+    }
+}
+
+/// Returns the value of the given key in the storage of the given block, and its version.
+async fn storage_get(
+    database: &database_thread::DatabaseThread,
+    block_hash: [u8; 32],
+    child_trie: Option<&[u8]>,
+    key: &[u8],
+) -> Result<Option<(Vec<u8>, runtime_host::TrieEntryVersion)>, Error> {
+    let child_trie = child_trie.map(|c| c.to_vec());
+    let key = key.to_vec();
+
+    let Some((value, version)) = database
+        .with_database(move |db| {
+            database_backend::storage_get(db, &block_hash, child_trie.as_deref(), &key)
+        })
+        .await?
+        else { return Ok(None) };
+
+    let version =
+        runtime_host::TrieEntryVersion::try_from(version).map_err(|_| Error::CorruptedDatabase)?;
+    Ok(Some((value, version)))
+}
+
+/// Returns the Merkle value of the closest descendant of the given key in the storage of the
+/// given block.
+async fn closest_descendant_merkle_value(
+    database: &database_thread::DatabaseThread,
+    block_hash: [u8; 32],
+    child_trie: Option<&[u8]>,
+    key_nibbles: Vec<u8>,
+) -> Result<Option<Vec<u8>>, Error> {
+    let parent_paths = database_backend::child_trie_parent_paths(child_trie);
+    Ok(database
+        .with_database(move |db| {
+            db.block_storage_closest_descendant_merkle_value(
+                &block_hash,
+                &parent_paths,
+                &key_nibbles,
+            )
+        })
+        .await?)
+}
+
+/// Returns the key in the storage of the given block that immediately follows or is equal to
+/// `key_nibbles` and starts with `prefix_nibbles`.
+async fn next_key(
+    database: &database_thread::DatabaseThread,
+    block_hash: [u8; 32],
+    child_trie: Option<&[u8]>,
+    key_nibbles: Vec<u8>,
+    prefix_nibbles: Vec<u8>,
+    branch_nodes: bool,
+) -> Result<Option<Vec<u8>>, Error> {
+    let parent_paths = database_backend::child_trie_parent_paths(child_trie);
+    Ok(database
+        .with_database(move |db| {
+            db.block_storage_next_key(
+                &block_hash,
+                &parent_paths,
+                &key_nibbles,
+                &prefix_nibbles,
+                branch_nodes,
+            )
+        })
+        .await?)
+}
+
+/// Error that can happen while running an off-chain worker.
+#[derive(Debug, derive_more::Display, derive_more::From)]
+enum Error {
+    /// Error while accessing the database.
+    #[display(fmt = "{_0}")]
+    Database(database_backend::StorageAccessError),
+    /// The database contains an invalid trie entry version.
+    #[display(fmt = "Corrupted database")]
+    CorruptedDatabase,
+}
+
+/// Returns `true` if the given runtime supports off-chain workers.
+pub fn is_supported(runtime: &executor::host::HostVmPrototype) -> bool {
+    runtime
+        .runtime_version()
+        .decode()
+        .apis
+        .find_version("OffchainWorkerApi")
+        .is_some()
+}
@@ -0,0 +1,462 @@
+// Smoldot
+// Copyright (C) 2023  Pierre Krieger
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Minimal HTTP/1.1 client used to perform the HTTP requests of off-chain workers.
+//!
+//! Only plain-text `http://` URLs are supported. TLS isn't implemented, and attempting to start a
+//! request towards an `https://` URL fails. Each request opens a new TCP connection, and the
+//! response is entirely buffered in memory before being handed to the runtime.
+//!
+//! Requests are dispatched once the runtime has finished writing their body or starts waiting
+//! for their response, and only make progress while the runtime is waiting for a response or
+//! reading a response body.
+//!
+//! Requests fail if they take longer than [`REQUEST_TIMEOUT`] to complete, and the runtime never
+//! waits for longer than [`MAX_WAIT_DURATION`], even if it doesn't pass any deadline.
+
+use futures_util::future;
+use smol::io::{AsyncReadExt as _, AsyncWriteExt as _};
+use smoldot::executor::host::{HttpError, HttpRequestStatus};
+use std::{
+    future::Future,
+    pin::Pin,
+    str,
+    task::Poll,
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
+
+mod tests;
+
+/// Maximum size, in bytes, of a response, headers included. Responses that are larger than this
+/// are considered as failed.
+const MAX_RESPONSE_SIZE: u64 = 16 * 1024 * 1024;
+
+/// Maximum duration of the establishment of the TCP connection of a request.
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Maximum duration between the moment a request is dispatched and the moment its response has
+/// been entirely received. Requests that take longer than this are considered as failed.
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// Maximum duration the runtime waits for responses when it doesn't pass any deadline.
+const MAX_WAIT_DURATION: Duration = Duration::from_secs(60);
+
+/// Collection of HTTP requests started by an off-chain worker.
+pub struct Requests {
+    /// Identifier to assign to the next request.
+    next_request_id: u16,
+
+    /// List of all the requests that haven't been entirely read by the runtime yet.
+    requests: hashbrown::HashMap<u16, Request, fnv::FnvBuildHasher>,
+}
+
+enum Request {
+    /// Request hasn't been dispatched yet, as the runtime might still add headers or write to
+    /// the body.
+    Building {
+        method: String,
+        host: String,
+        port: u16,
+        path: String,
+        headers: Vec<(String, String)>,
+        body: Vec<u8>,
+    },
+    /// Request has been dispatched, and its response isn't fully received yet.
+    InProgress(Pin<Box<dyn Future<Output = Option<Response>> + Send>>),
+    /// Response has been received.
+    Finished(Response),
+    /// Error while sending the request or receiving the response.
+    Failed,
+}
+
+struct Response {
+    status_code: u16,
+    headers: Vec<(String, String)>,
+    body: Vec<u8>,
+    /// Number of bytes of [`Response::body`] that have already been read by the runtime.
+    body_read: usize,
+}
+
+impl Requests {
+    /// Creates a new empty collection of requests.
+    pub fn new() -> Self {
+        Requests {
+            next_request_id: 0,
+            requests: hashbrown::HashMap::with_capacity_and_hasher(0, Default::default()),
+        }
+    }
+
+    /// Starts building a new request. Returns `None` if the method or URI is invalid or not
+    /// supported, or if too many requests are in progress.
+    pub fn start(&mut self, method: &str, uri: &str) -> Option<u16> {
+        if method.is_empty() || !method.bytes().all(|b| b.is_ascii_alphabetic()) {
+            return None;
+        }
+
+        let (host, port, path) = parse_uri(uri)?;
+
+        // Find a request ID that isn't in use. Since request IDs are 16 bits, this loop is
+        // guaranteed to end quickly unless the runtime starts a huge number of requests.
+        if self.requests.len() >= usize::from(u16::MAX) {
+            return None;
+        }
+        let request_id = loop {
+            let id = self.next_request_id;
+            self.next_request_id = self.next_request_id.wrapping_add(1);
+            if !self.requests.contains_key(&id) {
+                break id;
+            }
+        };
+
+        self.requests.insert(
+            request_id,
+            Request::Building {
+                method: method.to_owned(),
+                host: host.to_owned(),
+                port,
+                path: path.to_owned(),
+                headers: Vec::new(),
+                body: Vec::new(),
+            },
+        );
+
+        Some(request_id)
+    }
+
+    /// Adds a header to a request that hasn't been dispatched yet. Returns `false` if the
+    /// request is unknown, has already been dispatched, or if the header is invalid.
+    pub fn add_header(&mut self, request_id: u16, name: &str, value: &str) -> bool {
+        let Some(Request::Building { headers, .. }) = self.requests.get_mut(&request_id)
+            else { return false };
+
+        if name.is_empty()
+            || !name.bytes().all(|b| b.is_ascii_graphic() && b != b':')
+            || value.bytes().any(|b| b == b'\r' || b == b'\n')
+        {
+            return false;
+        }
+
+        headers.push((name.to_owned(), value.to_owned()));
+        true
+    }
+
+    /// Appends a chunk to the body of a request that hasn't been dispatched yet.
+    ///
+    /// An empty chunk indicates the end of the body, in which case the request is dispatched.
+    pub fn write_body(&mut self, request_id: u16, chunk: &[u8]) -> Result<(), HttpError> {
+        let Some(Request::Building { body, .. }) = self.requests.get_mut(&request_id)
+            else { return Err(HttpError::Invalid) };
+
+        if chunk.is_empty() {
+            self.dispatch(request_id);
+        } else {
+            body.extend_from_slice(chunk);
+        }
+
+        Ok(())
+    }
+
+    /// Dispatches all the given requests that haven't been dispatched yet, then waits for
+    /// their responses until the given deadline, in milliseconds since the UNIX epoch.
+    pub async fn wait(
+        &mut self,
+        request_ids: &[u16],
+        deadline: Option<u64>,
+    ) -> Vec<HttpRequestStatus> {
+        self.drive(request_ids, deadline).await;
+
+        request_ids
+            .iter()
+            .map(|request_id| match self.requests.get(request_id) {
+                Some(Request::Finished(response)) => {
+                    HttpRequestStatus::Finished(response.status_code)
+                }
+                Some(Request::Failed) => HttpRequestStatus::IoError,
+                Some(Request::InProgress(_)) => HttpRequestStatus::DeadlineReached,
+                Some(Request::Building { .. }) => unreachable!(),
+                None => HttpRequestStatus::Invalid,
+            })
+            .collect()
+    }
+
+    /// Returns the headers of the response of the given request. Empty if the response hasn't
+    /// been received yet or if the request is unknown.
+    pub fn response_headers(&self, request_id: u16) -> &[(String, String)] {
+        match self.requests.get(&request_id) {
+            Some(Request::Finished(response)) => &response.headers,
+            _ => &[],
+        }
+    }
+
+    /// Reads up to `max_size` bytes from the body of the response of the given request, waiting
+    /// until the given deadline if the response hasn't been received yet.
+    ///
+    /// Returns an empty chunk once the entire body has been read, at which point the request is
+    /// removed from the collection.
+    pub async fn read_body(
+        &mut self,
+        request_id: u16,
+        max_size: usize,
+        deadline: Option<u64>,
+    ) -> Result<Vec<u8>, HttpError> {
+        self.drive(&[request_id], deadline).await;
+
+        match self.requests.get_mut(&request_id) {
+            Some(Request::Finished(response)) => {
+                let chunk_end = response
+                    .body
+                    .len()
+                    .min(response.body_read.saturating_add(max_size));
+                let chunk = response.body[response.body_read..chunk_end].to_vec();
+                response.body_read = chunk_end;
+                if chunk.is_empty() {
+                    self.requests.remove(&request_id);
+                }
+                Ok(chunk)
+            }
+            Some(Request::Failed) => {
+                self.requests.remove(&request_id);
+                Err(HttpError::IoError)
+            }
+            Some(Request::InProgress(_)) => Err(HttpError::DeadlineReached),
+            Some(Request::Building { .. }) => unreachable!(),
+            None => Err(HttpError::Invalid),
+        }
+    }
+
+    /// Dispatches the given requests if necessary, then drives them until they have all
+    /// finished or the deadline is reached.
+    async fn drive(&mut self, request_ids: &[u16], deadline: Option<u64>) {
+        for request_id in request_ids {
+            self.dispatch(*request_id);
+        }
+
+        // Requests in progress are temporarily extracted from the list in order to be polled.
+        let mut in_progress = Vec::with_capacity(request_ids.len());
+        for request_id in request_ids {
+            if let Some(Request::InProgress(_)) = self.requests.get(request_id) {
+                let Some(Request::InProgress(future)) = self.requests.remove(request_id)
+                    else { unreachable!() };
+                in_progress.push((*request_id, future));
+            }
+        }
+
+        {
+            let requests = &mut self.requests;
+            let all_finished = future::poll_fn(|cx| {
+                let mut index = 0;
+                while index < in_progress.len() {
+                    if let Poll::Ready(response) = in_progress[index].1.as_mut().poll(cx) {
+                        let (request_id, _) = in_progress.swap_remove(index);
+                        requests.insert(
+                            request_id,
+                            response.map_or(Request::Failed, Request::Finished),
+                        );
+                    } else {
+                        index += 1;
+                    }
+                }
+
+                if in_progress.is_empty() {
+                    Poll::Ready(())
+                } else {
+                    Poll::Pending
+                }
+            });
+
+            let wait_duration = match deadline {
+                Some(deadline) => (UNIX_EPOCH + Duration::from_millis(deadline))
+                    .duration_since(SystemTime::now())
+                    .unwrap_or(Duration::new(0, 0))
+                    .min(MAX_WAIT_DURATION),
+                None => MAX_WAIT_DURATION,
+            };
+            smol::future::or(all_finished, async move {
+                smol::Timer::at(Instant::now() + wait_duration).await;
+            })
+            .await
+        }
+
+        for (request_id, future) in in_progress {
+            self.requests
+                .insert(request_id, Request::InProgress(future));
+        }
+    }
+
+    /// Dispatches the given request if it hasn't been dispatched yet.
+    fn dispatch(&mut self, request_id: u16) {
+        let Some(request) = self.requests.get_mut(&request_id) else { return };
+        if !matches!(request, Request::Building { .. }) {
+            return;
+        }
+        let Request::Building { method, host, port, path, headers, body } =
+            std::mem::replace(request, Request::Failed)
+            else { unreachable!() };
+
+        // The headers that are necessary for the connection to behave properly are always set
+        // by the client, and the ones provided by the runtime are ignored.
+        let mut request_bytes = format!(
+            "{method} {path} HTTP/1.1\r\nHost: {}\r\nContent-Length: {}\r\nConnection: close\r\n",
+            if port == 80 {
+                host.clone()
+            } else {
+                format!("{host}:{port}")
+            },
+            body.len()
+        );
+        for (name, value) in headers {
+            if ["host", "content-length", "connection", "transfer-encoding"]
+                .iter()
+                .any(|h| name.eq_ignore_ascii_case(h))
+            {
+                continue;
+            }
+            request_bytes.push_str(&format!("{name}: {value}\r\n"));
+        }
+        request_bytes.push_str("\r\n");
+        let mut request_bytes = request_bytes.into_bytes();
+        request_bytes.extend_from_slice(&body);
+
+        let timeout = Instant::now() + REQUEST_TIMEOUT;
+        let response = async move {
+            let host = host.trim_start_matches('[').trim_end_matches(']');
+            let mut stream = smol::future::or(
+                async { smol::net::TcpStream::connect((host, port)).await.ok() },
+                async {
+                    smol::Timer::after(CONNECT_TIMEOUT).await;
+                    None
+                },
+            )
+            .await?;
+            stream.write_all(&request_bytes).await.ok()?;
+
+            // Because of the `Connection: close` header, the server closes the connection after
+            // the response has been sent.
+            let mut response = Vec::new();
+            (&mut stream)
+                .take(MAX_RESPONSE_SIZE + 1)
+                .read_to_end(&mut response)
+                .await
+                .ok()?;
+            if u64::try_from(response.len()).unwrap_or(u64::MAX) > MAX_RESPONSE_SIZE {
+                return None;
+            }
+
+            parse_response(&response)
+        };
+
+        *request = Request::InProgress(Box::pin(smol::future::or(response, async move {
+            smol::Timer::at(timeout).await;
+            None
+        })));
+    }
+}
+
+/// Parses an `http://` URI into a host, port, and path.
+///
+/// Returns `None` for `https://` URIs, as TLS isn't supported.
+fn parse_uri(uri: &str) -> Option<(&str, u16, &str)> {
+    let uri = uri.strip_prefix("http://")?;
+    let (authority, path) = match uri.find(['/', '?']) {
+        Some(pos) if uri[pos..].starts_with('/') => (&uri[..pos], &uri[pos..]),
+        Some(_) => return None,
+        None => (uri, "/"),
+    };
+
+    if path.bytes().any(|b| !b.is_ascii_graphic()) {
+        return None;
+    }
+
+    let (host, port) = match authority.rsplit_once(':') {
+        Some((host, port)) if !authority.ends_with(']') => (host, port.parse().ok()?),
+        _ => (authority, 80),
+    };
+
+    if host.is_empty() || host.contains('@') {
+        return None;
+    }
+
+    Some((host, port, path))
+}
+
+/// Parses a full HTTP/1.1 response. Returns `None` if the response is malformed.
+fn parse_response(response: &[u8]) -> Option<Response> {
+    let head_end = find_subslice(response, b"\r\n\r\n")?;
+    let head = str::from_utf8(&response[..head_end]).ok()?;
+    let body = &response[head_end + 4..];
+
+    let mut lines = head.split("\r\n");
+    let status_line = lines.next()?;
+    if !status_line.starts_with("HTTP/1.") {
+        return None;
+    }
+    let status_code = status_line.split(' ').nth(1)?.parse::<u16>().ok()?;
+
+    let headers = lines
+        .map(|line| {
+            let (name, value) = line.split_once(':')?;
+            Some((name.trim().to_owned(), value.trim().to_owned()))
+        })
+        .collect::<Option<Vec<_>>>()?;
+
+    let header_value = |name: &str| {
+        headers
+            .iter()
+            .find(|(n, _)| n.eq_ignore_ascii_case(name))
+            .map(|(_, v)| v.as_str())
+    };
+
+    let body = if matches!(header_value("transfer-encoding"), Some(v) if v.to_ascii_lowercase().contains("chunked"))
+    {
+        decode_chunked(body)?
+    } else if let Some(content_length) = header_value("content-length") {
+        body.get(..content_length.parse::<usize>().ok()?)?.to_vec()
+    } else {
+        body.to_vec()
+    };
+
+    Some(Response {
+        status_code,
+        headers,
+        body,
+        body_read: 0,
+    })
+}
+
+/// Decodes a body that uses the `chunked` transfer encoding.
+fn decode_chunked(mut data: &[u8]) -> Option<Vec<u8>> {
+    let mut out = Vec::with_capacity(data.len());
+
+    loop {
+        let line_end = find_subslice(data, b"\r\n")?;
+        let size = str::from_utf8(&data[..line_end]).ok()?;
+        let size = usize::from_str_radix(size.split(';').next()?.trim(), 16).ok()?;
+        data = &data[line_end + 2..];
+
+        if size == 0 {
+            return Some(out);
+        }
+
+        out.extend_from_slice(data.get(..size)?);
+        data = data.get(size..)?.strip_prefix(b"\r\n")?;
+    }
+}
+
+/// Returns the position of the first occurrence of `pattern` within `data`.
+fn find_subslice(data: &[u8], pattern: &[u8]) -> Option<usize> {
+    data.windows(pattern.len()).position(|w| w == pattern)
+}
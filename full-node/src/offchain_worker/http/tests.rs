@@ -0,0 +1,143 @@
+// Smoldot
+// Copyright (C) 2023  Pierre Krieger
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <http://www.gnu.org/licenses/>.
+
+#![cfg(test)]
+
+use super::{decode_chunked, parse_response, parse_uri};
+
+#[test]
+fn uri_basic() {
+    assert_eq!(
+        parse_uri("http://example.com/foo?bar=baz"),
+        Some(("example.com", 80, "/foo?bar=baz"))
+    );
+    assert_eq!(
+        parse_uri("http://example.com"),
+        Some(("example.com", 80, "/"))
+    );
+}
+
+#[test]
+fn uri_with_port() {
+    assert_eq!(
+        parse_uri("http://127.0.0.1:9933/"),
+        Some(("127.0.0.1", 9933, "/"))
+    );
+    assert_eq!(
+        parse_uri("http://[::1]:8080/a"),
+        Some(("[::1]", 8080, "/a"))
+    );
+    assert_eq!(parse_uri("http://[::1]/a"), Some(("[::1]", 80, "/a")));
+    assert_eq!(parse_uri("http://example.com:notaport/"), None);
+}
+
+#[test]
+fn uri_unsupported() {
+    assert_eq!(parse_uri("https://example.com/"), None);
+    assert_eq!(parse_uri("ftp://example.com/"), None);
+    assert_eq!(parse_uri("http://user@example.com/"), None);
+    assert_eq!(parse_uri("http:///foo"), None);
+    assert_eq!(parse_uri("http://example.com?foo"), None);
+    assert_eq!(parse_uri("http://example.com/foo bar"), None);
+}
+
+#[test]
+fn response_content_length() {
+    let response = parse_response(
+        b"HTTP/1.1 200 OK\r\nContent-Type: text/plain\r\nContent-Length: 5\r\n\r\nhello world",
+    )
+    .unwrap();
+    assert_eq!(response.status_code, 200);
+    assert_eq!(
+        response.headers,
+        vec![
+            ("Content-Type".to_owned(), "text/plain".to_owned()),
+            ("Content-Length".to_owned(), "5".to_owned())
+        ]
+    );
+    assert_eq!(response.body, b"hello");
+}
+
+#[test]
+fn response_until_connection_close() {
+    let response = parse_response(b"HTTP/1.0 404 Not Found\r\n\r\nnot found").unwrap();
+    assert_eq!(response.status_code, 404);
+    assert!(response.headers.is_empty());
+    assert_eq!(response.body, b"not found");
+}
+
+#[test]
+fn response_chunked() {
+    let response = parse_response(
+        b"HTTP/1.1 200 OK\r\nTransfer-Encoding: chunked\r\n\r\n5\r\nhello\r\n6\r\n world\r\n0\r\n\r\n",
+    )
+    .unwrap();
+    assert_eq!(response.body, b"hello world");
+}
+
+#[test]
+fn response_malformed_status_line() {
+    assert!(parse_response(b"HTTP/2 200\r\n\r\n").is_none());
+    assert!(parse_response(b"HTTP/1.1\r\n\r\n").is_none());
+    assert!(parse_response(b"HTTP/1.1 abc OK\r\n\r\n").is_none());
+    assert!(parse_response(b"garbage\r\n\r\n").is_none());
+}
+
+#[test]
+fn response_malformed_headers() {
+    assert!(parse_response(b"HTTP/1.1 200 OK\r\nno colon\r\n\r\n").is_none());
+    assert!(parse_response(b"HTTP/1.1 200 OK\r\nContent-Length: abc\r\n\r\n").is_none());
+}
+
+#[test]
+fn response_truncated() {
+    // Head isn't finished.
+    assert!(parse_response(b"HTTP/1.1 200 OK\r\nContent-Length: 5\r\n").is_none());
+    // Body is shorter than announced.
+    assert!(parse_response(b"HTTP/1.1 200 OK\r\nContent-Length: 10\r\n\r\nhello").is_none());
+    // Last chunk is missing.
+    assert!(
+        parse_response(b"HTTP/1.1 200 OK\r\nTransfer-Encoding: chunked\r\n\r\n5\r\nhello\r\n")
+            .is_none()
+    );
+}
+
+#[test]
+fn chunked_with_extensions_and_trailers() {
+    assert_eq!(
+        decode_chunked(b"3;foo=bar\r\nabc\r\nA\r\n0123456789\r\n0\r\nTrailer: value\r\n\r\n")
+            .unwrap(),
+        b"abc0123456789"
+    );
+}
+
+#[test]
+fn chunked_empty() {
+    assert_eq!(decode_chunked(b"0\r\n\r\n").unwrap(), b"");
+}
+
+#[test]
+fn chunked_invalid() {
+    // Size isn't hexadecimal.
+    assert!(decode_chunked(b"xyz\r\nabc\r\n0\r\n\r\n").is_none());
+    // Chunk is shorter than its announced size.
+    assert!(decode_chunked(b"5\r\nabc").is_none());
+    // Chunk isn't followed with a line break.
+    assert!(decode_chunked(b"3\r\nabcdef\r\n0\r\n\r\n").is_none());
+    // Size line isn't terminated.
+    assert!(decode_chunked(b"3").is_none());
+}
@@ -0,0 +1,72 @@
+// Smoldot
+// Copyright (C) 2023  Pierre Krieger
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <http://www.gnu.org/licenses/>.
+
+//! On-disk cache of compiled runtimes.
+//!
+//! Compiling a runtime can take several seconds. In order to not have to do this at every
+//! restart of the node, the compiled runtimes are stored in a directory, one file per runtime.
+//! The name of each file is the hexadecimal encoding of the key provided by the executor.
+//!
+//! Files are never removed from this directory. Since each runtime upgrade of a chain only
+//! adds a handful of megabytes, this isn't considered a problem.
+
+use smoldot::executor::vm;
+use std::{fs, io, path::PathBuf};
+
+/// See [the module-level documentation](..).
+pub struct RuntimeCache {
+    /// Directory where the compiled runtimes are stored.
+    directory: PathBuf,
+}
+
+impl RuntimeCache {
+    /// Opens the cache at the given directory, creating the directory if necessary.
+    pub fn open(directory: PathBuf) -> Result<Self, io::Error> {
+        fs::create_dir_all(&directory)?;
+        Ok(RuntimeCache { directory })
+    }
+}
+
+// SAFETY: files are written in their entirety to a temporary location then atomically renamed
+// to their final name. As such, a file found under the name of a key always contains data that
+// was passed to `store` with that key. Third parties modifying the content of the directory is
+// considered out of scope, in the same way as for example modifying the database.
+unsafe impl vm::CompilationCache for RuntimeCache {
+    fn load(&self, key: &[u8; 32]) -> Option<Vec<u8>> {
+        fs::read(self.directory.join(hex::encode(key))).ok()
+    }
+
+    fn store(&self, key: &[u8; 32], compiled: &[u8]) {
+        // Multiple nodes might be sharing the same directory and compiling the same runtime
+        // at the same time. A random suffix is added to the name of the temporary file in order
+        // to not have them overwrite each other's temporary file.
+        let tmp_path = self.directory.join(format!(
+            "{}.{}.tmp",
+            hex::encode(key),
+            hex::encode(rand::random::<[u8; 8]>())
+        ));
+
+        if fs::write(&tmp_path, compiled).is_err() {
+            let _ = fs::remove_file(&tmp_path);
+            return;
+        }
+
+        if fs::rename(&tmp_path, self.directory.join(hex::encode(key))).is_err() {
+            let _ = fs::remove_file(&tmp_path);
+        }
+    }
+}
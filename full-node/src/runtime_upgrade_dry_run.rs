@@ -0,0 +1,801 @@
+// Smoldot
+// Copyright (C) 2023  Pierre Krieger
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Rehearsal of a runtime upgrade against the storage found in the database.
+//!
+//! The storage of a block of the database is overlaid with a candidate runtime at the `:code`
+//! key. Nothing is ever written to the database.
+//!
+//! The rehearsal consists in the following steps:
+//!
+//! - `Core_version` is called on the candidate runtime.
+//! - `TryRuntime_on_runtime_upgrade` is called on the candidate runtime, in order to run the
+//!   migrations of the runtime and, optionally, their pre- and post-upgrade checks. The
+//!   candidate runtime must have been compiled with the `try-runtime` feature for this function
+//!   to exist.
+//! - The extrinsics of the descendants of the block are applied one by one on top of the
+//!   upgraded storage, using `Core_initialize_block`, `BlockBuilder_apply_extrinsic`, and
+//!   `BlockBuilder_finalize_block`.
+//!
+//! The changes made by `TryRuntime_on_runtime_upgrade` are reported but discarded afterwards.
+//! On a live chain, the migrations run as part of the first block executed with the new
+//! runtime, and the execution of the descendants thus starts from the storage of the block
+//! where only `:code` has been modified. Each descendant is executed on top of the changes of
+//! the previous ones.
+//!
+//! The state root and extrinsics root found in the headers of the descendants are not verified,
+//! as they are expected to differ from the ones of the original chain.
+//!
+//! Only the changes to the main trie are carried from one runtime call to the next. A runtime
+//! call that modifies a child trie is reported as failing with
+//! [`RuntimeCallError::ChildTrieModified`], and no further block is executed.
+//!
+//! > **Note**: The weights found in the report are decoded assuming that the runtime is built
+//! >           using FRAME.
+
+use crate::database_backend;
+
+use smoldot::{
+    chain_spec,
+    database::full_sqlite,
+    executor::{self, host, runtime_host, storage_diff, vm},
+    header, trie, util,
+};
+use std::{borrow::Cow, iter, path::PathBuf};
+
+mod tests;
+
+/// Configuration for [`dry_run_runtime_upgrade`].
+pub struct RuntimeUpgradeDryRunConfig<'a> {
+    /// Specification of the chain.
+    pub chain_spec: Cow<'a, [u8]>,
+    /// Path to the SQLite database to read the blocks and the storage from.
+    pub sqlite_database_path: PathBuf,
+    /// Maximum size, in bytes, of the cache SQLite uses.
+    pub sqlite_cache_size: usize,
+    /// Wasm code of the candidate runtime.
+    pub runtime_code: Cow<'a, [u8]>,
+    /// Hash of the block whose storage the upgrade is performed on. `None` for the latest
+    /// finalized block of the database.
+    pub block_hash: Option<[u8; 32]>,
+    /// Maximum number of descendants of the block whose extrinsics are executed after the
+    /// upgrade. Fewer blocks are executed if the database doesn't contain enough of them.
+    pub num_blocks: u32,
+    /// If `true`, the runtime is asked to run the pre- and post-upgrade checks and the
+    /// `try_state` hooks alongside with the migrations.
+    pub try_runtime_checks: bool,
+    /// Maximum log level of the runtime. See [`runtime_host::Config::max_log_level`].
+    pub max_log_level: u32,
+}
+
+/// Outcome of a successful [`dry_run_runtime_upgrade`].
+#[derive(Debug)]
+pub struct RuntimeUpgradeDryRunReport {
+    /// Number of the block whose storage the upgrade has been performed on.
+    pub block_number: u64,
+    /// Hash of the block whose storage the upgrade has been performed on.
+    pub block_hash: [u8; 32],
+    /// Value returned by `Core_version` of the candidate runtime.
+    pub runtime_version: executor::CoreVersion,
+    /// Outcome of the call to `TryRuntime_on_runtime_upgrade`.
+    pub migration: MigrationOutcome,
+    /// Outcome of the execution of each descendant of the block, in increasing block number.
+    pub blocks: Vec<BlockOutcome>,
+}
+
+/// See [`RuntimeUpgradeDryRunReport::migration`].
+#[derive(Debug)]
+pub struct MigrationOutcome {
+    /// Weights returned by the runtime, or the reason why the migrations have failed.
+    pub result: Result<MigrationWeight, RuntimeCallError>,
+    /// Storage entries modified by the migrations. Empty if the migrations have failed.
+    pub storage_diff: Vec<StorageDiffEntry>,
+    /// Concatenation of all the log messages printed by the runtime.
+    pub logs: String,
+}
+
+/// See [`MigrationOutcome::result`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MigrationWeight {
+    /// Weight consumed by the migrations.
+    pub consumed: Weight,
+    /// Maximum weight of a block according to the runtime.
+    pub max_block: Weight,
+}
+
+/// Weight of an operation, as defined by FRAME.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Weight {
+    /// Computation time, in picoseconds.
+    pub ref_time: u64,
+    /// Size of the storage proof, in bytes. Always `0` for runtimes that predate proof size
+    /// weights.
+    pub proof_size: u64,
+}
+
+/// See [`RuntimeUpgradeDryRunReport::blocks`].
+#[derive(Debug)]
+pub struct BlockOutcome {
+    /// Number of the block.
+    pub number: u64,
+    /// Hash of the block.
+    pub hash: [u8; 32],
+    /// Outcome of applying each extrinsic of the block, in order. If [`BlockOutcome::error`] is
+    /// `Some`, only contains the extrinsics that have been applied before the error happened.
+    pub extrinsics: Vec<ExtrinsicOutcome>,
+    /// Weight consumed by the block, as found in the `System::BlockWeight` storage item at the
+    /// end of the block. `None` if the item couldn't be found or decoded.
+    pub weight: Option<BlockWeight>,
+    /// Storage entries modified by the block.
+    pub storage_diff: Vec<StorageDiffEntry>,
+    /// Error that has interrupted the execution of the block, if any. No further block is
+    /// executed after an error.
+    pub error: Option<RuntimeCallError>,
+    /// Concatenation of all the log messages printed by the runtime.
+    pub logs: String,
+}
+
+/// See [`BlockOutcome::weight`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BlockWeight {
+    /// Weight consumed by extrinsics of the normal dispatch class.
+    pub normal: Weight,
+    /// Weight consumed by extrinsics of the operational dispatch class.
+    pub operational: Weight,
+    /// Weight consumed by extrinsics of the mandatory dispatch class, such as inherents.
+    pub mandatory: Weight,
+}
+
+/// See [`BlockOutcome::extrinsics`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ExtrinsicOutcome {
+    /// The extrinsic has been applied and its dispatch has succeeded.
+    Success,
+    /// The extrinsic has been applied, but its dispatch has failed. Contains the SCALE-encoded
+    /// `DispatchError`.
+    DispatchError(Vec<u8>),
+    /// The extrinsic is invalid and hasn't been applied. Contains the SCALE-encoded
+    /// `TransactionValidityError`.
+    Invalid(Vec<u8>),
+}
+
+/// Storage entry of the main trie modified by a runtime call.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StorageDiffEntry {
+    /// Key of the storage entry.
+    pub key: Vec<u8>,
+    /// Value before the call, or `None` if there wasn't any value.
+    pub old_value: Option<Vec<u8>>,
+    /// Value after the call, or `None` if the entry has been removed.
+    pub new_value: Option<Vec<u8>>,
+}
+
+/// Error while calling a function of the candidate runtime.
+#[derive(Debug, Clone, derive_more::Display)]
+pub enum RuntimeCallError {
+    /// Failed to start the call, for example because the function doesn't exist.
+    #[display(fmt = "Failed to start the call: {_0}")]
+    Start(host::StartErr),
+    /// Error during the execution, for example because the runtime has panicked.
+    #[display(fmt = "{_0}")]
+    Execution(runtime_host::ErrorDetail),
+    /// The call has succeeded but its output couldn't be decoded.
+    #[display(fmt = "Failed to decode the output of the call")]
+    InvalidOutput,
+    /// The call has modified the content of a child trie, which isn't supported.
+    #[display(fmt = "The call has modified a child trie, which isn't supported")]
+    ChildTrieModified,
+}
+
+/// Overlays the candidate runtime on top of the storage of a block of the database, runs its
+/// migrations, and executes the descendants of the block on top of the upgraded storage.
+///
+/// See the documentation of the module for more information.
+pub fn dry_run_runtime_upgrade(
+    config: RuntimeUpgradeDryRunConfig,
+) -> Result<RuntimeUpgradeDryRunReport, RuntimeUpgradeDryRunError> {
+    let chain_spec = chain_spec::ChainSpec::from_json_bytes(&config.chain_spec)
+        .map_err(RuntimeUpgradeDryRunError::InvalidChainSpec)?;
+    let block_number_bytes = usize::from(chain_spec.block_number_bytes());
+
+    let database = match full_sqlite::open(full_sqlite::Config {
+        block_number_bytes,
+        genesis_block_hash: None,
+        extrinsics_index: false,
+        cache_size: config.sqlite_cache_size,
+        ty: full_sqlite::ConfigTy::Disk {
+            path: &config.sqlite_database_path,
+            memory_map_size: 1000000000, // TODO: make configurable
+        },
+    })
+    .map_err(RuntimeUpgradeDryRunError::DatabaseOpen)?
+    {
+        full_sqlite::DatabaseOpen::Open(database) => database,
+        full_sqlite::DatabaseOpen::Empty(_) => {
+            return Err(RuntimeUpgradeDryRunError::EmptyDatabase)
+        }
+    };
+
+    dry_run(
+        &database,
+        block_number_bytes,
+        &config.runtime_code,
+        config.block_hash,
+        config.num_blocks,
+        config.try_runtime_checks,
+        config.max_log_level,
+    )
+}
+
+/// Performs the rehearsal described in the documentation of the module against the given
+/// database. See [`RuntimeUpgradeDryRunConfig`] for the meaning of the parameters.
+fn dry_run(
+    database: &dyn database_backend::FullDatabase,
+    block_number_bytes: usize,
+    runtime_code: &[u8],
+    block_hash: Option<[u8; 32]>,
+    num_blocks: u32,
+    try_runtime_checks: bool,
+    max_log_level: u32,
+) -> Result<RuntimeUpgradeDryRunReport, RuntimeUpgradeDryRunError> {
+    let block_hash = match block_hash {
+        Some(hash) => hash,
+        None => database.finalized_block_hash()?,
+    };
+    let block_number = {
+        let scale_encoded_header = database
+            .block_scale_encoded_header(&block_hash)?
+            .ok_or(RuntimeUpgradeDryRunError::UnknownBlock)?;
+        header::decode(&scale_encoded_header, block_number_bytes)
+            .map_err(RuntimeUpgradeDryRunError::InvalidHeader)?
+            .number
+    };
+
+    // The candidate runtime uses the number of heap pages found in the storage of the block.
+    let mut runtime = {
+        let heap_pages = database_backend::storage_get(database, &block_hash, None, b":heappages")?;
+        let heap_pages =
+            executor::storage_heap_pages_to_value(heap_pages.as_ref().map(|(v, _)| &v[..]))
+                .map_err(RuntimeUpgradeDryRunError::InvalidHeapPages)?;
+        host::HostVmPrototype::new(host::Config {
+            module: runtime_code,
+            heap_pages,
+            exec_hint: vm::ExecHint::CompileAheadOfTime,
+            compilation_cache: None,
+            allow_unresolved_imports: true,
+        })
+        .map_err(RuntimeUpgradeDryRunError::InvalidRuntime)?
+    };
+
+    // Storage of the block, where only `:code` is modified.
+    let upgraded_storage = {
+        let mut diff = storage_diff::TrieDiff::empty();
+        diff.diff_insert(b":code".to_vec(), runtime_code.to_vec(), ());
+        diff
+    };
+
+    let runtime_version = {
+        let (call_result, prototype) = run_call(
+            database,
+            &block_hash,
+            runtime,
+            "Core_version",
+            &[],
+            upgraded_storage.clone(),
+            max_log_level,
+        )?;
+        runtime = prototype;
+        let success =
+            call_result.map_err(|failure| RuntimeUpgradeDryRunError::CoreVersion(failure.error))?;
+        executor::CoreVersion::from_slice(success.output)
+            .map_err(|_| RuntimeUpgradeDryRunError::CoreVersion(RuntimeCallError::InvalidOutput))?
+    };
+
+    let migration = {
+        // Depending on the version of Substrate, the parameter is either a `bool` or an
+        // `UpgradeCheckSelect`. In both cases, `0` disables the checks and `1` enables all
+        // of them.
+        let (call_result, prototype) = run_call(
+            database,
+            &block_hash,
+            runtime,
+            "TryRuntime_on_runtime_upgrade",
+            &[u8::from(try_runtime_checks)],
+            upgraded_storage.clone(),
+            max_log_level,
+        )?;
+        runtime = prototype;
+
+        match call_result {
+            Ok(success) => MigrationOutcome {
+                result: decode_weights::<2>(&success.output)
+                    .map(|[consumed, max_block]| MigrationWeight {
+                        consumed,
+                        max_block,
+                    })
+                    .ok_or(RuntimeCallError::InvalidOutput),
+                storage_diff: storage_diff_entries(
+                    database,
+                    &block_hash,
+                    &upgraded_storage,
+                    &success.storage_changes,
+                )?,
+                logs: success.logs,
+            },
+            Err(failure) => MigrationOutcome {
+                result: Err(failure.error),
+                storage_diff: Vec::new(),
+                logs: failure.logs,
+            },
+        }
+    };
+
+    let mut blocks = Vec::with_capacity(usize::try_from(num_blocks).unwrap_or(0));
+    let mut storage_changes = upgraded_storage;
+    let mut parent_hash = block_hash;
+
+    for child_number in block_number + 1..=block_number + u64::from(num_blocks) {
+        // Find the child of the previous block among the blocks with that number.
+        let mut child = None;
+        for hash in database.block_hash_by_number(child_number)? {
+            let Some(scale_encoded_header) = database.block_scale_encoded_header(&hash)? else {
+                continue;
+            };
+            let decoded = header::decode(&scale_encoded_header, block_number_bytes)
+                .map_err(RuntimeUpgradeDryRunError::InvalidHeader)?;
+            if *decoded.parent_hash == parent_hash {
+                child = Some((hash, scale_encoded_header));
+                break;
+            }
+        }
+        let Some((hash, scale_encoded_header)) = child else {
+            break;
+        };
+        let Some(body) = database.block_extrinsics(&hash)? else {
+            break;
+        };
+
+        let (outcome, prototype, new_storage_changes) = execute_block(
+            database,
+            &block_hash,
+            block_number_bytes,
+            runtime,
+            hash,
+            &scale_encoded_header,
+            &body,
+            storage_changes,
+            max_log_level,
+        )?;
+        runtime = prototype;
+        storage_changes = new_storage_changes;
+        parent_hash = hash;
+
+        let interrupted = outcome.error.is_some();
+        blocks.push(outcome);
+        if interrupted {
+            break;
+        }
+    }
+
+    Ok(RuntimeUpgradeDryRunReport {
+        block_number,
+        block_hash,
+        runtime_version,
+        migration,
+        blocks,
+    })
+}
+
+/// Error potentially returned by [`dry_run_runtime_upgrade`].
+#[derive(Debug, derive_more::Display)]
+pub enum RuntimeUpgradeDryRunError {
+    /// Failed to parse the chain specification.
+    #[display(fmt = "Failed to decode chain specification: {_0}")]
+    InvalidChainSpec(chain_spec::ParseError),
+    /// Failed to open the database.
+    #[display(fmt = "Failed to open database: {_0}")]
+    DatabaseOpen(full_sqlite::OpenError),
+    /// The database doesn't exist or is empty.
+    #[display(fmt = "Database is empty")]
+    EmptyDatabase,
+    /// Error while accessing the database.
+    #[display(fmt = "Error while accessing the database: {_0}")]
+    DatabaseAccess(database_backend::AccessError),
+    /// Error while accessing the storage of the block in the database.
+    #[display(fmt = "Error while accessing the storage of the block: {_0}")]
+    StorageAccess(database_backend::StorageAccessError),
+    /// The block couldn't be found in the database.
+    #[display(fmt = "Block not found in the database")]
+    UnknownBlock,
+    /// Failed to decode the header of a block.
+    #[display(fmt = "Failed to decode block header: {_0}")]
+    InvalidHeader(header::Error),
+    /// The value of `:heappages` in the storage of the block is invalid.
+    #[display(fmt = "Invalid heap pages in the storage of the block: {_0}")]
+    InvalidHeapPages(executor::InvalidHeapPagesError),
+    /// Failed to compile the candidate runtime.
+    #[display(fmt = "Failed to compile the candidate runtime: {_0}")]
+    InvalidRuntime(host::NewErr),
+    /// Failed to obtain the version of the candidate runtime.
+    #[display(fmt = "Failed to call `Core_version` on the candidate runtime: {_0}")]
+    CoreVersion(RuntimeCallError),
+    /// The content of the database is invalid.
+    #[display(fmt = "Database is corrupted")]
+    CorruptedDatabase,
+}
+
+impl From<database_backend::AccessError> for RuntimeUpgradeDryRunError {
+    fn from(err: database_backend::AccessError) -> Self {
+        RuntimeUpgradeDryRunError::DatabaseAccess(err)
+    }
+}
+
+impl From<database_backend::StorageAccessError> for RuntimeUpgradeDryRunError {
+    fn from(err: database_backend::StorageAccessError) -> Self {
+        RuntimeUpgradeDryRunError::StorageAccess(err)
+    }
+}
+
+/// Applies the extrinsics of the given block on top of the storage of `base_block_hash`
+/// overlaid with `storage_changes`.
+///
+/// Returns the outcome of the block, the runtime, and the storage changes to use as a base for
+/// the next block.
+#[allow(clippy::too_many_arguments)]
+fn execute_block(
+    database: &dyn database_backend::FullDatabase,
+    base_block_hash: &[u8; 32],
+    block_number_bytes: usize,
+    mut runtime: host::HostVmPrototype,
+    hash: [u8; 32],
+    scale_encoded_header: &[u8],
+    body: &[Vec<u8>],
+    storage_changes: storage_diff::TrieDiff,
+    max_log_level: u32,
+) -> Result<(BlockOutcome, host::HostVmPrototype, storage_diff::TrieDiff), RuntimeUpgradeDryRunError>
+{
+    let mut decoded_header = header::decode(scale_encoded_header, block_number_bytes)
+        .map_err(RuntimeUpgradeDryRunError::InvalidHeader)?;
+
+    let mut outcome = BlockOutcome {
+        number: decoded_header.number,
+        hash,
+        extrinsics: Vec::with_capacity(body.len()),
+        weight: None,
+        storage_diff: Vec::new(),
+        error: None,
+        logs: String::new(),
+    };
+
+    // `Core_initialize_block` expects the header without its seal.
+    let _seal_log = decoded_header.digest.pop_seal();
+    let initialize_parameter = decoded_header.scale_encoding_vec(block_number_bytes);
+
+    let calls = iter::once(("Core_initialize_block", &initialize_parameter[..]))
+        .chain(
+            body.iter()
+                .map(|extrinsic| ("BlockBuilder_apply_extrinsic", &extrinsic[..])),
+        )
+        .chain(iter::once(("BlockBuilder_finalize_block", &[][..])));
+
+    let mut current_changes = storage_changes.clone();
+    for (function_to_call, parameter) in calls {
+        let (call_result, prototype) = run_call(
+            database,
+            base_block_hash,
+            runtime,
+            function_to_call,
+            parameter,
+            current_changes.clone(),
+            max_log_level,
+        )?;
+        runtime = prototype;
+
+        let success = match call_result {
+            Ok(success) => success,
+            Err(failure) => {
+                outcome.logs.push_str(&failure.logs);
+                outcome.error = Some(failure.error);
+                break;
+            }
+        };
+
+        outcome.logs.push_str(&success.logs);
+        current_changes = success.storage_changes;
+
+        if function_to_call == "BlockBuilder_apply_extrinsic" {
+            // The output is a SCALE-encoded
+            // `Result<Result<(), DispatchError>, TransactionValidityError>`.
+            outcome.extrinsics.push(match success.output.split_first() {
+                Some((0, [0])) => ExtrinsicOutcome::Success,
+                Some((0, [1, error @ ..])) => ExtrinsicOutcome::DispatchError(error.to_vec()),
+                Some((1, error)) => ExtrinsicOutcome::Invalid(error.to_vec()),
+                _ => {
+                    outcome.error = Some(RuntimeCallError::InvalidOutput);
+                    break;
+                }
+            });
+        }
+    }
+
+    outcome.weight = current_changes
+        .diff_get(&SYSTEM_BLOCK_WEIGHT_KEY)
+        .and_then(|(value, ())| value)
+        .and_then(decode_weights::<3>)
+        .map(|[normal, operational, mandatory]| BlockWeight {
+            normal,
+            operational,
+            mandatory,
+        });
+    outcome.storage_diff = storage_diff_entries(
+        database,
+        base_block_hash,
+        &storage_changes,
+        &current_changes,
+    )?;
+
+    Ok((outcome, runtime, current_changes))
+}
+
+/// Successful runtime call. See [`run_call`].
+struct CallSuccess {
+    /// Output of the runtime function.
+    output: Vec<u8>,
+    /// Storage changes passed to [`run_call`], updated with the changes of the call.
+    storage_changes: storage_diff::TrieDiff,
+    /// Concatenation of all the log messages printed by the runtime.
+    logs: String,
+}
+
+/// Failed runtime call. See [`run_call`].
+struct CallFailure {
+    /// Reason for the failure.
+    error: RuntimeCallError,
+    /// Concatenation of all the log messages printed by the runtime before the failure.
+    logs: String,
+}
+
+/// Calls the given runtime function on top of the storage of `base_block_hash` overlaid with
+/// `storage_changes`.
+fn run_call(
+    database: &dyn database_backend::FullDatabase,
+    base_block_hash: &[u8; 32],
+    runtime: host::HostVmPrototype,
+    function_to_call: &str,
+    parameter: &[u8],
+    storage_changes: storage_diff::TrieDiff,
+    max_log_level: u32,
+) -> Result<(Result<CallSuccess, CallFailure>, host::HostVmPrototype), RuntimeUpgradeDryRunError> {
+    let mut call = match runtime_host::run(runtime_host::Config {
+        virtual_machine: runtime,
+        function_to_call,
+        parameter: iter::once(parameter),
+        storage_main_trie_changes: storage_changes,
+        offchain_storage_changes: Default::default(),
+        max_log_level,
+        trace: false,
+        fuel_budget: None,
+        max_pending_changes_size: None,
+    }) {
+        Ok(call) => call,
+        Err((error, prototype)) => {
+            return Ok((
+                Err(CallFailure {
+                    error: RuntimeCallError::Start(error),
+                    logs: String::new(),
+                }),
+                prototype,
+            ))
+        }
+    };
+
+    loop {
+        match call {
+            runtime_host::RuntimeHostVm::Finished(Ok(success)) => {
+                // Changes to child tries can't be passed to the next runtime call, and the
+                // calls that follow would run on top of an inconsistent storage.
+                if success.storage_changes.has_child_tries_changes() {
+                    return Ok((
+                        Err(CallFailure {
+                            error: RuntimeCallError::ChildTrieModified,
+                            logs: success.logs,
+                        }),
+                        success.virtual_machine.into_prototype(),
+                    ));
+                }
+
+                let output = success.virtual_machine.value().as_ref().to_vec();
+                return Ok((
+                    Ok(CallSuccess {
+                        output,
+                        storage_changes: success.storage_changes.into_main_trie_diff(),
+                        logs: success.logs,
+                    }),
+                    success.virtual_machine.into_prototype(),
+                ));
+            }
+            runtime_host::RuntimeHostVm::Finished(Err(error)) => {
+                let logs = match &error.detail {
+                    runtime_host::ErrorDetail::WasmVm { logs, .. } => logs.clone(),
+                    _ => String::new(),
+                };
+                return Ok((
+                    Err(CallFailure {
+                        error: RuntimeCallError::Execution(error.detail),
+                        logs,
+                    }),
+                    error.prototype,
+                ));
+            }
+
+            runtime_host::RuntimeHostVm::StorageGet(req) => {
+                let value = database_backend::storage_get(
+                    database,
+                    base_block_hash,
+                    req.child_trie().as_ref().map(|c| c.as_ref()),
+                    req.key().as_ref(),
+                )?;
+                let value = match value {
+                    Some((value, version)) => Some((
+                        value,
+                        runtime_host::TrieEntryVersion::try_from(version)
+                            .map_err(|()| RuntimeUpgradeDryRunError::CorruptedDatabase)?,
+                    )),
+                    None => None,
+                };
+                call = req.inject_value(
+                    value
+                        .as_ref()
+                        .map(|(value, version)| (iter::once(&value[..]), *version)),
+                );
+            }
+            runtime_host::RuntimeHostVm::ClosestDescendantMerkleValue(req) => {
+                let parent_paths = database_backend::child_trie_parent_paths(
+                    req.child_trie().as_ref().map(|c| c.as_ref()),
+                );
+                let merkle_value = database.block_storage_closest_descendant_merkle_value(
+                    base_block_hash,
+                    &parent_paths,
+                    &req.key().map(u8::from).collect::<Vec<_>>(),
+                )?;
+                call = req.inject_merkle_value(merkle_value.as_deref());
+            }
+            runtime_host::RuntimeHostVm::NextKey(req) => {
+                let parent_paths = database_backend::child_trie_parent_paths(
+                    req.child_trie().as_ref().map(|c| c.as_ref()),
+                );
+                let next_key = database.block_storage_next_key(
+                    base_block_hash,
+                    &parent_paths,
+                    &req.key()
+                        .map(u8::from)
+                        .chain(if req.or_equal() { None } else { Some(0u8) })
+                        .collect::<Vec<_>>(),
+                    &req.prefix().map(u8::from).collect::<Vec<_>>(),
+                    req.branch_nodes(),
+                )?;
+                call = req.inject_key(
+                    next_key.map(|k| k.into_iter().map(|b| trie::Nibble::try_from(b).unwrap())),
+                );
+            }
+            runtime_host::RuntimeHostVm::SignatureVerification(req) => {
+                call = req.verify_and_resume();
+            }
+
+            // The rehearsal never has access to the keystore or to off-chain-worker-related
+            // features.
+            runtime_host::RuntimeHostVm::KeystorePublicKeys(req) => {
+                call = req.resume(iter::empty::<&[u8]>());
+            }
+            runtime_host::RuntimeHostVm::KeystoreGenerate(req) => {
+                call = req.resume(None);
+            }
+            runtime_host::RuntimeHostVm::SignRequest(req) => {
+                call = req.resume(None);
+            }
+            runtime_host::RuntimeHostVm::Offchain(ctx) => {
+                call = ctx.reject();
+            }
+        }
+    }
+}
+
+/// Returns the list of entries of `after` whose value differs from the one in `before`, where
+/// both diffs are relative to the storage of `base_block_hash`. The list is ordered by key.
+fn storage_diff_entries(
+    database: &dyn database_backend::FullDatabase,
+    base_block_hash: &[u8; 32],
+    before: &storage_diff::TrieDiff,
+    after: &storage_diff::TrieDiff,
+) -> Result<Vec<StorageDiffEntry>, RuntimeUpgradeDryRunError> {
+    let mut entries = Vec::new();
+
+    for (key, new_value, ()) in after.diff_iter_unordered() {
+        let old_value = match before.diff_get(key) {
+            Some((value, ())) => value.map(|v| v.to_vec()),
+            None => database_backend::storage_get(database, base_block_hash, None, key)?
+                .map(|(value, _)| value),
+        };
+
+        if old_value.as_deref() == new_value {
+            continue;
+        }
+
+        entries.push(StorageDiffEntry {
+            key: key.to_vec(),
+            old_value,
+            new_value: new_value.map(|v| v.to_vec()),
+        });
+    }
+
+    entries.sort_unstable_by(|a, b| a.key.cmp(&b.key));
+    Ok(entries)
+}
+
+/// Key of the `System::BlockWeight` storage item of FRAME, in other words
+/// `twox128("System") ++ twox128("BlockWeight")`.
+const SYSTEM_BLOCK_WEIGHT_KEY: [u8; 32] = [
+    0x26, 0xaa, 0x39, 0x4e, 0xea, 0x56, 0x30, 0xe0, 0x7c, 0x48, 0xae, 0x0c, 0x95, 0x58, 0xce, 0xf7,
+    0x34, 0xab, 0xf5, 0xcb, 0x34, 0xd6, 0x24, 0x43, 0x78, 0xcd, 0xdb, 0xf1, 0x8e, 0x84, 0x9d, 0x96,
+];
+
+/// Decodes `N` consecutive FRAME weights.
+///
+/// Weights are made of two SCALE-compact numbers in recent runtimes, and of a single `u64` in
+/// older runtimes. The latter is only tried if the former fails.
+fn decode_weights<const N: usize>(bytes: &[u8]) -> Option<[Weight; N]> {
+    if let Some(weights) = decode_two_dimensional_weights(bytes) {
+        return Some(weights);
+    }
+
+    if bytes.len() != N * 8 {
+        return None;
+    }
+    let mut weights = [Weight {
+        ref_time: 0,
+        proof_size: 0,
+    }; N];
+    for (weight, chunk) in weights.iter_mut().zip(bytes.chunks_exact(8)) {
+        *weight = Weight {
+            ref_time: u64::from_le_bytes(<[u8; 8]>::try_from(chunk).unwrap()),
+            proof_size: 0,
+        };
+    }
+    Some(weights)
+}
+
+/// Decodes `N` consecutive weights made of two SCALE-compact numbers. See [`decode_weights`].
+fn decode_two_dimensional_weights<const N: usize>(bytes: &[u8]) -> Option<[Weight; N]> {
+    let mut cursor = bytes;
+    let mut weights = [Weight {
+        ref_time: 0,
+        proof_size: 0,
+    }; N];
+    for weight in &mut weights {
+        let (ref_time, rest) = util::decode_scale_compact_u64(cursor)?;
+        let (proof_size, rest) = util::decode_scale_compact_u64(rest)?;
+        *weight = Weight {
+            ref_time,
+            proof_size,
+        };
+        cursor = rest;
+    }
+    if !cursor.is_empty() {
+        return None;
+    }
+    Some(weights)
+}
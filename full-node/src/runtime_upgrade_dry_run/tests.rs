@@ -0,0 +1,158 @@
+// Smoldot
+// Copyright (C) 2023  Pierre Krieger
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <http://www.gnu.org/licenses/>.
+
+#![cfg(test)]
+
+use super::{decode_weights, dry_run, ExtrinsicOutcome, RuntimeCallError, Weight};
+
+use smoldot::{chain_spec, database::full_memory, header};
+use std::iter;
+
+const CHAIN_SPEC: &[u8] = include_bytes!("../../../demo-chain-specs/substrate-node-template.json");
+
+#[test]
+fn weights_two_dimensional() {
+    // `(ref_time, proof_size)` pairs encoded as SCALE-compact numbers.
+    assert_eq!(
+        decode_weights::<2>(&[5 << 2, 6 << 2, 0b0000_0001, 0b0000_0100, 0]),
+        Some([
+            Weight {
+                ref_time: 5,
+                proof_size: 6
+            },
+            Weight {
+                ref_time: 256,
+                proof_size: 0
+            }
+        ])
+    );
+}
+
+#[test]
+fn weights_legacy() {
+    // Some of these bytes aren't valid SCALE-compact encodings, which makes the decoding fall
+    // back to `u64`s.
+    let mut bytes = 7u64.to_le_bytes().to_vec();
+    bytes.extend_from_slice(&0x0123_4567_89ab_cdefu64.to_le_bytes());
+    assert_eq!(
+        decode_weights::<2>(&bytes),
+        Some([
+            Weight {
+                ref_time: 7,
+                proof_size: 0
+            },
+            Weight {
+                ref_time: 0x0123_4567_89ab_cdef,
+                proof_size: 0
+            }
+        ])
+    );
+}
+
+#[test]
+fn weights_invalid() {
+    assert_eq!(decode_weights::<2>(&[]), None);
+    // Trailing data.
+    assert_eq!(decode_weights::<1>(&[5 << 2, 6 << 2, 0]), None);
+    // Neither two compact numbers per weight nor one `u64`.
+    assert_eq!(decode_weights::<2>(&[5 << 2, 6 << 2, 7 << 2]), None);
+}
+
+#[test]
+fn dry_run_in_memory() {
+    let chain_spec = chain_spec::ChainSpec::from_json_bytes(CHAIN_SPEC).unwrap();
+    let genesis_chain_information = chain_spec.to_chain_information().unwrap().0;
+    let (genesis_trie_nodes, state_version) = crate::genesis_trie_nodes(&chain_spec);
+    let runtime_code = chain_spec
+        .genesis_storage()
+        .into_genesis_items()
+        .unwrap()
+        .value(b":code")
+        .unwrap()
+        .to_vec();
+
+    let mut database = full_memory::MemoryFullDatabase::new(
+        full_memory::Config {
+            block_number_bytes: 4,
+            extrinsics_index: false,
+        },
+        genesis_chain_information.as_ref(),
+        iter::empty(),
+        None,
+        genesis_trie_nodes.into_iter(),
+        state_version,
+    );
+
+    // Child of the genesis block whose only extrinsic is the `Timestamp::set` inherent. The
+    // timestamp is chosen so that the slot matches the one of the genesis block, as the header
+    // doesn't contain any Aura pre-runtime digest.
+    let body = [vec![
+        5 << 2,                 // Length of the extrinsic.
+        0x04,                   // Unsigned extrinsic, version 4.
+        TIMESTAMP_PALLET_INDEX, // `Timestamp` pallet.
+        0,                      // `set` call.
+        0xe1,                   // SCALE-compact encoding of `3000`.
+        0x2e,
+    ]];
+    let genesis_header = &genesis_chain_information.as_ref().finalized_block_header;
+    let genesis_hash = genesis_header.hash(4);
+    let scale_encoded_header = header::HeaderRef {
+        parent_hash: &genesis_hash,
+        number: 1,
+        state_root: genesis_header.state_root,
+        extrinsics_root: &header::extrinsics_root(&body),
+        digest: header::DigestRef::empty(),
+    }
+    .scale_encoding_vec(4);
+    let block_hash = header::hash_from_scale_encoded_header(&scale_encoded_header);
+    database
+        .insert(
+            &scale_encoded_header,
+            true,
+            body.iter(),
+            iter::empty(),
+            state_version,
+        )
+        .unwrap();
+
+    let report = dry_run(&database, 4, &runtime_code, None, 5, false, 0).unwrap();
+
+    // The dry run starts from the finalized block, in other words the genesis block.
+    assert_eq!(report.block_number, 0);
+    assert_eq!(report.block_hash, genesis_hash);
+    assert_eq!(report.runtime_version.decode().spec_name, "node-template");
+
+    // The runtime of the chain specification isn't compiled with the `try-runtime` feature.
+    assert!(matches!(
+        report.migration.result,
+        Err(RuntimeCallError::Start(_))
+    ));
+    assert!(report.migration.storage_diff.is_empty());
+
+    // Only one descendant is available in the database.
+    assert_eq!(report.blocks.len(), 1);
+    let block = &report.blocks[0];
+    assert!(block.error.is_none(), "{:?}", block.error);
+    assert_eq!(block.number, 1);
+    assert_eq!(block.hash, block_hash);
+    assert_eq!(block.extrinsics, [ExtrinsicOutcome::Success]);
+    assert!(block.weight.is_some());
+    assert!(!block.storage_diff.is_empty());
+}
+
+/// Index of the `Timestamp` pallet in the runtime of [`CHAIN_SPEC`].
+const TIMESTAMP_PALLET_INDEX: u8 = 2;
@@ -0,0 +1,454 @@
+// Smoldot
+// Copyright (C) 2023  Pierre Krieger
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Exporting the storage of the finalized block of the database to a state snapshot, and
+//! initializing a new database from such a snapshot.
+//!
+//! See the documentation of [`smoldot::database::state_snapshot`] for more information about
+//! the format of the file.
+//!
+//! Initializing a database from a snapshot makes it possible to skip syncing the chain from the
+//! genesis block. The finalized block of the newly-created database is the block of the
+//! snapshot. Its justification isn't part of the snapshot, and is thus not available. Its
+//! ancestors aren't available either.
+
+use smoldot::{
+    chain_spec,
+    database::{full_sqlite, state_snapshot},
+    executor, trie,
+};
+use std::{borrow::Cow, io, iter, path::PathBuf};
+
+mod tests;
+
+/// Configuration for [`export_snapshot`].
+pub struct ExportSnapshotConfig<'a> {
+    /// Specification of the chain.
+    pub chain_spec: Cow<'a, [u8]>,
+    /// Path to the SQLite database to read the storage from.
+    pub sqlite_database_path: PathBuf,
+    /// Maximum size, in bytes, of the cache SQLite uses.
+    pub sqlite_cache_size: usize,
+    /// Where to write the snapshot to.
+    pub output: Box<dyn io::Write + 'a>,
+}
+
+/// Outcome of a successful [`export_snapshot`].
+#[derive(Debug)]
+pub struct ExportSnapshotOutcome {
+    /// Number of the block whose storage has been exported.
+    pub block_number: u64,
+    /// Hash of the block whose storage has been exported.
+    pub block_hash: [u8; 32],
+    /// Number of storage entries that have been written, including the entries of child tries.
+    pub num_entries: u64,
+}
+
+/// Writes the chain information and the storage of the finalized block of the database to the
+/// given output.
+pub fn export_snapshot(
+    mut config: ExportSnapshotConfig,
+) -> Result<ExportSnapshotOutcome, ExportSnapshotError> {
+    let chain_spec = chain_spec::ChainSpec::from_json_bytes(&config.chain_spec)
+        .map_err(ExportSnapshotError::InvalidChainSpec)?;
+    let block_number_bytes = usize::from(chain_spec.block_number_bytes());
+    let genesis_block_hash = genesis_block_hash(&chain_spec);
+
+    // Passing the genesis block hash makes sure that the database belongs to the chain.
+    let database = match full_sqlite::open(full_sqlite::Config {
+        block_number_bytes,
+        genesis_block_hash: Some(genesis_block_hash),
+        extrinsics_index: false,
+        cache_size: config.sqlite_cache_size,
+        ty: full_sqlite::ConfigTy::Disk {
+            path: &config.sqlite_database_path,
+            memory_map_size: 1000000000, // TODO: make configurable
+        },
+    })
+    .map_err(ExportSnapshotError::DatabaseOpen)?
+    {
+        full_sqlite::DatabaseOpen::Open(database) => database,
+        full_sqlite::DatabaseOpen::Empty(_) => return Err(ExportSnapshotError::EmptyDatabase),
+    };
+
+    let block_hash = database.finalized_block_hash()?;
+    let chain_information = database.to_chain_information(&block_hash)?;
+    let block_number = chain_information.as_ref().finalized_block_header.number;
+    let body = database
+        .block_extrinsics(&block_hash)?
+        .ok_or(ExportSnapshotError::CorruptedDatabase)?;
+
+    let (mut encoder, header) = state_snapshot::SnapshotEncoder::new(
+        &genesis_block_hash,
+        &chain_information,
+        body,
+        block_number_bytes,
+    );
+    config.output.write_all(&header)?;
+
+    // Write the entries of the main trie, then the entries of each child tries.
+    let mut num_entries = 0;
+    let mut child_tries = Vec::new();
+    export_trie(
+        &database,
+        &block_hash,
+        None,
+        &mut encoder,
+        &mut config.output,
+        |key| {
+            num_entries += 1;
+            if let Some(child_trie) = key.strip_prefix(trie::CHILD_STORAGE_PREFIX) {
+                child_tries.push(child_trie.to_vec());
+            }
+        },
+    )?;
+    for child_trie in &child_tries {
+        export_trie(
+            &database,
+            &block_hash,
+            Some(child_trie),
+            &mut encoder,
+            &mut config.output,
+            |_| num_entries += 1,
+        )?;
+    }
+
+    config.output.write_all(&encoder.finish())?;
+    config.output.flush()?;
+
+    Ok(ExportSnapshotOutcome {
+        block_number,
+        block_hash,
+        num_entries,
+    })
+}
+
+/// Writes all the entries of the given trie of the storage of the given block to the output.
+///
+/// `on_entry` is called with the key of each entry that has been written.
+fn export_trie(
+    database: &full_sqlite::SqliteFullDatabase,
+    block_hash: &[u8; 32],
+    child_trie: Option<&[u8]>,
+    encoder: &mut state_snapshot::SnapshotEncoder,
+    output: &mut dyn io::Write,
+    mut on_entry: impl FnMut(&[u8]),
+) -> Result<(), ExportSnapshotError> {
+    let parent_path = match child_trie {
+        Some(child_trie) => trie::bytes_to_nibbles(
+            trie::CHILD_STORAGE_PREFIX
+                .iter()
+                .copied()
+                .chain(child_trie.iter().copied()),
+        )
+        .map(u8::from)
+        .collect::<Vec<_>>(),
+        None => Vec::new(),
+    };
+
+    let mut next_key_nibbles = Vec::new();
+    loop {
+        let Some(key_nibbles) = database.block_storage_next_key(
+            block_hash,
+            parent_tries_paths(&parent_path),
+            next_key_nibbles.iter().copied(),
+            iter::empty(),
+            false,
+        )?
+        else { break };
+
+        // The database might return branch nodes, which are skipped. Storage entries always
+        // have a key made of bytes.
+        let value = if key_nibbles.len() % 2 == 0 {
+            database.block_storage_get(
+                block_hash,
+                parent_tries_paths(&parent_path),
+                key_nibbles.iter().copied(),
+            )?
+        } else {
+            None
+        };
+
+        if let Some((value, version)) = value {
+            let key = trie::nibbles_to_bytes_suffix_extend(
+                key_nibbles
+                    .iter()
+                    .map(|n| trie::Nibble::try_from(*n).unwrap()),
+            )
+            .collect::<Vec<_>>();
+            let version = trie::TrieEntryVersion::try_from(version)
+                .map_err(|()| ExportSnapshotError::CorruptedDatabase)?;
+            output.write_all(&encoder.encode_entry(child_trie, &key, &value, version))?;
+            on_entry(&key);
+        }
+
+        // The smallest key strictly superior to `key_nibbles` is `key_nibbles` followed with
+        // a `0`.
+        next_key_nibbles = key_nibbles;
+        next_key_nibbles.push(0);
+    }
+
+    Ok(())
+}
+
+/// Turns a path to the root of a trie into the parameter expected by the database.
+fn parent_tries_paths(
+    parent_path: &[u8],
+) -> impl Iterator<Item = impl Iterator<Item = u8> + '_> + '_ {
+    iter::once(parent_path)
+        .filter(|p| !p.is_empty())
+        .map(|p| p.iter().copied())
+}
+
+/// Error potentially returned by [`export_snapshot`].
+#[derive(Debug, derive_more::Display)]
+pub enum ExportSnapshotError {
+    /// Failed to parse the chain specification.
+    #[display(fmt = "Failed to decode chain specification: {_0}")]
+    InvalidChainSpec(chain_spec::ParseError),
+    /// Failed to open the database.
+    #[display(fmt = "Failed to open database: {_0}")]
+    DatabaseOpen(full_sqlite::OpenError),
+    /// The database doesn't exist or is empty.
+    #[display(fmt = "Database is empty")]
+    EmptyDatabase,
+    /// Error while accessing the database.
+    #[display(fmt = "Error while accessing the database: {_0}")]
+    DatabaseAccess(full_sqlite::AccessError),
+    /// Error while accessing the storage of the finalized block in the database.
+    #[display(fmt = "Error while accessing the database: {_0}")]
+    StorageAccess(full_sqlite::StorageAccessError),
+    /// The content of the database is invalid.
+    #[display(fmt = "Database is corrupted")]
+    CorruptedDatabase,
+    /// Error while writing to the output.
+    #[display(fmt = "Failed to write output: {_0}")]
+    Io(io::Error),
+}
+
+impl From<full_sqlite::AccessError> for ExportSnapshotError {
+    fn from(err: full_sqlite::AccessError) -> Self {
+        ExportSnapshotError::DatabaseAccess(err)
+    }
+}
+
+impl From<full_sqlite::StorageAccessError> for ExportSnapshotError {
+    fn from(err: full_sqlite::StorageAccessError) -> Self {
+        ExportSnapshotError::StorageAccess(err)
+    }
+}
+
+impl From<io::Error> for ExportSnapshotError {
+    fn from(err: io::Error) -> Self {
+        ExportSnapshotError::Io(err)
+    }
+}
+
+/// Configuration for [`import_snapshot`].
+pub struct ImportSnapshotConfig<'a> {
+    /// Specification of the chain.
+    pub chain_spec: Cow<'a, [u8]>,
+    /// Path to the SQLite database to create. The database must not exist yet or be empty.
+    pub sqlite_database_path: PathBuf,
+    /// Maximum size, in bytes, of the cache SQLite uses.
+    pub sqlite_cache_size: usize,
+    /// Where to read the snapshot from.
+    pub input: Box<dyn io::Read + 'a>,
+}
+
+/// Outcome of a successful [`import_snapshot`].
+#[derive(Debug)]
+pub struct ImportSnapshotOutcome {
+    /// Number of the finalized block of the newly-created database.
+    pub block_number: u64,
+    /// Hash of the finalized block of the newly-created database.
+    pub block_hash: [u8; 32],
+    /// Number of storage entries that have been inserted, including the entries of child tries.
+    pub num_entries: u64,
+}
+
+/// Reads a state snapshot from the given input, verifies it, and initializes a new database
+/// whose finalized block is the block of the snapshot.
+///
+/// > **Note**: The snapshot is loaded in memory in its entirety before being processed.
+pub fn import_snapshot(
+    mut config: ImportSnapshotConfig,
+) -> Result<ImportSnapshotOutcome, ImportSnapshotError> {
+    let chain_spec = chain_spec::ChainSpec::from_json_bytes(&config.chain_spec)
+        .map_err(ImportSnapshotError::InvalidChainSpec)?;
+    let block_number_bytes = usize::from(chain_spec.block_number_bytes());
+    let genesis_block_hash = genesis_block_hash(&chain_spec);
+
+    let mut encoded = Vec::new();
+    config.input.read_to_end(&mut encoded)?;
+    let snapshot = state_snapshot::decode(&encoded, block_number_bytes)
+        .map_err(ImportSnapshotError::InvalidSnapshot)?;
+
+    let block_header = &snapshot.chain_information.as_ref().finalized_block_header;
+    let block_number = block_header.number;
+    let block_hash = block_header.hash(block_number_bytes);
+
+    // The snapshot must belong to the chain of the chain specification. Since the snapshot
+    // doesn't contain the ancestors of its block, the genesis block hash recorded in the
+    // snapshot can only be cross-checked with its block if this block is the genesis block or
+    // is its child.
+    if snapshot.genesis_block_hash != genesis_block_hash
+        || (block_number == 0 && block_hash != genesis_block_hash)
+        || (block_number == 1 && *block_header.parent_hash != genesis_block_hash)
+    {
+        return Err(ImportSnapshotError::WrongChain);
+    }
+
+    // In order to determine the state version of the block, we need to compile the runtime.
+    let state_version = {
+        let code = snapshot
+            .main_trie
+            .get(&b":code"[..])
+            .ok_or(ImportSnapshotError::MissingRuntimeCode)?
+            .0;
+        let heap_pages = executor::storage_heap_pages_to_value(
+            snapshot.main_trie.get(&b":heappages"[..]).map(|(v, _)| *v),
+        )
+        .map_err(ImportSnapshotError::InvalidHeapPages)?;
+        executor::host::HostVmPrototype::new(executor::host::Config {
+            module: code,
+            heap_pages,
+            exec_hint: executor::vm::ExecHint::Oneshot,
+            compilation_cache: None,
+            allow_unresolved_imports: true,
+        })
+        .map_err(ImportSnapshotError::InvalidRuntime)?
+        .runtime_version()
+        .decode()
+        .state_version
+        .map(u8::from)
+        .unwrap_or(0)
+    };
+
+    let database = match full_sqlite::open(full_sqlite::Config {
+        block_number_bytes,
+        genesis_block_hash: Some(genesis_block_hash),
+        extrinsics_index: false,
+        cache_size: config.sqlite_cache_size,
+        ty: full_sqlite::ConfigTy::Disk {
+            path: &config.sqlite_database_path,
+            memory_map_size: 1000000000, // TODO: make configurable
+        },
+    })
+    .map_err(ImportSnapshotError::DatabaseOpen)?
+    {
+        full_sqlite::DatabaseOpen::Open(_) => return Err(ImportSnapshotError::NonEmptyDatabase),
+        full_sqlite::DatabaseOpen::Empty(database) => database,
+    };
+
+    // The nodes of the child tries are inserted alongside with the nodes of the main trie. The
+    // entries of the main trie that correspond to child tries reference their roots.
+    let mut trie_nodes = super::build_trie_nodes(
+        snapshot
+            .main_trie
+            .iter()
+            .map(|(key, (value, version))| (*key, *value, u8::from(*version))),
+        true,
+    );
+    for child_trie in snapshot.child_tries.values() {
+        trie_nodes.extend(super::build_trie_nodes(
+            child_trie
+                .iter()
+                .map(|(key, (value, version))| (*key, *value, u8::from(*version))),
+            false,
+        ));
+    }
+
+    let num_entries = u64::try_from(
+        snapshot.main_trie.len()
+            + snapshot
+                .child_tries
+                .values()
+                .map(|child_trie| child_trie.len())
+                .sum::<usize>(),
+    )
+    .unwrap();
+
+    database
+        .initialize(
+            snapshot.chain_information.as_ref(),
+            snapshot.body.iter().copied(),
+            None,
+            trie_nodes.into_iter(),
+            state_version,
+        )
+        .map_err(ImportSnapshotError::DatabaseAccess)?;
+
+    Ok(ImportSnapshotOutcome {
+        block_number,
+        block_hash,
+        num_entries,
+    })
+}
+
+/// Returns the hash of the genesis block of the given chain.
+fn genesis_block_hash(chain_spec: &chain_spec::ChainSpec) -> [u8; 32] {
+    // TODO: don't unwrap?
+    chain_spec
+        .to_chain_information()
+        .unwrap()
+        .0
+        .as_ref()
+        .finalized_block_header
+        .hash(usize::from(chain_spec.block_number_bytes()))
+}
+
+/// Error potentially returned by [`import_snapshot`].
+#[derive(Debug, derive_more::Display)]
+pub enum ImportSnapshotError {
+    /// Failed to parse the chain specification.
+    #[display(fmt = "Failed to decode chain specification: {_0}")]
+    InvalidChainSpec(chain_spec::ParseError),
+    /// Error while reading the input.
+    #[display(fmt = "Failed to read input: {_0}")]
+    Io(io::Error),
+    /// The input isn't a valid state snapshot.
+    #[display(fmt = "Invalid state snapshot: {_0}")]
+    InvalidSnapshot(state_snapshot::DecodeError),
+    /// The snapshot belongs to a different chain than the chain specification.
+    #[display(fmt = "State snapshot doesn't match the chain specification")]
+    WrongChain,
+    /// The storage of the snapshot doesn't contain any runtime code.
+    #[display(fmt = "No runtime code found in the state snapshot")]
+    MissingRuntimeCode,
+    /// The value of `:heappages` in the storage of the snapshot is invalid.
+    #[display(fmt = "Invalid heap pages in the state snapshot: {_0}")]
+    InvalidHeapPages(executor::InvalidHeapPagesError),
+    /// Failed to compile the runtime found in the storage of the snapshot.
+    #[display(fmt = "Failed to compile the runtime of the state snapshot: {_0}")]
+    InvalidRuntime(executor::host::NewErr),
+    /// Failed to open the database.
+    #[display(fmt = "Failed to open database: {_0}")]
+    DatabaseOpen(full_sqlite::OpenError),
+    /// The database already contains data.
+    #[display(fmt = "Database isn't empty")]
+    NonEmptyDatabase,
+    /// Error while writing to the database.
+    #[display(fmt = "Error while accessing the database: {_0}")]
+    DatabaseAccess(full_sqlite::AccessError),
+}
+
+impl From<io::Error> for ImportSnapshotError {
+    fn from(err: io::Error) -> Self {
+        ImportSnapshotError::Io(err)
+    }
+}
@@ -0,0 +1,167 @@
+// Smoldot
+// Copyright (C) 2023  Pierre Krieger
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <http://www.gnu.org/licenses/>.
+
+#![cfg(test)]
+
+use super::{
+    export_snapshot, import_snapshot, ExportSnapshotConfig, ImportSnapshotConfig,
+    ImportSnapshotError,
+};
+
+use smoldot::{
+    chain_spec,
+    database::{full_sqlite, state_snapshot},
+    header,
+};
+use std::{iter, path::Path};
+
+const CHAIN_SPEC: &[u8] = include_bytes!("../../../demo-chain-specs/substrate-node-template.json");
+
+/// Body of the block that is exported.
+const BODY: [&[u8]; 2] = [b"hello", b"world"];
+
+fn open_database(path: &Path) -> full_sqlite::DatabaseOpen {
+    full_sqlite::open(full_sqlite::Config {
+        block_number_bytes: 4,
+        genesis_block_hash: None,
+        extrinsics_index: false,
+        cache_size: 1024 * 1024,
+        ty: full_sqlite::ConfigTy::Disk {
+            path,
+            memory_map_size: 1024 * 1024,
+        },
+    })
+    .unwrap()
+}
+
+/// Creates a database at the genesis block of [`CHAIN_SPEC`], then adds and finalizes a child
+/// of the genesis block whose body is [`BODY`]. Returns the hash of this child.
+fn create_database(path: &Path) -> [u8; 32] {
+    let chain_spec = chain_spec::ChainSpec::from_json_bytes(CHAIN_SPEC).unwrap();
+    let genesis_chain_information = chain_spec.to_chain_information().unwrap().0;
+    let (genesis_trie_nodes, state_version) = crate::genesis_trie_nodes(&chain_spec);
+
+    let full_sqlite::DatabaseOpen::Empty(database) = open_database(path)
+        else { panic!() };
+    let database = database
+        .initialize(
+            genesis_chain_information.as_ref(),
+            iter::empty(),
+            None,
+            genesis_trie_nodes.into_iter(),
+            state_version,
+        )
+        .unwrap();
+
+    // The child has the same storage as the genesis block.
+    let genesis_header = &genesis_chain_information.as_ref().finalized_block_header;
+    let scale_encoded_header = header::HeaderRef {
+        parent_hash: &genesis_header.hash(4),
+        number: 1,
+        state_root: genesis_header.state_root,
+        extrinsics_root: &header::extrinsics_root(&BODY),
+        digest: header::DigestRef::empty(),
+    }
+    .scale_encoding_vec(4);
+    let block_hash = header::hash_from_scale_encoded_header(&scale_encoded_header);
+    database
+        .insert(
+            &scale_encoded_header,
+            true,
+            BODY.iter(),
+            iter::empty(),
+            state_version,
+        )
+        .unwrap();
+    database.set_finalized(&block_hash).unwrap();
+    block_hash
+}
+
+fn export(path: &Path) -> Vec<u8> {
+    let mut snapshot = Vec::new();
+    export_snapshot(ExportSnapshotConfig {
+        chain_spec: CHAIN_SPEC.into(),
+        sqlite_database_path: path.to_owned(),
+        sqlite_cache_size: 1024 * 1024,
+        output: Box::new(&mut snapshot),
+    })
+    .unwrap();
+    snapshot
+}
+
+fn import(path: &Path, snapshot: &[u8]) -> Result<(), ImportSnapshotError> {
+    import_snapshot(ImportSnapshotConfig {
+        chain_spec: CHAIN_SPEC.into(),
+        sqlite_database_path: path.to_owned(),
+        sqlite_cache_size: 1024 * 1024,
+        input: Box::new(snapshot),
+    })
+    .map(|_| ())
+}
+
+#[test]
+fn export_import_check_integrity() {
+    let directory = tempfile::tempdir().unwrap();
+    let block_hash = create_database(&directory.path().join("source"));
+    let snapshot = export(&directory.path().join("source"));
+
+    import(&directory.path().join("imported"), &snapshot).unwrap();
+
+    let full_sqlite::DatabaseOpen::Open(database) =
+        open_database(&directory.path().join("imported"))
+        else { panic!() };
+    assert_eq!(database.finalized_block_hash().unwrap(), block_hash);
+    assert_eq!(
+        database
+            .block_extrinsics(&block_hash)
+            .unwrap()
+            .unwrap()
+            .collect::<Vec<_>>(),
+        BODY
+    );
+    assert!(database.check_integrity().unwrap().is_empty());
+}
+
+#[test]
+fn import_wrong_genesis_hash() {
+    let directory = tempfile::tempdir().unwrap();
+    create_database(&directory.path().join("source"));
+    let snapshot = export(&directory.path().join("source"));
+
+    // Re-encode the snapshot with a different genesis block hash.
+    let decoded = state_snapshot::decode(&snapshot, 4).unwrap();
+    let (mut encoder, mut modified) = state_snapshot::SnapshotEncoder::new(
+        &[0xaa; 32],
+        &decoded.chain_information,
+        decoded.body.iter(),
+        4,
+    );
+    for (key, (value, version)) in &decoded.main_trie {
+        modified.extend(encoder.encode_entry(None, key, value, *version));
+    }
+    for (child_trie, entries) in &decoded.child_tries {
+        for (key, (value, version)) in entries {
+            modified.extend(encoder.encode_entry(Some(child_trie), key, value, *version));
+        }
+    }
+    modified.extend(encoder.finish());
+
+    assert!(matches!(
+        import(&directory.path().join("imported"), &modified),
+        Err(ImportSnapshotError::WrongChain)
+    ));
+}
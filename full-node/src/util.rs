@@ -34,3 +34,27 @@ impl core::hash::BuildHasher for SipHasherBuild {
         siphasher::sip::SipHasher13::new_with_key(&self.0)
     }
 }
+
+/// Returns the SCALE-compact encoding of the given number.
+pub fn encode_compact(value: usize) -> Vec<u8> {
+    let value = u64::try_from(value).unwrap();
+    if value < 1 << 6 {
+        vec![u8::try_from(value << 2).unwrap()]
+    } else if value < 1 << 14 {
+        u16::try_from((value << 2) | 0b01)
+            .unwrap()
+            .to_le_bytes()
+            .to_vec()
+    } else if value < 1 << 30 {
+        u32::try_from((value << 2) | 0b10)
+            .unwrap()
+            .to_le_bytes()
+            .to_vec()
+    } else {
+        let num_bytes = 8 - usize::try_from(value.leading_zeros() / 8).unwrap();
+        let mut out = Vec::with_capacity(1 + num_bytes);
+        out.push((u8::try_from(num_bytes - 4).unwrap() << 2) | 0b11);
+        out.extend_from_slice(&value.to_le_bytes()[..num_bytes]);
+        out
+    }
+}
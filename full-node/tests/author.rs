@@ -28,9 +28,11 @@ fn basic_block_generated() {
                     "//Alice",
                 )
                 .unwrap()],
-                sqlite_database_path: None,
-                sqlite_cache_size: 256 * 1024 * 1024,
+                database_backend: smoldot_full_node::DatabaseBackend::Memory,
+                extrinsics_index: false,
                 keystore_path: None,
+                offchain_workers: false,
+                differential_execution: false,
             },
             relay_chain: None,
             libp2p_key: [0; 32],
@@ -39,6 +41,7 @@ fn basic_block_generated() {
             tasks_executor: Arc::new(|task| smol::spawn(task).detach()),
             log_callback: Arc::new(move |_, _| {}),
             jaeger_agent: None,
+            runtime_cache_path: None,
             show_informant: false,
         })
         .await;
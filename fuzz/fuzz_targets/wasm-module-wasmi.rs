@@ -22,6 +22,7 @@ libfuzzer_sys::fuzz_target!(|data: &[u8]| {
         module: data,
         heap_pages: smoldot::executor::DEFAULT_HEAP_PAGES,
         exec_hint: smoldot::executor::vm::ExecHint::ForceWasmi,
+        compilation_cache: None,
         allow_unresolved_imports: true,
     });
 });
@@ -536,7 +536,10 @@ impl Shared {
                     inner = a.inject_inherents(self.inherent_data.take().unwrap());
                 }
                 runtime::BlockBuild::ApplyExtrinsic(a) => {
-                    inner = a.finish();
+                    break BuilderAuthoring::ApplyExtrinsic(ApplyExtrinsic {
+                        inner: a,
+                        shared: self,
+                    });
                 }
                 runtime::BlockBuild::ApplyExtrinsicResult { result, resume } => {
                     break BuilderAuthoring::ApplyExtrinsicResult {
@@ -192,6 +192,9 @@ pub fn build_block(config: Config) -> BlockBuild {
         storage_main_trie_changes: Default::default(),
         offchain_storage_changes: Default::default(),
         max_log_level: config.max_log_level,
+        trace: false,
+        fuel_budget: None,
+        max_pending_changes_size: None,
     });
 
     let vm = match init_result {
@@ -285,6 +288,9 @@ impl BlockBuild {
                 (Inner::Runtime(runtime_host::RuntimeHostVm::NextKey(inner)), _) => {
                     return BlockBuild::NextKey(NextKey(inner, shared))
                 }
+                (Inner::Runtime(runtime_host::RuntimeHostVm::Offchain(ctx)), _) => {
+                    inner = Inner::Runtime(ctx.reject());
+                }
 
                 (
                     Inner::Runtime(runtime_host::RuntimeHostVm::Finished(Ok(success))),
@@ -342,6 +348,9 @@ impl BlockBuild {
                         storage_main_trie_changes: success.storage_changes.into_main_trie_diff(),
                         offchain_storage_changes: success.offchain_storage_changes,
                         max_log_level: shared.max_log_level,
+                        trace: false,
+                        fuel_budget: None,
+                        max_pending_changes_size: None,
                     });
 
                     inner = Inner::Runtime(match init_result {
@@ -546,6 +555,9 @@ impl InherentExtrinsics {
             storage_main_trie_changes: self.storage_changes.into_main_trie_diff(),
             offchain_storage_changes: self.offchain_storage_changes,
             max_log_level: self.shared.max_log_level,
+            trace: false,
+            fuel_budget: None,
+            max_pending_changes_size: None,
         });
 
         let vm = match init_result {
@@ -578,6 +590,9 @@ impl ApplyExtrinsic {
             storage_main_trie_changes: self.storage_changes.into_main_trie_diff(),
             offchain_storage_changes: self.offchain_storage_changes,
             max_log_level: self.shared.max_log_level,
+            trace: false,
+            fuel_budget: None,
+            max_pending_changes_size: None,
         });
 
         self.shared.stage = Stage::ApplyExtrinsic(extrinsic);
@@ -601,6 +616,9 @@ impl ApplyExtrinsic {
             storage_main_trie_changes: self.storage_changes.into_main_trie_diff(),
             offchain_storage_changes: self.offchain_storage_changes,
             max_log_level: self.shared.max_log_level,
+            trace: false,
+            fuel_budget: None,
+            max_pending_changes_size: None,
         });
 
         let vm = match init_result {
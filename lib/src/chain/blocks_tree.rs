@@ -72,6 +72,7 @@ use hashbrown::HashMap;
 
 mod best_block;
 mod finality;
+mod tests;
 mod verify;
 
 pub use self::finality::*;
@@ -147,6 +148,7 @@ impl<T> NonFinalizedTree<T> {
                         after_finalized_block_authorities_set_id,
                         finalized_scheduled_change: finalized_scheduled_change
                             .map(|(n, l)| (n, l.into_iter().collect())),
+                        finalized_forced_change: None,
                         finalized_triggered_authorities: finalized_triggered_authorities
                             .into_iter()
                             .collect(),
@@ -286,6 +288,7 @@ impl<T> NonFinalizedTree<T> {
                     after_finalized_block_authorities_set_id,
                     finalized_triggered_authorities,
                     finalized_scheduled_change,
+                    finalized_forced_change: None,
                 } => chain_information::ChainInformationFinalityRef::Grandpa {
                     after_finalized_block_authorities_set_id:
                         *after_finalized_block_authorities_set_id,
@@ -294,6 +297,40 @@ impl<T> NonFinalizedTree<T> {
                         .map(|(n, l)| (*n, &l[..])),
                     finalized_triggered_authorities,
                 },
+                // Pending forced changes can't be represented in the chain information. They are
+                // exported as a change triggered by the parent of the block that applies them,
+                // which gives the same authorities to that block and its descendants. Any
+                // change scheduled in the meanwhile is discarded, like when the forced change is
+                // applied.
+                Finality::Grandpa {
+                    after_finalized_block_authorities_set_id,
+                    finalized_triggered_authorities,
+                    finalized_forced_change: Some(forced_change),
+                    ..
+                } => {
+                    let finalized_block_number =
+                        header::decode(&inner.finalized_block_header, inner.block_number_bytes)
+                            .unwrap()
+                            .number;
+                    if forced_change.trigger_block_height - 1 > finalized_block_number {
+                        chain_information::ChainInformationFinalityRef::Grandpa {
+                            after_finalized_block_authorities_set_id:
+                                *after_finalized_block_authorities_set_id,
+                            finalized_scheduled_change: Some((
+                                forced_change.trigger_block_height - 1,
+                                &forced_change.authorities[..],
+                            )),
+                            finalized_triggered_authorities,
+                        }
+                    } else {
+                        chain_information::ChainInformationFinalityRef::Grandpa {
+                            after_finalized_block_authorities_set_id:
+                                *after_finalized_block_authorities_set_id + 1,
+                            finalized_scheduled_change: None,
+                            finalized_triggered_authorities: &forced_change.authorities,
+                        }
+                    }
+                }
             },
         };
 
@@ -418,6 +455,16 @@ impl<T> NonFinalizedTree<T> {
         Some(&inner.blocks.get(node_index).unwrap().user_data)
     }
 
+    /// Returns the SCALE-encoded header of a block stored by the [`NonFinalizedTree`], identified
+    /// by its hash.
+    ///
+    /// Returns `None` if the block can't be found.
+    pub fn non_finalized_block_scale_encoded_header(&self, hash: &[u8; 32]) -> Option<&[u8]> {
+        let inner = self.inner.as_ref().unwrap();
+        let node_index = *inner.blocks_by_hash.get(hash)?;
+        Some(&inner.blocks.get(node_index).unwrap().header)
+    }
+
     /// Gives access to a block stored by the [`NonFinalizedTree`], identified by its hash.
     pub fn non_finalized_block_by_hash(&mut self, hash: &[u8; 32]) -> Option<BlockAccess<T>> {
         let inner = self.inner.as_mut().unwrap();
@@ -569,9 +616,29 @@ enum Finality {
         /// number where the changes are to be triggered. The descendants of the block with that
         /// number need to be finalized with the new authorities.
         finalized_scheduled_change: Option<(u64, Arc<[header::GrandpaAuthority]>)>,
+
+        /// Forced change in the GrandPa authorities list that has been signalled by a block that
+        /// is already finalized but not applied yet.
+        finalized_forced_change: Option<GrandpaForcedChange>,
     },
 }
 
+/// Forced change in the list of GrandPa authorities that has been signalled but not applied yet.
+#[derive(Clone)]
+struct GrandpaForcedChange {
+    /// Number of the block that applies the change. Contrary to scheduled changes, the new
+    /// authorities immediately take over from this block, even if it isn't finalized. In other
+    /// words, this block and its descendants need to be finalized with the new authorities.
+    trigger_block_height: u64,
+
+    /// Block number up to which the chain is considered as finalized when the change is applied.
+    /// Changes scheduled to be triggered above this height are discarded.
+    reset_block_height: u64,
+
+    /// New list of GrandPa authorities.
+    authorities: Arc<[header::GrandpaAuthority]>,
+}
+
 struct Block<T> {
     /// Header of the block.
     ///
@@ -623,15 +690,20 @@ enum BlockFinality {
         /// Authorities set id that must be used to finalize the blocks that descend from this
         /// one.
         ///
-        /// If `triggers_change` is `false`, then this field must be equal to the parent block's.
+        /// If `triggers_change` is `false` and this block doesn't apply a forced change, then
+        /// this field must be equal to the parent block's.
         after_block_authorities_set_id: u64,
 
-        /// `true` if this block triggers a change in the list of Grandpa authorities.
+        /// `true` if this block triggers a scheduled change in the list of Grandpa authorities.
+        ///
+        /// Blocks that apply a forced change don't need to be finalized before their descendants
+        /// and thus never have this field set to `true`.
         triggers_change: bool,
 
         /// List of GrandPa authorities that need to finalize the block right after this block.
         ///
-        /// If `triggers_change` is `false`, then this field must be equal to the parent block's.
+        /// If `triggers_change` is `false` and this block doesn't apply a forced change, then
+        /// this field must be equal to the parent block's.
         triggered_authorities: Arc<[header::GrandpaAuthority]>,
 
         /// A change in the GrandPa authorities list that has been scheduled for the block with the
@@ -641,6 +713,13 @@ enum BlockFinality {
         ///
         /// If `Some`, the value must always be strictly superior to the attached block's number.
         scheduled_change: Option<(u64, Arc<[header::GrandpaAuthority]>)>,
+
+        /// A forced change in the GrandPa authorities list that has been signalled by this block
+        /// or one of its ancestors and that applies to a descendant of this block.
+        ///
+        /// If `Some`, the trigger height must always be strictly superior to the attached block's
+        /// number.
+        forced_change: Option<GrandpaForcedChange>,
     },
 }
 
@@ -175,8 +175,8 @@ impl<T> NonFinalizedTreeInner<T> {
             Finality::Outsourced => panic!(),
             Finality::Grandpa {
                 after_finalized_block_authorities_set_id,
-                finalized_scheduled_change,
                 finalized_triggered_authorities,
+                ..
             } => {
                 let finalized_block_number =
                     header::decode(&self.finalized_block_header, self.block_number_bytes)
@@ -227,19 +227,45 @@ impl<T> NonFinalizedTreeInner<T> {
                 }
 
                 // Find which authorities are supposed to finalize the target block.
-                let authorities_list = finalized_scheduled_change
-                    .as_ref()
-                    .filter(|(trigger_height, _)| *trigger_height < target_number)
-                    .map_or(finalized_triggered_authorities, |(_, list)| list);
-
-                // As per above check, we know that the authorities of the target block are either
-                // the same as the ones of the latest finalized block, or the ones contained in
-                // the header of the latest finalized block.
+                // As per above check, we know that no scheduled change has been triggered between
+                // the latest finalized block and the target block. However, a forced change might
+                // have been applied in the meanwhile, including by the target block itself.
+                // Consequently, the authorities are the ones that finalize the descendants of the
+                // target block, unless the target block triggers a scheduled change, in which
+                // case they are the ones that finalize the descendants of its parent.
+                let (authorities_set_id, authorities_list) =
+                    match self.blocks.get(block_index).unwrap().finality {
+                        BlockFinality::Grandpa {
+                            triggers_change: false,
+                            after_block_authorities_set_id,
+                            ref triggered_authorities,
+                            ..
+                        } => (after_block_authorities_set_id, triggered_authorities),
+                        BlockFinality::Grandpa { .. } => {
+                            match self
+                                .blocks
+                                .parent(block_index)
+                                .map(|parent| &self.blocks.get(parent).unwrap().finality)
+                            {
+                                Some(BlockFinality::Grandpa {
+                                    after_block_authorities_set_id,
+                                    triggered_authorities,
+                                    ..
+                                }) => (*after_block_authorities_set_id, triggered_authorities),
+                                None => (
+                                    *after_finalized_block_authorities_set_id,
+                                    finalized_triggered_authorities,
+                                ),
+                                Some(BlockFinality::Outsourced) => unreachable!(),
+                            }
+                        }
+                        BlockFinality::Outsourced => unreachable!(),
+                    };
 
                 // First verification step complete.
                 Ok((
                     block_index,
-                    *after_finalized_block_authorities_set_id,
+                    authorities_set_id,
                     authorities_list.iter().map(|a| a.public_key),
                 ))
             }
@@ -377,11 +403,13 @@ impl<T> NonFinalizedTreeInner<T> {
                     after_finalized_block_authorities_set_id,
                     finalized_scheduled_change,
                     finalized_triggered_authorities,
+                    finalized_forced_change,
                 },
                 BlockFinality::Grandpa {
                     after_block_authorities_set_id,
                     triggered_authorities,
                     scheduled_change,
+                    forced_change,
                     ..
                 },
             ) => {
@@ -400,6 +428,7 @@ impl<T> NonFinalizedTreeInner<T> {
                 *after_finalized_block_authorities_set_id = *after_block_authorities_set_id;
                 *finalized_triggered_authorities = triggered_authorities.clone();
                 *finalized_scheduled_change = scheduled_change.clone();
+                *finalized_forced_change = forced_change.clone();
             }
 
             // Mismatch between chain finality algorithm and block finality algorithm. Should never
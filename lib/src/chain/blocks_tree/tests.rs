@@ -0,0 +1,245 @@
+// Smoldot
+// Copyright (C) 2023  Pierre Krieger
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <http://www.gnu.org/licenses/>.
+
+#![cfg(test)]
+
+use super::{Config, HeaderVerifySuccess, NonFinalizedTree};
+use crate::{chain::chain_information, header, util};
+
+use core::{num::NonZeroU64, time::Duration};
+
+const BLOCK_NUMBER_BYTES: usize = 4;
+
+fn aura_keypair() -> schnorrkel::Keypair {
+    schnorrkel::MiniSecretKey::from_bytes(&[1; 32])
+        .unwrap()
+        .expand_to_keypair(schnorrkel::ExpansionMode::Ed25519)
+}
+
+fn grandpa_key(seed: u8) -> ed25519_zebra::SigningKey {
+    ed25519_zebra::SigningKey::from([seed; 32])
+}
+
+fn grandpa_authorities(seed: u8) -> Vec<header::GrandpaAuthority> {
+    vec![header::GrandpaAuthority {
+        public_key: ed25519_zebra::VerificationKey::from(&grandpa_key(seed)).into(),
+        weight: NonZeroU64::new(1).unwrap(),
+    }]
+}
+
+/// Builds a header signed by the only Aura authority, with the given GrandPa digest item.
+fn build_header(
+    parent_hash: &[u8; 32],
+    number: u64,
+    grandpa_log: Option<header::GrandpaConsensusLog>,
+) -> Vec<u8> {
+    let digest_items = [header::DigestItem::AuraPreDigest(header::AuraPreDigest {
+        slot_number: number,
+    })]
+    .into_iter()
+    .chain(grandpa_log.map(header::DigestItem::GrandpaConsensus))
+    .collect::<Vec<_>>();
+
+    let unsealed_header = header::HeaderRef {
+        parent_hash,
+        number,
+        state_root: &[0; 32],
+        extrinsics_root: &[0; 32],
+        digest: header::DigestRef::from_slice(&digest_items).unwrap(),
+    };
+    let seal = aura_keypair()
+        .sign_simple(b"substrate", &unsealed_header.hash(BLOCK_NUMBER_BYTES))
+        .to_bytes();
+
+    unsealed_header
+        .scale_encoding_with_extra_digest_item(
+            BLOCK_NUMBER_BYTES,
+            header::DigestItemRef::AuraSeal(&seal),
+        )
+        .fold(Vec::new(), |mut a, b| {
+            a.extend_from_slice(b.as_ref());
+            a
+        })
+}
+
+/// Builds a justification of the given block signed by the authority of the given seed.
+fn build_justification(
+    target_hash: &[u8; 32],
+    target_number: u64,
+    authorities_set_id: u64,
+    authority_seed: u8,
+) -> Vec<u8> {
+    let round: u64 = 1;
+
+    let mut message = vec![1u8];
+    message.extend_from_slice(target_hash);
+    message.extend_from_slice(&target_number.to_le_bytes()[..BLOCK_NUMBER_BYTES]);
+    message.extend_from_slice(&round.to_le_bytes());
+    message.extend_from_slice(&authorities_set_id.to_le_bytes());
+    let signature: [u8; 64] = grandpa_key(authority_seed).sign(&message).into();
+
+    let mut justification = round.to_le_bytes().to_vec();
+    justification.extend_from_slice(target_hash);
+    justification.extend_from_slice(&target_number.to_le_bytes()[..BLOCK_NUMBER_BYTES]);
+    justification.extend_from_slice(util::encode_scale_compact_usize(1).as_ref());
+    justification.extend_from_slice(target_hash);
+    justification.extend_from_slice(&target_number.to_le_bytes()[..BLOCK_NUMBER_BYTES]);
+    justification.extend_from_slice(&signature);
+    justification.extend_from_slice(&grandpa_authorities(authority_seed)[0].public_key);
+    justification.extend_from_slice(util::encode_scale_compact_usize(0).as_ref());
+    justification
+}
+
+#[test]
+fn finalize_past_forced_change() {
+    let genesis_header = header::HeaderRef {
+        parent_hash: &[0; 32],
+        number: 0,
+        state_root: &[0; 32],
+        extrinsics_root: &[0; 32],
+        digest: header::DigestRef::empty(),
+    };
+
+    let mut tree = NonFinalizedTree::<()>::new(Config {
+        chain_information: chain_information::ChainInformation {
+            finalized_block_header: Box::new(genesis_header.clone().into()),
+            consensus: chain_information::ChainInformationConsensus::Aura {
+                finalized_authorities_list: vec![header::AuraAuthority {
+                    public_key: aura_keypair().public.to_bytes(),
+                }],
+                slot_duration: NonZeroU64::new(6000).unwrap(),
+            },
+            finality: chain_information::ChainInformationFinality::Grandpa {
+                after_finalized_block_authorities_set_id: 0,
+                finalized_triggered_authorities: grandpa_authorities(1),
+                finalized_scheduled_change: None,
+            },
+        }
+        .try_into()
+        .unwrap(),
+        block_number_bytes: BLOCK_NUMBER_BYTES,
+        blocks_capacity: 16,
+        allow_unknown_consensus_engines: false,
+    });
+
+    // Block #1 schedules a change for block #4, and block #2 forces a change applied at block #3
+    // that discards the scheduled one. The old authorities never finalize block #3.
+    let grandpa_logs = [
+        Some(header::GrandpaConsensusLog::ScheduledChange(
+            header::GrandpaScheduledChange {
+                next_authorities: grandpa_authorities(2),
+                delay: 3,
+            },
+        )),
+        Some(header::GrandpaConsensusLog::ForcedChange {
+            reset_block_height: 0,
+            change: header::GrandpaScheduledChange {
+                next_authorities: grandpa_authorities(3),
+                delay: 1,
+            },
+        }),
+        None,
+        None,
+        None,
+    ];
+
+    let mut block_hashes = vec![genesis_header.hash(BLOCK_NUMBER_BYTES)];
+    for (number, grandpa_log) in (1..).zip(grandpa_logs) {
+        let scale_encoded_header = build_header(block_hashes.last().unwrap(), number, grandpa_log);
+        block_hashes.push(header::hash_from_scale_encoded_header(
+            &scale_encoded_header,
+        ));
+        match tree
+            .verify_header(scale_encoded_header, Duration::from_secs(1_000_000))
+            .unwrap()
+        {
+            HeaderVerifySuccess::Insert { insert, .. } => insert.insert(()),
+            HeaderVerifySuccess::Duplicate => panic!(),
+        }
+    }
+
+    // The forced change isn't applied yet at block #1.
+    tree.verify_justification(
+        *b"FRNK",
+        &build_justification(&block_hashes[1], 1, 0, 1),
+        [0; 32],
+    )
+    .unwrap()
+    .apply()
+    .for_each(drop);
+    let chain_information::ChainInformationFinality::Grandpa {
+        after_finalized_block_authorities_set_id,
+        finalized_triggered_authorities,
+        finalized_scheduled_change,
+    } = chain_information::ChainInformation::from(tree.as_chain_information().as_ref()).finality
+    else { panic!() };
+    assert_eq!(after_finalized_block_authorities_set_id, 0);
+    assert_eq!(finalized_triggered_authorities, grandpa_authorities(1));
+    assert_eq!(
+        finalized_scheduled_change,
+        Some((4, grandpa_authorities(2)))
+    );
+
+    // Once block #2 is finalized, the pending forced change means that block #3 must already be
+    // finalized by the new authorities.
+    tree.verify_justification(
+        *b"FRNK",
+        &build_justification(&block_hashes[2], 2, 0, 1),
+        [0; 32],
+    )
+    .unwrap()
+    .apply()
+    .for_each(drop);
+    let chain_information::ChainInformationFinality::Grandpa {
+        after_finalized_block_authorities_set_id,
+        finalized_triggered_authorities,
+        finalized_scheduled_change,
+    } = chain_information::ChainInformation::from(tree.as_chain_information().as_ref()).finality
+    else { panic!() };
+    assert_eq!(after_finalized_block_authorities_set_id, 1);
+    assert_eq!(finalized_triggered_authorities, grandpa_authorities(3));
+    assert_eq!(finalized_scheduled_change, None);
+
+    // Block #5 can only be finalized by the forced authorities, and doesn't require block #3 to
+    // be finalized beforehand.
+    assert!(tree
+        .verify_justification(
+            *b"FRNK",
+            &build_justification(&block_hashes[5], 5, 0, 1),
+            [0; 32],
+        )
+        .is_err());
+    tree.verify_justification(
+        *b"FRNK",
+        &build_justification(&block_hashes[5], 5, 1, 3),
+        [0; 32],
+    )
+    .unwrap()
+    .apply()
+    .for_each(drop);
+
+    assert_eq!(tree.finalized_block_hash(), block_hashes[5]);
+    let chain_information::ChainInformationFinality::Grandpa {
+        after_finalized_block_authorities_set_id,
+        finalized_triggered_authorities,
+        finalized_scheduled_change,
+    } = chain_information::ChainInformation::from(tree.as_chain_information().as_ref()).finality
+    else { panic!() };
+    assert_eq!(after_finalized_block_authorities_set_id, 1);
+    assert_eq!(finalized_triggered_authorities, grandpa_authorities(3));
+    assert_eq!(finalized_scheduled_change, None);
+}
@@ -22,13 +22,13 @@
 
 use crate::{
     chain::{chain_information, fork_tree},
-    executor::host,
+    executor::{host, vm},
     header, verify,
 };
 
 use super::{
     best_block, fmt, Arc, Block, BlockAccess, BlockConsensus, BlockFinality, Duration, Finality,
-    FinalizedConsensus, NonFinalizedTree, NonFinalizedTreeInner, Vec,
+    FinalizedConsensus, GrandpaForcedChange, NonFinalizedTree, NonFinalizedTreeInner, Vec,
 };
 
 use alloc::boxed::Box;
@@ -205,15 +205,21 @@ impl<T> NonFinalizedTreeInner<T> {
                     after_finalized_block_authorities_set_id,
                     ref finalized_scheduled_change,
                     ref finalized_triggered_authorities,
+                    ref finalized_forced_change,
                 } => {
                     debug_assert!(finalized_scheduled_change
                         .as_ref()
                         .map(|(n, _)| *n >= decoded_header.number)
                         .unwrap_or(true));
+                    debug_assert!(finalized_forced_change
+                        .as_ref()
+                        .map(|c| c.trigger_block_height >= decoded_header.number)
+                        .unwrap_or(true));
                     BlockFinality::Grandpa {
                         prev_auth_change_trigger_number: None,
                         triggers_change: false,
                         scheduled_change: finalized_scheduled_change.clone(),
+                        forced_change: finalized_forced_change.clone(),
                         after_block_authorities_set_id: after_finalized_block_authorities_set_id,
                         triggered_authorities: finalized_triggered_authorities.clone(),
                     }
@@ -395,10 +401,20 @@ impl<T> VerifyContext<T> {
                 _,
             ) => {
                 if authorities_change {
-                    todo!() // TODO: fetch from header
-                            /*BlockConsensus::Aura {
-                                authorities_list:
-                            }*/
+                    // `authorities_change` is `true` only if the header contains such an item.
+                    let authorities_list = decoded_header
+                        .digest
+                        .logs()
+                        .find_map(|item| match item {
+                            header::DigestItemRef::AuraConsensus(
+                                header::AuraConsensusLogRef::AuthoritiesChange(list),
+                            ) => Some(list.map(header::AuraAuthority::from).collect()),
+                            _ => None,
+                        })
+                        .unwrap();
+                    BlockConsensus::Aura {
+                        authorities_list: Arc::new(authorities_list),
+                    }
                 } else {
                     BlockConsensus::Aura {
                         authorities_list: parent_authorities.clone(),
@@ -526,40 +542,55 @@ impl<T> VerifyContext<T> {
                 scheduled_change: parent_scheduled_change,
                 triggered_authorities: parent_triggered_authorities,
                 triggers_change: parent_triggers_change,
-                ..
+                forced_change: parent_forced_change,
             } => {
                 let mut triggered_authorities = parent_triggered_authorities.clone();
                 let mut triggers_change = false;
                 let mut scheduled_change = parent_scheduled_change.clone();
+                let mut forced_change = parent_forced_change.clone();
 
                 // Check whether the verified block schedules a change of authorities.
                 for grandpa_digest_item in decoded_header.digest.logs().filter_map(|d| match d {
                     header::DigestItemRef::GrandpaConsensus(gp) => Some(gp),
                     _ => None,
                 }) {
-                    // TODO: implement items other than ScheduledChange
-                    // TODO: when it comes to forced change, they take precedence over scheduled changes but only sheduled changes within the same block
-                    if let header::GrandpaConsensusLogRef::ScheduledChange(change) =
-                        grandpa_digest_item
-                    {
-                        let trigger_block_height =
-                            decoded_header.number.checked_add(change.delay).unwrap();
-
-                        // It is forbidden to schedule a change while a change is already
-                        // scheduled, otherwise the block is invalid. This is verified during
-                        // the block verification.
-                        match scheduled_change {
-                            Some(_) => {
-                                // Ignore any new change if a change is already in progress.
-                                // Matches the behaviour here: <https://github.com/paritytech/substrate/blob/a357c29ebabb075235977edd5e3901c66575f995/client/finality-grandpa/src/authorities.rs#L479>
-                            }
-                            None => {
-                                scheduled_change = Some((
-                                    trigger_block_height,
-                                    change.next_authorities.map(|a| a.into()).collect(),
-                                ));
+                    match grandpa_digest_item {
+                        header::GrandpaConsensusLogRef::ScheduledChange(change) => {
+                            let trigger_block_height =
+                                decoded_header.number.checked_add(change.delay).unwrap();
+
+                            // It is forbidden to schedule a change while a change is already
+                            // scheduled, otherwise the block is invalid. This is verified during
+                            // the block verification.
+                            match scheduled_change {
+                                Some(_) => {
+                                    // Ignore any new change if a change is already in progress.
+                                    // Matches the behaviour here: <https://github.com/paritytech/substrate/blob/a357c29ebabb075235977edd5e3901c66575f995/client/finality-grandpa/src/authorities.rs#L479>
+                                }
+                                None => {
+                                    scheduled_change = Some((
+                                        trigger_block_height,
+                                        change.next_authorities.map(|a| a.into()).collect(),
+                                    ));
+                                }
                             }
                         }
+                        // Similarly, any new forced change is ignored if a forced change is
+                        // already pending.
+                        header::GrandpaConsensusLogRef::ForcedChange {
+                            reset_block_height,
+                            change,
+                        } if forced_change.is_none() => {
+                            forced_change = Some(GrandpaForcedChange {
+                                trigger_block_height: decoded_header
+                                    .number
+                                    .checked_add(change.delay)
+                                    .unwrap(),
+                                reset_block_height,
+                                authorities: change.next_authorities.map(|a| a.into()).collect(),
+                            });
+                        }
+                        _ => {}
                     }
                 }
 
@@ -575,30 +606,86 @@ impl<T> VerifyContext<T> {
                     }
                 }
 
+                let mut prev_auth_change_trigger_number = if *parent_triggers_change {
+                    Some(decoded_header.number - 1)
+                } else {
+                    *parent_prev_auth_change_trigger_number
+                };
+                let mut after_block_authorities_set_id = if triggers_change {
+                    *parent_after_block_authorities_set_id + 1
+                } else {
+                    *parent_after_block_authorities_set_id
+                };
+
+                // If the newly-verified block is the one where a forced change is applied, the
+                // new authorities immediately take over, without the need for any block to be
+                // finalized beforehand. The chain is considered as finalized up to the reset
+                // height, and the changes scheduled above this height are discarded.
+                if forced_change
+                    .as_ref()
+                    .is_some_and(|c| c.trigger_block_height == decoded_header.number)
+                {
+                    let forced_change = forced_change.take().unwrap();
+
+                    // The authorities set id of the new authorities is the one right after the
+                    // id in use at the reset height on this chain.
+                    let reset_authorities_set_id = self
+                        .parent_tree_index
+                        .into_iter()
+                        .flat_map(|parent| self.chain.blocks.node_to_root_path(parent))
+                        .map(|index| self.chain.blocks.get(index).unwrap())
+                        .find(|block| {
+                            header::decode(&block.header, self.chain.block_number_bytes)
+                                .unwrap()
+                                .number
+                                <= forced_change.reset_block_height
+                        })
+                        .map_or_else(
+                            || match self.chain.finality {
+                                Finality::Grandpa {
+                                    after_finalized_block_authorities_set_id,
+                                    ..
+                                } => after_finalized_block_authorities_set_id,
+                                Finality::Outsourced => unreachable!(),
+                            },
+                            |block| match block.finality {
+                                BlockFinality::Grandpa {
+                                    after_block_authorities_set_id,
+                                    ..
+                                } => after_block_authorities_set_id,
+                                BlockFinality::Outsourced => unreachable!(),
+                            },
+                        );
+
+                    triggers_change = false;
+                    triggered_authorities = forced_change.authorities;
+                    scheduled_change = None;
+                    after_block_authorities_set_id = reset_authorities_set_id + 1;
+                    prev_auth_change_trigger_number = prev_auth_change_trigger_number
+                        .filter(|n| *n <= forced_change.reset_block_height);
+                }
+
                 // Some sanity checks.
                 debug_assert!(scheduled_change
                     .as_ref()
                     .map(|(n, _)| *n > decoded_header.number)
                     .unwrap_or(true));
+                debug_assert!(forced_change
+                    .as_ref()
+                    .map(|c| c.trigger_block_height > decoded_header.number)
+                    .unwrap_or(true));
                 debug_assert!(parent_prev_auth_change_trigger_number
                     .as_ref()
                     .map(|n| *n < decoded_header.number)
                     .unwrap_or(true));
 
                 BlockFinality::Grandpa {
-                    prev_auth_change_trigger_number: if *parent_triggers_change {
-                        Some(decoded_header.number - 1)
-                    } else {
-                        *parent_prev_auth_change_trigger_number
-                    },
+                    prev_auth_change_trigger_number,
                     triggered_authorities,
                     scheduled_change,
+                    forced_change,
                     triggers_change,
-                    after_block_authorities_set_id: if triggers_change {
-                        *parent_after_block_authorities_set_id + 1
-                    } else {
-                        *parent_after_block_authorities_set_id
-                    },
+                    after_block_authorities_set_id,
                 }
             }
         };
@@ -1111,8 +1198,10 @@ pub struct RuntimeCompilation<T> {
 
 impl<T> RuntimeCompilation<T> {
     /// Performs the runtime compilation.
-    pub fn build(self) -> BodyVerifyStep2<T> {
-        let inner = self.inner.build();
+    ///
+    /// See [`vm::Config::compilation_cache`] for an explanation of `compilation_cache`.
+    pub fn build(self, compilation_cache: Option<&dyn vm::CompilationCache>) -> BodyVerifyStep2<T> {
+        let inner = self.inner.build(compilation_cache);
         self.context.with_body_verify(inner)
     }
 }
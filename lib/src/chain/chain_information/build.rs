@@ -449,6 +449,8 @@ impl ChainInformationBuild {
                 parameter: call.parameter_vectored(),
                 virtual_machine: inner.virtual_machine.take().unwrap(),
                 max_log_level: 0,
+                trace: false,
+                fuel_budget: None,
             });
 
             let vm = match vm_start_result {
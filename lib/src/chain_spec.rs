@@ -44,6 +44,7 @@ use crate::{
 
 use alloc::{
     boxed::Box,
+    collections::BTreeMap,
     string::{String, ToString as _},
     vec::Vec,
 };
@@ -65,11 +66,17 @@ impl ChainSpec {
             .map_err(ParseErrorInner::Serde)
             .map_err(ParseError)?;
 
-        // TODO: we don't support child tries in the genesis block
-        assert!(match &client_spec.genesis {
-            structs::Genesis::Raw(genesis) => genesis.children_default.is_empty(),
-            structs::Genesis::StateRootHash(_) => true,
-        });
+        // The entries of the main trie that reference child tries are implicit, and must not be
+        // found in the list of entries of the main trie.
+        if let structs::Genesis::Raw(genesis) = &client_spec.genesis {
+            if genesis
+                .top
+                .keys()
+                .any(|key| key.0.starts_with(b":child_storage:"))
+            {
+                return Err(ParseError(ParseErrorInner::ChildTrieEntryInTop));
+            }
+        }
 
         if client_spec.relay_chain.is_some() != client_spec.para_id.is_some() {
             return Err(ParseError(ParseErrorInner::Other));
@@ -110,6 +117,7 @@ impl ChainSpec {
             module: &wasm_code,
             heap_pages,
             exec_hint: executor::vm::ExecHint::Oneshot,
+            compilation_cache: None,
             allow_unresolved_imports: true,
         })
         .map_err(FromGenesisStorageError::VmInitialization)?;
@@ -123,33 +131,7 @@ impl ChainSpec {
                         .state_version
                         .unwrap_or(trie::TrieEntryVersion::V0);
 
-                    match self.genesis_storage() {
-                        GenesisStorage::TrieRootHash(hash) => *hash,
-                        GenesisStorage::Items(genesis_storage) => {
-                            let mut calculation = trie::calculate_root::root_merkle_value();
-
-                            loop {
-                                match calculation {
-                                    trie::calculate_root::RootMerkleValueCalculation::Finished {
-                                        hash,
-                                        ..
-                                    } => break hash,
-                                    trie::calculate_root::RootMerkleValueCalculation::NextKey(next_key) => {
-                                        // TODO: borrowchecker erroneously thinks that `outcome` borrows `next_key`
-                                        let outcome = genesis_storage.next_key(next_key.key_before(), next_key.or_equal(), next_key.prefix()).map(|k| k.collect::<Vec<_>>().into_iter());
-                                        calculation = next_key.inject_key(outcome);
-                                    }
-                                    trie::calculate_root::RootMerkleValueCalculation::StorageValue(
-                                        val,
-                                    ) => {
-                                        let key: alloc::vec::Vec<u8> = val.key().collect();
-                                        let value = genesis_storage.value(&key[..]);
-                                        calculation = val.inject(value.map(move |v| (v, state_version)));
-                                    }
-                                }
-                            }
-                        }
-                    }
+                    genesis_storage.root_hash(state_version)
                 },
             },
             block_number_bytes: usize::from(self.block_number_bytes()),
@@ -159,12 +141,34 @@ impl ChainSpec {
         let (chain_info, vm_prototype) = loop {
             match chain_information_build {
                 build::ChainInformationBuild::InProgress(build::InProgress::StorageGet(get)) => {
-                    // TODO: child tries not supported
-                    let value = genesis_storage.value(get.key().as_ref());
+                    let value = match get.child_trie() {
+                        Some(child_trie) => genesis_storage
+                            .child_trie_value(child_trie.as_ref(), get.key().as_ref()),
+                        None => genesis_storage.value(get.key().as_ref()),
+                    };
                     chain_information_build = get.inject_value(value.map(iter::once));
                 }
-                build::ChainInformationBuild::InProgress(build::InProgress::NextKey(_nk)) => {
-                    todo!() // TODO:
+                build::ChainInformationBuild::InProgress(build::InProgress::NextKey(nk)) => {
+                    // The genesis storage doesn't know about branch nodes.
+                    debug_assert!(!nk.branch_nodes());
+                    let next_key = match nk.child_trie() {
+                        Some(child_trie) => genesis_storage
+                            .child_trie_next_key(
+                                child_trie.as_ref(),
+                                nk.key().as_ref().iter().copied(),
+                                nk.or_equal(),
+                                nk.prefix().as_ref().iter().copied(),
+                            )
+                            .map(|k| k.collect::<Vec<_>>()),
+                        None => genesis_storage
+                            .next_key(
+                                nk.key().as_ref().iter().copied(),
+                                nk.or_equal(),
+                                nk.prefix().as_ref().iter().copied(),
+                            )
+                            .map(|k| k.collect::<Vec<_>>()),
+                    };
+                    chain_information_build = nk.inject_key(next_key);
                 }
                 build::ChainInformationBuild::Finished {
                     result: Err(err), ..
@@ -231,6 +235,23 @@ impl ChainSpec {
             .map(|h| &h.0)
     }
 
+    /// Returns the list of runtime codes that replace the on-chain runtime code, alongside with
+    /// the block number they are registered at.
+    ///
+    /// A substitute is used in place of the on-chain runtime code of all the blocks whose number
+    /// is superior or equal to the block number it is registered at, but only as long as the
+    /// `spec_version` ([`crate::executor::host::CoreVersionRef::spec_version`]) of the on-chain
+    /// runtime is equal to the `spec_version` of the substitute. In other words, a substitute
+    /// stops being used as soon as the runtime is upgraded on chain.
+    ///
+    /// Substitutes are typically used in order to fix bugs in the runtime of historical blocks.
+    pub fn code_substitutes(&'_ self) -> impl Iterator<Item = (u64, &'_ [u8])> + '_ {
+        self.client_spec
+            .code_substitutes
+            .iter()
+            .map(|(block_number, code)| (*block_number, &code.0[..]))
+    }
+
     /// Returns the list of bootnode addresses found in the chain spec.
     ///
     /// Bootnode addresses that have failed to be parsed are returned as well in the form of
@@ -384,11 +405,39 @@ pub struct GenesisStorageItems<'a> {
 }
 
 impl<'a> GenesisStorageItems<'a> {
-    /// Returns the list of storage keys and values of the genesis block.
+    /// Returns the list of storage keys and values of the main trie of the genesis block.
+    ///
+    /// > **Note**: The entries of the main trie that reference the roots of child tries, in
+    /// >           other words whose key starts with `:child_storage:default:`, aren't part of
+    /// >           this list. See [`GenesisStorageItems::child_trie_root_hash`].
     pub fn iter(&self) -> impl ExactSizeIterator<Item = (&[u8], &[u8])> + Clone {
         self.raw.top.iter().map(|(k, v)| (&k.0[..], &v.0[..]))
     }
 
+    /// Returns the list of identifiers of the child tries of the genesis block, without the
+    /// `:child_storage:default:` prefix.
+    ///
+    /// Child tries that don't contain any entry are ignored.
+    pub fn child_tries(&self) -> impl Iterator<Item = &[u8]> + Clone {
+        self.raw
+            .children_default
+            .iter()
+            .filter(|(_, entries)| !entries.is_empty())
+            .map(|(child_trie, _)| &child_trie.0[..])
+    }
+
+    /// Returns the list of storage keys and values of the given child trie of the genesis block.
+    ///
+    /// The list is empty if the child trie doesn't exist.
+    pub fn child_trie_iter(&self, child_trie: &[u8]) -> impl Iterator<Item = (&[u8], &[u8])> {
+        self.raw
+            .children_default
+            .get(child_trie)
+            .into_iter()
+            .flat_map(|entries| entries.iter())
+            .map(|(k, v)| (&k.0[..], &v.0[..]))
+    }
+
     /// Find the storage key that immediately follows `key_before` in the list of storage items.
     ///
     /// If `or_equal` is `true`, then `key_before` is returned if it corresponds to a key in the
@@ -422,6 +471,119 @@ impl<'a> GenesisStorageItems<'a> {
     pub fn value(&self, key: &[u8]) -> Option<&[u8]> {
         self.raw.top.get(key).map(|value| &value.0[..])
     }
+
+    /// Similar to [`GenesisStorageItems::next_key`], but for the given child trie.
+    ///
+    /// Returns `None` if the child trie doesn't exist.
+    pub fn child_trie_next_key(
+        &self,
+        child_trie: &[u8],
+        key_before: impl Iterator<Item = u8>,
+        or_equal: bool,
+        prefix: impl Iterator<Item = u8>,
+    ) -> Option<impl Iterator<Item = u8> + 'a> {
+        let lower_bound = if or_equal {
+            Bound::Included(structs::HexString(key_before.collect::<Vec<_>>()))
+        } else {
+            Bound::Excluded(structs::HexString(key_before.collect::<Vec<_>>()))
+        };
+
+        self.raw
+            .children_default
+            .get(child_trie)?
+            .range((lower_bound, Bound::Unbounded))
+            .next()
+            .filter(|(k, _)| k.0.iter().copied().zip(prefix).all(|(a, b)| a == b))
+            .map(|(k, _)| k.0.iter().copied())
+    }
+
+    /// Similar to [`GenesisStorageItems::value`], but for the given child trie.
+    ///
+    /// Returns `None` if the child trie doesn't exist.
+    pub fn child_trie_value(&self, child_trie: &[u8], key: &[u8]) -> Option<&[u8]> {
+        self.raw
+            .children_default
+            .get(child_trie)?
+            .get(key)
+            .map(|value| &value.0[..])
+    }
+
+    /// Calculates the hash of the root of the given child trie, in other words the value of the
+    /// entry of the main trie whose key is `:child_storage:default:` followed with the
+    /// identifier of the child trie.
+    ///
+    /// Returns `None` if the child trie doesn't exist or doesn't contain any entry.
+    pub fn child_trie_root_hash(
+        &self,
+        child_trie: &[u8],
+        version: trie::TrieEntryVersion,
+    ) -> Option<[u8; 32]> {
+        let entries = self.raw.children_default.get(child_trie)?;
+        if entries.is_empty() {
+            return None;
+        }
+
+        Some(trie_root_hash(
+            &entries.iter().map(|(k, v)| (&k.0[..], &v.0[..])).collect(),
+            version,
+        ))
+    }
+
+    /// Calculates the hash of the root of the main trie, including the entries that reference
+    /// child tries.
+    fn root_hash(&self, version: trie::TrieEntryVersion) -> [u8; 32] {
+        let child_tries = self
+            .child_tries()
+            .map(|child_trie| {
+                let mut key = trie::CHILD_STORAGE_PREFIX.to_vec();
+                key.extend_from_slice(child_trie);
+                (key, self.child_trie_root_hash(child_trie, version).unwrap())
+            })
+            .collect::<Vec<_>>();
+
+        trie_root_hash(
+            &self
+                .iter()
+                .chain(child_tries.iter().map(|(k, v)| (&k[..], &v[..])))
+                .collect(),
+            version,
+        )
+    }
+}
+
+/// Calculates the hash of the root of the trie containing the given entries.
+fn trie_root_hash(entries: &BTreeMap<&[u8], &[u8]>, version: trie::TrieEntryVersion) -> [u8; 32] {
+    let mut calculation = trie::calculate_root::root_merkle_value();
+
+    loop {
+        match calculation {
+            trie::calculate_root::RootMerkleValueCalculation::Finished { hash, .. } => break hash,
+            trie::calculate_root::RootMerkleValueCalculation::NextKey(next_key) => {
+                let key_before = next_key.key_before().collect::<Vec<_>>();
+                let lower_bound = if next_key.or_equal() {
+                    Bound::Included(&key_before[..])
+                } else {
+                    Bound::Excluded(&key_before[..])
+                };
+                let outcome = entries
+                    .range::<[u8], _>((lower_bound, Bound::Unbounded))
+                    .next()
+                    .filter(|(k, _)| {
+                        k.iter()
+                            .copied()
+                            .zip(next_key.prefix())
+                            .all(|(a, b)| a == b)
+                    })
+                    .map(|(k, _)| *k);
+                calculation = next_key.inject_key(outcome.map(|k| k.iter().copied()));
+            }
+            trie::calculate_root::RootMerkleValueCalculation::StorageValue(val) => {
+                let key = val.key().collect::<Vec<_>>();
+                let value = entries.get(&key[..]);
+                calculation = val.inject(value.map(move |v| (*v, version)));
+            }
+        }
+    }
 }
 
 pub struct LightSyncState {
@@ -525,6 +687,8 @@ pub struct ParseError(ParseErrorInner);
 #[derive(Debug, derive_more::Display)]
 enum ParseErrorInner {
     Serde(serde_json::Error),
+    /// The main trie of the genesis storage contains an entry that references a child trie.
+    ChildTrieEntryInTop,
     Other,
 }
 
@@ -563,6 +727,7 @@ pub enum CheckpointToChainInformationError {
 #[cfg(test)]
 mod tests {
     use super::{Bootnode, ChainSpec, CheckpointToChainInformationError};
+    use crate::trie;
 
     #[test]
     fn can_decode_polkadot_genesis() {
@@ -571,8 +736,10 @@ mod tests {
         assert_eq!(specs.id(), "polkadot");
 
         // code_substitutes field
-        assert_eq!(specs.client_spec.code_substitutes.get(&1), None);
-        assert!(specs.client_spec.code_substitutes.get(&5203203).is_some());
+        assert!(!specs.code_substitutes().any(|(n, _)| n == 1));
+        assert!(specs
+            .code_substitutes()
+            .any(|(n, code)| n == 5203203 && !code.is_empty()));
 
         // bootnodes field
         assert_eq!(
@@ -688,6 +855,106 @@ mod tests {
         .is_err());
     }
 
+    #[test]
+    fn child_tries_in_genesis() {
+        let without_child_tries =
+            ChainSpec::from_json_bytes(&include_bytes!("chain_spec/example.json")[..]).unwrap();
+
+        let with_child_tries = {
+            let mut json: serde_json::Value =
+                serde_json::from_slice(&include_bytes!("chain_spec/example.json")[..]).unwrap();
+            json["genesis"]["raw"]["childrenDefault"] = serde_json::json!({
+                "0x666f6f": { "0x01": "0x02", "0x0102": "0x03" },
+                "0x656d707479": {}
+            });
+            ChainSpec::from_json_bytes(serde_json::to_vec(&json).unwrap()).unwrap()
+        };
+
+        let genesis_storage = with_child_tries
+            .genesis_storage()
+            .into_genesis_items()
+            .unwrap();
+        assert_eq!(
+            genesis_storage.child_tries().collect::<Vec<_>>(),
+            vec![b"foo"]
+        );
+        assert_eq!(
+            genesis_storage.child_trie_value(b"foo", &[1, 2]),
+            Some(&[3][..])
+        );
+        assert_eq!(genesis_storage.child_trie_value(b"bar", &[1, 2]), None);
+        assert_eq!(
+            genesis_storage
+                .child_trie_next_key(b"foo", [1].into_iter(), false, [].into_iter())
+                .map(|k| k.collect::<Vec<_>>()),
+            Some(vec![1, 2])
+        );
+        assert!(genesis_storage
+            .child_trie_root_hash(b"empty", trie::TrieEntryVersion::V0)
+            .is_none());
+
+        // The state root of the genesis block must include the root of the child trie.
+        let child_trie_root = genesis_storage
+            .child_trie_root_hash(b"foo", trie::TrieEntryVersion::V0)
+            .unwrap();
+        assert_eq!(
+            child_trie_root,
+            trie::trie_root(
+                trie::TrieEntryVersion::V0,
+                &[(&[1][..], &[2][..]), (&[1, 2], &[3])]
+            )
+        );
+
+        let state_root = *with_child_tries
+            .to_chain_information()
+            .unwrap()
+            .0
+            .as_ref()
+            .finalized_block_header
+            .state_root;
+        assert_ne!(
+            state_root,
+            *without_child_tries
+                .to_chain_information()
+                .unwrap()
+                .0
+                .as_ref()
+                .finalized_block_header
+                .state_root
+        );
+        let mut main_trie = genesis_storage
+            .iter()
+            .map(|(k, v)| (k.to_vec(), v.to_vec()))
+            .collect::<Vec<_>>();
+        main_trie.push((
+            b":child_storage:default:foo".to_vec(),
+            child_trie_root.to_vec(),
+        ));
+        assert_eq!(
+            state_root,
+            trie::trie_root(trie::TrieEntryVersion::V0, &main_trie)
+        );
+    }
+
+    #[test]
+    fn child_trie_entry_in_top_refused() {
+        assert!(ChainSpec::from_json_bytes(
+            r#"{
+            "name": "Test",
+            "id": "test",
+            "bootNodes": [],
+            "genesis": {
+              "raw": {
+                "top": { "0x3a6368696c645f73746f726167653a64656661756c743a666f6f": "0x00" },
+                "childrenDefault": {}
+              }
+            }
+          }
+          "#,
+        )
+        .is_err());
+    }
+
     #[test]
     fn issue_598() {
         // Regression test for a panic.
@@ -43,7 +43,6 @@ pub(super) struct ClientSpec {
     /// the given block number until the `spec_version`
     /// ([`crate::executor::host::CoreVersionRef::spec_version`]) on chain changes.
     #[serde(default)]
-    // TODO: make use of this
     pub(super) code_substitutes: HashMap<u64, HexString, fnv::FnvBuildHasher>,
     pub(super) boot_nodes: Vec<String>,
     pub(super) telemetry_endpoints: Option<Vec<(String, u8)>>,
@@ -105,7 +104,9 @@ pub(super) enum Genesis {
 #[serde(deny_unknown_fields)]
 pub(super) struct RawGenesis {
     pub(super) top: BTreeMap<HexString, HexString>,
-    pub(super) children_default: BTreeMap<HexString, ChildRawStorage>,
+    /// Keys are the identifiers of the child tries, without the `:child_storage:default:`
+    /// prefix.
+    pub(super) children_default: BTreeMap<HexString, BTreeMap<HexString, HexString>>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -144,14 +145,6 @@ impl<'a> serde::Deserialize<'a> for HexString {
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
-#[serde(deny_unknown_fields)]
-pub(super) struct ChildRawStorage {
-    pub(super) child_info: Vec<u8>,
-    pub(super) child_type: u32,
-}
-
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub(super) struct HashHexString(pub(super) [u8; 32]);
 
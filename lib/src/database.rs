@@ -17,8 +17,33 @@
 
 //! Persistent data storage.
 //!
-//! This module contains sub-modules that provide different means of storing data in a
-//! persistent way.
+//! This module contains sub-modules that provide different means of storing data, most of them
+//! in a persistent way.
+
+use alloc::borrow::Cow;
 
 pub mod finalized_serialize;
+pub mod full_memory;
 pub mod full_sqlite;
+pub mod state_snapshot;
+
+/// Trie node to insert in a full database, as part of the storage of a block.
+///
+/// See [`full_memory::MemoryFullDatabase::insert`] and `full_sqlite::SqliteFullDatabase::insert`.
+pub struct InsertTrieNode<'a> {
+    pub merkle_value: Cow<'a, [u8]>,
+    pub partial_key_nibbles: Cow<'a, [u8]>,
+    pub children_merkle_values: [Option<Cow<'a, [u8]>>; 16],
+    pub storage_value: InsertTrieNodeStorageValue<'a>,
+}
+
+/// Storage value of an [`InsertTrieNode`].
+pub enum InsertTrieNodeStorageValue<'a> {
+    NoValue,
+    Value {
+        value: Cow<'a, [u8]>,
+        /// If `true`, the value is equal to the Merkle value of the root of another trie.
+        references_merkle_value: bool,
+    },
+    SameAsParent,
+}
@@ -0,0 +1,1238 @@
+// Smoldot
+// Copyright (C) 2023  Pierre Krieger
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Database containing all the information about a chain, stored entirely in memory.
+//!
+//! This module provides the same features as the `full_sqlite` module, except that nothing is
+//! ever written to the disk. Its content is lost when the [`MemoryFullDatabase`] is destroyed.
+//! It is mostly useful for testing purposes, or for short-lived nodes.
+//!
+//! # Usage
+//!
+//! Use [`MemoryFullDatabase::new`] to create a new database containing a single finalized
+//! block.
+//!
+//! Use [`MemoryFullDatabase::insert`] to insert a new block in the database. The block is assumed
+//! to have been successfully verified prior to insertion. An error is returned if this block is
+//! already in the database or isn't a descendant or ancestor of the latest finalized block.
+//!
+//! Use [`MemoryFullDatabase::set_finalized`] to mark a block already in the database as
+//! finalized. Any block that isn't an ancestor or descendant will be removed. Reverting
+//! finalization is not supported.
+//!
+//! Contrary to the SQLite database, accessing the database can never fail for reasons other
+//! than a misuse of the API, and the methods that only read from the database thus don't return
+//! any error.
+
+use crate::{chain::chain_information, header};
+
+use super::{InsertTrieNode, InsertTrieNodeStorageValue};
+use alloc::{
+    boxed::Box,
+    collections::{BTreeMap, BTreeSet},
+    vec,
+    vec::Vec,
+};
+use core::{cmp, fmt, iter, mem, ops};
+
+mod tests;
+
+/// Configuration for a new [`MemoryFullDatabase`].
+#[derive(Debug)]
+pub struct Config {
+    /// Number of bytes used to encode the block number.
+    pub block_number_bytes: usize,
+
+    /// If `true`, the database maintains an index of the extrinsics of the stored blocks by
+    /// hash. See [`MemoryFullDatabase::extrinsic_locations`].
+    pub extrinsics_index: bool,
+}
+
+/// Database stored in memory.
+pub struct MemoryFullDatabase {
+    /// See [`Config::block_number_bytes`].
+    block_number_bytes: usize,
+
+    /// List of all the blocks in the database.
+    blocks: BTreeMap<[u8; 32], Block>,
+
+    /// Same entries as [`MemoryFullDatabase::blocks`], indexed by block number.
+    blocks_by_number: BTreeSet<(u64, [u8; 32])>,
+
+    /// List of all the trie nodes of all the blocks, indexed by Merkle value. Trie nodes are
+    /// shared between blocks.
+    trie_nodes: BTreeMap<Vec<u8>, TrieNode>,
+
+    /// Hash of the best block.
+    best_block_hash: [u8; 32],
+
+    /// Hash of the latest finalized block.
+    finalized_block_hash: [u8; 32],
+
+    /// Number of the latest finalized block.
+    finalized_block_number: u64,
+
+    /// Consensus information of the latest finalized block.
+    consensus: chain_information::ChainInformationConsensus,
+
+    /// Finality information of the latest finalized block.
+    finality: chain_information::ChainInformationFinality,
+
+    /// List of extrinsic hashes, the hash of the block containing them, and the index of the
+    /// extrinsic within the body of that block. `None` if [`Config::extrinsics_index`] was
+    /// `false`.
+    extrinsics_index: Option<BTreeSet<ExtrinsicLocation>>,
+
+    /// Persistent storage of the off-chain workers.
+    offchain_storage: BTreeMap<Vec<u8>, Vec<u8>>,
+}
+
+/// Hash of an extrinsic, hash of the block containing it, and index of the extrinsic within
+/// the body of that block.
+type ExtrinsicLocation = ([u8; 32], [u8; 32], usize);
+
+struct Block {
+    number: u64,
+    parent_hash: [u8; 32],
+    state_trie_root_hash: [u8; 32],
+    scale_encoded_header: Vec<u8>,
+    body: Vec<Vec<u8>>,
+    justification: Option<Vec<u8>>,
+    /// `true` if the header of the block schedules or forces a change in the list of GrandPa
+    /// authorities.
+    grandpa_authorities_change: bool,
+}
+
+struct TrieNode {
+    /// Partial key of the node, as nibbles.
+    partial_key: Vec<u8>,
+    /// Merkle values of the children of the node.
+    children: [Option<Vec<u8>>; 16],
+    storage_value: StorageValue,
+}
+
+enum StorageValue {
+    None,
+    Value {
+        value: Vec<u8>,
+        trie_entry_version: u8,
+    },
+    /// The storage value is the Merkle value of the root of another trie, also found in
+    /// [`MemoryFullDatabase::trie_nodes`].
+    TrieRootRef {
+        merkle_value: Vec<u8>,
+        trie_entry_version: u8,
+    },
+}
+
+/// Nibble that, when found in a key, indicates that the search must continue in the trie whose
+/// root is referenced by the storage value of the current node.
+const TRIE_ROOT_REF_NIBBLE: u8 = 0x10;
+
+impl MemoryFullDatabase {
+    /// Creates a new database whose finalized block is described by the given
+    /// [`chain_information::ChainInformationRef`].
+    ///
+    /// Must also pass the body, justification, and state of the storage of the finalized block.
+    ///
+    /// # Panic
+    ///
+    /// Panics if any of the trie nodes has a [`InsertTrieNodeStorageValue::SameAsParent`]
+    /// storage value, as the finalized block has no parent in the database.
+    ///
+    pub fn new<'a>(
+        config: Config,
+        chain_information: impl Into<chain_information::ChainInformationRef<'a>>,
+        finalized_block_body: impl ExactSizeIterator<Item = &'a [u8]>,
+        finalized_block_justification: Option<Vec<u8>>,
+        finalized_block_storage_entries: impl Iterator<Item = InsertTrieNode<'a>>,
+        finalized_block_state_version: u8,
+    ) -> Self {
+        let chain_information: chain_information::ChainInformation =
+            chain_information.into().into();
+
+        let finalized_block_hash = chain_information
+            .finalized_block_header
+            .hash(config.block_number_bytes);
+        let finalized_block_number = chain_information.finalized_block_header.number;
+
+        let mut database = MemoryFullDatabase {
+            block_number_bytes: config.block_number_bytes,
+            blocks: BTreeMap::new(),
+            blocks_by_number: BTreeSet::new(),
+            trie_nodes: BTreeMap::new(),
+            best_block_hash: finalized_block_hash,
+            finalized_block_hash,
+            finalized_block_number,
+            consensus: chain_information.consensus,
+            finality: chain_information.finality,
+            extrinsics_index: if config.extrinsics_index {
+                Some(BTreeSet::new())
+            } else {
+                None
+            },
+            offchain_storage: BTreeMap::new(),
+        };
+
+        database.insert_storage(
+            None,
+            &chain_information.finalized_block_header.state_root,
+            finalized_block_storage_entries,
+            finalized_block_state_version,
+        );
+
+        database.insert_block(
+            finalized_block_hash,
+            Block {
+                number: finalized_block_number,
+                parent_hash: chain_information.finalized_block_header.parent_hash,
+                state_trie_root_hash: chain_information.finalized_block_header.state_root,
+                scale_encoded_header: chain_information
+                    .finalized_block_header
+                    .scale_encoding_vec(config.block_number_bytes),
+                body: finalized_block_body.map(|ext| ext.to_vec()).collect(),
+                justification: finalized_block_justification,
+                grandpa_authorities_change: header::DigestRef::from(
+                    &chain_information.finalized_block_header.digest,
+                )
+                .has_grandpa_authorities_change(),
+            },
+        );
+
+        database
+    }
+
+    /// Returns the hash of the block in the database whose storage is currently accessible.
+    pub fn best_block_hash(&self) -> [u8; 32] {
+        self.best_block_hash
+    }
+
+    /// Returns the hash of the finalized block in the database.
+    pub fn finalized_block_hash(&self) -> [u8; 32] {
+        self.finalized_block_hash
+    }
+
+    /// Returns the SCALE-encoded header of the given block, or `None` if the block is unknown.
+    pub fn block_scale_encoded_header(&self, block_hash: &[u8; 32]) -> Option<&[u8]> {
+        self.blocks
+            .get(block_hash)
+            .map(|block| &block.scale_encoded_header[..])
+    }
+
+    /// Returns the list of extrinsics of the given block, or `None` if the block is unknown.
+    ///
+    /// > **Note**: The list of extrinsics of a block is also known as its *body*.
+    pub fn block_extrinsics(
+        &self,
+        block_hash: &[u8; 32],
+    ) -> Option<impl ExactSizeIterator<Item = &[u8]> + '_> {
+        self.blocks
+            .get(block_hash)
+            .map(|block| block.body.iter().map(|ext| &ext[..]))
+    }
+
+    /// Returns the list of blocks whose body contains an extrinsic with the given hash, alongside
+    /// with the index of the extrinsic within each body.
+    ///
+    /// The hash of an extrinsic is the BLAKE2-256 hash of its SCALE encoding.
+    ///
+    /// Returns `None` if the database doesn't maintain an index of extrinsics. See
+    /// [`Config::extrinsics_index`].
+    pub fn extrinsic_locations(&self, extrinsic_hash: &[u8; 32]) -> Option<Vec<([u8; 32], usize)>> {
+        let index = self.extrinsics_index.as_ref()?;
+        Some(
+            index
+                .range((*extrinsic_hash, [0; 32], 0)..=(*extrinsic_hash, [0xff; 32], usize::MAX))
+                .map(|(_, block_hash, index)| (*block_hash, *index))
+                .collect(),
+        )
+    }
+
+    /// Returns the justification stored alongside with the given block, or `None` if the block
+    /// is unknown or if no justification is stored for it.
+    pub fn block_justification(&self, block_hash: &[u8; 32]) -> Option<&[u8]> {
+        self.blocks
+            .get(block_hash)
+            .and_then(|block| block.justification.as_deref())
+    }
+
+    /// Stores a justification alongside with the given block, replacing the one that was
+    /// previously stored, if any.
+    ///
+    /// The block must have been previously inserted using [`MemoryFullDatabase::insert`] and
+    /// must be finalized, otherwise an error is returned.
+    ///
+    /// The justification is expected to be valid. No verification is performed.
+    pub fn set_block_justification(
+        &mut self,
+        block_hash: &[u8; 32],
+        justification: &[u8],
+    ) -> Result<(), SetJustificationError> {
+        let block = self
+            .blocks
+            .get_mut(block_hash)
+            .ok_or(SetJustificationError::UnknownBlock)?;
+
+        if block.number > self.finalized_block_number {
+            return Err(SetJustificationError::NotFinalized);
+        }
+
+        block.justification = Some(justification.to_vec());
+        Ok(())
+    }
+
+    /// Returns the hashes of the finalized blocks whose number is strictly superior to
+    /// `after_block_number` and that have a justification stored alongside with them, ordered
+    /// by increasing block number.
+    pub fn finalized_justified_blocks(&self, after_block_number: u64) -> Vec<[u8; 32]> {
+        if after_block_number >= self.finalized_block_number {
+            return Vec::new();
+        }
+
+        self.blocks_by_number
+            .range((
+                ops::Bound::Excluded((after_block_number, [0xff; 32])),
+                ops::Bound::Included((self.finalized_block_number, [0xff; 32])),
+            ))
+            .filter(|(_, hash)| self.blocks[hash].justification.is_some())
+            .map(|(_, hash)| *hash)
+            .collect()
+    }
+
+    /// Returns the hashes of the finalized blocks whose number is strictly superior to
+    /// `after_block_number` and whose header schedules or forces a change in the list of GrandPa
+    /// authorities, ordered by increasing block number.
+    pub fn finalized_grandpa_authorities_changes(&self, after_block_number: u64) -> Vec<[u8; 32]> {
+        if after_block_number >= self.finalized_block_number {
+            return Vec::new();
+        }
+
+        self.blocks_by_number
+            .range((
+                ops::Bound::Excluded((after_block_number, [0xff; 32])),
+                ops::Bound::Included((self.finalized_block_number, [0xff; 32])),
+            ))
+            .filter(|(_, hash)| self.blocks[hash].grandpa_authorities_change)
+            .map(|(_, hash)| *hash)
+            .collect()
+    }
+
+    /// Returns the hash of the finalized block with the highest number that is strictly
+    /// superior to `after_block_number`, strictly inferior to `before_block_number`, and that has
+    /// a justification stored alongside with it.
+    pub fn latest_finalized_justified_block(
+        &self,
+        after_block_number: u64,
+        before_block_number: u64,
+    ) -> Option<[u8; 32]> {
+        let upper_bound = before_block_number.min(self.finalized_block_number.saturating_add(1));
+        if after_block_number >= upper_bound {
+            return None;
+        }
+
+        self.blocks_by_number
+            .range((
+                ops::Bound::Excluded((after_block_number, [0xff; 32])),
+                ops::Bound::Excluded((upper_bound, [0; 32])),
+            ))
+            .rev()
+            .find(|(_, hash)| self.blocks[hash].justification.is_some())
+            .map(|(_, hash)| *hash)
+    }
+
+    /// Returns the hashes of the blocks given a block number.
+    pub fn block_hash_by_number(&self, block_number: u64) -> impl Iterator<Item = [u8; 32]> + '_ {
+        self.blocks_by_number
+            .range((block_number, [0; 32])..=(block_number, [0xff; 32]))
+            .map(|(_, hash)| *hash)
+    }
+
+    /// Returns the value associated to the given key in the storage of the off-chain workers,
+    /// or `None` if there is no such value.
+    pub fn offchain_storage_get(&self, key: &[u8]) -> Option<&[u8]> {
+        self.offchain_storage.get(key).map(|v| &v[..])
+    }
+
+    /// Sets the value associated to the given key in the storage of the off-chain workers, or
+    /// removes it if `new_value` is `None`.
+    ///
+    /// If `old_value` is `Some`, the modification is only performed if the value currently
+    /// associated to the key matches `old_value`, where `Some(None)` means that no value must
+    /// currently be associated to the key.
+    ///
+    /// Returns `true` if the modification has been performed.
+    pub fn offchain_storage_compare_and_set(
+        &mut self,
+        key: &[u8],
+        old_value: Option<Option<&[u8]>>,
+        new_value: Option<&[u8]>,
+    ) -> bool {
+        if let Some(old_value) = old_value {
+            if self.offchain_storage_get(key) != old_value {
+                return false;
+            }
+        }
+
+        match new_value {
+            Some(new_value) => {
+                self.offchain_storage
+                    .insert(key.to_vec(), new_value.to_vec());
+            }
+            None => {
+                self.offchain_storage.remove(key);
+            }
+        }
+
+        true
+    }
+
+    /// Returns a [`chain_information::ChainInformation`] struct containing the information about
+    /// the current finalized state of the chain.
+    ///
+    /// In order to mirror the API of the SQLite database, the known finalized block hash must be
+    /// passed as parameter. If the finalized block in the database doesn't match the hash passed
+    /// as parameter, a [`StorageAccessError::Pruned`] error is returned.
+    pub fn to_chain_information(
+        &self,
+        finalized_block_hash: &[u8; 32],
+    ) -> Result<chain_information::ValidChainInformation, StorageAccessError> {
+        if self.finalized_block_hash != *finalized_block_hash {
+            return Err(StorageAccessError::Pruned);
+        }
+
+        let finalized_block_header = header::decode(
+            &self.blocks[finalized_block_hash].scale_encoded_header,
+            self.block_number_bytes,
+        )
+        .unwrap_or_else(|_| unreachable!());
+
+        // The consensus and finality information are only ever updated from the headers of
+        // blocks that have been verified before being inserted, and thus always remain valid.
+        let chain_information = chain_information::ValidChainInformation::try_from(
+            chain_information::ChainInformation {
+                finalized_block_header: Box::new(finalized_block_header.into()),
+                consensus: self.consensus.clone(),
+                finality: self.finality.clone(),
+            },
+        )
+        .unwrap();
+        Ok(chain_information)
+    }
+
+    /// Insert a new block in the database.
+    ///
+    /// Must pass the header and body of the block, and the changes to the storage that this block
+    /// performs relative to its parent.
+    ///
+    /// Blocks must be inserted in the correct order. An error is returned if the parent of the
+    /// newly-inserted block isn't present in the database.
+    pub fn insert<'a>(
+        &mut self,
+        scale_encoded_header: &[u8],
+        is_new_best: bool,
+        body: impl ExactSizeIterator<Item = impl AsRef<[u8]>>,
+        new_trie_nodes: impl Iterator<Item = InsertTrieNode<'a>>,
+        trie_entries_version: u8,
+    ) -> Result<(), InsertError> {
+        let block_hash = header::hash_from_scale_encoded_header(scale_encoded_header);
+        let header = header::decode(scale_encoded_header, self.block_number_bytes)
+            .map_err(InsertError::BadHeader)?;
+
+        if self.blocks.contains_key(&block_hash) {
+            return Err(InsertError::Duplicate);
+        }
+
+        let Some(parent) = self.blocks.get(header.parent_hash)
+            else { return Err(InsertError::MissingParent) };
+        let parent_state_trie_root_hash = parent.state_trie_root_hash;
+
+        // If the height of the block to insert is <= the latest finalized, it doesn't
+        // belong to the finalized chain and would be pruned.
+        if header.number <= self.finalized_block_number {
+            return Err(InsertError::FinalizedNephew);
+        }
+
+        self.insert_storage(
+            Some(&parent_state_trie_root_hash),
+            header.state_root,
+            new_trie_nodes,
+            trie_entries_version,
+        );
+
+        self.insert_block(
+            block_hash,
+            Block {
+                number: header.number,
+                parent_hash: *header.parent_hash,
+                state_trie_root_hash: *header.state_root,
+                scale_encoded_header: scale_encoded_header.to_vec(),
+                body: body.map(|ext| ext.as_ref().to_vec()).collect(),
+                justification: None,
+                grandpa_authorities_change: header.digest.has_grandpa_authorities_change(),
+            },
+        );
+
+        if is_new_best {
+            self.best_block_hash = block_hash;
+        }
+
+        Ok(())
+    }
+
+    /// Changes the finalized block to the given one.
+    ///
+    /// The block must have been previously inserted using [`MemoryFullDatabase::insert`],
+    /// otherwise an error is returned.
+    ///
+    /// The block must be a descendant of the current finalized block. Reverting finalization is
+    /// forbidden, as the database intentionally discards some information when finality is
+    /// applied.
+    pub fn set_finalized(
+        &mut self,
+        new_finalized_block_hash: &[u8; 32],
+    ) -> Result<(), SetFinalizedError> {
+        let new_finalized_number = self
+            .blocks
+            .get(new_finalized_block_hash)
+            .ok_or(SetFinalizedError::UnknownBlock)?
+            .number;
+
+        // Considering that the database only contains one block per height on the finalized
+        // chain, and that the presence of the block to finalize in the database has already been
+        // verified, it is guaranteed that the block to finalize is already the one finalized.
+        if new_finalized_number == self.finalized_block_number {
+            return Ok(());
+        }
+
+        if new_finalized_number < self.finalized_block_number {
+            return Err(SetFinalizedError::RevertForbidden);
+        }
+
+        let current_finalized_number = self.finalized_block_number;
+        let mut pruned_any = false;
+
+        // For each block height between the old finalized and new finalized, remove all blocks
+        // except the one in the new finalized chain.
+        let mut newly_finalized = Vec::with_capacity(
+            usize::try_from(new_finalized_number - current_finalized_number).unwrap_or(0),
+        );
+        {
+            let mut expected_hash = *new_finalized_block_hash;
+            for height in (current_finalized_number + 1..=new_finalized_number).rev() {
+                for hash_at_height in self.block_hash_by_number(height).collect::<Vec<_>>() {
+                    if hash_at_height != expected_hash {
+                        self.purge_block(&hash_at_height);
+                        pruned_any = true;
+                    }
+                }
+
+                newly_finalized.push(expected_hash);
+                expected_hash = self.blocks[&expected_hash].parent_hash;
+            }
+        }
+
+        // Take each block height starting from the new finalized block and remove blocks that
+        // aren't a descendant of it.
+        let mut allowed_parents = vec![*new_finalized_block_hash];
+        for height in new_finalized_number + 1.. {
+            let blocks_list = self.block_hash_by_number(height).collect::<Vec<_>>();
+            if blocks_list.is_empty() {
+                break;
+            }
+
+            let mut next_iter_allowed_parents = Vec::with_capacity(allowed_parents.len());
+            for block_hash in blocks_list {
+                if allowed_parents.contains(&self.blocks[&block_hash].parent_hash) {
+                    next_iter_allowed_parents.push(block_hash);
+                } else {
+                    self.purge_block(&block_hash);
+                    pruned_any = true;
+                }
+            }
+
+            allowed_parents = next_iter_allowed_parents;
+        }
+
+        if !self.blocks.contains_key(&self.best_block_hash) {
+            self.best_block_hash = *new_finalized_block_hash;
+        }
+
+        // Now update the consensus and finality information by applying the digests of the
+        // newly-finalized blocks in order.
+        for block_hash in newly_finalized.into_iter().rev() {
+            let block = &self.blocks[&block_hash];
+            let block_header = header::decode(&block.scale_encoded_header, self.block_number_bytes)
+                .unwrap_or_else(|_| unreachable!());
+            apply_finalized_digest(&mut self.consensus, &mut self.finality, &block_header);
+        }
+
+        self.finalized_block_hash = *new_finalized_block_hash;
+        self.finalized_block_number = new_finalized_number;
+
+        // Trie nodes that were only used by the blocks that have been removed are now unused.
+        if pruned_any {
+            self.collect_unused_trie_nodes();
+        }
+
+        Ok(())
+    }
+
+    /// Returns the value associated with a node of the trie of the given block.
+    ///
+    /// `parent_tries_paths_nibbles` is a list of keys to follow in order to find the root of the
+    /// trie into which `key_nibbles` should be searched.
+    ///
+    /// Beware that both `parent_tries_paths_nibbles` and `key_nibbles` must yield *nibbles*, in
+    /// other words values strictly inferior to 16.
+    ///
+    /// Returns an error if the block or its storage can't be found in the database.
+    ///
+    /// # Panic
+    ///
+    /// Panics if any of the values yielded by `parent_tries_paths_nibbles` or `key_nibbles` is
+    /// superior or equal to 16.
+    ///
+    pub fn block_storage_get(
+        &self,
+        block_hash: &[u8; 32],
+        parent_tries_paths_nibbles: impl Iterator<Item = impl Iterator<Item = u8>>,
+        key_nibbles: impl Iterator<Item = u8>,
+    ) -> Result<Option<(&[u8], u8)>, StorageAccessError> {
+        let state_trie_root_hash = self.block_state_trie_root_hash(block_hash)?;
+        let key = vectored_key(parent_tries_paths_nibbles, key_nibbles);
+
+        Ok(
+            match self
+                .find_node(state_trie_root_hash, &key)
+                .map(|n| &n.storage_value)
+            {
+                Some(StorageValue::Value {
+                    value,
+                    trie_entry_version,
+                }) => Some((&value[..], *trie_entry_version)),
+                Some(StorageValue::TrieRootRef {
+                    merkle_value,
+                    trie_entry_version,
+                }) => Some((&merkle_value[..], *trie_entry_version)),
+                Some(StorageValue::None) | None => None,
+            },
+        )
+    }
+
+    /// Returns the key in the storage that immediately follows or is equal to the key passed as
+    /// parameter in the storage of the block.
+    ///
+    /// `key_nibbles` must be an iterator to the **nibbles** of the key.
+    ///
+    /// `prefix_nibbles` must be an iterator to nibbles. If the result of the function wouldn't
+    /// start with this specific list of bytes, `None` is returned.
+    ///
+    /// `parent_tries_paths_nibbles` is a list of keys to follow in order to find the root of the
+    /// trie into which `key_nibbles` should be searched.
+    ///
+    /// Returns `None` if `parent_tries_paths_nibbles` didn't lead to any trie, or if there is no
+    /// next key.
+    ///
+    /// The key is returned in the same format as `key_nibbles`.
+    ///
+    /// If `branch_nodes` is `false`, then branch nodes (i.e. nodes with no value associated to
+    /// them) are ignored during the search.
+    ///
+    /// > **Note**: Similar to the SQLite database, there is no `or_equal` parameter to this
+    /// >           function. Instead, `or_equal` is implicitly `true`, and a value of `false` can
+    /// >           be easily emulated by appending a `0` at the end of `key_nibbles`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any of the values yielded by `parent_tries_paths_nibbles`, `key_nibbles`, or
+    /// `prefix_nibbles` is superior or equal to 16.
+    ///
+    pub fn block_storage_next_key(
+        &self,
+        block_hash: &[u8; 32],
+        parent_tries_paths_nibbles: impl Iterator<Item = impl Iterator<Item = u8>>,
+        key_nibbles: impl Iterator<Item = u8>,
+        prefix_nibbles: impl Iterator<Item = u8>,
+        branch_nodes: bool,
+    ) -> Result<Option<Vec<u8>>, StorageAccessError> {
+        let state_trie_root_hash = self.block_state_trie_root_hash(block_hash)?;
+
+        let parent_tries_paths_nibbles = vectored_key(parent_tries_paths_nibbles, iter::empty());
+        let key = {
+            let mut v = parent_tries_paths_nibbles.clone();
+            v.extend(key_nibbles.inspect(|n| assert!(*n < 16)));
+            v
+        };
+        let prefix = {
+            let mut v = parent_tries_paths_nibbles.clone();
+            v.extend(prefix_nibbles.inspect(|n| assert!(*n < 16)));
+            v
+        };
+
+        let Some(root_node) = self.trie_nodes.get(&state_trie_root_hash[..])
+            else { return Ok(None) };
+
+        let next_key = self.next_key_in_subtree(root_node, Vec::new(), &key, branch_nodes);
+
+        Ok(next_key
+            .filter(|next_key| next_key.starts_with(&prefix))
+            .map(|next_key| next_key[parent_tries_paths_nibbles.len()..].to_vec()))
+    }
+
+    /// Returns the Merkle value of the trie node in the storage that is the closest descendant
+    /// of the provided key.
+    ///
+    /// `key_nibbles` must be an iterator to the **nibbles** of the key.
+    ///
+    /// `parent_tries_paths_nibbles` is a list of keys to follow in order to find the root of the
+    /// trie into which `key_nibbles` should be searched.
+    ///
+    /// Returns `None` if `parent_tries_paths_nibbles` didn't lead to any trie, or if there is no
+    /// such descendant.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any of the values yielded by `parent_tries_paths_nibbles` or `key_nibbles` is
+    /// superior or equal to 16.
+    ///
+    pub fn block_storage_closest_descendant_merkle_value(
+        &self,
+        block_hash: &[u8; 32],
+        parent_tries_paths_nibbles: impl Iterator<Item = impl Iterator<Item = u8>>,
+        key_nibbles: impl Iterator<Item = u8>,
+    ) -> Result<Option<&[u8]>, StorageAccessError> {
+        let state_trie_root_hash = self.block_state_trie_root_hash(block_hash)?;
+        let key = vectored_key(parent_tries_paths_nibbles, key_nibbles);
+
+        let mut current_merkle_value = &state_trie_root_hash[..];
+        let mut remaining_key = &key[..];
+
+        loop {
+            let Some((merkle_value, node)) = self.trie_nodes.get_key_value(current_merkle_value)
+                else { return Ok(None) };
+
+            if node.partial_key.starts_with(remaining_key) {
+                return Ok(Some(&merkle_value[..]));
+            }
+
+            let Some(after_partial_key) = remaining_key.strip_prefix(&node.partial_key[..])
+                else { return Ok(None) };
+
+            match self.follow_nibble(node, after_partial_key[0]) {
+                Some(next) => current_merkle_value = next,
+                None => return Ok(None),
+            }
+            remaining_key = &after_partial_key[1..];
+        }
+    }
+
+    /// Returns the state trie root hash of the given block, after making sure that the root node
+    /// is still in the database.
+    fn block_state_trie_root_hash(
+        &self,
+        block_hash: &[u8; 32],
+    ) -> Result<&[u8; 32], StorageAccessError> {
+        let block = self
+            .blocks
+            .get(block_hash)
+            .ok_or(StorageAccessError::UnknownBlock)?;
+        if !self
+            .trie_nodes
+            .contains_key(&block.state_trie_root_hash[..])
+        {
+            return Err(StorageAccessError::Pruned);
+        }
+        Ok(&block.state_trie_root_hash)
+    }
+
+    /// Returns the node of the trie whose root is `root_merkle_value` and whose key is `key`.
+    ///
+    /// `key` can contain [`TRIE_ROOT_REF_NIBBLE`]s in order to continue the search in the trie
+    /// referenced by the storage value of a node.
+    fn find_node(&self, root_merkle_value: &[u8], key: &[u8]) -> Option<&TrieNode> {
+        let mut node = self.trie_nodes.get(root_merkle_value)?;
+        let mut remaining_key = key;
+
+        loop {
+            remaining_key = remaining_key.strip_prefix(&node.partial_key[..])?;
+            let (nibble, rest) = match remaining_key.split_first() {
+                Some(split) => split,
+                None => return Some(node),
+            };
+            node = self.trie_nodes.get(self.follow_nibble(node, *nibble)?)?;
+            remaining_key = rest;
+        }
+    }
+
+    /// Returns the Merkle value of the node to go to when `nibble` is the next nibble of the key
+    /// being searched after the partial key of `node`.
+    fn follow_nibble<'n>(&self, node: &'n TrieNode, nibble: u8) -> Option<&'n [u8]> {
+        if nibble == TRIE_ROOT_REF_NIBBLE {
+            match &node.storage_value {
+                StorageValue::TrieRootRef { merkle_value, .. } => Some(&merkle_value[..]),
+                _ => None,
+            }
+        } else {
+            node.children[usize::from(nibble)].as_deref()
+        }
+    }
+
+    /// Returns the smallest key, among the node and its descendants, that is superior or equal
+    /// to `key`. `node_key_before` is the key of the parent of `node` followed with the index of
+    /// `node` within its parent. `key` is relative to `node_key_before`.
+    fn next_key_in_subtree(
+        &self,
+        node: &TrieNode,
+        node_key_before: Vec<u8>,
+        key: &[u8],
+        branch_nodes: bool,
+    ) -> Option<Vec<u8>> {
+        let common_len = cmp::min(key.len(), node.partial_key.len());
+        let remaining_key = match key[..common_len].cmp(&node.partial_key[..common_len]) {
+            // All the keys in this subtree are inferior to the key.
+            cmp::Ordering::Greater => return None,
+            // All the keys in this subtree are superior to the key.
+            cmp::Ordering::Less => &[][..],
+            cmp::Ordering::Equal if key.len() <= node.partial_key.len() => &[][..],
+            cmp::Ordering::Equal => &key[node.partial_key.len()..],
+        };
+
+        let mut node_key = node_key_before;
+        node_key.extend_from_slice(&node.partial_key);
+
+        let Some((&first_nibble, after_first_nibble)) = remaining_key.split_first() else {
+            // The node itself is superior or equal to the key, and is thus the smallest key of
+            // this subtree unless it must be skipped.
+            if branch_nodes || !matches!(node.storage_value, StorageValue::None) {
+                return Some(node_key);
+            }
+
+            return node
+                .children
+                .iter()
+                .enumerate()
+                .find_map(|(child_index, child)| {
+                    let child = self.trie_nodes.get(child.as_ref()?)?;
+                    let mut child_key_before = node_key.clone();
+                    child_key_before.push(u8::try_from(child_index).unwrap());
+                    self.next_key_in_subtree(child, child_key_before, &[], branch_nodes)
+                });
+        };
+
+        // The trie referenced by the storage value of a node is only ever searched if the key
+        // explicitly points to it.
+        if first_nibble == TRIE_ROOT_REF_NIBBLE {
+            let child = self
+                .trie_nodes
+                .get(self.follow_nibble(node, first_nibble)?)?;
+            let mut child_key_before = node_key;
+            child_key_before.push(TRIE_ROOT_REF_NIBBLE);
+            return self.next_key_in_subtree(
+                child,
+                child_key_before,
+                after_first_nibble,
+                branch_nodes,
+            );
+        }
+
+        (usize::from(first_nibble)..16).find_map(|child_index| {
+            let child = self.trie_nodes.get(node.children[child_index].as_ref()?)?;
+            let mut child_key_before = node_key.clone();
+            child_key_before.push(u8::try_from(child_index).unwrap());
+            let child_key = if child_index == usize::from(first_nibble) {
+                after_first_nibble
+            } else {
+                &[]
+            };
+            self.next_key_in_subtree(child, child_key_before, child_key, branch_nodes)
+        })
+    }
+
+    fn insert_block(&mut self, block_hash: [u8; 32], block: Block) {
+        if let Some(index) = &mut self.extrinsics_index {
+            for (extrinsic_index, extrinsic) in block.body.iter().enumerate() {
+                let mut extrinsic_hash = [0; 32];
+                extrinsic_hash
+                    .copy_from_slice(blake2_rfc::blake2b::blake2b(32, &[], extrinsic).as_bytes());
+                index.insert((extrinsic_hash, block_hash, extrinsic_index));
+            }
+        }
+
+        self.blocks_by_number.insert((block.number, block_hash));
+        self.blocks.insert(block_hash, block);
+    }
+
+    /// Removes the given block from the database. Does not remove its trie nodes.
+    fn purge_block(&mut self, block_hash: &[u8; 32]) {
+        let Some(block) = self.blocks.remove(block_hash)
+            else { return };
+        self.blocks_by_number.remove(&(block.number, *block_hash));
+
+        if let Some(index) = &mut self.extrinsics_index {
+            for (extrinsic_index, extrinsic) in block.body.iter().enumerate() {
+                let mut extrinsic_hash = [0; 32];
+                extrinsic_hash
+                    .copy_from_slice(blake2_rfc::blake2b::blake2b(32, &[], extrinsic).as_bytes());
+                index.remove(&(extrinsic_hash, *block_hash, extrinsic_index));
+            }
+        }
+    }
+
+    /// Removes from [`MemoryFullDatabase::trie_nodes`] all the nodes that can't be reached from
+    /// the state trie root of any block.
+    ///
+    /// The cost of this operation is proportional to the total number of trie nodes, but it is
+    /// only performed when blocks are removed, which rarely happens.
+    fn collect_unused_trie_nodes(&mut self) {
+        let mut reachable = BTreeSet::new();
+        let mut to_visit = self
+            .blocks
+            .values()
+            .map(|block| &block.state_trie_root_hash[..])
+            .collect::<Vec<_>>();
+
+        while let Some(merkle_value) = to_visit.pop() {
+            let Some((merkle_value, node)) = self.trie_nodes.get_key_value(merkle_value)
+                else { continue };
+            if !reachable.insert(&merkle_value[..]) {
+                continue;
+            }
+
+            to_visit.extend(node.children.iter().filter_map(|c| c.as_deref()));
+            if let StorageValue::TrieRootRef { merkle_value, .. } = &node.storage_value {
+                to_visit.push(merkle_value);
+            }
+        }
+
+        let reachable = reachable
+            .into_iter()
+            .map(|mv| mv.to_vec())
+            .collect::<BTreeSet<_>>();
+        self.trie_nodes.retain(|mv, _| reachable.contains(mv));
+    }
+
+    /// Inserts the given trie nodes in [`MemoryFullDatabase::trie_nodes`].
+    ///
+    /// The trie nodes whose storage value is [`InsertTrieNodeStorageValue::SameAsParent`] copy
+    /// the storage value found at the same key in the trie of `parent_state_trie_root_hash`.
+    fn insert_storage<'a>(
+        &mut self,
+        parent_state_trie_root_hash: Option<&[u8; 32]>,
+        state_trie_root_hash: &[u8; 32],
+        new_trie_nodes: impl Iterator<Item = InsertTrieNode<'a>>,
+        entries_version: u8,
+    ) {
+        let mut new_nodes = BTreeSet::new();
+        let mut pending_parent_copies = BTreeSet::new();
+
+        for trie_node in new_trie_nodes {
+            assert!(trie_node.partial_key_nibbles.iter().all(|n| *n < 16));
+
+            // Trie nodes are identified by their Merkle value, and a node that is already in the
+            // database is thus identical to the one being inserted.
+            let merkle_value = trie_node.merkle_value.into_owned();
+            if self.trie_nodes.contains_key(&merkle_value) {
+                continue;
+            }
+
+            let storage_value = match trie_node.storage_value {
+                InsertTrieNodeStorageValue::Value {
+                    value,
+                    references_merkle_value: false,
+                } => StorageValue::Value {
+                    value: value.into_owned(),
+                    trie_entry_version: entries_version,
+                },
+                InsertTrieNodeStorageValue::Value {
+                    value,
+                    references_merkle_value: true,
+                } => StorageValue::TrieRootRef {
+                    merkle_value: value.into_owned(),
+                    trie_entry_version: entries_version,
+                },
+                InsertTrieNodeStorageValue::SameAsParent => {
+                    assert!(parent_state_trie_root_hash.is_some());
+                    pending_parent_copies.insert(merkle_value.clone());
+                    StorageValue::None
+                }
+                InsertTrieNodeStorageValue::NoValue => StorageValue::None,
+            };
+
+            self.trie_nodes.insert(
+                merkle_value.clone(),
+                TrieNode {
+                    partial_key: trie_node.partial_key_nibbles.into_owned(),
+                    children: trie_node
+                        .children_merkle_values
+                        .map(|child| child.map(|c| c.into_owned())),
+                    storage_value,
+                },
+            );
+            new_nodes.insert(merkle_value);
+        }
+
+        if pending_parent_copies.is_empty() {
+            return;
+        }
+
+        // In order to copy the storage value of the parent, we need to know the full key of each
+        // node. To do so, we walk down the new trie starting from its root. Since the Merkle
+        // value of a node depends on the Merkle values of its descendants, the ancestors of a
+        // newly-inserted node are always newly-inserted nodes as well.
+        let mut to_copy = Vec::with_capacity(pending_parent_copies.len());
+        let mut to_visit = vec![(state_trie_root_hash.to_vec(), Vec::new())];
+        while let Some((merkle_value, mut node_key)) = to_visit.pop() {
+            if !new_nodes.contains(&merkle_value) {
+                continue;
+            }
+
+            let node = &self.trie_nodes[&merkle_value];
+            node_key.extend_from_slice(&node.partial_key);
+
+            for (child_index, child) in node.children.iter().enumerate() {
+                if let Some(child) = child {
+                    let mut child_key = node_key.clone();
+                    child_key.push(u8::try_from(child_index).unwrap());
+                    to_visit.push((child.clone(), child_key));
+                }
+            }
+
+            if let StorageValue::TrieRootRef { merkle_value, .. } = &node.storage_value {
+                let mut child_key = node_key.clone();
+                child_key.push(TRIE_ROOT_REF_NIBBLE);
+                to_visit.push((merkle_value.clone(), child_key));
+            }
+
+            if pending_parent_copies.contains(&merkle_value) {
+                to_copy.push((merkle_value, node_key));
+            }
+        }
+
+        let parent_state_trie_root_hash = parent_state_trie_root_hash.unwrap();
+        for (merkle_value, key) in to_copy {
+            let storage_value = match self
+                .find_node(parent_state_trie_root_hash, &key)
+                .map(|n| &n.storage_value)
+            {
+                Some(StorageValue::Value {
+                    value,
+                    trie_entry_version,
+                }) => StorageValue::Value {
+                    value: value.clone(),
+                    trie_entry_version: *trie_entry_version,
+                },
+                Some(StorageValue::TrieRootRef {
+                    merkle_value,
+                    trie_entry_version,
+                }) => StorageValue::TrieRootRef {
+                    merkle_value: merkle_value.clone(),
+                    trie_entry_version: *trie_entry_version,
+                },
+                Some(StorageValue::None) | None => continue,
+            };
+
+            self.trie_nodes
+                .get_mut(&merkle_value)
+                .unwrap()
+                .storage_value = storage_value;
+        }
+    }
+}
+
+impl fmt::Debug for MemoryFullDatabase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("MemoryFullDatabase").finish()
+    }
+}
+
+/// Updates the consensus and finality information of the chain after the given block has been
+/// finalized.
+fn apply_finalized_digest(
+    consensus: &mut chain_information::ChainInformationConsensus,
+    finality: &mut chain_information::ChainInformationFinality,
+    block_header: &header::HeaderRef,
+) {
+    match consensus {
+        chain_information::ChainInformationConsensus::Babe {
+            finalized_block_epoch_information,
+            finalized_next_epoch_transition,
+            slots_per_epoch,
+        } => {
+            if let Some((new_epoch, next_config)) = block_header.digest.babe_epoch_information() {
+                let slot_number = block_header
+                    .digest
+                    .babe_pre_runtime()
+                    .unwrap()
+                    .slot_number();
+
+                let new_epoch = chain_information::BabeEpochInformation {
+                    epoch_index: finalized_next_epoch_transition
+                        .epoch_index
+                        .checked_add(1)
+                        .unwrap(),
+                    start_slot_number: Some(
+                        finalized_next_epoch_transition
+                            .start_slot_number
+                            .unwrap_or(slot_number)
+                            .checked_add(slots_per_epoch.get())
+                            .unwrap(),
+                    ),
+                    authorities: new_epoch.authorities.map(Into::into).collect(),
+                    randomness: *new_epoch.randomness,
+                    c: next_config.map_or(finalized_next_epoch_transition.c, |cfg| cfg.c),
+                    allowed_slots: next_config
+                        .map_or(finalized_next_epoch_transition.allowed_slots, |cfg| {
+                            cfg.allowed_slots
+                        }),
+                };
+
+                *finalized_block_epoch_information = Some(mem::replace(
+                    finalized_next_epoch_transition,
+                    Box::new(new_epoch),
+                ));
+            }
+        }
+        // Aura authorities changes apply to the children of the block containing them.
+        chain_information::ChainInformationConsensus::Aura {
+            finalized_authorities_list,
+            ..
+        } => {
+            if let Some(new_authorities) = block_header.digest.logs().find_map(|item| match item {
+                header::DigestItemRef::AuraConsensus(
+                    header::AuraConsensusLogRef::AuthoritiesChange(list),
+                ) => Some(list),
+                _ => None,
+            }) {
+                *finalized_authorities_list = new_authorities.map(Into::into).collect();
+            }
+        }
+        chain_information::ChainInformationConsensus::Unknown => {}
+    }
+
+    if let chain_information::ChainInformationFinality::Grandpa {
+        after_finalized_block_authorities_set_id,
+        finalized_triggered_authorities,
+        finalized_scheduled_change,
+    } = finality
+    {
+        for grandpa_digest_item in block_header.digest.logs().filter_map(|d| match d {
+            header::DigestItemRef::GrandpaConsensus(gp) => Some(gp),
+            _ => None,
+        }) {
+            // A scheduled change is ignored if a change is already scheduled.
+            let (trigger_block_height, change) = match grandpa_digest_item {
+                header::GrandpaConsensusLogRef::ScheduledChange(change)
+                    if finalized_scheduled_change.is_none() =>
+                {
+                    (block_header.number.saturating_add(change.delay), change)
+                }
+                // A forced change applies to the block `delay` blocks after this one whether or
+                // not the blocks in between are finalized, which is stored here as a change
+                // triggered by the parent of that block. Changes scheduled at or below
+                // `reset_block_height` are enacted beforehand, while the ones above are
+                // discarded.
+                header::GrandpaConsensusLogRef::ForcedChange {
+                    reset_block_height,
+                    change,
+                } => {
+                    if let Some((height, new_authorities)) = finalized_scheduled_change.take() {
+                        if height <= reset_block_height {
+                            *finalized_triggered_authorities = new_authorities;
+                            *after_finalized_block_authorities_set_id += 1;
+                        }
+                    }
+                    (
+                        block_header
+                            .number
+                            .saturating_add(change.delay.saturating_sub(1)),
+                        change,
+                    )
+                }
+                _ => continue,
+            };
+
+            *finalized_scheduled_change = Some((
+                trigger_block_height,
+                change.next_authorities.map(Into::into).collect(),
+            ));
+        }
+
+        // Trigger the scheduled change if this block is its target.
+        if matches!(finalized_scheduled_change, Some((height, _)) if *height == block_header.number)
+        {
+            let (_, new_authorities) = finalized_scheduled_change.take().unwrap();
+            *finalized_triggered_authorities = new_authorities;
+            *after_finalized_block_authorities_set_id += 1;
+        }
+    }
+}
+
+/// Builds the key to search in the trie from the paths to the parent tries and the key within
+/// the last trie.
+fn vectored_key(
+    parent_tries_paths_nibbles: impl Iterator<Item = impl Iterator<Item = u8>>,
+    key_nibbles: impl Iterator<Item = u8>,
+) -> Vec<u8> {
+    parent_tries_paths_nibbles
+        .flat_map(|t| {
+            t.inspect(|n| assert!(*n < 16))
+                .chain(iter::once(TRIE_ROOT_REF_NIBBLE))
+        })
+        .chain(key_nibbles.inspect(|n| assert!(*n < 16)))
+        .collect()
+}
+
+/// Error while calling [`MemoryFullDatabase::insert`].
+#[derive(Debug, derive_more::Display)]
+pub enum InsertError {
+    /// Block was already in the database.
+    Duplicate,
+    /// Error when decoding the header to import.
+    #[display(fmt = "Failed to decode header: {_0}")]
+    BadHeader(header::Error),
+    /// Parent of the block to insert isn't in the database.
+    MissingParent,
+    /// Block isn't a descendant of the latest finalized block.
+    FinalizedNephew,
+}
+
+/// Error while calling [`MemoryFullDatabase::set_finalized`].
+#[derive(Debug, derive_more::Display)]
+pub enum SetFinalizedError {
+    /// New finalized block isn't in the database.
+    UnknownBlock,
+    /// New finalized block must be a child of the previous finalized block.
+    RevertForbidden,
+}
+
+/// Error while calling [`MemoryFullDatabase::set_block_justification`].
+#[derive(Debug, derive_more::Display)]
+pub enum SetJustificationError {
+    /// Block isn't in the database.
+    UnknownBlock,
+    /// Block isn't finalized.
+    NotFinalized,
+}
+
+/// Error while accessing the storage of a block.
+#[derive(Debug, derive_more::Display)]
+pub enum StorageAccessError {
+    /// Storage of the block hash passed as parameter is no longer in the database.
+    Pruned,
+    /// Requested block couldn't be found in the database.
+    UnknownBlock,
+}
@@ -0,0 +1,679 @@
+// Smoldot
+// Copyright (C) 2023  Pierre Krieger
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <http://www.gnu.org/licenses/>.
+
+#![cfg(test)]
+
+use super::{
+    Config, InsertError, InsertTrieNode, InsertTrieNodeStorageValue, MemoryFullDatabase,
+    SetFinalizedError, SetJustificationError, StorageAccessError,
+};
+use crate::{chain::chain_information, header, trie};
+
+use alloc::borrow::Cow;
+use core::{array, iter};
+use rand::distributions::{Distribution as _, Uniform};
+
+/// Trie built by [`build_trie`].
+struct Trie {
+    structure: trie::trie_structure::TrieStructure<(
+        Option<Vec<u8>>,
+        Option<trie::trie_node::MerkleValueOutput>,
+    )>,
+}
+
+impl Trie {
+    fn state_root(&self) -> [u8; 32] {
+        self.structure
+            .root_user_data()
+            .map(|n| *<&[u8; 32]>::try_from(n.1.as_ref().unwrap().as_ref()).unwrap())
+            .unwrap_or(trie::EMPTY_TRIE_MERKLE_VALUE)
+    }
+
+    /// Returns the nodes of the trie in the format expected by the database. Nodes whose key
+    /// isn't in `modified_keys` have a storage value of
+    /// [`InsertTrieNodeStorageValue::SameAsParent`], unless `modified_keys` is `None`.
+    fn insert_trie_nodes(
+        &mut self,
+        modified_keys: Option<&[&[u8]]>,
+    ) -> Vec<InsertTrieNode<'static>> {
+        self.structure
+            .iter_unordered()
+            .collect::<Vec<_>>()
+            .into_iter()
+            .map(|node_index| {
+                let (storage_value, Some(merkle_value)) = self.structure[node_index].clone()
+                    else { unreachable!() };
+                let full_key = self
+                    .structure
+                    .node_full_key_by_index(node_index)
+                    .unwrap()
+                    .collect::<Vec<_>>();
+                let is_modified = modified_keys.map_or(true, |keys| {
+                    keys.iter().any(|k| {
+                        trie::bytes_to_nibbles(k.iter().copied()).collect::<Vec<_>>() == full_key
+                    })
+                });
+                let storage_value = match storage_value {
+                    Some(_) if !is_modified => InsertTrieNodeStorageValue::SameAsParent,
+                    Some(storage_value) => InsertTrieNodeStorageValue::Value {
+                        value: Cow::Owned(storage_value),
+                        references_merkle_value: false,
+                    },
+                    None => InsertTrieNodeStorageValue::NoValue,
+                };
+
+                let mut node_access = self.structure.node_by_index(node_index).unwrap();
+                InsertTrieNode {
+                    storage_value,
+                    merkle_value: Cow::Owned(merkle_value.as_ref().to_vec()),
+                    children_merkle_values: array::from_fn::<_, 16, _>(|n| {
+                        let child_index = trie::Nibble::try_from(u8::try_from(n).unwrap()).unwrap();
+                        node_access.child(child_index).map(|mut child| {
+                            Cow::Owned(child.user_data().1.as_ref().unwrap().as_ref().to_vec())
+                        })
+                    }),
+                    partial_key_nibbles: Cow::Owned(
+                        node_access.partial_key().map(u8::from).collect::<Vec<_>>(),
+                    ),
+                }
+            })
+            .collect()
+    }
+}
+
+/// Builds a trie containing the given entries and calculates the Merkle values of its nodes.
+fn build_trie<'a>(entries: impl Iterator<Item = (&'a [u8], &'a [u8])>) -> Trie {
+    let mut structure = trie::trie_structure::TrieStructure::<(
+        Option<Vec<u8>>,
+        Option<trie::trie_node::MerkleValueOutput>,
+    )>::new();
+
+    for (key, value) in entries {
+        match structure.node(trie::bytes_to_nibbles(key.iter().copied())) {
+            trie::trie_structure::Entry::Vacant(e) => {
+                e.insert_storage_value()
+                    .insert((Some(value.to_vec()), None), (None, None));
+            }
+            trie::trie_structure::Entry::Occupied(trie::trie_structure::NodeAccess::Branch(
+                mut e,
+            )) => {
+                *e.user_data() = (Some(value.to_vec()), None);
+                e.insert_storage_value();
+            }
+            trie::trie_structure::Entry::Occupied(trie::trie_structure::NodeAccess::Storage(_)) => {
+            }
+        }
+    }
+
+    for node_index in structure
+        .iter_ordered()
+        .collect::<Vec<_>>()
+        .into_iter()
+        .rev()
+    {
+        let mut node_access = structure.node_by_index(node_index).unwrap();
+
+        let children = core::array::from_fn::<_, 16, _>(|n| {
+            node_access
+                .child(trie::Nibble::try_from(u8::try_from(n).unwrap()).unwrap())
+                .map(|mut child| child.user_data().1.as_ref().unwrap().clone())
+        });
+
+        let is_root_node = node_access.is_root_node();
+        let partial_key = node_access.partial_key().collect::<Vec<_>>().into_iter();
+
+        let storage_value = match node_access.user_data().0.as_ref() {
+            Some(v) => trie::trie_node::StorageValue::Unhashed(&v[..]),
+            None => trie::trie_node::StorageValue::None,
+        };
+
+        let merkle_value = trie::trie_node::calculate_merkle_value(
+            trie::trie_node::Decoded {
+                children,
+                partial_key,
+                storage_value,
+            },
+            is_root_node,
+        )
+        .unwrap();
+
+        node_access.into_user_data().1 = Some(merkle_value);
+    }
+
+    Trie { structure }
+}
+
+fn new_database(
+    trie: &mut Trie,
+    finality: chain_information::ChainInformationFinalityRef,
+) -> MemoryFullDatabase {
+    MemoryFullDatabase::new(
+        Config {
+            block_number_bytes: 4,
+            extrinsics_index: true,
+        },
+        chain_information::ChainInformationRef {
+            finalized_block_header: header::HeaderRef {
+                number: 0,
+                extrinsics_root: &header::extrinsics_root(&[] as &[Vec<u8>]),
+                parent_hash: &[0; 32],
+                state_root: &trie.state_root(),
+                digest: header::DigestRef::empty(),
+            },
+            consensus: chain_information::ChainInformationConsensusRef::Unknown,
+            finality,
+        },
+        iter::empty(),
+        None,
+        trie.insert_trie_nodes(None).into_iter(),
+        0,
+    )
+}
+
+fn child_header(
+    parent_hash: &[u8; 32],
+    number: u64,
+    state_root: &[u8; 32],
+    digest: header::DigestRef,
+) -> Vec<u8> {
+    header::HeaderRef {
+        parent_hash,
+        number,
+        state_root,
+        extrinsics_root: &[0; 32],
+        digest,
+    }
+    .scale_encoding_vec(4)
+}
+
+#[test]
+fn random_trie_queries() {
+    // Repeat the test many times due to randomness.
+    for _ in 0..256 {
+        fn uniform_sample(min: u8, max: u8) -> u8 {
+            Uniform::new_inclusive(min, max).sample(&mut rand::thread_rng())
+        }
+
+        let mut list = vec![Vec::new()];
+        for elem in list.clone().into_iter() {
+            for _ in 0..uniform_sample(0, 4) {
+                let mut elem = elem.clone();
+                for _ in 0..uniform_sample(0, 3) {
+                    elem.push(uniform_sample(0, 255));
+                }
+                list.push(elem);
+            }
+        }
+        let entries = list
+            .into_iter()
+            .map(|key| {
+                let value = (0..uniform_sample(0, 24))
+                    .map(|_| uniform_sample(0, 255))
+                    .collect::<Vec<_>>();
+                (key, value)
+            })
+            .collect::<Vec<_>>();
+
+        let mut trie = build_trie(entries.iter().map(|(k, v)| (&k[..], &v[..])));
+        let database = new_database(
+            &mut trie,
+            chain_information::ChainInformationFinalityRef::Outsourced,
+        );
+        let block0_hash = database.finalized_block_hash();
+        let trie = &trie.structure;
+
+        // Ask random keys.
+        for _ in 0..256 {
+            let key = (0..uniform_sample(0, 4))
+                .map(|_| uniform_sample(0, 255))
+                .collect::<Vec<_>>();
+            let actual = database
+                .block_storage_get(
+                    &block0_hash,
+                    iter::empty::<iter::Empty<_>>(),
+                    trie::bytes_to_nibbles(key.iter().copied()).map(u8::from),
+                )
+                .unwrap()
+                .map(|(v, vers)| (v.to_vec(), vers));
+            let expected = trie
+                .node_by_full_key(trie::bytes_to_nibbles(key.iter().copied()))
+                .and_then(|n| Some((trie[n].0.as_ref()?.clone(), 0u8)));
+            assert_eq!(actual, expected, "\nkey = {key:?}\ntrie = {trie:?}");
+        }
+
+        // Ask random next keys.
+        for _ in 0..256 {
+            let key = (0..uniform_sample(0, 8))
+                .map(|_| trie::Nibble::try_from(uniform_sample(0u8, 15)).unwrap())
+                .collect::<Vec<_>>();
+            let prefix = (0..uniform_sample(0, 8))
+                .map(|_| trie::Nibble::try_from(uniform_sample(0u8, 15)).unwrap())
+                .collect::<Vec<_>>();
+            let branch_nodes = rand::random::<bool>();
+            let actual = database
+                .block_storage_next_key(
+                    &block0_hash,
+                    iter::empty::<iter::Empty<_>>(),
+                    key.iter().copied().map(u8::from),
+                    prefix.iter().copied().map(u8::from),
+                    branch_nodes,
+                )
+                .unwrap();
+            let expected = trie
+                .iter_ordered()
+                .filter(|n| branch_nodes || trie[*n].0.is_some())
+                .map(|n| trie.node_full_key_by_index(n).unwrap().collect::<Vec<_>>())
+                .find(|n| *n >= key)
+                .filter(|n| n.starts_with(&prefix))
+                .map(|k| k.iter().copied().map(u8::from).collect::<Vec<_>>());
+            assert_eq!(
+                actual, expected,
+                "\nkey = {key:?}\nprefix = {prefix:?}\nbranch_nodes = {branch_nodes:?}\ntrie = {trie:?}"
+            );
+        }
+
+        // Ask random closest descendant Merkle values.
+        for _ in 0..256 {
+            let key = (0..uniform_sample(0, 8))
+                .map(|_| trie::Nibble::try_from(uniform_sample(0u8, 15)).unwrap())
+                .collect::<Vec<_>>();
+            let actual = database
+                .block_storage_closest_descendant_merkle_value(
+                    &block0_hash,
+                    iter::empty::<iter::Empty<_>>(),
+                    key.iter().copied().map(u8::from),
+                )
+                .unwrap()
+                .map(|v| v.to_vec());
+            let expected = trie
+                .iter_ordered()
+                .find(|n| {
+                    let full_key = trie.node_full_key_by_index(*n).unwrap().collect::<Vec<_>>();
+                    full_key >= key && full_key.starts_with(&key)
+                })
+                .map(|n| trie[n].1.as_ref().unwrap().as_ref().to_vec());
+            assert_eq!(actual, expected, "\nkey = {key:?}\ntrie = {trie:?}");
+        }
+    }
+}
+
+#[test]
+fn insert_and_finalize() {
+    let mut genesis_trie =
+        build_trie([(&b"foo"[..], &b"1"[..]), (b"bar", b"2"), (b"baz", b"3")].into_iter());
+    let mut database = new_database(
+        &mut genesis_trie,
+        chain_information::ChainInformationFinalityRef::Outsourced,
+    );
+    let genesis_hash = database.finalized_block_hash();
+
+    // Block 1 modifies `foo` and keeps the other values.
+    let mut block1_trie =
+        build_trie([(&b"foo"[..], &b"4"[..]), (b"bar", b"2"), (b"baz", b"3")].into_iter());
+    let block1_header = child_header(
+        &genesis_hash,
+        1,
+        &block1_trie.state_root(),
+        header::DigestRef::empty(),
+    );
+    let block1_hash = header::hash_from_scale_encoded_header(&block1_header);
+    database
+        .insert(
+            &block1_header,
+            true,
+            iter::once(b"extrinsic"),
+            block1_trie
+                .insert_trie_nodes(Some(&[&b"foo"[..]]))
+                .into_iter(),
+            0,
+        )
+        .unwrap();
+
+    // Block 1 of a different fork adds `qux`.
+    let mut fork_trie = build_trie(
+        [
+            (&b"foo"[..], &b"1"[..]),
+            (b"bar", b"2"),
+            (b"baz", b"3"),
+            (b"qux", b"5"),
+        ]
+        .into_iter(),
+    );
+    let fork_header = child_header(
+        &genesis_hash,
+        1,
+        &fork_trie.state_root(),
+        header::DigestRef::empty(),
+    );
+    let fork_hash = header::hash_from_scale_encoded_header(&fork_header);
+    database
+        .insert(
+            &fork_header,
+            false,
+            iter::empty::<Vec<u8>>(),
+            fork_trie
+                .insert_trie_nodes(Some(&[&b"qux"[..]]))
+                .into_iter(),
+            0,
+        )
+        .unwrap();
+
+    assert!(matches!(
+        database.insert(
+            &block1_header,
+            false,
+            iter::empty::<Vec<u8>>(),
+            iter::empty(),
+            0
+        ),
+        Err(InsertError::Duplicate)
+    ));
+    assert!(matches!(
+        database.insert(
+            &child_header(&[0xaa; 32], 2, &[0; 32], header::DigestRef::empty()),
+            false,
+            iter::empty::<Vec<u8>>(),
+            iter::empty(),
+            0
+        ),
+        Err(InsertError::MissingParent)
+    ));
+
+    let get = |database: &MemoryFullDatabase, block_hash: &[u8; 32], key: &[u8]| {
+        database
+            .block_storage_get(
+                block_hash,
+                iter::empty::<iter::Empty<_>>(),
+                trie::bytes_to_nibbles(key.iter().copied()).map(u8::from),
+            )
+            .map(|value| value.map(|(v, _)| v.to_vec()))
+    };
+
+    // Values that haven't been modified are copied from the parent.
+    assert_eq!(get(&database, &block1_hash, b"foo").unwrap().unwrap(), b"4");
+    assert_eq!(get(&database, &block1_hash, b"bar").unwrap().unwrap(), b"2");
+    assert_eq!(get(&database, &block1_hash, b"baz").unwrap().unwrap(), b"3");
+    assert!(get(&database, &block1_hash, b"qux").unwrap().is_none());
+    assert_eq!(get(&database, &fork_hash, b"foo").unwrap().unwrap(), b"1");
+    assert_eq!(get(&database, &fork_hash, b"qux").unwrap().unwrap(), b"5");
+    assert_eq!(
+        get(&database, &genesis_hash, b"foo").unwrap().unwrap(),
+        b"1"
+    );
+
+    assert_eq!(database.best_block_hash(), block1_hash);
+    assert_eq!(database.block_hash_by_number(1).count(), 2);
+    assert!(matches!(
+        database.set_block_justification(&block1_hash, b"justif"),
+        Err(SetJustificationError::NotFinalized)
+    ));
+
+    let extrinsic_hash = blake2_rfc::blake2b::blake2b(32, &[], b"extrinsic");
+    assert_eq!(
+        database
+            .extrinsic_locations(extrinsic_hash.as_bytes().try_into().unwrap())
+            .unwrap(),
+        vec![(block1_hash, 0)]
+    );
+
+    // Finalizing block 1 removes the fork.
+    database.set_finalized(&block1_hash).unwrap();
+    assert_eq!(database.finalized_block_hash(), block1_hash);
+    assert!(database.block_scale_encoded_header(&fork_hash).is_none());
+    assert!(matches!(
+        get(&database, &fork_hash, b"foo"),
+        Err(StorageAccessError::UnknownBlock)
+    ));
+    assert_eq!(get(&database, &block1_hash, b"bar").unwrap().unwrap(), b"2");
+    assert!(matches!(
+        database.set_finalized(&genesis_hash),
+        Err(SetFinalizedError::RevertForbidden)
+    ));
+
+    // The trie nodes that were only used by the fork have been removed.
+    let qux_key = fork_trie
+        .structure
+        .node_by_full_key(trie::bytes_to_nibbles(b"qux".iter().copied()))
+        .unwrap();
+    let qux_merkle_value = fork_trie.structure[qux_key].1.as_ref().unwrap().as_ref();
+    assert!(!database.trie_nodes.contains_key(qux_merkle_value));
+
+    database
+        .set_block_justification(&block1_hash, b"justif")
+        .unwrap();
+    assert_eq!(
+        database.block_justification(&block1_hash).unwrap(),
+        b"justif"
+    );
+    assert_eq!(database.finalized_justified_blocks(0), vec![block1_hash]);
+    assert!(database.finalized_justified_blocks(1).is_empty());
+    assert_eq!(
+        database.latest_finalized_justified_block(0, 2),
+        Some(block1_hash)
+    );
+    assert_eq!(database.latest_finalized_justified_block(0, 1), None);
+    assert_eq!(database.latest_finalized_justified_block(1, 2), None);
+}
+
+#[test]
+fn child_trie() {
+    let mut child_trie = build_trie([(&b"a"[..], &b"child value"[..]), (b"b", b"2")].into_iter());
+    let child_trie_root = child_trie.state_root();
+    let mut top_trie = build_trie(
+        [
+            (&b":child_storage:default:test"[..], &child_trie_root[..]),
+            (b"top", b"1"),
+        ]
+        .into_iter(),
+    );
+
+    let database = MemoryFullDatabase::new(
+        Config {
+            block_number_bytes: 4,
+            extrinsics_index: false,
+        },
+        chain_information::ChainInformationRef {
+            finalized_block_header: header::HeaderRef {
+                number: 0,
+                extrinsics_root: &header::extrinsics_root(&[] as &[Vec<u8>]),
+                parent_hash: &[0; 32],
+                state_root: &top_trie.state_root(),
+                digest: header::DigestRef::empty(),
+            },
+            consensus: chain_information::ChainInformationConsensusRef::Unknown,
+            finality: chain_information::ChainInformationFinalityRef::Outsourced,
+        },
+        iter::empty(),
+        None,
+        top_trie
+            .insert_trie_nodes(None)
+            .into_iter()
+            .map(|mut node| {
+                if let InsertTrieNodeStorageValue::Value {
+                    value,
+                    references_merkle_value,
+                } = &mut node.storage_value
+                {
+                    *references_merkle_value = value[..] == child_trie_root[..];
+                }
+                node
+            })
+            .chain(child_trie.insert_trie_nodes(None)),
+        0,
+    );
+    let block_hash = database.finalized_block_hash();
+
+    let child_trie_path = trie::bytes_to_nibbles(b":child_storage:default:test".iter().copied())
+        .map(u8::from)
+        .collect::<Vec<_>>();
+
+    let (value, _) = database
+        .block_storage_get(
+            &block_hash,
+            iter::once(child_trie_path.iter().copied()),
+            trie::bytes_to_nibbles(b"a".iter().copied()).map(u8::from),
+        )
+        .unwrap()
+        .unwrap();
+    assert_eq!(value, b"child value");
+
+    let next_key = database
+        .block_storage_next_key(
+            &block_hash,
+            iter::once(child_trie_path.iter().copied()),
+            trie::bytes_to_nibbles(b"a".iter().copied())
+                .map(u8::from)
+                .chain(iter::once(0)),
+            iter::empty(),
+            false,
+        )
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        next_key,
+        trie::bytes_to_nibbles(b"b".iter().copied())
+            .map(u8::from)
+            .collect::<Vec<_>>()
+    );
+
+    let merkle_value = database
+        .block_storage_closest_descendant_merkle_value(
+            &block_hash,
+            iter::once(child_trie_path.iter().copied()),
+            iter::empty(),
+        )
+        .unwrap()
+        .unwrap();
+    assert_eq!(merkle_value, child_trie_root);
+
+    // The top trie doesn't continue into the child trie.
+    assert!(database
+        .block_storage_next_key(
+            &block_hash,
+            iter::empty::<iter::Empty<_>>(),
+            child_trie_path.iter().copied().chain(iter::once(0)),
+            iter::empty(),
+            false,
+        )
+        .unwrap()
+        .map_or(true, |k| k
+            == trie::bytes_to_nibbles(b"top".iter().copied())
+                .map(u8::from)
+                .collect::<Vec<_>>()));
+}
+
+#[test]
+fn grandpa_scheduled_change_applied_on_finalization() {
+    let mut genesis_trie = build_trie(iter::once((&b"foo"[..], &b"1"[..])));
+    let initial_authorities = vec![header::GrandpaAuthority {
+        public_key: [1; 32],
+        weight: core::num::NonZeroU64::new(1).unwrap(),
+    }];
+    let mut database = new_database(
+        &mut genesis_trie,
+        chain_information::ChainInformationFinalityRef::Grandpa {
+            after_finalized_block_authorities_set_id: 0,
+            finalized_triggered_authorities: &initial_authorities,
+            finalized_scheduled_change: None,
+        },
+    );
+
+    let new_authorities = [header::GrandpaAuthority {
+        public_key: [2; 32],
+        weight: core::num::NonZeroU64::new(1).unwrap(),
+    }];
+    let digest_items = [header::DigestItem::GrandpaConsensus(
+        header::GrandpaConsensusLog::ScheduledChange(header::GrandpaScheduledChange {
+            next_authorities: new_authorities.to_vec(),
+            delay: 1,
+        }),
+    )];
+    let digest = header::DigestRef::from_slice(&digest_items).unwrap();
+
+    let mut parent_hash = database.finalized_block_hash();
+    let mut hashes = Vec::new();
+    for number in 1..=2 {
+        let scale_encoded_header = child_header(
+            &parent_hash,
+            number,
+            &genesis_trie.state_root(),
+            if number == 1 {
+                digest.clone()
+            } else {
+                header::DigestRef::empty()
+            },
+        );
+        database
+            .insert(
+                &scale_encoded_header,
+                true,
+                iter::empty::<Vec<u8>>(),
+                iter::empty(),
+                0,
+            )
+            .unwrap();
+        parent_hash = header::hash_from_scale_encoded_header(&scale_encoded_header);
+        hashes.push(parent_hash);
+    }
+
+    database.set_finalized(&hashes[0]).unwrap();
+    let chain_information::ChainInformationFinality::Grandpa {
+        after_finalized_block_authorities_set_id,
+        finalized_scheduled_change,
+        ..
+    } = chain_information::ChainInformation::from(
+        database.to_chain_information(&hashes[0]).unwrap(),
+    )
+    .finality else { panic!() };
+    assert_eq!(after_finalized_block_authorities_set_id, 0);
+    assert_eq!(finalized_scheduled_change.unwrap().0, 2);
+
+    database.set_finalized(&hashes[1]).unwrap();
+    let chain_information::ChainInformationFinality::Grandpa {
+        after_finalized_block_authorities_set_id,
+        finalized_triggered_authorities,
+        finalized_scheduled_change,
+    } = chain_information::ChainInformation::from(
+        database.to_chain_information(&hashes[1]).unwrap(),
+    )
+    .finality else { panic!() };
+    assert_eq!(after_finalized_block_authorities_set_id, 1);
+    assert_eq!(finalized_triggered_authorities, new_authorities);
+    assert!(finalized_scheduled_change.is_none());
+
+    assert!(matches!(
+        database.to_chain_information(&hashes[0]),
+        Err(StorageAccessError::Pruned)
+    ));
+}
+
+#[test]
+fn offchain_storage() {
+    let mut database = new_database(
+        &mut build_trie(iter::empty()),
+        chain_information::ChainInformationFinalityRef::Outsourced,
+    );
+    assert_eq!(database.offchain_storage_get(b"foo"), None);
+
+    assert!(!database.offchain_storage_compare_and_set(b"foo", Some(Some(b"bar")), Some(b"baz")));
+    assert!(database.offchain_storage_compare_and_set(b"foo", Some(None), Some(b"bar")));
+    assert!(database.offchain_storage_compare_and_set(b"foo", Some(Some(b"bar")), Some(b"baz")));
+    assert_eq!(database.offchain_storage_get(b"foo"), Some(&b"baz"[..]));
+
+    assert!(database.offchain_storage_compare_and_set(b"foo", None, None));
+    assert_eq!(database.offchain_storage_get(b"foo"), None);
+}
@@ -72,13 +72,15 @@
 
 use crate::{chain::chain_information, header, util};
 
-use alloc::borrow::Cow;
 use core::{fmt, iter, num::NonZeroU64};
 use parking_lot::Mutex;
 use rusqlite::OptionalExtension as _;
 
-pub use open::{open, Config, ConfigTy, DatabaseEmpty, DatabaseOpen};
+pub use super::{InsertTrieNode, InsertTrieNodeStorageValue};
+pub use integrity::IntegrityIssue;
+pub use open::{open, Config, ConfigTy, DatabaseEmpty, DatabaseOpen, OpenError, SCHEMA_VERSION};
 
+mod integrity;
 mod open;
 mod tests;
 
@@ -100,6 +102,9 @@ pub struct SqliteFullDatabase {
 
     /// Number of bytes used to encode the block number.
     block_number_bytes: usize,
+
+    /// See [`Config::extrinsics_index`].
+    extrinsics_index: bool,
 }
 
 impl SqliteFullDatabase {
@@ -171,6 +176,210 @@ impl SqliteFullDatabase {
         Ok(Some(result.into_iter()))
     }
 
+    /// Returns the list of blocks whose body contains an extrinsic with the given hash, alongside
+    /// with the index of the extrinsic within each body.
+    ///
+    /// The hash of an extrinsic is the BLAKE2-256 hash of its SCALE encoding.
+    ///
+    /// Returns `None` if the database doesn't maintain an index of extrinsics. See
+    /// [`Config::extrinsics_index`].
+    ///
+    /// > **Note**: The same extrinsic can be found in multiple blocks, for example if it has
+    /// >           been included in multiple forks.
+    pub fn extrinsic_locations(
+        &self,
+        extrinsic_hash: &[u8; 32],
+    ) -> Result<Option<Vec<ExtrinsicLocation>>, AccessError> {
+        if !self.extrinsics_index {
+            return Ok(None);
+        }
+
+        let connection = self.database.lock();
+
+        let result = connection
+            .prepare_cached(
+                r#"SELECT block_hash, idx FROM extrinsics_index WHERE extrinsic_hash = ? ORDER BY block_hash, idx"#,
+            )
+            .map_err(|err| CorruptedError::Internal(InternalError(err)))?
+            .query_map((&extrinsic_hash[..],), |row| {
+                Ok((row.get::<_, Vec<u8>>(0)?, row.get::<_, i64>(1)?))
+            })
+            .map_err(|err| CorruptedError::Internal(InternalError(err)))?
+            .map(|row| {
+                let (block_hash, index) =
+                    row.map_err(|err| CorruptedError::Internal(InternalError(err)))?;
+                let block_hash = <[u8; 32]>::try_from(&block_hash[..])
+                    .map_err(|_| CorruptedError::InvalidBlockHashLen)?;
+                let index = usize::try_from(index).map_err(|_| CorruptedError::InvalidNumber)?;
+                Ok((block_hash, index))
+            })
+            .collect::<Result<Vec<_>, CorruptedError>>()?;
+
+        Ok(Some(result))
+    }
+
+    /// Returns the justification stored alongside with the given block, or `None` if the block
+    /// is unknown or if no justification is stored for it.
+    ///
+    /// > **Note**: Justifications are only ever stored for finalized blocks, and not necessarily
+    /// >           for all of them.
+    pub fn block_justification(
+        &self,
+        block_hash: &[u8; 32],
+    ) -> Result<Option<Vec<u8>>, AccessError> {
+        let connection = self.database.lock();
+
+        let out = connection
+            .prepare_cached(r#"SELECT justification FROM blocks WHERE hash = ?"#)
+            .map_err(|err| CorruptedError::Internal(InternalError(err)))?
+            .query_row((&block_hash[..],), |row| row.get::<_, Option<Vec<u8>>>(0))
+            .optional()
+            .map_err(|err| CorruptedError::Internal(InternalError(err)))?;
+
+        Ok(out.flatten())
+    }
+
+    /// Stores a justification alongside with the given block, replacing the one that was
+    /// previously stored, if any.
+    ///
+    /// The block must have been previously inserted using [`SqliteFullDatabase::insert`] and
+    /// must be finalized, otherwise an error is returned.
+    ///
+    /// The justification is expected to be valid. No verification is performed.
+    pub fn set_block_justification(
+        &self,
+        block_hash: &[u8; 32],
+        justification: &[u8],
+    ) -> Result<(), SetJustificationError> {
+        let connection = self.database.lock();
+
+        let block_number = connection
+            .prepare_cached(r#"SELECT number FROM blocks WHERE hash = ?"#)
+            .map_err(|err| AccessError::Corrupted(CorruptedError::Internal(InternalError(err))))?
+            .query_row((&block_hash[..],), |row| row.get::<_, i64>(0))
+            .optional()
+            .map_err(|err| AccessError::Corrupted(CorruptedError::Internal(InternalError(err))))?
+            .ok_or(SetJustificationError::UnknownBlock)?;
+        let block_number = u64::try_from(block_number)
+            .map_err(|_| AccessError::Corrupted(CorruptedError::InvalidNumber))?;
+
+        if block_number > finalized_num(&connection)? {
+            return Err(SetJustificationError::NotFinalized);
+        }
+
+        connection
+            .prepare_cached(r#"UPDATE blocks SET justification = ? WHERE hash = ?"#)
+            .map_err(|err| AccessError::Corrupted(CorruptedError::Internal(InternalError(err))))?
+            .execute((justification, &block_hash[..]))
+            .map_err(|err| AccessError::Corrupted(CorruptedError::Internal(InternalError(err))))?;
+
+        Ok(())
+    }
+
+    /// Returns the hashes of the finalized blocks whose number is strictly superior to
+    /// `after_block_number` and that have a justification stored alongside with them, ordered
+    /// by increasing block number.
+    pub fn finalized_justified_blocks(
+        &self,
+        after_block_number: u64,
+    ) -> Result<Vec<[u8; 32]>, AccessError> {
+        let connection = self.database.lock();
+
+        let after_block_number = i64::try_from(after_block_number).unwrap_or(i64::MAX);
+        let finalized_number = i64::try_from(finalized_num(&connection)?)
+            .map_err(|_| AccessError::Corrupted(CorruptedError::InvalidNumber))?;
+
+        let mut statement = connection
+            .prepare_cached(
+                r#"SELECT hash FROM blocks WHERE number > ? AND number <= ? AND justification IS NOT NULL ORDER BY number ASC"#,
+            )
+            .map_err(|err| AccessError::Corrupted(CorruptedError::Internal(InternalError(err))))?;
+        let result = statement
+            .query_map((after_block_number, finalized_number), |row| {
+                row.get::<_, Vec<u8>>(0)
+            })
+            .map_err(|err| AccessError::Corrupted(CorruptedError::Internal(InternalError(err))))?
+            .map(|value| {
+                let value = value.map_err(|err| {
+                    AccessError::Corrupted(CorruptedError::Internal(InternalError(err)))
+                })?;
+                <[u8; 32]>::try_from(&value[..])
+                    .map_err(|_| AccessError::Corrupted(CorruptedError::InvalidBlockHashLen))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(result)
+    }
+
+    /// Returns the hashes of the finalized blocks whose number is strictly superior to
+    /// `after_block_number` and whose header schedules or forces a change in the list of GrandPa
+    /// authorities, ordered by increasing block number.
+    pub fn finalized_grandpa_authorities_changes(
+        &self,
+        after_block_number: u64,
+    ) -> Result<Vec<[u8; 32]>, AccessError> {
+        let connection = self.database.lock();
+
+        let after_block_number = i64::try_from(after_block_number).unwrap_or(i64::MAX);
+        let finalized_number = i64::try_from(finalized_num(&connection)?)
+            .map_err(|_| AccessError::Corrupted(CorruptedError::InvalidNumber))?;
+
+        let mut statement = connection
+            .prepare_cached(
+                r#"SELECT hash FROM blocks WHERE number > ? AND number <= ? AND grandpa_authorities_change != 0 ORDER BY number ASC"#,
+            )
+            .map_err(|err| AccessError::Corrupted(CorruptedError::Internal(InternalError(err))))?;
+        let result = statement
+            .query_map((after_block_number, finalized_number), |row| {
+                row.get::<_, Vec<u8>>(0)
+            })
+            .map_err(|err| AccessError::Corrupted(CorruptedError::Internal(InternalError(err))))?
+            .map(|value| {
+                let value = value.map_err(|err| {
+                    AccessError::Corrupted(CorruptedError::Internal(InternalError(err)))
+                })?;
+                <[u8; 32]>::try_from(&value[..])
+                    .map_err(|_| AccessError::Corrupted(CorruptedError::InvalidBlockHashLen))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(result)
+    }
+
+    /// Returns the hash of the finalized block with the highest number that is strictly
+    /// superior to `after_block_number`, strictly inferior to `before_block_number`, and that has
+    /// a justification stored alongside with it.
+    pub fn latest_finalized_justified_block(
+        &self,
+        after_block_number: u64,
+        before_block_number: u64,
+    ) -> Result<Option<[u8; 32]>, AccessError> {
+        let connection = self.database.lock();
+
+        let after_block_number = i64::try_from(after_block_number).unwrap_or(i64::MAX);
+        let before_block_number = i64::try_from(before_block_number).unwrap_or(i64::MAX);
+        let finalized_number = i64::try_from(finalized_num(&connection)?)
+            .map_err(|_| AccessError::Corrupted(CorruptedError::InvalidNumber))?;
+
+        let hash = connection
+            .prepare_cached(
+                r#"SELECT hash FROM blocks WHERE number > ? AND number < ? AND number <= ? AND justification IS NOT NULL ORDER BY number DESC LIMIT 1"#,
+            )
+            .map_err(|err| AccessError::Corrupted(CorruptedError::Internal(InternalError(err))))?
+            .query_row(
+                (after_block_number, before_block_number, finalized_number),
+                |row| row.get::<_, Vec<u8>>(0),
+            )
+            .optional()
+            .map_err(|err| AccessError::Corrupted(CorruptedError::Internal(InternalError(err))))?;
+
+        hash.map(|hash| {
+            <[u8; 32]>::try_from(&hash[..])
+                .map_err(|_| AccessError::Corrupted(CorruptedError::InvalidBlockHashLen))
+        })
+        .transpose()
+    }
+
     /// Returns the hashes of the blocks given a block number.
     pub fn block_hash_by_number(
         &self,
@@ -181,6 +390,69 @@ impl SqliteFullDatabase {
         Ok(result.into_iter())
     }
 
+    /// Returns the value associated to the given key in the storage of the off-chain workers,
+    /// or `None` if there is no such value.
+    pub fn offchain_storage_get(&self, key: &[u8]) -> Result<Option<Vec<u8>>, AccessError> {
+        let connection = self.database.lock();
+
+        let out = connection
+            .prepare_cached(r#"SELECT value FROM offchain_storage WHERE key = ?"#)
+            .map_err(|err| CorruptedError::Internal(InternalError(err)))?
+            .query_row((key,), |row| row.get::<_, Vec<u8>>(0))
+            .optional()
+            .map_err(|err| CorruptedError::Internal(InternalError(err)))?;
+
+        Ok(out)
+    }
+
+    /// Sets the value associated to the given key in the storage of the off-chain workers, or
+    /// removes it if `new_value` is `None`.
+    ///
+    /// If `old_value` is `Some`, the modification is only performed if the value currently
+    /// associated to the key matches `old_value`, where `Some(None)` means that no value must
+    /// currently be associated to the key.
+    ///
+    /// Returns `true` if the modification has been performed.
+    pub fn offchain_storage_compare_and_set(
+        &self,
+        key: &[u8],
+        old_value: Option<Option<&[u8]>>,
+        new_value: Option<&[u8]>,
+    ) -> Result<bool, AccessError> {
+        // The connection is locked for the entire duration of this function, which guarantees
+        // the atomicity of the operation.
+        let connection = self.database.lock();
+
+        if let Some(old_value) = old_value {
+            let current_value = connection
+                .prepare_cached(r#"SELECT value FROM offchain_storage WHERE key = ?"#)
+                .map_err(|err| CorruptedError::Internal(InternalError(err)))?
+                .query_row((key,), |row| row.get::<_, Vec<u8>>(0))
+                .optional()
+                .map_err(|err| CorruptedError::Internal(InternalError(err)))?;
+            if current_value.as_deref() != old_value {
+                return Ok(false);
+            }
+        }
+
+        match new_value {
+            Some(new_value) => connection
+                .prepare_cached(
+                    r#"INSERT OR REPLACE INTO offchain_storage(key, value) VALUES (?, ?)"#,
+                )
+                .map_err(|err| CorruptedError::Internal(InternalError(err)))?
+                .execute((key, new_value))
+                .map_err(|err| CorruptedError::Internal(InternalError(err)))?,
+            None => connection
+                .prepare_cached(r#"DELETE FROM offchain_storage WHERE key = ?"#)
+                .map_err(|err| CorruptedError::Internal(InternalError(err)))?
+                .execute((key,))
+                .map_err(|err| CorruptedError::Internal(InternalError(err)))?,
+        };
+
+        Ok(true)
+    }
+
     /// Returns a [`chain_information::ChainInformation`] struct containing the information about
     /// the current finalized state of the chain.
     ///
@@ -345,7 +617,7 @@ impl SqliteFullDatabase {
 
         transaction
             .prepare_cached(
-                "INSERT INTO blocks(number, hash, parent_hash, state_trie_root_hash, header, justification) VALUES (?, ?, ?, ?, ?, NULL)",
+                "INSERT INTO blocks(number, hash, parent_hash, state_trie_root_hash, header, justification, grandpa_authorities_change) VALUES (?, ?, ?, ?, ?, NULL, ?)",
             )
             .unwrap()
             .execute((
@@ -353,7 +625,8 @@ impl SqliteFullDatabase {
                 &block_hash[..],
                 &header.parent_hash[..],
                 &header.state_root[..],
-                scale_encoded_header
+                scale_encoded_header,
+                header.digest.has_grandpa_authorities_change(),
             ))
             .unwrap();
 
@@ -372,6 +645,10 @@ impl SqliteFullDatabase {
             }
         }
 
+        if self.extrinsics_index {
+            index_block_extrinsics(&transaction, &block_hash)?;
+        }
+
         // Insert the changes in trie nodes.
         insert_storage(
             &transaction,
@@ -601,36 +878,91 @@ impl SqliteFullDatabase {
                 )?;
             }
 
-            // TODO: implement Aura
+            // Aura authorities changes apply to the children of the block containing them.
+            if meta_get_number(&transaction, "aura_slot_duration")?.is_some() {
+                if let Some(new_authorities) =
+                    block_header.digest.logs().find_map(|item| match item {
+                        header::DigestItemRef::AuraConsensus(
+                            header::AuraConsensusLogRef::AuthoritiesChange(list),
+                        ) => Some(list),
+                        _ => None,
+                    })
+                {
+                    transaction
+                        .execute("DELETE FROM aura_finalized_authorities", ())
+                        .map_err(|err| {
+                            AccessError::Corrupted(CorruptedError::Internal(InternalError(err)))
+                        })?;
+
+                    let mut statement = transaction
+                        .prepare_cached(
+                            "INSERT INTO aura_finalized_authorities(idx, public_key) VALUES(?, ?)",
+                        )
+                        .map_err(|err| {
+                            AccessError::Corrupted(CorruptedError::Internal(InternalError(err)))
+                        })?;
+                    for (index, item) in new_authorities.enumerate() {
+                        statement
+                            .execute((i64::try_from(index).unwrap(), &item.public_key[..]))
+                            .map_err(|err| {
+                                AccessError::Corrupted(CorruptedError::Internal(InternalError(err)))
+                            })?;
+                    }
+                }
+            }
 
             if grandpa_authorities_set_id(&transaction)?.is_some() {
                 for grandpa_digest_item in block_header.digest.logs().filter_map(|d| match d {
                     header::DigestItemRef::GrandpaConsensus(gp) => Some(gp),
                     _ => None,
                 }) {
-                    // TODO: implement items other than ScheduledChange
-                    if let header::GrandpaConsensusLogRef::ScheduledChange(change) =
-                        grandpa_digest_item
-                    {
-                        assert_eq!(change.delay, 0); // TODO: not implemented if != 0
-
-                        transaction
-                            .execute("DELETE FROM grandpa_triggered_authorities", ())
-                            .unwrap();
-
-                        let mut statement = transaction.prepare_cached("INSERT INTO grandpa_triggered_authorities(idx, public_key, weight) VALUES(?, ?, ?)").unwrap();
-                        for (index, item) in change.next_authorities.enumerate() {
-                            statement
-                                .execute((
-                                    i64::try_from(index).unwrap(),
-                                    &item.public_key[..],
-                                    i64::from_ne_bytes(item.weight.get().to_ne_bytes()),
-                                ))
-                                .unwrap();
+                    // This follows the same rules as the non-finalized blocks tree: a scheduled
+                    // change is ignored if a change is already scheduled.
+                    let (trigger_block_height, change) = match grandpa_digest_item {
+                        header::GrandpaConsensusLogRef::ScheduledChange(change)
+                            if meta_get_number(&transaction, "grandpa_scheduled_target")?
+                                .is_none() =>
+                        {
+                            (block_header.number.checked_add(change.delay), change)
                         }
+                        // A forced change applies to the block `delay` blocks after this one
+                        // whether or not the blocks in between are finalized, which is stored
+                        // here as a change triggered by the parent of that block. Changes
+                        // scheduled at or below `reset_block_height` are enacted beforehand, while
+                        // the ones above are discarded.
+                        header::GrandpaConsensusLogRef::ForcedChange {
+                            reset_block_height,
+                            change,
+                        } => {
+                            if meta_get_number(&transaction, "grandpa_scheduled_target")?
+                                .is_some_and(|target| target <= reset_block_height)
+                            {
+                                grandpa_trigger_scheduled_change(&transaction)?;
+                            }
+                            (
+                                block_header
+                                    .number
+                                    .checked_add(change.delay.saturating_sub(1)),
+                                change,
+                            )
+                        }
+                        _ => continue,
+                    };
+
+                    let trigger_block_height = trigger_block_height
+                        .ok_or(AccessError::Corrupted(CorruptedError::InvalidNumber))?;
+                    grandpa_set_scheduled_change(
+                        &transaction,
+                        trigger_block_height,
+                        change.next_authorities,
+                    )?;
+                }
 
-                        transaction.execute(r#"UPDATE meta SET value_number = value_number + 1 WHERE key = "grandpa_authorities_set_id""#, ()).unwrap();
-                    }
+                // Trigger the scheduled change if this block is its target.
+                if meta_get_number(&transaction, "grandpa_scheduled_target")?
+                    == Some(block_header.number)
+                {
+                    grandpa_trigger_scheduled_change(&transaction)?;
                 }
             }
         }
@@ -672,7 +1004,6 @@ impl SqliteFullDatabase {
         let connection = self.database.lock();
 
         // TODO: could be optimized by having a different request when `parent_tries_paths_nibbles` is empty and when it isn't
-        // TODO: trie_root_ref system untested
         let mut statement = connection
             .prepare_cached(
                 r#"
@@ -682,12 +1013,13 @@ impl SqliteFullDatabase {
                         FROM blocks, trie_node
                         WHERE blocks.hash = :block_hash AND blocks.state_trie_root_hash = trie_node.hash AND COALESCE(SUBSTR(:key, 1, LENGTH(trie_node.partial_key)), X'') = trie_node.partial_key
                     UNION ALL
-                    SELECT COALESCE(trie_node.hash, trie_node_storage.trie_root_ref), COALESCE(SUBSTR(node_with_key.search_remain, 2 + LENGTH(trie_node.partial_key)), SUBSTR(node_with_key.search_remain, 1))
+                    SELECT COALESCE(trie_node.hash, trie_node_trieref.hash), COALESCE(SUBSTR(node_with_key.search_remain, 2 + LENGTH(trie_node.partial_key)), SUBSTR(node_with_key.search_remain, 2 + LENGTH(trie_node_trieref.partial_key)))
                         FROM node_with_key
                         LEFT JOIN trie_node_child ON node_with_key.node_hash = trie_node_child.hash AND SUBSTR(node_with_key.search_remain, 1, 1) = trie_node_child.child_num
                         LEFT JOIN trie_node ON trie_node.hash = trie_node_child.child_hash AND SUBSTR(node_with_key.search_remain, 2, LENGTH(trie_node.partial_key)) = trie_node.partial_key
                         LEFT JOIN trie_node_storage ON node_with_key.node_hash = trie_node_storage.node_hash AND trie_node_storage.trie_root_ref IS NOT NULL AND HEX(SUBSTR(node_with_key.search_remain, 1, 1)) = '10'
-                        WHERE LENGTH(node_with_key.search_remain) >= 1 AND (trie_node.hash IS NOT NULL OR trie_node_storage.trie_root_ref IS NOT NULL)
+                        LEFT JOIN trie_node AS trie_node_trieref ON trie_node_trieref.hash = trie_node_storage.trie_root_ref AND SUBSTR(node_with_key.search_remain, 2, LENGTH(trie_node_trieref.partial_key)) = trie_node_trieref.partial_key
+                        WHERE LENGTH(node_with_key.search_remain) >= 1 AND (trie_node.hash IS NOT NULL OR trie_node_trieref.hash IS NOT NULL)
                 )
             SELECT COUNT(blocks.hash) >= 1, COUNT(trie_node.hash) >= 1, COALESCE(trie_node_storage.value, trie_node_storage.trie_root_ref), trie_node_storage.trie_entry_version
             FROM blocks
@@ -785,7 +1117,6 @@ impl SqliteFullDatabase {
         let connection = self.database.lock();
 
         // TODO: this algorithm relies the fact that leaf nodes always have a storage value, which isn't exactly clear in the schema ; however not relying on this makes it way harder to write
-        // TODO: trie_root_ref system untested and most likely not working
         let mut statement = connection
             .prepare_cached(
                 r#"
@@ -806,7 +1137,7 @@ impl SqliteFullDatabase {
                         SELECT
                             COALESCE(trie_node.hash, trie_node_trieref.hash),
                             trie_node_storage.value IS NULL AND trie_node_storage.trie_root_ref IS NULL,
-                            CAST(next_key.node_full_key || trie_node_child.child_num || COALESCE(trie_node.partial_key, trie_node_trieref.partial_key) AS BLOB)
+                            CAST(next_key.node_full_key || COALESCE(trie_node_child.child_num, X'10') || COALESCE(trie_node.partial_key, trie_node_trieref.partial_key) AS BLOB)
                                 AS node_full_key,
                             CASE SUBSTR(next_key.search_remain, 1, 1) = trie_node_child.child_num AND SUBSTR(next_key.search_remain, 2, LENGTH(trie_node.partial_key)) = trie_node.partial_key
                                 WHEN TRUE THEN SUBSTR(next_key.search_remain, 2 + LENGTH(trie_node.partial_key))
@@ -834,7 +1165,7 @@ impl SqliteFullDatabase {
                         LEFT JOIN trie_node_storage AS trie_node_storage_trieref
                             ON next_key.node_hash = trie_node_storage_trieref.node_hash AND trie_node_storage_trieref.trie_root_ref IS NOT NULL AND HEX(SUBSTR(next_key.search_remain, 1, 1)) = '10'
                         LEFT JOIN trie_node AS trie_node_trieref
-                            ON trie_node_trieref.hash = trie_node_storage_trieref.node_hash
+                            ON trie_node_trieref.hash = trie_node_storage_trieref.trie_root_ref
                             AND COALESCE(SUBSTR(next_key.search_remain, 2, LENGTH(trie_node_trieref.partial_key)), X'') <= trie_node_trieref.partial_key
 
                         LEFT JOIN trie_node_storage
@@ -940,7 +1271,6 @@ impl SqliteFullDatabase {
     ) -> Result<Option<Vec<u8>>, StorageAccessError> {
         let connection = self.database.lock();
 
-        // TODO: trie_root_ref system untested
         let mut statement = connection
             .prepare_cached(
                 r#"
@@ -955,8 +1285,8 @@ impl SqliteFullDatabase {
                             )
                     UNION ALL
                     SELECT
-                            COALESCE(trie_node.hash, trie_node_storage.trie_root_ref),
-                            COALESCE(SUBSTR(closest_descendant.search_remain, 2 + LENGTH(trie_node.partial_key)), SUBSTR(closest_descendant.search_remain, 1), X'')
+                            COALESCE(trie_node.hash, trie_node_trieref.hash),
+                            COALESCE(SUBSTR(closest_descendant.search_remain, 2 + LENGTH(trie_node.partial_key)), SUBSTR(closest_descendant.search_remain, 2 + LENGTH(trie_node_trieref.partial_key)), X'')
                         FROM closest_descendant
                         LEFT JOIN trie_node_child ON closest_descendant.node_hash = trie_node_child.hash
                             AND SUBSTR(closest_descendant.search_remain, 1, 1) = trie_node_child.child_num
@@ -966,7 +1296,12 @@ impl SqliteFullDatabase {
                                 OR COALESCE(SUBSTR(closest_descendant.search_remain, 2, LENGTH(trie_node.partial_key)), X'') = trie_node.partial_key
                             )
                         LEFT JOIN trie_node_storage ON closest_descendant.node_hash = trie_node_storage.node_hash AND trie_node_storage.trie_root_ref IS NOT NULL AND HEX(SUBSTR(closest_descendant.search_remain, 1, 1)) = '10'
-                        WHERE LENGTH(closest_descendant.search_remain) >= 1 AND (trie_node.hash IS NOT NULL OR trie_node_storage.trie_root_ref IS NOT NULL)
+                        LEFT JOIN trie_node AS trie_node_trieref ON trie_node_trieref.hash = trie_node_storage.trie_root_ref
+                            AND (
+                                COALESCE(SUBSTR(trie_node_trieref.partial_key, 1, LENGTH(closest_descendant.search_remain) - 1), X'') = COALESCE(SUBSTR(closest_descendant.search_remain, 2), X'')
+                                OR COALESCE(SUBSTR(closest_descendant.search_remain, 2, LENGTH(trie_node_trieref.partial_key)), X'') = trie_node_trieref.partial_key
+                            )
+                        WHERE LENGTH(closest_descendant.search_remain) >= 1 AND (trie_node.hash IS NOT NULL OR trie_node_trieref.hash IS NOT NULL)
                 )
             SELECT COUNT(blocks.hash) >= 1, COUNT(trie_node.hash) >= 1, closest_descendant.node_hash
             FROM blocks
@@ -1034,22 +1369,10 @@ impl Drop for SqliteFullDatabase {
     }
 }
 
-pub struct InsertTrieNode<'a> {
-    pub merkle_value: Cow<'a, [u8]>,
-    pub partial_key_nibbles: Cow<'a, [u8]>,
-    pub children_merkle_values: [Option<Cow<'a, [u8]>>; 16],
-    pub storage_value: InsertTrieNodeStorageValue<'a>,
-}
-
-pub enum InsertTrieNodeStorageValue<'a> {
-    NoValue,
-    Value {
-        value: Cow<'a, [u8]>,
-        /// If `true`, the value is equal to the Merkle value of the root of another trie.
-        references_merkle_value: bool,
-    },
-    SameAsParent,
-}
+/// Hash of a block and index of an extrinsic within the body of this block.
+///
+/// See [`SqliteFullDatabase::extrinsic_locations`].
+pub type ExtrinsicLocation = ([u8; 32], usize);
 
 /// Error while accessing some information.
 // TODO: completely replace with just CorruptedError?
@@ -1091,6 +1414,17 @@ pub enum SetFinalizedError {
     RevertForbidden,
 }
 
+/// Error while calling [`SqliteFullDatabase::set_block_justification`].
+#[derive(Debug, derive_more::Display, derive_more::From)]
+pub enum SetJustificationError {
+    /// Error accessing the database.
+    Access(AccessError),
+    /// Block isn't in the database.
+    UnknownBlock,
+    /// Justifications can only be stored for finalized blocks.
+    NotFinalized,
+}
+
 /// Error while accessing the storage of the finalized block.
 #[derive(Debug, derive_more::Display, derive_more::From)]
 pub enum StorageAccessError {
@@ -1140,6 +1474,38 @@ pub enum CorruptedError {
 #[derive(Debug, derive_more::Display)]
 pub struct InternalError(rusqlite::Error);
 
+/// Inserts in the `extrinsics_index` table the extrinsics of the body of the given block.
+///
+/// The body must have been inserted in the `blocks_body` table beforehand.
+fn index_block_extrinsics(
+    database: &rusqlite::Connection,
+    block_hash: &[u8],
+) -> Result<(), AccessError> {
+    let body = database
+        .prepare_cached(r#"SELECT idx, extrinsic FROM blocks_body WHERE hash = ?"#)
+        .map_err(|err| AccessError::Corrupted(CorruptedError::Internal(InternalError(err))))?
+        .query_map((block_hash,), |row| {
+            Ok((row.get::<_, i64>(0)?, row.get::<_, Vec<u8>>(1)?))
+        })
+        .map_err(|err| AccessError::Corrupted(CorruptedError::Internal(InternalError(err))))?
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|err| AccessError::Corrupted(CorruptedError::Internal(InternalError(err))))?;
+
+    let mut statement = database
+        .prepare_cached(
+            r#"INSERT OR IGNORE INTO extrinsics_index(extrinsic_hash, block_hash, idx) VALUES (?, ?, ?)"#,
+        )
+        .map_err(|err| AccessError::Corrupted(CorruptedError::Internal(InternalError(err))))?;
+    for (index, extrinsic) in body {
+        let extrinsic_hash = blake2_rfc::blake2b::blake2b(32, &[], &extrinsic);
+        statement
+            .execute((extrinsic_hash.as_bytes(), block_hash, index))
+            .map_err(|err| AccessError::Corrupted(CorruptedError::Internal(InternalError(err))))?;
+    }
+
+    Ok(())
+}
+
 fn meta_get_blob(
     database: &rusqlite::Connection,
     key: &str,
@@ -1354,7 +1720,8 @@ fn insert_storage<'a>(
     // TODO: not detected above yet ^
     // TODO: consider reference counting the storage values?
     // TODO: DRY with getting a value?
-    // TODO: doesn't properly work with feature `trie_root_ref`
+    // When walking up from the root of a child trie, the node whose storage value references
+    // this root is used as the parent, and `0x10` is used as the nibble separating the two.
     // TODO: will be an infinite loop if trie is recursive, can this happen?
     database
         .prepare_cached(
@@ -1363,7 +1730,7 @@ fn insert_storage<'a>(
             insertions(node_hash, copy_from_base, copy_from_relative_key) AS (
                 SELECT node_hash, node_hash, X'' FROM temp_pending_parent_copies
                 UNION ALL
-                SELECT insertions.node_hash, COALESCE(trie_node_child.hash, trie_node_storage.node_hash), CAST(COALESCE(trie_node_child.child_num, X'') || trie_node.partial_key || insertions.copy_from_relative_key AS BLOB)
+                SELECT insertions.node_hash, COALESCE(trie_node_child.hash, trie_node_storage.node_hash), CAST(COALESCE(trie_node_child.child_num, CASE WHEN trie_node_storage.node_hash IS NULL THEN X'' ELSE X'10' END) || trie_node.partial_key || insertions.copy_from_relative_key AS BLOB)
                     FROM insertions
                     JOIN trie_node ON trie_node.hash = insertions.copy_from_base
                     LEFT JOIN trie_node_child ON trie_node_child.child_hash = insertions.copy_from_base
@@ -1377,11 +1744,13 @@ fn insert_storage<'a>(
                     JOIN blocks ON blocks.hash = :parent_block_hash AND blocks.state_trie_root_hash = trie_node.hash
                     WHERE insertions.copy_from_base IS NULL
                 UNION ALL
-                SELECT node_with_key.node_hash, trie_node.hash, SUBSTR(node_with_key.search_remain, 2 + LENGTH(trie_node.partial_key))
+                SELECT node_with_key.node_hash, COALESCE(trie_node.hash, trie_node_trieref.hash), SUBSTR(node_with_key.search_remain, 2 + LENGTH(COALESCE(trie_node.partial_key, trie_node_trieref.partial_key)))
                     FROM node_with_key
-                    JOIN trie_node_child ON node_with_key.search_node_hash = trie_node_child.hash AND SUBSTR(node_with_key.search_remain, 1, 1) = trie_node_child.child_num
-                    JOIN trie_node ON trie_node.hash = trie_node_child.child_hash AND SUBSTR(node_with_key.search_remain, 2, LENGTH(trie_node.partial_key)) = trie_node.partial_key
-                    WHERE LENGTH(node_with_key.search_remain) >= 1
+                    LEFT JOIN trie_node_child ON node_with_key.search_node_hash = trie_node_child.hash AND SUBSTR(node_with_key.search_remain, 1, 1) = trie_node_child.child_num
+                    LEFT JOIN trie_node ON trie_node.hash = trie_node_child.child_hash AND SUBSTR(node_with_key.search_remain, 2, LENGTH(trie_node.partial_key)) = trie_node.partial_key
+                    LEFT JOIN trie_node_storage ON node_with_key.search_node_hash = trie_node_storage.node_hash AND trie_node_storage.trie_root_ref IS NOT NULL AND HEX(SUBSTR(node_with_key.search_remain, 1, 1)) = '10'
+                    LEFT JOIN trie_node AS trie_node_trieref ON trie_node_trieref.hash = trie_node_storage.trie_root_ref AND SUBSTR(node_with_key.search_remain, 2, LENGTH(trie_node_trieref.partial_key)) = trie_node_trieref.partial_key
+                    WHERE LENGTH(node_with_key.search_remain) >= 1 AND (trie_node.hash IS NOT NULL OR trie_node_trieref.hash IS NOT NULL)
             )
         INSERT OR IGNORE INTO trie_node_storage(node_hash, value, trie_root_ref, trie_entry_version)
         SELECT node_with_key.node_hash, trie_node_storage.value, trie_node_storage.trie_root_ref, trie_node_storage.trie_entry_version
@@ -1548,6 +1917,49 @@ fn grandpa_finalized_scheduled_change(
     }
 }
 
+/// Replaces the GrandPa change currently scheduled, if any, with the given one.
+fn grandpa_set_scheduled_change<'a>(
+    database: &rusqlite::Connection,
+    trigger_block_height: u64,
+    new_authorities: impl Iterator<Item = header::GrandpaAuthorityRef<'a>>,
+) -> Result<(), AccessError> {
+    database
+        .execute("DELETE FROM grandpa_scheduled_authorities", ())
+        .map_err(|err| AccessError::Corrupted(CorruptedError::Internal(InternalError(err))))?;
+
+    let mut statement = database
+        .prepare_cached(
+            "INSERT INTO grandpa_scheduled_authorities(idx, public_key, weight) VALUES(?, ?, ?)",
+        )
+        .map_err(|err| AccessError::Corrupted(CorruptedError::Internal(InternalError(err))))?;
+    for (index, item) in new_authorities.enumerate() {
+        statement
+            .execute((
+                i64::try_from(index).unwrap(),
+                &item.public_key[..],
+                i64::from_ne_bytes(item.weight.get().to_ne_bytes()),
+            ))
+            .map_err(|err| AccessError::Corrupted(CorruptedError::Internal(InternalError(err))))?;
+    }
+
+    meta_set_number(database, "grandpa_scheduled_target", trigger_block_height)
+}
+
+fn grandpa_trigger_scheduled_change(database: &rusqlite::Connection) -> Result<(), AccessError> {
+    database
+        .execute_batch(
+            r#"
+            DELETE FROM grandpa_triggered_authorities;
+            INSERT INTO grandpa_triggered_authorities(idx, public_key, weight)
+                SELECT idx, public_key, weight FROM grandpa_scheduled_authorities;
+            DELETE FROM grandpa_scheduled_authorities;
+            DELETE FROM meta WHERE key = "grandpa_scheduled_target";
+            UPDATE meta SET value_number = value_number + 1 WHERE key = "grandpa_authorities_set_id";
+            "#,
+        )
+        .map_err(|err| AccessError::Corrupted(CorruptedError::Internal(InternalError(err))))
+}
+
 fn expect_nz_u64(value: u64) -> Result<NonZeroU64, AccessError> {
     NonZeroU64::new(value)
         .ok_or(CorruptedError::InvalidNumber)
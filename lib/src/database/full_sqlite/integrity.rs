@@ -0,0 +1,466 @@
+// Smoldot
+// Copyright (C) 2023  Pierre Krieger
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Verification of the consistency of the content of the database.
+//!
+//! See [`SqliteFullDatabase::check_integrity`].
+
+use super::{finalized_hash, finalized_num, meta_get_blob, InternalError, SqliteFullDatabase};
+use crate::{header, trie};
+
+use alloc::{
+    string::{String, ToString as _},
+    vec::Vec,
+};
+use core::array;
+use rusqlite::OptionalExtension as _;
+
+impl SqliteFullDatabase {
+    /// Walks through the entire database and verifies the consistency of its content.
+    ///
+    /// The following is verified:
+    ///
+    /// - The low-level SQLite integrity and foreign key checks.
+    /// - For each block, that its hash, number, and parent hash match its header, that its parent
+    ///   is in the database (except for the oldest block, which is the block the database has
+    ///   been initialized with), that its body matches the extrinsics root of its header, and that
+    ///   its state trie root (if any) matches the state root of its header.
+    /// - That the finalized chain contains exactly one block at each height.
+    /// - For each block, that the root node of its state trie (if any) is in the database.
+    /// - For each trie node, that its children and the root of the child trie it refers to (if
+    ///   any) are in the database.
+    /// - For each trie node, that its Merkle value matches its partial key, storage value, and
+    ///   children. Since the Merkle value of a node depends on the Merkle values of its children,
+    ///   this guarantees, combined with the checks above, that the state root of each block
+    ///   matches the content of its storage.
+    /// - That the finalized consensus state can be loaded.
+    ///
+    /// Returns the list of problems that have been found. An empty list indicates that the
+    /// database is consistent. This function never panics in case of a corrupted database.
+    ///
+    /// An error is returned only if the database couldn't be accessed at all, for example due to
+    /// an I/O error.
+    ///
+    /// > **Note**: This function reads the entire database and can take a long time.
+    pub fn check_integrity(&self) -> Result<Vec<IntegrityIssue>, InternalError> {
+        let mut issues = Vec::new();
+
+        // `to_chain_information` locks the database as well, and is thus called before
+        // everything else.
+        let finalized_hash = finalized_hash(&self.database.lock());
+        if let Ok(finalized_hash) = finalized_hash {
+            if let Err(err) = self.to_chain_information(&finalized_hash) {
+                issues.push(IntegrityIssue::InvalidFinalizedState(err.to_string()));
+            }
+        }
+
+        let database = self.database.lock();
+
+        // SQLite-level checks.
+        {
+            let mut statement = database
+                .prepare("PRAGMA integrity_check")
+                .map_err(InternalError)?;
+            let mut rows = statement.query(()).map_err(InternalError)?;
+            while let Some(row) = rows.next().map_err(InternalError)? {
+                let message = row.get::<_, String>(0).map_err(InternalError)?;
+                if message != "ok" {
+                    issues.push(IntegrityIssue::Sqlite(message));
+                }
+            }
+        }
+        {
+            let mut statement = database
+                .prepare("PRAGMA foreign_key_check")
+                .map_err(InternalError)?;
+            let mut rows = statement.query(()).map_err(InternalError)?;
+            while let Some(row) = rows.next().map_err(InternalError)? {
+                issues.push(IntegrityIssue::ForeignKeyViolation {
+                    table: row.get::<_, String>(0).map_err(InternalError)?,
+                    parent_table: row.get::<_, String>(2).map_err(InternalError)?,
+                });
+            }
+        }
+
+        // Global values.
+        let finalized_number = match finalized_num(&database) {
+            Ok(n) => Some(n),
+            Err(_) => {
+                issues.push(IntegrityIssue::InvalidMeta("finalized"));
+                None
+            }
+        };
+        match meta_get_blob(&database, "best") {
+            Ok(Some(best)) => {
+                let exists = database
+                    .prepare_cached("SELECT COUNT(*) FROM blocks WHERE hash = ?")
+                    .map_err(InternalError)?
+                    .query_row((&best,), |row| row.get::<_, i64>(0))
+                    .map_err(InternalError)?
+                    != 0;
+                if !exists {
+                    issues.push(IntegrityIssue::InvalidMeta("best"));
+                }
+            }
+            _ => issues.push(IntegrityIssue::InvalidMeta("best")),
+        }
+
+        // The database has been initialized with its oldest block, whose parent isn't in the
+        // database. This block isn't necessarily the genesis block.
+        let oldest_number = database
+            .prepare_cached("SELECT MIN(number) FROM blocks")
+            .map_err(InternalError)?
+            .query_row((), |row| row.get::<_, Option<i64>>(0))
+            .map_err(InternalError)?;
+
+        // Check all the blocks one by one.
+        {
+            let mut statement = database
+                .prepare(
+                    "SELECT hash, parent_hash, number, header, state_trie_root_hash FROM blocks",
+                )
+                .map_err(InternalError)?;
+            let mut rows = statement.query(()).map_err(InternalError)?;
+            while let Some(row) = rows.next().map_err(InternalError)? {
+                let hash = row.get::<_, Vec<u8>>(0).map_err(InternalError)?;
+                let parent_hash = row.get::<_, Option<Vec<u8>>>(1).map_err(InternalError)?;
+                let number = row.get::<_, i64>(2).map_err(InternalError)?;
+                let scale_encoded_header = row.get::<_, Vec<u8>>(3).map_err(InternalError)?;
+                let state_trie_root_hash =
+                    row.get::<_, Option<Vec<u8>>>(4).map_err(InternalError)?;
+
+                let Ok(hash) = <[u8; 32]>::try_from(&hash[..]) else {
+                    issues.push(IntegrityIssue::InvalidBlockHash(hash));
+                    continue;
+                };
+
+                let decoded = match header::decode(&scale_encoded_header, self.block_number_bytes) {
+                    Ok(h) => h,
+                    Err(error) => {
+                        issues.push(IntegrityIssue::InvalidHeader {
+                            block_hash: hash,
+                            error,
+                        });
+                        continue;
+                    }
+                };
+
+                if header::hash_from_scale_encoded_header(&scale_encoded_header) != hash {
+                    issues.push(IntegrityIssue::HeaderHashMismatch { block_hash: hash });
+                }
+
+                if u64::try_from(number) != Ok(decoded.number) {
+                    issues.push(IntegrityIssue::NumberMismatch { block_hash: hash });
+                }
+
+                match (parent_hash, decoded.number) {
+                    (None, _) if oldest_number == Some(number) => {}
+                    (Some(parent_hash), n) if n != 0 && parent_hash == decoded.parent_hash => {
+                        let parent_number = database
+                            .prepare_cached("SELECT number FROM blocks WHERE hash = ?")
+                            .map_err(InternalError)?
+                            .query_row((&parent_hash,), |row| row.get::<_, i64>(0))
+                            .optional()
+                            .map_err(InternalError)?;
+                        match parent_number {
+                            Some(p) if u64::try_from(p) == Ok(n - 1) => {}
+                            Some(_) => {
+                                issues.push(IntegrityIssue::NumberMismatch { block_hash: hash })
+                            }
+                            // Blocks are only ever removed from the database by being pruned,
+                            // and pruning removes a block only alongside all its descendants.
+                            // A missing parent thus indicates a corruption.
+                            None => issues.push(IntegrityIssue::MissingParent { block_hash: hash }),
+                        }
+                    }
+                    _ => issues.push(IntegrityIssue::ParentHashMismatch { block_hash: hash }),
+                }
+
+                match state_trie_root_hash {
+                    Some(root) if root != decoded.state_root => {
+                        issues.push(IntegrityIssue::StateRootMismatch { block_hash: hash })
+                    }
+                    Some(root) => {
+                        if !trie_node_exists(&database, &root)? {
+                            issues.push(IntegrityIssue::MissingStateTrieRoot { block_hash: hash })
+                        }
+                    }
+                    None => {
+                        // The storage of the ancestors of the finalized block is pruned.
+                        if finalized_number.is_some_and(|f| decoded.number >= f)
+                            && *decoded.state_root != trie::EMPTY_TRIE_MERKLE_VALUE
+                        {
+                            issues.push(IntegrityIssue::MissingState { block_hash: hash })
+                        }
+                    }
+                }
+
+                let body = database
+                    .prepare_cached("SELECT extrinsic FROM blocks_body WHERE hash = ? ORDER BY idx")
+                    .map_err(InternalError)?
+                    .query_map((&hash[..],), |row| row.get::<_, Vec<u8>>(0))
+                    .map_err(InternalError)?
+                    .collect::<Result<Vec<_>, _>>()
+                    .map_err(InternalError)?;
+                if header::extrinsics_root(&body) != *decoded.extrinsics_root {
+                    issues.push(IntegrityIssue::ExtrinsicsRootMismatch { block_hash: hash });
+                }
+            }
+        }
+
+        // The finalized chain must contain exactly one block per height.
+        if let Some(finalized_number) = finalized_number {
+            let mut statement = database
+                .prepare(
+                    "SELECT number, COUNT(*) FROM blocks WHERE number <= ? GROUP BY number ORDER BY number",
+                )
+                .map_err(InternalError)?;
+            let mut rows = statement
+                .query((i64::try_from(finalized_number).unwrap_or(i64::MAX),))
+                .map_err(InternalError)?;
+            let mut expected_number = None::<u64>;
+            while let Some(row) = rows.next().map_err(InternalError)? {
+                let Ok(number) = u64::try_from(row.get::<_, i64>(0).map_err(InternalError)?) else {
+                    continue;
+                };
+                // The database doesn't necessarily contain all the blocks down to the genesis.
+                if expected_number.is_some_and(|n| n != number) {
+                    issues.push(IntegrityIssue::FinalizedChainGap {
+                        number: expected_number.unwrap(),
+                    });
+                }
+                if row.get::<_, i64>(1).map_err(InternalError)? != 1 {
+                    issues.push(IntegrityIssue::FinalizedChainFork { number });
+                }
+                expected_number = Some(number + 1);
+            }
+            if expected_number != Some(finalized_number + 1) {
+                issues.push(IntegrityIssue::FinalizedChainGap {
+                    number: finalized_number,
+                });
+            }
+        }
+
+        // Check the Merkle value of every trie node.
+        {
+            let mut statement = database
+                .prepare(
+                    r#"
+                SELECT trie_node.hash, trie_node.partial_key, COALESCE(trie_node_storage.value, trie_node_storage.trie_root_ref), trie_node_storage.trie_entry_version, trie_node_storage.trie_root_ref
+                FROM trie_node
+                LEFT JOIN trie_node_storage ON trie_node.hash = trie_node_storage.node_hash
+                "#,
+                )
+                .map_err(InternalError)?;
+            let mut rows = statement.query(()).map_err(InternalError)?;
+            while let Some(row) = rows.next().map_err(InternalError)? {
+                let node_hash = row.get::<_, Vec<u8>>(0).map_err(InternalError)?;
+                let partial_key = row.get::<_, Vec<u8>>(1).map_err(InternalError)?;
+                let storage_value = row.get::<_, Option<Vec<u8>>>(2).map_err(InternalError)?;
+                let trie_entry_version = row.get::<_, Option<i64>>(3).map_err(InternalError)?;
+                let trie_root_ref = row.get::<_, Option<Vec<u8>>>(4).map_err(InternalError)?;
+
+                if let Some(trie_root_ref) = trie_root_ref {
+                    if !trie_node_exists(&database, &trie_root_ref)? {
+                        issues.push(IntegrityIssue::MissingTrieNode {
+                            parent_hash: node_hash.clone(),
+                            node_hash: trie_root_ref,
+                        });
+                    }
+                }
+
+                let Ok(partial_key) = partial_key
+                    .iter()
+                    .map(|n| trie::Nibble::try_from(*n))
+                    .collect::<Result<Vec<_>, _>>()
+                else {
+                    issues.push(IntegrityIssue::InvalidTrieNode { node_hash });
+                    continue;
+                };
+
+                let mut children: [Option<Vec<u8>>; 16] = array::from_fn(|_| None);
+                let mut children_valid = true;
+                for child in database
+                    .prepare_cached(
+                        "SELECT child_num, child_hash FROM trie_node_child WHERE hash = ?",
+                    )
+                    .map_err(InternalError)?
+                    .query_map((&node_hash,), |row| {
+                        Ok((row.get::<_, Vec<u8>>(0)?, row.get::<_, Vec<u8>>(1)?))
+                    })
+                    .map_err(InternalError)?
+                {
+                    let (child_num, child_hash) = child.map_err(InternalError)?;
+                    if !trie_node_exists(&database, &child_hash)? {
+                        issues.push(IntegrityIssue::MissingTrieNode {
+                            parent_hash: node_hash.clone(),
+                            node_hash: child_hash.clone(),
+                        });
+                    }
+                    match &child_num[..] {
+                        [n] if *n < 16 => children[usize::from(*n)] = Some(child_hash),
+                        _ => children_valid = false,
+                    }
+                }
+                if !children_valid {
+                    issues.push(IntegrityIssue::InvalidTrieNode { node_hash });
+                    continue;
+                }
+
+                let storage_value_hash;
+                let storage_value = match (&storage_value, trie_entry_version) {
+                    (None, _) => trie::trie_node::StorageValue::None,
+                    (Some(value), Some(1)) if value.len() >= 33 => {
+                        storage_value_hash = blake2_rfc::blake2b::blake2b(32, &[], value);
+                        trie::trie_node::StorageValue::Hashed(
+                            <&[u8; 32]>::try_from(storage_value_hash.as_bytes()).unwrap(),
+                        )
+                    }
+                    (Some(value), Some(0 | 1)) => trie::trie_node::StorageValue::Unhashed(value),
+                    (Some(_), _) => {
+                        issues.push(IntegrityIssue::InvalidTrieNode { node_hash });
+                        continue;
+                    }
+                };
+
+                // The root node of a trie is always hashed, while other nodes are hashed only if
+                // their encoding is at least 32 bytes long. As such, a Merkle value of 32 bytes
+                // can be calculated as if the node was a root node.
+                let merkle_value = trie::trie_node::calculate_merkle_value(
+                    trie::trie_node::Decoded {
+                        children,
+                        partial_key: partial_key.into_iter(),
+                        storage_value,
+                    },
+                    node_hash.len() == 32,
+                );
+
+                match merkle_value {
+                    Ok(merkle_value) if merkle_value.as_ref() == &node_hash[..] => {}
+                    Ok(_) => issues.push(IntegrityIssue::TrieNodeMerkleValueMismatch { node_hash }),
+                    Err(_) => issues.push(IntegrityIssue::InvalidTrieNode { node_hash }),
+                }
+            }
+        }
+
+        Ok(issues)
+    }
+}
+
+/// Returns `true` if the `trie_node` table contains a node with the given hash.
+fn trie_node_exists(
+    database: &rusqlite::Connection,
+    node_hash: &[u8],
+) -> Result<bool, InternalError> {
+    database
+        .prepare_cached("SELECT COUNT(*) FROM trie_node WHERE hash = ?")
+        .map_err(InternalError)?
+        .query_row((node_hash,), |row| row.get::<_, i64>(0))
+        .map(|count| count != 0)
+        .map_err(InternalError)
+}
+
+/// Problem found by [`SqliteFullDatabase::check_integrity`].
+#[derive(Debug, derive_more::Display)]
+pub enum IntegrityIssue {
+    /// The SQLite integrity check has reported a problem.
+    #[display(fmt = "SQLite integrity check: {_0}")]
+    Sqlite(String),
+    /// A row refers to a row of another table that doesn't exist.
+    #[display(fmt = "Foreign key violation in table {table} referring to table {parent_table}")]
+    ForeignKeyViolation { table: String, parent_table: String },
+    /// An entry of the `meta` table is missing or invalid.
+    #[display(fmt = "Missing or invalid meta value: {_0}")]
+    InvalidMeta(&'static str),
+    /// The consensus-related information about the finalized block couldn't be loaded.
+    #[display(fmt = "Invalid finalized block state: {_0}")]
+    InvalidFinalizedState(String),
+    /// The hash of a block isn't 32 bytes.
+    #[display(fmt = "Invalid block hash: 0x{}", "hex::encode(_0)")]
+    InvalidBlockHash(Vec<u8>),
+    /// The header of a block has failed to decode.
+    #[display(
+        fmt = "Block 0x{} has an invalid header: {error}",
+        "hex::encode(block_hash)"
+    )]
+    InvalidHeader {
+        block_hash: [u8; 32],
+        error: header::Error,
+    },
+    /// The hash of a block doesn't match its header.
+    #[display(fmt = "Block 0x{} doesn't match its header", "hex::encode(block_hash)")]
+    HeaderHashMismatch { block_hash: [u8; 32] },
+    /// The number of a block doesn't match its header or the number of its parent.
+    #[display(fmt = "Block 0x{} has an invalid number", "hex::encode(block_hash)")]
+    NumberMismatch { block_hash: [u8; 32] },
+    /// The parent hash of a block doesn't match its header.
+    #[display(
+        fmt = "Block 0x{} has an invalid parent hash",
+        "hex::encode(block_hash)"
+    )]
+    ParentHashMismatch { block_hash: [u8; 32] },
+    /// The parent of a non-finalized block is missing from the database.
+    #[display(fmt = "Parent of block 0x{} is missing", "hex::encode(block_hash)")]
+    MissingParent { block_hash: [u8; 32] },
+    /// The body of a block doesn't match the extrinsics root of its header.
+    #[display(
+        fmt = "Body of block 0x{} doesn't match its header",
+        "hex::encode(block_hash)"
+    )]
+    ExtrinsicsRootMismatch { block_hash: [u8; 32] },
+    /// The state trie root of a block doesn't match the state root of its header.
+    #[display(
+        fmt = "State trie root of block 0x{} doesn't match its header",
+        "hex::encode(block_hash)"
+    )]
+    StateRootMismatch { block_hash: [u8; 32] },
+    /// The root node of the state trie of a block can't be found in the database.
+    #[display(
+        fmt = "State trie root of block 0x{} is missing",
+        "hex::encode(block_hash)"
+    )]
+    MissingStateTrieRoot { block_hash: [u8; 32] },
+    /// The storage of the finalized block or of a non-finalized block is missing.
+    #[display(fmt = "Storage of block 0x{} is missing", "hex::encode(block_hash)")]
+    MissingState { block_hash: [u8; 32] },
+    /// No block could be found at the given height of the finalized chain.
+    #[display(fmt = "Finalized chain is missing block #{number}")]
+    FinalizedChainGap { number: u64 },
+    /// Multiple blocks have been found at the given height of the finalized chain.
+    #[display(fmt = "Finalized chain has multiple blocks at height #{number}")]
+    FinalizedChainFork { number: u64 },
+    /// A trie node has an invalid partial key, children, or storage value.
+    #[display(fmt = "Invalid trie node 0x{}", "hex::encode(node_hash)")]
+    InvalidTrieNode { node_hash: Vec<u8> },
+    /// A trie node refers to a child node, or to the root node of a child trie, that can't be
+    /// found in the database.
+    #[display(
+        fmt = "Trie node 0x{} refers to missing trie node 0x{}",
+        "hex::encode(parent_hash)",
+        "hex::encode(node_hash)"
+    )]
+    MissingTrieNode {
+        parent_hash: Vec<u8>,
+        node_hash: Vec<u8>,
+    },
+    /// The Merkle value of a trie node doesn't match its content.
+    #[display(
+        fmt = "Merkle value of trie node 0x{} doesn't match its content",
+        "hex::encode(node_hash)"
+    )]
+    TrieNodeMerkleValueMismatch { node_hash: Vec<u8> },
+}
@@ -25,14 +25,23 @@ use super::{
     encode_babe_epoch_information, insert_storage, AccessError, CorruptedError, InsertTrieNode,
     InternalError, SqliteFullDatabase,
 };
-use crate::chain::chain_information;
+use crate::{chain::chain_information, header};
 
+use rusqlite::OptionalExtension as _;
 use std::{fs, path::Path};
 
+/// Version of the schema of the database created or opened by this code.
+///
+/// Must be increased by one every time a migration is added to [`migrate`].
+pub const SCHEMA_VERSION: u32 = 5;
+
 /// Opens the database using the given [`Config`].
 ///
+/// If the database was created by an older version of this code, its schema is automatically
+/// upgraded to [`SCHEMA_VERSION`].
+///
 /// Note that this doesn't return a [`SqliteFullDatabase`], but rather a [`DatabaseOpen`].
-pub fn open(config: Config) -> Result<DatabaseOpen, InternalError> {
+pub fn open(config: Config) -> Result<DatabaseOpen, OpenError> {
     let flags = rusqlite::OpenFlags::SQLITE_OPEN_READ_WRITE |
         rusqlite::OpenFlags::SQLITE_OPEN_CREATE |
         // The "no mutex" option opens SQLite in "multi-threaded" mode, meaning that it can safely
@@ -43,7 +52,7 @@ pub fn open(config: Config) -> Result<DatabaseOpen, InternalError> {
         // See https://www.sqlite.org/threadsafe.html
         rusqlite::OpenFlags::SQLITE_OPEN_NO_MUTEX;
 
-    let database = match config.ty {
+    let mut database = match config.ty {
         ConfigTy::Disk { path, .. } => {
             // Ignoring errors in `create_dir_all`, in order to avoid making the API of this
             // function more complex. If `create_dir_all` fails, opening the database will most
@@ -107,12 +116,102 @@ PRAGMA foreign_keys = ON;
         .map_err(InternalError)?
         .query_row((), |row| row.get::<_, i64>(0))
         .map_err(InternalError)?;
+    let user_version = u32::try_from(user_version)
+        .map_err(|_| OpenError::UnsupportedSchemaVersion(user_version))?;
+    if user_version > SCHEMA_VERSION {
+        return Err(OpenError::UnsupportedSchemaVersion(i64::from(user_version)));
+    }
 
-    // Migrations.
-    if user_version <= 0 {
-        database
-            .execute_batch(
-                r#"
+    // Upgrade the schema one version at a time. Each migration is performed within a
+    // transaction, so that an interrupted migration doesn't leave the database in a half-way
+    // state.
+    for version in user_version..SCHEMA_VERSION {
+        let transaction = database.transaction().map_err(InternalError)?;
+        migrate(&transaction, version, &config).map_err(InternalError)?;
+        // `PRAGMA` queries can't be parametrized, and thus we have to use `format!`.
+        transaction
+            .execute_batch(&format!("PRAGMA user_version = {}", version + 1))
+            .map_err(InternalError)?;
+        transaction.commit().map_err(InternalError)?;
+    }
+
+    let is_empty = database
+        .prepare_cached("SELECT COUNT(*) FROM meta WHERE key = ?")
+        .map_err(InternalError)?
+        .query_row(("best",), |row| row.get::<_, i64>(0))
+        .map_err(InternalError)?
+        == 0;
+
+    if is_empty {
+        return Ok(DatabaseOpen::Empty(DatabaseEmpty {
+            database,
+            block_number_bytes: config.block_number_bytes,
+            genesis_block_hash: config.genesis_block_hash,
+            extrinsics_index: config.extrinsics_index,
+        }));
+    }
+
+    // Make sure that the database matches the chain that the API user expects.
+    let stored_block_number_bytes = super::meta_get_number(&database, "block_number_bytes")
+        .map_err(OpenError::Access)?
+        .ok_or(OpenError::Access(AccessError::Corrupted(
+            CorruptedError::MissingMetaKey,
+        )))?;
+    if usize::try_from(stored_block_number_bytes) != Ok(config.block_number_bytes) {
+        return Err(OpenError::BlockNumberBytesMismatch {
+            stored: stored_block_number_bytes,
+            expected: config.block_number_bytes,
+        });
+    }
+    if let Some(expected) = config.genesis_block_hash {
+        if let Some(stored) =
+            super::meta_get_blob(&database, "genesis_hash").map_err(OpenError::Access)?
+        {
+            if stored != expected {
+                return Err(OpenError::GenesisHashMismatch { stored, expected });
+            }
+        }
+    }
+
+    // Build the extrinsics index if it has been requested and doesn't exist yet.
+    let extrinsics_index_present = super::meta_get_number(&database, "extrinsics_index")
+        .map_err(OpenError::Access)?
+        .is_some();
+    if config.extrinsics_index && !extrinsics_index_present {
+        let transaction = database.transaction().map_err(InternalError)?;
+        let block_hashes = transaction
+            .prepare("SELECT hash FROM blocks")
+            .map_err(InternalError)?
+            .query_map((), |row| row.get::<_, Vec<u8>>(0))
+            .map_err(InternalError)?
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(InternalError)?;
+        for block_hash in block_hashes {
+            super::index_block_extrinsics(&transaction, &block_hash).map_err(OpenError::Access)?;
+        }
+        super::meta_set_number(&transaction, "extrinsics_index", 1).map_err(OpenError::Access)?;
+        transaction.commit().map_err(InternalError)?;
+    }
+
+    Ok(DatabaseOpen::Open(SqliteFullDatabase {
+        database: parking_lot::Mutex::new(database),
+        block_number_bytes: config.block_number_bytes,
+        extrinsics_index: config.extrinsics_index || extrinsics_index_present,
+    }))
+}
+
+/// Upgrades the schema of the database from `from_version` to `from_version + 1`.
+///
+/// The caller is responsible for updating the `user_version` afterwards.
+fn migrate(
+    transaction: &rusqlite::Transaction,
+    from_version: u32,
+    config: &Config,
+) -> Result<(), rusqlite::Error> {
+    match from_version {
+        // Empty database. Creates the entire schema.
+        0 => transaction.execute_batch(
+            r#"
 -- `auto_vacuum` can switched between `NONE` and non-`NONE` on newly-created database.
 PRAGMA auto_vacuum = INCREMENTAL;
 
@@ -136,6 +235,16 @@ Keys in that table:
  been scheduled in or before the finalized block. Missing if no change is scheduled or if the
  chain doesn't use Grandpa.
 
+ - `block_number_bytes` (number): Number of bytes used to encode the block number in headers.
+ Always present. Added in schema version 2.
+
+ - `genesis_hash` (blob): Hash of the genesis block of the chain. Missing if the database has been
+ initialized from a non-genesis block and the genesis hash wasn't provided. Added in schema
+ version 2.
+
+ - `extrinsics_index` (number): Always 1. Present if and only if the `extrinsics_index` table is
+ filled. Added in schema version 3.
+
  - `aura_slot_duration` (number): Duration of an Aura slot in milliseconds. Missing if and only if
  the chain doesn't use Aura.
 
@@ -204,7 +313,7 @@ List of all known blocks, indexed by their hash or number.
 */
 CREATE TABLE blocks(
     hash BLOB NOT NULL PRIMARY KEY,
-    parent_hash BLOB,  -- NULL only for the genesis block
+    parent_hash BLOB,  -- NULL only for the block the database has been initialized with, whose parent isn't in the database
     state_trie_root_hash BLOB,  -- NULL if and only if the trie is empty or if the trie storage has been pruned from the database
     number INTEGER NOT NULL,
     header BLOB NOT NULL,
@@ -264,31 +373,153 @@ CREATE TABLE aura_finalized_authorities(
     CHECK(length(public_key) == 32)
 );
 
-PRAGMA user_version = 1;
+        "#,
+        ),
+
+        // Version 2 stores the number of bytes used to encode block numbers and the genesis block
+        // hash. Databases of version 1 were always opened with the value of `block_number_bytes`
+        // that the API user passed, which is thus assumed to be correct.
+        1 => {
+            let is_empty = transaction
+                .prepare_cached("SELECT COUNT(*) FROM meta WHERE key = ?")?
+                .query_row(("best",), |row| row.get::<_, i64>(0))?
+                == 0;
+            if is_empty {
+                return Ok(());
+            }
+
+            transaction
+                .prepare_cached(
+                    "INSERT OR REPLACE INTO meta(key, value_number) VALUES ('block_number_bytes', ?)",
+                )?
+                .execute((i64::try_from(config.block_number_bytes).unwrap_or(i64::MAX),))?;
+
+            // Databases of version 1 were always initialized from the genesis block, but the
+            // genesis block might have been pruned since then.
+            let genesis_hash = transaction
+                .prepare_cached("SELECT hash FROM blocks WHERE number = 0")?
+                .query_row((), |row| row.get::<_, Vec<u8>>(0))
+                .optional()?;
+            if let Some(genesis_hash) = genesis_hash {
+                transaction
+                    .prepare_cached(
+                        "INSERT OR REPLACE INTO meta(key, value_blob) VALUES ('genesis_hash', ?)",
+                    )?
+                    .execute((&genesis_hash,))?;
+            }
+
+            Ok(())
+        }
+
+        // Version 3 adds the optional index of extrinsics by hash.
+        2 => transaction.execute_batch(
+            r#"
+/*
+Index of the extrinsics found in `blocks_body` by hash, where the hash of an extrinsic is the
+BLAKE2-256 hash of its SCALE encoding. Filled if and only if the `extrinsics_index` key of the
+`meta` table is present.
+*/
+CREATE TABLE extrinsics_index(
+    extrinsic_hash BLOB NOT NULL,
+    block_hash BLOB NOT NULL,
+    idx INTEGER NOT NULL,
+    UNIQUE(extrinsic_hash, block_hash, idx),
+    CHECK(length(extrinsic_hash) == 32),
+    FOREIGN KEY (block_hash, idx) REFERENCES blocks_body(hash, idx) ON UPDATE CASCADE ON DELETE CASCADE
+);
+CREATE INDEX extrinsics_index_by_block ON extrinsics_index(block_hash, idx);
+        "#,
+        ),
 
+        // Version 4 adds the storage of off-chain workers.
+        3 => transaction.execute_batch(
+            r#"
+/*
+Persistent storage of the off-chain workers. Unrelated to the storage of the blocks, and not
+affected by forks or finality.
+*/
+CREATE TABLE offchain_storage(
+    key BLOB NOT NULL PRIMARY KEY,
+    value BLOB NOT NULL
+);
         "#,
-            )
-            .map_err(InternalError)?
-    }
+        ),
 
-    let is_empty = database
-        .prepare_cached("SELECT COUNT(*) FROM meta WHERE key = ?")
-        .map_err(InternalError)?
-        .query_row(("best",), |row| row.get::<_, i64>(0))
-        .map_err(InternalError)?
-        == 0;
+        // Version 5 adds an index of the blocks whose header schedules or forces a change in the
+        // list of GrandPa authorities, in order to quickly build GrandPa warp sync proofs.
+        4 => {
+            transaction.execute_batch(
+                r#"
+/*
+`grandpa_authorities_change` is 1 if the header of the block contains a GrandPa scheduled or
+forced authorities change, and 0 otherwise.
+*/
+ALTER TABLE blocks ADD COLUMN grandpa_authorities_change INTEGER NOT NULL DEFAULT 0;
+CREATE INDEX blocks_by_grandpa_authorities_change ON blocks(number) WHERE grandpa_authorities_change != 0;
+            "#,
+            )?;
+
+            // Databases of version 2 or above store the number of bytes used to encode block
+            // numbers, which is more trustworthy than the value passed by the API user.
+            let block_number_bytes = transaction
+                .prepare_cached("SELECT value_number FROM meta WHERE key = 'block_number_bytes'")?
+                .query_row((), |row| row.get::<_, i64>(0))
+                .optional()?
+                .and_then(|n| usize::try_from(n).ok())
+                .unwrap_or(config.block_number_bytes);
+
+            let blocks = transaction
+                .prepare("SELECT hash, header FROM blocks")?
+                .query_map((), |row| Ok((row.get::<_, Vec<u8>>(0)?, row.get::<_, Vec<u8>>(1)?)))?
+                .collect::<Result<Vec<_>, _>>()?;
+            for (hash, scale_encoded_header) in blocks {
+                // Headers that can't be decoded are reported by the integrity check.
+                let Ok(decoded) = header::decode(&scale_encoded_header, block_number_bytes)
+                    else { continue };
+                if decoded.digest.has_grandpa_authorities_change() {
+                    transaction
+                        .prepare_cached(
+                            "UPDATE blocks SET grandpa_authorities_change = 1 WHERE hash = ?",
+                        )?
+                        .execute((&hash,))?;
+                }
+            }
 
-    Ok(if !is_empty {
-        DatabaseOpen::Open(SqliteFullDatabase {
-            database: parking_lot::Mutex::new(database),
-            block_number_bytes: config.block_number_bytes, // TODO: consider storing this value in the DB and check it when opening
-        })
-    } else {
-        DatabaseOpen::Empty(DatabaseEmpty {
-            database,
-            block_number_bytes: config.block_number_bytes,
-        })
-    })
+            Ok(())
+        }
+
+        _ => unreachable!(),
+    }
+}
+
+/// Error potentially returned by [`open`].
+#[derive(Debug, derive_more::Display, derive_more::From)]
+pub enum OpenError {
+    /// Low-level error while accessing the database.
+    #[display(fmt = "{_0}")]
+    Internal(InternalError),
+    /// Error while reading the content of the database.
+    #[display(fmt = "{_0}")]
+    Access(AccessError),
+    /// The database has been created by a newer, incompatible version of this code.
+    #[display(
+        fmt = "Database schema version {_0} isn't supported (latest supported version is {SCHEMA_VERSION})"
+    )]
+    UnsupportedSchemaVersion(i64),
+    /// The number of bytes used to encode block numbers stored in the database doesn't match the
+    /// one passed in the [`Config`].
+    #[display(
+        fmt = "Database uses {stored} bytes to encode block numbers, while {expected} were expected"
+    )]
+    BlockNumberBytesMismatch { stored: u64, expected: usize },
+    /// The genesis block hash stored in the database doesn't match the one passed in the
+    /// [`Config`]. The database most likely belongs to a different chain.
+    #[display(
+        fmt = "Database belongs to a chain whose genesis hash is 0x{}, while 0x{} was expected",
+        "hex::encode(stored)",
+        "hex::encode(expected)"
+    )]
+    GenesisHashMismatch { stored: Vec<u8>, expected: [u8; 32] },
 }
 
 /// Configuration for the database.
@@ -298,8 +529,25 @@ pub struct Config<'a> {
     pub ty: ConfigTy<'a>,
 
     /// Number of bytes used to encode the block number.
+    ///
+    /// An error is returned when opening a database that has been initialized with a different
+    /// value.
     pub block_number_bytes: usize,
 
+    /// Hash of the genesis block of the chain, if known.
+    ///
+    /// If `Some`, an error is returned when opening a database that belongs to a chain with a
+    /// different genesis block. This value is also stored in the database on initialization.
+    pub genesis_block_hash: Option<[u8; 32]>,
+
+    /// If `true`, the database maintains an index of the extrinsics of the stored block bodies,
+    /// which makes it possible to use [`SqliteFullDatabase::extrinsic_locations`].
+    ///
+    /// The index is built when opening a database that didn't have one, which can take a long
+    /// time. Once built, the index is always kept up to date, even if the database is later
+    /// opened with this value set to `false`.
+    pub extrinsics_index: bool,
+
     /// Maximum allowed size, in bytes, of the SQLite cache.
     pub cache_size: usize,
 }
@@ -339,6 +587,12 @@ pub struct DatabaseEmpty {
 
     /// See the similar field in [`SqliteFullDatabase`].
     block_number_bytes: usize,
+
+    /// See [`Config::genesis_block_hash`].
+    genesis_block_hash: Option<[u8; 32]>,
+
+    /// See [`Config::extrinsics_index`].
+    extrinsics_index: bool,
 }
 
 impl DatabaseEmpty {
@@ -391,18 +645,22 @@ impl DatabaseEmpty {
 
         transaction
             .prepare_cached(
-                "INSERT INTO blocks(hash, parent_hash, state_trie_root_hash, number, header, justification) VALUES(?, ?, ?, ?, ?, ?)",
+                "INSERT INTO blocks(hash, parent_hash, state_trie_root_hash, number, header, justification, grandpa_authorities_change) VALUES(?, ?, ?, ?, ?, ?, ?)",
             )
             .unwrap()
             .execute((
                 &finalized_block_hash[..],
-                if chain_information.finalized_block_header.number != 0 {
-                    Some(&chain_information.finalized_block_header.parent_hash[..])
-                } else { None },
+                // The parent of the finalized block isn't in the database, even if the finalized
+                // block isn't the genesis block.
+                None::<&[u8]>,
                 &chain_information.finalized_block_header.state_root[..],
                 i64::try_from(chain_information.finalized_block_header.number).unwrap(),
                 &scale_encoded_finalized_block_header[..],
                 finalized_block_justification.as_deref(),
+                chain_information
+                    .finalized_block_header
+                    .digest
+                    .has_grandpa_authorities_change(),
             ))
             .unwrap();
 
@@ -421,7 +679,29 @@ impl DatabaseEmpty {
             }
         }
 
+        if self.extrinsics_index {
+            super::index_block_extrinsics(&transaction, &finalized_block_hash)?;
+            super::meta_set_number(&transaction, "extrinsics_index", 1)?;
+        }
+
         super::meta_set_blob(&transaction, "best", &finalized_block_hash[..]).unwrap();
+        super::meta_set_number(
+            &transaction,
+            "block_number_bytes",
+            u64::try_from(self.block_number_bytes).unwrap(),
+        )?;
+        match (
+            self.genesis_block_hash,
+            chain_information.finalized_block_header.number,
+        ) {
+            (Some(genesis_block_hash), _) => {
+                super::meta_set_blob(&transaction, "genesis_hash", &genesis_block_hash[..])?
+            }
+            (None, 0) => {
+                super::meta_set_blob(&transaction, "genesis_hash", &finalized_block_hash[..])?
+            }
+            (None, _) => {}
+        }
         super::meta_set_number(
             &transaction,
             "finalized",
@@ -522,6 +802,7 @@ impl DatabaseEmpty {
         Ok(SqliteFullDatabase {
             database: parking_lot::Mutex::new(self.database),
             block_number_bytes: self.block_number_bytes,
+            extrinsics_index: self.extrinsics_index,
         })
     }
 }
@@ -17,11 +17,15 @@
 
 #![cfg(test)]
 
-use super::{open, Config, ConfigTy, DatabaseOpen, InsertTrieNode, InsertTrieNodeStorageValue};
+use super::{
+    open, Config, ConfigTy, DatabaseEmpty, DatabaseOpen, InsertTrieNode,
+    InsertTrieNodeStorageValue, IntegrityIssue, OpenError, SetJustificationError,
+    SqliteFullDatabase, SCHEMA_VERSION,
+};
 use crate::{chain::chain_information, header, trie};
 
 use alloc::borrow::Cow;
-use core::{array, iter};
+use core::{array, iter, num::NonZeroU64};
 use rand::distributions::{Distribution as _, Uniform};
 
 #[test]
@@ -30,6 +34,8 @@ fn empty_database_fill_then_query() {
     for _ in 0..1024 {
         let DatabaseOpen::Empty(empty_db) = open(Config {
             block_number_bytes: 4,
+            genesis_block_hash: None,
+            extrinsics_index: false,
             cache_size: 2 * 1024 * 1024,
             ty: ConfigTy::Memory,
         })
@@ -268,3 +274,812 @@ fn empty_database_fill_then_query() {
         }
     }
 }
+
+#[test]
+fn initialized_database_has_no_integrity_issue() {
+    let DatabaseOpen::Empty(empty_db) = open(Config {
+        block_number_bytes: 4,
+        genesis_block_hash: None,
+        extrinsics_index: false,
+        cache_size: 2 * 1024 * 1024,
+        ty: ConfigTy::Memory,
+    })
+    .unwrap() else { panic!() };
+
+    let (open_db, _) = initialize_single_node(empty_db, &[b"foo", b"bar"]);
+
+    let integrity_issues = open_db.check_integrity().unwrap();
+    assert!(integrity_issues.is_empty(), "{integrity_issues:?}");
+}
+
+#[test]
+fn check_integrity_detects_corruption() {
+    let DatabaseOpen::Empty(empty_db) = open(Config {
+        block_number_bytes: 4,
+        genesis_block_hash: None,
+        extrinsics_index: false,
+        cache_size: 2 * 1024 * 1024,
+        ty: ConfigTy::Memory,
+    })
+    .unwrap() else { panic!() };
+
+    let (open_db, state_root) = initialize_single_node(empty_db, &[]);
+
+    assert!(open_db.check_integrity().unwrap().is_empty());
+
+    let block0_hash = open_db.finalized_block_hash().unwrap();
+    open_db
+        .database
+        .lock()
+        .execute(
+            "INSERT INTO blocks_body(hash, idx, extrinsic) VALUES(?, 0, X'00')",
+            (&block0_hash[..],),
+        )
+        .unwrap();
+
+    let issues = open_db.check_integrity().unwrap();
+    assert_eq!(issues.len(), 1);
+    assert!(matches!(
+        issues[0],
+        IntegrityIssue::ExtrinsicsRootMismatch { block_hash } if block_hash == block0_hash
+    ));
+
+    open_db
+        .database
+        .lock()
+        .execute("UPDATE trie_node_storage SET value = X'00'", ())
+        .unwrap();
+
+    let issues = open_db.check_integrity().unwrap();
+    assert_eq!(issues.len(), 2);
+    assert!(matches!(
+        &issues[1],
+        IntegrityIssue::TrieNodeMerkleValueMismatch { node_hash } if *node_hash == state_root
+    ));
+}
+
+#[test]
+fn check_integrity_detects_missing_trie_nodes() {
+    let DatabaseOpen::Empty(empty_db) = open(Config {
+        block_number_bytes: 4,
+        genesis_block_hash: None,
+        extrinsics_index: false,
+        cache_size: 2 * 1024 * 1024,
+        ty: ConfigTy::Memory,
+    })
+    .unwrap() else { panic!() };
+
+    let (open_db, state_root) = initialize_single_node(empty_db, &[]);
+    let block0_hash = open_db.finalized_block_hash().unwrap();
+
+    // Foreign keys must be disabled in order to be able to corrupt the database.
+    open_db
+        .database
+        .lock()
+        .execute_batch("PRAGMA foreign_keys = OFF")
+        .unwrap();
+
+    open_db
+        .database
+        .lock()
+        .execute(
+            "INSERT INTO trie_node_child(hash, child_num, child_hash) VALUES(?, X'00', X'0102')",
+            (&state_root[..],),
+        )
+        .unwrap();
+
+    let issues = open_db.check_integrity().unwrap();
+    assert!(issues.iter().any(|issue| matches!(
+        issue,
+        IntegrityIssue::MissingTrieNode { parent_hash, node_hash }
+            if *parent_hash == state_root && *node_hash == [1, 2]
+    )));
+    assert!(!issues
+        .iter()
+        .any(|issue| matches!(issue, IntegrityIssue::MissingStateTrieRoot { .. })));
+
+    open_db
+        .database
+        .lock()
+        .execute("DELETE FROM trie_node WHERE hash = ?", (&state_root[..],))
+        .unwrap();
+
+    let issues = open_db.check_integrity().unwrap();
+    assert!(issues.iter().any(|issue| matches!(
+        issue,
+        IntegrityIssue::MissingStateTrieRoot { block_hash } if *block_hash == block0_hash
+    )));
+}
+
+#[test]
+fn reopen_checks_chain_and_migrates() {
+    let directory = tempfile::tempdir().unwrap();
+    let config = |block_number_bytes, genesis_block_hash| Config {
+        block_number_bytes,
+        genesis_block_hash,
+        extrinsics_index: false,
+        cache_size: 2 * 1024 * 1024,
+        ty: ConfigTy::Disk {
+            path: directory.path(),
+            memory_map_size: 0,
+        },
+    };
+
+    let DatabaseOpen::Empty(empty_db) = open(config(4, None)).unwrap() else { panic!() };
+    let genesis_hash = initialize_single_node(empty_db, &[])
+        .0
+        .finalized_block_hash()
+        .unwrap();
+
+    assert!(matches!(
+        open(config(4, Some(genesis_hash))),
+        Ok(DatabaseOpen::Open(_))
+    ));
+    assert!(matches!(
+        open(config(8, Some(genesis_hash))),
+        Err(OpenError::BlockNumberBytesMismatch {
+            stored: 4,
+            expected: 8
+        })
+    ));
+    assert!(matches!(
+        open(config(4, Some([0xff; 32]))),
+        Err(OpenError::GenesisHashMismatch { .. })
+    ));
+
+    // Turn the database back into a database of schema version 1, then make sure that it is
+    // properly upgraded.
+    {
+        let database =
+            rusqlite::Connection::open(directory.path().join("database.sqlite")).unwrap();
+        database
+            .execute_batch(
+                "DELETE FROM meta WHERE key IN ('block_number_bytes', 'genesis_hash');
+                DROP TABLE extrinsics_index;
+                DROP TABLE offchain_storage;
+                DROP INDEX blocks_by_grandpa_authorities_change;
+                ALTER TABLE blocks DROP COLUMN grandpa_authorities_change;
+                PRAGMA user_version = 1;",
+            )
+            .unwrap();
+    }
+    assert!(matches!(
+        open(config(4, Some(genesis_hash))),
+        Ok(DatabaseOpen::Open(_))
+    ));
+    assert!(matches!(
+        open(config(4, Some([0xff; 32]))),
+        Err(OpenError::GenesisHashMismatch { .. })
+    ));
+
+    // Databases created by a future version must be rejected.
+    {
+        let database =
+            rusqlite::Connection::open(directory.path().join("database.sqlite")).unwrap();
+        database
+            .execute_batch(&format!("PRAGMA user_version = {}", SCHEMA_VERSION + 1))
+            .unwrap();
+    }
+    assert!(matches!(
+        open(config(4, Some(genesis_hash))),
+        Err(OpenError::UnsupportedSchemaVersion(_))
+    ));
+}
+
+#[test]
+fn extrinsics_index() {
+    let directory = tempfile::tempdir().unwrap();
+    let config = |extrinsics_index| Config {
+        block_number_bytes: 4,
+        genesis_block_hash: None,
+        extrinsics_index,
+        cache_size: 2 * 1024 * 1024,
+        ty: ConfigTy::Disk {
+            path: directory.path(),
+            memory_map_size: 0,
+        },
+    };
+
+    let extrinsic1_hash = *<&[u8; 32]>::try_from(
+        blake2_rfc::blake2b::blake2b(32, &[], b"\x04a").as_bytes(),
+    )
+    .unwrap();
+    let extrinsic2_hash = *<&[u8; 32]>::try_from(
+        blake2_rfc::blake2b::blake2b(32, &[], b"\x04b").as_bytes(),
+    )
+    .unwrap();
+
+    let DatabaseOpen::Empty(empty_db) = open(config(true)).unwrap() else { panic!() };
+    let (database, _) = initialize_single_node(empty_db, &[b"\x04a", b"\x04b", b"\x04a"]);
+    let genesis_hash = database.finalized_block_hash().unwrap();
+    assert_eq!(
+        database.extrinsic_locations(&extrinsic1_hash).unwrap(),
+        Some(vec![(genesis_hash, 0), (genesis_hash, 2)])
+    );
+    assert_eq!(
+        database.extrinsic_locations(&extrinsic2_hash).unwrap(),
+        Some(vec![(genesis_hash, 1)])
+    );
+    assert_eq!(
+        database.extrinsic_locations(&[0; 32]).unwrap(),
+        Some(Vec::new())
+    );
+    drop(database);
+
+    // Once built, the index is kept even if not requested.
+    let DatabaseOpen::Open(database) = open(config(false)).unwrap() else { panic!() };
+    assert_eq!(
+        database.extrinsic_locations(&extrinsic2_hash).unwrap(),
+        Some(vec![(genesis_hash, 1)])
+    );
+    drop(database);
+
+    // Requesting the index on a database that doesn't have one builds it.
+    {
+        let database =
+            rusqlite::Connection::open(directory.path().join("database.sqlite")).unwrap();
+        database
+            .execute_batch(
+                "DELETE FROM extrinsics_index;
+                DELETE FROM meta WHERE key = 'extrinsics_index';",
+            )
+            .unwrap();
+    }
+    let DatabaseOpen::Open(database) = open(config(false)).unwrap() else { panic!() };
+    assert_eq!(database.extrinsic_locations(&extrinsic1_hash).unwrap(), None);
+    drop(database);
+    let DatabaseOpen::Open(database) = open(config(true)).unwrap() else { panic!() };
+    assert_eq!(
+        database.extrinsic_locations(&extrinsic1_hash).unwrap(),
+        Some(vec![(genesis_hash, 0), (genesis_hash, 2)])
+    );
+    assert!(database.check_integrity().unwrap().is_empty());
+}
+
+#[test]
+fn offchain_storage() {
+    let directory = tempfile::tempdir().unwrap();
+    let config = || Config {
+        block_number_bytes: 4,
+        genesis_block_hash: None,
+        extrinsics_index: false,
+        cache_size: 2 * 1024 * 1024,
+        ty: ConfigTy::Disk {
+            path: directory.path(),
+            memory_map_size: 0,
+        },
+    };
+
+    let DatabaseOpen::Empty(empty_db) = open(config()).unwrap() else { panic!() };
+    let (database, _) = initialize_single_node(empty_db, &[]);
+    assert_eq!(database.offchain_storage_get(b"foo").unwrap(), None);
+
+    assert!(!database
+        .offchain_storage_compare_and_set(b"foo", Some(Some(b"bar")), Some(b"baz"))
+        .unwrap());
+    assert!(database
+        .offchain_storage_compare_and_set(b"foo", Some(None), Some(b"bar"))
+        .unwrap());
+    assert!(database
+        .offchain_storage_compare_and_set(b"foo", Some(Some(b"bar")), Some(b"baz"))
+        .unwrap());
+    assert!(database
+        .offchain_storage_compare_and_set(b"other", None, Some(b"value"))
+        .unwrap());
+    drop(database);
+
+    // The storage is persisted.
+    let DatabaseOpen::Open(database) = open(config()).unwrap() else { panic!() };
+    assert_eq!(
+        database.offchain_storage_get(b"foo").unwrap(),
+        Some(b"baz".to_vec())
+    );
+    assert!(database
+        .offchain_storage_compare_and_set(b"foo", None, None)
+        .unwrap());
+    assert_eq!(database.offchain_storage_get(b"foo").unwrap(), None);
+    assert_eq!(
+        database.offchain_storage_get(b"other").unwrap(),
+        Some(b"value".to_vec())
+    );
+    assert!(database.check_integrity().unwrap().is_empty());
+}
+
+#[test]
+fn justifications() {
+    let DatabaseOpen::Empty(empty_db) = open(Config {
+        block_number_bytes: 4,
+        genesis_block_hash: None,
+        extrinsics_index: false,
+        cache_size: 2 * 1024 * 1024,
+        ty: ConfigTy::Memory,
+    })
+    .unwrap() else { panic!() };
+    let (database, state_root) = initialize_single_node(empty_db, &[]);
+    let genesis_hash = database.finalized_block_hash().unwrap();
+
+    let block1 = header::HeaderRef {
+        number: 1,
+        extrinsics_root: &header::extrinsics_root(&[] as &[&[u8]]),
+        parent_hash: &genesis_hash,
+        state_root: &state_root,
+        digest: header::DigestRef::empty(),
+    }
+    .scale_encoding_vec(4);
+    let block1_hash = header::hash_from_scale_encoded_header(&block1);
+    database
+        .insert(&block1, true, iter::empty::<Vec<u8>>(), iter::empty(), 0)
+        .unwrap();
+
+    assert!(matches!(
+        database.set_block_justification(&[0xff; 32], b"foo"),
+        Err(SetJustificationError::UnknownBlock)
+    ));
+    assert!(matches!(
+        database.set_block_justification(&block1_hash, b"foo"),
+        Err(SetJustificationError::NotFinalized)
+    ));
+    assert_eq!(database.block_justification(&block1_hash).unwrap(), None);
+    assert!(database.finalized_justified_blocks(0).unwrap().is_empty());
+
+    database.set_finalized(&block1_hash).unwrap();
+    database
+        .set_block_justification(&block1_hash, b"foo")
+        .unwrap();
+    database
+        .set_block_justification(&block1_hash, b"bar")
+        .unwrap();
+    assert_eq!(
+        database.block_justification(&block1_hash).unwrap(),
+        Some(b"bar".to_vec())
+    );
+    assert_eq!(
+        database.finalized_justified_blocks(0).unwrap(),
+        vec![block1_hash]
+    );
+    assert!(database.finalized_justified_blocks(1).unwrap().is_empty());
+    assert_eq!(
+        database.latest_finalized_justified_block(0, 2).unwrap(),
+        Some(block1_hash)
+    );
+    assert_eq!(
+        database.latest_finalized_justified_block(0, 1).unwrap(),
+        None
+    );
+    assert_eq!(
+        database.latest_finalized_justified_block(1, 2).unwrap(),
+        None
+    );
+}
+
+#[test]
+fn set_finalized_updates_consensus_state() {
+    let DatabaseOpen::Empty(empty_db) = open(Config {
+        block_number_bytes: 4,
+        genesis_block_hash: None,
+        extrinsics_index: false,
+        cache_size: 2 * 1024 * 1024,
+        ty: ConfigTy::Memory,
+    })
+    .unwrap() else { panic!() };
+
+    let aura_authorities = |n: u8| {
+        vec![header::AuraAuthority {
+            public_key: [n; 32],
+        }]
+    };
+    let grandpa_authorities = |n: u8| {
+        vec![header::GrandpaAuthority {
+            public_key: [n; 32],
+            weight: NonZeroU64::new(1).unwrap(),
+        }]
+    };
+
+    let genesis_aura_authorities = aura_authorities(0);
+    let genesis_grandpa_authorities = grandpa_authorities(0);
+    let (database, state_root) = initialize_single_node_with(
+        empty_db,
+        &[],
+        chain_information::ChainInformationConsensusRef::Aura {
+            finalized_authorities_list: header::AuraAuthoritiesIter::from_slice(
+                &genesis_aura_authorities,
+            ),
+            slot_duration: NonZeroU64::new(6000).unwrap(),
+        },
+        chain_information::ChainInformationFinalityRef::Grandpa {
+            after_finalized_block_authorities_set_id: 0,
+            finalized_triggered_authorities: &genesis_grandpa_authorities,
+            finalized_scheduled_change: None,
+        },
+    );
+
+    // Block #1 changes the Aura authorities, block #2 schedules a GrandPa change with a delay,
+    // block #3 forces a GrandPa change that immediately applies and discards the scheduled one,
+    // and block #4 schedules another change.
+    let new_aura_authorities = aura_authorities(1);
+    let (scheduled1, forced, scheduled2) = (
+        grandpa_authorities(1),
+        grandpa_authorities(2),
+        grandpa_authorities(3),
+    );
+    let digest_items = [
+        header::DigestItemRef::AuraConsensus(header::AuraConsensusLogRef::AuthoritiesChange(
+            header::AuraAuthoritiesIter::from_slice(&new_aura_authorities),
+        )),
+        header::DigestItemRef::GrandpaConsensus(header::GrandpaConsensusLogRef::ScheduledChange(
+            header::GrandpaScheduledChangeRef {
+                next_authorities: header::GrandpaAuthoritiesIter::new(&scheduled1),
+                delay: 2,
+            },
+        )),
+        header::DigestItemRef::GrandpaConsensus(header::GrandpaConsensusLogRef::ForcedChange {
+            reset_block_height: 0,
+            change: header::GrandpaScheduledChangeRef {
+                next_authorities: header::GrandpaAuthoritiesIter::new(&forced),
+                delay: 0,
+            },
+        }),
+        header::DigestItemRef::GrandpaConsensus(header::GrandpaConsensusLogRef::ScheduledChange(
+            header::GrandpaScheduledChangeRef {
+                next_authorities: header::GrandpaAuthoritiesIter::new(&scheduled2),
+                delay: 1,
+            },
+        )),
+    ];
+
+    let mut block_hashes = vec![database.finalized_block_hash().unwrap()];
+    for (number, digest_item) in (1..).zip(digest_items) {
+        let scale_encoded_header = header::HeaderRef {
+            number,
+            extrinsics_root: &header::extrinsics_root(&[] as &[&[u8]]),
+            parent_hash: block_hashes.last().unwrap(),
+            state_root: &state_root,
+            digest: header::DigestRef::empty(),
+        }
+        .scale_encoding_with_extra_digest_item(4, digest_item)
+        .fold(Vec::new(), |mut a, b| {
+            a.extend_from_slice(b.as_ref());
+            a
+        });
+        database
+            .insert(
+                &scale_encoded_header,
+                true,
+                iter::empty::<Vec<u8>>(),
+                iter::empty(),
+                0,
+            )
+            .unwrap();
+        block_hashes.push(header::hash_from_scale_encoded_header(
+            &scale_encoded_header,
+        ));
+    }
+
+    database.set_finalized(&block_hashes[2]).unwrap();
+    let chain_information = chain_information::ChainInformation::from(
+        database.to_chain_information(&block_hashes[2]).unwrap(),
+    );
+    let chain_information::ChainInformationConsensus::Aura {
+        finalized_authorities_list,
+        ..
+    } = chain_information.consensus else { panic!() };
+    assert_eq!(finalized_authorities_list, new_aura_authorities);
+    let chain_information::ChainInformationFinality::Grandpa {
+        after_finalized_block_authorities_set_id,
+        finalized_triggered_authorities,
+        finalized_scheduled_change,
+    } = chain_information.finality else { panic!() };
+    assert_eq!(after_finalized_block_authorities_set_id, 0);
+    assert_eq!(finalized_triggered_authorities, genesis_grandpa_authorities);
+    assert_eq!(finalized_scheduled_change, Some((4, scheduled1)));
+    assert_eq!(
+        database.finalized_grandpa_authorities_changes(0).unwrap(),
+        vec![block_hashes[2]]
+    );
+
+    database.set_finalized(&block_hashes[4]).unwrap();
+    let chain_information = chain_information::ChainInformation::from(
+        database.to_chain_information(&block_hashes[4]).unwrap(),
+    );
+    let chain_information::ChainInformationFinality::Grandpa {
+        after_finalized_block_authorities_set_id,
+        finalized_triggered_authorities,
+        finalized_scheduled_change,
+    } = chain_information.finality else { panic!() };
+    assert_eq!(after_finalized_block_authorities_set_id, 1);
+    assert_eq!(finalized_triggered_authorities, forced);
+    assert_eq!(finalized_scheduled_change, Some((5, scheduled2)));
+    assert_eq!(
+        database.finalized_grandpa_authorities_changes(2).unwrap(),
+        vec![block_hashes[3], block_hashes[4]]
+    );
+}
+
+#[test]
+fn child_trie_values_copied_from_parent() {
+    let DatabaseOpen::Empty(empty_db) = open(Config {
+        block_number_bytes: 4,
+        genesis_block_hash: None,
+        extrinsics_index: false,
+        cache_size: 2 * 1024 * 1024,
+        ty: ConfigTy::Memory,
+    })
+    .unwrap() else { panic!() };
+
+    fn merkle_value(
+        children: [Option<&[u8]>; 16],
+        partial_key: &[u8],
+        storage_value: &[u8],
+        is_root_node: bool,
+    ) -> Vec<u8> {
+        trie::trie_node::calculate_merkle_value(
+            trie::trie_node::Decoded {
+                children,
+                partial_key: partial_key
+                    .iter()
+                    .map(|n| trie::Nibble::try_from(*n).unwrap()),
+                storage_value: trie::trie_node::StorageValue::Unhashed(storage_value),
+            },
+            is_root_node,
+        )
+        .unwrap()
+        .as_ref()
+        .to_vec()
+    }
+
+    // The main trie contains a single node that references a child trie. The child trie
+    // contains a root node with a storage value, and two leaves at `0x10` and `0x20`.
+    let child_trie_key = trie::bytes_to_nibbles(b":child_storage:default:foo".iter().copied())
+        .map(u8::from)
+        .collect::<Vec<_>>();
+    let leaf1 = merkle_value([None; 16], &[0], b"a", false);
+    let leaf2 = merkle_value([None; 16], &[0], b"b", false);
+    let child_root = merkle_value(
+        array::from_fn(|n| match n {
+            1 => Some(&leaf1[..]),
+            2 => Some(&leaf2[..]),
+            _ => None,
+        }),
+        &[],
+        b"root",
+        true,
+    );
+    let main_root = merkle_value([None; 16], &child_trie_key, &child_root, true);
+    let state_root = <[u8; 32]>::try_from(&main_root[..]).unwrap();
+
+    let database = empty_db
+        .initialize(
+            chain_information::ChainInformationRef {
+                finalized_block_header: header::HeaderRef {
+                    number: 0,
+                    extrinsics_root: &header::extrinsics_root(&[] as &[&[u8]]),
+                    parent_hash: &[0; 32],
+                    state_root: &state_root,
+                    digest: header::DigestRef::empty(),
+                },
+                consensus: chain_information::ChainInformationConsensusRef::Unknown,
+                finality: chain_information::ChainInformationFinalityRef::Outsourced,
+            },
+            iter::empty(),
+            None,
+            [
+                InsertTrieNode {
+                    storage_value: InsertTrieNodeStorageValue::Value {
+                        value: Cow::Borrowed(&child_root[..]),
+                        references_merkle_value: true,
+                    },
+                    merkle_value: Cow::Borrowed(&main_root[..]),
+                    children_merkle_values: array::from_fn(|_| None),
+                    partial_key_nibbles: Cow::Borrowed(&child_trie_key[..]),
+                },
+                InsertTrieNode {
+                    storage_value: InsertTrieNodeStorageValue::Value {
+                        value: Cow::Borrowed(b"root"),
+                        references_merkle_value: false,
+                    },
+                    merkle_value: Cow::Borrowed(&child_root[..]),
+                    children_merkle_values: array::from_fn(|n| match n {
+                        1 => Some(Cow::Borrowed(&leaf1[..])),
+                        2 => Some(Cow::Borrowed(&leaf2[..])),
+                        _ => None,
+                    }),
+                    partial_key_nibbles: Cow::Borrowed(&[]),
+                },
+                InsertTrieNode {
+                    storage_value: InsertTrieNodeStorageValue::Value {
+                        value: Cow::Borrowed(b"a"),
+                        references_merkle_value: false,
+                    },
+                    merkle_value: Cow::Borrowed(&leaf1[..]),
+                    children_merkle_values: array::from_fn(|_| None),
+                    partial_key_nibbles: Cow::Borrowed(&[0]),
+                },
+                InsertTrieNode {
+                    storage_value: InsertTrieNodeStorageValue::Value {
+                        value: Cow::Borrowed(b"b"),
+                        references_merkle_value: false,
+                    },
+                    merkle_value: Cow::Borrowed(&leaf2[..]),
+                    children_merkle_values: array::from_fn(|_| None),
+                    partial_key_nibbles: Cow::Borrowed(&[0]),
+                },
+            ]
+            .into_iter(),
+            0,
+        )
+        .unwrap();
+    let genesis_hash = database.finalized_block_hash().unwrap();
+
+    // Block 1 modifies the value at `0x20`. The root of the child trie is modified as well, but
+    // its storage value is copied from the parent.
+    let new_leaf2 = merkle_value([None; 16], &[0], b"c", false);
+    let new_child_root = merkle_value(
+        array::from_fn(|n| match n {
+            1 => Some(&leaf1[..]),
+            2 => Some(&new_leaf2[..]),
+            _ => None,
+        }),
+        &[],
+        b"root",
+        true,
+    );
+    let new_main_root = merkle_value([None; 16], &child_trie_key, &new_child_root, true);
+
+    let block1 = header::HeaderRef {
+        number: 1,
+        extrinsics_root: &header::extrinsics_root(&[] as &[&[u8]]),
+        parent_hash: &genesis_hash,
+        state_root: &<[u8; 32]>::try_from(&new_main_root[..]).unwrap(),
+        digest: header::DigestRef::empty(),
+    }
+    .scale_encoding_vec(4);
+    let block1_hash = header::hash_from_scale_encoded_header(&block1);
+    database
+        .insert(
+            &block1,
+            true,
+            iter::empty::<Vec<u8>>(),
+            [
+                InsertTrieNode {
+                    storage_value: InsertTrieNodeStorageValue::Value {
+                        value: Cow::Borrowed(&new_child_root[..]),
+                        references_merkle_value: true,
+                    },
+                    merkle_value: Cow::Borrowed(&new_main_root[..]),
+                    children_merkle_values: array::from_fn(|_| None),
+                    partial_key_nibbles: Cow::Borrowed(&child_trie_key[..]),
+                },
+                InsertTrieNode {
+                    storage_value: InsertTrieNodeStorageValue::SameAsParent,
+                    merkle_value: Cow::Borrowed(&new_child_root[..]),
+                    children_merkle_values: array::from_fn(|n| match n {
+                        1 => Some(Cow::Borrowed(&leaf1[..])),
+                        2 => Some(Cow::Borrowed(&new_leaf2[..])),
+                        _ => None,
+                    }),
+                    partial_key_nibbles: Cow::Borrowed(&[]),
+                },
+                InsertTrieNode {
+                    storage_value: InsertTrieNodeStorageValue::Value {
+                        value: Cow::Borrowed(b"c"),
+                        references_merkle_value: false,
+                    },
+                    merkle_value: Cow::Borrowed(&new_leaf2[..]),
+                    children_merkle_values: array::from_fn(|_| None),
+                    partial_key_nibbles: Cow::Borrowed(&[0]),
+                },
+            ]
+            .into_iter(),
+            0,
+        )
+        .unwrap();
+
+    let child_value = |block_hash: &[u8; 32], key: &[u8]| {
+        database
+            .block_storage_get(
+                block_hash,
+                iter::once(child_trie_key.iter().copied()),
+                key.iter().copied(),
+            )
+            .unwrap()
+            .map(|(value, _)| value)
+    };
+
+    assert_eq!(child_value(&genesis_hash, &[]), Some(b"root".to_vec()));
+    assert_eq!(child_value(&genesis_hash, &[2, 0]), Some(b"b".to_vec()));
+    assert_eq!(child_value(&block1_hash, &[]), Some(b"root".to_vec()));
+    assert_eq!(child_value(&block1_hash, &[1, 0]), Some(b"a".to_vec()));
+    assert_eq!(child_value(&block1_hash, &[2, 0]), Some(b"c".to_vec()));
+
+    let child_next_key = |key: &[u8]| {
+        database
+            .block_storage_next_key(
+                &block1_hash,
+                iter::once(child_trie_key.iter().copied()),
+                key.iter().copied(),
+                iter::empty(),
+                true,
+            )
+            .unwrap()
+    };
+
+    assert_eq!(child_next_key(&[]), Some(Vec::new()));
+    assert_eq!(child_next_key(&[1, 1]), Some(vec![2, 0]));
+    assert_eq!(child_next_key(&[2, 1]), None);
+
+    assert_eq!(
+        database
+            .block_storage_closest_descendant_merkle_value(
+                &block1_hash,
+                iter::once(child_trie_key.iter().copied()),
+                [2].into_iter(),
+            )
+            .unwrap(),
+        Some(new_leaf2)
+    );
+}
+
+/// Initializes the given database with a genesis block with the given body and whose storage
+/// contains a single entry.
+///
+/// Returns the database and the state root of the genesis block.
+fn initialize_single_node(
+    empty_db: DatabaseEmpty,
+    body: &[&[u8]],
+) -> (SqliteFullDatabase, [u8; 32]) {
+    initialize_single_node_with(
+        empty_db,
+        body,
+        chain_information::ChainInformationConsensusRef::Unknown,
+        chain_information::ChainInformationFinalityRef::Outsourced,
+    )
+}
+
+/// Same as [`initialize_single_node`], but with the given consensus and finality information.
+fn initialize_single_node_with(
+    empty_db: DatabaseEmpty,
+    body: &[&[u8]],
+    consensus: chain_information::ChainInformationConsensusRef,
+    finality: chain_information::ChainInformationFinalityRef,
+) -> (SqliteFullDatabase, [u8; 32]) {
+    let root_merkle_value = trie::trie_node::calculate_merkle_value(
+        trie::trie_node::Decoded {
+            children: [None::<&[u8]>; 16],
+            partial_key: iter::empty(),
+            storage_value: trie::trie_node::StorageValue::Unhashed(b"hello"),
+        },
+        true,
+    )
+    .unwrap();
+    let state_root = *<&[u8; 32]>::try_from(root_merkle_value.as_ref()).unwrap();
+
+    let database = empty_db
+        .initialize(
+            chain_information::ChainInformationRef {
+                finalized_block_header: header::HeaderRef {
+                    number: 0,
+                    extrinsics_root: &header::extrinsics_root(body),
+                    parent_hash: &[0; 32],
+                    state_root: &state_root,
+                    digest: header::DigestRef::empty(),
+                },
+                consensus,
+                finality,
+            },
+            body.iter().copied(),
+            None,
+            iter::once(InsertTrieNode {
+                storage_value: InsertTrieNodeStorageValue::Value {
+                    value: Cow::Borrowed(b"hello"),
+                    references_merkle_value: false,
+                },
+                merkle_value: Cow::Borrowed(&state_root[..]),
+                children_merkle_values: array::from_fn(|_| None),
+                partial_key_nibbles: Cow::Borrowed(&[]),
+            }),
+            0,
+        )
+        .unwrap();
+
+    (database, state_root)
+}
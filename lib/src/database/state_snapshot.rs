@@ -0,0 +1,370 @@
+// Smoldot
+// Copyright (C) 2023  Pierre Krieger
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Encoding and decoding of state snapshots.
+//!
+//! A state snapshot contains the chain information of a finalized block (which includes its
+//! header), the body of this block, and the complete content of the storage of this block,
+//! including its child tries.
+//! It can be used in order to initialize a database at this block rather than at the genesis
+//! block, skipping the process of syncing the chain from the genesis.
+//!
+//! # Format
+//!
+//! A state snapshot consists in:
+//!
+//! - The 8 bytes [`MAGIC`], followed with a one byte format version, currently always 1.
+//! - The 32 bytes hash of the genesis block of the chain the snapshot belongs to.
+//! - The SCALE-encoded (i.e. length-prefixed) chain information, in the JSON format of
+//!   [`finalized_serialize::encode_chain`].
+//! - The SCALE-encoded body of the block, in other words a SCALE-compact number of extrinsics
+//!   followed with each SCALE-encoded extrinsic.
+//! - A list of storage entries. Each entry starts with a one byte tag: `0` for an entry of the
+//!   main trie, `1` for an entry of a child trie, and `0xff` to indicate the end of the list. The
+//!   tag of child trie entries is followed with the SCALE-encoded identifier of the child trie.
+//!   Then comes the SCALE-encoded key, the SCALE-encoded value, and a one byte trie entry version.
+//! - The 32 bytes BLAKE2 hash of everything that precedes, used as a checksum.
+//!
+//! Entries can be in any order.
+//!
+//! # Verification
+//!
+//! [`decode`] verifies that the trie roots calculated from the entries match the state root
+//! found in the header of the block, that the entries of the main trie that refer to child
+//! tries match the roots of these child tries, and that the body matches the extrinsics root
+//! found in the header. As such, a successfully-decoded snapshot is guaranteed to be consistent
+//! with the block header that it contains.
+//!
+//! The genesis block hash, however, can't be verified, as the snapshot doesn't contain the
+//! ancestors of its block. It is up to the user to compare it with the genesis block hash of
+//! the chain they expect.
+
+use super::finalized_serialize;
+use crate::{
+    chain::chain_information,
+    header,
+    trie::{self, calculate_root, CHILD_STORAGE_PREFIX},
+    util,
+};
+
+use alloc::{collections::BTreeMap, vec::Vec};
+use core::{iter, ops};
+
+mod tests;
+
+/// Bytes found at the start of every state snapshot.
+pub const MAGIC: [u8; 8] = *b"smolsnap";
+
+/// Version of the format written by [`SnapshotEncoder`].
+const FORMAT_VERSION: u8 = 1;
+
+/// Entries of a trie, indexed by key. Values are the storage value and its version.
+pub type TrieEntries<'a> = BTreeMap<&'a [u8], (&'a [u8], trie::TrieEntryVersion)>;
+
+/// Incrementally builds a state snapshot.
+///
+/// The bytes returned by the methods of this struct must be concatenated together in order to
+/// form the snapshot.
+pub struct SnapshotEncoder {
+    /// Hash of everything that has been encoded so far.
+    hasher: blake2_rfc::blake2b::Blake2b,
+}
+
+impl SnapshotEncoder {
+    /// Starts encoding a snapshot of the finalized block described by the given chain
+    /// information and whose body is `finalized_block_body`.
+    ///
+    /// Returns the encoder and the bytes that the snapshot starts with.
+    pub fn new<'a>(
+        genesis_block_hash: &[u8; 32],
+        information: impl Into<chain_information::ValidChainInformationRef<'a>>,
+        finalized_block_body: impl ExactSizeIterator<Item = impl AsRef<[u8]>>,
+        block_number_bytes: usize,
+    ) -> (Self, Vec<u8>) {
+        let chain_information = finalized_serialize::encode_chain(information, block_number_bytes);
+
+        let mut out = Vec::with_capacity(MAGIC.len() + 1 + 32 + 5 + chain_information.len() + 5);
+        out.extend_from_slice(&MAGIC);
+        out.push(FORMAT_VERSION);
+        out.extend_from_slice(genesis_block_hash);
+        out.extend_from_slice(util::encode_scale_compact_usize(chain_information.len()).as_ref());
+        out.extend_from_slice(chain_information.as_bytes());
+        out.extend_from_slice(
+            util::encode_scale_compact_usize(finalized_block_body.len()).as_ref(),
+        );
+        for extrinsic in finalized_block_body {
+            let extrinsic = extrinsic.as_ref();
+            out.extend_from_slice(util::encode_scale_compact_usize(extrinsic.len()).as_ref());
+            out.extend_from_slice(extrinsic);
+        }
+
+        let mut hasher = blake2_rfc::blake2b::Blake2b::new(32);
+        hasher.update(&out);
+        (SnapshotEncoder { hasher }, out)
+    }
+
+    /// Encodes an entry of the storage. `child_trie` is `None` for entries of the main trie.
+    ///
+    /// Returns the bytes to append to the snapshot.
+    pub fn encode_entry(
+        &mut self,
+        child_trie: Option<&[u8]>,
+        key: &[u8],
+        value: &[u8],
+        version: trie::TrieEntryVersion,
+    ) -> Vec<u8> {
+        let mut out = Vec::with_capacity(1 + 5 + key.len() + 5 + value.len() + 1);
+        if let Some(child_trie) = child_trie {
+            out.push(1);
+            out.extend_from_slice(util::encode_scale_compact_usize(child_trie.len()).as_ref());
+            out.extend_from_slice(child_trie);
+        } else {
+            out.push(0);
+        }
+        out.extend_from_slice(util::encode_scale_compact_usize(key.len()).as_ref());
+        out.extend_from_slice(key);
+        out.extend_from_slice(util::encode_scale_compact_usize(value.len()).as_ref());
+        out.extend_from_slice(value);
+        out.push(u8::from(version));
+
+        self.hasher.update(&out);
+        out
+    }
+
+    /// Finishes the encoding. Returns the bytes to append to the snapshot.
+    pub fn finish(mut self) -> Vec<u8> {
+        self.hasher.update(&[0xff]);
+        iter::once(0xff)
+            .chain(self.hasher.finalize().as_bytes().iter().copied())
+            .collect()
+    }
+}
+
+/// Decodes and verifies a state snapshot.
+///
+/// See the documentation of the module for what is verified.
+pub fn decode(encoded: &[u8], block_number_bytes: usize) -> Result<Decoded<'_>, DecodeError> {
+    if !encoded.starts_with(&MAGIC) {
+        return Err(DecodeError::InvalidMagic);
+    }
+
+    let Some((content, checksum)) = encoded
+        .len()
+        .checked_sub(32)
+        .filter(|n| *n > MAGIC.len())
+        .map(|n| encoded.split_at(n))
+        else { return Err(DecodeError::InvalidFormat) };
+    if blake2_rfc::blake2b::blake2b(32, &[], content).as_bytes() != checksum {
+        return Err(DecodeError::ChecksumMismatch);
+    }
+
+    let after_magic = &content[MAGIC.len()..];
+    if after_magic[0] != FORMAT_VERSION {
+        return Err(DecodeError::UnsupportedVersion(after_magic[0]));
+    }
+
+    let (genesis_block_hash, chain_information, body, entries) = nom::combinator::all_consuming::<_, _, nom::error::Error<&[u8]>, _>(
+        nom::sequence::tuple((
+            nom::combinator::map(nom::bytes::complete::take(32u32), |hash| {
+                <[u8; 32]>::try_from(hash).unwrap()
+            }),
+            util::nom_string_decode,
+            nom::combinator::flat_map(util::nom_scale_compact_usize, |num_extrinsics| {
+                nom::multi::many_m_n(num_extrinsics, num_extrinsics, util::nom_bytes_decode)
+            }),
+            nom::multi::many_till(
+                nom::sequence::tuple((
+                    nom::branch::alt((
+                        nom::combinator::map(nom::bytes::complete::tag(&[0]), |_| None),
+                        nom::combinator::map(
+                            nom::sequence::preceded(
+                                nom::bytes::complete::tag(&[1]),
+                                util::nom_bytes_decode,
+                            ),
+                            Some,
+                        ),
+                    )),
+                    util::nom_bytes_decode,
+                    util::nom_bytes_decode,
+                    nom::combinator::map_res(nom::number::complete::u8, |version| {
+                        trie::TrieEntryVersion::try_from(version)
+                    }),
+                )),
+                nom::bytes::complete::tag(&[0xff]),
+            ),
+        )),
+    )(&after_magic[1..])
+    .map(|(_, (genesis_block_hash, chain_information, body, (entries, _)))| {
+        (genesis_block_hash, chain_information, body, entries)
+    })
+    .map_err(|_| DecodeError::InvalidFormat)?;
+
+    let chain_information =
+        finalized_serialize::decode_chain(chain_information, block_number_bytes)
+            .map_err(DecodeError::InvalidChainInformation)?
+            .chain_information;
+
+    if header::extrinsics_root(&body)
+        != *chain_information.as_ref().finalized_block_header.extrinsics_root
+    {
+        return Err(DecodeError::ExtrinsicsRootMismatch);
+    }
+
+    let mut main_trie = TrieEntries::new();
+    let mut child_tries = BTreeMap::<&[u8], TrieEntries>::new();
+    for (child_trie, key, value, version) in entries {
+        let trie = match child_trie {
+            Some(child_trie) => child_tries.entry(child_trie).or_default(),
+            None => &mut main_trie,
+        };
+        if trie.insert(key, (value, version)).is_some() {
+            return Err(DecodeError::DuplicateEntry);
+        }
+    }
+
+    // Make sure that the child tries and the entries of the main trie that point to them match.
+    for (child_trie, entries) in &child_tries {
+        let key = CHILD_STORAGE_PREFIX
+            .iter()
+            .chain(child_trie.iter())
+            .copied()
+            .collect::<Vec<_>>();
+        let root = trie_root(entries);
+        if main_trie.get(&key[..]).map(|(value, _)| *value) != Some(&root[..]) {
+            return Err(DecodeError::ChildTrieRootMismatch(child_trie.to_vec()));
+        }
+    }
+    for key in main_trie
+        .range::<[u8], _>((ops::Bound::Included(CHILD_STORAGE_PREFIX), ops::Bound::Unbounded))
+        .map(|(key, _)| *key)
+        .take_while(|key| key.starts_with(CHILD_STORAGE_PREFIX))
+    {
+        let child_trie = &key[CHILD_STORAGE_PREFIX.len()..];
+        if !child_tries.contains_key(child_trie) {
+            return Err(DecodeError::ChildTrieRootMismatch(child_trie.to_vec()));
+        }
+    }
+
+    let calculated = trie_root(&main_trie);
+    let expected = chain_information.as_ref().finalized_block_header.state_root;
+    if calculated != *expected {
+        return Err(DecodeError::StateRootMismatch {
+            expected: *expected,
+            calculated,
+        });
+    }
+
+    Ok(Decoded {
+        genesis_block_hash,
+        chain_information,
+        body,
+        main_trie,
+        child_tries,
+    })
+}
+
+/// Outcome of [`decode`].
+pub struct Decoded<'a> {
+    /// Hash of the genesis block of the chain the snapshot belongs to.
+    ///
+    /// > **Note**: This value isn't verified by [`decode`].
+    pub genesis_block_hash: [u8; 32],
+    /// Chain information of the block whose storage is contained in the snapshot.
+    pub chain_information: chain_information::ValidChainInformation,
+    /// List of SCALE-encoded extrinsics of the body of the block.
+    pub body: Vec<&'a [u8]>,
+    /// Entries of the main trie of the storage of the block.
+    pub main_trie: TrieEntries<'a>,
+    /// Entries of each child trie of the storage of the block, indexed by child trie identifier.
+    pub child_tries: BTreeMap<&'a [u8], TrieEntries<'a>>,
+}
+
+/// Error potentially returned by [`decode`].
+#[derive(Debug, derive_more::Display)]
+pub enum DecodeError {
+    /// The data doesn't start with [`MAGIC`].
+    #[display(fmt = "Not a state snapshot")]
+    InvalidMagic,
+    /// The snapshot uses a version of the format that isn't supported.
+    #[display(fmt = "Unsupported snapshot format version: {_0}")]
+    UnsupportedVersion(u8),
+    /// The checksum at the end of the snapshot doesn't match its content.
+    #[display(fmt = "Snapshot checksum mismatch")]
+    ChecksumMismatch,
+    /// Failed to parse the content of the snapshot.
+    #[display(fmt = "Invalid snapshot format")]
+    InvalidFormat,
+    /// Failed to decode the chain information contained in the snapshot.
+    #[display(fmt = "Invalid chain information: {_0}")]
+    InvalidChainInformation(finalized_serialize::CorruptedError),
+    /// The body of the block doesn't match the extrinsics root found in the header.
+    #[display(fmt = "Block body doesn't match the extrinsics root of the header")]
+    ExtrinsicsRootMismatch,
+    /// The same key is found multiple times in the same trie.
+    #[display(fmt = "Duplicate entry in snapshot")]
+    DuplicateEntry,
+    /// The root of a child trie doesn't match the corresponding entry of the main trie, or the
+    /// main trie refers to a child trie that isn't in the snapshot.
+    #[display(fmt = "Mismatch in the root of child trie 0x{}", "hex::encode(_0)")]
+    ChildTrieRootMismatch(Vec<u8>),
+    /// The root of the main trie doesn't match the state root found in the header.
+    #[display(
+        fmt = "State root mismatch: header contains 0x{}, snapshot has 0x{}",
+        "hex::encode(expected)",
+        "hex::encode(calculated)"
+    )]
+    StateRootMismatch {
+        /// State root found in the header of the block.
+        expected: [u8; 32],
+        /// State root calculated from the entries of the snapshot.
+        calculated: [u8; 32],
+    },
+}
+
+/// Calculates the Merkle value of the root of the trie containing the given entries.
+fn trie_root(entries: &TrieEntries) -> [u8; 32] {
+    let mut calculation = calculate_root::root_merkle_value();
+    loop {
+        match calculation {
+            calculate_root::RootMerkleValueCalculation::Finished { hash } => return hash,
+            calculate_root::RootMerkleValueCalculation::NextKey(next_key) => {
+                let key_before = next_key.key_before().collect::<Vec<_>>();
+                let lower_bound = if next_key.or_equal() {
+                    ops::Bound::Included(&key_before[..])
+                } else {
+                    ops::Bound::Excluded(&key_before[..])
+                };
+                let outcome = entries
+                    .range::<[u8], _>((lower_bound, ops::Bound::Unbounded))
+                    .next()
+                    .map(|(key, _)| *key)
+                    .filter(|key| {
+                        key.iter()
+                            .copied()
+                            .zip(next_key.prefix())
+                            .all(|(a, b)| a == b)
+                    });
+                calculation = next_key.inject_key(outcome.map(|key| key.iter().copied()));
+            }
+            calculate_root::RootMerkleValueCalculation::StorageValue(value_request) => {
+                let key = value_request.key().collect::<Vec<u8>>();
+                calculation = value_request.inject(entries.get(&key[..]).copied());
+            }
+        }
+    }
+}
+
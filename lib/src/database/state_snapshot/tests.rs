@@ -0,0 +1,187 @@
+// Smoldot
+// Copyright (C) 2023  Pierre Krieger
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <http://www.gnu.org/licenses/>.
+
+#![cfg(test)]
+
+use super::{decode, DecodeError, SnapshotEncoder};
+use crate::{chain::chain_information, header, trie};
+use core::num::NonZeroU64;
+
+/// Body of the block of the snapshots built by [`build_snapshot`].
+const BODY: [&[u8]; 2] = [b"extrinsic", &[0x56; 40]];
+
+/// Builds a snapshot containing the given child trie entries and main trie entries. The state
+/// root of the header is calculated from the entries, unless `state_root` is provided.
+fn build_snapshot(
+    main_trie: &[(&[u8], &[u8])],
+    child_trie: &[(&[u8], &[u8])],
+    state_root: Option<[u8; 32]>,
+) -> Vec<u8> {
+    let child_root = trie::trie_root(trie::TrieEntryVersion::V1, child_trie);
+    let child_trie_key = b":child_storage:default:foo".to_vec();
+
+    let mut main_trie_with_child = main_trie
+        .iter()
+        .map(|(k, v)| (k.to_vec(), v.to_vec()))
+        .collect::<Vec<_>>();
+    if !child_trie.is_empty() {
+        main_trie_with_child.push((child_trie_key.clone(), child_root.to_vec()));
+    }
+
+    let state_root = state_root.unwrap_or_else(|| {
+        trie::trie_root(trie::TrieEntryVersion::V1, &main_trie_with_child)
+    });
+
+    let chain_information = chain_information::ValidChainInformation::try_from(
+        chain_information::ChainInformation {
+            finalized_block_header: Box::new(header::Header {
+                parent_hash: [0; 32],
+                number: 5,
+                state_root,
+                extrinsics_root: header::extrinsics_root(&BODY),
+                digest: header::Digest::from(header::DigestRef::empty()),
+            }),
+            consensus: chain_information::ChainInformationConsensus::Aura {
+                finalized_authorities_list: Vec::new(),
+                slot_duration: NonZeroU64::new(6000).unwrap(),
+            },
+            finality: chain_information::ChainInformationFinality::Outsourced,
+        },
+    )
+    .unwrap();
+
+    let (mut encoder, mut out) =
+        SnapshotEncoder::new(&[0xee; 32], &chain_information, BODY.iter(), 4);
+    for (key, value) in &main_trie_with_child {
+        out.extend(encoder.encode_entry(None, key, value, trie::TrieEntryVersion::V1));
+    }
+    for (key, value) in child_trie {
+        out.extend(encoder.encode_entry(Some(b"foo"), key, value, trie::TrieEntryVersion::V1));
+    }
+    out.extend(encoder.finish());
+    out
+}
+
+#[test]
+fn round_trip() {
+    let snapshot = build_snapshot(
+        &[(b"hello", b"world"), (b"foo", &[0x12; 64]), (b"", b"empty")],
+        &[(b"child", b"value"), (b"child2", &[0x34; 40])],
+        None,
+    );
+
+    let decoded = decode(&snapshot, 4).unwrap();
+    assert_eq!(decoded.genesis_block_hash, [0xee; 32]);
+    assert_eq!(decoded.body, BODY);
+    assert_eq!(
+        decoded
+            .chain_information
+            .as_ref()
+            .finalized_block_header
+            .number,
+        5
+    );
+    assert_eq!(decoded.main_trie.len(), 4);
+    assert_eq!(decoded.main_trie[&b"hello"[..]].0, b"world");
+    assert_eq!(decoded.child_tries.len(), 1);
+    assert_eq!(decoded.child_tries[&b"foo"[..]][&b"child"[..]].0, b"value");
+}
+
+#[test]
+fn empty_storage() {
+    let snapshot = build_snapshot(&[], &[], None);
+    let decoded = decode(&snapshot, 4).unwrap();
+    assert!(decoded.main_trie.is_empty());
+    assert!(decoded.child_tries.is_empty());
+}
+
+#[test]
+fn checksum_mismatch() {
+    let mut snapshot = build_snapshot(&[(b"hello", b"world")], &[], None);
+    let len = snapshot.len();
+    snapshot[len - 40] ^= 0x1;
+    assert!(matches!(
+        decode(&snapshot, 4),
+        Err(DecodeError::ChecksumMismatch)
+    ));
+}
+
+#[test]
+fn state_root_mismatch() {
+    let snapshot = build_snapshot(&[(b"hello", b"world")], &[], Some([0xaa; 32]));
+    assert!(matches!(
+        decode(&snapshot, 4),
+        Err(DecodeError::StateRootMismatch { expected, .. }) if expected == [0xaa; 32]
+    ));
+}
+
+#[test]
+fn child_trie_root_mismatch() {
+    let mut snapshot = build_snapshot(&[(b"hello", b"world")], &[(b"child", b"value")], None);
+
+    // Decode then re-encode the snapshot with an entry missing from the child trie. The state
+    // root of the header still matches the main trie, but the child trie no longer does.
+    let decoded = decode(&snapshot, 4).unwrap();
+    let (mut encoder, mut out) = SnapshotEncoder::new(
+        &decoded.genesis_block_hash,
+        &decoded.chain_information,
+        decoded.body.iter(),
+        4,
+    );
+    for (key, (value, version)) in &decoded.main_trie {
+        out.extend(encoder.encode_entry(None, key, value, *version));
+    }
+    out.extend(encoder.encode_entry(Some(b"foo"), b"other", b"value", trie::TrieEntryVersion::V1));
+    out.extend(encoder.finish());
+    snapshot = out;
+
+    assert!(matches!(
+        decode(&snapshot, 4),
+        Err(DecodeError::ChildTrieRootMismatch(child_trie)) if child_trie == b"foo"
+    ));
+}
+
+#[test]
+fn extrinsics_root_mismatch() {
+    let snapshot = build_snapshot(&[(b"hello", b"world")], &[], None);
+
+    // Re-encode the snapshot with one extrinsic missing from the body.
+    let decoded = decode(&snapshot, 4).unwrap();
+    let (mut encoder, mut out) = SnapshotEncoder::new(
+        &decoded.genesis_block_hash,
+        &decoded.chain_information,
+        decoded.body.iter().take(1),
+        4,
+    );
+    for (key, (value, version)) in &decoded.main_trie {
+        out.extend(encoder.encode_entry(None, key, value, *version));
+    }
+    out.extend(encoder.finish());
+
+    assert!(matches!(
+        decode(&out, 4),
+        Err(DecodeError::ExtrinsicsRootMismatch)
+    ));
+}
+
+#[test]
+fn invalid_magic() {
+    let mut snapshot = build_snapshot(&[(b"hello", b"world")], &[], None);
+    snapshot[0] = b'x';
+    assert!(matches!(decode(&snapshot, 4), Err(DecodeError::InvalidMagic)));
+    assert!(matches!(decode(b"smolsnap", 4), Err(DecodeError::InvalidFormat)));
+}
@@ -29,10 +29,12 @@
 //! sub-module is [`runtime_host`].
 
 mod allocator; // TODO: make public after refactoring
+pub mod differential;
 pub mod host;
 pub mod read_only_runtime_host;
 pub mod runtime_host;
 pub mod storage_diff;
+pub mod trace;
 pub mod trie_root_calculator;
 pub mod vm;
 
@@ -73,3 +75,79 @@ pub enum InvalidHeapPagesError {
     /// Number of heap pages is too large.
     TooLarge,
 }
+
+/// Answers a [`host::CallRuntimeVersion`] request by compiling the provided runtime code with
+/// the number of heap pages found in the storage, at the key `:heappages`, and reading its
+/// runtime version.
+///
+/// The runtime version is reported as unavailable if `storage_heap_pages` is invalid or if the
+/// runtime code fails to compile.
+pub(crate) fn resume_call_runtime_version(
+    req: host::CallRuntimeVersion,
+    storage_heap_pages: Option<&[u8]>,
+) -> host::HostVm {
+    // TODO: make the user execute this ; see https://github.com/paritytech/smoldot/issues/144
+    // The code below compiles the provided WebAssembly runtime code, which is a
+    // relatively expensive operation (in the order of milliseconds).
+    // While it could be tempting to use a system cache, this function is expected
+    // to be called only right before runtime upgrades. Considering that runtime
+    // upgrades are quite uncommon and that a caching system is rather non-trivial
+    // to set up, the approach of recompiling every single time is preferred here.
+    let Ok(heap_pages) = storage_heap_pages_to_value(storage_heap_pages) else {
+        return req.resume(Err(()));
+    };
+
+    let vm_prototype = match host::HostVmPrototype::new(host::Config {
+        module: req.wasm_code(),
+        heap_pages,
+        exec_hint: vm::ExecHint::Oneshot,
+        compilation_cache: None,
+        allow_unresolved_imports: false, // TODO: what is a correct value here?
+    }) {
+        Ok(w) => w,
+        Err(_) => return req.resume(Err(())),
+    };
+
+    req.resume(Ok(vm_prototype.runtime_version().as_ref()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{storage_heap_pages_to_value, vm, InvalidHeapPagesError, DEFAULT_HEAP_PAGES};
+
+    #[test]
+    fn heap_pages_absent() {
+        assert_eq!(
+            storage_heap_pages_to_value(None).unwrap(),
+            DEFAULT_HEAP_PAGES
+        );
+    }
+
+    #[test]
+    fn heap_pages_valid() {
+        assert_eq!(
+            storage_heap_pages_to_value(Some(&1024u64.to_le_bytes())).unwrap(),
+            vm::HeapPages::new(1024)
+        );
+        assert_eq!(
+            storage_heap_pages_to_value(Some(&u64::from(u32::MAX).to_le_bytes())).unwrap(),
+            vm::HeapPages::new(u32::MAX)
+        );
+    }
+
+    #[test]
+    fn heap_pages_invalid() {
+        assert!(matches!(
+            storage_heap_pages_to_value(Some(&[])),
+            Err(InvalidHeapPagesError::WrongLen)
+        ));
+        assert!(matches!(
+            storage_heap_pages_to_value(Some(&1024u32.to_le_bytes())),
+            Err(InvalidHeapPagesError::WrongLen)
+        ));
+        assert!(matches!(
+            storage_heap_pages_to_value(Some(&(u64::from(u32::MAX) + 1).to_le_bytes())),
+            Err(InvalidHeapPagesError::TooLarge)
+        ));
+    }
+}
@@ -0,0 +1,961 @@
+// Smoldot
+// Copyright (C) 2023  Pierre Krieger
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Runtime call executed simultaneously on two different execution engines.
+//!
+//! This module is a wrapper around [`runtime_host`]. The same runtime call is started on two
+//! virtual machines, the *reference* and the *candidate*, which are normally compiled with
+//! respectively [`super::vm::ExecHint::ForceWasmi`] and `ExecHint::ForceWasmtime`. Both executions
+//! progress in lockstep: each request (storage access, signature verification, etc.) is
+//! exposed to the user only once, and the answer is passed to both virtual machines.
+//!
+//! Once the executions are over, their outcomes are compared. This includes the value returned
+//! by the runtime, the storage changes, the log messages, and the list of host functions called
+//! by the runtime together with their parameters and return values. Since the memory allocator
+//! is implemented through host functions, this last check also covers the behaviour of the
+//! allocator.
+//!
+//! If the executions differ, the first divergence is reported through a [`Divergence`].
+//!
+//! > **Note**: The keystore and the off-chain-worker-related host functions aren't supported.
+//! >           The keystore is considered empty, and calls to off-chain-worker-related host
+//! >           functions are rejected. This mode is meant to be used to execute blocks and to
+//! >           perform regular runtime calls.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use smoldot::executor::{differential, host, vm};
+//!
+//! # let runtime_code: Vec<u8> = unimplemented!();
+//! let build = |exec_hint| {
+//!     host::HostVmPrototype::new(host::Config {
+//!         module: &runtime_code,
+//!         heap_pages: host::HeapPages::from(2048),
+//!         exec_hint,
+//!         allow_unresolved_imports: false,
+//!         compilation_cache: None,
+//!     })
+//!     .unwrap()
+//! };
+//!
+//! let mut execution = differential::run(differential::Config {
+//!     reference: build(vm::ExecHint::ForceWasmi),
+//!     candidate: build(vm::ExecHint::force_wasmtime_if_available().unwrap()),
+//!     function_to_call: "Core_version",
+//!     parameter: core::iter::empty::<Vec<u8>>(),
+//!     storage_main_trie_changes: Default::default(),
+//!     max_log_level: 0,
+//! });
+//!
+//! loop {
+//!     match execution {
+//!         differential::DifferentialRuntimeHostVm::Finished(Ok(_)) => break,
+//!         differential::DifferentialRuntimeHostVm::Finished(Err(divergence)) => {
+//!             panic!("{}", divergence.detail)
+//!         }
+//!         differential::DifferentialRuntimeHostVm::StorageGet(req) => {
+//!             execution = req.inject_value(None::<(core::iter::Empty<Vec<u8>>, _)>);
+//!         }
+//!         // Other requests omitted for brevity.
+//!         _ => unimplemented!()
+//!     }
+//! }
+//! ```
+
+use super::{host, runtime_host, storage_diff, trace};
+use crate::trie::{self, Nibble};
+
+use alloc::{collections::BTreeMap, vec::Vec};
+use core::{iter, mem};
+
+pub use runtime_host::TrieEntryVersion;
+
+mod tests;
+
+/// Configuration for [`run`].
+pub struct Config<'a, TParams> {
+    /// Virtual machine whose behaviour is considered as the correct one.
+    pub reference: host::HostVmPrototype,
+
+    /// Virtual machine whose behaviour is compared with the one of
+    /// [`Config::reference`]. Must have been created from the same runtime code and heap pages
+    /// as [`Config::reference`].
+    pub candidate: host::HostVmPrototype,
+
+    /// Name of the function to call on both virtual machines.
+    pub function_to_call: &'a str,
+
+    /// Parameter of the call, as an iterator of bytes. The concatenation of bytes forms the
+    /// actual input.
+    pub parameter: TParams,
+
+    /// Initial state of the main trie. See [`runtime_host::Config::storage_main_trie_changes`].
+    pub storage_main_trie_changes: storage_diff::TrieDiff,
+
+    /// Maximum log level of the runtime. See [`runtime_host::Config::max_log_level`].
+    pub max_log_level: u32,
+}
+
+/// Start running the call on the two virtual machines.
+pub fn run(
+    config: Config<impl Iterator<Item = impl AsRef<[u8]>> + Clone>,
+) -> DifferentialRuntimeHostVm {
+    let reference = runtime_host::run(runtime_host::Config {
+        virtual_machine: config.reference,
+        function_to_call: config.function_to_call,
+        parameter: config.parameter.clone(),
+        storage_main_trie_changes: config.storage_main_trie_changes.clone(),
+        offchain_storage_changes: Default::default(),
+        max_log_level: config.max_log_level,
+        trace: true,
+        fuel_budget: None,
+        max_pending_changes_size: None,
+    });
+
+    let candidate = runtime_host::run(runtime_host::Config {
+        virtual_machine: config.candidate,
+        function_to_call: config.function_to_call,
+        parameter: config.parameter,
+        storage_main_trie_changes: config.storage_main_trie_changes,
+        offchain_storage_changes: Default::default(),
+        max_log_level: config.max_log_level,
+        trace: true,
+        fuel_budget: None,
+        max_pending_changes_size: None,
+    });
+
+    match (reference, candidate) {
+        (Ok(reference), Ok(candidate)) => advance(reference, candidate),
+        (Err((error, reference_prototype)), Err((_, candidate_prototype))) => {
+            // Starting a virtual machine doesn't involve executing any code. Both virtual
+            // machines failing to start is not a divergence.
+            DifferentialRuntimeHostVm::Finished(Ok(Success {
+                outcome: Err(Failure::Start(error)),
+                reference_prototype: Some(reference_prototype),
+                candidate_prototype,
+            }))
+        }
+        (Ok(reference), Err((error, candidate_prototype))) => {
+            DifferentialRuntimeHostVm::Finished(Err(Divergence {
+                detail: DivergenceDetail::Start {
+                    reference: None,
+                    candidate: Some(error),
+                },
+                reference_prototype: reference.into_prototype(),
+                candidate_prototype,
+            }))
+        }
+        (Err((error, reference_prototype)), Ok(candidate)) => {
+            DifferentialRuntimeHostVm::Finished(Err(Divergence {
+                detail: DivergenceDetail::Start {
+                    reference: Some(error),
+                    candidate: None,
+                },
+                reference_prototype,
+                candidate_prototype: candidate.into_prototype(),
+            }))
+        }
+    }
+}
+
+/// Both executions have finished identically.
+#[derive(Debug)]
+pub struct Success {
+    /// Outcome of the execution on the reference virtual machine.
+    ///
+    /// If `Ok`, the [`runtime_host::Success::virtual_machine`] contains the reference
+    /// virtual machine. If `Err`, the reference virtual machine is found in
+    /// [`Success::reference_prototype`].
+    pub outcome: Result<runtime_host::Success, Failure>,
+
+    /// Prototype of the reference virtual machine, if the execution has failed. `None` if
+    /// [`Success::outcome`] is `Ok`.
+    pub reference_prototype: Option<host::HostVmPrototype>,
+
+    /// Prototype that was passed through [`Config::candidate`].
+    pub candidate_prototype: host::HostVmPrototype,
+}
+
+impl Success {
+    /// Turns this object back into the two prototypes that were passed in the [`Config`], in
+    /// the order reference then candidate.
+    pub fn into_prototypes(self) -> (host::HostVmPrototype, host::HostVmPrototype) {
+        let reference = match (self.outcome, self.reference_prototype) {
+            (Ok(success), _) => success.virtual_machine.into_prototype(),
+            (Err(_), Some(prototype)) => prototype,
+            (Err(_), None) => unreachable!(),
+        };
+        (reference, self.candidate_prototype)
+    }
+}
+
+/// See [`Success::outcome`].
+#[derive(Debug, derive_more::Display)]
+pub enum Failure {
+    /// Both virtual machines have failed to start.
+    #[display(fmt = "{_0}")]
+    Start(host::StartErr),
+    /// Both executions have failed.
+    #[display(fmt = "{_0}")]
+    Execution(runtime_host::ErrorDetail),
+}
+
+/// The two executions have diverged.
+#[derive(Debug, derive_more::Display)]
+#[display(fmt = "{detail}")]
+pub struct Divergence {
+    /// First difference that has been detected.
+    pub detail: DivergenceDetail,
+    /// Prototype that was passed through [`Config::reference`].
+    pub reference_prototype: host::HostVmPrototype,
+    /// Prototype that was passed through [`Config::candidate`].
+    pub candidate_prototype: host::HostVmPrototype,
+}
+
+/// See [`Divergence::detail`].
+///
+/// In each variant, `reference` and `candidate` contain what has been observed on respectively
+/// the reference and the candidate virtual machines.
+#[derive(Debug, Clone, derive_more::Display)]
+pub enum DivergenceDetail {
+    /// Only one of the two virtual machines has failed to start.
+    #[display(fmt = "Virtual machine start: {reference:?} vs {candidate:?}")]
+    Start {
+        reference: Option<host::StartErr>,
+        candidate: Option<host::StartErr>,
+    },
+    /// The two executions have requested something different from the host.
+    #[display(fmt = "Request: {reference:?} vs {candidate:?}")]
+    Request {
+        reference: Request,
+        candidate: Request,
+    },
+    /// The runtime has called a different host function, or with different parameters, or the
+    /// host function has returned a different value.
+    ///
+    /// `None` if the execution has stopped before this host function call.
+    #[display(fmt = "Host function call #{index}: {reference:?} vs {candidate:?}")]
+    HostFunctionCall {
+        /// Index of the call within [`trace::Trace::host_function_calls`].
+        index: usize,
+        reference: Option<host::HostFunctionCall>,
+        candidate: Option<host::HostFunctionCall>,
+    },
+    /// A storage access or a log message differs.
+    ///
+    /// `None` if this event didn't happen.
+    #[display(fmt = "Event #{index}: {reference:?} vs {candidate:?}")]
+    Event {
+        /// Index of the event within [`trace::Trace::events`].
+        index: usize,
+        reference: Option<trace::Event>,
+        candidate: Option<trace::Event>,
+    },
+    /// Only one of the two executions has failed, or both have failed for different reasons.
+    ///
+    /// > **Note**: The messages of the errors generated by the execution engines themselves,
+    /// >           such as the description of a trap, aren't compared, as they are specific to
+    /// >           each engine.
+    #[display(fmt = "Outcome: {reference:?} vs {candidate:?}")]
+    Outcome {
+        reference: Result<(), runtime_host::ErrorDetail>,
+        candidate: Result<(), runtime_host::ErrorDetail>,
+    },
+    /// The value returned by the runtime differs.
+    #[display(fmt = "Return value: {reference:?} vs {candidate:?}")]
+    ReturnValue {
+        reference: Vec<u8>,
+        candidate: Vec<u8>,
+    },
+    /// The runtime has requested a different state trie version.
+    #[display(fmt = "State trie version: {reference:?} vs {candidate:?}")]
+    StateTrieVersion {
+        reference: TrieEntryVersion,
+        candidate: TrieEntryVersion,
+    },
+    /// The change to a storage item differs.
+    ///
+    /// `None` if the storage item isn't modified. `Some(None)` if it is erased.
+    #[display(fmt = "Storage change at {child_trie:?}:{key:?}: {reference:?} vs {candidate:?}")]
+    StorageChange {
+        /// Child trie of the storage item, or `None` for the main trie.
+        child_trie: Option<Vec<u8>>,
+        /// Key of the storage item.
+        key: Vec<u8>,
+        reference: Option<Option<Vec<u8>>>,
+        candidate: Option<Option<Vec<u8>>>,
+    },
+    /// The change to an item of the off-chain storage differs.
+    ///
+    /// `None` if the item isn't modified. `Some(None)` if it is erased.
+    #[display(fmt = "Off-chain storage change at {key:?}: {reference:?} vs {candidate:?}")]
+    OffchainStorageChange {
+        /// Key of the off-chain storage item.
+        key: Vec<u8>,
+        reference: Option<Option<Vec<u8>>>,
+        candidate: Option<Option<Vec<u8>>>,
+    },
+}
+
+/// Description of a request that an execution has made. See [`DivergenceDetail::Request`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Request {
+    /// The execution has finished.
+    Finished,
+    /// See [`runtime_host::RuntimeHostVm::StorageGet`].
+    StorageGet {
+        child_trie: Option<Vec<u8>>,
+        key: Vec<u8>,
+    },
+    /// See [`runtime_host::RuntimeHostVm::ClosestDescendantMerkleValue`].
+    ClosestDescendantMerkleValue {
+        child_trie: Option<Vec<u8>>,
+        key: Vec<Nibble>,
+    },
+    /// See [`runtime_host::RuntimeHostVm::NextKey`].
+    NextKey {
+        child_trie: Option<Vec<u8>>,
+        key: Vec<Nibble>,
+        prefix: Vec<Nibble>,
+        or_equal: bool,
+        branch_nodes: bool,
+    },
+    /// See [`runtime_host::RuntimeHostVm::SignatureVerification`].
+    SignatureVerification {
+        message: Vec<u8>,
+        signature: Vec<u8>,
+        public_key: Vec<u8>,
+    },
+    /// See [`runtime_host::RuntimeHostVm::KeystorePublicKeys`].
+    KeystorePublicKeys {
+        algorithm: host::KeyAlgorithm,
+        key_type_id: [u8; 4],
+    },
+    /// See [`runtime_host::RuntimeHostVm::KeystoreGenerate`].
+    KeystoreGenerate {
+        algorithm: host::KeyAlgorithm,
+        key_type_id: [u8; 4],
+    },
+    /// See [`runtime_host::RuntimeHostVm::SignRequest`].
+    SignRequest {
+        algorithm: host::KeyAlgorithm,
+        key_type_id: [u8; 4],
+        public_key: Vec<u8>,
+        message: Vec<u8>,
+    },
+    /// See [`runtime_host::RuntimeHostVm::Offchain`].
+    Offchain,
+}
+
+impl Request {
+    fn from_vm(vm: &runtime_host::RuntimeHostVm) -> Self {
+        match vm {
+            runtime_host::RuntimeHostVm::Finished(_) => Request::Finished,
+            runtime_host::RuntimeHostVm::StorageGet(req) => Request::StorageGet {
+                child_trie: req.child_trie().map(|ct| ct.as_ref().to_vec()),
+                key: req.key().as_ref().to_vec(),
+            },
+            runtime_host::RuntimeHostVm::ClosestDescendantMerkleValue(req) => {
+                Request::ClosestDescendantMerkleValue {
+                    child_trie: req.child_trie().map(|ct| ct.as_ref().to_vec()),
+                    key: req.key().collect(),
+                }
+            }
+            runtime_host::RuntimeHostVm::NextKey(req) => Request::NextKey {
+                child_trie: req.child_trie().map(|ct| ct.as_ref().to_vec()),
+                key: req.key().collect(),
+                prefix: req.prefix().collect(),
+                or_equal: req.or_equal(),
+                branch_nodes: req.branch_nodes(),
+            },
+            runtime_host::RuntimeHostVm::SignatureVerification(req) => {
+                Request::SignatureVerification {
+                    message: req.message().as_ref().to_vec(),
+                    signature: req.signature().as_ref().to_vec(),
+                    public_key: req.public_key().as_ref().to_vec(),
+                }
+            }
+            runtime_host::RuntimeHostVm::KeystorePublicKeys(req) => Request::KeystorePublicKeys {
+                algorithm: req.algorithm(),
+                key_type_id: *req.key_type_id(),
+            },
+            runtime_host::RuntimeHostVm::KeystoreGenerate(req) => Request::KeystoreGenerate {
+                algorithm: req.algorithm(),
+                key_type_id: *req.key_type_id(),
+            },
+            runtime_host::RuntimeHostVm::SignRequest(req) => Request::SignRequest {
+                algorithm: req.algorithm(),
+                key_type_id: *req.key_type_id(),
+                public_key: req.public_key().as_ref().to_vec(),
+                message: req.message().as_ref().to_vec(),
+            },
+            runtime_host::RuntimeHostVm::Offchain(_) => Request::Offchain,
+        }
+    }
+}
+
+/// Current state of the executions.
+#[must_use]
+pub enum DifferentialRuntimeHostVm {
+    /// Both executions are over.
+    Finished(Result<Success, Divergence>),
+    /// Loading a storage value is required in order to continue.
+    StorageGet(StorageGet),
+    /// Obtaining the Merkle value of the closest descendant of a trie node is required in order
+    /// to continue.
+    ClosestDescendantMerkleValue(ClosestDescendantMerkleValue),
+    /// Fetching the key that follows a given one is required in order to continue.
+    NextKey(NextKey),
+    /// Verifying whether a signature is correct is required in order to continue.
+    SignatureVerification(SignatureVerification),
+}
+
+impl DifferentialRuntimeHostVm {
+    /// Cancels execution of both virtual machines and returns back the prototypes, in the
+    /// order reference then candidate.
+    pub fn into_prototypes(self) -> (host::HostVmPrototype, host::HostVmPrototype) {
+        match self {
+            DifferentialRuntimeHostVm::Finished(Ok(success)) => success.into_prototypes(),
+            DifferentialRuntimeHostVm::Finished(Err(divergence)) => (
+                divergence.reference_prototype,
+                divergence.candidate_prototype,
+            ),
+            DifferentialRuntimeHostVm::StorageGet(req) => (
+                runtime_host::RuntimeHostVm::StorageGet(req.reference).into_prototype(),
+                runtime_host::RuntimeHostVm::StorageGet(req.candidate).into_prototype(),
+            ),
+            DifferentialRuntimeHostVm::ClosestDescendantMerkleValue(req) => (
+                runtime_host::RuntimeHostVm::ClosestDescendantMerkleValue(req.reference)
+                    .into_prototype(),
+                runtime_host::RuntimeHostVm::ClosestDescendantMerkleValue(req.candidate)
+                    .into_prototype(),
+            ),
+            DifferentialRuntimeHostVm::NextKey(req) => (
+                runtime_host::RuntimeHostVm::NextKey(req.reference).into_prototype(),
+                runtime_host::RuntimeHostVm::NextKey(req.candidate).into_prototype(),
+            ),
+            DifferentialRuntimeHostVm::SignatureVerification(req) => (
+                runtime_host::RuntimeHostVm::SignatureVerification(req.reference).into_prototype(),
+                runtime_host::RuntimeHostVm::SignatureVerification(req.candidate).into_prototype(),
+            ),
+        }
+    }
+}
+
+/// Loading a storage value is required in order to continue.
+#[must_use]
+pub struct StorageGet {
+    reference: runtime_host::StorageGet,
+    candidate: runtime_host::StorageGet,
+}
+
+impl StorageGet {
+    /// Returns the key whose value must be passed to [`StorageGet::inject_value`].
+    pub fn key(&'_ self) -> impl AsRef<[u8]> + '_ {
+        self.reference.key()
+    }
+
+    /// If `Some`, read from the given child trie. If `None`, read from the main trie.
+    pub fn child_trie(&'_ self) -> Option<impl AsRef<[u8]> + '_> {
+        self.reference.child_trie()
+    }
+
+    /// Injects the corresponding storage value.
+    pub fn inject_value(
+        self,
+        value: Option<(
+            impl Iterator<Item = impl AsRef<[u8]>> + Clone,
+            TrieEntryVersion,
+        )>,
+    ) -> DifferentialRuntimeHostVm {
+        let reference = self.reference.inject_value(value.clone());
+        let candidate = self.candidate.inject_value(value);
+        advance(reference, candidate)
+    }
+}
+
+/// Obtaining the Merkle value of the closest descendant of a trie node is required in order
+/// to continue.
+#[must_use]
+pub struct ClosestDescendantMerkleValue {
+    reference: runtime_host::ClosestDescendantMerkleValue,
+    candidate: runtime_host::ClosestDescendantMerkleValue,
+}
+
+impl ClosestDescendantMerkleValue {
+    /// Returns the key whose closest descendant Merkle value must be passed to
+    /// [`ClosestDescendantMerkleValue::inject_merkle_value`].
+    pub fn key(&'_ self) -> impl Iterator<Item = Nibble> + '_ {
+        self.reference.key()
+    }
+
+    /// If `Some`, read from the given child trie. If `None`, read from the main trie.
+    pub fn child_trie(&'_ self) -> Option<impl AsRef<[u8]> + '_> {
+        self.reference.child_trie()
+    }
+
+    /// Indicate that the value is unknown and resume the calculation.
+    ///
+    /// See [`runtime_host::ClosestDescendantMerkleValue::resume_unknown`].
+    pub fn resume_unknown(self) -> DifferentialRuntimeHostVm {
+        let reference = self.reference.resume_unknown();
+        let candidate = self.candidate.resume_unknown();
+        advance(reference, candidate)
+    }
+
+    /// Injects the corresponding Merkle value.
+    ///
+    /// `None` can be passed if there is no descendant or, in the case of a child trie read, in
+    /// order to indicate that the child trie does not exist.
+    pub fn inject_merkle_value(self, merkle_value: Option<&[u8]>) -> DifferentialRuntimeHostVm {
+        let reference = self.reference.inject_merkle_value(merkle_value);
+        let candidate = self.candidate.inject_merkle_value(merkle_value);
+        advance(reference, candidate)
+    }
+}
+
+/// Fetching the key that follows a given one is required in order to continue.
+#[must_use]
+pub struct NextKey {
+    reference: runtime_host::NextKey,
+    candidate: runtime_host::NextKey,
+}
+
+impl NextKey {
+    /// Returns the key whose next key must be passed back.
+    pub fn key(&'_ self) -> impl Iterator<Item = Nibble> + '_ {
+        self.reference.key()
+    }
+
+    /// If `Some`, read from the given child trie. If `None`, read from the main trie.
+    pub fn child_trie(&'_ self) -> Option<impl AsRef<[u8]> + '_> {
+        self.reference.child_trie()
+    }
+
+    /// See [`runtime_host::NextKey::or_equal`].
+    pub fn or_equal(&self) -> bool {
+        self.reference.or_equal()
+    }
+
+    /// See [`runtime_host::NextKey::branch_nodes`].
+    pub fn branch_nodes(&self) -> bool {
+        self.reference.branch_nodes()
+    }
+
+    /// See [`runtime_host::NextKey::prefix`].
+    pub fn prefix(&'_ self) -> impl Iterator<Item = Nibble> + '_ {
+        self.reference.prefix()
+    }
+
+    /// Injects the key.
+    ///
+    /// # Panic
+    ///
+    /// See [`runtime_host::NextKey::inject_key`].
+    ///
+    pub fn inject_key(
+        self,
+        key: Option<impl Iterator<Item = Nibble> + Clone>,
+    ) -> DifferentialRuntimeHostVm {
+        let reference = self.reference.inject_key(key.clone());
+        let candidate = self.candidate.inject_key(key);
+        advance(reference, candidate)
+    }
+}
+
+/// Verifying whether a signature is correct is required in order to continue.
+#[must_use]
+pub struct SignatureVerification {
+    reference: runtime_host::SignatureVerification,
+    candidate: runtime_host::SignatureVerification,
+}
+
+impl SignatureVerification {
+    /// Returns the message that the signature is expected to sign.
+    pub fn message(&'_ self) -> impl AsRef<[u8]> + '_ {
+        self.reference.message()
+    }
+
+    /// Returns the signature.
+    pub fn signature(&'_ self) -> impl AsRef<[u8]> + '_ {
+        self.reference.signature()
+    }
+
+    /// Returns the public key the signature is against.
+    pub fn public_key(&'_ self) -> impl AsRef<[u8]> + '_ {
+        self.reference.public_key()
+    }
+
+    /// Verify the signature. Returns `true` if it is valid.
+    pub fn is_valid(&self) -> bool {
+        self.reference.is_valid()
+    }
+
+    /// Verify the signature and resume execution.
+    pub fn verify_and_resume(self) -> DifferentialRuntimeHostVm {
+        if self.is_valid() {
+            self.resume_success()
+        } else {
+            self.resume_failed()
+        }
+    }
+
+    /// Resume the execution assuming that the signature is valid.
+    pub fn resume_success(self) -> DifferentialRuntimeHostVm {
+        let reference = self.reference.resume_success();
+        let candidate = self.candidate.resume_success();
+        advance(reference, candidate)
+    }
+
+    /// Resume the execution assuming that the signature is invalid.
+    pub fn resume_failed(self) -> DifferentialRuntimeHostVm {
+        let reference = self.reference.resume_failed();
+        let candidate = self.candidate.resume_failed();
+        advance(reference, candidate)
+    }
+}
+
+/// Makes both executions progress until they require something from the user or finish.
+fn advance(
+    mut reference: runtime_host::RuntimeHostVm,
+    mut candidate: runtime_host::RuntimeHostVm,
+) -> DifferentialRuntimeHostVm {
+    loop {
+        let reference_request = Request::from_vm(&reference);
+        let candidate_request = Request::from_vm(&candidate);
+        if reference_request != candidate_request {
+            return DifferentialRuntimeHostVm::Finished(Err(Divergence {
+                detail: DivergenceDetail::Request {
+                    reference: reference_request,
+                    candidate: candidate_request,
+                },
+                reference_prototype: reference.into_prototype(),
+                candidate_prototype: candidate.into_prototype(),
+            }));
+        }
+
+        match (reference, candidate) {
+            (
+                runtime_host::RuntimeHostVm::Finished(reference),
+                runtime_host::RuntimeHostVm::Finished(candidate),
+            ) => return compare_outcomes(reference, candidate),
+            (
+                runtime_host::RuntimeHostVm::StorageGet(reference),
+                runtime_host::RuntimeHostVm::StorageGet(candidate),
+            ) => {
+                return DifferentialRuntimeHostVm::StorageGet(StorageGet {
+                    reference,
+                    candidate,
+                })
+            }
+            (
+                runtime_host::RuntimeHostVm::ClosestDescendantMerkleValue(reference),
+                runtime_host::RuntimeHostVm::ClosestDescendantMerkleValue(candidate),
+            ) => {
+                return DifferentialRuntimeHostVm::ClosestDescendantMerkleValue(
+                    ClosestDescendantMerkleValue {
+                        reference,
+                        candidate,
+                    },
+                )
+            }
+            (
+                runtime_host::RuntimeHostVm::NextKey(reference),
+                runtime_host::RuntimeHostVm::NextKey(candidate),
+            ) => {
+                return DifferentialRuntimeHostVm::NextKey(NextKey {
+                    reference,
+                    candidate,
+                })
+            }
+            (
+                runtime_host::RuntimeHostVm::SignatureVerification(reference),
+                runtime_host::RuntimeHostVm::SignatureVerification(candidate),
+            ) => {
+                return DifferentialRuntimeHostVm::SignatureVerification(SignatureVerification {
+                    reference,
+                    candidate,
+                })
+            }
+
+            // The keystore is considered empty, and off-chain-worker-related host functions are
+            // forbidden.
+            (
+                runtime_host::RuntimeHostVm::KeystorePublicKeys(r),
+                runtime_host::RuntimeHostVm::KeystorePublicKeys(c),
+            ) => {
+                reference = r.resume(iter::empty::<&[u8]>());
+                candidate = c.resume(iter::empty::<&[u8]>());
+            }
+            (
+                runtime_host::RuntimeHostVm::KeystoreGenerate(r),
+                runtime_host::RuntimeHostVm::KeystoreGenerate(c),
+            ) => {
+                reference = r.resume(None);
+                candidate = c.resume(None);
+            }
+            (
+                runtime_host::RuntimeHostVm::SignRequest(r),
+                runtime_host::RuntimeHostVm::SignRequest(c),
+            ) => {
+                reference = r.resume(None);
+                candidate = c.resume(None);
+            }
+            (
+                runtime_host::RuntimeHostVm::Offchain(r),
+                runtime_host::RuntimeHostVm::Offchain(c),
+            ) => {
+                reference = r.reject();
+                candidate = c.reject();
+            }
+
+            // The requests have been compared above.
+            _ => unreachable!(),
+        }
+    }
+}
+
+/// Compares the outcomes of two executions that have both finished.
+fn compare_outcomes(
+    reference: Result<runtime_host::Success, runtime_host::Error>,
+    candidate: Result<runtime_host::Success, runtime_host::Error>,
+) -> DifferentialRuntimeHostVm {
+    let (reference_trace, candidate_trace) = match (&reference, &candidate) {
+        (Ok(r), Ok(c)) => (r.trace.as_ref(), c.trace.as_ref()),
+        (Ok(r), Err(c)) => (r.trace.as_ref(), c.trace.as_ref()),
+        (Err(r), Ok(c)) => (r.trace.as_ref(), c.trace.as_ref()),
+        (Err(r), Err(c)) => (r.trace.as_ref(), c.trace.as_ref()),
+    };
+
+    // Tracing is always enabled in `run`.
+    let mut detail = compare_traces(reference_trace.unwrap(), candidate_trace.unwrap());
+
+    if detail.is_none() {
+        detail = match (&reference, &candidate) {
+            (Ok(r), Ok(c)) => compare_successes(r, c),
+            (Err(r), Err(c)) if errors_match(&r.detail, &c.detail) => None,
+            _ => Some(DivergenceDetail::Outcome {
+                reference: reference.as_ref().map(|_| ()).map_err(|e| e.detail.clone()),
+                candidate: candidate.as_ref().map(|_| ()).map_err(|e| e.detail.clone()),
+            }),
+        };
+    }
+
+    let candidate_prototype = match candidate {
+        Ok(success) => success.virtual_machine.into_prototype(),
+        Err(error) => error.prototype,
+    };
+
+    DifferentialRuntimeHostVm::Finished(match (detail, reference) {
+        (None, Ok(success)) => Ok(Success {
+            outcome: Ok(success),
+            reference_prototype: None,
+            candidate_prototype,
+        }),
+        (None, Err(error)) => Ok(Success {
+            outcome: Err(Failure::Execution(error.detail)),
+            reference_prototype: Some(error.prototype),
+            candidate_prototype,
+        }),
+        (Some(detail), reference) => Err(Divergence {
+            detail,
+            reference_prototype: match reference {
+                Ok(success) => success.virtual_machine.into_prototype(),
+                Err(error) => error.prototype,
+            },
+            candidate_prototype,
+        }),
+    })
+}
+
+/// Compares two traces in chronological order. Each host function call is compared, followed
+/// with the events it has generated.
+fn compare_traces(reference: &trace::Trace, candidate: &trace::Trace) -> Option<DivergenceDetail> {
+    let mut reference_events = reference.events.iter().enumerate().peekable();
+    let mut candidate_events = candidate.events.iter().enumerate().peekable();
+
+    let num_calls = reference
+        .host_function_calls
+        .len()
+        .max(candidate.host_function_calls.len());
+
+    for index in 0..num_calls {
+        let reference_call = reference.host_function_calls.get(index);
+        let candidate_call = candidate.host_function_calls.get(index);
+        if reference_call != candidate_call {
+            return Some(DivergenceDetail::HostFunctionCall {
+                index,
+                reference: reference_call.cloned(),
+                candidate: candidate_call.cloned(),
+            });
+        }
+
+        loop {
+            let reference_event =
+                reference_events.next_if(|(_, ev)| ev.host_function_call <= index);
+            let candidate_event =
+                candidate_events.next_if(|(_, ev)| ev.host_function_call <= index);
+            match (reference_event, candidate_event) {
+                (None, None) => break,
+                (Some((_, r)), Some((_, c))) if r == c => {}
+                (r, c) => {
+                    return Some(DivergenceDetail::Event {
+                        index: r.or(c).map(|(n, _)| n).unwrap(),
+                        reference: r.map(|(_, ev)| ev.clone()),
+                        candidate: c.map(|(_, ev)| ev.clone()),
+                    })
+                }
+            }
+        }
+    }
+
+    // Events are always attached to a host function call, and all the host function calls have
+    // been processed above.
+    debug_assert!(reference_events.peek().is_none());
+    debug_assert!(candidate_events.peek().is_none());
+    None
+}
+
+/// Compares the outputs of two successful executions.
+fn compare_successes(
+    reference: &runtime_host::Success,
+    candidate: &runtime_host::Success,
+) -> Option<DivergenceDetail> {
+    let reference_value = reference.virtual_machine.value();
+    let candidate_value = candidate.virtual_machine.value();
+    if reference_value.as_ref() != candidate_value.as_ref() {
+        return Some(DivergenceDetail::ReturnValue {
+            reference: reference_value.as_ref().to_vec(),
+            candidate: candidate_value.as_ref().to_vec(),
+        });
+    }
+
+    if reference.state_trie_version != candidate.state_trie_version {
+        return Some(DivergenceDetail::StateTrieVersion {
+            reference: reference.state_trie_version,
+            candidate: candidate.state_trie_version,
+        });
+    }
+
+    let reference_changes = storage_changes(&reference.storage_changes);
+    let candidate_changes = storage_changes(&candidate.storage_changes);
+    if let Some(((child_trie, key), reference, candidate)) =
+        first_difference(reference_changes, candidate_changes)
+    {
+        return Some(DivergenceDetail::StorageChange {
+            child_trie,
+            key,
+            reference,
+            candidate,
+        });
+    }
+
+    let reference_offchain_changes = reference
+        .offchain_storage_changes
+        .iter()
+        .map(|(k, v)| (k.clone(), v.clone()))
+        .collect::<BTreeMap<_, _>>();
+    let candidate_offchain_changes = candidate
+        .offchain_storage_changes
+        .iter()
+        .map(|(k, v)| (k.clone(), v.clone()))
+        .collect::<BTreeMap<_, _>>();
+    if let Some((key, reference, candidate)) =
+        first_difference(reference_offchain_changes, candidate_offchain_changes)
+    {
+        return Some(DivergenceDetail::OffchainStorageChange {
+            key,
+            reference,
+            candidate,
+        });
+    }
+
+    None
+}
+
+/// List of storage changes, indexed by child trie (`None` for the main trie) and key. Values are
+/// `None` if the storage item is erased.
+type StorageChangesList = BTreeMap<(Option<Vec<u8>>, Vec<u8>), Option<Vec<u8>>>;
+
+/// Returns the list of storage changes, ordered by child trie and key.
+///
+/// The changes of the main trie are always available, while the changes of child tries are
+/// only known if the runtime has calculated their root.
+fn storage_changes(changes: &runtime_host::StorageChanges) -> StorageChangesList {
+    let mut list = changes
+        .main_trie_storage_changes_iter_unordered()
+        .map(|(key, value)| ((None, key.to_vec()), value.map(|v| v.to_vec())))
+        .collect::<BTreeMap<_, _>>();
+
+    for (child_trie, key, change) in changes.trie_changes_iter_ordered() {
+        let Some(child_trie) = child_trie else {
+            continue;
+        };
+        let runtime_host::TrieChange::InsertUpdate {
+            new_storage_value: runtime_host::TrieChangeStorageValue::Modified { new_value },
+            ..
+        } = change
+        else {
+            continue;
+        };
+
+        let key = trie::nibbles_to_bytes_truncate(key.iter().copied()).collect::<Vec<_>>();
+        list.insert(
+            (Some(child_trie.to_vec()), key),
+            new_value.map(|v| v.to_vec()),
+        );
+    }
+
+    list
+}
+
+/// Returns the first key whose value differs between the two lists, alongside with the
+/// values in each list.
+fn first_difference<K: Ord + Clone, V: PartialEq>(
+    mut reference: BTreeMap<K, V>,
+    mut candidate: BTreeMap<K, V>,
+) -> Option<(K, Option<V>, Option<V>)> {
+    let first_key = reference
+        .iter()
+        .filter(|(key, value)| candidate.get(*key) != Some(*value))
+        .map(|(key, _)| key)
+        .chain(candidate.keys().filter(|key| !reference.contains_key(*key)))
+        .min()?
+        .clone();
+
+    let reference_value = reference.remove(&first_key);
+    let candidate_value = candidate.remove(&first_key);
+    Some((first_key, reference_value, candidate_value))
+}
+
+/// Returns `true` if both errors are considered identical.
+///
+/// Errors generated by the execution engines themselves are compared only by their kind, as
+/// their messages are engine-specific.
+fn errors_match(
+    reference: &runtime_host::ErrorDetail,
+    candidate: &runtime_host::ErrorDetail,
+) -> bool {
+    match (reference, candidate) {
+        (
+            runtime_host::ErrorDetail::WasmVm { error: r, .. },
+            runtime_host::ErrorDetail::WasmVm { error: c, .. },
+        ) => mem::discriminant(r) == mem::discriminant(c),
+        _ => mem::discriminant(reference) == mem::discriminant(candidate),
+    }
+}
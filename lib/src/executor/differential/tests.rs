@@ -0,0 +1,118 @@
+// Smoldot
+// Copyright (C) 2023  Pierre Krieger
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <http://www.gnu.org/licenses/>.
+
+#![cfg(test)]
+
+use super::{run, Config, DifferentialRuntimeHostVm, Divergence, Success};
+use crate::{
+    executor::{host, vm},
+    header,
+};
+use core::iter;
+
+fn prototype(exec_hint: vm::ExecHint, heap_pages: u32) -> host::HostVmPrototype {
+    host::HostVmPrototype::new(host::Config {
+        module: &include_bytes!("../host/westend-runtime-v9300.wasm")[..],
+        heap_pages: host::HeapPages::new(heap_pages),
+        exec_hint,
+        allow_unresolved_imports: false,
+        compilation_cache: None,
+    })
+    .unwrap()
+}
+
+/// Runs the given call of the Westend runtime, with the candidate using the JIT if available.
+/// The storage is considered empty.
+fn execute(
+    function_to_call: &str,
+    parameter: &[u8],
+    reference_heap_pages: u32,
+    candidate_heap_pages: u32,
+) -> Result<Success, Divergence> {
+    let mut execution = run(Config {
+        reference: prototype(vm::ExecHint::ForceWasmi, reference_heap_pages),
+        candidate: prototype(
+            vm::ExecHint::force_wasmtime_if_available().unwrap_or(vm::ExecHint::ForceWasmi),
+            candidate_heap_pages,
+        ),
+        function_to_call,
+        parameter: iter::once(parameter),
+        storage_main_trie_changes: Default::default(),
+        max_log_level: 3,
+    });
+
+    loop {
+        match execution {
+            DifferentialRuntimeHostVm::Finished(outcome) => break outcome,
+            DifferentialRuntimeHostVm::StorageGet(req) => {
+                execution = req.inject_value(None::<(iter::Empty<&[u8]>, _)>)
+            }
+            DifferentialRuntimeHostVm::ClosestDescendantMerkleValue(req) => {
+                execution = req.resume_unknown()
+            }
+            DifferentialRuntimeHostVm::NextKey(req) => {
+                execution = req.inject_key(None::<iter::Empty<_>>)
+            }
+            DifferentialRuntimeHostVm::SignatureVerification(req) => {
+                execution = req.verify_and_resume()
+            }
+        }
+    }
+}
+
+#[test]
+fn metadata_identical() {
+    let success = execute("Metadata_metadata", &[], 2048, 2048).unwrap();
+    let output = success.outcome.unwrap();
+    assert!(!output.virtual_machine.value().as_ref().is_empty());
+
+    // The metadata is built by the runtime through many memory allocations, all of which have
+    // been compared.
+    let trace = output.trace.unwrap();
+    assert!(trace
+        .host_function_calls
+        .iter()
+        .any(|call| call.function_name == "ext_allocator_malloc_version_1"));
+}
+
+#[test]
+fn identical_failures() {
+    let header = header::HeaderRef {
+        parent_hash: &[0; 32],
+        number: 1,
+        state_root: &[0; 32],
+        extrinsics_root: &[0; 32],
+        digest: header::DigestRef::empty(),
+    }
+    .scale_encoding_vec(4);
+
+    // Initializing a block on top of an empty storage fails, but it does so identically on both
+    // engines, which isn't a divergence.
+    let success = execute("Core_initialize_block", &header, 2048, 2048).unwrap();
+    assert!(matches!(success.outcome, Err(super::Failure::Execution(_))));
+}
+
+#[test]
+fn divergence_detected() {
+    // Building the metadata doesn't fit in a single page of heap. Only the reference is able
+    // to perform the call.
+    let divergence = execute("Metadata_metadata", &[], 2048, 1).unwrap_err();
+    assert!(matches!(
+        divergence.detail,
+        super::DivergenceDetail::HostFunctionCall { .. } | super::DivergenceDetail::Outcome { .. }
+    ));
+}
@@ -153,6 +153,7 @@
 //!         module: &wasm_binary_code,
 //!         heap_pages: HeapPages::from(2048),
 //!         exec_hint: smoldot::executor::vm::ExecHint::Oneshot,
+//!         compilation_cache: None,
 //!         allow_unresolved_imports: false
 //!     }).unwrap();
 //!     prototype.run_no_param("Core_version").unwrap().into()
@@ -219,7 +220,7 @@ mod tests;
 mod zstd;
 
 /// Configuration for [`HostVmPrototype::new`].
-pub struct Config<TModule> {
+pub struct Config<'a, TModule> {
     /// Bytes of the WebAssembly module.
     ///
     /// The module can be either directly Wasm bytecode, or zstandard-compressed.
@@ -233,6 +234,9 @@ pub struct Config<TModule> {
     /// Hint used by the implementation to decide which kind of virtual machine to use.
     pub exec_hint: vm::ExecHint,
 
+    /// Cache of compiled runtimes. See [`vm::Config::compilation_cache`].
+    pub compilation_cache: Option<&'a dyn vm::CompilationCache>,
+
     /// If `true`, no [`vm::NewErr::UnresolvedFunctionImport`] error will be returned if the
     /// module trying to import functions that aren't recognized by the implementation. Instead,
     /// a [`Error::UnresolvedFunctionCalled`] error will be generated if the module tries to call
@@ -282,7 +286,7 @@ struct VmCommon {
 
 impl HostVmPrototype {
     /// Creates a new [`HostVmPrototype`]. Parses and potentially JITs the module.
-    pub fn new(config: Config<impl AsRef<[u8]>>) -> Result<Self, NewErr> {
+    pub fn new(config: Config<'_, impl AsRef<[u8]>>) -> Result<Self, NewErr> {
         // The maximum allowed size for the decompressed Wasm code needs to be the same amongst
         // all implementations.
         // See <https://github.com/paritytech/substrate/blob/f9d10fabe04d598d68f8b097cc4905adbb1ad630/primitives/maybe-compressed-blob/src/lib.rs#L37>.
@@ -330,6 +334,7 @@ impl HostVmPrototype {
             let vm_proto = vm::VirtualMachinePrototype::new(vm::Config {
                 module_bytes: &module_bytes[..],
                 exec_hint: config.exec_hint,
+                compilation_cache: config.compilation_cache,
                 // This closure is called back for each function that the runtime imports.
                 symbols: &mut |mod_name, f_name, signature| {
                     if mod_name != "env" {
@@ -444,30 +449,47 @@ impl HostVmPrototype {
     /// Starts the VM, calling the function passed as parameter.
     pub fn run(self, function_to_call: &str, data: &[u8]) -> Result<ReadyToRun, (StartErr, Self)> {
         self.run_vectored(function_to_call, iter::once(data))
+            .map_err(|err| *err)
     }
 
     /// Same as [`HostVmPrototype::run`], except that the function doesn't need any parameter.
     pub fn run_no_param(self, function_to_call: &str) -> Result<ReadyToRun, (StartErr, Self)> {
         self.run_vectored(function_to_call, iter::empty::<Vec<u8>>())
+            .map_err(|err| *err)
     }
 
     /// Same as [`HostVmPrototype::run`], except that the function parameter can be passed as
     /// a list of buffers. All the buffers will be concatenated in memory.
     pub fn run_vectored(
+        self,
+        function_to_call: &str,
+        data: impl Iterator<Item = impl AsRef<[u8]>> + Clone,
+    ) -> Result<ReadyToRun, Box<(StartErr, Self)>> {
+        self.run_vectored_with_fuel(function_to_call, data, None)
+    }
+
+    /// Same as [`HostVmPrototype::run_vectored`], except that the number of WebAssembly
+    /// instructions that the execution is allowed to perform is bounded by `fuel`. If the limit
+    /// is reached, the execution stops with [`Error::OutOfFuel`]. `None` means unlimited.
+    ///
+    /// The fuel is only enforced if the prototype was created with [`vm::ExecHint::Untrusted`]
+    /// or one of the `Force` hints. See [`vm::Prepare::set_fuel`].
+    pub fn run_vectored_with_fuel(
         mut self,
         function_to_call: &str,
         data: impl Iterator<Item = impl AsRef<[u8]>> + Clone,
-    ) -> Result<ReadyToRun, (StartErr, Self)> {
+        fuel: Option<u64>,
+    ) -> Result<ReadyToRun, Box<(StartErr, Self)>> {
         // Determine the total length of `data`.
         let mut data_len_u32: u32 = 0;
         for data in data.clone() {
             let len = match u32::try_from(data.as_ref().len()) {
                 Ok(v) => v,
-                Err(_) => return Err((StartErr::DataSizeOverflow, self)),
+                Err(_) => return Err(Box::new((StartErr::DataSizeOverflow, self))),
             };
             data_len_u32 = match data_len_u32.checked_add(len) {
                 Some(v) => v,
-                None => return Err((StartErr::DataSizeOverflow, self)),
+                None => return Err(Box::new((StartErr::DataSizeOverflow, self))),
             };
         }
 
@@ -478,6 +500,9 @@ impl HostVmPrototype {
 
         // Prepare the virtual machine for execution.
         let mut vm = self.vm_proto.prepare();
+        if let Some(fuel) = fuel {
+            vm.set_fuel(fuel);
+        }
 
         // Write the input data in the VM's memory using the allocator.
         let data_ptr = match allocator.allocate(
@@ -490,7 +515,7 @@ impl HostVmPrototype {
             Ok(p) => p,
             Err(_) => {
                 self.vm_proto = vm.into_prototype();
-                return Err((StartErr::DataSizeOverflow, self));
+                return Err(Box::new((StartErr::DataSizeOverflow, self)));
             }
         };
 
@@ -525,7 +550,7 @@ impl HostVmPrototype {
             Ok(vm) => vm,
             Err((error, vm_proto)) => {
                 self.vm_proto = vm_proto;
-                return Err((error.into(), self));
+                return Err(Box::new((error.into(), self)));
             }
         };
 
@@ -535,6 +560,7 @@ impl HostVmPrototype {
                 common: self.common,
                 vm,
                 storage_transaction_depth: 0,
+                signatures_batch: None,
                 allocator,
             }),
         })
@@ -593,6 +619,59 @@ pub enum HostVm {
     /// Need to verify whether a signature is valid.
     #[from]
     SignatureVerification(SignatureVerification),
+    /// Need to provide the list of public keys of the keystore of the given algorithm and key
+    /// type.
+    #[from]
+    KeystorePublicKeys(KeystorePublicKeys),
+    /// Need to generate a new key in the keystore.
+    #[from]
+    KeystoreGenerate(KeystoreGenerate),
+    /// Need to sign a message using a key of the keystore.
+    #[from]
+    SignRequest(SignRequest),
+    /// Need to load a value from the off-chain storage.
+    #[from]
+    OffchainStorageGet(OffchainStorageGet),
+    /// Need to set or remove a value of the off-chain storage, potentially only if the current
+    /// value matches a certain value.
+    #[from]
+    OffchainStorageSet(OffchainStorageSet),
+    /// Need to provide the current UNIX timestamp.
+    #[from]
+    OffchainTimestamp(OffchainTimestamp),
+    /// Need to wait until a certain UNIX timestamp has been reached.
+    #[from]
+    OffchainSleepUntil(OffchainSleepUntil),
+    /// Need to provide a randomly-generated seed.
+    #[from]
+    OffchainRandomSeed(OffchainRandomSeed),
+    /// Need to submit a transaction to the transactions pool.
+    #[from]
+    OffchainSubmitTransaction(OffchainSubmitTransaction),
+    /// Need to indicate whether the local node is a validator.
+    #[from]
+    OffchainIsValidator(OffchainIsValidator),
+    /// Need to provide the identity and addresses of the local node.
+    #[from]
+    OffchainNetworkState(OffchainNetworkState),
+    /// Need to start an HTTP request.
+    #[from]
+    OffchainHttpRequestStart(OffchainHttpRequestStart),
+    /// Need to add a header to an HTTP request that has been started.
+    #[from]
+    OffchainHttpRequestAddHeader(OffchainHttpRequestAddHeader),
+    /// Need to write a chunk of the body of an HTTP request.
+    #[from]
+    OffchainHttpRequestWriteBody(OffchainHttpRequestWriteBody),
+    /// Need to wait for HTTP requests to have received a response.
+    #[from]
+    OffchainHttpResponseWait(OffchainHttpResponseWait),
+    /// Need to provide the headers of the response to an HTTP request.
+    #[from]
+    OffchainHttpResponseHeaders(OffchainHttpResponseHeaders),
+    /// Need to read a chunk of the body of the response to an HTTP request.
+    #[from]
+    OffchainHttpResponseReadBody(OffchainHttpResponseReadBody),
     /// Need to call `Core_version` on the given Wasm code and return the raw output (i.e.
     /// still SCALE-encoded), or an error if the call has failed.
     #[from]
@@ -636,6 +715,23 @@ impl HostVm {
             HostVm::ExternalStorageNextKey(inner) => inner.inner.into_prototype(),
             HostVm::ExternalOffchainStorageSet(inner) => inner.inner.into_prototype(),
             HostVm::SignatureVerification(inner) => inner.inner.into_prototype(),
+            HostVm::KeystorePublicKeys(inner) => inner.inner.into_prototype(),
+            HostVm::KeystoreGenerate(inner) => inner.inner.into_prototype(),
+            HostVm::SignRequest(inner) => inner.inner.into_prototype(),
+            HostVm::OffchainStorageGet(inner) => inner.inner.into_prototype(),
+            HostVm::OffchainStorageSet(inner) => inner.inner.into_prototype(),
+            HostVm::OffchainTimestamp(inner) => inner.inner.into_prototype(),
+            HostVm::OffchainSleepUntil(inner) => inner.inner.into_prototype(),
+            HostVm::OffchainRandomSeed(inner) => inner.inner.into_prototype(),
+            HostVm::OffchainSubmitTransaction(inner) => inner.inner.into_prototype(),
+            HostVm::OffchainIsValidator(inner) => inner.inner.into_prototype(),
+            HostVm::OffchainNetworkState(inner) => inner.inner.into_prototype(),
+            HostVm::OffchainHttpRequestStart(inner) => inner.inner.into_prototype(),
+            HostVm::OffchainHttpRequestAddHeader(inner) => inner.inner.into_prototype(),
+            HostVm::OffchainHttpRequestWriteBody(inner) => inner.inner.into_prototype(),
+            HostVm::OffchainHttpResponseWait(inner) => inner.inner.into_prototype(),
+            HostVm::OffchainHttpResponseHeaders(inner) => inner.inner.into_prototype(),
+            HostVm::OffchainHttpResponseReadBody(inner) => inner.inner.into_prototype(),
             HostVm::CallRuntimeVersion(inner) => inner.inner.into_prototype(),
             HostVm::StartStorageTransaction(inner) => inner.inner.into_prototype(),
             HostVm::EndStorageTransaction { resume, .. } => resume.inner.into_prototype(),
@@ -645,6 +741,19 @@ impl HostVm {
     }
 }
 
+/// Host function call performed by the runtime. See [`ReadyToRun::run_traced`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HostFunctionCall {
+    /// Name of the host function, for example `ext_storage_get_version_1`.
+    pub function_name: &'static str,
+    /// Parameters passed by the runtime, as found on the Wasm stack. Pointers are not
+    /// dereferenced.
+    pub parameters: Vec<vm::WasmValue>,
+    /// Value returned to the runtime, if any. `None` if the host function doesn't return
+    /// anything or if the execution hasn't resumed after this call.
+    pub return_value: Option<vm::WasmValue>,
+}
+
 /// Virtual machine is ready to run.
 pub struct ReadyToRun {
     inner: Box<Inner>,
@@ -655,16 +764,38 @@ impl ReadyToRun {
     /// Runs the virtual machine until something important happens.
     ///
     /// > **Note**: This is when the actual CPU-heavy computation happens.
-    pub fn run(mut self) -> HostVm {
+    pub fn run(self) -> HostVm {
+        self.run_inner(None)
+    }
+
+    /// Same as [`ReadyToRun::run`], but additionally pushes to `host_function_calls` an entry
+    /// for each host function that the runtime calls, including the ones that are handled
+    /// internally and never surface as a variant of [`HostVm`].
+    ///
+    /// The return value of a host function call is only known when the execution resumes. For
+    /// this reason, the last element of `host_function_calls` is assumed to be the host function
+    /// call that execution resumes from and its [`HostFunctionCall::return_value`] is updated.
+    /// The same list must be passed to every call to this function for a given execution, and
+    /// this list must be empty at the start of the execution.
+    pub fn run_traced(self, host_function_calls: &mut Vec<HostFunctionCall>) -> HostVm {
+        self.run_inner(Some(host_function_calls))
+    }
+
+    fn run_inner(mut self, mut host_function_calls: Option<&mut Vec<HostFunctionCall>>) -> HostVm {
         loop {
-            match self.run_once() {
+            match self.run_once(host_function_calls.as_deref_mut()) {
                 HostVm::ReadyToRun(r) => self = r,
                 other => return other,
             }
         }
     }
 
-    fn run_once(mut self) -> HostVm {
+    fn run_once(mut self, mut host_function_calls: Option<&mut Vec<HostFunctionCall>>) -> HostVm {
+        // Execution always resumes from the latest host function call, if any.
+        if let Some(last_call) = host_function_calls.as_deref_mut().and_then(|c| c.last_mut()) {
+            last_call.return_value = self.resume_value;
+        }
+
         // `vm::ExecOutcome::Interrupted` is by far the variant that requires the most
         // handling code. As such, special-case all other variants before.
         let (id, params) = match self.inner.vm.run(self.resume_value) {
@@ -741,6 +872,13 @@ impl ReadyToRun {
                 unreachable!()
             }
 
+            Err(vm::RunErr::OutOfFuel) => {
+                return HostVm::Error {
+                    error: Error::OutOfFuel,
+                    prototype: self.inner.into_prototype(),
+                }
+            }
+
             Err(vm::RunErr::Poisoned) => {
                 // Can only happen if there's a bug somewhere.
                 unreachable!()
@@ -763,6 +901,14 @@ impl ReadyToRun {
             None => unreachable!(),
         };
 
+        if let Some(host_function_calls) = host_function_calls {
+            host_function_calls.push(HostFunctionCall {
+                function_name: host_fn.name(),
+                parameters: params.clone(),
+                return_value: None,
+            });
+        }
+
         // Passed a parameter index. Produces an `impl AsRef<[u8]>`.
         macro_rules! expect_pointer_size {
             ($num:expr) => {{
@@ -907,6 +1053,78 @@ impl ReadyToRun {
             }};
         }
 
+        macro_rules! expect_offchain_storage_kind {
+            ($num:expr) => {{
+                match &params[$num] {
+                    vm::WasmValue::I32(1) => OffchainStorageKind::Persistent,
+                    vm::WasmValue::I32(2) => OffchainStorageKind::Local,
+                    vm::WasmValue::I32(_) => {
+                        return HostVm::Error {
+                            error: Error::ParamDecodeError,
+                            prototype: self.inner.into_prototype(),
+                        }
+                    }
+                    // The signatures are checked at initialization and the Wasm VM ensures that
+                    // the proper parameter types are provided.
+                    _ => unreachable!(),
+                }
+            }};
+        }
+
+        // Produces a `u16`.
+        macro_rules! expect_http_request_id {
+            ($num:expr) => {{
+                // HTTP request IDs are 16 bits numbers passed as a `i32`. Similar to what
+                // Substrate does, the upper bits are silently ignored.
+                u16::try_from(expect_u32!($num) & 0xffff).unwrap()
+            }};
+        }
+
+        // Passed a parameter index pointing to a SCALE-encoded `Option<u64>`. Produces an
+        // `Option<u64>`.
+        macro_rules! expect_deadline {
+            ($num:expr) => {{
+                let input = expect_pointer_size!($num);
+                let parsing_result: Result<_, nom::Err<(&[u8], nom::error::ErrorKind)>> =
+                    nom::combinator::all_consuming(util::nom_option_decode(
+                        nom::number::complete::le_u64,
+                    ))(input.as_ref())
+                    .map(|(_, parse_result)| parse_result);
+
+                match parsing_result {
+                    Ok(deadline) => deadline,
+                    Err(_) => {
+                        drop(input);
+                        return HostVm::Error {
+                            error: Error::ParamDecodeError,
+                            prototype: self.inner.into_prototype(),
+                        };
+                    }
+                }
+            }};
+        }
+
+        // Passed a parameter index pointing to a UTF-8 string. Produces a `String`.
+        macro_rules! expect_string {
+            ($num:expr) => {{
+                let input = expect_pointer_size!($num);
+                match str::from_utf8(input.as_ref()) {
+                    Ok(s) => s.to_owned(),
+                    Err(error) => {
+                        drop(input);
+                        return HostVm::Error {
+                            error: Error::Utf8Error {
+                                function: host_fn.name(),
+                                param_num: $num,
+                                error,
+                            },
+                            prototype: self.inner.into_prototype(),
+                        };
+                    }
+                }
+            }};
+        }
+
         // TODO: implement all functions and remove this macro
         macro_rules! host_fn_not_implemented {
             () => {{
@@ -1351,9 +1569,98 @@ impl ReadyToRun {
                     child_trie_ptr_size: Some((child_trie_ptr, child_trie_size)),
                 })
             }
-            HostFunction::ext_crypto_ed25519_public_keys_version_1 => host_fn_not_implemented!(),
-            HostFunction::ext_crypto_ed25519_generate_version_1 => host_fn_not_implemented!(),
-            HostFunction::ext_crypto_ed25519_sign_version_1 => host_fn_not_implemented!(),
+            HostFunction::ext_crypto_ed25519_public_keys_version_1
+            | HostFunction::ext_crypto_sr25519_public_keys_version_1
+            | HostFunction::ext_crypto_ecdsa_public_keys_version_1 => {
+                let algorithm = match host_fn {
+                    HostFunction::ext_crypto_ed25519_public_keys_version_1 => KeyAlgorithm::Ed25519,
+                    HostFunction::ext_crypto_sr25519_public_keys_version_1 => KeyAlgorithm::Sr25519,
+                    _ => KeyAlgorithm::Ecdsa,
+                };
+
+                HostVm::KeystorePublicKeys(KeystorePublicKeys {
+                    key_type_id: expect_pointer_constant_size!(0, 4),
+                    algorithm,
+                    calling: host_fn,
+                    inner: self.inner,
+                })
+            }
+            HostFunction::ext_crypto_ed25519_generate_version_1
+            | HostFunction::ext_crypto_sr25519_generate_version_1
+            | HostFunction::ext_crypto_ecdsa_generate_version_1 => {
+                let algorithm = match host_fn {
+                    HostFunction::ext_crypto_ed25519_generate_version_1 => KeyAlgorithm::Ed25519,
+                    HostFunction::ext_crypto_sr25519_generate_version_1 => KeyAlgorithm::Sr25519,
+                    _ => KeyAlgorithm::Ecdsa,
+                };
+
+                let key_type_id = expect_pointer_constant_size!(0, 4);
+
+                let seed = {
+                    let input = expect_pointer_size!(1);
+                    let parsing_result: Result<_, nom::Err<(&[u8], nom::error::ErrorKind)>> =
+                        nom::combinator::all_consuming(util::nom_option_decode(
+                            util::nom_bytes_decode,
+                        ))(input.as_ref())
+                        .map(|(_, parse_result)| parse_result.map(|seed| seed.to_vec()));
+
+                    match parsing_result {
+                        Ok(val) => Ok(val),
+                        Err(_) => Err(()),
+                    }
+                };
+
+                let seed = match seed {
+                    Ok(Some(seed)) => match String::from_utf8(seed) {
+                        Ok(seed) => Some(seed),
+                        Err(error) => {
+                            return HostVm::Error {
+                                error: Error::Utf8Error {
+                                    function: host_fn.name(),
+                                    param_num: 1,
+                                    error: error.utf8_error(),
+                                },
+                                prototype: self.inner.into_prototype(),
+                            };
+                        }
+                    },
+                    Ok(None) => None,
+                    Err(()) => {
+                        return HostVm::Error {
+                            error: Error::ParamDecodeError,
+                            prototype: self.inner.into_prototype(),
+                        };
+                    }
+                };
+
+                HostVm::KeystoreGenerate(KeystoreGenerate {
+                    key_type_id,
+                    algorithm,
+                    seed,
+                    calling: host_fn,
+                    inner: self.inner,
+                })
+            }
+            HostFunction::ext_crypto_ed25519_sign_version_1
+            | HostFunction::ext_crypto_sr25519_sign_version_1 => {
+                let algorithm = match host_fn {
+                    HostFunction::ext_crypto_ed25519_sign_version_1 => KeyAlgorithm::Ed25519,
+                    _ => KeyAlgorithm::Sr25519,
+                };
+
+                let (message_ptr, message_size) = expect_pointer_size_raw!(2);
+                HostVm::SignRequest(SignRequest {
+                    key_type_id: expect_pointer_constant_size!(0, 4),
+                    public_key_ptr: expect_pointer_constant_size_raw!(1, 32),
+                    message: SignRequestMessage::Memory {
+                        ptr: message_ptr,
+                        size: message_size,
+                    },
+                    algorithm,
+                    calling: host_fn,
+                    inner: self.inner,
+                })
+            }
             HostFunction::ext_crypto_ed25519_verify_version_1 => {
                 let (message_ptr, message_size) = expect_pointer_size_raw!(1);
                 HostVm::SignatureVerification(SignatureVerification {
@@ -1362,13 +1669,40 @@ impl ReadyToRun {
                     public_key_ptr: expect_pointer_constant_size_raw!(2, 32),
                     message_ptr,
                     message_size,
+                    is_batch_verification: false,
+                    inner: self.inner,
+                })
+            }
+            HostFunction::ext_crypto_ed25519_batch_verify_version_1 => {
+                // If no batch verification is in progress, the signature is verified immediately
+                // the same way as `ext_crypto_ed25519_verify_version_1`.
+                if self.inner.signatures_batch.is_none() {
+                    let (message_ptr, message_size) = expect_pointer_size_raw!(1);
+                    return HostVm::SignatureVerification(SignatureVerification {
+                        algorithm: SignatureVerificationAlgorithm::Ed25519,
+                        signature_ptr: expect_pointer_constant_size_raw!(0, 64),
+                        public_key_ptr: expect_pointer_constant_size_raw!(2, 32),
+                        message_ptr,
+                        message_size,
+                        is_batch_verification: false,
+                        inner: self.inner,
+                    });
+                }
+
+                let signature = expect_pointer_constant_size!(0, 64);
+                let message = expect_pointer_size!(1).as_ref().to_vec();
+                let public_key = expect_pointer_constant_size!(2, 32);
+                if let Some(batch) = &mut self.inner.signatures_batch {
+                    batch.ed25519.push((public_key, signature, message));
+                }
+
+                // The actual outcome of the verification is only known when
+                // `ext_crypto_finish_batch_verify_version_1` is called.
+                HostVm::ReadyToRun(ReadyToRun {
+                    resume_value: Some(vm::WasmValue::I32(1)),
                     inner: self.inner,
                 })
             }
-            HostFunction::ext_crypto_ed25519_batch_verify_version_1 => host_fn_not_implemented!(),
-            HostFunction::ext_crypto_sr25519_public_keys_version_1 => host_fn_not_implemented!(),
-            HostFunction::ext_crypto_sr25519_generate_version_1 => host_fn_not_implemented!(),
-            HostFunction::ext_crypto_sr25519_sign_version_1 => host_fn_not_implemented!(),
             HostFunction::ext_crypto_sr25519_verify_version_1 => {
                 let (message_ptr, message_size) = expect_pointer_size_raw!(1);
                 HostVm::SignatureVerification(SignatureVerification {
@@ -1377,6 +1711,7 @@ impl ReadyToRun {
                     public_key_ptr: expect_pointer_constant_size_raw!(2, 32),
                     message_ptr,
                     message_size,
+                    is_batch_verification: false,
                     inner: self.inner,
                 })
             }
@@ -1388,38 +1723,58 @@ impl ReadyToRun {
                     public_key_ptr: expect_pointer_constant_size_raw!(2, 32),
                     message_ptr,
                     message_size,
+                    is_batch_verification: false,
+                    inner: self.inner,
+                })
+            }
+            HostFunction::ext_crypto_sr25519_batch_verify_version_1 => {
+                // If no batch verification is in progress, the signature is verified immediately
+                // the same way as `ext_crypto_sr25519_verify_version_2`.
+                if self.inner.signatures_batch.is_none() {
+                    let (message_ptr, message_size) = expect_pointer_size_raw!(1);
+                    return HostVm::SignatureVerification(SignatureVerification {
+                        algorithm: SignatureVerificationAlgorithm::Sr25519V2,
+                        signature_ptr: expect_pointer_constant_size_raw!(0, 64),
+                        public_key_ptr: expect_pointer_constant_size_raw!(2, 32),
+                        message_ptr,
+                        message_size,
+                        is_batch_verification: false,
+                        inner: self.inner,
+                    });
+                }
+
+                let signature = expect_pointer_constant_size!(0, 64);
+                let message = expect_pointer_size!(1).as_ref().to_vec();
+                let public_key = expect_pointer_constant_size!(2, 32);
+                if let Some(batch) = &mut self.inner.signatures_batch {
+                    batch.sr25519.push((public_key, signature, message));
+                }
+
+                // The actual outcome of the verification is only known when
+                // `ext_crypto_finish_batch_verify_version_1` is called.
+                HostVm::ReadyToRun(ReadyToRun {
+                    resume_value: Some(vm::WasmValue::I32(1)),
                     inner: self.inner,
                 })
             }
-            HostFunction::ext_crypto_sr25519_batch_verify_version_1 => host_fn_not_implemented!(),
-            HostFunction::ext_crypto_ecdsa_generate_version_1 => host_fn_not_implemented!(),
             HostFunction::ext_crypto_ecdsa_sign_version_1 => {
+                // The message is hashed with blake2-256 before being signed.
                 // NOTE: safe to unwrap here because we supply the nn to blake2b fn
-                let data = <[u8; 32]>::try_from(
-                    blake2_rfc::blake2b::blake2b(32, &[], expect_pointer_size!(0).as_ref())
+                let message_hash = <[u8; 32]>::try_from(
+                    blake2_rfc::blake2b::blake2b(32, &[], expect_pointer_size!(2).as_ref())
                         .as_bytes(),
                 )
                 .unwrap();
-                let message = libsecp256k1::Message::parse(&data);
 
-                if let Ok(sc) =
-                    libsecp256k1::SecretKey::parse(&expect_pointer_constant_size!(1, 32))
-                {
-                    let (sig, ri) = libsecp256k1::sign(&message, &sc);
-
-                    // NOTE: the function returns 2 slices: signature (64 bytes) and recovery ID (1 byte; AS A SLICE)
-                    self.inner.alloc_write_and_return_pointer(
-                        host_fn.name(),
-                        [&sig.serialize()[..], &[ri.serialize()]].into_iter(),
-                    )
-                } else {
-                    HostVm::Error {
-                        error: Error::ParamDecodeError,
-                        prototype: self.inner.into_prototype(),
-                    }
-                }
+                HostVm::SignRequest(SignRequest {
+                    key_type_id: expect_pointer_constant_size!(0, 4),
+                    public_key_ptr: expect_pointer_constant_size_raw!(1, 33),
+                    message: SignRequestMessage::Hash(message_hash),
+                    algorithm: KeyAlgorithm::Ecdsa,
+                    calling: host_fn,
+                    inner: self.inner,
+                })
             }
-            HostFunction::ext_crypto_ecdsa_public_keys_version_1 => host_fn_not_implemented!(),
             HostFunction::ext_crypto_ecdsa_verify_version_1 => {
                 let (message_ptr, message_size) = expect_pointer_size_raw!(1);
                 HostVm::SignatureVerification(SignatureVerification {
@@ -1428,30 +1783,31 @@ impl ReadyToRun {
                     public_key_ptr: expect_pointer_constant_size_raw!(2, 33),
                     message_ptr,
                     message_size,
+                    is_batch_verification: false,
+                    inner: self.inner,
+                })
+            }
+            HostFunction::ext_crypto_ecdsa_verify_version_2 => {
+                let (message_ptr, message_size) = expect_pointer_size_raw!(1);
+                HostVm::SignatureVerification(SignatureVerification {
+                    algorithm: SignatureVerificationAlgorithm::Ecdsa,
+                    signature_ptr: expect_pointer_constant_size_raw!(0, 65),
+                    public_key_ptr: expect_pointer_constant_size_raw!(2, 33),
+                    message_ptr,
+                    message_size,
+                    is_batch_verification: false,
                     inner: self.inner,
                 })
             }
-            HostFunction::ext_crypto_ecdsa_verify_version_2 => host_fn_not_implemented!(),
             HostFunction::ext_crypto_ecdsa_sign_prehashed_version_1 => {
-                // TODO: seems misimplemented, see https://spec.polkadot.network/#id-ext_crypto_ecdsa_sign_prehashed
-                let message = libsecp256k1::Message::parse(&expect_pointer_constant_size!(0, 32));
-
-                if let Ok(sc) =
-                    libsecp256k1::SecretKey::parse(&expect_pointer_constant_size!(1, 32))
-                {
-                    let (sig, ri) = libsecp256k1::sign(&message, &sc);
-
-                    // NOTE: the function returns 2 slices: signature (64 bytes) and recovery ID (1 byte; AS A SLICE)
-                    self.inner.alloc_write_and_return_pointer(
-                        host_fn.name(),
-                        [&sig.serialize()[..], &[ri.serialize()]].into_iter(),
-                    )
-                } else {
-                    HostVm::Error {
-                        error: Error::ParamDecodeError,
-                        prototype: self.inner.into_prototype(),
-                    }
-                }
+                HostVm::SignRequest(SignRequest {
+                    key_type_id: expect_pointer_constant_size!(0, 4),
+                    public_key_ptr: expect_pointer_constant_size_raw!(1, 33),
+                    message: SignRequestMessage::Hash(expect_pointer_constant_size!(2, 32)),
+                    algorithm: KeyAlgorithm::Ecdsa,
+                    calling: host_fn,
+                    inner: self.inner,
+                })
             }
             HostFunction::ext_crypto_ecdsa_verify_prehashed_version_1 => {
                 HostVm::SignatureVerification(SignatureVerification {
@@ -1460,10 +1816,25 @@ impl ReadyToRun {
                     public_key_ptr: expect_pointer_constant_size_raw!(2, 33),
                     message_ptr: expect_pointer_constant_size_raw!(1, 32),
                     message_size: 32,
+                    is_batch_verification: false,
+                    inner: self.inner,
+                })
+            }
+            HostFunction::ext_crypto_ecdsa_batch_verify_version_1 => {
+                // There is no batch verification algorithm for ECDSA signatures. The signature is
+                // verified immediately, and, if a batch verification is in progress, the outcome
+                // is reported when `ext_crypto_finish_batch_verify_version_1` is called.
+                let (message_ptr, message_size) = expect_pointer_size_raw!(1);
+                HostVm::SignatureVerification(SignatureVerification {
+                    algorithm: SignatureVerificationAlgorithm::Ecdsa,
+                    signature_ptr: expect_pointer_constant_size_raw!(0, 65),
+                    public_key_ptr: expect_pointer_constant_size_raw!(2, 33),
+                    message_ptr,
+                    message_size,
+                    is_batch_verification: self.inner.signatures_batch.is_some(),
                     inner: self.inner,
                 })
             }
-            HostFunction::ext_crypto_ecdsa_batch_verify_version_1 => host_fn_not_implemented!(),
 
             HostFunction::ext_crypto_secp256k1_ecdsa_recover_version_1
             | HostFunction::ext_crypto_secp256k1_ecdsa_recover_version_2 => {
@@ -1564,26 +1935,35 @@ impl ReadyToRun {
                     .alloc_write_and_return_pointer_size(host_fn.name(), iter::once(&result))
             }
             HostFunction::ext_crypto_start_batch_verify_version_1 => {
+                if self.inner.signatures_batch.is_some() {
+                    return HostVm::Error {
+                        error: Error::AlreadyBatchVerify,
+                        prototype: self.inner.into_prototype(),
+                    };
+                }
+
+                self.inner.signatures_batch = Some(SignaturesBatch {
+                    ed25519: Vec::new(),
+                    sr25519: Vec::new(),
+                    others_valid: true,
+                });
+
                 HostVm::ReadyToRun(ReadyToRun {
                     resume_value: None,
                     inner: self.inner,
                 })
             }
             HostFunction::ext_crypto_finish_batch_verify_version_1 => {
-                // This function is a dummy implementation. After `ext_crypto_start_batch_verify`
-                // has been called, the runtime is supposed to call
-                // `ext_crypto_ed25519_batch_verify`, `ext_crypto_sr25519_batch_verify`, or
-                // `ext_crypto_ecdsa_batch_verify`, then retrieve the result using
-                // `ext_crypto_finish_batch_verify`. However, none of the three
-                // `<algo>_batch_verify` functions is supported by smoldot at the moment.
-                // Thus, if the runtime calls `ext_crypto_finish_batch_verify`, then it
-                // necessarily means that the batch is empty, and thus we always return success.
-                // At the moment, batch signatures verification isn't enabled on any production
-                // chain.
-                // Once support for any of the `<algo>_batch_verify` function is added, this
-                // implementation should of course change.
+                let Some(batch) = self.inner.signatures_batch.take()
+                    else {
+                        return HostVm::Error {
+                            error: Error::NoBatchVerify,
+                            prototype: self.inner.into_prototype(),
+                        };
+                    };
+
                 HostVm::ReadyToRun(ReadyToRun {
-                    resume_value: Some(vm::WasmValue::I32(1)),
+                    resume_value: Some(vm::WasmValue::I32(if batch.verify() { 1 } else { 0 })),
                     inner: self.inner,
                 })
             }
@@ -1705,51 +2085,227 @@ impl ReadyToRun {
                     inner: self.inner,
                 })
             }
-            HostFunction::ext_offchain_is_validator_version_1 => host_fn_not_implemented!(),
-            HostFunction::ext_offchain_submit_transaction_version_1 => host_fn_not_implemented!(),
-            HostFunction::ext_offchain_network_state_version_1 => host_fn_not_implemented!(),
-            HostFunction::ext_offchain_timestamp_version_1 => host_fn_not_implemented!(),
-            HostFunction::ext_offchain_sleep_until_version_1 => host_fn_not_implemented!(),
-            HostFunction::ext_offchain_random_seed_version_1 => host_fn_not_implemented!(),
-            HostFunction::ext_offchain_local_storage_set_version_1 => host_fn_not_implemented!(),
-            HostFunction::ext_offchain_local_storage_compare_and_set_version_1 => {
-                host_fn_not_implemented!()
+            HostFunction::ext_offchain_is_validator_version_1 => {
+                HostVm::OffchainIsValidator(OffchainIsValidator { inner: self.inner })
             }
-            HostFunction::ext_offchain_local_storage_get_version_1 => host_fn_not_implemented!(),
-            HostFunction::ext_offchain_local_storage_clear_version_1 => host_fn_not_implemented!(),
-            HostFunction::ext_offchain_http_request_start_version_1 => host_fn_not_implemented!(),
-            HostFunction::ext_offchain_http_request_add_header_version_1 => {
-                host_fn_not_implemented!()
+            HostFunction::ext_offchain_submit_transaction_version_1 => {
+                let (transaction_ptr, transaction_size) = expect_pointer_size_raw!(0);
+                HostVm::OffchainSubmitTransaction(OffchainSubmitTransaction {
+                    transaction_ptr,
+                    transaction_size,
+                    inner: self.inner,
+                })
             }
-            HostFunction::ext_offchain_http_request_write_body_version_1 => {
-                host_fn_not_implemented!()
+            HostFunction::ext_offchain_network_state_version_1 => {
+                HostVm::OffchainNetworkState(OffchainNetworkState { inner: self.inner })
             }
-            HostFunction::ext_offchain_http_response_wait_version_1 => host_fn_not_implemented!(),
-            HostFunction::ext_offchain_http_response_headers_version_1 => {
-                host_fn_not_implemented!()
+            HostFunction::ext_offchain_timestamp_version_1 => {
+                HostVm::OffchainTimestamp(OffchainTimestamp { inner: self.inner })
             }
-            HostFunction::ext_offchain_http_response_read_body_version_1 => {
-                host_fn_not_implemented!()
+            HostFunction::ext_offchain_sleep_until_version_1 => {
+                let deadline = match params[0] {
+                    vm::WasmValue::I64(v) => u64::from_ne_bytes(v.to_ne_bytes()),
+                    // The signatures are checked at initialization and the Wasm VM ensures that
+                    // the proper parameter types are provided.
+                    _ => unreachable!(),
+                };
+
+                HostVm::OffchainSleepUntil(OffchainSleepUntil {
+                    deadline,
+                    inner: self.inner,
+                })
             }
-            HostFunction::ext_trie_blake2_256_root_version_1
-            | HostFunction::ext_trie_blake2_256_root_version_2 => {
-                let state_version =
-                    if matches!(host_fn, HostFunction::ext_trie_blake2_256_root_version_2) {
-                        expect_state_version!(1)
-                    } else {
-                        TrieEntryVersion::V0
-                    };
+            HostFunction::ext_offchain_random_seed_version_1 => {
+                HostVm::OffchainRandomSeed(OffchainRandomSeed { inner: self.inner })
+            }
+            HostFunction::ext_offchain_local_storage_set_version_1 => {
+                let kind = expect_offchain_storage_kind!(0);
+                let (key_ptr, key_size) = expect_pointer_size_raw!(1);
+                let (value_ptr, value_size) = expect_pointer_size_raw!(2);
+                HostVm::OffchainStorageSet(OffchainStorageSet {
+                    calling: host_fn,
+                    kind,
+                    key_ptr,
+                    key_size,
+                    value: Some((value_ptr, value_size)),
+                    old_value: None,
+                    inner: self.inner,
+                })
+            }
+            HostFunction::ext_offchain_local_storage_compare_and_set_version_1 => {
+                let kind = expect_offchain_storage_kind!(0);
+                let (key_ptr, key_size) = expect_pointer_size_raw!(1);
 
-                let result = {
-                    let input = expect_pointer_size!(0);
+                let old_value = {
+                    let input = expect_pointer_size!(2);
                     let parsing_result: Result<_, nom::Err<(&[u8], nom::error::ErrorKind)>> =
-                        nom::combinator::all_consuming(nom::combinator::flat_map(
-                            crate::util::nom_scale_compact_usize,
-                            |num_elems| {
-                                nom::multi::many_m_n(
-                                    num_elems,
-                                    num_elems,
-                                    nom::sequence::tuple((
+                        nom::combinator::all_consuming(util::nom_option_decode(
+                            util::nom_bytes_decode,
+                        ))(input.as_ref())
+                        .map(|(_, parse_result)| parse_result.map(|value| value.to_vec()));
+
+                    match parsing_result {
+                        Ok(val) => Ok(val),
+                        Err(_) => Err(()),
+                    }
+                };
+
+                let old_value = match old_value {
+                    Ok(old_value) => old_value,
+                    Err(()) => {
+                        return HostVm::Error {
+                            error: Error::ParamDecodeError,
+                            prototype: self.inner.into_prototype(),
+                        }
+                    }
+                };
+
+                let (value_ptr, value_size) = expect_pointer_size_raw!(3);
+                HostVm::OffchainStorageSet(OffchainStorageSet {
+                    calling: host_fn,
+                    kind,
+                    key_ptr,
+                    key_size,
+                    value: Some((value_ptr, value_size)),
+                    old_value: Some(old_value),
+                    inner: self.inner,
+                })
+            }
+            HostFunction::ext_offchain_local_storage_get_version_1 => {
+                let kind = expect_offchain_storage_kind!(0);
+                let (key_ptr, key_size) = expect_pointer_size_raw!(1);
+                HostVm::OffchainStorageGet(OffchainStorageGet {
+                    kind,
+                    key_ptr,
+                    key_size,
+                    inner: self.inner,
+                })
+            }
+            HostFunction::ext_offchain_local_storage_clear_version_1 => {
+                let kind = expect_offchain_storage_kind!(0);
+                let (key_ptr, key_size) = expect_pointer_size_raw!(1);
+                HostVm::OffchainStorageSet(OffchainStorageSet {
+                    calling: host_fn,
+                    kind,
+                    key_ptr,
+                    key_size,
+                    value: None,
+                    old_value: None,
+                    inner: self.inner,
+                })
+            }
+            HostFunction::ext_offchain_http_request_start_version_1 => {
+                let method = expect_string!(0);
+                let uri = expect_string!(1);
+                // The third parameter is a "meta" parameter that is unused at the moment and
+                // that is thus ignored.
+                let _ = expect_pointer_size_raw!(2);
+                HostVm::OffchainHttpRequestStart(OffchainHttpRequestStart {
+                    method,
+                    uri,
+                    inner: self.inner,
+                })
+            }
+            HostFunction::ext_offchain_http_request_add_header_version_1 => {
+                let request_id = expect_http_request_id!(0);
+                let name = expect_string!(1);
+                let value = expect_string!(2);
+                HostVm::OffchainHttpRequestAddHeader(OffchainHttpRequestAddHeader {
+                    request_id,
+                    name,
+                    value,
+                    inner: self.inner,
+                })
+            }
+            HostFunction::ext_offchain_http_request_write_body_version_1 => {
+                let request_id = expect_http_request_id!(0);
+                let (chunk_ptr, chunk_size) = expect_pointer_size_raw!(1);
+                let deadline = expect_deadline!(2);
+                HostVm::OffchainHttpRequestWriteBody(OffchainHttpRequestWriteBody {
+                    request_id,
+                    chunk_ptr,
+                    chunk_size,
+                    deadline,
+                    inner: self.inner,
+                })
+            }
+            HostFunction::ext_offchain_http_response_wait_version_1 => {
+                let request_ids = {
+                    let input = expect_pointer_size!(0);
+                    let parsing_result: Result<_, nom::Err<(&[u8], nom::error::ErrorKind)>> =
+                        nom::combinator::all_consuming(nom::combinator::flat_map(
+                            util::nom_scale_compact_usize,
+                            |num_elems| {
+                                nom::multi::many_m_n(
+                                    num_elems,
+                                    num_elems,
+                                    nom::number::complete::le_u16,
+                                )
+                            },
+                        ))(input.as_ref())
+                        .map(|(_, parse_result)| parse_result);
+
+                    match parsing_result {
+                        Ok(val) => Ok(val),
+                        Err(_) => Err(()),
+                    }
+                };
+
+                let request_ids = match request_ids {
+                    Ok(request_ids) => request_ids,
+                    Err(()) => {
+                        return HostVm::Error {
+                            error: Error::ParamDecodeError,
+                            prototype: self.inner.into_prototype(),
+                        }
+                    }
+                };
+
+                let deadline = expect_deadline!(1);
+                HostVm::OffchainHttpResponseWait(OffchainHttpResponseWait {
+                    request_ids,
+                    deadline,
+                    inner: self.inner,
+                })
+            }
+            HostFunction::ext_offchain_http_response_headers_version_1 => {
+                let request_id = expect_http_request_id!(0);
+                HostVm::OffchainHttpResponseHeaders(OffchainHttpResponseHeaders {
+                    request_id,
+                    inner: self.inner,
+                })
+            }
+            HostFunction::ext_offchain_http_response_read_body_version_1 => {
+                let request_id = expect_http_request_id!(0);
+                let (buffer_ptr, buffer_size) = expect_pointer_size_raw!(1);
+                let deadline = expect_deadline!(2);
+                HostVm::OffchainHttpResponseReadBody(OffchainHttpResponseReadBody {
+                    request_id,
+                    buffer_ptr,
+                    buffer_size,
+                    deadline,
+                    inner: self.inner,
+                })
+            }
+            HostFunction::ext_trie_blake2_256_root_version_1
+            | HostFunction::ext_trie_blake2_256_root_version_2 => {
+                let state_version =
+                    if matches!(host_fn, HostFunction::ext_trie_blake2_256_root_version_2) {
+                        expect_state_version!(1)
+                    } else {
+                        TrieEntryVersion::V0
+                    };
+
+                let result = {
+                    let input = expect_pointer_size!(0);
+                    let parsing_result: Result<_, nom::Err<(&[u8], nom::error::ErrorKind)>> =
+                        nom::combinator::all_consuming(nom::combinator::flat_map(
+                            crate::util::nom_scale_compact_usize,
+                            |num_elems| {
+                                nom::multi::many_m_n(
+                                    num_elems,
+                                    num_elems,
+                                    nom::sequence::tuple((
                                         nom::combinator::flat_map(
                                             crate::util::nom_scale_compact_usize,
                                             nom::bytes::complete::take,
@@ -1979,9 +2535,9 @@ impl ReadyToRun {
                 HostVm::LogEmit(LogEmit {
                     inner: self.inner,
                     log_entry: LogEmitInner::Log {
-                        _log_level: log_level,
-                        _target_str_ptr: target_str_ptr,
-                        _target_str_size: target_str_size,
+                        log_level,
+                        target_str_ptr,
+                        target_str_size,
                         msg_str_ptr,
                         msg_str_size,
                     },
@@ -2661,6 +3217,9 @@ pub struct SignatureVerification {
     message_ptr: u32,
     /// Size of the message. Guaranteed to be in range.
     message_size: u32,
+    /// `true` if the signature is part of a batch verification. The outcome of the verification
+    /// is then stored in [`Inner::signatures_batch`] rather than returned to the runtime.
+    is_batch_verification: bool,
 }
 
 enum SignatureVerificationAlgorithm {
@@ -2827,7 +3386,18 @@ impl SignatureVerification {
         self.resume(false)
     }
 
-    fn resume(self, success: bool) -> HostVm {
+    fn resume(mut self, success: bool) -> HostVm {
+        // Signatures that are part of a batch verification are always reported as valid to the
+        // runtime. The actual outcome is returned by `ext_crypto_finish_batch_verify_version_1`.
+        if self.is_batch_verification {
+            let batch = self.inner.signatures_batch.as_mut().unwrap();
+            batch.others_valid &= success;
+            return HostVm::ReadyToRun(ReadyToRun {
+                resume_value: Some(vm::WasmValue::I32(1)),
+                inner: self.inner,
+            });
+        }
+
         // All signature-related host functions work the same way in terms of return value.
         HostVm::ReadyToRun(ReadyToRun {
             resume_value: Some(vm::WasmValue::I32(if success { 1 } else { 0 })),
@@ -2945,147 +3515,1174 @@ impl fmt::Debug for ExternalOffchainStorageSet {
     }
 }
 
-/// Report about a log entry being emitted.
-///
-/// Use the implementation of [`fmt::Display`] to obtain the log entry. For example, you can
-/// call [`alloc::string::ToString::to_string`] to turn it into a `String`.
-pub struct LogEmit {
-    inner: Box<Inner>,
-    log_entry: LogEmitInner,
+/// Cryptographic algorithm of a key of the keystore.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum KeyAlgorithm {
+    /// Ed25519 key. Public keys are 32 bytes and signatures are 64 bytes.
+    Ed25519,
+    /// Sr25519 key. Public keys are 32 bytes and signatures are 64 bytes.
+    Sr25519,
+    /// ECDSA key on the secp256k1 curve. Public keys are 33 bytes (compressed form) and
+    /// signatures are 65 bytes (the signature followed with the recovery ID).
+    Ecdsa,
 }
 
-enum LogEmitInner {
-    Num(u64),
-    Utf8 {
-        /// Pointer to the string. Guaranteed to be in range and to be UTF-8.
-        str_ptr: u32,
-        /// Size of the string. Guaranteed to be in range and to be UTF-8.
-        str_size: u32,
-    },
-    Hex {
-        /// Pointer to the data. Guaranteed to be in range.
-        data_ptr: u32,
-        /// Size of the data. Guaranteed to be in range.
-        data_size: u32,
-    },
-    Log {
-        /// Log level. Arbitrary number indicated by runtime, but typically in the `1..=5` range.
-        _log_level: u32,
-        /// Pointer to the string of the log target. Guaranteed to be in range and to be UTF-8.
-        _target_str_ptr: u32,
-        /// Size of the string of the log target. Guaranteed to be in range and to be UTF-8.
-        _target_str_size: u32,
-        /// Pointer to the string of the log message. Guaranteed to be in range and to be UTF-8.
-        msg_str_ptr: u32,
-        /// Size of the string of the log message. Guaranteed to be in range and to be UTF-8.
-        msg_str_size: u32,
-    },
-}
+impl KeyAlgorithm {
+    /// Returns the size, in bytes, of a public key of this algorithm.
+    pub fn public_key_size(&self) -> usize {
+        match self {
+            KeyAlgorithm::Ed25519 | KeyAlgorithm::Sr25519 => 32,
+            KeyAlgorithm::Ecdsa => 33,
+        }
+    }
 
-impl LogEmit {
-    /// Resumes execution after having set the value.
-    pub fn resume(self) -> HostVm {
-        HostVm::ReadyToRun(ReadyToRun {
-            inner: self.inner,
-            resume_value: None,
-        })
+    /// Returns the size, in bytes, of a signature of this algorithm.
+    pub fn signature_size(&self) -> usize {
+        match self {
+            KeyAlgorithm::Ed25519 | KeyAlgorithm::Sr25519 => 64,
+            KeyAlgorithm::Ecdsa => 65,
+        }
     }
 }
 
-impl fmt::Display for LogEmit {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match self.log_entry {
-            LogEmitInner::Num(num) => write!(f, "{num}"),
-            LogEmitInner::Utf8 { str_ptr, str_size } => {
-                let str = self.inner.vm.read_memory(str_ptr, str_size).unwrap();
-                let str = str::from_utf8(str.as_ref()).unwrap();
-                write!(f, "{str}")
-            }
-            LogEmitInner::Hex {
-                data_ptr,
-                data_size,
-            } => {
-                let data = self.inner.vm.read_memory(data_ptr, data_size).unwrap();
-                write!(f, "{}", hex::encode(data.as_ref()))
-            }
-            LogEmitInner::Log {
-                msg_str_ptr,
-                msg_str_size,
-                ..
-            } => {
-                let str = self
-                    .inner
-                    .vm
-                    .read_memory(msg_str_ptr, msg_str_size)
-                    .unwrap();
-                let str = str::from_utf8(str.as_ref()).unwrap();
-                // TODO: don't just ignore the target and log level? better log representation? more control? also, it's unclear whether it should be an individual line
-                writeln!(f, "{str}")
-            }
+/// Must provide the list of public keys of the keystore that match the given key type and
+/// algorithm.
+pub struct KeystorePublicKeys {
+    inner: Box<Inner>,
+
+    /// Host function being called. Used for error reporting.
+    calling: HostFunction,
+    /// Algorithm of the keys to list.
+    algorithm: KeyAlgorithm,
+    /// Key type identifier, such as `b"beef"` or `b"babe"`.
+    key_type_id: [u8; 4],
+}
+
+impl KeystorePublicKeys {
+    /// Returns the algorithm of the keys to list.
+    pub fn algorithm(&self) -> KeyAlgorithm {
+        self.algorithm
+    }
+
+    /// Returns the key type identifier, such as `b"beef"` or `b"babe"`.
+    pub fn key_type_id(&self) -> &[u8; 4] {
+        &self.key_type_id
+    }
+
+    /// Writes the list of public keys to the memory and prepares for execution.
+    ///
+    /// # Panic
+    ///
+    /// Panics if one of the public keys doesn't have a size equal to
+    /// [`KeyAlgorithm::public_key_size`].
+    ///
+    pub fn resume(
+        self,
+        public_keys: impl Iterator<Item = impl AsRef<[u8]> + Clone> + Clone,
+    ) -> HostVm {
+        let mut num_keys = 0;
+        for public_key in public_keys.clone() {
+            assert_eq!(public_key.as_ref().len(), self.algorithm.public_key_size());
+            num_keys += 1;
         }
+
+        self.inner.alloc_write_and_return_pointer_size(
+            self.calling.name(),
+            iter::once(either::Left(util::encode_scale_compact_usize(num_keys)))
+                .chain(public_keys.map(either::Right)),
+        )
     }
 }
 
-impl fmt::Debug for LogEmit {
+impl fmt::Debug for KeystorePublicKeys {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        f.debug_struct("LogEmit")
-            .field("message", &self.to_string())
+        f.debug_struct("KeystorePublicKeys")
+            .field("algorithm", &self.algorithm)
+            .field("key_type_id", &self.key_type_id)
             .finish()
     }
 }
 
-/// Queries the maximum log level.
-pub struct GetMaxLogLevel {
+/// Must generate a new key in the keystore and provide its public key.
+pub struct KeystoreGenerate {
     inner: Box<Inner>,
+
+    /// Host function being called. Used for error reporting.
+    calling: HostFunction,
+    /// Algorithm of the key to generate.
+    algorithm: KeyAlgorithm,
+    /// Key type identifier, such as `b"beef"` or `b"babe"`.
+    key_type_id: [u8; 4],
+    /// Secret URI passed by the runtime, if any.
+    seed: Option<String>,
 }
 
-impl GetMaxLogLevel {
-    /// Resumes execution after indicating the maximum log level.
+impl KeystoreGenerate {
+    /// Returns the algorithm of the key to generate.
+    pub fn algorithm(&self) -> KeyAlgorithm {
+        self.algorithm
+    }
+
+    /// Returns the key type identifier, such as `b"beef"` or `b"babe"`.
+    pub fn key_type_id(&self) -> &[u8; 4] {
+        &self.key_type_id
+    }
+
+    /// Returns the secret URI (for example a BIP39 seed phrase followed with a derivation path)
+    /// the key must be derived from. If `None`, the key must be randomly generated.
     ///
-    /// 0 means off, 1 means error, 2 means warn, 3 means info, 4 means debug, 5 means trace.
-    pub fn resume(self, max_level: u32) -> HostVm {
-        HostVm::ReadyToRun(ReadyToRun {
-            inner: self.inner,
-            resume_value: Some(vm::WasmValue::I32(i32::from_ne_bytes(
-                max_level.to_ne_bytes(),
-            ))),
-        })
+    /// See also [`crate::identity::seed_phrase`].
+    pub fn seed(&self) -> Option<&str> {
+        self.seed.as_deref()
+    }
+
+    /// Writes the public key of the newly-generated key to the memory and prepares for
+    /// execution.
+    ///
+    /// Pass `None` if the key couldn't be generated, for example because there is no keystore
+    /// or because the seed is invalid. This leads to a [`HostVm::Error`].
+    ///
+    /// # Panic
+    ///
+    /// Panics if the public key doesn't have a size equal to [`KeyAlgorithm::public_key_size`].
+    ///
+    pub fn resume(self, public_key: Option<&[u8]>) -> HostVm {
+        let Some(public_key) = public_key
+        else {
+            return HostVm::Error {
+                error: Error::KeyGenerationFailed {
+                    function: self.calling.name(),
+                },
+                prototype: self.inner.into_prototype(),
+            };
+        };
+
+        assert_eq!(public_key.len(), self.algorithm.public_key_size());
+        self.inner
+            .alloc_write_and_return_pointer(self.calling.name(), iter::once(public_key))
     }
 }
 
-impl fmt::Debug for GetMaxLogLevel {
+impl fmt::Debug for KeystoreGenerate {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        f.debug_tuple("GetMaxLogLevel").finish()
+        f.debug_struct("KeystoreGenerate")
+            .field("algorithm", &self.algorithm)
+            .field("key_type_id", &self.key_type_id)
+            .finish()
     }
 }
 
-/// Declares the start of a transaction.
-pub struct StartStorageTransaction {
+/// Must sign a message using a key of the keystore.
+pub struct SignRequest {
     inner: Box<Inner>,
+
+    /// Host function being called. Used for error reporting.
+    calling: HostFunction,
+    /// Algorithm of the key to sign with.
+    algorithm: KeyAlgorithm,
+    /// Key type identifier, such as `b"beef"` or `b"babe"`.
+    key_type_id: [u8; 4],
+    /// Pointer to the public key. The size of the public key depends on the algorithm.
+    /// Guaranteed to be in range.
+    public_key_ptr: u32,
+    /// Message to sign.
+    message: SignRequestMessage,
 }
 
-impl StartStorageTransaction {
-    /// Resumes execution after having acknowledged the event.
-    pub fn resume(self) -> HostVm {
-        HostVm::ReadyToRun(ReadyToRun {
-            inner: self.inner,
-            resume_value: None,
-        })
-    }
+enum SignRequestMessage {
+    /// Message is in the memory of the virtual machine. Guaranteed to be in range.
+    Memory { ptr: u32, size: u32 },
+    /// Message is a hash that has been copied out of the memory or calculated by the host.
+    Hash([u8; 32]),
 }
 
-impl fmt::Debug for StartStorageTransaction {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        f.debug_tuple("StartStorageTransaction").finish()
+impl SignRequest {
+    /// Returns the algorithm of the key to sign with.
+    pub fn algorithm(&self) -> KeyAlgorithm {
+        self.algorithm
     }
-}
 
-/// Declares the end of a transaction.
-pub struct EndStorageTransaction {
-    inner: Box<Inner>,
-}
+    /// Returns the key type identifier, such as `b"beef"` or `b"babe"`.
+    pub fn key_type_id(&self) -> &[u8; 4] {
+        &self.key_type_id
+    }
 
-impl EndStorageTransaction {
+    /// Returns the public key of the key to sign with. Its size is equal to
+    /// [`KeyAlgorithm::public_key_size`].
+    ///
+    /// > **Note**: Be aware that this public key is untrusted input and might not be part of the
+    /// >           keystore.
+    pub fn public_key(&'_ self) -> impl AsRef<[u8]> + '_ {
+        self.inner
+            .vm
+            .read_memory(
+                self.public_key_ptr,
+                u32::try_from(self.algorithm.public_key_size()).unwrap(),
+            )
+            .unwrap()
+    }
+
+    /// Returns the message to sign.
+    ///
+    /// If the algorithm is [`KeyAlgorithm::Ecdsa`], this is always the 32 bytes hash of the
+    /// message, and it must be signed as is without being hashed again.
+    pub fn message(&'_ self) -> impl AsRef<[u8]> + '_ {
+        match &self.message {
+            SignRequestMessage::Memory { ptr, size } => {
+                either::Left(self.inner.vm.read_memory(*ptr, *size).unwrap())
+            }
+            SignRequestMessage::Hash(hash) => either::Right(&hash[..]),
+        }
+    }
+
+    /// Writes the signature to the memory and prepares for execution.
+    ///
+    /// Pass `None` if the key isn't in the keystore or if signing has failed.
+    ///
+    /// # Panic
+    ///
+    /// Panics if the signature doesn't have a size equal to [`KeyAlgorithm::signature_size`].
+    ///
+    pub fn resume(self, signature: Option<&[u8]>) -> HostVm {
+        if let Some(signature) = signature {
+            assert_eq!(signature.len(), self.algorithm.signature_size());
+            self.inner.alloc_write_and_return_pointer_size(
+                self.calling.name(),
+                [&[1][..], signature].into_iter(),
+            )
+        } else {
+            self.inner
+                .alloc_write_and_return_pointer_size(self.calling.name(), iter::once(&[0]))
+        }
+    }
+}
+
+impl fmt::Debug for SignRequest {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("SignRequest")
+            .field("algorithm", &self.algorithm)
+            .field("key_type_id", &self.key_type_id)
+            .field("public_key", &self.public_key().as_ref())
+            .field("message", &self.message().as_ref())
+            .finish()
+    }
+}
+
+/// Kind of off-chain storage.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum OffchainStorageKind {
+    /// Storage that is shared between all the executions of off-chain workers, regardless of
+    /// forks, and persisted across restarts of the node. This is also the storage that
+    /// `ext_offchain_index_set_version_1` writes to.
+    Persistent,
+    /// Storage whose modifications are supposed to be reverted if the block the off-chain worker
+    /// has been executed against gets pruned.
+    Local,
+}
+
+/// Must load a value from the off-chain storage.
+pub struct OffchainStorageGet {
+    inner: Box<Inner>,
+
+    /// Kind of storage to load from.
+    kind: OffchainStorageKind,
+    /// Pointer to the key whose value must be loaded. Guaranteed to be in range.
+    key_ptr: u32,
+    /// Size of the key whose value must be loaded. Guaranteed to be in range.
+    key_size: u32,
+}
+
+impl OffchainStorageGet {
+    /// Returns the kind of storage to load the value from.
+    pub fn kind(&self) -> OffchainStorageKind {
+        self.kind
+    }
+
+    /// Returns the key whose value must be loaded.
+    pub fn key(&'_ self) -> impl AsRef<[u8]> + '_ {
+        self.inner
+            .vm
+            .read_memory(self.key_ptr, self.key_size)
+            .unwrap()
+    }
+
+    /// Writes the storage value in the memory and prepares for execution. Pass `None` if the
+    /// storage doesn't contain any value for this key.
+    pub fn resume(self, value: Option<&[u8]>) -> HostVm {
+        if let Some(value) = value {
+            let value_len_enc = util::encode_scale_compact_usize(value.len());
+            self.inner.alloc_write_and_return_pointer_size(
+                HostFunction::ext_offchain_local_storage_get_version_1.name(),
+                [&[1][..], value_len_enc.as_ref(), value].into_iter(),
+            )
+        } else {
+            self.inner.alloc_write_and_return_pointer_size(
+                HostFunction::ext_offchain_local_storage_get_version_1.name(),
+                iter::once(&[0]),
+            )
+        }
+    }
+}
+
+impl fmt::Debug for OffchainStorageGet {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("OffchainStorageGet")
+            .field("kind", &self.kind)
+            .field("key", &self.key().as_ref())
+            .finish()
+    }
+}
+
+/// Must set or remove a value of the off-chain storage, potentially only if the current value
+/// matches a certain value.
+pub struct OffchainStorageSet {
+    inner: Box<Inner>,
+
+    /// Host function being called. Used to determine the return value.
+    calling: HostFunction,
+    /// Kind of storage to modify.
+    kind: OffchainStorageKind,
+    /// Pointer to the key whose value must be set. Guaranteed to be in range.
+    key_ptr: u32,
+    /// Size of the key whose value must be set. Guaranteed to be in range.
+    key_size: u32,
+    /// Pointer and size of the value to set. `None` for clearing. Guaranteed to be in range.
+    value: Option<(u32, u32)>,
+    /// If `Some`, the modification must only be performed if the current value is equal to
+    /// the inner value.
+    old_value: Option<Option<Vec<u8>>>,
+}
+
+impl OffchainStorageSet {
+    /// Returns the kind of storage to modify.
+    pub fn kind(&self) -> OffchainStorageKind {
+        self.kind
+    }
+
+    /// Returns the key whose value must be set.
+    pub fn key(&'_ self) -> impl AsRef<[u8]> + '_ {
+        self.inner
+            .vm
+            .read_memory(self.key_ptr, self.key_size)
+            .unwrap()
+    }
+
+    /// Returns the value to set.
+    ///
+    /// If `None` is returned, the key should be removed from the storage entirely.
+    pub fn value(&'_ self) -> Option<impl AsRef<[u8]> + '_> {
+        self.value
+            .map(|(ptr, size)| self.inner.vm.read_memory(ptr, size).unwrap())
+    }
+
+    /// Returns the value that the current value must be compared against.
+    ///
+    /// If `None`, the modification must be performed unconditionally. If `Some(None)`, the
+    /// modification must only be performed if the storage doesn't contain any value for this
+    /// key. If `Some(Some(_))`, the modification must only be performed if the current value is
+    /// equal to the given value.
+    ///
+    /// The comparison and the modification must be performed atomically.
+    pub fn old_value(&self) -> Option<Option<&[u8]>> {
+        self.old_value.as_ref().map(|v| v.as_deref())
+    }
+
+    /// Resumes execution after having set the value. Must indicate whether the value has been
+    /// modified, in other words `false` if the comparison against
+    /// [`OffchainStorageSet::old_value`] has failed.
+    pub fn resume(self, replaced: bool) -> HostVm {
+        debug_assert!(replaced || self.old_value.is_some());
+        HostVm::ReadyToRun(ReadyToRun {
+            inner: self.inner,
+            resume_value: match self.calling {
+                HostFunction::ext_offchain_local_storage_compare_and_set_version_1 => {
+                    Some(vm::WasmValue::I32(if replaced { 1 } else { 0 }))
+                }
+                _ => None,
+            },
+        })
+    }
+}
+
+impl fmt::Debug for OffchainStorageSet {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("OffchainStorageSet")
+            .field("kind", &self.kind)
+            .field("key", &self.key().as_ref())
+            .field("value", &self.value().as_ref().map(|v| v.as_ref()))
+            .field("old_value", &self.old_value())
+            .finish()
+    }
+}
+
+/// Must provide the current UNIX timestamp.
+pub struct OffchainTimestamp {
+    inner: Box<Inner>,
+}
+
+impl OffchainTimestamp {
+    /// Resumes execution after having provided the current number of milliseconds since the
+    /// UNIX epoch.
+    pub fn resume(self, milliseconds_since_unix_epoch: u64) -> HostVm {
+        HostVm::ReadyToRun(ReadyToRun {
+            inner: self.inner,
+            resume_value: Some(vm::WasmValue::I64(i64::from_ne_bytes(
+                milliseconds_since_unix_epoch.to_ne_bytes(),
+            ))),
+        })
+    }
+}
+
+impl fmt::Debug for OffchainTimestamp {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("OffchainTimestamp").finish()
+    }
+}
+
+/// Must wait until a certain UNIX timestamp has been reached.
+pub struct OffchainSleepUntil {
+    inner: Box<Inner>,
+
+    /// Value returned by [`OffchainSleepUntil::deadline`].
+    deadline: u64,
+}
+
+impl OffchainSleepUntil {
+    /// Returns the number of milliseconds since the UNIX epoch until which to wait before
+    /// resuming the execution.
+    pub fn deadline(&self) -> u64 {
+        self.deadline
+    }
+
+    /// Resumes execution after having waited until [`OffchainSleepUntil::deadline`].
+    pub fn resume(self) -> HostVm {
+        HostVm::ReadyToRun(ReadyToRun {
+            inner: self.inner,
+            resume_value: None,
+        })
+    }
+}
+
+impl fmt::Debug for OffchainSleepUntil {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("OffchainSleepUntil")
+            .field("deadline", &self.deadline)
+            .finish()
+    }
+}
+
+/// Must provide a randomly-generated seed.
+pub struct OffchainRandomSeed {
+    inner: Box<Inner>,
+}
+
+impl OffchainRandomSeed {
+    /// Writes the seed in the memory and prepares for execution.
+    ///
+    /// The seed should be generated using a cryptographically-secure source of randomness.
+    pub fn resume(self, seed: &[u8; 32]) -> HostVm {
+        self.inner.alloc_write_and_return_pointer(
+            HostFunction::ext_offchain_random_seed_version_1.name(),
+            iter::once(seed),
+        )
+    }
+}
+
+impl fmt::Debug for OffchainRandomSeed {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("OffchainRandomSeed").finish()
+    }
+}
+
+/// Must submit a transaction to the transactions pool.
+pub struct OffchainSubmitTransaction {
+    inner: Box<Inner>,
+
+    /// Pointer to the transaction. Guaranteed to be in range.
+    transaction_ptr: u32,
+    /// Size of the transaction. Guaranteed to be in range.
+    transaction_size: u32,
+}
+
+impl OffchainSubmitTransaction {
+    /// Returns the SCALE-encoded transaction to submit.
+    pub fn transaction(&'_ self) -> impl AsRef<[u8]> + '_ {
+        self.inner
+            .vm
+            .read_memory(self.transaction_ptr, self.transaction_size)
+            .unwrap()
+    }
+
+    /// Resumes execution after having submitted the transaction. Must indicate whether the
+    /// transaction has been accepted by the transactions pool.
+    pub fn resume(self, success: bool) -> HostVm {
+        // The runtime expects a SCALE-encoded `Result<(), ()>`.
+        self.inner.alloc_write_and_return_pointer_size(
+            HostFunction::ext_offchain_submit_transaction_version_1.name(),
+            iter::once(if success { &[0] } else { &[1] }),
+        )
+    }
+}
+
+impl fmt::Debug for OffchainSubmitTransaction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("OffchainSubmitTransaction")
+            .field(&self.transaction().as_ref())
+            .finish()
+    }
+}
+
+/// Must indicate whether the local node is a validator.
+pub struct OffchainIsValidator {
+    inner: Box<Inner>,
+}
+
+impl OffchainIsValidator {
+    /// Resumes execution after having indicated whether the local node is a validator.
+    pub fn resume(self, is_validator: bool) -> HostVm {
+        HostVm::ReadyToRun(ReadyToRun {
+            inner: self.inner,
+            resume_value: Some(vm::WasmValue::I32(if is_validator { 1 } else { 0 })),
+        })
+    }
+}
+
+impl fmt::Debug for OffchainIsValidator {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("OffchainIsValidator").finish()
+    }
+}
+
+/// Must provide the identity and addresses of the local node.
+pub struct OffchainNetworkState {
+    inner: Box<Inner>,
+}
+
+impl OffchainNetworkState {
+    /// Writes the network state in the memory and prepares for execution.
+    ///
+    /// Must be passed the binary encoding of the `PeerId` of the local node and the list of
+    /// binary-encoded multiaddresses the local node is reachable at, or `None` if this
+    /// information isn't available.
+    pub fn resume(
+        self,
+        network_state: Option<(&[u8], impl Iterator<Item = impl AsRef<[u8]>>)>,
+    ) -> HostVm {
+        // The runtime expects a SCALE-encoded `Result<OpaqueNetworkState, ()>`.
+        let mut encoded = Vec::new();
+        if let Some((peer_id, external_addresses)) = network_state {
+            encoded.push(0);
+            encoded.extend_from_slice(util::encode_scale_compact_usize(peer_id.len()).as_ref());
+            encoded.extend_from_slice(peer_id);
+
+            let mut num_addresses = 0;
+            let mut addresses_encoded = Vec::new();
+            for address in external_addresses {
+                let address = address.as_ref();
+                addresses_encoded
+                    .extend_from_slice(util::encode_scale_compact_usize(address.len()).as_ref());
+                addresses_encoded.extend_from_slice(address);
+                num_addresses += 1;
+            }
+
+            encoded.extend_from_slice(util::encode_scale_compact_usize(num_addresses).as_ref());
+            encoded.extend_from_slice(&addresses_encoded);
+        } else {
+            encoded.push(1);
+        }
+
+        self.inner.alloc_write_and_return_pointer_size(
+            HostFunction::ext_offchain_network_state_version_1.name(),
+            iter::once(&encoded),
+        )
+    }
+}
+
+impl fmt::Debug for OffchainNetworkState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("OffchainNetworkState").finish()
+    }
+}
+
+/// Error that an HTTP-related operation can report to the runtime.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum HttpError {
+    /// The operation couldn't be completed before the deadline.
+    DeadlineReached,
+    /// An I/O error has happened, for example the remote has closed the connection.
+    IoError,
+    /// The request ID is invalid, or the request isn't in a state where this operation is
+    /// possible.
+    Invalid,
+}
+
+impl HttpError {
+    /// Returns the SCALE encoding of this error.
+    fn scale_encoding(&self) -> u8 {
+        match self {
+            HttpError::DeadlineReached => 1,
+            HttpError::IoError => 2,
+            HttpError::Invalid => 3,
+        }
+    }
+}
+
+/// Status of an HTTP request, reported to the runtime.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum HttpRequestStatus {
+    /// The deadline has been reached while waiting for a response.
+    DeadlineReached,
+    /// An I/O error has happened. The request is now considered destroyed.
+    IoError,
+    /// The request ID is invalid.
+    Invalid,
+    /// A response has been received. Contains the HTTP status code of the response.
+    Finished(u16),
+}
+
+/// Must start an HTTP request.
+pub struct OffchainHttpRequestStart {
+    inner: Box<Inner>,
+
+    /// Value returned by [`OffchainHttpRequestStart::method`].
+    method: String,
+    /// Value returned by [`OffchainHttpRequestStart::uri`].
+    uri: String,
+}
+
+impl OffchainHttpRequestStart {
+    /// Returns the HTTP method of the request, such as `GET` or `POST`.
+    pub fn method(&self) -> &str {
+        &self.method
+    }
+
+    /// Returns the URI to send the request to.
+    pub fn uri(&self) -> &str {
+        &self.uri
+    }
+
+    /// Resumes execution after having started the request.
+    ///
+    /// Must be passed the identifier of the newly-started request, or `None` if the request
+    /// couldn't be started, for example because the URI is invalid or because too many requests
+    /// are in progress.
+    ///
+    /// Note that no network activity needs to happen yet, as the headers and body of the
+    /// request are provided later.
+    pub fn resume(self, request_id: Option<u16>) -> HostVm {
+        // The runtime expects a SCALE-encoded `Result<u16, ()>`.
+        let encoded = match request_id {
+            Some(id) => {
+                let id = id.to_le_bytes();
+                [0, id[0], id[1]]
+            }
+            None => [1, 0, 0],
+        };
+        let encoded_len = if request_id.is_some() { 3 } else { 1 };
+
+        self.inner.alloc_write_and_return_pointer_size(
+            HostFunction::ext_offchain_http_request_start_version_1.name(),
+            iter::once(&encoded[..encoded_len]),
+        )
+    }
+}
+
+impl fmt::Debug for OffchainHttpRequestStart {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("OffchainHttpRequestStart")
+            .field("method", &self.method)
+            .field("uri", &self.uri)
+            .finish()
+    }
+}
+
+/// Must add a header to an HTTP request that has been started.
+pub struct OffchainHttpRequestAddHeader {
+    inner: Box<Inner>,
+
+    /// Value returned by [`OffchainHttpRequestAddHeader::request_id`].
+    request_id: u16,
+    /// Value returned by [`OffchainHttpRequestAddHeader::name`].
+    name: String,
+    /// Value returned by [`OffchainHttpRequestAddHeader::value`].
+    value: String,
+}
+
+impl OffchainHttpRequestAddHeader {
+    /// Returns the identifier of the request, as previously passed to
+    /// [`OffchainHttpRequestStart::resume`].
+    ///
+    /// > **Note**: Be aware that this identifier is untrusted input and might not correspond to
+    /// >           any request.
+    pub fn request_id(&self) -> u16 {
+        self.request_id
+    }
+
+    /// Returns the name of the header to add.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the value of the header to add.
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+
+    /// Resumes execution after having added the header. Must indicate whether the header has
+    /// been successfully added, in other words `false` if the request ID is invalid or if the
+    /// body of the request has already started being sent.
+    pub fn resume(self, success: bool) -> HostVm {
+        // The runtime expects a SCALE-encoded `Result<(), ()>`.
+        self.inner.alloc_write_and_return_pointer_size(
+            HostFunction::ext_offchain_http_request_add_header_version_1.name(),
+            iter::once(if success { &[0] } else { &[1] }),
+        )
+    }
+}
+
+impl fmt::Debug for OffchainHttpRequestAddHeader {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("OffchainHttpRequestAddHeader")
+            .field("request_id", &self.request_id)
+            .field("name", &self.name)
+            .field("value", &self.value)
+            .finish()
+    }
+}
+
+/// Must write a chunk of the body of an HTTP request.
+pub struct OffchainHttpRequestWriteBody {
+    inner: Box<Inner>,
+
+    /// Value returned by [`OffchainHttpRequestWriteBody::request_id`].
+    request_id: u16,
+    /// Pointer to the chunk to write. Guaranteed to be in range.
+    chunk_ptr: u32,
+    /// Size of the chunk to write. Guaranteed to be in range.
+    chunk_size: u32,
+    /// Value returned by [`OffchainHttpRequestWriteBody::deadline`].
+    deadline: Option<u64>,
+}
+
+impl OffchainHttpRequestWriteBody {
+    /// Returns the identifier of the request, as previously passed to
+    /// [`OffchainHttpRequestStart::resume`].
+    ///
+    /// > **Note**: Be aware that this identifier is untrusted input and might not correspond to
+    /// >           any request.
+    pub fn request_id(&self) -> u16 {
+        self.request_id
+    }
+
+    /// Returns the chunk of body to write.
+    ///
+    /// An empty chunk indicates that the body is complete and that the request is now finished
+    /// being sent.
+    pub fn chunk(&'_ self) -> impl AsRef<[u8]> + '_ {
+        self.inner
+            .vm
+            .read_memory(self.chunk_ptr, self.chunk_size)
+            .unwrap()
+    }
+
+    /// Returns the number of milliseconds since the UNIX epoch after which the operation must
+    /// be aborted with [`HttpError::DeadlineReached`]. `None` if there is no deadline.
+    pub fn deadline(&self) -> Option<u64> {
+        self.deadline
+    }
+
+    /// Resumes execution after having written the chunk.
+    pub fn resume(self, result: Result<(), HttpError>) -> HostVm {
+        // The runtime expects a SCALE-encoded `Result<(), HttpError>`.
+        let encoded = match result {
+            Ok(()) => [0, 0],
+            Err(error) => [1, error.scale_encoding()],
+        };
+        let encoded_len = if result.is_ok() { 1 } else { 2 };
+
+        self.inner.alloc_write_and_return_pointer_size(
+            HostFunction::ext_offchain_http_request_write_body_version_1.name(),
+            iter::once(&encoded[..encoded_len]),
+        )
+    }
+}
+
+impl fmt::Debug for OffchainHttpRequestWriteBody {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("OffchainHttpRequestWriteBody")
+            .field("request_id", &self.request_id)
+            .field("chunk", &self.chunk().as_ref())
+            .field("deadline", &self.deadline)
+            .finish()
+    }
+}
+
+/// Must wait for HTTP requests to have received a response.
+pub struct OffchainHttpResponseWait {
+    inner: Box<Inner>,
+
+    /// Value returned by [`OffchainHttpResponseWait::request_ids`].
+    request_ids: Vec<u16>,
+    /// Value returned by [`OffchainHttpResponseWait::deadline`].
+    deadline: Option<u64>,
+}
+
+impl OffchainHttpResponseWait {
+    /// Returns the identifiers of the requests whose response to wait for.
+    ///
+    /// > **Note**: Be aware that these identifiers are untrusted input and might not correspond
+    /// >           to any request.
+    pub fn request_ids(&self) -> &[u16] {
+        &self.request_ids
+    }
+
+    /// Returns the number of milliseconds since the UNIX epoch after which to stop waiting.
+    /// `None` if there is no deadline.
+    pub fn deadline(&self) -> Option<u64> {
+        self.deadline
+    }
+
+    /// Writes the status of the requests in the memory and prepares for execution.
+    ///
+    /// Must be passed one status for each element of [`OffchainHttpResponseWait::request_ids`],
+    /// in the same order.
+    ///
+    /// # Panic
+    ///
+    /// Panics if the number of statuses doesn't match the number of request IDs.
+    ///
+    pub fn resume(self, statuses: &[HttpRequestStatus]) -> HostVm {
+        assert_eq!(statuses.len(), self.request_ids.len());
+
+        // The runtime expects a SCALE-encoded `Vec<HttpRequestStatus>`.
+        let mut encoded = Vec::with_capacity(4 + statuses.len() * 3);
+        encoded.extend_from_slice(util::encode_scale_compact_usize(statuses.len()).as_ref());
+        for status in statuses {
+            match status {
+                HttpRequestStatus::DeadlineReached => encoded.push(0),
+                HttpRequestStatus::IoError => encoded.push(1),
+                HttpRequestStatus::Invalid => encoded.push(2),
+                HttpRequestStatus::Finished(code) => {
+                    encoded.push(3);
+                    encoded.extend_from_slice(&code.to_le_bytes());
+                }
+            }
+        }
+
+        self.inner.alloc_write_and_return_pointer_size(
+            HostFunction::ext_offchain_http_response_wait_version_1.name(),
+            iter::once(&encoded),
+        )
+    }
+}
+
+impl fmt::Debug for OffchainHttpResponseWait {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("OffchainHttpResponseWait")
+            .field("request_ids", &self.request_ids)
+            .field("deadline", &self.deadline)
+            .finish()
+    }
+}
+
+/// Must provide the headers of the response to an HTTP request.
+pub struct OffchainHttpResponseHeaders {
+    inner: Box<Inner>,
+
+    /// Value returned by [`OffchainHttpResponseHeaders::request_id`].
+    request_id: u16,
+}
+
+impl OffchainHttpResponseHeaders {
+    /// Returns the identifier of the request, as previously passed to
+    /// [`OffchainHttpRequestStart::resume`].
+    ///
+    /// > **Note**: Be aware that this identifier is untrusted input and might not correspond to
+    /// >           any request.
+    pub fn request_id(&self) -> u16 {
+        self.request_id
+    }
+
+    /// Writes the list of headers in the memory and prepares for execution.
+    ///
+    /// Must be passed a list of names and values. The list must be empty if the request ID is
+    /// invalid or if no response has been received yet.
+    pub fn resume(
+        self,
+        headers: impl Iterator<Item = (impl AsRef<[u8]>, impl AsRef<[u8]>)>,
+    ) -> HostVm {
+        // The runtime expects a SCALE-encoded `Vec<(Vec<u8>, Vec<u8>)>`.
+        let mut num_headers = 0;
+        let mut headers_encoded = Vec::new();
+        for (name, value) in headers {
+            for item in [name.as_ref(), value.as_ref()] {
+                headers_encoded
+                    .extend_from_slice(util::encode_scale_compact_usize(item.len()).as_ref());
+                headers_encoded.extend_from_slice(item);
+            }
+            num_headers += 1;
+        }
+
+        let num_headers_encoded = util::encode_scale_compact_usize(num_headers);
+        self.inner.alloc_write_and_return_pointer_size(
+            HostFunction::ext_offchain_http_response_headers_version_1.name(),
+            [num_headers_encoded.as_ref(), &headers_encoded].into_iter(),
+        )
+    }
+}
+
+impl fmt::Debug for OffchainHttpResponseHeaders {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("OffchainHttpResponseHeaders")
+            .field("request_id", &self.request_id)
+            .finish()
+    }
+}
+
+/// Must read a chunk of the body of the response to an HTTP request.
+pub struct OffchainHttpResponseReadBody {
+    inner: Box<Inner>,
+
+    /// Value returned by [`OffchainHttpResponseReadBody::request_id`].
+    request_id: u16,
+    /// Pointer to the buffer where to write the body. Guaranteed to be in range.
+    buffer_ptr: u32,
+    /// Size of the buffer where to write the body. Guaranteed to be in range.
+    buffer_size: u32,
+    /// Value returned by [`OffchainHttpResponseReadBody::deadline`].
+    deadline: Option<u64>,
+}
+
+impl OffchainHttpResponseReadBody {
+    /// Returns the identifier of the request, as previously passed to
+    /// [`OffchainHttpRequestStart::resume`].
+    ///
+    /// > **Note**: Be aware that this identifier is untrusted input and might not correspond to
+    /// >           any request.
+    pub fn request_id(&self) -> u16 {
+        self.request_id
+    }
+
+    /// Returns the maximum number of bytes that can be passed to
+    /// [`OffchainHttpResponseReadBody::resume`].
+    pub fn max_size(&self) -> usize {
+        usize::try_from(self.buffer_size).unwrap()
+    }
+
+    /// Returns the number of milliseconds since the UNIX epoch after which the operation must
+    /// be aborted with [`HttpError::DeadlineReached`]. `None` if there is no deadline.
+    pub fn deadline(&self) -> Option<u64> {
+        self.deadline
+    }
+
+    /// Writes the next chunk of the body in the memory and prepares for execution.
+    ///
+    /// Passing an empty chunk indicates that the end of the body has been reached.
+    ///
+    /// # Panic
+    ///
+    /// Panics if the chunk is longer than [`OffchainHttpResponseReadBody::max_size`].
+    ///
+    pub fn resume(mut self, result: Result<&[u8], HttpError>) -> HostVm {
+        // The runtime expects a SCALE-encoded `Result<u32, HttpError>`.
+        let encoded = match result {
+            Ok(chunk) => {
+                assert!(chunk.len() <= self.max_size());
+                self.inner.vm.write_memory(self.buffer_ptr, chunk).unwrap();
+                let len = u32::try_from(chunk.len()).unwrap().to_le_bytes();
+                [0, len[0], len[1], len[2], len[3]]
+            }
+            Err(error) => [1, error.scale_encoding(), 0, 0, 0],
+        };
+        let encoded_len = if result.is_ok() { 5 } else { 2 };
+
+        self.inner.alloc_write_and_return_pointer_size(
+            HostFunction::ext_offchain_http_response_read_body_version_1.name(),
+            iter::once(&encoded[..encoded_len]),
+        )
+    }
+}
+
+impl fmt::Debug for OffchainHttpResponseReadBody {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("OffchainHttpResponseReadBody")
+            .field("request_id", &self.request_id)
+            .field("max_size", &self.buffer_size)
+            .field("deadline", &self.deadline)
+            .finish()
+    }
+}
+
+/// Report about a log entry being emitted.
+///
+/// Use the implementation of [`fmt::Display`] to obtain the log entry. For example, you can
+/// call [`alloc::string::ToString::to_string`] to turn it into a `String`.
+pub struct LogEmit {
+    inner: Box<Inner>,
+    log_entry: LogEmitInner,
+}
+
+enum LogEmitInner {
+    Num(u64),
+    Utf8 {
+        /// Pointer to the string. Guaranteed to be in range and to be UTF-8.
+        str_ptr: u32,
+        /// Size of the string. Guaranteed to be in range and to be UTF-8.
+        str_size: u32,
+    },
+    Hex {
+        /// Pointer to the data. Guaranteed to be in range.
+        data_ptr: u32,
+        /// Size of the data. Guaranteed to be in range.
+        data_size: u32,
+    },
+    Log {
+        /// Log level. Arbitrary number indicated by runtime, but typically in the `1..=5` range.
+        log_level: u32,
+        /// Pointer to the string of the log target. Guaranteed to be in range and to be UTF-8.
+        target_str_ptr: u32,
+        /// Size of the string of the log target. Guaranteed to be in range and to be UTF-8.
+        target_str_size: u32,
+        /// Pointer to the string of the log message. Guaranteed to be in range and to be UTF-8.
+        msg_str_ptr: u32,
+        /// Size of the string of the log message. Guaranteed to be in range and to be UTF-8.
+        msg_str_size: u32,
+    },
+}
+
+impl LogEmit {
+    /// Returns the log level of the entry, if the runtime has provided one.
+    ///
+    /// This is an arbitrary number indicated by runtime, but typically in the `1..=5` range.
+    pub fn log_level(&self) -> Option<u32> {
+        match self.log_entry {
+            LogEmitInner::Log { log_level, .. } => Some(log_level),
+            _ => None,
+        }
+    }
+
+    /// Returns the target of the log entry, if the runtime has provided one.
+    pub fn target(&self) -> Option<String> {
+        match self.log_entry {
+            LogEmitInner::Log {
+                target_str_ptr,
+                target_str_size,
+                ..
+            } => {
+                let target = self
+                    .inner
+                    .vm
+                    .read_memory(target_str_ptr, target_str_size)
+                    .unwrap();
+                Some(str::from_utf8(target.as_ref()).unwrap().to_owned())
+            }
+            _ => None,
+        }
+    }
+
+    /// Resumes execution after having set the value.
+    pub fn resume(self) -> HostVm {
+        HostVm::ReadyToRun(ReadyToRun {
+            inner: self.inner,
+            resume_value: None,
+        })
+    }
+}
+
+impl fmt::Display for LogEmit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.log_entry {
+            LogEmitInner::Num(num) => write!(f, "{num}"),
+            LogEmitInner::Utf8 { str_ptr, str_size } => {
+                let str = self.inner.vm.read_memory(str_ptr, str_size).unwrap();
+                let str = str::from_utf8(str.as_ref()).unwrap();
+                write!(f, "{str}")
+            }
+            LogEmitInner::Hex {
+                data_ptr,
+                data_size,
+            } => {
+                let data = self.inner.vm.read_memory(data_ptr, data_size).unwrap();
+                write!(f, "{}", hex::encode(data.as_ref()))
+            }
+            LogEmitInner::Log {
+                msg_str_ptr,
+                msg_str_size,
+                ..
+            } => {
+                let str = self
+                    .inner
+                    .vm
+                    .read_memory(msg_str_ptr, msg_str_size)
+                    .unwrap();
+                let str = str::from_utf8(str.as_ref()).unwrap();
+                // TODO: don't just ignore the target and log level? better log representation? more control? also, it's unclear whether it should be an individual line
+                writeln!(f, "{str}")
+            }
+        }
+    }
+}
+
+impl fmt::Debug for LogEmit {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("LogEmit")
+            .field("message", &self.to_string())
+            .finish()
+    }
+}
+
+/// Queries the maximum log level.
+pub struct GetMaxLogLevel {
+    inner: Box<Inner>,
+}
+
+impl GetMaxLogLevel {
+    /// Resumes execution after indicating the maximum log level.
+    ///
+    /// 0 means off, 1 means error, 2 means warn, 3 means info, 4 means debug, 5 means trace.
+    pub fn resume(self, max_level: u32) -> HostVm {
+        HostVm::ReadyToRun(ReadyToRun {
+            inner: self.inner,
+            resume_value: Some(vm::WasmValue::I32(i32::from_ne_bytes(
+                max_level.to_ne_bytes(),
+            ))),
+        })
+    }
+}
+
+impl fmt::Debug for GetMaxLogLevel {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("GetMaxLogLevel").finish()
+    }
+}
+
+/// Declares the start of a transaction.
+pub struct StartStorageTransaction {
+    inner: Box<Inner>,
+}
+
+impl StartStorageTransaction {
+    /// Resumes execution after having acknowledged the event.
+    pub fn resume(self) -> HostVm {
+        HostVm::ReadyToRun(ReadyToRun {
+            inner: self.inner,
+            resume_value: None,
+        })
+    }
+}
+
+impl fmt::Debug for StartStorageTransaction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("StartStorageTransaction").finish()
+    }
+}
+
+/// Declares the end of a transaction.
+pub struct EndStorageTransaction {
+    inner: Box<Inner>,
+}
+
+impl EndStorageTransaction {
     /// Resumes execution after having acknowledged the event.
     pub fn resume(self) -> HostVm {
         HostVm::ReadyToRun(ReadyToRun {
@@ -3118,6 +4715,10 @@ struct Inner {
     /// Memory allocator in order to answer the calls to `malloc` and `free`.
     allocator: allocator::FreeingBumpHeapAllocator,
 
+    /// Signatures verification batch started with `ext_crypto_start_batch_verify_version_1`.
+    /// `None` if no batch verification is in progress.
+    signatures_batch: Option<SignaturesBatch>,
+
     /// Fields that are kept as is even during the execution.
     common: Box<VmCommon>,
 }
@@ -3283,6 +4884,123 @@ impl Inner {
     }
 }
 
+/// Signatures queued with the `ext_crypto_*_batch_verify_version_1` host functions.
+struct SignaturesBatch {
+    /// List of public keys, signatures, and messages of the ed25519 signatures to verify.
+    ed25519: Vec<([u8; 32], [u8; 64], Vec<u8>)>,
+    /// List of public keys, signatures, and messages of the sr25519 signatures to verify.
+    sr25519: Vec<([u8; 32], [u8; 64], Vec<u8>)>,
+    /// `false` if any of the signatures of the batch that have been verified individually, such
+    /// as ECDSA signatures, was invalid.
+    others_valid: bool,
+}
+
+impl SignaturesBatch {
+    /// Verifies all the signatures of the batch. Returns `true` if all of them are valid.
+    fn verify(self) -> bool {
+        if !self.others_valid {
+            return false;
+        }
+
+        if !self.ed25519.is_empty() {
+            #[cfg(feature = "std")]
+            {
+                // Batch verification requires some randomness. Rather than using the system's
+                // randomness, the seed is derived from the content of the batch, which makes
+                // the outcome deterministic while remaining impossible to predict without
+                // knowing all the signatures.
+                let seed = {
+                    let mut hasher = blake2_rfc::blake2b::Blake2b::new(32);
+                    for (public_key, signature, message) in &self.ed25519 {
+                        hasher.update(public_key);
+                        hasher.update(signature);
+                        hasher.update(&u64::try_from(message.len()).unwrap().to_le_bytes());
+                        hasher.update(message);
+                    }
+                    <[u8; 32]>::try_from(hasher.finalize().as_bytes()).unwrap()
+                };
+
+                let mut verifier = ed25519_zebra::batch::Verifier::new();
+                for (public_key, signature, message) in &self.ed25519 {
+                    verifier.queue((
+                        ed25519_zebra::VerificationKeyBytes::from(*public_key),
+                        ed25519_zebra::Signature::from(*signature),
+                        message,
+                    ));
+                }
+
+                let rng =
+                    <rand_chacha::ChaCha20Rng as rand_chacha::rand_core::SeedableRng>::from_seed(
+                        seed,
+                    );
+                if verifier.verify(rng).is_err() {
+                    return false;
+                }
+            }
+
+            // `ed25519_zebra` doesn't support batch verification in no-std environments.
+            #[cfg(not(feature = "std"))]
+            for (public_key, signature, message) in &self.ed25519 {
+                let Ok(public_key) = ed25519_zebra::VerificationKey::try_from(*public_key)
+                    else { return false };
+                if public_key
+                    .verify(&ed25519_zebra::Signature::from(*signature), message)
+                    .is_err()
+                {
+                    return false;
+                }
+            }
+        }
+
+        if !self.sr25519.is_empty() {
+            #[cfg(feature = "std")]
+            {
+                let mut public_keys = Vec::with_capacity(self.sr25519.len());
+                let mut signatures = Vec::with_capacity(self.sr25519.len());
+                for (public_key, signature, _) in &self.sr25519 {
+                    let (Ok(public_key), Ok(signature)) = (
+                        schnorrkel::PublicKey::from_bytes(public_key),
+                        schnorrkel::Signature::from_bytes(signature),
+                    ) else { return false };
+                    public_keys.push(public_key);
+                    signatures.push(signature);
+                }
+
+                let transcripts = self.sr25519.iter().map(|(_, _, message)| {
+                    schnorrkel::signing_context(b"substrate").bytes(message)
+                });
+                if schnorrkel::verify_batch_deterministic(
+                    transcripts,
+                    &signatures,
+                    &public_keys,
+                    false,
+                )
+                .is_err()
+                {
+                    return false;
+                }
+            }
+
+            // `schnorrkel` doesn't support batch verification in no-std environments.
+            #[cfg(not(feature = "std"))]
+            for (public_key, signature, message) in &self.sr25519 {
+                let (Ok(public_key), Ok(signature)) = (
+                    schnorrkel::PublicKey::from_bytes(public_key),
+                    schnorrkel::Signature::from_bytes(signature),
+                ) else { return false };
+                if public_key
+                    .verify_simple(b"substrate", message, &signature)
+                    .is_err()
+                {
+                    return false;
+                }
+            }
+        }
+
+        true
+    }
+}
+
 /// Error that can happen when initializing a VM.
 #[derive(Debug, derive_more::From, derive_more::Display, Clone)]
 pub enum NewErr {
@@ -3334,6 +5052,10 @@ pub enum Error {
     /// Error in the Wasm code execution.
     #[display(fmt = "{_0}")]
     Trap(vm::Trap),
+    /// The execution has exceeded the fuel budget passed to
+    /// [`HostVmPrototype::run_vectored_with_fuel`].
+    #[display(fmt = "Execution has run out of fuel")]
+    OutOfFuel,
     /// A non-`i64` value has been returned by the Wasm entry point.
     #[display(fmt = "A non-I64 value has been returned: {actual:?}")]
     BadReturnValue {
@@ -3431,6 +5153,20 @@ pub enum Error {
     #[display(fmt = "Called `ext_default_child_storage_root_version_1` or
         `ext_default_child_storage_root_version_2` on a child trie that doesn't exist.")]
     ChildStorageRootTrieDoesntExist,
+    /// Called `ext_crypto_start_batch_verify_version_1` while a batch verification was already
+    /// in progress.
+    #[display(fmt = "Attempted to start a batch verification while one is already in progress")]
+    AlreadyBatchVerify,
+    /// Called `ext_crypto_finish_batch_verify_version_1` while no batch verification was in
+    /// progress.
+    #[display(fmt = "Attempted to finish a batch verification while none is in progress")]
+    NoBatchVerify,
+    /// The client has failed to generate a key requested by the runtime.
+    #[display(fmt = "Failed to generate a key during {function}")]
+    KeyGenerationFailed {
+        /// Name of the function being called.
+        function: &'static str,
+    },
     /// The host function isn't implemented.
     // TODO: this variant should eventually disappear as all functions are implemented
     #[display(fmt = "Host function not implemented: {function}")]
@@ -3694,7 +5430,7 @@ impl HostFunction {
                 crate::signature!((vm::ValueType::I32, vm::ValueType::I32, vm::ValueType::I64) => vm::ValueType::I64)
             }
             HostFunction::ext_crypto_ecdsa_public_keys_version_1 => {
-                crate::signature!((vm::ValueType::I64) => vm::ValueType::I64)
+                crate::signature!((vm::ValueType::I32) => vm::ValueType::I64)
             }
             HostFunction::ext_crypto_ecdsa_verify_version_1 => {
                 crate::signature!((vm::ValueType::I32, vm::ValueType::I64, vm::ValueType::I32) => vm::ValueType::I32)
@@ -3703,7 +5439,7 @@ impl HostFunction {
                 crate::signature!((vm::ValueType::I32, vm::ValueType::I64, vm::ValueType::I32) => vm::ValueType::I32)
             }
             HostFunction::ext_crypto_ecdsa_sign_prehashed_version_1 => {
-                crate::signature!((vm::ValueType::I32, vm::ValueType::I32, vm::ValueType::I64) => vm::ValueType::I64)
+                crate::signature!((vm::ValueType::I32, vm::ValueType::I32, vm::ValueType::I32) => vm::ValueType::I64)
             }
             HostFunction::ext_crypto_ecdsa_verify_prehashed_version_1 => {
                 crate::signature!((vm::ValueType::I32, vm::ValueType::I32, vm::ValueType::I32) => vm::ValueType::I32)
@@ -3800,7 +5536,7 @@ impl HostFunction {
                 crate::signature!((vm::ValueType::I64, vm::ValueType::I64) => vm::ValueType::I64)
             }
             HostFunction::ext_offchain_http_response_headers_version_1 => {
-                crate::signature!((vm::ValueType::I64) => vm::ValueType::I64)
+                crate::signature!((vm::ValueType::I32) => vm::ValueType::I64)
             }
             HostFunction::ext_offchain_http_response_read_body_version_1 => {
                 crate::signature!((vm::ValueType::I32, vm::ValueType::I64, vm::ValueType::I64) => vm::ValueType::I64)
@@ -19,9 +19,14 @@
 
 use super::{vm::ExecHint, Config, HeapPages, HostVm, HostVmPrototype};
 
+mod call_runtime_version;
 mod host_algorithms;
 mod initialization;
+mod keystore;
+mod offchain;
 mod run;
+mod signatures_batch;
+mod trace;
 
 /*
 
@@ -93,6 +98,7 @@ fn basic_core_version() {
             module: &include_bytes!("./westend-runtime-v9300.wasm")[..],
             heap_pages: HeapPages::new(2048),
             exec_hint,
+            compilation_cache: None,
             allow_unresolved_imports: true,
         })
         .unwrap();
@@ -133,6 +139,7 @@ fn min_requirements() {
         HostVmPrototype::new(Config {
             allow_unresolved_imports: false,
             exec_hint,
+            compilation_cache: None,
             heap_pages: HeapPages::new(1024),
             module: &module_bytes,
         })
@@ -0,0 +1,175 @@
+// Smoldot
+// Copyright (C) 2023  Pierre Krieger
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <http://www.gnu.org/licenses/>.
+
+use super::super::{vm::ExecHint, Config, HeapPages, HostVm, HostVmPrototype};
+use super::with_core_version_custom_sections;
+use crate::executor::{self, read_only_runtime_host};
+
+use alloc::vec::Vec;
+use core::iter;
+
+/// Module whose `test` function asks the host for the runtime version of the Wasm code passed
+/// as parameter, and returns the SCALE-encoded `Option` provided by the host.
+fn caller_module_bytes() -> Vec<u8> {
+    with_core_version_custom_sections(
+        wat::parse_str(
+            r#"
+    (module
+        (import "env" "memory" (memory 0))
+        (import "env" "ext_misc_runtime_version_version_1"
+            (func $runtime_version (param i64) (result i64)))
+        (global (export "__heap_base") i32 (i32.const 0))
+        (func (export "test") (param i32) (param i32) (result i64)
+            (call $runtime_version
+                (i64.or
+                    (i64.extend_i32_u (local.get 0))
+                    (i64.shl (i64.extend_i32_u (local.get 1)) (i64.const 32)))))
+    )
+    "#,
+        )
+        .unwrap(),
+    )
+}
+
+/// Module whose runtime version is queried. Its memory is limited to 64 pages, meaning that it
+/// fails to compile with more than 64 heap pages.
+fn queried_module_bytes() -> Vec<u8> {
+    with_core_version_custom_sections(
+        wat::parse_str(
+            r#"
+    (module
+        (import "env" "memory" (memory 0 64))
+        (global (export "__heap_base") i32 (i32.const 0))
+    )
+    "#,
+        )
+        .unwrap(),
+    )
+}
+
+/// Calls the `test` function of [`caller_module_bytes`] with [`queried_module_bytes`] as
+/// parameter, and answers the runtime version request with the given storage value of
+/// `:heappages`.
+fn call_runtime_version(exec_hint: ExecHint, storage_heap_pages: Option<&[u8]>) -> Vec<u8> {
+    let proto = HostVmPrototype::new(Config {
+        allow_unresolved_imports: false,
+        exec_hint,
+        compilation_cache: None,
+        heap_pages: HeapPages::new(1024),
+        module: &caller_module_bytes(),
+    })
+    .unwrap();
+
+    let mut vm = HostVm::from(proto.run("test", &queried_module_bytes()).unwrap());
+    loop {
+        match vm {
+            HostVm::ReadyToRun(r) => vm = r.run(),
+            HostVm::CallRuntimeVersion(req) => {
+                vm = executor::resume_call_runtime_version(req, storage_heap_pages)
+            }
+            HostVm::Finished(finished) => break finished.value().as_ref().to_vec(),
+            HostVm::Error { error, .. } => panic!("{error:?}"),
+            _ => unreachable!(),
+        }
+    }
+}
+
+#[test]
+fn heap_pages_from_storage() {
+    for exec_hint in ExecHint::available_engines() {
+        let output = call_runtime_version(exec_hint, Some(&64u64.to_le_bytes()));
+        let (1, runtime_version) = output.split_first().unwrap() else {
+            panic!()
+        };
+        let (runtime_version, _) =
+            crate::util::nom_scale_compact_usize::<nom::error::Error<&[u8]>>(runtime_version)
+                .unwrap();
+        let runtime_version = executor::CoreVersion::from_slice(runtime_version.to_vec()).unwrap();
+        assert_eq!(runtime_version.decode().spec_name, "foo");
+    }
+}
+
+#[test]
+fn heap_pages_too_large() {
+    for exec_hint in ExecHint::available_engines() {
+        assert_eq!(
+            call_runtime_version(exec_hint, Some(&65u64.to_le_bytes())),
+            [0]
+        );
+    }
+}
+
+#[test]
+fn default_heap_pages() {
+    // The default number of heap pages is larger than what the queried module accepts.
+    for exec_hint in ExecHint::available_engines() {
+        assert_eq!(call_runtime_version(exec_hint, None), [0]);
+    }
+}
+
+#[test]
+fn invalid_heap_pages() {
+    for exec_hint in ExecHint::available_engines() {
+        assert_eq!(call_runtime_version(exec_hint, Some(&[0; 4])), [0]);
+        assert_eq!(
+            call_runtime_version(exec_hint, Some(&u64::MAX.to_le_bytes())),
+            [0]
+        );
+    }
+}
+
+#[test]
+fn read_only_unavailable_heap_pages() {
+    // When the value of `:heappages` can't be obtained, the default number of heap pages is
+    // used, which is larger than what the queried module accepts.
+    for exec_hint in ExecHint::available_engines() {
+        let proto = HostVmPrototype::new(Config {
+            allow_unresolved_imports: false,
+            exec_hint,
+            compilation_cache: None,
+            heap_pages: HeapPages::new(1024),
+            module: &caller_module_bytes(),
+        })
+        .unwrap();
+
+        let queried_module_bytes = queried_module_bytes();
+        let mut call = read_only_runtime_host::run(read_only_runtime_host::Config {
+            virtual_machine: proto,
+            function_to_call: "test",
+            parameter: iter::once(&queried_module_bytes),
+            max_log_level: 0,
+            trace: false,
+            fuel_budget: None,
+        })
+        .unwrap();
+
+        let output = loop {
+            match call {
+                read_only_runtime_host::RuntimeHostVm::Finished(Ok(success)) => {
+                    break success.virtual_machine.value().as_ref().to_vec()
+                }
+                read_only_runtime_host::RuntimeHostVm::StorageGet(get) => {
+                    assert_eq!(get.key().as_ref(), b":heappages");
+                    call = get.inject_unavailable().unwrap_or_else(|_| panic!());
+                }
+                _ => panic!(),
+            }
+        };
+
+        assert_eq!(output, [0]);
+    }
+}
@@ -216,6 +216,7 @@ macro_rules! gen_test {
                 let proto = HostVmPrototype::new(Config {
                     allow_unresolved_imports: false,
                     exec_hint,
+                    compilation_cache: None,
                     heap_pages: HeapPages::new(1024),
                     module: &module_bytes,
                 })
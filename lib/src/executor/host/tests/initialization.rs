@@ -28,6 +28,7 @@ fn invalid_wasm() {
         match HostVmPrototype::new(Config {
             allow_unresolved_imports: false,
             exec_hint,
+            compilation_cache: None,
             heap_pages: HeapPages::new(1024),
             module: &module_bytes,
         }) {
@@ -46,6 +47,7 @@ fn invalid_zstd() {
         match HostVmPrototype::new(Config {
             allow_unresolved_imports: false,
             exec_hint,
+            compilation_cache: None,
             heap_pages: HeapPages::new(1024),
             module: &module_bytes,
         }) {
@@ -72,6 +74,7 @@ fn valid_zstd() {
         HostVmPrototype::new(Config {
             allow_unresolved_imports: false,
             exec_hint,
+            compilation_cache: None,
             heap_pages: HeapPages::new(1024),
             module: &module_bytes,
         })
@@ -96,6 +99,7 @@ fn no_heap_base() {
         match HostVmPrototype::new(Config {
             allow_unresolved_imports: false,
             exec_hint,
+            compilation_cache: None,
             heap_pages: HeapPages::new(1024),
             module: &module_bytes,
         }) {
@@ -123,6 +127,7 @@ fn memory_max_size_too_low() {
         match HostVmPrototype::new(Config {
             allow_unresolved_imports: false,
             exec_hint,
+            compilation_cache: None,
             heap_pages: HeapPages::new(1024),
             module: &module_bytes,
         }) {
@@ -151,6 +156,7 @@ fn unresolved_host_functions_setting() {
         match HostVmPrototype::new(Config {
             allow_unresolved_imports: true,
             exec_hint,
+            compilation_cache: None,
             heap_pages: HeapPages::new(1024),
             module: &module_bytes,
         }) {
@@ -161,6 +167,7 @@ fn unresolved_host_functions_setting() {
         match HostVmPrototype::new(Config {
             allow_unresolved_imports: false,
             exec_hint,
+            compilation_cache: None,
             heap_pages: HeapPages::new(1024),
             module: &module_bytes,
         }) {
@@ -191,6 +198,7 @@ fn host_function_bad_signature() {
         match HostVmPrototype::new(Config {
             allow_unresolved_imports: true,
             exec_hint,
+            compilation_cache: None,
             heap_pages: HeapPages::new(1024),
             module: &module_bytes,
         }) {
@@ -201,6 +209,7 @@ fn host_function_bad_signature() {
         match HostVmPrototype::new(Config {
             allow_unresolved_imports: false,
             exec_hint,
+            compilation_cache: None,
             heap_pages: HeapPages::new(1024),
             module: &module_bytes,
         }) {
@@ -220,6 +229,7 @@ fn rococo_genesis_works() {
         assert!(HostVmPrototype::new(Config {
             allow_unresolved_imports: true,
             exec_hint,
+            compilation_cache: None,
             heap_pages: HeapPages::new(1024),
             module: &module_bytes,
         })
@@ -0,0 +1,336 @@
+// Smoldot
+// Copyright (C) 2023  Pierre Krieger
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <http://www.gnu.org/licenses/>.
+
+use super::super::{vm::ExecHint, Config, Error, HeapPages, HostVm, HostVmPrototype, KeyAlgorithm};
+use super::with_core_version_custom_sections;
+use crate::identity::seed_phrase;
+
+use core::fmt::Write as _;
+
+/// Keystore used to answer the requests of the runtime. Contains ECDSA keys of the `beef` key
+/// type.
+#[derive(Default, Clone)]
+struct TestKeystore {
+    keys: Vec<libsecp256k1::SecretKey>,
+    /// If `true`, key generation requests are answered with a failure.
+    generation_fails: bool,
+}
+
+impl TestKeystore {
+    fn with_keys(seeds: &[u8]) -> Self {
+        TestKeystore {
+            keys: seeds
+                .iter()
+                .map(|seed| libsecp256k1::SecretKey::parse(&[*seed; 32]).unwrap())
+                .collect(),
+            generation_fails: false,
+        }
+    }
+
+    fn public_key(&self, n: usize) -> [u8; 33] {
+        libsecp256k1::PublicKey::from_secret_key(&self.keys[n]).serialize_compressed()
+    }
+}
+
+/// Generates a Wasm module with the given body for its `test` function, and the given data
+/// segments, then runs it while answering the keystore requests using the given keystore.
+///
+/// The `test` function must return a pointer-size to its output.
+fn run_module(
+    body: &str,
+    data: &[(u32, &[u8])],
+    keystore: &mut TestKeystore,
+) -> Result<Vec<u8>, Error> {
+    let mut data_segments = String::new();
+    for (offset, bytes) in data {
+        write!(data_segments, "(data (i32.const {offset}) \"").unwrap();
+        for byte in *bytes {
+            write!(data_segments, "\\{byte:02x}").unwrap();
+        }
+        writeln!(data_segments, "\")").unwrap();
+    }
+
+    let module_bytes = with_core_version_custom_sections(
+        wat::parse_str(format!(
+            r#"
+    (module
+        (import "env" "ext_crypto_ecdsa_public_keys_version_1" (func $public_keys (param i32) (result i64)))
+        (import "env" "ext_crypto_ecdsa_generate_version_1" (func $generate (param i32 i64) (result i32)))
+        (import "env" "ext_crypto_ecdsa_sign_version_1" (func $sign (param i32 i32 i64) (result i64)))
+        (import "env" "ext_crypto_ecdsa_sign_prehashed_version_1" (func $sign_prehashed (param i32 i32 i32) (result i64)))
+        (import "env" "ext_crypto_ecdsa_verify_version_2" (func $verify (param i32 i64 i32) (result i32)))
+        (memory (export "memory") 1)
+        (global (export "__heap_base") i32 (i32.const 32768))
+        (func (export "test") (param i32 i32) (result i64)
+            {body})
+        {data_segments}
+    )
+    "#
+        ))
+        .unwrap(),
+    );
+
+    let mut outcome = None;
+    for exec_hint in ExecHint::available_engines() {
+        let mut keystore = keystore.clone();
+
+        let proto = HostVmPrototype::new(Config {
+            allow_unresolved_imports: false,
+            exec_hint,
+            compilation_cache: None,
+            heap_pages: HeapPages::new(1024),
+            module: &module_bytes,
+        })
+        .unwrap();
+
+        let mut vm = HostVm::from(proto.run_no_param("test").unwrap());
+        let result = loop {
+            match vm {
+                HostVm::ReadyToRun(r) => vm = r.run(),
+                HostVm::SignatureVerification(sig) => vm = sig.verify_and_resume(),
+                HostVm::KeystorePublicKeys(req) => {
+                    assert_eq!(req.algorithm(), KeyAlgorithm::Ecdsa);
+                    let public_keys = if req.key_type_id() == b"beef" {
+                        (0..keystore.keys.len())
+                            .map(|n| keystore.public_key(n))
+                            .collect::<Vec<_>>()
+                    } else {
+                        Vec::new()
+                    };
+                    vm = req.resume(public_keys.into_iter());
+                }
+                HostVm::KeystoreGenerate(req) => {
+                    assert_eq!(req.algorithm(), KeyAlgorithm::Ecdsa);
+                    assert_eq!(req.key_type_id(), b"beef");
+                    if keystore.generation_fails {
+                        vm = req.resume(None);
+                        continue;
+                    }
+
+                    let private_key = match req.seed() {
+                        Some(seed) => seed_phrase::decode_ecdsa_private_key(seed).unwrap(),
+                        None => [u8::try_from(keystore.keys.len()).unwrap() + 1; 32],
+                    };
+                    keystore
+                        .keys
+                        .push(libsecp256k1::SecretKey::parse(&private_key).unwrap());
+                    let public_key = keystore.public_key(keystore.keys.len() - 1);
+                    vm = req.resume(Some(&public_key));
+                }
+                HostVm::SignRequest(req) => {
+                    assert_eq!(req.algorithm(), KeyAlgorithm::Ecdsa);
+                    let key = keystore.keys.iter().find(|key| {
+                        req.key_type_id() == b"beef"
+                            && libsecp256k1::PublicKey::from_secret_key(key).serialize_compressed()
+                                [..]
+                                == *req.public_key().as_ref()
+                    });
+
+                    let signature = key.map(|key| {
+                        let (signature, recovery_id) = libsecp256k1::sign(
+                            &libsecp256k1::Message::parse_slice(req.message().as_ref()).unwrap(),
+                            key,
+                        );
+                        let mut out = signature.serialize().to_vec();
+                        out.push(recovery_id.serialize());
+                        out
+                    });
+
+                    vm = req.resume(signature.as_deref());
+                }
+                HostVm::Finished(v) => break Ok(v.value().as_ref().to_vec()),
+                HostVm::Error { error, .. } => break Err(error),
+                _ => unreachable!(),
+            }
+        };
+
+        // All the execution engines are expected to yield the same outcome.
+        match (&outcome, &result) {
+            (None, _) => outcome = Some((result, keystore)),
+            (Some((Ok(a), _)), Ok(b)) => assert_eq!(a, b),
+            (Some((Err(_), _)), Err(_)) => {}
+            _ => panic!(),
+        }
+    }
+
+    let (result, new_keystore) = outcome.unwrap();
+    *keystore = new_keystore;
+    result
+}
+
+/// Returns the SCALE encoding of `Some(signature)`, where `signature` is the signature of the
+/// given 32 bytes hash with the given key.
+fn expected_signature(key: &libsecp256k1::SecretKey, hash: &[u8; 32]) -> Vec<u8> {
+    let (signature, recovery_id) = libsecp256k1::sign(&libsecp256k1::Message::parse(hash), key);
+    let mut out = vec![1];
+    out.extend_from_slice(&signature.serialize());
+    out.push(recovery_id.serialize());
+    out
+}
+
+#[test]
+fn public_keys() {
+    let mut keystore = TestKeystore::with_keys(&[1, 2]);
+    let output = run_module(
+        "(call $public_keys (i32.const 1024))",
+        &[(1024, b"beef")],
+        &mut keystore,
+    )
+    .unwrap();
+
+    let mut expected = vec![8];
+    expected.extend_from_slice(&keystore.public_key(0));
+    expected.extend_from_slice(&keystore.public_key(1));
+    assert_eq!(output, expected);
+}
+
+#[test]
+fn public_keys_other_key_type() {
+    let mut keystore = TestKeystore::with_keys(&[1, 2]);
+    let output = run_module(
+        "(call $public_keys (i32.const 1024))",
+        &[(1024, b"babe")],
+        &mut keystore,
+    )
+    .unwrap();
+    assert_eq!(output, [0]);
+}
+
+#[test]
+fn generate_random() {
+    let mut keystore = TestKeystore::default();
+    let output = run_module(
+        "(i64.or (i64.const 0x2100000000) (i64.extend_i32_u (call $generate (i32.const 1024) (i64.const 0x100000404))))",
+        &[(1024, b"beef"), (1028, &[0])],
+        &mut keystore,
+    )
+    .unwrap();
+
+    assert_eq!(keystore.keys.len(), 1);
+    assert_eq!(output, keystore.public_key(0));
+}
+
+#[test]
+fn generate_from_seed() {
+    let mut keystore = TestKeystore::default();
+    let mut seed = vec![1, 7 << 2];
+    seed.extend_from_slice(b"//Alice");
+    let output = run_module(
+        "(i64.or (i64.const 0x2100000000) (i64.extend_i32_u (call $generate (i32.const 1024) (i64.const 0x900000404))))",
+        &[(1024, b"beef"), (1028, &seed)],
+        &mut keystore,
+    )
+    .unwrap();
+
+    assert_eq!(
+        hex::encode(output),
+        "020a1091341fe5664bfa1782d5e04779689068c916b04cb365ec3153755684d9a1"
+    );
+}
+
+#[test]
+fn generate_invalid_utf8_seed() {
+    let mut keystore = TestKeystore::default();
+    let output = run_module(
+        "(i64.or (i64.const 0x2100000000) (i64.extend_i32_u (call $generate (i32.const 1024) (i64.const 0x300000404))))",
+        &[(1024, b"beef"), (1028, &[1, 1 << 2, 0xff])],
+        &mut keystore,
+    );
+    assert!(matches!(output, Err(Error::Utf8Error { param_num: 1, .. })));
+}
+
+#[test]
+fn generate_failure() {
+    let mut keystore = TestKeystore {
+        generation_fails: true,
+        ..Default::default()
+    };
+    let output = run_module(
+        "(i64.or (i64.const 0x2100000000) (i64.extend_i32_u (call $generate (i32.const 1024) (i64.const 0x100000404))))",
+        &[(1024, b"beef"), (1028, &[0])],
+        &mut keystore,
+    );
+    assert!(matches!(output, Err(Error::KeyGenerationFailed { .. })));
+}
+
+#[test]
+fn sign() {
+    let mut keystore = TestKeystore::with_keys(&[1, 2]);
+    let public_key = keystore.public_key(1);
+    let output = run_module(
+        "(call $sign (i32.const 1024) (i32.const 1028) (i64.const 0x500000425))",
+        &[(1024, b"beef"), (1028, &public_key), (1061, b"hello")],
+        &mut keystore,
+    )
+    .unwrap();
+
+    let hash = blake2_rfc::blake2b::blake2b(32, &[], b"hello");
+    assert_eq!(
+        output,
+        expected_signature(
+            &keystore.keys[1],
+            <&[u8; 32]>::try_from(hash.as_bytes()).unwrap()
+        )
+    );
+}
+
+#[test]
+fn sign_unknown_key() {
+    let mut keystore = TestKeystore::with_keys(&[1]);
+    let public_key = keystore.public_key(0);
+    let output = run_module(
+        "(call $sign (i32.const 1024) (i32.const 1028) (i64.const 0x500000425))",
+        &[(1024, b"babe"), (1028, &public_key), (1061, b"hello")],
+        &mut keystore,
+    )
+    .unwrap();
+    assert_eq!(output, [0]);
+}
+
+#[test]
+fn sign_prehashed() {
+    let mut keystore = TestKeystore::with_keys(&[1]);
+    let public_key = keystore.public_key(0);
+    let output = run_module(
+        "(call $sign_prehashed (i32.const 1024) (i32.const 1028) (i32.const 1061))",
+        &[(1024, b"beef"), (1028, &public_key), (1061, &[0xaa; 32])],
+        &mut keystore,
+    )
+    .unwrap();
+    assert_eq!(output, expected_signature(&keystore.keys[0], &[0xaa; 32]));
+}
+
+#[test]
+fn verify_version_2() {
+    let keystore = TestKeystore::with_keys(&[1]);
+    let public_key = keystore.public_key(0);
+    let hash = blake2_rfc::blake2b::blake2b(32, &[], b"hello");
+    let signature = expected_signature(
+        &keystore.keys[0],
+        <&[u8; 32]>::try_from(hash.as_bytes()).unwrap(),
+    );
+
+    for (message, expected) in [(b"hello", 1), (b"world", 0)] {
+        let output = run_module(
+            "(i32.store8 (i32.const 0) (call $verify (i32.const 1024) (i64.const 0x500000441) (i32.const 1094))) (i64.const 0x100000000)",
+            &[(1024, &signature[1..]), (1089, message), (1094, &public_key)],
+            &mut keystore.clone(),
+        )
+        .unwrap();
+        assert_eq!(output, [expected]);
+    }
+}
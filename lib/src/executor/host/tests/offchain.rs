@@ -0,0 +1,671 @@
+// Smoldot
+// Copyright (C) 2023  Pierre Krieger
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <http://www.gnu.org/licenses/>.
+
+use super::super::{
+    vm::ExecHint, Config, Error, HeapPages, HostVm, HostVmPrototype, HttpError, HttpRequestStatus,
+    OffchainStorageKind,
+};
+use super::with_core_version_custom_sections;
+
+use core::fmt::Write as _;
+
+/// State of the off-chain worker environment used to answer the requests of the runtime.
+#[derive(Default, Clone)]
+struct TestOffchain {
+    storage: hashbrown::HashMap<(OffchainStorageKind, Vec<u8>), Vec<u8>, fnv::FnvBuildHasher>,
+    submitted_transactions: Vec<Vec<u8>>,
+    sleeps: Vec<u64>,
+    http_requests: Vec<TestHttpRequest>,
+}
+
+#[derive(Default, Clone)]
+struct TestHttpRequest {
+    method: String,
+    uri: String,
+    headers: Vec<(String, String)>,
+    body: Vec<u8>,
+    /// Body of the response. Chunks are removed from the front as they are read.
+    response_body: Vec<u8>,
+}
+
+/// Returns the value of a parameter that designates a buffer of the given size at the given
+/// location.
+fn ptr_size(ptr: u32, size: u32) -> u64 {
+    (u64::from(size) << 32) | u64::from(ptr)
+}
+
+/// Generates a Wasm module with the given body for its `test` function, and the given data
+/// segments, then runs it while answering the off-chain requests using the given state.
+///
+/// The `test` function must return a pointer-size to its output.
+fn run_module(
+    body: &str,
+    data: &[(u32, &[u8])],
+    offchain: &mut TestOffchain,
+) -> Result<Vec<u8>, Error> {
+    let mut data_segments = String::new();
+    for (offset, bytes) in data {
+        write!(data_segments, "(data (i32.const {offset}) \"").unwrap();
+        for byte in *bytes {
+            write!(data_segments, "\\{byte:02x}").unwrap();
+        }
+        writeln!(data_segments, "\")").unwrap();
+    }
+
+    let module_bytes = with_core_version_custom_sections(
+        wat::parse_str(format!(
+            r#"
+    (module
+        (import "env" "ext_offchain_is_validator_version_1" (func $is_validator (result i32)))
+        (import "env" "ext_offchain_submit_transaction_version_1" (func $submit_transaction (param i64) (result i64)))
+        (import "env" "ext_offchain_network_state_version_1" (func $network_state (result i64)))
+        (import "env" "ext_offchain_timestamp_version_1" (func $timestamp (result i64)))
+        (import "env" "ext_offchain_sleep_until_version_1" (func $sleep_until (param i64)))
+        (import "env" "ext_offchain_random_seed_version_1" (func $random_seed (result i32)))
+        (import "env" "ext_offchain_local_storage_set_version_1" (func $storage_set (param i32 i64 i64)))
+        (import "env" "ext_offchain_local_storage_compare_and_set_version_1" (func $storage_compare_and_set (param i32 i64 i64 i64) (result i32)))
+        (import "env" "ext_offchain_local_storage_get_version_1" (func $storage_get (param i32 i64) (result i64)))
+        (import "env" "ext_offchain_local_storage_clear_version_1" (func $storage_clear (param i32 i64)))
+        (import "env" "ext_offchain_http_request_start_version_1" (func $http_start (param i64 i64 i64) (result i64)))
+        (import "env" "ext_offchain_http_request_add_header_version_1" (func $http_add_header (param i32 i64 i64) (result i64)))
+        (import "env" "ext_offchain_http_request_write_body_version_1" (func $http_write_body (param i32 i64 i64) (result i64)))
+        (import "env" "ext_offchain_http_response_wait_version_1" (func $http_wait (param i64 i64) (result i64)))
+        (import "env" "ext_offchain_http_response_headers_version_1" (func $http_headers (param i32) (result i64)))
+        (import "env" "ext_offchain_http_response_read_body_version_1" (func $http_read_body (param i32 i64 i64) (result i64)))
+        (memory (export "memory") 1)
+        (global (export "__heap_base") i32 (i32.const 32768))
+        (func (export "test") (param i32 i32) (result i64)
+            {body})
+        {data_segments}
+    )
+    "#
+        ))
+        .unwrap(),
+    );
+
+    let mut outcome = None;
+    for exec_hint in ExecHint::available_engines() {
+        let mut offchain = offchain.clone();
+
+        let proto = HostVmPrototype::new(Config {
+            allow_unresolved_imports: false,
+            exec_hint,
+            compilation_cache: None,
+            heap_pages: HeapPages::new(1024),
+            module: &module_bytes,
+        })
+        .unwrap();
+
+        let mut vm = HostVm::from(proto.run_no_param("test").unwrap());
+        let result = loop {
+            match vm {
+                HostVm::ReadyToRun(r) => vm = r.run(),
+                HostVm::OffchainStorageGet(req) => {
+                    let value = offchain
+                        .storage
+                        .get(&(req.kind(), req.key().as_ref().to_vec()))
+                        .cloned();
+                    vm = req.resume(value.as_deref());
+                }
+                HostVm::OffchainStorageSet(req) => {
+                    let key = (req.kind(), req.key().as_ref().to_vec());
+                    let current_value = offchain.storage.get(&key).map(|v| &v[..]);
+                    let replace = match req.old_value() {
+                        Some(old_value) => old_value == current_value,
+                        None => true,
+                    };
+                    if replace {
+                        match req.value() {
+                            Some(value) => {
+                                offchain.storage.insert(key, value.as_ref().to_vec());
+                            }
+                            None => {
+                                offchain.storage.remove(&key);
+                            }
+                        }
+                    }
+                    vm = req.resume(replace);
+                }
+                HostVm::OffchainTimestamp(req) => vm = req.resume(1_700_000_000_000),
+                HostVm::OffchainSleepUntil(req) => {
+                    offchain.sleeps.push(req.deadline());
+                    vm = req.resume();
+                }
+                HostVm::OffchainRandomSeed(req) => vm = req.resume(&[0x42; 32]),
+                HostVm::OffchainSubmitTransaction(req) => {
+                    offchain
+                        .submitted_transactions
+                        .push(req.transaction().as_ref().to_vec());
+                    vm = req.resume(true);
+                }
+                HostVm::OffchainIsValidator(req) => vm = req.resume(true),
+                HostVm::OffchainNetworkState(req) => {
+                    vm = req.resume(Some((&b"peer"[..], [b"addr1", b"addr2"].into_iter())));
+                }
+                HostVm::OffchainHttpRequestStart(req) => {
+                    let request_id = u16::try_from(offchain.http_requests.len()).unwrap();
+                    offchain.http_requests.push(TestHttpRequest {
+                        method: req.method().to_owned(),
+                        uri: req.uri().to_owned(),
+                        ..Default::default()
+                    });
+                    vm = req.resume(Some(request_id));
+                }
+                HostVm::OffchainHttpRequestAddHeader(req) => {
+                    match offchain
+                        .http_requests
+                        .get_mut(usize::from(req.request_id()))
+                    {
+                        Some(request) => {
+                            request
+                                .headers
+                                .push((req.name().to_owned(), req.value().to_owned()));
+                            vm = req.resume(true);
+                        }
+                        None => vm = req.resume(false),
+                    }
+                }
+                HostVm::OffchainHttpRequestWriteBody(req) => {
+                    match offchain
+                        .http_requests
+                        .get_mut(usize::from(req.request_id()))
+                    {
+                        Some(_) if req.deadline() == Some(0) => {
+                            vm = req.resume(Err(HttpError::DeadlineReached))
+                        }
+                        Some(request) => {
+                            request.body.extend_from_slice(req.chunk().as_ref());
+                            vm = req.resume(Ok(()));
+                        }
+                        None => vm = req.resume(Err(HttpError::Invalid)),
+                    }
+                }
+                HostVm::OffchainHttpResponseWait(req) => {
+                    let statuses = req
+                        .request_ids()
+                        .iter()
+                        .map(|id| {
+                            if usize::from(*id) < offchain.http_requests.len() {
+                                HttpRequestStatus::Finished(200)
+                            } else {
+                                HttpRequestStatus::Invalid
+                            }
+                        })
+                        .collect::<Vec<_>>();
+                    vm = req.resume(&statuses);
+                }
+                HostVm::OffchainHttpResponseHeaders(req) => {
+                    let headers = if usize::from(req.request_id()) < offchain.http_requests.len() {
+                        vec![("content-type", "text/plain")]
+                    } else {
+                        Vec::new()
+                    };
+                    vm = req.resume(headers.into_iter());
+                }
+                HostVm::OffchainHttpResponseReadBody(req) => {
+                    match offchain
+                        .http_requests
+                        .get_mut(usize::from(req.request_id()))
+                    {
+                        Some(request) => {
+                            let chunk_len = request.response_body.len().min(req.max_size());
+                            let chunk =
+                                request.response_body.drain(..chunk_len).collect::<Vec<_>>();
+                            vm = req.resume(Ok(&chunk));
+                        }
+                        None => vm = req.resume(Err(HttpError::Invalid)),
+                    }
+                }
+                HostVm::Finished(v) => break Ok(v.value().as_ref().to_vec()),
+                HostVm::Error { error, .. } => break Err(error),
+                _ => unreachable!(),
+            }
+        };
+
+        // All the execution engines are expected to yield the same outcome.
+        match (&outcome, &result) {
+            (None, _) => outcome = Some((result, offchain)),
+            (Some((Ok(a), _)), Ok(b)) => assert_eq!(a, b),
+            (Some((Err(_), _)), Err(_)) => {}
+            _ => panic!(),
+        }
+    }
+
+    let (result, new_offchain) = outcome.unwrap();
+    *offchain = new_offchain;
+    result
+}
+
+#[test]
+fn timestamp() {
+    let output = run_module(
+        "(i64.store (i32.const 0) (call $timestamp)) (i64.const 0x800000000)",
+        &[],
+        &mut TestOffchain::default(),
+    )
+    .unwrap();
+    assert_eq!(output, 1_700_000_000_000u64.to_le_bytes());
+}
+
+#[test]
+fn sleep_until() {
+    let mut offchain = TestOffchain::default();
+    let output = run_module(
+        "(call $sleep_until (i64.const 1234)) (i64.const 0)",
+        &[],
+        &mut offchain,
+    )
+    .unwrap();
+    assert!(output.is_empty());
+    assert_eq!(offchain.sleeps, [1234]);
+}
+
+#[test]
+fn random_seed() {
+    let output = run_module(
+        "(i64.or (i64.const 0x2000000000) (i64.extend_i32_u (call $random_seed)))",
+        &[],
+        &mut TestOffchain::default(),
+    )
+    .unwrap();
+    assert_eq!(output, [0x42; 32]);
+}
+
+#[test]
+fn is_validator() {
+    let output = run_module(
+        "(i32.store8 (i32.const 0) (call $is_validator)) (i64.const 0x100000000)",
+        &[],
+        &mut TestOffchain::default(),
+    )
+    .unwrap();
+    assert_eq!(output, [1]);
+}
+
+#[test]
+fn submit_transaction() {
+    let mut offchain = TestOffchain::default();
+    let output = run_module(
+        &format!(
+            "(call $submit_transaction (i64.const {}))",
+            ptr_size(1024, 4)
+        ),
+        &[(1024, &[0xde, 0xad, 0xbe, 0xef])],
+        &mut offchain,
+    )
+    .unwrap();
+    assert_eq!(output, [0]);
+    assert_eq!(
+        offchain.submitted_transactions,
+        [vec![0xde, 0xad, 0xbe, 0xef]]
+    );
+}
+
+#[test]
+fn network_state() {
+    let output = run_module("(call $network_state)", &[], &mut TestOffchain::default()).unwrap();
+
+    let mut expected = vec![0, 4 << 2];
+    expected.extend_from_slice(b"peer");
+    expected.push(2 << 2);
+    expected.push(5 << 2);
+    expected.extend_from_slice(b"addr1");
+    expected.push(5 << 2);
+    expected.extend_from_slice(b"addr2");
+    assert_eq!(output, expected);
+}
+
+#[test]
+fn local_storage_set_then_get() {
+    let mut offchain = TestOffchain::default();
+    let output = run_module(
+        &format!(
+            "(call $storage_set (i32.const 1) (i64.const {key}) (i64.const {value})) \
+            (call $storage_get (i32.const 1) (i64.const {key}))",
+            key = ptr_size(1024, 3),
+            value = ptr_size(1027, 5),
+        ),
+        &[(1024, b"foo"), (1027, b"hello")],
+        &mut offchain,
+    )
+    .unwrap();
+
+    let mut expected = vec![1, 5 << 2];
+    expected.extend_from_slice(b"hello");
+    assert_eq!(output, expected);
+    assert_eq!(
+        offchain
+            .storage
+            .get(&(OffchainStorageKind::Persistent, b"foo".to_vec())),
+        Some(&b"hello".to_vec())
+    );
+}
+
+#[test]
+fn local_storage_kinds_are_separate() {
+    let mut offchain = TestOffchain::default();
+    offchain.storage.insert(
+        (OffchainStorageKind::Persistent, b"foo".to_vec()),
+        b"hello".to_vec(),
+    );
+
+    let output = run_module(
+        &format!(
+            "(call $storage_get (i32.const 2) (i64.const {}))",
+            ptr_size(1024, 3)
+        ),
+        &[(1024, b"foo")],
+        &mut offchain,
+    )
+    .unwrap();
+    assert_eq!(output, [0]);
+}
+
+#[test]
+fn local_storage_clear() {
+    let mut offchain = TestOffchain::default();
+    offchain.storage.insert(
+        (OffchainStorageKind::Local, b"foo".to_vec()),
+        b"hello".to_vec(),
+    );
+
+    run_module(
+        &format!(
+            "(call $storage_clear (i32.const 2) (i64.const {})) (i64.const 0)",
+            ptr_size(1024, 3)
+        ),
+        &[(1024, b"foo")],
+        &mut offchain,
+    )
+    .unwrap();
+    assert!(offchain.storage.is_empty());
+}
+
+#[test]
+fn local_storage_compare_and_set() {
+    let mut old_value = vec![1, 5 << 2];
+    old_value.extend_from_slice(b"hello");
+
+    for (current_value, expected_replaced) in [
+        (Some(&b"hello"[..]), true),
+        (Some(&b"world"[..]), false),
+        (None, false),
+    ] {
+        let mut offchain = TestOffchain::default();
+        if let Some(current_value) = current_value {
+            offchain.storage.insert(
+                (OffchainStorageKind::Persistent, b"foo".to_vec()),
+                current_value.to_vec(),
+            );
+        }
+
+        let output = run_module(
+            &format!(
+                "(i32.store8 (i32.const 0) (call $storage_compare_and_set (i32.const 1) \
+                (i64.const {key}) (i64.const {old_value}) (i64.const {new_value}))) \
+                (i64.const 0x100000000)",
+                key = ptr_size(1024, 3),
+                old_value = ptr_size(1027, 7),
+                new_value = ptr_size(1034, 3),
+            ),
+            &[(1024, b"foo"), (1027, &old_value), (1034, b"bar")],
+            &mut offchain,
+        )
+        .unwrap();
+
+        assert_eq!(output, [u8::from(expected_replaced)]);
+        assert_eq!(
+            offchain
+                .storage
+                .get(&(OffchainStorageKind::Persistent, b"foo".to_vec()))
+                .map(|v| &v[..]),
+            if expected_replaced {
+                Some(&b"bar"[..])
+            } else {
+                current_value
+            }
+        );
+    }
+}
+
+#[test]
+fn local_storage_compare_and_set_none() {
+    let mut offchain = TestOffchain::default();
+    let output = run_module(
+        &format!(
+            "(i32.store8 (i32.const 0) (call $storage_compare_and_set (i32.const 1) \
+            (i64.const {key}) (i64.const {old_value}) (i64.const {new_value}))) \
+            (i64.const 0x100000000)",
+            key = ptr_size(1024, 3),
+            old_value = ptr_size(1027, 1),
+            new_value = ptr_size(1028, 3),
+        ),
+        &[(1024, b"foo"), (1027, &[0]), (1028, b"bar")],
+        &mut offchain,
+    )
+    .unwrap();
+    assert_eq!(output, [1]);
+    assert_eq!(
+        offchain
+            .storage
+            .get(&(OffchainStorageKind::Persistent, b"foo".to_vec())),
+        Some(&b"bar".to_vec())
+    );
+}
+
+#[test]
+fn local_storage_invalid_kind() {
+    let output = run_module(
+        &format!(
+            "(call $storage_get (i32.const 3) (i64.const {}))",
+            ptr_size(1024, 3)
+        ),
+        &[(1024, b"foo")],
+        &mut TestOffchain::default(),
+    );
+    assert!(matches!(output, Err(Error::ParamDecodeError)));
+}
+
+#[test]
+fn http_request_start() {
+    let mut offchain = TestOffchain::default();
+    offchain.http_requests.push(Default::default());
+
+    let output = run_module(
+        &format!(
+            "(call $http_start (i64.const {method}) (i64.const {uri}) (i64.const {meta}))",
+            method = ptr_size(1024, 4),
+            uri = ptr_size(1028, 18),
+            meta = ptr_size(1046, 0),
+        ),
+        &[(1024, b"POST"), (1028, b"http://example.com")],
+        &mut offchain,
+    )
+    .unwrap();
+
+    assert_eq!(output, [0, 1, 0]);
+    assert_eq!(offchain.http_requests[1].method, "POST");
+    assert_eq!(offchain.http_requests[1].uri, "http://example.com");
+}
+
+#[test]
+fn http_request_start_invalid_utf8() {
+    let output = run_module(
+        &format!(
+            "(call $http_start (i64.const {method}) (i64.const {uri}) (i64.const {meta}))",
+            method = ptr_size(1024, 3),
+            uri = ptr_size(1027, 1),
+            meta = ptr_size(1028, 0),
+        ),
+        &[(1024, b"GET"), (1027, &[0xff])],
+        &mut TestOffchain::default(),
+    );
+    assert!(matches!(output, Err(Error::Utf8Error { param_num: 1, .. })));
+}
+
+#[test]
+fn http_request_add_header() {
+    let mut offchain = TestOffchain::default();
+    offchain.http_requests.push(Default::default());
+
+    for (request_id, expected) in [(0, [0]), (1, [1])] {
+        let output = run_module(
+            &format!(
+                "(call $http_add_header (i32.const {request_id}) (i64.const {name}) (i64.const {value}))",
+                name = ptr_size(1024, 6),
+                value = ptr_size(1030, 3),
+            ),
+            &[(1024, b"Accept"), (1030, b"*/*")],
+            &mut offchain,
+        )
+        .unwrap();
+        assert_eq!(output, expected);
+    }
+
+    assert_eq!(
+        offchain.http_requests[0].headers,
+        [("Accept".to_owned(), "*/*".to_owned())]
+    );
+}
+
+#[test]
+fn http_request_write_body() {
+    let mut offchain = TestOffchain::default();
+    offchain.http_requests.push(Default::default());
+
+    // Respectively no deadline, a deadline of 0, and an invalid request ID.
+    for (request_id, deadline, expected) in [
+        (0, &[0][..], &[0][..]),
+        (0, &[1, 0, 0, 0, 0, 0, 0, 0, 0][..], &[1, 1][..]),
+        (5, &[0][..], &[1, 3][..]),
+    ] {
+        let output = run_module(
+            &format!(
+                "(call $http_write_body (i32.const {request_id}) (i64.const {chunk}) (i64.const {deadline}))",
+                chunk = ptr_size(1024, 5),
+                deadline = ptr_size(1029, u32::try_from(deadline.len()).unwrap()),
+            ),
+            &[(1024, b"hello"), (1029, deadline)],
+            &mut offchain,
+        )
+        .unwrap();
+        assert_eq!(output, expected);
+    }
+
+    assert_eq!(offchain.http_requests[0].body, b"hello");
+}
+
+#[test]
+fn http_request_write_body_invalid_deadline() {
+    let mut offchain = TestOffchain::default();
+    offchain.http_requests.push(Default::default());
+
+    let output = run_module(
+        &format!(
+            "(call $http_write_body (i32.const 0) (i64.const {chunk}) (i64.const {deadline}))",
+            chunk = ptr_size(1024, 5),
+            deadline = ptr_size(1029, 3),
+        ),
+        &[(1024, b"hello"), (1029, &[1, 0, 0])],
+        &mut offchain,
+    );
+    assert!(matches!(output, Err(Error::ParamDecodeError)));
+}
+
+#[test]
+fn http_response_wait() {
+    let mut offchain = TestOffchain::default();
+    offchain.http_requests.push(Default::default());
+
+    let output = run_module(
+        &format!(
+            "(call $http_wait (i64.const {ids}) (i64.const {deadline}))",
+            ids = ptr_size(1024, 5),
+            deadline = ptr_size(1029, 1),
+        ),
+        &[(1024, &[2 << 2, 0, 0, 5, 0]), (1029, &[0])],
+        &mut offchain,
+    )
+    .unwrap();
+    assert_eq!(output, [2 << 2, 3, 200, 0, 2]);
+}
+
+#[test]
+fn http_response_headers() {
+    let mut offchain = TestOffchain::default();
+    offchain.http_requests.push(Default::default());
+
+    let output = run_module("(call $http_headers (i32.const 0))", &[], &mut offchain).unwrap();
+    let mut expected = vec![1 << 2, 12 << 2];
+    expected.extend_from_slice(b"content-type");
+    expected.push(10 << 2);
+    expected.extend_from_slice(b"text/plain");
+    assert_eq!(output, expected);
+
+    let output = run_module("(call $http_headers (i32.const 1))", &[], &mut offchain).unwrap();
+    assert_eq!(output, [0]);
+}
+
+#[test]
+fn http_response_read_body() {
+    let mut offchain = TestOffchain::default();
+    offchain.http_requests.push(TestHttpRequest {
+        response_body: b"hello".to_vec(),
+        ..Default::default()
+    });
+
+    // Reads the body using a buffer of 3 bytes, and returns the content of the buffer.
+    let output = run_module(
+        &format!(
+            "(drop (call $http_read_body (i32.const 0) (i64.const {buffer}) (i64.const {deadline}))) \
+            (i64.const {buffer})",
+            buffer = ptr_size(2048, 3),
+            deadline = ptr_size(1024, 1),
+        ),
+        &[(1024, &[0])],
+        &mut offchain,
+    )
+    .unwrap();
+    assert_eq!(output, b"hel");
+
+    // Reads the rest of the body, and returns the outcome.
+    for expected in [&[0, 2, 0, 0, 0][..], &[0, 0, 0, 0, 0][..]] {
+        let output = run_module(
+            &format!(
+                "(call $http_read_body (i32.const 0) (i64.const {buffer}) (i64.const {deadline}))",
+                buffer = ptr_size(2048, 3),
+                deadline = ptr_size(1024, 1),
+            ),
+            &[(1024, &[0])],
+            &mut offchain,
+        )
+        .unwrap();
+        assert_eq!(output, expected);
+    }
+
+    let output = run_module(
+        &format!(
+            "(call $http_read_body (i32.const 1) (i64.const {buffer}) (i64.const {deadline}))",
+            buffer = ptr_size(2048, 3),
+            deadline = ptr_size(1024, 1),
+        ),
+        &[(1024, &[0])],
+        &mut offchain,
+    )
+    .unwrap();
+    assert_eq!(output, [1, 3]);
+}
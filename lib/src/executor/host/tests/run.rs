@@ -38,6 +38,7 @@ fn function_to_run_doesnt_exist() {
         let host_vm = HostVmPrototype::new(Config {
             allow_unresolved_imports: false,
             exec_hint,
+            compilation_cache: None,
             heap_pages: HeapPages::new(1024),
             module: &module_bytes,
         })
@@ -69,6 +70,7 @@ fn function_to_run_invalid_params() {
         let host_vm = HostVmPrototype::new(Config {
             allow_unresolved_imports: false,
             exec_hint,
+            compilation_cache: None,
             heap_pages: HeapPages::new(1024),
             module: &module_bytes,
         })
@@ -198,6 +200,7 @@ fn input_provided_correctly() {
         let proto = HostVmPrototype::new(Config {
             allow_unresolved_imports: false,
             exec_hint,
+            compilation_cache: None,
             heap_pages: HeapPages::new(1024),
             module: &module_bytes,
         })
@@ -220,6 +223,7 @@ fn input_provided_correctly() {
         let proto = HostVmPrototype::new(Config {
             allow_unresolved_imports: false,
             exec_hint,
+            compilation_cache: None,
             heap_pages: HeapPages::new(1024),
             module: &module_bytes,
         })
@@ -247,6 +251,7 @@ fn input_provided_correctly() {
         let proto = HostVmPrototype::new(Config {
             allow_unresolved_imports: false,
             exec_hint,
+            compilation_cache: None,
             heap_pages: HeapPages::new(1024),
             module: &module_bytes,
         })
@@ -353,6 +358,7 @@ fn large_input_provided_correctly() {
         let proto = HostVmPrototype::new(Config {
             allow_unresolved_imports: false,
             exec_hint,
+            compilation_cache: None,
             heap_pages: HeapPages::new(1024),
             module: &module_bytes,
         })
@@ -416,6 +422,7 @@ fn return_value_works() {
         let proto = HostVmPrototype::new(Config {
             allow_unresolved_imports: false,
             exec_hint,
+            compilation_cache: None,
             heap_pages: HeapPages::new(1024),
             module: &module_bytes,
         })
@@ -470,6 +477,7 @@ fn bad_return_value() {
         let proto = HostVmPrototype::new(Config {
             allow_unresolved_imports: false,
             exec_hint,
+            compilation_cache: None,
             heap_pages: HeapPages::new(1024),
             module: &module_bytes,
         })
@@ -529,6 +537,7 @@ fn returned_ptr_out_of_range() {
         let proto = HostVmPrototype::new(Config {
             allow_unresolved_imports: false,
             exec_hint,
+            compilation_cache: None,
             heap_pages: HeapPages::new(1024),
             module: &module_bytes,
         })
@@ -588,6 +597,7 @@ fn returned_size_out_of_range() {
         let proto = HostVmPrototype::new(Config {
             allow_unresolved_imports: false,
             exec_hint,
+            compilation_cache: None,
             heap_pages: HeapPages::new(1024),
             module: &module_bytes,
         })
@@ -654,6 +664,7 @@ fn unresolved_host_function_called() {
         match HostVmPrototype::new(Config {
             allow_unresolved_imports: false,
             exec_hint,
+            compilation_cache: None,
             heap_pages: HeapPages::new(1024),
             module: &module_bytes,
         }) {
@@ -664,6 +675,7 @@ fn unresolved_host_function_called() {
         let proto = HostVmPrototype::new(Config {
             allow_unresolved_imports: true,
             exec_hint,
+            compilation_cache: None,
             heap_pages: HeapPages::new(1024),
             module: &module_bytes,
         })
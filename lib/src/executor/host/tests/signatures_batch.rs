@@ -0,0 +1,318 @@
+// Smoldot
+// Copyright (C) 2023  Pierre Krieger
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <http://www.gnu.org/licenses/>.
+
+use super::super::{vm::ExecHint, Config, Error, HeapPages, HostVm, HostVmPrototype};
+use super::with_core_version_custom_sections;
+
+use core::fmt::Write as _;
+
+/// Call to a host function performed by the Wasm module generated by [`run_calls`].
+enum Call {
+    Start,
+    Finish,
+    Ed25519 {
+        signature: Vec<u8>,
+        message: Vec<u8>,
+        public_key: Vec<u8>,
+    },
+    Sr25519 {
+        signature: Vec<u8>,
+        message: Vec<u8>,
+        public_key: Vec<u8>,
+    },
+    Ecdsa {
+        signature: Vec<u8>,
+        message: Vec<u8>,
+        public_key: Vec<u8>,
+    },
+}
+
+fn ed25519(seed: u8, message: &[u8]) -> Call {
+    let signing_key = ed25519_zebra::SigningKey::from([seed; 32]);
+    Call::Ed25519 {
+        signature: <[u8; 64]>::from(signing_key.sign(message)).to_vec(),
+        message: message.to_vec(),
+        public_key: <[u8; 32]>::from(ed25519_zebra::VerificationKey::from(&signing_key)).to_vec(),
+    }
+}
+
+fn sr25519(seed: u8, message: &[u8]) -> Call {
+    let keypair = schnorrkel::MiniSecretKey::from_bytes(&[seed; 32])
+        .unwrap()
+        .expand_to_keypair(schnorrkel::ExpansionMode::Ed25519);
+    Call::Sr25519 {
+        signature: keypair
+            .sign(schnorrkel::signing_context(b"substrate").bytes(message))
+            .to_bytes()
+            .to_vec(),
+        message: message.to_vec(),
+        public_key: keypair.public.to_bytes().to_vec(),
+    }
+}
+
+fn ecdsa(seed: u8, message: &[u8]) -> Call {
+    let secret_key = libsecp256k1::SecretKey::parse(&[seed; 32]).unwrap();
+    let hash = blake2_rfc::blake2b::blake2b(32, &[], message);
+    let (signature, recovery_id) = libsecp256k1::sign(
+        &libsecp256k1::Message::parse(&<[u8; 32]>::try_from(hash.as_bytes()).unwrap()),
+        &secret_key,
+    );
+    let mut signature = signature.serialize().to_vec();
+    signature.push(recovery_id.serialize());
+    Call::Ecdsa {
+        signature,
+        message: message.to_vec(),
+        public_key: libsecp256k1::PublicKey::from_secret_key(&secret_key)
+            .serialize_compressed()
+            .to_vec(),
+    }
+}
+
+/// Generates a Wasm module that performs the given list of calls, runs it, and returns the
+/// values returned by the host functions, in order. Calls that don't return anything are
+/// ignored.
+fn run_calls(calls: &[Call]) -> Result<Vec<u8>, Error> {
+    let mut data = String::new();
+    let mut body = String::new();
+    let mut data_offset = 1024;
+    let mut num_outputs = 0u32;
+
+    for call in calls {
+        let (function, signature, message, public_key) = match call {
+            Call::Start => {
+                writeln!(body, "call $start").unwrap();
+                continue;
+            }
+            Call::Finish => {
+                writeln!(
+                    body,
+                    "(i32.store8 (i32.const {num_outputs}) (call $finish))"
+                )
+                .unwrap();
+                num_outputs += 1;
+                continue;
+            }
+            Call::Ed25519 {
+                signature,
+                message,
+                public_key,
+            } => ("$ed25519", signature, message, public_key),
+            Call::Sr25519 {
+                signature,
+                message,
+                public_key,
+            } => ("$sr25519", signature, message, public_key),
+            Call::Ecdsa {
+                signature,
+                message,
+                public_key,
+            } => ("$ecdsa", signature, message, public_key),
+        };
+
+        let mut pointers = Vec::with_capacity(3);
+        for buffer in [signature, message, public_key] {
+            write!(data, "(data (i32.const {data_offset}) \"").unwrap();
+            for byte in buffer {
+                write!(data, "\\{byte:02x}").unwrap();
+            }
+            writeln!(data, "\")").unwrap();
+            pointers.push(data_offset);
+            data_offset += buffer.len() as u64;
+        }
+
+        writeln!(
+            body,
+            "(i32.store8 (i32.const {num_outputs}) (call {function} (i32.const {}) (i64.const {}) (i32.const {})))",
+            pointers[0],
+            ((message.len() as u64) << 32) | pointers[1],
+            pointers[2]
+        )
+        .unwrap();
+        num_outputs += 1;
+    }
+
+    let module_bytes = with_core_version_custom_sections(
+        wat::parse_str(format!(
+            r#"
+    (module
+        (import "env" "ext_crypto_start_batch_verify_version_1" (func $start))
+        (import "env" "ext_crypto_finish_batch_verify_version_1" (func $finish (result i32)))
+        (import "env" "ext_crypto_ed25519_batch_verify_version_1" (func $ed25519 (param i32 i64 i32) (result i32)))
+        (import "env" "ext_crypto_sr25519_batch_verify_version_1" (func $sr25519 (param i32 i64 i32) (result i32)))
+        (import "env" "ext_crypto_ecdsa_batch_verify_version_1" (func $ecdsa (param i32 i64 i32) (result i32)))
+        (memory (export "memory") 1)
+        (global (export "__heap_base") i32 (i32.const 32768))
+        (func (export "test") (param i32 i32) (result i64)
+            {body}
+            i64.const {})
+        {data}
+    )
+    "#,
+            u64::from(num_outputs) << 32
+        ))
+        .unwrap(),
+    );
+
+    assert!(data_offset < 32768);
+
+    let mut outcome = None;
+    for exec_hint in ExecHint::available_engines() {
+        let proto = HostVmPrototype::new(Config {
+            allow_unresolved_imports: false,
+            exec_hint,
+            compilation_cache: None,
+            heap_pages: HeapPages::new(1024),
+            module: &module_bytes,
+        })
+        .unwrap();
+
+        let mut vm = HostVm::from(proto.run_no_param("test").unwrap());
+        let result = loop {
+            match vm {
+                HostVm::ReadyToRun(r) => vm = r.run(),
+                HostVm::SignatureVerification(sig) => vm = sig.verify_and_resume(),
+                HostVm::Finished(v) => break Ok(v.value().as_ref().to_vec()),
+                HostVm::Error { error, .. } => break Err(error),
+                _ => unreachable!(),
+            }
+        };
+
+        // All the execution engines are expected to yield the same outcome.
+        match (&outcome, &result) {
+            (None, _) => outcome = Some(result),
+            (Some(Ok(a)), Ok(b)) => assert_eq!(a, b),
+            (Some(Err(_)), Err(_)) => {}
+            _ => panic!(),
+        }
+    }
+
+    outcome.unwrap()
+}
+
+#[test]
+fn valid_batch() {
+    let outcome = run_calls(&[
+        Call::Start,
+        ed25519(1, b"hello"),
+        sr25519(2, b"world"),
+        ed25519(3, b"foo"),
+        sr25519(4, b"bar"),
+        ecdsa(5, b"baz"),
+        Call::Finish,
+    ])
+    .unwrap();
+    assert_eq!(outcome, [1, 1, 1, 1, 1, 1]);
+}
+
+#[test]
+fn invalid_ed25519_in_batch() {
+    let mut invalid = ed25519(3, b"foo");
+    let Call::Ed25519 { message, .. } = &mut invalid else { unreachable!() };
+    message[0] ^= 1;
+
+    let outcome = run_calls(&[
+        Call::Start,
+        ed25519(1, b"hello"),
+        invalid,
+        sr25519(2, b"world"),
+        Call::Finish,
+    ])
+    .unwrap();
+    assert_eq!(outcome, [1, 1, 1, 0]);
+}
+
+#[test]
+fn invalid_sr25519_in_batch() {
+    let mut invalid = sr25519(3, b"foo");
+    let Call::Sr25519 { signature, .. } = &mut invalid else { unreachable!() };
+    signature[5] ^= 1;
+
+    let outcome = run_calls(&[
+        Call::Start,
+        sr25519(1, b"hello"),
+        invalid,
+        ed25519(2, b"world"),
+        Call::Finish,
+    ])
+    .unwrap();
+    assert_eq!(outcome, [1, 1, 1, 0]);
+}
+
+#[test]
+fn invalid_ecdsa_in_batch() {
+    let mut invalid = ecdsa(3, b"foo");
+    let Call::Ecdsa { message, .. } = &mut invalid else { unreachable!() };
+    message[0] ^= 1;
+
+    let outcome = run_calls(&[Call::Start, ed25519(1, b"hello"), invalid, Call::Finish]).unwrap();
+    assert_eq!(outcome, [1, 1, 0]);
+}
+
+#[test]
+fn empty_batch() {
+    assert_eq!(run_calls(&[Call::Start, Call::Finish]).unwrap(), [1]);
+}
+
+#[test]
+fn multiple_batches() {
+    let mut invalid = ed25519(3, b"foo");
+    let Call::Ed25519 { message, .. } = &mut invalid else { unreachable!() };
+    message[0] ^= 1;
+
+    let outcome = run_calls(&[
+        Call::Start,
+        invalid,
+        Call::Finish,
+        Call::Start,
+        ed25519(1, b"hello"),
+        Call::Finish,
+    ])
+    .unwrap();
+    assert_eq!(outcome, [1, 0, 1, 1]);
+}
+
+#[test]
+fn verify_outside_of_batch() {
+    let mut invalid = sr25519(3, b"foo");
+    let Call::Sr25519 { message, .. } = &mut invalid else { unreachable!() };
+    message[0] ^= 1;
+
+    let outcome = run_calls(&[
+        ed25519(1, b"hello"),
+        sr25519(2, b"world"),
+        ecdsa(3, b"foo"),
+        invalid,
+    ])
+    .unwrap();
+    assert_eq!(outcome, [1, 1, 1, 0]);
+}
+
+#[test]
+fn finish_without_start() {
+    assert!(matches!(
+        run_calls(&[Call::Finish]),
+        Err(Error::NoBatchVerify)
+    ));
+}
+
+#[test]
+fn start_twice() {
+    assert!(matches!(
+        run_calls(&[Call::Start, Call::Start]),
+        Err(Error::AlreadyBatchVerify)
+    ));
+}
@@ -0,0 +1,83 @@
+// Smoldot
+// Copyright (C) 2023  Pierre Krieger
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <http://www.gnu.org/licenses/>.
+
+use super::super::{vm, vm::ExecHint, Config, HeapPages, HostVm, HostVmPrototype};
+use super::with_core_version_custom_sections;
+
+#[test]
+fn host_function_calls_traced() {
+    let module_bytes = with_core_version_custom_sections(
+        wat::parse_str(
+            r#"
+    (module
+        (import "env" "ext_hashing_twox_64_version_1" (func $twox_64 (param i64) (result i32)))
+        (import "env" "ext_storage_get_version_1" (func $storage_get (param i64) (result i64)))
+        (import "env" "ext_logging_max_level_version_1" (func $max_level (result i32)))
+        (memory (export "memory") 1)
+        (global (export "__heap_base") i32 (i32.const 1024))
+        (func (export "test") (param i32 i32) (result i64)
+            (drop (call $twox_64 (i64.const 0x300000000)))
+            (drop (call $storage_get (i64.const 0x300000000)))
+            (drop (call $max_level))
+            (i64.const 0))
+        (data (i32.const 0) "foo")
+    )
+    "#,
+        )
+        .unwrap(),
+    );
+
+    for exec_hint in ExecHint::available_engines() {
+        let proto = HostVmPrototype::new(Config {
+            allow_unresolved_imports: false,
+            exec_hint,
+            compilation_cache: None,
+            heap_pages: HeapPages::new(1024),
+            module: &module_bytes,
+        })
+        .unwrap();
+
+        let mut calls = Vec::new();
+        let mut vm = HostVm::from(proto.run_no_param("test").unwrap());
+        loop {
+            match vm {
+                HostVm::ReadyToRun(r) => vm = r.run_traced(&mut calls),
+                HostVm::Finished(_) => break,
+                HostVm::ExternalStorageGet(req) => {
+                    assert_eq!(req.key().as_ref(), b"foo");
+                    vm = req.resume_full_value(Some(b"bar"));
+                }
+                HostVm::GetMaxLogLevel(req) => vm = req.resume(3),
+                _ => unreachable!(),
+            }
+        }
+
+        assert_eq!(calls.len(), 3);
+
+        assert_eq!(calls[0].function_name, "ext_hashing_twox_64_version_1");
+        assert_eq!(calls[0].parameters, [vm::WasmValue::I64(0x300000000)]);
+        assert!(matches!(calls[0].return_value, Some(vm::WasmValue::I32(_))));
+
+        assert_eq!(calls[1].function_name, "ext_storage_get_version_1");
+        assert_eq!(calls[1].parameters, [vm::WasmValue::I64(0x300000000)]);
+        assert!(matches!(calls[1].return_value, Some(vm::WasmValue::I64(_))));
+
+        assert_eq!(calls[2].function_name, "ext_logging_max_level_version_1");
+        assert!(calls[2].parameters.is_empty());
+        assert_eq!(calls[2].return_value, Some(vm::WasmValue::I32(3)));
+    }
+}
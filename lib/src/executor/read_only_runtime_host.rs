@@ -20,11 +20,15 @@
 // TODO: more docs
 
 use crate::{
-    executor::{self, host, vm},
+    executor::{self, host, trace},
     trie,
 };
 
-use alloc::{string::String, vec::Vec};
+use alloc::{
+    boxed::Box,
+    string::{String, ToString as _},
+    vec::Vec,
+};
 use core::fmt;
 
 /// Configuration for [`run`].
@@ -46,6 +50,21 @@ pub struct Config<'a, TParams> {
     /// >           "off", `1` for "error", `2` for "warn", `3` for "info", `4` for "debug",
     /// >           and `5` for "trace".
     pub max_log_level: u32,
+
+    /// If `true`, all the host function calls, storage accesses, and log entries of the runtime
+    /// are recorded and reported in [`Success::trace`] or [`Error::trace`].
+    ///
+    /// > **Note**: Tracing has a significant cost in terms of memory and CPU. It is meant to be
+    /// >           used only for debugging purposes.
+    pub trace: bool,
+
+    /// Maximum number of WebAssembly instructions that the runtime is allowed to execute, or
+    /// `None` for no limit. If the limit is reached, the execution fails with
+    /// [`host::Error::OutOfFuel`].
+    ///
+    /// > **Note**: The limit is only enforced if the virtual machine has been created with
+    /// >           [`crate::executor::vm::ExecHint::Untrusted`].
+    pub fuel_budget: Option<u64>,
 }
 
 /// Start running the WebAssembly virtual machine.
@@ -55,10 +74,20 @@ pub fn run(
     Ok(Inner {
         vm: config
             .virtual_machine
-            .run_vectored(config.function_to_call, config.parameter)?
+            .run_vectored_with_fuel(
+                config.function_to_call,
+                config.parameter,
+                config.fuel_budget,
+            )
+            .map_err(|err| *err)?
             .into(),
         logs: String::new(),
         max_log_level: config.max_log_level,
+        trace: if config.trace {
+            Some(trace::Trace::default())
+        } else {
+            None
+        },
     }
     .run())
 }
@@ -71,6 +100,8 @@ pub struct Success {
     pub virtual_machine: SuccessVirtualMachine,
     /// Concatenation of all the log messages printed by the runtime.
     pub logs: String,
+    /// Trace of the execution. `Some` if and only if [`Config::trace`] was `true`.
+    pub trace: Option<trace::Trace>,
 }
 
 /// Function execution has succeeded. Contains the return value of the call.
@@ -102,6 +133,9 @@ pub struct Error {
     pub detail: ErrorDetail,
     /// Prototype of the virtual machine that was passed through [`Config::virtual_machine`].
     pub prototype: host::HostVmPrototype,
+    /// Trace of the execution up until the error. `Some` if and only if [`Config::trace`] was
+    /// `true`.
+    pub trace: Option<trace::Trace>,
 }
 
 /// See [`Error::detail`].
@@ -166,12 +200,14 @@ impl StorageGet {
                 let Some(child_trie) = req.child_trie()
                     else { unreachable!() };
                 // TODO: allocation here, but probably not problematic
-                const PREFIX: &[u8] = b":child_storage:default:";
-                let mut key = Vec::with_capacity(PREFIX.len() + child_trie.as_ref().len());
-                key.extend_from_slice(PREFIX);
+                let mut key = Vec::with_capacity(
+                    trie::CHILD_STORAGE_PREFIX.len() + child_trie.as_ref().len(),
+                );
+                key.extend_from_slice(trie::CHILD_STORAGE_PREFIX);
                 key.extend_from_slice(child_trie.as_ref());
                 either::Right(key)
             }
+            host::HostVm::CallRuntimeVersion(_) => either::Right(b":heappages".to_vec()),
             // We only create a `StorageGet` if the state is one of the above.
             _ => unreachable!(),
         }
@@ -182,6 +218,7 @@ impl StorageGet {
         match &self.inner.vm {
             host::HostVm::ExternalStorageGet(req) => req.child_trie(),
             host::HostVm::ExternalStorageRoot(_) => None,
+            host::HostVm::CallRuntimeVersion(_) => None,
             _ => unreachable!(),
         }
     }
@@ -201,10 +238,22 @@ impl StorageGet {
 
         match self.inner.vm {
             host::HostVm::ExternalStorageGet(req) => {
+                if let Some(trace) = &mut self.inner.trace {
+                    trace.push_event(trace::EventDetail::StorageRead {
+                        child_trie: req.child_trie().map(|ct| ct.as_ref().to_vec()),
+                        key: req.key().as_ref().to_vec(),
+                        value: value.clone(),
+                    });
+                }
                 // TODO: should actually report the offset and max_size in the API
                 self.inner.vm = req.resume_full_value(value.as_ref().map(|v| &v[..]));
             }
 
+            host::HostVm::CallRuntimeVersion(req) => {
+                self.inner.vm =
+                    executor::resume_call_runtime_version(req, value.as_ref().map(|v| &v[..]));
+            }
+
             host::HostVm::ExternalStorageRoot(req) => {
                 let hash = match value.as_ref() {
                     Some(v) => match <&[u8; 32]>::try_from(&v[..]) {
@@ -213,12 +262,19 @@ impl StorageGet {
                             return RuntimeHostVm::Finished(Err(Error {
                                 detail: ErrorDetail::ChildTrieRootHashInvalidLength,
                                 prototype: host::HostVm::ExternalStorageRoot(req).into_prototype(),
+                                trace: self.inner.trace,
                             }));
                         }
                     },
                     None => &trie::EMPTY_TRIE_MERKLE_VALUE,
                 };
 
+                if let Some(trace) = &mut self.inner.trace {
+                    trace.push_event(trace::EventDetail::StorageRoot {
+                        child_trie: req.child_trie().map(|ct| ct.as_ref().to_vec()),
+                        hash: *hash,
+                    });
+                }
                 self.inner.vm = req.resume(hash);
             }
 
@@ -228,6 +284,25 @@ impl StorageGet {
 
         self.inner.run()
     }
+
+    /// Indicates that the storage value can't be obtained, for example because it is missing
+    /// from a storage proof.
+    ///
+    /// The execution can only continue if the value of `:heappages` has been requested in order
+    /// to determine the version of a runtime code, in which case
+    /// [`executor::DEFAULT_HEAP_PAGES`] is used. Otherwise, `self` is returned back.
+    pub fn inject_unavailable(mut self) -> Result<RuntimeHostVm, Box<Self>> {
+        match self.inner.vm {
+            host::HostVm::CallRuntimeVersion(req) => {
+                self.inner.vm = executor::resume_call_runtime_version(req, None);
+                Ok(self.inner.run())
+            }
+            vm => {
+                self.inner.vm = vm;
+                Err(Box::new(self))
+            }
+        }
+    }
 }
 
 /// Fetching the key that follows a given one is required in order to continue.
@@ -282,6 +357,13 @@ impl NextKey {
 
         match self.inner.vm {
             host::HostVm::ExternalStorageNextKey(req) => {
+                if let Some(trace) = &mut self.inner.trace {
+                    trace.push_event(trace::EventDetail::StorageNextKey {
+                        child_trie: req.child_trie().map(|ct| ct.as_ref().to_vec()),
+                        key: req.key().as_ref().to_vec(),
+                        next_key: key.map(|k| k.to_vec()),
+                    });
+                }
                 self.inner.vm = req.resume(key.as_ref().map(|v| &v[..]));
             }
 
@@ -304,6 +386,12 @@ impl StorageRoot {
     pub fn resume(mut self, hash: &[u8; 32]) -> RuntimeHostVm {
         match self.inner.vm {
             host::HostVm::ExternalStorageRoot(req) => {
+                if let Some(trace) = &mut self.inner.trace {
+                    trace.push_event(trace::EventDetail::StorageRoot {
+                        child_trie: None,
+                        hash: *hash,
+                    });
+                }
                 self.inner.vm = req.resume(hash);
             }
 
@@ -408,6 +496,8 @@ struct Inner {
     logs: String,
     /// Value provided by [`Config::max_log_level`].
     max_log_level: u32,
+    /// Trace of the execution. `Some` if and only if [`Config::trace`] was `true`.
+    trace: Option<trace::Trace>,
 }
 
 impl Inner {
@@ -415,7 +505,12 @@ impl Inner {
     fn run(mut self) -> RuntimeHostVm {
         loop {
             match self.vm {
-                host::HostVm::ReadyToRun(r) => self.vm = r.run(),
+                host::HostVm::ReadyToRun(r) => {
+                    self.vm = match &mut self.trace {
+                        Some(trace) => r.run_traced(&mut trace.host_function_calls),
+                        None => r.run(),
+                    };
+                }
 
                 host::HostVm::Error { error, prototype } => {
                     return RuntimeHostVm::Finished(Err(Error {
@@ -424,6 +519,7 @@ impl Inner {
                             logs: self.logs,
                         },
                         prototype,
+                        trace: self.trace,
                     }));
                 }
 
@@ -431,6 +527,7 @@ impl Inner {
                     return RuntimeHostVm::Finished(Ok(Success {
                         virtual_machine: SuccessVirtualMachine(finished),
                         logs: self.logs,
+                        trace: self.trace,
                     }));
                 }
 
@@ -452,28 +549,10 @@ impl Inner {
                 }
 
                 host::HostVm::CallRuntimeVersion(req) => {
-                    // TODO: make the user execute this ; see https://github.com/paritytech/smoldot/issues/144
-                    // The code below compiles the provided WebAssembly runtime code, which is a
-                    // relatively expensive operation (in the order of milliseconds).
-                    // While it could be tempting to use a system cache, this function is expected
-                    // to be called only right before runtime upgrades. Considering that runtime
-                    // upgrades are quite uncommon and that a caching system is rather non-trivial
-                    // to set up, the approach of recompiling every single time is preferred here.
-                    // TODO: number of heap pages?! we use the default here, but not sure whether that's correct or if we have to take the current heap pages
-                    let vm_prototype = match host::HostVmPrototype::new(host::Config {
-                        module: req.wasm_code(),
-                        heap_pages: executor::DEFAULT_HEAP_PAGES,
-                        exec_hint: vm::ExecHint::Oneshot,
-                        allow_unresolved_imports: false, // TODO: what is a correct value here?
-                    }) {
-                        Ok(w) => w,
-                        Err(_) => {
-                            self.vm = req.resume(Err(()));
-                            continue;
-                        }
-                    };
-
-                    self.vm = req.resume(Ok(vm_prototype.runtime_version().as_ref()));
+                    // The runtime being queried is compiled with the number of heap pages found
+                    // in the storage.
+                    self.vm = req.into();
+                    return RuntimeHostVm::StorageGet(StorageGet { inner: self });
                 }
 
                 host::HostVm::ExternalStorageRoot(req) => {
@@ -522,9 +601,21 @@ impl Inner {
                             return RuntimeHostVm::Finished(Err(Error {
                                 detail: ErrorDetail::LogsTooLong,
                                 prototype: host::HostVm::LogEmit(req).into_prototype(),
+                                trace: self.trace,
                             }));
                         }
                     }
+                    if let Some(trace) = &mut self.trace {
+                        let mut message = req.to_string();
+                        if message.ends_with('\n') {
+                            message.pop();
+                        }
+                        trace.push_event(trace::EventDetail::Log {
+                            level: req.log_level(),
+                            target: req.target(),
+                            message,
+                        });
+                    }
                     self.vm = req.resume();
                 }
 
@@ -532,6 +623,7 @@ impl Inner {
                     return RuntimeHostVm::Finished(Err(Error {
                         detail: ErrorDetail::ForbiddenHostCall,
                         prototype: other.into_prototype(),
+                        trace: self.trace,
                     }))
                 }
             }
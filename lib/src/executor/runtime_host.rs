@@ -38,7 +38,7 @@
 // TODO: more docs
 
 use crate::{
-    executor::{self, host, storage_diff, trie_root_calculator, vm},
+    executor::{self, host, storage_diff, trace, trie_root_calculator},
     trie, util,
 };
 
@@ -85,6 +85,30 @@ pub struct Config<'a, TParams> {
     /// >           "off", `1` for "error", `2` for "warn", `3` for "info", `4` for "debug",
     /// >           and `5` for "trace".
     pub max_log_level: u32,
+
+    /// If `true`, all the host function calls, storage accesses, and log entries of the runtime
+    /// are recorded and reported in [`Success::trace`] or [`Error::trace`].
+    ///
+    /// > **Note**: Tracing has a significant cost in terms of memory and CPU. It is meant to be
+    /// >           used only for debugging purposes.
+    pub trace: bool,
+
+    /// Maximum number of WebAssembly instructions that the runtime is allowed to execute, or
+    /// `None` for no limit. If the limit is reached, the execution fails with
+    /// [`host::Error::OutOfFuel`].
+    ///
+    /// > **Note**: The limit is only enforced if the virtual machine has been created with
+    /// >           [`crate::executor::vm::ExecHint::Untrusted`].
+    pub fuel_budget: Option<u64>,
+
+    /// Maximum number of bytes that the pending changes of the call are allowed to occupy, or
+    /// `None` for no limit. If the limit is reached, the execution fails with
+    /// [`ErrorDetail::PendingChangesTooLarge`].
+    ///
+    /// The pending changes consist of the keys and values written to the storage and to the
+    /// off-chain storage, including the copies of these changes kept by storage transactions,
+    /// and of the logs printed by the runtime.
+    pub max_pending_changes_size: Option<usize>,
 }
 
 /// Start running the WebAssembly virtual machine.
@@ -101,7 +125,12 @@ pub fn run(
     Ok(Inner {
         vm: config
             .virtual_machine
-            .run_vectored(config.function_to_call, config.parameter)?
+            .run_vectored_with_fuel(
+                config.function_to_call,
+                config.parameter,
+                config.fuel_budget,
+            )
+            .map_err(|err| *err)?
             .into(),
         pending_storage_changes: PendingStorageChanges {
             trie_diffs: {
@@ -114,13 +143,21 @@ pub fn run(
                 Default::default(),
             ),
             tries_changes: BTreeMap::new(),
+            trie_diffs_size: 0,
         },
         state_trie_version,
         transactions_stack: Vec::new(),
         offchain_storage_changes: config.offchain_storage_changes,
+        offchain_storage_changes_size: 0,
+        max_pending_changes_size: config.max_pending_changes_size,
         root_calculation: None,
         logs: String::new(),
         max_log_level: config.max_log_level,
+        trace: if config.trace {
+            Some(trace::Trace::default())
+        } else {
+            None
+        },
     }
     .run())
 }
@@ -140,6 +177,8 @@ pub struct Success {
     pub offchain_storage_changes: hashbrown::HashMap<Vec<u8>, Option<Vec<u8>>, fnv::FnvBuildHasher>,
     /// Concatenation of all the log messages printed by the runtime.
     pub logs: String,
+    /// Trace of the execution. `Some` if and only if [`Config::trace`] was `true`.
+    pub trace: Option<trace::Trace>,
 }
 
 /// See [`Success::storage_changes`].
@@ -233,6 +272,16 @@ impl StorageChanges {
             })
     }
 
+    /// Returns `true` if the runtime call has modified at least one child trie.
+    pub fn has_child_tries_changes(&self) -> bool {
+        // Changes are ordered by child trie, and the main trie (`None`) comes first.
+        self.inner
+            .tries_changes
+            .keys()
+            .next_back()
+            .is_some_and(|(child_trie, _)| child_trie.is_some())
+    }
+
     /// Returns a diff of the main trie.
     // TODO: weird API, necessary to turn this object back to a value for Config::storage_changes
     pub fn into_main_trie_diff(mut self) -> storage_diff::TrieDiff {
@@ -381,6 +430,9 @@ pub struct Error {
     pub detail: ErrorDetail,
     /// Prototype of the virtual machine that was passed through [`Config::virtual_machine`].
     pub prototype: host::HostVmPrototype,
+    /// Trace of the execution up until the error. `Some` if and only if [`Config::trace`] was
+    /// `true`.
+    pub trace: Option<trace::Trace>,
 }
 
 /// See [`Error::detail`].
@@ -396,6 +448,11 @@ pub enum ErrorDetail {
     },
     /// Size of the logs generated by the runtime exceeds the limit.
     LogsTooLong,
+    /// The runtime has called an off-chain-worker-related host function while the call isn't
+    /// the execution of an off-chain worker. See [`OffchainContext::reject`].
+    ForbiddenHostCall,
+    /// Size of the pending changes of the call exceeds [`Config::max_pending_changes_size`].
+    PendingChangesTooLarge,
 }
 
 /// Current state of the execution.
@@ -412,6 +469,14 @@ pub enum RuntimeHostVm {
     NextKey(NextKey),
     /// Verifying whether a signature is correct is required in order to continue.
     SignatureVerification(SignatureVerification),
+    /// Obtaining the list of public keys of the keystore is required in order to continue.
+    KeystorePublicKeys(KeystorePublicKeys),
+    /// Generating a new key in the keystore is required in order to continue.
+    KeystoreGenerate(KeystoreGenerate),
+    /// Signing a message with a key of the keystore is required in order to continue.
+    SignRequest(SignRequest),
+    /// Off-chain-worker-related operation is required in order to continue.
+    Offchain(OffchainContext),
 }
 
 impl RuntimeHostVm {
@@ -424,6 +489,10 @@ impl RuntimeHostVm {
             RuntimeHostVm::ClosestDescendantMerkleValue(inner) => inner.inner.vm.into_prototype(),
             RuntimeHostVm::NextKey(inner) => inner.inner.vm.into_prototype(),
             RuntimeHostVm::SignatureVerification(inner) => inner.inner.vm.into_prototype(),
+            RuntimeHostVm::KeystorePublicKeys(inner) => inner.inner.vm.into_prototype(),
+            RuntimeHostVm::KeystoreGenerate(inner) => inner.inner.vm.into_prototype(),
+            RuntimeHostVm::SignRequest(inner) => inner.inner.vm.into_prototype(),
+            RuntimeHostVm::Offchain(inner) => inner.into_prototype(),
         }
     }
 }
@@ -456,6 +525,7 @@ impl StorageGet {
         match (&self.inner.vm, self.inner.root_calculation.as_ref()) {
             (host::HostVm::ExternalStorageGet(req), None) => Three::A(req.key()),
             (host::HostVm::ExternalStorageAppend(req), None) => Three::B(req.key()),
+            (host::HostVm::CallRuntimeVersion(_), None) => Three::C(b":heappages".to_vec()),
             (_, Some((_, trie_root_calculator::InProgress::StorageValue(value_request)))) => {
                 // TODO: optimize?
                 let key_nibbles = value_request.key().fold(Vec::new(), |mut a, b| {
@@ -495,6 +565,7 @@ impl StorageGet {
         match (&self.inner.vm, self.inner.root_calculation.as_ref()) {
             (host::HostVm::ExternalStorageGet(req), None) => req.child_trie().map(Three::A),
             (host::HostVm::ExternalStorageAppend(req), None) => req.child_trie().map(Three::B),
+            (host::HostVm::CallRuntimeVersion(_), None) => None,
             (_, Some((child_trie, trie_root_calculator::InProgress::StorageValue(_)))) => {
                 child_trie.as_ref().map(Three::C)
             }
@@ -519,25 +590,32 @@ impl StorageGet {
 
         match (self.inner.vm, self.inner.root_calculation.take()) {
             (host::HostVm::ExternalStorageGet(req), None) => {
+                if let Some(trace) = &mut self.inner.trace {
+                    trace.push_event(trace::EventDetail::StorageRead {
+                        child_trie: req.child_trie().map(|ct| ct.as_ref().to_vec()),
+                        key: req.key().as_ref().to_vec(),
+                        value: value.as_ref().map(|(v, _)| v.clone()),
+                    });
+                }
                 // TODO: should actually report the offset and max_size in the API
                 self.inner.vm = req.resume_full_value(value.as_ref().map(|(v, _)| &v[..]));
             }
             (host::HostVm::ExternalStorageAppend(req), None) => {
-                // TODO: could be less overhead?
-                let trie = self
-                    .inner
-                    .pending_storage_changes
-                    .trie_diffs
-                    .entry(req.child_trie().map(|ct| ct.as_ref().to_vec()))
-                    .or_insert(storage_diff::TrieDiff::empty());
-
                 // TODO: could be less overhead?
                 let mut value = value.map(|(v, _)| v).unwrap_or_default();
                 append_to_storage_value(&mut value, req.value().as_ref());
-                trie.diff_insert(req.key().as_ref().to_vec(), value, ());
+                self.inner.pending_storage_changes.diff_insert(
+                    req.child_trie().as_ref().map(|ct| ct.as_ref()),
+                    req.key().as_ref().to_vec(),
+                    Some(value),
+                );
 
                 self.inner.vm = req.resume();
             }
+            (host::HostVm::CallRuntimeVersion(req), None) => {
+                self.inner.vm =
+                    executor::resume_call_runtime_version(req, value.as_ref().map(|(v, _)| &v[..]));
+            }
             (vm, Some((trie, trie_root_calculator::InProgress::StorageValue(value_request)))) => {
                 self.inner.vm = vm;
                 self.inner.root_calculation = Some((
@@ -676,6 +754,13 @@ impl NextKey {
 
                 match search {
                     storage_diff::StorageNextKey::Found(k) => {
+                        if let Some(trace) = &mut self.inner.trace {
+                            trace.push_event(trace::EventDetail::StorageNextKey {
+                                child_trie: req.child_trie().map(|ct| ct.as_ref().to_vec()),
+                                key: req.key().as_ref().to_vec(),
+                                next_key: k.map(|k| k.to_vec()),
+                            });
+                        }
                         self.inner.vm = req.resume(k);
                     }
                     storage_diff::StorageNextKey::NextOf(next) => {
@@ -703,17 +788,22 @@ impl NextKey {
                         .max_keys_to_remove()
                         .map_or(false, |max| self.keys_removed_so_far >= max)
                     {
+                        if let Some(trace) = &mut self.inner.trace {
+                            trace.push_event(trace::EventDetail::StorageClearPrefix {
+                                child_trie: req.child_trie().map(|ct| ct.as_ref().to_vec()),
+                                prefix: req.prefix().as_ref().to_vec(),
+                                keys_removed: self.keys_removed_so_far,
+                                some_keys_remain: true,
+                            });
+                        }
                         self.inner.vm = req.resume(self.keys_removed_so_far, true);
                     } else {
                         // TODO: overhead
-                        let trie = self
-                            .inner
-                            .pending_storage_changes
-                            .trie_diffs
-                            .entry(req.child_trie().map(|ct| ct.as_ref().to_vec()))
-                            .or_insert(storage_diff::TrieDiff::empty());
-
-                        trie.diff_insert_erase(key.clone(), ());
+                        self.inner.pending_storage_changes.diff_insert(
+                            req.child_trie().as_ref().map(|ct| ct.as_ref()),
+                            key.clone(),
+                            None,
+                        );
                         self.keys_removed_so_far += 1;
                         self.key_overwrite = Some(key); // TODO: might be expensive if lots of keys
                         self.inner.vm = req.into();
@@ -721,6 +811,14 @@ impl NextKey {
                         return RuntimeHostVm::NextKey(self);
                     }
                 } else {
+                    if let Some(trace) = &mut self.inner.trace {
+                        trace.push_event(trace::EventDetail::StorageClearPrefix {
+                            child_trie: req.child_trie().map(|ct| ct.as_ref().to_vec()),
+                            prefix: req.prefix().as_ref().to_vec(),
+                            keys_removed: self.keys_removed_so_far,
+                            some_keys_remain: false,
+                        });
+                    }
                     self.inner.vm = req.resume(self.keys_removed_so_far, false);
                 }
             }
@@ -884,174 +982,952 @@ impl SignatureVerification {
     }
 }
 
-/// Implementation detail of the execution. Shared by all the variants of [`RuntimeHostVm`]
-/// other than [`RuntimeHostVm::Finished`].
-struct Inner {
-    /// Virtual machine running the call.
-    vm: host::HostVm,
+/// Obtaining the list of public keys of the keystore is required in order to continue.
+#[must_use]
+pub struct KeystorePublicKeys {
+    inner: Inner,
+}
 
-    /// Pending changes to the storage that this execution performs.
-    pending_storage_changes: PendingStorageChanges,
+impl KeystorePublicKeys {
+    /// Returns the algorithm of the keys to list.
+    pub fn algorithm(&self) -> host::KeyAlgorithm {
+        match self.inner.vm {
+            host::HostVm::KeystorePublicKeys(ref req) => req.algorithm(),
+            _ => unreachable!(),
+        }
+    }
 
-    /// Contains a copy of [`Inner::pending_storage_changes`] at the time when the transaction
-    /// started. When the storage transaction ends, either the entry is silently discarded (to
-    /// commit), or is written over [`Inner::pending_storage_changes`] (to rollback).
-    ///
-    /// Contains a `Vec` in case transactions are stacked.
-    transactions_stack: Vec<PendingStorageChanges>,
+    /// Returns the key type identifier, such as `b"beef"` or `b"babe"`.
+    pub fn key_type_id(&self) -> &[u8; 4] {
+        match self.inner.vm {
+            host::HostVm::KeystorePublicKeys(ref req) => req.key_type_id(),
+            _ => unreachable!(),
+        }
+    }
 
-    /// State trie version indicated by the runtime. All the storage changes that are performed
-    /// use this version.
-    state_trie_version: TrieEntryVersion,
+    /// Injects the list of public keys and resumes execution.
+    ///
+    /// If no keystore is available, pass an empty iterator.
+    ///
+    /// # Panic
+    ///
+    /// Panics if one of the public keys doesn't have a size equal to
+    /// [`host::KeyAlgorithm::public_key_size`].
+    ///
+    pub fn resume(
+        mut self,
+        public_keys: impl Iterator<Item = impl AsRef<[u8]> + Clone> + Clone,
+    ) -> RuntimeHostVm {
+        match self.inner.vm {
+            host::HostVm::KeystorePublicKeys(req) => self.inner.vm = req.resume(public_keys),
+            _ => unreachable!(),
+        }
 
-    /// Pending changes to the off-chain storage that this execution performs.
-    offchain_storage_changes: hashbrown::HashMap<Vec<u8>, Option<Vec<u8>>, fnv::FnvBuildHasher>,
+        self.inner.run()
+    }
+}
 
-    /// Trie root calculation in progress. Contains the trie whose root is being calculated
-    /// (`Some` for a child trie or `None` for the main trie) and the calculation state machine.
-    root_calculation: Option<(Option<Vec<u8>>, trie_root_calculator::InProgress)>,
+/// Generating a new key in the keystore is required in order to continue.
+#[must_use]
+pub struct KeystoreGenerate {
+    inner: Inner,
+}
 
-    /// Concatenation of all the log messages generated by the runtime.
-    logs: String,
+impl KeystoreGenerate {
+    /// Returns the algorithm of the key to generate.
+    pub fn algorithm(&self) -> host::KeyAlgorithm {
+        match self.inner.vm {
+            host::HostVm::KeystoreGenerate(ref req) => req.algorithm(),
+            _ => unreachable!(),
+        }
+    }
 
-    /// Value provided by [`Config::max_log_level`].
-    max_log_level: u32,
-}
+    /// Returns the key type identifier, such as `b"beef"` or `b"babe"`.
+    pub fn key_type_id(&self) -> &[u8; 4] {
+        match self.inner.vm {
+            host::HostVm::KeystoreGenerate(ref req) => req.key_type_id(),
+            _ => unreachable!(),
+        }
+    }
 
-/// See [`Inner::pending_storage_changes`].
-#[derive(Clone)]
-struct PendingStorageChanges {
-    /// For each trie, the values that have been written to it.
-    trie_diffs: hashbrown::HashMap<Option<Vec<u8>>, storage_diff::TrieDiff, fnv::FnvBuildHasher>,
+    /// Returns the secret URI the key must be derived from, or `None` if the key must be
+    /// randomly generated.
+    pub fn seed(&self) -> Option<&str> {
+        match self.inner.vm {
+            host::HostVm::KeystoreGenerate(ref req) => req.seed(),
+            _ => unreachable!(),
+        }
+    }
 
-    /// List of tries (`None` for the main trie and `Some` for child tries) whose root hash must
-    /// be recalculated (and for child tries stored into the main trie).
-    /// This is necessary in order to populate [`PendingStorageChanges::tries_changes`].
-    stale_child_tries_root_hashes: hashbrown::HashSet<Option<Vec<u8>>, fnv::FnvBuildHasher>,
+    /// Injects the public key of the newly-generated key and resumes execution.
+    ///
+    /// Pass `None` if the key couldn't be generated, for example because no keystore is
+    /// available. This makes the execution fail.
+    ///
+    /// # Panic
+    ///
+    /// Panics if the public key doesn't have a size equal to
+    /// [`host::KeyAlgorithm::public_key_size`].
+    ///
+    pub fn resume(mut self, public_key: Option<&[u8]>) -> RuntimeHostVm {
+        match self.inner.vm {
+            host::HostVm::KeystoreGenerate(req) => self.inner.vm = req.resume(public_key),
+            _ => unreachable!(),
+        }
 
-    /// Changes to the trie nodes of all the tries.
-    tries_changes: BTreeMap<(Option<Vec<u8>>, Vec<Nibble>), PendingStorageChangesTrieNode>,
+        self.inner.run()
+    }
 }
 
-/// See [`PendingStorageChanges::tries_changes`].
-#[derive(Clone)]
-enum PendingStorageChangesTrieNode {
-    Removed,
-    InsertUpdate {
-        new_merkle_value: Vec<u8>,
-        partial_key: Vec<Nibble>,
-        children_merkle_values: Box<[Option<Vec<u8>>; 16]>,
-    },
+/// Signing a message with a key of the keystore is required in order to continue.
+#[must_use]
+pub struct SignRequest {
+    inner: Inner,
 }
 
-/// Writing and reading keys the main trie under this prefix obeys special rules.
-const CHILD_STORAGE_SPECIAL_PREFIX: &[u8] = b":child_storage:";
-/// Writing and reading keys the main trie under this prefix obeys special rules.
-const DEFAULT_CHILD_STORAGE_SPECIAL_PREFIX: &[u8] = b":child_storage:default:";
+impl SignRequest {
+    /// Returns the algorithm of the key to sign with.
+    pub fn algorithm(&self) -> host::KeyAlgorithm {
+        match self.inner.vm {
+            host::HostVm::SignRequest(ref req) => req.algorithm(),
+            _ => unreachable!(),
+        }
+    }
 
-impl Inner {
-    /// Continues the execution.
-    fn run(mut self) -> RuntimeHostVm {
-        loop {
-            match self.root_calculation.take() {
-                None => {}
-                Some((trie, trie_root_calculator::InProgress::ClosestDescendant(calc_req))) => {
-                    self.root_calculation = Some((
-                        trie,
-                        trie_root_calculator::InProgress::ClosestDescendant(calc_req),
-                    ));
-                    return RuntimeHostVm::NextKey(NextKey {
-                        inner: self,
-                        key_overwrite: None,
-                        keys_removed_so_far: 0,
-                    });
-                }
-                Some((trie, trie_root_calculator::InProgress::StorageValue(calc_req))) => {
-                    if calc_req
-                        .key()
-                        .fold(0, |count, slice| count + slice.as_ref().len())
-                        % 2
-                        == 0
-                    {
-                        self.root_calculation = Some((
-                            trie,
-                            trie_root_calculator::InProgress::StorageValue(calc_req),
-                        ));
-                        return RuntimeHostVm::StorageGet(StorageGet { inner: self });
-                    } else {
-                        // If the number of nibbles in the key is uneven, we are sure that
-                        // there exists no storage value.
-                        self.root_calculation = Some((trie, calc_req.inject_value(None)));
-                        continue;
-                    }
-                }
-                Some((
-                    trie,
-                    trie_root_calculator::InProgress::ClosestDescendantMerkleValue(calc_req),
-                )) => {
-                    self.root_calculation = Some((
-                        trie,
-                        trie_root_calculator::InProgress::ClosestDescendantMerkleValue(calc_req),
-                    ));
-                    return RuntimeHostVm::ClosestDescendantMerkleValue(
-                        ClosestDescendantMerkleValue { inner: self },
-                    );
-                }
-                Some((trie, trie_root_calculator::InProgress::TrieNodeInsertUpdateEvent(ev))) => {
-                    self.pending_storage_changes.tries_changes.insert(
-                        (trie.clone(), ev.key_as_vec()),
-                        PendingStorageChangesTrieNode::InsertUpdate {
-                            new_merkle_value: ev.merkle_value().to_owned(),
-                            partial_key: ev.partial_key().to_owned(),
-                            children_merkle_values: TryFrom::try_from(
-                                ev.children_merkle_values()
-                                    .map(|mv| mv.map(|mv| mv.to_owned()))
-                                    .collect::<Vec<_>>()
-                                    .into_boxed_slice(),
-                            )
-                            .unwrap(),
-                        },
-                    );
+    /// Returns the key type identifier, such as `b"beef"` or `b"babe"`.
+    pub fn key_type_id(&self) -> &[u8; 4] {
+        match self.inner.vm {
+            host::HostVm::SignRequest(ref req) => req.key_type_id(),
+            _ => unreachable!(),
+        }
+    }
 
-                    self.root_calculation = Some((trie, ev.resume()));
-                    continue;
-                }
-                Some((trie, trie_root_calculator::InProgress::TrieNodeRemoveEvent(ev))) => {
-                    self.pending_storage_changes.tries_changes.insert(
-                        (trie.clone(), ev.key_as_vec()),
-                        PendingStorageChangesTrieNode::Removed,
-                    );
+    /// Returns the public key of the key to sign with.
+    ///
+    /// > **Note**: Be aware that this public key is untrusted input and might not be part of the
+    /// >           keystore.
+    pub fn public_key(&'_ self) -> impl AsRef<[u8]> + '_ {
+        match self.inner.vm {
+            host::HostVm::SignRequest(ref req) => req.public_key(),
+            _ => unreachable!(),
+        }
+    }
 
-                    self.root_calculation = Some((trie, ev.resume()));
-                    continue;
-                }
-                Some((trie, trie_root_calculator::InProgress::Finished { trie_root_hash })) => {
-                    self.pending_storage_changes
-                        .stale_child_tries_root_hashes
-                        .remove(&trie);
+    /// Returns the message to sign.
+    ///
+    /// If the algorithm is [`host::KeyAlgorithm::Ecdsa`], this is always the 32 bytes hash of
+    /// the message, and it must be signed as is without being hashed again.
+    pub fn message(&'_ self) -> impl AsRef<[u8]> + '_ {
+        match self.inner.vm {
+            host::HostVm::SignRequest(ref req) => req.message(),
+            _ => unreachable!(),
+        }
+    }
 
-                    // If we've finished calculating a child trie, update its entry in the
-                    // main trie.
-                    if let Some(child_trie) = &trie {
-                        let mut main_trie_key = Vec::with_capacity(
-                            DEFAULT_CHILD_STORAGE_SPECIAL_PREFIX.len() + child_trie.len(),
-                        );
-                        main_trie_key.extend_from_slice(DEFAULT_CHILD_STORAGE_SPECIAL_PREFIX);
-                        main_trie_key.extend_from_slice(child_trie);
+    /// Injects the signature and resumes execution.
+    ///
+    /// Pass `None` if the key isn't in the keystore, if no keystore is available, or if signing
+    /// has failed.
+    ///
+    /// # Panic
+    ///
+    /// Panics if the signature doesn't have a size equal to
+    /// [`host::KeyAlgorithm::signature_size`].
+    ///
+    pub fn resume(mut self, signature: Option<&[u8]>) -> RuntimeHostVm {
+        match self.inner.vm {
+            host::HostVm::SignRequest(req) => self.inner.vm = req.resume(signature),
+            _ => unreachable!(),
+        }
 
-                        if trie_root_hash != trie::EMPTY_TRIE_MERKLE_VALUE {
-                            self.pending_storage_changes
-                                .trie_diffs
-                                .entry(None)
-                                .or_default()
-                                .diff_insert(main_trie_key, trie_root_hash.to_vec(), ());
-                        } else {
-                            self.pending_storage_changes
-                                .trie_diffs
-                                .entry(None)
-                                .or_default()
-                                .diff_insert_erase(main_trie_key, ());
-                        }
+        self.inner.run()
+    }
+}
+
+/// Off-chain-worker-related operation is required in order to continue.
+///
+/// These operations can only happen if the runtime call is the execution of an off-chain
+/// worker. If that isn't the case, use [`OffchainContext::reject`].
+#[must_use]
+pub enum OffchainContext {
+    /// Loading a value from the off-chain storage is required in order to continue.
+    StorageGet(OffchainContextStorageGet),
+    /// Setting or removing a value of the off-chain storage is required in order to continue.
+    StorageSet(OffchainContextStorageSet),
+    /// Obtaining the current UNIX timestamp is required in order to continue.
+    Timestamp(OffchainContextTimestamp),
+    /// Waiting until a certain UNIX timestamp is required in order to continue.
+    SleepUntil(OffchainContextSleepUntil),
+    /// Generating a random seed is required in order to continue.
+    RandomSeed(OffchainContextRandomSeed),
+    /// Submitting a transaction to the transactions pool is required in order to continue.
+    SubmitTransaction(OffchainContextSubmitTransaction),
+    /// Indicating whether the local node is a validator is required in order to continue.
+    IsValidator(OffchainContextIsValidator),
+    /// Obtaining the identity and addresses of the local node is required in order to continue.
+    NetworkState(OffchainContextNetworkState),
+    /// Starting an HTTP request is required in order to continue.
+    HttpRequestStart(OffchainContextHttpRequestStart),
+    /// Adding a header to an HTTP request is required in order to continue.
+    HttpRequestAddHeader(OffchainContextHttpRequestAddHeader),
+    /// Writing a chunk of the body of an HTTP request is required in order to continue.
+    HttpRequestWriteBody(OffchainContextHttpRequestWriteBody),
+    /// Waiting for HTTP requests to have received a response is required in order to continue.
+    HttpResponseWait(OffchainContextHttpResponseWait),
+    /// Obtaining the headers of the response to an HTTP request is required in order to
+    /// continue.
+    HttpResponseHeaders(OffchainContextHttpResponseHeaders),
+    /// Reading a chunk of the body of the response to an HTTP request is required in order to
+    /// continue.
+    HttpResponseReadBody(OffchainContextHttpResponseReadBody),
+}
+
+impl OffchainContext {
+    /// Cancels execution of the virtual machine and returns back the prototype.
+    pub fn into_prototype(self) -> host::HostVmPrototype {
+        self.into_inner().vm.into_prototype()
+    }
+
+    /// Stops the execution with an [`ErrorDetail::ForbiddenHostCall`] error.
+    ///
+    /// Must be used when the runtime call isn't the execution of an off-chain worker, in which
+    /// case the runtime isn't allowed to call off-chain-worker-related host functions.
+    pub fn reject(self) -> RuntimeHostVm {
+        let inner = self.into_inner();
+        RuntimeHostVm::Finished(Err(Error {
+            detail: ErrorDetail::ForbiddenHostCall,
+            prototype: inner.vm.into_prototype(),
+            trace: inner.trace,
+        }))
+    }
+
+    fn into_inner(self) -> Inner {
+        match self {
+            OffchainContext::StorageGet(inner) => inner.inner,
+            OffchainContext::StorageSet(inner) => inner.inner,
+            OffchainContext::Timestamp(inner) => inner.inner,
+            OffchainContext::SleepUntil(inner) => inner.inner,
+            OffchainContext::RandomSeed(inner) => inner.inner,
+            OffchainContext::SubmitTransaction(inner) => inner.inner,
+            OffchainContext::IsValidator(inner) => inner.inner,
+            OffchainContext::NetworkState(inner) => inner.inner,
+            OffchainContext::HttpRequestStart(inner) => inner.inner,
+            OffchainContext::HttpRequestAddHeader(inner) => inner.inner,
+            OffchainContext::HttpRequestWriteBody(inner) => inner.inner,
+            OffchainContext::HttpResponseWait(inner) => inner.inner,
+            OffchainContext::HttpResponseHeaders(inner) => inner.inner,
+            OffchainContext::HttpResponseReadBody(inner) => inner.inner,
+        }
+    }
+}
+
+/// Loading a value from the off-chain storage is required in order to continue.
+#[must_use]
+pub struct OffchainContextStorageGet {
+    inner: Inner,
+}
+
+impl OffchainContextStorageGet {
+    /// Returns the kind of storage to load the value from.
+    pub fn kind(&self) -> host::OffchainStorageKind {
+        match self.inner.vm {
+            host::HostVm::OffchainStorageGet(ref req) => req.kind(),
+            _ => unreachable!(),
+        }
+    }
+
+    /// Returns the key whose value must be loaded.
+    pub fn key(&'_ self) -> impl AsRef<[u8]> + '_ {
+        match self.inner.vm {
+            host::HostVm::OffchainStorageGet(ref req) => req.key(),
+            _ => unreachable!(),
+        }
+    }
+
+    /// Injects the corresponding storage value and resumes execution.
+    pub fn inject_value(mut self, value: Option<&[u8]>) -> RuntimeHostVm {
+        match self.inner.vm {
+            host::HostVm::OffchainStorageGet(req) => self.inner.vm = req.resume(value),
+            _ => unreachable!(),
+        }
+
+        self.inner.run()
+    }
+}
+
+/// Setting or removing a value of the off-chain storage is required in order to continue.
+#[must_use]
+pub struct OffchainContextStorageSet {
+    inner: Inner,
+}
+
+impl OffchainContextStorageSet {
+    /// Returns the kind of storage to modify.
+    pub fn kind(&self) -> host::OffchainStorageKind {
+        match self.inner.vm {
+            host::HostVm::OffchainStorageSet(ref req) => req.kind(),
+            _ => unreachable!(),
+        }
+    }
+
+    /// Returns the key whose value must be set.
+    pub fn key(&'_ self) -> impl AsRef<[u8]> + '_ {
+        match self.inner.vm {
+            host::HostVm::OffchainStorageSet(ref req) => req.key(),
+            _ => unreachable!(),
+        }
+    }
+
+    /// Returns the value to set. If `None`, the key must be removed from the storage.
+    pub fn value(&'_ self) -> Option<impl AsRef<[u8]> + '_> {
+        match self.inner.vm {
+            host::HostVm::OffchainStorageSet(ref req) => req.value(),
+            _ => unreachable!(),
+        }
+    }
+
+    /// Returns the value that the current value must be compared against.
+    ///
+    /// See [`host::OffchainStorageSet::old_value`].
+    pub fn old_value(&self) -> Option<Option<&[u8]>> {
+        match self.inner.vm {
+            host::HostVm::OffchainStorageSet(ref req) => req.old_value(),
+            _ => unreachable!(),
+        }
+    }
+
+    /// Resumes execution after having set the value. Must indicate whether the value has been
+    /// modified, in other words `false` if the comparison against
+    /// [`OffchainContextStorageSet::old_value`] has failed.
+    pub fn resume(mut self, replaced: bool) -> RuntimeHostVm {
+        match self.inner.vm {
+            host::HostVm::OffchainStorageSet(req) => self.inner.vm = req.resume(replaced),
+            _ => unreachable!(),
+        }
+
+        self.inner.run()
+    }
+}
+
+/// Obtaining the current UNIX timestamp is required in order to continue.
+#[must_use]
+pub struct OffchainContextTimestamp {
+    inner: Inner,
+}
+
+impl OffchainContextTimestamp {
+    /// Injects the number of milliseconds since the UNIX epoch and resumes execution.
+    pub fn inject_timestamp(mut self, milliseconds_since_unix_epoch: u64) -> RuntimeHostVm {
+        match self.inner.vm {
+            host::HostVm::OffchainTimestamp(req) => {
+                self.inner.vm = req.resume(milliseconds_since_unix_epoch)
+            }
+            _ => unreachable!(),
+        }
+
+        self.inner.run()
+    }
+}
+
+/// Waiting until a certain UNIX timestamp is required in order to continue.
+#[must_use]
+pub struct OffchainContextSleepUntil {
+    inner: Inner,
+}
+
+impl OffchainContextSleepUntil {
+    /// Returns the number of milliseconds since the UNIX epoch until which to wait.
+    pub fn deadline(&self) -> u64 {
+        match self.inner.vm {
+            host::HostVm::OffchainSleepUntil(ref req) => req.deadline(),
+            _ => unreachable!(),
+        }
+    }
+
+    /// Resumes execution after having waited until [`OffchainContextSleepUntil::deadline`].
+    pub fn resume(mut self) -> RuntimeHostVm {
+        match self.inner.vm {
+            host::HostVm::OffchainSleepUntil(req) => self.inner.vm = req.resume(),
+            _ => unreachable!(),
+        }
+
+        self.inner.run()
+    }
+}
+
+/// Generating a random seed is required in order to continue.
+#[must_use]
+pub struct OffchainContextRandomSeed {
+    inner: Inner,
+}
+
+impl OffchainContextRandomSeed {
+    /// Injects a randomly-generated seed and resumes execution.
+    pub fn inject_random_seed(mut self, seed: &[u8; 32]) -> RuntimeHostVm {
+        match self.inner.vm {
+            host::HostVm::OffchainRandomSeed(req) => self.inner.vm = req.resume(seed),
+            _ => unreachable!(),
+        }
+
+        self.inner.run()
+    }
+}
+
+/// Submitting a transaction to the transactions pool is required in order to continue.
+#[must_use]
+pub struct OffchainContextSubmitTransaction {
+    inner: Inner,
+}
+
+impl OffchainContextSubmitTransaction {
+    /// Returns the SCALE-encoded transaction to submit.
+    pub fn transaction(&'_ self) -> impl AsRef<[u8]> + '_ {
+        match self.inner.vm {
+            host::HostVm::OffchainSubmitTransaction(ref req) => req.transaction(),
+            _ => unreachable!(),
+        }
+    }
+
+    /// Resumes execution after having submitted the transaction. Must indicate whether the
+    /// transaction has been accepted by the transactions pool.
+    pub fn resume(mut self, success: bool) -> RuntimeHostVm {
+        match self.inner.vm {
+            host::HostVm::OffchainSubmitTransaction(req) => self.inner.vm = req.resume(success),
+            _ => unreachable!(),
+        }
+
+        self.inner.run()
+    }
+}
+
+/// Indicating whether the local node is a validator is required in order to continue.
+#[must_use]
+pub struct OffchainContextIsValidator {
+    inner: Inner,
+}
+
+impl OffchainContextIsValidator {
+    /// Injects whether the local node is a validator and resumes execution.
+    pub fn resume(mut self, is_validator: bool) -> RuntimeHostVm {
+        match self.inner.vm {
+            host::HostVm::OffchainIsValidator(req) => self.inner.vm = req.resume(is_validator),
+            _ => unreachable!(),
+        }
+
+        self.inner.run()
+    }
+}
+
+/// Obtaining the identity and addresses of the local node is required in order to continue.
+#[must_use]
+pub struct OffchainContextNetworkState {
+    inner: Inner,
+}
+
+impl OffchainContextNetworkState {
+    /// Injects the network state and resumes execution.
+    ///
+    /// See [`host::OffchainNetworkState::resume`].
+    pub fn resume(
+        mut self,
+        network_state: Option<(&[u8], impl Iterator<Item = impl AsRef<[u8]>>)>,
+    ) -> RuntimeHostVm {
+        match self.inner.vm {
+            host::HostVm::OffchainNetworkState(req) => self.inner.vm = req.resume(network_state),
+            _ => unreachable!(),
+        }
+
+        self.inner.run()
+    }
+}
+
+/// Starting an HTTP request is required in order to continue.
+#[must_use]
+pub struct OffchainContextHttpRequestStart {
+    inner: Inner,
+}
+
+impl OffchainContextHttpRequestStart {
+    /// Returns the HTTP method of the request, such as `GET` or `POST`.
+    pub fn method(&self) -> &str {
+        match self.inner.vm {
+            host::HostVm::OffchainHttpRequestStart(ref req) => req.method(),
+            _ => unreachable!(),
+        }
+    }
+
+    /// Returns the URI to send the request to.
+    pub fn uri(&self) -> &str {
+        match self.inner.vm {
+            host::HostVm::OffchainHttpRequestStart(ref req) => req.uri(),
+            _ => unreachable!(),
+        }
+    }
+
+    /// Injects the identifier of the newly-started request, or `None` if the request couldn't
+    /// be started, and resumes execution.
+    pub fn resume(mut self, request_id: Option<u16>) -> RuntimeHostVm {
+        match self.inner.vm {
+            host::HostVm::OffchainHttpRequestStart(req) => self.inner.vm = req.resume(request_id),
+            _ => unreachable!(),
+        }
+
+        self.inner.run()
+    }
+}
+
+/// Adding a header to an HTTP request is required in order to continue.
+#[must_use]
+pub struct OffchainContextHttpRequestAddHeader {
+    inner: Inner,
+}
+
+impl OffchainContextHttpRequestAddHeader {
+    /// Returns the identifier of the request.
+    ///
+    /// > **Note**: Be aware that this identifier is untrusted input and might not correspond to
+    /// >           any request.
+    pub fn request_id(&self) -> u16 {
+        match self.inner.vm {
+            host::HostVm::OffchainHttpRequestAddHeader(ref req) => req.request_id(),
+            _ => unreachable!(),
+        }
+    }
+
+    /// Returns the name of the header to add.
+    pub fn name(&self) -> &str {
+        match self.inner.vm {
+            host::HostVm::OffchainHttpRequestAddHeader(ref req) => req.name(),
+            _ => unreachable!(),
+        }
+    }
+
+    /// Returns the value of the header to add.
+    pub fn value(&self) -> &str {
+        match self.inner.vm {
+            host::HostVm::OffchainHttpRequestAddHeader(ref req) => req.value(),
+            _ => unreachable!(),
+        }
+    }
+
+    /// Resumes execution after having added the header. Must indicate whether the header has
+    /// been successfully added.
+    pub fn resume(mut self, success: bool) -> RuntimeHostVm {
+        match self.inner.vm {
+            host::HostVm::OffchainHttpRequestAddHeader(req) => self.inner.vm = req.resume(success),
+            _ => unreachable!(),
+        }
+
+        self.inner.run()
+    }
+}
+
+/// Writing a chunk of the body of an HTTP request is required in order to continue.
+#[must_use]
+pub struct OffchainContextHttpRequestWriteBody {
+    inner: Inner,
+}
+
+impl OffchainContextHttpRequestWriteBody {
+    /// Returns the identifier of the request.
+    ///
+    /// > **Note**: Be aware that this identifier is untrusted input and might not correspond to
+    /// >           any request.
+    pub fn request_id(&self) -> u16 {
+        match self.inner.vm {
+            host::HostVm::OffchainHttpRequestWriteBody(ref req) => req.request_id(),
+            _ => unreachable!(),
+        }
+    }
+
+    /// Returns the chunk of body to write. An empty chunk indicates that the body is complete.
+    pub fn chunk(&'_ self) -> impl AsRef<[u8]> + '_ {
+        match self.inner.vm {
+            host::HostVm::OffchainHttpRequestWriteBody(ref req) => req.chunk(),
+            _ => unreachable!(),
+        }
+    }
+
+    /// Returns the number of milliseconds since the UNIX epoch after which the operation must
+    /// be aborted. `None` if there is no deadline.
+    pub fn deadline(&self) -> Option<u64> {
+        match self.inner.vm {
+            host::HostVm::OffchainHttpRequestWriteBody(ref req) => req.deadline(),
+            _ => unreachable!(),
+        }
+    }
+
+    /// Resumes execution after having written the chunk.
+    pub fn resume(mut self, result: Result<(), host::HttpError>) -> RuntimeHostVm {
+        match self.inner.vm {
+            host::HostVm::OffchainHttpRequestWriteBody(req) => self.inner.vm = req.resume(result),
+            _ => unreachable!(),
+        }
+
+        self.inner.run()
+    }
+}
+
+/// Waiting for HTTP requests to have received a response is required in order to continue.
+#[must_use]
+pub struct OffchainContextHttpResponseWait {
+    inner: Inner,
+}
+
+impl OffchainContextHttpResponseWait {
+    /// Returns the identifiers of the requests whose response to wait for.
+    ///
+    /// > **Note**: Be aware that these identifiers are untrusted input and might not correspond
+    /// >           to any request.
+    pub fn request_ids(&self) -> &[u16] {
+        match self.inner.vm {
+            host::HostVm::OffchainHttpResponseWait(ref req) => req.request_ids(),
+            _ => unreachable!(),
+        }
+    }
+
+    /// Returns the number of milliseconds since the UNIX epoch after which to stop waiting.
+    /// `None` if there is no deadline.
+    pub fn deadline(&self) -> Option<u64> {
+        match self.inner.vm {
+            host::HostVm::OffchainHttpResponseWait(ref req) => req.deadline(),
+            _ => unreachable!(),
+        }
+    }
+
+    /// Injects the status of each request and resumes execution.
+    ///
+    /// # Panic
+    ///
+    /// Panics if the number of statuses doesn't match the number of request IDs.
+    ///
+    pub fn resume(mut self, statuses: &[host::HttpRequestStatus]) -> RuntimeHostVm {
+        match self.inner.vm {
+            host::HostVm::OffchainHttpResponseWait(req) => self.inner.vm = req.resume(statuses),
+            _ => unreachable!(),
+        }
+
+        self.inner.run()
+    }
+}
+
+/// Obtaining the headers of the response to an HTTP request is required in order to continue.
+#[must_use]
+pub struct OffchainContextHttpResponseHeaders {
+    inner: Inner,
+}
+
+impl OffchainContextHttpResponseHeaders {
+    /// Returns the identifier of the request.
+    ///
+    /// > **Note**: Be aware that this identifier is untrusted input and might not correspond to
+    /// >           any request.
+    pub fn request_id(&self) -> u16 {
+        match self.inner.vm {
+            host::HostVm::OffchainHttpResponseHeaders(ref req) => req.request_id(),
+            _ => unreachable!(),
+        }
+    }
+
+    /// Injects the list of headers of the response and resumes execution.
+    ///
+    /// The list must be empty if the request ID is invalid or if no response has been received
+    /// yet.
+    pub fn resume(
+        mut self,
+        headers: impl Iterator<Item = (impl AsRef<[u8]>, impl AsRef<[u8]>)>,
+    ) -> RuntimeHostVm {
+        match self.inner.vm {
+            host::HostVm::OffchainHttpResponseHeaders(req) => self.inner.vm = req.resume(headers),
+            _ => unreachable!(),
+        }
+
+        self.inner.run()
+    }
+}
+
+/// Reading a chunk of the body of the response to an HTTP request is required in order to
+/// continue.
+#[must_use]
+pub struct OffchainContextHttpResponseReadBody {
+    inner: Inner,
+}
+
+impl OffchainContextHttpResponseReadBody {
+    /// Returns the identifier of the request.
+    ///
+    /// > **Note**: Be aware that this identifier is untrusted input and might not correspond to
+    /// >           any request.
+    pub fn request_id(&self) -> u16 {
+        match self.inner.vm {
+            host::HostVm::OffchainHttpResponseReadBody(ref req) => req.request_id(),
+            _ => unreachable!(),
+        }
+    }
+
+    /// Returns the maximum size of the chunk that can be injected.
+    pub fn max_size(&self) -> usize {
+        match self.inner.vm {
+            host::HostVm::OffchainHttpResponseReadBody(ref req) => req.max_size(),
+            _ => unreachable!(),
+        }
+    }
+
+    /// Returns the number of milliseconds since the UNIX epoch after which the operation must
+    /// be aborted. `None` if there is no deadline.
+    pub fn deadline(&self) -> Option<u64> {
+        match self.inner.vm {
+            host::HostVm::OffchainHttpResponseReadBody(ref req) => req.deadline(),
+            _ => unreachable!(),
+        }
+    }
+
+    /// Injects the next chunk of the body and resumes execution. An empty chunk indicates that
+    /// the end of the body has been reached.
+    ///
+    /// # Panic
+    ///
+    /// Panics if the chunk is longer than [`OffchainContextHttpResponseReadBody::max_size`].
+    ///
+    pub fn resume(mut self, result: Result<&[u8], host::HttpError>) -> RuntimeHostVm {
+        match self.inner.vm {
+            host::HostVm::OffchainHttpResponseReadBody(req) => self.inner.vm = req.resume(result),
+            _ => unreachable!(),
+        }
+
+        self.inner.run()
+    }
+}
+
+/// Implementation detail of the execution. Shared by all the variants of [`RuntimeHostVm`]
+/// other than [`RuntimeHostVm::Finished`].
+struct Inner {
+    /// Virtual machine running the call.
+    vm: host::HostVm,
+
+    /// Pending changes to the storage that this execution performs.
+    pending_storage_changes: PendingStorageChanges,
+
+    /// Contains a copy of [`Inner::pending_storage_changes`] at the time when the transaction
+    /// started. When the storage transaction ends, either the entry is silently discarded (to
+    /// commit), or is written over [`Inner::pending_storage_changes`] (to rollback).
+    ///
+    /// Contains a `Vec` in case transactions are stacked.
+    transactions_stack: Vec<PendingStorageChanges>,
+
+    /// State trie version indicated by the runtime. All the storage changes that are performed
+    /// use this version.
+    state_trie_version: TrieEntryVersion,
+
+    /// Pending changes to the off-chain storage that this execution performs.
+    offchain_storage_changes: hashbrown::HashMap<Vec<u8>, Option<Vec<u8>>, fnv::FnvBuildHasher>,
+
+    /// Number of bytes of the keys and values written in [`Inner::offchain_storage_changes`].
+    offchain_storage_changes_size: usize,
+
+    /// Value provided by [`Config::max_pending_changes_size`].
+    max_pending_changes_size: Option<usize>,
+
+    /// Trie root calculation in progress. Contains the trie whose root is being calculated
+    /// (`Some` for a child trie or `None` for the main trie) and the calculation state machine.
+    root_calculation: Option<(Option<Vec<u8>>, trie_root_calculator::InProgress)>,
+
+    /// Concatenation of all the log messages generated by the runtime.
+    logs: String,
+
+    /// Value provided by [`Config::max_log_level`].
+    max_log_level: u32,
+
+    /// Trace of the execution. `Some` if and only if [`Config::trace`] was `true`.
+    trace: Option<trace::Trace>,
+}
+
+/// See [`Inner::pending_storage_changes`].
+#[derive(Clone)]
+struct PendingStorageChanges {
+    /// For each trie, the values that have been written to it.
+    trie_diffs: hashbrown::HashMap<Option<Vec<u8>>, storage_diff::TrieDiff, fnv::FnvBuildHasher>,
+
+    /// List of tries (`None` for the main trie and `Some` for child tries) whose root hash must
+    /// be recalculated (and for child tries stored into the main trie).
+    /// This is necessary in order to populate [`PendingStorageChanges::tries_changes`].
+    stale_child_tries_root_hashes: hashbrown::HashSet<Option<Vec<u8>>, fnv::FnvBuildHasher>,
+
+    /// Changes to the trie nodes of all the tries.
+    tries_changes: BTreeMap<(Option<Vec<u8>>, Vec<Nibble>), PendingStorageChangesTrieNode>,
+
+    /// Number of bytes of the keys and values found in [`PendingStorageChanges::trie_diffs`].
+    /// Kept up to date by [`PendingStorageChanges::diff_insert`].
+    trie_diffs_size: usize,
+}
+
+impl PendingStorageChanges {
+    /// Writes the given value at the given key of the diff of the given trie (`None` for the
+    /// main trie), or erases the value if `None`, and updates
+    /// [`PendingStorageChanges::trie_diffs_size`] accordingly.
+    fn diff_insert(&mut self, child_trie: Option<&[u8]>, key: Vec<u8>, value: Option<Vec<u8>>) {
+        let key_len = key.len();
+        let new_entry_size = key_len.saturating_add(value.as_ref().map_or(0, |v| v.len()));
+
+        let trie = self
+            .trie_diffs
+            .entry(child_trie.map(|ct| ct.to_vec()))
+            .or_default();
+        let previous = match value {
+            Some(value) => trie.diff_insert(key, value, ()),
+            None => trie.diff_insert_erase(key, ()),
+        };
+
+        // The size of the entry that is replaced, if any, is no longer part of the diff.
+        let previous_entry_size = previous.map_or(0, |(value, ())| {
+            key_len.saturating_add(value.map_or(0, |v| v.len()))
+        });
+
+        self.trie_diffs_size = self
+            .trie_diffs_size
+            .saturating_sub(previous_entry_size)
+            .saturating_add(new_entry_size);
+    }
+}
+
+/// See [`PendingStorageChanges::tries_changes`].
+#[derive(Clone)]
+enum PendingStorageChangesTrieNode {
+    Removed,
+    InsertUpdate {
+        new_merkle_value: Vec<u8>,
+        partial_key: Vec<Nibble>,
+        children_merkle_values: Box<[Option<Vec<u8>>; 16]>,
+    },
+}
+
+/// Writing and reading keys the main trie under this prefix obeys special rules.
+const CHILD_STORAGE_SPECIAL_PREFIX: &[u8] = b":child_storage:";
+
+impl Inner {
+    /// Returns the number of bytes compared against [`Config::max_pending_changes_size`].
+    fn pending_changes_size(&self) -> usize {
+        self.transactions_stack
+            .iter()
+            .fold(self.pending_storage_changes.trie_diffs_size, |size, tx| {
+                size.saturating_add(tx.trie_diffs_size)
+            })
+            .saturating_add(self.offchain_storage_changes_size)
+            .saturating_add(self.logs.len())
+    }
+
+    /// Continues the execution.
+    fn run(mut self) -> RuntimeHostVm {
+        loop {
+            if self
+                .max_pending_changes_size
+                .is_some_and(|max| self.pending_changes_size() > max)
+            {
+                return RuntimeHostVm::Finished(Err(Error {
+                    detail: ErrorDetail::PendingChangesTooLarge,
+                    prototype: self.vm.into_prototype(),
+                    trace: self.trace,
+                }));
+            }
+
+            match self.root_calculation.take() {
+                None => {}
+                Some((trie, trie_root_calculator::InProgress::ClosestDescendant(calc_req))) => {
+                    self.root_calculation = Some((
+                        trie,
+                        trie_root_calculator::InProgress::ClosestDescendant(calc_req),
+                    ));
+                    return RuntimeHostVm::NextKey(NextKey {
+                        inner: self,
+                        key_overwrite: None,
+                        keys_removed_so_far: 0,
+                    });
+                }
+                Some((trie, trie_root_calculator::InProgress::StorageValue(calc_req))) => {
+                    if calc_req
+                        .key()
+                        .fold(0, |count, slice| count + slice.as_ref().len())
+                        % 2
+                        == 0
+                    {
+                        self.root_calculation = Some((
+                            trie,
+                            trie_root_calculator::InProgress::StorageValue(calc_req),
+                        ));
+                        return RuntimeHostVm::StorageGet(StorageGet { inner: self });
+                    } else {
+                        // If the number of nibbles in the key is uneven, we are sure that
+                        // there exists no storage value.
+                        self.root_calculation = Some((trie, calc_req.inject_value(None)));
+                        continue;
+                    }
+                }
+                Some((
+                    trie,
+                    trie_root_calculator::InProgress::ClosestDescendantMerkleValue(calc_req),
+                )) => {
+                    self.root_calculation = Some((
+                        trie,
+                        trie_root_calculator::InProgress::ClosestDescendantMerkleValue(calc_req),
+                    ));
+                    return RuntimeHostVm::ClosestDescendantMerkleValue(
+                        ClosestDescendantMerkleValue { inner: self },
+                    );
+                }
+                Some((trie, trie_root_calculator::InProgress::TrieNodeInsertUpdateEvent(ev))) => {
+                    self.pending_storage_changes.tries_changes.insert(
+                        (trie.clone(), ev.key_as_vec()),
+                        PendingStorageChangesTrieNode::InsertUpdate {
+                            new_merkle_value: ev.merkle_value().to_owned(),
+                            partial_key: ev.partial_key().to_owned(),
+                            children_merkle_values: TryFrom::try_from(
+                                ev.children_merkle_values()
+                                    .map(|mv| mv.map(|mv| mv.to_owned()))
+                                    .collect::<Vec<_>>()
+                                    .into_boxed_slice(),
+                            )
+                            .unwrap(),
+                        },
+                    );
+
+                    self.root_calculation = Some((trie, ev.resume()));
+                    continue;
+                }
+                Some((trie, trie_root_calculator::InProgress::TrieNodeRemoveEvent(ev))) => {
+                    self.pending_storage_changes.tries_changes.insert(
+                        (trie.clone(), ev.key_as_vec()),
+                        PendingStorageChangesTrieNode::Removed,
+                    );
+
+                    self.root_calculation = Some((trie, ev.resume()));
+                    continue;
+                }
+                Some((trie, trie_root_calculator::InProgress::Finished { trie_root_hash })) => {
+                    self.pending_storage_changes
+                        .stale_child_tries_root_hashes
+                        .remove(&trie);
+
+                    // If we've finished calculating a child trie, update its entry in the
+                    // main trie.
+                    if let Some(child_trie) = &trie {
+                        let mut main_trie_key =
+                            Vec::with_capacity(trie::CHILD_STORAGE_PREFIX.len() + child_trie.len());
+                        main_trie_key.extend_from_slice(trie::CHILD_STORAGE_PREFIX);
+                        main_trie_key.extend_from_slice(child_trie);
+
+                        if trie_root_hash != trie::EMPTY_TRIE_MERKLE_VALUE {
+                            self.pending_storage_changes
+                                .trie_diffs
+                                .entry(None)
+                                .or_default()
+                                .diff_insert(main_trie_key, trie_root_hash.to_vec(), ());
+                        } else {
+                            self.pending_storage_changes
+                                .trie_diffs
+                                .entry(None)
+                                .or_default()
+                                .diff_insert_erase(main_trie_key, ());
+                        }
 
                         self.pending_storage_changes
                             .stale_child_tries_root_hashes
@@ -1068,6 +1944,12 @@ impl Inner {
                             _ => false,
                         };
                         if trie_match {
+                            if let Some(trace) = &mut self.trace {
+                                trace.push_event(trace::EventDetail::StorageRoot {
+                                    child_trie: req.child_trie().map(|ct| ct.as_ref().to_vec()),
+                                    hash: trie_root_hash,
+                                });
+                            }
                             self.vm = req.resume(&trie_root_hash);
                         } else {
                             self.vm = host::HostVm::ExternalStorageRoot(req);
@@ -1162,7 +2044,12 @@ impl Inner {
             }
 
             match self.vm {
-                host::HostVm::ReadyToRun(r) => self.vm = r.run(),
+                host::HostVm::ReadyToRun(r) => {
+                    self.vm = match &mut self.trace {
+                        Some(trace) => r.run_traced(&mut trace.host_function_calls),
+                        None => r.run(),
+                    };
+                }
 
                 host::HostVm::Error { error, prototype } => {
                     return RuntimeHostVm::Finished(Err(Error {
@@ -1171,6 +2058,7 @@ impl Inner {
                             logs: self.logs,
                         },
                         prototype,
+                        trace: self.trace,
                     }));
                 }
 
@@ -1189,6 +2077,7 @@ impl Inner {
                         state_trie_version: self.state_trie_version,
                         offchain_storage_changes: self.offchain_storage_changes,
                         logs: self.logs,
+                        trace: self.trace,
                     }));
                 }
 
@@ -1200,6 +2089,13 @@ impl Inner {
                         .and_then(|diff| diff.diff_get(req.key().as_ref()));
 
                     if let Some((value_in_diff, _)) = diff_search {
+                        if let Some(trace) = &mut self.trace {
+                            trace.push_event(trace::EventDetail::StorageRead {
+                                child_trie: req.child_trie().map(|ct| ct.as_ref().to_vec()),
+                                key: req.key().as_ref().to_vec(),
+                                value: value_in_diff.map(|v| v.to_vec()),
+                            });
+                        }
                         self.vm = req.resume_full_value(value_in_diff);
                     } else {
                         self.vm = req.into();
@@ -1208,6 +2104,14 @@ impl Inner {
                 }
 
                 host::HostVm::ExternalStorageSet(req) => {
+                    if let Some(trace) = &mut self.trace {
+                        trace.push_event(trace::EventDetail::StorageWrite {
+                            child_trie: req.child_trie().map(|ct| ct.as_ref().to_vec()),
+                            key: req.key().as_ref().to_vec(),
+                            value: req.value().map(|v| v.as_ref().to_vec()),
+                        });
+                    }
+
                     // Any attempt at writing a key that starts with `CHILD_STORAGE_SPECIAL_PREFIX`
                     // is silently ignored, as per spec.
                     if req.child_trie().is_none()
@@ -1222,22 +2126,24 @@ impl Inner {
                         .stale_child_tries_root_hashes
                         .insert(req.child_trie().map(|ct| ct.as_ref().to_owned()));
 
-                    let trie = self
-                        .pending_storage_changes
-                        .trie_diffs
-                        .entry(req.child_trie().map(|ct| ct.as_ref().to_vec()))
-                        .or_insert(storage_diff::TrieDiff::empty());
-
-                    if let Some(value) = req.value() {
-                        trie.diff_insert(req.key().as_ref(), value.as_ref(), ());
-                    } else {
-                        trie.diff_insert_erase(req.key().as_ref(), ());
-                    }
+                    self.pending_storage_changes.diff_insert(
+                        req.child_trie().as_ref().map(|ct| ct.as_ref()),
+                        req.key().as_ref().to_vec(),
+                        req.value().map(|v| v.as_ref().to_vec()),
+                    );
 
                     self.vm = req.resume()
                 }
 
                 host::HostVm::ExternalStorageAppend(req) => {
+                    if let Some(trace) = &mut self.trace {
+                        trace.push_event(trace::EventDetail::StorageAppend {
+                            child_trie: req.child_trie().map(|ct| ct.as_ref().to_vec()),
+                            key: req.key().as_ref().to_vec(),
+                            value: req.value().as_ref().to_vec(),
+                        });
+                    }
+
                     // Any attempt at writing a key that starts with `CHILD_STORAGE_SPECIAL_PREFIX`
                     // is silently ignored, as per spec.
                     if req.child_trie().is_none()
@@ -1263,7 +2169,11 @@ impl Inner {
                     if let Some(current_value) = current_value {
                         let mut current_value = current_value.unwrap_or_default().to_vec();
                         append_to_storage_value(&mut current_value, req.value().as_ref());
-                        trie.diff_insert(req.key().as_ref().to_vec(), current_value, ());
+                        self.pending_storage_changes.diff_insert(
+                            req.child_trie().as_ref().map(|ct| ct.as_ref()),
+                            req.key().as_ref().to_vec(),
+                            Some(current_value),
+                        );
                         self.vm = req.resume();
                     } else {
                         self.vm = req.into();
@@ -1277,6 +2187,14 @@ impl Inner {
                     if req.child_trie().is_none()
                         && CHILD_STORAGE_SPECIAL_PREFIX.starts_with(req.prefix().as_ref())
                     {
+                        if let Some(trace) = &mut self.trace {
+                            trace.push_event(trace::EventDetail::StorageClearPrefix {
+                                child_trie: None,
+                                prefix: req.prefix().as_ref().to_vec(),
+                                keys_removed: 0,
+                                some_keys_remain: false,
+                            });
+                        }
                         self.vm = req.resume(0, false); // TODO: what's the correct return value for `some_keys_remain`?
                         continue;
                     }
@@ -1311,6 +2229,10 @@ impl Inner {
                 }
 
                 host::HostVm::ExternalOffchainStorageSet(req) => {
+                    self.offchain_storage_changes_size = self
+                        .offchain_storage_changes_size
+                        .saturating_add(req.key().as_ref().len())
+                        .saturating_add(req.value().map_or(0, |v| v.as_ref().len()));
                     self.offchain_storage_changes.insert(
                         req.key().as_ref().to_vec(),
                         req.value().map(|v| v.as_ref().to_owned()),
@@ -1325,32 +2247,141 @@ impl Inner {
                     });
                 }
 
+                host::HostVm::KeystorePublicKeys(req) => {
+                    self.vm = req.into();
+                    return RuntimeHostVm::KeystorePublicKeys(KeystorePublicKeys { inner: self });
+                }
+
+                host::HostVm::KeystoreGenerate(req) => {
+                    self.vm = req.into();
+                    return RuntimeHostVm::KeystoreGenerate(KeystoreGenerate { inner: self });
+                }
+
+                host::HostVm::SignRequest(req) => {
+                    self.vm = req.into();
+                    return RuntimeHostVm::SignRequest(SignRequest { inner: self });
+                }
+
+                host::HostVm::OffchainStorageGet(req) => {
+                    self.vm = req.into();
+                    return RuntimeHostVm::Offchain(OffchainContext::StorageGet(OffchainContextStorageGet {
+                        inner: self,
+                    }));
+                }
+
+                host::HostVm::OffchainStorageSet(req) => {
+                    self.vm = req.into();
+                    return RuntimeHostVm::Offchain(OffchainContext::StorageSet(OffchainContextStorageSet {
+                        inner: self,
+                    }));
+                }
+
+                host::HostVm::OffchainTimestamp(req) => {
+                    self.vm = req.into();
+                    return RuntimeHostVm::Offchain(OffchainContext::Timestamp(OffchainContextTimestamp {
+                        inner: self,
+                    }));
+                }
+
+                host::HostVm::OffchainSleepUntil(req) => {
+                    self.vm = req.into();
+                    return RuntimeHostVm::Offchain(OffchainContext::SleepUntil(OffchainContextSleepUntil {
+                        inner: self,
+                    }));
+                }
+
+                host::HostVm::OffchainRandomSeed(req) => {
+                    self.vm = req.into();
+                    return RuntimeHostVm::Offchain(OffchainContext::RandomSeed(OffchainContextRandomSeed {
+                        inner: self,
+                    }));
+                }
+
+                host::HostVm::OffchainSubmitTransaction(req) => {
+                    self.vm = req.into();
+                    return RuntimeHostVm::Offchain(OffchainContext::SubmitTransaction(OffchainContextSubmitTransaction {
+                        inner: self,
+                    }));
+                }
+
+                host::HostVm::OffchainIsValidator(req) => {
+                    self.vm = req.into();
+                    return RuntimeHostVm::Offchain(OffchainContext::IsValidator(OffchainContextIsValidator {
+                        inner: self,
+                    }));
+                }
+
+                host::HostVm::OffchainNetworkState(req) => {
+                    self.vm = req.into();
+                    return RuntimeHostVm::Offchain(OffchainContext::NetworkState(OffchainContextNetworkState {
+                        inner: self,
+                    }));
+                }
+
+                host::HostVm::OffchainHttpRequestStart(req) => {
+                    self.vm = req.into();
+                    return RuntimeHostVm::Offchain(OffchainContext::HttpRequestStart(OffchainContextHttpRequestStart {
+                        inner: self,
+                    }));
+                }
+
+                host::HostVm::OffchainHttpRequestAddHeader(req) => {
+                    self.vm = req.into();
+                    return RuntimeHostVm::Offchain(OffchainContext::HttpRequestAddHeader(OffchainContextHttpRequestAddHeader {
+                        inner: self,
+                    }));
+                }
+
+                host::HostVm::OffchainHttpRequestWriteBody(req) => {
+                    self.vm = req.into();
+                    return RuntimeHostVm::Offchain(OffchainContext::HttpRequestWriteBody(OffchainContextHttpRequestWriteBody {
+                        inner: self,
+                    }));
+                }
+
+                host::HostVm::OffchainHttpResponseWait(req) => {
+                    self.vm = req.into();
+                    return RuntimeHostVm::Offchain(OffchainContext::HttpResponseWait(OffchainContextHttpResponseWait {
+                        inner: self,
+                    }));
+                }
+
+                host::HostVm::OffchainHttpResponseHeaders(req) => {
+                    self.vm = req.into();
+                    return RuntimeHostVm::Offchain(OffchainContext::HttpResponseHeaders(OffchainContextHttpResponseHeaders {
+                        inner: self,
+                    }));
+                }
+
+                host::HostVm::OffchainHttpResponseReadBody(req) => {
+                    self.vm = req.into();
+                    return RuntimeHostVm::Offchain(OffchainContext::HttpResponseReadBody(OffchainContextHttpResponseReadBody {
+                        inner: self,
+                    }));
+                }
+
                 host::HostVm::CallRuntimeVersion(req) => {
-                    // TODO: make the user execute this ; see https://github.com/paritytech/smoldot/issues/144
-                    // The code below compiles the provided WebAssembly runtime code, which is a
-                    // relatively expensive operation (in the order of milliseconds).
-                    // While it could be tempting to use a system cache, this function is expected
-                    // to be called only right before runtime upgrades. Considering that runtime
-                    // upgrades are quite uncommon and that a caching system is rather non-trivial
-                    // to set up, the approach of recompiling every single time is preferred here.
-                    // TODO: number of heap pages?! we use the default here, but not sure whether that's correct or if we have to take the current heap pages
-                    let vm_prototype = match host::HostVmPrototype::new(host::Config {
-                        module: req.wasm_code(),
-                        heap_pages: executor::DEFAULT_HEAP_PAGES,
-                        exec_hint: vm::ExecHint::Oneshot,
-                        allow_unresolved_imports: false, // TODO: what is a correct value here?
-                    }) {
-                        Ok(w) => w,
-                        Err(_) => {
-                            self.vm = req.resume(Err(()));
-                            continue;
-                        }
-                    };
+                    // The runtime being queried is compiled with the number of heap pages found
+                    // in the storage, which might have been modified by the current execution.
+                    let diff_search = self
+                        .pending_storage_changes
+                        .trie_diffs
+                        .get(&None)
+                        .and_then(|diff| diff.diff_get(b":heappages"));
 
-                    self.vm = req.resume(Ok(vm_prototype.runtime_version().as_ref()));
+                    if let Some((heap_pages_in_diff, _)) = diff_search {
+                        self.vm = executor::resume_call_runtime_version(req, heap_pages_in_diff);
+                    } else {
+                        self.vm = req.into();
+                        return RuntimeHostVm::StorageGet(StorageGet { inner: self });
+                    }
                 }
 
                 host::HostVm::StartStorageTransaction(tx) => {
+                    if let Some(trace) = &mut self.trace {
+                        trace.push_event(trace::EventDetail::StorageTransactionStart);
+                    }
+
                     // TODO: this cloning is very expensive, but providing a more optimized implementation is very complicated
                     self.transactions_stack
                         .push(self.pending_storage_changes.clone());
@@ -1367,6 +2398,10 @@ impl Inner {
                         self.pending_storage_changes = rollback_diff;
                     }
 
+                    if let Some(trace) = &mut self.trace {
+                        trace.push_event(trace::EventDetail::StorageTransactionEnd { rollback });
+                    }
+
                     self.vm = resume.resume();
                 }
 
@@ -1403,9 +2438,21 @@ impl Inner {
                             return RuntimeHostVm::Finished(Err(Error {
                                 detail: ErrorDetail::LogsTooLong,
                                 prototype: host::HostVm::LogEmit(req).into_prototype(),
+                                trace: self.trace,
                             }));
                         }
                     }
+                    if let Some(trace) = &mut self.trace {
+                        let mut message = req.to_string();
+                        if message.ends_with('\n') {
+                            message.pop();
+                        }
+                        trace.push_event(trace::EventDetail::Log {
+                            level: req.log_level(),
+                            target: req.target(),
+                            message,
+                        });
+                    }
                     self.vm = req.resume();
                 }
             }
@@ -26,7 +26,10 @@
 use core::{iter, ops};
 
 use super::{run, Config, RuntimeHostVm};
-use crate::{executor::host, trie};
+use crate::{
+    executor::{host, trace},
+    trie,
+};
 use alloc::collections::BTreeMap;
 
 #[test]
@@ -74,6 +77,7 @@ fn execute_blocks() {
             host::HostVmPrototype::new(host::Config {
                 module: code,
                 heap_pages,
+                compilation_cache: None,
                 exec_hint: crate::executor::vm::ExecHint::Oneshot,
                 allow_unresolved_imports: false,
             })
@@ -95,6 +99,9 @@ fn execute_blocks() {
             virtual_machine,
             function_to_call: "Core_execute_block",
             max_log_level: 3,
+            trace: true,
+            fuel_budget: None,
+            max_pending_changes_size: None,
             offchain_storage_changes: Default::default(),
             storage_main_trie_changes: Default::default(),
             parameter: {
@@ -110,11 +117,40 @@ fn execute_blocks() {
 
         loop {
             match execution {
-                RuntimeHostVm::Finished(Ok(_)) => break, // Test successful!
+                RuntimeHostVm::Finished(Ok(success)) => {
+                    // Make sure that the storage accesses have been traced and are attributed
+                    // to the appropriate host functions.
+                    let trace = success.trace.unwrap();
+                    assert!(trace.events.iter().any(|ev| matches!(
+                        ev.detail,
+                        trace::EventDetail::StorageRead { .. }
+                    )));
+                    assert!(trace.events.iter().any(|ev| matches!(
+                        ev.detail,
+                        trace::EventDetail::StorageWrite { .. }
+                    )));
+                    for event in &trace.events {
+                        let function_name =
+                            trace.host_function_calls[event.host_function_call].function_name;
+                        match event.detail {
+                            trace::EventDetail::Log { .. } => {
+                                assert!(function_name.starts_with("ext_logging_"))
+                            }
+                            _ => assert!(function_name.contains("storage")),
+                        }
+                    }
+                    break; // Test successful!
+                }
                 RuntimeHostVm::Finished(Err(err)) => {
                     panic!("Error during test #{}: {:?}", test_num, err)
                 }
                 RuntimeHostVm::SignatureVerification(sig) => execution = sig.verify_and_resume(),
+                RuntimeHostVm::KeystorePublicKeys(req) => {
+                    execution = req.resume(iter::empty::<&[u8]>())
+                }
+                RuntimeHostVm::KeystoreGenerate(req) => execution = req.resume(None),
+                RuntimeHostVm::SignRequest(req) => execution = req.resume(None),
+                RuntimeHostVm::Offchain(ctx) => execution = ctx.reject(),
                 RuntimeHostVm::ClosestDescendantMerkleValue(req) => {
                     execution = req.resume_unknown()
                 }
@@ -184,6 +220,96 @@ fn execute_blocks() {
     }
 }
 
+#[test]
+fn pending_changes_size_limit() {
+    let virtual_machine = host::HostVmPrototype::new(host::Config {
+        module: &include_bytes!("../host/westend-runtime-v9300.wasm")[..],
+        heap_pages: crate::executor::DEFAULT_HEAP_PAGES,
+        compilation_cache: None,
+        exec_hint: crate::executor::vm::ExecHint::Oneshot,
+        allow_unresolved_imports: false,
+    })
+    .unwrap();
+
+    let header = crate::header::HeaderRef {
+        parent_hash: &[0; 32],
+        number: 1,
+        state_root: &[0; 32],
+        extrinsics_root: &[0; 32],
+        digest: crate::header::DigestRef::empty(),
+    }
+    .scale_encoding_vec(4);
+
+    // Initializing a block writes to the storage, which immediately exceeds the limit.
+    let mut execution = run(Config {
+        virtual_machine,
+        function_to_call: "Core_initialize_block",
+        max_log_level: 0,
+        trace: false,
+        fuel_budget: None,
+        max_pending_changes_size: Some(0),
+        offchain_storage_changes: Default::default(),
+        storage_main_trie_changes: Default::default(),
+        parameter: iter::once(&header),
+    })
+    .unwrap();
+
+    loop {
+        match execution {
+            RuntimeHostVm::Finished(Err(err)) => {
+                assert!(matches!(
+                    err.detail,
+                    super::ErrorDetail::PendingChangesTooLarge
+                ));
+                break;
+            }
+            RuntimeHostVm::Finished(Ok(_)) => panic!(),
+            RuntimeHostVm::StorageGet(get) => {
+                execution = get.inject_value(None::<(iter::Empty<&[u8]>, _)>)
+            }
+            RuntimeHostVm::NextKey(req) => execution = req.inject_key(None::<iter::Empty<_>>),
+            RuntimeHostVm::ClosestDescendantMerkleValue(req) => execution = req.resume_unknown(),
+            RuntimeHostVm::SignatureVerification(sig) => execution = sig.verify_and_resume(),
+            RuntimeHostVm::KeystorePublicKeys(req) => {
+                execution = req.resume(iter::empty::<&[u8]>())
+            }
+            RuntimeHostVm::KeystoreGenerate(req) => execution = req.resume(None),
+            RuntimeHostVm::SignRequest(req) => execution = req.resume(None),
+            RuntimeHostVm::Offchain(ctx) => execution = ctx.reject(),
+        }
+    }
+}
+
+#[test]
+fn pending_changes_size_repeated_appends() {
+    let mut changes = super::PendingStorageChanges {
+        trie_diffs: Default::default(),
+        stale_child_tries_root_hashes: Default::default(),
+        tries_changes: BTreeMap::new(),
+        trie_diffs_size: 0,
+    };
+
+    // Appending many times to the same key, similar to what `System::Events` does, must only
+    // account for the final size of the value rather than for all its intermediary states.
+    let key = b"events".to_vec();
+    let mut value = Vec::new();
+    for _ in 0..10000 {
+        super::append_to_storage_value(&mut value, &[0xaa; 32]);
+        changes.diff_insert(None, key.clone(), Some(value.clone()));
+    }
+    assert_eq!(changes.trie_diffs_size, key.len() + value.len());
+
+    // Overwriting or erasing an entry replaces its size.
+    changes.diff_insert(None, key.clone(), Some(vec![1, 2, 3]));
+    assert_eq!(changes.trie_diffs_size, key.len() + 3);
+    changes.diff_insert(None, key.clone(), None);
+    assert_eq!(changes.trie_diffs_size, key.len());
+
+    // Entries of different tries are accounted for separately.
+    changes.diff_insert(Some(b"child"), key.clone(), Some(vec![0; 10]));
+    assert_eq!(changes.trie_diffs_size, 2 * key.len() + 10);
+}
+
 // Serde structs used to decode the test fixtures.
 
 #[derive(serde::Deserialize)]
@@ -0,0 +1,146 @@
+// Smoldot
+// Copyright (C) 2023  Pierre Krieger
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Trace of what a runtime call has done.
+//!
+//! Tracing is opt-in and is enabled through the `trace` field of the configuration of
+//! [`super::runtime_host::run`] and [`super::read_only_runtime_host::run`]. When enabled, the
+//! trace is available after the execution has finished, no matter whether it has succeeded
+//! or failed.
+//!
+//! A trace consists of the list of all the host functions that the runtime has called, and of
+//! a list of higher-level events (such as storage accesses and log entries) each attached to the
+//! host function call that triggered it.
+
+use super::host;
+
+use alloc::{string::String, vec::Vec};
+
+/// Trace of a runtime call.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Trace {
+    /// List of all the host functions called by the runtime, in chronological order.
+    pub host_function_calls: Vec<host::HostFunctionCall>,
+
+    /// List of events that happened during the call, in chronological order.
+    pub events: Vec<Event>,
+}
+
+impl Trace {
+    /// Pushes a new event at the end of [`Trace::events`], attached to the latest host function
+    /// call.
+    pub(super) fn push_event(&mut self, detail: EventDetail) {
+        self.events.push(Event {
+            host_function_call: self.host_function_calls.len().saturating_sub(1),
+            detail,
+        });
+    }
+}
+
+/// See [`Trace::events`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Event {
+    /// Index within [`Trace::host_function_calls`] of the host function call that has led to
+    /// this event.
+    pub host_function_call: usize,
+
+    /// What happened.
+    pub detail: EventDetail,
+}
+
+/// See [`Event::detail`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EventDetail {
+    /// The runtime has read a storage value.
+    StorageRead {
+        /// Child trie the value was read from, or `None` for the main trie.
+        child_trie: Option<Vec<u8>>,
+        /// Key that was read.
+        key: Vec<u8>,
+        /// Value that was read, or `None` if there wasn't any value.
+        value: Option<Vec<u8>>,
+    },
+
+    /// The runtime has written or erased a storage value.
+    StorageWrite {
+        /// Child trie the value was written to, or `None` for the main trie.
+        child_trie: Option<Vec<u8>>,
+        /// Key that was written.
+        key: Vec<u8>,
+        /// New value, or `None` if the value was erased.
+        value: Option<Vec<u8>>,
+    },
+
+    /// The runtime has appended an item to a storage value.
+    StorageAppend {
+        /// Child trie of the value, or `None` for the main trie.
+        child_trie: Option<Vec<u8>>,
+        /// Key of the value.
+        key: Vec<u8>,
+        /// Item that was appended to the value.
+        value: Vec<u8>,
+    },
+
+    /// The runtime has erased all the storage values whose key starts with a certain prefix.
+    StorageClearPrefix {
+        /// Child trie of the values, or `None` for the main trie.
+        child_trie: Option<Vec<u8>>,
+        /// Prefix of the keys that were erased.
+        prefix: Vec<u8>,
+        /// Number of keys that were erased.
+        keys_removed: u32,
+        /// `true` if some keys with the prefix remain because of the limit passed by the runtime.
+        some_keys_remain: bool,
+    },
+
+    /// The runtime has requested the key that follows another one.
+    StorageNextKey {
+        /// Child trie of the keys, or `None` for the main trie.
+        child_trie: Option<Vec<u8>>,
+        /// Key passed by the runtime.
+        key: Vec<u8>,
+        /// Key that follows [`EventDetail::StorageNextKey::key`], if any.
+        next_key: Option<Vec<u8>>,
+    },
+
+    /// The runtime has requested the root hash of a trie.
+    StorageRoot {
+        /// Child trie whose root was requested, or `None` for the main trie.
+        child_trie: Option<Vec<u8>>,
+        /// Root hash returned to the runtime.
+        hash: [u8; 32],
+    },
+
+    /// The runtime has started a storage transaction.
+    StorageTransactionStart,
+
+    /// The runtime has ended a storage transaction.
+    StorageTransactionEnd {
+        /// `true` if the changes performed during the transaction have been reverted.
+        rollback: bool,
+    },
+
+    /// The runtime has emitted a log entry.
+    Log {
+        /// Log level indicated by the runtime, if any.
+        level: Option<u32>,
+        /// Target of the log entry indicated by the runtime, if any.
+        target: Option<String>,
+        /// Message of the log entry.
+        message: String,
+    },
+}
@@ -93,12 +93,42 @@ pub struct Config<'a> {
     /// Hint about how to execute the WebAssembly code.
     pub exec_hint: ExecHint,
 
+    /// Cache where to load the compiled module from, and where to store it after compilation.
+    ///
+    /// Only used if the WebAssembly code is compiled to machine code, which depends on
+    /// [`Config::exec_hint`] and on the platform. Ignored otherwise.
+    pub compilation_cache: Option<&'a dyn CompilationCache>,
+
     /// Called for each import that the module has. It must assign a number to each import, or
     /// return an error if the import can't be resolved. When the VM calls one of these functions,
     /// this number will be returned back in order for the user to know how to handle the call.
     pub symbols: &'a mut dyn FnMut(&str, &str, &Signature) -> Result<usize, ()>,
 }
 
+/// Storage for the compiled form of WebAssembly modules, in order to avoid compiling the same
+/// module multiple times.
+///
+/// Entries are identified by a 32 bytes key derived from the WebAssembly bytecode, the version
+/// of the compiler, and its configuration. Entries are never invalidated, as a key always
+/// corresponds to the same compiled module.
+///
+/// # Safety
+///
+/// The content returned by [`CompilationCache::load`] is executed as machine code without any
+/// verification. Implementations must guarantee that [`CompilationCache::load`] only ever
+/// returns data that was previously passed to [`CompilationCache::store`] with the same key.
+pub unsafe trait CompilationCache {
+    /// Returns the data that was stored under the given key, or `None` if there is no such
+    /// data.
+    fn load(&self, key: &[u8; 32]) -> Option<Vec<u8>>;
+
+    /// Stores the given data under the given key.
+    ///
+    /// Failing to store the data isn't considered as an error, and implementations are
+    /// encouraged to silently discard it instead.
+    fn store(&self, key: &[u8; 32], compiled: &[u8]);
+}
+
 /// Virtual machine ready to start executing a function.
 ///
 /// > **Note**: This struct implements `Clone`. Cloning a [`VirtualMachinePrototype`] allocates
@@ -145,9 +175,14 @@ impl VirtualMachinePrototype {
                     ),
                     feature = "wasmtime"
                 ))]
-                ExecHint::CompileAheadOfTime => VirtualMachinePrototypeInner::Jit(
-                    jit::JitPrototype::new(config.module_bytes, config.symbols)?,
-                ),
+                ExecHint::CompileAheadOfTime => {
+                    VirtualMachinePrototypeInner::Jit(jit::JitPrototype::new(
+                        config.module_bytes,
+                        false,
+                        config.compilation_cache,
+                        config.symbols,
+                    )?)
+                }
                 #[cfg(not(all(
                     any(
                         all(
@@ -160,12 +195,24 @@ impl VirtualMachinePrototype {
                     feature = "wasmtime"
                 )))]
                 ExecHint::CompileAheadOfTime => VirtualMachinePrototypeInner::Interpreter(
-                    interpreter::InterpreterPrototype::new(config.module_bytes, config.symbols)?,
+                    interpreter::InterpreterPrototype::new(
+                        config.module_bytes,
+                        false,
+                        config.symbols,
+                    )?,
                 ),
-                ExecHint::Oneshot | ExecHint::Untrusted | ExecHint::ForceWasmi => {
+                ExecHint::Oneshot => VirtualMachinePrototypeInner::Interpreter(
+                    interpreter::InterpreterPrototype::new(
+                        config.module_bytes,
+                        false,
+                        config.symbols,
+                    )?,
+                ),
+                ExecHint::Untrusted | ExecHint::ForceWasmi => {
                     VirtualMachinePrototypeInner::Interpreter(
                         interpreter::InterpreterPrototype::new(
                             config.module_bytes,
+                            true,
                             config.symbols,
                         )?,
                     )
@@ -182,9 +229,14 @@ impl VirtualMachinePrototype {
                     ),
                     feature = "wasmtime"
                 ))]
-                ExecHint::ForceWasmtime => VirtualMachinePrototypeInner::Jit(
-                    jit::JitPrototype::new(config.module_bytes, config.symbols)?,
-                ),
+                ExecHint::ForceWasmtime => {
+                    VirtualMachinePrototypeInner::Jit(jit::JitPrototype::new(
+                        config.module_bytes,
+                        true,
+                        config.compilation_cache,
+                        config.symbols,
+                    )?)
+                }
             },
         })
     }
@@ -435,6 +487,33 @@ impl Prepare {
         }
     }
 
+    /// Sets the maximum amount of fuel that the execution is allowed to consume. Roughly, each
+    /// WebAssembly instruction executed consumes one unit of fuel. Once the fuel runs out,
+    /// [`VirtualMachine::run`] returns [`RunErr::OutOfFuel`].
+    ///
+    /// If this method isn't called, the amount of fuel is unlimited.
+    ///
+    /// Fuel is only metered if the virtual machine has been created with
+    /// [`ExecHint::Untrusted`], [`ExecHint::ForceWasmi`], or `ExecHint::ForceWasmtime`. For the
+    /// other hints, calling this method has no effect.
+    pub fn set_fuel(&mut self, fuel: u64) {
+        match &mut self.inner {
+            #[cfg(all(
+                any(
+                    all(
+                        target_arch = "x86_64",
+                        any(target_os = "windows", target_os = "linux", target_os = "macos")
+                    ),
+                    all(target_arch = "aarch64", target_os = "linux"),
+                    all(target_arch = "s390x", target_os = "linux")
+                ),
+                feature = "wasmtime"
+            ))]
+            PrepareInner::Jit(inner) => inner.set_fuel(fuel),
+            PrepareInner::Interpreter(inner) => inner.set_fuel(fuel),
+        }
+    }
+
     /// Turns this prototype into an actual virtual machine. This requires choosing which function
     /// to execute.
     pub fn start(
@@ -718,14 +797,17 @@ pub enum ExecHint {
     ///
     /// > **Note**: This isn't a hard requirement but a hint.
     Oneshot,
-    /// The WebAssembly code running through this VM is untrusted.
+    /// The WebAssembly code running through this VM is untrusted. The code is interpreted.
+    ///
+    /// The number of instructions executed is metered, making it possible to put an upper bound
+    /// on the execution time with [`Prepare::set_fuel`].
     Untrusted,
 
-    /// Forces using the `wasmi` backend.
+    /// Forces using the `wasmi` backend, with fuel metering enabled.
     ///
     /// This variant is useful for testing purposes.
     ForceWasmi,
-    /// Forces using the `wasmtime` backend.
+    /// Forces using the `wasmtime` backend, with fuel metering enabled.
     ///
     /// This variant is useful for testing purposes.
     #[cfg(all(
@@ -1237,6 +1319,10 @@ pub enum RunErr {
     /// The state machine is poisoned.
     #[display(fmt = "State machine is poisoned")]
     Poisoned,
+    /// The execution has consumed all the fuel passed to [`Prepare::set_fuel`]. The state machine
+    /// is now poisoned.
+    #[display(fmt = "Execution has run out of fuel")]
+    OutOfFuel,
     /// Passed a wrong value back.
     #[display(fmt = "Expected value of type {expected:?} but got {obtained:?} instead")]
     BadValueTy {
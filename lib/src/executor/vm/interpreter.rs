@@ -43,6 +43,9 @@ pub struct InterpreterPrototype {
 struct BaseComponents {
     module: Arc<wasmi::Module>,
 
+    /// `true` if the module has been compiled with fuel metering enabled.
+    fuel_metering: bool,
+
     /// For each import of the module, either `None` if not a function, or `Some` containing the
     /// `usize` of that function.
     resolved_imports: Vec<Option<usize>>,
@@ -52,6 +55,7 @@ impl InterpreterPrototype {
     /// See [`super::VirtualMachinePrototype::new`].
     pub fn new(
         module_bytes: &[u8],
+        fuel_metering: bool,
         symbols: &mut dyn FnMut(&str, &str, &Signature) -> Result<usize, ()>,
     ) -> Result<Self, NewErr> {
         let engine = {
@@ -67,6 +71,8 @@ impl InterpreterPrototype {
             config.wasm_saturating_float_to_int(false);
             config.wasm_tail_call(false);
 
+            config.consume_fuel(fuel_metering);
+
             wasmi::Engine::new(&config)
         };
 
@@ -106,6 +112,7 @@ impl InterpreterPrototype {
 
         Self::from_base_components(BaseComponents {
             module: Arc::new(module),
+            fuel_metering,
             resolved_imports,
         })
     }
@@ -228,7 +235,10 @@ impl InterpreterPrototype {
 
     /// See [`super::VirtualMachinePrototype::prepare`].
     pub fn prepare(self) -> Prepare {
-        Prepare { inner: self }
+        Prepare {
+            inner: self,
+            fuel: None,
+        }
     }
 }
 
@@ -241,6 +251,7 @@ impl Clone for InterpreterPrototype {
         // acceptable reason to panic.
         InterpreterPrototype::from_base_components(BaseComponents {
             module: self.base_components.module.clone(),
+            fuel_metering: self.base_components.fuel_metering,
             resolved_imports: self.base_components.resolved_imports.clone(),
         })
         .unwrap()
@@ -256,6 +267,9 @@ impl fmt::Debug for InterpreterPrototype {
 /// See [`super::Prepare`].
 pub struct Prepare {
     inner: InterpreterPrototype,
+
+    /// Value passed to [`Prepare::set_fuel`]. `None` if unlimited.
+    fuel: Option<u64>,
 }
 
 impl Prepare {
@@ -338,9 +352,14 @@ impl Prepare {
         Ok(())
     }
 
+    /// See [`super::Prepare::set_fuel`].
+    pub fn set_fuel(&mut self, fuel: u64) {
+        self.fuel = Some(fuel);
+    }
+
     /// See [`super::Prepare::start`].
     pub fn start(
-        self,
+        mut self,
         function_name: &str,
         params: &[WasmValue],
     ) -> Result<Interpreter, (StartErr, InterpreterPrototype)> {
@@ -374,6 +393,15 @@ impl Prepare {
             None => return Err((StartErr::FunctionNotFound, self.inner)),
         };
 
+        // The store of a prototype never has any fuel, as a new store is created every time the
+        // prototype is rebuilt.
+        if self.inner.base_components.fuel_metering {
+            self.inner
+                .store
+                .add_fuel(self.fuel.unwrap_or(u64::MAX))
+                .unwrap();
+        }
+
         let dummy_output_value = {
             let func_to_call_ty = func_to_call.ty(&self.inner.store);
             let list = func_to_call_ty.results();
@@ -526,6 +554,11 @@ impl Interpreter {
                 self.execution = Some(Execution::Started(next));
                 Ok(outcome)
             }
+            Err(wasmi::Error::Trap(trap))
+                if matches!(trap.trap_code(), Some(wasmi::core::TrapCode::OutOfFuel)) =>
+            {
+                Err(RunErr::OutOfFuel)
+            }
             Err(err) => Ok(ExecOutcome::Finished {
                 return_value: Err(Trap(err.to_string())),
             }),
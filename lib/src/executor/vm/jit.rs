@@ -18,8 +18,8 @@
 //! Implements the API documented [in the parent module](..).
 
 use super::{
-    ExecOutcome, GlobalValueErr, HeapPages, NewErr, OutOfBoundsError, RunErr, Signature, StartErr,
-    Trap, ValueType, WasmValue,
+    CompilationCache, ExecOutcome, GlobalValueErr, HeapPages, NewErr, OutOfBoundsError, RunErr,
+    Signature, StartErr, Trap, ValueType, WasmValue,
 };
 
 use alloc::{boxed::Box, sync::Arc, vec::Vec};
@@ -56,6 +56,9 @@ pub struct JitPrototype {
 struct BaseComponents {
     module: wasmtime::Module,
 
+    /// `true` if the module has been compiled with fuel metering enabled.
+    fuel_metering: bool,
+
     /// For each import of the module, either `None` if not a function, or `Some` containing the
     /// `usize` of that function.
     resolved_imports: Vec<Option<usize>>,
@@ -65,6 +68,8 @@ impl JitPrototype {
     /// See [`super::VirtualMachinePrototype::new`].
     pub fn new(
         module_bytes: &[u8],
+        fuel_metering: bool,
+        compilation_cache: Option<&dyn CompilationCache>,
         symbols: &mut dyn FnMut(&str, &str, &Signature) -> Result<usize, ()>,
     ) -> Result<Self, NewErr> {
         let mut config = wasmtime::Config::new();
@@ -89,11 +94,44 @@ impl JitPrototype {
         config.wasm_multi_memory(false);
         config.wasm_memory64(false);
 
+        config.consume_fuel(fuel_metering);
+
         let engine =
             wasmtime::Engine::new(&config).map_err(|err| NewErr::InvalidWasm(err.to_string()))?;
 
-        let module = wasmtime::Module::from_binary(&engine, module_bytes)
-            .map_err(|err| NewErr::InvalidWasm(err.to_string()))?;
+        let module = if let Some(compilation_cache) = compilation_cache {
+            // The key of the cache must change whenever the output of the compilation might
+            // change, which is the case if the code, the version of `wasmtime`, or its
+            // configuration change.
+            let cache_key = {
+                let mut hasher = Blake2Hasher(blake2_rfc::blake2b::Blake2b::new(32));
+                hasher.0.update(module_bytes);
+                core::hash::Hash::hash(&engine.precompile_compatibility_hash(), &mut hasher);
+                <[u8; 32]>::try_from(hasher.0.finalize().as_bytes()).unwrap()
+            };
+
+            // A failure to deserialize is silently ignored, and the module compiled again.
+            // This can legitimately happen if the cached data was for example truncated.
+            // SAFETY: the implementation of `CompilationCache` guarantees that the data
+            // has previously been produced by `Module::serialize` with the same key.
+            let cached = compilation_cache.load(&cache_key).and_then(|compiled| {
+                unsafe { wasmtime::Module::deserialize(&engine, compiled) }.ok()
+            });
+
+            if let Some(module) = cached {
+                module
+            } else {
+                let module = wasmtime::Module::from_binary(&engine, module_bytes)
+                    .map_err(|err| NewErr::InvalidWasm(err.to_string()))?;
+                if let Ok(compiled) = module.serialize() {
+                    compilation_cache.store(&cache_key, &compiled);
+                }
+                module
+            }
+        } else {
+            wasmtime::Module::from_binary(&engine, module_bytes)
+                .map_err(|err| NewErr::InvalidWasm(err.to_string()))?
+        };
 
         // Building the list of imports that the Wasm VM is able to use.
         let resolved_imports = {
@@ -134,6 +172,7 @@ impl JitPrototype {
 
         Self::from_base_components(BaseComponents {
             module,
+            fuel_metering,
             resolved_imports,
         })
     }
@@ -294,11 +333,19 @@ impl JitPrototype {
         // error. However we return an error anyway, just in case.
         // If the `start` function doesn't call any import, then it will go undetected and no
         // error will be returned.
+        // If fuel metering is enabled, the store doesn't have any fuel at this point, and the
+        // `start` function, if any, immediately runs out of fuel.
         // TODO: detect `start` anyway, for consistency with other backends
         let instance = wasmtime::Instance::new_async(&mut store, &base_components.module, &imports)
             .now_or_never()
             .ok_or(NewErr::StartFunctionNotSupported)? // TODO: hacky error value, as the error could also be different
-            .map_err(|err| NewErr::Other(err.to_string()))?;
+            .map_err(|err| {
+                if err.downcast_ref::<wasmtime::Trap>() == Some(&wasmtime::Trap::OutOfFuel) {
+                    NewErr::StartFunctionNotSupported
+                } else {
+                    NewErr::Other(err.to_string())
+                }
+            })?;
 
         // Now that we are passed the `start` stage, update the state of execution.
         *shared.lock().unwrap() = Shared::Poisoned;
@@ -355,7 +402,10 @@ impl JitPrototype {
 
     /// See [`super::VirtualMachinePrototype::prepare`].
     pub fn prepare(self) -> Prepare {
-        Prepare { inner: self }
+        Prepare {
+            inner: self,
+            fuel: None,
+        }
     }
 }
 
@@ -368,6 +418,7 @@ impl Clone for JitPrototype {
         // acceptable reason to panic.
         JitPrototype::from_base_components(BaseComponents {
             module: self.base_components.module.clone(),
+            fuel_metering: self.base_components.fuel_metering,
             resolved_imports: self.base_components.resolved_imports.clone(),
         })
         .unwrap()
@@ -383,6 +434,9 @@ impl fmt::Debug for JitPrototype {
 /// See [`super::Prepare`].
 pub struct Prepare {
     inner: JitPrototype,
+
+    /// Value passed to [`Prepare::set_fuel`]. `None` if unlimited.
+    fuel: Option<u64>,
 }
 
 impl Prepare {
@@ -446,6 +500,11 @@ impl Prepare {
         Ok(())
     }
 
+    /// See [`super::Prepare::set_fuel`].
+    pub fn set_fuel(&mut self, fuel: u64) {
+        self.fuel = Some(fuel);
+    }
+
     /// See [`super::Prepare::start`].
     pub fn start(
         mut self,
@@ -480,6 +539,15 @@ impl Prepare {
             }
         }
 
+        // The store of a prototype never has any fuel, as a new store is created every time the
+        // prototype is rebuilt.
+        if self.inner.base_components.fuel_metering {
+            self.inner
+                .store
+                .add_fuel(self.fuel.unwrap_or(u64::MAX))
+                .unwrap();
+        }
+
         // This function only performs all the verifications and preparations, but the call isn't
         // actually started here because we might still need to potentially access `store`
         // before being in the context of a function handler.
@@ -738,6 +806,12 @@ impl Jit {
                     return_value: Ok(val),
                 })
             }
+            Poll::Ready((store, Err(err)))
+                if err.downcast_ref::<wasmtime::Trap>() == Some(&wasmtime::Trap::OutOfFuel) =>
+            {
+                self.inner = JitInner::Done(store);
+                Err(RunErr::OutOfFuel)
+            }
             Poll::Ready((store, Err(err))) => {
                 self.inner = JitInner::Done(store);
                 Ok(ExecOutcome::Finished {
@@ -948,3 +1022,18 @@ impl fmt::Debug for Jit {
         f.debug_tuple("Jit").finish()
     }
 }
+
+/// Implementation of [`core::hash::Hasher`] that feeds data to a BLAKE2 hasher, in order to obtain
+/// a hash that is stable across executions.
+struct Blake2Hasher(blake2_rfc::blake2b::Blake2b);
+
+impl core::hash::Hasher for Blake2Hasher {
+    fn write(&mut self, bytes: &[u8]) {
+        self.0.update(bytes);
+    }
+
+    fn finish(&self) -> u64 {
+        // Only the final BLAKE2 hash is used.
+        unreachable!()
+    }
+}
@@ -34,6 +34,7 @@ fn basic_seems_to_work() {
         let prototype = super::VirtualMachinePrototype::new(super::Config {
             module_bytes: &include_bytes!("./test-polkadot-runtime-v9160.wasm")[..],
             exec_hint,
+            compilation_cache: None,
             symbols: &mut |_, _, _| Ok(0),
         })
         .unwrap();
@@ -72,6 +73,7 @@ fn wat_not_accepted() {
             super::VirtualMachinePrototype::new(super::Config {
                 module_bytes: b"(module)",
                 exec_hint,
+                compilation_cache: None,
                 symbols: &mut |_, _, _| Ok(0)
             }),
             Err(super::NewErr::InvalidWasm(_))
@@ -90,6 +92,7 @@ fn out_of_memory_access() {
         assert!(super::VirtualMachinePrototype::new(super::Config {
             module_bytes: &module_bytes[..],
             exec_hint,
+            compilation_cache: None,
             symbols: &mut |_, _, _| Ok(0)
         })
         .is_err());
@@ -107,6 +110,7 @@ fn has_start_function() {
         assert!(super::VirtualMachinePrototype::new(super::Config {
             module_bytes: &module_bytes[..],
             exec_hint,
+            compilation_cache: None,
             symbols: &mut |_, _, _| Ok(0)
         })
         .is_err());
@@ -125,6 +129,7 @@ fn unsupported_type() {
         assert!(super::VirtualMachinePrototype::new(super::Config {
             module_bytes: &module_bytes[..],
             exec_hint,
+            compilation_cache: None,
             symbols: &mut |_, _, _| Ok(0)
         })
         .is_err());
@@ -152,6 +157,7 @@ fn basic_host_function_return_value_works() {
         let prototype = super::VirtualMachinePrototype::new(super::Config {
             module_bytes: &module_bytes,
             exec_hint,
+            compilation_cache: None,
             symbols: &mut |_, _, _| Ok(0),
         })
         .unwrap();
@@ -200,6 +206,7 @@ fn no_memory() {
             super::VirtualMachinePrototype::new(super::Config {
                 module_bytes: &module_bytes,
                 exec_hint,
+                compilation_cache: None,
                 symbols: &mut |_, _, _| Ok(0)
             }),
             Err(super::NewErr::NoMemory)
@@ -227,6 +234,7 @@ fn memory_misnamed() {
             super::VirtualMachinePrototype::new(super::Config {
                 module_bytes: &module_bytes,
                 exec_hint,
+                compilation_cache: None,
                 symbols: &mut |_, _, _| Ok(0)
             }),
             Err(super::NewErr::MemoryNotNamedMemory)
@@ -250,6 +258,7 @@ fn memory_isnt_memory() {
             super::VirtualMachinePrototype::new(super::Config {
                 module_bytes: &module_bytes,
                 exec_hint,
+                compilation_cache: None,
                 symbols: &mut |_, _, _| Ok(0)
             }),
             Err(super::NewErr::MemoryIsntMemory)
@@ -281,6 +290,7 @@ fn two_memories() {
             super::VirtualMachinePrototype::new(super::Config {
                 module_bytes: &module_bytes,
                 exec_hint,
+                compilation_cache: None,
                 symbols: &mut |_, _, _| Ok(0)
             }),
             Err(super::NewErr::InvalidWasm(_) | super::NewErr::TwoMemories)
@@ -307,6 +317,7 @@ fn exported_memory_works() {
         super::VirtualMachinePrototype::new(super::Config {
             module_bytes: &module_bytes,
             exec_hint,
+            compilation_cache: None,
             symbols: &mut |_, _, _| Ok(0),
         })
         .unwrap();
@@ -333,6 +344,7 @@ fn unresolved_function() {
             super::VirtualMachinePrototype::new(super::Config {
                 module_bytes: &module_bytes,
                 exec_hint,
+                compilation_cache: None,
                 symbols: &mut |_, _, _| Err(())
             }),
             Err(super::NewErr::UnresolvedFunctionImport { .. })
@@ -358,6 +370,7 @@ fn unsupported_signature() {
             super::VirtualMachinePrototype::new(super::Config {
                 module_bytes: &module_bytes,
                 exec_hint,
+                compilation_cache: None,
                 symbols: &mut |_, _, _| Ok(0)
             }),
             Err(super::NewErr::UnresolvedFunctionImport { .. })
@@ -383,6 +396,7 @@ fn unsupported_import_type() {
             super::VirtualMachinePrototype::new(super::Config {
                 module_bytes: &module_bytes,
                 exec_hint,
+                compilation_cache: None,
                 symbols: &mut |_, _, _| Ok(0)
             }),
             Err(super::NewErr::ImportTypeNotSupported)
@@ -409,6 +423,7 @@ fn start_function_forbidden() {
             super::VirtualMachinePrototype::new(super::Config {
                 module_bytes: &module_bytes,
                 exec_hint,
+                compilation_cache: None,
                 symbols: &mut |_, _, _| Ok(0)
             }),
             Err(super::NewErr::StartFunctionNotSupported) | Ok(_)
@@ -432,6 +447,7 @@ fn max_memory_pages() {
         let prototype = super::VirtualMachinePrototype::new(super::Config {
             module_bytes: &module_bytes,
             exec_hint,
+            compilation_cache: None,
             symbols: &mut |_, _, _| Ok(0),
         })
         .unwrap();
@@ -458,6 +474,7 @@ fn get_global() {
         let mut prototype = super::VirtualMachinePrototype::new(super::Config {
             module_bytes: &module_bytes,
             exec_hint,
+            compilation_cache: None,
             symbols: &mut |_, _, _| Ok(0),
         })
         .unwrap();
@@ -480,6 +497,7 @@ fn global_doesntexist() {
         let mut prototype = super::VirtualMachinePrototype::new(super::Config {
             module_bytes: &module_bytes,
             exec_hint,
+            compilation_cache: None,
             symbols: &mut |_, _, _| Ok(0),
         })
         .unwrap();
@@ -506,6 +524,7 @@ fn global_wrong_type() {
         let mut prototype = super::VirtualMachinePrototype::new(super::Config {
             module_bytes: &module_bytes,
             exec_hint,
+            compilation_cache: None,
             symbols: &mut |_, _, _| Ok(0),
         })
         .unwrap();
@@ -532,6 +551,7 @@ fn global_isnt_global() {
         let mut prototype = super::VirtualMachinePrototype::new(super::Config {
             module_bytes: &module_bytes,
             exec_hint,
+            compilation_cache: None,
             symbols: &mut |_, _, _| Ok(0),
         })
         .unwrap();
@@ -558,6 +578,7 @@ fn global_negative_value_works() {
         let mut prototype = super::VirtualMachinePrototype::new(super::Config {
             module_bytes: &module_bytes,
             exec_hint,
+            compilation_cache: None,
             symbols: &mut |_, _, _| Ok(0),
         })
         .unwrap();
@@ -581,6 +602,7 @@ fn call_non_existing_function() {
         let prototype = super::VirtualMachinePrototype::new(super::Config {
             module_bytes: &module_bytes,
             exec_hint,
+            compilation_cache: None,
             symbols: &mut |_, _, _| Ok(0),
         })
         .unwrap();
@@ -607,6 +629,7 @@ fn call_signature_not_supported() {
         let prototype = super::VirtualMachinePrototype::new(super::Config {
             module_bytes: &module_bytes,
             exec_hint,
+            compilation_cache: None,
             symbols: &mut |_, _, _| Ok(0),
         })
         .unwrap();
@@ -633,6 +656,7 @@ fn bad_params_types() {
         let prototype = super::VirtualMachinePrototype::new(super::Config {
             module_bytes: &module_bytes,
             exec_hint,
+            compilation_cache: None,
             symbols: &mut |_, _, _| Ok(0),
         })
         .unwrap();
@@ -659,6 +683,7 @@ fn try_to_call_global() {
         let prototype = super::VirtualMachinePrototype::new(super::Config {
             module_bytes: &module_bytes,
             exec_hint,
+            compilation_cache: None,
             symbols: &mut |_, _, _| Ok(0),
         })
         .unwrap();
@@ -688,6 +713,7 @@ fn wrong_type_provided_initially() {
         let prototype = super::VirtualMachinePrototype::new(super::Config {
             module_bytes: &module_bytes,
             exec_hint,
+            compilation_cache: None,
             symbols: &mut |_, _, _| Ok(0),
         })
         .unwrap();
@@ -720,6 +746,7 @@ fn wrong_type_returned_by_host_function_call() {
         let prototype = super::VirtualMachinePrototype::new(super::Config {
             module_bytes: &module_bytes,
             exec_hint,
+            compilation_cache: None,
             symbols: &mut |_, _, _| Ok(0),
         })
         .unwrap();
@@ -750,6 +777,7 @@ fn memory_min_specified_in_wasm() {
         let prototype = super::VirtualMachinePrototype::new(super::Config {
             module_bytes: &module_bytes,
             exec_hint,
+            compilation_cache: None,
             symbols: &mut |_, _, _| Ok(0),
         })
         .unwrap();
@@ -774,6 +802,7 @@ fn memory_grow_works() {
         let prototype = super::VirtualMachinePrototype::new(super::Config {
             module_bytes: &module_bytes,
             exec_hint,
+            compilation_cache: None,
             symbols: &mut |_, _, _| Ok(0),
         })
         .unwrap();
@@ -800,6 +829,7 @@ fn memory_grow_detects_limit() {
         let prototype = super::VirtualMachinePrototype::new(super::Config {
             module_bytes: &module_bytes,
             exec_hint,
+            compilation_cache: None,
             symbols: &mut |_, _, _| Ok(0),
         })
         .unwrap();
@@ -828,6 +858,7 @@ fn memory_grow_detects_limit_within_host_function() {
         let prototype = super::VirtualMachinePrototype::new(super::Config {
             module_bytes: &module_bytes,
             exec_hint,
+            compilation_cache: None,
             symbols: &mut |_, _, _| Ok(0),
         })
         .unwrap();
@@ -880,6 +911,7 @@ fn globals_reinitialized_after_reset() {
         let mut prototype = super::VirtualMachinePrototype::new(super::Config {
             module_bytes: &module_bytes,
             exec_hint,
+            compilation_cache: None,
             symbols: &mut |_, _, _| Ok(0),
         })
         .unwrap();
@@ -914,6 +946,7 @@ fn memory_zeroed_after_reset() {
         let prototype = super::VirtualMachinePrototype::new(super::Config {
             module_bytes: &module_bytes,
             exec_hint,
+            compilation_cache: None,
             symbols: &mut |_, _, _| Ok(0),
         })
         .unwrap();
@@ -962,6 +995,7 @@ fn memory_zeroed_after_prepare() {
         let prototype = super::VirtualMachinePrototype::new(super::Config {
             module_bytes: &module_bytes,
             exec_hint,
+            compilation_cache: None,
             symbols: &mut |_, _, _| Ok(0),
         })
         .unwrap();
@@ -1000,6 +1034,7 @@ fn feature_disabled_signext() {
         assert!(super::VirtualMachinePrototype::new(super::Config {
             module_bytes: &module_bytes,
             exec_hint,
+            compilation_cache: None,
             symbols: &mut |_, _, _| Ok(0),
         })
         .is_err());
@@ -1031,6 +1066,7 @@ fn feature_disabled_saturated_float_to_int() {
         assert!(super::VirtualMachinePrototype::new(super::Config {
             module_bytes: &module_bytes,
             exec_hint,
+            compilation_cache: None,
             symbols: &mut |_, _, _| Ok(0),
         })
         .is_err());
@@ -1056,6 +1092,7 @@ fn feature_disabled_threads() {
         assert!(super::VirtualMachinePrototype::new(super::Config {
             module_bytes: &module_bytes,
             exec_hint,
+            compilation_cache: None,
             symbols: &mut |_, _, _| Ok(0),
         })
         .is_err());
@@ -1078,6 +1115,7 @@ fn feature_disabled_reference_type() {
         assert!(super::VirtualMachinePrototype::new(super::Config {
             module_bytes: &module_bytes,
             exec_hint,
+            compilation_cache: None,
             symbols: &mut |_, _, _| Ok(0),
         })
         .is_err());
@@ -1103,6 +1141,7 @@ fn feature_disabled_bulk_memory() {
         assert!(super::VirtualMachinePrototype::new(super::Config {
             module_bytes: &module_bytes,
             exec_hint,
+            compilation_cache: None,
             symbols: &mut |_, _, _| Ok(0),
         })
         .is_err());
@@ -1127,6 +1166,7 @@ fn feature_disabled_multi_value() {
         assert!(super::VirtualMachinePrototype::new(super::Config {
             module_bytes: &module_bytes,
             exec_hint,
+            compilation_cache: None,
             symbols: &mut |_, _, _| Ok(0),
         })
         .is_err());
@@ -1148,6 +1188,7 @@ fn feature_disabled_memory64() {
         assert!(super::VirtualMachinePrototype::new(super::Config {
             module_bytes: &module_bytes,
             exec_hint,
+            compilation_cache: None,
             symbols: &mut |_, _, _| Ok(0),
         })
         .is_err());
@@ -1176,6 +1217,7 @@ fn feature_disabled_mutable_globals() {
         assert!(super::VirtualMachinePrototype::new(super::Config {
             module_bytes: &module_bytes,
             exec_hint,
+            compilation_cache: None,
             symbols: &mut |_, _, _| Ok(0),
         })
         .is_err());
@@ -1211,6 +1253,7 @@ fn feature_disabled_tail_call() {
         assert!(super::VirtualMachinePrototype::new(super::Config {
             module_bytes: &module_bytes,
             exec_hint,
+            compilation_cache: None,
             symbols: &mut |_, _, _| Ok(0),
         })
         .is_err());
@@ -1270,12 +1313,136 @@ fn feature_disabled_simd128() {
         assert!(super::VirtualMachinePrototype::new(super::Config {
             module_bytes: &module_bytes,
             exec_hint,
+            compilation_cache: None,
             symbols: &mut |_, _, _| Ok(0),
         })
         .is_err());
     }
 }
 
+#[test]
+fn infinite_loop_runs_out_of_fuel() {
+    let module_bytes = wat::parse_str(
+        r#"
+    (module
+        (import "env" "memory" (memory $mem 0 4096))
+        (func (export "hello")
+            (loop $l
+                br $l))
+    )
+    "#,
+    )
+    .unwrap();
+
+    for exec_hint in
+        super::ExecHint::available_engines().chain(core::iter::once(super::ExecHint::Untrusted))
+    {
+        let prototype = super::VirtualMachinePrototype::new(super::Config {
+            module_bytes: &module_bytes,
+            exec_hint,
+            compilation_cache: None,
+            symbols: &mut |_, _, _| Ok(0),
+        })
+        .unwrap();
+
+        let mut prepare = prototype.prepare();
+        prepare.set_fuel(100_000);
+        let mut vm = prepare.start("hello", &[]).unwrap();
+
+        assert!(matches!(vm.run(None), Err(super::RunErr::OutOfFuel)));
+        assert!(matches!(vm.run(None), Err(super::RunErr::Poisoned)));
+    }
+}
+
+#[test]
+fn fuel_is_enough() {
+    let module_bytes = wat::parse_str(
+        r#"
+    (module
+        (import "env" "memory" (memory $mem 0 4096))
+        (func (export "hello") (result i32)
+            i32.const 1
+            i32.const 2
+            i32.add)
+    )
+    "#,
+    )
+    .unwrap();
+
+    for exec_hint in super::ExecHint::available_engines() {
+        let prototype = super::VirtualMachinePrototype::new(super::Config {
+            module_bytes: &module_bytes,
+            exec_hint,
+            compilation_cache: None,
+            symbols: &mut |_, _, _| Ok(0),
+        })
+        .unwrap();
+
+        let mut prepare = prototype.prepare();
+        prepare.set_fuel(100);
+        let mut vm = prepare.start("hello", &[]).unwrap();
+
+        assert!(matches!(
+            vm.run(None),
+            Ok(super::ExecOutcome::Finished {
+                return_value: Ok(Some(super::WasmValue::I32(3)))
+            })
+        ));
+    }
+}
+
+#[test]
+fn compilation_cache_works() {
+    struct Cache(std::sync::Mutex<hashbrown::HashMap<[u8; 32], Vec<u8>>>);
+    unsafe impl super::CompilationCache for Cache {
+        fn load(&self, key: &[u8; 32]) -> Option<Vec<u8>> {
+            self.0.lock().unwrap().get(key).cloned()
+        }
+        fn store(&self, key: &[u8; 32], compiled: &[u8]) {
+            self.0.lock().unwrap().insert(*key, compiled.to_vec());
+        }
+    }
+
+    let module_bytes = wat::parse_str(
+        r#"
+    (module
+        (import "env" "memory" (memory $mem 0 4096))
+        (func (export "hello") (result i32) i32.const 5)
+    )
+    "#,
+    )
+    .unwrap();
+
+    let cache = Cache(Default::default());
+
+    for exec_hint in super::ExecHint::available_engines() {
+        // Instantiate the module twice, the second time presumably from the cache.
+        for _ in 0..2 {
+            let prototype = super::VirtualMachinePrototype::new(super::Config {
+                module_bytes: &module_bytes,
+                exec_hint,
+                compilation_cache: Some(&cache),
+                symbols: &mut |_, _, _| Ok(0),
+            })
+            .unwrap();
+
+            let mut vm = prototype.prepare().start("hello", &[]).unwrap();
+            assert!(matches!(
+                vm.run(None),
+                Ok(super::ExecOutcome::Finished {
+                    return_value: Ok(Some(super::WasmValue::I32(5)))
+                })
+            ));
+        }
+    }
+
+    // Only the `wasmtime` backend stores its compilation output.
+    assert_eq!(
+        cache.0.lock().unwrap().len(),
+        super::ExecHint::force_wasmtime_if_available().map_or(0, |_| 1)
+    );
+}
+
 // TODO: check that the extended-const feature is disabled: https://github.com/WebAssembly/extended-const/blob/master/proposals/extended-const/Overview.md
 
 // TODO: test for memory reads and writes, including within host functions
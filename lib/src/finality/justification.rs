@@ -29,4 +29,5 @@
 //! [`verify`] module.
 
 pub mod decode;
+pub mod encode;
 pub mod verify;
@@ -0,0 +1,144 @@
+// Smoldot
+// Copyright (C) 2023  Pierre Krieger
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <http://www.gnu.org/licenses/>.
+
+use crate::{finality::grandpa::commit::decode::CommitMessageRef, util};
+
+use alloc::vec::Vec;
+use core::{cmp, mem};
+
+/// Builds the SCALE-encoded GrandPa justification equivalent to the given commit.
+///
+/// Contrary to justifications, commits don't contain the headers of the blocks that have been
+/// voted on. `votes_ancestries` must contain the SCALE-encoded headers of all the blocks between
+/// the targets of the precommits (inclusive) and the target of the commit (exclusive).
+///
+/// The commit is expected to have been verified, as the precommits and their signatures are
+/// copied as-is. Precommits that don't have a matching signature are ignored.
+pub fn encode_grandpa_from_commit<'a>(
+    commit: &CommitMessageRef,
+    votes_ancestries: impl ExactSizeIterator<Item = &'a [u8]>,
+    block_number_bytes: usize,
+) -> Vec<u8> {
+    let num_precommits = cmp::min(
+        commit.message.precommits.len(),
+        commit.message.auth_data.len(),
+    );
+
+    let mut out = Vec::with_capacity(
+        8 + 32 + block_number_bytes + 5 + num_precommits * (32 + block_number_bytes + 64 + 32),
+    );
+
+    out.extend_from_slice(&commit.round_number.to_le_bytes());
+    out.extend_from_slice(commit.message.target_hash);
+    encode_block_number(&mut out, commit.message.target_number, block_number_bytes);
+
+    out.extend_from_slice(util::encode_scale_compact_usize(num_precommits).as_ref());
+    for (precommit, (signature, authority_public_key)) in commit
+        .message
+        .precommits
+        .iter()
+        .zip(commit.message.auth_data.iter())
+    {
+        out.extend_from_slice(precommit.target_hash);
+        encode_block_number(&mut out, precommit.target_number, block_number_bytes);
+        out.extend_from_slice(&signature[..]);
+        out.extend_from_slice(&authority_public_key[..]);
+    }
+
+    out.extend_from_slice(util::encode_scale_compact_usize(votes_ancestries.len()).as_ref());
+    for header in votes_ancestries {
+        out.extend_from_slice(header);
+    }
+
+    out
+}
+
+/// Appends the little endian block number to `out`, truncated or padded with zeroes to
+/// `block_number_bytes`.
+fn encode_block_number(out: &mut Vec<u8>, number: u64, block_number_bytes: usize) {
+    out.extend_from_slice(
+        &number.to_le_bytes()[..cmp::min(mem::size_of_val(&number), block_number_bytes)],
+    );
+    out.resize(
+        out.len() + block_number_bytes.saturating_sub(mem::size_of_val(&number)),
+        0,
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        finality::{grandpa::commit::decode, justification},
+        header,
+    };
+    use alloc::vec::Vec;
+
+    #[test]
+    fn commit_to_justification() {
+        let ancestor = header::Header {
+            parent_hash: [1; 32],
+            number: 0x1235,
+            state_root: [2; 32],
+            extrinsics_root: [3; 32],
+            digest: header::DigestRef::empty().into(),
+        };
+
+        let mut scale_encoded_commit = Vec::new();
+        scale_encoded_commit.extend_from_slice(&7u64.to_le_bytes());
+        scale_encoded_commit.extend_from_slice(&3u64.to_le_bytes());
+        scale_encoded_commit.extend_from_slice(&[4; 32]);
+        scale_encoded_commit.extend_from_slice(&0x1234u32.to_le_bytes());
+        scale_encoded_commit.push(2 << 2);
+        scale_encoded_commit.extend_from_slice(&[4; 32]);
+        scale_encoded_commit.extend_from_slice(&0x1234u32.to_le_bytes());
+        scale_encoded_commit.extend_from_slice(&ancestor.hash(4));
+        scale_encoded_commit.extend_from_slice(&0x1235u32.to_le_bytes());
+        scale_encoded_commit.push(2 << 2);
+        scale_encoded_commit.extend_from_slice(&[5; 64]);
+        scale_encoded_commit.extend_from_slice(&[6; 32]);
+        scale_encoded_commit.extend_from_slice(&[7; 64]);
+        scale_encoded_commit.extend_from_slice(&[8; 32]);
+
+        let commit = decode::decode_grandpa_commit(&scale_encoded_commit, 4).unwrap();
+        let scale_encoded_ancestor = ancestor.scale_encoding_vec(4);
+        let encoded = super::encode_grandpa_from_commit(
+            &commit,
+            [&scale_encoded_ancestor[..]].into_iter(),
+            4,
+        );
+
+        let decoded = justification::decode::decode_grandpa(&encoded, 4).unwrap();
+        assert_eq!(decoded.round, 7);
+        assert_eq!(*decoded.target_hash, [4; 32]);
+        assert_eq!(decoded.target_number, 0x1234);
+
+        let precommits = decoded.precommits.iter().collect::<Vec<_>>();
+        assert_eq!(precommits.len(), 2);
+        assert_eq!(*precommits[0].target_hash, [4; 32]);
+        assert_eq!(precommits[0].target_number, 0x1234);
+        assert_eq!(*precommits[0].signature, [5; 64]);
+        assert_eq!(*precommits[0].authority_public_key, [6; 32]);
+        assert_eq!(*precommits[1].target_hash, ancestor.hash(4));
+        assert_eq!(precommits[1].target_number, 0x1235);
+        assert_eq!(*precommits[1].signature, [7; 64]);
+        assert_eq!(*precommits[1].authority_public_key, [8; 32]);
+
+        let votes_ancestries = decoded.votes_ancestries.collect::<Vec<_>>();
+        assert_eq!(votes_ancestries.len(), 1);
+        assert_eq!(votes_ancestries[0].hash(4), ancestor.hash(4));
+    }
+}
@@ -444,6 +444,22 @@ impl<'a> DigestRef<'a> {
         self.logs().any(|l| l.is_grandpa())
     }
 
+    /// Returns true if the list has any item that schedules or forces a change in the list of
+    /// Grandpa authorities.
+    ///
+    /// This function is `O(n)` over the number of log items.
+    pub fn has_grandpa_authorities_change(&self) -> bool {
+        self.logs().any(|l| {
+            matches!(
+                l,
+                DigestItemRef::GrandpaConsensus(
+                    GrandpaConsensusLogRef::ScheduledChange(_)
+                        | GrandpaConsensusLogRef::ForcedChange { .. }
+                )
+            )
+        })
+    }
+
     /// Returns the Aura seal digest item, if any.
     pub fn aura_seal(&self) -> Option<&'a [u8; 64]> {
         if let Some(aura_seal_index) = self.aura_seal_index {
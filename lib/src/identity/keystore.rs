@@ -22,7 +22,8 @@
 //! cryptographic key pairs (i.e. both the public and secret keys).
 //!
 //! Each key pair contained within the keystore is identified as a `(KeyNamespace, [u8; 32])`
-//! tuple, where the `[u8; 32]` is the public key. See [`KeyNamespace`].
+//! tuple, where the `[u8; 32]` is the public key. See [`KeyNamespace`]. ECDSA key pairs, whose
+//! public keys are 33 bytes, are identified as a `(KeyNamespace, [u8; 33])` tuple instead.
 //!
 //! A keystore is optionally associated with a directory of the file system into which it will
 //! store secret keys permanently. Keys present in this directory are considered to be the content
@@ -56,6 +57,7 @@ pub enum KeyNamespace {
     Aura,
     AuthorityDiscovery,
     Babe,
+    Beefy,
     Grandpa,
     ImOnline,
     // TODO: there exists other variants in Substrate but it's unclear whether they're in use (see https://github.com/paritytech/substrate/blob/cafe12e7785bf92e5dc04780c10e7f8330a15a4c/primitives/core/src/crypto.rs)
@@ -68,17 +70,25 @@ impl KeyNamespace {
             KeyNamespace::Aura,
             KeyNamespace::AuthorityDiscovery,
             KeyNamespace::Babe,
+            KeyNamespace::Beefy,
             KeyNamespace::Grandpa,
             KeyNamespace::ImOnline,
         ]
         .into_iter()
     }
 
+    /// Returns the [`KeyNamespace`] corresponding to the given key type identifier, as passed
+    /// by the runtime. Returns `None` if the key type is unknown.
+    pub fn from_key_type_id(key_type_id: &[u8; 4]) -> Option<Self> {
+        Self::from_string(str::from_utf8(key_type_id).ok()?)
+    }
+
     fn from_string(str: &str) -> Option<Self> {
         match str {
             "aura" => Some(KeyNamespace::Aura),
             "audi" => Some(KeyNamespace::AuthorityDiscovery),
             "babe" => Some(KeyNamespace::Babe),
+            "beef" => Some(KeyNamespace::Beefy),
             "gran" => Some(KeyNamespace::Grandpa),
             "imon" => Some(KeyNamespace::ImOnline),
             _ => None,
@@ -90,6 +100,7 @@ impl KeyNamespace {
             KeyNamespace::Aura => "aura",
             KeyNamespace::AuthorityDiscovery => "audi",
             KeyNamespace::Babe => "babe",
+            KeyNamespace::Beefy => "beef",
             KeyNamespace::Grandpa => "gran",
             KeyNamespace::ImOnline => "imon",
         }
@@ -125,6 +136,9 @@ impl Keystore {
         let mut keys = hashbrown::HashMap::with_capacity_and_hasher(32, {
             SipHasherBuild::new(gen_rng.sample(rand::distributions::Standard))
         });
+        let mut ecdsa_keys = hashbrown::HashMap::with_capacity_and_hasher(8, {
+            SipHasherBuild::new(gen_rng.sample(rand::distributions::Standard))
+        });
 
         // Load the keys from the disk.
         // TODO: return some diagnostic about invalid files?
@@ -153,27 +167,15 @@ impl Keystore {
                                 KeyNamespace::from_string,
                             ),
                             nom::bytes::complete::tag("-"),
-                            nom::combinator::map_opt(
-                                nom::bytes::complete::take(7u32),
-                                |b| match b {
-                                    "ed25519" => Some(PrivateKey::FileEd25519),
-                                    "sr25519" => Some(PrivateKey::FileSr25519),
-                                    _ => None,
-                                },
-                            ),
+                            nom::branch::alt((
+                                nom::bytes::complete::tag("ed25519"),
+                                nom::bytes::complete::tag("sr25519"),
+                                nom::bytes::complete::tag("ecdsa"),
+                            )),
                             nom::bytes::complete::tag("-"),
-                            nom::combinator::map_opt(
-                                nom::bytes::complete::take_while(|c: char| {
-                                    c.is_ascii_digit() || ('a'..='f').contains(&c)
-                                }),
-                                |k: &str| {
-                                    if k.len() == 64 {
-                                        Some(<[u8; 32]>::try_from(hex::decode(k).unwrap()).unwrap())
-                                    } else {
-                                        None
-                                    }
-                                },
-                            ),
+                            nom::bytes::complete::take_while(|c: char| {
+                                c.is_ascii_digit() || ('a'..='f').contains(&c)
+                            }),
                         ))),
                     );
 
@@ -182,6 +184,47 @@ impl Keystore {
                     Err(_) => continue,
                 };
 
+                // ECDSA keys are stored separately, as their public keys are 33 bytes.
+                if algorithm == "ecdsa" {
+                    let public_key = match hex::decode(public_key)
+                        .ok()
+                        .and_then(|k| <[u8; 33]>::try_from(k).ok())
+                    {
+                        Some(k) => k,
+                        None => continue,
+                    };
+
+                    // Make sure that the content of the file is valid and that it corresponds to
+                    // the public key advertised in the file name.
+                    match Self::load_ecdsa_from_file(keys_directory.join(entry.path())).await {
+                        Ok(sk) => {
+                            if libsecp256k1::PublicKey::from_secret_key(&sk).serialize_compressed()
+                                != public_key
+                            {
+                                continue;
+                            }
+                        }
+                        Err(_) => continue,
+                    }
+
+                    ecdsa_keys.insert((namespace, public_key), EcdsaPrivateKey::File);
+                    continue;
+                }
+
+                let algorithm = match algorithm {
+                    "ed25519" => PrivateKey::FileEd25519,
+                    "sr25519" => PrivateKey::FileSr25519,
+                    _ => unreachable!(),
+                };
+
+                let public_key = match hex::decode(public_key)
+                    .ok()
+                    .and_then(|k| <[u8; 32]>::try_from(k).ok())
+                {
+                    Some(k) => k,
+                    None => continue,
+                };
+
                 // Make sure that the content of the file is valid and that it corresponds to
                 // the public key advertised in the file name.
                 match algorithm {
@@ -217,7 +260,11 @@ impl Keystore {
 
         Ok(Keystore {
             keys_directory,
-            guarded: Mutex::new(Guarded { gen_rng, keys }),
+            guarded: Mutex::new(Guarded {
+                gen_rng,
+                keys,
+                ecdsa_keys,
+            }),
             sr25519_signing_context: schnorrkel::signing_context(b"substrate"),
         })
     }
@@ -349,6 +396,66 @@ impl Keystore {
         Ok(public_key)
     }
 
+    /// Generates a new ECDSA key on the secp256k1 curve and inserts it in the keystore.
+    ///
+    /// If `save` is `true`, the generated key is saved in the file system. This function returns
+    /// an error only if `save` is `true` and the key couldn't be written to the file system.
+    /// The value of `save` is silently ignored if no path was provided to [`Keystore::new`].
+    ///
+    /// Returns the corresponding public key, in its 33 bytes compressed form.
+    pub async fn generate_ecdsa(
+        &self,
+        namespace: KeyNamespace,
+        save: bool,
+    ) -> Result<[u8; 33], io::Error> {
+        let mut guarded = self.guarded.lock().await;
+
+        // Not all 32 bytes values are valid secp256k1 private keys, but the probability of
+        // generating an invalid one is negligible.
+        let private_key = loop {
+            let bytes: [u8; 32] = guarded.gen_rng.sample(rand::distributions::Standard);
+            if let Ok(private_key) = libsecp256k1::SecretKey::parse(&bytes) {
+                break private_key;
+            }
+        };
+        let public_key =
+            libsecp256k1::PublicKey::from_secret_key(&private_key).serialize_compressed();
+
+        let save_path = if save {
+            self.path_of_key(namespace, "ecdsa", &public_key)
+        } else {
+            None
+        };
+
+        if let Some(save_path) = save_path {
+            Self::write_to_file(&save_path, &hex::encode(private_key.serialize())).await?;
+            guarded
+                .ecdsa_keys
+                .insert((namespace, public_key), EcdsaPrivateKey::File);
+        } else {
+            guarded.ecdsa_keys.insert(
+                (namespace, public_key),
+                EcdsaPrivateKey::Memory(private_key),
+            );
+        }
+
+        Ok(public_key)
+    }
+
+    /// Returns the list of all ECDSA keys known to this keystore.
+    ///
+    /// > **Note**: Keep in mind that this function is racy, as keys can be added and removed
+    /// >           in parallel of this function being called.
+    pub async fn ecdsa_keys(&self) -> impl Iterator<Item = (KeyNamespace, [u8; 33])> {
+        let guarded = self.guarded.lock().await;
+        guarded
+            .ecdsa_keys
+            .keys()
+            .cloned()
+            .collect::<Vec<_>>()
+            .into_iter()
+    }
+
     /// Signs the given payload using the private key associated to the public key passed as
     /// parameter.
     ///
@@ -409,6 +516,73 @@ impl Keystore {
         }
     }
 
+    /// Signs the given payload using the ECDSA private key associated to the public key passed
+    /// as parameter.
+    ///
+    /// The payload is hashed using blake2-256 before being signed. The returned signature
+    /// consists of 64 bytes of signature followed with the recovery ID.
+    ///
+    /// An error is returned if the key-namespace combination is not in the keystore, or if the
+    /// key couldn't be loaded from disk. In the case when a key couldn't be loaded from disk, it
+    /// is automatically removed from the keystore.
+    pub async fn sign_ecdsa(
+        &self,
+        key_namespace: KeyNamespace,
+        public_key: &[u8; 33],
+        payload: &[u8],
+    ) -> Result<[u8; 65], SignError> {
+        let hash = blake2_rfc::blake2b::blake2b(32, &[], payload);
+        self.sign_ecdsa_prehashed(
+            key_namespace,
+            public_key,
+            <&[u8; 32]>::try_from(hash.as_bytes()).unwrap(),
+        )
+        .await
+    }
+
+    /// Similar to [`Keystore::sign_ecdsa`], but signs a 32 bytes hash as is.
+    pub async fn sign_ecdsa_prehashed(
+        &self,
+        key_namespace: KeyNamespace,
+        public_key: &[u8; 33],
+        message_hash: &[u8; 32],
+    ) -> Result<[u8; 65], SignError> {
+        let mut guarded = self.guarded.lock().await;
+        let key = guarded
+            .ecdsa_keys
+            .get(&(key_namespace, *public_key))
+            .ok_or(SignError::UnknownPublicKey)?;
+
+        let private_key = match key {
+            EcdsaPrivateKey::Memory(key) => *key,
+            EcdsaPrivateKey::File => {
+                match Self::load_ecdsa_from_file(
+                    self.path_of_key(key_namespace, "ecdsa", public_key)
+                        .unwrap(),
+                )
+                .await
+                {
+                    Ok(key) => {
+                        drop(guarded);
+                        key
+                    }
+                    Err(err) => {
+                        guarded.ecdsa_keys.remove(&(key_namespace, *public_key));
+                        return Err(err.into());
+                    }
+                }
+            }
+        };
+
+        let (signature, recovery_id) =
+            libsecp256k1::sign(&libsecp256k1::Message::parse(message_hash), &private_key);
+
+        let mut out = [0; 65];
+        out[..64].copy_from_slice(&signature.serialize());
+        out[64] = recovery_id.serialize();
+        Ok(out)
+    }
+
     // TODO: doc
     ///
     /// Note that the labels must be `'static` due to requirements from the underlying library.
@@ -505,6 +679,20 @@ impl Keystore {
             .into())
     }
 
+    async fn load_ecdsa_from_file(
+        path: impl AsRef<path::Path>,
+    ) -> Result<libsecp256k1::SecretKey, KeyLoadError> {
+        // TODO: read asynchronously?
+        let bytes = fs::read(path).map_err(KeyLoadError::Io)?;
+        let phrase =
+            str::from_utf8(&bytes).map_err(|err| KeyLoadError::BadFormat(err.to_string()))?;
+        let private_key = seed_phrase::decode_ecdsa_private_key(phrase)
+            .map_err(|err| KeyLoadError::BadFormat(err.to_string()))?;
+        // TODO: zero memory of the private key on drop ^
+        libsecp256k1::SecretKey::parse(&private_key)
+            .map_err(|err| KeyLoadError::BadFormat(err.to_string()))
+    }
+
     async fn write_to_file_ed25519(
         path: impl AsRef<path::Path>,
         key: &ed25519_zebra::SigningKey,
@@ -556,7 +744,7 @@ impl Keystore {
         &self,
         key_namespace: KeyNamespace,
         key_algorithm: &str,
-        public_key: &[u8; 32],
+        public_key: &[u8],
     ) -> Option<path::PathBuf> {
         let keys_directory = match &self.keys_directory {
             Some(k) => k,
@@ -566,7 +754,7 @@ impl Keystore {
         // We don't use the same pathing scheme as Substrate, for two reasons:
         // - The fact that Substrate hex-encodes the namespace is completely unnecessary and
         // confusing.
-        // - Substrate doesn't indicate whether the key is ed25519 or sr25519, because the
+        // - Substrate doesn't indicate whether the key is ed25519, sr25519, or ecdsa, because the
         // algorithm to use is provided when signing or verifying. This is weird and in my opinion
         // not a good practice.
 
@@ -588,6 +776,7 @@ impl Keystore {
 struct Guarded {
     gen_rng: rand_chacha::ChaCha20Rng,
     keys: hashbrown::HashMap<(KeyNamespace, [u8; 32]), PrivateKey, SipHasherBuild>,
+    ecdsa_keys: hashbrown::HashMap<(KeyNamespace, [u8; 33]), EcdsaPrivateKey, SipHasherBuild>,
 }
 
 pub struct VrfSignature {
@@ -632,6 +821,11 @@ enum PrivateKey {
     FileSr25519,
 }
 
+enum EcdsaPrivateKey {
+    Memory(libsecp256k1::SecretKey),
+    File,
+}
+
 impl From<KeyLoadError> for SignError {
     fn from(err: KeyLoadError) -> SignError {
         SignError::KeyLoad(err)
@@ -719,4 +913,71 @@ mod tests {
                 .is_ok());
         });
     }
+
+    #[test]
+    fn disk_storage_works_ecdsa() {
+        futures_executor::block_on(async move {
+            let path = tempfile::tempdir().unwrap();
+
+            let keystore1 = Keystore::new(Some(path.path().to_owned()), rand::random())
+                .await
+                .unwrap();
+            let public_key = keystore1
+                .generate_ecdsa(KeyNamespace::Beefy, true)
+                .await
+                .unwrap();
+            drop(keystore1);
+
+            let keystore2 = Keystore::new(Some(path.path().to_owned()), rand::random())
+                .await
+                .unwrap();
+            assert!(keystore2.keys().await.next().is_none());
+            assert_eq!(
+                keystore2.ecdsa_keys().await.next(),
+                Some((KeyNamespace::Beefy, public_key))
+            );
+
+            let signature = keystore2
+                .sign_ecdsa(KeyNamespace::Beefy, &public_key, b"hello world")
+                .await
+                .unwrap();
+
+            let hash = blake2_rfc::blake2b::blake2b(32, &[], b"hello world");
+            let recovered = libsecp256k1::recover(
+                &libsecp256k1::Message::parse_slice(hash.as_bytes()).unwrap(),
+                &libsecp256k1::Signature::parse_standard_slice(&signature[..64]).unwrap(),
+                &libsecp256k1::RecoveryId::parse(signature[64]).unwrap(),
+            )
+            .unwrap();
+            assert_eq!(recovered.serialize_compressed(), public_key);
+        });
+    }
+
+    #[test]
+    fn memory_storage_works_ecdsa() {
+        futures_executor::block_on(async move {
+            let keystore = Keystore::new(None, rand::random()).await.unwrap();
+            let public_key = keystore
+                .generate_ecdsa(KeyNamespace::Beefy, true)
+                .await
+                .unwrap();
+
+            assert!(keystore
+                .sign_ecdsa(KeyNamespace::Grandpa, &public_key, b"hello world")
+                .await
+                .is_err());
+
+            let signature = keystore
+                .sign_ecdsa_prehashed(KeyNamespace::Beefy, &public_key, &[0xaa; 32])
+                .await
+                .unwrap();
+            let recovered = libsecp256k1::recover(
+                &libsecp256k1::Message::parse(&[0xaa; 32]),
+                &libsecp256k1::Signature::parse_standard_slice(&signature[..64]).unwrap(),
+                &libsecp256k1::RecoveryId::parse(signature[64]).unwrap(),
+            )
+            .unwrap();
+            assert_eq!(recovered.serialize_compressed(), public_key);
+        });
+    }
 }
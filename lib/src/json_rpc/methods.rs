@@ -424,6 +424,7 @@ define_methods! {
     state_queryStorageAt(keys: Vec<HexString>, at: Option<HashHexString>) -> Vec<StorageChangeSet>, // TODO:
     state_subscribeRuntimeVersion() -> Cow<'a, str> [chain_subscribeRuntimeVersion],
     state_subscribeStorage(list: Vec<HexString>) -> Cow<'a, str>,
+    state_traceBlock(block: HashHexString, targets: Option<Cow<'a, str>>, storage_keys: Option<Cow<'a, str>>, methods: Option<Cow<'a, str>>) -> TraceBlockResponse,
     state_unsubscribeRuntimeVersion(subscription: Cow<'a, str>) -> bool [chain_unsubscribeRuntimeVersion],
     state_unsubscribeStorage(subscription: Cow<'a, str>) -> bool,
     system_accountNextIndex(account: AccountId) -> u64,
@@ -509,6 +510,15 @@ define_methods! {
     network_unstable_subscribeEvents() -> Cow<'a, str>,
     network_unstable_unsubscribeEvents(subscription: Cow<'a, str>) -> (),
     chainHead_unstable_finalizedDatabase(#[rename = "maxSizeBytes"] max_size_bytes: Option<u64>) -> Cow<'a, str>,
+    /// Returns the list of blocks whose body contains the extrinsic with the given hash, and the
+    /// index of the extrinsic within each body. Only supported by full nodes that maintain an
+    /// index of extrinsics.
+    chain_unstable_extrinsicLocations(#[rename = "extrinsicHash"] extrinsic_hash: HashHexString) -> Vec<ExtrinsicLocation>,
+    /// Subscribes to the events emitted by the runtime during each new finalized block, or during
+    /// each new best block if `bestBlock` is `true`. The events are decoded using the metadata of
+    /// the runtime of each block.
+    chain_unstable_subscribeEvents(#[rename = "bestBlock"] best_block: Option<bool>) -> Cow<'a, str>,
+    chain_unstable_unsubscribeEvents(subscription: Cow<'a, str>) -> (),
 }
 
 define_methods! {
@@ -531,6 +541,7 @@ define_methods! {
     // This function is a custom addition in smoldot. As of the writing of this comment, there is
     // no plan to standardize it. See https://github.com/paritytech/smoldot/issues/2245.
     network_unstable_event(subscription: Cow<'a, str>, result: NetworkEvent<'a>) -> (),
+    chain_unstable_events(subscription: Cow<'a, str>, result: RuntimeEvents<'a>) -> (),
 }
 
 #[derive(Clone, PartialEq, Eq, Hash)]
@@ -992,6 +1003,13 @@ impl Header {
     }
 }
 
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct ExtrinsicLocation {
+    #[serde(rename = "blockHash")]
+    pub block_hash: HashHexString,
+    pub index: u32,
+}
+
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct HeaderDigest {
     pub logs: Vec<HexString>,
@@ -1081,6 +1099,44 @@ pub enum DispatchClass {
     Mandatory,
 }
 
+/// Unstable notification containing the events emitted by the runtime during a block.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct RuntimeEvents<'a> {
+    #[serde(rename = "blockHash")]
+    pub block_hash: HashHexString,
+    #[serde(rename = "blockNumber")]
+    pub block_number: u64,
+    pub events: Vec<RuntimeEvent<'a>>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct RuntimeEvent<'a> {
+    pub phase: RuntimeEventPhase,
+    pub pallet: Cow<'a, str>,
+    #[serde(rename = "palletIndex")]
+    pub pallet_index: u8,
+    pub variant: Cow<'a, str>,
+    #[serde(rename = "variantIndex")]
+    pub variant_index: u8,
+    /// Fields of the event. A JSON object if all the fields have a name, otherwise a JSON array.
+    pub fields: serde_json::Value,
+    pub topics: Vec<HexString>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(tag = "type")]
+pub enum RuntimeEventPhase {
+    #[serde(rename = "applyExtrinsic")]
+    ApplyExtrinsic {
+        #[serde(rename = "extrinsicIndex")]
+        extrinsic_index: u32,
+    },
+    #[serde(rename = "finalization")]
+    Finalization,
+    #[serde(rename = "initialization")]
+    Initialization,
+}
+
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct StorageChangeSet {
     pub block: HashHexString,
@@ -1115,6 +1171,120 @@ pub enum SystemPeerRole {
     Light,
 }
 
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub enum TraceBlockResponse {
+    #[serde(rename = "blockTrace")]
+    BlockTrace(BlockTrace),
+    #[serde(rename = "traceError")]
+    TraceError { error: String },
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct BlockTrace {
+    #[serde(rename = "blockHash")]
+    pub block_hash: HashHexString,
+    #[serde(rename = "parentHash")]
+    pub parent_hash: HashHexString,
+    #[serde(rename = "tracingTargets")]
+    pub tracing_targets: String,
+    #[serde(rename = "storageKeys")]
+    pub storage_keys: String,
+    pub methods: String,
+    #[serde(rename = "hostFunctionCalls")]
+    pub host_function_calls: Vec<TraceHostFunctionCall>,
+    pub events: Vec<TraceEvent>,
+    /// Error that happened while executing the block, if any. The trace contains everything
+    /// that happened up until the error.
+    #[serde(rename = "executionError")]
+    pub execution_error: Option<String>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct TraceHostFunctionCall {
+    pub name: String,
+    pub parameters: Vec<TraceWasmValue>,
+    #[serde(rename = "returnValue")]
+    pub return_value: Option<TraceWasmValue>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub enum TraceWasmValue {
+    #[serde(rename = "i32")]
+    I32(i32),
+    #[serde(rename = "i64")]
+    I64(i64),
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct TraceEvent {
+    /// Index within [`BlockTrace::host_function_calls`] of the host function call that has led
+    /// to this event.
+    #[serde(rename = "hostFunctionCall")]
+    pub host_function_call: u32,
+    #[serde(flatten)]
+    pub detail: TraceEventDetail,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(tag = "type")]
+pub enum TraceEventDetail {
+    #[serde(rename = "storageRead")]
+    StorageRead {
+        #[serde(rename = "childTrie")]
+        child_trie: Option<HexString>,
+        key: HexString,
+        value: Option<HexString>,
+    },
+    #[serde(rename = "storageWrite")]
+    StorageWrite {
+        #[serde(rename = "childTrie")]
+        child_trie: Option<HexString>,
+        key: HexString,
+        value: Option<HexString>,
+    },
+    #[serde(rename = "storageAppend")]
+    StorageAppend {
+        #[serde(rename = "childTrie")]
+        child_trie: Option<HexString>,
+        key: HexString,
+        value: HexString,
+    },
+    #[serde(rename = "storageClearPrefix")]
+    StorageClearPrefix {
+        #[serde(rename = "childTrie")]
+        child_trie: Option<HexString>,
+        prefix: HexString,
+        #[serde(rename = "keysRemoved")]
+        keys_removed: u32,
+        #[serde(rename = "someKeysRemain")]
+        some_keys_remain: bool,
+    },
+    #[serde(rename = "storageNextKey")]
+    StorageNextKey {
+        #[serde(rename = "childTrie")]
+        child_trie: Option<HexString>,
+        key: HexString,
+        #[serde(rename = "nextKey")]
+        next_key: Option<HexString>,
+    },
+    #[serde(rename = "storageRoot")]
+    StorageRoot {
+        #[serde(rename = "childTrie")]
+        child_trie: Option<HexString>,
+        hash: HashHexString,
+    },
+    #[serde(rename = "storageTransactionStart")]
+    StorageTransactionStart,
+    #[serde(rename = "storageTransactionEnd")]
+    StorageTransactionEnd { rollback: bool },
+    #[serde(rename = "log")]
+    Log {
+        level: Option<u32>,
+        target: Option<String>,
+        message: String,
+    },
+}
+
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub enum TransactionStatus {
     #[serde(rename = "future")]
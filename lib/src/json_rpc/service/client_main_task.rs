@@ -435,6 +435,7 @@ impl ClientMainTask {
                 | methods::MethodCall::state_getStorageSize { .. }
                 | methods::MethodCall::state_queryStorage { .. }
                 | methods::MethodCall::state_queryStorageAt { .. }
+                | methods::MethodCall::state_traceBlock { .. }
                 | methods::MethodCall::system_accountNextIndex { .. }
                 | methods::MethodCall::system_addReservedPeer { .. }
                 | methods::MethodCall::system_chain { .. }
@@ -458,6 +459,7 @@ impl ClientMainTask {
                 | methods::MethodCall::sudo_unstable_p2pDiscover { .. }
                 | methods::MethodCall::sudo_unstable_version { .. }
                 | methods::MethodCall::chainHead_unstable_finalizedDatabase { .. }
+                | methods::MethodCall::chain_unstable_extrinsicLocations { .. }
                 | methods::MethodCall::chainHead_unstable_header { .. }
                 | methods::MethodCall::chainHead_unstable_storageContinue { .. }
                 | methods::MethodCall::chainHead_unstable_unpin { .. } => {
@@ -483,6 +485,7 @@ impl ClientMainTask {
                 | methods::MethodCall::state_subscribeStorage { .. }
                 | methods::MethodCall::transaction_unstable_submitAndWatch { .. }
                 | methods::MethodCall::network_unstable_subscribeEvents { .. }
+                | methods::MethodCall::chain_unstable_subscribeEvents { .. }
                 | methods::MethodCall::chainHead_unstable_body { .. }
                 | methods::MethodCall::chainHead_unstable_call { .. }
                 | methods::MethodCall::chainHead_unstable_follow { .. }
@@ -551,6 +554,7 @@ impl ClientMainTask {
                 | methods::MethodCall::network_unstable_unsubscribeEvents {
                     subscription, ..
                 }
+                | methods::MethodCall::chain_unstable_unsubscribeEvents { subscription, .. }
                 | methods::MethodCall::chainHead_unstable_stopBody { subscription, .. }
                 | methods::MethodCall::chainHead_unstable_stopStorage { subscription, .. }
                 | methods::MethodCall::chainHead_unstable_stopCall { subscription, .. }
@@ -581,6 +585,9 @@ impl ClientMainTask {
                                     methods::MethodCall::network_unstable_unsubscribeEvents {
                                         ..
                                     } => methods::Response::network_unstable_unsubscribeEvents(()),
+                                    methods::MethodCall::chain_unstable_unsubscribeEvents {
+                                        ..
+                                    } => methods::Response::chain_unstable_unsubscribeEvents(()),
                                     methods::MethodCall::chainHead_unstable_stopBody { .. } => {
                                         methods::Response::chainHead_unstable_stopBody(())
                                     }
@@ -1043,6 +1050,11 @@ impl SubscriptionStartProcess {
                     &self.subscription_id,
                 ))
             }
+            methods::MethodCall::chain_unstable_subscribeEvents { .. } => {
+                methods::Response::chain_unstable_subscribeEvents(Cow::Borrowed(
+                    &self.subscription_id,
+                ))
+            }
             methods::MethodCall::chainHead_unstable_body { .. } => {
                 methods::Response::chainHead_unstable_body(Cow::Borrowed(&self.subscription_id))
             }
@@ -207,10 +207,10 @@ pub mod identity;
 pub mod informant;
 pub mod json_rpc;
 pub mod libp2p;
+pub mod metadata;
 pub mod network;
 pub mod sync;
 pub mod transactions;
 pub mod trie;
+pub mod util;
 pub mod verify;
-
-mod util;
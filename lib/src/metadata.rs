@@ -0,0 +1,999 @@
+// Smoldot
+// Copyright (C) 2023  Pierre Krieger
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Decoding of the metadata of a runtime.
+//!
+//! The metadata of a runtime describes, amongst other things, the list of pallets of the
+//! runtime, the layout of their storage, and the types of the calls, events, and errors they
+//! define. It is obtained by calling the `Metadata_metadata` or `Metadata_metadata_at_version`
+//! runtime functions.
+//!
+//! Types in the metadata are never described inline. Instead, they are designated by an
+//! identifier that refers to an entry of the *type registry* found in [`Metadata::types`]. Use
+//! [`Metadata::type_by_id`] in order to look up a type.
+//!
+//! Versions 14 and 15 of the metadata format are supported. Older versions don't contain any
+//! type registry and are thus not supported.
+//!
+//! # Example
+//!
+//! ```no_run
+//! # let runtime_output: &[u8] = &[];
+//! // `runtime_output` is the output of a call to `Metadata_metadata`.
+//! let metadata_bytes = smoldot::metadata::decode_metadata_output(runtime_output).unwrap();
+//! let metadata = smoldot::metadata::decode(metadata_bytes).unwrap();
+//!
+//! for pallet in &metadata.pallets {
+//!     println!("Pallet #{}: {}", pallet.index, pallet.name);
+//! }
+//! ```
+
+use alloc::vec::Vec;
+use core::str;
+
+pub mod events;
+pub mod value;
+
+mod tests;
+
+/// Name of the runtime function that returns the metadata in the format preferred by the
+/// runtime. Its output must be passed to [`decode_metadata_output`].
+pub const METADATA_FUNCTION_NAME: &str = "Metadata_metadata";
+
+/// Name of the runtime function that returns the metadata in a specific format. Its parameter
+/// can be obtained with [`metadata_at_version_parameter`], and its output must be passed to
+/// [`decode_metadata_at_version_output`].
+///
+/// > **Note**: This function has been added in version 2 of the `Metadata` runtime API. Older
+/// >           runtimes only provide [`METADATA_FUNCTION_NAME`].
+pub const METADATA_AT_VERSION_FUNCTION_NAME: &str = "Metadata_metadata_at_version";
+
+/// Returns the parameter to pass to [`METADATA_AT_VERSION_FUNCTION_NAME`] in order to obtain
+/// the metadata in the given format version.
+pub fn metadata_at_version_parameter(version: u32) -> [u8; 4] {
+    version.to_le_bytes()
+}
+
+/// Removes the length prefix of the output of [`METADATA_FUNCTION_NAME`]. The value returned
+/// can be passed to [`decode`].
+pub fn decode_metadata_output(output: &[u8]) -> Result<&[u8], DecodeError> {
+    match nom::combinator::all_consuming(crate::util::nom_bytes_decode::<nom::error::Error<&[u8]>>)(
+        output,
+    ) {
+        Ok((_, metadata)) => Ok(metadata),
+        Err(_) => Err(DecodeError::InvalidRuntimeOutput),
+    }
+}
+
+/// Removes the SCALE encoding of the output of [`METADATA_AT_VERSION_FUNCTION_NAME`]. Returns
+/// `None` if the runtime doesn't support the requested version. The value returned can be
+/// passed to [`decode`].
+pub fn decode_metadata_at_version_output(output: &[u8]) -> Result<Option<&[u8]>, DecodeError> {
+    match nom::combinator::all_consuming(crate::util::nom_option_decode(
+        crate::util::nom_bytes_decode::<nom::error::Error<&[u8]>>,
+    ))(output)
+    {
+        Ok((_, metadata)) => Ok(metadata),
+        Err(_) => Err(DecodeError::InvalidRuntimeOutput),
+    }
+}
+
+/// Decodes the given SCALE-encoded metadata.
+///
+/// The input must start with the `meta` magic number, as found in the output of the runtime
+/// once its length prefix has been removed.
+pub fn decode(metadata: &[u8]) -> Result<Metadata<'_>, DecodeError> {
+    let (after_magic, version) = nom::sequence::preceded(
+        nom::bytes::complete::tag::<_, _, nom::error::Error<&[u8]>>(&b"meta"[..]),
+        nom::number::complete::u8,
+    )(metadata)
+    .map_err(|_| DecodeError::InvalidMagicNumber)?;
+
+    let result = match version {
+        14 => nom::combinator::all_consuming(metadata_v14::<nom::error::Error<&[u8]>>)(after_magic),
+        15 => nom::combinator::all_consuming(metadata_v15::<nom::error::Error<&[u8]>>)(after_magic),
+        version => return Err(DecodeError::UnsupportedVersion(version)),
+    };
+
+    match result {
+        Ok((_, metadata)) => Ok(metadata),
+        Err(_) => Err(DecodeError::ParseError),
+    }
+}
+
+/// Error potentially returned by [`decode`], [`decode_metadata_output`], or
+/// [`decode_metadata_at_version_output`].
+#[derive(Debug, Clone, derive_more::Display)]
+pub enum DecodeError {
+    /// The output of the runtime function isn't in the expected format.
+    InvalidRuntimeOutput,
+    /// The metadata doesn't start with the expected magic number.
+    InvalidMagicNumber,
+    /// The version of the metadata isn't supported.
+    #[display(fmt = "Unsupported metadata version: {_0}")]
+    UnsupportedVersion(u8),
+    /// Failed to parse the metadata.
+    ParseError,
+}
+
+/// Decoded metadata of a runtime.
+#[derive(Debug, Clone)]
+pub struct Metadata<'a> {
+    /// Version of the metadata format. Either 14 or 15.
+    pub version: u8,
+    /// Registry containing all the types referred to by the rest of the metadata.
+    pub types: Vec<Type<'a>>,
+    /// List of pallets of the runtime.
+    pub pallets: Vec<Pallet<'a>>,
+    /// Information about the format of the extrinsics.
+    pub extrinsic: Extrinsic<'a>,
+    /// Identifier of the type of the runtime.
+    pub runtime_ty: u32,
+    /// List of runtime APIs of the runtime. Always empty in version 14 of the metadata.
+    pub apis: Vec<RuntimeApi<'a>>,
+    /// Identifiers of the types that aggregate the calls, events, and errors of all the pallets.
+    /// Always `None` in version 14 of the metadata.
+    pub outer_enums: Option<OuterEnums>,
+    /// Additional values not covered by the rest of the metadata. Always empty in version 14 of
+    /// the metadata.
+    pub custom: Vec<CustomValue<'a>>,
+}
+
+impl<'a> Metadata<'a> {
+    /// Returns the type of the type registry with the given identifier.
+    pub fn type_by_id(&self, id: u32) -> Option<&Type<'a>> {
+        // Types are normally stored ordered by identifier and without gap.
+        if let Some(ty) = usize::try_from(id).ok().and_then(|i| self.types.get(i)) {
+            if ty.id == id {
+                return Some(ty);
+            }
+        }
+
+        self.types.iter().find(|ty| ty.id == id)
+    }
+
+    /// Returns the pallet with the given name.
+    pub fn pallet_by_name(&self, name: &str) -> Option<&Pallet<'a>> {
+        self.pallets.iter().find(|p| p.name == name)
+    }
+
+    /// Returns the pallet with the given index.
+    pub fn pallet_by_index(&self, index: u8) -> Option<&Pallet<'a>> {
+        self.pallets.iter().find(|p| p.index == index)
+    }
+}
+
+/// Entry of the type registry. See [`Metadata::types`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Type<'a> {
+    /// Identifier of the type, used in the rest of the metadata in order to refer to it.
+    pub id: u32,
+    /// Path of the type in the source code of the runtime, for example
+    /// `["frame_system", "AccountInfo"]`. Empty for types that don't have a path, such as
+    /// primitives or tuples.
+    pub path: Vec<&'a str>,
+    /// Generic parameters of the type.
+    pub params: Vec<TypeParameter<'a>>,
+    /// Definition of the type.
+    pub def: TypeDef<'a>,
+    /// Documentation of the type.
+    pub docs: Vec<&'a str>,
+}
+
+/// Generic parameter of a type. See [`Type::params`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TypeParameter<'a> {
+    /// Name of the parameter.
+    pub name: &'a str,
+    /// Identifier of the type of the parameter, or `None` if the parameter isn't used.
+    pub ty: Option<u32>,
+}
+
+/// Definition of a type. See [`Type::def`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TypeDef<'a> {
+    /// Structure or tuple struct, encoded as the concatenation of its fields.
+    Composite {
+        /// Fields of the structure.
+        fields: Vec<Field<'a>>,
+    },
+    /// Enumeration, encoded as the index of the variant followed with its fields.
+    Variant {
+        /// Variants of the enumeration.
+        variants: Vec<Variant<'a>>,
+    },
+    /// Variable-length list of items, encoded as the SCALE-compact number of items followed
+    /// with the items.
+    Sequence {
+        /// Identifier of the type of the items.
+        item_ty: u32,
+    },
+    /// Fixed-length list of items.
+    Array {
+        /// Number of items.
+        len: u32,
+        /// Identifier of the type of the items.
+        item_ty: u32,
+    },
+    /// Tuple, encoded as the concatenation of its fields.
+    Tuple {
+        /// Identifiers of the types of the fields.
+        fields_tys: Vec<u32>,
+    },
+    /// Primitive type.
+    Primitive(Primitive),
+    /// Number encoded in the SCALE-compact format.
+    Compact {
+        /// Identifier of the type of the number.
+        ty: u32,
+    },
+    /// Sequence of bits.
+    BitSequence {
+        /// Identifier of the type of the storage unit of the bits, for example `u8`.
+        store_ty: u32,
+        /// Identifier of the type indicating the order of the bits within a storage unit.
+        order_ty: u32,
+    },
+}
+
+/// Primitive type. See [`TypeDef::Primitive`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Primitive {
+    /// Boolean, encoded as a single byte.
+    Bool,
+    /// Unicode scalar value, encoded as a little endian `u32`.
+    Char,
+    /// UTF-8 string, encoded as the SCALE-compact length followed with the bytes.
+    Str,
+    /// Unsigned 8 bits integer.
+    U8,
+    /// Unsigned 16 bits integer, encoded in little endian.
+    U16,
+    /// Unsigned 32 bits integer, encoded in little endian.
+    U32,
+    /// Unsigned 64 bits integer, encoded in little endian.
+    U64,
+    /// Unsigned 128 bits integer, encoded in little endian.
+    U128,
+    /// Unsigned 256 bits integer, encoded in little endian.
+    U256,
+    /// Signed 8 bits integer.
+    I8,
+    /// Signed 16 bits integer, encoded in little endian.
+    I16,
+    /// Signed 32 bits integer, encoded in little endian.
+    I32,
+    /// Signed 64 bits integer, encoded in little endian.
+    I64,
+    /// Signed 128 bits integer, encoded in little endian.
+    I128,
+    /// Signed 256 bits integer, encoded in little endian.
+    I256,
+}
+
+/// Field of a [`TypeDef::Composite`] or of a [`Variant`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Field<'a> {
+    /// Name of the field, or `None` for the fields of tuple structs.
+    pub name: Option<&'a str>,
+    /// Identifier of the type of the field.
+    pub ty: u32,
+    /// Name of the type of the field as written in the source code of the runtime, if known.
+    pub type_name: Option<&'a str>,
+    /// Documentation of the field.
+    pub docs: Vec<&'a str>,
+}
+
+/// Variant of a [`TypeDef::Variant`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Variant<'a> {
+    /// Name of the variant.
+    pub name: &'a str,
+    /// Fields of the variant.
+    pub fields: Vec<Field<'a>>,
+    /// Index of the variant, found at the start of its encoding.
+    pub index: u8,
+    /// Documentation of the variant.
+    pub docs: Vec<&'a str>,
+}
+
+/// Pallet of the runtime. See [`Metadata::pallets`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Pallet<'a> {
+    /// Name of the pallet.
+    pub name: &'a str,
+    /// Storage items of the pallet, or `None` if the pallet doesn't have any storage.
+    pub storage: Option<PalletStorage<'a>>,
+    /// Identifier of the enumeration of the calls of the pallet, if any.
+    pub call_ty: Option<u32>,
+    /// Identifier of the enumeration of the events of the pallet, if any.
+    pub event_ty: Option<u32>,
+    /// Constants of the pallet.
+    pub constants: Vec<PalletConstant<'a>>,
+    /// Identifier of the enumeration of the errors of the pallet, if any.
+    pub error_ty: Option<u32>,
+    /// Index of the pallet, found at the start of the encoding of calls, events, and errors.
+    pub index: u8,
+    /// Documentation of the pallet. Always empty in version 14 of the metadata.
+    pub docs: Vec<&'a str>,
+}
+
+impl<'a> Pallet<'a> {
+    /// Returns the storage entry of this pallet with the given name.
+    pub fn storage_entry_by_name(&self, name: &str) -> Option<&StorageEntry<'a>> {
+        self.storage
+            .as_ref()?
+            .entries
+            .iter()
+            .find(|entry| entry.name == name)
+    }
+}
+
+/// Storage of a pallet. See [`Pallet::storage`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PalletStorage<'a> {
+    /// Prefix of all the storage items of the pallet. The keys of the storage items start with
+    /// the `twox128` hash of this prefix.
+    pub prefix: &'a str,
+    /// Storage items of the pallet.
+    pub entries: Vec<StorageEntry<'a>>,
+}
+
+/// Storage item of a pallet. See [`PalletStorage::entries`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StorageEntry<'a> {
+    /// Name of the storage item. The keys of the storage item start with the `twox128` hash of
+    /// the prefix of the pallet followed with the `twox128` hash of this name.
+    pub name: &'a str,
+    /// Behavior of the storage item when no value is stored.
+    pub modifier: StorageEntryModifier,
+    /// Layout of the storage item.
+    pub ty: StorageEntryType,
+    /// SCALE-encoded value to use when no value is stored, if
+    /// [`StorageEntry::modifier`] is [`StorageEntryModifier::Default`].
+    pub default: &'a [u8],
+    /// Documentation of the storage item.
+    pub docs: Vec<&'a str>,
+}
+
+/// See [`StorageEntry::modifier`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum StorageEntryModifier {
+    /// The absence of value is exposed to the runtime as `None`.
+    Optional,
+    /// The absence of value is exposed to the runtime as [`StorageEntry::default`].
+    Default,
+}
+
+/// See [`StorageEntry::ty`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StorageEntryType {
+    /// The storage item consists in a single value.
+    Plain {
+        /// Identifier of the type of the value.
+        value_ty: u32,
+    },
+    /// The storage item is a map.
+    Map {
+        /// Hashers applied to each component of the key. If there are multiple hashers,
+        /// [`StorageEntryType::Map::key_ty`] is a tuple whose fields correspond to the hashers.
+        hashers: Vec<StorageHasher>,
+        /// Identifier of the type of the key.
+        key_ty: u32,
+        /// Identifier of the type of the values.
+        value_ty: u32,
+    },
+}
+
+/// Hasher applied to a component of the key of a storage map. See [`StorageEntryType::Map`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum StorageHasher {
+    /// 128 bits BLAKE2 hash.
+    Blake2_128,
+    /// 256 bits BLAKE2 hash.
+    Blake2_256,
+    /// 128 bits BLAKE2 hash followed with the SCALE-encoded key.
+    Blake2_128Concat,
+    /// 128 bits XXHash.
+    Twox128,
+    /// 256 bits XXHash.
+    Twox256,
+    /// 64 bits XXHash followed with the SCALE-encoded key.
+    Twox64Concat,
+    /// SCALE-encoded key.
+    Identity,
+}
+
+impl StorageHasher {
+    /// Returns `true` if the hash is followed with the SCALE-encoded key, making it possible
+    /// to recover the key from the hash.
+    pub fn is_concat(&self) -> bool {
+        matches!(
+            self,
+            StorageHasher::Blake2_128Concat | StorageHasher::Twox64Concat | StorageHasher::Identity
+        )
+    }
+}
+
+/// Constant of a pallet. See [`Pallet::constants`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PalletConstant<'a> {
+    /// Name of the constant.
+    pub name: &'a str,
+    /// Identifier of the type of the constant.
+    pub ty: u32,
+    /// SCALE-encoded value of the constant.
+    pub value: &'a [u8],
+    /// Documentation of the constant.
+    pub docs: Vec<&'a str>,
+}
+
+/// Format of the extrinsics. See [`Metadata::extrinsic`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Extrinsic<'a> {
+    /// Version of the extrinsics format.
+    pub version: u8,
+    /// Identifier of the type of the extrinsics. Only present in version 14 of the metadata.
+    pub ty: Option<u32>,
+    /// Identifiers of the types of the components of the extrinsics. Only present in version 15
+    /// of the metadata.
+    pub parts_tys: Option<ExtrinsicPartsTypes>,
+    /// Signed extensions, in the order in which they are encoded in the extrinsics.
+    pub signed_extensions: Vec<SignedExtension<'a>>,
+}
+
+/// See [`Extrinsic::parts_tys`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct ExtrinsicPartsTypes {
+    /// Identifier of the type of the address of the signer.
+    pub address_ty: u32,
+    /// Identifier of the type of the call.
+    pub call_ty: u32,
+    /// Identifier of the type of the signature.
+    pub signature_ty: u32,
+    /// Identifier of the type of the signed extensions.
+    pub extra_ty: u32,
+}
+
+/// Signed extension of the extrinsics. See [`Extrinsic::signed_extensions`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SignedExtension<'a> {
+    /// Name of the signed extension.
+    pub identifier: &'a str,
+    /// Identifier of the type of the data included in the extrinsic.
+    pub ty: u32,
+    /// Identifier of the type of the data included in the signed payload but not in the
+    /// extrinsic.
+    pub additional_signed_ty: u32,
+}
+
+/// Runtime API. See [`Metadata::apis`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RuntimeApi<'a> {
+    /// Name of the runtime API, for example `Core`.
+    pub name: &'a str,
+    /// Functions of the runtime API.
+    pub methods: Vec<RuntimeApiMethod<'a>>,
+    /// Documentation of the runtime API.
+    pub docs: Vec<&'a str>,
+}
+
+/// Function of a runtime API. See [`RuntimeApi::methods`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RuntimeApiMethod<'a> {
+    /// Name of the function, for example `version`. The name of the runtime function to call is
+    /// the name of the runtime API and the name of the function separated with `_`.
+    pub name: &'a str,
+    /// Parameters of the function, in order.
+    pub inputs: Vec<RuntimeApiMethodParam<'a>>,
+    /// Identifier of the type of the output of the function.
+    pub output_ty: u32,
+    /// Documentation of the function.
+    pub docs: Vec<&'a str>,
+}
+
+/// Parameter of a function of a runtime API. See [`RuntimeApiMethod::inputs`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RuntimeApiMethodParam<'a> {
+    /// Name of the parameter.
+    pub name: &'a str,
+    /// Identifier of the type of the parameter.
+    pub ty: u32,
+}
+
+/// See [`Metadata::outer_enums`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct OuterEnums {
+    /// Identifier of the enumeration of the calls of all the pallets.
+    pub call_enum_ty: u32,
+    /// Identifier of the enumeration of the events of all the pallets.
+    pub event_enum_ty: u32,
+    /// Identifier of the enumeration of the errors of all the pallets.
+    pub error_enum_ty: u32,
+}
+
+/// Custom value. See [`Metadata::custom`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CustomValue<'a> {
+    /// Name of the value.
+    pub name: &'a str,
+    /// Identifier of the type of the value.
+    pub ty: u32,
+    /// SCALE-encoded value.
+    pub value: &'a [u8],
+}
+
+fn metadata_v14<'a, E: nom::error::ParseError<&'a [u8]>>(
+    bytes: &'a [u8],
+) -> nom::IResult<&'a [u8], Metadata<'a>, E> {
+    nom::combinator::map(
+        nom::sequence::tuple((
+            vec_decode(portable_type),
+            vec_decode(pallet(false)),
+            extrinsic_v14,
+            type_id,
+        )),
+        |(types, pallets, extrinsic, runtime_ty)| Metadata {
+            version: 14,
+            types,
+            pallets,
+            extrinsic,
+            runtime_ty,
+            apis: Vec::new(),
+            outer_enums: None,
+            custom: Vec::new(),
+        },
+    )(bytes)
+}
+
+fn metadata_v15<'a, E: nom::error::ParseError<&'a [u8]>>(
+    bytes: &'a [u8],
+) -> nom::IResult<&'a [u8], Metadata<'a>, E> {
+    nom::combinator::map(
+        nom::sequence::tuple((
+            vec_decode(portable_type),
+            vec_decode(pallet(true)),
+            extrinsic_v15,
+            type_id,
+            vec_decode(runtime_api),
+            outer_enums,
+            vec_decode(custom_value),
+        )),
+        |(types, pallets, extrinsic, runtime_ty, apis, outer_enums, custom)| Metadata {
+            version: 15,
+            types,
+            pallets,
+            extrinsic,
+            runtime_ty,
+            apis,
+            outer_enums: Some(outer_enums),
+            custom,
+        },
+    )(bytes)
+}
+
+fn portable_type<'a, E: nom::error::ParseError<&'a [u8]>>(
+    bytes: &'a [u8],
+) -> nom::IResult<&'a [u8], Type<'a>, E> {
+    nom::combinator::map(
+        nom::sequence::tuple((
+            type_id,
+            vec_decode(string_decode),
+            vec_decode(type_parameter),
+            type_def,
+            docs,
+        )),
+        |(id, path, params, def, docs)| Type {
+            id,
+            path,
+            params,
+            def,
+            docs,
+        },
+    )(bytes)
+}
+
+fn type_parameter<'a, E: nom::error::ParseError<&'a [u8]>>(
+    bytes: &'a [u8],
+) -> nom::IResult<&'a [u8], TypeParameter<'a>, E> {
+    nom::combinator::map(
+        nom::sequence::tuple((string_decode, crate::util::nom_option_decode(type_id))),
+        |(name, ty)| TypeParameter { name, ty },
+    )(bytes)
+}
+
+fn type_def<'a, E: nom::error::ParseError<&'a [u8]>>(
+    bytes: &'a [u8],
+) -> nom::IResult<&'a [u8], TypeDef<'a>, E> {
+    nom::branch::alt((
+        nom::combinator::map(
+            nom::sequence::preceded(nom::bytes::complete::tag(&[0]), vec_decode(field)),
+            |fields| TypeDef::Composite { fields },
+        ),
+        nom::combinator::map(
+            nom::sequence::preceded(nom::bytes::complete::tag(&[1]), vec_decode(variant)),
+            |variants| TypeDef::Variant { variants },
+        ),
+        nom::combinator::map(
+            nom::sequence::preceded(nom::bytes::complete::tag(&[2]), type_id),
+            |item_ty| TypeDef::Sequence { item_ty },
+        ),
+        nom::combinator::map(
+            nom::sequence::preceded(
+                nom::bytes::complete::tag(&[3]),
+                nom::sequence::tuple((nom::number::complete::le_u32, type_id)),
+            ),
+            |(len, item_ty)| TypeDef::Array { len, item_ty },
+        ),
+        nom::combinator::map(
+            nom::sequence::preceded(nom::bytes::complete::tag(&[4]), vec_decode(type_id)),
+            |fields_tys| TypeDef::Tuple { fields_tys },
+        ),
+        nom::combinator::map(
+            nom::sequence::preceded(nom::bytes::complete::tag(&[5]), primitive),
+            TypeDef::Primitive,
+        ),
+        nom::combinator::map(
+            nom::sequence::preceded(nom::bytes::complete::tag(&[6]), type_id),
+            |ty| TypeDef::Compact { ty },
+        ),
+        nom::combinator::map(
+            nom::sequence::preceded(
+                nom::bytes::complete::tag(&[7]),
+                nom::sequence::tuple((type_id, type_id)),
+            ),
+            |(store_ty, order_ty)| TypeDef::BitSequence { store_ty, order_ty },
+        ),
+    ))(bytes)
+}
+
+fn primitive<'a, E: nom::error::ParseError<&'a [u8]>>(
+    bytes: &'a [u8],
+) -> nom::IResult<&'a [u8], Primitive, E> {
+    nom::combinator::map_opt(nom::number::complete::u8, |n| match n {
+        0 => Some(Primitive::Bool),
+        1 => Some(Primitive::Char),
+        2 => Some(Primitive::Str),
+        3 => Some(Primitive::U8),
+        4 => Some(Primitive::U16),
+        5 => Some(Primitive::U32),
+        6 => Some(Primitive::U64),
+        7 => Some(Primitive::U128),
+        8 => Some(Primitive::U256),
+        9 => Some(Primitive::I8),
+        10 => Some(Primitive::I16),
+        11 => Some(Primitive::I32),
+        12 => Some(Primitive::I64),
+        13 => Some(Primitive::I128),
+        14 => Some(Primitive::I256),
+        _ => None,
+    })(bytes)
+}
+
+fn field<'a, E: nom::error::ParseError<&'a [u8]>>(
+    bytes: &'a [u8],
+) -> nom::IResult<&'a [u8], Field<'a>, E> {
+    nom::combinator::map(
+        nom::sequence::tuple((
+            crate::util::nom_option_decode(string_decode),
+            type_id,
+            crate::util::nom_option_decode(string_decode),
+            docs,
+        )),
+        |(name, ty, type_name, docs)| Field {
+            name,
+            ty,
+            type_name,
+            docs,
+        },
+    )(bytes)
+}
+
+fn variant<'a, E: nom::error::ParseError<&'a [u8]>>(
+    bytes: &'a [u8],
+) -> nom::IResult<&'a [u8], Variant<'a>, E> {
+    nom::combinator::map(
+        nom::sequence::tuple((
+            string_decode,
+            vec_decode(field),
+            nom::number::complete::u8,
+            docs,
+        )),
+        |(name, fields, index, docs)| Variant {
+            name,
+            fields,
+            index,
+            docs,
+        },
+    )(bytes)
+}
+
+/// Decodes a pallet. `with_docs` must be `true` for version 15 of the metadata, where pallets
+/// have documentation.
+fn pallet<'a, E: nom::error::ParseError<&'a [u8]>>(
+    with_docs: bool,
+) -> impl FnMut(&'a [u8]) -> nom::IResult<&'a [u8], Pallet<'a>, E> {
+    nom::combinator::map(
+        nom::sequence::tuple((
+            string_decode,
+            crate::util::nom_option_decode(pallet_storage),
+            crate::util::nom_option_decode(type_id),
+            crate::util::nom_option_decode(type_id),
+            vec_decode(pallet_constant),
+            crate::util::nom_option_decode(type_id),
+            nom::number::complete::u8,
+            move |bytes| {
+                if with_docs {
+                    docs(bytes)
+                } else {
+                    Ok((bytes, Vec::new()))
+                }
+            },
+        )),
+        |(name, storage, call_ty, event_ty, constants, error_ty, index, docs)| Pallet {
+            name,
+            storage,
+            call_ty,
+            event_ty,
+            constants,
+            error_ty,
+            index,
+            docs,
+        },
+    )
+}
+
+fn pallet_storage<'a, E: nom::error::ParseError<&'a [u8]>>(
+    bytes: &'a [u8],
+) -> nom::IResult<&'a [u8], PalletStorage<'a>, E> {
+    nom::combinator::map(
+        nom::sequence::tuple((string_decode, vec_decode(storage_entry))),
+        |(prefix, entries)| PalletStorage { prefix, entries },
+    )(bytes)
+}
+
+fn storage_entry<'a, E: nom::error::ParseError<&'a [u8]>>(
+    bytes: &'a [u8],
+) -> nom::IResult<&'a [u8], StorageEntry<'a>, E> {
+    nom::combinator::map(
+        nom::sequence::tuple((
+            string_decode,
+            nom::combinator::map_opt(nom::number::complete::u8, |n| match n {
+                0 => Some(StorageEntryModifier::Optional),
+                1 => Some(StorageEntryModifier::Default),
+                _ => None,
+            }),
+            storage_entry_type,
+            crate::util::nom_bytes_decode,
+            docs,
+        )),
+        |(name, modifier, ty, default, docs)| StorageEntry {
+            name,
+            modifier,
+            ty,
+            default,
+            docs,
+        },
+    )(bytes)
+}
+
+fn storage_entry_type<'a, E: nom::error::ParseError<&'a [u8]>>(
+    bytes: &'a [u8],
+) -> nom::IResult<&'a [u8], StorageEntryType, E> {
+    nom::branch::alt((
+        nom::combinator::map(
+            nom::sequence::preceded(nom::bytes::complete::tag(&[0]), type_id),
+            |value_ty| StorageEntryType::Plain { value_ty },
+        ),
+        nom::combinator::map(
+            nom::sequence::preceded(
+                nom::bytes::complete::tag(&[1]),
+                nom::sequence::tuple((vec_decode(storage_hasher), type_id, type_id)),
+            ),
+            |(hashers, key_ty, value_ty)| StorageEntryType::Map {
+                hashers,
+                key_ty,
+                value_ty,
+            },
+        ),
+    ))(bytes)
+}
+
+fn storage_hasher<'a, E: nom::error::ParseError<&'a [u8]>>(
+    bytes: &'a [u8],
+) -> nom::IResult<&'a [u8], StorageHasher, E> {
+    nom::combinator::map_opt(nom::number::complete::u8, |n| match n {
+        0 => Some(StorageHasher::Blake2_128),
+        1 => Some(StorageHasher::Blake2_256),
+        2 => Some(StorageHasher::Blake2_128Concat),
+        3 => Some(StorageHasher::Twox128),
+        4 => Some(StorageHasher::Twox256),
+        5 => Some(StorageHasher::Twox64Concat),
+        6 => Some(StorageHasher::Identity),
+        _ => None,
+    })(bytes)
+}
+
+fn pallet_constant<'a, E: nom::error::ParseError<&'a [u8]>>(
+    bytes: &'a [u8],
+) -> nom::IResult<&'a [u8], PalletConstant<'a>, E> {
+    nom::combinator::map(
+        nom::sequence::tuple((string_decode, type_id, crate::util::nom_bytes_decode, docs)),
+        |(name, ty, value, docs)| PalletConstant {
+            name,
+            ty,
+            value,
+            docs,
+        },
+    )(bytes)
+}
+
+fn extrinsic_v14<'a, E: nom::error::ParseError<&'a [u8]>>(
+    bytes: &'a [u8],
+) -> nom::IResult<&'a [u8], Extrinsic<'a>, E> {
+    nom::combinator::map(
+        nom::sequence::tuple((
+            type_id,
+            nom::number::complete::u8,
+            vec_decode(signed_extension),
+        )),
+        |(ty, version, signed_extensions)| Extrinsic {
+            version,
+            ty: Some(ty),
+            parts_tys: None,
+            signed_extensions,
+        },
+    )(bytes)
+}
+
+fn extrinsic_v15<'a, E: nom::error::ParseError<&'a [u8]>>(
+    bytes: &'a [u8],
+) -> nom::IResult<&'a [u8], Extrinsic<'a>, E> {
+    nom::combinator::map(
+        nom::sequence::tuple((
+            nom::number::complete::u8,
+            type_id,
+            type_id,
+            type_id,
+            type_id,
+            vec_decode(signed_extension),
+        )),
+        |(version, address_ty, call_ty, signature_ty, extra_ty, signed_extensions)| Extrinsic {
+            version,
+            ty: None,
+            parts_tys: Some(ExtrinsicPartsTypes {
+                address_ty,
+                call_ty,
+                signature_ty,
+                extra_ty,
+            }),
+            signed_extensions,
+        },
+    )(bytes)
+}
+
+fn signed_extension<'a, E: nom::error::ParseError<&'a [u8]>>(
+    bytes: &'a [u8],
+) -> nom::IResult<&'a [u8], SignedExtension<'a>, E> {
+    nom::combinator::map(
+        nom::sequence::tuple((string_decode, type_id, type_id)),
+        |(identifier, ty, additional_signed_ty)| SignedExtension {
+            identifier,
+            ty,
+            additional_signed_ty,
+        },
+    )(bytes)
+}
+
+fn runtime_api<'a, E: nom::error::ParseError<&'a [u8]>>(
+    bytes: &'a [u8],
+) -> nom::IResult<&'a [u8], RuntimeApi<'a>, E> {
+    nom::combinator::map(
+        nom::sequence::tuple((string_decode, vec_decode(runtime_api_method), docs)),
+        |(name, methods, docs)| RuntimeApi {
+            name,
+            methods,
+            docs,
+        },
+    )(bytes)
+}
+
+fn runtime_api_method<'a, E: nom::error::ParseError<&'a [u8]>>(
+    bytes: &'a [u8],
+) -> nom::IResult<&'a [u8], RuntimeApiMethod<'a>, E> {
+    nom::combinator::map(
+        nom::sequence::tuple((
+            string_decode,
+            vec_decode(nom::combinator::map(
+                nom::sequence::tuple((string_decode, type_id)),
+                |(name, ty)| RuntimeApiMethodParam { name, ty },
+            )),
+            type_id,
+            docs,
+        )),
+        |(name, inputs, output_ty, docs)| RuntimeApiMethod {
+            name,
+            inputs,
+            output_ty,
+            docs,
+        },
+    )(bytes)
+}
+
+fn outer_enums<'a, E: nom::error::ParseError<&'a [u8]>>(
+    bytes: &'a [u8],
+) -> nom::IResult<&'a [u8], OuterEnums, E> {
+    nom::combinator::map(
+        nom::sequence::tuple((type_id, type_id, type_id)),
+        |(call_enum_ty, event_enum_ty, error_enum_ty)| OuterEnums {
+            call_enum_ty,
+            event_enum_ty,
+            error_enum_ty,
+        },
+    )(bytes)
+}
+
+fn custom_value<'a, E: nom::error::ParseError<&'a [u8]>>(
+    bytes: &'a [u8],
+) -> nom::IResult<&'a [u8], CustomValue<'a>, E> {
+    nom::combinator::map(
+        nom::sequence::tuple((string_decode, type_id, crate::util::nom_bytes_decode)),
+        |(name, ty, value)| CustomValue { name, ty, value },
+    )(bytes)
+}
+
+/// Decodes an identifier of the type registry, which is encoded as a SCALE-compact `u32`.
+fn type_id<'a, E: nom::error::ParseError<&'a [u8]>>(
+    bytes: &'a [u8],
+) -> nom::IResult<&'a [u8], u32, E> {
+    nom::combinator::map_opt(crate::util::nom_scale_compact_u64, |id| {
+        u32::try_from(id).ok()
+    })(bytes)
+}
+
+fn docs<'a, E: nom::error::ParseError<&'a [u8]>>(
+    bytes: &'a [u8],
+) -> nom::IResult<&'a [u8], Vec<&'a str>, E> {
+    vec_decode(string_decode)(bytes)
+}
+
+/// Decodes a SCALE-encoded string.
+///
+/// Contrary to [`crate::util::nom_string_decode`], invalid UTF-8 is reported as a regular
+/// parsing error, which avoids having to propagate an additional bound on `E`.
+fn string_decode<'a, E: nom::error::ParseError<&'a [u8]>>(
+    bytes: &'a [u8],
+) -> nom::IResult<&'a [u8], &'a str, E> {
+    nom::combinator::map_opt(crate::util::nom_bytes_decode, |s| str::from_utf8(s).ok())(bytes)
+}
+
+/// Decodes a SCALE-encoded vector of items.
+fn vec_decode<'a, O, E: nom::error::ParseError<&'a [u8]>>(
+    mut inner_decode: impl FnMut(&'a [u8]) -> nom::IResult<&'a [u8], O, E>,
+) -> impl FnMut(&'a [u8]) -> nom::IResult<&'a [u8], Vec<O>, E> {
+    move |bytes| {
+        let (mut bytes, num_elems) = crate::util::nom_scale_compact_usize(bytes)?;
+        // The capacity is capped in order to not trust the length prefix too much.
+        let mut out = Vec::with_capacity(num_elems.min(64));
+        for _ in 0..num_elems {
+            let (rest, item) = inner_decode(bytes)?;
+            out.push(item);
+            bytes = rest;
+        }
+        Ok((bytes, out))
+    }
+}
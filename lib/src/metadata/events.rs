@@ -0,0 +1,201 @@
+// Smoldot
+// Copyright (C) 2023  Pierre Krieger
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Decoding of the events emitted by the runtime during a block.
+//!
+//! The events that the runtime has emitted during the execution of a block are stored in the
+//! storage of this block under the key [`SYSTEM_EVENTS_KEY`]. Their layout depends on the
+//! runtime and is described by its metadata. Use [`decode_events`] in order to decode them.
+
+use super::{
+    value::{self, Value},
+    Metadata, StorageEntryType, TypeDef,
+};
+
+use alloc::vec::Vec;
+
+/// Storage key of the events emitted by the runtime during the block, in other words the
+/// `Events` storage item of the `System` pallet.
+///
+/// This is equal to the concatenation of `twox128("System")` and `twox128("Events")`.
+pub const SYSTEM_EVENTS_KEY: [u8; 32] = [
+    0x26, 0xaa, 0x39, 0x4e, 0xea, 0x56, 0x30, 0xe0, 0x7c, 0x48, 0xae, 0x0c, 0x95, 0x58, 0xce, 0xf7,
+    0x80, 0xd4, 0x1e, 0x5e, 0x16, 0x05, 0x67, 0x65, 0xbc, 0x84, 0x61, 0x85, 0x10, 0x72, 0xc9, 0xd7,
+];
+
+/// Decodes the value stored under [`SYSTEM_EVENTS_KEY`].
+///
+/// The events are decoded using the type registry of the given metadata, which must be the
+/// metadata of the runtime of the block the events were read from.
+pub fn decode_events<'a>(
+    metadata: &Metadata<'a>,
+    events: &'a [u8],
+) -> Result<Vec<EventRecord<'a>>, DecodeEventsError> {
+    let events_ty = match metadata
+        .pallet_by_name("System")
+        .and_then(|pallet| pallet.storage_entry_by_name("Events"))
+    {
+        Some(entry) => match entry.ty {
+            StorageEntryType::Plain { value_ty } => value_ty,
+            StorageEntryType::Map { .. } => return Err(DecodeEventsError::UnexpectedLayout),
+        },
+        None => return Err(DecodeEventsError::NoEventsStorageEntry),
+    };
+
+    // Before decoding anything, make sure that the events are a list, as the rest of the
+    // decoding would otherwise produce confusing errors.
+    if !matches!(
+        metadata.type_by_id(events_ty).map(|ty| &ty.def),
+        Some(TypeDef::Sequence { .. })
+    ) {
+        return Err(DecodeEventsError::UnexpectedLayout);
+    }
+
+    let (decoded, rest) =
+        value::decode_value(metadata, events_ty, events).map_err(DecodeEventsError::Value)?;
+    if !rest.is_empty() {
+        return Err(DecodeEventsError::TrailingData);
+    }
+
+    let Value::Sequence(records) = decoded else {
+        // Sequences of `u8`s are decoded as `Value::Bytes`.
+        return Err(DecodeEventsError::UnexpectedLayout);
+    };
+
+    records.into_iter().map(event_record).collect()
+}
+
+/// Event emitted by the runtime. See [`decode_events`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EventRecord<'a> {
+    /// Moment during the block when the event has been emitted.
+    pub phase: Phase,
+    /// Name of the pallet that has emitted the event.
+    pub pallet_name: &'a str,
+    /// Index of the pallet that has emitted the event.
+    pub pallet_index: u8,
+    /// Name of the event, in other words the name of the variant of the enumeration of the
+    /// events of the pallet.
+    pub variant_name: &'a str,
+    /// Index of the variant of the enumeration of the events of the pallet.
+    pub variant_index: u8,
+    /// Fields of the event. Each field is accompanied with its name, if any.
+    pub fields: Vec<(Option<&'a str>, Value<'a>)>,
+    /// Topics of the event, which can be used to easily find events.
+    pub topics: Vec<&'a [u8]>,
+}
+
+/// See [`EventRecord::phase`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Phase {
+    /// Event emitted during the application of the extrinsic with the given index.
+    ApplyExtrinsic(u32),
+    /// Event emitted after all the extrinsics have been applied.
+    Finalization,
+    /// Event emitted before the extrinsics are applied.
+    Initialization,
+}
+
+/// Error potentially returned by [`decode_events`].
+#[derive(Debug, Clone, derive_more::Display)]
+pub enum DecodeEventsError {
+    /// The metadata doesn't contain any `Events` storage item in the `System` pallet.
+    NoEventsStorageEntry,
+    /// The layout of the events described by the metadata isn't the expected one.
+    UnexpectedLayout,
+    /// Failed to decode the events.
+    #[display(fmt = "{_0}")]
+    Value(value::DecodeValueError),
+    /// The value contains more data than the events.
+    TrailingData,
+}
+
+/// Converts a decoded `frame_system::EventRecord` into an [`EventRecord`].
+fn event_record(record: Value) -> Result<EventRecord, DecodeEventsError> {
+    let Value::Composite(fields) = record else {
+        return Err(DecodeEventsError::UnexpectedLayout);
+    };
+
+    let mut phase = None;
+    let mut event = None;
+    let mut topics = None;
+    for (name, value) in fields {
+        match name {
+            Some("phase") => phase = Some(value),
+            Some("event") => event = Some(value),
+            Some("topics") => topics = Some(value),
+            _ => {}
+        }
+    }
+
+    let phase = match phase {
+        Some(Value::Variant { name, fields, .. }) => match (name, &fields[..]) {
+            ("ApplyExtrinsic", [(_, Value::Unsigned(index))]) => Phase::ApplyExtrinsic(
+                u32::try_from(*index).map_err(|_| DecodeEventsError::UnexpectedLayout)?,
+            ),
+            ("Finalization", []) => Phase::Finalization,
+            ("Initialization", []) => Phase::Initialization,
+            _ => return Err(DecodeEventsError::UnexpectedLayout),
+        },
+        _ => return Err(DecodeEventsError::UnexpectedLayout),
+    };
+
+    // The event is an enumeration whose variants correspond to the pallets, and whose unique
+    // field is the enumeration of the events of this pallet.
+    let (pallet_name, pallet_index, variant_name, variant_index, fields) = match event {
+        Some(Value::Variant {
+            name: pallet_name,
+            index: pallet_index,
+            fields: mut pallet_fields,
+        }) if pallet_fields.len() == 1 => match pallet_fields.pop().unwrap().1 {
+            Value::Variant {
+                name: variant_name,
+                index: variant_index,
+                fields,
+            } => (
+                pallet_name,
+                pallet_index,
+                variant_name,
+                variant_index,
+                fields,
+            ),
+            _ => return Err(DecodeEventsError::UnexpectedLayout),
+        },
+        _ => return Err(DecodeEventsError::UnexpectedLayout),
+    };
+
+    let topics = match topics {
+        Some(Value::Sequence(topics)) => topics
+            .iter()
+            .map(|topic| match topic.unwrap_newtype() {
+                Value::Bytes(topic) => Ok(*topic),
+                _ => Err(DecodeEventsError::UnexpectedLayout),
+            })
+            .collect::<Result<Vec<_>, _>>()?,
+        _ => return Err(DecodeEventsError::UnexpectedLayout),
+    };
+
+    Ok(EventRecord {
+        phase,
+        pallet_name,
+        pallet_index,
+        variant_name,
+        variant_index,
+        fields,
+        topics,
+    })
+}
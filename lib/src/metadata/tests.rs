@@ -0,0 +1,271 @@
+// Smoldot
+// Copyright (C) 2023  Pierre Krieger
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <http://www.gnu.org/licenses/>.
+
+#![cfg(test)]
+
+use crate::executor::host::{Config, HeapPages, HostVm, HostVmPrototype};
+use crate::executor::vm::ExecHint;
+
+use super::{
+    decode, decode_metadata_at_version_output, decode_metadata_output,
+    events::{decode_events, Phase},
+    value::Value,
+    DecodeError, Primitive, StorageEntryModifier, StorageEntryType, StorageHasher, TypeDef,
+};
+
+/// Returns the output of `Metadata_metadata` of the Westend runtime v9300.
+fn westend_v9300_metadata() -> Vec<u8> {
+    let proto = HostVmPrototype::new(Config {
+        module: &include_bytes!("../executor/host/westend-runtime-v9300.wasm")[..],
+        heap_pages: HeapPages::new(2048),
+        exec_hint: ExecHint::Oneshot,
+        compilation_cache: None,
+        allow_unresolved_imports: true,
+    })
+    .unwrap();
+
+    let mut vm = proto
+        .run_no_param(super::METADATA_FUNCTION_NAME)
+        .unwrap()
+        .run();
+    loop {
+        match vm {
+            HostVm::ReadyToRun(r) => vm = r.run(),
+            HostVm::Error { error, .. } => panic!("{error:?}"),
+            HostVm::Finished(finished) => break finished.value().as_ref().to_vec(),
+            HostVm::GetMaxLogLevel(r) => vm = r.resume(0),
+            _ => unreachable!(),
+        }
+    }
+}
+
+#[test]
+fn westend_v9300() {
+    let output = westend_v9300_metadata();
+    let metadata = decode(decode_metadata_output(&output).unwrap()).unwrap();
+    assert_eq!(metadata.version, 14);
+    assert!(metadata.apis.is_empty());
+    assert!(metadata.outer_enums.is_none());
+
+    for (index, ty) in metadata.types.iter().enumerate() {
+        assert_eq!(usize::try_from(ty.id).unwrap(), index);
+    }
+
+    let system = metadata.pallet_by_name("System").unwrap();
+    assert_eq!(system.index, 0);
+    assert_eq!(metadata.pallet_by_index(0).unwrap().name, "System");
+    assert_eq!(system.storage.as_ref().unwrap().prefix, "System");
+
+    let account = system.storage_entry_by_name("Account").unwrap();
+    assert_eq!(account.modifier, StorageEntryModifier::Default);
+    match &account.ty {
+        StorageEntryType::Map {
+            hashers, value_ty, ..
+        } => {
+            assert_eq!(hashers, &[StorageHasher::Blake2_128Concat]);
+            let value_ty = metadata.type_by_id(*value_ty).unwrap();
+            assert_eq!(value_ty.path, ["frame_system", "AccountInfo"]);
+            assert!(matches!(value_ty.def, TypeDef::Composite { .. }));
+        }
+        _ => panic!(),
+    }
+
+    let events = system.storage_entry_by_name("Events").unwrap();
+    assert!(matches!(events.ty, StorageEntryType::Plain { .. }));
+
+    assert!(metadata
+        .extrinsic
+        .signed_extensions
+        .iter()
+        .any(|ext| ext.identifier == "CheckNonce"));
+    assert_eq!(metadata.extrinsic.version, 4);
+}
+
+#[test]
+fn westend_v9300_events() {
+    let output = westend_v9300_metadata();
+    let metadata = decode(decode_metadata_output(&output).unwrap()).unwrap();
+
+    // A single `Balances::Transfer` event emitted by the extrinsic at index 1.
+    let events = [
+        &[4][..],
+        &[0, 1, 0, 0, 0],
+        &[4, 2],
+        &[0xaa; 32],
+        &[0xbb; 32],
+        &1_000_000u128.to_le_bytes(),
+        &[4],
+        &[0xcc; 32],
+    ]
+    .concat();
+
+    let events = decode_events(&metadata, &events).unwrap();
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].phase, Phase::ApplyExtrinsic(1));
+    assert_eq!(events[0].pallet_name, "Balances");
+    assert_eq!(events[0].pallet_index, 4);
+    assert_eq!(events[0].variant_name, "Transfer");
+    assert_eq!(events[0].variant_index, 2);
+    assert_eq!(events[0].topics, [&[0xcc; 32][..]]);
+
+    let fields = &events[0].fields;
+    assert_eq!(fields.len(), 3);
+    assert_eq!(fields[0].0, Some("from"));
+    assert_eq!(fields[0].1.unwrap_newtype(), &Value::Bytes(&[0xaa; 32]));
+    assert_eq!(fields[1].0, Some("to"));
+    assert_eq!(fields[2], (Some("amount"), Value::Unsigned(1_000_000)));
+
+    // Truncated events.
+    assert!(decode_events(&metadata, &[4, 0, 1, 0, 0, 0, 4]).is_err());
+}
+
+#[test]
+fn minimal_v15() {
+    let metadata_bytes = [
+        &b"meta"[..],
+        &[15],
+        // Types.
+        &[8],
+        &[0, 0, 0, 5, 5, 0],
+        &[4, 8, 24],
+        &b"pallet"[..],
+        &[16],
+        &b"Call"[..],
+        &[4, 4],
+        &b"T"[..],
+        &[0, 1, 4, 24],
+        &b"remark"[..],
+        &[4, 1, 4, b'x', 0, 1, 12],
+        &b"u32"[..],
+        &[0, 0, 4, 12],
+        &b"Doc"[..],
+        &[0],
+        // Pallets.
+        &[4, 24],
+        &b"System"[..],
+        &[1, 24],
+        &b"System"[..],
+        &[8, 24],
+        &b"Number"[..],
+        &[1, 0, 0, 16, 0, 0, 0, 0, 0, 12],
+        &b"Map"[..],
+        &[0, 1, 4, 5, 0, 0, 0, 0],
+        &[1, 4, 0, 4, 28],
+        &b"Version"[..],
+        &[0, 16, 1, 0, 0, 0, 0, 0, 0, 4, 52],
+        &b"System pallet"[..],
+        // Extrinsic.
+        &[4, 0, 4, 0, 0, 4, 40],
+        &b"CheckNonce"[..],
+        &[0, 0],
+        // Runtime type.
+        &[0],
+        // Runtime APIs.
+        &[4, 16],
+        &b"Core"[..],
+        &[4, 28],
+        &b"version"[..],
+        &[0, 0, 0, 0],
+        // Outer enums.
+        &[4, 4, 4],
+        // Custom values.
+        &[4, 12],
+        &b"foo"[..],
+        &[0, 16, 1, 2, 3, 4],
+    ]
+    .concat();
+
+    let metadata = decode(&metadata_bytes).unwrap();
+    assert_eq!(metadata.version, 15);
+    assert_eq!(metadata.types.len(), 2);
+    assert_eq!(
+        metadata.type_by_id(0).unwrap().def,
+        TypeDef::Primitive(Primitive::U32)
+    );
+
+    let call_ty = metadata.type_by_id(1).unwrap();
+    assert_eq!(call_ty.path, ["pallet", "Call"]);
+    assert_eq!(call_ty.params[0].name, "T");
+    assert_eq!(call_ty.params[0].ty, None);
+    match &call_ty.def {
+        TypeDef::Variant { variants } => {
+            assert_eq!(variants.len(), 1);
+            assert_eq!(variants[0].name, "remark");
+            assert_eq!(variants[0].fields[0].name, Some("x"));
+            assert_eq!(variants[0].fields[0].type_name, Some("u32"));
+            assert_eq!(variants[0].docs, ["Doc"]);
+        }
+        _ => panic!(),
+    }
+
+    let system = metadata.pallet_by_index(0).unwrap();
+    assert_eq!(system.name, "System");
+    assert_eq!(system.call_ty, Some(1));
+    assert_eq!(system.event_ty, None);
+    assert_eq!(system.constants[0].value, &[1, 0, 0, 0]);
+    assert_eq!(system.docs, ["System pallet"]);
+    assert_eq!(
+        system.storage_entry_by_name("Number").unwrap().default,
+        &[0, 0, 0, 0]
+    );
+    assert_eq!(
+        system.storage_entry_by_name("Map").unwrap().ty,
+        StorageEntryType::Map {
+            hashers: vec![StorageHasher::Twox64Concat],
+            key_ty: 0,
+            value_ty: 0
+        }
+    );
+
+    assert_eq!(metadata.extrinsic.ty, None);
+    assert_eq!(metadata.extrinsic.parts_tys.unwrap().call_ty, 1);
+    assert_eq!(metadata.apis[0].name, "Core");
+    assert_eq!(metadata.apis[0].methods[0].name, "version");
+    assert_eq!(metadata.outer_enums.unwrap().event_enum_ty, 1);
+    assert_eq!(metadata.custom[0].name, "foo");
+    assert_eq!(metadata.custom[0].value, &[1, 2, 3, 4]);
+
+    // Trailing data is refused.
+    let mut with_trailing = metadata_bytes.clone();
+    with_trailing.push(0);
+    assert!(matches!(
+        decode(&with_trailing),
+        Err(DecodeError::ParseError)
+    ));
+}
+
+#[test]
+fn unsupported_version() {
+    assert!(matches!(
+        decode(b"meta\x0d"),
+        Err(DecodeError::UnsupportedVersion(13))
+    ));
+    assert!(matches!(
+        decode(b"atem\x0e"),
+        Err(DecodeError::InvalidMagicNumber)
+    ));
+}
+
+#[test]
+fn at_version_output() {
+    assert_eq!(decode_metadata_at_version_output(&[0]).unwrap(), None);
+    assert_eq!(
+        decode_metadata_at_version_output(&[1, 8, 1, 2]).unwrap(),
+        Some(&[1, 2][..])
+    );
+    assert!(decode_metadata_at_version_output(&[1, 8, 1]).is_err());
+}
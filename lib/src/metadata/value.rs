@@ -0,0 +1,323 @@
+// Smoldot
+// Copyright (C) 2023  Pierre Krieger
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Decoding of SCALE-encoded values whose layout is described by the type registry of the
+//! metadata.
+//!
+//! See [`decode_value`].
+
+use super::{Metadata, Primitive, TypeDef};
+
+use alloc::vec::Vec;
+
+/// Maximum nesting depth of the types being decoded.
+///
+/// The type registry is provided by the runtime and might contain recursive types. This limit
+/// guarantees that decoding can't overflow the stack.
+const MAX_DEPTH: usize = 64;
+
+/// Decodes a SCALE-encoded value of the type with the given identifier.
+///
+/// Returns the decoded value and the bytes that follow it.
+pub fn decode_value<'a>(
+    metadata: &Metadata<'a>,
+    ty: u32,
+    bytes: &'a [u8],
+) -> Result<(Value<'a>, &'a [u8]), DecodeValueError> {
+    decode_inner(metadata, ty, bytes, 0)
+}
+
+/// Value decoded by [`decode_value`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Value<'a> {
+    /// Boolean.
+    Bool(bool),
+    /// Unicode scalar value.
+    Char(char),
+    /// UTF-8 string.
+    Str(&'a str),
+    /// Unsigned integer of at most 128 bits, either encoded in little endian or in the
+    /// SCALE-compact format.
+    Unsigned(u128),
+    /// Signed integer of at most 128 bits.
+    Signed(i128),
+    /// Unsigned 256 bits integer, in little endian.
+    U256(&'a [u8; 32]),
+    /// Signed 256 bits integer, in little endian.
+    I256(&'a [u8; 32]),
+    /// Sequence or array of `u8`s.
+    Bytes(&'a [u8]),
+    /// Sequence, array, or tuple whose items aren't `u8`s.
+    Sequence(Vec<Value<'a>>),
+    /// Structure. Each field is accompanied with its name, if any.
+    Composite(Vec<(Option<&'a str>, Value<'a>)>),
+    /// Variant of an enumeration.
+    Variant {
+        /// Name of the variant.
+        name: &'a str,
+        /// Index of the variant.
+        index: u8,
+        /// Fields of the variant. Each field is accompanied with its name, if any.
+        fields: Vec<(Option<&'a str>, Value<'a>)>,
+    },
+    /// Sequence of bits.
+    BitSequence {
+        /// Number of bits in the sequence.
+        num_bits: usize,
+        /// Storage units containing the bits, as found in the encoded value. The order of the
+        /// bits within each storage unit isn't decoded.
+        data: &'a [u8],
+    },
+}
+
+impl<'a> Value<'a> {
+    /// If this value is a [`Value::Composite`] containing a single unnamed field, returns the
+    /// value of this field. Otherwise, returns the value itself.
+    ///
+    /// Wrapper types such as `H256` or `AccountId32` are composites containing a single unnamed
+    /// field, which this function removes.
+    pub fn unwrap_newtype(&self) -> &Value<'a> {
+        match self {
+            Value::Composite(fields) if fields.len() == 1 && fields[0].0.is_none() => {
+                fields[0].1.unwrap_newtype()
+            }
+            v => v,
+        }
+    }
+}
+
+/// Error potentially returned by [`decode_value`].
+#[derive(Debug, Clone, derive_more::Display)]
+pub enum DecodeValueError {
+    /// A type referred to by the metadata can't be found in the type registry.
+    #[display(fmt = "Unknown type: {_0}")]
+    UnknownType(u32),
+    /// The encoded value isn't valid or is truncated.
+    InvalidEncoding,
+    /// The encoded value refers to a variant of an enumeration that doesn't exist.
+    #[display(fmt = "Unknown variant {index} in type {ty}")]
+    UnknownVariant {
+        /// Identifier of the type of the enumeration.
+        ty: u32,
+        /// Index of the variant found in the encoded value.
+        index: u8,
+    },
+    /// The storage unit of a bit sequence isn't an unsigned integer.
+    InvalidBitSequenceStore,
+    /// Types are nested too deeply.
+    RecursionLimitReached,
+}
+
+fn decode_inner<'a>(
+    metadata: &Metadata<'a>,
+    ty: u32,
+    bytes: &'a [u8],
+    depth: usize,
+) -> Result<(Value<'a>, &'a [u8]), DecodeValueError> {
+    if depth >= MAX_DEPTH {
+        return Err(DecodeValueError::RecursionLimitReached);
+    }
+
+    let ty_def = &metadata
+        .type_by_id(ty)
+        .ok_or(DecodeValueError::UnknownType(ty))?
+        .def;
+
+    match ty_def {
+        TypeDef::Composite { fields } => {
+            let mut bytes = bytes;
+            let mut out = Vec::with_capacity(fields.len());
+            for field in fields {
+                let (value, rest) = decode_inner(metadata, field.ty, bytes, depth + 1)?;
+                out.push((field.name, value));
+                bytes = rest;
+            }
+            Ok((Value::Composite(out), bytes))
+        }
+        TypeDef::Variant { variants } => {
+            let (&index, mut bytes) = bytes
+                .split_first()
+                .ok_or(DecodeValueError::InvalidEncoding)?;
+            let variant = variants
+                .iter()
+                .find(|v| v.index == index)
+                .ok_or(DecodeValueError::UnknownVariant { ty, index })?;
+            let mut fields = Vec::with_capacity(variant.fields.len());
+            for field in &variant.fields {
+                let (value, rest) = decode_inner(metadata, field.ty, bytes, depth + 1)?;
+                fields.push((field.name, value));
+                bytes = rest;
+            }
+            Ok((
+                Value::Variant {
+                    name: variant.name,
+                    index,
+                    fields,
+                },
+                bytes,
+            ))
+        }
+        TypeDef::Sequence { item_ty } => {
+            let (bytes, len) =
+                crate::util::nom_scale_compact_usize::<nom::error::Error<&[u8]>>(bytes)
+                    .map_err(|_| DecodeValueError::InvalidEncoding)?;
+            decode_items(metadata, *item_ty, len, bytes, depth)
+        }
+        TypeDef::Array { len, item_ty } => {
+            let len = usize::try_from(*len).map_err(|_| DecodeValueError::InvalidEncoding)?;
+            decode_items(metadata, *item_ty, len, bytes, depth)
+        }
+        TypeDef::Tuple { fields_tys } => {
+            let mut bytes = bytes;
+            let mut out = Vec::with_capacity(fields_tys.len());
+            for field_ty in fields_tys {
+                let (value, rest) = decode_inner(metadata, *field_ty, bytes, depth + 1)?;
+                out.push(value);
+                bytes = rest;
+            }
+            Ok((Value::Sequence(out), bytes))
+        }
+        TypeDef::Primitive(primitive) => decode_primitive(*primitive, bytes),
+        TypeDef::Compact { .. } => {
+            // The type wrapped in a `Compact` is always an integer or a structure containing a
+            // single integer. In both cases, the value is reported as an integer.
+            let (bytes, value) =
+                crate::util::nom_scale_compact_u128::<nom::error::Error<&[u8]>>(bytes)
+                    .map_err(|_| DecodeValueError::InvalidEncoding)?;
+            Ok((Value::Unsigned(value), bytes))
+        }
+        TypeDef::BitSequence { store_ty, .. } => {
+            let store_size = match metadata.type_by_id(*store_ty).map(|t| &t.def) {
+                Some(TypeDef::Primitive(Primitive::U8)) => 1,
+                Some(TypeDef::Primitive(Primitive::U16)) => 2,
+                Some(TypeDef::Primitive(Primitive::U32)) => 4,
+                Some(TypeDef::Primitive(Primitive::U64)) => 8,
+                Some(_) => return Err(DecodeValueError::InvalidBitSequenceStore),
+                None => return Err(DecodeValueError::UnknownType(*store_ty)),
+            };
+            let (bytes, num_bits) =
+                crate::util::nom_scale_compact_usize::<nom::error::Error<&[u8]>>(bytes)
+                    .map_err(|_| DecodeValueError::InvalidEncoding)?;
+            let num_bytes = num_bits
+                .checked_add(store_size * 8 - 1)
+                .ok_or(DecodeValueError::InvalidEncoding)?
+                / (store_size * 8)
+                * store_size;
+            let (data, bytes) = take(bytes, num_bytes)?;
+            Ok((Value::BitSequence { num_bits, data }, bytes))
+        }
+    }
+}
+
+/// Decodes `len` items of the given type. Used for sequences and arrays.
+fn decode_items<'a>(
+    metadata: &Metadata<'a>,
+    item_ty: u32,
+    len: usize,
+    bytes: &'a [u8],
+    depth: usize,
+) -> Result<(Value<'a>, &'a [u8]), DecodeValueError> {
+    if matches!(
+        metadata.type_by_id(item_ty).map(|t| &t.def),
+        Some(TypeDef::Primitive(Primitive::U8))
+    ) {
+        let (data, bytes) = take(bytes, len)?;
+        return Ok((Value::Bytes(data), bytes));
+    }
+
+    let mut bytes = bytes;
+    // The capacity is capped in order to not trust the length prefix too much.
+    let mut out = Vec::with_capacity(len.min(64));
+    for _ in 0..len {
+        let (value, rest) = decode_inner(metadata, item_ty, bytes, depth + 1)?;
+        out.push(value);
+        bytes = rest;
+    }
+    Ok((Value::Sequence(out), bytes))
+}
+
+fn decode_primitive(
+    primitive: Primitive,
+    bytes: &[u8],
+) -> Result<(Value<'_>, &[u8]), DecodeValueError> {
+    Ok(match primitive {
+        Primitive::Bool => {
+            let (bytes, value) = crate::util::nom_bool_decode::<nom::error::Error<&[u8]>>(bytes)
+                .map_err(|_| DecodeValueError::InvalidEncoding)?;
+            (Value::Bool(value), bytes)
+        }
+        Primitive::Char => {
+            let (value, bytes) = take(bytes, 4)?;
+            let value = char::from_u32(u32::from_le_bytes(<[u8; 4]>::try_from(value).unwrap()))
+                .ok_or(DecodeValueError::InvalidEncoding)?;
+            (Value::Char(value), bytes)
+        }
+        Primitive::Str => {
+            let (bytes, value) = crate::util::nom_bytes_decode::<nom::error::Error<&[u8]>>(bytes)
+                .map_err(|_| DecodeValueError::InvalidEncoding)?;
+            let value =
+                core::str::from_utf8(value).map_err(|_| DecodeValueError::InvalidEncoding)?;
+            (Value::Str(value), bytes)
+        }
+        Primitive::U8 | Primitive::U16 | Primitive::U32 | Primitive::U64 | Primitive::U128 => {
+            let (value, bytes) = take(bytes, primitive_size(primitive))?;
+            let mut buf = [0; 16];
+            buf[..value.len()].copy_from_slice(value);
+            (Value::Unsigned(u128::from_le_bytes(buf)), bytes)
+        }
+        Primitive::I8 | Primitive::I16 | Primitive::I32 | Primitive::I64 | Primitive::I128 => {
+            let (value, bytes) = take(bytes, primitive_size(primitive))?;
+            // Sign-extend the value.
+            let fill = if value.last().is_some_and(|b| b & 0x80 != 0) {
+                0xff
+            } else {
+                0
+            };
+            let mut buf = [fill; 16];
+            buf[..value.len()].copy_from_slice(value);
+            (Value::Signed(i128::from_le_bytes(buf)), bytes)
+        }
+        Primitive::U256 => {
+            let (value, bytes) = take(bytes, 32)?;
+            (Value::U256(<&[u8; 32]>::try_from(value).unwrap()), bytes)
+        }
+        Primitive::I256 => {
+            let (value, bytes) = take(bytes, 32)?;
+            (Value::I256(<&[u8; 32]>::try_from(value).unwrap()), bytes)
+        }
+    })
+}
+
+/// Returns the number of bytes of the encoding of the given integer primitive.
+fn primitive_size(primitive: Primitive) -> usize {
+    match primitive {
+        Primitive::U8 | Primitive::I8 => 1,
+        Primitive::U16 | Primitive::I16 => 2,
+        Primitive::U32 | Primitive::I32 => 4,
+        Primitive::U64 | Primitive::I64 => 8,
+        Primitive::U128 | Primitive::I128 => 16,
+        _ => unreachable!(),
+    }
+}
+
+/// Splits the first `num` bytes of `bytes`.
+fn take(bytes: &[u8], num: usize) -> Result<(&[u8], &[u8]), DecodeValueError> {
+    if bytes.len() < num {
+        return Err(DecodeValueError::InvalidEncoding);
+    }
+    Ok(bytes.split_at(num))
+}
@@ -35,9 +35,10 @@
 //! it does so, [`GrandpaWarpSyncResponse::is_finished`] should be set to `false`, so that the
 //! requester can start additional warp sync requests afterwards.
 
-use crate::{finality, header};
+use crate::{finality, header, util};
 
 use alloc::vec::Vec;
+use core::iter;
 
 // TODO: all the constraints explained here should be checked when decoding the message
 
@@ -69,6 +70,39 @@ pub struct GrandpaWarpSyncResponseFragment<'a> {
     pub scale_encoded_justification: &'a [u8],
 }
 
+/// Error potentially returned by [`decode_grandpa_warp_sync_request`].
+#[derive(Debug, derive_more::Display)]
+#[display(fmt = "Failed to decode request")]
+pub struct DecodeGrandpaWarpSyncRequestError;
+
+/// Decodes a GrandPa warp sync request. Returns the hash of the block the requester starts
+/// from.
+pub fn decode_grandpa_warp_sync_request(
+    request_bytes: &[u8],
+) -> Result<[u8; 32], DecodeGrandpaWarpSyncRequestError> {
+    <[u8; 32]>::try_from(request_bytes).map_err(|_| DecodeGrandpaWarpSyncRequestError)
+}
+
+/// Builds the bytes corresponding to a GrandPa warp sync response.
+pub fn build_grandpa_warp_sync_response<'a>(
+    response: &'a GrandpaWarpSyncResponse<'a>,
+) -> impl Iterator<Item = impl AsRef<[u8]> + 'a> + 'a {
+    iter::once(either::Left(util::encode_scale_compact_usize(
+        response.fragments.len(),
+    )))
+    .chain(response.fragments.iter().flat_map(|fragment| {
+        [
+            either::Right(fragment.scale_encoded_header),
+            either::Right(fragment.scale_encoded_justification),
+        ]
+    }))
+    .chain(iter::once(either::Right(if response.is_finished {
+        &[1][..]
+    } else {
+        &[0][..]
+    })))
+}
+
 /// Error potentially returned by [`decode_grandpa_warp_sync_response`].
 #[derive(Debug, derive_more::Display)]
 #[display(fmt = "Failed to decode response")]
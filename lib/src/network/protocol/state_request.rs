@@ -90,7 +90,7 @@ pub fn build_state_request(
         }
         StateRequestStart::ChildTrieDefault { child_trie, key } => either::Right(
             protobuf::bytes_tag_encode(2, {
-                let mut vec = b":child_storage:default:".to_vec();
+                let mut vec = crate::trie::CHILD_STORAGE_PREFIX.to_vec();
                 vec.extend(child_trie);
                 vec
             })
@@ -119,6 +119,9 @@ pub struct ChainConfig {
     /// `true` if incoming block requests are allowed.
     pub allow_inbound_block_requests: bool,
 
+    /// `true` if incoming GrandPa warp sync requests are allowed.
+    pub allow_inbound_grandpa_warp_sync_requests: bool,
+
     pub in_slots: u32,
 
     pub out_slots: u32,
@@ -237,6 +240,7 @@ struct KBucketsPeer {
 enum InRequestTy {
     Identify { observed_addr: multiaddr::Multiaddr },
     Blocks,
+    GrandpaWarpSync,
 }
 
 enum OutRequestTy {
@@ -1268,6 +1272,23 @@ pub enum Event {
         request_id: InRequestId,
     },
 
+    /// A remote has sent a request for a GrandPa warp sync proof.
+    ///
+    /// Can only happen for chains where
+    /// [`ChainConfig::allow_inbound_grandpa_warp_sync_requests`] is `true`.
+    ///
+    /// You are strongly encouraged to call [`ChainNetwork::respond_grandpa_warp_sync`].
+    GrandpaWarpSyncRequestIn {
+        /// Remote that has sent the request.
+        peer_id: PeerId,
+        /// Index of the chain concerned by the request.
+        chain_index: usize,
+        /// Hash of the block the proof should start from.
+        begin_hash: [u8; 32],
+        /// Identifier of the request. Necessary to send back the answer.
+        request_id: InRequestId,
+    },
+
     RequestInCancel {
         request_id: InRequestId,
     },
@@ -1308,4 +1329,7 @@ pub enum ProtocolError {
     /// Error while decoding a received blocks request.
     #[display(fmt = "Error while decoding a received blocks request: {_0}")]
     BadBlocksRequest(protocol::DecodeBlockRequestError),
+    /// Error while decoding a received GrandPa warp sync request.
+    #[display(fmt = "Error while decoding a received GrandPa warp sync request: {_0}")]
+    BadGrandpaWarpSyncRequest(protocol::DecodeGrandpaWarpSyncRequestError),
 }
@@ -67,6 +67,13 @@
 //! be malicious. After all, a chain whose runtime is intentionally trying to crash a client is
 //! borked and there is no reason to connect to it.
 //!
+//! > **Note**: Runtime calls that aren't part of the synchronization, such as calls requested by
+//! >           a JSON-RPC client, can however be given a maximum number of instructions to execute
+//! >           and a maximum size of storage changes.
+//! >           See [`crate::executor::vm::ExecHint::Untrusted`],
+//! >           [`crate::executor::vm::Prepare::set_fuel`], and
+//! >           [`crate::executor::runtime_host::Config::max_pending_changes_size`].
+//!
 
 pub mod all;
 pub mod all_forks;
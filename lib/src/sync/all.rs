@@ -32,7 +32,7 @@
 
 use crate::{
     chain::{blocks_tree, chain_information},
-    executor::host,
+    executor::{host, vm},
     header,
     sync::{all_forks, optimistic, warp_sync},
     verify,
@@ -2526,6 +2526,7 @@ impl<TRq, TSrc, TBl> FinalityProofVerify<TRq, TSrc, TBl> {
                         all_forks::FinalityProofVerifyOutcome::NewFinalized {
                             finalized_blocks,
                             updates_best_block,
+                            mut justification,
                         },
                     ) => (
                         sync,
@@ -2535,7 +2536,8 @@ impl<TRq, TSrc, TBl> FinalityProofVerify<TRq, TSrc, TBl> {
                                 .map(|b| Block {
                                     full: None, // TODO: wrong
                                     header: b.0,
-                                    justifications: Vec::new(), // TODO: wrong
+                                    // The justification, if any, targets the first block.
+                                    justifications: justification.take().into_iter().collect(),
                                     user_data: b.1.unwrap(),
                                 })
                                 .collect(),
@@ -2775,6 +2777,13 @@ impl<TRq, TSrc, TBl> HeaderBodyVerify<TRq, TSrc, TBl> {
         }
     }
 
+    /// Returns the list of SCALE-encoded extrinsics of the block about to be verified.
+    pub fn scale_encoded_extrinsics(&self) -> &[Vec<u8>] {
+        match &self.inner {
+            HeaderBodyVerifyInner::Optimistic(verify) => verify.scale_encoded_extrinsics(),
+        }
+    }
+
     /// Start the verification process.
     pub fn start(
         self,
@@ -3097,8 +3106,13 @@ pub struct BlockVerificationRuntimeCompilation<TRq, TSrc, TBl> {
 
 impl<TRq, TSrc, TBl> BlockVerificationRuntimeCompilation<TRq, TSrc, TBl> {
     /// Builds the runtime.
-    pub fn build(self) -> BlockVerification<TRq, TSrc, TBl> {
-        let inner = self.inner.build();
+    ///
+    /// See [`vm::Config::compilation_cache`] for an explanation of `compilation_cache`.
+    pub fn build(
+        self,
+        compilation_cache: Option<&dyn vm::CompilationCache>,
+    ) -> BlockVerification<TRq, TSrc, TBl> {
+        let inner = self.inner.build(compilation_cache);
         BlockVerification::from_inner(inner, self.shared, self.user_data)
     }
 }
@@ -84,11 +84,11 @@
 
 use crate::{
     chain::{blocks_tree, chain_information},
-    finality::grandpa,
+    finality::{self, grandpa},
     header, verify,
 };
 
-use alloc::{borrow::ToOwned as _, boxed::Box, vec::Vec};
+use alloc::{borrow::ToOwned as _, boxed::Box, collections::BTreeSet, vec::Vec};
 use core::{
     cmp, mem,
     num::{NonZeroU32, NonZeroU64},
@@ -2103,9 +2103,65 @@ impl<TBl, TRq, TSrc> FinalityProofVerify<TBl, TRq, TSrc> {
                             self.parent.inner.blocks.set_finalized_block_height(
                                 finalized_blocks.last().unwrap().0.number,
                             );
+
+                        // Convert the commit into a justification, so that the finality of the
+                        // block can later be proven to third parties. The headers of the blocks
+                        // that have been voted on are still in the tree, as they descend from
+                        // the block that has just been finalized.
+                        let justification = {
+                            let block_number_bytes = self.parent.chain.block_number_bytes();
+                            // The commit has been successfully verified and can thus be decoded.
+                            let decoded_commit = grandpa::commit::decode::decode_grandpa_commit(
+                                &scale_encoded_commit,
+                                block_number_bytes,
+                            )
+                            .unwrap();
+
+                            let mut votes_ancestries = Vec::new();
+                            let mut visited = BTreeSet::new();
+                            let mut all_known = true;
+                            for precommit in &decoded_commit.message.precommits {
+                                let mut hash = *precommit.target_hash;
+                                while hash != *decoded_commit.message.target_hash
+                                    && visited.insert(hash)
+                                {
+                                    let scale_encoded_header = match self
+                                        .parent
+                                        .chain
+                                        .non_finalized_block_scale_encoded_header(&hash)
+                                    {
+                                        Some(h) => h,
+                                        None => {
+                                            all_known = false;
+                                            break;
+                                        }
+                                    };
+                                    votes_ancestries.push(scale_encoded_header);
+                                    hash =
+                                        *header::decode(scale_encoded_header, block_number_bytes)
+                                            .unwrap()
+                                            .parent_hash;
+                                }
+                            }
+
+                            if all_known {
+                                Some((
+                                    *b"FRNK",
+                                    finality::justification::encode::encode_grandpa_from_commit(
+                                        &decoded_commit,
+                                        votes_ancestries.into_iter(),
+                                        block_number_bytes,
+                                    ),
+                                ))
+                            } else {
+                                None
+                            }
+                        };
+
                         FinalityProofVerifyOutcome::NewFinalized {
                             finalized_blocks,
                             updates_best_block,
+                            justification,
                         }
                     }
                     // In case where the commit message concerns a block older or equal to the
@@ -2161,6 +2217,7 @@ impl<TBl, TRq, TSrc> FinalityProofVerify<TBl, TRq, TSrc> {
                         FinalityProofVerifyOutcome::NewFinalized {
                             finalized_blocks,
                             updates_best_block,
+                            justification: Some((consensus_engine_id, scale_encoded_justification)),
                         }
                     }
                     // In case where the commit message concerns a block older or equal to the
@@ -2258,6 +2315,13 @@ pub enum FinalityProofVerifyOutcome<TBl> {
         /// This can happen if the previous best block isn't a descendant of the now finalized
         /// block.
         updates_best_block: bool,
+        /// Consensus engine id and SCALE-encoded justification proving the finality of the first
+        /// block of `finalized_blocks`.
+        ///
+        /// If the finality proof was a GrandPa commit message, contains the equivalent GrandPa
+        /// justification. `None` if the headers of some of the blocks voted on by the commit
+        /// aren't known, which is never the case in practice.
+        justification: Option<([u8; 4], Vec<u8>)>,
     },
     /// Finality proof concerns block that was already finalized.
     AlreadyFinalized,
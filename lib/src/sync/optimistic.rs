@@ -47,7 +47,7 @@
 
 use crate::{
     chain::{blocks_tree, chain_information},
-    executor::host,
+    executor::{host, vm},
     header,
 };
 
@@ -816,6 +816,18 @@ impl<TRq, TSrc, TBl> BlockVerify<TRq, TSrc, TBl> {
             .scale_encoded_header
     }
 
+    /// Returns the list of SCALE-encoded extrinsics of the block about to be verified.
+    ///
+    /// This list is always empty if [`Config::full_mode`] was `false` at initialization.
+    pub fn scale_encoded_extrinsics(&self) -> &[Vec<u8>] {
+        &self
+            .inner
+            .verification_queue
+            .first_block()
+            .unwrap()
+            .scale_encoded_extrinsics
+    }
+
     /// Start the verification of the block.
     ///
     /// Must be passed the current UNIX time in order to verify that the block doesn't pretend to
@@ -1469,8 +1481,13 @@ pub struct RuntimeCompilation<TRq, TSrc, TBl> {
 
 impl<TRq, TSrc, TBl> RuntimeCompilation<TRq, TSrc, TBl> {
     /// Builds the runtime.
-    pub fn build(self) -> BlockVerification<TRq, TSrc, TBl> {
-        let inner = self.inner.build();
+    ///
+    /// See [`vm::Config::compilation_cache`] for an explanation of `compilation_cache`.
+    pub fn build(
+        self,
+        compilation_cache: Option<&dyn vm::CompilationCache>,
+    ) -> BlockVerification<TRq, TSrc, TBl> {
+        let inner = self.inner.build(compilation_cache);
         BlockVerification::from(Inner::Step2(inner), self.shared)
     }
 }
@@ -1284,6 +1284,7 @@ impl<TSrc, TRq> BuildRuntime<TSrc, TRq> {
                 module: &finalized_storage_code,
                 heap_pages: decoded_heap_pages,
                 exec_hint,
+                compilation_cache: None,
                 allow_unresolved_imports,
             }) {
                 Ok(runtime) => runtime,
@@ -335,6 +335,9 @@ pub fn validate_transaction(
                 storage_main_trie_changes: storage_diff::TrieDiff::empty(),
                 offchain_storage_changes: Default::default(),
                 max_log_level: config.max_log_level,
+                trace: false,
+                fuel_budget: None,
+                max_pending_changes_size: None,
             });
 
             // Information used later, after `Core_initialize_block` is done.
@@ -372,6 +375,9 @@ pub fn validate_transaction(
                 storage_main_trie_changes: storage_diff::TrieDiff::empty(),
                 offchain_storage_changes: Default::default(),
                 max_log_level: config.max_log_level,
+                trace: false,
+                fuel_budget: None,
+                max_pending_changes_size: None,
             });
 
             match vm {
@@ -461,6 +467,9 @@ impl Query {
                         storage_main_trie_changes: success.storage_changes.into_main_trie_diff(),
                         offchain_storage_changes: success.offchain_storage_changes,
                         max_log_level: info.max_log_level,
+                        trace: false,
+                        fuel_budget: None,
+                        max_pending_changes_size: None,
                     });
 
                     match vm {
@@ -490,6 +499,23 @@ impl Query {
                     inner = sig.verify_and_resume();
                     continue;
                 }
+                // No keystore is available when validating a transaction.
+                runtime_host::RuntimeHostVm::KeystorePublicKeys(req) => {
+                    inner = req.resume(iter::empty::<&[u8]>());
+                    continue;
+                }
+                runtime_host::RuntimeHostVm::KeystoreGenerate(req) => {
+                    inner = req.resume(None);
+                    continue;
+                }
+                runtime_host::RuntimeHostVm::SignRequest(req) => {
+                    inner = req.resume(None);
+                    continue;
+                }
+                runtime_host::RuntimeHostVm::Offchain(ctx) => {
+                    inner = ctx.reject();
+                    continue;
+                }
             };
         }
     }
@@ -550,6 +576,23 @@ impl Query {
                     inner = sig.verify_and_resume();
                     continue;
                 }
+                // No keystore is available when validating a transaction.
+                runtime_host::RuntimeHostVm::KeystorePublicKeys(req) => {
+                    inner = req.resume(iter::empty::<&[u8]>());
+                    continue;
+                }
+                runtime_host::RuntimeHostVm::KeystoreGenerate(req) => {
+                    inner = req.resume(None);
+                    continue;
+                }
+                runtime_host::RuntimeHostVm::SignRequest(req) => {
+                    inner = req.resume(None);
+                    continue;
+                }
+                runtime_host::RuntimeHostVm::Offchain(ctx) => {
+                    inner = ctx.reject();
+                    continue;
+                }
             };
         }
     }
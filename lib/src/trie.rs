@@ -106,12 +106,15 @@ use alloc::vec::Vec;
 use core::{cmp, ops::Bound};
 
 mod nibble;
+mod test_fixtures;
 
 pub mod branch_search;
 pub mod calculate_root;
+pub mod compact_proof;
 pub mod prefix_proof;
 pub mod proof_decode;
 pub mod proof_encode;
+pub mod storage_diff;
 pub mod trie_node;
 pub mod trie_structure;
 
@@ -156,6 +159,11 @@ impl From<TrieEntryVersion> for u8 {
     }
 }
 
+/// Prefix of the keys of the main trie under which the roots of the child tries are stored. The
+/// root of a child trie is found at the key made of this prefix followed with the identifier of
+/// the child trie.
+pub const CHILD_STORAGE_PREFIX: &[u8] = b":child_storage:default:";
+
 /// Merkle value of the root node of an empty trie.
 pub const EMPTY_TRIE_MERKLE_VALUE: [u8; 32] = [
     3, 23, 10, 46, 117, 151, 183, 183, 227, 216, 76, 5, 57, 29, 19, 154, 98, 177, 87, 231, 135,
@@ -0,0 +1,344 @@
+// Smoldot
+// Copyright (C) 2023  Pierre Krieger
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Encoding and decoding of compact trie proofs.
+//!
+//! A compact trie proof is an alternative encoding of a trie proof (see the [`proof_decode`]
+//! module) that omits the information that can be recalculated by the receiver of the proof.
+//!
+//! # Details
+//!
+//! Similar to a regular trie proof, a compact trie proof is a SCALE-encoded `Vec<Vec<u8>>`.
+//!
+//! Each trie found in the proof is encoded as a list of node values, in the order of a
+//! depth-first pre-order traversal of the trie, starting with its root node. Nodes whose node
+//! value is inlined within their parent aren't part of this list.
+//!
+//! Within these node values, the Merkle value of each child that can be found later in the list
+//! is replaced with an empty inline Merkle value. The receiver can recalculate the Merkle value
+//! of the child by hashing its node value.
+//!
+//! When the storage value of a node is hashed and the unhashed storage value is part of the
+//! proof, the node is encoded as if it had an empty unhashed storage value, the resulting node
+//! value is prefixed with a `0x01` byte, and the unhashed storage value directly follows it in
+//! the list.
+//!
+//! The main trie is always encoded first. It is followed with the child tries whose root hash
+//! is found in the main trie, in the lexicographic order of the keys of the main trie they are
+//! found at.
+//!
+//! # Usage
+//!
+//! Use [`encode_compact_proof`] to turn a [`proof_decode::DecodedTrieProof`] into a compact
+//! proof, and [`decode_and_verify_compact_proof`] to decode a compact proof.
+
+use super::{nibble, proof_decode, trie_node, CHILD_STORAGE_PREFIX};
+
+use alloc::{collections::BTreeSet, vec::Vec};
+
+mod tests;
+
+/// Byte prefixed to node values whose storage value hash has been omitted.
+const ESCAPE_HEADER: u8 = 0x01;
+
+/// Encodes the given proof as a compact proof.
+///
+/// Entries of the proof that belong to a trie other than the main trie and the child tries
+/// referenced by the main trie aren't part of the output.
+///
+/// Returns an error if the root node of the main trie can't be found in the proof.
+pub fn encode_compact_proof<T: AsRef<[u8]>>(
+    proof: &proof_decode::DecodedTrieProof<T>,
+    main_trie_root_hash: &[u8; 32],
+) -> Result<Vec<u8>, EncodeError> {
+    // List of all the trie roots that have at least one entry in the proof.
+    let trie_roots = proof
+        .iter_ordered()
+        .map(|(key, _)| *key.trie_root_hash)
+        .collect::<BTreeSet<_>>();
+
+    if !trie_roots.contains(main_trie_root_hash) {
+        return Err(EncodeError::MainTrieRootNotInProof);
+    }
+
+    // Build the list of tries to encode, starting with the main trie.
+    let tries_to_encode = {
+        let child_storage_prefix =
+            nibble::bytes_to_nibbles(CHILD_STORAGE_PREFIX.iter().copied()).collect::<Vec<_>>();
+
+        let mut list = Vec::with_capacity(trie_roots.len());
+        list.push(*main_trie_root_hash);
+
+        for (key, entry) in proof.iter_ordered() {
+            if key.trie_root_hash != main_trie_root_hash
+                || !key.key.starts_with(&child_storage_prefix)
+            {
+                continue;
+            }
+
+            let proof_decode::StorageValue::Known { value, .. } =
+                entry.trie_node_info.storage_value
+            else { continue };
+            let Ok(child_trie_root_hash) = <[u8; 32]>::try_from(value)
+            else { continue };
+
+            if trie_roots.contains(&child_trie_root_hash) && !list.contains(&child_trie_root_hash) {
+                list.push(child_trie_root_hash);
+            }
+        }
+
+        list
+    };
+
+    let mut items = Vec::with_capacity(proof.iter_ordered().count());
+
+    for trie_root_hash in &tries_to_encode {
+        // `iter_ordered` yields the entries in lexicographic order of their key, which is the
+        // same as a depth-first pre-order traversal.
+        for (key, entry) in proof.iter_ordered() {
+            if key.trie_root_hash != trie_root_hash {
+                continue;
+            }
+
+            // Nodes whose node value is inlined within their parent are skipped.
+            if !key.key.is_empty() && entry.node_value.len() < 32 {
+                continue;
+            }
+
+            // Since the proof has been verified, the node value is guaranteed to be valid.
+            let mut decoded = trie_node::decode(entry.node_value).unwrap();
+
+            for (nibble, child) in nibble::all_nibbles().zip(decoded.children.iter_mut()) {
+                if !child.is_some_and(|merkle_value| merkle_value.len() == 32) {
+                    continue;
+                }
+
+                if matches!(
+                    entry.trie_node_info.children.child(nibble),
+                    proof_decode::Child::InProof { .. }
+                ) {
+                    *child = Some(&[][..]);
+                }
+            }
+
+            match (decoded.storage_value, entry.unhashed_storage_value) {
+                (trie_node::StorageValue::Hashed(_), Some(unhashed_storage_value)) => {
+                    decoded.storage_value = trie_node::StorageValue::Unhashed(&[]);
+                    let mut node_value = trie_node::encode_to_vec(decoded).unwrap();
+                    node_value.insert(0, ESCAPE_HEADER);
+                    items.push(node_value);
+                    items.push(unhashed_storage_value.to_vec());
+                }
+                _ => items.push(trie_node::encode_to_vec(decoded).unwrap()),
+            }
+        }
+    }
+
+    let mut out = Vec::with_capacity(items.iter().map(|item| item.len() + 4).sum::<usize>() + 4);
+    out.extend_from_slice(crate::util::encode_scale_compact_usize(items.len()).as_ref());
+    for item in items {
+        out.extend_from_slice(crate::util::encode_scale_compact_usize(item.len()).as_ref());
+        out.extend_from_slice(&item);
+    }
+    Ok(out)
+}
+
+/// Error potentially returned by [`encode_compact_proof`].
+#[derive(Debug, Clone, derive_more::Display)]
+pub enum EncodeError {
+    /// The root node of the main trie can't be found in the proof.
+    MainTrieRootNotInProof,
+}
+
+/// Decodes a compact proof and verifies whether it is correct.
+///
+/// The compact proof is converted into a regular trie proof, which is then decoded with
+/// [`proof_decode::decode_and_verify_proof`].
+///
+/// Because a compact proof doesn't contain the Merkle values of the nodes it contains, it is
+/// always "correct". The caller must compare [`DecodedCompactProof::main_trie_root_hash`] with
+/// the expected main trie root hash.
+pub fn decode_and_verify_compact_proof(
+    compact_proof: &[u8],
+) -> Result<DecodedCompactProof, DecodeError> {
+    let (_, items) = nom::combinator::all_consuming(nom::combinator::flat_map(
+        crate::util::nom_scale_compact_usize,
+        |num_elems| nom::multi::many_m_n(num_elems, num_elems, crate::util::nom_bytes_decode),
+    ))(compact_proof)
+    .map_err(|_: nom::Err<nom::error::Error<&[u8]>>| DecodeError::InvalidFormat)?;
+
+    // List of entries of the regular proof being built.
+    let mut proof_entries = Vec::with_capacity(items.len());
+
+    let mut items = items.into_iter();
+    let mut main_trie_root_hash = None;
+
+    // Each iteration decodes one trie.
+    while items.len() != 0 {
+        let trie_root_hash = decode_trie(&mut items, &mut proof_entries)?;
+        main_trie_root_hash.get_or_insert(trie_root_hash);
+    }
+
+    let Some(main_trie_root_hash) = main_trie_root_hash
+    else { return Err(DecodeError::Empty) };
+
+    // The same node value can be found multiple times in a compact proof, for example if two
+    // different tries contain the same node. Regular proofs must not contain duplicates.
+    proof_entries.sort_unstable();
+    proof_entries.dedup();
+
+    let mut proof = Vec::with_capacity(
+        proof_entries
+            .iter()
+            .map(|entry| entry.len() + 4)
+            .sum::<usize>()
+            + 4,
+    );
+    proof.extend_from_slice(crate::util::encode_scale_compact_usize(proof_entries.len()).as_ref());
+    for entry in proof_entries {
+        proof.extend_from_slice(crate::util::encode_scale_compact_usize(entry.len()).as_ref());
+        proof.extend_from_slice(&entry);
+    }
+
+    let proof = proof_decode::decode_and_verify_proof(proof_decode::Config { proof })
+        .map_err(DecodeError::InvalidProof)?;
+
+    Ok(DecodedCompactProof {
+        main_trie_root_hash,
+        proof,
+    })
+}
+
+/// Decodes the items of a single trie from `items`, and pushes the corresponding regular proof
+/// entries to `proof_entries`.
+///
+/// Returns the hash of the root node of the trie.
+fn decode_trie<'a>(
+    items: &mut impl Iterator<Item = &'a [u8]>,
+    proof_entries: &mut Vec<Vec<u8>>,
+) -> Result<[u8; 32], DecodeError> {
+    struct StackEntry<'a> {
+        /// Node value, where the Merkle value of the children before `next_child` have been
+        /// filled.
+        node_value: Vec<u8>,
+        /// Unhashed storage value found in the compact proof, if any.
+        unhashed_storage_value: Option<&'a [u8]>,
+        /// Index of the next child whose Merkle value might need to be filled.
+        next_child: usize,
+    }
+
+    // Nodes whose node value isn't complete yet. The last entry is the parent of the next node
+    // found in `items`.
+    let mut stack = Vec::<StackEntry>::new();
+
+    loop {
+        let item = items.next().ok_or(DecodeError::IncompleteTrie)?;
+
+        let (node_value, unhashed_storage_value) = if item.first() == Some(&ESCAPE_HEADER) {
+            let storage_value = items.next().ok_or(DecodeError::MissingStorageValue)?;
+            let storage_value_hash = blake2_rfc::blake2b::blake2b(32, &[], storage_value);
+
+            let mut decoded =
+                trie_node::decode(&item[1..]).map_err(DecodeError::InvalidNodeValue)?;
+            if !matches!(
+                decoded.storage_value,
+                trie_node::StorageValue::Unhashed(&[])
+            ) {
+                return Err(DecodeError::InvalidFormat);
+            }
+            decoded.storage_value = trie_node::StorageValue::Hashed(
+                <&[u8; 32]>::try_from(storage_value_hash.as_bytes()).unwrap(),
+            );
+            let node_value =
+                trie_node::encode_to_vec(decoded).map_err(|_| DecodeError::InvalidFormat)?;
+            (node_value, Some(storage_value))
+        } else {
+            (item.to_vec(), None)
+        };
+
+        trie_node::decode(&node_value).map_err(DecodeError::InvalidNodeValue)?;
+
+        stack.push(StackEntry {
+            node_value,
+            unhashed_storage_value,
+            next_child: 0,
+        });
+
+        // Pop all the nodes that are now complete, and stop at the first node that has a child
+        // whose node value is the next item.
+        loop {
+            let top = stack.last_mut().unwrap();
+
+            let decoded = trie_node::decode(&top.node_value).unwrap();
+            if let Some(next_child) = (top.next_child..16)
+                .find(|n| decoded.children[*n].is_some_and(|child| child.is_empty()))
+            {
+                top.next_child = next_child;
+                break;
+            }
+
+            let complete = stack.pop().unwrap();
+            let hash = *<&[u8; 32]>::try_from(
+                blake2_rfc::blake2b::blake2b(32, &[], &complete.node_value).as_bytes(),
+            )
+            .unwrap();
+            proof_entries.push(complete.node_value);
+            if let Some(unhashed_storage_value) = complete.unhashed_storage_value {
+                proof_entries.push(unhashed_storage_value.to_vec());
+            }
+
+            let Some(parent) = stack.last_mut() else { return Ok(hash) };
+
+            let mut decoded = trie_node::decode(&parent.node_value).unwrap();
+            decoded.children[parent.next_child] = Some(&hash[..]);
+            let new_node_value = trie_node::encode_to_vec(decoded).unwrap();
+            parent.node_value = new_node_value;
+            parent.next_child += 1;
+        }
+    }
+}
+
+/// Compact proof decoded by [`decode_and_verify_compact_proof`].
+#[derive(Debug)]
+pub struct DecodedCompactProof {
+    /// Hash of the root node of the main trie, in other words the first trie found in the
+    /// compact proof.
+    pub main_trie_root_hash: [u8; 32],
+
+    /// Equivalent regular trie proof.
+    pub proof: proof_decode::DecodedTrieProof<Vec<u8>>,
+}
+
+/// Possible error returned by [`decode_and_verify_compact_proof`].
+#[derive(Debug, Clone, derive_more::Display)]
+pub enum DecodeError {
+    /// Proof is in an invalid format.
+    InvalidFormat,
+    /// Proof doesn't contain any trie.
+    Empty,
+    /// One of the node values in the proof has an invalid format.
+    #[display(fmt = "A node of the proof has an invalid format: {_0}")]
+    InvalidNodeValue(trie_node::Error),
+    /// A node value whose storage value hash has been omitted isn't followed with the storage
+    /// value.
+    MissingStorageValue,
+    /// The proof ends before the node values of all the children of a node have been found.
+    IncompleteTrie,
+    /// Error while decoding the equivalent regular trie proof.
+    #[display(fmt = "{_0}")]
+    InvalidProof(proof_decode::Error),
+}
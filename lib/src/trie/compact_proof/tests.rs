@@ -0,0 +1,217 @@
+// Smoldot
+// Copyright (C) 2023  Pierre Krieger
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <http://www.gnu.org/licenses/>.
+
+#![cfg(test)]
+
+use super::{decode_and_verify_compact_proof, encode_compact_proof, DecodeError, EncodeError};
+use crate::trie::{
+    nibble, proof_decode, proof_encode,
+    test_fixtures::{PROOFS, STATE_TRIE_ROOT},
+    trie_node,
+};
+
+use alloc::vec::Vec;
+
+/// Returns the content of the proof in a format that can be compared.
+#[allow(clippy::type_complexity)]
+fn proof_content(
+    proof: &proof_decode::DecodedTrieProof<impl AsRef<[u8]>>,
+) -> Vec<([u8; 32], Vec<nibble::Nibble>, Vec<u8>, Option<Vec<u8>>)> {
+    proof
+        .iter_ordered()
+        .map(|(key, entry)| {
+            (
+                *key.trie_root_hash,
+                key.key.to_vec(),
+                entry.node_value.to_vec(),
+                entry.unhashed_storage_value.map(|value| value.to_vec()),
+            )
+        })
+        .collect()
+}
+
+#[test]
+fn prefix_proof_vectors_round_trip() {
+    for proof in PROOFS {
+        let proof = proof_decode::decode_and_verify_proof(proof_decode::Config { proof }).unwrap();
+
+        let compact_proof = encode_compact_proof(&proof, &STATE_TRIE_ROOT).unwrap();
+        let decoded = decode_and_verify_compact_proof(&compact_proof).unwrap();
+
+        assert_eq!(decoded.main_trie_root_hash, STATE_TRIE_ROOT);
+        assert_eq!(proof_content(&decoded.proof), proof_content(&proof));
+    }
+}
+
+#[test]
+fn substrate_vector() {
+    // Compact proof of a trie containing the entries `0x10 => [0x11; 40]`, `0x20 => [0x01, 0x02]`
+    // and `0x30 => [0x33; 40]` (state version 1), where the node of `0x30` isn't part of the
+    // proof.
+    //
+    // This vector has been constructed by hand by following the algorithm of
+    // `trie_db::encode_compact` (used by `sp_trie::encode_compact`) rather than generated by
+    // Substrate itself. In particular, the node whose storage value is omitted is encoded with
+    // an empty unhashed storage value, as trie-db does with its `OMIT_VALUE_HASH` constant.
+    const COMPACT_PROOF: &[u8] = &[
+        12, // Three items.
+        // Root node, where the Merkle value of the child `1` has been omitted.
+        172, 128, 14, 0, 0, 20, 65, 0, 8, 1, 2, 128, 234, 59, 60, 102, 82, 189, 9, 112, 38, 202, 93,
+        9, 233, 108, 33, 56, 173, 8, 181, 228, 160, 113, 15, 4, 97, 2, 139, 177, 80, 102, 62,
+        226, //
+        // Node of `0x10`, prefixed with the escape header.
+        16, 1, 65, 0, 0, //
+        // Storage value of `0x10`.
+        160, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17,
+        17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17,
+    ];
+
+    let main_trie_root_hash = crate::trie::trie_root(
+        crate::trie::TrieEntryVersion::V1,
+        &[
+            (&[0x10][..], &[0x11; 40][..]),
+            (&[0x20][..], &[0x01, 0x02][..]),
+            (&[0x30][..], &[0x33; 40][..]),
+        ],
+    );
+
+    let decoded = decode_and_verify_compact_proof(COMPACT_PROOF).unwrap();
+    assert_eq!(decoded.main_trie_root_hash, main_trie_root_hash);
+    assert_eq!(
+        decoded
+            .proof
+            .storage_value(&main_trie_root_hash, &[0x10])
+            .unwrap()
+            .map(|(value, _)| value),
+        Some(&[0x11; 40][..])
+    );
+    assert_eq!(
+        decoded
+            .proof
+            .storage_value(&main_trie_root_hash, &[0x20])
+            .unwrap()
+            .map(|(value, _)| value),
+        Some(&[0x01, 0x02][..])
+    );
+    assert!(decoded
+        .proof
+        .storage_value(&main_trie_root_hash, &[0x30])
+        .is_err());
+
+    assert_eq!(
+        encode_compact_proof(&decoded.proof, &main_trie_root_hash).unwrap(),
+        COMPACT_PROOF
+    );
+}
+
+#[test]
+fn child_trie_and_hashed_value_round_trip() {
+    let mut proof_builder = proof_encode::ProofBuilder::new();
+
+    let main_trie_key = b":child_storage:default:foo";
+    let main_trie_node_value = trie_node::encode_to_vec(trie_node::Decoded {
+        children: [None::<&[u8]>; 16],
+        partial_key: nibble::bytes_to_nibbles(main_trie_key.iter().copied()),
+        storage_value: trie_node::StorageValue::Unhashed(&[0; 32]),
+    })
+    .unwrap();
+    proof_builder.set_node_value(
+        &nibble::bytes_to_nibbles(main_trie_key.iter().copied()).collect::<Vec<_>>(),
+        &main_trie_node_value,
+        None,
+    );
+
+    // The child trie contains a storage value that is large enough to be hashed.
+    let storage_value = [0xaa; 64];
+    let storage_value_hash = blake2_rfc::blake2b::blake2b(32, &[], &storage_value);
+    let child_trie_node_value = trie_node::encode_to_vec(trie_node::Decoded {
+        children: [None::<&[u8]>; 16],
+        partial_key: nibble::bytes_to_nibbles(b"abc".iter().copied()),
+        storage_value: trie_node::StorageValue::Hashed(
+            <&[u8; 32]>::try_from(storage_value_hash.as_bytes()).unwrap(),
+        ),
+    })
+    .unwrap();
+    proof_builder.set_child_trie_node_value(
+        b"foo",
+        &nibble::bytes_to_nibbles(b"abc".iter().copied()).collect::<Vec<_>>(),
+        &child_trie_node_value,
+        Some(&storage_value),
+    );
+
+    proof_builder.make_coherent();
+    let main_trie_root_hash = proof_builder.trie_root_hash().unwrap();
+
+    let proof = proof_decode::decode_and_verify_proof(proof_decode::Config {
+        proof: proof_builder.build_to_vec(),
+    })
+    .unwrap();
+
+    let compact_proof = encode_compact_proof(&proof, &main_trie_root_hash).unwrap();
+    let decoded = decode_and_verify_compact_proof(&compact_proof).unwrap();
+
+    assert_eq!(decoded.main_trie_root_hash, main_trie_root_hash);
+    assert_eq!(proof_content(&decoded.proof), proof_content(&proof));
+    assert_eq!(
+        decoded
+            .proof
+            .child_trie_storage_value(&main_trie_root_hash, b"foo", b"abc")
+            .unwrap()
+            .map(|(value, _)| value),
+        Some(&storage_value[..])
+    );
+}
+
+#[test]
+fn main_trie_root_missing() {
+    let proof =
+        proof_decode::decode_and_verify_proof(proof_decode::Config { proof: PROOFS[0] }).unwrap();
+    assert!(matches!(
+        encode_compact_proof(&proof, &[0; 32]),
+        Err(EncodeError::MainTrieRootNotInProof)
+    ));
+}
+
+#[test]
+fn truncated_compact_proof() {
+    let proof =
+        proof_decode::decode_and_verify_proof(proof_decode::Config { proof: PROOFS[0] }).unwrap();
+    let compact_proof = encode_compact_proof(&proof, &STATE_TRIE_ROOT).unwrap();
+
+    // Remove the last item of the compact proof and patch the number of items.
+    let (_, mut items) = nom::combinator::all_consuming(nom::combinator::flat_map(
+        crate::util::nom_scale_compact_usize,
+        |num_elems| nom::multi::many_m_n(num_elems, num_elems, crate::util::nom_bytes_decode),
+    ))(&compact_proof[..])
+    .map_err(|_: nom::Err<nom::error::Error<&[u8]>>| ())
+    .unwrap();
+    assert!(items.len() >= 2);
+    items.pop();
+
+    let mut truncated = crate::util::encode_scale_compact_usize(items.len())
+        .as_ref()
+        .to_vec();
+    for item in items {
+        truncated.extend_from_slice(crate::util::encode_scale_compact_usize(item.len()).as_ref());
+        truncated.extend_from_slice(item);
+    }
+
+    assert!(matches!(
+        decode_and_verify_compact_proof(&truncated),
+        Err(DecodeError::IncompleteTrie)
+    ));
+}
@@ -18,6 +18,7 @@
 #![cfg(test)]
 
 use super::{prefix_scan, Config, ResumeOutcome};
+use crate::trie::test_fixtures::{PROOFS, STATE_TRIE_ROOT};
 
 // TODO: more tests
 
@@ -29,8930 +30,6 @@ fn regression_test_174() {
         76, 101, 130, 39, 238, 29, 250, 252, 214, 225, 105, 3,
     ];
 
-    const STATE_TRIE_ROOT: [u8; 32] = [
-        8, 16, 226, 153, 213, 126, 186, 247, 138, 44, 80, 221, 195, 71, 115, 83, 22, 230, 240, 197,
-        79, 157, 126, 138, 173, 69, 129, 61, 60, 36, 196, 22,
-    ];
-
-    const PROOFS: &[&[u8]] = &[
-        &[
-            24, 153, 1, 128, 1, 9, 128, 182, 176, 86, 103, 130, 112, 152, 70, 130, 123, 97, 130,
-            253, 202, 191, 114, 214, 5, 255, 18, 214, 188, 64, 45, 189, 70, 119, 130, 247, 143,
-            146, 25, 128, 222, 84, 191, 139, 38, 192, 231, 202, 43, 181, 111, 182, 5, 135, 40, 28,
-            47, 210, 52, 7, 8, 92, 121, 239, 243, 65, 5, 1, 245, 93, 26, 21, 128, 184, 185, 201,
-            70, 229, 20, 169, 131, 43, 58, 19, 47, 56, 193, 99, 137, 165, 239, 91, 197, 213, 64,
-            238, 202, 57, 22, 108, 109, 197, 119, 57, 10, 153, 1, 128, 1, 144, 128, 21, 128, 171,
-            91, 28, 68, 227, 29, 171, 149, 8, 252, 76, 113, 13, 128, 97, 167, 49, 177, 152, 172,
-            155, 64, 212, 178, 180, 93, 244, 51, 6, 39, 128, 126, 107, 34, 59, 69, 6, 67, 70, 79,
-            86, 249, 72, 161, 122, 212, 192, 93, 174, 248, 25, 226, 34, 184, 244, 144, 164, 6, 171,
-            173, 118, 195, 159, 128, 241, 254, 244, 155, 200, 49, 12, 118, 133, 129, 7, 120, 80,
-            24, 65, 237, 125, 34, 62, 9, 239, 48, 29, 43, 163, 152, 36, 39, 242, 113, 119, 154,
-            153, 1, 128, 12, 2, 128, 238, 137, 237, 115, 49, 164, 218, 12, 210, 155, 191, 14, 64,
-            100, 28, 89, 68, 22, 41, 5, 36, 244, 195, 253, 122, 3, 45, 20, 174, 230, 130, 168, 128,
-            67, 211, 156, 230, 184, 3, 172, 115, 156, 201, 25, 198, 245, 134, 141, 46, 176, 144,
-            168, 93, 204, 208, 84, 103, 232, 127, 128, 119, 85, 73, 242, 132, 128, 245, 172, 7,
-            230, 107, 246, 103, 139, 204, 44, 209, 95, 107, 145, 71, 96, 179, 166, 58, 114, 240,
-            232, 168, 7, 13, 226, 92, 2, 58, 9, 225, 1, 77, 8, 128, 255, 255, 128, 98, 224, 118,
-            12, 76, 51, 177, 134, 55, 11, 240, 54, 16, 130, 71, 53, 233, 48, 237, 233, 145, 55,
-            196, 116, 75, 125, 25, 10, 222, 110, 145, 72, 128, 123, 97, 129, 68, 255, 146, 109,
-            130, 170, 164, 51, 127, 247, 61, 60, 39, 45, 247, 96, 65, 102, 249, 254, 240, 235, 150,
-            72, 191, 254, 139, 212, 143, 128, 10, 145, 115, 111, 253, 223, 196, 17, 64, 94, 25,
-            146, 103, 246, 48, 225, 201, 116, 96, 194, 171, 54, 38, 98, 86, 3, 3, 71, 129, 31, 6,
-            175, 128, 199, 195, 90, 57, 51, 127, 202, 28, 248, 27, 156, 174, 209, 38, 194, 27, 143,
-            96, 209, 92, 159, 32, 96, 206, 102, 91, 145, 17, 100, 34, 161, 225, 128, 111, 174, 226,
-            248, 116, 189, 185, 98, 48, 158, 21, 64, 84, 37, 132, 251, 159, 119, 145, 158, 160, 94,
-            50, 14, 78, 36, 7, 69, 225, 169, 118, 163, 128, 130, 131, 93, 107, 228, 124, 194, 73,
-            133, 43, 145, 205, 67, 139, 12, 122, 202, 182, 137, 8, 117, 46, 210, 14, 252, 151, 44,
-            230, 172, 18, 153, 208, 128, 90, 123, 171, 183, 168, 73, 23, 229, 112, 45, 203, 101,
-            146, 205, 166, 179, 139, 173, 186, 161, 12, 37, 201, 110, 4, 24, 59, 86, 176, 85, 19,
-            214, 128, 34, 137, 24, 131, 123, 49, 233, 144, 163, 244, 68, 122, 147, 162, 184, 83,
-            218, 111, 153, 130, 208, 164, 104, 189, 32, 120, 168, 115, 238, 172, 147, 241, 128,
-            253, 185, 119, 165, 240, 12, 10, 37, 37, 189, 229, 131, 173, 106, 160, 226, 95, 234,
-            200, 170, 142, 79, 97, 231, 226, 69, 40, 164, 129, 29, 119, 217, 128, 215, 202, 10,
-            223, 65, 233, 98, 218, 223, 110, 11, 98, 108, 232, 78, 52, 25, 27, 11, 140, 93, 219,
-            35, 105, 104, 192, 213, 107, 103, 160, 147, 131, 128, 213, 23, 236, 208, 56, 116, 59,
-            227, 151, 243, 244, 198, 4, 58, 214, 202, 73, 178, 35, 192, 153, 13, 96, 246, 250, 18,
-            143, 18, 70, 157, 58, 4, 128, 50, 104, 164, 63, 181, 151, 229, 156, 25, 254, 186, 145,
-            93, 195, 131, 202, 231, 191, 255, 114, 215, 176, 156, 119, 36, 246, 10, 30, 88, 250,
-            251, 240, 128, 103, 233, 178, 7, 124, 202, 44, 82, 24, 123, 141, 45, 162, 11, 34, 22,
-            42, 104, 22, 81, 73, 15, 223, 49, 220, 156, 80, 188, 243, 88, 152, 39, 128, 24, 57, 95,
-            182, 144, 171, 157, 147, 39, 2, 248, 223, 190, 4, 238, 26, 205, 51, 243, 21, 92, 81,
-            253, 196, 58, 117, 8, 176, 3, 193, 96, 167, 128, 151, 147, 172, 137, 135, 140, 127, 19,
-            211, 158, 45, 240, 247, 87, 188, 12, 50, 211, 176, 157, 204, 105, 116, 196, 132, 232,
-            58, 25, 94, 127, 118, 37, 128, 243, 24, 174, 152, 134, 125, 213, 8, 179, 123, 215, 74,
-            241, 38, 134, 46, 15, 246, 224, 164, 243, 101, 224, 238, 242, 220, 108, 49, 7, 49, 19,
-            171, 141, 7, 157, 14, 73, 7, 247, 22, 172, 137, 182, 52, 125, 21, 236, 236, 237, 202,
-            255, 223, 88, 95, 11, 106, 69, 50, 30, 250, 233, 42, 234, 21, 224, 116, 14, 199, 175,
-            231, 16, 219, 3, 0, 0, 88, 95, 3, 142, 113, 97, 36, 145, 25, 45, 104, 222, 171, 126,
-            111, 86, 63, 225, 16, 41, 1, 0, 0, 128, 110, 200, 13, 122, 252, 137, 163, 204, 235,
-            225, 15, 205, 240, 76, 161, 101, 24, 255, 136, 214, 178, 148, 98, 236, 134, 27, 0, 211,
-            18, 57, 219, 53, 128, 24, 179, 250, 7, 252, 211, 24, 112, 36, 254, 110, 123, 88, 175,
-            104, 227, 26, 244, 141, 143, 123, 92, 93, 148, 246, 56, 254, 87, 54, 202, 227, 116,
-            128, 212, 186, 95, 7, 117, 214, 42, 252, 137, 90, 39, 204, 71, 219, 16, 172, 46, 91,
-            134, 227, 70, 144, 166, 190, 177, 236, 42, 57, 145, 233, 105, 61, 128, 14, 214, 94,
-            167, 86, 123, 217, 88, 156, 68, 3, 216, 251, 117, 130, 117, 173, 120, 238, 191, 150,
-            165, 214, 18, 109, 103, 131, 162, 149, 60, 190, 15, 128, 145, 79, 88, 158, 111, 117,
-            135, 211, 50, 230, 34, 131, 178, 91, 19, 230, 181, 115, 168, 181, 12, 173, 102, 237,
-            72, 30, 125, 22, 166, 190, 163, 226, 128, 150, 97, 48, 76, 74, 235, 167, 247, 69, 103,
-            4, 154, 130, 7, 3, 22, 72, 160, 82, 232, 18, 244, 176, 58, 42, 23, 7, 231, 12, 78, 180,
-            155, 128, 191, 24, 153, 195, 29, 109, 95, 250, 129, 242, 53, 146, 183, 178, 157, 20,
-            56, 109, 0, 24, 132, 32, 212, 75, 125, 0, 101, 184, 163, 124, 84, 180, 128, 223, 120,
-            85, 135, 67, 76, 242, 99, 220, 31, 86, 41, 97, 198, 169, 114, 181, 40, 200, 30, 33,
-            169, 7, 31, 17, 77, 67, 188, 58, 79, 49, 70, 128, 65, 72, 245, 149, 209, 123, 119, 9,
-            68, 98, 196, 190, 181, 249, 88, 108, 108, 19, 163, 131, 105, 250, 44, 15, 229, 23, 79,
-            70, 55, 48, 24, 207, 88, 95, 4, 154, 39, 56, 238, 179, 8, 150, 170, 203, 139, 63, 180,
-            100, 113, 189, 16, 4, 0, 0, 0, 128, 150, 77, 204, 23, 91, 96, 20, 177, 216, 5, 108,
-            247, 200, 200, 199, 101, 114, 110, 31, 183, 134, 248, 253, 142, 109, 152, 116, 233, 25,
-            185, 123, 153, 128, 5, 145, 11, 232, 224, 155, 242, 140, 49, 94, 170, 70, 75, 193, 225,
-            78, 35, 140, 92, 155, 200, 205, 85, 110, 178, 60, 35, 99, 30, 6, 200, 106, 128, 180,
-            147, 63, 242, 56, 9, 87, 70, 86, 7, 181, 212, 101, 128, 67, 39, 192, 87, 234, 252, 168,
-            145, 67, 182, 36, 28, 227, 4, 153, 70, 59, 250, 137, 8, 158, 220, 222, 147, 76, 101,
-            130, 39, 238, 29, 250, 252, 214, 225, 105, 3, 255, 255, 128, 212, 192, 107, 17, 26,
-            200, 122, 195, 120, 105, 34, 67, 13, 110, 222, 20, 168, 48, 115, 160, 212, 187, 246,
-            92, 253, 220, 93, 68, 165, 244, 128, 149, 128, 251, 50, 66, 146, 95, 208, 2, 95, 45,
-            114, 134, 72, 91, 66, 74, 130, 178, 173, 14, 21, 149, 16, 128, 230, 116, 61, 76, 168,
-            24, 113, 226, 218, 128, 130, 145, 33, 106, 121, 80, 96, 66, 164, 70, 191, 105, 83, 79,
-            54, 235, 249, 71, 225, 58, 5, 127, 130, 251, 200, 50, 19, 156, 250, 206, 78, 78, 128,
-            44, 192, 40, 14, 185, 110, 219, 179, 241, 90, 91, 52, 41, 102, 199, 113, 82, 132, 131,
-            96, 38, 20, 10, 30, 59, 82, 176, 198, 176, 87, 21, 87, 128, 195, 5, 208, 225, 21, 57,
-            155, 53, 89, 33, 130, 228, 169, 0, 76, 48, 24, 82, 5, 42, 137, 197, 162, 63, 21, 197,
-            141, 251, 212, 115, 106, 82, 128, 121, 48, 30, 3, 225, 115, 179, 164, 175, 222, 54, 84,
-            122, 135, 160, 236, 243, 69, 251, 160, 241, 3, 245, 102, 217, 137, 189, 2, 173, 64, 53,
-            101, 128, 153, 158, 145, 143, 88, 49, 185, 124, 0, 150, 98, 54, 245, 85, 94, 94, 215,
-            123, 89, 24, 10, 71, 85, 52, 250, 210, 20, 228, 16, 149, 211, 151, 128, 35, 126, 133,
-            35, 101, 156, 135, 154, 57, 170, 188, 113, 130, 140, 189, 101, 229, 126, 131, 120, 56,
-            160, 48, 107, 104, 186, 122, 224, 85, 129, 147, 46, 128, 105, 89, 155, 175, 165, 144,
-            141, 206, 81, 133, 45, 110, 19, 216, 203, 165, 255, 113, 78, 67, 243, 110, 156, 71,
-            109, 18, 18, 115, 92, 216, 173, 77, 128, 123, 141, 236, 214, 0, 104, 118, 31, 247, 52,
-            108, 8, 252, 87, 196, 216, 167, 133, 138, 61, 36, 211, 220, 61, 76, 245, 34, 188, 150,
-            163, 67, 176, 128, 145, 171, 89, 220, 120, 189, 81, 183, 187, 110, 153, 163, 54, 248,
-            154, 229, 38, 99, 248, 20, 255, 92, 198, 245, 44, 156, 75, 241, 238, 7, 6, 224, 128,
-            239, 194, 154, 97, 78, 80, 169, 43, 93, 31, 212, 140, 222, 208, 103, 168, 30, 51, 240,
-            20, 63, 224, 162, 202, 9, 214, 212, 169, 103, 167, 181, 110, 128, 97, 138, 136, 94, 17,
-            95, 133, 176, 236, 165, 222, 28, 208, 47, 223, 231, 215, 15, 27, 155, 26, 211, 56, 191,
-            215, 96, 178, 205, 221, 97, 26, 31, 128, 246, 79, 70, 71, 167, 94, 137, 199, 210, 5,
-            154, 192, 53, 113, 183, 23, 32, 135, 208, 76, 45, 4, 58, 15, 13, 182, 41, 24, 188, 104,
-            66, 111, 128, 68, 251, 235, 134, 154, 122, 195, 108, 116, 251, 35, 161, 59, 76, 88,
-            164, 75, 140, 124, 44, 177, 90, 195, 238, 131, 61, 234, 70, 60, 255, 30, 252, 128, 12,
-            221, 198, 252, 251, 59, 183, 181, 5, 106, 13, 14, 159, 159, 196, 193, 91, 10, 108, 175,
-            177, 207, 135, 14, 162, 164, 53, 52, 72, 212, 240, 144,
-        ],
-        &[
-            152, 188, 127, 15, 100, 54, 252, 165, 207, 192, 157, 138, 196, 166, 13, 133, 208, 37,
-            129, 55, 185, 181, 166, 102, 206, 33, 102, 110, 205, 164, 72, 128, 84, 249, 59, 62, 95,
-            188, 250, 246, 30, 8, 32, 20, 2, 40, 107, 238, 0, 188, 127, 15, 133, 195, 201, 127,
-            134, 142, 2, 146, 8, 175, 47, 185, 241, 81, 31, 172, 189, 81, 123, 167, 236, 2, 150,
-            214, 252, 159, 208, 16, 137, 110, 236, 15, 67, 164, 21, 182, 139, 55, 6, 20, 2, 40,
-            107, 238, 0, 153, 1, 128, 1, 9, 128, 182, 176, 86, 103, 130, 112, 152, 70, 130, 123,
-            97, 130, 253, 202, 191, 114, 214, 5, 255, 18, 214, 188, 64, 45, 189, 70, 119, 130, 247,
-            143, 146, 25, 128, 222, 84, 191, 139, 38, 192, 231, 202, 43, 181, 111, 182, 5, 135, 40,
-            28, 47, 210, 52, 7, 8, 92, 121, 239, 243, 65, 5, 1, 245, 93, 26, 21, 128, 184, 185,
-            201, 70, 229, 20, 169, 131, 43, 58, 19, 47, 56, 193, 99, 137, 165, 239, 91, 197, 213,
-            64, 238, 202, 57, 22, 108, 109, 197, 119, 57, 10, 153, 1, 128, 1, 144, 128, 21, 128,
-            171, 91, 28, 68, 227, 29, 171, 149, 8, 252, 76, 113, 13, 128, 97, 167, 49, 177, 152,
-            172, 155, 64, 212, 178, 180, 93, 244, 51, 6, 39, 128, 126, 107, 34, 59, 69, 6, 67, 70,
-            79, 86, 249, 72, 161, 122, 212, 192, 93, 174, 248, 25, 226, 34, 184, 244, 144, 164, 6,
-            171, 173, 118, 195, 159, 128, 241, 254, 244, 155, 200, 49, 12, 118, 133, 129, 7, 120,
-            80, 24, 65, 237, 125, 34, 62, 9, 239, 48, 29, 43, 163, 152, 36, 39, 242, 113, 119, 154,
-            153, 1, 128, 2, 65, 128, 42, 185, 0, 171, 19, 73, 134, 70, 6, 217, 41, 60, 182, 75, 29,
-            146, 188, 1, 188, 186, 109, 102, 186, 161, 140, 30, 139, 14, 233, 165, 198, 15, 128,
-            145, 205, 231, 252, 159, 113, 53, 198, 116, 188, 137, 215, 53, 216, 191, 224, 13, 143,
-            242, 230, 219, 84, 59, 61, 176, 65, 252, 132, 84, 180, 235, 75, 128, 249, 146, 9, 36,
-            244, 148, 11, 120, 165, 171, 175, 165, 189, 133, 36, 56, 223, 65, 180, 36, 13, 164,
-            100, 79, 94, 49, 90, 234, 181, 189, 3, 168, 21, 1, 128, 4, 4, 128, 73, 155, 51, 111,
-            74, 82, 232, 13, 233, 110, 225, 61, 178, 2, 165, 63, 15, 178, 50, 225, 15, 22, 12, 23,
-            135, 125, 108, 161, 164, 91, 110, 84, 128, 113, 222, 224, 169, 5, 105, 110, 221, 213,
-            153, 193, 134, 221, 36, 55, 24, 98, 199, 120, 31, 0, 191, 129, 9, 60, 218, 96, 248, 51,
-            200, 144, 186, 29, 2, 128, 8, 70, 128, 177, 1, 172, 13, 83, 139, 5, 204, 229, 111, 246,
-            204, 194, 20, 158, 43, 3, 245, 142, 44, 211, 82, 169, 130, 239, 144, 201, 70, 145, 35,
-            53, 105, 128, 182, 105, 53, 175, 246, 169, 22, 31, 77, 195, 195, 71, 97, 63, 196, 209,
-            28, 47, 24, 13, 96, 106, 68, 204, 222, 38, 194, 92, 76, 161, 110, 14, 128, 134, 170,
-            215, 237, 176, 180, 95, 73, 27, 2, 167, 231, 44, 148, 35, 162, 185, 4, 17, 172, 160,
-            184, 205, 244, 171, 66, 55, 14, 67, 81, 167, 0, 128, 31, 232, 22, 199, 150, 59, 28,
-            250, 81, 9, 252, 173, 163, 108, 212, 143, 19, 189, 122, 12, 109, 65, 13, 236, 20, 122,
-            4, 13, 247, 13, 104, 149, 153, 1, 128, 9, 16, 128, 180, 179, 2, 6, 181, 127, 8, 157,
-            119, 71, 74, 10, 176, 103, 100, 83, 220, 204, 165, 115, 164, 103, 85, 159, 91, 207,
-            203, 87, 4, 157, 237, 98, 128, 126, 120, 51, 7, 186, 182, 1, 239, 45, 77, 178, 148,
-            227, 86, 15, 221, 191, 45, 114, 231, 130, 82, 181, 5, 61, 175, 134, 31, 55, 202, 234,
-            186, 128, 211, 149, 155, 10, 202, 92, 217, 74, 137, 96, 193, 164, 207, 64, 212, 191,
-            183, 83, 161, 237, 9, 0, 173, 102, 17, 235, 73, 101, 250, 106, 74, 184, 153, 1, 128,
-            12, 2, 128, 238, 137, 237, 115, 49, 164, 218, 12, 210, 155, 191, 14, 64, 100, 28, 89,
-            68, 22, 41, 5, 36, 244, 195, 253, 122, 3, 45, 20, 174, 230, 130, 168, 128, 67, 211,
-            156, 230, 184, 3, 172, 115, 156, 201, 25, 198, 245, 134, 141, 46, 176, 144, 168, 93,
-            204, 208, 84, 103, 232, 127, 128, 119, 85, 73, 242, 132, 128, 245, 172, 7, 230, 107,
-            246, 103, 139, 204, 44, 209, 95, 107, 145, 71, 96, 179, 166, 58, 114, 240, 232, 168, 7,
-            13, 226, 92, 2, 58, 9, 225, 1, 161, 2, 128, 18, 26, 128, 140, 29, 10, 195, 174, 248,
-            16, 230, 61, 126, 168, 67, 163, 152, 125, 128, 174, 4, 99, 185, 153, 59, 27, 96, 125,
-            28, 8, 135, 117, 21, 155, 230, 128, 127, 64, 246, 106, 192, 156, 233, 168, 86, 30, 195,
-            181, 193, 80, 28, 34, 131, 33, 187, 247, 231, 103, 53, 15, 5, 254, 233, 75, 97, 8, 105,
-            61, 128, 222, 156, 253, 223, 128, 105, 169, 227, 224, 43, 149, 231, 151, 229, 1, 184,
-            110, 77, 123, 158, 17, 178, 129, 48, 24, 34, 37, 189, 20, 27, 88, 90, 128, 169, 39, 34,
-            230, 141, 3, 154, 118, 53, 181, 88, 112, 170, 45, 193, 36, 80, 168, 248, 166, 8, 81,
-            144, 22, 156, 183, 73, 222, 144, 162, 122, 216, 128, 192, 68, 126, 3, 87, 18, 69, 143,
-            122, 174, 49, 122, 96, 87, 64, 61, 152, 231, 42, 50, 168, 127, 228, 143, 82, 59, 60,
-            115, 92, 142, 105, 221, 153, 1, 128, 33, 4, 128, 17, 99, 241, 69, 108, 92, 70, 227,
-            250, 240, 14, 212, 123, 235, 66, 25, 91, 80, 69, 171, 29, 105, 186, 200, 108, 230, 62,
-            74, 157, 97, 163, 52, 128, 20, 85, 227, 127, 187, 7, 222, 110, 11, 109, 222, 202, 245,
-            121, 87, 253, 7, 184, 129, 231, 57, 227, 216, 41, 180, 248, 82, 86, 81, 224, 137, 161,
-            128, 16, 72, 143, 251, 143, 134, 82, 140, 231, 121, 145, 117, 65, 1, 22, 176, 138, 17,
-            90, 90, 54, 184, 138, 171, 251, 26, 190, 96, 44, 175, 115, 241, 161, 2, 128, 49, 6,
-            128, 151, 61, 121, 121, 179, 225, 75, 165, 144, 213, 105, 137, 40, 118, 155, 236, 229,
-            37, 188, 137, 240, 217, 35, 15, 179, 1, 211, 37, 88, 86, 148, 166, 128, 239, 41, 201,
-            45, 174, 218, 202, 85, 6, 143, 180, 15, 34, 52, 76, 90, 41, 188, 81, 148, 161, 179,
-            212, 98, 127, 19, 141, 75, 95, 86, 67, 98, 128, 104, 238, 181, 41, 176, 100, 191, 192,
-            196, 241, 17, 241, 245, 0, 243, 35, 75, 86, 58, 254, 119, 86, 47, 160, 117, 215, 59,
-            198, 254, 151, 172, 62, 128, 14, 75, 56, 166, 111, 182, 144, 33, 203, 237, 2, 104, 92,
-            64, 87, 247, 150, 99, 47, 57, 131, 175, 121, 35, 86, 133, 102, 197, 114, 21, 244, 180,
-            128, 234, 106, 46, 227, 188, 21, 170, 108, 101, 101, 254, 219, 49, 149, 247, 104, 143,
-            63, 239, 145, 153, 247, 18, 112, 10, 130, 147, 248, 200, 228, 3, 168, 29, 2, 128, 52,
-            32, 128, 17, 118, 218, 98, 238, 240, 71, 235, 208, 148, 212, 86, 179, 200, 88, 216,
-            202, 109, 6, 214, 104, 176, 30, 45, 146, 92, 53, 108, 161, 198, 76, 19, 128, 181, 159,
-            207, 143, 50, 110, 124, 234, 254, 178, 253, 235, 161, 158, 49, 194, 85, 43, 210, 215,
-            94, 15, 43, 135, 120, 183, 145, 75, 53, 68, 164, 135, 128, 29, 105, 129, 105, 155, 88,
-            2, 153, 188, 69, 144, 97, 11, 129, 248, 230, 181, 115, 197, 119, 64, 220, 127, 219, 66,
-            241, 207, 29, 31, 204, 209, 10, 128, 135, 172, 1, 144, 43, 86, 19, 113, 58, 212, 211,
-            110, 38, 58, 114, 64, 86, 15, 206, 200, 98, 130, 91, 194, 133, 65, 9, 30, 221, 216,
-            106, 248, 29, 2, 128, 68, 33, 128, 83, 194, 62, 74, 131, 206, 135, 14, 90, 255, 180,
-            167, 214, 153, 60, 30, 8, 176, 185, 87, 124, 94, 236, 100, 111, 111, 55, 103, 229, 110,
-            251, 245, 128, 189, 243, 1, 183, 150, 187, 180, 158, 41, 71, 15, 168, 111, 64, 63, 85,
-            4, 30, 69, 252, 134, 233, 150, 127, 181, 17, 12, 60, 41, 72, 91, 126, 128, 28, 233,
-            130, 156, 168, 203, 1, 42, 60, 141, 228, 70, 244, 68, 205, 196, 130, 79, 153, 126, 11,
-            132, 158, 43, 91, 176, 186, 129, 115, 170, 243, 250, 128, 33, 27, 88, 247, 200, 87, 21,
-            43, 30, 191, 81, 157, 253, 201, 196, 208, 11, 78, 22, 126, 84, 245, 60, 91, 248, 61,
-            106, 84, 58, 128, 25, 119, 153, 1, 128, 72, 2, 128, 75, 21, 242, 153, 124, 116, 224,
-            132, 116, 44, 98, 110, 31, 18, 14, 137, 247, 174, 170, 28, 63, 153, 177, 94, 52, 196,
-            235, 219, 43, 9, 19, 179, 128, 225, 252, 213, 51, 90, 66, 104, 126, 29, 207, 43, 124,
-            0, 62, 133, 197, 84, 131, 52, 231, 139, 18, 189, 172, 203, 184, 177, 86, 237, 189, 158,
-            222, 128, 1, 8, 211, 242, 151, 215, 97, 3, 214, 26, 149, 224, 146, 195, 216, 28, 96,
-            47, 53, 169, 60, 76, 182, 127, 59, 232, 14, 216, 54, 210, 215, 163, 153, 1, 128, 80, 1,
-            128, 13, 198, 123, 182, 240, 208, 62, 98, 30, 225, 230, 120, 35, 210, 138, 67, 191,
-            223, 104, 118, 201, 90, 103, 182, 120, 105, 127, 142, 126, 166, 241, 17, 128, 42, 250,
-            248, 73, 153, 213, 27, 175, 214, 97, 245, 179, 80, 37, 243, 12, 241, 232, 213, 106,
-            230, 251, 2, 228, 225, 143, 177, 76, 173, 9, 36, 207, 128, 191, 102, 74, 223, 38, 1,
-            233, 115, 227, 88, 4, 129, 138, 169, 23, 122, 15, 99, 117, 144, 121, 159, 6, 31, 131,
-            178, 199, 41, 144, 34, 223, 79, 21, 1, 128, 128, 16, 128, 200, 128, 16, 212, 232, 182,
-            216, 97, 102, 108, 226, 156, 85, 54, 165, 56, 13, 145, 228, 9, 120, 216, 234, 211, 208,
-            138, 102, 58, 245, 99, 242, 48, 128, 53, 156, 240, 243, 197, 208, 226, 16, 222, 145,
-            176, 86, 164, 194, 122, 57, 119, 44, 189, 200, 75, 45, 194, 224, 207, 214, 116, 215,
-            31, 131, 107, 113, 153, 1, 128, 196, 0, 128, 191, 250, 68, 12, 200, 161, 149, 187, 237,
-            128, 22, 189, 119, 185, 179, 209, 205, 89, 115, 195, 232, 228, 195, 79, 167, 203, 63,
-            108, 237, 158, 186, 54, 128, 169, 52, 211, 157, 220, 101, 189, 101, 130, 30, 68, 144,
-            16, 140, 16, 252, 43, 222, 0, 78, 201, 185, 10, 92, 108, 185, 196, 91, 15, 198, 59, 36,
-            128, 179, 219, 254, 223, 66, 93, 13, 2, 34, 102, 11, 29, 215, 92, 14, 83, 6, 70, 220,
-            163, 174, 50, 1, 94, 17, 68, 86, 9, 139, 121, 37, 194, 201, 7, 128, 255, 191, 128, 158,
-            63, 149, 1, 58, 90, 199, 8, 180, 42, 100, 95, 68, 184, 64, 112, 145, 78, 130, 76, 221,
-            11, 40, 239, 41, 58, 200, 68, 13, 72, 188, 165, 128, 204, 220, 74, 251, 110, 231, 249,
-            175, 205, 130, 132, 250, 22, 40, 138, 163, 127, 121, 154, 73, 233, 16, 94, 82, 45, 183,
-            72, 11, 43, 119, 87, 234, 128, 88, 86, 140, 228, 43, 157, 244, 179, 181, 121, 251, 212,
-            56, 35, 194, 12, 228, 254, 104, 65, 27, 165, 158, 216, 188, 139, 168, 225, 61, 132,
-            202, 76, 128, 201, 23, 138, 207, 176, 44, 214, 85, 204, 70, 100, 4, 116, 82, 71, 228,
-            241, 90, 108, 43, 26, 68, 144, 18, 122, 64, 161, 70, 98, 251, 10, 252, 128, 110, 47,
-            111, 14, 41, 34, 78, 147, 203, 192, 168, 4, 197, 79, 134, 68, 109, 231, 253, 158, 107,
-            203, 91, 130, 151, 98, 180, 213, 9, 189, 68, 80, 128, 4, 201, 186, 96, 21, 36, 18, 155,
-            170, 90, 58, 226, 214, 67, 127, 237, 196, 222, 226, 174, 36, 51, 157, 46, 240, 74, 122,
-            183, 135, 39, 48, 186, 128, 170, 114, 149, 137, 242, 192, 204, 114, 121, 187, 191, 250,
-            8, 227, 127, 93, 220, 108, 194, 11, 178, 203, 61, 123, 11, 240, 189, 208, 206, 163,
-            200, 140, 128, 248, 91, 155, 199, 230, 136, 20, 51, 123, 8, 11, 178, 38, 69, 109, 117,
-            201, 28, 130, 75, 142, 190, 181, 184, 152, 190, 68, 115, 200, 51, 207, 108, 128, 209,
-            155, 60, 4, 152, 91, 147, 234, 238, 184, 1, 28, 148, 0, 120, 154, 38, 123, 111, 197,
-            112, 199, 2, 246, 233, 50, 71, 25, 87, 61, 100, 229, 128, 239, 53, 195, 76, 247, 20,
-            70, 41, 115, 0, 224, 30, 90, 189, 247, 57, 116, 102, 92, 3, 138, 59, 45, 103, 186, 65,
-            237, 78, 15, 134, 29, 177, 128, 220, 55, 8, 25, 116, 102, 167, 225, 3, 235, 164, 6, 67,
-            84, 93, 44, 192, 179, 134, 137, 114, 64, 202, 131, 226, 175, 96, 99, 103, 199, 178,
-            109, 128, 100, 45, 137, 234, 36, 180, 77, 29, 187, 234, 88, 127, 142, 106, 2, 148, 5,
-            11, 91, 223, 10, 213, 40, 60, 178, 56, 121, 179, 79, 196, 48, 49, 128, 229, 200, 177,
-            66, 203, 20, 230, 123, 193, 84, 65, 201, 133, 24, 68, 116, 225, 116, 176, 21, 130, 113,
-            115, 14, 112, 131, 6, 183, 93, 119, 144, 20, 128, 56, 87, 4, 116, 11, 175, 92, 158, 44,
-            17, 84, 84, 85, 175, 230, 255, 86, 111, 189, 17, 10, 242, 108, 19, 96, 55, 130, 150,
-            66, 30, 80, 122, 128, 99, 209, 106, 138, 72, 129, 164, 121, 105, 105, 236, 38, 195,
-            244, 250, 153, 205, 101, 159, 223, 245, 83, 115, 131, 230, 213, 201, 110, 78, 17, 82,
-            225, 201, 7, 128, 255, 254, 128, 208, 158, 3, 190, 40, 216, 177, 220, 46, 85, 232, 253,
-            123, 170, 53, 34, 76, 254, 150, 49, 92, 15, 76, 10, 166, 209, 43, 211, 223, 249, 176,
-            21, 128, 219, 204, 84, 174, 161, 163, 190, 254, 117, 195, 29, 7, 217, 23, 86, 245, 46,
-            71, 60, 203, 129, 217, 72, 123, 16, 220, 42, 200, 244, 54, 55, 50, 128, 215, 170, 165,
-            0, 121, 88, 226, 241, 89, 20, 184, 131, 70, 164, 242, 225, 11, 201, 96, 65, 23, 183,
-            32, 101, 9, 91, 191, 193, 240, 147, 90, 83, 128, 155, 146, 150, 77, 205, 13, 254, 237,
-            2, 217, 113, 89, 34, 87, 59, 162, 204, 246, 188, 218, 172, 221, 125, 223, 74, 198, 152,
-            6, 237, 249, 73, 114, 128, 117, 34, 190, 142, 140, 232, 56, 6, 243, 125, 122, 209, 114,
-            15, 29, 177, 82, 154, 33, 204, 129, 188, 72, 165, 102, 5, 238, 174, 87, 60, 231, 27,
-            128, 204, 83, 179, 23, 92, 175, 137, 194, 112, 119, 109, 117, 95, 176, 38, 183, 59,
-            179, 231, 142, 26, 164, 251, 72, 147, 40, 45, 28, 130, 88, 70, 76, 128, 118, 78, 9,
-            107, 124, 99, 15, 237, 148, 97, 66, 12, 196, 117, 177, 216, 180, 229, 24, 126, 95, 182,
-            75, 250, 54, 28, 34, 122, 132, 90, 21, 199, 128, 104, 216, 101, 53, 136, 32, 38, 150,
-            106, 89, 151, 102, 119, 159, 172, 121, 179, 251, 63, 218, 73, 27, 84, 71, 231, 233,
-            129, 165, 181, 87, 152, 28, 128, 97, 60, 168, 151, 117, 208, 149, 36, 153, 18, 159, 83,
-            4, 237, 135, 16, 3, 193, 13, 32, 215, 87, 3, 48, 254, 192, 221, 112, 223, 53, 77, 198,
-            128, 54, 63, 114, 225, 243, 241, 166, 252, 248, 189, 209, 228, 214, 120, 245, 224, 182,
-            166, 118, 14, 16, 83, 231, 209, 79, 4, 149, 145, 108, 15, 62, 108, 128, 158, 133, 78,
-            141, 28, 208, 225, 65, 226, 172, 19, 242, 66, 244, 222, 59, 231, 68, 69, 248, 111, 2,
-            162, 135, 191, 44, 203, 10, 194, 164, 231, 216, 128, 173, 122, 140, 218, 205, 222, 128,
-            215, 191, 40, 216, 214, 77, 27, 90, 144, 253, 77, 208, 214, 73, 167, 101, 40, 33, 2,
-            19, 2, 78, 125, 126, 229, 128, 25, 249, 143, 91, 234, 229, 99, 135, 101, 225, 221, 126,
-            43, 39, 116, 209, 166, 211, 150, 71, 204, 86, 227, 60, 52, 130, 224, 117, 243, 57, 157,
-            133, 128, 94, 84, 87, 142, 53, 210, 91, 172, 239, 170, 106, 177, 233, 85, 248, 15, 170,
-            36, 125, 161, 234, 254, 205, 255, 121, 105, 53, 86, 149, 83, 11, 37, 128, 91, 33, 242,
-            120, 86, 85, 201, 95, 166, 117, 7, 166, 199, 189, 199, 190, 198, 224, 142, 226, 153,
-            99, 71, 72, 174, 83, 235, 245, 119, 185, 65, 205, 77, 8, 128, 255, 255, 128, 6, 32,
-            133, 84, 101, 18, 126, 217, 191, 6, 177, 132, 244, 120, 216, 103, 150, 164, 175, 134,
-            187, 115, 179, 22, 186, 234, 226, 161, 121, 26, 222, 55, 128, 46, 76, 12, 88, 33, 101,
-            141, 239, 249, 204, 50, 40, 146, 72, 53, 196, 237, 137, 201, 156, 162, 175, 126, 235,
-            75, 129, 36, 170, 173, 227, 151, 84, 128, 150, 97, 175, 74, 255, 77, 186, 203, 201,
-            206, 163, 28, 53, 137, 246, 23, 243, 50, 33, 254, 102, 73, 205, 72, 95, 66, 237, 244,
-            178, 220, 95, 243, 128, 248, 133, 191, 217, 234, 55, 50, 83, 196, 84, 222, 191, 3, 25,
-            251, 225, 206, 154, 97, 250, 230, 7, 18, 148, 82, 158, 159, 24, 229, 187, 78, 248, 128,
-            53, 90, 65, 180, 241, 62, 98, 76, 77, 130, 116, 124, 57, 131, 192, 159, 129, 74, 73,
-            156, 182, 254, 24, 66, 120, 29, 111, 104, 113, 116, 87, 177, 128, 196, 149, 246, 233,
-            244, 233, 39, 94, 22, 147, 84, 71, 207, 212, 192, 32, 144, 53, 0, 121, 159, 67, 225,
-            200, 168, 120, 90, 201, 19, 105, 76, 107, 128, 16, 175, 11, 70, 56, 176, 152, 223, 221,
-            147, 33, 254, 212, 24, 192, 57, 46, 100, 39, 187, 73, 8, 172, 4, 82, 249, 0, 159, 110,
-            17, 65, 123, 128, 246, 155, 84, 144, 169, 142, 185, 181, 234, 141, 158, 150, 32, 54,
-            105, 130, 210, 235, 115, 71, 136, 249, 50, 98, 125, 33, 20, 63, 7, 21, 164, 206, 128,
-            235, 123, 178, 124, 30, 33, 51, 40, 195, 132, 173, 39, 202, 95, 110, 0, 162, 23, 137,
-            201, 55, 15, 57, 106, 235, 188, 156, 61, 114, 10, 174, 41, 128, 42, 252, 113, 214, 77,
-            16, 238, 32, 236, 70, 139, 69, 68, 26, 121, 55, 233, 83, 116, 152, 48, 185, 130, 225,
-            142, 192, 112, 218, 253, 171, 126, 230, 128, 74, 251, 28, 15, 44, 86, 24, 172, 214, 56,
-            125, 22, 226, 30, 144, 222, 170, 183, 86, 162, 193, 59, 163, 11, 245, 67, 117, 43, 41,
-            211, 246, 146, 128, 163, 87, 215, 73, 240, 35, 44, 127, 73, 101, 212, 114, 170, 201,
-            100, 246, 17, 253, 119, 168, 232, 187, 51, 39, 139, 234, 2, 152, 243, 120, 44, 228,
-            128, 132, 220, 6, 255, 222, 136, 239, 82, 249, 233, 142, 5, 148, 32, 136, 57, 224, 142,
-            86, 126, 17, 138, 137, 68, 89, 197, 42, 134, 72, 54, 105, 250, 128, 92, 187, 114, 111,
-            209, 220, 174, 223, 213, 39, 229, 13, 50, 234, 154, 124, 62, 120, 168, 20, 137, 91,
-            147, 151, 6, 46, 70, 148, 199, 180, 0, 128, 128, 253, 216, 104, 15, 195, 83, 31, 85, 6,
-            177, 192, 180, 67, 107, 77, 9, 12, 55, 182, 235, 115, 200, 71, 68, 190, 10, 25, 215,
-            53, 44, 255, 156, 128, 60, 106, 75, 142, 183, 243, 52, 207, 38, 138, 150, 93, 200, 67,
-            157, 246, 180, 22, 137, 12, 131, 69, 201, 138, 5, 64, 84, 62, 64, 78, 179, 199, 77, 8,
-            128, 255, 255, 128, 21, 95, 36, 122, 109, 82, 214, 86, 36, 233, 147, 9, 162, 15, 102,
-            163, 18, 54, 145, 232, 242, 92, 13, 57, 165, 177, 124, 159, 134, 148, 230, 191, 128,
-            17, 180, 212, 187, 199, 116, 184, 60, 247, 67, 45, 186, 99, 105, 214, 152, 151, 73,
-            184, 35, 176, 77, 137, 22, 86, 133, 113, 242, 131, 0, 114, 248, 128, 225, 64, 116, 89,
-            219, 148, 156, 23, 45, 108, 174, 179, 142, 4, 217, 70, 205, 136, 140, 181, 132, 177,
-            59, 99, 206, 108, 35, 84, 201, 222, 39, 26, 128, 52, 63, 111, 5, 37, 101, 158, 213,
-            159, 237, 129, 146, 15, 212, 168, 102, 128, 134, 142, 108, 250, 94, 0, 180, 92, 11, 78,
-            6, 31, 236, 117, 18, 128, 6, 234, 209, 47, 60, 70, 239, 171, 95, 104, 208, 48, 160,
-            199, 230, 154, 11, 9, 142, 177, 62, 180, 79, 232, 211, 82, 242, 194, 201, 131, 55, 216,
-            128, 26, 178, 61, 58, 248, 177, 214, 141, 149, 119, 43, 226, 196, 36, 145, 205, 209,
-            220, 22, 67, 145, 235, 28, 21, 68, 78, 17, 120, 10, 136, 203, 24, 128, 182, 219, 79,
-            27, 116, 254, 54, 219, 149, 90, 99, 158, 133, 31, 236, 70, 251, 155, 212, 117, 127,
-            167, 65, 153, 216, 53, 45, 196, 83, 37, 232, 179, 128, 19, 182, 172, 52, 190, 147, 215,
-            30, 0, 60, 93, 205, 2, 201, 109, 82, 110, 164, 3, 7, 164, 214, 154, 147, 23, 39, 241,
-            169, 64, 83, 146, 0, 128, 127, 74, 176, 32, 37, 115, 76, 139, 211, 14, 236, 66, 70, 48,
-            218, 49, 75, 65, 242, 41, 224, 8, 173, 179, 96, 55, 245, 179, 172, 240, 182, 25, 128,
-            55, 115, 145, 131, 180, 160, 117, 172, 130, 14, 156, 99, 139, 15, 7, 92, 3, 101, 20,
-            195, 126, 192, 251, 240, 207, 63, 247, 21, 6, 254, 158, 11, 128, 38, 117, 118, 233, 8,
-            173, 43, 112, 52, 222, 176, 191, 214, 37, 140, 221, 108, 2, 5, 177, 124, 224, 37, 131,
-            226, 44, 139, 76, 119, 193, 106, 228, 128, 60, 66, 232, 6, 65, 57, 164, 242, 246, 193,
-            0, 186, 127, 11, 93, 189, 9, 5, 247, 228, 206, 41, 25, 126, 99, 255, 190, 56, 250, 18,
-            237, 163, 128, 180, 239, 24, 135, 163, 86, 144, 19, 95, 194, 3, 154, 158, 101, 108,
-            146, 217, 137, 206, 117, 162, 93, 253, 169, 85, 204, 228, 147, 230, 236, 78, 0, 128,
-            127, 165, 31, 245, 177, 222, 92, 217, 26, 171, 244, 149, 96, 40, 164, 152, 42, 34, 154,
-            179, 57, 108, 74, 212, 136, 80, 214, 0, 216, 39, 244, 98, 128, 233, 240, 108, 189, 251,
-            219, 73, 68, 43, 151, 120, 194, 137, 251, 91, 11, 244, 59, 6, 184, 236, 190, 61, 39,
-            87, 87, 222, 170, 186, 8, 25, 8, 128, 204, 207, 180, 179, 96, 147, 201, 61, 238, 185,
-            48, 155, 251, 199, 208, 118, 234, 226, 157, 221, 210, 63, 166, 242, 24, 216, 230, 91,
-            208, 87, 9, 15, 77, 8, 128, 255, 255, 128, 32, 71, 114, 154, 208, 90, 0, 91, 213, 125,
-            214, 149, 124, 79, 23, 205, 52, 232, 217, 89, 34, 42, 160, 39, 122, 43, 229, 98, 243,
-            42, 206, 40, 128, 131, 102, 63, 30, 5, 91, 18, 216, 61, 183, 253, 15, 9, 26, 163, 2,
-            218, 1, 134, 95, 193, 154, 158, 13, 78, 98, 203, 226, 183, 67, 171, 125, 128, 157, 109,
-            179, 216, 230, 139, 210, 90, 41, 101, 53, 14, 53, 48, 207, 54, 65, 67, 137, 49, 73,
-            123, 138, 221, 32, 141, 114, 111, 219, 172, 85, 158, 128, 44, 1, 232, 124, 192, 192,
-            10, 7, 124, 59, 229, 244, 183, 26, 152, 13, 90, 74, 46, 88, 99, 20, 179, 33, 33, 14,
-            121, 131, 218, 93, 228, 18, 128, 194, 190, 49, 52, 173, 254, 128, 110, 138, 177, 97,
-            153, 252, 60, 138, 233, 134, 69, 106, 32, 184, 185, 13, 81, 162, 128, 227, 210, 219,
-            50, 32, 56, 128, 220, 163, 230, 155, 6, 111, 176, 235, 78, 131, 102, 186, 89, 228, 98,
-            9, 122, 63, 152, 75, 221, 230, 177, 197, 127, 42, 86, 36, 149, 100, 211, 10, 128, 93,
-            16, 106, 37, 26, 141, 150, 6, 81, 253, 146, 149, 21, 130, 37, 174, 124, 41, 1, 106,
-            230, 129, 65, 69, 180, 62, 9, 64, 15, 109, 119, 154, 128, 222, 83, 57, 102, 10, 56, 28,
-            143, 48, 68, 101, 86, 234, 53, 203, 83, 164, 112, 62, 220, 93, 180, 69, 149, 250, 235,
-            250, 124, 141, 91, 182, 134, 128, 197, 220, 184, 144, 48, 87, 179, 9, 12, 245, 165,
-            103, 193, 28, 253, 79, 70, 230, 195, 131, 5, 124, 112, 171, 160, 54, 72, 205, 74, 36,
-            252, 245, 128, 245, 22, 100, 52, 187, 161, 87, 79, 148, 130, 213, 215, 219, 143, 29,
-            139, 179, 140, 108, 71, 105, 9, 8, 103, 53, 88, 66, 77, 219, 88, 209, 174, 128, 212,
-            186, 208, 207, 165, 55, 183, 68, 170, 127, 146, 158, 202, 36, 119, 238, 109, 55, 243,
-            183, 112, 162, 134, 253, 86, 22, 237, 103, 242, 252, 208, 237, 128, 17, 99, 203, 152,
-            149, 177, 197, 203, 162, 120, 228, 91, 138, 154, 181, 225, 237, 175, 118, 130, 210,
-            223, 193, 13, 112, 221, 215, 17, 6, 226, 73, 28, 128, 4, 216, 2, 157, 168, 220, 49,
-            106, 247, 67, 24, 49, 155, 69, 122, 72, 239, 54, 246, 135, 222, 46, 194, 58, 113, 145,
-            235, 169, 125, 64, 211, 122, 128, 195, 66, 41, 72, 199, 187, 180, 153, 243, 223, 71,
-            98, 195, 151, 156, 184, 112, 50, 29, 246, 216, 59, 20, 165, 7, 240, 232, 102, 97, 80,
-            164, 206, 128, 145, 63, 250, 26, 195, 75, 39, 16, 83, 141, 48, 10, 106, 126, 174, 199,
-            201, 116, 94, 48, 35, 20, 96, 5, 64, 109, 69, 226, 8, 122, 20, 148, 128, 101, 188, 79,
-            138, 0, 82, 49, 142, 224, 148, 243, 97, 110, 49, 210, 178, 183, 118, 23, 19, 0, 26,
-            255, 9, 38, 239, 0, 59, 152, 8, 232, 65, 77, 8, 128, 255, 255, 128, 52, 152, 211, 190,
-            190, 158, 24, 14, 187, 114, 245, 129, 54, 61, 154, 0, 117, 139, 218, 240, 191, 56, 170,
-            150, 251, 101, 38, 180, 152, 139, 79, 38, 128, 251, 253, 5, 15, 246, 96, 81, 182, 123,
-            17, 154, 162, 226, 188, 243, 6, 139, 58, 157, 116, 190, 107, 108, 13, 89, 59, 173, 22,
-            237, 116, 122, 235, 128, 179, 28, 208, 45, 209, 100, 121, 155, 83, 66, 137, 54, 219,
-            53, 142, 32, 150, 7, 172, 148, 18, 122, 21, 36, 182, 93, 206, 2, 165, 195, 64, 44, 128,
-            8, 180, 57, 2, 38, 131, 151, 161, 248, 77, 111, 109, 180, 209, 200, 113, 88, 154, 143,
-            210, 153, 37, 193, 151, 17, 159, 76, 65, 90, 104, 54, 176, 128, 18, 52, 74, 173, 8,
-            114, 201, 4, 207, 101, 41, 158, 121, 41, 143, 233, 34, 3, 199, 241, 113, 152, 253, 255,
-            203, 131, 13, 102, 74, 25, 177, 183, 128, 202, 97, 201, 10, 67, 13, 160, 242, 224, 111,
-            202, 243, 55, 6, 9, 56, 208, 220, 27, 245, 152, 201, 250, 249, 228, 7, 139, 211, 173,
-            141, 5, 174, 128, 220, 10, 234, 6, 246, 9, 0, 85, 192, 41, 60, 21, 71, 124, 110, 49,
-            251, 223, 34, 177, 93, 219, 34, 146, 191, 71, 237, 3, 129, 158, 76, 193, 128, 192, 236,
-            45, 201, 236, 228, 208, 145, 220, 128, 181, 224, 168, 124, 9, 188, 11, 192, 67, 96,
-            172, 46, 68, 103, 55, 211, 214, 222, 12, 139, 101, 140, 128, 86, 218, 58, 172, 28, 45,
-            161, 2, 182, 166, 215, 163, 52, 76, 140, 207, 108, 135, 124, 120, 62, 158, 57, 193, 15,
-            143, 147, 86, 16, 141, 186, 29, 128, 111, 168, 178, 161, 106, 91, 217, 175, 166, 28,
-            60, 105, 190, 193, 46, 168, 61, 135, 62, 166, 95, 215, 142, 58, 13, 243, 77, 117, 49,
-            78, 151, 160, 128, 191, 212, 224, 98, 47, 88, 40, 255, 123, 79, 95, 176, 156, 243, 208,
-            110, 56, 211, 109, 85, 137, 179, 175, 25, 13, 241, 107, 209, 177, 135, 146, 163, 128,
-            194, 209, 192, 145, 251, 123, 139, 22, 187, 137, 235, 254, 6, 111, 31, 158, 192, 69,
-            78, 108, 156, 64, 187, 115, 82, 223, 9, 82, 61, 51, 119, 95, 128, 245, 163, 92, 184,
-            100, 208, 179, 172, 214, 16, 140, 71, 242, 205, 253, 56, 207, 97, 126, 100, 165, 248,
-            139, 109, 172, 208, 235, 249, 200, 80, 220, 254, 128, 158, 200, 116, 18, 168, 59, 54,
-            44, 90, 135, 190, 184, 13, 98, 181, 73, 85, 229, 180, 57, 54, 210, 160, 124, 167, 184,
-            99, 32, 99, 12, 75, 105, 128, 11, 18, 66, 155, 163, 28, 120, 167, 85, 154, 216, 121,
-            19, 215, 47, 51, 104, 158, 103, 55, 192, 211, 168, 253, 220, 78, 160, 45, 253, 45, 211,
-            192, 128, 255, 205, 138, 183, 70, 207, 10, 69, 205, 195, 48, 211, 73, 18, 184, 30, 90,
-            174, 196, 104, 34, 171, 249, 50, 206, 143, 203, 180, 165, 233, 162, 162, 77, 8, 128,
-            255, 255, 128, 58, 201, 166, 146, 29, 113, 160, 18, 70, 236, 115, 70, 19, 165, 72, 68,
-            38, 85, 231, 110, 214, 218, 156, 215, 151, 175, 220, 188, 182, 73, 74, 17, 128, 57, 37,
-            64, 205, 152, 86, 106, 143, 131, 35, 221, 109, 149, 45, 141, 167, 37, 79, 236, 129, 65,
-            7, 181, 110, 55, 11, 250, 240, 52, 117, 169, 158, 128, 204, 101, 91, 16, 8, 83, 117,
-            119, 139, 121, 194, 37, 123, 134, 194, 90, 40, 219, 78, 233, 131, 48, 203, 219, 106,
-            122, 71, 25, 40, 11, 225, 153, 128, 58, 54, 217, 135, 108, 131, 104, 19, 90, 4, 53,
-            235, 35, 168, 213, 219, 94, 202, 119, 183, 11, 157, 233, 29, 160, 191, 108, 188, 51,
-            12, 60, 141, 128, 160, 174, 75, 199, 199, 233, 169, 101, 168, 78, 26, 106, 176, 105,
-            38, 129, 28, 247, 14, 214, 218, 221, 13, 240, 9, 20, 63, 123, 141, 26, 190, 139, 128,
-            45, 224, 62, 80, 60, 203, 182, 72, 101, 127, 17, 216, 166, 187, 183, 16, 192, 61, 92,
-            109, 127, 2, 139, 56, 251, 157, 175, 125, 230, 247, 185, 101, 128, 33, 130, 72, 131,
-            94, 76, 68, 224, 132, 150, 251, 90, 1, 34, 7, 183, 173, 178, 240, 110, 250, 90, 137,
-            100, 116, 16, 195, 20, 129, 2, 12, 105, 128, 88, 2, 36, 134, 82, 225, 134, 78, 174, 11,
-            164, 130, 125, 128, 124, 207, 48, 63, 217, 247, 112, 230, 13, 150, 217, 139, 64, 60,
-            81, 63, 44, 190, 128, 248, 1, 253, 234, 97, 207, 65, 159, 63, 170, 168, 182, 82, 204,
-            227, 23, 134, 91, 81, 166, 28, 113, 220, 58, 95, 180, 123, 243, 6, 127, 139, 43, 128,
-            196, 0, 96, 165, 125, 95, 251, 75, 16, 200, 144, 62, 177, 94, 27, 145, 69, 51, 153,
-            206, 126, 148, 236, 188, 24, 58, 222, 98, 81, 47, 192, 198, 128, 251, 20, 123, 1, 82,
-            61, 114, 201, 104, 55, 28, 164, 11, 225, 82, 170, 17, 15, 255, 80, 199, 143, 187, 252,
-            119, 113, 83, 197, 12, 133, 199, 101, 128, 0, 93, 235, 202, 77, 21, 48, 18, 116, 37,
-            121, 157, 205, 37, 139, 102, 158, 231, 50, 87, 189, 72, 167, 17, 211, 116, 181, 1, 120,
-            27, 19, 249, 128, 87, 232, 34, 89, 226, 3, 137, 227, 28, 203, 181, 169, 215, 140, 10,
-            249, 197, 27, 218, 50, 128, 145, 14, 251, 156, 87, 140, 184, 184, 215, 8, 42, 128, 214,
-            186, 104, 28, 203, 26, 133, 97, 30, 82, 219, 208, 159, 189, 193, 198, 175, 244, 101,
-            39, 137, 114, 76, 22, 132, 158, 108, 244, 117, 184, 90, 85, 128, 14, 208, 6, 122, 78,
-            49, 9, 54, 65, 194, 183, 96, 215, 66, 8, 73, 20, 5, 110, 155, 203, 10, 199, 77, 90,
-            151, 44, 247, 242, 234, 143, 76, 128, 137, 223, 40, 196, 188, 73, 243, 240, 70, 195,
-            150, 112, 119, 234, 146, 188, 3, 143, 95, 228, 15, 193, 177, 235, 203, 165, 146, 65,
-            141, 18, 248, 235, 77, 8, 128, 255, 255, 128, 72, 227, 186, 208, 65, 38, 67, 240, 103,
-            92, 8, 31, 242, 47, 245, 124, 69, 128, 43, 61, 106, 233, 158, 65, 44, 123, 190, 30,
-            136, 221, 162, 110, 128, 20, 154, 240, 177, 127, 113, 180, 62, 122, 234, 248, 200, 205,
-            42, 62, 125, 112, 181, 95, 33, 195, 255, 255, 169, 168, 104, 31, 17, 9, 24, 157, 171,
-            128, 208, 215, 84, 115, 128, 144, 126, 59, 82, 130, 127, 14, 160, 184, 204, 246, 129,
-            118, 140, 182, 5, 115, 188, 129, 114, 59, 124, 74, 133, 195, 116, 82, 128, 245, 84,
-            141, 238, 0, 88, 3, 93, 78, 160, 149, 210, 209, 205, 188, 118, 155, 9, 41, 170, 77, 84,
-            141, 233, 167, 40, 235, 193, 187, 22, 235, 200, 128, 67, 167, 238, 15, 193, 10, 24,
-            162, 254, 231, 215, 199, 172, 254, 225, 135, 55, 189, 91, 55, 228, 121, 131, 223, 125,
-            40, 54, 153, 135, 202, 139, 121, 128, 40, 199, 178, 138, 237, 194, 159, 253, 110, 97,
-            131, 44, 90, 159, 151, 221, 200, 60, 141, 9, 61, 225, 19, 96, 144, 170, 177, 48, 242,
-            92, 158, 235, 128, 252, 114, 254, 107, 51, 251, 164, 43, 155, 188, 232, 54, 164, 242,
-            239, 19, 144, 44, 168, 19, 159, 30, 79, 200, 254, 66, 197, 216, 213, 160, 83, 224, 128,
-            152, 98, 71, 87, 176, 216, 190, 121, 251, 201, 190, 32, 15, 23, 229, 227, 64, 32, 180,
-            98, 168, 212, 246, 224, 140, 113, 76, 238, 1, 159, 197, 67, 128, 183, 68, 13, 132, 134,
-            132, 53, 250, 94, 25, 59, 107, 189, 188, 146, 126, 239, 148, 197, 234, 108, 254, 34,
-            173, 202, 44, 89, 168, 218, 147, 247, 187, 128, 203, 121, 148, 63, 84, 92, 146, 115,
-            80, 45, 207, 173, 216, 199, 47, 180, 75, 35, 145, 102, 158, 38, 123, 19, 85, 144, 176,
-            241, 59, 96, 81, 31, 128, 28, 122, 35, 15, 86, 120, 97, 7, 171, 19, 190, 105, 208, 148,
-            100, 236, 249, 120, 241, 229, 197, 41, 220, 148, 178, 32, 20, 138, 252, 160, 3, 181,
-            128, 107, 53, 208, 95, 35, 48, 191, 237, 246, 105, 67, 216, 186, 129, 234, 10, 245,
-            180, 72, 82, 209, 69, 228, 22, 204, 157, 177, 214, 7, 125, 130, 162, 128, 200, 182,
-            239, 18, 113, 83, 136, 129, 241, 86, 75, 2, 108, 211, 184, 233, 133, 125, 126, 229,
-            197, 18, 141, 74, 49, 209, 251, 162, 123, 132, 67, 241, 128, 98, 169, 128, 71, 226, 12,
-            74, 91, 45, 220, 144, 255, 5, 43, 4, 161, 5, 168, 254, 146, 163, 188, 181, 188, 245,
-            209, 229, 67, 252, 181, 99, 38, 128, 141, 190, 165, 156, 57, 149, 153, 127, 68, 145,
-            42, 198, 63, 244, 246, 245, 163, 214, 214, 230, 239, 171, 146, 69, 208, 69, 33, 128,
-            110, 194, 184, 150, 128, 87, 53, 244, 157, 86, 127, 165, 192, 182, 32, 74, 181, 129,
-            234, 159, 244, 180, 47, 218, 164, 250, 98, 146, 170, 222, 30, 50, 65, 128, 99, 114,
-            192, 77, 8, 128, 255, 255, 128, 98, 224, 118, 12, 76, 51, 177, 134, 55, 11, 240, 54,
-            16, 130, 71, 53, 233, 48, 237, 233, 145, 55, 196, 116, 75, 125, 25, 10, 222, 110, 145,
-            72, 128, 123, 97, 129, 68, 255, 146, 109, 130, 170, 164, 51, 127, 247, 61, 60, 39, 45,
-            247, 96, 65, 102, 249, 254, 240, 235, 150, 72, 191, 254, 139, 212, 143, 128, 10, 145,
-            115, 111, 253, 223, 196, 17, 64, 94, 25, 146, 103, 246, 48, 225, 201, 116, 96, 194,
-            171, 54, 38, 98, 86, 3, 3, 71, 129, 31, 6, 175, 128, 199, 195, 90, 57, 51, 127, 202,
-            28, 248, 27, 156, 174, 209, 38, 194, 27, 143, 96, 209, 92, 159, 32, 96, 206, 102, 91,
-            145, 17, 100, 34, 161, 225, 128, 111, 174, 226, 248, 116, 189, 185, 98, 48, 158, 21,
-            64, 84, 37, 132, 251, 159, 119, 145, 158, 160, 94, 50, 14, 78, 36, 7, 69, 225, 169,
-            118, 163, 128, 130, 131, 93, 107, 228, 124, 194, 73, 133, 43, 145, 205, 67, 139, 12,
-            122, 202, 182, 137, 8, 117, 46, 210, 14, 252, 151, 44, 230, 172, 18, 153, 208, 128, 90,
-            123, 171, 183, 168, 73, 23, 229, 112, 45, 203, 101, 146, 205, 166, 179, 139, 173, 186,
-            161, 12, 37, 201, 110, 4, 24, 59, 86, 176, 85, 19, 214, 128, 34, 137, 24, 131, 123, 49,
-            233, 144, 163, 244, 68, 122, 147, 162, 184, 83, 218, 111, 153, 130, 208, 164, 104, 189,
-            32, 120, 168, 115, 238, 172, 147, 241, 128, 253, 185, 119, 165, 240, 12, 10, 37, 37,
-            189, 229, 131, 173, 106, 160, 226, 95, 234, 200, 170, 142, 79, 97, 231, 226, 69, 40,
-            164, 129, 29, 119, 217, 128, 215, 202, 10, 223, 65, 233, 98, 218, 223, 110, 11, 98,
-            108, 232, 78, 52, 25, 27, 11, 140, 93, 219, 35, 105, 104, 192, 213, 107, 103, 160, 147,
-            131, 128, 213, 23, 236, 208, 56, 116, 59, 227, 151, 243, 244, 198, 4, 58, 214, 202, 73,
-            178, 35, 192, 153, 13, 96, 246, 250, 18, 143, 18, 70, 157, 58, 4, 128, 50, 104, 164,
-            63, 181, 151, 229, 156, 25, 254, 186, 145, 93, 195, 131, 202, 231, 191, 255, 114, 215,
-            176, 156, 119, 36, 246, 10, 30, 88, 250, 251, 240, 128, 103, 233, 178, 7, 124, 202, 44,
-            82, 24, 123, 141, 45, 162, 11, 34, 22, 42, 104, 22, 81, 73, 15, 223, 49, 220, 156, 80,
-            188, 243, 88, 152, 39, 128, 24, 57, 95, 182, 144, 171, 157, 147, 39, 2, 248, 223, 190,
-            4, 238, 26, 205, 51, 243, 21, 92, 81, 253, 196, 58, 117, 8, 176, 3, 193, 96, 167, 128,
-            151, 147, 172, 137, 135, 140, 127, 19, 211, 158, 45, 240, 247, 87, 188, 12, 50, 211,
-            176, 157, 204, 105, 116, 196, 132, 232, 58, 25, 94, 127, 118, 37, 128, 243, 24, 174,
-            152, 134, 125, 213, 8, 179, 123, 215, 74, 241, 38, 134, 46, 15, 246, 224, 164, 243,
-            101, 224, 238, 242, 220, 108, 49, 7, 49, 19, 171, 77, 8, 128, 255, 255, 128, 126, 26,
-            2, 115, 125, 60, 237, 15, 224, 8, 167, 168, 170, 64, 193, 115, 82, 79, 139, 126, 45,
-            146, 247, 235, 138, 57, 197, 193, 187, 235, 128, 255, 128, 25, 176, 10, 6, 193, 115,
-            82, 78, 202, 212, 37, 48, 143, 31, 79, 135, 198, 58, 65, 93, 240, 196, 114, 75, 89,
-            200, 211, 24, 49, 211, 43, 248, 128, 139, 88, 169, 62, 211, 4, 166, 73, 191, 142, 142,
-            178, 189, 92, 38, 216, 103, 217, 211, 148, 1, 115, 180, 191, 77, 82, 223, 207, 20, 125,
-            130, 6, 128, 209, 115, 203, 18, 175, 206, 244, 86, 105, 74, 157, 81, 221, 58, 102, 232,
-            144, 178, 104, 52, 9, 139, 91, 182, 234, 82, 83, 165, 63, 160, 7, 114, 128, 243, 117,
-            5, 152, 169, 216, 80, 167, 179, 87, 94, 251, 216, 140, 34, 48, 215, 29, 91, 152, 228,
-            185, 96, 65, 138, 128, 76, 168, 225, 197, 100, 250, 128, 73, 28, 242, 167, 201, 42,
-            255, 15, 244, 48, 253, 183, 243, 188, 29, 212, 143, 41, 81, 54, 199, 149, 4, 212, 255,
-            92, 150, 2, 85, 79, 165, 249, 128, 70, 150, 17, 211, 84, 245, 117, 100, 205, 53, 45, 9,
-            109, 239, 251, 118, 83, 123, 151, 163, 98, 7, 31, 40, 101, 127, 254, 39, 185, 235, 191,
-            126, 128, 172, 29, 177, 47, 180, 240, 225, 255, 229, 172, 48, 134, 20, 239, 15, 216,
-            82, 179, 108, 203, 153, 93, 70, 159, 209, 54, 236, 145, 124, 170, 81, 107, 128, 186,
-            239, 163, 235, 187, 188, 130, 158, 8, 16, 226, 8, 219, 34, 130, 174, 188, 108, 89, 154,
-            67, 90, 241, 11, 216, 9, 10, 128, 23, 129, 19, 221, 128, 186, 48, 62, 144, 230, 102,
-            102, 238, 208, 166, 83, 134, 87, 62, 187, 7, 25, 145, 177, 195, 61, 237, 224, 149, 223,
-            100, 143, 62, 16, 52, 146, 152, 128, 156, 234, 72, 98, 6, 27, 209, 167, 158, 64, 242,
-            192, 158, 62, 9, 86, 106, 165, 19, 16, 74, 190, 17, 113, 127, 135, 236, 235, 0, 127,
-            106, 39, 128, 40, 166, 91, 35, 4, 38, 216, 38, 58, 31, 180, 163, 228, 163, 221, 27, 13,
-            131, 28, 101, 92, 226, 34, 98, 131, 32, 29, 241, 155, 64, 143, 57, 128, 82, 245, 36,
-            234, 173, 169, 46, 196, 144, 134, 113, 200, 13, 87, 68, 4, 37, 101, 66, 251, 109, 83,
-            160, 85, 86, 69, 174, 215, 89, 230, 112, 188, 128, 221, 67, 169, 147, 247, 63, 47, 70,
-            136, 91, 226, 27, 120, 51, 140, 162, 62, 91, 88, 70, 44, 204, 72, 185, 113, 201, 160,
-            83, 159, 129, 97, 215, 128, 132, 55, 78, 3, 203, 239, 90, 240, 56, 255, 237, 43, 30,
-            91, 205, 38, 41, 33, 240, 10, 17, 141, 71, 173, 58, 21, 199, 163, 147, 192, 51, 251,
-            128, 180, 232, 114, 114, 129, 107, 156, 91, 142, 40, 134, 148, 85, 46, 182, 150, 215,
-            2, 32, 125, 172, 93, 123, 212, 118, 25, 55, 24, 116, 138, 17, 101, 77, 8, 128, 255,
-            255, 128, 128, 254, 250, 23, 70, 79, 240, 10, 70, 190, 77, 23, 122, 171, 19, 37, 218,
-            153, 112, 130, 94, 30, 206, 18, 150, 32, 162, 65, 73, 163, 16, 207, 128, 137, 190, 230,
-            69, 134, 11, 100, 164, 10, 55, 253, 247, 153, 197, 172, 120, 125, 235, 240, 22, 86,
-            199, 75, 131, 220, 63, 150, 25, 139, 71, 46, 106, 128, 73, 13, 115, 235, 64, 36, 245,
-            221, 252, 29, 178, 97, 173, 142, 37, 101, 82, 201, 121, 39, 123, 78, 17, 212, 132, 196,
-            101, 210, 70, 235, 119, 106, 128, 253, 222, 171, 127, 115, 14, 115, 223, 212, 163, 30,
-            77, 202, 78, 253, 70, 8, 102, 29, 54, 82, 179, 118, 125, 228, 121, 204, 89, 155, 222,
-            147, 83, 128, 227, 191, 35, 207, 4, 38, 245, 230, 87, 71, 56, 117, 73, 164, 79, 249,
-            251, 166, 168, 12, 182, 31, 114, 208, 98, 190, 35, 40, 34, 54, 149, 142, 128, 210, 191,
-            125, 82, 15, 167, 39, 230, 247, 255, 40, 150, 213, 65, 72, 78, 231, 253, 153, 154, 181,
-            54, 10, 85, 83, 168, 104, 19, 123, 238, 210, 157, 128, 188, 148, 108, 164, 57, 34, 79,
-            98, 107, 189, 224, 8, 59, 254, 177, 215, 116, 47, 35, 20, 70, 19, 107, 248, 229, 173,
-            205, 99, 127, 84, 140, 38, 128, 89, 233, 77, 43, 63, 224, 59, 236, 215, 221, 139, 212,
-            69, 171, 94, 165, 14, 206, 152, 89, 1, 53, 196, 34, 246, 82, 59, 225, 142, 199, 88,
-            243, 128, 117, 226, 17, 47, 123, 236, 238, 34, 252, 243, 63, 155, 180, 126, 70, 157,
-            186, 178, 213, 77, 110, 145, 185, 75, 124, 249, 105, 205, 153, 181, 43, 36, 128, 157,
-            170, 117, 166, 123, 135, 0, 223, 80, 39, 185, 37, 13, 197, 134, 103, 88, 139, 185, 94,
-            124, 140, 157, 193, 219, 105, 162, 48, 142, 116, 46, 248, 128, 160, 91, 89, 227, 55,
-            123, 4, 89, 135, 36, 197, 89, 65, 101, 147, 121, 121, 162, 65, 36, 178, 25, 40, 209,
-            173, 255, 147, 13, 45, 189, 157, 250, 128, 41, 153, 119, 56, 243, 143, 95, 161, 80, 46,
-            98, 74, 216, 137, 226, 201, 173, 30, 216, 212, 152, 61, 217, 17, 119, 35, 134, 112,
-            232, 65, 164, 120, 128, 165, 55, 127, 175, 186, 31, 23, 220, 207, 96, 33, 98, 108, 159,
-            8, 38, 117, 139, 15, 18, 75, 119, 213, 84, 118, 33, 191, 143, 40, 188, 193, 157, 128,
-            222, 213, 70, 76, 206, 239, 36, 101, 17, 253, 75, 245, 2, 241, 235, 87, 87, 222, 38,
-            84, 92, 92, 188, 240, 191, 129, 94, 229, 17, 75, 55, 228, 128, 162, 140, 154, 201, 203,
-            90, 176, 148, 127, 231, 168, 21, 129, 93, 169, 155, 69, 224, 117, 12, 52, 111, 190, 79,
-            218, 237, 3, 26, 139, 72, 33, 23, 128, 28, 63, 210, 252, 209, 52, 215, 165, 54, 173,
-            246, 45, 171, 12, 203, 82, 21, 25, 104, 150, 58, 235, 34, 234, 147, 220, 232, 209, 128,
-            233, 52, 165, 77, 8, 128, 255, 255, 128, 137, 99, 201, 150, 213, 190, 114, 244, 6, 179,
-            169, 97, 77, 105, 45, 53, 22, 156, 93, 12, 184, 184, 84, 163, 91, 104, 22, 72, 172, 99,
-            223, 187, 128, 197, 219, 148, 198, 173, 94, 34, 66, 48, 182, 111, 221, 140, 160, 148,
-            77, 231, 210, 200, 148, 207, 51, 95, 208, 62, 210, 245, 20, 68, 139, 200, 4, 128, 77,
-            144, 61, 88, 8, 197, 14, 144, 145, 125, 56, 136, 208, 232, 145, 155, 219, 207, 92, 164,
-            189, 153, 166, 199, 121, 225, 217, 127, 9, 134, 39, 186, 128, 196, 124, 80, 137, 76,
-            55, 85, 185, 142, 135, 241, 142, 186, 100, 253, 165, 77, 160, 85, 32, 134, 41, 247,
-            184, 65, 131, 59, 132, 169, 9, 30, 166, 128, 18, 117, 140, 186, 228, 64, 135, 84, 30,
-            98, 181, 80, 230, 253, 70, 136, 95, 136, 171, 130, 46, 36, 28, 19, 143, 163, 105, 132,
-            15, 51, 193, 199, 128, 221, 162, 121, 197, 49, 117, 171, 105, 13, 99, 191, 44, 219, 54,
-            37, 241, 44, 45, 172, 149, 42, 252, 240, 231, 151, 32, 250, 229, 157, 108, 230, 123,
-            128, 85, 204, 145, 73, 4, 179, 123, 90, 28, 38, 46, 107, 205, 172, 132, 83, 73, 150,
-            32, 87, 203, 253, 11, 237, 250, 4, 168, 207, 139, 102, 15, 50, 128, 79, 6, 201, 246,
-            121, 70, 151, 141, 50, 106, 166, 207, 183, 186, 173, 93, 245, 23, 122, 225, 14, 110,
-            67, 117, 79, 88, 197, 78, 27, 243, 172, 211, 128, 26, 191, 13, 212, 124, 197, 72, 93,
-            188, 69, 32, 48, 26, 65, 16, 151, 10, 142, 204, 17, 255, 100, 85, 131, 92, 60, 48, 128,
-            164, 159, 216, 161, 128, 176, 133, 137, 219, 162, 236, 2, 48, 223, 221, 131, 212, 23,
-            43, 87, 248, 115, 231, 110, 26, 95, 225, 207, 107, 93, 164, 187, 72, 69, 86, 225, 188,
-            128, 50, 147, 36, 178, 48, 203, 172, 96, 168, 69, 36, 123, 104, 92, 74, 223, 197, 134,
-            151, 188, 138, 123, 247, 105, 170, 126, 38, 79, 175, 6, 110, 251, 128, 91, 14, 212,
-            160, 69, 125, 86, 21, 62, 176, 59, 10, 244, 109, 5, 126, 233, 245, 115, 236, 1, 216,
-            100, 233, 73, 229, 190, 213, 199, 186, 115, 57, 128, 122, 80, 179, 183, 66, 91, 86, 66,
-            44, 113, 18, 236, 200, 216, 160, 62, 130, 153, 124, 125, 218, 30, 160, 165, 232, 219,
-            187, 161, 206, 217, 238, 164, 128, 23, 137, 235, 127, 228, 222, 79, 161, 131, 192, 77,
-            101, 19, 4, 8, 144, 158, 213, 48, 93, 185, 40, 209, 229, 224, 60, 56, 207, 3, 27, 102,
-            215, 128, 149, 254, 169, 111, 198, 105, 94, 163, 208, 239, 170, 235, 31, 98, 253, 82,
-            71, 239, 23, 186, 176, 141, 237, 85, 39, 235, 244, 19, 248, 173, 73, 74, 128, 18, 246,
-            63, 139, 55, 101, 233, 177, 71, 116, 10, 149, 84, 98, 53, 181, 234, 20, 179, 187, 71,
-            94, 87, 226, 132, 165, 35, 69, 46, 19, 162, 231, 77, 8, 128, 255, 255, 128, 156, 1, 8,
-            84, 91, 129, 182, 188, 2, 83, 7, 165, 8, 120, 243, 87, 173, 180, 121, 91, 65, 154, 45,
-            21, 71, 174, 77, 241, 179, 136, 13, 72, 128, 230, 28, 144, 93, 130, 226, 188, 221, 170,
-            208, 100, 82, 172, 225, 62, 209, 46, 68, 196, 33, 67, 4, 255, 22, 37, 196, 36, 174,
-            251, 236, 118, 222, 128, 214, 118, 96, 46, 102, 89, 165, 41, 246, 190, 182, 43, 105,
-            90, 176, 76, 189, 195, 215, 36, 237, 235, 187, 37, 79, 1, 100, 87, 76, 41, 73, 144,
-            128, 54, 183, 21, 156, 176, 38, 153, 68, 182, 160, 1, 199, 236, 221, 5, 213, 176, 16,
-            142, 78, 216, 40, 162, 25, 141, 11, 188, 248, 30, 210, 31, 163, 128, 75, 85, 160, 58,
-            176, 187, 39, 87, 199, 87, 164, 214, 101, 23, 26, 161, 102, 141, 46, 78, 231, 201, 60,
-            193, 167, 203, 38, 224, 183, 212, 245, 200, 128, 195, 26, 85, 247, 19, 6, 184, 129,
-            146, 36, 245, 138, 228, 216, 21, 170, 212, 18, 69, 21, 148, 124, 116, 72, 53, 13, 208,
-            37, 217, 253, 6, 212, 128, 40, 219, 41, 78, 113, 46, 62, 53, 196, 90, 196, 164, 54,
-            253, 126, 163, 171, 242, 23, 98, 212, 184, 39, 40, 163, 161, 94, 91, 183, 100, 173,
-            194, 128, 134, 161, 195, 243, 2, 138, 198, 95, 202, 238, 164, 185, 49, 3, 130, 20, 17,
-            174, 158, 221, 122, 51, 240, 97, 103, 182, 227, 64, 20, 199, 20, 49, 128, 255, 72, 48,
-            114, 3, 35, 14, 17, 46, 168, 199, 166, 43, 153, 163, 166, 53, 162, 127, 82, 97, 138,
-            54, 53, 35, 12, 177, 237, 37, 53, 113, 72, 128, 46, 149, 49, 64, 22, 110, 232, 250,
-            216, 176, 185, 77, 36, 109, 242, 191, 137, 155, 42, 3, 180, 61, 5, 146, 70, 239, 219,
-            5, 155, 189, 189, 161, 128, 241, 246, 18, 164, 219, 201, 100, 248, 235, 228, 160, 102,
-            103, 123, 5, 69, 48, 159, 2, 44, 149, 207, 228, 250, 167, 169, 178, 164, 72, 221, 10,
-            171, 128, 165, 52, 248, 115, 98, 191, 225, 147, 225, 201, 200, 167, 240, 3, 242, 222,
-            73, 84, 112, 54, 52, 42, 159, 193, 123, 184, 193, 197, 167, 182, 220, 134, 128, 126,
-            186, 202, 232, 87, 89, 157, 125, 156, 148, 133, 242, 194, 73, 236, 186, 151, 239, 221,
-            24, 95, 9, 136, 87, 139, 73, 49, 113, 145, 93, 7, 62, 128, 172, 197, 129, 8, 203, 252,
-            76, 29, 110, 106, 58, 21, 178, 190, 175, 177, 137, 207, 54, 251, 73, 35, 232, 228, 250,
-            155, 129, 160, 134, 206, 127, 235, 128, 248, 191, 25, 102, 35, 45, 111, 123, 238, 86,
-            29, 254, 128, 68, 227, 175, 192, 93, 19, 217, 162, 138, 47, 252, 170, 21, 173, 80, 187,
-            35, 100, 208, 128, 50, 196, 199, 63, 198, 32, 22, 234, 199, 53, 49, 204, 66, 56, 241,
-            135, 31, 199, 250, 237, 131, 168, 77, 113, 91, 198, 114, 41, 43, 164, 199, 210, 77, 8,
-            128, 255, 255, 128, 161, 170, 188, 190, 218, 88, 84, 19, 91, 88, 194, 120, 165, 126,
-            95, 15, 221, 19, 203, 0, 88, 211, 220, 121, 20, 193, 150, 197, 57, 168, 204, 61, 128,
-            174, 73, 255, 182, 60, 253, 41, 184, 208, 16, 215, 111, 181, 47, 64, 67, 144, 183, 182,
-            191, 233, 211, 225, 217, 247, 13, 18, 235, 176, 230, 25, 21, 128, 245, 160, 188, 5, 44,
-            199, 63, 129, 18, 187, 84, 101, 202, 147, 10, 147, 49, 3, 218, 70, 121, 111, 233, 66,
-            32, 233, 248, 133, 165, 13, 28, 219, 128, 14, 131, 238, 132, 41, 5, 14, 36, 248, 102,
-            22, 239, 179, 79, 208, 144, 227, 144, 199, 3, 94, 206, 131, 69, 146, 217, 208, 123,
-            251, 157, 205, 225, 128, 201, 255, 73, 224, 110, 158, 8, 94, 36, 55, 212, 237, 246, 37,
-            55, 70, 188, 222, 152, 76, 249, 234, 109, 66, 106, 45, 72, 221, 238, 43, 110, 75, 128,
-            38, 150, 145, 120, 219, 99, 149, 1, 78, 49, 180, 66, 12, 123, 211, 181, 120, 66, 152,
-            13, 242, 211, 225, 0, 43, 152, 83, 59, 130, 55, 158, 228, 128, 100, 229, 120, 116, 34,
-            155, 226, 67, 141, 201, 28, 67, 9, 149, 245, 162, 250, 33, 233, 147, 156, 18, 139, 46,
-            109, 175, 110, 62, 250, 255, 210, 189, 128, 222, 185, 16, 105, 45, 31, 111, 154, 244,
-            95, 235, 90, 16, 147, 39, 115, 135, 248, 251, 150, 146, 57, 252, 46, 166, 223, 188, 49,
-            240, 234, 13, 158, 128, 33, 232, 62, 9, 240, 6, 208, 13, 100, 149, 241, 92, 217, 45,
-            171, 37, 171, 218, 162, 174, 16, 47, 220, 90, 75, 48, 68, 124, 218, 138, 55, 107, 128,
-            103, 57, 14, 58, 242, 151, 78, 13, 140, 17, 77, 20, 3, 138, 153, 157, 25, 43, 143, 232,
-            153, 195, 243, 132, 121, 2, 137, 118, 173, 42, 18, 130, 128, 220, 215, 139, 137, 220,
-            192, 81, 98, 186, 83, 66, 246, 89, 5, 28, 36, 157, 107, 51, 113, 149, 84, 177, 143, 36,
-            186, 87, 61, 170, 103, 132, 151, 128, 46, 137, 147, 218, 166, 199, 59, 11, 162, 119,
-            139, 113, 85, 21, 33, 126, 149, 93, 122, 75, 232, 194, 66, 202, 86, 21, 224, 108, 135,
-            52, 133, 99, 128, 136, 117, 227, 97, 91, 152, 206, 125, 157, 195, 110, 114, 146, 128,
-            133, 192, 53, 85, 62, 141, 210, 118, 103, 252, 54, 41, 18, 47, 156, 42, 197, 6, 128,
-            177, 173, 252, 1, 57, 120, 8, 117, 140, 223, 84, 189, 163, 99, 215, 140, 235, 62, 53,
-            235, 56, 186, 37, 242, 41, 101, 60, 179, 144, 127, 14, 167, 128, 108, 64, 169, 3, 47,
-            216, 115, 25, 60, 249, 194, 24, 40, 42, 40, 173, 243, 102, 103, 209, 105, 30, 255, 123,
-            74, 82, 84, 37, 14, 19, 27, 124, 128, 14, 185, 46, 220, 127, 28, 164, 140, 32, 139,
-            241, 98, 71, 73, 138, 142, 127, 110, 90, 3, 172, 238, 101, 44, 220, 132, 205, 186, 94,
-            173, 21, 95, 77, 8, 128, 255, 255, 128, 168, 223, 25, 11, 206, 236, 171, 241, 168, 7,
-            44, 229, 142, 32, 218, 112, 90, 156, 111, 64, 130, 88, 124, 95, 39, 103, 100, 229, 18,
-            248, 121, 81, 128, 173, 73, 101, 206, 33, 240, 110, 100, 50, 233, 29, 115, 6, 148, 121,
-            110, 108, 122, 117, 183, 147, 109, 246, 48, 112, 83, 111, 156, 15, 4, 60, 75, 128, 22,
-            191, 84, 38, 63, 203, 40, 96, 183, 176, 170, 253, 141, 66, 144, 99, 155, 239, 192, 137,
-            33, 5, 0, 155, 134, 161, 228, 213, 51, 241, 199, 5, 128, 255, 29, 100, 191, 213, 214,
-            208, 199, 139, 190, 129, 8, 130, 20, 155, 156, 192, 65, 253, 63, 170, 224, 44, 156, 85,
-            163, 220, 218, 84, 40, 90, 75, 128, 131, 103, 65, 192, 138, 102, 118, 137, 191, 238,
-            116, 89, 53, 132, 154, 108, 119, 7, 57, 224, 123, 106, 166, 147, 108, 163, 73, 159,
-            224, 89, 93, 100, 128, 142, 245, 75, 52, 182, 2, 50, 50, 55, 244, 125, 107, 123, 69,
-            137, 65, 158, 59, 98, 196, 253, 79, 62, 145, 255, 200, 27, 4, 38, 231, 230, 66, 128,
-            32, 76, 194, 99, 158, 73, 99, 190, 128, 213, 50, 172, 63, 224, 128, 120, 196, 33, 85,
-            82, 174, 161, 222, 124, 74, 169, 99, 56, 246, 239, 102, 251, 128, 225, 75, 1, 43, 138,
-            191, 52, 196, 247, 204, 144, 239, 61, 142, 125, 36, 7, 82, 37, 185, 161, 215, 97, 79,
-            249, 120, 241, 151, 162, 113, 193, 240, 128, 78, 100, 19, 32, 69, 26, 151, 245, 12, 12,
-            164, 216, 124, 219, 29, 59, 171, 50, 240, 123, 5, 189, 80, 155, 53, 169, 24, 60, 221,
-            190, 239, 138, 128, 37, 202, 46, 52, 121, 244, 43, 35, 82, 59, 162, 209, 187, 193, 150,
-            50, 18, 61, 162, 156, 172, 159, 5, 34, 120, 183, 237, 222, 15, 51, 98, 15, 128, 214,
-            215, 183, 54, 157, 86, 208, 53, 194, 194, 180, 166, 204, 122, 184, 39, 161, 169, 213,
-            53, 217, 45, 177, 222, 144, 217, 226, 250, 145, 159, 46, 66, 128, 84, 107, 60, 172, 40,
-            120, 176, 253, 9, 181, 23, 51, 211, 193, 124, 85, 37, 210, 8, 243, 129, 188, 254, 242,
-            197, 206, 56, 31, 126, 221, 246, 122, 128, 74, 176, 47, 192, 71, 227, 110, 159, 95,
-            177, 45, 87, 106, 251, 81, 229, 6, 121, 193, 191, 214, 62, 142, 135, 219, 38, 176, 129,
-            80, 46, 186, 86, 128, 57, 42, 237, 125, 44, 113, 246, 243, 236, 154, 250, 193, 180,
-            225, 81, 8, 228, 9, 128, 119, 167, 65, 255, 134, 195, 139, 195, 200, 211, 57, 148, 69,
-            128, 122, 25, 208, 150, 207, 187, 6, 193, 249, 234, 175, 181, 47, 148, 109, 209, 128,
-            129, 18, 214, 156, 2, 17, 122, 23, 114, 165, 66, 16, 87, 128, 78, 128, 133, 118, 210,
-            161, 195, 242, 152, 179, 136, 192, 161, 168, 232, 145, 119, 44, 206, 69, 150, 202, 131,
-            232, 153, 33, 94, 211, 167, 64, 0, 147, 18, 145, 77, 8, 128, 255, 255, 128, 235, 170,
-            176, 106, 31, 135, 91, 27, 168, 205, 4, 210, 244, 245, 153, 39, 146, 173, 71, 237, 34,
-            85, 25, 35, 65, 251, 16, 181, 194, 133, 69, 177, 128, 30, 105, 245, 56, 77, 120, 75,
-            118, 9, 103, 147, 15, 128, 37, 232, 103, 192, 56, 91, 149, 243, 47, 3, 44, 248, 217,
-            163, 21, 81, 78, 7, 44, 128, 219, 63, 87, 8, 229, 222, 132, 250, 1, 225, 51, 237, 254,
-            225, 29, 204, 131, 255, 36, 183, 178, 64, 127, 15, 70, 65, 169, 135, 234, 35, 190, 42,
-            128, 76, 160, 43, 46, 103, 108, 169, 140, 103, 27, 31, 189, 229, 148, 228, 166, 43, 33,
-            214, 75, 165, 53, 37, 39, 41, 130, 233, 94, 21, 229, 163, 118, 128, 120, 29, 226, 227,
-            235, 204, 91, 18, 175, 148, 50, 116, 126, 12, 234, 177, 74, 190, 46, 22, 132, 176, 114,
-            171, 146, 51, 253, 7, 0, 173, 40, 190, 128, 10, 193, 46, 129, 134, 245, 146, 190, 101,
-            121, 204, 152, 197, 80, 63, 220, 158, 200, 3, 88, 43, 44, 24, 165, 47, 181, 36, 193,
-            216, 241, 206, 153, 128, 90, 202, 167, 68, 255, 202, 210, 189, 211, 102, 71, 223, 102,
-            46, 128, 75, 170, 79, 177, 199, 67, 251, 183, 170, 185, 240, 181, 231, 43, 232, 225,
-            37, 128, 57, 84, 84, 151, 169, 159, 193, 167, 123, 121, 3, 115, 144, 171, 214, 20, 201,
-            43, 11, 242, 239, 31, 98, 31, 245, 174, 91, 190, 231, 85, 96, 239, 128, 35, 46, 229,
-            132, 40, 97, 158, 102, 223, 11, 72, 121, 149, 157, 232, 35, 79, 168, 102, 156, 86, 110,
-            1, 163, 100, 191, 166, 149, 195, 160, 170, 76, 128, 147, 191, 101, 119, 78, 36, 203,
-            198, 170, 164, 76, 173, 14, 57, 162, 118, 53, 78, 69, 161, 117, 97, 154, 32, 69, 17,
-            180, 12, 124, 129, 5, 14, 128, 238, 162, 232, 223, 55, 63, 249, 78, 244, 140, 123, 149,
-            7, 55, 164, 246, 80, 99, 65, 115, 207, 179, 161, 63, 52, 204, 24, 202, 215, 242, 56,
-            91, 128, 69, 153, 241, 208, 132, 246, 94, 133, 85, 90, 253, 147, 62, 108, 52, 231, 201,
-            200, 204, 100, 172, 217, 54, 40, 58, 143, 109, 54, 93, 201, 144, 103, 128, 238, 151,
-            217, 228, 185, 160, 7, 251, 223, 94, 194, 138, 47, 1, 36, 8, 197, 236, 30, 85, 222, 21,
-            46, 179, 128, 162, 136, 151, 90, 189, 253, 212, 128, 253, 255, 224, 120, 100, 7, 148,
-            55, 13, 206, 19, 157, 182, 204, 14, 230, 188, 48, 69, 14, 155, 189, 228, 115, 84, 223,
-            109, 202, 174, 60, 215, 10, 128, 71, 87, 138, 22, 28, 81, 197, 47, 104, 24, 131, 127,
-            84, 51, 252, 148, 54, 126, 65, 42, 48, 206, 113, 157, 82, 77, 29, 141, 183, 252, 55,
-            162, 128, 30, 161, 101, 93, 118, 108, 61, 92, 80, 96, 64, 174, 203, 66, 250, 140, 126,
-            84, 178, 13, 191, 111, 201, 6, 8, 253, 43, 80, 178, 215, 207, 63, 77, 8, 128, 255, 255,
-            128, 254, 30, 183, 151, 115, 142, 17, 117, 113, 99, 88, 46, 121, 61, 40, 246, 85, 123,
-            86, 225, 182, 110, 88, 7, 127, 236, 14, 247, 70, 244, 163, 95, 128, 25, 118, 253, 146,
-            237, 189, 40, 204, 170, 7, 92, 76, 35, 100, 223, 137, 144, 220, 46, 13, 101, 243, 110,
-            138, 17, 236, 143, 31, 34, 190, 216, 200, 128, 74, 137, 75, 14, 17, 249, 82, 101, 241,
-            44, 148, 157, 131, 227, 88, 236, 107, 178, 19, 144, 255, 18, 182, 150, 74, 201, 124,
-            242, 141, 123, 121, 194, 128, 250, 151, 110, 6, 119, 107, 220, 202, 108, 18, 127, 86,
-            201, 163, 98, 44, 240, 91, 227, 12, 214, 245, 104, 219, 186, 53, 19, 255, 8, 122, 188,
-            184, 128, 211, 146, 138, 20, 223, 29, 48, 26, 253, 202, 160, 72, 103, 101, 78, 222,
-            176, 49, 168, 126, 253, 186, 119, 249, 220, 62, 132, 135, 196, 129, 116, 158, 128, 30,
-            126, 214, 204, 31, 158, 45, 18, 145, 46, 133, 139, 72, 107, 230, 241, 68, 112, 169, 12,
-            13, 151, 23, 114, 0, 208, 118, 200, 40, 134, 94, 230, 128, 206, 213, 245, 105, 26, 106,
-            101, 23, 189, 76, 239, 199, 85, 40, 254, 229, 155, 194, 221, 190, 45, 96, 147, 175, 87,
-            138, 232, 181, 205, 150, 49, 80, 128, 141, 60, 110, 194, 237, 191, 197, 236, 116, 191,
-            135, 52, 79, 195, 187, 30, 254, 141, 40, 180, 204, 202, 137, 57, 245, 236, 209, 254,
-            232, 102, 172, 72, 128, 228, 113, 46, 103, 38, 244, 186, 42, 112, 88, 56, 48, 50, 241,
-            222, 58, 103, 225, 69, 239, 218, 99, 131, 101, 241, 105, 46, 126, 2, 246, 164, 71, 128,
-            74, 99, 84, 35, 53, 202, 101, 249, 124, 253, 188, 88, 201, 89, 107, 158, 190, 68, 36,
-            233, 21, 90, 130, 64, 9, 18, 59, 117, 41, 63, 74, 7, 128, 154, 164, 8, 129, 49, 88,
-            194, 88, 72, 14, 246, 132, 59, 151, 117, 243, 62, 10, 41, 34, 136, 118, 211, 40, 254,
-            207, 186, 250, 43, 27, 87, 33, 128, 89, 102, 96, 102, 172, 67, 126, 155, 245, 12, 166,
-            66, 237, 38, 24, 131, 52, 181, 1, 198, 204, 160, 195, 248, 144, 117, 252, 103, 13, 126,
-            103, 10, 128, 63, 129, 94, 79, 79, 8, 140, 151, 2, 191, 171, 207, 108, 8, 93, 93, 155,
-            179, 221, 143, 76, 165, 157, 232, 18, 160, 132, 170, 25, 238, 72, 7, 128, 119, 254, 39,
-            23, 181, 253, 242, 111, 177, 210, 15, 3, 56, 7, 104, 89, 7, 32, 59, 114, 109, 158, 109,
-            127, 21, 120, 95, 152, 43, 208, 228, 144, 128, 87, 85, 51, 133, 211, 92, 206, 71, 197,
-            21, 108, 152, 171, 3, 90, 151, 239, 201, 239, 189, 201, 85, 93, 55, 251, 66, 69, 103,
-            157, 210, 91, 233, 128, 186, 242, 201, 220, 156, 216, 1, 119, 120, 25, 180, 119, 197,
-            240, 220, 204, 81, 84, 145, 46, 3, 146, 76, 153, 132, 215, 36, 246, 57, 65, 242, 201,
-            157, 1, 129, 3, 16, 129, 128, 162, 244, 187, 39, 198, 61, 200, 252, 246, 226, 225, 112,
-            127, 160, 243, 128, 155, 50, 48, 103, 62, 100, 200, 111, 221, 171, 148, 146, 131, 130,
-            0, 137, 128, 236, 128, 227, 54, 60, 222, 65, 39, 12, 163, 123, 164, 43, 134, 168, 173,
-            33, 242, 196, 253, 150, 251, 211, 64, 201, 205, 39, 12, 146, 78, 29, 221, 128, 5, 167,
-            72, 17, 43, 203, 70, 247, 245, 221, 47, 109, 19, 43, 13, 204, 55, 233, 152, 93, 209,
-            121, 230, 108, 164, 98, 26, 255, 206, 210, 142, 73, 141, 7, 157, 14, 73, 7, 247, 22,
-            172, 137, 182, 52, 125, 21, 236, 236, 237, 202, 255, 223, 88, 95, 11, 106, 69, 50, 30,
-            250, 233, 42, 234, 21, 224, 116, 14, 199, 175, 231, 16, 219, 3, 0, 0, 88, 95, 3, 142,
-            113, 97, 36, 145, 25, 45, 104, 222, 171, 126, 111, 86, 63, 225, 16, 41, 1, 0, 0, 128,
-            110, 200, 13, 122, 252, 137, 163, 204, 235, 225, 15, 205, 240, 76, 161, 101, 24, 255,
-            136, 214, 178, 148, 98, 236, 134, 27, 0, 211, 18, 57, 219, 53, 128, 24, 179, 250, 7,
-            252, 211, 24, 112, 36, 254, 110, 123, 88, 175, 104, 227, 26, 244, 141, 143, 123, 92,
-            93, 148, 246, 56, 254, 87, 54, 202, 227, 116, 128, 212, 186, 95, 7, 117, 214, 42, 252,
-            137, 90, 39, 204, 71, 219, 16, 172, 46, 91, 134, 227, 70, 144, 166, 190, 177, 236, 42,
-            57, 145, 233, 105, 61, 128, 14, 214, 94, 167, 86, 123, 217, 88, 156, 68, 3, 216, 251,
-            117, 130, 117, 173, 120, 238, 191, 150, 165, 214, 18, 109, 103, 131, 162, 149, 60, 190,
-            15, 128, 145, 79, 88, 158, 111, 117, 135, 211, 50, 230, 34, 131, 178, 91, 19, 230, 181,
-            115, 168, 181, 12, 173, 102, 237, 72, 30, 125, 22, 166, 190, 163, 226, 128, 150, 97,
-            48, 76, 74, 235, 167, 247, 69, 103, 4, 154, 130, 7, 3, 22, 72, 160, 82, 232, 18, 244,
-            176, 58, 42, 23, 7, 231, 12, 78, 180, 155, 128, 191, 24, 153, 195, 29, 109, 95, 250,
-            129, 242, 53, 146, 183, 178, 157, 20, 56, 109, 0, 24, 132, 32, 212, 75, 125, 0, 101,
-            184, 163, 124, 84, 180, 128, 223, 120, 85, 135, 67, 76, 242, 99, 220, 31, 86, 41, 97,
-            198, 169, 114, 181, 40, 200, 30, 33, 169, 7, 31, 17, 77, 67, 188, 58, 79, 49, 70, 128,
-            65, 72, 245, 149, 209, 123, 119, 9, 68, 98, 196, 190, 181, 249, 88, 108, 108, 19, 163,
-            131, 105, 250, 44, 15, 229, 23, 79, 70, 55, 48, 24, 207, 88, 95, 4, 154, 39, 56, 238,
-            179, 8, 150, 170, 203, 139, 63, 180, 100, 113, 189, 16, 4, 0, 0, 0, 128, 150, 77, 204,
-            23, 91, 96, 20, 177, 216, 5, 108, 247, 200, 200, 199, 101, 114, 110, 31, 183, 134, 248,
-            253, 142, 109, 152, 116, 233, 25, 185, 123, 153, 128, 5, 145, 11, 232, 224, 155, 242,
-            140, 49, 94, 170, 70, 75, 193, 225, 78, 35, 140, 92, 155, 200, 205, 85, 110, 178, 60,
-            35, 99, 30, 6, 200, 106, 128, 180, 147, 63, 242, 56, 9, 87, 70, 86, 7, 181, 212, 101,
-            128, 67, 39, 192, 87, 234, 252, 168, 145, 67, 182, 36, 28, 227, 4, 153, 70, 59, 250,
-            137, 8, 158, 220, 222, 147, 76, 101, 130, 39, 238, 29, 250, 252, 214, 225, 105, 3, 255,
-            255, 128, 212, 192, 107, 17, 26, 200, 122, 195, 120, 105, 34, 67, 13, 110, 222, 20,
-            168, 48, 115, 160, 212, 187, 246, 92, 253, 220, 93, 68, 165, 244, 128, 149, 128, 251,
-            50, 66, 146, 95, 208, 2, 95, 45, 114, 134, 72, 91, 66, 74, 130, 178, 173, 14, 21, 149,
-            16, 128, 230, 116, 61, 76, 168, 24, 113, 226, 218, 128, 130, 145, 33, 106, 121, 80, 96,
-            66, 164, 70, 191, 105, 83, 79, 54, 235, 249, 71, 225, 58, 5, 127, 130, 251, 200, 50,
-            19, 156, 250, 206, 78, 78, 128, 44, 192, 40, 14, 185, 110, 219, 179, 241, 90, 91, 52,
-            41, 102, 199, 113, 82, 132, 131, 96, 38, 20, 10, 30, 59, 82, 176, 198, 176, 87, 21, 87,
-            128, 195, 5, 208, 225, 21, 57, 155, 53, 89, 33, 130, 228, 169, 0, 76, 48, 24, 82, 5,
-            42, 137, 197, 162, 63, 21, 197, 141, 251, 212, 115, 106, 82, 128, 121, 48, 30, 3, 225,
-            115, 179, 164, 175, 222, 54, 84, 122, 135, 160, 236, 243, 69, 251, 160, 241, 3, 245,
-            102, 217, 137, 189, 2, 173, 64, 53, 101, 128, 153, 158, 145, 143, 88, 49, 185, 124, 0,
-            150, 98, 54, 245, 85, 94, 94, 215, 123, 89, 24, 10, 71, 85, 52, 250, 210, 20, 228, 16,
-            149, 211, 151, 128, 35, 126, 133, 35, 101, 156, 135, 154, 57, 170, 188, 113, 130, 140,
-            189, 101, 229, 126, 131, 120, 56, 160, 48, 107, 104, 186, 122, 224, 85, 129, 147, 46,
-            128, 105, 89, 155, 175, 165, 144, 141, 206, 81, 133, 45, 110, 19, 216, 203, 165, 255,
-            113, 78, 67, 243, 110, 156, 71, 109, 18, 18, 115, 92, 216, 173, 77, 128, 123, 141, 236,
-            214, 0, 104, 118, 31, 247, 52, 108, 8, 252, 87, 196, 216, 167, 133, 138, 61, 36, 211,
-            220, 61, 76, 245, 34, 188, 150, 163, 67, 176, 128, 145, 171, 89, 220, 120, 189, 81,
-            183, 187, 110, 153, 163, 54, 248, 154, 229, 38, 99, 248, 20, 255, 92, 198, 245, 44,
-            156, 75, 241, 238, 7, 6, 224, 128, 239, 194, 154, 97, 78, 80, 169, 43, 93, 31, 212,
-            140, 222, 208, 103, 168, 30, 51, 240, 20, 63, 224, 162, 202, 9, 214, 212, 169, 103,
-            167, 181, 110, 128, 97, 138, 136, 94, 17, 95, 133, 176, 236, 165, 222, 28, 208, 47,
-            223, 231, 215, 15, 27, 155, 26, 211, 56, 191, 215, 96, 178, 205, 221, 97, 26, 31, 128,
-            246, 79, 70, 71, 167, 94, 137, 199, 210, 5, 154, 192, 53, 113, 183, 23, 32, 135, 208,
-            76, 45, 4, 58, 15, 13, 182, 41, 24, 188, 104, 66, 111, 128, 68, 251, 235, 134, 154,
-            122, 195, 108, 116, 251, 35, 161, 59, 76, 88, 164, 75, 140, 124, 44, 177, 90, 195, 238,
-            131, 61, 234, 70, 60, 255, 30, 252, 128, 12, 221, 198, 252, 251, 59, 183, 181, 5, 106,
-            13, 14, 159, 159, 196, 193, 91, 10, 108, 175, 177, 207, 135, 14, 162, 164, 53, 52, 72,
-            212, 240, 144,
-        ],
-        &[
-            5, 5, 184, 127, 13, 12, 82, 197, 19, 18, 40, 44, 42, 85, 181, 149, 176, 157, 35, 203,
-            158, 160, 81, 231, 101, 95, 144, 33, 81, 35, 182, 207, 78, 220, 107, 191, 175, 195,
-            169, 158, 54, 103, 8, 20, 2, 132, 215, 23, 0, 184, 127, 13, 164, 57, 144, 160, 123,
-            184, 18, 128, 164, 121, 238, 59, 236, 167, 175, 22, 54, 172, 161, 117, 130, 243, 8, 41,
-            120, 46, 44, 155, 27, 156, 114, 170, 248, 6, 5, 99, 171, 55, 20, 2, 14, 39, 7, 0, 184,
-            127, 13, 250, 199, 109, 228, 237, 133, 44, 42, 85, 181, 209, 9, 4, 206, 141, 175, 144,
-            149, 165, 115, 37, 59, 214, 111, 236, 126, 150, 85, 120, 54, 246, 100, 164, 158, 91,
-            137, 85, 58, 20, 2, 132, 215, 23, 0, 188, 127, 14, 0, 226, 114, 9, 87, 233, 227, 46,
-            119, 137, 109, 164, 122, 40, 71, 218, 37, 5, 13, 198, 102, 4, 22, 60, 210, 124, 89,
-            137, 83, 199, 57, 179, 233, 186, 41, 208, 253, 110, 43, 20, 2, 40, 107, 238, 0, 188,
-            127, 14, 1, 106, 172, 168, 198, 155, 248, 44, 42, 85, 181, 109, 163, 126, 113, 35, 108,
-            80, 171, 88, 107, 165, 186, 85, 227, 71, 156, 55, 95, 244, 185, 36, 96, 130, 181, 210,
-            27, 75, 123, 20, 2, 132, 215, 23, 0, 188, 127, 14, 1, 217, 80, 188, 217, 138, 239, 245,
-            192, 220, 113, 191, 248, 203, 220, 230, 195, 42, 229, 38, 5, 73, 236, 206, 144, 175,
-            25, 81, 46, 125, 160, 49, 242, 221, 2, 253, 146, 6, 137, 20, 2, 194, 235, 11, 0, 188,
-            127, 14, 1, 240, 13, 104, 11, 142, 159, 120, 36, 103, 54, 19, 210, 143, 208, 224, 3,
-            129, 231, 162, 87, 166, 112, 201, 254, 60, 175, 86, 111, 94, 163, 180, 234, 224, 25,
-            12, 63, 208, 106, 20, 2, 14, 39, 7, 0, 188, 127, 14, 2, 36, 247, 51, 74, 91, 145, 166,
-            234, 211, 103, 169, 227, 48, 172, 157, 150, 11, 193, 99, 28, 246, 228, 37, 149, 107,
-            154, 150, 166, 136, 94, 15, 117, 5, 114, 49, 192, 181, 74, 20, 2, 40, 107, 238, 0, 188,
-            127, 14, 2, 65, 140, 137, 82, 117, 255, 62, 234, 46, 28, 23, 252, 135, 142, 32, 252,
-            95, 136, 200, 45, 107, 8, 253, 51, 36, 203, 210, 76, 23, 99, 81, 171, 162, 176, 109,
-            135, 79, 33, 20, 2, 40, 107, 238, 0, 188, 127, 14, 2, 105, 220, 78, 139, 138, 114, 195,
-            64, 95, 99, 222, 225, 38, 65, 171, 236, 108, 18, 178, 148, 83, 183, 6, 93, 160, 74,
-            149, 192, 153, 152, 103, 58, 202, 111, 12, 74, 22, 78, 20, 2, 14, 39, 7, 0, 188, 127,
-            14, 3, 135, 62, 166, 208, 66, 16, 228, 93, 218, 74, 58, 193, 134, 226, 172, 84, 216,
-            174, 217, 141, 185, 219, 223, 255, 84, 8, 123, 150, 65, 64, 87, 173, 38, 60, 160, 43,
-            251, 150, 20, 2, 40, 107, 238, 0, 188, 127, 14, 3, 158, 169, 110, 102, 181, 156, 44,
-            42, 85, 181, 183, 225, 58, 119, 46, 11, 105, 60, 59, 53, 29, 47, 181, 229, 180, 218,
-            24, 172, 55, 158, 189, 178, 241, 242, 231, 85, 151, 118, 20, 2, 132, 215, 23, 0, 188,
-            127, 14, 4, 84, 51, 83, 6, 123, 109, 124, 140, 58, 146, 216, 254, 185, 210, 127, 50,
-            243, 236, 103, 188, 198, 121, 47, 132, 150, 247, 237, 134, 209, 178, 73, 197, 66, 5,
-            163, 158, 227, 12, 20, 2, 208, 18, 19, 0, 188, 127, 14, 4, 98, 0, 240, 182, 85, 210,
-            156, 107, 174, 87, 136, 18, 8, 11, 47, 158, 205, 239, 145, 63, 79, 104, 171, 195, 220,
-            238, 105, 127, 80, 86, 37, 232, 67, 12, 140, 227, 18, 44, 20, 2, 40, 107, 238, 0, 188,
-            127, 14, 5, 109, 153, 111, 99, 234, 101, 64, 213, 230, 2, 191, 241, 241, 103, 238, 81,
-            60, 102, 111, 40, 181, 65, 63, 221, 175, 221, 82, 103, 207, 173, 163, 139, 142, 215,
-            173, 220, 143, 44, 20, 2, 14, 39, 7, 0, 188, 127, 14, 5, 115, 123, 55, 185, 80, 198,
-            14, 81, 129, 7, 180, 37, 98, 142, 73, 196, 254, 227, 127, 188, 8, 2, 2, 53, 208, 102,
-            79, 197, 5, 44, 79, 167, 65, 94, 87, 151, 199, 44, 20, 2, 40, 107, 238, 0, 188, 127,
-            14, 5, 172, 19, 217, 254, 183, 184, 240, 100, 168, 210, 144, 83, 196, 33, 155, 41, 188,
-            220, 211, 67, 34, 5, 183, 109, 20, 175, 73, 150, 187, 186, 122, 144, 36, 182, 105, 111,
-            15, 32, 20, 2, 90, 98, 2, 0, 188, 127, 14, 5, 174, 127, 207, 61, 252, 107, 56, 201, 91,
-            49, 254, 129, 186, 34, 155, 46, 34, 22, 240, 191, 104, 18, 202, 69, 220, 58, 213, 196,
-            158, 21, 180, 142, 14, 239, 85, 85, 191, 64, 20, 2, 40, 107, 238, 0, 188, 127, 14, 5,
-            206, 35, 50, 73, 66, 71, 180, 222, 14, 5, 83, 248, 84, 199, 39, 70, 4, 91, 144, 200,
-            229, 198, 125, 116, 245, 216, 165, 45, 65, 52, 178, 89, 255, 86, 46, 75, 20, 9, 20, 2,
-            180, 196, 4, 0, 188, 127, 14, 5, 219, 181, 207, 37, 137, 100, 44, 42, 85, 181, 158,
-            209, 149, 78, 128, 191, 19, 73, 238, 88, 130, 212, 41, 210, 97, 207, 153, 98, 188, 93,
-            136, 161, 252, 23, 110, 96, 201, 24, 20, 2, 132, 215, 23, 0, 188, 127, 14, 6, 106, 118,
-            208, 10, 142, 144, 71, 80, 13, 97, 106, 120, 213, 155, 216, 135, 188, 222, 65, 214, 31,
-            78, 200, 128, 208, 73, 111, 180, 217, 72, 47, 111, 99, 127, 85, 204, 27, 41, 20, 2,
-            180, 196, 4, 0, 188, 127, 14, 6, 189, 71, 63, 246, 249, 80, 44, 42, 85, 181, 224, 36,
-            31, 233, 42, 117, 194, 60, 73, 204, 21, 179, 85, 164, 245, 44, 147, 71, 25, 177, 183,
-            56, 44, 92, 43, 133, 154, 70, 20, 2, 132, 215, 23, 0, 188, 127, 14, 7, 64, 154, 3, 94,
-            127, 182, 138, 100, 220, 166, 124, 100, 217, 54, 25, 1, 161, 65, 91, 251, 52, 105, 176,
-            0, 208, 191, 127, 29, 67, 152, 36, 206, 199, 31, 135, 2, 33, 89, 20, 2, 14, 39, 7, 0,
-            188, 127, 14, 7, 118, 206, 24, 157, 42, 187, 250, 181, 115, 251, 227, 41, 101, 99, 32,
-            85, 99, 235, 57, 150, 89, 51, 211, 62, 165, 165, 145, 196, 90, 240, 127, 14, 238, 34,
-            114, 239, 103, 35, 20, 2, 104, 137, 9, 0, 188, 127, 14, 8, 67, 3, 75, 122, 44, 197,
-            170, 184, 89, 28, 40, 183, 5, 179, 184, 182, 127, 186, 52, 55, 56, 71, 153, 75, 102,
-            192, 96, 220, 50, 178, 37, 19, 149, 68, 204, 170, 67, 53, 20, 2, 40, 107, 238, 0, 188,
-            127, 14, 8, 175, 154, 123, 198, 237, 216, 212, 201, 181, 52, 27, 96, 64, 230, 242, 24,
-            197, 71, 110, 91, 184, 122, 38, 253, 128, 224, 58, 10, 198, 91, 146, 212, 211, 235,
-            145, 124, 143, 34, 20, 2, 40, 107, 238, 0, 188, 127, 14, 8, 234, 170, 143, 72, 16, 109,
-            27, 170, 69, 57, 102, 192, 67, 202, 54, 124, 207, 161, 159, 69, 2, 68, 68, 123, 157,
-            50, 244, 183, 175, 45, 151, 73, 229, 90, 87, 172, 9, 204, 20, 2, 90, 98, 2, 0, 188,
-            127, 14, 9, 30, 232, 90, 68, 53, 25, 15, 232, 236, 187, 167, 161, 226, 22, 189, 28, 59,
-            183, 231, 73, 169, 143, 202, 231, 123, 65, 133, 197, 82, 44, 186, 216, 232, 38, 208,
-            217, 35, 113, 20, 2, 14, 39, 7, 0, 188, 127, 14, 9, 227, 32, 76, 125, 17, 247, 42, 138,
-            211, 59, 99, 189, 14, 193, 163, 25, 120, 76, 28, 58, 133, 17, 204, 161, 147, 68, 117,
-            222, 188, 102, 241, 37, 19, 212, 104, 146, 248, 92, 20, 2, 208, 18, 19, 0, 176, 127,
-            14, 10, 11, 159, 119, 221, 248, 67, 76, 16, 142, 228, 167, 252, 90, 211, 142, 90, 249,
-            212, 8, 72, 109, 102, 5, 90, 154, 120, 147, 198, 68, 120, 157, 8, 16, 64, 207, 236,
-            255, 97, 8, 4, 0, 188, 127, 14, 10, 54, 53, 90, 154, 223, 64, 17, 79, 113, 14, 144,
-            148, 209, 116, 238, 83, 122, 134, 167, 235, 154, 178, 0, 78, 100, 5, 98, 106, 132, 61,
-            79, 254, 167, 38, 148, 185, 226, 62, 20, 2, 194, 235, 11, 0, 188, 127, 14, 11, 20, 237,
-            200, 62, 252, 150, 98, 179, 36, 35, 1, 109, 40, 181, 28, 80, 205, 102, 217, 194, 39,
-            133, 244, 235, 173, 158, 97, 87, 131, 225, 90, 250, 123, 102, 5, 164, 218, 81, 20, 2,
-            40, 107, 238, 0, 188, 127, 14, 11, 40, 232, 239, 177, 68, 150, 152, 181, 39, 100, 140,
-            131, 91, 79, 254, 79, 23, 109, 193, 160, 162, 133, 127, 101, 41, 188, 112, 0, 169, 62,
-            188, 237, 73, 39, 112, 52, 240, 213, 20, 2, 104, 137, 9, 1, 188, 127, 14, 11, 194, 150,
-            3, 223, 175, 157, 44, 42, 85, 181, 179, 93, 197, 118, 138, 251, 167, 29, 11, 175, 35,
-            119, 238, 111, 142, 35, 92, 39, 208, 78, 217, 13, 117, 224, 255, 248, 6, 37, 20, 2,
-            132, 215, 23, 0, 188, 127, 14, 12, 247, 253, 244, 51, 59, 130, 86, 54, 59, 160, 99, 28,
-            168, 203, 19, 113, 231, 6, 168, 195, 175, 39, 1, 30, 152, 52, 128, 231, 208, 145, 36,
-            215, 179, 198, 115, 45, 99, 59, 20, 2, 40, 107, 238, 0, 188, 127, 14, 13, 78, 213, 137,
-            143, 43, 140, 128, 175, 77, 57, 198, 37, 195, 43, 43, 110, 160, 185, 76, 52, 26, 200,
-            38, 196, 159, 162, 132, 81, 118, 142, 113, 175, 180, 107, 199, 175, 172, 4, 20, 2, 14,
-            39, 7, 0, 188, 127, 14, 13, 119, 42, 96, 237, 84, 29, 108, 82, 96, 222, 214, 104, 102,
-            44, 2, 2, 16, 21, 94, 184, 122, 95, 12, 154, 190, 95, 44, 209, 5, 211, 226, 227, 89,
-            79, 33, 190, 252, 56, 20, 2, 180, 196, 4, 0, 188, 127, 14, 14, 24, 193, 108, 45, 104,
-            245, 44, 42, 85, 181, 69, 175, 191, 4, 187, 104, 124, 191, 229, 247, 164, 254, 230,
-            111, 15, 14, 144, 54, 7, 45, 195, 239, 136, 190, 111, 60, 108, 54, 20, 2, 132, 215, 23,
-            0, 188, 127, 14, 14, 109, 168, 103, 187, 244, 68, 128, 176, 177, 62, 53, 81, 64, 184,
-            100, 119, 225, 183, 251, 125, 58, 108, 81, 144, 93, 108, 222, 50, 43, 221, 100, 42, 40,
-            110, 108, 126, 183, 85, 20, 2, 40, 107, 238, 0, 188, 127, 14, 14, 124, 37, 245, 9, 173,
-            104, 148, 170, 77, 51, 65, 118, 35, 226, 8, 83, 72, 147, 121, 60, 144, 240, 246, 65,
-            73, 184, 121, 247, 26, 174, 244, 135, 15, 37, 38, 1, 102, 35, 20, 2, 14, 39, 7, 0, 188,
-            127, 14, 14, 252, 217, 166, 153, 177, 217, 42, 3, 126, 164, 228, 44, 231, 177, 73, 252,
-            150, 176, 136, 41, 205, 26, 24, 77, 33, 153, 103, 207, 63, 87, 34, 185, 50, 112, 65,
-            12, 29, 92, 20, 2, 40, 107, 238, 0, 188, 127, 14, 15, 11, 124, 152, 191, 218, 213, 228,
-            158, 140, 155, 224, 203, 231, 209, 209, 182, 237, 234, 194, 54, 159, 32, 243, 187, 21,
-            143, 116, 164, 171, 168, 192, 139, 234, 67, 43, 18, 59, 108, 20, 2, 40, 107, 238, 0,
-            176, 127, 14, 15, 81, 96, 161, 240, 73, 72, 178, 232, 152, 11, 175, 243, 169, 107, 172,
-            193, 94, 63, 49, 75, 223, 166, 65, 18, 166, 122, 252, 115, 107, 238, 227, 127, 93, 46,
-            27, 144, 116, 98, 8, 4, 0, 176, 127, 15, 6, 51, 33, 53, 125, 9, 35, 38, 225, 240, 113,
-            127, 153, 191, 165, 235, 149, 152, 22, 221, 27, 216, 183, 155, 104, 108, 163, 81, 254,
-            22, 193, 223, 201, 41, 213, 94, 73, 242, 71, 8, 4, 0, 188, 127, 15, 18, 185, 23, 246,
-            115, 213, 198, 246, 99, 179, 60, 166, 96, 81, 160, 52, 104, 68, 147, 174, 198, 109,
-            241, 70, 109, 148, 0, 74, 116, 89, 186, 93, 232, 233, 231, 218, 166, 195, 1, 20, 2,
-            132, 215, 23, 0, 188, 127, 15, 27, 234, 45, 132, 184, 185, 184, 68, 161, 51, 104, 84,
-            228, 76, 219, 250, 146, 154, 209, 46, 145, 62, 74, 24, 112, 197, 144, 166, 220, 94, 57,
-            131, 166, 253, 65, 107, 146, 127, 83, 20, 2, 208, 18, 19, 0, 188, 127, 15, 31, 18, 11,
-            178, 252, 95, 227, 44, 42, 85, 181, 198, 1, 57, 249, 87, 46, 193, 248, 143, 84, 23, 68,
-            173, 152, 202, 156, 208, 155, 86, 65, 232, 64, 136, 171, 10, 179, 146, 12, 20, 2, 132,
-            215, 23, 0, 188, 127, 15, 32, 170, 230, 198, 148, 60, 140, 118, 55, 151, 229, 4, 231,
-            164, 132, 55, 46, 220, 214, 81, 154, 95, 91, 227, 83, 75, 220, 30, 0, 156, 106, 106,
-            232, 202, 99, 174, 219, 13, 51, 20, 2, 14, 39, 7, 0, 188, 127, 15, 33, 25, 146, 113,
-            102, 99, 230, 130, 99, 83, 105, 210, 14, 54, 204, 206, 130, 201, 130, 131, 65, 11, 250,
-            74, 65, 83, 227, 27, 237, 181, 43, 51, 203, 206, 85, 67, 203, 162, 27, 20, 2, 40, 107,
-            238, 0, 188, 127, 15, 69, 183, 159, 241, 123, 100, 171, 146, 152, 133, 198, 96, 35,
-            244, 146, 223, 72, 156, 236, 193, 49, 109, 11, 162, 210, 225, 175, 119, 44, 85, 193,
-            216, 40, 92, 64, 148, 53, 185, 50, 20, 2, 40, 107, 238, 0, 188, 127, 15, 71, 94, 244,
-            4, 238, 125, 231, 250, 181, 116, 34, 14, 148, 197, 226, 164, 248, 252, 252, 159, 14,
-            113, 146, 17, 107, 36, 235, 93, 242, 99, 35, 150, 217, 77, 212, 52, 1, 162, 82, 20, 2,
-            104, 137, 9, 0, 188, 127, 15, 79, 74, 254, 206, 12, 133, 162, 145, 225, 51, 26, 178,
-            175, 75, 81, 12, 162, 159, 94, 93, 204, 142, 113, 209, 252, 108, 199, 10, 178, 78, 44,
-            248, 81, 61, 84, 15, 163, 136, 238, 20, 2, 180, 196, 4, 0, 176, 127, 15, 90, 190, 89,
-            101, 163, 20, 220, 4, 93, 6, 227, 125, 124, 32, 234, 66, 124, 50, 161, 96, 35, 243, 85,
-            77, 203, 64, 109, 228, 121, 21, 32, 230, 155, 226, 2, 130, 36, 243, 103, 8, 4, 0, 188,
-            127, 15, 98, 104, 251, 65, 228, 184, 246, 192, 141, 93, 231, 165, 217, 123, 234, 44,
-            125, 223, 81, 109, 6, 53, 189, 220, 67, 243, 38, 174, 47, 128, 226, 89, 91, 73, 212,
-            160, 140, 70, 25, 20, 2, 194, 235, 11, 0, 188, 127, 15, 149, 220, 64, 108, 185, 18,
-            142, 164, 211, 10, 54, 117, 112, 190, 95, 194, 237, 119, 134, 37, 75, 153, 11, 232,
-            233, 228, 176, 222, 12, 207, 166, 186, 97, 14, 68, 92, 191, 168, 38, 20, 2, 16, 94, 95,
-            0, 188, 127, 15, 167, 254, 233, 125, 223, 224, 116, 32, 118, 219, 59, 89, 242, 93, 190,
-            20, 31, 47, 70, 254, 2, 8, 151, 141, 228, 249, 163, 146, 147, 118, 37, 59, 21, 150,
-            139, 85, 2, 94, 72, 20, 2, 40, 107, 238, 0, 188, 127, 15, 172, 227, 14, 111, 59, 10,
-            131, 10, 127, 41, 33, 29, 80, 70, 21, 136, 236, 60, 104, 87, 201, 202, 37, 71, 76, 101,
-            12, 125, 32, 72, 239, 34, 131, 162, 36, 92, 234, 168, 49, 20, 2, 14, 39, 7, 0, 188,
-            127, 15, 174, 175, 189, 81, 36, 137, 124, 54, 27, 23, 181, 30, 35, 135, 105, 207, 122,
-            212, 253, 137, 239, 27, 178, 44, 60, 145, 108, 108, 118, 10, 197, 126, 166, 194, 215,
-            8, 134, 9, 111, 20, 2, 40, 107, 238, 0, 188, 127, 15, 203, 28, 247, 177, 0, 219, 88,
-            60, 52, 60, 98, 169, 56, 226, 85, 6, 13, 70, 67, 82, 51, 171, 22, 187, 17, 22, 138,
-            223, 169, 156, 119, 15, 202, 69, 154, 240, 139, 2, 65, 20, 2, 90, 98, 2, 0, 188, 127,
-            15, 203, 133, 182, 234, 233, 215, 121, 52, 232, 248, 14, 118, 72, 166, 140, 238, 243,
-            209, 111, 136, 17, 38, 10, 207, 95, 252, 20, 47, 195, 101, 182, 35, 229, 123, 110, 69,
-            115, 33, 57, 20, 2, 14, 39, 7, 0, 188, 127, 15, 206, 110, 122, 59, 26, 241, 144, 44,
-            42, 85, 181, 90, 5, 166, 160, 43, 123, 59, 166, 83, 159, 55, 68, 190, 215, 163, 235,
-            244, 168, 106, 117, 152, 110, 64, 69, 83, 191, 6, 53, 20, 2, 132, 215, 23, 0, 188, 127,
-            15, 207, 39, 228, 9, 30, 110, 228, 174, 9, 0, 0, 94, 200, 85, 76, 204, 1, 200, 155,
-            199, 61, 13, 69, 164, 243, 7, 250, 190, 182, 207, 232, 123, 20, 30, 36, 110, 51, 231,
-            86, 20, 2, 40, 107, 238, 0, 188, 127, 15, 207, 173, 117, 193, 151, 94, 119, 80, 53,
-            235, 101, 0, 51, 86, 172, 85, 128, 109, 106, 0, 209, 146, 8, 219, 234, 55, 38, 200, 90,
-            91, 169, 157, 199, 138, 156, 154, 234, 211, 28, 20, 2, 40, 107, 238, 0, 188, 127, 15,
-            216, 57, 227, 198, 238, 241, 37, 214, 42, 43, 128, 235, 205, 161, 178, 241, 77, 42,
-            144, 48, 136, 117, 156, 229, 100, 130, 64, 31, 180, 19, 12, 222, 50, 119, 93, 109, 33,
-            10, 106, 20, 2, 225, 245, 5, 0, 188, 127, 15, 221, 124, 65, 131, 190, 27, 36, 107, 151,
-            61, 241, 60, 240, 25, 10, 117, 241, 118, 51, 248, 163, 145, 2, 13, 248, 186, 201, 170,
-            158, 76, 80, 103, 14, 13, 173, 19, 91, 247, 38, 20, 2, 194, 235, 11, 0, 188, 127, 15,
-            249, 134, 107, 165, 203, 8, 80, 232, 182, 164, 238, 254, 180, 148, 43, 188, 162, 190,
-            230, 186, 238, 115, 40, 13, 73, 193, 183, 175, 248, 209, 170, 118, 22, 208, 107, 193,
-            115, 173, 127, 20, 2, 208, 18, 19, 0, 188, 127, 15, 251, 172, 48, 250, 91, 98, 101, 42,
-            2, 11, 118, 89, 68, 65, 35, 249, 114, 189, 135, 63, 60, 45, 181, 8, 49, 51, 219, 175,
-            6, 11, 191, 119, 230, 78, 138, 36, 153, 204, 22, 20, 2, 94, 208, 178, 0, 21, 1, 128, 0,
-            10, 128, 217, 47, 232, 165, 108, 162, 238, 202, 186, 189, 80, 92, 190, 127, 147, 78,
-            76, 233, 45, 118, 15, 57, 184, 186, 59, 94, 43, 162, 236, 216, 140, 42, 128, 139, 106,
-            155, 95, 37, 141, 68, 143, 98, 8, 25, 88, 114, 211, 53, 181, 231, 89, 21, 111, 240, 79,
-            87, 94, 138, 127, 187, 136, 90, 139, 157, 163, 21, 1, 128, 0, 36, 128, 230, 151, 234,
-            181, 215, 247, 242, 173, 248, 84, 181, 216, 195, 81, 96, 17, 41, 22, 25, 212, 24, 244,
-            246, 93, 135, 152, 39, 203, 218, 0, 30, 233, 128, 142, 93, 122, 115, 104, 161, 58, 247,
-            200, 227, 226, 157, 94, 198, 64, 138, 23, 209, 252, 10, 178, 53, 10, 107, 207, 41, 25,
-            102, 58, 234, 55, 194, 153, 1, 128, 0, 50, 128, 189, 173, 113, 253, 136, 44, 207, 1,
-            66, 238, 77, 115, 217, 174, 162, 44, 77, 67, 159, 151, 181, 105, 154, 13, 86, 56, 62,
-            28, 13, 201, 229, 179, 128, 92, 103, 231, 188, 140, 109, 41, 252, 230, 119, 253, 237,
-            170, 147, 151, 133, 58, 42, 191, 215, 212, 186, 17, 205, 23, 236, 129, 171, 0, 178,
-            101, 202, 128, 52, 4, 231, 0, 37, 130, 112, 248, 189, 242, 11, 246, 77, 248, 94, 58,
-            112, 245, 170, 91, 55, 45, 62, 151, 52, 56, 18, 216, 118, 201, 173, 168, 21, 1, 128, 0,
-            80, 128, 201, 177, 128, 215, 253, 131, 210, 212, 94, 28, 179, 255, 131, 72, 160, 214,
-            140, 35, 74, 117, 56, 218, 182, 34, 134, 57, 106, 109, 188, 97, 156, 0, 128, 154, 21,
-            24, 123, 142, 24, 216, 21, 162, 4, 144, 128, 185, 44, 115, 200, 166, 79, 64, 101, 100,
-            200, 25, 191, 26, 35, 206, 48, 17, 154, 197, 111, 153, 1, 128, 0, 100, 128, 247, 5,
-            108, 68, 149, 125, 8, 98, 184, 113, 51, 10, 133, 101, 252, 134, 189, 181, 31, 14, 230,
-            10, 147, 168, 102, 29, 26, 51, 206, 181, 201, 218, 128, 117, 85, 233, 198, 183, 181,
-            190, 72, 153, 238, 14, 45, 229, 156, 150, 41, 37, 205, 27, 109, 104, 107, 221, 162,
-            239, 56, 204, 133, 186, 194, 180, 252, 128, 79, 137, 13, 123, 228, 205, 186, 65, 21,
-            51, 130, 12, 137, 121, 118, 112, 226, 53, 191, 133, 118, 184, 142, 215, 109, 147, 61,
-            10, 63, 200, 165, 116, 29, 2, 128, 0, 102, 128, 82, 31, 150, 27, 157, 7, 195, 84, 56,
-            93, 10, 78, 192, 160, 151, 254, 190, 225, 106, 84, 112, 20, 171, 148, 189, 98, 83, 2,
-            248, 16, 182, 73, 128, 220, 92, 70, 187, 28, 210, 178, 130, 116, 31, 142, 200, 223,
-            251, 213, 141, 162, 5, 63, 157, 245, 5, 213, 112, 217, 134, 104, 61, 70, 235, 111, 197,
-            128, 61, 209, 100, 118, 205, 186, 98, 72, 45, 235, 240, 71, 136, 139, 19, 254, 151,
-            156, 254, 87, 162, 151, 249, 116, 230, 127, 173, 227, 12, 61, 161, 10, 128, 143, 230,
-            230, 46, 119, 105, 230, 3, 27, 175, 254, 207, 227, 21, 230, 18, 194, 224, 165, 101,
-            102, 59, 98, 41, 95, 191, 218, 197, 210, 58, 135, 168, 153, 1, 128, 0, 112, 128, 126,
-            164, 206, 137, 193, 5, 222, 25, 34, 85, 231, 76, 211, 52, 116, 60, 207, 177, 68, 184,
-            49, 61, 203, 201, 9, 114, 238, 52, 40, 133, 233, 29, 128, 52, 115, 159, 162, 183, 8,
-            172, 129, 205, 184, 156, 171, 156, 85, 97, 47, 9, 41, 108, 171, 118, 31, 103, 7, 170,
-            68, 26, 188, 227, 128, 97, 110, 128, 121, 67, 169, 107, 152, 182, 49, 84, 52, 82, 70,
-            179, 157, 50, 239, 24, 128, 112, 4, 142, 129, 241, 176, 244, 211, 248, 179, 62, 223,
-            46, 90, 133, 153, 1, 128, 0, 133, 128, 220, 8, 21, 117, 25, 61, 211, 17, 15, 241, 125,
-            50, 251, 121, 185, 101, 131, 108, 148, 110, 32, 54, 31, 203, 109, 70, 157, 239, 67,
-            139, 63, 163, 128, 27, 229, 247, 69, 144, 123, 15, 195, 10, 136, 242, 204, 30, 189,
-            213, 222, 60, 50, 171, 3, 192, 89, 178, 34, 99, 218, 78, 185, 240, 230, 62, 48, 128,
-            185, 187, 1, 169, 46, 116, 207, 110, 200, 171, 190, 197, 196, 162, 139, 123, 113, 213,
-            227, 22, 181, 141, 47, 138, 106, 92, 212, 22, 43, 138, 30, 21, 21, 1, 128, 0, 136, 128,
-            213, 18, 168, 197, 130, 7, 113, 96, 193, 39, 77, 246, 245, 130, 180, 108, 53, 219, 123,
-            177, 9, 204, 203, 55, 27, 112, 235, 152, 218, 24, 226, 99, 128, 155, 94, 72, 75, 185,
-            78, 213, 59, 4, 250, 80, 38, 16, 90, 84, 81, 135, 182, 50, 202, 156, 20, 90, 220, 131,
-            241, 177, 95, 3, 184, 88, 238, 153, 1, 128, 0, 138, 128, 108, 72, 239, 56, 96, 242, 36,
-            8, 74, 4, 35, 15, 60, 55, 87, 226, 93, 191, 241, 95, 95, 63, 205, 14, 21, 181, 132, 38,
-            108, 223, 174, 164, 128, 10, 130, 99, 67, 185, 26, 248, 188, 69, 24, 215, 66, 29, 98,
-            227, 206, 79, 8, 105, 195, 48, 139, 188, 219, 61, 110, 189, 17, 48, 147, 52, 36, 128,
-            37, 17, 60, 19, 64, 31, 26, 89, 104, 83, 49, 86, 58, 159, 56, 85, 122, 124, 26, 52,
-            111, 63, 29, 61, 184, 125, 239, 187, 6, 183, 218, 118, 29, 2, 128, 0, 177, 128, 199,
-            50, 201, 57, 175, 2, 55, 217, 58, 32, 234, 223, 238, 32, 112, 236, 219, 36, 102, 113,
-            31, 243, 123, 106, 206, 138, 78, 143, 65, 95, 64, 176, 128, 65, 255, 101, 134, 146,
-            204, 253, 34, 139, 32, 244, 235, 96, 48, 198, 94, 101, 134, 62, 99, 236, 36, 42, 146,
-            116, 238, 10, 175, 100, 189, 196, 185, 128, 225, 47, 191, 31, 181, 226, 227, 124, 242,
-            35, 4, 136, 217, 229, 205, 112, 47, 3, 102, 112, 68, 148, 204, 243, 133, 170, 158, 209,
-            180, 222, 40, 70, 128, 198, 122, 38, 81, 132, 119, 187, 167, 111, 31, 91, 202, 253,
-            207, 46, 104, 233, 141, 114, 109, 42, 137, 139, 63, 196, 97, 121, 148, 216, 149, 230,
-            221, 153, 1, 128, 0, 194, 128, 160, 100, 233, 91, 3, 183, 14, 46, 38, 91, 232, 130, 92,
-            197, 149, 174, 201, 100, 16, 253, 83, 81, 116, 25, 183, 147, 35, 216, 216, 118, 99,
-            122, 128, 179, 44, 124, 227, 90, 178, 230, 69, 120, 240, 134, 195, 239, 158, 174, 55,
-            184, 252, 171, 186, 143, 225, 250, 234, 45, 202, 218, 206, 117, 218, 23, 232, 128, 220,
-            17, 208, 182, 33, 98, 216, 24, 6, 184, 186, 104, 207, 11, 44, 216, 77, 151, 178, 228,
-            45, 48, 62, 32, 65, 82, 85, 57, 254, 69, 79, 117, 153, 1, 128, 0, 224, 128, 197, 236,
-            227, 105, 205, 222, 253, 5, 57, 175, 252, 136, 94, 138, 33, 152, 154, 161, 174, 155,
-            145, 167, 2, 33, 216, 105, 175, 246, 138, 54, 164, 219, 128, 246, 221, 126, 19, 113,
-            128, 152, 54, 57, 71, 94, 66, 195, 210, 232, 191, 62, 192, 119, 151, 205, 160, 206, 67,
-            224, 218, 71, 55, 215, 21, 171, 150, 128, 54, 213, 140, 201, 119, 178, 46, 128, 249,
-            141, 41, 194, 163, 208, 52, 160, 78, 3, 41, 39, 22, 45, 154, 152, 246, 132, 146, 91,
-            137, 124, 83, 10, 21, 1, 128, 1, 1, 128, 154, 238, 244, 153, 68, 101, 249, 251, 224,
-            43, 120, 197, 101, 83, 230, 254, 136, 9, 197, 141, 164, 237, 142, 207, 92, 253, 255,
-            125, 116, 169, 192, 133, 128, 225, 27, 135, 108, 183, 91, 136, 55, 241, 94, 112, 159,
-            232, 244, 120, 36, 252, 187, 24, 98, 117, 59, 76, 80, 177, 97, 62, 92, 219, 46, 215,
-            65, 21, 1, 128, 1, 4, 128, 171, 209, 40, 189, 64, 152, 210, 223, 88, 66, 245, 196, 122,
-            195, 211, 245, 23, 192, 92, 114, 20, 140, 16, 202, 67, 148, 180, 32, 222, 208, 216,
-            231, 128, 22, 31, 152, 8, 51, 189, 132, 147, 12, 157, 29, 60, 172, 92, 124, 32, 137,
-            193, 135, 242, 115, 131, 27, 69, 121, 63, 33, 231, 86, 153, 86, 99, 21, 1, 128, 1, 8,
-            128, 110, 192, 240, 57, 158, 118, 15, 175, 199, 118, 118, 220, 63, 175, 0, 124, 110,
-            228, 22, 167, 52, 22, 215, 92, 138, 168, 118, 67, 3, 60, 144, 166, 128, 223, 193, 50,
-            116, 17, 2, 40, 9, 245, 215, 175, 164, 19, 73, 200, 15, 239, 167, 147, 115, 56, 192,
-            199, 253, 17, 101, 136, 203, 166, 80, 248, 140, 153, 1, 128, 1, 9, 128, 182, 176, 86,
-            103, 130, 112, 152, 70, 130, 123, 97, 130, 253, 202, 191, 114, 214, 5, 255, 18, 214,
-            188, 64, 45, 189, 70, 119, 130, 247, 143, 146, 25, 128, 222, 84, 191, 139, 38, 192,
-            231, 202, 43, 181, 111, 182, 5, 135, 40, 28, 47, 210, 52, 7, 8, 92, 121, 239, 243, 65,
-            5, 1, 245, 93, 26, 21, 128, 184, 185, 201, 70, 229, 20, 169, 131, 43, 58, 19, 47, 56,
-            193, 99, 137, 165, 239, 91, 197, 213, 64, 238, 202, 57, 22, 108, 109, 197, 119, 57, 10,
-            21, 1, 128, 1, 16, 128, 104, 253, 140, 131, 62, 42, 58, 99, 141, 68, 213, 105, 192,
-            236, 98, 149, 242, 168, 136, 44, 3, 39, 13, 93, 3, 189, 153, 196, 88, 211, 136, 88,
-            128, 81, 35, 65, 138, 245, 146, 96, 154, 41, 132, 161, 162, 177, 43, 191, 160, 6, 133,
-            104, 96, 104, 44, 119, 172, 221, 7, 181, 254, 59, 178, 123, 157, 153, 1, 128, 1, 18,
-            128, 197, 242, 132, 168, 58, 218, 234, 112, 28, 70, 194, 201, 30, 41, 10, 61, 24, 36,
-            80, 200, 195, 81, 112, 137, 14, 19, 157, 129, 182, 33, 46, 10, 128, 48, 127, 1, 203, 8,
-            181, 157, 96, 133, 85, 76, 69, 177, 27, 99, 6, 106, 193, 82, 147, 247, 111, 158, 183,
-            241, 167, 21, 196, 192, 7, 15, 137, 128, 227, 4, 7, 134, 111, 116, 198, 67, 239, 154,
-            7, 102, 41, 77, 28, 148, 91, 31, 61, 160, 127, 237, 135, 92, 45, 244, 243, 215, 252,
-            67, 210, 71, 29, 2, 128, 1, 21, 128, 234, 15, 43, 225, 116, 227, 138, 210, 80, 29, 154,
-            87, 105, 154, 121, 50, 194, 246, 240, 102, 200, 20, 142, 170, 218, 160, 138, 222, 238,
-            244, 241, 189, 128, 69, 48, 206, 45, 236, 253, 216, 58, 169, 21, 246, 164, 206, 175, 6,
-            106, 111, 17, 205, 58, 183, 121, 193, 102, 178, 224, 214, 242, 30, 33, 40, 247, 128,
-            142, 174, 53, 151, 200, 186, 200, 31, 143, 97, 56, 103, 2, 77, 80, 122, 49, 62, 238,
-            142, 150, 46, 202, 113, 101, 72, 252, 96, 182, 54, 167, 47, 128, 129, 159, 230, 208,
-            223, 97, 185, 89, 109, 109, 134, 2, 50, 143, 62, 111, 200, 150, 242, 177, 140, 84, 243,
-            114, 86, 3, 14, 36, 99, 200, 0, 56, 153, 1, 128, 1, 34, 128, 34, 112, 76, 20, 158, 144,
-            41, 121, 240, 196, 194, 90, 86, 10, 255, 107, 157, 146, 55, 121, 209, 65, 103, 85, 27,
-            29, 96, 43, 161, 100, 193, 175, 128, 54, 117, 25, 160, 229, 116, 23, 210, 6, 109, 158,
-            227, 106, 108, 26, 195, 182, 86, 60, 222, 152, 177, 177, 79, 223, 16, 99, 90, 76, 128,
-            94, 186, 128, 238, 201, 178, 129, 220, 67, 15, 1, 229, 109, 12, 46, 223, 171, 47, 148,
-            184, 172, 41, 93, 248, 8, 149, 9, 185, 206, 98, 196, 161, 233, 144, 214, 153, 1, 128,
-            1, 34, 128, 180, 144, 69, 59, 47, 165, 102, 15, 144, 97, 154, 68, 51, 210, 22, 33, 235,
-            90, 193, 120, 169, 122, 180, 52, 97, 52, 74, 108, 102, 159, 19, 178, 128, 80, 49, 248,
-            169, 26, 60, 223, 243, 139, 79, 173, 148, 244, 31, 158, 244, 178, 245, 89, 151, 21,
-            151, 93, 176, 149, 197, 140, 77, 93, 68, 211, 97, 128, 62, 142, 157, 136, 196, 247, 69,
-            125, 163, 100, 116, 38, 129, 86, 32, 177, 9, 236, 225, 23, 217, 157, 43, 200, 137, 66,
-            28, 214, 138, 29, 59, 171, 153, 1, 128, 1, 36, 128, 14, 170, 0, 109, 214, 215, 167,
-            163, 13, 219, 206, 10, 30, 107, 113, 29, 39, 91, 244, 58, 153, 2, 161, 102, 3, 140,
-            218, 20, 131, 32, 242, 8, 128, 133, 177, 246, 221, 107, 71, 189, 138, 186, 82, 98, 163,
-            60, 242, 43, 255, 173, 108, 48, 50, 166, 70, 71, 161, 223, 160, 240, 54, 68, 45, 114,
-            163, 128, 198, 226, 248, 195, 76, 125, 159, 27, 6, 223, 128, 132, 230, 130, 49, 135,
-            214, 81, 143, 190, 25, 228, 87, 39, 162, 36, 73, 203, 148, 225, 105, 75, 21, 1, 128, 1,
-            64, 128, 167, 246, 224, 72, 248, 13, 103, 88, 223, 167, 13, 137, 184, 207, 159, 160,
-            202, 18, 52, 245, 194, 232, 0, 86, 48, 145, 216, 144, 188, 1, 123, 166, 128, 218, 9,
-            161, 155, 140, 37, 188, 81, 60, 101, 163, 134, 254, 25, 6, 148, 176, 213, 28, 19, 42,
-            94, 131, 4, 168, 212, 44, 182, 197, 19, 231, 149, 29, 2, 128, 1, 84, 128, 17, 23, 230,
-            230, 143, 163, 217, 25, 138, 100, 192, 43, 48, 140, 161, 78, 41, 251, 147, 63, 163, 86,
-            41, 222, 2, 20, 130, 83, 57, 191, 79, 126, 128, 61, 0, 118, 239, 107, 7, 10, 93, 74,
-            175, 172, 212, 108, 108, 249, 147, 224, 18, 66, 27, 58, 51, 9, 110, 237, 136, 169, 110,
-            118, 125, 190, 14, 128, 137, 192, 197, 116, 146, 233, 79, 154, 244, 27, 190, 228, 87,
-            205, 203, 22, 50, 160, 164, 195, 200, 109, 39, 86, 1, 226, 172, 107, 3, 147, 15, 177,
-            128, 30, 32, 47, 195, 1, 44, 229, 234, 208, 204, 202, 120, 125, 92, 146, 60, 190, 189,
-            5, 50, 192, 122, 48, 55, 97, 92, 12, 61, 136, 228, 207, 35, 21, 1, 128, 1, 128, 128,
-            129, 216, 217, 35, 55, 222, 245, 165, 194, 135, 143, 195, 72, 87, 0, 253, 64, 84, 63,
-            114, 85, 209, 61, 158, 75, 254, 96, 216, 242, 43, 106, 12, 128, 157, 104, 103, 244,
-            223, 99, 228, 171, 124, 93, 105, 135, 2, 190, 193, 182, 168, 89, 140, 179, 67, 199,
-            199, 99, 13, 93, 73, 221, 199, 24, 22, 248, 153, 1, 128, 1, 129, 128, 14, 79, 66, 100,
-            131, 147, 185, 54, 15, 141, 3, 226, 200, 237, 1, 31, 66, 198, 70, 255, 175, 138, 251,
-            103, 32, 82, 16, 147, 4, 74, 194, 85, 128, 230, 108, 3, 23, 130, 145, 205, 119, 136,
-            28, 69, 177, 82, 52, 206, 253, 162, 54, 146, 73, 51, 183, 199, 175, 32, 213, 154, 211,
-            128, 143, 174, 131, 128, 42, 161, 119, 243, 58, 128, 5, 252, 145, 90, 168, 133, 25, 14,
-            107, 167, 244, 36, 118, 17, 172, 89, 40, 178, 50, 179, 152, 145, 136, 254, 187, 79,
-            153, 1, 128, 1, 144, 128, 21, 128, 171, 91, 28, 68, 227, 29, 171, 149, 8, 252, 76, 113,
-            13, 128, 97, 167, 49, 177, 152, 172, 155, 64, 212, 178, 180, 93, 244, 51, 6, 39, 128,
-            126, 107, 34, 59, 69, 6, 67, 70, 79, 86, 249, 72, 161, 122, 212, 192, 93, 174, 248, 25,
-            226, 34, 184, 244, 144, 164, 6, 171, 173, 118, 195, 159, 128, 241, 254, 244, 155, 200,
-            49, 12, 118, 133, 129, 7, 120, 80, 24, 65, 237, 125, 34, 62, 9, 239, 48, 29, 43, 163,
-            152, 36, 39, 242, 113, 119, 154, 29, 2, 128, 1, 162, 128, 126, 24, 249, 232, 3, 225, 8,
-            10, 1, 223, 142, 253, 126, 196, 255, 67, 192, 219, 172, 21, 106, 4, 120, 29, 124, 192,
-            116, 95, 67, 146, 188, 76, 128, 98, 5, 57, 189, 26, 73, 162, 70, 245, 231, 125, 205,
-            81, 236, 143, 183, 0, 199, 100, 123, 232, 131, 146, 150, 39, 12, 156, 125, 27, 177,
-            145, 56, 128, 60, 24, 220, 170, 164, 249, 202, 22, 59, 149, 233, 128, 38, 157, 196, 12,
-            217, 160, 91, 192, 14, 110, 22, 228, 218, 85, 255, 27, 198, 187, 172, 124, 128, 17,
-            172, 46, 37, 224, 197, 170, 61, 186, 196, 145, 118, 173, 128, 44, 124, 93, 137, 161,
-            202, 144, 39, 183, 212, 253, 28, 151, 182, 78, 73, 129, 24, 37, 3, 128, 1, 199, 128,
-            229, 52, 140, 251, 89, 18, 229, 223, 37, 116, 177, 95, 130, 85, 209, 100, 159, 224, 58,
-            178, 86, 52, 224, 208, 224, 105, 41, 125, 21, 39, 200, 153, 128, 56, 122, 161, 184,
-            180, 37, 225, 132, 219, 219, 210, 76, 124, 50, 54, 199, 218, 12, 48, 7, 188, 164, 189,
-            120, 68, 121, 198, 107, 120, 134, 75, 245, 128, 128, 194, 189, 61, 153, 136, 242, 95,
-            74, 24, 139, 74, 158, 136, 241, 247, 112, 85, 39, 202, 235, 190, 234, 111, 149, 128,
-            253, 109, 127, 107, 221, 33, 128, 158, 232, 136, 238, 212, 88, 112, 177, 200, 61, 65,
-            82, 11, 102, 89, 39, 220, 163, 123, 229, 152, 171, 30, 124, 40, 19, 231, 99, 135, 125,
-            142, 236, 128, 132, 101, 157, 170, 113, 35, 232, 185, 96, 247, 218, 132, 16, 134, 225,
-            25, 172, 6, 79, 216, 229, 133, 53, 253, 210, 183, 95, 225, 116, 85, 36, 126, 128, 200,
-            169, 163, 87, 47, 206, 153, 182, 169, 113, 79, 197, 99, 160, 124, 17, 47, 143, 21, 1,
-            230, 214, 198, 87, 114, 191, 20, 120, 251, 21, 130, 170, 21, 1, 128, 2, 2, 128, 73,
-            196, 182, 221, 136, 247, 220, 151, 214, 103, 235, 237, 7, 155, 40, 161, 134, 20, 251,
-            30, 67, 124, 137, 163, 126, 224, 162, 188, 46, 129, 149, 48, 128, 163, 0, 139, 38, 49,
-            119, 79, 158, 33, 183, 237, 105, 15, 118, 134, 57, 94, 226, 176, 9, 44, 235, 243, 251,
-            111, 188, 135, 129, 145, 216, 251, 186, 21, 1, 128, 2, 4, 128, 5, 136, 179, 176, 111,
-            55, 25, 253, 118, 122, 238, 112, 104, 73, 195, 22, 38, 185, 52, 131, 177, 255, 123,
-            177, 59, 22, 225, 114, 23, 203, 243, 99, 128, 180, 185, 228, 86, 67, 152, 80, 168, 26,
-            69, 123, 164, 49, 1, 129, 166, 175, 248, 32, 39, 225, 173, 69, 40, 76, 255, 110, 41,
-            227, 56, 59, 51, 21, 1, 128, 2, 32, 128, 88, 92, 103, 248, 179, 176, 126, 171, 115,
-            199, 154, 157, 112, 143, 251, 63, 131, 212, 21, 116, 205, 24, 153, 91, 156, 255, 37,
-            238, 221, 143, 160, 154, 128, 113, 204, 218, 83, 230, 240, 255, 177, 14, 28, 62, 128,
-            21, 22, 25, 172, 1, 127, 123, 97, 107, 17, 62, 255, 51, 73, 8, 183, 37, 224, 12, 218,
-            21, 1, 128, 2, 32, 128, 174, 236, 138, 170, 190, 40, 99, 252, 175, 239, 127, 31, 237,
-            206, 178, 175, 103, 70, 1, 6, 131, 162, 69, 170, 48, 72, 211, 237, 177, 217, 140, 179,
-            128, 240, 9, 237, 231, 166, 153, 186, 153, 163, 250, 44, 33, 31, 103, 155, 177, 155,
-            147, 115, 85, 105, 74, 148, 132, 237, 215, 76, 107, 53, 203, 115, 68, 153, 1, 128, 2,
-            65, 128, 42, 185, 0, 171, 19, 73, 134, 70, 6, 217, 41, 60, 182, 75, 29, 146, 188, 1,
-            188, 186, 109, 102, 186, 161, 140, 30, 139, 14, 233, 165, 198, 15, 128, 145, 205, 231,
-            252, 159, 113, 53, 198, 116, 188, 137, 215, 53, 216, 191, 224, 13, 143, 242, 230, 219,
-            84, 59, 61, 176, 65, 252, 132, 84, 180, 235, 75, 128, 249, 146, 9, 36, 244, 148, 11,
-            120, 165, 171, 175, 165, 189, 133, 36, 56, 223, 65, 180, 36, 13, 164, 100, 79, 94, 49,
-            90, 234, 181, 189, 3, 168, 161, 2, 128, 2, 71, 128, 13, 184, 172, 213, 17, 102, 181,
-            223, 188, 198, 11, 113, 39, 22, 98, 157, 201, 246, 109, 232, 100, 79, 204, 69, 218, 88,
-            174, 73, 107, 44, 42, 95, 128, 241, 101, 97, 31, 118, 15, 249, 190, 146, 153, 219, 139,
-            142, 4, 31, 184, 84, 108, 50, 105, 119, 83, 9, 40, 95, 113, 249, 84, 55, 34, 33, 71,
-            128, 60, 255, 251, 186, 30, 23, 76, 244, 9, 122, 40, 138, 111, 14, 132, 219, 199, 6,
-            67, 100, 226, 108, 197, 123, 238, 105, 18, 135, 167, 61, 47, 42, 128, 159, 21, 120, 36,
-            39, 93, 107, 16, 107, 77, 27, 231, 206, 43, 223, 85, 197, 46, 68, 222, 113, 82, 204,
-            42, 67, 103, 5, 81, 98, 34, 250, 110, 128, 12, 17, 40, 13, 128, 106, 16, 209, 79, 150,
-            31, 200, 43, 21, 112, 41, 94, 102, 103, 114, 56, 211, 131, 219, 123, 232, 236, 68, 48,
-            153, 22, 229, 153, 1, 128, 2, 96, 128, 71, 66, 171, 183, 93, 208, 136, 233, 2, 50, 106,
-            26, 45, 221, 176, 111, 60, 245, 71, 133, 63, 240, 37, 130, 70, 70, 38, 235, 35, 163,
-            224, 61, 128, 211, 87, 189, 146, 157, 104, 140, 26, 179, 90, 246, 141, 5, 95, 214, 77,
-            102, 222, 36, 210, 37, 21, 240, 69, 53, 56, 63, 64, 182, 53, 136, 204, 128, 153, 23, 8,
-            50, 193, 121, 97, 203, 236, 251, 208, 5, 6, 70, 177, 10, 229, 2, 0, 75, 209, 88, 12,
-            93, 38, 211, 182, 253, 106, 182, 69, 250, 153, 1, 128, 2, 136, 128, 245, 186, 30, 234,
-            46, 209, 152, 84, 69, 42, 94, 14, 62, 154, 92, 170, 55, 38, 224, 91, 117, 175, 112,
-            206, 94, 50, 235, 190, 158, 223, 48, 122, 128, 145, 158, 73, 145, 122, 74, 4, 222, 83,
-            240, 208, 57, 26, 103, 51, 60, 72, 207, 208, 76, 23, 238, 52, 134, 110, 84, 101, 191,
-            65, 186, 138, 16, 128, 133, 4, 233, 24, 79, 228, 255, 81, 201, 201, 217, 145, 56, 243,
-            223, 227, 221, 32, 153, 144, 98, 1, 118, 27, 38, 18, 0, 77, 217, 229, 167, 219, 29, 2,
-            128, 2, 148, 128, 120, 1, 229, 133, 127, 25, 181, 209, 230, 92, 49, 229, 247, 31, 24,
-            105, 136, 25, 137, 19, 83, 134, 122, 196, 1, 70, 155, 112, 204, 141, 146, 61, 128, 90,
-            209, 77, 30, 0, 34, 229, 94, 44, 81, 106, 16, 64, 132, 0, 95, 29, 132, 49, 8, 153, 142,
-            15, 162, 129, 202, 59, 5, 207, 143, 162, 50, 128, 188, 235, 241, 241, 171, 214, 187,
-            162, 83, 116, 178, 167, 145, 20, 178, 145, 246, 23, 198, 164, 162, 133, 240, 212, 144,
-            101, 91, 25, 67, 49, 206, 61, 128, 0, 86, 202, 132, 154, 216, 154, 30, 105, 210, 195,
-            219, 251, 129, 118, 210, 189, 140, 144, 211, 81, 177, 47, 218, 121, 84, 173, 35, 58,
-            241, 251, 162, 153, 1, 128, 3, 1, 128, 194, 74, 43, 3, 221, 175, 58, 115, 85, 9, 234,
-            129, 87, 230, 93, 57, 75, 108, 247, 33, 156, 251, 99, 165, 71, 63, 238, 241, 85, 81,
-            21, 171, 128, 131, 110, 150, 246, 191, 159, 97, 109, 48, 158, 7, 224, 88, 156, 135,
-            228, 28, 1, 172, 108, 104, 202, 30, 229, 219, 72, 35, 239, 235, 241, 59, 209, 128, 92,
-            169, 127, 96, 219, 124, 181, 165, 37, 155, 241, 128, 228, 128, 203, 7, 160, 138, 37,
-            250, 199, 8, 127, 3, 86, 72, 195, 88, 93, 199, 157, 152, 153, 1, 128, 3, 2, 128, 86, 5,
-            179, 136, 255, 241, 125, 53, 93, 165, 223, 152, 211, 145, 1, 42, 22, 74, 71, 134, 58,
-            210, 82, 240, 30, 237, 201, 45, 31, 93, 74, 71, 128, 162, 245, 13, 243, 217, 123, 164,
-            211, 81, 182, 37, 157, 246, 239, 105, 124, 109, 209, 58, 122, 192, 121, 216, 31, 253,
-            160, 220, 210, 23, 168, 59, 48, 128, 5, 224, 143, 52, 1, 209, 34, 178, 244, 87, 189, 1,
-            55, 180, 200, 245, 236, 196, 182, 85, 115, 163, 83, 35, 175, 80, 14, 231, 70, 239, 61,
-            70, 29, 2, 128, 3, 65, 128, 1, 120, 71, 45, 184, 21, 224, 129, 126, 8, 211, 214, 10,
-            83, 5, 244, 53, 95, 105, 237, 61, 244, 211, 147, 155, 231, 230, 104, 222, 173, 167,
-            177, 128, 78, 172, 16, 193, 118, 34, 162, 111, 0, 200, 79, 33, 182, 8, 243, 75, 162,
-            183, 23, 9, 46, 122, 196, 131, 127, 13, 153, 138, 93, 184, 107, 30, 128, 97, 163, 152,
-            53, 156, 173, 202, 56, 59, 196, 93, 212, 113, 36, 135, 232, 2, 107, 61, 5, 14, 250, 71,
-            108, 217, 211, 116, 142, 120, 55, 237, 109, 128, 148, 148, 206, 212, 106, 233, 238,
-            200, 252, 24, 238, 10, 74, 113, 247, 99, 219, 198, 187, 18, 140, 165, 170, 207, 40, 1,
-            156, 182, 39, 64, 205, 42, 21, 1, 128, 4, 1, 128, 184, 244, 202, 100, 182, 8, 21, 20,
-            125, 75, 62, 61, 215, 87, 85, 14, 42, 67, 116, 83, 156, 165, 32, 3, 68, 253, 224, 70,
-            53, 249, 133, 210, 128, 133, 55, 229, 76, 92, 21, 107, 187, 90, 198, 245, 77, 86, 83,
-            91, 141, 32, 210, 232, 192, 152, 192, 212, 252, 182, 214, 200, 195, 189, 15, 183, 184,
-            21, 1, 128, 4, 4, 128, 20, 154, 27, 63, 205, 29, 207, 50, 26, 247, 87, 168, 66, 96,
-            179, 223, 138, 234, 154, 205, 148, 44, 247, 168, 33, 236, 134, 19, 101, 32, 213, 175,
-            128, 91, 204, 217, 254, 218, 46, 51, 97, 186, 63, 240, 17, 85, 221, 236, 30, 102, 119,
-            45, 130, 199, 183, 185, 78, 134, 67, 169, 163, 245, 117, 33, 225, 21, 1, 128, 4, 4,
-            128, 73, 155, 51, 111, 74, 82, 232, 13, 233, 110, 225, 61, 178, 2, 165, 63, 15, 178,
-            50, 225, 15, 22, 12, 23, 135, 125, 108, 161, 164, 91, 110, 84, 128, 113, 222, 224, 169,
-            5, 105, 110, 221, 213, 153, 193, 134, 221, 36, 55, 24, 98, 199, 120, 31, 0, 191, 129,
-            9, 60, 218, 96, 248, 51, 200, 144, 186, 21, 1, 128, 4, 16, 128, 15, 224, 158, 217, 187,
-            168, 239, 139, 16, 56, 102, 95, 164, 202, 53, 212, 46, 106, 112, 180, 161, 88, 114,
-            112, 157, 237, 179, 94, 34, 184, 67, 214, 128, 55, 31, 167, 135, 149, 168, 155, 36,
-            248, 37, 44, 142, 68, 61, 135, 45, 201, 205, 211, 147, 184, 50, 140, 77, 89, 213, 48,
-            120, 70, 237, 197, 234, 153, 1, 128, 4, 33, 128, 209, 170, 213, 100, 195, 186, 56, 45,
-            113, 62, 12, 218, 215, 54, 101, 11, 144, 224, 200, 169, 77, 37, 45, 119, 8, 150, 227,
-            87, 38, 214, 135, 215, 128, 5, 44, 105, 45, 254, 142, 215, 199, 70, 4, 91, 143, 228,
-            251, 185, 13, 132, 58, 51, 94, 133, 94, 184, 62, 121, 29, 64, 179, 131, 226, 52, 20,
-            128, 25, 6, 250, 231, 47, 248, 136, 92, 44, 66, 67, 138, 49, 104, 255, 67, 200, 197,
-            98, 58, 241, 51, 188, 230, 246, 248, 77, 241, 233, 212, 76, 138, 21, 1, 128, 4, 64,
-            128, 112, 129, 123, 15, 255, 72, 144, 97, 80, 232, 38, 129, 54, 32, 159, 50, 41, 18,
-            185, 136, 100, 211, 175, 170, 176, 92, 1, 41, 6, 47, 214, 193, 128, 241, 180, 141, 84,
-            196, 140, 158, 218, 157, 39, 220, 133, 69, 10, 206, 105, 209, 209, 179, 216, 213, 81,
-            65, 134, 40, 134, 240, 45, 241, 5, 153, 190, 153, 1, 128, 4, 80, 128, 136, 1, 179, 51,
-            123, 21, 128, 190, 108, 92, 82, 8, 19, 29, 98, 70, 15, 154, 49, 234, 95, 89, 76, 192,
-            233, 42, 149, 42, 185, 118, 85, 161, 128, 211, 55, 222, 224, 240, 222, 55, 204, 172,
-            19, 198, 215, 6, 187, 194, 242, 240, 36, 97, 156, 89, 175, 202, 96, 243, 145, 208, 58,
-            148, 3, 110, 236, 128, 2, 136, 164, 88, 89, 94, 184, 210, 47, 187, 181, 115, 83, 227,
-            64, 234, 255, 174, 86, 145, 24, 211, 74, 30, 14, 108, 78, 126, 14, 87, 79, 229, 29, 2,
-            128, 4, 100, 128, 225, 236, 90, 149, 228, 161, 167, 219, 244, 209, 23, 190, 230, 74,
-            114, 206, 248, 204, 105, 217, 222, 35, 186, 21, 215, 92, 205, 240, 145, 192, 60, 108,
-            128, 141, 93, 26, 245, 195, 157, 132, 179, 83, 209, 25, 196, 93, 64, 137, 188, 23, 181,
-            100, 52, 98, 56, 177, 195, 73, 62, 213, 190, 247, 83, 129, 67, 128, 167, 162, 53, 166,
-            14, 252, 176, 21, 148, 155, 229, 195, 224, 197, 255, 9, 146, 27, 207, 62, 12, 249, 212,
-            132, 217, 57, 70, 198, 19, 93, 150, 45, 128, 105, 122, 113, 6, 170, 14, 215, 77, 192,
-            229, 73, 71, 86, 230, 230, 44, 32, 79, 168, 51, 211, 219, 14, 165, 152, 11, 73, 17, 57,
-            227, 198, 106, 29, 2, 128, 4, 164, 128, 87, 52, 136, 49, 1, 133, 172, 25, 73, 69, 111,
-            193, 250, 82, 10, 40, 13, 75, 163, 46, 40, 32, 101, 84, 78, 50, 74, 237, 46, 65, 72,
-            196, 128, 83, 105, 201, 103, 139, 146, 66, 67, 123, 5, 238, 21, 126, 231, 131, 236,
-            233, 106, 173, 92, 177, 166, 248, 170, 14, 116, 17, 28, 14, 238, 204, 221, 128, 160,
-            197, 125, 252, 222, 87, 237, 50, 167, 85, 48, 206, 37, 202, 53, 133, 213, 10, 179, 106,
-            68, 173, 170, 236, 42, 213, 253, 28, 61, 124, 87, 90, 128, 98, 150, 43, 222, 173, 83,
-            243, 167, 227, 167, 164, 23, 135, 47, 10, 111, 221, 11, 45, 21, 34, 136, 67, 52, 230,
-            19, 69, 1, 49, 224, 95, 94, 21, 1, 128, 5, 0, 128, 82, 163, 200, 252, 174, 158, 209, 8,
-            249, 157, 253, 171, 168, 120, 93, 177, 190, 216, 243, 106, 219, 159, 168, 101, 81, 105,
-            248, 183, 141, 218, 241, 31, 128, 84, 168, 156, 209, 30, 210, 204, 75, 192, 75, 110,
-            243, 112, 114, 40, 1, 226, 141, 52, 10, 247, 45, 44, 67, 172, 212, 238, 0, 79, 96, 89,
-            80, 29, 2, 128, 5, 24, 128, 130, 65, 83, 186, 188, 140, 42, 248, 141, 195, 125, 127,
-            40, 109, 66, 78, 147, 63, 224, 219, 177, 206, 229, 84, 21, 45, 19, 132, 47, 139, 188,
-            25, 128, 129, 185, 144, 198, 225, 214, 218, 192, 190, 28, 202, 191, 41, 77, 167, 161,
-            171, 201, 23, 170, 251, 66, 211, 244, 157, 204, 174, 202, 114, 81, 179, 6, 128, 85, 4,
-            147, 6, 155, 253, 217, 221, 166, 220, 210, 29, 218, 245, 162, 36, 39, 83, 93, 116, 76,
-            218, 20, 207, 219, 225, 101, 111, 90, 99, 162, 15, 128, 168, 172, 197, 46, 122, 12,
-            186, 110, 116, 175, 2, 238, 217, 136, 146, 86, 117, 9, 120, 185, 188, 96, 188, 102,
-            191, 115, 132, 13, 109, 25, 42, 42, 153, 1, 128, 6, 8, 128, 99, 129, 96, 54, 57, 125,
-            127, 11, 10, 160, 247, 5, 109, 108, 151, 98, 180, 98, 99, 55, 111, 229, 109, 229, 89,
-            220, 177, 74, 102, 124, 14, 77, 128, 36, 41, 235, 101, 132, 128, 98, 125, 151, 75, 215,
-            85, 253, 53, 125, 186, 33, 228, 212, 18, 250, 68, 92, 135, 223, 109, 23, 66, 194, 165,
-            128, 99, 128, 89, 35, 50, 115, 60, 255, 199, 193, 31, 100, 50, 177, 167, 39, 32, 137,
-            111, 181, 38, 15, 112, 23, 141, 92, 153, 65, 1, 28, 5, 140, 225, 4, 37, 3, 128, 6, 197,
-            128, 6, 135, 183, 121, 122, 10, 2, 249, 139, 237, 84, 52, 220, 99, 107, 28, 115, 190,
-            19, 117, 232, 86, 92, 233, 157, 59, 109, 24, 232, 196, 96, 141, 128, 30, 111, 27, 172,
-            94, 198, 92, 118, 141, 72, 147, 114, 189, 113, 139, 188, 196, 31, 142, 3, 103, 201,
-            152, 207, 35, 23, 74, 240, 186, 22, 243, 55, 128, 247, 73, 230, 91, 243, 66, 196, 69,
-            160, 124, 49, 219, 19, 16, 94, 254, 34, 66, 93, 109, 17, 224, 185, 74, 102, 151, 129,
-            149, 156, 92, 76, 186, 128, 148, 246, 214, 20, 35, 4, 129, 115, 46, 81, 105, 70, 75,
-            247, 48, 5, 187, 93, 244, 175, 116, 235, 82, 202, 37, 201, 41, 170, 0, 65, 144, 72,
-            128, 156, 98, 111, 205, 46, 137, 244, 169, 9, 58, 88, 141, 144, 114, 195, 151, 114,
-            182, 91, 220, 217, 184, 51, 19, 111, 114, 7, 152, 185, 225, 190, 22, 128, 129, 25, 145,
-            64, 248, 199, 57, 114, 250, 93, 87, 3, 21, 123, 56, 246, 208, 163, 160, 171, 229, 28,
-            152, 194, 19, 243, 123, 163, 28, 181, 253, 104, 21, 1, 128, 8, 1, 128, 123, 224, 17,
-            161, 146, 233, 241, 15, 146, 185, 28, 148, 71, 91, 145, 169, 235, 35, 73, 16, 208, 156,
-            236, 139, 200, 12, 203, 102, 133, 13, 255, 225, 128, 183, 150, 31, 232, 83, 182, 30,
-            79, 224, 183, 100, 136, 38, 125, 89, 222, 25, 62, 52, 16, 251, 11, 122, 125, 197, 63,
-            133, 242, 164, 32, 201, 200, 21, 1, 128, 8, 8, 128, 38, 208, 238, 212, 73, 211, 96, 63,
-            30, 38, 220, 41, 141, 114, 159, 158, 93, 128, 48, 155, 19, 143, 46, 25, 92, 53, 240,
-            51, 193, 98, 29, 124, 128, 38, 251, 103, 25, 165, 63, 88, 244, 76, 148, 77, 17, 43, 58,
-            235, 177, 190, 174, 146, 107, 199, 6, 133, 98, 28, 200, 31, 117, 100, 16, 8, 220, 21,
-            1, 128, 8, 16, 128, 127, 183, 2, 197, 211, 251, 192, 88, 221, 135, 54, 236, 192, 193,
-            136, 154, 25, 3, 233, 164, 99, 141, 114, 153, 40, 134, 88, 10, 173, 122, 170, 163, 128,
-            196, 49, 223, 55, 244, 24, 36, 206, 46, 176, 10, 200, 156, 9, 86, 188, 83, 181, 71,
-            160, 173, 178, 229, 185, 109, 132, 54, 188, 73, 59, 135, 237, 21, 1, 128, 8, 16, 128,
-            149, 65, 179, 70, 24, 61, 92, 33, 17, 102, 240, 131, 5, 35, 64, 32, 60, 89, 228, 255,
-            196, 184, 138, 52, 169, 102, 224, 164, 110, 205, 226, 146, 128, 30, 81, 172, 54, 71,
-            42, 219, 76, 62, 8, 121, 143, 130, 152, 170, 194, 50, 155, 170, 52, 220, 243, 206, 201,
-            139, 119, 125, 228, 180, 102, 112, 6, 153, 1, 128, 8, 20, 128, 121, 239, 207, 121, 248,
-            24, 24, 79, 128, 101, 99, 170, 236, 35, 242, 8, 26, 53, 205, 203, 35, 6, 209, 83, 124,
-            183, 65, 220, 45, 5, 176, 93, 128, 160, 39, 252, 20, 7, 150, 144, 36, 36, 69, 21, 58,
-            184, 195, 145, 237, 144, 77, 229, 77, 17, 111, 74, 99, 143, 66, 84, 9, 190, 96, 87, 80,
-            128, 117, 157, 214, 35, 127, 97, 9, 161, 44, 239, 163, 244, 116, 155, 38, 126, 170,
-            140, 233, 209, 248, 118, 235, 44, 34, 82, 67, 243, 205, 230, 18, 99, 153, 1, 128, 8,
-            24, 128, 164, 116, 165, 145, 179, 109, 56, 89, 156, 37, 82, 152, 42, 30, 244, 100, 238,
-            124, 81, 234, 182, 237, 121, 112, 81, 224, 32, 26, 197, 9, 116, 235, 128, 26, 69, 235,
-            233, 130, 114, 8, 90, 210, 116, 5, 1, 149, 130, 244, 196, 140, 198, 39, 100, 21, 129,
-            164, 126, 78, 29, 73, 118, 229, 145, 125, 46, 128, 9, 112, 60, 180, 26, 244, 117, 95,
-            190, 115, 59, 220, 224, 86, 172, 66, 188, 157, 112, 31, 214, 34, 119, 38, 223, 82, 131,
-            179, 169, 63, 230, 51, 21, 1, 128, 8, 32, 128, 166, 248, 120, 131, 147, 66, 24, 156,
-            249, 70, 156, 164, 82, 79, 22, 56, 173, 211, 154, 64, 12, 65, 102, 57, 200, 185, 24,
-            188, 110, 23, 218, 80, 128, 246, 77, 152, 16, 99, 105, 211, 208, 170, 230, 28, 192,
-            109, 240, 98, 86, 77, 107, 115, 121, 214, 169, 1, 222, 57, 171, 117, 189, 145, 244, 88,
-            109, 29, 2, 128, 8, 70, 128, 177, 1, 172, 13, 83, 139, 5, 204, 229, 111, 246, 204, 194,
-            20, 158, 43, 3, 245, 142, 44, 211, 82, 169, 130, 239, 144, 201, 70, 145, 35, 53, 105,
-            128, 182, 105, 53, 175, 246, 169, 22, 31, 77, 195, 195, 71, 97, 63, 196, 209, 28, 47,
-            24, 13, 96, 106, 68, 204, 222, 38, 194, 92, 76, 161, 110, 14, 128, 134, 170, 215, 237,
-            176, 180, 95, 73, 27, 2, 167, 231, 44, 148, 35, 162, 185, 4, 17, 172, 160, 184, 205,
-            244, 171, 66, 55, 14, 67, 81, 167, 0, 128, 31, 232, 22, 199, 150, 59, 28, 250, 81, 9,
-            252, 173, 163, 108, 212, 143, 19, 189, 122, 12, 109, 65, 13, 236, 20, 122, 4, 13, 247,
-            13, 104, 149, 153, 1, 128, 8, 136, 128, 1, 133, 197, 204, 54, 88, 246, 134, 79, 81,
-            210, 83, 103, 236, 10, 208, 146, 56, 156, 42, 255, 141, 40, 14, 148, 34, 206, 217, 24,
-            43, 9, 33, 128, 56, 26, 155, 50, 21, 127, 229, 37, 105, 75, 244, 23, 53, 77, 39, 7, 30,
-            57, 212, 184, 59, 207, 198, 228, 174, 160, 150, 113, 231, 73, 107, 88, 128, 167, 254,
-            145, 105, 195, 215, 183, 221, 157, 13, 74, 34, 46, 190, 187, 21, 187, 147, 138, 119,
-            213, 170, 207, 79, 15, 157, 5, 91, 35, 41, 142, 204, 153, 1, 128, 8, 136, 128, 118, 61,
-            155, 200, 23, 166, 124, 184, 81, 77, 110, 50, 89, 185, 75, 14, 50, 16, 22, 249, 17,
-            246, 60, 170, 8, 96, 77, 141, 116, 215, 27, 81, 128, 33, 43, 230, 91, 108, 168, 18,
-            169, 144, 75, 112, 146, 147, 205, 116, 151, 252, 22, 180, 96, 31, 120, 18, 76, 203,
-            129, 140, 207, 243, 52, 249, 195, 128, 65, 198, 65, 64, 222, 63, 132, 177, 210, 60, 13,
-            29, 250, 3, 85, 144, 81, 164, 231, 195, 36, 15, 142, 223, 199, 138, 249, 185, 86, 189,
-            187, 246, 21, 1, 128, 9, 0, 128, 196, 207, 11, 28, 182, 148, 126, 68, 115, 137, 171, 0,
-            63, 229, 49, 20, 255, 147, 57, 18, 222, 17, 211, 37, 54, 205, 176, 224, 120, 50, 140,
-            84, 128, 118, 60, 139, 203, 89, 246, 240, 147, 241, 237, 220, 106, 94, 218, 94, 213,
-            192, 130, 50, 98, 191, 76, 50, 198, 242, 71, 95, 148, 75, 111, 2, 146, 29, 2, 128, 9,
-            12, 128, 187, 26, 188, 121, 21, 128, 237, 250, 182, 234, 183, 64, 209, 69, 101, 164,
-            40, 123, 30, 227, 161, 52, 47, 90, 90, 62, 55, 61, 239, 192, 96, 190, 128, 47, 154, 60,
-            133, 43, 228, 77, 134, 102, 81, 83, 92, 101, 25, 2, 25, 75, 45, 247, 135, 181, 21, 204,
-            38, 4, 212, 211, 81, 128, 41, 189, 2, 128, 91, 73, 197, 237, 65, 220, 41, 162, 99, 4,
-            30, 80, 4, 53, 195, 19, 250, 177, 1, 96, 230, 126, 150, 46, 143, 253, 214, 94, 138, 37,
-            199, 200, 128, 118, 225, 93, 149, 196, 19, 227, 227, 102, 248, 99, 7, 60, 130, 79, 233,
-            158, 84, 202, 138, 163, 237, 146, 181, 184, 161, 12, 201, 251, 119, 120, 138, 153, 1,
-            128, 9, 16, 128, 180, 179, 2, 6, 181, 127, 8, 157, 119, 71, 74, 10, 176, 103, 100, 83,
-            220, 204, 165, 115, 164, 103, 85, 159, 91, 207, 203, 87, 4, 157, 237, 98, 128, 126,
-            120, 51, 7, 186, 182, 1, 239, 45, 77, 178, 148, 227, 86, 15, 221, 191, 45, 114, 231,
-            130, 82, 181, 5, 61, 175, 134, 31, 55, 202, 234, 186, 128, 211, 149, 155, 10, 202, 92,
-            217, 74, 137, 96, 193, 164, 207, 64, 212, 191, 183, 83, 161, 237, 9, 0, 173, 102, 17,
-            235, 73, 101, 250, 106, 74, 184, 29, 2, 128, 9, 34, 128, 122, 205, 185, 216, 117, 184,
-            223, 19, 89, 250, 73, 47, 124, 80, 80, 181, 22, 82, 12, 151, 20, 167, 140, 25, 79, 201,
-            199, 137, 167, 43, 59, 114, 128, 225, 176, 222, 40, 140, 122, 164, 240, 191, 193, 238,
-            126, 230, 154, 76, 219, 235, 217, 74, 54, 212, 178, 56, 251, 178, 99, 52, 210, 147,
-            219, 172, 198, 128, 169, 223, 155, 252, 136, 6, 72, 137, 208, 51, 22, 47, 145, 16, 86,
-            162, 91, 234, 235, 85, 114, 59, 76, 82, 166, 203, 226, 23, 208, 34, 139, 247, 128, 103,
-            93, 175, 0, 75, 24, 109, 226, 149, 253, 158, 221, 213, 60, 153, 151, 4, 72, 82, 147,
-            163, 129, 193, 178, 112, 225, 200, 130, 193, 226, 62, 168, 29, 2, 128, 9, 80, 128, 82,
-            248, 188, 114, 113, 220, 134, 251, 58, 126, 74, 147, 170, 153, 245, 245, 241, 175, 175,
-            159, 242, 27, 142, 102, 225, 125, 202, 206, 34, 77, 45, 21, 128, 203, 245, 70, 149,
-            205, 148, 20, 105, 203, 61, 234, 56, 97, 130, 160, 21, 130, 49, 24, 18, 168, 218, 65,
-            54, 182, 188, 172, 231, 4, 124, 179, 78, 128, 72, 55, 239, 103, 21, 70, 29, 43, 92, 8,
-            79, 52, 108, 182, 156, 195, 126, 192, 200, 101, 87, 161, 91, 106, 65, 146, 132, 19, 15,
-            206, 67, 26, 128, 175, 67, 76, 20, 148, 130, 76, 243, 100, 125, 132, 32, 137, 246, 169,
-            16, 183, 131, 51, 6, 131, 118, 149, 148, 91, 42, 60, 124, 166, 162, 159, 246, 153, 1,
-            128, 9, 128, 128, 222, 100, 45, 208, 183, 218, 251, 179, 51, 102, 199, 1, 244, 179, 45,
-            133, 163, 131, 159, 155, 34, 25, 90, 155, 247, 107, 23, 238, 231, 89, 154, 68, 128,
-            237, 230, 139, 217, 60, 119, 75, 17, 248, 235, 128, 93, 35, 203, 216, 10, 51, 5, 123,
-            247, 96, 105, 87, 34, 218, 205, 225, 66, 222, 145, 1, 64, 128, 38, 238, 218, 75, 219,
-            186, 116, 210, 140, 58, 203, 111, 2, 51, 26, 220, 34, 191, 99, 90, 118, 13, 158, 118,
-            53, 171, 29, 43, 240, 107, 171, 174, 21, 1, 128, 10, 0, 128, 144, 214, 176, 156, 175,
-            233, 30, 95, 217, 86, 104, 10, 105, 200, 57, 201, 192, 200, 3, 243, 225, 96, 2, 99, 36,
-            103, 172, 4, 98, 57, 195, 38, 128, 3, 141, 51, 77, 6, 60, 168, 109, 8, 189, 183, 146,
-            79, 66, 56, 31, 192, 64, 115, 6, 56, 199, 2, 210, 233, 15, 170, 130, 162, 126, 80, 1,
-            29, 2, 128, 10, 160, 128, 79, 139, 199, 50, 81, 101, 28, 166, 154, 176, 171, 0, 145,
-            221, 200, 73, 165, 38, 162, 31, 204, 187, 191, 116, 5, 15, 207, 55, 180, 37, 62, 60,
-            128, 99, 253, 250, 231, 77, 221, 174, 216, 149, 213, 239, 187, 40, 246, 163, 124, 99,
-            133, 62, 8, 129, 218, 226, 185, 21, 5, 146, 94, 17, 36, 119, 83, 128, 33, 202, 229, 16,
-            159, 250, 53, 191, 119, 230, 234, 70, 45, 198, 224, 146, 222, 29, 197, 98, 105, 181,
-            44, 85, 164, 113, 64, 243, 125, 222, 140, 216, 128, 245, 192, 212, 235, 190, 198, 60,
-            71, 180, 12, 243, 105, 105, 56, 73, 232, 11, 208, 155, 177, 130, 46, 119, 34, 123, 43,
-            25, 235, 121, 72, 98, 18, 161, 2, 128, 11, 66, 128, 68, 208, 153, 216, 120, 10, 47,
-            206, 208, 81, 223, 191, 140, 146, 54, 251, 104, 84, 47, 205, 247, 134, 173, 239, 63,
-            40, 89, 226, 246, 179, 162, 103, 128, 49, 122, 8, 197, 197, 134, 127, 105, 148, 80, 73,
-            148, 158, 131, 187, 254, 22, 73, 15, 13, 241, 217, 163, 150, 171, 195, 22, 177, 67,
-            214, 50, 152, 128, 156, 255, 104, 214, 72, 105, 154, 11, 59, 7, 216, 163, 203, 53, 77,
-            224, 209, 18, 230, 59, 47, 125, 14, 86, 167, 84, 206, 39, 88, 124, 30, 120, 128, 204,
-            133, 68, 233, 188, 243, 5, 243, 115, 83, 228, 44, 118, 130, 103, 17, 95, 255, 93, 232,
-            65, 226, 112, 180, 236, 220, 239, 167, 9, 179, 81, 208, 128, 160, 132, 21, 235, 7, 116,
-            147, 3, 80, 201, 40, 159, 35, 21, 246, 175, 71, 117, 38, 114, 171, 242, 7, 152, 73,
-            252, 230, 176, 163, 66, 10, 41, 153, 1, 128, 12, 2, 128, 238, 137, 237, 115, 49, 164,
-            218, 12, 210, 155, 191, 14, 64, 100, 28, 89, 68, 22, 41, 5, 36, 244, 195, 253, 122, 3,
-            45, 20, 174, 230, 130, 168, 128, 67, 211, 156, 230, 184, 3, 172, 115, 156, 201, 25,
-            198, 245, 134, 141, 46, 176, 144, 168, 93, 204, 208, 84, 103, 232, 127, 128, 119, 85,
-            73, 242, 132, 128, 245, 172, 7, 230, 107, 246, 103, 139, 204, 44, 209, 95, 107, 145,
-            71, 96, 179, 166, 58, 114, 240, 232, 168, 7, 13, 226, 92, 2, 58, 9, 225, 1, 29, 2, 128,
-            12, 5, 128, 84, 200, 11, 192, 95, 88, 110, 51, 221, 222, 153, 60, 150, 144, 33, 16, 98,
-            160, 240, 216, 30, 170, 78, 242, 120, 165, 188, 76, 89, 25, 0, 32, 128, 151, 236, 76,
-            53, 60, 241, 156, 247, 50, 200, 52, 232, 95, 25, 22, 136, 197, 122, 140, 19, 107, 252,
-            193, 188, 181, 58, 178, 182, 63, 206, 223, 126, 128, 139, 27, 80, 18, 39, 196, 97, 248,
-            66, 228, 23, 170, 164, 213, 161, 98, 253, 1, 182, 43, 223, 163, 79, 81, 168, 196, 9,
-            227, 110, 45, 225, 80, 128, 18, 213, 212, 40, 110, 57, 222, 111, 198, 15, 108, 48, 89,
-            209, 85, 39, 14, 91, 165, 177, 241, 85, 8, 84, 62, 235, 199, 35, 163, 189, 26, 219,
-            153, 1, 128, 12, 32, 128, 84, 169, 41, 29, 162, 47, 54, 59, 94, 146, 73, 108, 112, 100,
-            119, 166, 243, 228, 188, 87, 50, 200, 51, 32, 32, 225, 174, 84, 10, 157, 150, 166, 128,
-            45, 61, 190, 158, 184, 115, 220, 195, 120, 154, 2, 111, 224, 72, 97, 154, 240, 39, 42,
-            164, 152, 229, 105, 30, 223, 37, 0, 171, 149, 9, 127, 182, 128, 192, 188, 101, 87, 202,
-            179, 212, 140, 200, 248, 191, 144, 181, 250, 188, 35, 233, 120, 124, 118, 130, 35, 45,
-            13, 174, 118, 13, 146, 122, 59, 145, 128, 21, 1, 128, 16, 8, 128, 157, 138, 224, 106,
-            211, 220, 100, 217, 64, 87, 198, 192, 12, 162, 7, 157, 143, 189, 49, 109, 9, 111, 187,
-            179, 119, 10, 254, 62, 126, 107, 49, 195, 128, 103, 60, 176, 147, 117, 30, 74, 228,
-            217, 27, 2, 49, 1, 56, 108, 247, 190, 76, 82, 244, 216, 77, 158, 118, 199, 151, 199,
-            213, 203, 40, 138, 0, 21, 1, 128, 16, 16, 128, 89, 44, 185, 184, 156, 130, 98, 35, 79,
-            112, 112, 113, 251, 255, 151, 166, 158, 68, 212, 228, 116, 76, 59, 133, 253, 51, 10,
-            46, 99, 61, 194, 24, 128, 211, 110, 218, 22, 198, 45, 120, 66, 172, 53, 185, 35, 74,
-            227, 61, 48, 60, 22, 121, 97, 83, 115, 16, 216, 143, 220, 96, 121, 39, 42, 49, 188,
-            153, 1, 128, 16, 24, 128, 160, 122, 156, 155, 164, 27, 253, 182, 105, 98, 68, 142, 40,
-            134, 117, 137, 108, 94, 116, 38, 85, 4, 75, 63, 140, 1, 185, 191, 206, 116, 145, 71,
-            128, 102, 106, 102, 163, 122, 93, 133, 204, 235, 189, 233, 182, 23, 100, 20, 26, 183,
-            64, 102, 223, 120, 100, 33, 64, 141, 53, 76, 50, 152, 186, 245, 174, 128, 234, 126, 53,
-            17, 5, 47, 80, 178, 219, 88, 236, 169, 216, 59, 155, 140, 58, 19, 252, 181, 179, 205,
-            177, 96, 174, 168, 32, 250, 84, 212, 112, 162, 21, 1, 128, 16, 32, 128, 21, 20, 220,
-            145, 208, 120, 7, 210, 103, 176, 117, 63, 121, 4, 19, 37, 106, 240, 74, 91, 206, 147,
-            107, 115, 171, 206, 198, 195, 50, 50, 99, 99, 128, 56, 180, 68, 96, 159, 234, 83, 177,
-            6, 235, 198, 16, 85, 54, 46, 225, 212, 226, 121, 204, 90, 238, 161, 194, 229, 202, 195,
-            217, 171, 42, 156, 140, 153, 1, 128, 16, 36, 128, 94, 142, 71, 99, 255, 254, 249, 13,
-            215, 45, 105, 167, 13, 209, 171, 32, 199, 249, 218, 182, 72, 144, 74, 86, 156, 145, 4,
-            209, 155, 29, 209, 135, 128, 192, 0, 62, 110, 71, 122, 33, 138, 90, 46, 178, 125, 197,
-            66, 203, 15, 26, 239, 101, 58, 101, 172, 144, 51, 93, 81, 204, 171, 179, 224, 113, 10,
-            128, 249, 238, 161, 30, 32, 186, 195, 145, 212, 95, 127, 23, 147, 40, 185, 146, 175,
-            155, 120, 108, 49, 111, 24, 84, 246, 111, 164, 240, 152, 25, 121, 208, 161, 2, 128, 16,
-            90, 128, 179, 21, 155, 7, 87, 148, 17, 189, 38, 236, 224, 239, 67, 97, 28, 251, 85, 97,
-            32, 105, 20, 240, 35, 248, 199, 237, 3, 140, 224, 234, 225, 128, 128, 26, 124, 25, 199,
-            68, 40, 4, 48, 95, 173, 208, 45, 171, 112, 9, 242, 81, 121, 18, 237, 238, 167, 179, 30,
-            95, 55, 227, 22, 61, 191, 55, 46, 128, 127, 145, 197, 201, 186, 71, 215, 210, 223, 235,
-            23, 253, 159, 0, 120, 233, 188, 63, 209, 161, 249, 154, 103, 59, 106, 206, 252, 213,
-            100, 41, 39, 18, 128, 30, 155, 41, 205, 39, 68, 216, 10, 152, 80, 32, 70, 69, 254, 209,
-            161, 241, 130, 206, 128, 220, 214, 140, 139, 74, 158, 2, 211, 230, 164, 196, 156, 128,
-            57, 51, 17, 71, 50, 135, 231, 91, 131, 225, 236, 164, 168, 95, 218, 119, 81, 110, 170,
-            103, 176, 221, 41, 206, 30, 54, 80, 150, 23, 8, 171, 151, 21, 1, 128, 16, 128, 128,
-            103, 157, 166, 42, 91, 83, 172, 237, 138, 205, 47, 223, 39, 207, 85, 122, 73, 52, 145,
-            250, 229, 208, 129, 7, 37, 72, 115, 62, 187, 99, 33, 90, 128, 191, 134, 206, 238, 236,
-            106, 237, 200, 46, 86, 166, 96, 85, 102, 237, 32, 46, 92, 18, 167, 8, 92, 127, 144, 87,
-            63, 190, 138, 111, 88, 227, 121, 29, 2, 128, 17, 3, 128, 65, 80, 156, 114, 24, 181,
-            252, 147, 40, 221, 145, 20, 245, 71, 4, 109, 30, 54, 204, 234, 115, 236, 188, 39, 75,
-            29, 42, 10, 166, 37, 174, 176, 128, 25, 15, 234, 120, 52, 94, 129, 196, 82, 128, 238,
-            44, 185, 184, 11, 252, 5, 8, 1, 199, 2, 136, 122, 137, 122, 223, 173, 117, 53, 172,
-            172, 30, 128, 208, 169, 49, 67, 77, 103, 37, 139, 237, 250, 104, 55, 246, 95, 40, 195,
-            116, 87, 237, 24, 50, 147, 38, 175, 238, 21, 45, 129, 32, 38, 116, 254, 128, 130, 168,
-            146, 189, 167, 223, 67, 200, 79, 62, 58, 30, 131, 28, 18, 28, 22, 194, 160, 5, 250, 85,
-            15, 123, 86, 59, 59, 88, 200, 130, 174, 69, 153, 1, 128, 17, 32, 128, 133, 158, 172,
-            201, 1, 133, 92, 92, 72, 123, 79, 18, 236, 10, 80, 88, 168, 71, 104, 36, 91, 249, 208,
-            124, 48, 78, 160, 232, 230, 226, 188, 92, 128, 60, 180, 65, 111, 126, 158, 180, 197,
-            76, 146, 39, 181, 133, 161, 205, 157, 162, 215, 155, 75, 27, 24, 72, 225, 148, 29, 90,
-            201, 30, 45, 17, 71, 128, 193, 64, 135, 72, 0, 139, 247, 86, 20, 87, 188, 169, 52, 17,
-            105, 118, 4, 154, 49, 31, 84, 73, 193, 150, 101, 208, 204, 224, 222, 14, 229, 92, 37,
-            3, 128, 17, 226, 128, 81, 178, 105, 9, 244, 212, 140, 228, 86, 192, 38, 225, 254, 77,
-            205, 185, 207, 179, 199, 76, 27, 38, 36, 108, 170, 50, 160, 252, 76, 214, 68, 184, 128,
-            183, 18, 165, 5, 105, 176, 118, 244, 204, 153, 124, 102, 67, 254, 112, 139, 169, 67,
-            246, 30, 161, 79, 111, 117, 150, 205, 185, 254, 188, 252, 197, 172, 128, 43, 28, 120,
-            184, 188, 134, 78, 142, 106, 7, 123, 148, 108, 13, 191, 100, 56, 171, 218, 218, 153,
-            123, 83, 54, 243, 23, 168, 142, 172, 90, 120, 88, 128, 159, 242, 105, 14, 70, 161, 58,
-            51, 216, 19, 219, 67, 32, 63, 132, 92, 178, 109, 222, 122, 187, 206, 206, 121, 210,
-            113, 168, 108, 52, 61, 183, 8, 128, 243, 217, 93, 243, 21, 184, 191, 185, 212, 137, 46,
-            175, 29, 49, 230, 112, 21, 46, 98, 95, 81, 47, 121, 207, 201, 105, 93, 4, 10, 156, 40,
-            229, 128, 96, 151, 140, 68, 249, 194, 13, 36, 168, 165, 176, 54, 10, 136, 231, 20, 231,
-            220, 40, 236, 242, 63, 215, 78, 2, 252, 241, 182, 213, 101, 89, 41, 153, 1, 128, 18, 4,
-            128, 244, 123, 248, 108, 73, 116, 42, 160, 248, 183, 10, 56, 182, 193, 46, 63, 242, 86,
-            30, 20, 38, 139, 135, 234, 150, 76, 67, 38, 7, 2, 152, 105, 128, 79, 151, 160, 220, 25,
-            91, 248, 197, 210, 90, 185, 101, 200, 23, 82, 228, 155, 101, 80, 80, 150, 227, 203, 56,
-            95, 184, 173, 94, 130, 4, 246, 138, 128, 15, 234, 147, 28, 12, 152, 145, 130, 204, 71,
-            233, 26, 0, 34, 69, 72, 119, 161, 20, 35, 11, 149, 167, 184, 172, 77, 174, 238, 234,
-            56, 165, 13, 161, 2, 128, 18, 26, 128, 140, 29, 10, 195, 174, 248, 16, 230, 61, 126,
-            168, 67, 163, 152, 125, 128, 174, 4, 99, 185, 153, 59, 27, 96, 125, 28, 8, 135, 117,
-            21, 155, 230, 128, 127, 64, 246, 106, 192, 156, 233, 168, 86, 30, 195, 181, 193, 80,
-            28, 34, 131, 33, 187, 247, 231, 103, 53, 15, 5, 254, 233, 75, 97, 8, 105, 61, 128, 222,
-            156, 253, 223, 128, 105, 169, 227, 224, 43, 149, 231, 151, 229, 1, 184, 110, 77, 123,
-            158, 17, 178, 129, 48, 24, 34, 37, 189, 20, 27, 88, 90, 128, 169, 39, 34, 230, 141, 3,
-            154, 118, 53, 181, 88, 112, 170, 45, 193, 36, 80, 168, 248, 166, 8, 81, 144, 22, 156,
-            183, 73, 222, 144, 162, 122, 216, 128, 192, 68, 126, 3, 87, 18, 69, 143, 122, 174, 49,
-            122, 96, 87, 64, 61, 152, 231, 42, 50, 168, 127, 228, 143, 82, 59, 60, 115, 92, 142,
-            105, 221, 153, 1, 128, 20, 2, 128, 56, 42, 69, 209, 94, 61, 53, 184, 74, 118, 181, 75,
-            160, 227, 177, 83, 132, 4, 107, 11, 78, 120, 26, 6, 116, 38, 34, 6, 38, 0, 192, 249,
-            128, 41, 23, 119, 57, 212, 188, 64, 252, 29, 182, 93, 92, 117, 59, 102, 242, 71, 68,
-            218, 109, 24, 4, 173, 29, 40, 63, 169, 238, 53, 27, 2, 228, 128, 153, 94, 101, 138,
-            143, 243, 68, 3, 215, 162, 208, 225, 143, 20, 100, 58, 132, 102, 6, 29, 61, 81, 230,
-            78, 184, 153, 88, 186, 62, 107, 26, 14, 37, 3, 128, 20, 46, 128, 92, 242, 65, 167, 30,
-            93, 113, 31, 25, 6, 158, 94, 144, 14, 195, 58, 58, 53, 235, 192, 27, 16, 79, 35, 166,
-            159, 69, 66, 215, 195, 190, 219, 128, 171, 177, 93, 217, 133, 44, 2, 75, 105, 116, 192,
-            140, 224, 136, 91, 58, 247, 207, 23, 47, 51, 36, 232, 130, 4, 214, 192, 42, 209, 117,
-            197, 181, 128, 197, 152, 248, 92, 245, 140, 1, 246, 93, 136, 22, 28, 114, 134, 7, 229,
-            168, 29, 230, 177, 54, 164, 96, 163, 7, 208, 31, 118, 238, 236, 47, 138, 128, 231, 141,
-            255, 213, 96, 250, 138, 150, 205, 68, 33, 51, 170, 171, 157, 191, 46, 146, 9, 33, 37,
-            234, 124, 126, 183, 151, 186, 63, 144, 120, 32, 69, 128, 207, 40, 26, 121, 185, 89, 35,
-            37, 60, 187, 250, 189, 179, 111, 189, 202, 112, 41, 219, 26, 106, 78, 215, 70, 165, 96,
-            108, 165, 18, 72, 248, 202, 128, 242, 175, 174, 178, 154, 237, 217, 222, 177, 225, 241,
-            183, 174, 42, 126, 42, 111, 58, 179, 122, 12, 23, 186, 88, 182, 42, 17, 81, 120, 232,
-            62, 38, 29, 2, 128, 20, 72, 128, 134, 168, 112, 246, 147, 124, 19, 51, 157, 186, 86,
-            222, 138, 39, 164, 3, 215, 245, 69, 245, 243, 78, 38, 88, 85, 151, 174, 178, 123, 158,
-            228, 121, 128, 189, 80, 0, 134, 178, 36, 91, 184, 110, 54, 159, 11, 194, 90, 136, 113,
-            161, 171, 237, 240, 198, 21, 241, 86, 196, 203, 84, 123, 95, 235, 97, 184, 128, 94, 5,
-            139, 41, 28, 125, 131, 77, 142, 248, 107, 68, 125, 98, 15, 109, 144, 195, 191, 249, 80,
-            57, 248, 210, 63, 24, 161, 59, 181, 128, 184, 72, 128, 3, 211, 75, 208, 48, 76, 11, 82,
-            254, 209, 16, 69, 55, 128, 243, 255, 134, 232, 166, 172, 144, 7, 0, 38, 119, 50, 201,
-            150, 3, 167, 76, 48, 29, 2, 128, 20, 129, 128, 150, 255, 72, 226, 241, 59, 74, 231, 31,
-            124, 254, 198, 42, 131, 205, 137, 83, 152, 17, 130, 161, 44, 172, 47, 206, 130, 46,
-            227, 82, 87, 43, 107, 128, 21, 4, 91, 180, 164, 143, 198, 152, 232, 67, 162, 72, 5,
-            203, 116, 101, 34, 62, 126, 29, 129, 153, 178, 23, 106, 41, 202, 43, 193, 79, 36, 61,
-            128, 249, 23, 152, 16, 223, 41, 88, 14, 128, 32, 236, 184, 102, 214, 228, 172, 145, 96,
-            244, 190, 64, 10, 211, 199, 136, 230, 79, 120, 120, 146, 50, 163, 128, 220, 227, 112,
-            67, 111, 110, 158, 87, 37, 213, 100, 117, 219, 62, 196, 123, 186, 17, 39, 71, 83, 24,
-            190, 237, 102, 206, 56, 202, 189, 63, 182, 216, 21, 1, 128, 24, 0, 128, 149, 14, 30,
-            212, 28, 157, 55, 151, 8, 133, 170, 51, 64, 234, 212, 235, 112, 62, 223, 248, 173, 158,
-            28, 243, 184, 109, 128, 60, 71, 51, 212, 199, 128, 215, 152, 143, 191, 41, 166, 163,
-            65, 144, 54, 79, 71, 160, 159, 215, 196, 215, 41, 95, 79, 132, 252, 87, 20, 34, 245,
-            245, 177, 8, 72, 204, 27, 153, 1, 128, 24, 4, 128, 191, 251, 4, 202, 204, 205, 142, 22,
-            237, 93, 242, 15, 154, 1, 96, 120, 214, 12, 223, 87, 112, 251, 129, 106, 134, 117, 219,
-            215, 26, 70, 240, 49, 128, 53, 210, 10, 196, 5, 16, 105, 77, 226, 162, 211, 69, 90, 38,
-            206, 116, 203, 88, 221, 182, 141, 157, 5, 237, 246, 250, 21, 35, 133, 218, 15, 154,
-            128, 154, 212, 69, 85, 24, 162, 194, 163, 228, 112, 102, 80, 144, 148, 18, 142, 146,
-            187, 161, 248, 173, 8, 123, 224, 212, 192, 2, 98, 163, 157, 127, 100, 161, 2, 128, 24,
-            21, 128, 128, 254, 7, 142, 15, 142, 234, 159, 98, 171, 119, 5, 218, 140, 17, 206, 84,
-            172, 69, 50, 12, 208, 4, 149, 244, 233, 238, 30, 216, 8, 197, 230, 128, 144, 101, 239,
-            210, 10, 14, 138, 40, 238, 221, 161, 134, 27, 176, 152, 6, 223, 124, 153, 144, 241,
-            146, 140, 60, 87, 227, 192, 189, 22, 26, 28, 189, 128, 42, 90, 18, 243, 219, 156, 140,
-            156, 49, 181, 181, 68, 224, 20, 96, 250, 11, 33, 87, 206, 115, 37, 73, 10, 250, 237,
-            89, 252, 251, 203, 177, 241, 128, 147, 211, 132, 15, 235, 91, 61, 211, 118, 142, 51,
-            95, 93, 249, 220, 202, 111, 66, 29, 63, 84, 29, 59, 200, 168, 169, 191, 153, 76, 27,
-            38, 125, 128, 113, 193, 219, 182, 55, 211, 135, 116, 59, 199, 10, 229, 113, 250, 30,
-            52, 250, 240, 89, 103, 202, 209, 141, 219, 7, 206, 39, 0, 9, 33, 93, 46, 29, 2, 128,
-            24, 129, 128, 234, 179, 133, 182, 63, 96, 44, 103, 224, 4, 163, 1, 163, 246, 169, 43,
-            62, 200, 127, 186, 133, 61, 127, 90, 2, 133, 109, 249, 228, 105, 241, 91, 128, 210, 56,
-            237, 113, 78, 200, 136, 18, 50, 105, 221, 34, 101, 103, 114, 148, 143, 38, 237, 103,
-            131, 73, 175, 101, 220, 40, 107, 230, 50, 121, 203, 178, 128, 0, 127, 123, 174, 251,
-            83, 119, 195, 96, 152, 60, 181, 42, 26, 202, 28, 104, 134, 130, 165, 35, 177, 232, 242,
-            139, 26, 2, 44, 14, 110, 6, 128, 128, 118, 63, 113, 242, 124, 231, 212, 8, 82, 183, 24,
-            24, 130, 47, 242, 244, 239, 77, 79, 143, 77, 92, 138, 40, 193, 232, 240, 172, 212, 219,
-            251, 253, 153, 1, 128, 25, 0, 128, 131, 216, 237, 91, 73, 32, 179, 149, 147, 229, 128,
-            243, 150, 34, 77, 196, 2, 220, 114, 110, 222, 73, 237, 95, 79, 2, 153, 120, 123, 68,
-            40, 41, 128, 202, 189, 221, 119, 170, 102, 177, 23, 130, 60, 67, 68, 166, 211, 40, 227,
-            60, 236, 175, 67, 23, 197, 225, 205, 155, 225, 249, 77, 165, 180, 156, 72, 128, 25,
-            141, 203, 151, 66, 148, 197, 11, 2, 4, 154, 70, 86, 72, 181, 199, 241, 162, 17, 63,
-            102, 61, 167, 140, 47, 71, 148, 144, 130, 222, 95, 120, 161, 2, 128, 25, 144, 128, 124,
-            64, 196, 229, 80, 168, 165, 68, 83, 189, 233, 254, 77, 200, 221, 245, 37, 108, 71, 238,
-            214, 44, 231, 120, 123, 141, 25, 208, 126, 64, 39, 137, 128, 136, 83, 118, 141, 236,
-            159, 214, 79, 137, 164, 200, 141, 31, 182, 228, 245, 254, 107, 34, 121, 230, 28, 56,
-            103, 156, 212, 3, 236, 238, 247, 236, 54, 128, 248, 213, 148, 133, 76, 109, 186, 82,
-            73, 89, 243, 37, 46, 148, 151, 48, 199, 169, 171, 14, 165, 238, 9, 124, 59, 91, 63,
-            175, 118, 35, 194, 65, 128, 148, 64, 239, 131, 49, 37, 24, 198, 172, 226, 23, 213, 168,
-            19, 131, 79, 146, 25, 204, 0, 172, 128, 91, 60, 247, 60, 137, 21, 149, 3, 63, 5, 128,
-            235, 18, 177, 170, 150, 70, 142, 125, 245, 174, 97, 223, 109, 245, 163, 5, 244, 5, 168,
-            38, 66, 87, 156, 210, 164, 50, 51, 180, 99, 2, 184, 177, 29, 2, 128, 28, 1, 128, 1, 14,
-            241, 194, 133, 160, 107, 146, 140, 26, 34, 109, 174, 111, 254, 122, 224, 7, 205, 107,
-            177, 239, 70, 149, 185, 55, 129, 104, 114, 214, 167, 174, 128, 234, 243, 200, 140, 99,
-            91, 181, 198, 217, 191, 177, 206, 224, 52, 225, 194, 175, 53, 80, 84, 161, 206, 92, 34,
-            121, 70, 199, 75, 243, 17, 248, 4, 128, 86, 185, 253, 101, 119, 109, 238, 159, 46, 1,
-            99, 153, 103, 66, 36, 108, 224, 213, 27, 22, 98, 142, 213, 177, 196, 128, 215, 181,
-            233, 161, 77, 235, 128, 69, 241, 17, 180, 174, 22, 128, 154, 210, 189, 76, 35, 147,
-            165, 241, 163, 188, 41, 167, 232, 106, 146, 91, 25, 56, 98, 138, 43, 31, 241, 2, 119,
-            21, 1, 128, 32, 1, 128, 40, 220, 230, 147, 210, 205, 205, 120, 160, 226, 19, 146, 71,
-            188, 7, 221, 128, 19, 198, 88, 6, 15, 114, 227, 245, 41, 14, 110, 10, 23, 147, 55, 128,
-            48, 227, 163, 227, 72, 147, 26, 78, 64, 235, 9, 100, 178, 69, 220, 253, 0, 14, 45, 40,
-            241, 210, 181, 158, 142, 65, 227, 9, 85, 55, 43, 11, 153, 1, 128, 32, 9, 128, 50, 18,
-            46, 85, 33, 214, 103, 120, 18, 225, 122, 132, 189, 80, 163, 249, 102, 171, 185, 125,
-            250, 182, 244, 28, 167, 126, 9, 97, 43, 6, 235, 82, 128, 15, 249, 23, 87, 236, 109, 72,
-            91, 91, 139, 172, 240, 77, 53, 16, 227, 114, 156, 55, 32, 108, 85, 210, 158, 114, 190,
-            110, 89, 23, 95, 7, 27, 128, 76, 86, 226, 80, 106, 113, 12, 109, 148, 2, 142, 227, 59,
-            99, 212, 155, 150, 209, 219, 49, 199, 44, 61, 87, 160, 105, 253, 74, 82, 139, 100, 56,
-            153, 1, 128, 32, 9, 128, 79, 207, 236, 205, 253, 184, 210, 119, 100, 147, 252, 242, 22,
-            100, 244, 124, 193, 183, 212, 219, 197, 179, 209, 54, 159, 247, 17, 121, 38, 13, 99,
-            251, 128, 244, 181, 22, 87, 215, 184, 153, 75, 220, 197, 84, 40, 111, 98, 49, 135, 204,
-            57, 47, 54, 52, 233, 35, 90, 168, 30, 253, 44, 234, 128, 36, 65, 128, 28, 221, 182,
-            188, 116, 62, 216, 177, 145, 80, 70, 104, 14, 247, 212, 212, 236, 183, 215, 108, 43,
-            142, 14, 194, 104, 13, 116, 110, 180, 159, 144, 46, 29, 2, 128, 32, 44, 128, 178, 210,
-            234, 27, 227, 206, 114, 134, 138, 16, 130, 240, 138, 23, 117, 58, 153, 229, 245, 31,
-            254, 73, 63, 55, 2, 59, 110, 196, 38, 206, 228, 1, 128, 164, 173, 93, 168, 35, 213, 82,
-            58, 205, 192, 133, 250, 221, 24, 247, 219, 211, 24, 70, 0, 160, 138, 118, 92, 147, 190,
-            255, 77, 222, 49, 183, 59, 128, 235, 190, 244, 56, 249, 152, 19, 184, 209, 7, 46, 171,
-            149, 41, 55, 203, 13, 247, 42, 122, 191, 97, 18, 223, 37, 219, 188, 45, 61, 131, 11,
-            227, 128, 122, 92, 235, 221, 230, 226, 114, 37, 36, 52, 84, 95, 96, 104, 63, 156, 254,
-            28, 228, 115, 84, 189, 184, 15, 43, 253, 82, 86, 191, 174, 100, 233, 21, 1, 128, 32,
-            64, 128, 134, 96, 70, 69, 253, 170, 56, 93, 20, 35, 124, 69, 69, 224, 170, 101, 165,
-            251, 226, 81, 99, 14, 27, 126, 87, 56, 65, 33, 181, 20, 239, 211, 128, 15, 67, 246,
-            129, 23, 193, 1, 147, 161, 221, 183, 83, 236, 108, 134, 213, 100, 143, 177, 179, 36,
-            27, 246, 140, 212, 53, 85, 50, 141, 235, 99, 52, 21, 1, 128, 32, 64, 128, 213, 21, 208,
-            135, 163, 102, 197, 155, 27, 127, 201, 146, 66, 229, 75, 92, 70, 224, 208, 139, 180,
-            112, 140, 150, 186, 175, 238, 190, 169, 146, 193, 247, 128, 3, 215, 253, 221, 138, 226,
-            134, 229, 65, 13, 247, 84, 168, 250, 109, 26, 148, 74, 32, 68, 21, 217, 251, 242, 56,
-            44, 217, 27, 251, 87, 121, 129, 153, 1, 128, 32, 160, 128, 168, 98, 185, 14, 127, 140,
-            169, 45, 140, 39, 62, 162, 181, 233, 138, 202, 246, 236, 19, 159, 118, 24, 249, 135,
-            125, 167, 213, 15, 121, 181, 165, 128, 128, 243, 246, 74, 130, 37, 112, 14, 147, 147,
-            91, 46, 244, 226, 238, 163, 204, 179, 174, 181, 8, 174, 75, 4, 163, 0, 5, 253, 56, 67,
-            219, 248, 168, 128, 111, 53, 182, 173, 109, 89, 222, 208, 184, 34, 33, 130, 251, 104,
-            242, 66, 24, 107, 180, 9, 155, 224, 227, 226, 213, 142, 66, 182, 173, 100, 67, 56, 21,
-            1, 128, 33, 0, 128, 31, 42, 133, 238, 243, 59, 240, 16, 222, 33, 219, 227, 249, 130,
-            128, 135, 38, 229, 213, 70, 118, 24, 158, 118, 200, 70, 34, 238, 181, 190, 173, 179,
-            128, 67, 138, 84, 31, 252, 132, 148, 206, 91, 38, 82, 194, 179, 143, 53, 74, 209, 192,
-            215, 229, 253, 43, 9, 168, 189, 68, 233, 218, 89, 240, 129, 8, 153, 1, 128, 33, 4, 128,
-            17, 99, 241, 69, 108, 92, 70, 227, 250, 240, 14, 212, 123, 235, 66, 25, 91, 80, 69,
-            171, 29, 105, 186, 200, 108, 230, 62, 74, 157, 97, 163, 52, 128, 20, 85, 227, 127, 187,
-            7, 222, 110, 11, 109, 222, 202, 245, 121, 87, 253, 7, 184, 129, 231, 57, 227, 216, 41,
-            180, 248, 82, 86, 81, 224, 137, 161, 128, 16, 72, 143, 251, 143, 134, 82, 140, 231,
-            121, 145, 117, 65, 1, 22, 176, 138, 17, 90, 90, 54, 184, 138, 171, 251, 26, 190, 96,
-            44, 175, 115, 241, 161, 2, 128, 33, 21, 128, 115, 93, 252, 132, 119, 249, 35, 77, 32,
-            226, 220, 123, 22, 218, 160, 52, 163, 240, 111, 17, 163, 212, 163, 84, 206, 117, 142,
-            202, 152, 53, 134, 131, 128, 239, 158, 114, 146, 71, 228, 89, 47, 80, 68, 28, 83, 23,
-            19, 147, 114, 180, 3, 221, 74, 28, 138, 56, 17, 119, 44, 110, 203, 20, 137, 24, 3, 128,
-            236, 103, 44, 112, 160, 220, 208, 8, 46, 222, 200, 245, 168, 150, 83, 180, 184, 144,
-            183, 22, 181, 100, 14, 245, 173, 21, 137, 109, 230, 67, 77, 43, 128, 41, 20, 242, 111,
-            84, 45, 180, 25, 1, 125, 150, 182, 15, 79, 225, 24, 54, 60, 34, 23, 47, 83, 59, 238,
-            26, 117, 8, 58, 240, 86, 146, 253, 128, 237, 213, 64, 26, 54, 65, 248, 26, 133, 188,
-            101, 110, 242, 52, 180, 163, 41, 171, 241, 140, 149, 80, 191, 114, 95, 180, 40, 222,
-            192, 59, 147, 113, 29, 2, 128, 33, 144, 128, 174, 197, 70, 203, 116, 144, 71, 233, 40,
-            105, 28, 94, 192, 43, 50, 196, 14, 97, 237, 191, 207, 102, 15, 248, 3, 0, 209, 117,
-            109, 179, 94, 168, 128, 83, 92, 14, 188, 59, 5, 218, 255, 32, 71, 164, 99, 38, 225, 43,
-            167, 35, 34, 118, 171, 88, 110, 170, 99, 135, 103, 110, 174, 6, 193, 179, 218, 128,
-            108, 214, 166, 241, 29, 208, 140, 82, 222, 167, 47, 11, 1, 99, 61, 200, 131, 125, 249,
-            176, 173, 183, 42, 254, 71, 100, 254, 140, 117, 163, 200, 135, 128, 105, 18, 41, 255,
-            230, 214, 179, 127, 128, 55, 100, 158, 52, 232, 184, 66, 21, 158, 144, 120, 209, 31,
-            122, 30, 250, 88, 38, 4, 193, 96, 50, 235, 21, 1, 128, 34, 0, 128, 82, 16, 102, 143,
-            95, 31, 236, 195, 104, 39, 71, 132, 228, 54, 231, 43, 68, 34, 6, 213, 162, 166, 207,
-            107, 120, 169, 18, 223, 163, 76, 6, 131, 128, 3, 243, 14, 81, 26, 99, 213, 31, 165,
-            220, 158, 45, 118, 190, 228, 61, 195, 135, 50, 167, 32, 85, 46, 43, 10, 20, 74, 98,
-            253, 125, 158, 29, 21, 1, 128, 34, 0, 128, 87, 171, 94, 106, 6, 137, 139, 252, 192, 15,
-            68, 7, 132, 90, 34, 64, 23, 0, 28, 189, 56, 123, 30, 180, 116, 51, 122, 23, 50, 126,
-            178, 129, 128, 178, 10, 246, 12, 31, 15, 172, 22, 114, 158, 145, 39, 255, 240, 150,
-            213, 205, 224, 10, 72, 107, 49, 61, 104, 13, 0, 60, 251, 11, 169, 17, 198, 21, 1, 128,
-            34, 0, 128, 228, 234, 94, 106, 205, 181, 185, 183, 238, 118, 140, 254, 9, 73, 183, 14,
-            40, 141, 219, 255, 68, 250, 192, 108, 176, 211, 91, 239, 84, 46, 248, 131, 128, 71,
-            152, 216, 226, 7, 202, 240, 36, 84, 225, 188, 171, 166, 150, 142, 99, 100, 146, 234,
-            253, 162, 152, 36, 245, 156, 167, 44, 92, 141, 19, 111, 208, 153, 1, 128, 34, 4, 128,
-            101, 52, 22, 150, 51, 147, 244, 203, 230, 152, 184, 177, 30, 186, 145, 27, 107, 71,
-            177, 48, 99, 180, 237, 188, 43, 13, 92, 43, 141, 163, 189, 48, 128, 42, 173, 59, 69,
-            207, 36, 190, 93, 118, 61, 149, 3, 227, 72, 167, 70, 157, 34, 231, 255, 181, 198, 65,
-            218, 76, 239, 140, 120, 84, 11, 70, 213, 128, 111, 99, 25, 70, 226, 13, 215, 161, 231,
-            99, 61, 12, 134, 237, 106, 7, 21, 139, 180, 56, 66, 18, 150, 234, 129, 127, 181, 142,
-            134, 117, 208, 50, 37, 3, 128, 35, 67, 128, 173, 109, 185, 76, 139, 168, 234, 212, 53,
-            41, 239, 243, 132, 148, 129, 152, 54, 228, 56, 233, 100, 88, 9, 44, 166, 83, 33, 4,
-            215, 91, 140, 242, 128, 15, 108, 176, 93, 114, 58, 109, 136, 49, 113, 213, 225, 29, 58,
-            0, 250, 96, 197, 247, 5, 175, 95, 211, 129, 20, 83, 192, 19, 241, 16, 129, 243, 128,
-            146, 173, 244, 216, 171, 214, 126, 199, 244, 127, 156, 201, 177, 124, 57, 205, 227,
-            253, 80, 67, 189, 168, 89, 82, 105, 227, 56, 70, 155, 77, 84, 227, 128, 215, 14, 158,
-            78, 135, 14, 15, 46, 94, 114, 96, 211, 76, 227, 153, 209, 243, 115, 187, 7, 16, 122,
-            230, 237, 13, 168, 219, 72, 50, 254, 231, 253, 128, 182, 78, 125, 146, 180, 18, 17,
-            237, 66, 10, 102, 244, 117, 226, 88, 220, 206, 120, 170, 3, 163, 237, 194, 138, 91,
-            228, 245, 182, 69, 245, 255, 63, 128, 72, 200, 184, 80, 9, 117, 215, 207, 249, 233, 90,
-            55, 199, 178, 173, 186, 128, 67, 173, 244, 83, 60, 22, 39, 86, 204, 39, 51, 240, 228,
-            184, 229, 153, 1, 128, 36, 128, 128, 196, 238, 151, 169, 167, 16, 107, 155, 97, 147,
-            106, 134, 188, 48, 88, 174, 161, 60, 147, 49, 166, 146, 180, 34, 21, 3, 226, 233, 133,
-            164, 44, 144, 128, 153, 6, 81, 135, 181, 229, 83, 139, 242, 167, 107, 62, 43, 15, 245,
-            135, 219, 14, 188, 203, 174, 10, 139, 163, 126, 242, 160, 227, 11, 25, 46, 236, 128,
-            118, 30, 167, 54, 238, 186, 60, 231, 139, 242, 200, 160, 238, 94, 178, 161, 227, 246,
-            108, 100, 244, 26, 147, 82, 237, 246, 24, 2, 167, 230, 142, 112, 21, 1, 128, 40, 0,
-            128, 92, 21, 62, 131, 29, 64, 180, 148, 19, 29, 21, 146, 96, 177, 125, 245, 180, 149,
-            250, 216, 197, 232, 168, 249, 235, 178, 77, 144, 222, 40, 204, 244, 128, 230, 36, 77,
-            11, 36, 58, 207, 217, 158, 196, 140, 236, 121, 142, 157, 67, 150, 11, 164, 207, 25,
-            204, 129, 147, 81, 97, 127, 210, 165, 37, 102, 140, 21, 1, 128, 40, 0, 128, 129, 53,
-            239, 107, 102, 141, 74, 57, 43, 10, 128, 71, 76, 142, 167, 157, 223, 73, 65, 153, 232,
-            90, 85, 24, 70, 86, 206, 11, 105, 151, 213, 97, 128, 254, 42, 127, 25, 238, 243, 48,
-            82, 136, 220, 23, 19, 158, 190, 192, 188, 234, 149, 186, 16, 125, 98, 177, 206, 192,
-            210, 179, 189, 251, 184, 217, 249, 153, 1, 128, 40, 4, 128, 106, 21, 52, 6, 14, 7, 244,
-            247, 184, 69, 134, 71, 177, 94, 0, 129, 216, 5, 87, 201, 250, 53, 42, 92, 147, 230,
-            130, 146, 199, 163, 62, 37, 128, 214, 189, 193, 211, 159, 105, 184, 102, 199, 84, 146,
-            152, 224, 163, 26, 145, 134, 53, 4, 89, 165, 86, 235, 14, 133, 199, 118, 13, 164, 29,
-            51, 28, 128, 169, 84, 224, 95, 123, 99, 89, 181, 107, 213, 157, 99, 65, 226, 171, 122,
-            204, 194, 208, 175, 94, 208, 161, 225, 12, 8, 181, 198, 246, 128, 243, 78, 29, 2, 128,
-            40, 5, 128, 173, 112, 234, 59, 17, 139, 28, 229, 46, 253, 178, 126, 173, 182, 75, 170,
-            114, 227, 179, 170, 20, 29, 30, 160, 71, 252, 86, 79, 122, 80, 180, 111, 128, 220, 49,
-            4, 22, 163, 128, 85, 192, 169, 211, 199, 108, 66, 75, 211, 72, 139, 153, 12, 119, 247,
-            249, 204, 180, 162, 36, 160, 96, 202, 137, 181, 194, 128, 136, 24, 63, 210, 214, 136,
-            36, 119, 116, 224, 188, 211, 87, 135, 139, 77, 254, 20, 20, 117, 220, 176, 173, 55, 96,
-            0, 167, 213, 154, 66, 203, 248, 128, 188, 3, 64, 97, 136, 90, 231, 148, 152, 212, 107,
-            19, 25, 145, 126, 63, 44, 106, 32, 41, 72, 241, 217, 25, 73, 27, 74, 58, 190, 33, 34,
-            227, 153, 1, 128, 40, 8, 128, 136, 5, 104, 46, 168, 35, 153, 168, 121, 173, 103, 154,
-            165, 229, 127, 242, 199, 214, 60, 100, 80, 48, 83, 0, 207, 150, 154, 124, 30, 192, 96,
-            47, 128, 27, 69, 10, 233, 216, 133, 29, 206, 206, 117, 188, 47, 188, 186, 25, 104, 165,
-            149, 94, 4, 132, 79, 58, 33, 69, 177, 196, 33, 213, 15, 76, 184, 128, 123, 175, 78, 24,
-            159, 242, 95, 228, 251, 33, 140, 250, 67, 91, 157, 233, 112, 185, 105, 76, 56, 135,
-            106, 15, 173, 167, 125, 70, 119, 12, 74, 231, 29, 2, 128, 40, 36, 128, 68, 13, 175, 73,
-            82, 163, 15, 59, 204, 0, 104, 19, 62, 86, 240, 212, 53, 36, 126, 106, 56, 154, 20, 205,
-            87, 197, 187, 214, 199, 199, 119, 41, 128, 3, 48, 245, 208, 26, 149, 243, 133, 84, 40,
-            79, 160, 147, 170, 45, 41, 119, 70, 118, 105, 0, 143, 168, 236, 231, 12, 203, 131, 224,
-            218, 222, 144, 128, 90, 137, 26, 133, 192, 216, 222, 235, 192, 201, 158, 237, 31, 56,
-            9, 179, 254, 218, 139, 99, 27, 18, 231, 164, 81, 32, 23, 230, 12, 182, 13, 193, 128,
-            156, 192, 43, 122, 26, 8, 120, 14, 234, 4, 86, 63, 109, 63, 1, 1, 156, 128, 132, 249,
-            244, 11, 230, 219, 195, 101, 174, 212, 84, 245, 215, 120, 161, 2, 128, 40, 37, 128,
-            216, 90, 214, 108, 84, 19, 74, 228, 40, 166, 192, 80, 213, 244, 134, 19, 190, 40, 28,
-            181, 222, 254, 130, 32, 139, 104, 198, 7, 6, 61, 45, 145, 128, 202, 49, 137, 194, 40,
-            200, 67, 226, 131, 26, 105, 147, 133, 64, 100, 186, 73, 31, 215, 96, 165, 66, 130, 154,
-            17, 55, 3, 58, 63, 53, 5, 206, 128, 214, 189, 116, 112, 111, 230, 123, 255, 26, 61,
-            194, 111, 252, 66, 164, 152, 32, 240, 197, 37, 222, 84, 46, 223, 211, 107, 140, 224,
-            160, 87, 97, 5, 128, 32, 19, 58, 231, 46, 106, 223, 115, 169, 164, 172, 218, 148, 202,
-            66, 120, 130, 198, 208, 128, 38, 164, 120, 127, 145, 163, 79, 113, 209, 77, 194, 250,
-            128, 11, 7, 103, 50, 56, 132, 43, 39, 127, 69, 209, 36, 99, 112, 55, 121, 176, 163,
-            250, 161, 42, 171, 177, 99, 46, 76, 6, 17, 175, 208, 129, 15, 161, 2, 128, 40, 69, 128,
-            79, 158, 127, 47, 190, 186, 147, 135, 200, 107, 207, 144, 164, 100, 38, 29, 29, 247,
-            85, 207, 150, 53, 230, 56, 126, 163, 252, 90, 30, 134, 80, 220, 128, 180, 104, 53, 29,
-            225, 208, 41, 173, 77, 142, 197, 39, 83, 104, 152, 111, 253, 173, 224, 231, 173, 215,
-            233, 57, 18, 95, 67, 96, 214, 160, 47, 192, 128, 168, 101, 87, 1, 104, 71, 108, 4, 26,
-            1, 186, 101, 247, 214, 160, 107, 42, 176, 202, 166, 185, 156, 111, 63, 207, 53, 124,
-            179, 134, 192, 16, 135, 128, 149, 196, 225, 43, 134, 140, 143, 85, 94, 132, 130, 252,
-            55, 214, 130, 173, 130, 4, 141, 29, 211, 105, 104, 70, 66, 0, 46, 189, 219, 79, 173,
-            255, 128, 95, 19, 121, 148, 242, 42, 90, 111, 71, 229, 48, 44, 202, 204, 186, 83, 164,
-            78, 227, 200, 99, 44, 72, 133, 94, 4, 31, 194, 152, 57, 137, 167, 161, 2, 128, 40, 70,
-            128, 200, 33, 99, 190, 18, 29, 40, 253, 204, 244, 157, 175, 144, 73, 213, 60, 101, 82,
-            190, 139, 224, 56, 41, 34, 88, 85, 75, 15, 142, 76, 232, 93, 128, 175, 240, 86, 127,
-            64, 156, 5, 23, 165, 98, 138, 14, 224, 198, 78, 5, 76, 128, 146, 41, 43, 45, 131, 51,
-            140, 61, 36, 201, 164, 235, 46, 183, 128, 51, 106, 124, 89, 151, 250, 30, 160, 239,
-            115, 218, 205, 95, 180, 42, 249, 25, 188, 137, 166, 208, 250, 18, 217, 81, 37, 179, 33,
-            138, 0, 246, 224, 128, 3, 26, 67, 30, 183, 92, 239, 153, 87, 81, 30, 32, 99, 87, 124,
-            232, 103, 255, 44, 41, 121, 156, 222, 141, 177, 190, 191, 11, 13, 89, 42, 38, 128, 221,
-            60, 39, 0, 200, 66, 174, 153, 145, 35, 164, 39, 248, 44, 229, 94, 12, 3, 111, 77, 174,
-            105, 158, 108, 56, 170, 18, 48, 32, 112, 238, 103, 161, 2, 128, 40, 200, 128, 164, 110,
-            94, 80, 218, 5, 224, 50, 92, 234, 116, 43, 233, 237, 219, 242, 107, 198, 241, 248, 36,
-            227, 82, 82, 17, 166, 79, 217, 224, 104, 40, 186, 128, 43, 214, 145, 176, 25, 3, 147,
-            159, 93, 219, 240, 156, 231, 14, 63, 144, 53, 128, 68, 93, 205, 218, 55, 150, 253, 123,
-            150, 202, 151, 218, 25, 167, 128, 69, 236, 230, 138, 92, 143, 199, 129, 48, 208, 195,
-            14, 135, 127, 114, 24, 173, 214, 142, 11, 92, 200, 54, 218, 105, 100, 131, 22, 198,
-            177, 113, 107, 128, 199, 106, 134, 1, 231, 227, 114, 179, 77, 233, 198, 180, 45, 206,
-            12, 162, 0, 173, 56, 219, 118, 144, 38, 88, 203, 224, 216, 254, 4, 21, 103, 243, 128,
-            41, 107, 163, 85, 162, 80, 3, 65, 107, 132, 44, 180, 105, 190, 250, 228, 63, 20, 53,
-            178, 67, 206, 97, 97, 86, 36, 57, 45, 237, 39, 239, 240, 153, 1, 128, 48, 4, 128, 77,
-            220, 50, 157, 94, 25, 57, 3, 235, 46, 255, 85, 137, 126, 145, 248, 148, 215, 29, 228,
-            2, 242, 67, 115, 30, 42, 208, 241, 85, 18, 120, 210, 128, 105, 194, 118, 178, 249, 158,
-            127, 225, 56, 163, 153, 29, 75, 94, 63, 72, 77, 52, 109, 252, 240, 38, 123, 142, 81,
-            227, 248, 221, 237, 26, 141, 81, 128, 1, 245, 156, 179, 132, 199, 252, 122, 147, 112,
-            54, 187, 33, 139, 60, 4, 113, 25, 226, 227, 188, 199, 145, 231, 209, 98, 104, 237, 151,
-            231, 54, 143, 29, 2, 128, 48, 24, 128, 22, 34, 216, 237, 119, 98, 167, 252, 23, 130,
-            158, 119, 2, 76, 56, 202, 79, 20, 128, 187, 215, 12, 36, 158, 160, 138, 161, 246, 40,
-            23, 28, 142, 128, 217, 79, 208, 243, 28, 223, 33, 139, 65, 211, 214, 118, 253, 88, 146,
-            150, 212, 89, 80, 154, 220, 52, 253, 114, 49, 118, 201, 51, 248, 144, 118, 33, 128,
-            228, 185, 238, 121, 144, 20, 8, 190, 3, 209, 46, 177, 206, 16, 7, 86, 226, 184, 185,
-            170, 231, 177, 39, 76, 28, 15, 172, 45, 237, 19, 168, 183, 128, 148, 63, 145, 242, 205,
-            122, 189, 4, 212, 196, 33, 209, 133, 71, 173, 7, 100, 31, 226, 37, 194, 54, 106, 190,
-            193, 93, 217, 186, 22, 121, 173, 244, 153, 1, 128, 48, 32, 128, 140, 235, 82, 153, 143,
-            180, 245, 147, 147, 205, 182, 42, 201, 145, 135, 18, 82, 153, 77, 187, 21, 171, 191,
-            140, 110, 21, 86, 234, 226, 169, 170, 119, 128, 117, 0, 180, 37, 226, 184, 132, 126, 4,
-            201, 22, 190, 249, 186, 103, 17, 17, 138, 22, 182, 76, 137, 155, 8, 213, 112, 33, 220,
-            183, 213, 214, 212, 128, 209, 14, 231, 101, 114, 156, 255, 225, 97, 171, 176, 113, 123,
-            211, 253, 245, 79, 238, 116, 196, 145, 179, 246, 175, 70, 122, 146, 115, 177, 59, 51,
-            246, 37, 3, 128, 48, 201, 128, 10, 99, 36, 23, 82, 61, 187, 81, 62, 118, 110, 200, 249,
-            33, 203, 107, 7, 57, 219, 229, 243, 123, 231, 246, 86, 119, 155, 30, 73, 59, 149, 169,
-            128, 45, 37, 73, 81, 154, 140, 32, 199, 242, 156, 182, 10, 83, 93, 58, 112, 75, 119,
-            35, 244, 234, 246, 77, 218, 12, 180, 0, 59, 166, 199, 128, 58, 128, 248, 86, 225, 202,
-            28, 29, 196, 100, 113, 93, 174, 159, 25, 183, 84, 223, 166, 153, 252, 161, 35, 155,
-            201, 210, 220, 76, 251, 21, 92, 242, 174, 5, 128, 106, 113, 219, 203, 192, 102, 230,
-            171, 150, 169, 83, 110, 64, 78, 239, 7, 159, 203, 88, 13, 199, 242, 219, 51, 226, 57,
-            68, 10, 219, 55, 144, 127, 128, 142, 33, 243, 167, 51, 32, 0, 69, 22, 151, 157, 128,
-            77, 158, 209, 224, 146, 61, 118, 27, 25, 240, 20, 148, 51, 117, 177, 96, 202, 105, 33,
-            239, 128, 225, 237, 205, 138, 131, 249, 204, 45, 32, 182, 44, 113, 211, 118, 198, 131,
-            118, 37, 253, 140, 118, 133, 229, 10, 236, 167, 68, 195, 107, 100, 190, 206, 161, 2,
-            128, 49, 6, 128, 151, 61, 121, 121, 179, 225, 75, 165, 144, 213, 105, 137, 40, 118,
-            155, 236, 229, 37, 188, 137, 240, 217, 35, 15, 179, 1, 211, 37, 88, 86, 148, 166, 128,
-            239, 41, 201, 45, 174, 218, 202, 85, 6, 143, 180, 15, 34, 52, 76, 90, 41, 188, 81, 148,
-            161, 179, 212, 98, 127, 19, 141, 75, 95, 86, 67, 98, 128, 104, 238, 181, 41, 176, 100,
-            191, 192, 196, 241, 17, 241, 245, 0, 243, 35, 75, 86, 58, 254, 119, 86, 47, 160, 117,
-            215, 59, 198, 254, 151, 172, 62, 128, 14, 75, 56, 166, 111, 182, 144, 33, 203, 237, 2,
-            104, 92, 64, 87, 247, 150, 99, 47, 57, 131, 175, 121, 35, 86, 133, 102, 197, 114, 21,
-            244, 180, 128, 234, 106, 46, 227, 188, 21, 170, 108, 101, 101, 254, 219, 49, 149, 247,
-            104, 143, 63, 239, 145, 153, 247, 18, 112, 10, 130, 147, 248, 200, 228, 3, 168, 153, 1,
-            128, 50, 0, 128, 247, 80, 250, 229, 218, 197, 33, 75, 21, 142, 233, 161, 220, 200, 51,
-            3, 5, 167, 221, 150, 154, 14, 96, 77, 92, 196, 129, 45, 97, 5, 32, 138, 128, 129, 11,
-            248, 163, 188, 2, 64, 197, 201, 45, 15, 86, 84, 159, 194, 39, 201, 77, 159, 213, 99,
-            75, 63, 107, 185, 235, 216, 133, 108, 141, 100, 178, 128, 21, 205, 99, 177, 109, 195,
-            76, 216, 73, 194, 238, 49, 43, 105, 217, 130, 217, 60, 112, 166, 48, 150, 140, 2, 62,
-            206, 161, 83, 181, 5, 239, 182, 29, 2, 128, 52, 32, 128, 17, 118, 218, 98, 238, 240,
-            71, 235, 208, 148, 212, 86, 179, 200, 88, 216, 202, 109, 6, 214, 104, 176, 30, 45, 146,
-            92, 53, 108, 161, 198, 76, 19, 128, 181, 159, 207, 143, 50, 110, 124, 234, 254, 178,
-            253, 235, 161, 158, 49, 194, 85, 43, 210, 215, 94, 15, 43, 135, 120, 183, 145, 75, 53,
-            68, 164, 135, 128, 29, 105, 129, 105, 155, 88, 2, 153, 188, 69, 144, 97, 11, 129, 248,
-            230, 181, 115, 197, 119, 64, 220, 127, 219, 66, 241, 207, 29, 31, 204, 209, 10, 128,
-            135, 172, 1, 144, 43, 86, 19, 113, 58, 212, 211, 110, 38, 58, 114, 64, 86, 15, 206,
-            200, 98, 130, 91, 194, 133, 65, 9, 30, 221, 216, 106, 248, 161, 2, 128, 54, 4, 128,
-            198, 103, 98, 149, 72, 209, 158, 203, 169, 62, 137, 202, 241, 184, 141, 161, 154, 247,
-            132, 113, 227, 23, 88, 165, 23, 103, 64, 7, 156, 198, 234, 138, 128, 16, 245, 139, 202,
-            188, 139, 113, 157, 38, 34, 72, 67, 249, 107, 251, 196, 86, 238, 87, 46, 182, 230, 83,
-            143, 186, 140, 86, 5, 49, 2, 85, 129, 128, 129, 62, 161, 3, 116, 9, 199, 55, 40, 172,
-            67, 159, 153, 102, 3, 240, 65, 162, 40, 111, 89, 136, 131, 156, 51, 83, 115, 149, 16,
-            190, 189, 150, 128, 188, 153, 105, 218, 57, 172, 43, 26, 248, 0, 70, 190, 91, 167, 32,
-            98, 177, 99, 170, 236, 177, 85, 42, 118, 198, 25, 236, 137, 112, 23, 197, 220, 128, 44,
-            234, 17, 69, 195, 36, 138, 80, 246, 228, 207, 239, 94, 172, 207, 197, 65, 171, 116,
-            234, 37, 147, 10, 198, 118, 252, 48, 71, 209, 229, 245, 155, 169, 3, 128, 54, 70, 128,
-            224, 47, 149, 61, 127, 141, 118, 137, 80, 123, 14, 162, 144, 43, 58, 156, 159, 170, 93,
-            214, 116, 226, 160, 152, 48, 35, 151, 43, 70, 88, 66, 160, 128, 239, 87, 30, 199, 21,
-            65, 183, 205, 54, 79, 144, 37, 100, 61, 133, 61, 210, 165, 225, 39, 149, 86, 49, 109,
-            246, 179, 40, 111, 200, 187, 207, 79, 128, 172, 215, 45, 175, 208, 78, 133, 75, 130,
-            103, 150, 37, 202, 159, 183, 51, 39, 41, 65, 123, 82, 107, 169, 153, 65, 119, 105, 131,
-            145, 109, 55, 106, 128, 199, 178, 80, 241, 203, 7, 230, 172, 170, 21, 129, 205, 247,
-            238, 64, 53, 56, 102, 223, 195, 196, 223, 85, 91, 2, 199, 52, 73, 107, 93, 253, 181,
-            128, 41, 158, 227, 139, 23, 20, 200, 95, 187, 3, 219, 129, 51, 231, 174, 123, 7, 46,
-            209, 1, 108, 203, 196, 116, 183, 182, 131, 105, 124, 186, 225, 188, 128, 51, 235, 5,
-            32, 113, 30, 196, 154, 70, 253, 13, 42, 238, 173, 239, 232, 45, 99, 107, 22, 94, 133,
-            217, 134, 48, 202, 69, 224, 208, 37, 75, 162, 128, 64, 124, 241, 227, 207, 255, 238,
-            124, 87, 121, 167, 0, 25, 191, 0, 42, 76, 228, 150, 17, 112, 43, 218, 104, 150, 47,
-            185, 229, 226, 225, 227, 224, 29, 2, 128, 56, 64, 128, 201, 205, 244, 177, 123, 149,
-            84, 103, 114, 223, 46, 92, 126, 84, 144, 239, 95, 80, 6, 100, 249, 192, 149, 109, 20,
-            208, 254, 240, 141, 13, 161, 31, 128, 127, 58, 101, 223, 196, 230, 210, 190, 141, 1,
-            92, 79, 55, 37, 109, 135, 108, 214, 13, 215, 135, 166, 122, 183, 76, 150, 166, 115,
-            136, 171, 238, 166, 128, 53, 70, 55, 18, 22, 245, 27, 77, 137, 148, 115, 254, 170, 219,
-            44, 215, 8, 106, 142, 217, 61, 159, 12, 200, 29, 152, 64, 28, 216, 206, 107, 158, 128,
-            139, 126, 235, 153, 77, 101, 60, 146, 110, 78, 64, 182, 238, 231, 96, 188, 126, 45, 40,
-            53, 127, 171, 253, 229, 70, 133, 93, 185, 74, 194, 245, 227, 37, 3, 128, 62, 4, 128,
-            36, 11, 29, 47, 223, 80, 137, 190, 187, 212, 36, 37, 58, 56, 66, 101, 180, 252, 152,
-            21, 58, 166, 241, 79, 189, 237, 231, 139, 131, 222, 185, 203, 128, 149, 211, 52, 164,
-            218, 56, 82, 207, 132, 88, 34, 112, 154, 40, 179, 158, 29, 78, 218, 16, 49, 45, 145,
-            21, 93, 23, 183, 200, 178, 35, 185, 84, 128, 176, 60, 170, 39, 81, 162, 211, 137, 40,
-            50, 167, 212, 153, 222, 224, 152, 88, 189, 17, 212, 163, 99, 205, 35, 255, 205, 81,
-            187, 248, 48, 30, 115, 128, 189, 188, 131, 61, 209, 19, 69, 45, 233, 239, 164, 231,
-            202, 206, 192, 128, 94, 221, 152, 172, 38, 140, 28, 93, 162, 71, 180, 182, 246, 76, 47,
-            89, 128, 40, 102, 115, 56, 45, 33, 88, 164, 213, 228, 40, 199, 85, 48, 144, 168, 65,
-            39, 124, 29, 30, 148, 219, 248, 71, 93, 198, 100, 67, 60, 158, 156, 128, 47, 246, 70,
-            62, 251, 25, 29, 196, 149, 12, 238, 78, 200, 225, 248, 106, 21, 55, 104, 250, 160, 135,
-            146, 192, 233, 108, 116, 122, 73, 17, 239, 19, 21, 1, 128, 64, 2, 128, 245, 244, 152,
-            150, 98, 194, 169, 100, 208, 46, 196, 56, 231, 195, 124, 242, 4, 144, 230, 230, 18, 20,
-            209, 140, 191, 208, 255, 133, 157, 126, 31, 230, 128, 116, 178, 225, 24, 11, 85, 103,
-            139, 212, 139, 137, 226, 107, 138, 144, 49, 42, 8, 205, 54, 254, 84, 220, 53, 99, 146,
-            24, 219, 143, 173, 202, 218, 21, 1, 128, 64, 4, 128, 32, 223, 84, 247, 212, 234, 16,
-            90, 120, 106, 151, 135, 93, 177, 114, 193, 232, 23, 23, 32, 123, 73, 230, 239, 242,
-            155, 1, 134, 71, 239, 238, 227, 128, 223, 58, 146, 142, 109, 182, 102, 99, 199, 120,
-            248, 67, 49, 121, 61, 122, 196, 37, 155, 19, 68, 238, 117, 185, 219, 222, 167, 117,
-            166, 140, 24, 126, 153, 1, 128, 64, 6, 128, 77, 57, 50, 111, 136, 148, 178, 41, 180,
-            235, 70, 58, 221, 134, 81, 89, 213, 0, 66, 5, 97, 59, 235, 4, 91, 203, 152, 12, 243,
-            251, 157, 205, 128, 6, 85, 54, 189, 43, 92, 42, 255, 91, 141, 130, 90, 199, 176, 127,
-            210, 169, 22, 88, 42, 180, 121, 22, 27, 3, 239, 141, 101, 201, 138, 26, 155, 128, 127,
-            144, 156, 250, 234, 74, 106, 152, 0, 38, 248, 54, 27, 54, 43, 254, 65, 244, 124, 146,
-            21, 187, 128, 140, 61, 130, 216, 54, 47, 111, 150, 82, 29, 2, 128, 64, 7, 128, 84, 190,
-            66, 200, 168, 30, 83, 68, 84, 114, 19, 72, 220, 254, 53, 208, 197, 132, 4, 110, 185,
-            237, 201, 176, 134, 20, 87, 253, 106, 47, 142, 52, 128, 247, 65, 137, 241, 231, 252,
-            85, 157, 158, 172, 241, 231, 53, 188, 36, 127, 157, 27, 75, 175, 95, 115, 137, 164,
-            179, 78, 219, 2, 98, 228, 182, 50, 128, 11, 119, 177, 200, 187, 249, 149, 80, 48, 228,
-            110, 125, 164, 187, 201, 142, 90, 133, 111, 20, 82, 56, 42, 74, 98, 206, 111, 108, 104,
-            212, 137, 106, 128, 238, 76, 255, 193, 14, 226, 95, 35, 26, 64, 235, 12, 5, 119, 196,
-            138, 42, 57, 48, 66, 139, 251, 231, 115, 114, 137, 132, 128, 21, 250, 226, 195, 21, 1,
-            128, 64, 8, 128, 77, 100, 78, 23, 97, 182, 216, 199, 131, 56, 177, 59, 20, 255, 142,
-            236, 171, 123, 79, 185, 75, 247, 161, 112, 183, 37, 193, 207, 87, 185, 222, 106, 128,
-            39, 209, 52, 158, 121, 59, 28, 7, 95, 111, 75, 88, 94, 242, 230, 233, 9, 201, 253, 227,
-            208, 23, 131, 247, 122, 134, 190, 251, 138, 16, 66, 244, 21, 1, 128, 64, 8, 128, 182,
-            94, 17, 252, 211, 130, 23, 174, 211, 141, 42, 224, 79, 159, 181, 255, 241, 91, 182, 13,
-            208, 171, 75, 105, 82, 62, 134, 242, 158, 45, 76, 105, 128, 74, 85, 169, 189, 86, 158,
-            131, 186, 251, 12, 41, 140, 224, 134, 193, 100, 143, 224, 224, 7, 184, 168, 107, 161,
-            112, 228, 140, 156, 157, 143, 168, 120, 29, 2, 128, 64, 11, 128, 253, 48, 220, 47, 114,
-            241, 178, 33, 15, 217, 63, 121, 22, 54, 97, 82, 115, 224, 247, 196, 101, 38, 213, 126,
-            89, 123, 43, 7, 54, 202, 187, 120, 128, 105, 107, 158, 48, 31, 105, 187, 157, 44, 89,
-            22, 83, 80, 66, 171, 80, 227, 38, 135, 231, 92, 218, 1, 132, 111, 102, 149, 5, 115,
-            139, 111, 221, 128, 214, 14, 112, 5, 36, 84, 12, 77, 145, 70, 115, 117, 162, 140, 101,
-            211, 158, 183, 178, 54, 144, 110, 44, 104, 123, 139, 34, 202, 44, 221, 63, 147, 128,
-            191, 224, 27, 12, 195, 185, 28, 245, 30, 166, 64, 0, 76, 100, 54, 72, 122, 126, 191,
-            161, 48, 178, 246, 67, 109, 131, 75, 136, 210, 120, 179, 232, 21, 1, 128, 64, 16, 128,
-            99, 149, 242, 232, 75, 125, 69, 60, 202, 221, 126, 53, 135, 90, 1, 167, 144, 3, 135,
-            153, 73, 243, 184, 148, 55, 47, 80, 141, 85, 244, 149, 219, 128, 37, 119, 167, 166, 14,
-            127, 251, 11, 135, 110, 250, 126, 216, 160, 15, 133, 152, 193, 76, 23, 126, 173, 222,
-            207, 62, 254, 136, 218, 121, 10, 124, 75, 153, 1, 128, 64, 33, 128, 142, 133, 154, 131,
-            95, 55, 120, 71, 115, 47, 209, 22, 134, 203, 94, 73, 66, 235, 110, 101, 186, 123, 202,
-            4, 224, 233, 186, 74, 109, 148, 102, 72, 128, 248, 0, 3, 184, 161, 79, 9, 107, 220, 44,
-            205, 166, 55, 236, 188, 2, 22, 38, 13, 69, 240, 65, 15, 191, 53, 40, 254, 51, 103, 51,
-            93, 132, 128, 104, 54, 127, 239, 128, 10, 241, 52, 255, 26, 138, 63, 131, 94, 225, 64,
-            208, 200, 200, 250, 249, 46, 29, 121, 191, 164, 210, 189, 200, 118, 57, 114, 29, 2,
-            128, 64, 73, 128, 118, 233, 240, 87, 66, 188, 104, 191, 64, 72, 156, 81, 244, 32, 108,
-            84, 157, 89, 125, 9, 107, 149, 191, 160, 156, 167, 89, 94, 100, 227, 71, 116, 128, 83,
-            213, 9, 42, 191, 27, 146, 25, 243, 226, 171, 21, 120, 233, 143, 71, 24, 54, 20, 119,
-            168, 105, 26, 112, 107, 246, 174, 177, 100, 253, 66, 50, 128, 174, 182, 178, 44, 24,
-            138, 206, 25, 57, 104, 138, 33, 166, 87, 33, 149, 99, 236, 230, 160, 37, 166, 217, 145,
-            110, 11, 49, 178, 149, 44, 103, 121, 128, 63, 253, 131, 42, 186, 207, 80, 128, 98, 7,
-            254, 173, 155, 183, 105, 61, 153, 115, 223, 145, 67, 164, 66, 122, 249, 67, 62, 113,
-            227, 171, 16, 72, 161, 2, 128, 64, 149, 128, 137, 116, 180, 121, 17, 15, 168, 9, 247,
-            151, 187, 167, 141, 105, 171, 32, 31, 22, 160, 156, 156, 5, 157, 37, 221, 131, 57, 42,
-            33, 55, 189, 77, 128, 164, 232, 207, 131, 71, 2, 246, 209, 245, 245, 133, 56, 22, 85,
-            159, 184, 148, 109, 95, 102, 199, 228, 3, 92, 231, 206, 87, 195, 14, 19, 212, 217, 128,
-            77, 41, 72, 124, 154, 181, 220, 141, 140, 185, 5, 99, 103, 252, 77, 207, 252, 72, 13,
-            43, 88, 55, 64, 198, 176, 135, 38, 137, 199, 92, 90, 16, 128, 177, 178, 214, 86, 169,
-            3, 107, 1, 99, 224, 152, 108, 69, 166, 222, 2, 16, 187, 200, 12, 222, 145, 7, 37, 101,
-            195, 201, 243, 84, 59, 10, 100, 128, 208, 3, 203, 87, 84, 1, 192, 115, 180, 255, 31,
-            248, 210, 180, 48, 0, 155, 83, 253, 54, 145, 239, 171, 252, 184, 192, 185, 168, 198,
-            201, 118, 58, 21, 1, 128, 65, 0, 128, 151, 238, 242, 39, 94, 156, 23, 65, 91, 239, 171,
-            68, 188, 20, 45, 223, 40, 17, 161, 225, 35, 153, 9, 245, 239, 58, 223, 186, 46, 13,
-            120, 186, 128, 38, 78, 69, 11, 202, 18, 109, 254, 229, 0, 87, 86, 122, 198, 145, 28,
-            85, 213, 167, 123, 183, 155, 20, 67, 139, 185, 177, 151, 100, 163, 100, 2, 29, 2, 128,
-            65, 17, 128, 251, 138, 91, 126, 148, 53, 88, 151, 109, 138, 239, 57, 254, 191, 28, 52,
-            247, 81, 102, 18, 207, 35, 121, 18, 54, 30, 114, 107, 27, 63, 83, 65, 128, 253, 74, 2,
-            155, 252, 198, 51, 226, 160, 67, 16, 169, 4, 64, 112, 25, 162, 166, 75, 138, 232, 112,
-            213, 173, 244, 186, 117, 3, 134, 160, 105, 46, 128, 236, 35, 106, 124, 110, 203, 113,
-            232, 134, 155, 157, 67, 5, 109, 37, 27, 206, 27, 13, 36, 66, 223, 243, 138, 97, 208, 3,
-            40, 121, 211, 221, 49, 128, 6, 85, 58, 60, 75, 131, 112, 255, 39, 134, 249, 52, 19,
-            228, 77, 87, 26, 183, 247, 239, 187, 228, 124, 200, 183, 180, 190, 192, 177, 147, 102,
-            220, 29, 2, 128, 68, 33, 128, 83, 194, 62, 74, 131, 206, 135, 14, 90, 255, 180, 167,
-            214, 153, 60, 30, 8, 176, 185, 87, 124, 94, 236, 100, 111, 111, 55, 103, 229, 110, 251,
-            245, 128, 189, 243, 1, 183, 150, 187, 180, 158, 41, 71, 15, 168, 111, 64, 63, 85, 4,
-            30, 69, 252, 134, 233, 150, 127, 181, 17, 12, 60, 41, 72, 91, 126, 128, 28, 233, 130,
-            156, 168, 203, 1, 42, 60, 141, 228, 70, 244, 68, 205, 196, 130, 79, 153, 126, 11, 132,
-            158, 43, 91, 176, 186, 129, 115, 170, 243, 250, 128, 33, 27, 88, 247, 200, 87, 21, 43,
-            30, 191, 81, 157, 253, 201, 196, 208, 11, 78, 22, 126, 84, 245, 60, 91, 248, 61, 106,
-            84, 58, 128, 25, 119, 29, 2, 128, 68, 40, 128, 99, 252, 106, 144, 221, 238, 225, 7, 45,
-            183, 22, 125, 147, 84, 172, 254, 102, 192, 125, 73, 165, 138, 46, 142, 46, 174, 251,
-            196, 145, 141, 157, 161, 128, 111, 231, 160, 54, 93, 206, 12, 87, 106, 132, 180, 191,
-            233, 246, 213, 58, 32, 77, 125, 69, 12, 46, 41, 239, 198, 7, 126, 124, 69, 95, 83, 235,
-            128, 110, 87, 5, 52, 135, 77, 138, 144, 173, 17, 56, 243, 170, 8, 29, 80, 119, 208,
-            226, 125, 4, 57, 151, 236, 85, 202, 176, 68, 152, 103, 128, 31, 128, 23, 119, 104, 161,
-            101, 168, 79, 91, 126, 254, 195, 103, 156, 4, 9, 135, 202, 98, 153, 34, 0, 128, 62,
-            232, 28, 0, 131, 149, 77, 20, 235, 183, 37, 3, 128, 69, 138, 128, 21, 237, 146, 124, 6,
-            237, 193, 168, 109, 138, 160, 181, 135, 199, 125, 64, 35, 199, 136, 219, 14, 225, 240,
-            73, 89, 62, 82, 191, 174, 9, 14, 115, 128, 157, 239, 194, 239, 131, 84, 1, 216, 188,
-            149, 20, 251, 147, 123, 105, 147, 144, 192, 215, 227, 188, 35, 122, 186, 14, 196, 118,
-            128, 61, 215, 122, 14, 128, 152, 170, 70, 218, 38, 241, 185, 229, 105, 169, 133, 71,
-            217, 31, 120, 143, 153, 197, 76, 77, 20, 97, 128, 249, 222, 139, 211, 237, 195, 221,
-            101, 255, 128, 42, 56, 242, 58, 240, 194, 54, 9, 216, 18, 96, 212, 132, 145, 243, 198,
-            170, 137, 154, 199, 224, 88, 232, 144, 214, 7, 189, 165, 140, 111, 85, 58, 128, 164,
-            240, 241, 149, 45, 60, 126, 243, 135, 187, 165, 160, 152, 222, 134, 224, 241, 208, 208,
-            100, 208, 23, 133, 199, 235, 32, 225, 155, 249, 237, 26, 90, 128, 253, 119, 116, 133,
-            194, 157, 90, 52, 12, 104, 80, 2, 238, 233, 72, 165, 234, 93, 74, 96, 190, 140, 136,
-            26, 169, 21, 221, 78, 81, 30, 123, 216, 153, 1, 128, 70, 0, 128, 36, 179, 75, 10, 84,
-            142, 195, 162, 126, 246, 61, 132, 34, 61, 125, 215, 36, 232, 151, 254, 108, 53, 176,
-            21, 152, 153, 60, 229, 140, 247, 187, 95, 128, 220, 76, 135, 61, 210, 180, 135, 181,
-            25, 223, 86, 11, 17, 31, 28, 183, 88, 234, 152, 175, 180, 64, 43, 55, 11, 233, 210, 20,
-            56, 92, 254, 56, 128, 29, 167, 213, 132, 70, 107, 195, 205, 83, 71, 125, 150, 150, 143,
-            115, 97, 160, 254, 246, 231, 13, 141, 88, 196, 69, 177, 150, 232, 49, 178, 154, 28,
-            153, 1, 128, 72, 2, 128, 75, 21, 242, 153, 124, 116, 224, 132, 116, 44, 98, 110, 31,
-            18, 14, 137, 247, 174, 170, 28, 63, 153, 177, 94, 52, 196, 235, 219, 43, 9, 19, 179,
-            128, 225, 252, 213, 51, 90, 66, 104, 126, 29, 207, 43, 124, 0, 62, 133, 197, 84, 131,
-            52, 231, 139, 18, 189, 172, 203, 184, 177, 86, 237, 189, 158, 222, 128, 1, 8, 211, 242,
-            151, 215, 97, 3, 214, 26, 149, 224, 146, 195, 216, 28, 96, 47, 53, 169, 60, 76, 182,
-            127, 59, 232, 14, 216, 54, 210, 215, 163, 29, 2, 128, 72, 33, 128, 193, 124, 81, 146,
-            192, 248, 78, 86, 214, 108, 255, 81, 196, 137, 52, 195, 222, 50, 20, 145, 27, 30, 111,
-            196, 186, 165, 225, 31, 209, 160, 25, 106, 128, 217, 51, 253, 224, 111, 227, 122, 253,
-            40, 197, 40, 122, 125, 61, 16, 35, 220, 226, 112, 205, 24, 108, 155, 167, 157, 35, 116,
-            91, 11, 12, 36, 75, 128, 205, 183, 208, 96, 168, 190, 80, 223, 233, 84, 250, 27, 49,
-            190, 157, 112, 158, 116, 233, 104, 186, 175, 39, 188, 250, 141, 166, 95, 190, 57, 37,
-            159, 128, 0, 174, 224, 95, 31, 225, 63, 90, 214, 63, 220, 156, 119, 255, 20, 24, 116,
-            217, 90, 249, 114, 57, 230, 39, 19, 151, 223, 85, 113, 76, 71, 167, 29, 2, 128, 74, 4,
-            128, 13, 246, 159, 94, 146, 188, 191, 215, 193, 136, 42, 21, 92, 222, 123, 85, 52, 147,
-            75, 123, 122, 12, 109, 45, 111, 54, 127, 131, 186, 247, 15, 124, 128, 218, 213, 240,
-            74, 192, 213, 6, 126, 73, 13, 242, 216, 52, 26, 212, 191, 68, 201, 24, 67, 186, 147,
-            17, 105, 129, 106, 68, 152, 166, 197, 194, 251, 128, 190, 13, 223, 15, 36, 16, 241,
-            209, 83, 233, 2, 211, 44, 142, 207, 186, 222, 232, 0, 101, 179, 74, 210, 76, 25, 115,
-            226, 38, 226, 45, 239, 221, 128, 191, 226, 131, 155, 48, 119, 31, 71, 39, 17, 169, 64,
-            240, 88, 67, 214, 53, 208, 25, 19, 17, 147, 119, 244, 65, 34, 13, 64, 164, 227, 46, 64,
-            169, 3, 128, 75, 7, 128, 145, 110, 172, 5, 89, 221, 130, 69, 125, 25, 234, 15, 85, 197,
-            156, 128, 218, 115, 69, 3, 232, 104, 172, 175, 142, 53, 146, 54, 181, 19, 116, 213,
-            128, 103, 182, 169, 228, 112, 90, 85, 255, 178, 80, 34, 187, 5, 198, 192, 30, 213, 144,
-            26, 190, 232, 79, 235, 132, 70, 38, 171, 22, 195, 104, 71, 27, 128, 7, 121, 148, 102,
-            63, 139, 161, 237, 70, 110, 134, 179, 52, 15, 185, 167, 122, 244, 105, 213, 114, 85,
-            80, 164, 181, 96, 112, 97, 252, 10, 232, 20, 128, 104, 49, 107, 69, 172, 6, 243, 148,
-            42, 60, 217, 168, 232, 176, 114, 117, 54, 103, 11, 113, 211, 217, 81, 234, 22, 120, 47,
-            156, 0, 68, 181, 127, 128, 158, 96, 88, 99, 235, 203, 8, 3, 76, 96, 153, 126, 103, 148,
-            180, 65, 40, 255, 159, 37, 103, 36, 253, 239, 51, 46, 253, 220, 224, 5, 224, 89, 128,
-            230, 132, 84, 213, 242, 170, 185, 74, 49, 148, 95, 99, 204, 116, 187, 37, 86, 62, 19,
-            135, 89, 107, 19, 87, 217, 16, 26, 224, 40, 112, 15, 80, 128, 133, 44, 185, 159, 53,
-            212, 219, 105, 4, 209, 106, 73, 249, 141, 172, 187, 5, 209, 180, 161, 247, 194, 189,
-            42, 126, 119, 55, 202, 106, 19, 47, 224, 161, 2, 128, 76, 130, 128, 146, 216, 85, 172,
-            53, 95, 184, 44, 165, 48, 3, 165, 55, 153, 168, 220, 14, 54, 130, 172, 142, 144, 61,
-            110, 38, 141, 26, 167, 53, 148, 178, 164, 128, 29, 90, 113, 38, 192, 68, 23, 9, 53,
-            251, 180, 109, 77, 228, 61, 144, 99, 28, 87, 91, 91, 235, 175, 112, 122, 63, 140, 3,
-            192, 73, 218, 191, 128, 243, 160, 153, 34, 204, 102, 190, 222, 97, 60, 72, 129, 52,
-            220, 225, 177, 27, 8, 115, 1, 141, 68, 50, 180, 251, 79, 48, 189, 160, 119, 167, 181,
-            128, 86, 176, 169, 22, 117, 91, 22, 2, 162, 99, 190, 136, 83, 26, 27, 130, 123, 223,
-            255, 61, 109, 54, 129, 160, 14, 228, 84, 242, 192, 9, 12, 61, 128, 179, 85, 60, 129,
-            65, 78, 40, 12, 162, 56, 110, 136, 78, 120, 9, 42, 181, 80, 15, 211, 18, 223, 106, 120,
-            41, 162, 110, 238, 196, 70, 37, 163, 21, 1, 128, 80, 0, 128, 107, 88, 112, 25, 157, 32,
-            125, 144, 32, 67, 255, 69, 28, 220, 132, 204, 231, 183, 4, 34, 154, 221, 244, 215, 109,
-            255, 156, 242, 81, 200, 43, 129, 128, 228, 213, 237, 88, 101, 114, 217, 251, 86, 167,
-            137, 0, 169, 130, 6, 161, 135, 148, 86, 67, 25, 73, 80, 250, 131, 221, 99, 34, 188,
-            107, 145, 143, 21, 1, 128, 80, 0, 128, 120, 211, 117, 222, 215, 128, 174, 255, 43, 34,
-            130, 2, 13, 75, 76, 30, 106, 110, 163, 6, 154, 189, 243, 94, 73, 65, 28, 122, 105, 63,
-            255, 168, 128, 134, 141, 52, 47, 121, 69, 151, 197, 62, 106, 164, 120, 99, 213, 105,
-            185, 47, 203, 47, 174, 161, 237, 39, 163, 62, 140, 173, 51, 25, 27, 233, 210, 153, 1,
-            128, 80, 1, 128, 13, 198, 123, 182, 240, 208, 62, 98, 30, 225, 230, 120, 35, 210, 138,
-            67, 191, 223, 104, 118, 201, 90, 103, 182, 120, 105, 127, 142, 126, 166, 241, 17, 128,
-            42, 250, 248, 73, 153, 213, 27, 175, 214, 97, 245, 179, 80, 37, 243, 12, 241, 232, 213,
-            106, 230, 251, 2, 228, 225, 143, 177, 76, 173, 9, 36, 207, 128, 191, 102, 74, 223, 38,
-            1, 233, 115, 227, 88, 4, 129, 138, 169, 23, 122, 15, 99, 117, 144, 121, 159, 6, 31,
-            131, 178, 199, 41, 144, 34, 223, 79, 29, 2, 128, 80, 48, 128, 182, 14, 111, 242, 214,
-            91, 30, 157, 227, 73, 92, 195, 119, 113, 233, 169, 10, 66, 164, 64, 243, 210, 87, 89,
-            145, 164, 97, 51, 20, 236, 196, 66, 128, 227, 10, 155, 115, 84, 165, 191, 98, 59, 174,
-            235, 24, 236, 47, 57, 192, 94, 94, 142, 48, 221, 163, 125, 203, 241, 223, 30, 70, 234,
-            26, 21, 15, 128, 223, 53, 9, 139, 250, 211, 40, 196, 227, 92, 114, 254, 97, 71, 40, 13,
-            183, 138, 12, 146, 121, 184, 223, 206, 247, 23, 43, 250, 183, 183, 30, 3, 128, 185, 64,
-            10, 91, 90, 110, 236, 238, 108, 84, 240, 23, 129, 35, 21, 231, 173, 49, 229, 222, 6,
-            158, 104, 135, 195, 42, 164, 8, 19, 249, 226, 188, 161, 2, 128, 80, 100, 128, 39, 63,
-            234, 110, 14, 74, 205, 56, 71, 30, 146, 173, 17, 255, 71, 194, 53, 2, 40, 246, 49, 3,
-            200, 87, 60, 12, 174, 3, 173, 232, 91, 75, 128, 106, 229, 188, 61, 215, 82, 61, 241,
-            83, 228, 209, 90, 90, 108, 97, 188, 193, 19, 51, 127, 22, 65, 217, 243, 130, 144, 99,
-            124, 11, 64, 50, 154, 128, 165, 225, 155, 46, 25, 48, 186, 139, 231, 131, 131, 104,
-            138, 213, 226, 12, 85, 196, 56, 188, 172, 219, 66, 227, 7, 184, 122, 206, 244, 217, 95,
-            203, 128, 226, 61, 190, 51, 27, 20, 129, 141, 145, 191, 146, 104, 112, 127, 14, 243,
-            219, 240, 92, 28, 240, 164, 140, 202, 177, 83, 242, 216, 184, 254, 187, 49, 128, 45,
-            103, 242, 215, 209, 38, 239, 115, 143, 125, 133, 0, 242, 245, 230, 151, 97, 127, 198,
-            197, 207, 68, 181, 189, 235, 243, 2, 119, 147, 29, 232, 236, 29, 2, 128, 88, 4, 128,
-            209, 166, 194, 156, 74, 63, 96, 67, 124, 17, 177, 209, 242, 253, 158, 94, 191, 33, 82,
-            28, 186, 39, 23, 90, 47, 128, 137, 29, 189, 80, 115, 154, 128, 144, 220, 234, 16, 35,
-            73, 16, 19, 39, 193, 127, 112, 222, 83, 124, 69, 15, 103, 134, 152, 56, 241, 93, 250,
-            110, 212, 148, 131, 168, 93, 87, 68, 128, 194, 214, 231, 38, 178, 228, 88, 96, 73, 3,
-            20, 163, 187, 185, 36, 64, 29, 94, 212, 175, 134, 26, 180, 189, 215, 80, 242, 201, 118,
-            223, 175, 104, 128, 147, 127, 179, 31, 215, 127, 43, 166, 124, 197, 176, 96, 121, 3,
-            30, 194, 29, 152, 42, 63, 132, 242, 56, 29, 177, 2, 59, 157, 106, 100, 59, 88, 161, 2,
-            128, 88, 36, 128, 127, 75, 172, 70, 230, 162, 15, 220, 166, 18, 251, 117, 175, 189, 33,
-            18, 88, 194, 133, 240, 56, 6, 24, 185, 69, 100, 49, 220, 246, 137, 122, 161, 128, 33,
-            182, 203, 76, 50, 195, 54, 201, 37, 15, 171, 91, 195, 125, 94, 45, 51, 122, 82, 195,
-            41, 61, 248, 7, 225, 173, 209, 153, 82, 138, 100, 215, 128, 176, 94, 194, 60, 186, 120,
-            95, 101, 113, 242, 194, 253, 120, 187, 6, 194, 28, 107, 69, 149, 19, 141, 111, 199,
-            191, 80, 110, 102, 221, 26, 96, 14, 128, 19, 96, 232, 153, 96, 228, 173, 151, 114, 115,
-            151, 162, 72, 137, 252, 115, 186, 39, 117, 166, 201, 187, 249, 84, 133, 20, 176, 103,
-            174, 135, 186, 26, 128, 220, 71, 250, 95, 82, 92, 121, 191, 47, 232, 57, 35, 66, 122,
-            245, 170, 83, 13, 132, 172, 11, 94, 36, 85, 74, 161, 24, 205, 99, 91, 206, 174, 153, 1,
-            128, 96, 16, 128, 56, 218, 99, 176, 18, 235, 65, 126, 233, 235, 222, 45, 149, 159, 64,
-            234, 244, 255, 49, 136, 164, 47, 42, 124, 40, 153, 108, 36, 229, 95, 179, 183, 128, 38,
-            61, 43, 77, 133, 2, 144, 166, 102, 115, 46, 46, 194, 103, 196, 133, 223, 102, 165, 162,
-            148, 58, 228, 59, 40, 237, 253, 201, 3, 29, 192, 115, 128, 204, 244, 29, 1, 131, 82,
-            190, 157, 130, 204, 191, 47, 234, 243, 96, 63, 83, 214, 24, 202, 241, 204, 211, 229,
-            234, 68, 87, 49, 104, 226, 45, 151, 29, 2, 128, 96, 36, 128, 170, 19, 237, 40, 151,
-            134, 247, 214, 15, 157, 156, 46, 172, 109, 141, 125, 63, 58, 136, 66, 223, 196, 36,
-            196, 175, 34, 239, 217, 29, 115, 56, 72, 128, 185, 100, 226, 115, 142, 44, 10, 132, 15,
-            59, 230, 226, 19, 111, 66, 60, 196, 190, 70, 156, 227, 71, 97, 223, 245, 85, 205, 76,
-            33, 164, 22, 30, 128, 215, 148, 100, 192, 28, 203, 102, 174, 139, 174, 149, 16, 0, 249,
-            105, 247, 33, 122, 204, 158, 214, 21, 132, 197, 47, 132, 253, 128, 27, 242, 51, 102,
-            128, 137, 162, 236, 191, 232, 64, 249, 240, 59, 223, 128, 146, 22, 175, 88, 156, 251,
-            36, 151, 164, 104, 254, 0, 126, 188, 77, 161, 167, 129, 91, 119, 24, 29, 2, 128, 96,
-            130, 128, 63, 84, 138, 205, 182, 235, 245, 13, 27, 254, 60, 112, 15, 30, 127, 168, 126,
-            24, 75, 241, 44, 158, 208, 230, 96, 50, 78, 189, 183, 119, 81, 77, 128, 174, 90, 93,
-            254, 75, 238, 186, 25, 237, 176, 237, 130, 200, 73, 137, 149, 16, 88, 153, 224, 29,
-            226, 25, 159, 169, 225, 15, 251, 3, 77, 51, 22, 128, 233, 182, 149, 136, 47, 250, 77,
-            62, 117, 85, 241, 152, 208, 194, 239, 55, 83, 33, 141, 72, 181, 35, 222, 189, 14, 194,
-            192, 219, 105, 59, 243, 174, 128, 100, 77, 122, 64, 252, 58, 156, 218, 38, 73, 211,
-            226, 150, 159, 204, 211, 129, 144, 128, 174, 221, 25, 47, 24, 246, 189, 149, 225, 82,
-            193, 27, 37, 29, 2, 128, 96, 130, 128, 65, 236, 157, 146, 30, 46, 78, 18, 38, 217, 5,
-            201, 30, 25, 131, 172, 6, 27, 132, 10, 140, 203, 39, 119, 47, 170, 234, 218, 117, 196,
-            230, 182, 128, 132, 95, 199, 119, 24, 229, 151, 79, 229, 111, 139, 76, 162, 21, 121,
-            222, 35, 225, 249, 122, 7, 120, 147, 39, 224, 166, 122, 128, 234, 34, 238, 80, 128,
-            107, 223, 98, 172, 80, 39, 232, 188, 107, 50, 4, 103, 163, 181, 209, 122, 92, 137, 70,
-            31, 44, 41, 148, 91, 169, 117, 182, 41, 211, 89, 98, 91, 128, 228, 235, 89, 50, 120,
-            146, 61, 144, 192, 28, 171, 80, 105, 55, 25, 188, 150, 251, 250, 53, 158, 250, 8, 72,
-            132, 170, 242, 111, 136, 17, 129, 175, 161, 2, 128, 102, 16, 128, 195, 24, 127, 114,
-            196, 12, 212, 133, 156, 131, 68, 52, 186, 121, 245, 206, 206, 253, 93, 47, 243, 237, 7,
-            199, 122, 136, 203, 44, 208, 55, 57, 177, 128, 230, 18, 203, 148, 54, 243, 240, 178,
-            125, 170, 196, 123, 165, 32, 172, 45, 236, 201, 207, 215, 183, 37, 141, 254, 104, 233,
-            234, 0, 155, 37, 13, 169, 128, 200, 185, 135, 124, 237, 203, 8, 45, 45, 37, 118, 135,
-            163, 60, 194, 91, 75, 94, 167, 158, 142, 103, 98, 248, 155, 98, 74, 228, 193, 32, 221,
-            222, 128, 238, 185, 90, 178, 116, 31, 62, 118, 32, 76, 87, 75, 94, 114, 90, 248, 222,
-            176, 208, 121, 32, 209, 24, 215, 58, 179, 26, 249, 171, 71, 183, 165, 128, 81, 68, 255,
-            76, 195, 242, 230, 48, 55, 52, 67, 148, 57, 201, 16, 188, 239, 1, 44, 174, 46, 147, 53,
-            61, 113, 118, 171, 52, 190, 156, 254, 45, 37, 3, 128, 102, 66, 128, 132, 82, 100, 77,
-            235, 27, 39, 120, 123, 40, 201, 93, 123, 227, 62, 248, 136, 240, 219, 20, 63, 16, 104,
-            70, 130, 234, 50, 82, 86, 243, 88, 21, 128, 204, 212, 233, 173, 184, 104, 146, 165, 37,
-            213, 40, 222, 108, 233, 67, 76, 227, 154, 166, 201, 223, 135, 56, 218, 37, 87, 100, 38,
-            181, 231, 195, 45, 128, 212, 46, 152, 91, 217, 127, 246, 31, 122, 123, 99, 174, 5, 36,
-            201, 160, 140, 49, 188, 171, 173, 0, 10, 91, 86, 219, 132, 235, 151, 142, 102, 204,
-            128, 118, 189, 211, 100, 113, 55, 100, 231, 101, 191, 115, 15, 6, 14, 96, 62, 173, 203,
-            86, 234, 171, 90, 101, 205, 66, 200, 154, 87, 253, 46, 45, 90, 128, 106, 193, 144, 36,
-            121, 21, 180, 146, 57, 199, 127, 143, 105, 167, 163, 127, 121, 131, 231, 104, 46, 138,
-            233, 239, 54, 22, 141, 112, 157, 107, 231, 102, 128, 195, 123, 74, 111, 154, 33, 223,
-            73, 42, 218, 188, 4, 184, 253, 28, 231, 56, 247, 88, 157, 38, 98, 51, 51, 56, 229, 0,
-            246, 15, 222, 213, 126, 161, 2, 128, 104, 192, 128, 202, 155, 51, 157, 153, 27, 36,
-            132, 85, 87, 192, 105, 86, 104, 153, 154, 153, 17, 228, 6, 137, 88, 251, 2, 108, 48,
-            216, 253, 179, 189, 218, 159, 128, 116, 62, 160, 126, 231, 21, 233, 219, 168, 57, 155,
-            51, 226, 34, 141, 57, 216, 56, 159, 89, 157, 69, 100, 82, 187, 237, 47, 95, 233, 132,
-            73, 145, 128, 244, 20, 109, 23, 228, 245, 234, 61, 162, 244, 158, 15, 144, 56, 150, 76,
-            226, 115, 40, 74, 172, 103, 221, 84, 175, 94, 241, 93, 68, 92, 167, 175, 128, 155, 162,
-            118, 102, 232, 199, 53, 180, 102, 234, 12, 59, 5, 54, 118, 86, 206, 23, 51, 16, 102,
-            241, 57, 220, 97, 93, 84, 126, 40, 210, 152, 178, 128, 67, 137, 174, 4, 185, 81, 164,
-            48, 49, 190, 177, 2, 83, 176, 187, 172, 137, 87, 239, 145, 222, 236, 13, 129, 161, 168,
-            75, 180, 49, 209, 3, 206, 161, 2, 128, 106, 1, 128, 110, 165, 223, 156, 143, 213, 203,
-            224, 42, 244, 228, 19, 10, 114, 76, 45, 149, 168, 87, 47, 127, 240, 118, 170, 133, 42,
-            11, 151, 103, 178, 87, 145, 128, 35, 60, 203, 97, 0, 2, 74, 33, 42, 131, 152, 25, 104,
-            95, 163, 10, 219, 89, 227, 169, 39, 111, 223, 55, 167, 157, 168, 234, 119, 255, 172,
-            253, 128, 118, 228, 125, 133, 59, 107, 99, 89, 137, 56, 118, 185, 11, 71, 188, 233,
-            216, 202, 165, 69, 56, 86, 150, 15, 221, 100, 17, 114, 95, 36, 194, 254, 128, 222, 246,
-            236, 115, 201, 246, 46, 78, 17, 4, 224, 246, 219, 242, 153, 0, 90, 194, 206, 145, 17,
-            121, 123, 12, 202, 95, 77, 206, 122, 71, 113, 53, 128, 131, 171, 66, 236, 52, 183, 12,
-            251, 231, 12, 145, 173, 209, 229, 150, 9, 13, 92, 245, 214, 83, 128, 118, 31, 199, 44,
-            59, 235, 197, 159, 65, 239, 37, 3, 128, 106, 24, 128, 164, 15, 80, 80, 116, 195, 129,
-            192, 66, 215, 178, 238, 140, 166, 234, 84, 119, 116, 86, 218, 7, 108, 46, 230, 41, 152,
-            152, 196, 21, 203, 241, 194, 128, 71, 155, 58, 15, 6, 20, 206, 59, 220, 136, 11, 212,
-            160, 136, 29, 194, 105, 154, 198, 90, 72, 61, 221, 76, 50, 22, 34, 205, 118, 38, 27,
-            179, 128, 58, 116, 136, 151, 96, 35, 175, 223, 187, 192, 6, 93, 58, 64, 113, 232, 202,
-            155, 115, 154, 236, 36, 237, 238, 154, 65, 148, 23, 205, 208, 113, 89, 128, 216, 20,
-            108, 132, 135, 106, 210, 140, 169, 154, 204, 197, 84, 210, 195, 80, 118, 102, 82, 52,
-            188, 215, 225, 245, 33, 78, 85, 82, 34, 141, 164, 156, 128, 189, 14, 200, 17, 213, 142,
-            95, 135, 126, 25, 94, 74, 150, 84, 30, 177, 42, 80, 114, 223, 200, 148, 126, 188, 54,
-            203, 54, 70, 245, 107, 204, 207, 128, 13, 47, 141, 15, 23, 153, 183, 11, 166, 236, 126,
-            144, 247, 158, 139, 197, 236, 45, 35, 62, 222, 178, 90, 230, 243, 208, 225, 140, 14,
-            58, 82, 225, 29, 2, 128, 112, 8, 128, 89, 243, 233, 147, 3, 26, 120, 101, 149, 208,
-            162, 40, 17, 17, 157, 200, 95, 198, 247, 169, 13, 28, 77, 100, 237, 207, 40, 90, 231,
-            190, 24, 244, 128, 189, 211, 23, 134, 67, 0, 237, 115, 70, 224, 129, 36, 239, 170, 9,
-            59, 177, 252, 9, 245, 159, 254, 117, 82, 18, 37, 151, 20, 136, 229, 65, 104, 128, 113,
-            225, 194, 204, 123, 135, 77, 161, 185, 179, 148, 12, 19, 16, 255, 110, 100, 106, 135,
-            8, 154, 60, 207, 185, 105, 46, 209, 146, 180, 173, 187, 187, 128, 241, 136, 113, 168,
-            218, 191, 117, 178, 239, 227, 134, 71, 107, 108, 99, 11, 108, 59, 71, 45, 57, 150, 1,
-            223, 221, 217, 245, 247, 179, 107, 44, 65, 21, 1, 128, 128, 4, 128, 106, 30, 175, 29,
-            234, 3, 224, 11, 238, 249, 213, 242, 149, 156, 241, 17, 12, 8, 111, 102, 113, 183, 189,
-            107, 117, 92, 243, 49, 119, 100, 157, 2, 128, 47, 31, 113, 138, 162, 38, 45, 121, 35,
-            66, 92, 66, 123, 14, 60, 7, 56, 7, 18, 251, 83, 132, 185, 133, 234, 142, 23, 134, 255,
-            226, 193, 226, 21, 1, 128, 128, 16, 128, 200, 128, 16, 212, 232, 182, 216, 97, 102,
-            108, 226, 156, 85, 54, 165, 56, 13, 145, 228, 9, 120, 216, 234, 211, 208, 138, 102, 58,
-            245, 99, 242, 48, 128, 53, 156, 240, 243, 197, 208, 226, 16, 222, 145, 176, 86, 164,
-            194, 122, 57, 119, 44, 189, 200, 75, 45, 194, 224, 207, 214, 116, 215, 31, 131, 107,
-            113, 153, 1, 128, 128, 40, 128, 211, 237, 238, 187, 159, 98, 174, 107, 152, 87, 124,
-            58, 74, 72, 19, 3, 72, 68, 154, 175, 195, 170, 178, 255, 126, 122, 155, 17, 100, 188,
-            195, 184, 128, 80, 193, 104, 32, 118, 12, 123, 135, 92, 13, 104, 186, 8, 160, 145, 243,
-            97, 216, 39, 138, 193, 92, 180, 216, 180, 85, 7, 83, 195, 147, 188, 69, 128, 119, 241,
-            145, 174, 141, 253, 128, 131, 21, 142, 98, 30, 65, 77, 18, 136, 127, 195, 50, 85, 82,
-            180, 229, 185, 5, 241, 129, 66, 61, 245, 150, 14, 153, 1, 128, 128, 132, 128, 79, 124,
-            198, 204, 28, 39, 172, 232, 157, 57, 219, 58, 53, 1, 223, 177, 19, 158, 123, 149, 60,
-            207, 97, 87, 25, 21, 135, 66, 5, 40, 251, 131, 128, 107, 240, 84, 29, 114, 39, 127,
-            111, 235, 204, 128, 34, 231, 136, 195, 194, 31, 147, 84, 195, 234, 186, 185, 181, 215,
-            84, 9, 196, 253, 2, 102, 197, 128, 213, 51, 150, 141, 114, 57, 73, 39, 179, 229, 35,
-            125, 150, 118, 203, 98, 118, 212, 99, 239, 27, 187, 219, 155, 60, 243, 104, 63, 166,
-            75, 23, 176, 29, 2, 128, 128, 137, 128, 135, 108, 218, 121, 214, 100, 146, 113, 109,
-            138, 148, 84, 8, 99, 132, 150, 138, 215, 66, 174, 67, 108, 18, 143, 83, 53, 103, 47,
-            142, 79, 90, 114, 128, 58, 242, 189, 112, 214, 121, 145, 124, 17, 215, 84, 35, 45, 238,
-            218, 156, 148, 76, 253, 178, 249, 156, 48, 120, 27, 241, 110, 111, 227, 128, 100, 25,
-            128, 231, 15, 152, 12, 119, 145, 120, 170, 223, 45, 159, 7, 224, 255, 161, 14, 126, 4,
-            187, 154, 127, 81, 229, 57, 25, 111, 254, 188, 45, 141, 71, 226, 128, 92, 35, 71, 228,
-            144, 180, 211, 27, 31, 75, 118, 2, 222, 1, 189, 24, 118, 235, 79, 161, 171, 163, 148,
-            87, 112, 122, 60, 141, 151, 94, 94, 110, 161, 2, 128, 128, 197, 128, 68, 170, 34, 114,
-            233, 186, 96, 89, 12, 41, 65, 99, 203, 12, 166, 182, 113, 224, 158, 118, 45, 224, 199,
-            47, 222, 115, 129, 57, 74, 103, 109, 148, 128, 228, 5, 87, 70, 180, 113, 255, 19, 83,
-            242, 155, 145, 165, 39, 198, 17, 47, 15, 206, 91, 119, 121, 147, 91, 184, 199, 189, 52,
-            12, 89, 30, 141, 128, 2, 133, 57, 91, 19, 131, 13, 139, 89, 103, 128, 214, 94, 83, 60,
-            54, 166, 86, 83, 167, 104, 115, 166, 226, 238, 47, 114, 168, 19, 137, 209, 190, 128,
-            171, 111, 148, 206, 245, 10, 9, 30, 64, 47, 205, 179, 232, 110, 86, 245, 150, 148, 97,
-            254, 1, 175, 232, 236, 145, 234, 166, 112, 221, 13, 123, 211, 128, 20, 134, 200, 71,
-            105, 242, 216, 136, 33, 107, 129, 188, 203, 22, 63, 79, 43, 219, 148, 54, 213, 97, 76,
-            1, 70, 250, 169, 101, 93, 85, 236, 200, 21, 1, 128, 129, 0, 128, 152, 89, 99, 18, 66,
-            175, 75, 13, 163, 140, 151, 70, 236, 61, 205, 137, 183, 222, 79, 210, 149, 115, 235,
-            220, 118, 86, 21, 137, 27, 177, 79, 98, 128, 25, 134, 98, 211, 142, 125, 26, 23, 104,
-            200, 112, 213, 67, 231, 48, 153, 68, 83, 72, 213, 247, 10, 233, 132, 107, 90, 5, 104,
-            110, 36, 183, 38, 153, 1, 128, 129, 32, 128, 38, 10, 87, 159, 192, 34, 81, 171, 134,
-            249, 97, 51, 92, 240, 167, 48, 18, 228, 114, 153, 153, 84, 110, 217, 193, 235, 96, 242,
-            240, 122, 135, 88, 128, 18, 23, 137, 236, 58, 73, 221, 204, 242, 214, 175, 205, 238,
-            169, 178, 253, 108, 93, 51, 17, 82, 237, 126, 250, 105, 238, 209, 74, 57, 171, 74, 119,
-            128, 157, 54, 204, 242, 157, 246, 97, 3, 218, 99, 248, 138, 197, 252, 59, 250, 26, 3,
-            109, 42, 38, 198, 196, 127, 135, 217, 14, 243, 98, 152, 115, 68, 153, 1, 128, 129, 32,
-            128, 123, 100, 135, 187, 137, 113, 69, 126, 17, 131, 234, 238, 109, 111, 195, 108, 102,
-            68, 162, 51, 203, 170, 206, 162, 206, 21, 56, 80, 240, 118, 108, 166, 128, 44, 100, 71,
-            150, 100, 156, 207, 214, 40, 147, 60, 204, 245, 95, 28, 174, 150, 13, 5, 203, 248, 230,
-            79, 251, 129, 93, 100, 227, 34, 22, 96, 140, 128, 94, 150, 96, 17, 67, 178, 138, 45,
-            53, 131, 50, 34, 155, 33, 209, 53, 203, 54, 194, 252, 133, 176, 118, 184, 227, 146,
-            249, 145, 82, 123, 216, 134, 161, 2, 128, 129, 98, 128, 219, 234, 33, 218, 109, 77,
-            180, 37, 144, 233, 69, 144, 233, 242, 170, 141, 5, 177, 57, 203, 102, 151, 99, 64, 162,
-            209, 31, 161, 250, 27, 60, 25, 128, 150, 201, 83, 108, 172, 200, 149, 175, 152, 239,
-            68, 46, 70, 171, 161, 225, 213, 67, 134, 52, 20, 196, 112, 18, 17, 15, 153, 79, 229,
-            12, 116, 125, 128, 240, 166, 193, 252, 109, 246, 105, 196, 144, 75, 63, 183, 160, 251,
-            118, 10, 35, 208, 150, 210, 210, 76, 56, 240, 185, 208, 105, 202, 133, 184, 200, 224,
-            128, 37, 123, 205, 80, 214, 27, 8, 122, 131, 47, 98, 14, 100, 97, 128, 153, 35, 146,
-            233, 11, 8, 35, 91, 135, 99, 43, 178, 171, 39, 252, 255, 60, 128, 155, 121, 239, 202,
-            225, 89, 85, 106, 242, 110, 68, 156, 254, 69, 176, 142, 234, 163, 175, 133, 1, 11, 6,
-            111, 36, 186, 161, 149, 252, 236, 248, 85, 29, 2, 128, 130, 3, 128, 176, 187, 196, 23,
-            87, 249, 84, 214, 236, 132, 108, 249, 28, 128, 108, 136, 100, 240, 72, 145, 25, 138,
-            140, 16, 186, 154, 138, 180, 53, 24, 47, 109, 128, 186, 239, 101, 239, 85, 128, 120,
-            156, 207, 35, 89, 115, 77, 40, 152, 176, 12, 122, 235, 235, 169, 167, 40, 16, 254, 226,
-            89, 115, 111, 33, 8, 165, 128, 222, 28, 245, 168, 138, 140, 212, 113, 167, 118, 253,
-            96, 16, 123, 78, 64, 36, 167, 102, 200, 43, 220, 201, 230, 158, 19, 163, 214, 115, 72,
-            229, 64, 128, 5, 199, 109, 1, 36, 59, 152, 39, 186, 84, 227, 31, 181, 54, 0, 79, 143,
-            179, 34, 5, 117, 206, 38, 198, 200, 53, 22, 36, 174, 18, 88, 175, 153, 1, 128, 130, 4,
-            128, 195, 164, 72, 162, 124, 144, 196, 12, 227, 115, 166, 145, 92, 48, 107, 49, 45,
-            209, 46, 223, 129, 22, 239, 91, 60, 225, 63, 36, 15, 234, 232, 30, 128, 20, 210, 136,
-            31, 76, 46, 16, 105, 228, 105, 33, 215, 159, 19, 76, 145, 141, 177, 235, 219, 40, 216,
-            254, 28, 155, 108, 0, 57, 83, 45, 82, 147, 128, 128, 189, 204, 239, 128, 208, 64, 248,
-            16, 14, 204, 133, 63, 128, 1, 41, 75, 215, 249, 197, 67, 75, 10, 10, 106, 156, 99, 16,
-            124, 145, 72, 9, 29, 2, 128, 130, 65, 128, 163, 145, 128, 255, 22, 222, 252, 142, 76,
-            67, 177, 82, 244, 65, 238, 141, 159, 142, 125, 80, 185, 246, 144, 241, 101, 228, 224,
-            127, 24, 56, 207, 203, 128, 228, 7, 147, 212, 200, 72, 88, 64, 122, 71, 28, 223, 0,
-            224, 25, 148, 50, 17, 38, 103, 157, 23, 220, 96, 71, 200, 67, 103, 197, 41, 45, 219,
-            128, 48, 112, 207, 175, 132, 115, 195, 32, 43, 137, 140, 240, 76, 228, 183, 173, 153,
-            91, 192, 52, 43, 24, 114, 252, 88, 208, 134, 220, 98, 100, 28, 121, 128, 93, 184, 246,
-            178, 33, 95, 61, 160, 196, 58, 228, 246, 154, 237, 79, 104, 70, 115, 137, 145, 49, 59,
-            166, 65, 206, 87, 9, 141, 193, 58, 183, 167, 29, 2, 128, 130, 80, 128, 136, 6, 211,
-            176, 113, 135, 136, 124, 153, 25, 252, 187, 129, 19, 255, 187, 123, 187, 55, 138, 135,
-            221, 95, 164, 169, 147, 200, 3, 34, 173, 92, 215, 128, 22, 251, 200, 31, 185, 40, 63,
-            181, 202, 11, 16, 199, 231, 238, 150, 78, 10, 119, 249, 174, 121, 242, 126, 178, 163,
-            164, 225, 210, 44, 250, 195, 36, 128, 88, 140, 254, 161, 229, 126, 185, 1, 117, 190,
-            83, 5, 132, 184, 246, 32, 7, 124, 219, 254, 62, 122, 156, 25, 22, 205, 60, 141, 29,
-            145, 146, 48, 128, 17, 102, 153, 1, 238, 171, 94, 16, 209, 246, 112, 201, 70, 91, 70,
-            166, 112, 54, 182, 201, 234, 28, 138, 66, 79, 52, 232, 83, 177, 87, 253, 30, 21, 1,
-            128, 132, 0, 128, 119, 156, 55, 177, 239, 189, 26, 253, 96, 127, 108, 244, 126, 18,
-            155, 52, 148, 36, 34, 156, 254, 232, 74, 206, 52, 169, 109, 97, 29, 232, 103, 78, 128,
-            85, 98, 102, 231, 149, 52, 146, 122, 165, 216, 177, 103, 119, 221, 0, 162, 39, 125,
-            200, 204, 101, 65, 238, 72, 159, 50, 115, 175, 221, 150, 164, 234, 153, 1, 128, 132, 1,
-            128, 140, 12, 77, 69, 80, 210, 193, 235, 115, 127, 76, 127, 90, 127, 1, 106, 160, 145,
-            61, 13, 252, 101, 241, 186, 98, 123, 20, 249, 30, 33, 189, 206, 128, 119, 131, 216, 72,
-            128, 83, 20, 51, 132, 104, 35, 120, 135, 89, 254, 155, 34, 121, 11, 134, 221, 208, 211,
-            13, 137, 17, 126, 128, 201, 221, 156, 149, 128, 245, 118, 131, 69, 149, 84, 219, 92,
-            28, 114, 0, 55, 176, 192, 105, 102, 245, 79, 190, 13, 20, 178, 201, 159, 63, 131, 19,
-            196, 8, 252, 8, 122, 153, 1, 128, 132, 2, 128, 32, 197, 58, 44, 68, 67, 163, 199, 8,
-            65, 201, 230, 30, 23, 138, 247, 63, 33, 175, 27, 212, 201, 207, 40, 207, 247, 139, 215,
-            203, 232, 15, 61, 128, 52, 23, 39, 243, 83, 217, 19, 158, 141, 158, 211, 228, 49, 217,
-            113, 137, 171, 117, 244, 189, 164, 92, 205, 131, 44, 246, 11, 193, 192, 186, 34, 66,
-            128, 241, 131, 3, 173, 200, 94, 21, 39, 93, 223, 119, 126, 120, 228, 98, 28, 97, 206,
-            217, 180, 70, 199, 149, 8, 201, 137, 193, 185, 196, 94, 6, 5, 153, 1, 128, 132, 16,
-            128, 46, 239, 231, 131, 21, 170, 103, 147, 13, 24, 26, 99, 75, 230, 132, 251, 103, 201,
-            193, 144, 106, 253, 101, 192, 12, 141, 255, 118, 204, 156, 235, 228, 128, 168, 28, 77,
-            16, 108, 165, 21, 185, 9, 249, 134, 49, 137, 12, 109, 255, 194, 208, 45, 144, 9, 219,
-            142, 82, 92, 135, 245, 28, 245, 163, 39, 248, 128, 160, 148, 193, 68, 253, 77, 124,
-            212, 175, 38, 83, 17, 235, 215, 2, 80, 182, 15, 77, 247, 33, 40, 57, 154, 27, 144, 76,
-            44, 122, 146, 88, 156, 153, 1, 128, 134, 0, 128, 172, 93, 246, 87, 152, 19, 111, 66,
-            200, 187, 110, 23, 93, 61, 51, 20, 116, 28, 99, 65, 232, 151, 154, 19, 26, 195, 250,
-            123, 190, 235, 183, 152, 128, 126, 191, 116, 81, 156, 13, 171, 84, 179, 229, 214, 56,
-            171, 219, 74, 233, 55, 254, 10, 83, 255, 74, 87, 98, 42, 12, 250, 10, 50, 126, 197,
-            139, 128, 122, 130, 182, 225, 237, 48, 154, 238, 157, 116, 230, 205, 194, 14, 241, 239,
-            40, 214, 55, 210, 189, 154, 129, 25, 14, 220, 237, 62, 114, 56, 125, 71, 21, 1, 128,
-            136, 0, 128, 134, 39, 13, 176, 120, 64, 39, 172, 92, 6, 20, 121, 214, 117, 164, 19, 34,
-            53, 74, 116, 71, 230, 193, 148, 125, 76, 177, 94, 103, 81, 230, 232, 128, 210, 50, 102,
-            87, 29, 57, 178, 141, 188, 188, 203, 246, 3, 200, 197, 237, 189, 133, 188, 163, 202,
-            252, 69, 7, 12, 31, 106, 173, 47, 146, 93, 216, 21, 1, 128, 136, 0, 128, 144, 48, 232,
-            254, 218, 114, 107, 190, 107, 36, 217, 19, 8, 94, 202, 15, 139, 220, 148, 60, 87, 132,
-            145, 197, 169, 96, 180, 138, 222, 218, 121, 229, 128, 123, 196, 191, 197, 30, 36, 235,
-            30, 225, 250, 40, 151, 147, 227, 94, 4, 188, 164, 136, 185, 172, 134, 84, 155, 27, 160,
-            76, 79, 247, 50, 74, 47, 153, 1, 128, 136, 64, 128, 121, 128, 164, 64, 155, 210, 90,
-            233, 120, 19, 202, 53, 253, 164, 51, 229, 88, 214, 63, 119, 104, 66, 62, 72, 162, 20,
-            203, 205, 194, 141, 16, 170, 128, 216, 10, 170, 208, 104, 173, 34, 27, 226, 5, 190,
-            149, 190, 226, 119, 78, 133, 75, 71, 32, 233, 44, 226, 106, 224, 150, 7, 62, 182, 76,
-            160, 241, 128, 112, 63, 4, 244, 6, 38, 205, 11, 15, 255, 6, 171, 170, 119, 123, 0, 130,
-            16, 180, 245, 221, 241, 88, 85, 109, 49, 4, 0, 20, 78, 164, 153, 29, 2, 128, 136, 65,
-            128, 150, 50, 44, 11, 154, 242, 220, 90, 177, 244, 196, 15, 39, 193, 42, 147, 229, 107,
-            47, 52, 32, 41, 103, 46, 40, 218, 225, 254, 164, 198, 82, 219, 128, 147, 157, 21, 197,
-            28, 129, 163, 215, 133, 57, 177, 34, 174, 4, 250, 243, 66, 26, 114, 177, 18, 7, 28, 38,
-            24, 96, 180, 185, 241, 246, 172, 40, 128, 227, 199, 72, 45, 200, 36, 141, 61, 95, 244,
-            41, 72, 79, 254, 134, 27, 77, 36, 47, 134, 228, 25, 212, 142, 193, 71, 36, 116, 135,
-            54, 98, 124, 128, 112, 182, 43, 60, 67, 155, 213, 35, 40, 115, 195, 103, 67, 47, 11,
-            128, 135, 49, 11, 210, 97, 191, 245, 88, 91, 88, 189, 74, 144, 92, 161, 68, 153, 1,
-            128, 136, 128, 128, 228, 202, 209, 49, 182, 6, 236, 17, 103, 60, 206, 96, 89, 95, 12,
-            76, 227, 100, 50, 25, 119, 154, 20, 6, 195, 119, 145, 202, 236, 194, 0, 220, 128, 209,
-            236, 192, 224, 53, 233, 52, 156, 201, 148, 11, 59, 127, 172, 108, 212, 220, 133, 148,
-            81, 48, 241, 95, 193, 42, 216, 228, 156, 33, 6, 97, 73, 128, 120, 10, 162, 1, 225, 131,
-            188, 3, 141, 253, 175, 242, 138, 189, 134, 211, 4, 228, 184, 23, 223, 33, 21, 236, 238,
-            156, 121, 66, 192, 13, 244, 105, 161, 2, 128, 137, 96, 128, 28, 93, 176, 157, 10, 217,
-            221, 131, 69, 51, 187, 53, 215, 112, 159, 240, 88, 149, 2, 187, 172, 17, 149, 159, 10,
-            120, 29, 139, 139, 108, 233, 80, 128, 147, 245, 244, 215, 140, 157, 9, 154, 149, 109,
-            193, 141, 88, 85, 129, 83, 40, 111, 97, 227, 215, 114, 128, 142, 220, 220, 206, 60, 74,
-            138, 72, 250, 128, 212, 193, 142, 41, 173, 252, 206, 149, 239, 229, 135, 253, 130, 15,
-            81, 90, 57, 2, 41, 17, 16, 49, 84, 60, 92, 104, 161, 20, 151, 31, 49, 29, 128, 11, 135,
-            250, 116, 184, 7, 11, 233, 243, 119, 248, 204, 251, 184, 33, 200, 154, 151, 164, 100,
-            35, 99, 2, 121, 159, 85, 192, 116, 114, 1, 167, 108, 128, 157, 169, 85, 36, 254, 200,
-            128, 87, 101, 90, 127, 37, 121, 122, 195, 229, 153, 221, 73, 253, 150, 30, 126, 85, 52,
-            158, 225, 202, 172, 229, 2, 176, 161, 2, 128, 139, 64, 128, 44, 104, 77, 98, 68, 200,
-            76, 51, 242, 193, 132, 147, 238, 189, 77, 100, 40, 221, 144, 131, 85, 100, 230, 19,
-            124, 239, 34, 214, 45, 16, 93, 91, 128, 171, 229, 218, 116, 200, 192, 187, 97, 167,
-            223, 0, 217, 76, 28, 215, 2, 145, 70, 107, 99, 196, 238, 186, 153, 166, 171, 155, 149,
-            244, 230, 31, 144, 128, 85, 195, 253, 157, 61, 19, 153, 187, 109, 249, 15, 149, 188,
-            40, 118, 96, 238, 85, 70, 11, 191, 107, 139, 98, 1, 39, 120, 142, 228, 17, 204, 234,
-            128, 181, 123, 91, 143, 71, 213, 124, 186, 111, 181, 241, 152, 139, 39, 240, 69, 130,
-            121, 176, 53, 29, 251, 142, 45, 135, 63, 59, 69, 248, 120, 240, 154, 128, 154, 13, 77,
-            188, 24, 194, 32, 205, 121, 60, 130, 185, 217, 6, 173, 57, 130, 104, 6, 255, 155, 184,
-            191, 113, 45, 65, 222, 45, 84, 137, 250, 111, 153, 1, 128, 140, 0, 128, 101, 102, 146,
-            193, 195, 229, 132, 255, 81, 54, 93, 207, 95, 81, 15, 105, 47, 35, 59, 124, 88, 40,
-            244, 92, 207, 75, 23, 199, 50, 102, 135, 131, 128, 235, 211, 188, 34, 198, 130, 16, 37,
-            251, 63, 26, 17, 68, 110, 115, 56, 97, 76, 220, 169, 163, 93, 216, 135, 235, 235, 218,
-            161, 198, 72, 30, 6, 128, 231, 103, 182, 59, 191, 53, 86, 193, 138, 6, 114, 186, 183,
-            61, 125, 66, 168, 69, 184, 210, 161, 18, 215, 96, 192, 201, 239, 48, 124, 113, 237,
-            224, 153, 1, 128, 144, 2, 128, 165, 79, 149, 254, 171, 14, 165, 127, 106, 44, 174, 5,
-            174, 254, 243, 82, 198, 148, 91, 110, 162, 227, 94, 87, 235, 201, 205, 222, 90, 147,
-            101, 239, 128, 191, 74, 66, 38, 65, 127, 178, 52, 242, 14, 146, 45, 224, 19, 188, 26,
-            194, 47, 1, 124, 145, 73, 166, 58, 125, 236, 25, 173, 88, 2, 231, 248, 128, 143, 173,
-            100, 143, 37, 123, 217, 249, 72, 200, 184, 216, 188, 159, 210, 174, 19, 45, 239, 70,
-            230, 69, 172, 122, 251, 168, 200, 45, 155, 185, 91, 157, 29, 2, 128, 144, 34, 128, 227,
-            6, 134, 107, 111, 144, 213, 153, 192, 194, 55, 142, 211, 119, 197, 134, 84, 156, 149,
-            251, 131, 216, 225, 5, 99, 182, 38, 250, 12, 54, 236, 201, 128, 88, 72, 208, 132, 151,
-            184, 77, 228, 64, 12, 196, 223, 15, 221, 30, 160, 203, 209, 137, 1, 221, 182, 196, 112,
-            32, 28, 71, 194, 55, 43, 134, 249, 128, 152, 209, 75, 22, 182, 57, 164, 242, 97, 56,
-            32, 125, 124, 47, 204, 67, 160, 104, 165, 25, 82, 229, 27, 14, 157, 71, 81, 63, 251,
-            177, 4, 164, 128, 97, 122, 28, 110, 215, 233, 8, 85, 82, 19, 221, 183, 134, 20, 101,
-            144, 199, 22, 158, 33, 173, 184, 7, 96, 225, 34, 154, 128, 165, 22, 219, 99, 161, 2,
-            128, 144, 52, 128, 184, 99, 235, 215, 28, 112, 10, 90, 105, 221, 204, 247, 12, 103, 28,
-            136, 154, 172, 187, 170, 93, 74, 247, 148, 178, 34, 52, 177, 251, 44, 46, 54, 128, 165,
-            198, 231, 149, 240, 73, 164, 175, 19, 5, 18, 111, 77, 144, 61, 22, 169, 188, 117, 224,
-            207, 41, 201, 237, 114, 138, 158, 174, 85, 23, 204, 60, 128, 28, 168, 187, 40, 128, 55,
-            146, 116, 98, 213, 235, 127, 4, 130, 45, 35, 63, 40, 68, 190, 238, 36, 42, 53, 254,
-            229, 14, 84, 152, 227, 3, 94, 128, 121, 238, 97, 54, 86, 19, 60, 143, 121, 54, 59, 240,
-            75, 220, 161, 177, 124, 193, 7, 79, 91, 9, 170, 76, 103, 228, 206, 92, 247, 139, 156,
-            108, 128, 85, 85, 183, 52, 67, 246, 57, 130, 205, 86, 57, 5, 106, 79, 81, 56, 239, 40,
-            87, 63, 71, 154, 6, 159, 233, 33, 174, 243, 160, 221, 10, 137, 37, 3, 128, 147, 65,
-            128, 185, 117, 8, 214, 189, 154, 45, 30, 170, 51, 69, 246, 223, 48, 225, 216, 64, 190,
-            212, 245, 80, 219, 154, 7, 144, 204, 42, 219, 90, 81, 213, 149, 128, 50, 124, 107, 2,
-            179, 99, 253, 57, 93, 54, 7, 216, 250, 4, 27, 133, 12, 184, 144, 34, 171, 107, 179,
-            151, 154, 174, 171, 133, 90, 32, 211, 64, 128, 179, 108, 43, 116, 5, 103, 226, 192,
-            122, 145, 28, 17, 74, 249, 210, 87, 95, 217, 75, 156, 14, 232, 34, 108, 51, 126, 225,
-            244, 183, 83, 110, 112, 128, 68, 218, 25, 166, 56, 232, 42, 245, 113, 82, 157, 224, 39,
-            203, 19, 9, 64, 143, 174, 144, 196, 186, 240, 170, 144, 53, 133, 177, 166, 139, 87,
-            176, 128, 240, 29, 236, 130, 218, 239, 34, 144, 55, 215, 133, 193, 125, 228, 25, 242,
-            128, 55, 179, 111, 221, 99, 91, 108, 193, 245, 27, 143, 249, 114, 124, 197, 128, 19,
-            119, 161, 85, 194, 104, 76, 19, 129, 96, 191, 167, 253, 129, 238, 68, 123, 222, 65, 3,
-            160, 48, 156, 80, 84, 239, 57, 69, 30, 204, 88, 20, 161, 2, 128, 149, 64, 128, 175, 82,
-            155, 186, 254, 164, 97, 208, 49, 35, 33, 250, 58, 143, 107, 188, 135, 91, 252, 195,
-            209, 209, 113, 49, 249, 15, 232, 139, 56, 72, 15, 56, 128, 191, 83, 56, 146, 90, 19,
-            194, 122, 85, 2, 135, 196, 209, 137, 221, 0, 242, 61, 20, 88, 111, 119, 121, 95, 239,
-            46, 138, 3, 140, 254, 117, 96, 128, 126, 174, 99, 49, 14, 30, 101, 6, 36, 162, 80, 16,
-            164, 40, 71, 72, 131, 253, 217, 27, 97, 104, 128, 248, 63, 115, 180, 177, 130, 180,
-            229, 129, 128, 204, 34, 38, 233, 252, 18, 209, 0, 97, 40, 142, 51, 103, 248, 61, 207,
-            210, 221, 65, 240, 7, 37, 164, 180, 195, 88, 32, 76, 154, 1, 7, 206, 128, 83, 60, 112,
-            67, 112, 118, 152, 149, 73, 224, 201, 70, 0, 7, 92, 185, 85, 252, 166, 93, 165, 46,
-            150, 184, 39, 151, 190, 200, 80, 49, 10, 219, 29, 2, 128, 150, 0, 128, 218, 77, 152,
-            86, 147, 47, 61, 233, 42, 203, 156, 234, 106, 106, 27, 71, 74, 249, 139, 138, 201, 33,
-            142, 47, 203, 59, 206, 107, 221, 214, 1, 60, 128, 211, 140, 144, 183, 230, 114, 102,
-            64, 57, 7, 95, 85, 57, 154, 35, 37, 84, 167, 100, 200, 8, 148, 235, 2, 108, 108, 205,
-            137, 27, 167, 142, 40, 128, 159, 0, 51, 108, 247, 99, 43, 248, 208, 184, 52, 34, 133,
-            133, 168, 128, 70, 199, 201, 92, 136, 219, 173, 157, 110, 21, 47, 2, 29, 48, 231, 21,
-            128, 165, 87, 61, 23, 192, 51, 225, 219, 183, 165, 43, 219, 137, 209, 112, 74, 254,
-            223, 27, 207, 15, 104, 253, 64, 244, 92, 24, 8, 57, 121, 24, 127, 169, 3, 128, 156,
-            112, 128, 14, 140, 202, 119, 209, 245, 174, 128, 240, 85, 235, 240, 241, 206, 74, 44,
-            42, 71, 144, 1, 247, 60, 161, 206, 50, 222, 161, 247, 10, 91, 40, 0, 128, 186, 237, 33,
-            59, 29, 218, 43, 18, 117, 127, 187, 6, 142, 204, 177, 231, 175, 254, 36, 109, 110, 120,
-            71, 231, 157, 35, 44, 142, 250, 22, 159, 235, 128, 138, 111, 67, 172, 226, 163, 169,
-            247, 13, 168, 150, 235, 105, 217, 118, 1, 109, 91, 45, 27, 106, 69, 247, 36, 188, 62,
-            106, 168, 134, 44, 232, 172, 128, 196, 120, 208, 59, 46, 137, 16, 79, 29, 124, 62, 216,
-            224, 23, 178, 220, 231, 169, 35, 85, 121, 181, 100, 147, 61, 1, 236, 135, 181, 72, 145,
-            56, 128, 23, 33, 158, 2, 6, 241, 153, 107, 251, 222, 161, 192, 154, 154, 219, 76, 147,
-            216, 69, 4, 253, 178, 231, 202, 48, 148, 163, 129, 57, 43, 166, 145, 128, 204, 158, 55,
-            76, 234, 150, 219, 213, 8, 83, 193, 111, 45, 245, 167, 6, 78, 167, 228, 212, 250, 237,
-            13, 13, 178, 230, 136, 110, 245, 65, 105, 156, 128, 82, 123, 1, 143, 84, 152, 200, 17,
-            94, 138, 95, 146, 202, 116, 112, 140, 77, 19, 186, 240, 131, 116, 213, 115, 253, 78,
-            135, 217, 193, 41, 194, 251, 153, 1, 128, 160, 1, 128, 130, 206, 3, 16, 243, 250, 134,
-            217, 111, 2, 105, 107, 245, 196, 148, 120, 101, 194, 145, 154, 153, 230, 165, 145, 113,
-            86, 157, 232, 253, 184, 25, 153, 128, 172, 95, 209, 236, 96, 128, 58, 255, 205, 92,
-            113, 213, 68, 96, 167, 34, 150, 157, 226, 37, 128, 239, 1, 169, 107, 245, 208, 40, 145,
-            155, 61, 28, 128, 185, 145, 173, 124, 89, 35, 31, 227, 8, 205, 124, 191, 196, 18, 51,
-            132, 243, 119, 242, 232, 60, 197, 49, 4, 184, 116, 229, 88, 240, 159, 145, 107, 29, 2,
-            128, 160, 24, 128, 109, 198, 50, 181, 54, 207, 86, 32, 238, 194, 243, 9, 215, 55, 191,
-            100, 214, 28, 131, 23, 71, 148, 65, 201, 159, 220, 138, 71, 229, 186, 51, 217, 128,
-            211, 25, 247, 95, 56, 33, 251, 45, 120, 194, 189, 164, 191, 43, 19, 235, 50, 83, 154,
-            79, 55, 240, 3, 3, 35, 233, 221, 5, 19, 105, 140, 116, 128, 120, 225, 20, 8, 175, 33,
-            158, 17, 136, 45, 55, 178, 40, 228, 139, 249, 89, 72, 197, 8, 50, 114, 237, 243, 31,
-            158, 127, 204, 166, 52, 45, 8, 128, 84, 240, 74, 173, 246, 159, 166, 31, 178, 144, 145,
-            130, 98, 144, 89, 43, 9, 248, 111, 29, 111, 184, 88, 216, 119, 189, 169, 84, 80, 28,
-            253, 153, 37, 3, 128, 161, 112, 128, 140, 137, 169, 32, 124, 226, 21, 6, 4, 155, 0, 83,
-            253, 225, 112, 80, 155, 80, 85, 171, 37, 88, 224, 98, 127, 46, 108, 213, 160, 246, 38,
-            232, 128, 52, 144, 143, 68, 24, 97, 156, 76, 183, 26, 179, 124, 26, 52, 1, 63, 170,
-            190, 77, 140, 119, 161, 211, 94, 29, 180, 232, 132, 255, 207, 192, 215, 128, 153, 219,
-            103, 128, 177, 121, 67, 128, 108, 182, 69, 136, 227, 4, 192, 169, 84, 97, 114, 50, 145,
-            53, 49, 5, 33, 168, 247, 39, 36, 56, 234, 60, 128, 213, 84, 180, 229, 106, 90, 0, 176,
-            64, 214, 106, 255, 21, 29, 237, 42, 118, 91, 8, 229, 218, 16, 207, 253, 116, 248, 240,
-            109, 102, 22, 188, 17, 128, 74, 145, 58, 222, 44, 118, 71, 22, 248, 170, 58, 156, 30,
-            149, 253, 204, 57, 102, 49, 184, 16, 103, 137, 65, 161, 116, 207, 48, 193, 223, 67, 10,
-            128, 47, 122, 167, 250, 181, 77, 3, 236, 148, 11, 99, 74, 186, 250, 1, 243, 228, 85,
-            196, 227, 200, 163, 43, 124, 251, 13, 109, 229, 127, 200, 115, 211, 153, 1, 128, 162,
-            0, 128, 202, 141, 85, 133, 131, 160, 34, 64, 191, 48, 123, 89, 71, 173, 1, 75, 217, 32,
-            20, 206, 65, 5, 223, 116, 239, 250, 175, 32, 113, 71, 201, 79, 128, 205, 232, 27, 47,
-            26, 118, 55, 59, 107, 57, 166, 238, 133, 56, 232, 148, 18, 25, 16, 6, 203, 75, 31, 232,
-            182, 198, 133, 200, 87, 54, 32, 225, 128, 216, 29, 104, 252, 100, 191, 177, 175, 248,
-            88, 173, 103, 30, 211, 132, 139, 201, 136, 6, 226, 194, 98, 100, 154, 202, 145, 182,
-            63, 190, 150, 37, 218, 161, 2, 128, 162, 6, 128, 28, 101, 250, 154, 82, 36, 39, 8, 236,
-            187, 22, 20, 156, 248, 93, 195, 154, 44, 129, 187, 96, 239, 4, 225, 201, 62, 148, 95,
-            43, 120, 66, 122, 128, 10, 148, 252, 223, 26, 191, 184, 235, 220, 115, 208, 234, 136,
-            44, 198, 17, 2, 60, 220, 158, 244, 170, 125, 61, 118, 112, 117, 98, 138, 155, 55, 18,
-            128, 70, 48, 240, 18, 232, 71, 187, 0, 144, 76, 166, 68, 162, 28, 180, 66, 34, 76, 7,
-            160, 138, 79, 34, 93, 69, 74, 18, 99, 197, 2, 5, 146, 128, 177, 172, 94, 31, 119, 0,
-            68, 147, 161, 248, 3, 33, 37, 101, 198, 177, 193, 191, 175, 25, 107, 251, 99, 165, 239,
-            81, 154, 39, 46, 16, 81, 95, 128, 50, 226, 56, 174, 240, 246, 92, 64, 65, 235, 213,
-            227, 128, 172, 129, 27, 206, 150, 52, 188, 55, 11, 129, 70, 154, 192, 8, 36, 154, 176,
-            244, 185, 29, 2, 128, 164, 1, 128, 161, 138, 80, 140, 83, 90, 208, 88, 81, 80, 249, 64,
-            199, 86, 62, 66, 137, 93, 151, 3, 43, 122, 161, 150, 248, 149, 249, 176, 8, 223, 90,
-            90, 128, 163, 192, 125, 241, 242, 244, 27, 112, 33, 98, 87, 80, 162, 39, 152, 207, 49,
-            149, 104, 216, 68, 118, 213, 30, 0, 62, 158, 152, 236, 98, 93, 135, 128, 114, 193, 253,
-            2, 140, 142, 71, 240, 121, 110, 255, 207, 197, 5, 155, 208, 23, 202, 138, 141, 152,
-            184, 100, 77, 217, 37, 51, 24, 109, 200, 190, 44, 128, 56, 215, 95, 95, 111, 253, 178,
-            14, 182, 235, 199, 84, 199, 54, 68, 250, 131, 150, 70, 205, 196, 78, 156, 6, 189, 60,
-            67, 233, 182, 39, 117, 24, 153, 1, 128, 168, 0, 128, 240, 48, 243, 251, 208, 247, 236,
-            17, 185, 255, 58, 78, 247, 222, 31, 217, 222, 18, 94, 184, 182, 220, 27, 214, 33, 85,
-            245, 191, 28, 135, 90, 72, 128, 11, 129, 41, 233, 114, 234, 254, 188, 99, 107, 7, 234,
-            225, 71, 192, 237, 10, 76, 132, 34, 143, 106, 66, 237, 124, 89, 243, 113, 249, 48, 49,
-            98, 128, 170, 217, 33, 154, 27, 152, 98, 144, 14, 55, 223, 159, 72, 230, 7, 79, 173, 0,
-            207, 173, 243, 48, 1, 191, 128, 235, 146, 101, 200, 107, 24, 224, 161, 2, 128, 168, 24,
-            128, 40, 111, 242, 142, 54, 175, 246, 8, 116, 101, 96, 107, 154, 60, 151, 230, 217,
-            189, 238, 61, 32, 46, 185, 56, 24, 244, 166, 230, 156, 188, 148, 202, 128, 37, 72, 73,
-            117, 50, 53, 103, 57, 146, 88, 243, 148, 231, 172, 101, 197, 154, 200, 72, 5, 144, 7,
-            244, 33, 122, 243, 55, 203, 4, 18, 187, 211, 128, 112, 9, 110, 60, 163, 45, 172, 146,
-            171, 9, 227, 212, 100, 219, 86, 173, 80, 85, 69, 158, 204, 153, 107, 211, 131, 1, 245,
-            106, 254, 247, 164, 247, 128, 171, 135, 117, 81, 213, 127, 86, 37, 148, 54, 68, 184,
-            248, 143, 215, 184, 22, 146, 194, 100, 171, 89, 231, 249, 210, 163, 209, 95, 186, 233,
-            161, 248, 128, 130, 63, 255, 126, 12, 120, 8, 166, 176, 191, 206, 147, 167, 171, 210,
-            98, 59, 21, 36, 80, 224, 75, 81, 139, 249, 93, 216, 177, 57, 161, 129, 181, 169, 3,
-            128, 169, 193, 128, 249, 104, 120, 127, 129, 211, 196, 249, 21, 122, 248, 193, 111, 21,
-            192, 10, 68, 132, 249, 206, 197, 207, 19, 0, 38, 50, 125, 105, 124, 113, 241, 211, 128,
-            224, 16, 171, 27, 224, 172, 166, 89, 145, 104, 36, 203, 11, 220, 68, 138, 95, 13, 24,
-            121, 58, 4, 205, 56, 252, 199, 62, 13, 157, 42, 72, 99, 128, 102, 170, 111, 106, 100,
-            115, 124, 28, 108, 202, 80, 27, 228, 252, 127, 210, 209, 179, 9, 102, 113, 190, 24, 87,
-            155, 132, 164, 173, 212, 67, 248, 94, 128, 171, 212, 159, 36, 40, 202, 127, 62, 159,
-            112, 173, 131, 162, 78, 90, 25, 125, 246, 178, 119, 3, 38, 180, 35, 20, 119, 3, 16,
-            206, 68, 205, 236, 128, 60, 238, 106, 82, 128, 146, 205, 140, 246, 76, 74, 211, 234,
-            173, 62, 206, 172, 242, 118, 248, 51, 149, 130, 116, 171, 77, 7, 74, 197, 244, 71, 67,
-            128, 140, 141, 124, 113, 23, 251, 136, 237, 42, 128, 1, 109, 92, 40, 133, 224, 30, 98,
-            172, 153, 115, 66, 217, 254, 6, 26, 213, 202, 200, 227, 119, 190, 128, 152, 75, 151,
-            61, 50, 172, 116, 103, 238, 34, 0, 156, 6, 67, 88, 151, 203, 185, 220, 134, 90, 118,
-            186, 62, 251, 189, 171, 115, 167, 25, 215, 42, 37, 3, 128, 170, 65, 128, 139, 244, 129,
-            3, 156, 81, 134, 167, 245, 241, 97, 219, 85, 169, 136, 149, 58, 40, 126, 1, 211, 214,
-            65, 218, 144, 237, 116, 42, 51, 49, 140, 232, 128, 193, 156, 240, 161, 83, 138, 218,
-            57, 235, 154, 215, 181, 171, 60, 23, 231, 52, 177, 179, 90, 135, 198, 194, 85, 63, 221,
-            193, 66, 97, 96, 215, 194, 128, 171, 144, 60, 235, 162, 53, 150, 176, 7, 83, 142, 26,
-            51, 244, 230, 142, 183, 122, 164, 138, 37, 119, 202, 111, 240, 70, 188, 132, 214, 48,
-            50, 32, 128, 9, 34, 212, 151, 176, 105, 27, 18, 49, 226, 250, 134, 157, 15, 186, 151,
-            65, 143, 76, 130, 17, 189, 57, 86, 51, 219, 106, 189, 251, 5, 38, 49, 128, 117, 237,
-            39, 39, 16, 66, 3, 174, 143, 251, 241, 6, 108, 183, 110, 13, 174, 62, 175, 54, 45, 98,
-            119, 90, 37, 151, 106, 160, 3, 187, 71, 112, 128, 48, 145, 143, 92, 86, 56, 100, 221,
-            33, 65, 77, 162, 168, 114, 204, 95, 148, 106, 20, 209, 65, 96, 237, 174, 174, 213, 3,
-            170, 216, 202, 163, 105, 37, 3, 128, 180, 36, 128, 197, 69, 226, 239, 160, 231, 234,
-            218, 90, 103, 53, 140, 101, 52, 133, 54, 135, 78, 14, 247, 197, 184, 200, 82, 192, 217,
-            10, 255, 157, 1, 255, 195, 128, 38, 4, 51, 59, 8, 243, 205, 122, 137, 63, 52, 201, 35,
-            43, 250, 207, 129, 202, 4, 162, 188, 19, 30, 51, 24, 144, 165, 79, 103, 1, 222, 149,
-            128, 4, 217, 112, 215, 84, 135, 209, 164, 38, 242, 20, 198, 17, 179, 130, 26, 177, 159,
-            144, 198, 70, 179, 93, 173, 118, 107, 51, 208, 162, 89, 56, 63, 128, 235, 77, 192, 119,
-            32, 206, 161, 110, 134, 108, 231, 107, 64, 21, 242, 70, 183, 107, 0, 188, 214, 239,
-            226, 213, 47, 193, 210, 248, 131, 76, 75, 174, 128, 76, 93, 236, 159, 69, 145, 130,
-            192, 112, 29, 78, 116, 85, 181, 60, 33, 246, 161, 80, 184, 2, 244, 101, 6, 170, 239,
-            233, 200, 150, 151, 152, 125, 128, 43, 54, 66, 214, 82, 23, 237, 174, 62, 196, 88, 201,
-            50, 42, 250, 189, 198, 75, 241, 147, 143, 215, 150, 87, 48, 145, 46, 158, 224, 108, 46,
-            130, 37, 3, 128, 180, 144, 128, 183, 198, 239, 208, 158, 81, 182, 252, 162, 31, 125,
-            138, 100, 121, 156, 95, 129, 200, 211, 61, 131, 213, 21, 44, 61, 32, 99, 119, 112, 80,
-            170, 163, 128, 189, 99, 96, 5, 95, 174, 35, 141, 129, 252, 113, 154, 74, 208, 4, 94,
-            46, 13, 146, 152, 36, 82, 98, 63, 126, 228, 77, 167, 253, 206, 106, 63, 128, 179, 111,
-            191, 252, 166, 78, 94, 80, 128, 33, 44, 74, 66, 133, 211, 121, 70, 6, 123, 161, 61, 37,
-            200, 93, 171, 18, 96, 9, 178, 64, 121, 35, 128, 213, 234, 38, 67, 7, 177, 55, 4, 158,
-            174, 207, 134, 40, 220, 99, 114, 247, 241, 120, 140, 59, 54, 171, 131, 48, 26, 255,
-            255, 162, 218, 240, 132, 128, 12, 100, 249, 121, 128, 147, 52, 235, 27, 74, 223, 246,
-            54, 124, 1, 236, 132, 13, 211, 21, 238, 159, 216, 156, 11, 110, 189, 10, 70, 141, 152,
-            124, 128, 54, 211, 44, 215, 222, 246, 214, 49, 122, 64, 17, 50, 94, 57, 202, 102, 60,
-            208, 122, 252, 233, 247, 112, 123, 60, 211, 25, 229, 252, 104, 125, 30, 21, 1, 128,
-            192, 0, 128, 227, 107, 23, 229, 172, 188, 36, 77, 0, 133, 71, 104, 195, 203, 222, 80,
-            200, 253, 140, 47, 231, 126, 218, 81, 125, 152, 253, 242, 254, 120, 42, 218, 128, 75,
-            165, 166, 125, 51, 203, 206, 137, 134, 22, 203, 94, 238, 78, 223, 213, 86, 88, 34, 179,
-            35, 70, 42, 221, 82, 241, 223, 233, 224, 64, 56, 168, 153, 1, 128, 192, 2, 128, 124,
-            72, 194, 76, 147, 214, 250, 123, 197, 87, 1, 209, 82, 169, 125, 220, 152, 121, 81, 232,
-            57, 227, 32, 101, 223, 21, 65, 122, 212, 136, 229, 28, 128, 184, 178, 47, 94, 211, 20,
-            196, 250, 174, 111, 166, 3, 30, 213, 126, 146, 3, 201, 207, 227, 179, 179, 159, 165,
-            130, 86, 51, 80, 253, 251, 197, 79, 128, 35, 70, 39, 41, 223, 75, 217, 13, 89, 185,
-            126, 29, 60, 122, 163, 119, 123, 126, 163, 205, 128, 62, 254, 160, 104, 124, 130, 206,
-            197, 161, 142, 173, 153, 1, 128, 192, 32, 128, 251, 102, 131, 35, 68, 144, 149, 5, 81,
-            210, 157, 254, 179, 208, 231, 55, 72, 251, 244, 144, 54, 246, 45, 175, 26, 93, 21, 186,
-            77, 42, 48, 19, 128, 69, 25, 218, 74, 19, 146, 154, 84, 83, 90, 253, 224, 239, 154,
-            109, 171, 149, 125, 253, 215, 150, 215, 49, 163, 82, 59, 163, 240, 99, 200, 134, 86,
-            128, 88, 145, 185, 64, 217, 182, 203, 122, 141, 43, 178, 95, 33, 116, 230, 56, 124,
-            187, 61, 166, 135, 156, 17, 154, 117, 224, 119, 62, 133, 168, 104, 136, 153, 1, 128,
-            192, 64, 128, 233, 237, 70, 138, 83, 44, 191, 250, 74, 46, 86, 243, 35, 230, 247, 169,
-            109, 152, 58, 187, 167, 196, 80, 214, 85, 20, 197, 233, 86, 225, 64, 82, 128, 73, 228,
-            41, 218, 194, 182, 129, 5, 23, 179, 89, 92, 235, 155, 180, 255, 34, 123, 67, 180, 210,
-            115, 198, 106, 36, 86, 11, 2, 131, 48, 221, 88, 128, 108, 198, 93, 231, 100, 62, 118,
-            251, 168, 165, 119, 69, 210, 78, 193, 106, 63, 72, 182, 145, 40, 204, 126, 54, 46, 59,
-            52, 168, 76, 92, 102, 163, 153, 1, 128, 193, 0, 128, 224, 95, 51, 210, 23, 178, 104,
-            17, 216, 72, 199, 174, 41, 252, 4, 109, 166, 158, 15, 40, 51, 225, 120, 119, 239, 149,
-            177, 188, 139, 202, 203, 45, 128, 169, 220, 229, 110, 23, 117, 31, 79, 216, 7, 185, 20,
-            191, 121, 220, 142, 45, 197, 140, 253, 45, 179, 203, 109, 150, 149, 244, 73, 175, 145,
-            197, 206, 128, 238, 116, 177, 151, 37, 48, 16, 182, 34, 41, 40, 90, 27, 215, 11, 176,
-            186, 215, 211, 168, 105, 74, 158, 202, 39, 229, 218, 121, 126, 248, 137, 57, 161, 2,
-            128, 193, 9, 128, 203, 20, 139, 146, 23, 176, 175, 38, 72, 44, 141, 186, 94, 22, 158,
-            18, 105, 63, 53, 164, 146, 46, 223, 36, 210, 166, 220, 9, 18, 102, 6, 142, 128, 194,
-            82, 130, 214, 197, 216, 208, 149, 155, 181, 133, 72, 62, 113, 150, 219, 254, 253, 135,
-            120, 55, 101, 21, 122, 221, 36, 92, 88, 191, 31, 100, 21, 128, 241, 55, 179, 9, 103,
-            186, 171, 243, 198, 192, 249, 165, 181, 124, 173, 187, 191, 162, 86, 73, 216, 91, 128,
-            111, 60, 10, 205, 128, 20, 34, 228, 208, 128, 201, 49, 252, 67, 78, 14, 211, 108, 53,
-            225, 157, 95, 105, 224, 32, 119, 156, 188, 109, 51, 244, 172, 238, 182, 29, 168, 17,
-            190, 131, 155, 220, 152, 128, 239, 225, 63, 180, 34, 236, 200, 189, 167, 69, 111, 223,
-            215, 65, 202, 212, 185, 83, 158, 182, 5, 209, 75, 28, 206, 173, 214, 232, 107, 107, 55,
-            9, 153, 1, 128, 196, 0, 128, 191, 250, 68, 12, 200, 161, 149, 187, 237, 128, 22, 189,
-            119, 185, 179, 209, 205, 89, 115, 195, 232, 228, 195, 79, 167, 203, 63, 108, 237, 158,
-            186, 54, 128, 169, 52, 211, 157, 220, 101, 189, 101, 130, 30, 68, 144, 16, 140, 16,
-            252, 43, 222, 0, 78, 201, 185, 10, 92, 108, 185, 196, 91, 15, 198, 59, 36, 128, 179,
-            219, 254, 223, 66, 93, 13, 2, 34, 102, 11, 29, 215, 92, 14, 83, 6, 70, 220, 163, 174,
-            50, 1, 94, 17, 68, 86, 9, 139, 121, 37, 194, 153, 1, 128, 200, 0, 128, 49, 44, 161, 25,
-            66, 190, 118, 121, 231, 28, 221, 170, 171, 153, 116, 144, 175, 23, 242, 66, 222, 210,
-            28, 49, 140, 19, 45, 9, 150, 252, 128, 201, 128, 251, 253, 83, 109, 137, 177, 179, 227,
-            224, 222, 175, 58, 4, 11, 216, 194, 200, 26, 128, 46, 15, 188, 129, 60, 24, 6, 228,
-            218, 199, 31, 66, 54, 128, 10, 41, 188, 87, 119, 254, 197, 4, 204, 159, 109, 58, 184,
-            190, 250, 20, 40, 53, 212, 45, 62, 181, 73, 88, 28, 95, 95, 203, 84, 196, 61, 111, 169,
-            3, 128, 220, 68, 128, 11, 3, 128, 223, 141, 239, 186, 202, 200, 134, 248, 86, 242, 64,
-            171, 70, 122, 26, 255, 204, 234, 156, 30, 61, 127, 110, 105, 3, 9, 82, 231, 227, 128,
-            45, 138, 198, 173, 246, 227, 7, 60, 12, 17, 59, 69, 37, 17, 214, 244, 202, 123, 237,
-            74, 170, 253, 169, 131, 228, 206, 126, 192, 137, 61, 89, 113, 128, 33, 242, 59, 126,
-            80, 98, 73, 125, 236, 52, 247, 115, 89, 121, 195, 170, 171, 107, 252, 32, 89, 123, 210,
-            244, 51, 71, 232, 238, 94, 239, 6, 232, 128, 94, 31, 50, 39, 98, 199, 217, 235, 230,
-            37, 117, 32, 219, 24, 186, 192, 117, 23, 210, 2, 177, 98, 90, 26, 2, 61, 201, 112, 149,
-            140, 233, 134, 128, 31, 109, 84, 248, 13, 200, 152, 80, 5, 153, 20, 209, 97, 74, 104,
-            4, 85, 91, 203, 113, 65, 25, 241, 12, 25, 248, 249, 108, 90, 173, 148, 134, 128, 21,
-            55, 253, 47, 191, 186, 176, 109, 71, 249, 237, 104, 93, 184, 79, 207, 79, 31, 197, 175,
-            34, 164, 148, 193, 74, 74, 149, 52, 119, 94, 162, 11, 128, 50, 103, 100, 24, 131, 75,
-            236, 127, 11, 61, 157, 78, 216, 103, 114, 20, 156, 6, 247, 160, 235, 43, 176, 58, 107,
-            81, 84, 66, 107, 28, 160, 239, 201, 7, 128, 255, 191, 128, 158, 63, 149, 1, 58, 90,
-            199, 8, 180, 42, 100, 95, 68, 184, 64, 112, 145, 78, 130, 76, 221, 11, 40, 239, 41, 58,
-            200, 68, 13, 72, 188, 165, 128, 204, 220, 74, 251, 110, 231, 249, 175, 205, 130, 132,
-            250, 22, 40, 138, 163, 127, 121, 154, 73, 233, 16, 94, 82, 45, 183, 72, 11, 43, 119,
-            87, 234, 128, 88, 86, 140, 228, 43, 157, 244, 179, 181, 121, 251, 212, 56, 35, 194, 12,
-            228, 254, 104, 65, 27, 165, 158, 216, 188, 139, 168, 225, 61, 132, 202, 76, 128, 201,
-            23, 138, 207, 176, 44, 214, 85, 204, 70, 100, 4, 116, 82, 71, 228, 241, 90, 108, 43,
-            26, 68, 144, 18, 122, 64, 161, 70, 98, 251, 10, 252, 128, 110, 47, 111, 14, 41, 34, 78,
-            147, 203, 192, 168, 4, 197, 79, 134, 68, 109, 231, 253, 158, 107, 203, 91, 130, 151,
-            98, 180, 213, 9, 189, 68, 80, 128, 4, 201, 186, 96, 21, 36, 18, 155, 170, 90, 58, 226,
-            214, 67, 127, 237, 196, 222, 226, 174, 36, 51, 157, 46, 240, 74, 122, 183, 135, 39, 48,
-            186, 128, 170, 114, 149, 137, 242, 192, 204, 114, 121, 187, 191, 250, 8, 227, 127, 93,
-            220, 108, 194, 11, 178, 203, 61, 123, 11, 240, 189, 208, 206, 163, 200, 140, 128, 248,
-            91, 155, 199, 230, 136, 20, 51, 123, 8, 11, 178, 38, 69, 109, 117, 201, 28, 130, 75,
-            142, 190, 181, 184, 152, 190, 68, 115, 200, 51, 207, 108, 128, 209, 155, 60, 4, 152,
-            91, 147, 234, 238, 184, 1, 28, 148, 0, 120, 154, 38, 123, 111, 197, 112, 199, 2, 246,
-            233, 50, 71, 25, 87, 61, 100, 229, 128, 239, 53, 195, 76, 247, 20, 70, 41, 115, 0, 224,
-            30, 90, 189, 247, 57, 116, 102, 92, 3, 138, 59, 45, 103, 186, 65, 237, 78, 15, 134, 29,
-            177, 128, 220, 55, 8, 25, 116, 102, 167, 225, 3, 235, 164, 6, 67, 84, 93, 44, 192, 179,
-            134, 137, 114, 64, 202, 131, 226, 175, 96, 99, 103, 199, 178, 109, 128, 100, 45, 137,
-            234, 36, 180, 77, 29, 187, 234, 88, 127, 142, 106, 2, 148, 5, 11, 91, 223, 10, 213, 40,
-            60, 178, 56, 121, 179, 79, 196, 48, 49, 128, 229, 200, 177, 66, 203, 20, 230, 123, 193,
-            84, 65, 201, 133, 24, 68, 116, 225, 116, 176, 21, 130, 113, 115, 14, 112, 131, 6, 183,
-            93, 119, 144, 20, 128, 56, 87, 4, 116, 11, 175, 92, 158, 44, 17, 84, 84, 85, 175, 230,
-            255, 86, 111, 189, 17, 10, 242, 108, 19, 96, 55, 130, 150, 66, 30, 80, 122, 128, 99,
-            209, 106, 138, 72, 129, 164, 121, 105, 105, 236, 38, 195, 244, 250, 153, 205, 101, 159,
-            223, 245, 83, 115, 131, 230, 213, 201, 110, 78, 17, 82, 225, 201, 7, 128, 255, 254,
-            128, 208, 158, 3, 190, 40, 216, 177, 220, 46, 85, 232, 253, 123, 170, 53, 34, 76, 254,
-            150, 49, 92, 15, 76, 10, 166, 209, 43, 211, 223, 249, 176, 21, 128, 219, 204, 84, 174,
-            161, 163, 190, 254, 117, 195, 29, 7, 217, 23, 86, 245, 46, 71, 60, 203, 129, 217, 72,
-            123, 16, 220, 42, 200, 244, 54, 55, 50, 128, 215, 170, 165, 0, 121, 88, 226, 241, 89,
-            20, 184, 131, 70, 164, 242, 225, 11, 201, 96, 65, 23, 183, 32, 101, 9, 91, 191, 193,
-            240, 147, 90, 83, 128, 155, 146, 150, 77, 205, 13, 254, 237, 2, 217, 113, 89, 34, 87,
-            59, 162, 204, 246, 188, 218, 172, 221, 125, 223, 74, 198, 152, 6, 237, 249, 73, 114,
-            128, 117, 34, 190, 142, 140, 232, 56, 6, 243, 125, 122, 209, 114, 15, 29, 177, 82, 154,
-            33, 204, 129, 188, 72, 165, 102, 5, 238, 174, 87, 60, 231, 27, 128, 204, 83, 179, 23,
-            92, 175, 137, 194, 112, 119, 109, 117, 95, 176, 38, 183, 59, 179, 231, 142, 26, 164,
-            251, 72, 147, 40, 45, 28, 130, 88, 70, 76, 128, 118, 78, 9, 107, 124, 99, 15, 237, 148,
-            97, 66, 12, 196, 117, 177, 216, 180, 229, 24, 126, 95, 182, 75, 250, 54, 28, 34, 122,
-            132, 90, 21, 199, 128, 104, 216, 101, 53, 136, 32, 38, 150, 106, 89, 151, 102, 119,
-            159, 172, 121, 179, 251, 63, 218, 73, 27, 84, 71, 231, 233, 129, 165, 181, 87, 152, 28,
-            128, 97, 60, 168, 151, 117, 208, 149, 36, 153, 18, 159, 83, 4, 237, 135, 16, 3, 193,
-            13, 32, 215, 87, 3, 48, 254, 192, 221, 112, 223, 53, 77, 198, 128, 54, 63, 114, 225,
-            243, 241, 166, 252, 248, 189, 209, 228, 214, 120, 245, 224, 182, 166, 118, 14, 16, 83,
-            231, 209, 79, 4, 149, 145, 108, 15, 62, 108, 128, 158, 133, 78, 141, 28, 208, 225, 65,
-            226, 172, 19, 242, 66, 244, 222, 59, 231, 68, 69, 248, 111, 2, 162, 135, 191, 44, 203,
-            10, 194, 164, 231, 216, 128, 173, 122, 140, 218, 205, 222, 128, 215, 191, 40, 216, 214,
-            77, 27, 90, 144, 253, 77, 208, 214, 73, 167, 101, 40, 33, 2, 19, 2, 78, 125, 126, 229,
-            128, 25, 249, 143, 91, 234, 229, 99, 135, 101, 225, 221, 126, 43, 39, 116, 209, 166,
-            211, 150, 71, 204, 86, 227, 60, 52, 130, 224, 117, 243, 57, 157, 133, 128, 94, 84, 87,
-            142, 53, 210, 91, 172, 239, 170, 106, 177, 233, 85, 248, 15, 170, 36, 125, 161, 234,
-            254, 205, 255, 121, 105, 53, 86, 149, 83, 11, 37, 128, 91, 33, 242, 120, 86, 85, 201,
-            95, 166, 117, 7, 166, 199, 189, 199, 190, 198, 224, 142, 226, 153, 99, 71, 72, 174, 83,
-            235, 245, 119, 185, 65, 205, 77, 8, 128, 255, 255, 128, 6, 32, 133, 84, 101, 18, 126,
-            217, 191, 6, 177, 132, 244, 120, 216, 103, 150, 164, 175, 134, 187, 115, 179, 22, 186,
-            234, 226, 161, 121, 26, 222, 55, 128, 46, 76, 12, 88, 33, 101, 141, 239, 249, 204, 50,
-            40, 146, 72, 53, 196, 237, 137, 201, 156, 162, 175, 126, 235, 75, 129, 36, 170, 173,
-            227, 151, 84, 128, 150, 97, 175, 74, 255, 77, 186, 203, 201, 206, 163, 28, 53, 137,
-            246, 23, 243, 50, 33, 254, 102, 73, 205, 72, 95, 66, 237, 244, 178, 220, 95, 243, 128,
-            248, 133, 191, 217, 234, 55, 50, 83, 196, 84, 222, 191, 3, 25, 251, 225, 206, 154, 97,
-            250, 230, 7, 18, 148, 82, 158, 159, 24, 229, 187, 78, 248, 128, 53, 90, 65, 180, 241,
-            62, 98, 76, 77, 130, 116, 124, 57, 131, 192, 159, 129, 74, 73, 156, 182, 254, 24, 66,
-            120, 29, 111, 104, 113, 116, 87, 177, 128, 196, 149, 246, 233, 244, 233, 39, 94, 22,
-            147, 84, 71, 207, 212, 192, 32, 144, 53, 0, 121, 159, 67, 225, 200, 168, 120, 90, 201,
-            19, 105, 76, 107, 128, 16, 175, 11, 70, 56, 176, 152, 223, 221, 147, 33, 254, 212, 24,
-            192, 57, 46, 100, 39, 187, 73, 8, 172, 4, 82, 249, 0, 159, 110, 17, 65, 123, 128, 246,
-            155, 84, 144, 169, 142, 185, 181, 234, 141, 158, 150, 32, 54, 105, 130, 210, 235, 115,
-            71, 136, 249, 50, 98, 125, 33, 20, 63, 7, 21, 164, 206, 128, 235, 123, 178, 124, 30,
-            33, 51, 40, 195, 132, 173, 39, 202, 95, 110, 0, 162, 23, 137, 201, 55, 15, 57, 106,
-            235, 188, 156, 61, 114, 10, 174, 41, 128, 42, 252, 113, 214, 77, 16, 238, 32, 236, 70,
-            139, 69, 68, 26, 121, 55, 233, 83, 116, 152, 48, 185, 130, 225, 142, 192, 112, 218,
-            253, 171, 126, 230, 128, 74, 251, 28, 15, 44, 86, 24, 172, 214, 56, 125, 22, 226, 30,
-            144, 222, 170, 183, 86, 162, 193, 59, 163, 11, 245, 67, 117, 43, 41, 211, 246, 146,
-            128, 163, 87, 215, 73, 240, 35, 44, 127, 73, 101, 212, 114, 170, 201, 100, 246, 17,
-            253, 119, 168, 232, 187, 51, 39, 139, 234, 2, 152, 243, 120, 44, 228, 128, 132, 220, 6,
-            255, 222, 136, 239, 82, 249, 233, 142, 5, 148, 32, 136, 57, 224, 142, 86, 126, 17, 138,
-            137, 68, 89, 197, 42, 134, 72, 54, 105, 250, 128, 92, 187, 114, 111, 209, 220, 174,
-            223, 213, 39, 229, 13, 50, 234, 154, 124, 62, 120, 168, 20, 137, 91, 147, 151, 6, 46,
-            70, 148, 199, 180, 0, 128, 128, 253, 216, 104, 15, 195, 83, 31, 85, 6, 177, 192, 180,
-            67, 107, 77, 9, 12, 55, 182, 235, 115, 200, 71, 68, 190, 10, 25, 215, 53, 44, 255, 156,
-            128, 60, 106, 75, 142, 183, 243, 52, 207, 38, 138, 150, 93, 200, 67, 157, 246, 180, 22,
-            137, 12, 131, 69, 201, 138, 5, 64, 84, 62, 64, 78, 179, 199, 77, 8, 128, 255, 255, 128,
-            21, 95, 36, 122, 109, 82, 214, 86, 36, 233, 147, 9, 162, 15, 102, 163, 18, 54, 145,
-            232, 242, 92, 13, 57, 165, 177, 124, 159, 134, 148, 230, 191, 128, 17, 180, 212, 187,
-            199, 116, 184, 60, 247, 67, 45, 186, 99, 105, 214, 152, 151, 73, 184, 35, 176, 77, 137,
-            22, 86, 133, 113, 242, 131, 0, 114, 248, 128, 225, 64, 116, 89, 219, 148, 156, 23, 45,
-            108, 174, 179, 142, 4, 217, 70, 205, 136, 140, 181, 132, 177, 59, 99, 206, 108, 35, 84,
-            201, 222, 39, 26, 128, 52, 63, 111, 5, 37, 101, 158, 213, 159, 237, 129, 146, 15, 212,
-            168, 102, 128, 134, 142, 108, 250, 94, 0, 180, 92, 11, 78, 6, 31, 236, 117, 18, 128, 6,
-            234, 209, 47, 60, 70, 239, 171, 95, 104, 208, 48, 160, 199, 230, 154, 11, 9, 142, 177,
-            62, 180, 79, 232, 211, 82, 242, 194, 201, 131, 55, 216, 128, 26, 178, 61, 58, 248, 177,
-            214, 141, 149, 119, 43, 226, 196, 36, 145, 205, 209, 220, 22, 67, 145, 235, 28, 21, 68,
-            78, 17, 120, 10, 136, 203, 24, 128, 182, 219, 79, 27, 116, 254, 54, 219, 149, 90, 99,
-            158, 133, 31, 236, 70, 251, 155, 212, 117, 127, 167, 65, 153, 216, 53, 45, 196, 83, 37,
-            232, 179, 128, 19, 182, 172, 52, 190, 147, 215, 30, 0, 60, 93, 205, 2, 201, 109, 82,
-            110, 164, 3, 7, 164, 214, 154, 147, 23, 39, 241, 169, 64, 83, 146, 0, 128, 127, 74,
-            176, 32, 37, 115, 76, 139, 211, 14, 236, 66, 70, 48, 218, 49, 75, 65, 242, 41, 224, 8,
-            173, 179, 96, 55, 245, 179, 172, 240, 182, 25, 128, 55, 115, 145, 131, 180, 160, 117,
-            172, 130, 14, 156, 99, 139, 15, 7, 92, 3, 101, 20, 195, 126, 192, 251, 240, 207, 63,
-            247, 21, 6, 254, 158, 11, 128, 38, 117, 118, 233, 8, 173, 43, 112, 52, 222, 176, 191,
-            214, 37, 140, 221, 108, 2, 5, 177, 124, 224, 37, 131, 226, 44, 139, 76, 119, 193, 106,
-            228, 128, 60, 66, 232, 6, 65, 57, 164, 242, 246, 193, 0, 186, 127, 11, 93, 189, 9, 5,
-            247, 228, 206, 41, 25, 126, 99, 255, 190, 56, 250, 18, 237, 163, 128, 180, 239, 24,
-            135, 163, 86, 144, 19, 95, 194, 3, 154, 158, 101, 108, 146, 217, 137, 206, 117, 162,
-            93, 253, 169, 85, 204, 228, 147, 230, 236, 78, 0, 128, 127, 165, 31, 245, 177, 222, 92,
-            217, 26, 171, 244, 149, 96, 40, 164, 152, 42, 34, 154, 179, 57, 108, 74, 212, 136, 80,
-            214, 0, 216, 39, 244, 98, 128, 233, 240, 108, 189, 251, 219, 73, 68, 43, 151, 120, 194,
-            137, 251, 91, 11, 244, 59, 6, 184, 236, 190, 61, 39, 87, 87, 222, 170, 186, 8, 25, 8,
-            128, 204, 207, 180, 179, 96, 147, 201, 61, 238, 185, 48, 155, 251, 199, 208, 118, 234,
-            226, 157, 221, 210, 63, 166, 242, 24, 216, 230, 91, 208, 87, 9, 15, 77, 8, 128, 255,
-            255, 128, 32, 71, 114, 154, 208, 90, 0, 91, 213, 125, 214, 149, 124, 79, 23, 205, 52,
-            232, 217, 89, 34, 42, 160, 39, 122, 43, 229, 98, 243, 42, 206, 40, 128, 131, 102, 63,
-            30, 5, 91, 18, 216, 61, 183, 253, 15, 9, 26, 163, 2, 218, 1, 134, 95, 193, 154, 158,
-            13, 78, 98, 203, 226, 183, 67, 171, 125, 128, 157, 109, 179, 216, 230, 139, 210, 90,
-            41, 101, 53, 14, 53, 48, 207, 54, 65, 67, 137, 49, 73, 123, 138, 221, 32, 141, 114,
-            111, 219, 172, 85, 158, 128, 44, 1, 232, 124, 192, 192, 10, 7, 124, 59, 229, 244, 183,
-            26, 152, 13, 90, 74, 46, 88, 99, 20, 179, 33, 33, 14, 121, 131, 218, 93, 228, 18, 128,
-            194, 190, 49, 52, 173, 254, 128, 110, 138, 177, 97, 153, 252, 60, 138, 233, 134, 69,
-            106, 32, 184, 185, 13, 81, 162, 128, 227, 210, 219, 50, 32, 56, 128, 220, 163, 230,
-            155, 6, 111, 176, 235, 78, 131, 102, 186, 89, 228, 98, 9, 122, 63, 152, 75, 221, 230,
-            177, 197, 127, 42, 86, 36, 149, 100, 211, 10, 128, 93, 16, 106, 37, 26, 141, 150, 6,
-            81, 253, 146, 149, 21, 130, 37, 174, 124, 41, 1, 106, 230, 129, 65, 69, 180, 62, 9, 64,
-            15, 109, 119, 154, 128, 222, 83, 57, 102, 10, 56, 28, 143, 48, 68, 101, 86, 234, 53,
-            203, 83, 164, 112, 62, 220, 93, 180, 69, 149, 250, 235, 250, 124, 141, 91, 182, 134,
-            128, 197, 220, 184, 144, 48, 87, 179, 9, 12, 245, 165, 103, 193, 28, 253, 79, 70, 230,
-            195, 131, 5, 124, 112, 171, 160, 54, 72, 205, 74, 36, 252, 245, 128, 245, 22, 100, 52,
-            187, 161, 87, 79, 148, 130, 213, 215, 219, 143, 29, 139, 179, 140, 108, 71, 105, 9, 8,
-            103, 53, 88, 66, 77, 219, 88, 209, 174, 128, 212, 186, 208, 207, 165, 55, 183, 68, 170,
-            127, 146, 158, 202, 36, 119, 238, 109, 55, 243, 183, 112, 162, 134, 253, 86, 22, 237,
-            103, 242, 252, 208, 237, 128, 17, 99, 203, 152, 149, 177, 197, 203, 162, 120, 228, 91,
-            138, 154, 181, 225, 237, 175, 118, 130, 210, 223, 193, 13, 112, 221, 215, 17, 6, 226,
-            73, 28, 128, 4, 216, 2, 157, 168, 220, 49, 106, 247, 67, 24, 49, 155, 69, 122, 72, 239,
-            54, 246, 135, 222, 46, 194, 58, 113, 145, 235, 169, 125, 64, 211, 122, 128, 195, 66,
-            41, 72, 199, 187, 180, 153, 243, 223, 71, 98, 195, 151, 156, 184, 112, 50, 29, 246,
-            216, 59, 20, 165, 7, 240, 232, 102, 97, 80, 164, 206, 128, 145, 63, 250, 26, 195, 75,
-            39, 16, 83, 141, 48, 10, 106, 126, 174, 199, 201, 116, 94, 48, 35, 20, 96, 5, 64, 109,
-            69, 226, 8, 122, 20, 148, 128, 101, 188, 79, 138, 0, 82, 49, 142, 224, 148, 243, 97,
-            110, 49, 210, 178, 183, 118, 23, 19, 0, 26, 255, 9, 38, 239, 0, 59, 152, 8, 232, 65,
-            77, 8, 128, 255, 255, 128, 52, 152, 211, 190, 190, 158, 24, 14, 187, 114, 245, 129, 54,
-            61, 154, 0, 117, 139, 218, 240, 191, 56, 170, 150, 251, 101, 38, 180, 152, 139, 79, 38,
-            128, 251, 253, 5, 15, 246, 96, 81, 182, 123, 17, 154, 162, 226, 188, 243, 6, 139, 58,
-            157, 116, 190, 107, 108, 13, 89, 59, 173, 22, 237, 116, 122, 235, 128, 179, 28, 208,
-            45, 209, 100, 121, 155, 83, 66, 137, 54, 219, 53, 142, 32, 150, 7, 172, 148, 18, 122,
-            21, 36, 182, 93, 206, 2, 165, 195, 64, 44, 128, 8, 180, 57, 2, 38, 131, 151, 161, 248,
-            77, 111, 109, 180, 209, 200, 113, 88, 154, 143, 210, 153, 37, 193, 151, 17, 159, 76,
-            65, 90, 104, 54, 176, 128, 18, 52, 74, 173, 8, 114, 201, 4, 207, 101, 41, 158, 121, 41,
-            143, 233, 34, 3, 199, 241, 113, 152, 253, 255, 203, 131, 13, 102, 74, 25, 177, 183,
-            128, 202, 97, 201, 10, 67, 13, 160, 242, 224, 111, 202, 243, 55, 6, 9, 56, 208, 220,
-            27, 245, 152, 201, 250, 249, 228, 7, 139, 211, 173, 141, 5, 174, 128, 220, 10, 234, 6,
-            246, 9, 0, 85, 192, 41, 60, 21, 71, 124, 110, 49, 251, 223, 34, 177, 93, 219, 34, 146,
-            191, 71, 237, 3, 129, 158, 76, 193, 128, 192, 236, 45, 201, 236, 228, 208, 145, 220,
-            128, 181, 224, 168, 124, 9, 188, 11, 192, 67, 96, 172, 46, 68, 103, 55, 211, 214, 222,
-            12, 139, 101, 140, 128, 86, 218, 58, 172, 28, 45, 161, 2, 182, 166, 215, 163, 52, 76,
-            140, 207, 108, 135, 124, 120, 62, 158, 57, 193, 15, 143, 147, 86, 16, 141, 186, 29,
-            128, 111, 168, 178, 161, 106, 91, 217, 175, 166, 28, 60, 105, 190, 193, 46, 168, 61,
-            135, 62, 166, 95, 215, 142, 58, 13, 243, 77, 117, 49, 78, 151, 160, 128, 191, 212, 224,
-            98, 47, 88, 40, 255, 123, 79, 95, 176, 156, 243, 208, 110, 56, 211, 109, 85, 137, 179,
-            175, 25, 13, 241, 107, 209, 177, 135, 146, 163, 128, 194, 209, 192, 145, 251, 123, 139,
-            22, 187, 137, 235, 254, 6, 111, 31, 158, 192, 69, 78, 108, 156, 64, 187, 115, 82, 223,
-            9, 82, 61, 51, 119, 95, 128, 245, 163, 92, 184, 100, 208, 179, 172, 214, 16, 140, 71,
-            242, 205, 253, 56, 207, 97, 126, 100, 165, 248, 139, 109, 172, 208, 235, 249, 200, 80,
-            220, 254, 128, 158, 200, 116, 18, 168, 59, 54, 44, 90, 135, 190, 184, 13, 98, 181, 73,
-            85, 229, 180, 57, 54, 210, 160, 124, 167, 184, 99, 32, 99, 12, 75, 105, 128, 11, 18,
-            66, 155, 163, 28, 120, 167, 85, 154, 216, 121, 19, 215, 47, 51, 104, 158, 103, 55, 192,
-            211, 168, 253, 220, 78, 160, 45, 253, 45, 211, 192, 128, 255, 205, 138, 183, 70, 207,
-            10, 69, 205, 195, 48, 211, 73, 18, 184, 30, 90, 174, 196, 104, 34, 171, 249, 50, 206,
-            143, 203, 180, 165, 233, 162, 162, 77, 8, 128, 255, 255, 128, 58, 201, 166, 146, 29,
-            113, 160, 18, 70, 236, 115, 70, 19, 165, 72, 68, 38, 85, 231, 110, 214, 218, 156, 215,
-            151, 175, 220, 188, 182, 73, 74, 17, 128, 57, 37, 64, 205, 152, 86, 106, 143, 131, 35,
-            221, 109, 149, 45, 141, 167, 37, 79, 236, 129, 65, 7, 181, 110, 55, 11, 250, 240, 52,
-            117, 169, 158, 128, 204, 101, 91, 16, 8, 83, 117, 119, 139, 121, 194, 37, 123, 134,
-            194, 90, 40, 219, 78, 233, 131, 48, 203, 219, 106, 122, 71, 25, 40, 11, 225, 153, 128,
-            58, 54, 217, 135, 108, 131, 104, 19, 90, 4, 53, 235, 35, 168, 213, 219, 94, 202, 119,
-            183, 11, 157, 233, 29, 160, 191, 108, 188, 51, 12, 60, 141, 128, 160, 174, 75, 199,
-            199, 233, 169, 101, 168, 78, 26, 106, 176, 105, 38, 129, 28, 247, 14, 214, 218, 221,
-            13, 240, 9, 20, 63, 123, 141, 26, 190, 139, 128, 45, 224, 62, 80, 60, 203, 182, 72,
-            101, 127, 17, 216, 166, 187, 183, 16, 192, 61, 92, 109, 127, 2, 139, 56, 251, 157, 175,
-            125, 230, 247, 185, 101, 128, 33, 130, 72, 131, 94, 76, 68, 224, 132, 150, 251, 90, 1,
-            34, 7, 183, 173, 178, 240, 110, 250, 90, 137, 100, 116, 16, 195, 20, 129, 2, 12, 105,
-            128, 88, 2, 36, 134, 82, 225, 134, 78, 174, 11, 164, 130, 125, 128, 124, 207, 48, 63,
-            217, 247, 112, 230, 13, 150, 217, 139, 64, 60, 81, 63, 44, 190, 128, 248, 1, 253, 234,
-            97, 207, 65, 159, 63, 170, 168, 182, 82, 204, 227, 23, 134, 91, 81, 166, 28, 113, 220,
-            58, 95, 180, 123, 243, 6, 127, 139, 43, 128, 196, 0, 96, 165, 125, 95, 251, 75, 16,
-            200, 144, 62, 177, 94, 27, 145, 69, 51, 153, 206, 126, 148, 236, 188, 24, 58, 222, 98,
-            81, 47, 192, 198, 128, 251, 20, 123, 1, 82, 61, 114, 201, 104, 55, 28, 164, 11, 225,
-            82, 170, 17, 15, 255, 80, 199, 143, 187, 252, 119, 113, 83, 197, 12, 133, 199, 101,
-            128, 0, 93, 235, 202, 77, 21, 48, 18, 116, 37, 121, 157, 205, 37, 139, 102, 158, 231,
-            50, 87, 189, 72, 167, 17, 211, 116, 181, 1, 120, 27, 19, 249, 128, 87, 232, 34, 89,
-            226, 3, 137, 227, 28, 203, 181, 169, 215, 140, 10, 249, 197, 27, 218, 50, 128, 145, 14,
-            251, 156, 87, 140, 184, 184, 215, 8, 42, 128, 214, 186, 104, 28, 203, 26, 133, 97, 30,
-            82, 219, 208, 159, 189, 193, 198, 175, 244, 101, 39, 137, 114, 76, 22, 132, 158, 108,
-            244, 117, 184, 90, 85, 128, 14, 208, 6, 122, 78, 49, 9, 54, 65, 194, 183, 96, 215, 66,
-            8, 73, 20, 5, 110, 155, 203, 10, 199, 77, 90, 151, 44, 247, 242, 234, 143, 76, 128,
-            137, 223, 40, 196, 188, 73, 243, 240, 70, 195, 150, 112, 119, 234, 146, 188, 3, 143,
-            95, 228, 15, 193, 177, 235, 203, 165, 146, 65, 141, 18, 248, 235, 77, 8, 128, 255, 255,
-            128, 72, 227, 186, 208, 65, 38, 67, 240, 103, 92, 8, 31, 242, 47, 245, 124, 69, 128,
-            43, 61, 106, 233, 158, 65, 44, 123, 190, 30, 136, 221, 162, 110, 128, 20, 154, 240,
-            177, 127, 113, 180, 62, 122, 234, 248, 200, 205, 42, 62, 125, 112, 181, 95, 33, 195,
-            255, 255, 169, 168, 104, 31, 17, 9, 24, 157, 171, 128, 208, 215, 84, 115, 128, 144,
-            126, 59, 82, 130, 127, 14, 160, 184, 204, 246, 129, 118, 140, 182, 5, 115, 188, 129,
-            114, 59, 124, 74, 133, 195, 116, 82, 128, 245, 84, 141, 238, 0, 88, 3, 93, 78, 160,
-            149, 210, 209, 205, 188, 118, 155, 9, 41, 170, 77, 84, 141, 233, 167, 40, 235, 193,
-            187, 22, 235, 200, 128, 67, 167, 238, 15, 193, 10, 24, 162, 254, 231, 215, 199, 172,
-            254, 225, 135, 55, 189, 91, 55, 228, 121, 131, 223, 125, 40, 54, 153, 135, 202, 139,
-            121, 128, 40, 199, 178, 138, 237, 194, 159, 253, 110, 97, 131, 44, 90, 159, 151, 221,
-            200, 60, 141, 9, 61, 225, 19, 96, 144, 170, 177, 48, 242, 92, 158, 235, 128, 252, 114,
-            254, 107, 51, 251, 164, 43, 155, 188, 232, 54, 164, 242, 239, 19, 144, 44, 168, 19,
-            159, 30, 79, 200, 254, 66, 197, 216, 213, 160, 83, 224, 128, 152, 98, 71, 87, 176, 216,
-            190, 121, 251, 201, 190, 32, 15, 23, 229, 227, 64, 32, 180, 98, 168, 212, 246, 224,
-            140, 113, 76, 238, 1, 159, 197, 67, 128, 183, 68, 13, 132, 134, 132, 53, 250, 94, 25,
-            59, 107, 189, 188, 146, 126, 239, 148, 197, 234, 108, 254, 34, 173, 202, 44, 89, 168,
-            218, 147, 247, 187, 128, 203, 121, 148, 63, 84, 92, 146, 115, 80, 45, 207, 173, 216,
-            199, 47, 180, 75, 35, 145, 102, 158, 38, 123, 19, 85, 144, 176, 241, 59, 96, 81, 31,
-            128, 28, 122, 35, 15, 86, 120, 97, 7, 171, 19, 190, 105, 208, 148, 100, 236, 249, 120,
-            241, 229, 197, 41, 220, 148, 178, 32, 20, 138, 252, 160, 3, 181, 128, 107, 53, 208, 95,
-            35, 48, 191, 237, 246, 105, 67, 216, 186, 129, 234, 10, 245, 180, 72, 82, 209, 69, 228,
-            22, 204, 157, 177, 214, 7, 125, 130, 162, 128, 200, 182, 239, 18, 113, 83, 136, 129,
-            241, 86, 75, 2, 108, 211, 184, 233, 133, 125, 126, 229, 197, 18, 141, 74, 49, 209, 251,
-            162, 123, 132, 67, 241, 128, 98, 169, 128, 71, 226, 12, 74, 91, 45, 220, 144, 255, 5,
-            43, 4, 161, 5, 168, 254, 146, 163, 188, 181, 188, 245, 209, 229, 67, 252, 181, 99, 38,
-            128, 141, 190, 165, 156, 57, 149, 153, 127, 68, 145, 42, 198, 63, 244, 246, 245, 163,
-            214, 214, 230, 239, 171, 146, 69, 208, 69, 33, 128, 110, 194, 184, 150, 128, 87, 53,
-            244, 157, 86, 127, 165, 192, 182, 32, 74, 181, 129, 234, 159, 244, 180, 47, 218, 164,
-            250, 98, 146, 170, 222, 30, 50, 65, 128, 99, 114, 192, 77, 8, 128, 255, 255, 128, 98,
-            224, 118, 12, 76, 51, 177, 134, 55, 11, 240, 54, 16, 130, 71, 53, 233, 48, 237, 233,
-            145, 55, 196, 116, 75, 125, 25, 10, 222, 110, 145, 72, 128, 123, 97, 129, 68, 255, 146,
-            109, 130, 170, 164, 51, 127, 247, 61, 60, 39, 45, 247, 96, 65, 102, 249, 254, 240, 235,
-            150, 72, 191, 254, 139, 212, 143, 128, 10, 145, 115, 111, 253, 223, 196, 17, 64, 94,
-            25, 146, 103, 246, 48, 225, 201, 116, 96, 194, 171, 54, 38, 98, 86, 3, 3, 71, 129, 31,
-            6, 175, 128, 199, 195, 90, 57, 51, 127, 202, 28, 248, 27, 156, 174, 209, 38, 194, 27,
-            143, 96, 209, 92, 159, 32, 96, 206, 102, 91, 145, 17, 100, 34, 161, 225, 128, 111, 174,
-            226, 248, 116, 189, 185, 98, 48, 158, 21, 64, 84, 37, 132, 251, 159, 119, 145, 158,
-            160, 94, 50, 14, 78, 36, 7, 69, 225, 169, 118, 163, 128, 130, 131, 93, 107, 228, 124,
-            194, 73, 133, 43, 145, 205, 67, 139, 12, 122, 202, 182, 137, 8, 117, 46, 210, 14, 252,
-            151, 44, 230, 172, 18, 153, 208, 128, 90, 123, 171, 183, 168, 73, 23, 229, 112, 45,
-            203, 101, 146, 205, 166, 179, 139, 173, 186, 161, 12, 37, 201, 110, 4, 24, 59, 86, 176,
-            85, 19, 214, 128, 34, 137, 24, 131, 123, 49, 233, 144, 163, 244, 68, 122, 147, 162,
-            184, 83, 218, 111, 153, 130, 208, 164, 104, 189, 32, 120, 168, 115, 238, 172, 147, 241,
-            128, 253, 185, 119, 165, 240, 12, 10, 37, 37, 189, 229, 131, 173, 106, 160, 226, 95,
-            234, 200, 170, 142, 79, 97, 231, 226, 69, 40, 164, 129, 29, 119, 217, 128, 215, 202,
-            10, 223, 65, 233, 98, 218, 223, 110, 11, 98, 108, 232, 78, 52, 25, 27, 11, 140, 93,
-            219, 35, 105, 104, 192, 213, 107, 103, 160, 147, 131, 128, 213, 23, 236, 208, 56, 116,
-            59, 227, 151, 243, 244, 198, 4, 58, 214, 202, 73, 178, 35, 192, 153, 13, 96, 246, 250,
-            18, 143, 18, 70, 157, 58, 4, 128, 50, 104, 164, 63, 181, 151, 229, 156, 25, 254, 186,
-            145, 93, 195, 131, 202, 231, 191, 255, 114, 215, 176, 156, 119, 36, 246, 10, 30, 88,
-            250, 251, 240, 128, 103, 233, 178, 7, 124, 202, 44, 82, 24, 123, 141, 45, 162, 11, 34,
-            22, 42, 104, 22, 81, 73, 15, 223, 49, 220, 156, 80, 188, 243, 88, 152, 39, 128, 24, 57,
-            95, 182, 144, 171, 157, 147, 39, 2, 248, 223, 190, 4, 238, 26, 205, 51, 243, 21, 92,
-            81, 253, 196, 58, 117, 8, 176, 3, 193, 96, 167, 128, 151, 147, 172, 137, 135, 140, 127,
-            19, 211, 158, 45, 240, 247, 87, 188, 12, 50, 211, 176, 157, 204, 105, 116, 196, 132,
-            232, 58, 25, 94, 127, 118, 37, 128, 243, 24, 174, 152, 134, 125, 213, 8, 179, 123, 215,
-            74, 241, 38, 134, 46, 15, 246, 224, 164, 243, 101, 224, 238, 242, 220, 108, 49, 7, 49,
-            19, 171, 77, 8, 128, 255, 255, 128, 126, 26, 2, 115, 125, 60, 237, 15, 224, 8, 167,
-            168, 170, 64, 193, 115, 82, 79, 139, 126, 45, 146, 247, 235, 138, 57, 197, 193, 187,
-            235, 128, 255, 128, 25, 176, 10, 6, 193, 115, 82, 78, 202, 212, 37, 48, 143, 31, 79,
-            135, 198, 58, 65, 93, 240, 196, 114, 75, 89, 200, 211, 24, 49, 211, 43, 248, 128, 139,
-            88, 169, 62, 211, 4, 166, 73, 191, 142, 142, 178, 189, 92, 38, 216, 103, 217, 211, 148,
-            1, 115, 180, 191, 77, 82, 223, 207, 20, 125, 130, 6, 128, 209, 115, 203, 18, 175, 206,
-            244, 86, 105, 74, 157, 81, 221, 58, 102, 232, 144, 178, 104, 52, 9, 139, 91, 182, 234,
-            82, 83, 165, 63, 160, 7, 114, 128, 243, 117, 5, 152, 169, 216, 80, 167, 179, 87, 94,
-            251, 216, 140, 34, 48, 215, 29, 91, 152, 228, 185, 96, 65, 138, 128, 76, 168, 225, 197,
-            100, 250, 128, 73, 28, 242, 167, 201, 42, 255, 15, 244, 48, 253, 183, 243, 188, 29,
-            212, 143, 41, 81, 54, 199, 149, 4, 212, 255, 92, 150, 2, 85, 79, 165, 249, 128, 70,
-            150, 17, 211, 84, 245, 117, 100, 205, 53, 45, 9, 109, 239, 251, 118, 83, 123, 151, 163,
-            98, 7, 31, 40, 101, 127, 254, 39, 185, 235, 191, 126, 128, 172, 29, 177, 47, 180, 240,
-            225, 255, 229, 172, 48, 134, 20, 239, 15, 216, 82, 179, 108, 203, 153, 93, 70, 159,
-            209, 54, 236, 145, 124, 170, 81, 107, 128, 186, 239, 163, 235, 187, 188, 130, 158, 8,
-            16, 226, 8, 219, 34, 130, 174, 188, 108, 89, 154, 67, 90, 241, 11, 216, 9, 10, 128, 23,
-            129, 19, 221, 128, 186, 48, 62, 144, 230, 102, 102, 238, 208, 166, 83, 134, 87, 62,
-            187, 7, 25, 145, 177, 195, 61, 237, 224, 149, 223, 100, 143, 62, 16, 52, 146, 152, 128,
-            156, 234, 72, 98, 6, 27, 209, 167, 158, 64, 242, 192, 158, 62, 9, 86, 106, 165, 19, 16,
-            74, 190, 17, 113, 127, 135, 236, 235, 0, 127, 106, 39, 128, 40, 166, 91, 35, 4, 38,
-            216, 38, 58, 31, 180, 163, 228, 163, 221, 27, 13, 131, 28, 101, 92, 226, 34, 98, 131,
-            32, 29, 241, 155, 64, 143, 57, 128, 82, 245, 36, 234, 173, 169, 46, 196, 144, 134, 113,
-            200, 13, 87, 68, 4, 37, 101, 66, 251, 109, 83, 160, 85, 86, 69, 174, 215, 89, 230, 112,
-            188, 128, 221, 67, 169, 147, 247, 63, 47, 70, 136, 91, 226, 27, 120, 51, 140, 162, 62,
-            91, 88, 70, 44, 204, 72, 185, 113, 201, 160, 83, 159, 129, 97, 215, 128, 132, 55, 78,
-            3, 203, 239, 90, 240, 56, 255, 237, 43, 30, 91, 205, 38, 41, 33, 240, 10, 17, 141, 71,
-            173, 58, 21, 199, 163, 147, 192, 51, 251, 128, 180, 232, 114, 114, 129, 107, 156, 91,
-            142, 40, 134, 148, 85, 46, 182, 150, 215, 2, 32, 125, 172, 93, 123, 212, 118, 25, 55,
-            24, 116, 138, 17, 101, 77, 8, 128, 255, 255, 128, 128, 254, 250, 23, 70, 79, 240, 10,
-            70, 190, 77, 23, 122, 171, 19, 37, 218, 153, 112, 130, 94, 30, 206, 18, 150, 32, 162,
-            65, 73, 163, 16, 207, 128, 137, 190, 230, 69, 134, 11, 100, 164, 10, 55, 253, 247, 153,
-            197, 172, 120, 125, 235, 240, 22, 86, 199, 75, 131, 220, 63, 150, 25, 139, 71, 46, 106,
-            128, 73, 13, 115, 235, 64, 36, 245, 221, 252, 29, 178, 97, 173, 142, 37, 101, 82, 201,
-            121, 39, 123, 78, 17, 212, 132, 196, 101, 210, 70, 235, 119, 106, 128, 253, 222, 171,
-            127, 115, 14, 115, 223, 212, 163, 30, 77, 202, 78, 253, 70, 8, 102, 29, 54, 82, 179,
-            118, 125, 228, 121, 204, 89, 155, 222, 147, 83, 128, 227, 191, 35, 207, 4, 38, 245,
-            230, 87, 71, 56, 117, 73, 164, 79, 249, 251, 166, 168, 12, 182, 31, 114, 208, 98, 190,
-            35, 40, 34, 54, 149, 142, 128, 210, 191, 125, 82, 15, 167, 39, 230, 247, 255, 40, 150,
-            213, 65, 72, 78, 231, 253, 153, 154, 181, 54, 10, 85, 83, 168, 104, 19, 123, 238, 210,
-            157, 128, 188, 148, 108, 164, 57, 34, 79, 98, 107, 189, 224, 8, 59, 254, 177, 215, 116,
-            47, 35, 20, 70, 19, 107, 248, 229, 173, 205, 99, 127, 84, 140, 38, 128, 89, 233, 77,
-            43, 63, 224, 59, 236, 215, 221, 139, 212, 69, 171, 94, 165, 14, 206, 152, 89, 1, 53,
-            196, 34, 246, 82, 59, 225, 142, 199, 88, 243, 128, 117, 226, 17, 47, 123, 236, 238, 34,
-            252, 243, 63, 155, 180, 126, 70, 157, 186, 178, 213, 77, 110, 145, 185, 75, 124, 249,
-            105, 205, 153, 181, 43, 36, 128, 157, 170, 117, 166, 123, 135, 0, 223, 80, 39, 185, 37,
-            13, 197, 134, 103, 88, 139, 185, 94, 124, 140, 157, 193, 219, 105, 162, 48, 142, 116,
-            46, 248, 128, 160, 91, 89, 227, 55, 123, 4, 89, 135, 36, 197, 89, 65, 101, 147, 121,
-            121, 162, 65, 36, 178, 25, 40, 209, 173, 255, 147, 13, 45, 189, 157, 250, 128, 41, 153,
-            119, 56, 243, 143, 95, 161, 80, 46, 98, 74, 216, 137, 226, 201, 173, 30, 216, 212, 152,
-            61, 217, 17, 119, 35, 134, 112, 232, 65, 164, 120, 128, 165, 55, 127, 175, 186, 31, 23,
-            220, 207, 96, 33, 98, 108, 159, 8, 38, 117, 139, 15, 18, 75, 119, 213, 84, 118, 33,
-            191, 143, 40, 188, 193, 157, 128, 222, 213, 70, 76, 206, 239, 36, 101, 17, 253, 75,
-            245, 2, 241, 235, 87, 87, 222, 38, 84, 92, 92, 188, 240, 191, 129, 94, 229, 17, 75, 55,
-            228, 128, 162, 140, 154, 201, 203, 90, 176, 148, 127, 231, 168, 21, 129, 93, 169, 155,
-            69, 224, 117, 12, 52, 111, 190, 79, 218, 237, 3, 26, 139, 72, 33, 23, 128, 28, 63, 210,
-            252, 209, 52, 215, 165, 54, 173, 246, 45, 171, 12, 203, 82, 21, 25, 104, 150, 58, 235,
-            34, 234, 147, 220, 232, 209, 128, 233, 52, 165, 77, 8, 128, 255, 255, 128, 137, 99,
-            201, 150, 213, 190, 114, 244, 6, 179, 169, 97, 77, 105, 45, 53, 22, 156, 93, 12, 184,
-            184, 84, 163, 91, 104, 22, 72, 172, 99, 223, 187, 128, 197, 219, 148, 198, 173, 94, 34,
-            66, 48, 182, 111, 221, 140, 160, 148, 77, 231, 210, 200, 148, 207, 51, 95, 208, 62,
-            210, 245, 20, 68, 139, 200, 4, 128, 77, 144, 61, 88, 8, 197, 14, 144, 145, 125, 56,
-            136, 208, 232, 145, 155, 219, 207, 92, 164, 189, 153, 166, 199, 121, 225, 217, 127, 9,
-            134, 39, 186, 128, 196, 124, 80, 137, 76, 55, 85, 185, 142, 135, 241, 142, 186, 100,
-            253, 165, 77, 160, 85, 32, 134, 41, 247, 184, 65, 131, 59, 132, 169, 9, 30, 166, 128,
-            18, 117, 140, 186, 228, 64, 135, 84, 30, 98, 181, 80, 230, 253, 70, 136, 95, 136, 171,
-            130, 46, 36, 28, 19, 143, 163, 105, 132, 15, 51, 193, 199, 128, 221, 162, 121, 197, 49,
-            117, 171, 105, 13, 99, 191, 44, 219, 54, 37, 241, 44, 45, 172, 149, 42, 252, 240, 231,
-            151, 32, 250, 229, 157, 108, 230, 123, 128, 85, 204, 145, 73, 4, 179, 123, 90, 28, 38,
-            46, 107, 205, 172, 132, 83, 73, 150, 32, 87, 203, 253, 11, 237, 250, 4, 168, 207, 139,
-            102, 15, 50, 128, 79, 6, 201, 246, 121, 70, 151, 141, 50, 106, 166, 207, 183, 186, 173,
-            93, 245, 23, 122, 225, 14, 110, 67, 117, 79, 88, 197, 78, 27, 243, 172, 211, 128, 26,
-            191, 13, 212, 124, 197, 72, 93, 188, 69, 32, 48, 26, 65, 16, 151, 10, 142, 204, 17,
-            255, 100, 85, 131, 92, 60, 48, 128, 164, 159, 216, 161, 128, 176, 133, 137, 219, 162,
-            236, 2, 48, 223, 221, 131, 212, 23, 43, 87, 248, 115, 231, 110, 26, 95, 225, 207, 107,
-            93, 164, 187, 72, 69, 86, 225, 188, 128, 50, 147, 36, 178, 48, 203, 172, 96, 168, 69,
-            36, 123, 104, 92, 74, 223, 197, 134, 151, 188, 138, 123, 247, 105, 170, 126, 38, 79,
-            175, 6, 110, 251, 128, 91, 14, 212, 160, 69, 125, 86, 21, 62, 176, 59, 10, 244, 109, 5,
-            126, 233, 245, 115, 236, 1, 216, 100, 233, 73, 229, 190, 213, 199, 186, 115, 57, 128,
-            122, 80, 179, 183, 66, 91, 86, 66, 44, 113, 18, 236, 200, 216, 160, 62, 130, 153, 124,
-            125, 218, 30, 160, 165, 232, 219, 187, 161, 206, 217, 238, 164, 128, 23, 137, 235, 127,
-            228, 222, 79, 161, 131, 192, 77, 101, 19, 4, 8, 144, 158, 213, 48, 93, 185, 40, 209,
-            229, 224, 60, 56, 207, 3, 27, 102, 215, 128, 149, 254, 169, 111, 198, 105, 94, 163,
-            208, 239, 170, 235, 31, 98, 253, 82, 71, 239, 23, 186, 176, 141, 237, 85, 39, 235, 244,
-            19, 248, 173, 73, 74, 128, 18, 246, 63, 139, 55, 101, 233, 177, 71, 116, 10, 149, 84,
-            98, 53, 181, 234, 20, 179, 187, 71, 94, 87, 226, 132, 165, 35, 69, 46, 19, 162, 231,
-            77, 8, 128, 255, 255, 128, 156, 1, 8, 84, 91, 129, 182, 188, 2, 83, 7, 165, 8, 120,
-            243, 87, 173, 180, 121, 91, 65, 154, 45, 21, 71, 174, 77, 241, 179, 136, 13, 72, 128,
-            230, 28, 144, 93, 130, 226, 188, 221, 170, 208, 100, 82, 172, 225, 62, 209, 46, 68,
-            196, 33, 67, 4, 255, 22, 37, 196, 36, 174, 251, 236, 118, 222, 128, 214, 118, 96, 46,
-            102, 89, 165, 41, 246, 190, 182, 43, 105, 90, 176, 76, 189, 195, 215, 36, 237, 235,
-            187, 37, 79, 1, 100, 87, 76, 41, 73, 144, 128, 54, 183, 21, 156, 176, 38, 153, 68, 182,
-            160, 1, 199, 236, 221, 5, 213, 176, 16, 142, 78, 216, 40, 162, 25, 141, 11, 188, 248,
-            30, 210, 31, 163, 128, 75, 85, 160, 58, 176, 187, 39, 87, 199, 87, 164, 214, 101, 23,
-            26, 161, 102, 141, 46, 78, 231, 201, 60, 193, 167, 203, 38, 224, 183, 212, 245, 200,
-            128, 195, 26, 85, 247, 19, 6, 184, 129, 146, 36, 245, 138, 228, 216, 21, 170, 212, 18,
-            69, 21, 148, 124, 116, 72, 53, 13, 208, 37, 217, 253, 6, 212, 128, 40, 219, 41, 78,
-            113, 46, 62, 53, 196, 90, 196, 164, 54, 253, 126, 163, 171, 242, 23, 98, 212, 184, 39,
-            40, 163, 161, 94, 91, 183, 100, 173, 194, 128, 134, 161, 195, 243, 2, 138, 198, 95,
-            202, 238, 164, 185, 49, 3, 130, 20, 17, 174, 158, 221, 122, 51, 240, 97, 103, 182, 227,
-            64, 20, 199, 20, 49, 128, 255, 72, 48, 114, 3, 35, 14, 17, 46, 168, 199, 166, 43, 153,
-            163, 166, 53, 162, 127, 82, 97, 138, 54, 53, 35, 12, 177, 237, 37, 53, 113, 72, 128,
-            46, 149, 49, 64, 22, 110, 232, 250, 216, 176, 185, 77, 36, 109, 242, 191, 137, 155, 42,
-            3, 180, 61, 5, 146, 70, 239, 219, 5, 155, 189, 189, 161, 128, 241, 246, 18, 164, 219,
-            201, 100, 248, 235, 228, 160, 102, 103, 123, 5, 69, 48, 159, 2, 44, 149, 207, 228, 250,
-            167, 169, 178, 164, 72, 221, 10, 171, 128, 165, 52, 248, 115, 98, 191, 225, 147, 225,
-            201, 200, 167, 240, 3, 242, 222, 73, 84, 112, 54, 52, 42, 159, 193, 123, 184, 193, 197,
-            167, 182, 220, 134, 128, 126, 186, 202, 232, 87, 89, 157, 125, 156, 148, 133, 242, 194,
-            73, 236, 186, 151, 239, 221, 24, 95, 9, 136, 87, 139, 73, 49, 113, 145, 93, 7, 62, 128,
-            172, 197, 129, 8, 203, 252, 76, 29, 110, 106, 58, 21, 178, 190, 175, 177, 137, 207, 54,
-            251, 73, 35, 232, 228, 250, 155, 129, 160, 134, 206, 127, 235, 128, 248, 191, 25, 102,
-            35, 45, 111, 123, 238, 86, 29, 254, 128, 68, 227, 175, 192, 93, 19, 217, 162, 138, 47,
-            252, 170, 21, 173, 80, 187, 35, 100, 208, 128, 50, 196, 199, 63, 198, 32, 22, 234, 199,
-            53, 49, 204, 66, 56, 241, 135, 31, 199, 250, 237, 131, 168, 77, 113, 91, 198, 114, 41,
-            43, 164, 199, 210, 77, 8, 128, 255, 255, 128, 161, 170, 188, 190, 218, 88, 84, 19, 91,
-            88, 194, 120, 165, 126, 95, 15, 221, 19, 203, 0, 88, 211, 220, 121, 20, 193, 150, 197,
-            57, 168, 204, 61, 128, 174, 73, 255, 182, 60, 253, 41, 184, 208, 16, 215, 111, 181, 47,
-            64, 67, 144, 183, 182, 191, 233, 211, 225, 217, 247, 13, 18, 235, 176, 230, 25, 21,
-            128, 245, 160, 188, 5, 44, 199, 63, 129, 18, 187, 84, 101, 202, 147, 10, 147, 49, 3,
-            218, 70, 121, 111, 233, 66, 32, 233, 248, 133, 165, 13, 28, 219, 128, 14, 131, 238,
-            132, 41, 5, 14, 36, 248, 102, 22, 239, 179, 79, 208, 144, 227, 144, 199, 3, 94, 206,
-            131, 69, 146, 217, 208, 123, 251, 157, 205, 225, 128, 201, 255, 73, 224, 110, 158, 8,
-            94, 36, 55, 212, 237, 246, 37, 55, 70, 188, 222, 152, 76, 249, 234, 109, 66, 106, 45,
-            72, 221, 238, 43, 110, 75, 128, 38, 150, 145, 120, 219, 99, 149, 1, 78, 49, 180, 66,
-            12, 123, 211, 181, 120, 66, 152, 13, 242, 211, 225, 0, 43, 152, 83, 59, 130, 55, 158,
-            228, 128, 100, 229, 120, 116, 34, 155, 226, 67, 141, 201, 28, 67, 9, 149, 245, 162,
-            250, 33, 233, 147, 156, 18, 139, 46, 109, 175, 110, 62, 250, 255, 210, 189, 128, 222,
-            185, 16, 105, 45, 31, 111, 154, 244, 95, 235, 90, 16, 147, 39, 115, 135, 248, 251, 150,
-            146, 57, 252, 46, 166, 223, 188, 49, 240, 234, 13, 158, 128, 33, 232, 62, 9, 240, 6,
-            208, 13, 100, 149, 241, 92, 217, 45, 171, 37, 171, 218, 162, 174, 16, 47, 220, 90, 75,
-            48, 68, 124, 218, 138, 55, 107, 128, 103, 57, 14, 58, 242, 151, 78, 13, 140, 17, 77,
-            20, 3, 138, 153, 157, 25, 43, 143, 232, 153, 195, 243, 132, 121, 2, 137, 118, 173, 42,
-            18, 130, 128, 220, 215, 139, 137, 220, 192, 81, 98, 186, 83, 66, 246, 89, 5, 28, 36,
-            157, 107, 51, 113, 149, 84, 177, 143, 36, 186, 87, 61, 170, 103, 132, 151, 128, 46,
-            137, 147, 218, 166, 199, 59, 11, 162, 119, 139, 113, 85, 21, 33, 126, 149, 93, 122, 75,
-            232, 194, 66, 202, 86, 21, 224, 108, 135, 52, 133, 99, 128, 136, 117, 227, 97, 91, 152,
-            206, 125, 157, 195, 110, 114, 146, 128, 133, 192, 53, 85, 62, 141, 210, 118, 103, 252,
-            54, 41, 18, 47, 156, 42, 197, 6, 128, 177, 173, 252, 1, 57, 120, 8, 117, 140, 223, 84,
-            189, 163, 99, 215, 140, 235, 62, 53, 235, 56, 186, 37, 242, 41, 101, 60, 179, 144, 127,
-            14, 167, 128, 108, 64, 169, 3, 47, 216, 115, 25, 60, 249, 194, 24, 40, 42, 40, 173,
-            243, 102, 103, 209, 105, 30, 255, 123, 74, 82, 84, 37, 14, 19, 27, 124, 128, 14, 185,
-            46, 220, 127, 28, 164, 140, 32, 139, 241, 98, 71, 73, 138, 142, 127, 110, 90, 3, 172,
-            238, 101, 44, 220, 132, 205, 186, 94, 173, 21, 95, 77, 8, 128, 255, 255, 128, 168, 223,
-            25, 11, 206, 236, 171, 241, 168, 7, 44, 229, 142, 32, 218, 112, 90, 156, 111, 64, 130,
-            88, 124, 95, 39, 103, 100, 229, 18, 248, 121, 81, 128, 173, 73, 101, 206, 33, 240, 110,
-            100, 50, 233, 29, 115, 6, 148, 121, 110, 108, 122, 117, 183, 147, 109, 246, 48, 112,
-            83, 111, 156, 15, 4, 60, 75, 128, 22, 191, 84, 38, 63, 203, 40, 96, 183, 176, 170, 253,
-            141, 66, 144, 99, 155, 239, 192, 137, 33, 5, 0, 155, 134, 161, 228, 213, 51, 241, 199,
-            5, 128, 255, 29, 100, 191, 213, 214, 208, 199, 139, 190, 129, 8, 130, 20, 155, 156,
-            192, 65, 253, 63, 170, 224, 44, 156, 85, 163, 220, 218, 84, 40, 90, 75, 128, 131, 103,
-            65, 192, 138, 102, 118, 137, 191, 238, 116, 89, 53, 132, 154, 108, 119, 7, 57, 224,
-            123, 106, 166, 147, 108, 163, 73, 159, 224, 89, 93, 100, 128, 142, 245, 75, 52, 182, 2,
-            50, 50, 55, 244, 125, 107, 123, 69, 137, 65, 158, 59, 98, 196, 253, 79, 62, 145, 255,
-            200, 27, 4, 38, 231, 230, 66, 128, 32, 76, 194, 99, 158, 73, 99, 190, 128, 213, 50,
-            172, 63, 224, 128, 120, 196, 33, 85, 82, 174, 161, 222, 124, 74, 169, 99, 56, 246, 239,
-            102, 251, 128, 225, 75, 1, 43, 138, 191, 52, 196, 247, 204, 144, 239, 61, 142, 125, 36,
-            7, 82, 37, 185, 161, 215, 97, 79, 249, 120, 241, 151, 162, 113, 193, 240, 128, 78, 100,
-            19, 32, 69, 26, 151, 245, 12, 12, 164, 216, 124, 219, 29, 59, 171, 50, 240, 123, 5,
-            189, 80, 155, 53, 169, 24, 60, 221, 190, 239, 138, 128, 37, 202, 46, 52, 121, 244, 43,
-            35, 82, 59, 162, 209, 187, 193, 150, 50, 18, 61, 162, 156, 172, 159, 5, 34, 120, 183,
-            237, 222, 15, 51, 98, 15, 128, 214, 215, 183, 54, 157, 86, 208, 53, 194, 194, 180, 166,
-            204, 122, 184, 39, 161, 169, 213, 53, 217, 45, 177, 222, 144, 217, 226, 250, 145, 159,
-            46, 66, 128, 84, 107, 60, 172, 40, 120, 176, 253, 9, 181, 23, 51, 211, 193, 124, 85,
-            37, 210, 8, 243, 129, 188, 254, 242, 197, 206, 56, 31, 126, 221, 246, 122, 128, 74,
-            176, 47, 192, 71, 227, 110, 159, 95, 177, 45, 87, 106, 251, 81, 229, 6, 121, 193, 191,
-            214, 62, 142, 135, 219, 38, 176, 129, 80, 46, 186, 86, 128, 57, 42, 237, 125, 44, 113,
-            246, 243, 236, 154, 250, 193, 180, 225, 81, 8, 228, 9, 128, 119, 167, 65, 255, 134,
-            195, 139, 195, 200, 211, 57, 148, 69, 128, 122, 25, 208, 150, 207, 187, 6, 193, 249,
-            234, 175, 181, 47, 148, 109, 209, 128, 129, 18, 214, 156, 2, 17, 122, 23, 114, 165, 66,
-            16, 87, 128, 78, 128, 133, 118, 210, 161, 195, 242, 152, 179, 136, 192, 161, 168, 232,
-            145, 119, 44, 206, 69, 150, 202, 131, 232, 153, 33, 94, 211, 167, 64, 0, 147, 18, 145,
-            77, 8, 128, 255, 255, 128, 235, 170, 176, 106, 31, 135, 91, 27, 168, 205, 4, 210, 244,
-            245, 153, 39, 146, 173, 71, 237, 34, 85, 25, 35, 65, 251, 16, 181, 194, 133, 69, 177,
-            128, 30, 105, 245, 56, 77, 120, 75, 118, 9, 103, 147, 15, 128, 37, 232, 103, 192, 56,
-            91, 149, 243, 47, 3, 44, 248, 217, 163, 21, 81, 78, 7, 44, 128, 219, 63, 87, 8, 229,
-            222, 132, 250, 1, 225, 51, 237, 254, 225, 29, 204, 131, 255, 36, 183, 178, 64, 127, 15,
-            70, 65, 169, 135, 234, 35, 190, 42, 128, 76, 160, 43, 46, 103, 108, 169, 140, 103, 27,
-            31, 189, 229, 148, 228, 166, 43, 33, 214, 75, 165, 53, 37, 39, 41, 130, 233, 94, 21,
-            229, 163, 118, 128, 120, 29, 226, 227, 235, 204, 91, 18, 175, 148, 50, 116, 126, 12,
-            234, 177, 74, 190, 46, 22, 132, 176, 114, 171, 146, 51, 253, 7, 0, 173, 40, 190, 128,
-            10, 193, 46, 129, 134, 245, 146, 190, 101, 121, 204, 152, 197, 80, 63, 220, 158, 200,
-            3, 88, 43, 44, 24, 165, 47, 181, 36, 193, 216, 241, 206, 153, 128, 90, 202, 167, 68,
-            255, 202, 210, 189, 211, 102, 71, 223, 102, 46, 128, 75, 170, 79, 177, 199, 67, 251,
-            183, 170, 185, 240, 181, 231, 43, 232, 225, 37, 128, 57, 84, 84, 151, 169, 159, 193,
-            167, 123, 121, 3, 115, 144, 171, 214, 20, 201, 43, 11, 242, 239, 31, 98, 31, 245, 174,
-            91, 190, 231, 85, 96, 239, 128, 35, 46, 229, 132, 40, 97, 158, 102, 223, 11, 72, 121,
-            149, 157, 232, 35, 79, 168, 102, 156, 86, 110, 1, 163, 100, 191, 166, 149, 195, 160,
-            170, 76, 128, 147, 191, 101, 119, 78, 36, 203, 198, 170, 164, 76, 173, 14, 57, 162,
-            118, 53, 78, 69, 161, 117, 97, 154, 32, 69, 17, 180, 12, 124, 129, 5, 14, 128, 238,
-            162, 232, 223, 55, 63, 249, 78, 244, 140, 123, 149, 7, 55, 164, 246, 80, 99, 65, 115,
-            207, 179, 161, 63, 52, 204, 24, 202, 215, 242, 56, 91, 128, 69, 153, 241, 208, 132,
-            246, 94, 133, 85, 90, 253, 147, 62, 108, 52, 231, 201, 200, 204, 100, 172, 217, 54, 40,
-            58, 143, 109, 54, 93, 201, 144, 103, 128, 238, 151, 217, 228, 185, 160, 7, 251, 223,
-            94, 194, 138, 47, 1, 36, 8, 197, 236, 30, 85, 222, 21, 46, 179, 128, 162, 136, 151, 90,
-            189, 253, 212, 128, 253, 255, 224, 120, 100, 7, 148, 55, 13, 206, 19, 157, 182, 204,
-            14, 230, 188, 48, 69, 14, 155, 189, 228, 115, 84, 223, 109, 202, 174, 60, 215, 10, 128,
-            71, 87, 138, 22, 28, 81, 197, 47, 104, 24, 131, 127, 84, 51, 252, 148, 54, 126, 65, 42,
-            48, 206, 113, 157, 82, 77, 29, 141, 183, 252, 55, 162, 128, 30, 161, 101, 93, 118, 108,
-            61, 92, 80, 96, 64, 174, 203, 66, 250, 140, 126, 84, 178, 13, 191, 111, 201, 6, 8, 253,
-            43, 80, 178, 215, 207, 63, 77, 8, 128, 255, 255, 128, 254, 30, 183, 151, 115, 142, 17,
-            117, 113, 99, 88, 46, 121, 61, 40, 246, 85, 123, 86, 225, 182, 110, 88, 7, 127, 236,
-            14, 247, 70, 244, 163, 95, 128, 25, 118, 253, 146, 237, 189, 40, 204, 170, 7, 92, 76,
-            35, 100, 223, 137, 144, 220, 46, 13, 101, 243, 110, 138, 17, 236, 143, 31, 34, 190,
-            216, 200, 128, 74, 137, 75, 14, 17, 249, 82, 101, 241, 44, 148, 157, 131, 227, 88, 236,
-            107, 178, 19, 144, 255, 18, 182, 150, 74, 201, 124, 242, 141, 123, 121, 194, 128, 250,
-            151, 110, 6, 119, 107, 220, 202, 108, 18, 127, 86, 201, 163, 98, 44, 240, 91, 227, 12,
-            214, 245, 104, 219, 186, 53, 19, 255, 8, 122, 188, 184, 128, 211, 146, 138, 20, 223,
-            29, 48, 26, 253, 202, 160, 72, 103, 101, 78, 222, 176, 49, 168, 126, 253, 186, 119,
-            249, 220, 62, 132, 135, 196, 129, 116, 158, 128, 30, 126, 214, 204, 31, 158, 45, 18,
-            145, 46, 133, 139, 72, 107, 230, 241, 68, 112, 169, 12, 13, 151, 23, 114, 0, 208, 118,
-            200, 40, 134, 94, 230, 128, 206, 213, 245, 105, 26, 106, 101, 23, 189, 76, 239, 199,
-            85, 40, 254, 229, 155, 194, 221, 190, 45, 96, 147, 175, 87, 138, 232, 181, 205, 150,
-            49, 80, 128, 141, 60, 110, 194, 237, 191, 197, 236, 116, 191, 135, 52, 79, 195, 187,
-            30, 254, 141, 40, 180, 204, 202, 137, 57, 245, 236, 209, 254, 232, 102, 172, 72, 128,
-            228, 113, 46, 103, 38, 244, 186, 42, 112, 88, 56, 48, 50, 241, 222, 58, 103, 225, 69,
-            239, 218, 99, 131, 101, 241, 105, 46, 126, 2, 246, 164, 71, 128, 74, 99, 84, 35, 53,
-            202, 101, 249, 124, 253, 188, 88, 201, 89, 107, 158, 190, 68, 36, 233, 21, 90, 130, 64,
-            9, 18, 59, 117, 41, 63, 74, 7, 128, 154, 164, 8, 129, 49, 88, 194, 88, 72, 14, 246,
-            132, 59, 151, 117, 243, 62, 10, 41, 34, 136, 118, 211, 40, 254, 207, 186, 250, 43, 27,
-            87, 33, 128, 89, 102, 96, 102, 172, 67, 126, 155, 245, 12, 166, 66, 237, 38, 24, 131,
-            52, 181, 1, 198, 204, 160, 195, 248, 144, 117, 252, 103, 13, 126, 103, 10, 128, 63,
-            129, 94, 79, 79, 8, 140, 151, 2, 191, 171, 207, 108, 8, 93, 93, 155, 179, 221, 143, 76,
-            165, 157, 232, 18, 160, 132, 170, 25, 238, 72, 7, 128, 119, 254, 39, 23, 181, 253, 242,
-            111, 177, 210, 15, 3, 56, 7, 104, 89, 7, 32, 59, 114, 109, 158, 109, 127, 21, 120, 95,
-            152, 43, 208, 228, 144, 128, 87, 85, 51, 133, 211, 92, 206, 71, 197, 21, 108, 152, 171,
-            3, 90, 151, 239, 201, 239, 189, 201, 85, 93, 55, 251, 66, 69, 103, 157, 210, 91, 233,
-            128, 186, 242, 201, 220, 156, 216, 1, 119, 120, 25, 180, 119, 197, 240, 220, 204, 81,
-            84, 145, 46, 3, 146, 76, 153, 132, 215, 36, 246, 57, 65, 242, 201, 25, 1, 129, 0, 1, 4,
-            128, 151, 240, 251, 168, 161, 213, 53, 147, 49, 115, 40, 24, 91, 138, 12, 10, 127, 180,
-            207, 145, 60, 236, 125, 178, 72, 131, 191, 240, 147, 216, 114, 80, 128, 99, 225, 30,
-            90, 6, 223, 163, 17, 116, 110, 66, 221, 95, 25, 157, 184, 185, 225, 169, 125, 177, 0,
-            197, 157, 193, 173, 187, 54, 214, 47, 174, 150, 157, 1, 129, 3, 16, 129, 128, 162, 244,
-            187, 39, 198, 61, 200, 252, 246, 226, 225, 112, 127, 160, 243, 128, 155, 50, 48, 103,
-            62, 100, 200, 111, 221, 171, 148, 146, 131, 130, 0, 137, 128, 236, 128, 227, 54, 60,
-            222, 65, 39, 12, 163, 123, 164, 43, 134, 168, 173, 33, 242, 196, 253, 150, 251, 211,
-            64, 201, 205, 39, 12, 146, 78, 29, 221, 128, 5, 167, 72, 17, 43, 203, 70, 247, 245,
-            221, 47, 109, 19, 43, 13, 204, 55, 233, 152, 93, 209, 121, 230, 108, 164, 98, 26, 255,
-            206, 210, 142, 73, 25, 1, 129, 9, 128, 2, 128, 162, 89, 253, 127, 244, 163, 33, 35,
-            118, 232, 205, 99, 63, 229, 23, 82, 44, 44, 145, 169, 157, 142, 83, 61, 209, 191, 103,
-            206, 212, 252, 44, 67, 128, 201, 48, 61, 36, 212, 70, 232, 0, 64, 26, 187, 18, 168,
-            251, 92, 249, 56, 107, 140, 149, 213, 112, 111, 41, 161, 186, 55, 153, 222, 204, 120,
-            61, 141, 7, 157, 14, 73, 7, 247, 22, 172, 137, 182, 52, 125, 21, 236, 236, 237, 202,
-            255, 223, 88, 95, 11, 106, 69, 50, 30, 250, 233, 42, 234, 21, 224, 116, 14, 199, 175,
-            231, 16, 219, 3, 0, 0, 88, 95, 3, 142, 113, 97, 36, 145, 25, 45, 104, 222, 171, 126,
-            111, 86, 63, 225, 16, 41, 1, 0, 0, 128, 110, 200, 13, 122, 252, 137, 163, 204, 235,
-            225, 15, 205, 240, 76, 161, 101, 24, 255, 136, 214, 178, 148, 98, 236, 134, 27, 0, 211,
-            18, 57, 219, 53, 128, 24, 179, 250, 7, 252, 211, 24, 112, 36, 254, 110, 123, 88, 175,
-            104, 227, 26, 244, 141, 143, 123, 92, 93, 148, 246, 56, 254, 87, 54, 202, 227, 116,
-            128, 212, 186, 95, 7, 117, 214, 42, 252, 137, 90, 39, 204, 71, 219, 16, 172, 46, 91,
-            134, 227, 70, 144, 166, 190, 177, 236, 42, 57, 145, 233, 105, 61, 128, 14, 214, 94,
-            167, 86, 123, 217, 88, 156, 68, 3, 216, 251, 117, 130, 117, 173, 120, 238, 191, 150,
-            165, 214, 18, 109, 103, 131, 162, 149, 60, 190, 15, 128, 145, 79, 88, 158, 111, 117,
-            135, 211, 50, 230, 34, 131, 178, 91, 19, 230, 181, 115, 168, 181, 12, 173, 102, 237,
-            72, 30, 125, 22, 166, 190, 163, 226, 128, 150, 97, 48, 76, 74, 235, 167, 247, 69, 103,
-            4, 154, 130, 7, 3, 22, 72, 160, 82, 232, 18, 244, 176, 58, 42, 23, 7, 231, 12, 78, 180,
-            155, 128, 191, 24, 153, 195, 29, 109, 95, 250, 129, 242, 53, 146, 183, 178, 157, 20,
-            56, 109, 0, 24, 132, 32, 212, 75, 125, 0, 101, 184, 163, 124, 84, 180, 128, 223, 120,
-            85, 135, 67, 76, 242, 99, 220, 31, 86, 41, 97, 198, 169, 114, 181, 40, 200, 30, 33,
-            169, 7, 31, 17, 77, 67, 188, 58, 79, 49, 70, 128, 65, 72, 245, 149, 209, 123, 119, 9,
-            68, 98, 196, 190, 181, 249, 88, 108, 108, 19, 163, 131, 105, 250, 44, 15, 229, 23, 79,
-            70, 55, 48, 24, 207, 88, 95, 4, 154, 39, 56, 238, 179, 8, 150, 170, 203, 139, 63, 180,
-            100, 113, 189, 16, 4, 0, 0, 0, 128, 150, 77, 204, 23, 91, 96, 20, 177, 216, 5, 108,
-            247, 200, 200, 199, 101, 114, 110, 31, 183, 134, 248, 253, 142, 109, 152, 116, 233, 25,
-            185, 123, 153, 128, 5, 145, 11, 232, 224, 155, 242, 140, 49, 94, 170, 70, 75, 193, 225,
-            78, 35, 140, 92, 155, 200, 205, 85, 110, 178, 60, 35, 99, 30, 6, 200, 106, 128, 180,
-            147, 63, 242, 56, 9, 87, 70, 86, 7, 181, 212, 101, 128, 67, 39, 192, 87, 234, 252, 168,
-            145, 67, 182, 36, 28, 227, 4, 153, 70, 59, 250, 137, 8, 158, 220, 222, 147, 76, 101,
-            130, 39, 238, 29, 250, 252, 214, 225, 105, 3, 255, 255, 128, 212, 192, 107, 17, 26,
-            200, 122, 195, 120, 105, 34, 67, 13, 110, 222, 20, 168, 48, 115, 160, 212, 187, 246,
-            92, 253, 220, 93, 68, 165, 244, 128, 149, 128, 251, 50, 66, 146, 95, 208, 2, 95, 45,
-            114, 134, 72, 91, 66, 74, 130, 178, 173, 14, 21, 149, 16, 128, 230, 116, 61, 76, 168,
-            24, 113, 226, 218, 128, 130, 145, 33, 106, 121, 80, 96, 66, 164, 70, 191, 105, 83, 79,
-            54, 235, 249, 71, 225, 58, 5, 127, 130, 251, 200, 50, 19, 156, 250, 206, 78, 78, 128,
-            44, 192, 40, 14, 185, 110, 219, 179, 241, 90, 91, 52, 41, 102, 199, 113, 82, 132, 131,
-            96, 38, 20, 10, 30, 59, 82, 176, 198, 176, 87, 21, 87, 128, 195, 5, 208, 225, 21, 57,
-            155, 53, 89, 33, 130, 228, 169, 0, 76, 48, 24, 82, 5, 42, 137, 197, 162, 63, 21, 197,
-            141, 251, 212, 115, 106, 82, 128, 121, 48, 30, 3, 225, 115, 179, 164, 175, 222, 54, 84,
-            122, 135, 160, 236, 243, 69, 251, 160, 241, 3, 245, 102, 217, 137, 189, 2, 173, 64, 53,
-            101, 128, 153, 158, 145, 143, 88, 49, 185, 124, 0, 150, 98, 54, 245, 85, 94, 94, 215,
-            123, 89, 24, 10, 71, 85, 52, 250, 210, 20, 228, 16, 149, 211, 151, 128, 35, 126, 133,
-            35, 101, 156, 135, 154, 57, 170, 188, 113, 130, 140, 189, 101, 229, 126, 131, 120, 56,
-            160, 48, 107, 104, 186, 122, 224, 85, 129, 147, 46, 128, 105, 89, 155, 175, 165, 144,
-            141, 206, 81, 133, 45, 110, 19, 216, 203, 165, 255, 113, 78, 67, 243, 110, 156, 71,
-            109, 18, 18, 115, 92, 216, 173, 77, 128, 123, 141, 236, 214, 0, 104, 118, 31, 247, 52,
-            108, 8, 252, 87, 196, 216, 167, 133, 138, 61, 36, 211, 220, 61, 76, 245, 34, 188, 150,
-            163, 67, 176, 128, 145, 171, 89, 220, 120, 189, 81, 183, 187, 110, 153, 163, 54, 248,
-            154, 229, 38, 99, 248, 20, 255, 92, 198, 245, 44, 156, 75, 241, 238, 7, 6, 224, 128,
-            239, 194, 154, 97, 78, 80, 169, 43, 93, 31, 212, 140, 222, 208, 103, 168, 30, 51, 240,
-            20, 63, 224, 162, 202, 9, 214, 212, 169, 103, 167, 181, 110, 128, 97, 138, 136, 94, 17,
-            95, 133, 176, 236, 165, 222, 28, 208, 47, 223, 231, 215, 15, 27, 155, 26, 211, 56, 191,
-            215, 96, 178, 205, 221, 97, 26, 31, 128, 246, 79, 70, 71, 167, 94, 137, 199, 210, 5,
-            154, 192, 53, 113, 183, 23, 32, 135, 208, 76, 45, 4, 58, 15, 13, 182, 41, 24, 188, 104,
-            66, 111, 128, 68, 251, 235, 134, 154, 122, 195, 108, 116, 251, 35, 161, 59, 76, 88,
-            164, 75, 140, 124, 44, 177, 90, 195, 238, 131, 61, 234, 70, 60, 255, 30, 252, 128, 12,
-            221, 198, 252, 251, 59, 183, 181, 5, 106, 13, 14, 159, 159, 196, 193, 91, 10, 108, 175,
-            177, 207, 135, 14, 162, 164, 53, 52, 72, 212, 240, 144,
-        ],
-        &[
-            237, 15, 184, 127, 13, 11, 71, 190, 102, 151, 76, 44, 42, 85, 189, 59, 0, 40, 114, 107,
-            247, 118, 78, 58, 96, 68, 33, 233, 142, 147, 15, 133, 26, 43, 250, 253, 245, 210, 158,
-            182, 148, 210, 117, 20, 2, 132, 215, 23, 0, 184, 127, 13, 13, 156, 1, 243, 17, 253, 0,
-            250, 6, 147, 52, 75, 229, 123, 67, 110, 112, 90, 234, 158, 192, 123, 65, 140, 4, 46,
-            70, 1, 59, 15, 7, 104, 110, 183, 241, 252, 54, 69, 20, 2, 90, 98, 2, 0, 172, 127, 13,
-            17, 203, 75, 183, 181, 85, 178, 8, 84, 21, 66, 99, 218, 114, 143, 66, 155, 20, 17, 102,
-            193, 36, 117, 195, 107, 250, 226, 64, 141, 144, 250, 115, 235, 192, 153, 188, 33, 115,
-            8, 4, 0, 172, 127, 13, 20, 221, 104, 135, 46, 13, 226, 23, 39, 49, 43, 43, 124, 229,
-            121, 220, 15, 130, 241, 41, 212, 237, 236, 188, 240, 10, 188, 166, 7, 215, 60, 207, 22,
-            232, 53, 44, 199, 119, 8, 4, 0, 184, 127, 13, 25, 132, 82, 86, 168, 233, 44, 42, 85,
-            181, 254, 176, 235, 40, 252, 105, 117, 102, 56, 77, 171, 18, 113, 112, 240, 140, 28,
-            42, 111, 235, 223, 180, 203, 49, 133, 239, 137, 23, 20, 2, 132, 215, 23, 0, 184, 127,
-            13, 43, 55, 140, 223, 82, 21, 80, 40, 165, 177, 243, 15, 208, 117, 102, 10, 191, 89,
-            128, 155, 237, 130, 163, 252, 55, 163, 5, 157, 48, 101, 200, 10, 187, 61, 244, 39, 54,
-            91, 20, 2, 40, 107, 238, 0, 184, 127, 13, 53, 10, 204, 122, 188, 185, 246, 99, 17, 177,
-            114, 14, 224, 218, 2, 175, 43, 149, 199, 3, 241, 219, 78, 45, 176, 145, 41, 107, 89,
-            139, 70, 106, 225, 92, 158, 162, 147, 68, 20, 2, 40, 107, 238, 0, 184, 127, 13, 57,
-            196, 35, 162, 83, 168, 80, 143, 44, 180, 86, 124, 175, 240, 64, 208, 164, 125, 178, 68,
-            215, 234, 121, 29, 158, 194, 63, 13, 93, 51, 146, 138, 124, 106, 98, 179, 7, 122, 20,
-            2, 194, 235, 11, 0, 184, 127, 13, 71, 216, 136, 42, 241, 254, 188, 97, 246, 194, 186,
-            91, 66, 218, 147, 108, 18, 199, 229, 195, 63, 108, 197, 115, 152, 133, 33, 229, 53, 13,
-            229, 136, 123, 32, 174, 156, 174, 73, 20, 2, 180, 196, 4, 0, 184, 127, 13, 92, 44, 20,
-            196, 243, 153, 210, 91, 42, 168, 64, 21, 141, 217, 75, 185, 161, 158, 133, 167, 152,
-            50, 78, 42, 14, 71, 72, 234, 192, 141, 237, 71, 165, 254, 40, 20, 186, 40, 20, 2, 28,
-            78, 14, 0, 184, 127, 13, 97, 55, 56, 240, 210, 246, 44, 42, 85, 181, 133, 221, 62, 123,
-            245, 204, 146, 211, 238, 169, 108, 209, 48, 24, 117, 83, 130, 240, 186, 27, 226, 30,
-            193, 134, 107, 4, 50, 18, 20, 2, 132, 215, 23, 0, 184, 127, 13, 106, 132, 24, 120, 67,
-            159, 54, 253, 155, 100, 233, 150, 137, 54, 51, 104, 41, 134, 128, 203, 53, 117, 10, 89,
-            65, 3, 4, 139, 255, 216, 57, 175, 119, 15, 229, 83, 108, 108, 20, 2, 14, 39, 7, 0, 184,
-            127, 13, 106, 189, 56, 237, 227, 148, 150, 83, 188, 241, 142, 48, 83, 16, 146, 253,
-            193, 197, 42, 254, 6, 207, 97, 245, 111, 177, 250, 93, 113, 144, 120, 205, 105, 20,
-            211, 149, 237, 15, 20, 2, 14, 39, 7, 0, 172, 127, 13, 116, 73, 216, 163, 69, 88, 60,
-            47, 34, 142, 31, 23, 15, 207, 206, 101, 251, 11, 113, 212, 41, 94, 12, 23, 216, 51,
-            228, 69, 29, 144, 90, 134, 85, 152, 23, 136, 100, 223, 8, 4, 0, 184, 127, 13, 127, 157,
-            27, 24, 237, 180, 114, 147, 36, 255, 103, 152, 9, 57, 57, 167, 53, 70, 224, 243, 213,
-            58, 156, 215, 212, 233, 56, 210, 56, 20, 92, 148, 34, 206, 159, 11, 235, 7, 20, 2, 78,
-            114, 83, 0, 184, 127, 13, 161, 129, 88, 161, 123, 75, 254, 181, 134, 166, 210, 64, 106,
-            235, 103, 216, 220, 56, 149, 240, 157, 222, 24, 110, 137, 85, 110, 195, 193, 102, 182,
-            251, 29, 148, 189, 122, 2, 94, 20, 2, 40, 107, 238, 0, 184, 127, 13, 209, 134, 62, 148,
-            92, 2, 44, 42, 85, 181, 39, 167, 124, 108, 229, 151, 105, 83, 180, 176, 191, 211, 247,
-            219, 138, 150, 210, 127, 174, 22, 15, 36, 160, 166, 117, 156, 212, 102, 20, 2, 132,
-            215, 23, 0, 184, 127, 13, 213, 153, 97, 5, 126, 244, 74, 69, 184, 71, 87, 196, 135,
-            118, 244, 197, 216, 135, 226, 105, 232, 230, 195, 242, 252, 85, 247, 90, 223, 34, 38,
-            98, 241, 41, 249, 250, 91, 64, 20, 2, 90, 98, 2, 0, 184, 127, 13, 215, 152, 40, 224,
-            236, 137, 126, 106, 121, 69, 195, 16, 143, 188, 53, 238, 96, 214, 243, 56, 244, 71, 45,
-            70, 88, 36, 157, 150, 73, 48, 134, 153, 78, 2, 231, 221, 58, 17, 20, 2, 194, 235, 11,
-            0, 184, 127, 13, 227, 61, 158, 201, 179, 109, 34, 72, 114, 173, 96, 52, 93, 114, 103,
-            114, 54, 18, 145, 180, 207, 127, 62, 27, 128, 135, 242, 39, 126, 43, 92, 194, 90, 155,
-            48, 159, 71, 39, 20, 2, 14, 39, 7, 0, 184, 127, 13, 239, 30, 187, 86, 50, 243, 12, 160,
-            219, 2, 131, 219, 248, 209, 35, 96, 42, 46, 195, 52, 171, 92, 63, 217, 226, 84, 5, 119,
-            224, 149, 94, 174, 198, 121, 206, 250, 79, 10, 20, 2, 14, 39, 7, 0, 184, 127, 13, 240,
-            17, 46, 241, 9, 111, 176, 35, 209, 41, 217, 160, 203, 148, 144, 208, 151, 219, 211,
-            202, 148, 125, 72, 48, 211, 166, 215, 224, 250, 153, 117, 255, 39, 137, 217, 217, 115,
-            82, 20, 2, 14, 39, 7, 0, 184, 127, 13, 241, 230, 157, 31, 17, 128, 139, 23, 137, 40,
-            27, 56, 57, 42, 8, 171, 5, 22, 162, 29, 238, 73, 251, 166, 245, 229, 83, 129, 16, 111,
-            247, 174, 25, 5, 99, 204, 132, 244, 20, 2, 194, 235, 11, 0, 184, 127, 13, 248, 27, 1,
-            98, 80, 170, 228, 13, 180, 29, 127, 7, 178, 184, 103, 250, 224, 215, 184, 237, 103,
-            103, 210, 63, 123, 83, 176, 50, 113, 13, 197, 181, 4, 52, 116, 191, 29, 17, 20, 2, 208,
-            18, 19, 0, 188, 127, 14, 0, 6, 46, 70, 100, 4, 240, 151, 108, 228, 32, 60, 132, 74, 17,
-            22, 77, 27, 63, 131, 66, 173, 22, 164, 204, 93, 153, 172, 142, 170, 229, 205, 116, 244,
-            177, 204, 104, 199, 100, 20, 2, 14, 39, 7, 0, 188, 127, 14, 0, 17, 132, 111, 43, 146,
-            119, 54, 25, 40, 156, 178, 230, 96, 219, 193, 197, 116, 117, 6, 163, 125, 247, 164,
-            211, 17, 170, 200, 242, 156, 105, 190, 139, 113, 4, 149, 229, 22, 35, 20, 2, 122, 3,
-            10, 0, 188, 127, 14, 0, 22, 67, 68, 31, 192, 204, 10, 115, 4, 124, 75, 202, 18, 117,
-            130, 250, 21, 65, 200, 134, 145, 91, 42, 135, 44, 149, 251, 83, 143, 206, 160, 153,
-            186, 176, 164, 123, 143, 84, 20, 2, 194, 235, 11, 0, 188, 127, 14, 0, 27, 93, 231, 203,
-            83, 79, 46, 84, 77, 225, 252, 145, 152, 211, 93, 212, 89, 200, 255, 139, 240, 162, 200,
-            110, 234, 164, 237, 138, 217, 179, 47, 184, 208, 22, 230, 150, 173, 119, 20, 2, 14, 39,
-            7, 0, 188, 127, 14, 0, 31, 125, 60, 190, 173, 136, 98, 197, 148, 106, 208, 189, 221,
-            174, 86, 60, 236, 36, 75, 159, 166, 46, 243, 32, 252, 250, 143, 220, 217, 206, 149,
-            110, 131, 157, 1, 202, 87, 100, 20, 2, 40, 107, 238, 0, 188, 127, 14, 0, 42, 159, 115,
-            37, 70, 226, 48, 51, 73, 253, 16, 83, 84, 153, 191, 250, 169, 20, 90, 153, 176, 233,
-            226, 141, 242, 156, 63, 249, 239, 26, 27, 170, 59, 230, 64, 153, 199, 124, 20, 2, 40,
-            107, 238, 0, 188, 127, 14, 0, 44, 149, 244, 44, 135, 7, 40, 197, 119, 1, 175, 240, 134,
-            226, 212, 208, 5, 54, 163, 94, 187, 13, 234, 119, 50, 213, 189, 40, 92, 99, 250, 199,
-            25, 123, 107, 243, 227, 126, 20, 2, 132, 215, 23, 0, 188, 127, 14, 0, 52, 13, 132, 30,
-            129, 216, 134, 57, 217, 245, 119, 212, 145, 6, 39, 205, 93, 70, 67, 178, 5, 252, 84,
-            118, 150, 42, 253, 209, 208, 6, 140, 189, 208, 31, 87, 97, 143, 96, 20, 2, 40, 107,
-            238, 0, 188, 127, 14, 0, 72, 201, 255, 218, 120, 160, 50, 133, 89, 142, 128, 78, 74,
-            168, 28, 40, 79, 1, 107, 119, 70, 65, 139, 80, 224, 115, 103, 234, 149, 134, 6, 225,
-            13, 116, 121, 184, 107, 105, 20, 2, 40, 107, 238, 0, 188, 127, 14, 0, 77, 100, 183,
-            157, 202, 62, 138, 68, 159, 172, 72, 117, 162, 71, 116, 23, 86, 81, 255, 202, 204, 133,
-            25, 57, 239, 242, 49, 252, 210, 226, 112, 225, 226, 148, 41, 144, 32, 89, 20, 2, 194,
-            235, 11, 0, 188, 127, 14, 0, 77, 215, 249, 112, 190, 6, 68, 124, 172, 217, 124, 16, 7,
-            47, 130, 237, 7, 175, 231, 121, 75, 197, 26, 67, 49, 199, 198, 15, 83, 233, 120, 163,
-            217, 229, 171, 39, 166, 23, 20, 2, 194, 235, 11, 0, 188, 127, 14, 0, 80, 136, 39, 190,
-            216, 179, 56, 90, 58, 83, 91, 241, 8, 8, 196, 214, 177, 96, 157, 54, 4, 45, 155, 134,
-            123, 185, 68, 107, 3, 244, 230, 80, 50, 69, 220, 12, 158, 64, 20, 2, 194, 235, 11, 0,
-            188, 127, 14, 0, 83, 175, 154, 105, 32, 244, 68, 49, 112, 139, 71, 78, 136, 131, 24,
-            246, 92, 147, 240, 4, 159, 163, 4, 61, 249, 215, 241, 236, 31, 95, 61, 125, 94, 20,
-            147, 53, 158, 88, 20, 2, 90, 98, 2, 0, 188, 127, 14, 0, 84, 118, 253, 197, 2, 143, 180,
-            172, 25, 201, 167, 35, 71, 124, 11, 49, 239, 65, 221, 12, 253, 149, 57, 163, 168, 215,
-            99, 230, 166, 196, 201, 110, 186, 110, 186, 94, 77, 127, 20, 2, 194, 235, 11, 0, 188,
-            127, 14, 0, 89, 129, 223, 44, 131, 8, 216, 95, 147, 244, 127, 148, 171, 184, 223, 175,
-            140, 129, 122, 243, 212, 181, 57, 132, 113, 65, 39, 116, 121, 80, 96, 39, 240, 108, 84,
-            203, 164, 64, 20, 2, 40, 107, 238, 0, 188, 127, 14, 0, 116, 40, 113, 55, 88, 100, 44,
-            42, 85, 180, 250, 132, 233, 183, 232, 92, 106, 59, 179, 127, 75, 179, 131, 27, 181, 26,
-            91, 187, 22, 102, 255, 146, 200, 184, 116, 220, 180, 46, 20, 2, 132, 215, 23, 0, 188,
-            127, 14, 0, 116, 211, 150, 129, 150, 237, 230, 143, 87, 15, 19, 212, 88, 25, 64, 96,
-            46, 227, 234, 142, 187, 124, 63, 202, 62, 232, 226, 29, 223, 144, 8, 75, 61, 243, 163,
-            174, 115, 54, 20, 2, 14, 39, 7, 0, 188, 127, 14, 0, 118, 129, 81, 217, 216, 158, 0,
-            203, 104, 162, 192, 62, 102, 111, 52, 106, 39, 112, 54, 232, 242, 124, 145, 43, 174,
-            227, 199, 180, 28, 92, 25, 18, 56, 64, 243, 168, 232, 252, 20, 2, 194, 235, 11, 0, 176,
-            127, 14, 0, 119, 15, 242, 202, 171, 115, 136, 184, 162, 75, 26, 28, 184, 67, 213, 131,
-            153, 170, 54, 45, 101, 171, 136, 27, 122, 66, 25, 182, 222, 219, 213, 17, 50, 28, 254,
-            75, 116, 89, 8, 4, 0, 188, 127, 14, 0, 123, 223, 129, 136, 66, 203, 44, 237, 255, 221,
-            149, 1, 26, 240, 161, 197, 104, 63, 132, 69, 160, 80, 6, 243, 4, 135, 240, 221, 37,
-            190, 172, 102, 76, 84, 191, 85, 68, 29, 20, 2, 208, 18, 19, 0, 188, 127, 14, 0, 125,
-            68, 79, 191, 195, 189, 230, 138, 49, 194, 60, 80, 211, 26, 148, 42, 97, 55, 85, 11,
-            237, 216, 160, 23, 173, 82, 178, 244, 33, 160, 133, 18, 19, 111, 91, 67, 219, 69, 20,
-            2, 40, 107, 238, 0, 188, 127, 14, 0, 133, 0, 53, 253, 26, 62, 136, 35, 19, 158, 48,
-            212, 1, 247, 169, 66, 46, 104, 32, 143, 243, 173, 47, 142, 64, 233, 44, 131, 175, 38,
-            228, 0, 39, 52, 118, 12, 184, 126, 20, 2, 225, 245, 5, 0, 188, 127, 14, 0, 141, 128,
-            95, 204, 166, 227, 68, 42, 253, 228, 28, 141, 12, 255, 150, 128, 132, 152, 36, 113, 41,
-            150, 208, 205, 150, 144, 109, 186, 150, 151, 170, 81, 16, 205, 109, 2, 94, 21, 20, 2,
-            225, 245, 5, 0, 188, 127, 14, 0, 147, 49, 71, 240, 226, 35, 52, 150, 135, 89, 200, 134,
-            149, 237, 96, 254, 254, 55, 79, 247, 18, 82, 206, 223, 58, 73, 242, 56, 181, 249, 234,
-            68, 66, 175, 161, 24, 79, 41, 20, 2, 40, 107, 238, 0, 188, 127, 14, 0, 147, 149, 51,
-            45, 85, 119, 106, 233, 153, 37, 180, 251, 181, 254, 0, 189, 76, 134, 64, 70, 131, 146,
-            103, 58, 99, 15, 164, 86, 198, 205, 9, 248, 120, 252, 85, 229, 237, 113, 20, 2, 8, 175,
-            47, 0, 188, 127, 14, 0, 148, 77, 119, 69, 219, 214, 246, 136, 30, 130, 175, 25, 27,
-            148, 69, 159, 102, 231, 7, 165, 88, 151, 202, 208, 220, 6, 30, 36, 153, 89, 72, 246,
-            132, 238, 57, 128, 65, 40, 20, 2, 194, 235, 11, 0, 188, 127, 14, 0, 152, 252, 91, 249,
-            81, 243, 148, 68, 121, 31, 62, 121, 30, 36, 126, 105, 243, 205, 142, 103, 247, 184, 47,
-            110, 14, 184, 217, 67, 246, 252, 118, 196, 41, 41, 66, 107, 240, 88, 20, 2, 40, 107,
-            238, 0, 188, 127, 14, 0, 177, 116, 54, 254, 42, 223, 44, 42, 85, 181, 186, 173, 22, 93,
-            100, 234, 206, 95, 117, 100, 42, 196, 97, 46, 193, 239, 18, 187, 193, 203, 39, 160, 19,
-            116, 115, 120, 130, 87, 20, 2, 132, 215, 23, 0, 188, 127, 14, 0, 189, 153, 128, 150,
-            213, 191, 179, 152, 138, 106, 242, 34, 71, 36, 151, 23, 189, 134, 2, 183, 172, 62, 15,
-            198, 63, 70, 154, 233, 255, 142, 157, 2, 42, 165, 4, 214, 27, 94, 20, 2, 194, 235, 11,
-            0, 188, 127, 14, 0, 189, 165, 102, 62, 24, 40, 96, 114, 98, 184, 59, 147, 73, 239, 202,
-            215, 43, 95, 42, 12, 188, 128, 177, 253, 182, 34, 170, 129, 168, 97, 165, 98, 9, 1, 78,
-            86, 131, 55, 20, 2, 90, 98, 2, 0, 188, 127, 14, 0, 195, 83, 204, 232, 157, 188, 98,
-            131, 142, 133, 214, 254, 234, 64, 107, 69, 68, 184, 81, 241, 4, 73, 194, 242, 55, 107,
-            157, 56, 5, 163, 183, 205, 185, 142, 242, 87, 63, 125, 20, 2, 14, 39, 7, 0, 188, 127,
-            14, 0, 197, 77, 225, 225, 51, 174, 208, 6, 208, 242, 196, 131, 179, 173, 125, 244, 167,
-            100, 50, 175, 121, 255, 45, 109, 42, 219, 96, 140, 16, 102, 199, 170, 23, 88, 204, 107,
-            12, 8, 20, 2, 40, 107, 238, 0, 188, 127, 14, 0, 204, 246, 24, 197, 251, 48, 173, 209,
-            212, 14, 241, 4, 252, 178, 75, 230, 55, 234, 235, 151, 4, 6, 70, 99, 223, 35, 95, 234,
-            7, 153, 130, 158, 39, 3, 51, 119, 79, 59, 20, 2, 194, 235, 11, 0, 188, 127, 14, 0, 227,
-            173, 118, 192, 117, 142, 106, 13, 161, 85, 22, 166, 62, 204, 149, 204, 207, 254, 206,
-            194, 162, 174, 217, 98, 233, 44, 171, 102, 26, 244, 177, 247, 107, 101, 228, 41, 207,
-            121, 20, 2, 90, 98, 2, 0, 188, 127, 14, 0, 232, 226, 71, 160, 226, 15, 48, 207, 219,
-            72, 255, 127, 51, 176, 132, 153, 223, 198, 24, 168, 239, 150, 153, 184, 52, 95, 166,
-            95, 11, 19, 57, 235, 142, 236, 60, 14, 69, 85, 20, 2, 194, 235, 11, 0, 188, 127, 14, 1,
-            13, 251, 222, 130, 119, 50, 80, 78, 227, 211, 101, 37, 208, 171, 130, 27, 239, 81, 97,
-            215, 220, 50, 234, 183, 1, 95, 207, 111, 156, 145, 61, 139, 113, 120, 249, 165, 210,
-            97, 20, 2, 194, 235, 11, 0, 188, 127, 14, 1, 15, 31, 242, 1, 64, 195, 136, 149, 149,
-            186, 170, 159, 0, 72, 252, 138, 159, 171, 132, 29, 36, 202, 125, 27, 16, 126, 187, 65,
-            249, 14, 30, 244, 84, 48, 142, 213, 198, 73, 20, 2, 40, 107, 238, 0, 188, 127, 14, 1,
-            25, 42, 225, 250, 2, 107, 86, 6, 252, 214, 23, 187, 115, 40, 248, 40, 235, 192, 115,
-            150, 171, 39, 240, 56, 235, 95, 3, 97, 37, 162, 171, 91, 96, 171, 146, 24, 242, 66, 20,
-            2, 40, 107, 238, 0, 188, 127, 14, 1, 29, 121, 6, 117, 108, 198, 156, 213, 24, 78, 131,
-            132, 125, 235, 167, 37, 191, 9, 142, 55, 139, 165, 121, 232, 109, 141, 226, 49, 118,
-            251, 136, 85, 63, 195, 124, 1, 23, 16, 20, 2, 40, 107, 238, 0, 188, 127, 14, 1, 39, 86,
-            249, 33, 25, 228, 24, 241, 111, 38, 40, 217, 61, 229, 96, 226, 189, 182, 209, 203, 74,
-            163, 182, 129, 202, 173, 44, 90, 231, 35, 242, 108, 9, 236, 182, 118, 188, 104, 20, 2,
-            40, 107, 238, 0, 188, 127, 14, 1, 40, 79, 128, 217, 49, 140, 250, 117, 225, 117, 75,
-            71, 148, 44, 175, 169, 74, 100, 82, 4, 90, 202, 129, 232, 16, 149, 169, 212, 231, 125,
-            63, 88, 67, 190, 15, 53, 208, 194, 20, 2, 40, 107, 238, 0, 188, 127, 14, 1, 48, 172,
-            130, 100, 22, 242, 134, 246, 131, 97, 208, 163, 70, 166, 43, 226, 103, 85, 142, 114,
-            223, 185, 227, 181, 160, 74, 220, 194, 201, 228, 111, 183, 185, 72, 47, 124, 135, 111,
-            20, 2, 180, 196, 4, 0, 188, 127, 14, 1, 53, 213, 219, 59, 3, 107, 206, 71, 97, 234, 93,
-            141, 41, 221, 146, 167, 141, 198, 6, 36, 109, 129, 70, 50, 10, 94, 146, 146, 222, 36,
-            10, 177, 95, 96, 199, 128, 46, 74, 20, 2, 90, 98, 2, 0, 188, 127, 14, 1, 56, 65, 170,
-            8, 144, 72, 37, 42, 105, 203, 59, 120, 44, 252, 54, 150, 254, 208, 254, 103, 189, 12,
-            146, 202, 33, 242, 118, 116, 130, 175, 65, 169, 2, 229, 168, 40, 17, 69, 20, 2, 14, 39,
-            7, 0, 188, 127, 14, 1, 56, 118, 169, 181, 114, 219, 186, 198, 202, 149, 32, 91, 125,
-            41, 169, 249, 90, 223, 40, 213, 249, 75, 160, 16, 235, 181, 31, 65, 65, 99, 164, 135,
-            71, 67, 108, 159, 156, 171, 20, 2, 194, 235, 11, 0, 188, 127, 14, 1, 61, 66, 6, 209,
-            109, 107, 168, 27, 138, 42, 3, 175, 217, 42, 241, 143, 3, 56, 228, 58, 252, 80, 76, 96,
-            24, 172, 166, 217, 25, 124, 13, 58, 20, 156, 230, 94, 250, 14, 20, 2, 40, 107, 238, 0,
-            188, 127, 14, 1, 68, 87, 187, 3, 56, 155, 44, 42, 85, 181, 241, 181, 225, 196, 87, 56,
-            226, 53, 43, 61, 0, 103, 58, 64, 244, 81, 173, 210, 28, 248, 230, 160, 195, 234, 230,
-            187, 104, 36, 20, 2, 132, 215, 23, 0, 188, 127, 14, 1, 72, 35, 149, 40, 84, 102, 0,
-            176, 59, 35, 118, 109, 112, 208, 68, 89, 67, 178, 144, 96, 101, 33, 172, 174, 254, 231,
-            102, 13, 82, 25, 80, 250, 242, 128, 28, 121, 212, 40, 20, 2, 225, 245, 5, 0, 176, 127,
-            14, 1, 76, 22, 228, 10, 215, 119, 212, 62, 166, 161, 74, 225, 161, 201, 211, 206, 137,
-            199, 76, 130, 176, 231, 116, 63, 53, 1, 87, 3, 2, 96, 64, 161, 177, 245, 58, 221, 222,
-            117, 8, 4, 0, 188, 127, 14, 1, 83, 91, 18, 199, 240, 34, 44, 42, 85, 181, 187, 96, 74,
-            40, 237, 245, 232, 178, 76, 114, 240, 200, 81, 166, 68, 12, 24, 137, 52, 109, 140, 207,
-            205, 41, 139, 96, 30, 37, 20, 2, 132, 215, 23, 0, 188, 127, 14, 1, 91, 108, 99, 35, 39,
-            66, 230, 225, 61, 131, 91, 248, 180, 73, 20, 202, 36, 97, 59, 156, 205, 42, 162, 255,
-            106, 24, 125, 205, 203, 17, 213, 49, 112, 28, 95, 206, 249, 16, 20, 2, 40, 107, 238, 0,
-            188, 127, 14, 1, 99, 126, 6, 195, 208, 97, 120, 46, 170, 62, 126, 235, 33, 196, 103,
-            138, 121, 74, 147, 191, 224, 133, 205, 250, 195, 191, 79, 164, 170, 29, 93, 27, 31,
-            135, 167, 192, 49, 7, 20, 2, 14, 39, 7, 0, 188, 127, 14, 1, 111, 47, 102, 2, 130, 100,
-            222, 59, 62, 48, 31, 195, 139, 33, 81, 117, 182, 54, 164, 167, 27, 33, 184, 147, 69,
-            59, 162, 32, 139, 130, 128, 211, 139, 60, 48, 174, 7, 41, 20, 2, 40, 107, 238, 0, 176,
-            127, 14, 1, 112, 7, 206, 235, 147, 159, 180, 215, 138, 139, 179, 90, 123, 15, 248, 174,
-            106, 120, 8, 249, 177, 124, 131, 239, 210, 139, 134, 18, 194, 56, 128, 52, 208, 227,
-            92, 229, 19, 118, 8, 4, 0, 188, 127, 14, 1, 112, 192, 128, 226, 201, 177, 138, 150,
-            199, 164, 145, 198, 143, 142, 29, 249, 157, 116, 74, 128, 195, 200, 255, 2, 4, 31, 154,
-            222, 123, 243, 159, 93, 244, 249, 20, 68, 164, 67, 20, 2, 40, 107, 238, 0, 188, 127,
-            14, 1, 119, 9, 12, 246, 162, 8, 68, 235, 210, 185, 52, 96, 106, 48, 70, 155, 253, 80,
-            146, 147, 195, 65, 116, 51, 111, 151, 71, 9, 150, 74, 24, 191, 64, 217, 154, 11, 237,
-            13, 20, 2, 194, 235, 11, 0, 188, 127, 14, 1, 123, 156, 84, 189, 76, 154, 204, 176, 167,
-            37, 4, 89, 220, 74, 40, 70, 123, 64, 172, 235, 212, 80, 5, 183, 26, 239, 153, 85, 98,
-            94, 138, 190, 124, 85, 153, 74, 99, 88, 20, 2, 40, 107, 238, 0, 176, 127, 14, 1, 128,
-            154, 154, 202, 196, 187, 140, 82, 157, 228, 53, 167, 100, 69, 125, 98, 71, 239, 147,
-            181, 204, 114, 253, 115, 185, 34, 228, 144, 56, 52, 223, 180, 61, 191, 100, 1, 51, 101,
-            8, 4, 0, 188, 127, 14, 1, 135, 199, 241, 39, 173, 110, 108, 73, 224, 179, 242, 182,
-            116, 55, 74, 213, 216, 10, 117, 241, 230, 12, 221, 1, 46, 110, 142, 55, 57, 161, 12,
-            218, 233, 203, 118, 239, 133, 124, 20, 2, 14, 39, 7, 0, 188, 127, 14, 1, 140, 23, 40,
-            198, 34, 49, 102, 69, 19, 192, 70, 212, 73, 123, 160, 92, 25, 239, 172, 71, 203, 13,
-            190, 73, 142, 32, 176, 137, 223, 242, 90, 160, 141, 26, 119, 236, 151, 11, 20, 2, 14,
-            39, 7, 0, 188, 127, 14, 1, 142, 233, 99, 13, 130, 6, 42, 233, 57, 252, 192, 212, 251,
-            11, 240, 149, 228, 142, 210, 148, 70, 54, 19, 161, 227, 3, 129, 210, 105, 148, 12, 234,
-            252, 223, 175, 135, 236, 148, 20, 2, 40, 107, 238, 0, 188, 127, 14, 1, 150, 153, 13,
-            230, 20, 183, 250, 35, 194, 62, 115, 82, 211, 170, 95, 213, 130, 137, 2, 13, 215, 50,
-            173, 54, 160, 9, 161, 198, 95, 61, 1, 50, 232, 103, 181, 238, 17, 2, 20, 2, 40, 107,
-            238, 0, 188, 127, 14, 1, 164, 216, 79, 42, 243, 2, 116, 6, 90, 96, 250, 51, 212, 194,
-            200, 146, 109, 13, 118, 29, 19, 58, 48, 95, 80, 8, 128, 250, 149, 173, 134, 172, 216,
-            101, 242, 116, 65, 76, 20, 2, 194, 235, 11, 0, 188, 127, 14, 1, 170, 18, 69, 94, 167,
-            10, 76, 211, 129, 129, 224, 46, 136, 10, 83, 17, 76, 202, 249, 135, 166, 245, 26, 16,
-            188, 29, 23, 45, 80, 155, 171, 111, 126, 157, 110, 178, 224, 11, 20, 2, 14, 39, 7, 0,
-            188, 127, 14, 1, 171, 145, 56, 44, 90, 135, 160, 130, 239, 103, 101, 163, 238, 245,
-            204, 226, 145, 178, 80, 124, 90, 195, 214, 255, 229, 225, 14, 206, 194, 82, 93, 21, 84,
-            184, 210, 219, 20, 64, 20, 2, 132, 215, 23, 0, 188, 127, 14, 1, 173, 200, 156, 34, 98,
-            171, 168, 120, 189, 169, 12, 166, 218, 102, 97, 57, 116, 8, 194, 169, 208, 81, 84, 192,
-            97, 85, 20, 190, 229, 41, 241, 146, 136, 129, 128, 27, 63, 124, 20, 2, 40, 107, 238, 0,
-            188, 127, 14, 1, 174, 68, 103, 136, 21, 185, 116, 77, 154, 119, 139, 92, 83, 181, 78,
-            183, 67, 221, 147, 204, 144, 7, 146, 97, 166, 231, 253, 255, 216, 121, 142, 100, 6,
-            129, 125, 18, 93, 123, 20, 2, 225, 245, 5, 0, 188, 127, 14, 1, 174, 134, 10, 225, 242,
-            136, 72, 119, 81, 18, 69, 248, 149, 78, 72, 133, 141, 167, 67, 185, 235, 53, 68, 104,
-            28, 39, 255, 216, 128, 44, 142, 161, 102, 158, 150, 26, 43, 97, 20, 2, 208, 18, 19, 0,
-            188, 127, 14, 1, 176, 184, 223, 226, 30, 186, 236, 77, 197, 238, 190, 120, 157, 179,
-            131, 11, 13, 178, 41, 147, 161, 108, 215, 130, 208, 26, 131, 173, 232, 100, 205, 222,
-            166, 169, 72, 187, 166, 97, 20, 2, 40, 107, 238, 0, 188, 127, 14, 1, 177, 34, 129, 172,
-            63, 7, 44, 42, 85, 182, 10, 50, 84, 92, 137, 168, 210, 186, 93, 4, 205, 224, 138, 222,
-            176, 233, 27, 194, 208, 205, 144, 157, 22, 109, 177, 94, 15, 124, 20, 2, 132, 215, 23,
-            0, 188, 127, 14, 1, 181, 80, 251, 197, 126, 96, 72, 183, 85, 235, 41, 33, 86, 207, 3,
-            112, 216, 144, 132, 88, 41, 79, 233, 134, 253, 84, 182, 5, 171, 220, 245, 113, 207,
-            149, 27, 27, 38, 58, 20, 2, 14, 39, 7, 0, 188, 127, 14, 1, 182, 171, 68, 192, 14, 179,
-            18, 122, 48, 228, 134, 73, 41, 33, 229, 143, 37, 100, 179, 106, 177, 202, 33, 255, 99,
-            6, 114, 240, 231, 105, 32, 237, 214, 1, 248, 242, 184, 154, 20, 2, 90, 98, 2, 0, 188,
-            127, 14, 1, 184, 104, 76, 108, 175, 205, 240, 248, 106, 40, 63, 34, 188, 159, 224, 89,
-            55, 73, 239, 85, 131, 178, 40, 59, 213, 200, 93, 228, 87, 89, 67, 35, 224, 199, 139,
-            71, 241, 56, 20, 2, 40, 107, 238, 0, 188, 127, 14, 1, 196, 236, 152, 75, 97, 140, 70,
-            128, 126, 27, 91, 164, 32, 7, 180, 110, 88, 242, 79, 174, 0, 173, 228, 17, 94, 27, 21,
-            104, 3, 55, 249, 145, 55, 165, 51, 62, 14, 67, 20, 2, 14, 39, 7, 0, 188, 127, 14, 1,
-            204, 243, 196, 198, 88, 156, 164, 113, 199, 170, 144, 156, 198, 101, 33, 43, 179, 96,
-            3, 197, 44, 93, 62, 238, 195, 159, 150, 85, 106, 130, 66, 232, 97, 197, 221, 125, 222,
-            65, 20, 2, 194, 235, 11, 0, 188, 127, 14, 1, 208, 147, 99, 78, 188, 220, 192, 49, 216,
-            81, 157, 6, 197, 138, 43, 66, 174, 112, 34, 222, 79, 50, 66, 211, 147, 142, 133, 85, 1,
-            69, 91, 48, 87, 162, 143, 227, 142, 108, 20, 2, 40, 107, 238, 0, 188, 127, 14, 1, 208,
-            227, 156, 187, 116, 235, 120, 110, 9, 14, 235, 167, 172, 173, 33, 158, 88, 80, 121, 2,
-            187, 162, 117, 123, 124, 134, 149, 248, 239, 91, 115, 224, 103, 61, 111, 165, 131, 76,
-            20, 2, 90, 98, 2, 0, 188, 127, 14, 1, 210, 203, 172, 89, 95, 168, 112, 86, 172, 51, 6,
-            224, 252, 165, 239, 151, 132, 231, 211, 75, 31, 114, 175, 142, 38, 80, 162, 159, 11,
-            60, 229, 15, 134, 223, 80, 28, 232, 88, 20, 2, 14, 39, 7, 0, 188, 127, 14, 1, 214, 200,
-            102, 61, 36, 152, 168, 36, 86, 213, 30, 131, 170, 109, 191, 122, 71, 59, 87, 118, 69,
-            79, 161, 117, 213, 220, 151, 117, 98, 154, 160, 91, 176, 155, 40, 254, 69, 9, 20, 2,
-            194, 235, 11, 0, 188, 127, 14, 1, 214, 234, 173, 215, 235, 35, 44, 42, 85, 181, 255,
-            220, 162, 102, 189, 2, 7, 223, 151, 86, 91, 3, 37, 95, 112, 120, 60, 161, 163, 73, 190,
-            94, 217, 244, 69, 137, 195, 96, 20, 2, 132, 215, 23, 0, 188, 127, 14, 1, 220, 30, 170,
-            50, 243, 22, 52, 34, 46, 224, 19, 223, 244, 4, 52, 71, 199, 23, 106, 127, 160, 99, 165,
-            105, 208, 197, 71, 131, 248, 115, 135, 64, 209, 105, 159, 40, 191, 4, 20, 2, 40, 107,
-            238, 0, 176, 127, 14, 1, 220, 224, 71, 139, 95, 48, 218, 244, 112, 105, 206, 207, 92,
-            49, 218, 154, 28, 253, 227, 115, 45, 43, 112, 27, 249, 249, 76, 119, 186, 126, 153, 51,
-            93, 107, 48, 173, 8, 10, 8, 4, 0, 188, 127, 14, 1, 237, 170, 214, 187, 144, 233, 38,
-            169, 68, 194, 13, 6, 9, 131, 122, 185, 144, 235, 181, 22, 195, 15, 35, 15, 29, 41, 190,
-            125, 99, 247, 195, 104, 201, 164, 153, 190, 79, 34, 20, 2, 14, 39, 7, 0, 188, 127, 14,
-            1, 245, 98, 203, 239, 50, 245, 116, 21, 129, 175, 60, 155, 255, 87, 105, 189, 231, 53,
-            238, 98, 63, 117, 128, 87, 127, 77, 236, 136, 140, 147, 24, 72, 94, 22, 182, 160, 216,
-            16, 20, 2, 40, 107, 238, 0, 188, 127, 14, 1, 251, 13, 31, 44, 41, 254, 144, 136, 185,
-            145, 38, 16, 145, 212, 60, 146, 206, 121, 13, 76, 143, 176, 7, 183, 232, 209, 156, 156,
-            97, 154, 125, 114, 44, 111, 189, 181, 95, 4, 20, 2, 40, 107, 238, 0, 188, 127, 14, 1,
-            251, 175, 85, 221, 216, 160, 214, 57, 242, 50, 102, 225, 91, 193, 194, 187, 216, 203,
-            200, 78, 15, 242, 24, 237, 231, 140, 156, 235, 248, 220, 68, 82, 49, 129, 131, 218, 67,
-            8, 20, 2, 132, 215, 23, 0, 188, 127, 14, 2, 1, 205, 65, 117, 39, 143, 23, 49, 104, 41,
-            196, 6, 160, 92, 217, 205, 184, 213, 222, 95, 178, 61, 38, 179, 103, 47, 140, 188, 161,
-            252, 198, 83, 136, 51, 88, 154, 18, 26, 20, 2, 40, 107, 238, 0, 188, 127, 14, 2, 7, 13,
-            156, 127, 208, 142, 216, 142, 113, 229, 80, 247, 195, 24, 6, 95, 228, 206, 156, 125,
-            88, 67, 10, 241, 127, 165, 52, 248, 125, 39, 25, 93, 217, 60, 206, 102, 119, 25, 20, 2,
-            132, 215, 23, 0, 188, 127, 14, 2, 7, 68, 81, 210, 68, 102, 178, 224, 123, 228, 214,
-            216, 47, 84, 110, 201, 29, 96, 9, 238, 33, 91, 183, 54, 190, 91, 67, 98, 230, 110, 123,
-            70, 110, 199, 45, 71, 98, 79, 20, 130, 153, 190, 4, 0, 188, 127, 14, 2, 10, 254, 128,
-            17, 239, 125, 4, 13, 19, 36, 160, 91, 185, 160, 184, 109, 158, 171, 208, 18, 69, 255,
-            207, 39, 127, 70, 17, 249, 117, 13, 254, 70, 87, 97, 72, 27, 160, 83, 20, 2, 14, 39, 7,
-            0, 188, 127, 14, 2, 21, 215, 152, 227, 140, 117, 130, 217, 217, 21, 37, 44, 51, 49, 98,
-            109, 123, 239, 159, 185, 198, 171, 253, 163, 236, 193, 136, 75, 241, 101, 128, 127,
-            167, 194, 75, 40, 225, 103, 20, 2, 40, 107, 238, 0, 188, 127, 14, 2, 23, 85, 217, 77,
-            251, 148, 197, 232, 10, 204, 244, 9, 46, 166, 248, 237, 8, 117, 68, 87, 109, 221, 207,
-            221, 81, 54, 107, 73, 40, 104, 247, 59, 12, 156, 161, 156, 95, 49, 20, 2, 40, 107, 238,
-            0, 188, 127, 14, 2, 23, 97, 217, 242, 245, 120, 195, 89, 2, 5, 164, 228, 132, 70, 83,
-            89, 7, 64, 198, 78, 195, 30, 93, 42, 241, 38, 204, 231, 27, 198, 13, 249, 91, 173, 99,
-            151, 73, 234, 20, 2, 194, 235, 11, 0, 188, 127, 14, 2, 25, 68, 210, 104, 197, 106, 200,
-            15, 202, 171, 225, 70, 62, 154, 126, 164, 204, 182, 188, 159, 89, 149, 12, 251, 36, 48,
-            63, 58, 45, 67, 54, 93, 27, 194, 52, 134, 143, 20, 20, 2, 14, 39, 7, 0, 188, 127, 14,
-            2, 36, 135, 223, 216, 164, 137, 176, 173, 205, 239, 238, 136, 248, 82, 168, 92, 224,
-            58, 254, 93, 68, 3, 135, 95, 203, 171, 195, 80, 188, 213, 209, 185, 76, 124, 230, 142,
-            236, 2, 20, 2, 180, 196, 4, 0, 176, 127, 14, 2, 37, 47, 95, 206, 35, 13, 124, 190, 202,
-            232, 154, 164, 28, 236, 125, 53, 95, 37, 107, 158, 195, 114, 170, 209, 185, 173, 160,
-            62, 37, 171, 158, 142, 171, 79, 58, 172, 68, 114, 8, 0, 0, 188, 127, 14, 2, 37, 202,
-            183, 75, 28, 68, 52, 22, 70, 180, 243, 22, 5, 236, 202, 173, 2, 58, 142, 23, 214, 216,
-            172, 66, 52, 32, 112, 13, 180, 191, 15, 18, 88, 9, 198, 130, 230, 63, 20, 2, 40, 107,
-            238, 0, 188, 127, 14, 2, 38, 144, 161, 203, 212, 68, 215, 38, 72, 84, 252, 86, 69, 208,
-            101, 52, 27, 144, 148, 123, 217, 190, 21, 100, 223, 130, 32, 7, 62, 171, 64, 67, 244,
-            2, 46, 99, 62, 143, 20, 2, 194, 235, 11, 0, 188, 127, 14, 2, 40, 20, 220, 62, 51, 106,
-            36, 207, 130, 109, 148, 92, 60, 253, 221, 14, 118, 200, 177, 240, 147, 188, 93, 188,
-            252, 238, 244, 227, 8, 23, 51, 120, 135, 129, 53, 103, 251, 4, 20, 2, 132, 215, 23, 0,
-            188, 127, 14, 2, 53, 146, 247, 231, 247, 131, 172, 18, 225, 200, 78, 107, 17, 23, 93,
-            203, 142, 225, 241, 63, 82, 211, 102, 238, 35, 120, 31, 32, 66, 2, 13, 218, 38, 83,
-            239, 219, 232, 75, 20, 2, 40, 107, 238, 0, 188, 127, 14, 2, 56, 5, 35, 40, 249, 88, 44,
-            42, 85, 181, 160, 172, 46, 70, 124, 114, 194, 5, 174, 146, 82, 164, 232, 160, 194, 6,
-            112, 57, 80, 240, 149, 195, 30, 163, 240, 34, 73, 58, 20, 2, 132, 215, 23, 0, 188, 127,
-            14, 2, 58, 231, 183, 113, 150, 28, 62, 97, 48, 184, 142, 235, 74, 53, 37, 67, 8, 116,
-            238, 227, 196, 188, 65, 210, 53, 207, 83, 250, 159, 131, 118, 175, 102, 181, 8, 115, 1,
-            27, 20, 2, 180, 196, 4, 0, 188, 127, 14, 2, 61, 60, 35, 198, 58, 245, 110, 194, 143,
-            37, 244, 40, 14, 201, 183, 70, 70, 229, 225, 196, 246, 42, 102, 220, 219, 225, 164,
-            132, 7, 246, 219, 122, 90, 238, 151, 224, 204, 111, 20, 2, 40, 107, 238, 0, 176, 127,
-            14, 2, 75, 71, 227, 6, 155, 57, 62, 143, 170, 228, 197, 113, 60, 114, 174, 166, 93, 82,
-            170, 22, 22, 211, 233, 24, 222, 227, 129, 159, 187, 224, 140, 212, 199, 109, 189, 117,
-            74, 80, 8, 4, 0, 188, 127, 14, 2, 77, 110, 227, 195, 248, 138, 34, 204, 203, 53, 247,
-            106, 190, 66, 23, 73, 8, 36, 139, 191, 30, 107, 212, 210, 108, 38, 69, 166, 197, 34,
-            254, 229, 188, 176, 186, 68, 238, 127, 20, 2, 208, 18, 19, 0, 188, 127, 14, 2, 87, 229,
-            221, 62, 45, 15, 22, 27, 224, 151, 164, 40, 42, 165, 141, 174, 2, 150, 36, 57, 95, 167,
-            252, 28, 37, 207, 169, 90, 167, 204, 81, 0, 101, 73, 99, 54, 132, 66, 20, 2, 40, 107,
-            238, 0, 188, 127, 14, 2, 109, 39, 2, 32, 169, 95, 40, 46, 123, 156, 88, 219, 26, 50,
-            140, 3, 195, 177, 116, 236, 46, 109, 77, 108, 241, 228, 214, 160, 34, 26, 58, 241, 59,
-            85, 210, 8, 196, 67, 20, 2, 14, 39, 7, 0, 176, 127, 14, 2, 114, 94, 132, 80, 10, 4,
-            118, 1, 162, 31, 183, 214, 159, 229, 130, 89, 165, 82, 198, 42, 155, 137, 199, 123, 66,
-            136, 116, 17, 52, 64, 6, 119, 74, 205, 250, 59, 69, 119, 8, 4, 0, 188, 127, 14, 2, 117,
-            129, 42, 119, 47, 202, 186, 67, 88, 184, 29, 165, 71, 186, 70, 248, 151, 220, 200, 60,
-            214, 201, 74, 42, 255, 7, 94, 37, 210, 249, 45, 66, 11, 9, 14, 206, 87, 40, 20, 2, 40,
-            107, 238, 0, 176, 127, 14, 2, 126, 252, 120, 2, 12, 11, 178, 226, 175, 118, 192, 146,
-            106, 244, 56, 203, 92, 58, 44, 15, 83, 77, 164, 194, 90, 179, 190, 208, 6, 223, 78,
-            202, 49, 154, 211, 191, 136, 116, 8, 4, 0, 188, 127, 14, 2, 127, 152, 216, 68, 78, 55,
-            48, 96, 107, 76, 27, 137, 180, 229, 98, 239, 175, 231, 107, 236, 128, 21, 74, 200, 179,
-            225, 110, 4, 194, 224, 246, 25, 188, 220, 10, 94, 239, 82, 20, 2, 171, 135, 4, 0, 188,
-            127, 14, 2, 135, 10, 134, 227, 120, 226, 126, 253, 77, 39, 46, 65, 47, 113, 105, 71,
-            13, 147, 255, 251, 3, 7, 88, 84, 175, 238, 198, 240, 35, 10, 212, 83, 15, 29, 85, 252,
-            72, 71, 20, 2, 40, 107, 238, 0, 188, 127, 14, 2, 145, 212, 34, 248, 26, 142, 224, 64,
-            189, 192, 204, 142, 170, 100, 140, 131, 100, 74, 134, 205, 174, 97, 242, 178, 226, 184,
-            53, 197, 234, 119, 235, 95, 104, 28, 132, 244, 86, 191, 20, 2, 132, 215, 23, 0, 188,
-            127, 14, 2, 153, 154, 206, 90, 166, 170, 168, 232, 115, 136, 224, 131, 179, 241, 169,
-            223, 238, 242, 121, 119, 216, 131, 207, 16, 231, 201, 74, 205, 240, 198, 15, 87, 240,
-            169, 98, 29, 69, 57, 20, 2, 208, 18, 19, 0, 188, 127, 14, 2, 161, 241, 177, 36, 37,
-            185, 214, 158, 21, 128, 3, 34, 47, 109, 104, 226, 93, 159, 57, 232, 129, 199, 76, 42,
-            131, 58, 111, 4, 139, 133, 79, 121, 160, 62, 236, 153, 110, 64, 20, 2, 40, 107, 238, 0,
-            188, 127, 14, 2, 167, 125, 185, 88, 215, 254, 160, 32, 156, 16, 199, 214, 99, 61, 230,
-            78, 22, 106, 104, 217, 180, 94, 127, 2, 170, 205, 148, 151, 245, 197, 82, 229, 119,
-            171, 205, 177, 125, 40, 20, 2, 90, 98, 2, 0, 188, 127, 14, 2, 171, 188, 33, 205, 119,
-            12, 216, 138, 75, 85, 130, 116, 229, 119, 55, 255, 211, 252, 151, 65, 132, 133, 150,
-            25, 154, 41, 215, 127, 229, 17, 128, 77, 32, 129, 12, 183, 96, 80, 20, 2, 14, 39, 7, 0,
-            188, 127, 14, 2, 192, 109, 196, 81, 143, 74, 178, 169, 220, 179, 94, 39, 183, 27, 117,
-            189, 171, 241, 204, 112, 108, 86, 126, 80, 187, 178, 6, 49, 228, 249, 77, 94, 246, 170,
-            215, 64, 252, 109, 20, 2, 14, 39, 7, 0, 188, 127, 14, 2, 193, 228, 117, 99, 56, 140,
-            94, 185, 89, 232, 71, 142, 53, 153, 7, 180, 130, 250, 125, 223, 18, 246, 221, 89, 89,
-            186, 151, 189, 135, 135, 234, 236, 233, 156, 214, 162, 49, 37, 20, 2, 40, 107, 238, 0,
-            188, 127, 14, 2, 206, 208, 61, 158, 144, 141, 44, 42, 85, 181, 143, 120, 66, 63, 163,
-            79, 99, 218, 82, 204, 182, 153, 228, 105, 82, 249, 64, 83, 18, 54, 97, 171, 131, 22,
-            212, 247, 142, 7, 20, 2, 132, 215, 23, 0, 188, 127, 14, 2, 226, 235, 124, 156, 102,
-            125, 160, 204, 34, 36, 8, 108, 44, 255, 202, 196, 124, 99, 242, 113, 182, 148, 237,
-            169, 132, 191, 240, 31, 93, 159, 31, 253, 126, 111, 39, 74, 231, 19, 20, 2, 40, 107,
-            238, 0, 188, 127, 14, 2, 231, 152, 253, 60, 211, 185, 14, 205, 117, 58, 244, 18, 211,
-            188, 8, 69, 113, 175, 78, 80, 157, 43, 53, 18, 159, 174, 162, 22, 101, 242, 51, 41,
-            143, 182, 187, 142, 185, 66, 20, 2, 208, 18, 19, 0, 188, 127, 14, 3, 6, 235, 41, 212,
-            31, 230, 92, 192, 204, 105, 88, 47, 72, 179, 139, 170, 12, 122, 203, 41, 117, 128, 196,
-            250, 202, 211, 80, 86, 105, 31, 153, 155, 243, 197, 58, 224, 138, 68, 20, 2, 194, 235,
-            11, 0, 188, 127, 14, 3, 11, 81, 128, 65, 96, 132, 60, 109, 33, 178, 87, 187, 250, 110,
-            174, 246, 82, 39, 195, 202, 143, 147, 77, 77, 249, 70, 245, 63, 101, 174, 229, 5, 223,
-            166, 183, 65, 200, 82, 20, 2, 132, 215, 23, 0, 188, 127, 14, 3, 13, 11, 219, 207, 219,
-            198, 240, 222, 120, 46, 139, 173, 60, 102, 59, 230, 8, 18, 240, 162, 172, 99, 70, 79,
-            93, 163, 236, 68, 140, 115, 51, 76, 7, 215, 30, 242, 127, 44, 20, 2, 14, 39, 7, 0, 188,
-            127, 14, 3, 18, 55, 166, 61, 241, 76, 96, 95, 209, 48, 138, 241, 206, 133, 186, 181,
-            186, 63, 177, 155, 51, 10, 183, 218, 194, 158, 1, 173, 80, 20, 32, 86, 15, 68, 223,
-            126, 14, 28, 20, 2, 208, 18, 19, 0, 188, 127, 14, 3, 30, 203, 128, 152, 213, 170, 140,
-            43, 174, 96, 104, 222, 131, 141, 31, 198, 132, 219, 102, 157, 90, 209, 223, 226, 111,
-            55, 136, 124, 232, 21, 115, 65, 69, 118, 75, 126, 113, 36, 20, 2, 194, 235, 11, 0, 188,
-            127, 14, 3, 38, 176, 254, 172, 113, 168, 160, 171, 174, 58, 48, 90, 119, 2, 5, 92, 33,
-            47, 123, 235, 81, 182, 216, 240, 64, 178, 125, 184, 66, 117, 42, 228, 205, 15, 54, 161,
-            14, 89, 20, 2, 14, 39, 7, 0, 188, 127, 14, 3, 40, 49, 43, 251, 2, 58, 98, 2, 71, 74,
-            61, 130, 30, 61, 45, 249, 91, 78, 176, 248, 64, 95, 26, 8, 108, 187, 189, 37, 196, 66,
-            91, 201, 120, 206, 141, 161, 179, 186, 20, 2, 14, 39, 7, 0, 188, 127, 14, 3, 40, 77,
-            161, 9, 46, 99, 180, 186, 89, 31, 140, 101, 160, 11, 134, 128, 60, 133, 85, 201, 231,
-            242, 217, 88, 249, 109, 236, 9, 113, 206, 83, 186, 240, 169, 100, 211, 65, 56, 20, 2,
-            208, 18, 19, 0, 188, 127, 14, 3, 50, 116, 84, 1, 202, 65, 53, 248, 157, 148, 22, 169,
-            58, 236, 250, 11, 131, 167, 107, 3, 121, 207, 191, 220, 216, 181, 114, 111, 142, 195,
-            253, 243, 183, 137, 226, 149, 210, 108, 20, 2, 14, 39, 7, 0, 188, 127, 14, 3, 50, 156,
-            147, 118, 169, 94, 44, 42, 85, 181, 138, 5, 217, 73, 177, 235, 227, 209, 143, 29, 63,
-            77, 67, 255, 147, 205, 128, 5, 185, 207, 221, 136, 74, 144, 23, 97, 234, 59, 20, 2,
-            132, 215, 23, 0, 188, 127, 14, 3, 61, 56, 23, 29, 226, 57, 176, 97, 77, 228, 222, 140,
-            251, 215, 199, 96, 251, 169, 155, 68, 106, 3, 11, 13, 221, 143, 0, 160, 221, 132, 223,
-            200, 140, 120, 117, 216, 12, 7, 20, 2, 194, 235, 11, 0, 188, 127, 14, 3, 65, 101, 187,
-            35, 16, 86, 16, 130, 177, 169, 17, 221, 142, 157, 1, 235, 236, 83, 89, 141, 165, 2,
-            207, 17, 138, 245, 67, 219, 69, 140, 230, 208, 230, 11, 67, 165, 175, 102, 20, 2, 194,
-            235, 11, 0, 188, 127, 14, 3, 66, 123, 160, 36, 94, 233, 194, 186, 109, 50, 217, 128,
-            236, 184, 69, 5, 17, 195, 79, 60, 79, 154, 26, 70, 196, 12, 189, 97, 196, 212, 18, 13,
-            227, 82, 93, 159, 5, 115, 20, 2, 40, 107, 238, 0, 188, 127, 14, 3, 77, 74, 253, 13,
-            185, 137, 122, 252, 242, 65, 5, 251, 227, 145, 206, 156, 93, 217, 119, 42, 79, 153,
-            250, 167, 121, 4, 78, 189, 242, 227, 1, 67, 52, 30, 254, 179, 183, 61, 20, 2, 40, 107,
-            238, 0, 188, 127, 14, 3, 80, 238, 3, 109, 170, 209, 242, 23, 121, 198, 233, 232, 97,
-            199, 175, 137, 216, 237, 247, 7, 122, 115, 197, 77, 22, 238, 65, 157, 128, 128, 227,
-            83, 153, 55, 191, 86, 94, 79, 20, 2, 132, 215, 23, 0, 176, 127, 14, 3, 82, 71, 170, 74,
-            169, 37, 120, 139, 181, 80, 245, 42, 37, 223, 223, 225, 219, 36, 53, 97, 239, 185, 86,
-            146, 75, 143, 10, 118, 193, 108, 98, 171, 71, 185, 246, 209, 204, 83, 8, 4, 0, 188,
-            127, 14, 3, 97, 138, 200, 6, 165, 9, 72, 102, 199, 197, 201, 58, 9, 132, 57, 77, 82,
-            121, 251, 190, 203, 7, 193, 228, 131, 104, 176, 66, 86, 110, 187, 99, 44, 32, 85, 105,
-            139, 2, 20, 2, 14, 39, 7, 0, 188, 127, 14, 3, 98, 174, 203, 36, 141, 20, 28, 149, 17,
-            90, 218, 7, 241, 170, 191, 216, 132, 9, 75, 105, 178, 64, 120, 8, 226, 180, 16, 190,
-            106, 188, 42, 63, 205, 239, 60, 84, 23, 16, 20, 2, 40, 107, 238, 0, 188, 127, 14, 3,
-            99, 93, 107, 202, 64, 145, 148, 55, 140, 236, 107, 194, 108, 158, 53, 44, 250, 125, 98,
-            121, 220, 128, 34, 147, 85, 133, 74, 108, 228, 252, 150, 153, 233, 200, 182, 221, 201,
-            58, 20, 2, 40, 107, 238, 0, 188, 127, 14, 3, 104, 130, 162, 35, 151, 117, 228, 178,
-            194, 125, 5, 245, 63, 101, 165, 201, 103, 78, 31, 149, 55, 252, 201, 183, 43, 170, 50,
-            226, 227, 150, 228, 93, 108, 58, 2, 134, 110, 85, 20, 2, 40, 107, 238, 0, 188, 127, 14,
-            3, 109, 203, 44, 120, 41, 214, 140, 7, 108, 45, 214, 233, 202, 168, 209, 53, 206, 100,
-            114, 73, 62, 163, 53, 234, 174, 94, 130, 80, 167, 26, 35, 101, 146, 155, 153, 33, 96,
-            37, 20, 2, 56, 156, 28, 0, 176, 127, 14, 3, 130, 194, 187, 20, 123, 137, 63, 233, 95,
-            59, 84, 220, 185, 135, 242, 159, 61, 231, 201, 121, 168, 142, 154, 138, 182, 164, 92,
-            71, 240, 195, 47, 44, 241, 79, 193, 210, 115, 240, 8, 4, 0, 176, 127, 14, 3, 139, 202,
-            0, 30, 252, 230, 44, 226, 3, 169, 73, 253, 55, 107, 148, 25, 136, 169, 175, 160, 217,
-            231, 126, 80, 38, 188, 168, 243, 170, 132, 97, 244, 55, 83, 253, 79, 54, 96, 8, 4, 0,
-            188, 127, 14, 3, 140, 231, 249, 132, 141, 49, 148, 200, 242, 170, 101, 223, 242, 63,
-            84, 46, 233, 157, 113, 145, 232, 142, 236, 244, 91, 58, 97, 183, 53, 13, 195, 212, 136,
-            4, 16, 126, 57, 61, 20, 2, 180, 196, 4, 0, 188, 127, 14, 3, 147, 128, 49, 117, 225,
-            244, 44, 42, 85, 181, 6, 153, 247, 219, 111, 73, 151, 24, 213, 114, 222, 129, 176, 209,
-            132, 60, 20, 102, 36, 218, 169, 158, 221, 154, 102, 9, 239, 100, 20, 2, 132, 215, 23,
-            0, 188, 127, 14, 3, 149, 159, 132, 176, 99, 188, 214, 194, 154, 124, 57, 206, 228, 91,
-            14, 4, 90, 148, 8, 27, 193, 136, 239, 115, 190, 43, 224, 134, 214, 106, 239, 216, 80,
-            252, 126, 234, 204, 69, 20, 2, 14, 39, 7, 0, 188, 127, 14, 3, 166, 72, 121, 93, 109,
-            150, 240, 147, 253, 215, 33, 79, 172, 165, 126, 143, 10, 16, 232, 234, 76, 3, 196, 96,
-            42, 249, 118, 118, 36, 43, 147, 58, 117, 120, 36, 202, 131, 53, 20, 2, 104, 137, 9, 0,
-            188, 127, 14, 3, 172, 180, 102, 190, 221, 19, 2, 27, 168, 239, 70, 108, 203, 122, 6,
-            191, 222, 253, 186, 1, 129, 126, 98, 10, 218, 89, 84, 195, 79, 97, 127, 102, 98, 226,
-            103, 219, 218, 21, 20, 2, 14, 39, 7, 0, 188, 127, 14, 3, 173, 171, 180, 10, 106, 102,
-            12, 94, 157, 227, 218, 111, 109, 199, 26, 1, 133, 243, 89, 65, 142, 14, 243, 23, 124,
-            235, 142, 176, 39, 214, 85, 128, 127, 0, 172, 191, 160, 85, 20, 2, 40, 107, 238, 0,
-            188, 127, 14, 3, 190, 106, 172, 37, 95, 204, 70, 253, 0, 101, 146, 110, 170, 52, 236,
-            209, 52, 125, 110, 242, 207, 199, 223, 190, 48, 88, 185, 73, 159, 195, 198, 253, 220,
-            42, 22, 30, 215, 46, 20, 2, 40, 107, 238, 0, 188, 127, 14, 3, 195, 1, 34, 125, 14, 161,
-            36, 135, 143, 234, 245, 146, 67, 112, 144, 153, 212, 220, 159, 168, 172, 235, 60, 127,
-            114, 132, 198, 196, 52, 115, 206, 216, 167, 9, 159, 4, 37, 48, 20, 2, 90, 98, 2, 0,
-            188, 127, 14, 3, 200, 255, 48, 255, 123, 253, 90, 231, 1, 2, 72, 218, 241, 154, 11,
-            131, 179, 209, 49, 246, 58, 105, 55, 133, 34, 34, 147, 175, 67, 84, 3, 91, 141, 206,
-            133, 31, 176, 43, 20, 2, 225, 245, 5, 0, 188, 127, 14, 3, 207, 25, 18, 249, 156, 97,
-            76, 124, 21, 81, 226, 92, 172, 121, 182, 126, 2, 111, 150, 12, 66, 82, 185, 199, 254,
-            2, 47, 176, 152, 202, 144, 255, 241, 87, 109, 136, 19, 42, 20, 2, 40, 107, 238, 0, 188,
-            127, 14, 3, 208, 38, 148, 211, 43, 116, 180, 21, 79, 18, 205, 239, 136, 51, 142, 223,
-            168, 92, 189, 214, 75, 97, 196, 16, 24, 126, 79, 121, 113, 5, 122, 239, 50, 205, 175,
-            223, 183, 2, 20, 2, 194, 235, 11, 0, 188, 127, 14, 3, 220, 211, 156, 22, 159, 189, 218,
-            162, 84, 17, 60, 153, 46, 219, 239, 40, 244, 82, 104, 159, 116, 179, 29, 39, 77, 86,
-            129, 194, 160, 18, 55, 159, 228, 196, 14, 64, 238, 121, 20, 2, 40, 107, 238, 0, 176,
-            127, 14, 3, 220, 214, 79, 252, 218, 127, 86, 46, 13, 148, 255, 184, 226, 73, 46, 18,
-            250, 15, 2, 188, 26, 250, 13, 44, 236, 59, 51, 9, 161, 147, 55, 253, 101, 62, 196, 216,
-            30, 100, 8, 4, 0, 188, 127, 14, 3, 229, 82, 147, 197, 223, 203, 30, 159, 217, 165, 119,
-            110, 97, 242, 54, 204, 58, 66, 206, 212, 58, 150, 152, 253, 4, 255, 18, 214, 208, 8,
-            110, 92, 158, 170, 164, 250, 110, 33, 20, 2, 40, 107, 238, 0, 188, 127, 14, 3, 239,
-            254, 95, 14, 183, 96, 152, 187, 59, 226, 224, 57, 214, 109, 88, 31, 254, 116, 203, 68,
-            150, 137, 172, 116, 66, 133, 18, 182, 126, 3, 56, 85, 222, 93, 117, 184, 77, 8, 20, 2,
-            194, 235, 11, 0, 188, 127, 14, 3, 240, 107, 143, 9, 41, 148, 156, 102, 80, 115, 152,
-            12, 155, 219, 213, 98, 14, 249, 168, 96, 185, 241, 239, 190, 218, 143, 16, 225, 62,
-            247, 67, 31, 105, 112, 215, 101, 162, 87, 20, 2, 132, 215, 23, 0, 188, 127, 14, 3, 245,
-            234, 16, 31, 82, 245, 164, 197, 182, 135, 40, 247, 253, 189, 180, 66, 103, 20, 163,
-            133, 1, 122, 71, 29, 92, 210, 42, 21, 106, 205, 48, 164, 2, 119, 230, 65, 123, 96, 20,
-            2, 180, 196, 4, 0, 188, 127, 14, 4, 10, 16, 163, 87, 145, 94, 230, 147, 248, 200, 198,
-            4, 58, 93, 140, 142, 214, 77, 86, 82, 61, 21, 118, 37, 1, 25, 71, 168, 167, 152, 129,
-            152, 125, 158, 145, 0, 150, 58, 20, 2, 225, 245, 5, 0, 188, 127, 14, 4, 12, 41, 177,
-            253, 44, 147, 178, 4, 5, 29, 85, 194, 200, 12, 223, 110, 13, 51, 70, 148, 78, 146, 31,
-            201, 127, 11, 134, 164, 204, 94, 181, 84, 121, 130, 252, 71, 93, 104, 20, 2, 194, 235,
-            11, 0, 188, 127, 14, 4, 16, 51, 181, 167, 117, 81, 182, 195, 223, 161, 166, 200, 123,
-            55, 236, 57, 149, 231, 99, 150, 206, 142, 4, 24, 109, 166, 36, 158, 37, 192, 100, 110,
-            1, 244, 146, 6, 114, 88, 20, 2, 194, 235, 11, 0, 188, 127, 14, 4, 22, 165, 2, 158, 72,
-            127, 176, 234, 141, 0, 108, 40, 223, 141, 40, 51, 222, 250, 72, 80, 166, 181, 255, 71,
-            23, 199, 133, 212, 129, 96, 61, 42, 188, 68, 32, 5, 198, 90, 20, 2, 40, 107, 238, 0,
-            188, 127, 14, 4, 26, 98, 38, 156, 1, 66, 182, 135, 184, 86, 143, 5, 249, 54, 232, 131,
-            108, 63, 24, 78, 207, 154, 19, 85, 111, 14, 42, 7, 213, 91, 40, 235, 211, 25, 153, 243,
-            247, 31, 20, 2, 40, 107, 238, 0, 188, 127, 14, 4, 29, 71, 138, 80, 215, 151, 136, 57,
-            247, 142, 203, 82, 233, 105, 58, 181, 251, 42, 188, 219, 151, 131, 252, 223, 233, 67,
-            66, 144, 59, 86, 176, 24, 163, 177, 55, 0, 66, 90, 20, 2, 40, 107, 238, 0, 188, 127,
-            14, 4, 34, 233, 175, 89, 82, 81, 192, 200, 240, 79, 157, 81, 209, 70, 254, 132, 1, 66,
-            54, 29, 146, 144, 222, 218, 225, 231, 99, 86, 15, 22, 228, 194, 5, 244, 131, 40, 77, 0,
-            20, 2, 208, 18, 19, 0, 188, 127, 14, 4, 37, 74, 67, 244, 17, 189, 44, 42, 85, 181, 196,
-            250, 146, 184, 131, 86, 178, 102, 111, 219, 245, 136, 64, 181, 8, 19, 20, 61, 147, 200,
-            134, 246, 10, 57, 184, 235, 32, 76, 20, 2, 132, 215, 23, 0, 188, 127, 14, 4, 43, 206,
-            41, 53, 226, 77, 72, 76, 220, 118, 224, 182, 178, 203, 78, 48, 133, 3, 39, 207, 55,
-            231, 23, 217, 30, 52, 58, 98, 187, 250, 222, 211, 138, 168, 19, 60, 254, 52, 20, 2, 14,
-            39, 7, 0, 188, 127, 14, 4, 49, 105, 171, 45, 223, 113, 42, 190, 121, 165, 187, 140, 50,
-            200, 243, 8, 79, 202, 149, 164, 26, 18, 85, 77, 51, 17, 130, 86, 184, 76, 254, 71, 32,
-            125, 190, 117, 19, 20, 20, 2, 132, 215, 23, 0, 188, 127, 14, 4, 52, 42, 95, 87, 119,
-            10, 174, 71, 89, 32, 54, 131, 162, 40, 39, 1, 146, 171, 83, 98, 48, 183, 163, 126, 100,
-            113, 147, 130, 143, 25, 116, 197, 159, 108, 26, 91, 192, 44, 20, 2, 14, 39, 7, 0, 188,
-            127, 14, 4, 58, 249, 108, 244, 37, 200, 76, 72, 150, 63, 192, 243, 192, 114, 231, 163,
-            234, 228, 193, 1, 78, 107, 247, 51, 60, 183, 81, 62, 123, 208, 194, 4, 47, 232, 103,
-            132, 129, 46, 20, 2, 14, 39, 7, 0, 188, 127, 14, 4, 75, 108, 162, 153, 179, 63, 48, 29,
-            49, 85, 162, 67, 77, 12, 23, 9, 86, 153, 79, 13, 231, 249, 251, 69, 97, 191, 209, 14,
-            37, 72, 105, 92, 63, 57, 151, 99, 122, 36, 20, 2, 90, 98, 2, 0, 188, 127, 14, 4, 78,
-            92, 67, 187, 227, 8, 100, 146, 227, 234, 14, 98, 228, 227, 87, 229, 124, 91, 103, 50,
-            119, 111, 6, 158, 153, 97, 51, 160, 137, 83, 177, 193, 235, 207, 150, 123, 100, 123,
-            20, 2, 194, 235, 11, 0, 188, 127, 14, 4, 80, 108, 130, 140, 87, 47, 44, 133, 187, 231,
-            126, 70, 75, 151, 173, 77, 54, 164, 155, 194, 184, 69, 102, 195, 139, 155, 246, 239,
-            244, 154, 175, 0, 92, 103, 47, 175, 231, 82, 20, 2, 194, 235, 11, 0, 188, 127, 14, 4,
-            85, 232, 30, 62, 45, 107, 226, 224, 65, 231, 16, 158, 222, 188, 36, 139, 255, 215, 8,
-            110, 58, 118, 210, 156, 100, 3, 255, 73, 24, 97, 187, 80, 102, 130, 148, 185, 59, 82,
-            20, 2, 90, 98, 2, 0, 188, 127, 14, 4, 104, 50, 211, 132, 203, 44, 94, 30, 185, 66, 229,
-            245, 145, 188, 29, 144, 47, 198, 160, 77, 215, 236, 173, 245, 244, 145, 111, 37, 151,
-            223, 5, 5, 198, 197, 33, 65, 44, 75, 20, 2, 132, 215, 23, 0, 188, 127, 14, 4, 106, 18,
-            188, 24, 64, 255, 163, 122, 16, 33, 246, 234, 185, 162, 101, 143, 47, 106, 94, 8, 245,
-            133, 29, 232, 2, 48, 210, 112, 102, 45, 254, 100, 140, 12, 123, 236, 46, 70, 20, 2,
-            194, 235, 11, 0, 188, 127, 14, 4, 106, 252, 227, 7, 35, 31, 122, 177, 170, 206, 123,
-            136, 48, 202, 104, 60, 138, 139, 249, 154, 135, 41, 150, 88, 107, 33, 14, 15, 146, 184,
-            245, 162, 143, 233, 133, 172, 7, 49, 20, 2, 14, 39, 7, 0, 176, 127, 14, 4, 110, 0, 103,
-            200, 85, 168, 20, 159, 213, 115, 164, 173, 142, 181, 221, 128, 161, 183, 14, 137, 54,
-            46, 114, 240, 251, 37, 18, 7, 91, 77, 154, 82, 231, 210, 60, 12, 119, 111, 8, 4, 0,
-            188, 127, 14, 4, 111, 6, 242, 13, 81, 136, 60, 1, 121, 48, 180, 106, 181, 164, 65, 59,
-            243, 21, 59, 0, 18, 135, 237, 95, 247, 253, 189, 39, 52, 207, 105, 171, 200, 67, 244,
-            238, 4, 71, 20, 2, 90, 98, 2, 0, 188, 127, 14, 4, 113, 165, 236, 6, 78, 122, 144, 6,
-            26, 204, 62, 177, 152, 113, 16, 27, 115, 47, 95, 171, 96, 255, 78, 19, 157, 222, 14,
-            210, 123, 133, 137, 62, 209, 90, 206, 60, 223, 26, 20, 2, 40, 107, 238, 0, 188, 127,
-            14, 4, 114, 220, 188, 169, 115, 113, 53, 59, 102, 190, 189, 212, 6, 25, 57, 255, 177,
-            167, 145, 39, 228, 109, 76, 38, 43, 184, 84, 142, 74, 5, 47, 32, 135, 112, 108, 212,
-            204, 98, 20, 2, 90, 98, 2, 0, 188, 127, 14, 4, 126, 48, 36, 214, 109, 254, 98, 143, 54,
-            221, 223, 140, 219, 2, 66, 16, 74, 37, 49, 231, 211, 239, 212, 134, 10, 154, 70, 51,
-            190, 105, 170, 243, 15, 99, 204, 178, 90, 94, 20, 2, 90, 98, 2, 0, 188, 127, 14, 4,
-            132, 179, 25, 171, 205, 149, 44, 42, 85, 181, 40, 91, 80, 80, 191, 175, 124, 96, 43,
-            55, 72, 223, 203, 51, 6, 137, 106, 232, 66, 156, 44, 10, 216, 16, 76, 80, 52, 1, 20, 2,
-            132, 215, 23, 0, 188, 127, 14, 4, 138, 205, 166, 31, 251, 192, 104, 188, 251, 134, 102,
-            58, 190, 198, 32, 120, 78, 236, 81, 87, 47, 161, 43, 165, 225, 10, 127, 49, 41, 129,
-            228, 214, 241, 188, 227, 121, 185, 57, 20, 2, 40, 107, 238, 0, 188, 127, 14, 4, 140,
-            113, 153, 234, 76, 18, 44, 42, 85, 181, 14, 69, 56, 13, 45, 181, 30, 223, 160, 127,
-            244, 109, 1, 187, 195, 79, 144, 143, 122, 166, 180, 112, 193, 89, 53, 118, 172, 53, 20,
-            2, 132, 215, 23, 0, 188, 127, 14, 4, 141, 43, 200, 77, 103, 50, 176, 158, 4, 163, 60,
-            184, 24, 109, 4, 99, 116, 239, 60, 252, 11, 71, 224, 182, 156, 238, 173, 242, 220, 193,
-            140, 27, 172, 221, 205, 97, 188, 3, 20, 2, 148, 53, 119, 0, 188, 127, 14, 4, 146, 175,
-            41, 171, 177, 43, 44, 42, 85, 182, 6, 142, 59, 134, 38, 96, 131, 33, 4, 74, 137, 184,
-            47, 196, 137, 142, 206, 52, 82, 70, 89, 244, 138, 167, 42, 239, 85, 108, 20, 2, 132,
-            215, 23, 0, 188, 127, 14, 4, 159, 180, 125, 77, 35, 250, 246, 143, 175, 221, 166, 31,
-            112, 138, 228, 220, 46, 56, 76, 128, 18, 113, 151, 116, 55, 110, 71, 139, 220, 133,
-            127, 113, 145, 239, 68, 154, 181, 34, 20, 2, 40, 107, 238, 0, 188, 127, 14, 4, 163, 65,
-            118, 56, 138, 127, 170, 182, 147, 134, 226, 184, 38, 79, 189, 190, 154, 179, 87, 6,
-            206, 231, 15, 227, 49, 71, 212, 46, 190, 43, 69, 176, 82, 27, 67, 131, 29, 84, 20, 2,
-            40, 107, 238, 0, 188, 127, 14, 4, 173, 56, 79, 249, 103, 35, 194, 238, 164, 63, 208,
-            228, 94, 14, 117, 97, 48, 224, 22, 103, 83, 59, 202, 160, 1, 226, 158, 1, 146, 80, 30,
-            124, 226, 24, 110, 195, 85, 74, 20, 2, 208, 18, 19, 0, 188, 127, 14, 4, 194, 41, 105,
-            159, 148, 184, 46, 36, 9, 181, 239, 80, 158, 30, 88, 69, 132, 237, 201, 69, 84, 95, 66,
-            252, 187, 63, 40, 143, 51, 85, 233, 25, 66, 6, 180, 206, 119, 63, 20, 2, 14, 39, 7, 0,
-            188, 127, 14, 4, 201, 40, 5, 53, 139, 210, 120, 94, 130, 110, 91, 247, 245, 135, 203,
-            235, 168, 36, 147, 229, 91, 204, 21, 157, 61, 33, 17, 152, 56, 225, 6, 171, 183, 9, 26,
-            196, 66, 45, 20, 2, 40, 107, 238, 0, 188, 127, 14, 4, 215, 213, 237, 138, 16, 239, 128,
-            117, 97, 37, 123, 131, 110, 74, 67, 103, 205, 95, 180, 51, 98, 23, 129, 248, 130, 222,
-            146, 169, 126, 176, 57, 119, 224, 146, 246, 74, 245, 124, 20, 2, 90, 98, 2, 0, 188,
-            127, 14, 4, 216, 146, 124, 161, 96, 65, 115, 8, 0, 71, 122, 95, 176, 48, 47, 169, 255,
-            75, 86, 35, 102, 42, 39, 113, 154, 48, 77, 237, 179, 225, 46, 176, 4, 244, 142, 207,
-            107, 41, 20, 2, 194, 235, 11, 0, 188, 127, 14, 4, 228, 126, 20, 248, 201, 175, 204, 71,
-            47, 138, 163, 175, 218, 185, 160, 241, 171, 182, 96, 136, 129, 116, 72, 105, 4, 38,
-            186, 204, 10, 246, 245, 9, 36, 26, 228, 82, 166, 92, 20, 2, 40, 107, 238, 0, 188, 127,
-            14, 4, 229, 205, 160, 228, 15, 6, 244, 240, 130, 72, 190, 8, 142, 131, 240, 253, 23,
-            159, 59, 50, 212, 140, 69, 64, 173, 170, 17, 150, 48, 151, 146, 146, 232, 178, 75, 18,
-            20, 79, 20, 2, 14, 39, 7, 0, 188, 127, 14, 4, 236, 32, 235, 163, 175, 195, 44, 42, 85,
-            181, 166, 57, 35, 5, 198, 15, 188, 252, 223, 119, 164, 4, 21, 7, 242, 189, 25, 118,
-            192, 93, 193, 73, 210, 35, 157, 21, 130, 33, 20, 2, 132, 215, 23, 0, 188, 127, 14, 4,
-            236, 207, 30, 20, 74, 16, 206, 121, 56, 58, 93, 119, 249, 116, 186, 34, 186, 218, 115,
-            95, 170, 32, 117, 113, 110, 68, 32, 121, 183, 213, 143, 241, 253, 137, 139, 99, 37, 29,
-            20, 2, 104, 137, 9, 0, 188, 127, 14, 4, 243, 108, 184, 49, 194, 109, 56, 7, 102, 178,
-            217, 63, 23, 55, 198, 216, 220, 113, 62, 74, 109, 105, 185, 97, 161, 74, 17, 246, 118,
-            252, 71, 155, 30, 187, 228, 184, 31, 3, 20, 2, 40, 107, 238, 0, 188, 127, 14, 4, 245,
-            3, 92, 22, 231, 216, 184, 23, 18, 225, 142, 118, 207, 231, 39, 189, 163, 250, 101, 102,
-            36, 5, 245, 132, 241, 237, 5, 154, 172, 17, 10, 182, 244, 24, 81, 186, 126, 38, 20, 2,
-            40, 107, 238, 0, 188, 127, 14, 4, 250, 211, 104, 128, 201, 102, 70, 135, 254, 126, 38,
-            48, 56, 187, 193, 179, 113, 198, 126, 79, 24, 178, 58, 107, 47, 101, 201, 207, 232,
-            118, 117, 219, 6, 147, 236, 168, 22, 29, 20, 2, 16, 94, 95, 0, 188, 127, 14, 4, 251,
-            160, 250, 215, 238, 159, 10, 115, 200, 110, 228, 80, 36, 79, 247, 83, 51, 179, 36, 195,
-            19, 248, 253, 227, 95, 140, 242, 22, 96, 30, 209, 184, 184, 189, 152, 227, 142, 114,
-            20, 2, 40, 107, 238, 0, 188, 127, 14, 4, 254, 160, 170, 243, 64, 218, 166, 7, 90, 153,
-            104, 177, 116, 206, 103, 237, 170, 181, 251, 219, 1, 27, 214, 37, 226, 180, 114, 143,
-            149, 62, 100, 68, 56, 106, 54, 184, 114, 42, 20, 2, 180, 196, 4, 0, 188, 127, 14, 4,
-            254, 179, 148, 103, 142, 133, 74, 232, 64, 167, 73, 114, 85, 88, 221, 181, 109, 33,
-            178, 150, 234, 5, 125, 130, 149, 186, 124, 125, 237, 131, 250, 2, 133, 175, 162, 76,
-            198, 0, 20, 2, 72, 232, 1, 0, 188, 127, 14, 5, 3, 58, 45, 174, 192, 195, 240, 102, 165,
-            9, 21, 195, 52, 121, 155, 255, 112, 142, 85, 145, 136, 53, 169, 58, 249, 237, 228, 220,
-            242, 100, 234, 186, 3, 14, 59, 160, 192, 43, 20, 2, 90, 98, 2, 0, 188, 127, 14, 5, 8,
-            42, 163, 78, 67, 84, 44, 42, 85, 181, 246, 79, 237, 181, 233, 165, 53, 228, 53, 140,
-            245, 57, 226, 148, 204, 221, 220, 117, 54, 16, 251, 232, 194, 94, 215, 47, 148, 9, 20,
-            2, 132, 215, 23, 0, 188, 127, 14, 5, 11, 245, 204, 142, 68, 97, 148, 116, 244, 79, 206,
-            201, 104, 235, 215, 189, 170, 236, 27, 205, 238, 12, 1, 233, 64, 20, 42, 232, 28, 96,
-            9, 147, 61, 190, 252, 32, 118, 12, 20, 2, 40, 107, 238, 0, 188, 127, 14, 5, 12, 50,
-            152, 210, 39, 243, 56, 140, 243, 81, 100, 178, 34, 58, 196, 133, 110, 240, 64, 94, 172,
-            216, 42, 43, 46, 38, 99, 144, 199, 191, 145, 77, 207, 10, 224, 206, 155, 18, 20, 2, 40,
-            107, 238, 0, 188, 127, 14, 5, 16, 217, 52, 185, 65, 197, 254, 186, 113, 212, 31, 48,
-            124, 107, 76, 89, 72, 28, 251, 58, 93, 115, 33, 117, 247, 118, 106, 202, 130, 30, 152,
-            177, 255, 168, 234, 159, 53, 91, 20, 2, 132, 215, 23, 0, 188, 127, 14, 5, 17, 37, 187,
-            23, 200, 138, 144, 203, 243, 123, 148, 251, 183, 88, 128, 79, 63, 6, 81, 8, 64, 182,
-            100, 156, 10, 44, 172, 170, 173, 218, 104, 73, 201, 220, 183, 102, 183, 103, 20, 2, 14,
-            39, 7, 0, 188, 127, 14, 5, 18, 160, 13, 23, 104, 250, 102, 114, 204, 126, 216, 29, 75,
-            190, 47, 253, 227, 48, 167, 193, 5, 205, 177, 96, 152, 159, 184, 48, 187, 254, 13, 5,
-            233, 172, 114, 20, 205, 1, 20, 2, 40, 107, 238, 0, 188, 127, 14, 5, 20, 142, 154, 87,
-            134, 243, 72, 60, 229, 222, 191, 6, 86, 69, 73, 181, 231, 104, 133, 196, 118, 7, 28,
-            111, 8, 5, 209, 88, 76, 236, 167, 108, 65, 236, 185, 226, 90, 83, 20, 2, 40, 107, 238,
-            0, 188, 127, 14, 5, 21, 41, 247, 225, 108, 36, 136, 62, 14, 193, 146, 45, 154, 130, 62,
-            24, 156, 193, 100, 143, 167, 11, 198, 204, 189, 191, 114, 104, 186, 98, 108, 124, 228,
-            92, 103, 109, 52, 78, 20, 2, 40, 107, 238, 0, 188, 127, 14, 5, 24, 2, 145, 44, 143,
-            248, 104, 246, 191, 244, 155, 45, 148, 80, 37, 240, 173, 221, 66, 170, 221, 186, 151,
-            128, 152, 69, 165, 87, 44, 36, 247, 169, 17, 36, 157, 220, 7, 99, 20, 2, 40, 107, 238,
-            0, 188, 127, 14, 5, 57, 188, 97, 199, 39, 225, 222, 125, 141, 167, 181, 85, 211, 136,
-            246, 55, 39, 15, 231, 34, 85, 113, 41, 185, 11, 227, 132, 73, 33, 164, 179, 224, 31,
-            65, 42, 152, 245, 73, 20, 2, 40, 107, 238, 0, 188, 127, 14, 5, 67, 41, 4, 69, 21, 84,
-            24, 114, 167, 244, 234, 229, 86, 157, 85, 8, 137, 106, 58, 238, 46, 4, 143, 179, 145,
-            248, 204, 84, 183, 60, 70, 252, 9, 61, 204, 110, 120, 85, 20, 2, 40, 107, 238, 0, 188,
-            127, 14, 5, 67, 243, 192, 99, 6, 238, 198, 182, 17, 87, 32, 226, 193, 4, 158, 172, 64,
-            210, 16, 108, 210, 245, 193, 145, 137, 255, 89, 132, 165, 190, 185, 193, 60, 165, 99,
-            91, 56, 27, 20, 2, 90, 98, 2, 0, 188, 127, 14, 5, 72, 196, 47, 76, 37, 0, 228, 23, 23,
-            14, 109, 119, 249, 15, 109, 123, 48, 139, 221, 184, 164, 20, 244, 74, 135, 98, 55, 4,
-            218, 98, 130, 41, 186, 119, 123, 100, 70, 71, 20, 2, 14, 39, 7, 0, 188, 127, 14, 5, 77,
-            59, 195, 201, 97, 85, 124, 110, 113, 218, 176, 42, 23, 41, 46, 156, 47, 63, 176, 29,
-            18, 163, 137, 90, 243, 57, 113, 168, 250, 113, 11, 178, 130, 12, 185, 215, 163, 30, 20,
-            2, 40, 107, 238, 0, 188, 127, 14, 5, 78, 129, 37, 2, 6, 39, 4, 146, 98, 150, 174, 108,
-            145, 85, 85, 122, 108, 90, 172, 152, 217, 119, 86, 100, 239, 216, 96, 126, 137, 78,
-            242, 16, 250, 44, 128, 182, 89, 65, 20, 2, 194, 235, 11, 0, 188, 127, 14, 5, 84, 3,
-            237, 142, 188, 169, 44, 42, 85, 181, 215, 199, 136, 208, 212, 189, 31, 124, 171, 140,
-            126, 122, 109, 19, 30, 62, 195, 150, 5, 109, 98, 209, 55, 109, 33, 23, 121, 65, 20, 2,
-            132, 215, 23, 0, 188, 127, 14, 5, 99, 57, 62, 245, 114, 225, 190, 229, 108, 163, 106,
-            10, 83, 147, 191, 155, 251, 229, 210, 7, 158, 49, 212, 53, 157, 53, 56, 141, 242, 87,
-            226, 55, 147, 199, 177, 149, 184, 85, 20, 2, 208, 18, 19, 0, 188, 127, 14, 5, 105, 144,
-            198, 211, 138, 56, 156, 20, 219, 228, 152, 42, 231, 58, 8, 75, 175, 225, 247, 238, 162,
-            213, 31, 56, 25, 8, 143, 8, 161, 14, 178, 253, 122, 19, 67, 197, 20, 11, 20, 2, 208,
-            18, 19, 0, 188, 127, 14, 5, 107, 201, 172, 163, 245, 207, 216, 233, 67, 112, 12, 102,
-            37, 255, 134, 155, 37, 140, 119, 34, 30, 165, 254, 10, 1, 56, 15, 175, 96, 89, 210,
-            139, 229, 246, 148, 5, 253, 81, 20, 2, 40, 107, 238, 0, 188, 127, 14, 5, 108, 79, 140,
-            140, 162, 183, 244, 162, 154, 243, 184, 172, 10, 167, 189, 147, 94, 227, 160, 210, 142,
-            238, 239, 166, 235, 117, 47, 139, 29, 153, 213, 118, 152, 136, 42, 18, 104, 117, 20, 2,
-            40, 107, 238, 0, 188, 127, 14, 5, 111, 64, 106, 121, 124, 119, 165, 98, 27, 172, 236,
-            219, 157, 68, 238, 150, 235, 121, 16, 157, 35, 251, 56, 247, 41, 31, 156, 175, 240,
-            211, 220, 20, 103, 198, 104, 71, 218, 231, 20, 2, 40, 107, 238, 0, 188, 127, 14, 5,
-            117, 18, 154, 187, 191, 188, 50, 183, 31, 128, 196, 189, 168, 199, 215, 115, 230, 148,
-            18, 83, 68, 199, 226, 218, 4, 1, 236, 227, 146, 80, 247, 214, 141, 28, 120, 32, 82, 48,
-            20, 2, 40, 107, 238, 0, 188, 127, 14, 5, 118, 237, 216, 158, 56, 200, 35, 87, 216, 238,
-            98, 33, 104, 193, 76, 141, 59, 101, 243, 17, 196, 45, 103, 2, 57, 53, 238, 218, 52,
-            191, 232, 28, 142, 215, 121, 16, 50, 56, 20, 2, 90, 98, 2, 0, 188, 127, 14, 5, 139,
-            197, 199, 99, 170, 139, 44, 42, 85, 181, 176, 87, 35, 88, 92, 116, 33, 66, 141, 158,
-            244, 81, 49, 62, 51, 225, 56, 3, 66, 75, 15, 140, 171, 211, 131, 208, 223, 55, 20, 2,
-            132, 215, 23, 0, 188, 127, 14, 5, 152, 27, 166, 1, 2, 199, 232, 45, 59, 127, 191, 247,
-            245, 255, 16, 16, 178, 121, 67, 80, 18, 224, 206, 59, 104, 220, 56, 126, 178, 130, 127,
-            194, 233, 213, 0, 26, 218, 124, 20, 2, 40, 107, 238, 0, 176, 127, 14, 5, 155, 150, 189,
-            23, 149, 75, 196, 68, 0, 153, 214, 100, 227, 175, 56, 74, 123, 104, 106, 85, 158, 123,
-            101, 235, 126, 58, 96, 54, 168, 217, 30, 151, 86, 253, 101, 223, 109, 21, 8, 4, 0, 188,
-            127, 14, 5, 155, 160, 224, 33, 73, 149, 124, 191, 170, 240, 254, 219, 161, 31, 35, 120,
-            14, 139, 27, 252, 172, 94, 146, 168, 95, 86, 131, 80, 39, 232, 59, 210, 3, 186, 184,
-            108, 203, 7, 20, 2, 194, 235, 11, 0, 188, 127, 14, 5, 160, 170, 223, 33, 245, 214, 218,
-            66, 142, 0, 50, 37, 156, 66, 97, 218, 244, 56, 228, 43, 198, 175, 152, 138, 97, 37, 21,
-            198, 218, 223, 134, 18, 168, 160, 92, 136, 67, 64, 20, 2, 40, 107, 238, 0, 188, 127,
-            14, 5, 178, 63, 37, 169, 157, 127, 16, 178, 243, 251, 170, 8, 235, 51, 97, 161, 89, 11,
-            184, 50, 43, 31, 190, 241, 59, 253, 86, 191, 94, 4, 71, 77, 42, 30, 202, 249, 187, 72,
-            20, 2, 40, 107, 238, 0, 188, 127, 14, 5, 193, 2, 140, 2, 101, 241, 208, 238, 84, 98,
-            170, 31, 105, 251, 177, 61, 27, 187, 163, 254, 119, 65, 103, 206, 130, 165, 205, 54,
-            218, 115, 63, 47, 45, 176, 231, 228, 173, 77, 20, 2, 217, 26, 7, 0, 176, 127, 14, 5,
-            194, 85, 187, 138, 160, 140, 152, 29, 94, 144, 3, 30, 172, 39, 146, 118, 120, 45, 13,
-            21, 171, 151, 251, 48, 137, 138, 105, 171, 196, 238, 250, 167, 151, 197, 114, 130, 59,
-            44, 8, 4, 0, 188, 127, 14, 5, 197, 241, 91, 92, 94, 142, 0, 206, 201, 183, 27, 2, 11,
-            191, 180, 29, 39, 117, 214, 109, 121, 17, 242, 32, 56, 204, 174, 89, 201, 135, 16, 143,
-            233, 164, 109, 50, 42, 110, 20, 2, 40, 107, 238, 0, 188, 127, 14, 5, 204, 76, 97, 123,
-            86, 20, 10, 195, 106, 30, 73, 2, 62, 91, 230, 2, 190, 145, 107, 244, 87, 72, 59, 209,
-            58, 83, 34, 98, 248, 142, 251, 184, 173, 211, 52, 176, 166, 104, 20, 2, 40, 107, 238,
-            0, 188, 127, 14, 5, 210, 170, 63, 157, 130, 149, 184, 224, 109, 194, 230, 187, 107,
-            210, 105, 49, 154, 206, 76, 248, 246, 99, 51, 143, 143, 40, 90, 5, 100, 214, 161, 57,
-            6, 60, 152, 93, 35, 16, 20, 2, 14, 39, 7, 0, 188, 127, 14, 5, 216, 33, 66, 3, 225, 243,
-            152, 163, 247, 12, 232, 54, 214, 228, 24, 222, 168, 101, 234, 181, 118, 35, 146, 201,
-            128, 170, 39, 2, 119, 32, 155, 91, 171, 242, 140, 252, 119, 79, 20, 2, 40, 107, 238, 0,
-            188, 127, 14, 5, 216, 183, 96, 83, 12, 195, 193, 242, 21, 230, 209, 196, 206, 222, 15,
-            103, 199, 93, 254, 140, 31, 111, 50, 68, 163, 24, 180, 64, 95, 183, 117, 231, 107, 139,
-            100, 83, 205, 60, 20, 2, 40, 107, 238, 0, 188, 127, 14, 5, 218, 58, 152, 53, 170, 6,
-            94, 214, 244, 182, 138, 96, 161, 23, 163, 32, 89, 233, 102, 120, 242, 47, 160, 134,
-            197, 5, 247, 168, 236, 225, 60, 122, 46, 120, 180, 120, 143, 21, 20, 2, 14, 39, 7, 0,
-            188, 127, 14, 5, 218, 135, 229, 51, 245, 223, 245, 137, 39, 178, 150, 162, 60, 191, 37,
-            121, 75, 124, 187, 143, 227, 28, 94, 104, 242, 188, 210, 194, 72, 62, 156, 156, 208,
-            113, 34, 22, 242, 50, 20, 2, 90, 98, 2, 0, 188, 127, 14, 5, 218, 197, 225, 185, 116,
-            213, 0, 13, 187, 205, 79, 79, 109, 191, 63, 98, 88, 29, 5, 12, 75, 158, 15, 35, 171,
-            89, 154, 89, 80, 45, 242, 224, 205, 12, 131, 103, 119, 70, 20, 2, 208, 18, 19, 0, 188,
-            127, 14, 5, 229, 8, 200, 197, 43, 201, 126, 246, 199, 80, 7, 30, 196, 234, 103, 58,
-            223, 58, 2, 200, 32, 98, 208, 66, 172, 168, 62, 171, 21, 159, 89, 148, 52, 137, 73, 70,
-            66, 61, 20, 2, 135, 147, 3, 0, 188, 127, 14, 5, 230, 147, 49, 219, 164, 245, 236, 41,
-            143, 234, 90, 187, 45, 20, 42, 55, 145, 87, 199, 111, 149, 69, 19, 121, 60, 37, 212,
-            77, 16, 195, 109, 26, 240, 173, 176, 187, 93, 63, 20, 2, 40, 107, 238, 0, 188, 127, 14,
-            5, 231, 255, 41, 187, 196, 153, 196, 130, 101, 105, 230, 139, 126, 238, 27, 91, 147,
-            64, 110, 73, 81, 252, 215, 171, 107, 64, 190, 81, 154, 125, 181, 198, 115, 47, 102,
-            218, 17, 73, 20, 2, 14, 39, 7, 0, 188, 127, 14, 5, 239, 15, 240, 183, 178, 172, 46,
-            239, 42, 238, 101, 77, 73, 117, 83, 95, 39, 1, 175, 134, 186, 109, 22, 156, 44, 154,
-            21, 153, 177, 102, 53, 162, 165, 228, 100, 13, 185, 77, 20, 2, 208, 18, 19, 0, 188,
-            127, 14, 5, 243, 34, 180, 151, 22, 1, 24, 165, 101, 123, 136, 214, 103, 171, 70, 209,
-            7, 13, 88, 236, 193, 241, 80, 17, 246, 13, 242, 15, 81, 14, 224, 170, 198, 23, 84, 187,
-            158, 1, 20, 2, 40, 107, 238, 0, 176, 127, 14, 5, 248, 102, 52, 208, 169, 225, 70, 25,
-            5, 9, 40, 143, 29, 29, 171, 230, 222, 73, 23, 226, 165, 118, 94, 218, 6, 187, 170, 21,
-            109, 25, 213, 94, 43, 127, 129, 61, 212, 22, 8, 4, 0, 188, 127, 14, 5, 255, 110, 225,
-            236, 149, 145, 44, 42, 85, 182, 21, 249, 182, 74, 198, 168, 79, 196, 81, 60, 67, 26,
-            123, 110, 11, 211, 129, 42, 72, 33, 57, 42, 70, 45, 4, 154, 170, 58, 20, 2, 132, 215,
-            23, 0, 188, 127, 14, 6, 6, 51, 103, 238, 156, 146, 232, 16, 6, 119, 42, 137, 71, 129,
-            125, 185, 230, 103, 111, 13, 103, 234, 233, 207, 246, 53, 79, 45, 106, 201, 57, 33, 50,
-            222, 75, 42, 16, 52, 20, 2, 45, 49, 1, 0, 188, 127, 14, 6, 11, 2, 233, 159, 37, 153,
-            48, 201, 62, 85, 125, 140, 97, 144, 188, 241, 154, 16, 122, 152, 80, 166, 14, 246, 62,
-            219, 146, 51, 248, 125, 176, 186, 24, 36, 194, 139, 162, 29, 20, 2, 208, 18, 19, 0,
-            188, 127, 14, 6, 12, 118, 49, 28, 152, 144, 200, 30, 251, 20, 156, 170, 191, 194, 227,
-            76, 86, 120, 32, 225, 235, 188, 11, 5, 38, 24, 36, 109, 206, 170, 238, 241, 238, 225,
-            18, 92, 128, 105, 20, 2, 40, 107, 238, 0, 188, 127, 14, 6, 12, 127, 150, 44, 126, 18,
-            101, 129, 157, 243, 60, 34, 215, 35, 70, 187, 149, 175, 215, 158, 85, 65, 79, 53, 172,
-            191, 9, 151, 198, 187, 174, 173, 221, 29, 54, 136, 80, 106, 20, 2, 14, 39, 7, 0, 188,
-            127, 14, 6, 27, 23, 58, 204, 119, 46, 4, 90, 176, 229, 70, 93, 96, 139, 74, 175, 31,
-            151, 189, 18, 53, 221, 213, 144, 37, 193, 217, 109, 32, 145, 167, 105, 108, 170, 85,
-            36, 106, 73, 20, 2, 40, 107, 238, 0, 188, 127, 14, 6, 32, 94, 241, 127, 226, 93, 46,
-            235, 26, 104, 132, 191, 54, 156, 125, 26, 179, 249, 255, 117, 183, 157, 208, 246, 198,
-            169, 121, 40, 52, 224, 38, 168, 210, 247, 239, 4, 158, 77, 20, 2, 90, 98, 2, 0, 188,
-            127, 14, 6, 42, 163, 55, 218, 29, 151, 202, 211, 150, 240, 195, 185, 50, 41, 254, 104,
-            212, 27, 249, 77, 59, 90, 240, 225, 41, 229, 147, 26, 179, 98, 21, 252, 200, 56, 46,
-            127, 91, 96, 20, 2, 40, 107, 238, 0, 188, 127, 14, 6, 50, 208, 221, 51, 238, 91, 128,
-            50, 118, 55, 50, 187, 88, 177, 132, 162, 204, 173, 24, 157, 123, 8, 16, 206, 204, 72,
-            205, 245, 105, 146, 148, 123, 128, 4, 77, 55, 80, 37, 20, 2, 132, 215, 23, 0, 188, 127,
-            14, 6, 55, 201, 216, 108, 161, 89, 132, 19, 252, 162, 249, 59, 53, 38, 121, 79, 101,
-            95, 195, 80, 75, 64, 224, 223, 240, 100, 138, 73, 153, 241, 15, 142, 202, 124, 33, 209,
-            194, 30, 20, 130, 38, 105, 4, 0, 188, 127, 14, 6, 56, 163, 84, 73, 189, 114, 222, 24,
-            148, 1, 64, 38, 114, 11, 153, 24, 177, 178, 27, 72, 138, 248, 160, 212, 241, 89, 83,
-            98, 18, 51, 131, 9, 70, 236, 11, 77, 123, 117, 20, 2, 194, 235, 11, 0, 188, 127, 14, 6,
-            70, 87, 4, 69, 47, 110, 29, 225, 84, 248, 188, 22, 228, 167, 186, 207, 48, 49, 65, 201,
-            19, 217, 27, 30, 253, 244, 174, 6, 195, 162, 39, 240, 162, 135, 122, 25, 233, 10, 20,
-            2, 14, 39, 7, 0, 188, 127, 14, 6, 72, 88, 135, 110, 251, 233, 24, 246, 132, 126, 77,
-            76, 155, 202, 127, 220, 105, 78, 234, 223, 168, 227, 109, 47, 73, 213, 129, 243, 254,
-            158, 133, 153, 101, 143, 71, 220, 45, 92, 20, 2, 40, 107, 238, 0, 188, 127, 14, 6, 81,
-            78, 140, 247, 99, 167, 169, 105, 84, 65, 243, 1, 186, 120, 189, 0, 206, 1, 95, 47, 29,
-            161, 78, 184, 145, 69, 49, 250, 56, 105, 85, 2, 54, 157, 231, 44, 37, 111, 20, 2, 194,
-            235, 11, 0, 188, 127, 14, 6, 86, 169, 235, 20, 115, 80, 44, 42, 85, 181, 114, 194, 133,
-            2, 5, 170, 225, 187, 40, 203, 15, 111, 225, 192, 230, 6, 53, 63, 157, 196, 32, 179,
-            121, 99, 23, 186, 196, 84, 20, 2, 132, 215, 23, 0, 188, 127, 14, 6, 93, 209, 133, 185,
-            29, 169, 26, 49, 154, 100, 171, 193, 170, 47, 228, 167, 33, 194, 241, 129, 229, 34, 85,
-            60, 232, 246, 154, 88, 83, 72, 91, 139, 1, 130, 37, 84, 162, 25, 20, 2, 90, 98, 2, 0,
-            188, 127, 14, 6, 101, 147, 193, 200, 246, 217, 222, 215, 232, 218, 81, 143, 238, 194,
-            252, 250, 52, 107, 192, 33, 234, 45, 49, 160, 66, 203, 180, 230, 152, 56, 205, 110,
-            221, 134, 81, 8, 50, 82, 20, 2, 40, 107, 238, 0, 176, 127, 14, 6, 103, 203, 39, 208,
-            142, 93, 238, 5, 114, 222, 206, 143, 246, 11, 187, 54, 161, 32, 228, 122, 121, 32, 164,
-            253, 204, 93, 218, 129, 164, 214, 182, 55, 14, 52, 224, 192, 24, 96, 8, 4, 0, 188, 127,
-            14, 6, 106, 126, 124, 87, 58, 128, 178, 203, 145, 135, 84, 111, 202, 168, 199, 33, 176,
-            40, 5, 166, 237, 114, 252, 22, 44, 12, 186, 145, 110, 74, 242, 110, 149, 182, 36, 102,
-            141, 71, 20, 2, 194, 235, 11, 0, 188, 127, 14, 6, 107, 125, 91, 251, 100, 32, 44, 42,
-            85, 180, 252, 37, 125, 218, 56, 135, 3, 30, 162, 159, 190, 42, 142, 167, 71, 100, 219,
-            15, 63, 243, 238, 116, 107, 88, 54, 94, 243, 37, 20, 2, 132, 215, 23, 0, 188, 127, 14,
-            6, 109, 26, 94, 156, 19, 223, 196, 131, 33, 90, 62, 114, 209, 113, 221, 113, 197, 93,
-            56, 133, 223, 167, 58, 64, 56, 86, 130, 249, 254, 76, 237, 30, 129, 19, 32, 38, 87, 20,
-            20, 2, 90, 98, 2, 0, 188, 127, 14, 6, 109, 182, 146, 94, 18, 239, 4, 218, 151, 117,
-            203, 11, 47, 52, 175, 169, 199, 106, 30, 88, 26, 74, 155, 231, 187, 234, 36, 197, 120,
-            239, 237, 73, 103, 114, 188, 252, 217, 118, 20, 2, 40, 107, 238, 0, 188, 127, 14, 6,
-            113, 161, 132, 197, 100, 212, 160, 155, 135, 179, 72, 128, 197, 55, 94, 204, 168, 73,
-            85, 125, 200, 122, 0, 166, 36, 57, 56, 213, 136, 32, 23, 250, 13, 31, 96, 25, 56, 21,
-            20, 2, 40, 107, 238, 0, 188, 127, 14, 6, 119, 20, 215, 20, 136, 170, 44, 42, 85, 181,
-            209, 51, 68, 42, 197, 109, 183, 229, 235, 254, 115, 152, 151, 169, 138, 55, 194, 88,
-            172, 203, 227, 105, 125, 13, 127, 41, 163, 115, 20, 2, 132, 215, 23, 0, 188, 127, 14,
-            6, 130, 67, 231, 101, 193, 182, 94, 44, 76, 231, 253, 196, 111, 244, 161, 148, 3, 140,
-            12, 196, 94, 105, 106, 189, 90, 7, 37, 133, 170, 31, 56, 140, 206, 81, 41, 20, 199, 42,
-            20, 2, 40, 107, 238, 0, 188, 127, 14, 6, 130, 212, 220, 176, 54, 242, 218, 73, 10, 86,
-            124, 247, 247, 247, 186, 72, 137, 29, 71, 37, 27, 10, 111, 204, 176, 76, 74, 183, 10,
-            251, 142, 238, 127, 69, 64, 116, 215, 35, 20, 2, 132, 215, 23, 0, 188, 127, 14, 6, 132,
-            248, 75, 125, 115, 224, 176, 84, 245, 134, 69, 178, 119, 74, 5, 231, 10, 180, 249, 181,
-            157, 250, 70, 237, 96, 172, 208, 102, 135, 19, 233, 33, 50, 74, 147, 62, 32, 39, 20, 2,
-            194, 235, 11, 0, 188, 127, 14, 6, 154, 161, 6, 14, 152, 134, 44, 42, 85, 181, 161, 22,
-            164, 200, 138, 255, 87, 232, 242, 183, 11, 167, 45, 218, 114, 221, 164, 183, 134, 48,
-            225, 106, 208, 202, 105, 0, 111, 24, 20, 2, 132, 215, 23, 0, 188, 127, 14, 6, 156, 14,
-            23, 83, 227, 139, 74, 21, 133, 71, 66, 153, 185, 222, 182, 144, 19, 42, 109, 88, 110,
-            139, 237, 254, 77, 142, 117, 236, 112, 194, 220, 189, 45, 186, 36, 38, 146, 21, 20, 2,
-            90, 98, 2, 0, 188, 127, 14, 6, 156, 215, 156, 143, 234, 200, 36, 248, 179, 219, 203,
-            19, 234, 33, 75, 103, 12, 182, 17, 254, 121, 57, 226, 10, 35, 219, 25, 100, 116, 133,
-            224, 18, 6, 80, 46, 100, 239, 123, 20, 2, 180, 196, 4, 0, 188, 127, 14, 6, 158, 44,
-            151, 110, 56, 171, 9, 33, 253, 126, 255, 124, 27, 73, 11, 217, 197, 55, 118, 203, 182,
-            194, 185, 43, 182, 187, 223, 190, 137, 205, 119, 1, 133, 136, 127, 168, 191, 74, 20, 2,
-            180, 196, 4, 0, 188, 127, 14, 6, 161, 147, 98, 2, 215, 56, 213, 125, 246, 15, 153, 138,
-            240, 145, 82, 129, 50, 253, 255, 50, 171, 131, 82, 10, 177, 23, 154, 95, 49, 22, 15,
-            51, 212, 56, 63, 103, 143, 191, 20, 2, 90, 98, 2, 0, 188, 127, 14, 6, 162, 81, 170,
-            183, 81, 236, 178, 196, 210, 119, 42, 22, 158, 60, 205, 145, 45, 182, 65, 90, 57, 166,
-            240, 34, 140, 137, 199, 4, 85, 86, 149, 27, 119, 145, 51, 6, 35, 117, 20, 2, 40, 107,
-            238, 0, 188, 127, 14, 6, 163, 166, 179, 98, 57, 10, 44, 42, 85, 181, 184, 109, 35, 35,
-            9, 204, 45, 138, 237, 244, 198, 71, 101, 111, 198, 164, 14, 44, 220, 41, 144, 32, 23,
-            65, 84, 167, 252, 66, 20, 2, 132, 215, 23, 0, 188, 127, 14, 6, 164, 167, 195, 87, 26,
-            132, 102, 227, 138, 101, 195, 245, 58, 25, 95, 99, 205, 115, 76, 88, 57, 67, 61, 38,
-            121, 119, 95, 102, 48, 104, 123, 220, 7, 4, 63, 212, 161, 21, 20, 2, 40, 107, 238, 0,
-            188, 127, 14, 6, 170, 232, 204, 175, 77, 10, 164, 218, 179, 77, 226, 110, 87, 14, 143,
-            179, 1, 249, 201, 71, 198, 245, 248, 98, 223, 187, 39, 201, 222, 92, 244, 115, 103, 92,
-            194, 68, 33, 58, 20, 2, 14, 39, 7, 0, 188, 127, 14, 6, 171, 41, 1, 43, 130, 26, 238, 8,
-            8, 85, 246, 6, 204, 230, 107, 223, 255, 184, 167, 60, 84, 164, 64, 250, 74, 78, 161,
-            249, 164, 135, 183, 226, 218, 222, 218, 172, 32, 91, 20, 2, 16, 94, 95, 0, 188, 127,
-            14, 6, 176, 230, 22, 228, 160, 78, 242, 231, 140, 103, 58, 133, 92, 227, 65, 184, 212,
-            49, 179, 226, 206, 41, 62, 129, 34, 54, 226, 228, 38, 130, 4, 119, 150, 254, 89, 155,
-            183, 52, 20, 2, 194, 235, 11, 0, 188, 127, 14, 6, 185, 155, 216, 24, 10, 172, 232, 199,
-            173, 101, 193, 95, 163, 186, 100, 66, 74, 97, 177, 119, 56, 42, 12, 84, 104, 19, 90,
-            236, 202, 156, 164, 84, 245, 231, 206, 77, 48, 91, 20, 2, 14, 39, 7, 0, 188, 127, 14,
-            6, 196, 163, 208, 84, 83, 145, 98, 21, 104, 191, 75, 122, 185, 15, 192, 106, 193, 148,
-            167, 67, 228, 172, 101, 190, 197, 247, 227, 17, 50, 84, 55, 61, 113, 8, 84, 81, 75, 13,
-            20, 2, 40, 107, 238, 0, 188, 127, 14, 6, 213, 247, 87, 23, 166, 62, 182, 0, 145, 186,
-            31, 156, 11, 170, 183, 48, 13, 35, 187, 201, 206, 163, 188, 65, 30, 81, 138, 104, 36,
-            229, 254, 239, 211, 237, 216, 179, 201, 31, 20, 2, 14, 39, 7, 0, 188, 127, 14, 6, 217,
-            18, 224, 139, 103, 60, 180, 198, 176, 36, 91, 246, 221, 157, 126, 85, 156, 209, 149,
-            175, 220, 1, 161, 71, 243, 218, 208, 177, 84, 164, 217, 112, 220, 31, 197, 247, 48,
-            149, 20, 2, 90, 98, 2, 0, 188, 127, 14, 6, 220, 53, 131, 108, 208, 164, 142, 65, 223,
-            120, 100, 132, 126, 195, 31, 192, 22, 137, 103, 221, 91, 121, 18, 194, 31, 74, 89, 116,
-            56, 198, 151, 247, 244, 193, 162, 156, 77, 87, 20, 2, 14, 39, 7, 0, 188, 127, 14, 6,
-            223, 133, 176, 204, 16, 254, 5, 7, 83, 80, 116, 183, 218, 154, 228, 152, 157, 80, 16,
-            205, 15, 203, 58, 51, 248, 165, 22, 183, 33, 221, 26, 130, 192, 164, 73, 1, 25, 46, 20,
-            2, 40, 107, 238, 0, 188, 127, 14, 6, 240, 121, 151, 49, 61, 69, 174, 21, 149, 248, 112,
-            204, 39, 163, 67, 116, 166, 184, 25, 165, 84, 226, 66, 153, 126, 254, 183, 96, 67, 60,
-            111, 219, 67, 114, 194, 242, 130, 4, 20, 2, 208, 18, 19, 0, 188, 127, 14, 6, 243, 86,
-            74, 162, 243, 59, 86, 25, 108, 20, 223, 10, 112, 54, 185, 67, 236, 208, 19, 150, 104,
-            94, 121, 159, 120, 108, 15, 19, 23, 150, 192, 104, 80, 236, 147, 66, 255, 1, 20, 2,
-            208, 18, 19, 0, 176, 127, 14, 6, 250, 221, 111, 111, 205, 174, 252, 126, 52, 7, 162,
-            23, 41, 100, 192, 225, 128, 190, 180, 246, 141, 217, 32, 124, 19, 136, 130, 47, 169,
-            131, 110, 151, 196, 92, 194, 93, 91, 39, 8, 4, 0, 188, 127, 14, 6, 252, 162, 208, 198,
-            132, 80, 142, 3, 209, 214, 83, 195, 122, 92, 45, 190, 15, 244, 185, 109, 123, 176, 124,
-            153, 8, 89, 74, 93, 248, 71, 64, 178, 6, 204, 81, 17, 23, 118, 20, 2, 40, 107, 238, 0,
-            188, 127, 14, 7, 7, 168, 69, 57, 169, 57, 48, 227, 242, 96, 148, 162, 83, 110, 2, 182,
-            135, 154, 42, 146, 117, 36, 147, 48, 45, 132, 2, 155, 128, 255, 68, 158, 186, 139, 157,
-            216, 13, 29, 20, 2, 40, 107, 238, 0, 188, 127, 14, 7, 9, 83, 104, 118, 103, 179, 8,
-            156, 86, 69, 17, 132, 212, 11, 121, 17, 249, 26, 183, 241, 45, 7, 239, 110, 43, 27, 77,
-            197, 10, 5, 10, 219, 250, 79, 139, 88, 203, 85, 20, 2, 132, 215, 23, 0, 176, 127, 14,
-            7, 13, 9, 143, 0, 97, 97, 38, 67, 25, 237, 106, 8, 149, 192, 65, 18, 145, 127, 201,
-            189, 192, 119, 31, 74, 71, 115, 170, 224, 20, 169, 157, 37, 187, 224, 111, 161, 5, 122,
-            8, 4, 0, 188, 127, 14, 7, 13, 195, 57, 102, 150, 27, 244, 238, 31, 241, 103, 134, 115,
-            127, 159, 254, 170, 12, 129, 109, 180, 27, 153, 220, 12, 125, 116, 181, 37, 111, 45,
-            153, 2, 110, 208, 182, 83, 84, 20, 2, 208, 18, 19, 0, 188, 127, 14, 7, 20, 13, 143,
-            206, 95, 40, 128, 187, 72, 111, 155, 68, 205, 156, 50, 0, 170, 95, 133, 204, 81, 232,
-            146, 26, 188, 129, 187, 121, 81, 66, 72, 228, 196, 134, 232, 92, 249, 101, 20, 2, 40,
-            107, 238, 0, 188, 127, 14, 7, 25, 202, 233, 32, 98, 115, 50, 245, 4, 14, 79, 242, 42,
-            156, 234, 67, 247, 207, 209, 36, 46, 58, 224, 229, 113, 64, 194, 244, 185, 133, 232,
-            63, 253, 229, 229, 196, 73, 44, 20, 2, 194, 235, 11, 0, 188, 127, 14, 7, 27, 249, 85,
-            164, 128, 224, 118, 166, 184, 8, 71, 211, 159, 34, 66, 24, 138, 184, 72, 138, 246, 49,
-            119, 219, 108, 192, 45, 21, 128, 143, 221, 241, 134, 104, 226, 214, 188, 37, 20, 2,
-            132, 215, 23, 0, 188, 127, 14, 7, 28, 125, 211, 181, 63, 179, 52, 250, 78, 16, 242, 67,
-            65, 105, 110, 70, 214, 27, 152, 47, 39, 153, 105, 96, 139, 0, 237, 213, 211, 34, 152,
-            243, 202, 176, 175, 187, 105, 90, 20, 2, 90, 98, 2, 0, 188, 127, 14, 7, 33, 135, 39,
-            143, 21, 215, 61, 118, 129, 166, 151, 59, 132, 38, 200, 225, 164, 7, 155, 37, 216, 164,
-            102, 254, 213, 40, 238, 247, 247, 62, 91, 173, 72, 248, 62, 130, 245, 224, 20, 2, 194,
-            235, 11, 0, 188, 127, 14, 7, 47, 76, 21, 88, 209, 117, 44, 42, 85, 181, 249, 23, 240,
-            172, 96, 85, 97, 155, 174, 255, 57, 38, 3, 32, 109, 244, 0, 112, 99, 49, 125, 48, 248,
-            35, 83, 126, 242, 52, 20, 2, 132, 215, 23, 0, 188, 127, 14, 7, 48, 93, 161, 160, 153,
-            16, 192, 114, 68, 175, 138, 201, 184, 16, 48, 179, 196, 124, 35, 205, 91, 16, 112, 29,
-            74, 84, 144, 121, 14, 32, 209, 42, 88, 62, 94, 96, 104, 35, 20, 2, 90, 98, 2, 0, 188,
-            127, 14, 7, 52, 102, 227, 20, 133, 190, 14, 25, 60, 39, 50, 126, 242, 38, 44, 23, 85,
-            181, 181, 101, 11, 150, 74, 223, 139, 17, 55, 50, 120, 53, 126, 255, 176, 229, 131,
-            130, 214, 24, 20, 2, 40, 107, 238, 0, 188, 127, 14, 7, 69, 130, 196, 159, 57, 40, 210,
-            23, 5, 240, 42, 229, 143, 50, 223, 231, 19, 36, 118, 156, 3, 133, 124, 198, 237, 230,
-            247, 29, 91, 49, 193, 193, 104, 23, 247, 226, 148, 125, 20, 2, 132, 215, 23, 0, 188,
-            127, 14, 7, 92, 217, 71, 6, 2, 213, 44, 42, 85, 181, 241, 158, 83, 189, 139, 176, 140,
-            28, 245, 213, 64, 248, 140, 37, 181, 42, 16, 199, 197, 129, 107, 249, 6, 176, 206, 32,
-            135, 124, 20, 2, 132, 215, 23, 0, 176, 127, 14, 7, 100, 138, 62, 49, 148, 218, 178,
-            189, 176, 215, 116, 152, 102, 37, 73, 142, 11, 95, 206, 134, 12, 125, 88, 16, 59, 219,
-            107, 123, 52, 128, 84, 213, 37, 253, 220, 63, 62, 127, 8, 4, 0, 176, 127, 14, 7, 110,
-            193, 28, 229, 133, 241, 96, 154, 76, 198, 15, 128, 51, 239, 57, 247, 208, 69, 253, 188,
-            208, 146, 9, 212, 237, 37, 123, 223, 150, 221, 4, 237, 54, 74, 118, 57, 141, 83, 8, 4,
-            0, 188, 127, 14, 7, 115, 63, 115, 210, 53, 63, 86, 30, 218, 23, 230, 245, 251, 84, 217,
-            180, 114, 47, 141, 227, 179, 176, 71, 218, 177, 27, 248, 217, 8, 106, 124, 178, 167,
-            33, 134, 12, 208, 28, 20, 2, 40, 107, 238, 0, 188, 127, 14, 7, 120, 164, 116, 6, 219,
-            33, 142, 175, 17, 65, 30, 211, 189, 164, 44, 119, 217, 201, 204, 113, 245, 228, 169,
-            188, 233, 121, 23, 148, 70, 233, 200, 170, 8, 9, 88, 30, 67, 44, 20, 2, 40, 107, 238,
-            0, 188, 127, 14, 7, 137, 23, 130, 144, 151, 130, 63, 36, 198, 31, 102, 245, 199, 152,
-            204, 151, 173, 245, 37, 134, 100, 147, 127, 60, 22, 208, 179, 81, 33, 168, 180, 92,
-            200, 22, 17, 188, 142, 89, 20, 2, 194, 235, 11, 0, 188, 127, 14, 7, 137, 247, 135, 39,
-            206, 227, 200, 133, 160, 17, 219, 198, 49, 131, 65, 111, 167, 127, 120, 169, 103, 173,
-            15, 251, 187, 182, 46, 68, 78, 158, 180, 144, 171, 210, 252, 103, 211, 38, 20, 2, 40,
-            107, 238, 0, 188, 127, 14, 7, 149, 49, 83, 103, 83, 187, 112, 124, 148, 227, 173, 98,
-            237, 145, 156, 241, 238, 190, 255, 227, 56, 17, 97, 196, 218, 239, 132, 154, 48, 109,
-            105, 133, 57, 147, 26, 8, 206, 20, 20, 2, 180, 196, 4, 0, 188, 127, 14, 7, 150, 67, 27,
-            239, 82, 245, 44, 42, 85, 181, 8, 23, 64, 9, 234, 73, 143, 147, 77, 254, 252, 26, 2,
-            62, 157, 3, 180, 59, 198, 1, 162, 247, 250, 8, 68, 18, 97, 96, 20, 2, 132, 215, 23, 0,
-            188, 127, 14, 7, 189, 245, 157, 127, 105, 57, 44, 42, 85, 181, 40, 214, 226, 224, 224,
-            183, 212, 83, 142, 176, 5, 166, 50, 2, 171, 27, 185, 253, 142, 35, 205, 232, 136, 188,
-            87, 63, 103, 20, 20, 2, 132, 215, 23, 0, 188, 127, 14, 7, 199, 4, 168, 185, 31, 21,
-            208, 222, 163, 183, 180, 90, 164, 147, 92, 93, 205, 115, 108, 40, 108, 139, 28, 199,
-            74, 72, 249, 203, 217, 227, 111, 119, 83, 251, 237, 42, 48, 59, 20, 2, 40, 107, 238, 0,
-            188, 127, 14, 7, 204, 241, 148, 63, 194, 135, 218, 16, 50, 249, 8, 68, 144, 101, 217,
-            81, 34, 116, 224, 127, 162, 188, 131, 17, 101, 138, 137, 225, 14, 187, 43, 147, 212,
-            212, 66, 195, 206, 41, 20, 2, 40, 107, 238, 0, 188, 127, 14, 7, 207, 164, 55, 99, 70,
-            198, 112, 70, 176, 158, 27, 137, 167, 13, 104, 1, 248, 243, 234, 15, 179, 193, 158,
-            139, 123, 186, 182, 70, 23, 179, 18, 190, 130, 111, 176, 138, 89, 14, 20, 2, 40, 107,
-            238, 0, 188, 127, 14, 7, 217, 92, 83, 95, 151, 125, 245, 5, 86, 227, 1, 48, 183, 194,
-            63, 78, 197, 32, 51, 40, 23, 98, 153, 99, 178, 166, 158, 91, 2, 74, 175, 194, 159, 231,
-            190, 246, 144, 91, 20, 2, 194, 235, 11, 0, 188, 127, 14, 7, 223, 214, 183, 178, 28,
-            162, 250, 245, 246, 143, 232, 40, 245, 175, 141, 105, 193, 22, 239, 217, 55, 217, 15,
-            25, 86, 176, 237, 217, 78, 5, 216, 181, 40, 90, 142, 178, 166, 99, 20, 2, 90, 98, 2, 0,
-            188, 127, 14, 7, 233, 90, 253, 63, 53, 41, 250, 191, 193, 81, 166, 109, 124, 189, 44,
-            254, 187, 222, 161, 149, 77, 19, 250, 71, 33, 165, 104, 168, 8, 99, 134, 244, 69, 194,
-            206, 250, 38, 28, 20, 2, 194, 235, 11, 0, 188, 127, 14, 7, 244, 176, 190, 168, 206,
-            194, 44, 42, 85, 181, 126, 249, 255, 255, 164, 127, 77, 111, 198, 117, 15, 21, 174, 83,
-            134, 58, 29, 15, 217, 157, 131, 134, 167, 0, 84, 213, 94, 75, 20, 2, 132, 215, 23, 0,
-            188, 127, 14, 8, 16, 11, 237, 9, 248, 102, 176, 176, 0, 228, 8, 80, 155, 176, 51, 68,
-            58, 240, 188, 112, 14, 193, 24, 148, 248, 28, 9, 13, 88, 215, 220, 42, 227, 23, 76, 84,
-            144, 43, 20, 2, 194, 235, 11, 0, 188, 127, 14, 8, 44, 80, 229, 102, 21, 111, 158, 38,
-            145, 194, 157, 6, 37, 2, 250, 222, 129, 68, 160, 234, 194, 91, 227, 54, 154, 39, 27,
-            63, 223, 34, 8, 169, 216, 108, 254, 198, 249, 72, 20, 2, 225, 245, 5, 0, 188, 127, 14,
-            8, 50, 156, 251, 190, 10, 235, 48, 25, 141, 182, 176, 169, 121, 73, 113, 95, 3, 209,
-            205, 199, 254, 221, 109, 41, 178, 44, 173, 200, 214, 57, 234, 11, 205, 44, 246, 132,
-            88, 41, 20, 2, 40, 107, 238, 0, 176, 127, 14, 8, 50, 197, 11, 197, 12, 187, 222, 220,
-            124, 107, 143, 75, 146, 143, 37, 48, 21, 234, 147, 132, 200, 179, 24, 178, 149, 65,
-            168, 215, 191, 63, 226, 5, 86, 79, 172, 163, 50, 126, 8, 4, 0, 188, 127, 14, 8, 55,
-            229, 14, 38, 146, 45, 38, 80, 19, 128, 60, 190, 95, 159, 62, 247, 179, 138, 210, 120,
-            182, 208, 151, 211, 190, 62, 215, 146, 72, 3, 15, 70, 11, 169, 61, 22, 74, 96, 20, 2,
-            14, 39, 7, 0, 188, 127, 14, 8, 58, 42, 86, 11, 85, 233, 84, 94, 128, 100, 248, 137,
-            138, 41, 212, 129, 30, 9, 178, 7, 207, 51, 2, 229, 206, 254, 241, 102, 21, 248, 88, 15,
-            205, 143, 166, 58, 98, 78, 20, 2, 225, 245, 5, 0, 188, 127, 14, 8, 63, 44, 125, 184,
-            126, 104, 224, 67, 216, 247, 135, 44, 216, 149, 248, 149, 124, 145, 121, 196, 38, 72,
-            22, 190, 62, 100, 151, 19, 203, 59, 220, 82, 63, 117, 38, 2, 204, 58, 20, 2, 132, 215,
-            23, 0, 188, 127, 14, 8, 80, 254, 39, 179, 18, 3, 198, 53, 153, 216, 68, 47, 63, 175, 2,
-            71, 69, 82, 245, 152, 28, 255, 46, 112, 20, 75, 90, 59, 185, 117, 180, 141, 236, 45,
-            241, 212, 6, 44, 20, 2, 40, 107, 238, 0, 188, 127, 14, 8, 88, 9, 42, 18, 116, 55, 12,
-            225, 79, 20, 72, 111, 63, 179, 81, 58, 68, 161, 153, 112, 160, 187, 244, 218, 139, 100,
-            44, 152, 55, 207, 162, 81, 179, 51, 132, 222, 194, 87, 20, 2, 194, 235, 11, 0, 188,
-            127, 14, 8, 96, 125, 243, 99, 233, 187, 6, 225, 31, 208, 212, 223, 108, 71, 101, 235,
-            52, 106, 172, 71, 104, 44, 183, 135, 29, 169, 236, 253, 35, 82, 85, 246, 234, 219, 131,
-            146, 178, 13, 20, 2, 208, 18, 19, 0, 176, 127, 14, 8, 99, 202, 176, 204, 92, 211, 140,
-            89, 23, 181, 26, 199, 150, 244, 219, 181, 58, 53, 0, 140, 30, 12, 197, 168, 227, 170,
-            161, 48, 12, 194, 232, 69, 248, 231, 112, 44, 212, 229, 8, 4, 0, 188, 127, 14, 8, 114,
-            129, 78, 27, 146, 74, 96, 159, 16, 28, 143, 135, 8, 141, 81, 228, 227, 254, 62, 223,
-            30, 98, 69, 26, 64, 190, 247, 52, 132, 232, 42, 158, 125, 214, 81, 216, 18, 63, 20, 2,
-            90, 98, 2, 0, 188, 127, 14, 8, 121, 148, 194, 121, 1, 160, 44, 42, 85, 181, 207, 141,
-            0, 81, 30, 245, 74, 199, 166, 7, 115, 129, 14, 144, 107, 239, 177, 179, 34, 242, 213,
-            129, 153, 150, 61, 201, 115, 7, 20, 2, 132, 215, 23, 0, 188, 127, 14, 8, 126, 198, 78,
-            138, 29, 156, 234, 215, 197, 35, 118, 31, 140, 167, 39, 128, 210, 65, 241, 29, 251, 75,
-            53, 67, 196, 161, 225, 114, 99, 39, 77, 52, 244, 104, 0, 29, 87, 26, 20, 2, 14, 39, 7,
-            0, 176, 127, 14, 8, 127, 246, 22, 221, 37, 160, 86, 13, 87, 112, 138, 40, 232, 203,
-            184, 121, 76, 4, 214, 123, 102, 84, 28, 25, 29, 46, 255, 173, 133, 150, 171, 99, 198,
-            25, 242, 87, 170, 17, 8, 4, 0, 188, 127, 14, 8, 147, 124, 39, 57, 199, 3, 212, 156,
-            176, 48, 169, 68, 197, 214, 109, 227, 202, 125, 75, 143, 91, 55, 223, 3, 166, 16, 160,
-            40, 239, 223, 49, 59, 91, 64, 91, 54, 20, 164, 20, 2, 40, 107, 238, 0, 188, 127, 14, 8,
-            157, 252, 49, 176, 226, 241, 38, 168, 28, 199, 241, 231, 35, 128, 148, 148, 145, 203,
-            149, 56, 209, 37, 212, 13, 228, 142, 99, 30, 14, 139, 196, 9, 100, 253, 222, 229, 155,
-            204, 20, 2, 14, 39, 7, 0, 188, 127, 14, 8, 163, 202, 60, 139, 40, 34, 204, 45, 222, 68,
-            3, 212, 119, 215, 132, 171, 176, 72, 108, 225, 137, 8, 175, 32, 159, 44, 157, 133, 129,
-            211, 63, 126, 132, 118, 8, 181, 209, 36, 20, 2, 90, 98, 2, 0, 188, 127, 14, 8, 167,
-            196, 155, 6, 81, 195, 28, 206, 44, 69, 109, 54, 137, 38, 161, 142, 129, 238, 166, 186,
-            84, 4, 214, 41, 187, 18, 150, 72, 184, 35, 119, 176, 126, 239, 227, 83, 173, 76, 20, 2,
-            40, 107, 238, 0, 188, 127, 14, 8, 171, 46, 226, 196, 255, 163, 209, 159, 141, 243, 205,
-            177, 148, 219, 7, 89, 121, 3, 154, 111, 212, 217, 18, 41, 131, 86, 187, 41, 49, 192,
-            140, 53, 219, 73, 3, 245, 51, 69, 20, 2, 14, 39, 7, 0, 188, 127, 14, 8, 172, 153, 252,
-            198, 106, 22, 184, 84, 251, 236, 22, 86, 0, 140, 65, 34, 82, 133, 232, 57, 206, 93,
-            105, 235, 17, 177, 174, 56, 202, 18, 96, 170, 87, 234, 6, 141, 211, 61, 20, 2, 40, 107,
-            238, 0, 176, 127, 14, 8, 174, 177, 239, 67, 245, 66, 196, 76, 213, 40, 94, 22, 10, 33,
-            132, 243, 245, 128, 28, 220, 232, 81, 126, 191, 253, 181, 145, 23, 122, 203, 23, 207,
-            242, 219, 47, 253, 147, 113, 8, 4, 0, 188, 127, 14, 8, 174, 211, 84, 202, 66, 181, 108,
-            236, 234, 44, 72, 39, 22, 135, 201, 38, 167, 40, 20, 207, 204, 237, 233, 147, 218, 210,
-            184, 3, 236, 13, 84, 109, 43, 175, 165, 134, 193, 29, 20, 2, 208, 18, 19, 0, 188, 127,
-            14, 8, 176, 179, 182, 134, 156, 67, 32, 133, 114, 6, 253, 230, 62, 165, 8, 163, 23,
-            167, 123, 193, 202, 42, 121, 92, 151, 133, 51, 183, 31, 199, 188, 33, 211, 82, 216, 50,
-            99, 124, 20, 2, 208, 18, 19, 0, 188, 127, 14, 8, 183, 170, 202, 37, 239, 213, 188, 46,
-            16, 210, 178, 238, 244, 52, 30, 158, 146, 178, 201, 235, 192, 158, 125, 200, 253, 105,
-            213, 22, 18, 140, 168, 14, 145, 244, 3, 34, 176, 42, 20, 2, 90, 98, 2, 0, 188, 127, 14,
-            8, 194, 41, 212, 193, 42, 212, 208, 13, 232, 99, 164, 225, 204, 140, 43, 32, 80, 186,
-            75, 231, 132, 179, 19, 202, 161, 229, 17, 103, 171, 172, 243, 146, 193, 240, 114, 112,
-            131, 78, 20, 2, 194, 235, 11, 0, 188, 127, 14, 8, 200, 117, 118, 229, 246, 64, 204,
-            147, 133, 194, 29, 59, 107, 99, 128, 203, 65, 42, 92, 87, 160, 128, 50, 122, 237, 120,
-            104, 224, 144, 50, 210, 28, 149, 138, 105, 230, 158, 5, 20, 2, 16, 94, 95, 0, 188, 127,
-            14, 8, 201, 9, 7, 200, 151, 136, 156, 106, 52, 1, 208, 108, 239, 48, 253, 187, 51, 144,
-            19, 40, 243, 97, 29, 174, 130, 83, 112, 135, 121, 165, 214, 97, 121, 201, 103, 88, 38,
-            53, 20, 2, 225, 245, 5, 0, 188, 127, 14, 8, 201, 77, 226, 171, 109, 133, 222, 232, 96,
-            249, 127, 209, 112, 29, 131, 223, 199, 27, 16, 223, 160, 208, 239, 168, 126, 208, 19,
-            23, 12, 46, 96, 234, 8, 148, 94, 161, 186, 216, 20, 2, 14, 39, 7, 0, 188, 127, 14, 8,
-            202, 43, 161, 156, 144, 54, 248, 89, 109, 61, 27, 254, 77, 123, 150, 183, 106, 53, 187,
-            82, 7, 142, 219, 67, 122, 94, 91, 147, 47, 173, 95, 101, 59, 240, 8, 14, 13, 80, 20, 2,
-            104, 137, 9, 0, 188, 127, 14, 8, 208, 164, 248, 123, 50, 164, 124, 63, 25, 15, 10, 190,
-            207, 43, 57, 100, 58, 33, 166, 125, 243, 2, 160, 36, 72, 125, 132, 18, 141, 91, 198,
-            143, 204, 68, 90, 194, 58, 6, 20, 2, 40, 107, 238, 0, 188, 127, 14, 8, 212, 196, 154,
-            140, 174, 54, 66, 239, 162, 229, 122, 129, 57, 137, 218, 75, 172, 69, 81, 228, 1, 14,
-            228, 80, 3, 252, 63, 54, 15, 82, 2, 169, 88, 178, 177, 162, 153, 24, 20, 2, 208, 18,
-            19, 0, 188, 127, 14, 8, 226, 194, 180, 127, 97, 194, 112, 145, 249, 55, 251, 169, 72,
-            101, 66, 32, 164, 30, 222, 83, 107, 13, 98, 204, 48, 210, 2, 116, 162, 128, 5, 184, 2,
-            101, 100, 219, 141, 37, 20, 2, 40, 107, 238, 0, 188, 127, 14, 8, 226, 251, 15, 201, 56,
-            201, 80, 153, 115, 100, 144, 146, 22, 141, 7, 95, 143, 249, 25, 43, 70, 220, 116, 165,
-            21, 221, 43, 204, 3, 161, 6, 23, 99, 195, 150, 229, 220, 98, 20, 2, 40, 107, 238, 0,
-            188, 127, 14, 8, 236, 254, 92, 63, 121, 97, 88, 201, 65, 217, 15, 33, 156, 244, 28,
-            225, 105, 245, 64, 201, 105, 54, 25, 63, 191, 238, 96, 28, 14, 154, 230, 228, 21, 199,
-            90, 143, 128, 216, 20, 2, 90, 98, 2, 0, 188, 127, 14, 8, 237, 180, 159, 77, 212, 189,
-            184, 71, 59, 94, 55, 233, 147, 9, 47, 49, 63, 84, 181, 135, 98, 149, 53, 137, 36, 95,
-            85, 82, 97, 46, 201, 0, 66, 166, 213, 157, 128, 122, 20, 2, 16, 94, 95, 0, 188, 127,
-            14, 8, 244, 249, 195, 253, 153, 251, 138, 101, 4, 18, 201, 34, 41, 191, 26, 46, 177,
-            192, 202, 185, 193, 51, 213, 76, 91, 130, 204, 114, 59, 32, 46, 230, 52, 233, 37, 239,
-            250, 106, 20, 2, 132, 215, 23, 0, 188, 127, 14, 8, 247, 197, 155, 102, 254, 34, 22, 52,
-            246, 174, 38, 32, 23, 90, 245, 4, 165, 76, 69, 156, 59, 64, 167, 226, 248, 184, 158,
-            166, 4, 208, 92, 131, 74, 46, 93, 109, 43, 87, 20, 2, 40, 107, 238, 0, 188, 127, 14, 8,
-            250, 176, 32, 72, 167, 247, 190, 228, 255, 26, 218, 172, 235, 89, 42, 150, 91, 8, 233,
-            87, 176, 190, 252, 149, 45, 115, 241, 247, 195, 207, 11, 115, 136, 195, 156, 121, 250,
-            49, 20, 2, 40, 107, 238, 0, 188, 127, 14, 8, 251, 124, 213, 120, 84, 218, 222, 188, 83,
-            110, 125, 251, 179, 121, 112, 210, 50, 191, 75, 94, 109, 6, 221, 66, 29, 155, 66, 148,
-            102, 180, 15, 55, 136, 63, 63, 197, 82, 0, 20, 2, 70, 195, 35, 0, 188, 127, 14, 8, 252,
-            230, 211, 185, 134, 26, 66, 203, 175, 169, 102, 156, 37, 134, 138, 229, 239, 102, 166,
-            43, 150, 22, 159, 157, 86, 249, 177, 221, 39, 174, 219, 36, 29, 134, 188, 178, 221,
-            104, 20, 2, 225, 245, 5, 0, 188, 127, 14, 9, 4, 51, 187, 22, 124, 111, 44, 42, 85, 181,
-            105, 54, 206, 10, 77, 82, 146, 198, 104, 87, 167, 37, 205, 123, 48, 166, 211, 5, 146,
-            47, 70, 49, 56, 6, 102, 229, 255, 46, 20, 2, 132, 215, 23, 0, 188, 127, 14, 9, 8, 41,
-            72, 16, 2, 144, 18, 136, 101, 252, 237, 31, 226, 173, 135, 13, 15, 30, 246, 172, 60,
-            115, 199, 128, 18, 219, 175, 115, 238, 157, 176, 107, 164, 3, 203, 115, 165, 35, 20, 2,
-            208, 18, 19, 0, 188, 127, 14, 9, 13, 53, 203, 146, 201, 112, 94, 52, 136, 23, 171, 185,
-            140, 185, 98, 252, 7, 128, 164, 126, 189, 71, 29, 156, 49, 131, 149, 250, 128, 180, 82,
-            154, 100, 207, 171, 178, 227, 44, 20, 2, 208, 18, 19, 0, 188, 127, 14, 9, 17, 109, 4,
-            118, 196, 62, 246, 27, 128, 66, 206, 62, 111, 76, 55, 49, 71, 28, 106, 87, 228, 168,
-            174, 48, 161, 255, 165, 135, 13, 181, 4, 59, 147, 61, 222, 137, 175, 27, 20, 2, 225,
-            245, 5, 0, 188, 127, 14, 9, 20, 14, 152, 212, 252, 223, 216, 128, 180, 81, 84, 0, 108,
-            9, 235, 86, 140, 105, 241, 254, 188, 13, 173, 204, 170, 89, 114, 61, 205, 5, 140, 218,
-            228, 92, 156, 19, 174, 104, 20, 2, 132, 215, 23, 0, 188, 127, 14, 9, 22, 238, 35, 142,
-            86, 224, 88, 224, 225, 148, 10, 178, 8, 156, 228, 246, 93, 198, 58, 186, 209, 166, 182,
-            84, 86, 26, 154, 11, 9, 254, 92, 198, 121, 240, 222, 211, 252, 40, 20, 2, 194, 235, 11,
-            0, 188, 127, 14, 9, 27, 217, 119, 164, 117, 212, 250, 21, 7, 66, 197, 57, 151, 162, 39,
-            203, 86, 72, 194, 16, 41, 216, 166, 160, 135, 234, 28, 39, 197, 2, 144, 58, 166, 14,
-            247, 112, 24, 35, 20, 2, 194, 235, 11, 0, 188, 127, 14, 9, 32, 50, 228, 53, 70, 143,
-            165, 66, 255, 24, 118, 114, 125, 197, 7, 94, 10, 50, 135, 72, 230, 76, 66, 73, 252, 82,
-            42, 167, 46, 183, 61, 93, 214, 100, 90, 116, 104, 219, 20, 2, 40, 107, 238, 0, 188,
-            127, 14, 9, 38, 36, 26, 174, 239, 122, 52, 220, 77, 110, 108, 117, 32, 121, 27, 233,
-            13, 168, 135, 90, 154, 64, 101, 59, 72, 69, 202, 3, 129, 28, 49, 124, 220, 176, 52,
-            206, 158, 60, 20, 2, 90, 98, 2, 0, 188, 127, 14, 9, 43, 71, 253, 247, 87, 141, 198,
-            191, 198, 163, 202, 222, 19, 176, 252, 45, 26, 209, 116, 31, 63, 180, 86, 43, 137, 199,
-            243, 191, 181, 98, 45, 205, 174, 2, 219, 101, 52, 85, 20, 2, 40, 107, 238, 0, 188, 127,
-            14, 9, 49, 247, 83, 106, 196, 171, 72, 77, 98, 162, 40, 18, 26, 24, 0, 226, 110, 139,
-            1, 57, 153, 87, 3, 48, 154, 129, 202, 68, 103, 48, 141, 115, 75, 70, 120, 218, 61, 64,
-            20, 2, 132, 215, 23, 0, 176, 127, 14, 9, 55, 147, 253, 218, 61, 211, 226, 67, 133, 83,
-            201, 56, 119, 20, 66, 206, 8, 43, 154, 117, 62, 130, 245, 79, 205, 238, 62, 102, 142,
-            170, 198, 222, 57, 233, 236, 112, 209, 122, 8, 4, 0, 176, 127, 14, 9, 56, 179, 227,
-            202, 237, 139, 92, 10, 213, 113, 78, 190, 92, 47, 247, 186, 96, 192, 159, 105, 60, 254,
-            37, 130, 186, 125, 214, 94, 31, 235, 116, 177, 201, 156, 204, 183, 80, 121, 8, 4, 0,
-            188, 127, 14, 9, 61, 57, 50, 83, 215, 56, 44, 42, 85, 181, 114, 221, 143, 120, 4, 21,
-            242, 30, 232, 18, 181, 187, 50, 214, 186, 167, 145, 206, 76, 143, 219, 4, 161, 62, 178,
-            29, 63, 9, 20, 2, 132, 215, 23, 0, 188, 127, 14, 9, 66, 84, 183, 200, 198, 160, 118,
-            119, 194, 164, 222, 178, 104, 147, 119, 49, 154, 142, 131, 13, 93, 156, 224, 174, 50,
-            185, 95, 9, 106, 116, 71, 248, 247, 17, 183, 240, 51, 63, 20, 2, 14, 39, 7, 0, 188,
-            127, 14, 9, 71, 251, 198, 253, 235, 181, 4, 112, 65, 151, 234, 7, 103, 135, 9, 191,
-            254, 77, 124, 196, 160, 32, 62, 95, 146, 218, 47, 104, 28, 197, 229, 235, 211, 153,
-            184, 59, 57, 73, 20, 2, 225, 245, 5, 0, 188, 127, 14, 9, 75, 117, 62, 16, 18, 95, 44,
-            42, 85, 181, 111, 36, 179, 93, 218, 109, 167, 174, 242, 73, 116, 221, 101, 1, 217, 200,
-            87, 248, 212, 29, 102, 207, 136, 213, 189, 5, 48, 68, 20, 2, 132, 215, 23, 0, 188, 127,
-            14, 9, 77, 55, 65, 39, 150, 244, 96, 168, 48, 141, 252, 182, 97, 29, 52, 192, 120, 5,
-            98, 113, 61, 221, 182, 47, 229, 66, 238, 13, 81, 243, 242, 74, 165, 173, 119, 107, 46,
-            82, 20, 2, 40, 107, 238, 0, 188, 127, 14, 9, 81, 248, 202, 117, 175, 25, 44, 42, 85,
-            181, 23, 209, 193, 163, 12, 112, 19, 60, 165, 64, 80, 178, 212, 9, 200, 110, 135, 228,
-            186, 35, 181, 100, 193, 133, 241, 208, 89, 120, 20, 2, 132, 215, 23, 0, 188, 127, 14,
-            9, 92, 56, 58, 236, 65, 202, 126, 116, 226, 149, 192, 64, 146, 125, 228, 32, 12, 119,
-            9, 148, 163, 20, 24, 93, 62, 228, 71, 190, 61, 112, 199, 158, 208, 86, 253, 209, 172,
-            83, 20, 2, 194, 235, 11, 0, 188, 127, 14, 9, 97, 98, 45, 241, 132, 74, 92, 205, 25,
-            199, 130, 95, 241, 122, 109, 215, 230, 105, 23, 156, 225, 190, 155, 222, 253, 5, 160,
-            15, 231, 210, 62, 117, 143, 84, 37, 244, 58, 10, 20, 2, 40, 107, 238, 0, 188, 127, 14,
-            9, 103, 40, 122, 58, 215, 244, 194, 180, 254, 107, 248, 61, 113, 77, 134, 172, 155,
-            218, 88, 72, 210, 177, 142, 70, 31, 91, 3, 61, 17, 35, 75, 46, 243, 222, 102, 12, 25,
-            5, 20, 2, 40, 107, 238, 0, 188, 127, 14, 9, 104, 103, 43, 56, 222, 36, 104, 41, 6, 123,
-            225, 18, 144, 46, 171, 28, 253, 38, 3, 155, 111, 198, 127, 233, 253, 77, 77, 136, 178,
-            54, 14, 123, 30, 191, 6, 28, 16, 15, 20, 2, 40, 107, 238, 0, 188, 127, 14, 9, 114, 198,
-            100, 248, 48, 129, 81, 218, 214, 154, 153, 175, 64, 81, 147, 243, 238, 199, 89, 62,
-            155, 175, 240, 34, 183, 250, 85, 222, 20, 125, 27, 79, 157, 33, 71, 16, 56, 133, 20, 2,
-            194, 235, 11, 0, 188, 127, 14, 9, 127, 218, 167, 203, 73, 130, 202, 151, 110, 73, 159,
-            246, 102, 54, 134, 206, 8, 186, 72, 214, 236, 203, 246, 169, 155, 62, 124, 138, 251,
-            208, 19, 62, 196, 137, 219, 127, 55, 77, 20, 2, 40, 107, 238, 0, 188, 127, 14, 9, 129,
-            67, 54, 19, 140, 148, 138, 46, 58, 81, 56, 87, 232, 252, 102, 230, 126, 75, 215, 90,
-            127, 134, 250, 174, 195, 108, 64, 167, 229, 157, 103, 35, 225, 54, 94, 26, 236, 66, 20,
-            2, 40, 107, 238, 0, 188, 127, 14, 9, 133, 26, 64, 160, 208, 152, 222, 27, 102, 53, 21,
-            81, 95, 99, 238, 18, 40, 2, 75, 241, 101, 80, 248, 236, 192, 87, 195, 193, 18, 18, 201,
-            177, 152, 143, 114, 97, 248, 107, 20, 2, 40, 107, 238, 0, 188, 127, 14, 9, 137, 97, 26,
-            236, 24, 146, 82, 32, 241, 83, 36, 102, 63, 27, 176, 186, 214, 33, 42, 52, 76, 127, 77,
-            235, 64, 170, 110, 63, 72, 154, 86, 219, 113, 213, 69, 80, 126, 25, 20, 2, 194, 235,
-            11, 0, 188, 127, 14, 9, 139, 129, 48, 153, 63, 114, 0, 0, 150, 109, 116, 248, 2, 126,
-            7, 180, 55, 23, 182, 135, 109, 151, 84, 79, 224, 215, 31, 172, 239, 6, 172, 200, 56,
-            39, 73, 174, 148, 78, 20, 2, 40, 107, 238, 0, 188, 127, 14, 9, 141, 213, 126, 68, 241,
-            126, 6, 149, 165, 3, 238, 168, 190, 118, 46, 172, 117, 180, 128, 85, 152, 252, 255,
-            134, 116, 174, 125, 168, 42, 3, 4, 109, 213, 226, 83, 45, 203, 55, 20, 2, 40, 107, 238,
-            0, 188, 127, 14, 9, 145, 204, 128, 138, 100, 235, 120, 9, 70, 40, 155, 75, 239, 255,
-            13, 169, 145, 28, 1, 58, 172, 210, 178, 128, 236, 5, 41, 167, 89, 185, 76, 103, 228,
-            137, 155, 155, 112, 88, 20, 2, 90, 98, 2, 0, 188, 127, 14, 9, 151, 31, 252, 136, 56,
-            48, 168, 132, 212, 105, 75, 239, 109, 28, 105, 1, 71, 80, 40, 226, 63, 36, 174, 176,
-            199, 105, 231, 168, 133, 68, 18, 148, 179, 135, 41, 94, 107, 4, 20, 2, 40, 107, 238, 0,
-            188, 127, 14, 9, 152, 48, 150, 157, 200, 236, 44, 42, 85, 181, 225, 208, 242, 202, 191,
-            189, 202, 104, 27, 172, 67, 193, 246, 132, 116, 141, 24, 192, 59, 14, 4, 226, 139, 47,
-            174, 156, 215, 4, 20, 2, 132, 215, 23, 0, 188, 127, 14, 9, 161, 112, 100, 167, 97, 59,
-            185, 242, 109, 231, 128, 143, 252, 240, 135, 73, 47, 192, 74, 44, 0, 23, 140, 59, 249,
-            174, 81, 45, 31, 200, 23, 248, 67, 15, 96, 9, 56, 1, 20, 2, 194, 235, 11, 0, 188, 127,
-            14, 9, 162, 167, 86, 147, 218, 235, 14, 60, 130, 2, 12, 141, 203, 58, 180, 234, 143,
-            35, 148, 54, 195, 209, 126, 32, 74, 55, 214, 118, 254, 129, 58, 228, 173, 18, 94, 17,
-            216, 13, 20, 2, 132, 215, 23, 0, 188, 127, 14, 9, 164, 60, 17, 82, 158, 250, 242, 232,
-            161, 100, 163, 81, 125, 3, 252, 88, 171, 237, 177, 184, 198, 237, 149, 108, 105, 27,
-            52, 171, 40, 248, 107, 60, 69, 225, 216, 183, 119, 122, 20, 2, 40, 107, 238, 0, 188,
-            127, 14, 9, 170, 127, 76, 79, 58, 6, 254, 244, 140, 192, 252, 233, 19, 51, 203, 218,
-            86, 167, 189, 104, 18, 211, 146, 60, 238, 213, 151, 73, 102, 120, 176, 48, 107, 171,
-            250, 144, 240, 68, 20, 2, 40, 107, 238, 0, 188, 127, 14, 9, 202, 93, 227, 116, 139, 45,
-            40, 182, 92, 198, 98, 82, 31, 148, 206, 226, 182, 207, 148, 157, 2, 30, 24, 137, 246,
-            50, 126, 196, 192, 184, 255, 133, 66, 253, 109, 24, 231, 111, 20, 2, 132, 215, 23, 0,
-            176, 127, 14, 9, 205, 28, 97, 100, 121, 125, 70, 155, 173, 27, 240, 238, 120, 71, 63,
-            20, 113, 252, 150, 168, 73, 46, 41, 150, 141, 128, 75, 208, 242, 55, 18, 255, 37, 160,
-            35, 165, 120, 22, 8, 4, 0, 188, 127, 14, 9, 208, 127, 8, 68, 29, 38, 44, 42, 85, 181,
-            72, 173, 70, 1, 62, 253, 28, 4, 94, 196, 122, 164, 239, 82, 176, 117, 162, 250, 61,
-            225, 28, 72, 109, 238, 128, 124, 137, 15, 20, 2, 132, 215, 23, 0, 188, 127, 14, 9, 215,
-            34, 52, 174, 189, 232, 44, 42, 85, 181, 163, 229, 248, 187, 15, 214, 21, 92, 232, 86,
-            104, 216, 230, 6, 215, 222, 254, 199, 4, 77, 134, 21, 34, 91, 185, 118, 154, 110, 20,
-            2, 132, 215, 23, 0, 176, 127, 14, 9, 251, 28, 50, 143, 111, 148, 71, 229, 3, 182, 48,
-            195, 112, 87, 2, 60, 4, 234, 87, 20, 157, 199, 10, 225, 159, 24, 109, 178, 79, 89, 136,
-            28, 85, 203, 97, 218, 82, 47, 8, 4, 0, 188, 127, 14, 9, 252, 7, 129, 12, 8, 220, 56,
-            54, 81, 97, 15, 204, 82, 209, 229, 250, 54, 75, 166, 167, 51, 0, 231, 54, 253, 26, 226,
-            177, 249, 82, 31, 254, 140, 109, 111, 94, 57, 53, 20, 2, 40, 107, 238, 0, 188, 127, 14,
-            10, 11, 85, 57, 92, 69, 135, 248, 114, 12, 144, 93, 122, 193, 172, 171, 37, 196, 243,
-            83, 223, 158, 183, 89, 224, 20, 30, 71, 50, 84, 13, 22, 58, 196, 68, 38, 15, 1, 119,
-            20, 2, 14, 39, 7, 0, 188, 127, 14, 10, 19, 215, 109, 28, 253, 28, 58, 164, 242, 2, 230,
-            103, 49, 157, 171, 180, 87, 134, 170, 94, 59, 43, 241, 251, 83, 203, 148, 171, 57, 129,
-            178, 157, 32, 230, 155, 178, 141, 94, 20, 2, 40, 107, 238, 0, 188, 127, 14, 10, 23,
-            208, 100, 149, 6, 50, 149, 11, 185, 160, 134, 29, 126, 224, 220, 178, 213, 151, 129,
-            117, 15, 71, 43, 179, 197, 48, 25, 217, 219, 167, 43, 134, 183, 249, 46, 8, 174, 122,
-            20, 2, 14, 39, 7, 0, 188, 127, 14, 10, 26, 30, 4, 12, 26, 153, 19, 190, 115, 206, 146,
-            183, 18, 208, 9, 49, 212, 152, 7, 19, 191, 75, 232, 151, 66, 85, 225, 229, 26, 126,
-            215, 30, 210, 234, 55, 240, 53, 221, 20, 2, 194, 235, 11, 0, 188, 127, 14, 10, 30, 153,
-            171, 203, 119, 92, 46, 26, 171, 238, 231, 165, 11, 113, 85, 168, 145, 220, 20, 28, 91,
-            84, 223, 151, 223, 243, 12, 40, 129, 85, 124, 251, 141, 28, 8, 179, 84, 118, 20, 2, 40,
-            107, 238, 0, 188, 127, 14, 10, 32, 203, 91, 251, 236, 240, 254, 157, 113, 78, 201, 238,
-            15, 116, 227, 50, 130, 221, 158, 65, 31, 208, 244, 124, 26, 161, 117, 83, 57, 38, 66,
-            223, 153, 209, 68, 13, 249, 81, 20, 2, 14, 39, 7, 0, 188, 127, 14, 10, 36, 192, 19,
-            248, 126, 164, 10, 77, 205, 222, 223, 34, 155, 48, 171, 38, 143, 17, 168, 177, 64, 196,
-            24, 17, 191, 236, 131, 62, 70, 82, 161, 205, 32, 165, 113, 176, 183, 45, 20, 2, 14, 39,
-            7, 0, 188, 127, 14, 10, 36, 229, 6, 250, 57, 54, 50, 29, 132, 127, 138, 83, 146, 127,
-            225, 19, 39, 84, 167, 112, 140, 97, 4, 155, 45, 72, 22, 171, 108, 231, 25, 90, 131, 8,
-            233, 124, 61, 65, 20, 2, 14, 39, 7, 0, 188, 127, 14, 10, 37, 39, 226, 174, 108, 46, 18,
-            191, 203, 167, 186, 245, 153, 26, 252, 161, 36, 249, 14, 136, 205, 187, 78, 85, 56, 12,
-            129, 187, 197, 54, 56, 146, 94, 97, 28, 46, 85, 61, 20, 2, 40, 107, 238, 0, 188, 127,
-            14, 10, 42, 95, 215, 200, 89, 167, 178, 235, 206, 206, 133, 255, 236, 239, 45, 246,
-            150, 151, 136, 255, 167, 220, 10, 31, 165, 253, 134, 29, 248, 27, 17, 144, 186, 47,
-            233, 60, 64, 107, 20, 2, 208, 18, 19, 0, 188, 127, 14, 10, 53, 12, 0, 130, 90, 150,
-            218, 17, 2, 25, 115, 72, 184, 5, 165, 251, 79, 225, 61, 68, 150, 8, 47, 100, 253, 221,
-            89, 14, 44, 175, 92, 9, 233, 27, 115, 124, 187, 93, 20, 2, 194, 235, 11, 0, 188, 127,
-            14, 10, 59, 208, 87, 135, 212, 141, 188, 23, 41, 165, 39, 172, 135, 112, 193, 132, 86,
-            241, 66, 220, 87, 178, 64, 105, 201, 255, 16, 50, 214, 195, 161, 87, 45, 132, 184, 17,
-            172, 126, 20, 2, 90, 98, 2, 0, 188, 127, 14, 10, 85, 14, 151, 168, 25, 88, 44, 42, 85,
-            181, 54, 67, 56, 250, 223, 69, 152, 29, 23, 58, 205, 117, 101, 31, 142, 244, 27, 87,
-            147, 22, 148, 195, 135, 12, 188, 197, 72, 112, 20, 2, 132, 215, 23, 0, 188, 127, 14,
-            10, 98, 63, 110, 124, 248, 210, 44, 42, 85, 181, 151, 110, 178, 231, 137, 63, 124, 63,
-            135, 91, 59, 28, 243, 214, 83, 223, 48, 160, 165, 129, 37, 217, 254, 15, 139, 135, 184,
-            50, 20, 2, 132, 215, 23, 0, 188, 127, 14, 10, 99, 100, 5, 121, 185, 68, 234, 103, 73,
-            88, 133, 95, 22, 246, 9, 162, 126, 238, 74, 75, 25, 109, 185, 33, 93, 107, 217, 19, 23,
-            44, 144, 235, 55, 91, 199, 31, 129, 17, 20, 2, 40, 107, 238, 0, 188, 127, 14, 10, 101,
-            207, 60, 36, 93, 25, 152, 42, 160, 15, 223, 56, 53, 241, 9, 171, 152, 165, 105, 160,
-            71, 106, 242, 232, 124, 146, 187, 243, 203, 108, 57, 146, 84, 253, 155, 49, 201, 0, 20,
-            2, 208, 18, 19, 0, 188, 127, 14, 10, 106, 33, 238, 197, 37, 244, 153, 106, 72, 190,
-            158, 81, 76, 98, 113, 34, 210, 140, 188, 129, 40, 56, 155, 136, 178, 48, 168, 82, 40,
-            162, 253, 9, 28, 197, 216, 41, 43, 57, 20, 2, 40, 107, 238, 0, 188, 127, 14, 10, 119,
-            113, 4, 3, 119, 9, 242, 33, 4, 157, 244, 21, 149, 180, 41, 111, 124, 223, 71, 163, 139,
-            91, 124, 49, 135, 249, 202, 213, 92, 72, 173, 96, 39, 126, 201, 44, 232, 105, 20, 2,
-            14, 39, 7, 0, 188, 127, 14, 10, 122, 180, 18, 129, 8, 225, 220, 226, 219, 158, 128,
-            157, 80, 111, 91, 177, 82, 59, 170, 3, 28, 37, 244, 141, 52, 72, 238, 229, 56, 162, 55,
-            102, 194, 104, 106, 127, 254, 44, 20, 2, 180, 196, 4, 0, 188, 127, 14, 10, 131, 132,
-            201, 65, 194, 26, 50, 241, 143, 117, 230, 140, 231, 28, 253, 232, 85, 26, 128, 169,
-            174, 175, 86, 175, 230, 60, 23, 115, 250, 250, 236, 167, 38, 15, 129, 50, 162, 20, 20,
-            2, 40, 107, 238, 0, 188, 127, 14, 10, 133, 221, 248, 230, 163, 227, 210, 79, 128, 92,
-            167, 186, 251, 17, 207, 219, 10, 206, 88, 90, 157, 14, 110, 223, 131, 135, 141, 199,
-            180, 41, 70, 195, 60, 35, 120, 33, 20, 100, 20, 2, 194, 235, 11, 0, 188, 127, 14, 10,
-            134, 247, 192, 206, 0, 179, 22, 94, 120, 240, 175, 55, 79, 165, 128, 120, 52, 60, 246,
-            210, 143, 217, 85, 78, 118, 39, 140, 158, 14, 1, 8, 59, 51, 58, 53, 219, 94, 15, 20, 2,
-            40, 107, 238, 0, 188, 127, 14, 10, 138, 202, 96, 83, 132, 210, 32, 172, 108, 35, 230,
-            149, 24, 245, 192, 72, 205, 212, 52, 31, 67, 29, 35, 241, 189, 203, 163, 171, 250, 247,
-            52, 146, 65, 219, 97, 206, 19, 23, 20, 2, 90, 98, 2, 0, 188, 127, 14, 10, 140, 8, 17,
-            29, 176, 90, 44, 42, 85, 181, 59, 66, 206, 168, 239, 26, 43, 111, 127, 102, 137, 91,
-            137, 4, 164, 118, 75, 204, 93, 163, 210, 63, 148, 25, 83, 7, 108, 72, 20, 2, 132, 215,
-            23, 0, 188, 127, 14, 10, 141, 145, 129, 151, 238, 72, 6, 218, 37, 83, 175, 35, 202, 20,
-            254, 131, 67, 211, 19, 177, 163, 159, 110, 182, 129, 55, 117, 54, 93, 157, 208, 13,
-            224, 34, 9, 205, 172, 82, 20, 2, 90, 98, 2, 0, 188, 127, 14, 10, 147, 135, 124, 227,
-            66, 55, 79, 203, 137, 204, 117, 102, 99, 56, 72, 172, 68, 193, 175, 175, 66, 97, 64,
-            46, 228, 141, 40, 179, 234, 157, 82, 212, 164, 68, 209, 155, 42, 52, 20, 2, 14, 39, 7,
-            0, 188, 127, 14, 10, 148, 78, 171, 195, 193, 171, 172, 178, 8, 197, 224, 254, 216, 51,
-            199, 168, 77, 212, 31, 140, 121, 34, 23, 146, 102, 250, 124, 228, 223, 169, 96, 75,
-            187, 178, 214, 80, 253, 26, 20, 2, 40, 107, 238, 0, 188, 127, 14, 10, 151, 152, 63,
-            110, 207, 252, 150, 98, 90, 12, 189, 9, 49, 173, 131, 26, 221, 59, 202, 166, 50, 9, 80,
-            56, 90, 236, 35, 179, 133, 76, 108, 233, 135, 222, 28, 159, 136, 55, 20, 2, 225, 245,
-            5, 0, 188, 127, 14, 10, 156, 195, 85, 208, 104, 22, 128, 216, 163, 244, 49, 114, 73,
-            168, 149, 228, 180, 155, 173, 207, 167, 41, 60, 251, 210, 21, 214, 229, 82, 209, 192,
-            112, 36, 211, 106, 207, 189, 93, 20, 2, 14, 39, 7, 0, 188, 127, 14, 10, 156, 205, 208,
-            203, 74, 84, 139, 118, 2, 246, 125, 157, 97, 104, 41, 100, 243, 181, 152, 159, 53, 119,
-            82, 247, 93, 251, 164, 48, 96, 61, 161, 56, 64, 89, 183, 159, 21, 53, 20, 2, 194, 235,
-            11, 0, 188, 127, 14, 10, 175, 159, 15, 232, 145, 144, 232, 146, 136, 18, 147, 10, 125,
-            153, 100, 22, 102, 46, 92, 65, 253, 84, 59, 161, 118, 141, 13, 235, 36, 62, 23, 129,
-            16, 113, 229, 125, 163, 96, 20, 2, 40, 107, 238, 0, 188, 127, 14, 10, 177, 168, 203,
-            227, 252, 44, 216, 115, 229, 246, 8, 73, 103, 66, 215, 202, 221, 30, 171, 251, 141,
-            161, 24, 164, 225, 35, 132, 226, 58, 105, 74, 123, 113, 209, 79, 51, 54, 110, 20, 2,
-            40, 107, 238, 0, 188, 127, 14, 10, 186, 249, 158, 141, 89, 242, 88, 101, 49, 53, 127,
-            68, 150, 208, 97, 35, 54, 196, 105, 11, 73, 136, 163, 131, 83, 238, 71, 209, 108, 248,
-            206, 177, 112, 207, 58, 165, 250, 23, 20, 2, 208, 18, 19, 0, 188, 127, 14, 10, 189,
-            172, 218, 106, 15, 240, 44, 42, 85, 181, 84, 56, 76, 230, 3, 231, 1, 140, 27, 208, 223,
-            92, 241, 168, 177, 246, 21, 74, 118, 17, 50, 192, 3, 72, 106, 114, 62, 39, 20, 2, 132,
-            215, 23, 0, 188, 127, 14, 10, 191, 129, 136, 8, 193, 196, 92, 22, 59, 59, 165, 243,
-            255, 249, 185, 8, 125, 26, 115, 164, 15, 44, 161, 245, 160, 119, 178, 58, 242, 255,
-            160, 128, 211, 79, 32, 229, 172, 103, 20, 2, 40, 107, 238, 0, 188, 127, 14, 10, 200,
-            56, 228, 128, 80, 236, 196, 246, 72, 18, 193, 239, 254, 159, 89, 205, 42, 134, 4, 197,
-            223, 4, 15, 149, 130, 163, 32, 159, 118, 32, 177, 251, 24, 35, 55, 164, 229, 25, 20, 2,
-            90, 98, 2, 0, 188, 127, 14, 10, 207, 178, 183, 165, 251, 239, 72, 139, 97, 232, 13,
-            112, 188, 169, 177, 204, 222, 101, 176, 245, 152, 152, 160, 93, 201, 162, 108, 90, 40,
-            69, 62, 50, 69, 208, 243, 223, 202, 70, 20, 2, 90, 98, 2, 0, 188, 127, 14, 10, 210, 37,
-            188, 5, 65, 129, 114, 136, 40, 237, 82, 134, 183, 13, 244, 162, 95, 123, 192, 155, 26,
-            50, 180, 67, 43, 243, 27, 197, 111, 22, 139, 204, 131, 161, 213, 229, 142, 88, 20, 2,
-            180, 196, 4, 0, 188, 127, 14, 10, 213, 185, 53, 212, 64, 135, 162, 137, 66, 169, 210,
-            232, 200, 80, 24, 96, 184, 71, 238, 206, 220, 69, 214, 2, 230, 20, 184, 176, 132, 155,
-            149, 150, 7, 208, 222, 195, 208, 113, 20, 2, 208, 18, 19, 0, 188, 127, 14, 10, 216, 58,
-            188, 50, 238, 3, 76, 38, 11, 58, 113, 150, 102, 0, 113, 202, 27, 39, 231, 155, 242, 52,
-            203, 126, 250, 161, 37, 48, 10, 105, 106, 10, 66, 238, 104, 107, 23, 52, 20, 2, 194,
-            235, 11, 0, 188, 127, 14, 10, 218, 218, 140, 48, 34, 149, 16, 167, 103, 207, 72, 60,
-            160, 98, 156, 108, 181, 219, 253, 32, 245, 249, 216, 70, 139, 21, 59, 144, 191, 60, 64,
-            68, 59, 251, 169, 53, 74, 20, 20, 2, 90, 98, 2, 0, 188, 127, 14, 10, 223, 148, 189,
-            105, 33, 186, 162, 130, 210, 213, 235, 8, 134, 88, 144, 240, 202, 169, 21, 73, 51, 138,
-            243, 227, 128, 76, 112, 245, 165, 145, 125, 169, 196, 242, 69, 160, 30, 63, 20, 2, 16,
-            94, 95, 0, 188, 127, 14, 10, 242, 158, 152, 40, 170, 166, 3, 97, 253, 44, 172, 2, 214,
-            180, 214, 176, 243, 31, 58, 190, 112, 16, 79, 0, 146, 74, 133, 190, 190, 208, 177, 114,
-            103, 153, 11, 56, 200, 139, 20, 2, 90, 98, 2, 0, 188, 127, 14, 10, 243, 1, 102, 163,
-            11, 163, 226, 212, 37, 193, 90, 200, 116, 16, 155, 99, 131, 96, 98, 49, 226, 140, 121,
-            203, 200, 98, 226, 98, 233, 180, 6, 198, 17, 74, 39, 130, 50, 119, 20, 2, 118, 176, 16,
-            0, 188, 127, 14, 10, 243, 3, 200, 15, 121, 71, 198, 24, 131, 138, 124, 218, 15, 186, 3,
-            62, 172, 75, 61, 243, 226, 179, 30, 27, 48, 71, 101, 191, 152, 0, 78, 183, 38, 144,
-            252, 17, 127, 9, 20, 2, 40, 107, 238, 0, 188, 127, 14, 10, 245, 0, 169, 62, 104, 98,
-            34, 17, 37, 197, 147, 77, 117, 37, 159, 51, 91, 253, 206, 157, 106, 171, 158, 76, 107,
-            87, 114, 73, 147, 243, 47, 85, 95, 252, 78, 117, 208, 112, 20, 2, 104, 137, 9, 0, 188,
-            127, 14, 10, 249, 20, 77, 243, 177, 24, 182, 98, 25, 131, 201, 221, 223, 139, 65, 30,
-            109, 84, 94, 62, 249, 110, 200, 213, 166, 230, 55, 159, 152, 250, 94, 228, 77, 233,
-            149, 79, 96, 109, 20, 2, 40, 107, 238, 0, 188, 127, 14, 10, 255, 231, 223, 74, 64, 192,
-            148, 199, 55, 61, 30, 51, 94, 40, 27, 42, 128, 86, 91, 51, 112, 197, 222, 157, 107, 45,
-            135, 253, 240, 205, 218, 197, 100, 19, 119, 162, 75, 80, 20, 2, 132, 215, 23, 0, 188,
-            127, 14, 11, 1, 87, 211, 211, 23, 159, 254, 255, 171, 216, 243, 116, 123, 92, 161, 151,
-            102, 134, 107, 213, 152, 86, 162, 230, 92, 83, 124, 100, 86, 198, 246, 134, 60, 2, 105,
-            21, 244, 115, 20, 2, 40, 107, 238, 0, 188, 127, 14, 11, 16, 208, 234, 167, 178, 105,
-            118, 114, 158, 23, 173, 49, 70, 157, 235, 203, 96, 243, 206, 54, 34, 247, 145, 67, 228,
-            66, 231, 123, 88, 214, 226, 25, 93, 158, 169, 152, 104, 13, 20, 2, 194, 235, 11, 0,
-            188, 127, 14, 11, 22, 63, 45, 30, 127, 197, 62, 240, 164, 19, 107, 171, 234, 115, 159,
-            220, 220, 48, 15, 98, 47, 35, 217, 167, 253, 34, 157, 217, 12, 143, 60, 158, 61, 117,
-            253, 238, 174, 220, 20, 2, 194, 235, 11, 0, 188, 127, 14, 11, 27, 104, 15, 112, 192,
-            124, 224, 81, 34, 5, 184, 203, 184, 81, 231, 185, 216, 98, 210, 4, 177, 223, 45, 117,
-            152, 224, 48, 65, 199, 231, 11, 55, 58, 196, 91, 30, 108, 252, 20, 2, 194, 235, 11, 0,
-            188, 127, 14, 11, 27, 163, 86, 22, 133, 210, 120, 23, 122, 138, 199, 197, 30, 154, 22,
-            94, 221, 26, 2, 116, 2, 85, 90, 180, 45, 47, 142, 200, 16, 117, 54, 223, 127, 179, 64,
-            132, 235, 53, 20, 2, 40, 107, 238, 0, 188, 127, 14, 11, 34, 131, 134, 197, 9, 166, 240,
-            239, 114, 176, 16, 85, 39, 109, 174, 40, 31, 109, 161, 161, 221, 20, 198, 39, 97, 66,
-            175, 211, 174, 216, 248, 182, 201, 75, 54, 210, 167, 23, 20, 2, 225, 245, 5, 0, 188,
-            127, 14, 11, 35, 227, 185, 14, 236, 227, 122, 192, 199, 230, 16, 186, 2, 46, 42, 4,
-            143, 191, 95, 217, 148, 159, 150, 216, 19, 129, 95, 113, 116, 206, 48, 240, 18, 75,
-            197, 44, 150, 100, 20, 2, 40, 107, 238, 0, 188, 127, 14, 11, 35, 231, 30, 232, 51, 249,
-            84, 92, 24, 249, 86, 199, 249, 45, 50, 140, 40, 180, 163, 104, 3, 40, 57, 54, 66, 123,
-            206, 80, 192, 207, 99, 0, 44, 6, 224, 56, 221, 97, 20, 2, 40, 107, 238, 0, 188, 127,
-            14, 11, 36, 87, 61, 165, 100, 250, 56, 149, 114, 62, 133, 232, 121, 129, 109, 241, 164,
-            186, 156, 8, 92, 54, 204, 106, 89, 29, 112, 133, 210, 235, 239, 223, 156, 58, 63, 186,
-            94, 41, 20, 2, 132, 215, 23, 0, 188, 127, 14, 11, 43, 202, 113, 157, 151, 199, 238, 32,
-            25, 221, 69, 175, 254, 168, 234, 250, 162, 99, 243, 70, 194, 91, 71, 124, 239, 104, 88,
-            189, 115, 40, 152, 153, 78, 70, 76, 181, 114, 17, 20, 2, 90, 98, 2, 0, 188, 127, 14,
-            11, 44, 139, 50, 71, 125, 157, 128, 131, 59, 152, 222, 170, 122, 226, 1, 201, 210, 170,
-            187, 166, 215, 203, 220, 20, 236, 254, 21, 72, 197, 43, 138, 196, 144, 122, 203, 20,
-            200, 99, 20, 2, 225, 245, 5, 0, 188, 127, 14, 11, 46, 191, 34, 229, 248, 72, 158, 255,
-            199, 251, 144, 76, 163, 216, 204, 79, 69, 180, 100, 238, 77, 172, 112, 92, 137, 136,
-            141, 41, 139, 201, 223, 210, 186, 86, 61, 91, 62, 63, 20, 2, 90, 98, 2, 0, 176, 127,
-            14, 11, 56, 246, 153, 44, 213, 92, 14, 6, 167, 179, 1, 59, 219, 205, 79, 100, 157, 71,
-            168, 46, 100, 107, 225, 34, 59, 229, 206, 184, 211, 46, 136, 71, 251, 125, 189, 44,
-            207, 87, 8, 4, 0, 176, 127, 14, 11, 67, 53, 121, 228, 129, 32, 24, 158, 246, 93, 26,
-            119, 172, 188, 20, 146, 201, 94, 163, 165, 140, 183, 11, 159, 255, 242, 17, 25, 3, 104,
-            187, 250, 33, 25, 140, 115, 76, 45, 8, 4, 0, 188, 127, 14, 11, 69, 58, 87, 255, 226,
-            199, 64, 154, 50, 54, 167, 155, 242, 77, 18, 37, 42, 151, 102, 240, 179, 169, 22, 238,
-            187, 128, 33, 178, 76, 131, 254, 198, 227, 114, 101, 29, 58, 73, 20, 2, 14, 39, 7, 0,
-            188, 127, 14, 11, 70, 206, 129, 186, 54, 176, 126, 183, 28, 35, 86, 237, 208, 57, 231,
-            20, 20, 31, 240, 35, 87, 158, 151, 148, 102, 192, 243, 172, 236, 86, 103, 20, 235, 238,
-            209, 95, 93, 108, 20, 2, 180, 196, 4, 0, 188, 127, 14, 11, 75, 0, 235, 154, 12, 207,
-            158, 68, 124, 173, 71, 175, 162, 31, 52, 32, 131, 233, 239, 24, 207, 4, 162, 125, 129,
-            251, 208, 205, 116, 46, 142, 195, 101, 40, 196, 81, 66, 105, 20, 2, 90, 98, 2, 0, 188,
-            127, 14, 11, 76, 113, 194, 173, 21, 195, 248, 91, 212, 236, 154, 85, 140, 222, 63, 5,
-            227, 59, 11, 116, 249, 231, 50, 204, 65, 249, 4, 137, 72, 115, 36, 125, 76, 67, 90, 59,
-            139, 99, 20, 2, 90, 98, 2, 0, 188, 127, 14, 11, 85, 20, 249, 176, 117, 191, 44, 42, 85,
-            181, 227, 17, 6, 135, 120, 75, 167, 6, 55, 83, 117, 123, 164, 9, 5, 43, 41, 137, 171,
-            157, 239, 9, 166, 132, 232, 174, 253, 109, 20, 2, 132, 215, 23, 0, 188, 127, 14, 11,
-            106, 23, 171, 132, 98, 79, 210, 108, 196, 214, 223, 55, 98, 109, 138, 75, 150, 152, 87,
-            77, 245, 149, 241, 178, 82, 86, 167, 130, 253, 86, 50, 242, 179, 71, 113, 10, 239, 93,
-            20, 2, 90, 98, 2, 0, 188, 127, 14, 11, 112, 92, 189, 204, 119, 106, 180, 65, 13, 51,
-            241, 60, 5, 61, 202, 135, 190, 101, 122, 10, 227, 204, 135, 101, 91, 175, 67, 247, 239,
-            221, 69, 79, 247, 78, 58, 157, 138, 47, 20, 2, 194, 235, 11, 0, 188, 127, 14, 11, 117,
-            31, 127, 145, 209, 241, 190, 125, 52, 43, 109, 206, 170, 11, 200, 15, 14, 28, 109, 132,
-            21, 165, 137, 144, 141, 219, 80, 239, 89, 241, 235, 189, 82, 7, 39, 255, 96, 121, 20,
-            2, 40, 107, 238, 0, 188, 127, 14, 11, 118, 113, 6, 225, 98, 225, 122, 151, 73, 207,
-            183, 37, 110, 126, 72, 237, 202, 122, 105, 17, 1, 136, 97, 236, 110, 38, 217, 235, 44,
-            129, 187, 240, 55, 98, 143, 239, 207, 4, 20, 2, 194, 235, 11, 0, 188, 127, 14, 11, 120,
-            124, 44, 203, 137, 195, 246, 190, 101, 204, 22, 198, 87, 8, 187, 106, 14, 75, 153, 88,
-            255, 226, 61, 28, 86, 238, 86, 131, 103, 10, 105, 219, 187, 183, 12, 16, 213, 7, 20, 2,
-            90, 98, 2, 0, 188, 127, 14, 11, 123, 32, 163, 31, 152, 123, 44, 42, 85, 181, 227, 71,
-            203, 234, 86, 141, 161, 220, 41, 108, 19, 182, 129, 238, 75, 101, 81, 8, 231, 139, 185,
-            195, 57, 128, 212, 94, 37, 0, 20, 2, 132, 215, 23, 0, 188, 127, 14, 11, 124, 161, 57,
-            137, 244, 117, 68, 171, 112, 173, 249, 177, 166, 64, 44, 241, 75, 60, 97, 249, 138,
-            207, 91, 204, 171, 175, 0, 48, 213, 55, 81, 1, 102, 162, 30, 228, 77, 22, 20, 2, 225,
-            245, 5, 0, 188, 127, 14, 11, 126, 237, 26, 106, 163, 9, 162, 26, 82, 7, 141, 194, 136,
-            15, 24, 198, 248, 21, 52, 41, 139, 101, 197, 252, 169, 95, 247, 218, 252, 178, 238,
-            122, 83, 7, 168, 218, 115, 73, 20, 2, 40, 107, 238, 0, 188, 127, 14, 11, 127, 162, 240,
-            70, 51, 203, 30, 1, 113, 42, 103, 121, 39, 138, 120, 117, 197, 13, 44, 11, 242, 132,
-            183, 18, 120, 247, 135, 250, 205, 82, 7, 74, 98, 190, 17, 162, 1, 46, 20, 2, 40, 107,
-            238, 0, 188, 127, 14, 11, 136, 116, 18, 221, 216, 200, 46, 155, 187, 44, 254, 191, 235,
-            34, 98, 44, 41, 186, 86, 205, 118, 96, 37, 244, 207, 53, 199, 115, 123, 220, 198, 118,
-            35, 120, 183, 203, 32, 123, 20, 2, 194, 235, 11, 0, 188, 127, 14, 11, 150, 25, 178, 22,
-            49, 122, 44, 42, 85, 181, 214, 15, 143, 81, 116, 93, 155, 151, 158, 167, 221, 219, 15,
-            121, 105, 45, 81, 204, 161, 76, 71, 145, 231, 45, 165, 154, 201, 99, 20, 2, 132, 215,
-            23, 0, 176, 127, 14, 11, 150, 232, 247, 225, 150, 98, 28, 251, 253, 227, 105, 198, 245,
-            139, 77, 247, 112, 204, 59, 164, 125, 101, 131, 192, 233, 96, 48, 7, 51, 135, 121, 203,
-            173, 116, 38, 118, 117, 120, 8, 4, 0, 188, 127, 14, 11, 167, 125, 226, 87, 150, 88,
-            206, 228, 213, 173, 122, 161, 149, 232, 238, 34, 47, 235, 59, 212, 178, 172, 59, 223,
-            177, 86, 166, 11, 22, 113, 56, 245, 140, 69, 152, 35, 123, 42, 20, 2, 40, 107, 238, 0,
-            188, 127, 14, 11, 169, 247, 11, 54, 153, 198, 176, 192, 40, 97, 219, 46, 103, 172, 14,
-            72, 127, 199, 101, 211, 239, 141, 48, 230, 88, 36, 247, 128, 216, 64, 107, 0, 48, 12,
-            7, 141, 159, 111, 20, 2, 132, 215, 23, 0, 188, 127, 14, 11, 171, 57, 245, 47, 221, 245,
-            142, 7, 212, 59, 25, 217, 1, 186, 223, 58, 127, 87, 21, 92, 168, 79, 47, 131, 84, 72,
-            233, 58, 20, 27, 187, 211, 62, 172, 75, 118, 125, 21, 20, 2, 14, 39, 7, 0, 188, 127,
-            14, 11, 171, 215, 0, 41, 178, 181, 6, 78, 231, 192, 239, 86, 133, 123, 234, 205, 158,
-            218, 250, 182, 174, 196, 231, 71, 2, 230, 187, 96, 146, 20, 124, 107, 39, 134, 154,
-            172, 252, 68, 20, 2, 40, 107, 238, 0, 188, 127, 14, 11, 178, 124, 176, 134, 208, 184,
-            200, 139, 194, 108, 60, 251, 55, 151, 193, 178, 226, 201, 231, 253, 3, 32, 217, 137,
-            146, 113, 186, 146, 85, 162, 64, 140, 79, 238, 179, 60, 212, 37, 20, 2, 194, 235, 11,
-            0, 188, 127, 14, 11, 182, 141, 6, 224, 136, 225, 44, 42, 85, 181, 198, 155, 94, 19, 31,
-            176, 246, 90, 199, 202, 112, 127, 75, 197, 62, 77, 153, 26, 45, 25, 113, 171, 94, 112,
-            47, 105, 244, 92, 20, 2, 132, 215, 23, 0, 188, 127, 14, 11, 187, 148, 230, 85, 168, 75,
-            154, 42, 145, 43, 167, 217, 101, 38, 124, 80, 87, 197, 94, 192, 61, 108, 47, 165, 231,
-            105, 94, 34, 95, 137, 32, 211, 130, 59, 47, 189, 219, 38, 20, 2, 180, 196, 4, 0, 188,
-            127, 14, 11, 187, 172, 104, 161, 156, 183, 160, 47, 115, 51, 226, 85, 144, 228, 245,
-            104, 174, 138, 45, 220, 147, 168, 121, 233, 46, 72, 250, 60, 241, 102, 106, 197, 110,
-            2, 12, 16, 109, 85, 20, 2, 90, 98, 2, 0, 188, 127, 14, 11, 189, 2, 30, 194, 189, 226,
-            240, 104, 80, 90, 102, 226, 114, 217, 135, 195, 119, 240, 174, 12, 10, 121, 94, 219,
-            180, 219, 64, 190, 98, 24, 114, 5, 128, 57, 32, 17, 138, 40, 20, 2, 194, 235, 11, 0,
-            188, 127, 14, 11, 197, 166, 79, 129, 224, 202, 124, 187, 14, 216, 191, 34, 137, 53, 36,
-            23, 116, 41, 7, 83, 191, 40, 32, 32, 215, 62, 69, 246, 114, 75, 1, 150, 201, 123, 59,
-            213, 52, 98, 20, 2, 194, 235, 11, 0, 188, 127, 14, 11, 219, 151, 41, 209, 127, 100, 22,
-            0, 224, 158, 29, 138, 19, 36, 147, 79, 131, 213, 93, 95, 111, 80, 62, 45, 145, 191, 66,
-            112, 238, 174, 253, 70, 47, 36, 228, 72, 126, 41, 20, 2, 208, 18, 19, 0, 188, 127, 14,
-            11, 224, 243, 119, 180, 207, 254, 124, 18, 7, 3, 14, 235, 191, 241, 170, 141, 29, 63,
-            177, 62, 244, 164, 66, 81, 248, 20, 128, 255, 98, 114, 64, 212, 90, 14, 69, 71, 198,
-            57, 20, 2, 104, 137, 9, 0, 188, 127, 14, 11, 245, 72, 83, 181, 39, 130, 18, 31, 189,
-            128, 182, 91, 91, 141, 248, 215, 50, 146, 61, 188, 215, 210, 65, 15, 243, 63, 6, 41,
-            97, 89, 59, 128, 161, 199, 212, 102, 44, 90, 20, 2, 132, 215, 23, 0, 188, 127, 14, 11,
-            251, 61, 240, 128, 83, 140, 60, 26, 170, 231, 207, 92, 100, 225, 164, 170, 50, 163,
-            177, 17, 190, 231, 36, 49, 234, 21, 130, 105, 113, 145, 195, 34, 233, 55, 224, 174, 94,
-            20, 20, 2, 132, 215, 23, 0, 176, 127, 14, 11, 255, 66, 158, 55, 200, 47, 110, 183, 118,
-            49, 246, 94, 160, 75, 62, 241, 64, 123, 4, 221, 89, 165, 14, 246, 175, 36, 158, 186,
-            93, 130, 205, 97, 96, 64, 118, 171, 6, 122, 8, 4, 0, 188, 127, 14, 12, 6, 137, 122,
-            109, 252, 27, 140, 238, 50, 77, 74, 15, 43, 206, 96, 10, 104, 26, 59, 162, 244, 207,
-            80, 124, 131, 1, 32, 8, 240, 21, 87, 242, 197, 228, 19, 183, 96, 102, 20, 2, 194, 235,
-            11, 0, 188, 127, 14, 12, 12, 63, 254, 75, 89, 19, 106, 243, 88, 229, 101, 11, 97, 148,
-            59, 167, 9, 239, 197, 219, 197, 1, 64, 94, 4, 213, 222, 87, 152, 8, 125, 108, 114, 112,
-            39, 81, 26, 101, 20, 2, 14, 39, 7, 0, 188, 127, 14, 12, 23, 180, 3, 103, 183, 106, 206,
-            50, 104, 212, 21, 241, 54, 218, 181, 117, 210, 93, 192, 177, 147, 43, 115, 108, 124,
-            199, 133, 161, 204, 192, 79, 4, 116, 180, 88, 17, 95, 12, 20, 2, 208, 18, 19, 0, 188,
-            127, 14, 12, 24, 236, 40, 11, 146, 232, 150, 22, 129, 32, 99, 237, 180, 96, 36, 19,
-            134, 140, 123, 133, 150, 85, 1, 99, 66, 175, 125, 106, 100, 147, 30, 69, 32, 174, 246,
-            90, 70, 39, 20, 2, 40, 107, 238, 0, 188, 127, 14, 12, 25, 198, 19, 238, 175, 230, 176,
-            236, 53, 202, 202, 26, 171, 86, 223, 120, 20, 184, 14, 160, 88, 94, 174, 121, 236, 111,
-            88, 207, 3, 168, 190, 12, 64, 165, 198, 112, 119, 17, 20, 2, 14, 39, 7, 0, 188, 127,
-            14, 12, 28, 38, 84, 68, 200, 168, 44, 42, 85, 181, 152, 113, 173, 34, 55, 172, 20, 125,
-            25, 183, 235, 187, 218, 57, 149, 123, 125, 88, 129, 223, 14, 92, 132, 147, 146, 85,
-            103, 118, 20, 2, 132, 215, 23, 0, 188, 127, 14, 12, 28, 67, 129, 101, 157, 219, 102,
-            28, 52, 17, 18, 99, 133, 134, 106, 108, 114, 165, 163, 57, 222, 26, 135, 6, 99, 85, 90,
-            22, 254, 30, 197, 82, 68, 1, 17, 27, 66, 31, 20, 2, 40, 107, 238, 0, 188, 127, 14, 12,
-            28, 167, 184, 49, 54, 79, 152, 133, 68, 217, 4, 182, 159, 101, 76, 148, 169, 24, 222,
-            32, 242, 54, 208, 4, 239, 99, 222, 119, 43, 15, 43, 145, 214, 170, 91, 135, 30, 8, 20,
-            2, 40, 107, 238, 0, 188, 127, 14, 12, 31, 106, 121, 214, 151, 67, 54, 13, 82, 9, 11,
-            187, 211, 43, 89, 139, 235, 128, 25, 124, 149, 193, 0, 69, 5, 19, 95, 4, 147, 176, 137,
-            51, 74, 82, 227, 161, 129, 64, 20, 2, 16, 94, 95, 0, 188, 127, 14, 12, 35, 195, 42,
-            191, 7, 202, 162, 250, 254, 174, 100, 30, 110, 38, 77, 119, 114, 60, 0, 171, 5, 245, 3,
-            219, 72, 202, 53, 151, 203, 50, 66, 194, 181, 77, 144, 171, 208, 29, 20, 2, 208, 18,
-            19, 0, 188, 127, 14, 12, 39, 219, 114, 230, 120, 88, 44, 42, 85, 181, 13, 60, 75, 139,
-            96, 125, 20, 195, 18, 214, 226, 95, 190, 10, 154, 88, 91, 175, 214, 143, 89, 122, 211,
-            121, 137, 249, 196, 6, 20, 2, 132, 215, 23, 0, 188, 127, 14, 12, 46, 192, 124, 247,
-            238, 233, 224, 35, 116, 189, 250, 146, 217, 150, 156, 60, 111, 140, 49, 59, 237, 46,
-            129, 101, 4, 13, 62, 246, 4, 0, 27, 161, 217, 200, 56, 233, 220, 93, 20, 2, 14, 39, 7,
-            0, 188, 127, 14, 12, 60, 190, 178, 44, 237, 129, 96, 151, 19, 148, 222, 188, 95, 54,
-            175, 124, 233, 204, 147, 163, 245, 207, 220, 64, 12, 131, 11, 138, 133, 16, 209, 5,
-            222, 67, 100, 231, 203, 15, 20, 2, 180, 196, 4, 0, 188, 127, 14, 12, 72, 198, 233, 207,
-            103, 251, 76, 242, 231, 116, 227, 76, 54, 3, 178, 66, 138, 105, 12, 5, 141, 42, 184,
-            38, 179, 154, 46, 186, 78, 34, 179, 170, 232, 95, 155, 250, 120, 2, 20, 2, 90, 98, 2,
-            0, 188, 127, 14, 12, 78, 240, 136, 27, 63, 96, 26, 126, 183, 190, 144, 166, 14, 163,
-            196, 180, 103, 223, 47, 143, 216, 146, 136, 239, 68, 245, 129, 66, 107, 217, 140, 195,
-            74, 11, 103, 187, 149, 159, 20, 2, 194, 235, 11, 0, 188, 127, 14, 12, 95, 165, 9, 90,
-            70, 60, 250, 159, 180, 236, 202, 180, 145, 148, 23, 226, 166, 7, 44, 119, 229, 91, 125,
-            27, 105, 169, 104, 38, 41, 160, 153, 83, 206, 105, 146, 96, 181, 100, 20, 2, 132, 215,
-            23, 0, 188, 127, 14, 12, 99, 125, 213, 129, 119, 54, 168, 100, 190, 50, 5, 94, 152,
-            194, 179, 33, 106, 70, 62, 79, 58, 141, 52, 95, 212, 111, 17, 32, 87, 13, 127, 89, 39,
-            108, 70, 75, 119, 195, 20, 2, 40, 107, 238, 0, 188, 127, 14, 12, 104, 253, 141, 47,
-            158, 120, 50, 158, 191, 143, 106, 218, 254, 113, 224, 239, 203, 252, 15, 87, 211, 138,
-            187, 243, 178, 2, 191, 98, 64, 97, 246, 41, 254, 195, 235, 3, 204, 105, 20, 2, 40, 107,
-            238, 0, 188, 127, 14, 12, 109, 164, 68, 43, 144, 53, 50, 150, 201, 179, 166, 84, 109,
-            35, 25, 167, 100, 224, 14, 17, 38, 33, 91, 119, 108, 178, 117, 113, 219, 46, 240, 57,
-            43, 191, 188, 102, 212, 95, 20, 2, 194, 235, 11, 0, 188, 127, 14, 12, 122, 253, 235,
-            136, 252, 157, 10, 160, 65, 191, 98, 213, 181, 42, 222, 224, 126, 124, 188, 6, 135,
-            172, 232, 110, 119, 48, 138, 152, 44, 7, 219, 135, 30, 242, 218, 182, 57, 1, 20, 2, 40,
-            107, 238, 0, 188, 127, 14, 12, 133, 33, 214, 214, 148, 31, 217, 186, 120, 18, 196, 107,
-            230, 14, 134, 225, 220, 231, 204, 35, 114, 29, 76, 220, 174, 178, 91, 216, 167, 150,
-            10, 95, 188, 72, 214, 140, 65, 8, 20, 2, 40, 107, 238, 0, 188, 127, 14, 12, 141, 248,
-            74, 100, 40, 193, 191, 16, 208, 130, 254, 233, 24, 84, 247, 166, 166, 197, 110, 191,
-            139, 240, 214, 91, 249, 20, 166, 131, 206, 74, 225, 219, 92, 206, 206, 6, 173, 39, 20,
-            2, 194, 235, 11, 0, 188, 127, 14, 12, 167, 21, 92, 38, 198, 4, 40, 42, 25, 64, 144,
-            253, 103, 21, 224, 100, 48, 216, 166, 233, 198, 130, 240, 33, 234, 243, 152, 131, 11,
-            16, 219, 148, 202, 140, 39, 201, 174, 76, 20, 2, 90, 98, 2, 0, 188, 127, 14, 12, 173,
-            107, 220, 165, 140, 16, 102, 121, 142, 90, 176, 60, 56, 176, 164, 173, 236, 99, 170,
-            57, 33, 14, 133, 42, 238, 173, 106, 244, 216, 168, 45, 202, 20, 210, 99, 18, 235, 9,
-            20, 2, 16, 94, 95, 0, 176, 127, 14, 12, 174, 109, 233, 34, 121, 24, 16, 45, 246, 133,
-            196, 101, 159, 156, 36, 47, 249, 172, 138, 78, 229, 48, 87, 112, 237, 225, 6, 219, 122,
-            60, 213, 211, 232, 130, 62, 51, 208, 1, 8, 4, 0, 176, 127, 14, 12, 183, 82, 111, 250,
-            55, 86, 116, 58, 80, 76, 208, 83, 196, 187, 75, 112, 194, 156, 213, 155, 243, 140, 201,
-            45, 31, 81, 120, 73, 105, 192, 9, 77, 1, 5, 37, 181, 65, 69, 8, 4, 0, 188, 127, 14, 12,
-            195, 87, 248, 86, 121, 83, 244, 251, 104, 100, 13, 240, 58, 238, 202, 161, 157, 110,
-            239, 221, 177, 21, 22, 196, 43, 88, 109, 10, 192, 229, 111, 53, 75, 67, 183, 15, 188,
-            34, 20, 2, 90, 98, 2, 0, 188, 127, 14, 12, 200, 80, 235, 32, 182, 187, 130, 167, 42,
-            195, 150, 13, 134, 198, 24, 106, 197, 120, 131, 47, 15, 245, 186, 206, 213, 18, 225,
-            252, 151, 133, 57, 91, 213, 222, 88, 106, 80, 77, 20, 2, 40, 107, 238, 0, 188, 127, 14,
-            12, 210, 141, 207, 153, 192, 125, 172, 19, 62, 191, 222, 68, 22, 114, 192, 85, 183,
-            156, 169, 166, 5, 152, 80, 152, 78, 234, 209, 174, 3, 111, 72, 202, 18, 48, 231, 240,
-            85, 111, 20, 2, 90, 98, 2, 0, 188, 127, 14, 12, 220, 188, 215, 157, 224, 97, 40, 129,
-            151, 254, 29, 159, 107, 18, 238, 127, 150, 86, 102, 55, 145, 54, 111, 213, 234, 78,
-            192, 25, 153, 215, 167, 248, 184, 78, 34, 160, 89, 27, 20, 2, 40, 107, 238, 0, 188,
-            127, 14, 12, 229, 212, 5, 144, 6, 88, 40, 254, 194, 85, 127, 111, 145, 155, 223, 132,
-            163, 177, 4, 223, 193, 99, 205, 191, 186, 44, 227, 33, 129, 246, 7, 165, 192, 233, 50,
-            227, 180, 1, 20, 2, 40, 107, 238, 0, 188, 127, 14, 12, 236, 122, 248, 44, 109, 34, 210,
-            47, 75, 63, 122, 159, 8, 120, 164, 153, 84, 231, 180, 73, 28, 168, 65, 246, 136, 49,
-            194, 232, 174, 179, 131, 205, 121, 220, 220, 0, 41, 91, 20, 2, 40, 107, 238, 0, 188,
-            127, 14, 12, 238, 220, 255, 30, 234, 39, 58, 90, 126, 26, 85, 145, 104, 23, 162, 59, 8,
-            67, 59, 255, 133, 186, 252, 40, 133, 95, 190, 103, 248, 210, 149, 43, 107, 91, 40, 22,
-            64, 48, 20, 2, 40, 107, 238, 0, 188, 127, 14, 12, 238, 245, 34, 200, 248, 8, 136, 24,
-            241, 178, 137, 223, 136, 135, 111, 1, 153, 170, 31, 215, 35, 219, 236, 110, 123, 188,
-            91, 8, 229, 234, 187, 137, 237, 235, 251, 227, 152, 58, 20, 2, 90, 98, 2, 0, 176, 127,
-            14, 12, 240, 36, 147, 117, 136, 22, 164, 205, 77, 209, 81, 240, 16, 109, 129, 87, 189,
-            240, 43, 250, 199, 95, 154, 190, 142, 99, 94, 204, 100, 152, 184, 168, 246, 172, 193,
-            245, 230, 116, 8, 4, 0, 188, 127, 14, 12, 240, 228, 226, 25, 121, 201, 226, 233, 50,
-            13, 8, 38, 204, 102, 32, 81, 202, 224, 57, 188, 11, 253, 98, 159, 229, 91, 255, 106,
-            77, 34, 7, 240, 107, 125, 90, 202, 79, 129, 20, 2, 40, 107, 238, 0, 188, 127, 14, 12,
-            243, 151, 180, 8, 201, 132, 44, 42, 85, 181, 177, 218, 104, 253, 44, 165, 109, 131,
-            186, 253, 190, 232, 237, 67, 103, 9, 84, 32, 49, 230, 37, 114, 61, 76, 176, 160, 27,
-            49, 20, 2, 132, 215, 23, 0, 188, 127, 14, 12, 246, 168, 29, 76, 14, 224, 104, 242, 104,
-            41, 236, 71, 15, 218, 210, 230, 61, 151, 116, 26, 136, 223, 18, 67, 234, 20, 123, 251,
-            70, 57, 185, 122, 76, 129, 111, 150, 5, 253, 20, 2, 90, 98, 2, 0, 188, 127, 14, 12,
-            247, 2, 180, 125, 56, 4, 222, 6, 67, 134, 163, 81, 43, 186, 6, 126, 246, 189, 9, 158,
-            127, 58, 62, 104, 254, 96, 116, 168, 193, 171, 135, 45, 221, 123, 235, 140, 144, 2, 20,
-            2, 194, 235, 11, 0, 188, 127, 14, 13, 1, 12, 83, 146, 22, 161, 184, 254, 204, 114, 251,
-            2, 75, 97, 2, 197, 58, 57, 36, 232, 183, 226, 193, 99, 207, 230, 229, 184, 66, 153,
-            196, 72, 30, 250, 5, 128, 105, 76, 20, 2, 194, 235, 11, 0, 188, 127, 14, 13, 15, 19,
-            229, 211, 140, 212, 42, 129, 56, 52, 217, 83, 45, 4, 125, 204, 28, 135, 163, 171, 222,
-            18, 114, 74, 191, 168, 182, 129, 237, 175, 137, 213, 157, 196, 214, 157, 62, 96, 20, 2,
-            202, 154, 59, 0, 188, 127, 14, 13, 16, 154, 230, 62, 174, 102, 154, 27, 85, 207, 247,
-            17, 125, 255, 88, 43, 78, 120, 246, 16, 180, 126, 118, 7, 232, 182, 225, 19, 219, 29,
-            233, 68, 192, 23, 19, 249, 28, 59, 20, 2, 194, 235, 11, 0, 188, 127, 14, 13, 26, 106,
-            203, 38, 58, 143, 60, 31, 125, 19, 117, 17, 86, 15, 52, 189, 23, 255, 142, 158, 72,
-            169, 131, 31, 36, 247, 21, 18, 206, 150, 195, 146, 103, 232, 35, 75, 94, 38, 20, 2, 40,
-            107, 238, 0, 188, 127, 14, 13, 26, 159, 255, 213, 156, 132, 41, 168, 67, 206, 90, 103,
-            194, 210, 133, 204, 95, 48, 45, 177, 115, 238, 69, 99, 231, 175, 56, 61, 252, 107, 154,
-            143, 166, 78, 20, 142, 253, 18, 20, 2, 40, 107, 238, 0, 188, 127, 14, 13, 28, 177, 199,
-            131, 60, 217, 4, 154, 150, 135, 194, 43, 241, 156, 65, 156, 252, 199, 154, 119, 182,
-            11, 7, 250, 163, 223, 32, 52, 215, 207, 164, 99, 83, 80, 87, 28, 189, 50, 20, 2, 225,
-            245, 5, 0, 188, 127, 14, 13, 32, 10, 55, 79, 19, 50, 56, 162, 149, 85, 157, 137, 119,
-            70, 79, 216, 205, 209, 51, 248, 128, 95, 35, 136, 228, 42, 110, 0, 146, 25, 36, 112,
-            72, 162, 125, 154, 192, 107, 20, 2, 208, 18, 19, 0, 188, 127, 14, 13, 33, 114, 59, 77,
-            131, 197, 141, 107, 222, 109, 203, 192, 11, 88, 138, 14, 203, 113, 244, 197, 22, 217,
-            237, 227, 17, 211, 252, 249, 66, 248, 146, 40, 34, 27, 146, 162, 193, 163, 20, 2, 40,
-            107, 238, 0, 188, 127, 14, 13, 36, 10, 48, 0, 202, 14, 44, 42, 85, 181, 98, 158, 105,
-            207, 156, 8, 180, 185, 38, 231, 232, 80, 60, 244, 211, 55, 252, 98, 103, 127, 64, 64,
-            154, 104, 254, 155, 154, 26, 20, 2, 132, 215, 23, 0, 188, 127, 14, 13, 48, 213, 251,
-            242, 73, 126, 212, 129, 96, 162, 238, 143, 217, 67, 43, 227, 251, 54, 25, 228, 179, 9,
-            218, 135, 48, 91, 131, 242, 159, 135, 206, 57, 24, 70, 1, 64, 237, 9, 20, 2, 40, 107,
-            238, 0, 188, 127, 14, 13, 55, 70, 183, 26, 12, 79, 32, 124, 88, 65, 204, 213, 101, 239,
-            240, 248, 225, 94, 213, 211, 177, 117, 39, 73, 226, 252, 158, 63, 87, 76, 132, 52, 189,
-            9, 215, 248, 248, 21, 20, 2, 208, 18, 19, 0, 188, 127, 14, 13, 67, 163, 142, 103, 155,
-            72, 232, 56, 38, 128, 230, 114, 184, 64, 60, 87, 242, 189, 16, 115, 195, 66, 25, 251,
-            212, 1, 96, 232, 144, 127, 244, 203, 197, 72, 151, 109, 38, 63, 20, 2, 132, 215, 23, 0,
-            188, 127, 14, 13, 81, 13, 217, 42, 52, 51, 58, 15, 60, 112, 132, 96, 185, 110, 77, 124,
-            216, 116, 112, 190, 187, 182, 210, 133, 63, 214, 236, 138, 83, 120, 125, 222, 224, 97,
-            149, 150, 102, 121, 20, 2, 40, 107, 238, 0, 188, 127, 14, 13, 84, 49, 113, 125, 75,
-            233, 72, 147, 29, 64, 164, 147, 102, 89, 165, 176, 62, 65, 157, 184, 211, 223, 200,
-            255, 250, 169, 115, 122, 213, 119, 22, 81, 85, 44, 200, 64, 33, 10, 20, 2, 40, 107,
-            238, 0, 188, 127, 14, 13, 90, 14, 87, 184, 53, 234, 72, 225, 129, 187, 105, 3, 54, 120,
-            44, 253, 11, 66, 47, 174, 192, 165, 97, 181, 179, 90, 108, 34, 119, 248, 7, 49, 39,
-            234, 140, 246, 123, 53, 20, 2, 40, 107, 238, 0, 188, 127, 14, 13, 94, 162, 225, 226,
-            93, 161, 42, 178, 239, 196, 94, 145, 204, 183, 184, 225, 22, 56, 141, 18, 221, 59, 227,
-            165, 125, 101, 191, 140, 14, 110, 159, 112, 253, 23, 104, 93, 143, 20, 20, 2, 90, 98,
-            2, 0, 188, 127, 14, 13, 98, 96, 219, 231, 10, 250, 44, 42, 85, 181, 234, 191, 153, 112,
-            140, 42, 159, 30, 33, 163, 190, 208, 250, 88, 155, 24, 40, 98, 37, 206, 177, 188, 158,
-            40, 255, 6, 160, 79, 20, 2, 132, 215, 23, 0, 188, 127, 14, 13, 113, 50, 69, 160, 122,
-            205, 34, 247, 119, 221, 12, 113, 12, 133, 195, 196, 165, 37, 168, 91, 164, 16, 198, 64,
-            179, 32, 70, 225, 240, 181, 179, 165, 132, 175, 186, 48, 0, 17, 20, 2, 40, 107, 238, 0,
-            188, 127, 14, 13, 116, 170, 157, 203, 160, 62, 14, 218, 160, 208, 142, 139, 33, 214,
-            232, 249, 70, 239, 243, 129, 173, 75, 226, 154, 166, 53, 105, 165, 74, 106, 117, 201,
-            27, 135, 139, 70, 48, 51, 20, 2, 194, 235, 11, 0, 188, 127, 14, 13, 128, 112, 188, 244,
-            124, 62, 44, 42, 85, 181, 81, 105, 180, 139, 164, 142, 153, 218, 187, 97, 128, 116, 99,
-            193, 249, 27, 206, 46, 201, 145, 156, 22, 240, 253, 243, 97, 97, 102, 20, 2, 132, 215,
-            23, 0, 188, 127, 14, 13, 132, 149, 195, 36, 23, 213, 44, 42, 85, 181, 159, 38, 217,
-            136, 128, 251, 96, 76, 221, 120, 72, 228, 193, 87, 106, 153, 11, 11, 168, 70, 141, 212,
-            34, 42, 162, 127, 158, 34, 20, 2, 132, 215, 23, 0, 188, 127, 14, 13, 137, 232, 140, 25,
-            1, 168, 0, 95, 167, 54, 55, 6, 43, 227, 251, 251, 151, 33, 116, 165, 188, 133, 162,
-            246, 204, 3, 80, 203, 132, 170, 157, 101, 116, 34, 121, 107, 253, 241, 20, 2, 72, 232,
-            1, 0, 188, 127, 14, 13, 144, 158, 177, 118, 179, 143, 13, 146, 162, 82, 77, 80, 29,
-            172, 203, 136, 216, 103, 32, 7, 151, 49, 249, 181, 48, 56, 224, 174, 221, 239, 184,
-            130, 138, 251, 175, 165, 234, 207, 20, 2, 194, 235, 11, 0, 188, 127, 14, 13, 157, 91,
-            27, 122, 214, 25, 44, 42, 85, 181, 7, 155, 13, 121, 138, 68, 45, 135, 228, 191, 102,
-            74, 1, 213, 12, 17, 175, 156, 162, 48, 238, 71, 56, 177, 154, 169, 91, 109, 20, 2, 132,
-            215, 23, 0, 188, 127, 14, 13, 164, 197, 130, 69, 214, 248, 162, 109, 116, 163, 3, 148,
-            194, 54, 73, 20, 207, 240, 7, 83, 40, 252, 167, 5, 35, 101, 55, 199, 188, 11, 85, 97,
-            131, 43, 161, 238, 139, 97, 20, 2, 40, 107, 238, 0, 188, 127, 14, 13, 165, 35, 182, 31,
-            24, 94, 212, 69, 51, 164, 210, 31, 217, 214, 245, 213, 124, 140, 208, 92, 97, 166, 242,
-            63, 145, 49, 206, 200, 174, 56, 107, 107, 67, 125, 179, 153, 236, 61, 20, 2, 225, 245,
-            5, 0, 188, 127, 14, 13, 165, 41, 101, 91, 0, 192, 14, 195, 247, 41, 31, 130, 51, 86, 6,
-            249, 142, 22, 245, 72, 11, 56, 179, 218, 149, 210, 231, 238, 148, 137, 168, 154, 138,
-            57, 249, 218, 201, 86, 20, 2, 208, 18, 19, 0, 188, 127, 14, 13, 168, 17, 67, 51, 174,
-            123, 138, 228, 55, 204, 36, 32, 198, 23, 242, 205, 236, 5, 64, 93, 182, 196, 73, 186,
-            218, 125, 43, 32, 99, 234, 222, 174, 99, 106, 37, 197, 202, 121, 20, 2, 194, 235, 11,
-            0, 176, 127, 14, 13, 169, 134, 80, 145, 117, 75, 107, 114, 115, 29, 225, 180, 206, 136,
-            212, 150, 95, 189, 100, 231, 251, 225, 123, 146, 12, 111, 184, 192, 33, 100, 210, 211,
-            81, 212, 54, 218, 167, 206, 8, 4, 0, 188, 127, 14, 13, 175, 43, 238, 128, 134, 91, 212,
-            156, 216, 100, 93, 18, 167, 144, 91, 231, 70, 100, 152, 49, 91, 208, 28, 30, 185, 69,
-            158, 233, 78, 35, 82, 57, 190, 25, 201, 204, 10, 68, 20, 2, 40, 107, 238, 0, 188, 127,
-            14, 13, 184, 186, 230, 139, 184, 53, 252, 111, 131, 128, 100, 107, 250, 25, 244, 220,
-            124, 30, 214, 235, 251, 10, 147, 245, 120, 23, 147, 174, 249, 34, 74, 5, 232, 5, 66,
-            109, 21, 28, 20, 2, 40, 107, 238, 0, 188, 127, 14, 13, 200, 228, 79, 206, 36, 90, 28,
-            121, 165, 173, 162, 255, 13, 85, 170, 166, 93, 254, 175, 12, 186, 102, 123, 171, 243,
-            18, 249, 191, 16, 4, 68, 39, 155, 52, 205, 118, 158, 73, 20, 2, 40, 107, 238, 0, 188,
-            127, 14, 13, 201, 165, 5, 146, 226, 19, 227, 63, 69, 32, 183, 149, 76, 181, 254, 232,
-            119, 139, 68, 40, 61, 235, 9, 72, 194, 60, 107, 67, 32, 88, 178, 30, 232, 78, 146, 8,
-            95, 61, 20, 2, 194, 235, 11, 0, 188, 127, 14, 13, 213, 24, 229, 30, 81, 33, 84, 72, 3,
-            246, 123, 141, 157, 149, 95, 98, 205, 120, 138, 235, 178, 166, 131, 68, 67, 98, 124,
-            111, 40, 165, 219, 150, 3, 162, 147, 128, 73, 82, 20, 2, 40, 107, 238, 0, 176, 127, 14,
-            13, 214, 36, 138, 138, 255, 99, 122, 105, 228, 128, 202, 30, 171, 200, 121, 129, 196,
-            95, 49, 134, 155, 127, 20, 26, 254, 251, 43, 118, 206, 150, 178, 44, 114, 233, 24, 86,
-            255, 6, 8, 4, 0, 188, 127, 14, 13, 226, 248, 192, 26, 208, 121, 94, 93, 115, 59, 29,
-            70, 12, 8, 54, 194, 240, 16, 68, 83, 126, 104, 70, 230, 32, 9, 35, 194, 167, 225, 228,
-            203, 234, 77, 255, 68, 54, 71, 20, 2, 40, 107, 238, 0, 188, 127, 14, 13, 227, 84, 130,
-            214, 2, 239, 138, 50, 245, 151, 19, 240, 161, 41, 251, 195, 149, 219, 200, 83, 245, 26,
-            181, 61, 69, 209, 104, 76, 75, 200, 221, 173, 137, 253, 85, 252, 9, 111, 20, 2, 90, 98,
-            2, 0, 188, 127, 14, 13, 227, 167, 3, 11, 246, 197, 44, 42, 85, 181, 20, 234, 49, 130,
-            2, 246, 185, 32, 7, 61, 135, 140, 44, 156, 148, 251, 56, 164, 142, 76, 123, 61, 72,
-            116, 247, 85, 192, 110, 20, 2, 132, 215, 23, 0, 188, 127, 14, 13, 255, 140, 247, 59,
-            94, 7, 86, 220, 150, 94, 136, 102, 157, 206, 248, 97, 226, 2, 120, 152, 29, 14, 210,
-            145, 206, 163, 249, 124, 14, 46, 94, 126, 153, 125, 158, 83, 194, 42, 20, 2, 208, 18,
-            19, 0, 188, 127, 14, 13, 255, 163, 5, 233, 194, 98, 14, 110, 247, 28, 123, 19, 143,
-            156, 63, 62, 47, 192, 105, 74, 74, 180, 133, 62, 218, 248, 64, 167, 142, 174, 214, 218,
-            88, 149, 135, 35, 51, 110, 20, 2, 40, 107, 238, 0, 188, 127, 14, 14, 6, 119, 222, 117,
-            38, 98, 238, 188, 120, 135, 114, 14, 193, 206, 139, 117, 150, 41, 203, 66, 93, 245,
-            241, 80, 17, 240, 212, 85, 191, 225, 162, 42, 212, 207, 179, 109, 26, 59, 20, 2, 40,
-            107, 238, 0, 188, 127, 14, 14, 14, 29, 209, 236, 138, 46, 196, 38, 27, 238, 185, 101,
-            122, 217, 173, 240, 39, 124, 252, 113, 4, 172, 233, 27, 218, 65, 162, 210, 167, 126,
-            181, 92, 32, 190, 131, 84, 163, 83, 20, 2, 40, 107, 238, 0, 188, 127, 14, 14, 16, 37,
-            212, 253, 172, 24, 44, 42, 85, 181, 27, 170, 148, 255, 45, 196, 254, 174, 40, 197, 236,
-            204, 183, 116, 198, 34, 84, 91, 10, 18, 125, 189, 90, 232, 226, 200, 225, 21, 20, 2,
-            132, 215, 23, 0, 188, 127, 14, 14, 20, 143, 37, 118, 129, 39, 44, 42, 85, 181, 115,
-            220, 254, 53, 175, 235, 123, 255, 179, 29, 168, 62, 157, 249, 206, 135, 202, 14, 210,
-            13, 151, 81, 178, 232, 35, 150, 51, 66, 20, 2, 132, 215, 23, 0, 188, 127, 14, 14, 24,
-            192, 127, 95, 186, 59, 102, 45, 247, 252, 121, 152, 100, 190, 140, 153, 209, 23, 35,
-            180, 235, 219, 72, 55, 171, 50, 135, 164, 102, 139, 27, 214, 216, 63, 144, 76, 251,
-            127, 20, 2, 90, 98, 2, 0, 188, 127, 14, 14, 25, 66, 35, 72, 126, 106, 214, 12, 251,
-            237, 104, 10, 90, 251, 242, 136, 142, 161, 137, 17, 165, 209, 10, 187, 199, 57, 143,
-            25, 212, 80, 124, 206, 213, 140, 143, 192, 213, 117, 20, 2, 194, 235, 11, 0, 188, 127,
-            14, 14, 26, 52, 194, 105, 205, 235, 44, 42, 85, 181, 222, 18, 6, 109, 74, 229, 132, 1,
-            108, 109, 85, 109, 54, 247, 73, 132, 220, 47, 179, 59, 55, 46, 86, 142, 64, 76, 9, 103,
-            20, 2, 132, 215, 23, 0, 188, 127, 14, 14, 27, 34, 164, 77, 25, 53, 44, 42, 85, 181,
-            224, 210, 140, 199, 114, 180, 123, 185, 178, 89, 129, 203, 182, 158, 202, 115, 247,
-            195, 56, 143, 182, 70, 78, 125, 36, 190, 71, 14, 20, 2, 132, 215, 23, 0, 188, 127, 14,
-            14, 27, 115, 118, 67, 20, 244, 88, 205, 6, 248, 55, 24, 107, 167, 226, 175, 232, 103,
-            160, 11, 151, 243, 195, 227, 84, 72, 11, 254, 13, 220, 165, 9, 210, 18, 6, 69, 38, 116,
-            20, 2, 40, 107, 238, 0, 188, 127, 14, 14, 29, 18, 127, 151, 139, 105, 101, 74, 204, 63,
-            0, 44, 237, 92, 141, 31, 188, 230, 38, 132, 133, 254, 10, 43, 227, 214, 36, 211, 43,
-            110, 83, 19, 45, 175, 143, 40, 163, 128, 20, 2, 194, 235, 11, 0, 188, 127, 14, 14, 44,
-            66, 41, 133, 74, 117, 140, 203, 51, 247, 127, 191, 0, 154, 215, 142, 94, 125, 43, 54,
-            187, 245, 53, 91, 156, 76, 238, 226, 45, 117, 177, 50, 60, 12, 25, 110, 28, 57, 20, 2,
-            40, 107, 238, 0, 188, 127, 14, 14, 46, 237, 168, 201, 128, 114, 44, 42, 85, 181, 156,
-            57, 153, 244, 65, 165, 150, 179, 78, 65, 240, 213, 90, 136, 192, 249, 251, 122, 244,
-            199, 98, 65, 166, 245, 211, 163, 229, 20, 20, 2, 132, 215, 23, 0, 176, 127, 14, 14, 50,
-            14, 226, 85, 158, 234, 200, 34, 155, 222, 83, 149, 146, 160, 233, 83, 66, 79, 97, 243,
-            98, 210, 226, 117, 238, 197, 28, 111, 136, 250, 205, 27, 140, 188, 146, 95, 4, 90, 8,
-            4, 0, 188, 127, 14, 14, 52, 181, 12, 25, 250, 161, 70, 119, 158, 71, 84, 88, 11, 78,
-            148, 172, 34, 229, 218, 73, 154, 163, 156, 241, 174, 206, 53, 213, 22, 45, 177, 80, 3,
-            177, 65, 16, 243, 27, 20, 2, 180, 196, 4, 0, 188, 127, 14, 14, 56, 78, 92, 91, 205,
-            166, 90, 196, 187, 232, 64, 227, 50, 218, 38, 86, 192, 231, 96, 144, 64, 3, 250, 122,
-            33, 35, 162, 22, 163, 62, 129, 184, 83, 20, 0, 96, 3, 4, 20, 2, 194, 235, 11, 0, 188,
-            127, 14, 14, 59, 228, 131, 97, 226, 212, 176, 201, 38, 110, 191, 9, 88, 208, 212, 51,
-            249, 208, 104, 100, 207, 110, 90, 79, 97, 90, 185, 120, 219, 25, 110, 106, 148, 19,
-            236, 45, 228, 72, 20, 2, 40, 107, 238, 0, 188, 127, 14, 14, 80, 133, 173, 104, 148,
-            175, 38, 235, 242, 157, 126, 58, 229, 75, 163, 128, 161, 38, 42, 250, 126, 51, 251,
-            137, 191, 186, 16, 251, 105, 49, 11, 86, 90, 61, 94, 207, 204, 95, 20, 2, 40, 107, 238,
-            0, 188, 127, 14, 14, 87, 101, 252, 184, 23, 112, 44, 42, 85, 181, 63, 115, 180, 210,
-            108, 230, 198, 43, 0, 130, 25, 69, 96, 228, 161, 134, 243, 73, 30, 131, 71, 33, 140,
-            119, 93, 202, 72, 48, 20, 2, 132, 215, 23, 0, 188, 127, 14, 14, 90, 191, 187, 172, 79,
-            161, 116, 115, 152, 213, 114, 38, 113, 159, 129, 248, 113, 184, 164, 60, 167, 229, 242,
-            124, 225, 155, 83, 91, 151, 41, 238, 162, 24, 181, 101, 239, 153, 66, 20, 2, 40, 107,
-            238, 0, 188, 127, 14, 14, 94, 81, 27, 204, 31, 244, 30, 20, 73, 221, 204, 198, 59, 70,
-            82, 205, 209, 38, 187, 173, 92, 80, 184, 63, 241, 195, 89, 119, 233, 252, 157, 113,
-            118, 156, 16, 85, 123, 51, 20, 2, 148, 53, 119, 0, 188, 127, 14, 14, 94, 102, 251, 34,
-            77, 24, 130, 67, 41, 39, 13, 201, 15, 22, 73, 187, 37, 103, 204, 240, 241, 107, 233,
-            199, 148, 224, 220, 245, 197, 96, 63, 33, 31, 210, 32, 45, 50, 29, 20, 2, 148, 53, 119,
-            0, 188, 127, 14, 14, 101, 125, 132, 148, 20, 214, 44, 42, 85, 185, 2, 7, 50, 206, 183,
-            219, 62, 211, 173, 53, 162, 173, 163, 26, 101, 187, 224, 172, 148, 238, 7, 45, 146,
-            205, 39, 163, 143, 123, 20, 2, 132, 215, 23, 0, 188, 127, 14, 14, 114, 68, 243, 116,
-            204, 158, 44, 42, 85, 182, 9, 76, 245, 87, 188, 133, 250, 64, 25, 81, 180, 212, 132,
-            65, 70, 33, 31, 132, 94, 28, 33, 38, 64, 104, 93, 66, 5, 123, 20, 2, 132, 215, 23, 0,
-            188, 127, 14, 14, 120, 149, 165, 221, 186, 174, 104, 127, 62, 201, 140, 45, 99, 255,
-            217, 81, 155, 94, 130, 162, 214, 209, 60, 139, 73, 103, 206, 118, 37, 9, 29, 112, 63,
-            237, 191, 173, 187, 110, 20, 2, 40, 107, 238, 0, 188, 127, 14, 14, 132, 136, 74, 236,
-            237, 13, 207, 28, 164, 70, 199, 232, 24, 40, 76, 218, 135, 161, 204, 120, 113, 123, 87,
-            69, 36, 142, 117, 142, 250, 214, 52, 207, 233, 186, 147, 183, 27, 227, 20, 2, 208, 18,
-            19, 0, 188, 127, 14, 14, 132, 140, 32, 37, 146, 116, 8, 28, 84, 102, 87, 79, 147, 46,
-            245, 225, 70, 158, 152, 77, 93, 57, 173, 89, 70, 70, 143, 10, 185, 208, 108, 69, 79,
-            116, 207, 194, 241, 108, 20, 2, 194, 235, 11, 0, 188, 127, 14, 14, 138, 65, 69, 63,
-            158, 88, 88, 123, 25, 204, 98, 224, 28, 220, 24, 161, 73, 156, 218, 39, 176, 251, 51,
-            38, 77, 12, 56, 23, 102, 134, 9, 187, 88, 247, 98, 1, 38, 152, 20, 2, 194, 235, 11, 0,
-            188, 127, 14, 14, 144, 226, 162, 176, 232, 218, 102, 219, 196, 238, 84, 68, 242, 224,
-            156, 14, 187, 116, 92, 35, 10, 141, 197, 22, 48, 42, 141, 40, 164, 44, 253, 120, 130,
-            205, 216, 22, 46, 121, 20, 2, 40, 107, 238, 0, 188, 127, 14, 14, 149, 153, 80, 43, 130,
-            180, 250, 109, 222, 195, 40, 128, 127, 52, 45, 47, 53, 145, 116, 181, 28, 98, 15, 132,
-            51, 146, 174, 128, 79, 150, 192, 120, 214, 164, 99, 249, 137, 110, 20, 2, 40, 107, 238,
-            0, 188, 127, 14, 14, 149, 167, 251, 204, 195, 192, 18, 215, 97, 172, 17, 231, 12, 53,
-            89, 93, 56, 42, 12, 134, 11, 176, 80, 26, 176, 105, 13, 246, 201, 249, 110, 167, 23,
-            155, 32, 108, 29, 64, 20, 2, 132, 215, 23, 0, 188, 127, 14, 14, 153, 202, 171, 13, 99,
-            176, 150, 47, 97, 81, 3, 16, 191, 41, 235, 12, 1, 112, 162, 63, 207, 175, 186, 191, 61,
-            11, 8, 142, 105, 209, 152, 43, 42, 49, 176, 215, 222, 70, 20, 2, 180, 196, 4, 0, 188,
-            127, 14, 14, 154, 95, 161, 70, 10, 158, 236, 119, 128, 7, 241, 211, 136, 124, 145, 169,
-            5, 230, 52, 10, 14, 132, 100, 126, 96, 162, 155, 90, 186, 117, 4, 103, 248, 36, 38,
-            155, 141, 44, 20, 2, 132, 215, 23, 0, 188, 127, 14, 14, 165, 76, 184, 18, 163, 36, 132,
-            113, 148, 50, 90, 18, 188, 79, 79, 170, 203, 106, 239, 151, 62, 218, 49, 101, 129, 149,
-            194, 107, 147, 67, 24, 185, 96, 170, 105, 5, 8, 25, 20, 2, 14, 39, 7, 0, 188, 127, 14,
-            14, 179, 72, 14, 17, 206, 37, 244, 62, 17, 78, 87, 157, 117, 3, 232, 107, 198, 49, 197,
-            132, 21, 205, 28, 223, 134, 77, 15, 207, 81, 43, 221, 92, 250, 229, 26, 172, 6, 43, 20,
-            2, 194, 235, 11, 0, 188, 127, 14, 14, 190, 136, 89, 130, 30, 40, 48, 129, 207, 80, 21,
-            88, 78, 113, 247, 19, 13, 109, 61, 116, 79, 153, 160, 9, 163, 71, 115, 77, 133, 235,
-            142, 79, 83, 215, 157, 54, 35, 101, 20, 2, 14, 39, 7, 0, 188, 127, 14, 14, 202, 121,
-            201, 242, 246, 65, 144, 51, 134, 230, 28, 145, 153, 68, 22, 203, 0, 56, 84, 198, 38,
-            14, 19, 137, 250, 65, 73, 243, 128, 22, 90, 136, 147, 72, 225, 70, 168, 117, 20, 2, 90,
-            98, 2, 0, 188, 127, 14, 14, 204, 144, 39, 38, 239, 16, 180, 125, 70, 202, 17, 83, 122,
-            52, 97, 36, 10, 187, 183, 154, 190, 80, 248, 59, 96, 126, 80, 181, 40, 55, 24, 112,
-            123, 170, 153, 213, 45, 22, 20, 2, 40, 107, 238, 0, 188, 127, 14, 14, 208, 82, 90, 97,
-            244, 57, 208, 84, 144, 170, 116, 113, 121, 242, 184, 149, 194, 197, 23, 30, 156, 177,
-            10, 71, 79, 208, 125, 26, 128, 105, 56, 150, 120, 225, 101, 54, 158, 86, 20, 2, 14, 39,
-            7, 0, 188, 127, 14, 14, 211, 66, 185, 76, 26, 29, 128, 46, 66, 164, 131, 157, 126, 59,
-            185, 107, 142, 189, 179, 72, 116, 161, 67, 116, 152, 3, 221, 17, 54, 60, 204, 28, 186,
-            128, 17, 56, 99, 115, 20, 2, 40, 107, 238, 0, 188, 127, 14, 14, 215, 248, 255, 115, 57,
-            72, 44, 42, 85, 181, 5, 216, 217, 151, 184, 13, 116, 115, 149, 193, 71, 26, 209, 225,
-            232, 170, 87, 49, 156, 87, 39, 198, 241, 120, 189, 226, 66, 43, 20, 2, 132, 215, 23, 0,
-            188, 127, 14, 14, 220, 82, 219, 193, 132, 224, 52, 12, 167, 195, 213, 93, 42, 191, 61,
-            31, 44, 80, 5, 87, 201, 230, 120, 100, 187, 213, 232, 141, 195, 234, 65, 213, 28, 192,
-            80, 124, 78, 38, 20, 2, 14, 39, 7, 0, 188, 127, 14, 14, 221, 131, 211, 165, 86, 75, 10,
-            60, 154, 13, 194, 164, 87, 141, 75, 88, 204, 228, 82, 88, 179, 235, 176, 100, 69, 2,
-            179, 85, 206, 18, 65, 248, 163, 139, 189, 28, 10, 114, 20, 2, 14, 39, 7, 0, 176, 127,
-            14, 14, 224, 252, 162, 214, 122, 183, 213, 115, 83, 90, 64, 187, 1, 144, 62, 97, 106,
-            56, 61, 238, 210, 43, 94, 63, 243, 14, 85, 32, 23, 210, 57, 94, 62, 117, 168, 231, 134,
-            19, 8, 4, 0, 188, 127, 14, 14, 233, 26, 52, 114, 226, 191, 46, 217, 184, 3, 94, 11,
-            182, 70, 133, 245, 97, 103, 115, 73, 197, 77, 10, 232, 128, 111, 30, 168, 107, 116,
-            193, 43, 81, 220, 129, 84, 188, 156, 20, 2, 194, 235, 11, 0, 188, 127, 14, 14, 242, 41,
-            53, 226, 63, 201, 44, 42, 85, 181, 199, 161, 196, 142, 23, 106, 174, 175, 246, 154, 1,
-            125, 133, 178, 122, 73, 241, 250, 252, 22, 7, 37, 163, 55, 111, 65, 125, 0, 20, 2, 132,
-            215, 23, 0, 188, 127, 14, 14, 249, 246, 182, 76, 157, 241, 96, 2, 64, 179, 183, 159,
-            205, 0, 45, 117, 192, 154, 127, 37, 27, 87, 66, 148, 73, 198, 70, 190, 167, 167, 36, 3,
-            160, 81, 250, 138, 64, 54, 20, 2, 40, 107, 238, 0, 188, 127, 14, 14, 255, 201, 13, 209,
-            242, 120, 94, 195, 53, 145, 99, 16, 158, 121, 59, 114, 96, 101, 160, 172, 229, 162, 1,
-            183, 47, 191, 194, 211, 70, 193, 129, 119, 58, 4, 238, 62, 200, 114, 20, 2, 40, 107,
-            238, 0, 188, 127, 14, 15, 51, 107, 197, 223, 217, 90, 162, 210, 88, 31, 243, 152, 191,
-            188, 4, 245, 23, 48, 223, 163, 228, 106, 218, 19, 97, 8, 156, 93, 25, 231, 34, 80, 147,
-            50, 32, 149, 232, 83, 20, 2, 40, 107, 238, 0, 188, 127, 14, 15, 51, 188, 226, 37, 231,
-            114, 161, 96, 167, 48, 92, 181, 180, 122, 41, 3, 246, 100, 143, 187, 107, 161, 173, 36,
-            215, 47, 212, 156, 57, 236, 127, 157, 33, 225, 132, 243, 220, 199, 20, 2, 90, 98, 2, 0,
-            188, 127, 14, 15, 64, 31, 193, 37, 155, 75, 44, 42, 85, 180, 249, 86, 179, 63, 198,
-            212, 48, 19, 211, 32, 184, 124, 46, 221, 186, 49, 185, 222, 213, 53, 9, 151, 52, 214,
-            243, 171, 168, 14, 20, 2, 132, 215, 23, 0, 188, 127, 14, 15, 72, 37, 172, 249, 44, 190,
-            158, 220, 103, 7, 106, 28, 34, 250, 199, 166, 208, 185, 148, 76, 227, 189, 83, 166,
-            240, 226, 156, 207, 130, 209, 81, 173, 148, 134, 20, 81, 214, 44, 20, 2, 40, 107, 238,
-            0, 188, 127, 14, 15, 77, 39, 28, 159, 25, 83, 92, 181, 111, 103, 168, 46, 122, 84, 192,
-            185, 142, 82, 106, 65, 116, 67, 169, 117, 11, 200, 198, 78, 170, 27, 72, 123, 20, 71,
-            217, 164, 31, 92, 20, 2, 40, 107, 238, 0, 188, 127, 14, 15, 83, 39, 129, 109, 187, 199,
-            86, 173, 100, 181, 133, 83, 224, 67, 130, 251, 185, 81, 69, 239, 227, 223, 218, 136,
-            181, 81, 44, 15, 143, 207, 28, 236, 142, 224, 160, 93, 209, 114, 20, 2, 16, 94, 95, 0,
-            188, 127, 14, 15, 85, 53, 26, 144, 8, 146, 74, 141, 226, 198, 182, 193, 105, 10, 39,
-            170, 164, 203, 100, 224, 22, 139, 206, 213, 74, 181, 104, 149, 137, 101, 24, 118, 70,
-            240, 100, 66, 190, 109, 20, 2, 208, 18, 19, 0, 188, 127, 14, 15, 85, 189, 150, 184,
-            150, 69, 44, 42, 85, 181, 130, 87, 46, 163, 160, 45, 146, 209, 243, 3, 149, 158, 216,
-            211, 1, 242, 12, 247, 55, 119, 149, 187, 164, 231, 124, 165, 240, 89, 20, 2, 132, 215,
-            23, 0, 188, 127, 14, 15, 92, 103, 134, 221, 63, 61, 118, 134, 89, 137, 44, 169, 250,
-            184, 214, 78, 253, 24, 128, 84, 178, 40, 14, 252, 195, 105, 66, 200, 70, 22, 101, 50,
-            101, 212, 23, 254, 150, 107, 20, 2, 14, 39, 7, 0, 188, 127, 14, 15, 94, 27, 137, 101,
-            126, 7, 188, 248, 14, 235, 9, 178, 58, 55, 124, 82, 254, 199, 245, 133, 152, 17, 120,
-            175, 120, 49, 95, 186, 128, 144, 234, 85, 76, 78, 47, 189, 101, 13, 20, 2, 40, 107,
-            238, 0, 188, 127, 14, 15, 99, 195, 176, 25, 66, 49, 188, 153, 167, 248, 33, 178, 109,
-            170, 97, 47, 187, 156, 31, 125, 24, 238, 73, 26, 57, 206, 92, 225, 204, 171, 183, 5,
-            120, 34, 84, 170, 163, 4, 20, 2, 14, 39, 7, 0, 188, 127, 14, 15, 109, 210, 98, 83, 43,
-            25, 124, 239, 47, 147, 231, 172, 211, 91, 148, 57, 127, 169, 249, 14, 31, 98, 139, 240,
-            126, 112, 192, 136, 119, 249, 84, 5, 153, 41, 57, 180, 25, 83, 20, 2, 40, 107, 238, 0,
-            188, 127, 14, 15, 113, 211, 203, 91, 135, 115, 34, 208, 233, 131, 185, 234, 126, 253,
-            29, 40, 49, 95, 223, 212, 124, 251, 164, 239, 82, 0, 127, 50, 9, 89, 90, 198, 150, 244,
-            171, 203, 131, 53, 20, 2, 40, 107, 238, 0, 188, 127, 14, 15, 115, 129, 217, 36, 239,
-            38, 70, 176, 15, 193, 17, 70, 234, 108, 225, 36, 5, 232, 62, 197, 82, 201, 243, 214,
-            109, 207, 129, 172, 79, 216, 116, 226, 77, 177, 72, 79, 64, 65, 20, 130, 36, 165, 1, 0,
-            188, 127, 14, 15, 133, 80, 31, 96, 192, 173, 60, 63, 61, 201, 127, 46, 153, 112, 249,
-            22, 214, 207, 41, 225, 136, 253, 159, 248, 78, 82, 145, 208, 181, 207, 232, 251, 65,
-            21, 221, 54, 160, 56, 20, 2, 40, 107, 238, 0, 188, 127, 14, 15, 138, 189, 54, 68, 121,
-            49, 68, 17, 181, 76, 72, 106, 253, 214, 91, 190, 111, 72, 47, 171, 104, 162, 132, 67,
-            200, 69, 192, 4, 161, 197, 209, 65, 49, 69, 119, 214, 125, 10, 20, 2, 132, 215, 23, 0,
-            188, 127, 14, 15, 144, 149, 51, 81, 145, 144, 132, 16, 108, 80, 43, 243, 206, 46, 134,
-            156, 226, 204, 186, 186, 176, 134, 198, 210, 90, 149, 60, 26, 2, 230, 159, 63, 169, 97,
-            84, 107, 52, 35, 20, 2, 40, 107, 238, 0, 188, 127, 14, 15, 156, 102, 40, 42, 115, 190,
-            132, 93, 38, 133, 59, 92, 175, 132, 68, 181, 153, 92, 80, 183, 185, 186, 185, 231, 255,
-            178, 124, 210, 6, 65, 204, 76, 159, 4, 229, 148, 43, 65, 20, 2, 40, 107, 238, 0, 188,
-            127, 14, 15, 165, 152, 156, 206, 36, 139, 224, 118, 40, 222, 170, 156, 111, 187, 242,
-            40, 143, 135, 147, 150, 255, 53, 102, 135, 28, 13, 188, 232, 92, 158, 35, 118, 77, 21,
-            184, 16, 101, 127, 20, 2, 14, 39, 7, 0, 188, 127, 14, 15, 175, 125, 186, 21, 106, 248,
-            148, 138, 65, 179, 151, 242, 79, 54, 165, 255, 159, 214, 224, 202, 165, 146, 224, 112,
-            192, 47, 223, 111, 21, 66, 108, 82, 181, 140, 127, 64, 23, 123, 20, 2, 40, 107, 238, 0,
-            188, 127, 14, 15, 176, 163, 159, 181, 166, 221, 32, 96, 188, 89, 42, 23, 154, 62, 253,
-            4, 249, 1, 162, 39, 237, 95, 153, 152, 63, 12, 157, 247, 255, 84, 83, 197, 228, 123,
-            175, 173, 169, 10, 20, 2, 180, 196, 4, 0, 176, 127, 14, 15, 184, 214, 240, 25, 172, 53,
-            160, 2, 167, 210, 201, 58, 30, 76, 95, 62, 213, 233, 91, 6, 134, 47, 145, 204, 143,
-            145, 76, 100, 186, 63, 232, 68, 179, 92, 227, 108, 225, 81, 8, 4, 0, 188, 127, 14, 15,
-            187, 52, 52, 124, 173, 209, 44, 42, 85, 181, 38, 86, 23, 134, 40, 221, 160, 75, 225,
-            225, 106, 171, 21, 119, 86, 56, 207, 211, 186, 194, 90, 89, 22, 70, 49, 160, 107, 27,
-            20, 2, 132, 215, 23, 0, 188, 127, 14, 15, 202, 113, 212, 148, 150, 194, 204, 171, 50,
-            134, 17, 177, 172, 233, 252, 111, 160, 230, 123, 171, 7, 146, 36, 39, 197, 207, 111, 2,
-            145, 197, 181, 35, 94, 104, 104, 249, 243, 16, 20, 2, 90, 98, 2, 0, 188, 127, 14, 15,
-            214, 170, 70, 36, 141, 210, 44, 42, 85, 181, 154, 40, 47, 177, 115, 183, 250, 246, 232,
-            41, 132, 104, 111, 111, 184, 59, 2, 147, 221, 73, 141, 182, 50, 148, 100, 164, 93, 28,
-            20, 2, 132, 215, 23, 0, 188, 127, 14, 15, 219, 4, 96, 235, 166, 223, 114, 202, 122,
-            149, 211, 33, 33, 122, 202, 109, 164, 100, 174, 182, 164, 1, 164, 138, 164, 180, 87,
-            202, 23, 232, 229, 209, 222, 128, 10, 7, 108, 67, 20, 2, 40, 107, 238, 0, 188, 127, 14,
-            15, 226, 31, 52, 128, 145, 240, 124, 175, 11, 215, 109, 24, 14, 138, 172, 227, 107,
-            128, 240, 10, 133, 130, 240, 97, 125, 138, 217, 87, 177, 117, 176, 121, 131, 199, 86,
-            212, 101, 75, 20, 2, 40, 107, 238, 0, 188, 127, 14, 15, 226, 209, 19, 124, 129, 96,
-            192, 86, 217, 31, 216, 251, 141, 171, 230, 105, 116, 141, 62, 10, 235, 245, 96, 68,
-            157, 174, 228, 124, 78, 76, 27, 182, 228, 205, 48, 217, 16, 69, 20, 2, 40, 107, 238, 0,
-            188, 127, 14, 15, 231, 18, 48, 182, 30, 30, 62, 38, 99, 45, 229, 77, 130, 55, 8, 81, 6,
-            158, 246, 82, 213, 232, 165, 27, 35, 254, 215, 17, 205, 0, 201, 164, 220, 131, 96, 245,
-            73, 33, 20, 2, 40, 107, 238, 0, 188, 127, 14, 15, 231, 20, 39, 188, 160, 211, 202, 56,
-            115, 9, 25, 153, 141, 141, 220, 245, 231, 41, 168, 162, 117, 118, 155, 26, 224, 100,
-            209, 224, 245, 82, 141, 176, 62, 237, 224, 93, 94, 36, 20, 2, 90, 98, 2, 0, 188, 127,
-            14, 15, 231, 186, 250, 212, 28, 209, 19, 59, 137, 8, 213, 89, 245, 153, 64, 100, 241,
-            142, 157, 234, 230, 237, 215, 186, 166, 184, 163, 161, 61, 102, 55, 62, 193, 107, 205,
-            133, 205, 147, 20, 2, 194, 235, 11, 0, 188, 127, 14, 15, 232, 191, 13, 24, 82, 120,
-            164, 162, 180, 238, 90, 208, 188, 66, 60, 62, 43, 84, 146, 84, 95, 32, 150, 85, 15,
-            244, 151, 176, 116, 157, 83, 91, 136, 91, 217, 109, 24, 40, 20, 2, 40, 107, 238, 0,
-            188, 127, 14, 15, 234, 101, 15, 91, 197, 87, 246, 178, 37, 97, 120, 240, 33, 5, 87,
-            221, 204, 19, 142, 139, 38, 66, 4, 228, 103, 79, 178, 215, 58, 129, 144, 250, 244, 172,
-            55, 36, 114, 47, 20, 2, 208, 18, 19, 0, 176, 127, 14, 15, 247, 189, 72, 223, 123, 219,
-            120, 199, 220, 107, 49, 27, 199, 228, 77, 201, 87, 147, 240, 184, 225, 49, 106, 177,
-            68, 112, 115, 114, 132, 112, 254, 8, 224, 180, 168, 221, 36, 59, 8, 4, 0, 21, 1, 128,
-            0, 3, 128, 13, 9, 45, 153, 24, 19, 57, 120, 123, 67, 38, 20, 175, 95, 225, 82, 181,
-            251, 197, 27, 181, 80, 241, 226, 96, 169, 200, 115, 5, 238, 50, 158, 128, 231, 29, 122,
-            59, 0, 180, 184, 185, 185, 191, 138, 82, 158, 241, 78, 110, 34, 43, 155, 17, 45, 179,
-            156, 85, 217, 25, 233, 235, 169, 157, 160, 112, 21, 1, 128, 0, 3, 128, 78, 56, 14, 34,
-            187, 0, 41, 77, 203, 208, 33, 249, 164, 253, 169, 152, 229, 208, 7, 147, 6, 63, 92,
-            124, 51, 113, 199, 4, 246, 254, 70, 224, 128, 48, 24, 41, 153, 85, 140, 114, 146, 80,
-            242, 33, 24, 127, 51, 12, 162, 23, 16, 124, 189, 221, 48, 222, 228, 208, 204, 76, 136,
-            109, 182, 192, 132, 21, 1, 128, 0, 5, 128, 60, 122, 209, 162, 66, 95, 4, 47, 159, 209,
-            247, 162, 180, 40, 31, 191, 106, 227, 177, 52, 160, 27, 92, 115, 193, 167, 65, 115, 63,
-            36, 236, 189, 128, 10, 195, 194, 145, 88, 144, 33, 205, 201, 229, 250, 88, 213, 128,
-            176, 14, 86, 209, 151, 174, 217, 20, 176, 243, 208, 236, 45, 197, 108, 14, 199, 173,
-            21, 1, 128, 0, 5, 128, 112, 8, 87, 126, 164, 203, 234, 7, 181, 167, 77, 15, 85, 161,
-            122, 148, 185, 224, 183, 60, 255, 247, 208, 118, 184, 104, 214, 227, 77, 41, 132, 139,
-            128, 121, 118, 175, 193, 241, 219, 97, 80, 61, 57, 55, 196, 20, 69, 29, 87, 103, 202,
-            72, 92, 250, 71, 102, 12, 49, 30, 48, 99, 105, 107, 186, 154, 21, 1, 128, 0, 6, 128,
-            144, 224, 22, 213, 209, 190, 75, 206, 219, 156, 186, 192, 177, 212, 108, 176, 111, 181,
-            206, 4, 31, 24, 67, 223, 66, 41, 62, 123, 246, 208, 94, 248, 128, 58, 87, 236, 183,
-            205, 127, 88, 182, 103, 58, 123, 226, 196, 106, 61, 16, 142, 25, 6, 249, 115, 163, 101,
-            89, 230, 167, 106, 166, 161, 34, 197, 215, 21, 1, 128, 0, 10, 128, 217, 47, 232, 165,
-            108, 162, 238, 202, 186, 189, 80, 92, 190, 127, 147, 78, 76, 233, 45, 118, 15, 57, 184,
-            186, 59, 94, 43, 162, 236, 216, 140, 42, 128, 139, 106, 155, 95, 37, 141, 68, 143, 98,
-            8, 25, 88, 114, 211, 53, 181, 231, 89, 21, 111, 240, 79, 87, 94, 138, 127, 187, 136,
-            90, 139, 157, 163, 21, 1, 128, 0, 17, 128, 203, 203, 140, 101, 119, 223, 129, 66, 44,
-            197, 230, 80, 29, 196, 100, 29, 53, 203, 53, 162, 159, 254, 86, 45, 3, 214, 138, 130,
-            149, 94, 161, 221, 128, 48, 186, 210, 47, 123, 78, 251, 66, 39, 93, 209, 91, 50, 176,
-            149, 69, 214, 152, 53, 248, 62, 6, 248, 34, 224, 136, 235, 146, 158, 117, 249, 126, 21,
-            1, 128, 0, 18, 128, 199, 243, 93, 71, 133, 243, 28, 192, 248, 136, 133, 84, 40, 175,
-            160, 190, 144, 220, 62, 47, 93, 42, 87, 73, 176, 220, 23, 138, 53, 86, 88, 123, 128,
-            105, 5, 171, 36, 100, 161, 173, 161, 223, 209, 166, 146, 68, 76, 108, 235, 242, 144,
-            20, 189, 131, 55, 202, 64, 168, 237, 227, 148, 128, 43, 43, 245, 21, 1, 128, 0, 33,
-            128, 194, 162, 118, 194, 42, 147, 176, 229, 225, 99, 210, 146, 182, 131, 119, 73, 74,
-            76, 119, 195, 197, 176, 188, 164, 141, 179, 121, 160, 214, 203, 62, 27, 128, 37, 59,
-            55, 74, 158, 61, 110, 126, 20, 112, 30, 50, 202, 162, 250, 8, 109, 160, 161, 130, 50,
-            105, 72, 152, 240, 152, 26, 96, 37, 211, 8, 131, 21, 1, 128, 0, 36, 128, 189, 238, 72,
-            186, 179, 16, 208, 48, 168, 59, 124, 101, 253, 84, 60, 255, 56, 167, 255, 81, 40, 65,
-            59, 67, 106, 166, 146, 233, 201, 157, 232, 76, 128, 198, 81, 213, 112, 244, 121, 254,
-            196, 198, 32, 148, 29, 32, 8, 239, 11, 49, 219, 225, 89, 152, 177, 30, 234, 42, 134,
-            22, 171, 117, 38, 252, 89, 21, 1, 128, 0, 36, 128, 230, 151, 234, 181, 215, 247, 242,
-            173, 248, 84, 181, 216, 195, 81, 96, 17, 41, 22, 25, 212, 24, 244, 246, 93, 135, 152,
-            39, 203, 218, 0, 30, 233, 128, 142, 93, 122, 115, 104, 161, 58, 247, 200, 227, 226,
-            157, 94, 198, 64, 138, 23, 209, 252, 10, 178, 53, 10, 107, 207, 41, 25, 102, 58, 234,
-            55, 194, 21, 1, 128, 0, 40, 128, 200, 152, 243, 174, 14, 229, 40, 203, 246, 20, 48,
-            114, 248, 246, 146, 113, 106, 133, 242, 183, 248, 28, 228, 62, 8, 254, 166, 74, 254,
-            105, 95, 45, 128, 200, 181, 178, 116, 92, 168, 179, 5, 134, 76, 5, 132, 149, 110, 235,
-            122, 38, 123, 82, 20, 146, 202, 50, 0, 41, 48, 178, 226, 207, 96, 244, 202, 21, 1, 128,
-            0, 48, 128, 9, 19, 154, 13, 14, 175, 246, 154, 31, 108, 1, 174, 120, 53, 97, 186, 214,
-            102, 164, 66, 94, 137, 111, 175, 191, 179, 51, 133, 105, 224, 126, 200, 128, 253, 121,
-            113, 239, 229, 75, 169, 173, 250, 233, 223, 15, 85, 148, 17, 200, 98, 12, 145, 240,
-            141, 18, 55, 127, 9, 68, 185, 122, 225, 97, 66, 241, 153, 1, 128, 0, 50, 128, 189, 173,
-            113, 253, 136, 44, 207, 1, 66, 238, 77, 115, 217, 174, 162, 44, 77, 67, 159, 151, 181,
-            105, 154, 13, 86, 56, 62, 28, 13, 201, 229, 179, 128, 92, 103, 231, 188, 140, 109, 41,
-            252, 230, 119, 253, 237, 170, 147, 151, 133, 58, 42, 191, 215, 212, 186, 17, 205, 23,
-            236, 129, 171, 0, 178, 101, 202, 128, 52, 4, 231, 0, 37, 130, 112, 248, 189, 242, 11,
-            246, 77, 248, 94, 58, 112, 245, 170, 91, 55, 45, 62, 151, 52, 56, 18, 216, 118, 201,
-            173, 168, 21, 1, 128, 0, 66, 128, 5, 237, 231, 244, 186, 82, 13, 167, 140, 236, 212,
-            31, 126, 33, 88, 231, 168, 129, 230, 118, 211, 110, 178, 170, 87, 79, 53, 127, 132, 66,
-            76, 76, 128, 47, 87, 224, 4, 232, 47, 174, 79, 131, 221, 80, 150, 118, 207, 82, 33,
-            207, 202, 133, 151, 213, 212, 85, 97, 193, 247, 100, 195, 212, 31, 43, 43, 21, 1, 128,
-            0, 72, 128, 109, 45, 76, 205, 96, 154, 211, 59, 158, 39, 138, 168, 200, 55, 166, 47,
-            20, 210, 71, 232, 120, 246, 24, 20, 106, 3, 190, 191, 64, 151, 52, 141, 128, 0, 248,
-            202, 97, 235, 202, 108, 164, 15, 117, 32, 221, 200, 121, 44, 197, 202, 14, 245, 108,
-            190, 214, 47, 14, 56, 159, 160, 211, 56, 142, 246, 232, 21, 1, 128, 0, 80, 128, 201,
-            177, 128, 215, 253, 131, 210, 212, 94, 28, 179, 255, 131, 72, 160, 214, 140, 35, 74,
-            117, 56, 218, 182, 34, 134, 57, 106, 109, 188, 97, 156, 0, 128, 154, 21, 24, 123, 142,
-            24, 216, 21, 162, 4, 144, 128, 185, 44, 115, 200, 166, 79, 64, 101, 100, 200, 25, 191,
-            26, 35, 206, 48, 17, 154, 197, 111, 21, 1, 128, 0, 96, 128, 197, 225, 8, 75, 11, 193,
-            154, 186, 98, 167, 112, 163, 86, 166, 132, 8, 180, 211, 229, 124, 193, 166, 101, 89,
-            222, 8, 28, 64, 71, 9, 144, 221, 128, 12, 99, 108, 248, 34, 184, 254, 243, 80, 173,
-            110, 242, 93, 69, 91, 180, 247, 111, 233, 51, 158, 174, 77, 169, 145, 90, 138, 173,
-            191, 156, 108, 172, 153, 1, 128, 0, 100, 128, 247, 5, 108, 68, 149, 125, 8, 98, 184,
-            113, 51, 10, 133, 101, 252, 134, 189, 181, 31, 14, 230, 10, 147, 168, 102, 29, 26, 51,
-            206, 181, 201, 218, 128, 117, 85, 233, 198, 183, 181, 190, 72, 153, 238, 14, 45, 229,
-            156, 150, 41, 37, 205, 27, 109, 104, 107, 221, 162, 239, 56, 204, 133, 186, 194, 180,
-            252, 128, 79, 137, 13, 123, 228, 205, 186, 65, 21, 51, 130, 12, 137, 121, 118, 112,
-            226, 53, 191, 133, 118, 184, 142, 215, 109, 147, 61, 10, 63, 200, 165, 116, 29, 2, 128,
-            0, 102, 128, 82, 31, 150, 27, 157, 7, 195, 84, 56, 93, 10, 78, 192, 160, 151, 254, 190,
-            225, 106, 84, 112, 20, 171, 148, 189, 98, 83, 2, 248, 16, 182, 73, 128, 220, 92, 70,
-            187, 28, 210, 178, 130, 116, 31, 142, 200, 223, 251, 213, 141, 162, 5, 63, 157, 245, 5,
-            213, 112, 217, 134, 104, 61, 70, 235, 111, 197, 128, 61, 209, 100, 118, 205, 186, 98,
-            72, 45, 235, 240, 71, 136, 139, 19, 254, 151, 156, 254, 87, 162, 151, 249, 116, 230,
-            127, 173, 227, 12, 61, 161, 10, 128, 143, 230, 230, 46, 119, 105, 230, 3, 27, 175, 254,
-            207, 227, 21, 230, 18, 194, 224, 165, 101, 102, 59, 98, 41, 95, 191, 218, 197, 210, 58,
-            135, 168, 153, 1, 128, 0, 112, 128, 126, 164, 206, 137, 193, 5, 222, 25, 34, 85, 231,
-            76, 211, 52, 116, 60, 207, 177, 68, 184, 49, 61, 203, 201, 9, 114, 238, 52, 40, 133,
-            233, 29, 128, 52, 115, 159, 162, 183, 8, 172, 129, 205, 184, 156, 171, 156, 85, 97, 47,
-            9, 41, 108, 171, 118, 31, 103, 7, 170, 68, 26, 188, 227, 128, 97, 110, 128, 121, 67,
-            169, 107, 152, 182, 49, 84, 52, 82, 70, 179, 157, 50, 239, 24, 128, 112, 4, 142, 129,
-            241, 176, 244, 211, 248, 179, 62, 223, 46, 90, 133, 21, 1, 128, 0, 132, 128, 30, 233,
-            216, 81, 138, 58, 243, 226, 22, 185, 101, 65, 245, 133, 149, 155, 7, 57, 109, 26, 202,
-            143, 55, 145, 127, 128, 161, 85, 232, 216, 84, 242, 128, 114, 139, 159, 52, 143, 141,
-            196, 238, 7, 37, 144, 51, 89, 11, 75, 229, 234, 23, 31, 48, 150, 79, 235, 250, 30, 8,
-            205, 41, 254, 136, 86, 253, 153, 1, 128, 0, 133, 128, 220, 8, 21, 117, 25, 61, 211, 17,
-            15, 241, 125, 50, 251, 121, 185, 101, 131, 108, 148, 110, 32, 54, 31, 203, 109, 70,
-            157, 239, 67, 139, 63, 163, 128, 27, 229, 247, 69, 144, 123, 15, 195, 10, 136, 242,
-            204, 30, 189, 213, 222, 60, 50, 171, 3, 192, 89, 178, 34, 99, 218, 78, 185, 240, 230,
-            62, 48, 128, 185, 187, 1, 169, 46, 116, 207, 110, 200, 171, 190, 197, 196, 162, 139,
-            123, 113, 213, 227, 22, 181, 141, 47, 138, 106, 92, 212, 22, 43, 138, 30, 21, 21, 1,
-            128, 0, 136, 128, 91, 136, 156, 126, 169, 45, 2, 248, 182, 91, 133, 61, 111, 77, 167,
-            105, 219, 203, 46, 156, 13, 196, 206, 196, 84, 38, 91, 158, 60, 92, 152, 247, 128, 99,
-            177, 162, 155, 184, 3, 68, 113, 9, 165, 174, 182, 107, 77, 17, 223, 121, 140, 224, 39,
-            164, 88, 107, 65, 234, 103, 98, 44, 221, 83, 100, 148, 21, 1, 128, 0, 136, 128, 213,
-            18, 168, 197, 130, 7, 113, 96, 193, 39, 77, 246, 245, 130, 180, 108, 53, 219, 123, 177,
-            9, 204, 203, 55, 27, 112, 235, 152, 218, 24, 226, 99, 128, 155, 94, 72, 75, 185, 78,
-            213, 59, 4, 250, 80, 38, 16, 90, 84, 81, 135, 182, 50, 202, 156, 20, 90, 220, 131, 241,
-            177, 95, 3, 184, 88, 238, 153, 1, 128, 0, 138, 128, 108, 72, 239, 56, 96, 242, 36, 8,
-            74, 4, 35, 15, 60, 55, 87, 226, 93, 191, 241, 95, 95, 63, 205, 14, 21, 181, 132, 38,
-            108, 223, 174, 164, 128, 10, 130, 99, 67, 185, 26, 248, 188, 69, 24, 215, 66, 29, 98,
-            227, 206, 79, 8, 105, 195, 48, 139, 188, 219, 61, 110, 189, 17, 48, 147, 52, 36, 128,
-            37, 17, 60, 19, 64, 31, 26, 89, 104, 83, 49, 86, 58, 159, 56, 85, 122, 124, 26, 52,
-            111, 63, 29, 61, 184, 125, 239, 187, 6, 183, 218, 118, 21, 1, 128, 0, 144, 128, 68,
-            139, 74, 217, 3, 89, 216, 203, 235, 187, 166, 222, 195, 24, 206, 96, 67, 132, 61, 219,
-            214, 6, 75, 45, 94, 153, 107, 109, 210, 248, 157, 175, 128, 235, 240, 211, 254, 162,
-            115, 123, 242, 225, 141, 104, 46, 79, 82, 88, 95, 190, 248, 65, 177, 23, 247, 201, 219,
-            0, 28, 225, 84, 48, 1, 115, 74, 21, 1, 128, 0, 160, 128, 182, 32, 11, 28, 38, 242, 152,
-            197, 156, 118, 215, 128, 180, 24, 245, 107, 126, 33, 4, 68, 229, 146, 77, 111, 138,
-            158, 238, 251, 187, 95, 76, 187, 128, 66, 189, 109, 70, 139, 144, 84, 127, 159, 241,
-            99, 151, 114, 100, 157, 54, 249, 88, 246, 138, 33, 24, 196, 82, 234, 110, 104, 215, 96,
-            23, 214, 247, 29, 2, 128, 0, 177, 128, 199, 50, 201, 57, 175, 2, 55, 217, 58, 32, 234,
-            223, 238, 32, 112, 236, 219, 36, 102, 113, 31, 243, 123, 106, 206, 138, 78, 143, 65,
-            95, 64, 176, 128, 65, 255, 101, 134, 146, 204, 253, 34, 139, 32, 244, 235, 96, 48, 198,
-            94, 101, 134, 62, 99, 236, 36, 42, 146, 116, 238, 10, 175, 100, 189, 196, 185, 128,
-            225, 47, 191, 31, 181, 226, 227, 124, 242, 35, 4, 136, 217, 229, 205, 112, 47, 3, 102,
-            112, 68, 148, 204, 243, 133, 170, 158, 209, 180, 222, 40, 70, 128, 198, 122, 38, 81,
-            132, 119, 187, 167, 111, 31, 91, 202, 253, 207, 46, 104, 233, 141, 114, 109, 42, 137,
-            139, 63, 196, 97, 121, 148, 216, 149, 230, 221, 153, 1, 128, 0, 194, 128, 160, 100,
-            233, 91, 3, 183, 14, 46, 38, 91, 232, 130, 92, 197, 149, 174, 201, 100, 16, 253, 83,
-            81, 116, 25, 183, 147, 35, 216, 216, 118, 99, 122, 128, 179, 44, 124, 227, 90, 178,
-            230, 69, 120, 240, 134, 195, 239, 158, 174, 55, 184, 252, 171, 186, 143, 225, 250, 234,
-            45, 202, 218, 206, 117, 218, 23, 232, 128, 220, 17, 208, 182, 33, 98, 216, 24, 6, 184,
-            186, 104, 207, 11, 44, 216, 77, 151, 178, 228, 45, 48, 62, 32, 65, 82, 85, 57, 254, 69,
-            79, 117, 153, 1, 128, 0, 224, 128, 197, 236, 227, 105, 205, 222, 253, 5, 57, 175, 252,
-            136, 94, 138, 33, 152, 154, 161, 174, 155, 145, 167, 2, 33, 216, 105, 175, 246, 138,
-            54, 164, 219, 128, 246, 221, 126, 19, 113, 128, 152, 54, 57, 71, 94, 66, 195, 210, 232,
-            191, 62, 192, 119, 151, 205, 160, 206, 67, 224, 218, 71, 55, 215, 21, 171, 150, 128,
-            54, 213, 140, 201, 119, 178, 46, 128, 249, 141, 41, 194, 163, 208, 52, 160, 78, 3, 41,
-            39, 22, 45, 154, 152, 246, 132, 146, 91, 137, 124, 83, 10, 21, 1, 128, 1, 1, 128, 62,
-            151, 247, 122, 48, 155, 254, 155, 53, 173, 206, 45, 154, 21, 86, 2, 196, 110, 212, 63,
-            19, 1, 182, 58, 226, 169, 155, 27, 188, 249, 218, 118, 128, 106, 232, 74, 5, 153, 1,
-            117, 178, 181, 147, 95, 83, 15, 137, 138, 52, 172, 20, 137, 226, 174, 221, 37, 188,
-            130, 23, 115, 235, 194, 175, 37, 134, 21, 1, 128, 1, 1, 128, 154, 238, 244, 153, 68,
-            101, 249, 251, 224, 43, 120, 197, 101, 83, 230, 254, 136, 9, 197, 141, 164, 237, 142,
-            207, 92, 253, 255, 125, 116, 169, 192, 133, 128, 225, 27, 135, 108, 183, 91, 136, 55,
-            241, 94, 112, 159, 232, 244, 120, 36, 252, 187, 24, 98, 117, 59, 76, 80, 177, 97, 62,
-            92, 219, 46, 215, 65, 21, 1, 128, 1, 4, 128, 171, 209, 40, 189, 64, 152, 210, 223, 88,
-            66, 245, 196, 122, 195, 211, 245, 23, 192, 92, 114, 20, 140, 16, 202, 67, 148, 180, 32,
-            222, 208, 216, 231, 128, 22, 31, 152, 8, 51, 189, 132, 147, 12, 157, 29, 60, 172, 92,
-            124, 32, 137, 193, 135, 242, 115, 131, 27, 69, 121, 63, 33, 231, 86, 153, 86, 99, 21,
-            1, 128, 1, 4, 128, 255, 150, 72, 251, 4, 71, 48, 167, 124, 71, 230, 55, 174, 221, 2,
-            248, 192, 42, 37, 106, 215, 94, 81, 151, 209, 60, 14, 90, 53, 169, 93, 151, 128, 60,
-            140, 203, 47, 66, 139, 177, 59, 53, 132, 124, 7, 98, 66, 243, 98, 117, 214, 161, 229,
-            81, 160, 184, 147, 140, 160, 70, 78, 212, 51, 13, 32, 21, 1, 128, 1, 8, 128, 110, 192,
-            240, 57, 158, 118, 15, 175, 199, 118, 118, 220, 63, 175, 0, 124, 110, 228, 22, 167, 52,
-            22, 215, 92, 138, 168, 118, 67, 3, 60, 144, 166, 128, 223, 193, 50, 116, 17, 2, 40, 9,
-            245, 215, 175, 164, 19, 73, 200, 15, 239, 167, 147, 115, 56, 192, 199, 253, 17, 101,
-            136, 203, 166, 80, 248, 140, 153, 1, 128, 1, 9, 128, 182, 176, 86, 103, 130, 112, 152,
-            70, 130, 123, 97, 130, 253, 202, 191, 114, 214, 5, 255, 18, 214, 188, 64, 45, 189, 70,
-            119, 130, 247, 143, 146, 25, 128, 222, 84, 191, 139, 38, 192, 231, 202, 43, 181, 111,
-            182, 5, 135, 40, 28, 47, 210, 52, 7, 8, 92, 121, 239, 243, 65, 5, 1, 245, 93, 26, 21,
-            128, 184, 185, 201, 70, 229, 20, 169, 131, 43, 58, 19, 47, 56, 193, 99, 137, 165, 239,
-            91, 197, 213, 64, 238, 202, 57, 22, 108, 109, 197, 119, 57, 10, 21, 1, 128, 1, 16, 128,
-            104, 253, 140, 131, 62, 42, 58, 99, 141, 68, 213, 105, 192, 236, 98, 149, 242, 168,
-            136, 44, 3, 39, 13, 93, 3, 189, 153, 196, 88, 211, 136, 88, 128, 81, 35, 65, 138, 245,
-            146, 96, 154, 41, 132, 161, 162, 177, 43, 191, 160, 6, 133, 104, 96, 104, 44, 119, 172,
-            221, 7, 181, 254, 59, 178, 123, 157, 153, 1, 128, 1, 18, 128, 197, 242, 132, 168, 58,
-            218, 234, 112, 28, 70, 194, 201, 30, 41, 10, 61, 24, 36, 80, 200, 195, 81, 112, 137,
-            14, 19, 157, 129, 182, 33, 46, 10, 128, 48, 127, 1, 203, 8, 181, 157, 96, 133, 85, 76,
-            69, 177, 27, 99, 6, 106, 193, 82, 147, 247, 111, 158, 183, 241, 167, 21, 196, 192, 7,
-            15, 137, 128, 227, 4, 7, 134, 111, 116, 198, 67, 239, 154, 7, 102, 41, 77, 28, 148, 91,
-            31, 61, 160, 127, 237, 135, 92, 45, 244, 243, 215, 252, 67, 210, 71, 29, 2, 128, 1, 21,
-            128, 234, 15, 43, 225, 116, 227, 138, 210, 80, 29, 154, 87, 105, 154, 121, 50, 194,
-            246, 240, 102, 200, 20, 142, 170, 218, 160, 138, 222, 238, 244, 241, 189, 128, 69, 48,
-            206, 45, 236, 253, 216, 58, 169, 21, 246, 164, 206, 175, 6, 106, 111, 17, 205, 58, 183,
-            121, 193, 102, 178, 224, 214, 242, 30, 33, 40, 247, 128, 142, 174, 53, 151, 200, 186,
-            200, 31, 143, 97, 56, 103, 2, 77, 80, 122, 49, 62, 238, 142, 150, 46, 202, 113, 101,
-            72, 252, 96, 182, 54, 167, 47, 128, 129, 159, 230, 208, 223, 97, 185, 89, 109, 109,
-            134, 2, 50, 143, 62, 111, 200, 150, 242, 177, 140, 84, 243, 114, 86, 3, 14, 36, 99,
-            200, 0, 56, 153, 1, 128, 1, 34, 128, 34, 112, 76, 20, 158, 144, 41, 121, 240, 196, 194,
-            90, 86, 10, 255, 107, 157, 146, 55, 121, 209, 65, 103, 85, 27, 29, 96, 43, 161, 100,
-            193, 175, 128, 54, 117, 25, 160, 229, 116, 23, 210, 6, 109, 158, 227, 106, 108, 26,
-            195, 182, 86, 60, 222, 152, 177, 177, 79, 223, 16, 99, 90, 76, 128, 94, 186, 128, 238,
-            201, 178, 129, 220, 67, 15, 1, 229, 109, 12, 46, 223, 171, 47, 148, 184, 172, 41, 93,
-            248, 8, 149, 9, 185, 206, 98, 196, 161, 233, 144, 214, 153, 1, 128, 1, 34, 128, 180,
-            144, 69, 59, 47, 165, 102, 15, 144, 97, 154, 68, 51, 210, 22, 33, 235, 90, 193, 120,
-            169, 122, 180, 52, 97, 52, 74, 108, 102, 159, 19, 178, 128, 80, 49, 248, 169, 26, 60,
-            223, 243, 139, 79, 173, 148, 244, 31, 158, 244, 178, 245, 89, 151, 21, 151, 93, 176,
-            149, 197, 140, 77, 93, 68, 211, 97, 128, 62, 142, 157, 136, 196, 247, 69, 125, 163,
-            100, 116, 38, 129, 86, 32, 177, 9, 236, 225, 23, 217, 157, 43, 200, 137, 66, 28, 214,
-            138, 29, 59, 171, 153, 1, 128, 1, 36, 128, 14, 170, 0, 109, 214, 215, 167, 163, 13,
-            219, 206, 10, 30, 107, 113, 29, 39, 91, 244, 58, 153, 2, 161, 102, 3, 140, 218, 20,
-            131, 32, 242, 8, 128, 133, 177, 246, 221, 107, 71, 189, 138, 186, 82, 98, 163, 60, 242,
-            43, 255, 173, 108, 48, 50, 166, 70, 71, 161, 223, 160, 240, 54, 68, 45, 114, 163, 128,
-            198, 226, 248, 195, 76, 125, 159, 27, 6, 223, 128, 132, 230, 130, 49, 135, 214, 81,
-            143, 190, 25, 228, 87, 39, 162, 36, 73, 203, 148, 225, 105, 75, 21, 1, 128, 1, 64, 128,
-            167, 246, 224, 72, 248, 13, 103, 88, 223, 167, 13, 137, 184, 207, 159, 160, 202, 18,
-            52, 245, 194, 232, 0, 86, 48, 145, 216, 144, 188, 1, 123, 166, 128, 218, 9, 161, 155,
-            140, 37, 188, 81, 60, 101, 163, 134, 254, 25, 6, 148, 176, 213, 28, 19, 42, 94, 131, 4,
-            168, 212, 44, 182, 197, 19, 231, 149, 29, 2, 128, 1, 84, 128, 17, 23, 230, 230, 143,
-            163, 217, 25, 138, 100, 192, 43, 48, 140, 161, 78, 41, 251, 147, 63, 163, 86, 41, 222,
-            2, 20, 130, 83, 57, 191, 79, 126, 128, 61, 0, 118, 239, 107, 7, 10, 93, 74, 175, 172,
-            212, 108, 108, 249, 147, 224, 18, 66, 27, 58, 51, 9, 110, 237, 136, 169, 110, 118, 125,
-            190, 14, 128, 137, 192, 197, 116, 146, 233, 79, 154, 244, 27, 190, 228, 87, 205, 203,
-            22, 50, 160, 164, 195, 200, 109, 39, 86, 1, 226, 172, 107, 3, 147, 15, 177, 128, 30,
-            32, 47, 195, 1, 44, 229, 234, 208, 204, 202, 120, 125, 92, 146, 60, 190, 189, 5, 50,
-            192, 122, 48, 55, 97, 92, 12, 61, 136, 228, 207, 35, 21, 1, 128, 1, 128, 128, 101, 88,
-            102, 24, 69, 170, 173, 13, 212, 132, 64, 235, 198, 189, 112, 254, 22, 254, 27, 108,
-            202, 165, 127, 2, 169, 246, 133, 225, 196, 20, 188, 247, 128, 32, 71, 227, 60, 193,
-            112, 104, 173, 19, 63, 15, 50, 210, 42, 26, 154, 58, 152, 60, 91, 251, 19, 55, 1, 142,
-            46, 230, 121, 141, 181, 209, 105, 21, 1, 128, 1, 128, 128, 129, 216, 217, 35, 55, 222,
-            245, 165, 194, 135, 143, 195, 72, 87, 0, 253, 64, 84, 63, 114, 85, 209, 61, 158, 75,
-            254, 96, 216, 242, 43, 106, 12, 128, 157, 104, 103, 244, 223, 99, 228, 171, 124, 93,
-            105, 135, 2, 190, 193, 182, 168, 89, 140, 179, 67, 199, 199, 99, 13, 93, 73, 221, 199,
-            24, 22, 248, 153, 1, 128, 1, 129, 128, 14, 79, 66, 100, 131, 147, 185, 54, 15, 141, 3,
-            226, 200, 237, 1, 31, 66, 198, 70, 255, 175, 138, 251, 103, 32, 82, 16, 147, 4, 74,
-            194, 85, 128, 230, 108, 3, 23, 130, 145, 205, 119, 136, 28, 69, 177, 82, 52, 206, 253,
-            162, 54, 146, 73, 51, 183, 199, 175, 32, 213, 154, 211, 128, 143, 174, 131, 128, 42,
-            161, 119, 243, 58, 128, 5, 252, 145, 90, 168, 133, 25, 14, 107, 167, 244, 36, 118, 17,
-            172, 89, 40, 178, 50, 179, 152, 145, 136, 254, 187, 79, 153, 1, 128, 1, 144, 128, 21,
-            128, 171, 91, 28, 68, 227, 29, 171, 149, 8, 252, 76, 113, 13, 128, 97, 167, 49, 177,
-            152, 172, 155, 64, 212, 178, 180, 93, 244, 51, 6, 39, 128, 126, 107, 34, 59, 69, 6, 67,
-            70, 79, 86, 249, 72, 161, 122, 212, 192, 93, 174, 248, 25, 226, 34, 184, 244, 144, 164,
-            6, 171, 173, 118, 195, 159, 128, 241, 254, 244, 155, 200, 49, 12, 118, 133, 129, 7,
-            120, 80, 24, 65, 237, 125, 34, 62, 9, 239, 48, 29, 43, 163, 152, 36, 39, 242, 113, 119,
-            154, 29, 2, 128, 1, 162, 128, 126, 24, 249, 232, 3, 225, 8, 10, 1, 223, 142, 253, 126,
-            196, 255, 67, 192, 219, 172, 21, 106, 4, 120, 29, 124, 192, 116, 95, 67, 146, 188, 76,
-            128, 98, 5, 57, 189, 26, 73, 162, 70, 245, 231, 125, 205, 81, 236, 143, 183, 0, 199,
-            100, 123, 232, 131, 146, 150, 39, 12, 156, 125, 27, 177, 145, 56, 128, 60, 24, 220,
-            170, 164, 249, 202, 22, 59, 149, 233, 128, 38, 157, 196, 12, 217, 160, 91, 192, 14,
-            110, 22, 228, 218, 85, 255, 27, 198, 187, 172, 124, 128, 17, 172, 46, 37, 224, 197,
-            170, 61, 186, 196, 145, 118, 173, 128, 44, 124, 93, 137, 161, 202, 144, 39, 183, 212,
-            253, 28, 151, 182, 78, 73, 129, 24, 37, 3, 128, 1, 199, 128, 229, 52, 140, 251, 89, 18,
-            229, 223, 37, 116, 177, 95, 130, 85, 209, 100, 159, 224, 58, 178, 86, 52, 224, 208,
-            224, 105, 41, 125, 21, 39, 200, 153, 128, 56, 122, 161, 184, 180, 37, 225, 132, 219,
-            219, 210, 76, 124, 50, 54, 199, 218, 12, 48, 7, 188, 164, 189, 120, 68, 121, 198, 107,
-            120, 134, 75, 245, 128, 128, 194, 189, 61, 153, 136, 242, 95, 74, 24, 139, 74, 158,
-            136, 241, 247, 112, 85, 39, 202, 235, 190, 234, 111, 149, 128, 253, 109, 127, 107, 221,
-            33, 128, 158, 232, 136, 238, 212, 88, 112, 177, 200, 61, 65, 82, 11, 102, 89, 39, 220,
-            163, 123, 229, 152, 171, 30, 124, 40, 19, 231, 99, 135, 125, 142, 236, 128, 132, 101,
-            157, 170, 113, 35, 232, 185, 96, 247, 218, 132, 16, 134, 225, 25, 172, 6, 79, 216, 229,
-            133, 53, 253, 210, 183, 95, 225, 116, 85, 36, 126, 128, 200, 169, 163, 87, 47, 206,
-            153, 182, 169, 113, 79, 197, 99, 160, 124, 17, 47, 143, 21, 1, 230, 214, 198, 87, 114,
-            191, 20, 120, 251, 21, 130, 170, 21, 1, 128, 2, 1, 128, 157, 101, 55, 214, 161, 76,
-            215, 250, 122, 137, 171, 5, 225, 88, 48, 8, 189, 160, 18, 0, 209, 114, 66, 117, 18,
-            239, 153, 108, 36, 55, 240, 229, 128, 65, 118, 166, 45, 36, 126, 148, 138, 114, 29, 55,
-            208, 231, 160, 206, 167, 27, 182, 25, 122, 121, 61, 40, 219, 20, 129, 243, 200, 164,
-            75, 223, 25, 21, 1, 128, 2, 1, 128, 182, 124, 62, 39, 159, 24, 140, 204, 46, 154, 68,
-            61, 252, 134, 77, 96, 249, 83, 33, 17, 55, 233, 75, 174, 195, 57, 236, 149, 89, 44, 20,
-            129, 128, 244, 2, 150, 76, 157, 45, 142, 117, 104, 71, 253, 33, 114, 107, 189, 199,
-            147, 9, 219, 6, 222, 146, 222, 169, 126, 175, 5, 129, 17, 186, 203, 36, 21, 1, 128, 2,
-            2, 128, 73, 196, 182, 221, 136, 247, 220, 151, 214, 103, 235, 237, 7, 155, 40, 161,
-            134, 20, 251, 30, 67, 124, 137, 163, 126, 224, 162, 188, 46, 129, 149, 48, 128, 163, 0,
-            139, 38, 49, 119, 79, 158, 33, 183, 237, 105, 15, 118, 134, 57, 94, 226, 176, 9, 44,
-            235, 243, 251, 111, 188, 135, 129, 145, 216, 251, 186, 21, 1, 128, 2, 2, 128, 145, 164,
-            48, 145, 174, 151, 23, 165, 212, 19, 9, 172, 230, 175, 92, 26, 24, 160, 18, 220, 217,
-            250, 187, 65, 230, 0, 250, 57, 3, 196, 134, 183, 128, 121, 44, 158, 95, 85, 171, 141,
-            171, 8, 61, 76, 205, 77, 32, 8, 195, 91, 87, 132, 247, 81, 79, 198, 27, 9, 174, 154,
-            140, 39, 120, 103, 119, 21, 1, 128, 2, 4, 128, 5, 136, 179, 176, 111, 55, 25, 253, 118,
-            122, 238, 112, 104, 73, 195, 22, 38, 185, 52, 131, 177, 255, 123, 177, 59, 22, 225,
-            114, 23, 203, 243, 99, 128, 180, 185, 228, 86, 67, 152, 80, 168, 26, 69, 123, 164, 49,
-            1, 129, 166, 175, 248, 32, 39, 225, 173, 69, 40, 76, 255, 110, 41, 227, 56, 59, 51, 21,
-            1, 128, 2, 8, 128, 36, 254, 90, 102, 69, 2, 91, 191, 241, 84, 79, 118, 92, 141, 104,
-            135, 59, 244, 218, 43, 17, 46, 107, 148, 207, 35, 198, 76, 9, 248, 38, 98, 128, 231,
-            74, 188, 180, 2, 64, 229, 5, 198, 205, 126, 180, 80, 50, 21, 6, 178, 212, 179, 56, 52,
-            243, 145, 155, 132, 122, 209, 214, 250, 206, 110, 12, 21, 1, 128, 2, 8, 128, 139, 57,
-            240, 50, 220, 148, 216, 253, 194, 78, 125, 129, 214, 146, 103, 182, 0, 172, 1, 80, 26,
-            27, 101, 109, 161, 104, 129, 191, 208, 156, 88, 148, 128, 3, 5, 76, 99, 71, 3, 51, 138,
-            188, 23, 78, 61, 249, 14, 237, 158, 224, 174, 23, 157, 78, 106, 165, 12, 145, 40, 178,
-            177, 39, 227, 210, 122, 21, 1, 128, 2, 32, 128, 88, 92, 103, 248, 179, 176, 126, 171,
-            115, 199, 154, 157, 112, 143, 251, 63, 131, 212, 21, 116, 205, 24, 153, 91, 156, 255,
-            37, 238, 221, 143, 160, 154, 128, 113, 204, 218, 83, 230, 240, 255, 177, 14, 28, 62,
-            128, 21, 22, 25, 172, 1, 127, 123, 97, 107, 17, 62, 255, 51, 73, 8, 183, 37, 224, 12,
-            218, 21, 1, 128, 2, 32, 128, 174, 236, 138, 170, 190, 40, 99, 252, 175, 239, 127, 31,
-            237, 206, 178, 175, 103, 70, 1, 6, 131, 162, 69, 170, 48, 72, 211, 237, 177, 217, 140,
-            179, 128, 240, 9, 237, 231, 166, 153, 186, 153, 163, 250, 44, 33, 31, 103, 155, 177,
-            155, 147, 115, 85, 105, 74, 148, 132, 237, 215, 76, 107, 53, 203, 115, 68, 21, 1, 128,
-            2, 32, 128, 245, 23, 17, 209, 83, 248, 96, 237, 45, 182, 34, 99, 154, 216, 216, 90,
-            202, 78, 60, 185, 156, 215, 94, 44, 3, 115, 235, 57, 57, 75, 244, 36, 128, 188, 172,
-            71, 218, 164, 41, 163, 143, 246, 173, 211, 233, 220, 28, 18, 108, 127, 83, 165, 155,
-            240, 99, 150, 145, 176, 36, 240, 69, 77, 209, 117, 234, 21, 1, 128, 2, 64, 128, 235,
-            102, 63, 161, 178, 133, 237, 119, 157, 200, 245, 214, 231, 223, 87, 114, 82, 87, 248,
-            51, 141, 157, 195, 216, 27, 247, 196, 106, 15, 229, 7, 35, 128, 95, 65, 153, 9, 68,
-            151, 146, 77, 147, 194, 172, 221, 143, 255, 191, 252, 126, 199, 87, 104, 78, 164, 236,
-            10, 208, 127, 237, 95, 5, 159, 199, 157, 153, 1, 128, 2, 65, 128, 99, 210, 154, 21,
-            178, 193, 125, 85, 206, 249, 58, 101, 156, 18, 63, 145, 182, 34, 237, 231, 73, 47, 226,
-            4, 86, 141, 92, 193, 43, 181, 28, 163, 128, 23, 129, 30, 191, 96, 191, 89, 80, 194, 41,
-            17, 11, 91, 104, 94, 6, 104, 77, 70, 127, 167, 28, 147, 147, 113, 43, 249, 251, 162,
-            184, 245, 189, 128, 251, 96, 197, 142, 226, 159, 95, 72, 144, 68, 183, 104, 187, 122,
-            61, 47, 241, 149, 122, 87, 35, 217, 102, 184, 119, 109, 172, 81, 212, 36, 93, 223, 161,
-            2, 128, 2, 71, 128, 13, 184, 172, 213, 17, 102, 181, 223, 188, 198, 11, 113, 39, 22,
-            98, 157, 201, 246, 109, 232, 100, 79, 204, 69, 218, 88, 174, 73, 107, 44, 42, 95, 128,
-            241, 101, 97, 31, 118, 15, 249, 190, 146, 153, 219, 139, 142, 4, 31, 184, 84, 108, 50,
-            105, 119, 83, 9, 40, 95, 113, 249, 84, 55, 34, 33, 71, 128, 60, 255, 251, 186, 30, 23,
-            76, 244, 9, 122, 40, 138, 111, 14, 132, 219, 199, 6, 67, 100, 226, 108, 197, 123, 238,
-            105, 18, 135, 167, 61, 47, 42, 128, 159, 21, 120, 36, 39, 93, 107, 16, 107, 77, 27,
-            231, 206, 43, 223, 85, 197, 46, 68, 222, 113, 82, 204, 42, 67, 103, 5, 81, 98, 34, 250,
-            110, 128, 12, 17, 40, 13, 128, 106, 16, 209, 79, 150, 31, 200, 43, 21, 112, 41, 94,
-            102, 103, 114, 56, 211, 131, 219, 123, 232, 236, 68, 48, 153, 22, 229, 153, 1, 128, 2,
-            96, 128, 71, 66, 171, 183, 93, 208, 136, 233, 2, 50, 106, 26, 45, 221, 176, 111, 60,
-            245, 71, 133, 63, 240, 37, 130, 70, 70, 38, 235, 35, 163, 224, 61, 128, 211, 87, 189,
-            146, 157, 104, 140, 26, 179, 90, 246, 141, 5, 95, 214, 77, 102, 222, 36, 210, 37, 21,
-            240, 69, 53, 56, 63, 64, 182, 53, 136, 204, 128, 153, 23, 8, 50, 193, 121, 97, 203,
-            236, 251, 208, 5, 6, 70, 177, 10, 229, 2, 0, 75, 209, 88, 12, 93, 38, 211, 182, 253,
-            106, 182, 69, 250, 21, 1, 128, 2, 128, 128, 39, 43, 181, 44, 99, 235, 129, 58, 178,
-            238, 135, 119, 123, 213, 233, 213, 221, 190, 24, 208, 102, 32, 41, 146, 247, 102, 251,
-            55, 228, 191, 112, 132, 128, 198, 97, 115, 128, 126, 175, 232, 202, 255, 189, 21, 84,
-            228, 246, 221, 67, 147, 231, 171, 70, 137, 116, 51, 210, 98, 93, 4, 8, 111, 1, 215,
-            134, 153, 1, 128, 2, 136, 128, 245, 186, 30, 234, 46, 209, 152, 84, 69, 42, 94, 14, 62,
-            154, 92, 170, 55, 38, 224, 91, 117, 175, 112, 206, 94, 50, 235, 190, 158, 223, 48, 122,
-            128, 145, 158, 73, 145, 122, 74, 4, 222, 83, 240, 208, 57, 26, 103, 51, 60, 72, 207,
-            208, 76, 23, 238, 52, 134, 110, 84, 101, 191, 65, 186, 138, 16, 128, 133, 4, 233, 24,
-            79, 228, 255, 81, 201, 201, 217, 145, 56, 243, 223, 227, 221, 32, 153, 144, 98, 1, 118,
-            27, 38, 18, 0, 77, 217, 229, 167, 219, 29, 2, 128, 2, 148, 128, 120, 1, 229, 133, 127,
-            25, 181, 209, 230, 92, 49, 229, 247, 31, 24, 105, 136, 25, 137, 19, 83, 134, 122, 196,
-            1, 70, 155, 112, 204, 141, 146, 61, 128, 90, 209, 77, 30, 0, 34, 229, 94, 44, 81, 106,
-            16, 64, 132, 0, 95, 29, 132, 49, 8, 153, 142, 15, 162, 129, 202, 59, 5, 207, 143, 162,
-            50, 128, 188, 235, 241, 241, 171, 214, 187, 162, 83, 116, 178, 167, 145, 20, 178, 145,
-            246, 23, 198, 164, 162, 133, 240, 212, 144, 101, 91, 25, 67, 49, 206, 61, 128, 0, 86,
-            202, 132, 154, 216, 154, 30, 105, 210, 195, 219, 251, 129, 118, 210, 189, 140, 144,
-            211, 81, 177, 47, 218, 121, 84, 173, 35, 58, 241, 251, 162, 21, 1, 128, 3, 0, 128, 121,
-            49, 22, 126, 47, 59, 139, 160, 154, 90, 135, 3, 220, 51, 167, 123, 171, 122, 97, 186,
-            255, 255, 84, 124, 25, 80, 221, 13, 143, 181, 61, 3, 128, 236, 94, 135, 1, 122, 117,
-            175, 238, 159, 61, 84, 32, 62, 56, 106, 132, 73, 156, 178, 100, 22, 164, 181, 25, 252,
-            79, 139, 151, 87, 167, 169, 105, 153, 1, 128, 3, 1, 128, 194, 74, 43, 3, 221, 175, 58,
-            115, 85, 9, 234, 129, 87, 230, 93, 57, 75, 108, 247, 33, 156, 251, 99, 165, 71, 63,
-            238, 241, 85, 81, 21, 171, 128, 131, 110, 150, 246, 191, 159, 97, 109, 48, 158, 7, 224,
-            88, 156, 135, 228, 28, 1, 172, 108, 104, 202, 30, 229, 219, 72, 35, 239, 235, 241, 59,
-            209, 128, 92, 169, 127, 96, 219, 124, 181, 165, 37, 155, 241, 128, 228, 128, 203, 7,
-            160, 138, 37, 250, 199, 8, 127, 3, 86, 72, 195, 88, 93, 199, 157, 152, 153, 1, 128, 3,
-            2, 128, 86, 5, 179, 136, 255, 241, 125, 53, 93, 165, 223, 152, 211, 145, 1, 42, 22, 74,
-            71, 134, 58, 210, 82, 240, 30, 237, 201, 45, 31, 93, 74, 71, 128, 162, 245, 13, 243,
-            217, 123, 164, 211, 81, 182, 37, 157, 246, 239, 105, 124, 109, 209, 58, 122, 192, 121,
-            216, 31, 253, 160, 220, 210, 23, 168, 59, 48, 128, 5, 224, 143, 52, 1, 209, 34, 178,
-            244, 87, 189, 1, 55, 180, 200, 245, 236, 196, 182, 85, 115, 163, 83, 35, 175, 80, 14,
-            231, 70, 239, 61, 70, 29, 2, 128, 3, 65, 128, 1, 120, 71, 45, 184, 21, 224, 129, 126,
-            8, 211, 214, 10, 83, 5, 244, 53, 95, 105, 237, 61, 244, 211, 147, 155, 231, 230, 104,
-            222, 173, 167, 177, 128, 78, 172, 16, 193, 118, 34, 162, 111, 0, 200, 79, 33, 182, 8,
-            243, 75, 162, 183, 23, 9, 46, 122, 196, 131, 127, 13, 153, 138, 93, 184, 107, 30, 128,
-            97, 163, 152, 53, 156, 173, 202, 56, 59, 196, 93, 212, 113, 36, 135, 232, 2, 107, 61,
-            5, 14, 250, 71, 108, 217, 211, 116, 142, 120, 55, 237, 109, 128, 148, 148, 206, 212,
-            106, 233, 238, 200, 252, 24, 238, 10, 74, 113, 247, 99, 219, 198, 187, 18, 140, 165,
-            170, 207, 40, 1, 156, 182, 39, 64, 205, 42, 21, 1, 128, 4, 1, 128, 77, 95, 110, 84, 68,
-            162, 31, 192, 217, 93, 187, 71, 134, 176, 197, 66, 59, 47, 56, 58, 36, 122, 123, 123,
-            214, 5, 93, 10, 242, 207, 162, 188, 128, 78, 66, 70, 45, 171, 140, 108, 233, 242, 60,
-            189, 28, 119, 93, 163, 77, 26, 42, 177, 204, 25, 135, 7, 19, 50, 74, 26, 166, 219, 42,
-            248, 62, 21, 1, 128, 4, 1, 128, 184, 244, 202, 100, 182, 8, 21, 20, 125, 75, 62, 61,
-            215, 87, 85, 14, 42, 67, 116, 83, 156, 165, 32, 3, 68, 253, 224, 70, 53, 249, 133, 210,
-            128, 133, 55, 229, 76, 92, 21, 107, 187, 90, 198, 245, 77, 86, 83, 91, 141, 32, 210,
-            232, 192, 152, 192, 212, 252, 182, 214, 200, 195, 189, 15, 183, 184, 21, 1, 128, 4, 1,
-            128, 249, 109, 122, 234, 91, 148, 137, 50, 62, 114, 226, 42, 10, 203, 69, 113, 37, 15,
-            85, 48, 8, 42, 197, 197, 122, 181, 74, 173, 130, 240, 163, 87, 128, 122, 103, 245, 83,
-            96, 202, 235, 8, 42, 189, 114, 119, 45, 139, 234, 40, 73, 210, 46, 66, 79, 126, 23,
-            201, 49, 82, 52, 57, 184, 58, 179, 184, 21, 1, 128, 4, 4, 128, 20, 154, 27, 63, 205,
-            29, 207, 50, 26, 247, 87, 168, 66, 96, 179, 223, 138, 234, 154, 205, 148, 44, 247, 168,
-            33, 236, 134, 19, 101, 32, 213, 175, 128, 91, 204, 217, 254, 218, 46, 51, 97, 186, 63,
-            240, 17, 85, 221, 236, 30, 102, 119, 45, 130, 199, 183, 185, 78, 134, 67, 169, 163,
-            245, 117, 33, 225, 21, 1, 128, 4, 16, 128, 15, 224, 158, 217, 187, 168, 239, 139, 16,
-            56, 102, 95, 164, 202, 53, 212, 46, 106, 112, 180, 161, 88, 114, 112, 157, 237, 179,
-            94, 34, 184, 67, 214, 128, 55, 31, 167, 135, 149, 168, 155, 36, 248, 37, 44, 142, 68,
-            61, 135, 45, 201, 205, 211, 147, 184, 50, 140, 77, 89, 213, 48, 120, 70, 237, 197, 234,
-            21, 1, 128, 4, 32, 128, 13, 54, 149, 21, 219, 3, 119, 196, 111, 37, 1, 63, 243, 183,
-            137, 62, 88, 151, 16, 94, 163, 187, 8, 135, 147, 138, 159, 128, 151, 11, 19, 12, 128,
-            242, 160, 48, 172, 182, 126, 33, 120, 225, 148, 224, 38, 118, 5, 62, 82, 12, 117, 90,
-            32, 80, 77, 98, 162, 150, 244, 161, 219, 193, 126, 204, 107, 153, 1, 128, 4, 33, 128,
-            209, 170, 213, 100, 195, 186, 56, 45, 113, 62, 12, 218, 215, 54, 101, 11, 144, 224,
-            200, 169, 77, 37, 45, 119, 8, 150, 227, 87, 38, 214, 135, 215, 128, 5, 44, 105, 45,
-            254, 142, 215, 199, 70, 4, 91, 143, 228, 251, 185, 13, 132, 58, 51, 94, 133, 94, 184,
-            62, 121, 29, 64, 179, 131, 226, 52, 20, 128, 25, 6, 250, 231, 47, 248, 136, 92, 44, 66,
-            67, 138, 49, 104, 255, 67, 200, 197, 98, 58, 241, 51, 188, 230, 246, 248, 77, 241, 233,
-            212, 76, 138, 21, 1, 128, 4, 64, 128, 112, 129, 123, 15, 255, 72, 144, 97, 80, 232, 38,
-            129, 54, 32, 159, 50, 41, 18, 185, 136, 100, 211, 175, 170, 176, 92, 1, 41, 6, 47, 214,
-            193, 128, 241, 180, 141, 84, 196, 140, 158, 218, 157, 39, 220, 133, 69, 10, 206, 105,
-            209, 209, 179, 216, 213, 81, 65, 134, 40, 134, 240, 45, 241, 5, 153, 190, 153, 1, 128,
-            4, 80, 128, 136, 1, 179, 51, 123, 21, 128, 190, 108, 92, 82, 8, 19, 29, 98, 70, 15,
-            154, 49, 234, 95, 89, 76, 192, 233, 42, 149, 42, 185, 118, 85, 161, 128, 211, 55, 222,
-            224, 240, 222, 55, 204, 172, 19, 198, 215, 6, 187, 194, 242, 240, 36, 97, 156, 89, 175,
-            202, 96, 243, 145, 208, 58, 148, 3, 110, 236, 128, 2, 136, 164, 88, 89, 94, 184, 210,
-            47, 187, 181, 115, 83, 227, 64, 234, 255, 174, 86, 145, 24, 211, 74, 30, 14, 108, 78,
-            126, 14, 87, 79, 229, 29, 2, 128, 4, 100, 128, 225, 236, 90, 149, 228, 161, 167, 219,
-            244, 209, 23, 190, 230, 74, 114, 206, 248, 204, 105, 217, 222, 35, 186, 21, 215, 92,
-            205, 240, 145, 192, 60, 108, 128, 141, 93, 26, 245, 195, 157, 132, 179, 83, 209, 25,
-            196, 93, 64, 137, 188, 23, 181, 100, 52, 98, 56, 177, 195, 73, 62, 213, 190, 247, 83,
-            129, 67, 128, 167, 162, 53, 166, 14, 252, 176, 21, 148, 155, 229, 195, 224, 197, 255,
-            9, 146, 27, 207, 62, 12, 249, 212, 132, 217, 57, 70, 198, 19, 93, 150, 45, 128, 105,
-            122, 113, 6, 170, 14, 215, 77, 192, 229, 73, 71, 86, 230, 230, 44, 32, 79, 168, 51,
-            211, 219, 14, 165, 152, 11, 73, 17, 57, 227, 198, 106, 21, 1, 128, 4, 128, 128, 153,
-            56, 155, 61, 8, 145, 20, 76, 73, 160, 186, 125, 27, 0, 202, 166, 50, 62, 40, 198, 122,
-            66, 34, 64, 111, 80, 92, 22, 141, 43, 207, 63, 128, 37, 43, 205, 144, 232, 106, 73,
-            233, 221, 152, 31, 209, 193, 99, 197, 60, 189, 102, 223, 229, 214, 63, 172, 146, 119,
-            132, 253, 59, 68, 124, 61, 66, 21, 1, 128, 4, 128, 128, 154, 168, 69, 168, 28, 241, 89,
-            156, 255, 74, 142, 56, 93, 241, 119, 5, 179, 221, 10, 101, 60, 22, 28, 151, 233, 178,
-            186, 141, 214, 212, 87, 17, 128, 136, 103, 87, 66, 14, 4, 86, 32, 189, 253, 89, 232,
-            200, 3, 58, 149, 92, 9, 159, 29, 15, 184, 13, 42, 22, 39, 93, 24, 121, 42, 176, 115,
-            29, 2, 128, 4, 164, 128, 87, 52, 136, 49, 1, 133, 172, 25, 73, 69, 111, 193, 250, 82,
-            10, 40, 13, 75, 163, 46, 40, 32, 101, 84, 78, 50, 74, 237, 46, 65, 72, 196, 128, 83,
-            105, 201, 103, 139, 146, 66, 67, 123, 5, 238, 21, 126, 231, 131, 236, 233, 106, 173,
-            92, 177, 166, 248, 170, 14, 116, 17, 28, 14, 238, 204, 221, 128, 160, 197, 125, 252,
-            222, 87, 237, 50, 167, 85, 48, 206, 37, 202, 53, 133, 213, 10, 179, 106, 68, 173, 170,
-            236, 42, 213, 253, 28, 61, 124, 87, 90, 128, 98, 150, 43, 222, 173, 83, 243, 167, 227,
-            167, 164, 23, 135, 47, 10, 111, 221, 11, 45, 21, 34, 136, 67, 52, 230, 19, 69, 1, 49,
-            224, 95, 94, 21, 1, 128, 5, 0, 128, 82, 163, 200, 252, 174, 158, 209, 8, 249, 157, 253,
-            171, 168, 120, 93, 177, 190, 216, 243, 106, 219, 159, 168, 101, 81, 105, 248, 183, 141,
-            218, 241, 31, 128, 84, 168, 156, 209, 30, 210, 204, 75, 192, 75, 110, 243, 112, 114,
-            40, 1, 226, 141, 52, 10, 247, 45, 44, 67, 172, 212, 238, 0, 79, 96, 89, 80, 21, 1, 128,
-            5, 0, 128, 87, 15, 178, 119, 209, 246, 139, 93, 186, 44, 142, 158, 105, 23, 163, 199,
-            4, 20, 246, 123, 207, 73, 150, 98, 76, 16, 179, 253, 136, 197, 74, 44, 128, 123, 58,
-            148, 113, 33, 114, 17, 78, 239, 74, 78, 228, 66, 211, 54, 188, 105, 234, 253, 171, 184,
-            23, 254, 73, 7, 211, 217, 87, 249, 154, 172, 197, 29, 2, 128, 5, 24, 128, 130, 65, 83,
-            186, 188, 140, 42, 248, 141, 195, 125, 127, 40, 109, 66, 78, 147, 63, 224, 219, 177,
-            206, 229, 84, 21, 45, 19, 132, 47, 139, 188, 25, 128, 129, 185, 144, 198, 225, 214,
-            218, 192, 190, 28, 202, 191, 41, 77, 167, 161, 171, 201, 23, 170, 251, 66, 211, 244,
-            157, 204, 174, 202, 114, 81, 179, 6, 128, 85, 4, 147, 6, 155, 253, 217, 221, 166, 220,
-            210, 29, 218, 245, 162, 36, 39, 83, 93, 116, 76, 218, 20, 207, 219, 225, 101, 111, 90,
-            99, 162, 15, 128, 168, 172, 197, 46, 122, 12, 186, 110, 116, 175, 2, 238, 217, 136,
-            146, 86, 117, 9, 120, 185, 188, 96, 188, 102, 191, 115, 132, 13, 109, 25, 42, 42, 153,
-            1, 128, 6, 8, 128, 99, 129, 96, 54, 57, 125, 127, 11, 10, 160, 247, 5, 109, 108, 151,
-            98, 180, 98, 99, 55, 111, 229, 109, 229, 89, 220, 177, 74, 102, 124, 14, 77, 128, 36,
-            41, 235, 101, 132, 128, 98, 125, 151, 75, 215, 85, 253, 53, 125, 186, 33, 228, 212, 18,
-            250, 68, 92, 135, 223, 109, 23, 66, 194, 165, 128, 99, 128, 89, 35, 50, 115, 60, 255,
-            199, 193, 31, 100, 50, 177, 167, 39, 32, 137, 111, 181, 38, 15, 112, 23, 141, 92, 153,
-            65, 1, 28, 5, 140, 225, 4, 37, 3, 128, 6, 197, 128, 6, 135, 183, 121, 122, 10, 2, 249,
-            139, 237, 84, 52, 220, 99, 107, 28, 115, 190, 19, 117, 232, 86, 92, 233, 157, 59, 109,
-            24, 232, 196, 96, 141, 128, 30, 111, 27, 172, 94, 198, 92, 118, 141, 72, 147, 114, 189,
-            113, 139, 188, 196, 31, 142, 3, 103, 201, 152, 207, 35, 23, 74, 240, 186, 22, 243, 55,
-            128, 247, 73, 230, 91, 243, 66, 196, 69, 160, 124, 49, 219, 19, 16, 94, 254, 34, 66,
-            93, 109, 17, 224, 185, 74, 102, 151, 129, 149, 156, 92, 76, 186, 128, 148, 246, 214,
-            20, 35, 4, 129, 115, 46, 81, 105, 70, 75, 247, 48, 5, 187, 93, 244, 175, 116, 235, 82,
-            202, 37, 201, 41, 170, 0, 65, 144, 72, 128, 156, 98, 111, 205, 46, 137, 244, 169, 9,
-            58, 88, 141, 144, 114, 195, 151, 114, 182, 91, 220, 217, 184, 51, 19, 111, 114, 7, 152,
-            185, 225, 190, 22, 128, 129, 25, 145, 64, 248, 199, 57, 114, 250, 93, 87, 3, 21, 123,
-            56, 246, 208, 163, 160, 171, 229, 28, 152, 194, 19, 243, 123, 163, 28, 181, 253, 104,
-            21, 1, 128, 8, 1, 128, 123, 224, 17, 161, 146, 233, 241, 15, 146, 185, 28, 148, 71, 91,
-            145, 169, 235, 35, 73, 16, 208, 156, 236, 139, 200, 12, 203, 102, 133, 13, 255, 225,
-            128, 183, 150, 31, 232, 83, 182, 30, 79, 224, 183, 100, 136, 38, 125, 89, 222, 25, 62,
-            52, 16, 251, 11, 122, 125, 197, 63, 133, 242, 164, 32, 201, 200, 21, 1, 128, 8, 1, 128,
-            211, 179, 22, 239, 85, 202, 141, 38, 195, 11, 124, 79, 68, 184, 24, 113, 174, 130, 93,
-            148, 253, 200, 105, 32, 138, 32, 116, 213, 155, 137, 12, 1, 128, 103, 7, 89, 172, 66,
-            32, 167, 199, 61, 65, 59, 119, 98, 197, 91, 253, 46, 7, 249, 117, 66, 7, 92, 123, 97,
-            254, 106, 145, 42, 157, 142, 18, 21, 1, 128, 8, 4, 128, 73, 61, 180, 155, 69, 98, 242,
-            228, 142, 89, 167, 47, 241, 167, 246, 116, 250, 135, 17, 113, 165, 19, 201, 204, 13,
-            232, 190, 7, 210, 247, 14, 97, 128, 212, 77, 219, 124, 93, 168, 23, 247, 202, 187, 47,
-            141, 116, 66, 177, 6, 40, 55, 220, 91, 22, 62, 247, 198, 38, 1, 240, 105, 77, 190, 150,
-            130, 21, 1, 128, 8, 8, 128, 38, 208, 238, 212, 73, 211, 96, 63, 30, 38, 220, 41, 141,
-            114, 159, 158, 93, 128, 48, 155, 19, 143, 46, 25, 92, 53, 240, 51, 193, 98, 29, 124,
-            128, 38, 251, 103, 25, 165, 63, 88, 244, 76, 148, 77, 17, 43, 58, 235, 177, 190, 174,
-            146, 107, 199, 6, 133, 98, 28, 200, 31, 117, 100, 16, 8, 220, 21, 1, 128, 8, 8, 128,
-            152, 6, 119, 224, 149, 249, 110, 59, 103, 230, 162, 75, 41, 85, 157, 103, 237, 223, 42,
-            184, 88, 207, 110, 153, 19, 50, 141, 34, 205, 155, 236, 6, 128, 11, 99, 183, 82, 52,
-            33, 203, 27, 240, 179, 208, 179, 44, 222, 156, 19, 193, 147, 52, 2, 88, 21, 205, 40,
-            230, 254, 237, 40, 136, 22, 96, 236, 21, 1, 128, 8, 16, 128, 127, 183, 2, 197, 211,
-            251, 192, 88, 221, 135, 54, 236, 192, 193, 136, 154, 25, 3, 233, 164, 99, 141, 114,
-            153, 40, 134, 88, 10, 173, 122, 170, 163, 128, 196, 49, 223, 55, 244, 24, 36, 206, 46,
-            176, 10, 200, 156, 9, 86, 188, 83, 181, 71, 160, 173, 178, 229, 185, 109, 132, 54, 188,
-            73, 59, 135, 237, 21, 1, 128, 8, 16, 128, 149, 65, 179, 70, 24, 61, 92, 33, 17, 102,
-            240, 131, 5, 35, 64, 32, 60, 89, 228, 255, 196, 184, 138, 52, 169, 102, 224, 164, 110,
-            205, 226, 146, 128, 30, 81, 172, 54, 71, 42, 219, 76, 62, 8, 121, 143, 130, 152, 170,
-            194, 50, 155, 170, 52, 220, 243, 206, 201, 139, 119, 125, 228, 180, 102, 112, 6, 21, 1,
-            128, 8, 16, 128, 166, 132, 119, 164, 242, 182, 214, 115, 227, 96, 46, 46, 25, 176, 239,
-            175, 183, 23, 45, 26, 227, 99, 8, 21, 143, 250, 80, 199, 16, 162, 236, 91, 128, 210,
-            157, 212, 221, 78, 39, 208, 191, 77, 41, 249, 135, 131, 80, 44, 113, 6, 134, 86, 217,
-            97, 86, 107, 105, 141, 17, 67, 11, 213, 141, 174, 201, 153, 1, 128, 8, 20, 128, 121,
-            239, 207, 121, 248, 24, 24, 79, 128, 101, 99, 170, 236, 35, 242, 8, 26, 53, 205, 203,
-            35, 6, 209, 83, 124, 183, 65, 220, 45, 5, 176, 93, 128, 160, 39, 252, 20, 7, 150, 144,
-            36, 36, 69, 21, 58, 184, 195, 145, 237, 144, 77, 229, 77, 17, 111, 74, 99, 143, 66, 84,
-            9, 190, 96, 87, 80, 128, 117, 157, 214, 35, 127, 97, 9, 161, 44, 239, 163, 244, 116,
-            155, 38, 126, 170, 140, 233, 209, 248, 118, 235, 44, 34, 82, 67, 243, 205, 230, 18, 99,
-            153, 1, 128, 8, 24, 128, 164, 116, 165, 145, 179, 109, 56, 89, 156, 37, 82, 152, 42,
-            30, 244, 100, 238, 124, 81, 234, 182, 237, 121, 112, 81, 224, 32, 26, 197, 9, 116, 235,
-            128, 26, 69, 235, 233, 130, 114, 8, 90, 210, 116, 5, 1, 149, 130, 244, 196, 140, 198,
-            39, 100, 21, 129, 164, 126, 78, 29, 73, 118, 229, 145, 125, 46, 128, 9, 112, 60, 180,
-            26, 244, 117, 95, 190, 115, 59, 220, 224, 86, 172, 66, 188, 157, 112, 31, 214, 34, 119,
-            38, 223, 82, 131, 179, 169, 63, 230, 51, 21, 1, 128, 8, 32, 128, 106, 213, 108, 221,
-            235, 169, 210, 224, 45, 158, 174, 49, 151, 192, 5, 169, 121, 216, 60, 185, 163, 124,
-            146, 193, 185, 50, 20, 224, 33, 254, 180, 30, 128, 54, 103, 254, 236, 254, 60, 140,
-            166, 9, 11, 121, 120, 126, 52, 214, 104, 47, 217, 236, 101, 6, 63, 54, 250, 49, 243,
-            36, 189, 142, 191, 50, 77, 21, 1, 128, 8, 32, 128, 166, 248, 120, 131, 147, 66, 24,
-            156, 249, 70, 156, 164, 82, 79, 22, 56, 173, 211, 154, 64, 12, 65, 102, 57, 200, 185,
-            24, 188, 110, 23, 218, 80, 128, 246, 77, 152, 16, 99, 105, 211, 208, 170, 230, 28, 192,
-            109, 240, 98, 86, 77, 107, 115, 121, 214, 169, 1, 222, 57, 171, 117, 189, 145, 244, 88,
-            109, 21, 1, 128, 8, 128, 128, 61, 134, 77, 72, 175, 71, 130, 78, 38, 143, 86, 70, 12,
-            158, 178, 6, 58, 192, 83, 51, 243, 106, 24, 215, 230, 31, 225, 35, 80, 121, 122, 99,
-            128, 98, 75, 24, 173, 58, 140, 184, 149, 251, 58, 100, 70, 84, 150, 99, 159, 157, 94,
-            129, 87, 103, 81, 183, 179, 21, 226, 152, 166, 224, 122, 164, 0, 153, 1, 128, 8, 136,
-            128, 1, 133, 197, 204, 54, 88, 246, 134, 79, 81, 210, 83, 103, 236, 10, 208, 146, 56,
-            156, 42, 255, 141, 40, 14, 148, 34, 206, 217, 24, 43, 9, 33, 128, 56, 26, 155, 50, 21,
-            127, 229, 37, 105, 75, 244, 23, 53, 77, 39, 7, 30, 57, 212, 184, 59, 207, 198, 228,
-            174, 160, 150, 113, 231, 73, 107, 88, 128, 167, 254, 145, 105, 195, 215, 183, 221, 157,
-            13, 74, 34, 46, 190, 187, 21, 187, 147, 138, 119, 213, 170, 207, 79, 15, 157, 5, 91,
-            35, 41, 142, 204, 153, 1, 128, 8, 136, 128, 118, 61, 155, 200, 23, 166, 124, 184, 81,
-            77, 110, 50, 89, 185, 75, 14, 50, 16, 22, 249, 17, 246, 60, 170, 8, 96, 77, 141, 116,
-            215, 27, 81, 128, 33, 43, 230, 91, 108, 168, 18, 169, 144, 75, 112, 146, 147, 205, 116,
-            151, 252, 22, 180, 96, 31, 120, 18, 76, 203, 129, 140, 207, 243, 52, 249, 195, 128, 65,
-            198, 65, 64, 222, 63, 132, 177, 210, 60, 13, 29, 250, 3, 85, 144, 81, 164, 231, 195,
-            36, 15, 142, 223, 199, 138, 249, 185, 86, 189, 187, 246, 21, 1, 128, 9, 0, 128, 40, 71,
-            240, 20, 152, 158, 92, 130, 134, 246, 69, 4, 108, 143, 184, 113, 195, 74, 205, 88, 97,
-            206, 175, 65, 171, 220, 127, 225, 44, 226, 17, 192, 128, 152, 173, 56, 234, 248, 113,
-            248, 197, 27, 230, 174, 225, 68, 36, 30, 105, 212, 134, 128, 6, 151, 191, 77, 65, 166,
-            30, 135, 117, 67, 218, 198, 31, 21, 1, 128, 9, 0, 128, 196, 207, 11, 28, 182, 148, 126,
-            68, 115, 137, 171, 0, 63, 229, 49, 20, 255, 147, 57, 18, 222, 17, 211, 37, 54, 205,
-            176, 224, 120, 50, 140, 84, 128, 118, 60, 139, 203, 89, 246, 240, 147, 241, 237, 220,
-            106, 94, 218, 94, 213, 192, 130, 50, 98, 191, 76, 50, 198, 242, 71, 95, 148, 75, 111,
-            2, 146, 21, 1, 128, 9, 0, 128, 251, 146, 140, 240, 112, 66, 64, 60, 33, 53, 175, 248,
-            137, 193, 55, 207, 129, 212, 10, 28, 77, 0, 218, 149, 131, 103, 106, 197, 99, 17, 151,
-            7, 128, 36, 237, 198, 105, 60, 172, 84, 166, 51, 70, 61, 165, 51, 146, 43, 187, 56, 77,
-            226, 181, 227, 11, 250, 233, 41, 74, 92, 57, 239, 75, 106, 43, 29, 2, 128, 9, 12, 128,
-            187, 26, 188, 121, 21, 128, 237, 250, 182, 234, 183, 64, 209, 69, 101, 164, 40, 123,
-            30, 227, 161, 52, 47, 90, 90, 62, 55, 61, 239, 192, 96, 190, 128, 47, 154, 60, 133, 43,
-            228, 77, 134, 102, 81, 83, 92, 101, 25, 2, 25, 75, 45, 247, 135, 181, 21, 204, 38, 4,
-            212, 211, 81, 128, 41, 189, 2, 128, 91, 73, 197, 237, 65, 220, 41, 162, 99, 4, 30, 80,
-            4, 53, 195, 19, 250, 177, 1, 96, 230, 126, 150, 46, 143, 253, 214, 94, 138, 37, 199,
-            200, 128, 118, 225, 93, 149, 196, 19, 227, 227, 102, 248, 99, 7, 60, 130, 79, 233, 158,
-            84, 202, 138, 163, 237, 146, 181, 184, 161, 12, 201, 251, 119, 120, 138, 29, 2, 128, 9,
-            34, 128, 122, 205, 185, 216, 117, 184, 223, 19, 89, 250, 73, 47, 124, 80, 80, 181, 22,
-            82, 12, 151, 20, 167, 140, 25, 79, 201, 199, 137, 167, 43, 59, 114, 128, 225, 176, 222,
-            40, 140, 122, 164, 240, 191, 193, 238, 126, 230, 154, 76, 219, 235, 217, 74, 54, 212,
-            178, 56, 251, 178, 99, 52, 210, 147, 219, 172, 198, 128, 169, 223, 155, 252, 136, 6,
-            72, 137, 208, 51, 22, 47, 145, 16, 86, 162, 91, 234, 235, 85, 114, 59, 76, 82, 166,
-            203, 226, 23, 208, 34, 139, 247, 128, 103, 93, 175, 0, 75, 24, 109, 226, 149, 253, 158,
-            221, 213, 60, 153, 151, 4, 72, 82, 147, 163, 129, 193, 178, 112, 225, 200, 130, 193,
-            226, 62, 168, 29, 2, 128, 9, 80, 128, 82, 248, 188, 114, 113, 220, 134, 251, 58, 126,
-            74, 147, 170, 153, 245, 245, 241, 175, 175, 159, 242, 27, 142, 102, 225, 125, 202, 206,
-            34, 77, 45, 21, 128, 203, 245, 70, 149, 205, 148, 20, 105, 203, 61, 234, 56, 97, 130,
-            160, 21, 130, 49, 24, 18, 168, 218, 65, 54, 182, 188, 172, 231, 4, 124, 179, 78, 128,
-            72, 55, 239, 103, 21, 70, 29, 43, 92, 8, 79, 52, 108, 182, 156, 195, 126, 192, 200,
-            101, 87, 161, 91, 106, 65, 146, 132, 19, 15, 206, 67, 26, 128, 175, 67, 76, 20, 148,
-            130, 76, 243, 100, 125, 132, 32, 137, 246, 169, 16, 183, 131, 51, 6, 131, 118, 149,
-            148, 91, 42, 60, 124, 166, 162, 159, 246, 153, 1, 128, 9, 128, 128, 222, 100, 45, 208,
-            183, 218, 251, 179, 51, 102, 199, 1, 244, 179, 45, 133, 163, 131, 159, 155, 34, 25, 90,
-            155, 247, 107, 23, 238, 231, 89, 154, 68, 128, 237, 230, 139, 217, 60, 119, 75, 17,
-            248, 235, 128, 93, 35, 203, 216, 10, 51, 5, 123, 247, 96, 105, 87, 34, 218, 205, 225,
-            66, 222, 145, 1, 64, 128, 38, 238, 218, 75, 219, 186, 116, 210, 140, 58, 203, 111, 2,
-            51, 26, 220, 34, 191, 99, 90, 118, 13, 158, 118, 53, 171, 29, 43, 240, 107, 171, 174,
-            21, 1, 128, 10, 0, 128, 144, 214, 176, 156, 175, 233, 30, 95, 217, 86, 104, 10, 105,
-            200, 57, 201, 192, 200, 3, 243, 225, 96, 2, 99, 36, 103, 172, 4, 98, 57, 195, 38, 128,
-            3, 141, 51, 77, 6, 60, 168, 109, 8, 189, 183, 146, 79, 66, 56, 31, 192, 64, 115, 6, 56,
-            199, 2, 210, 233, 15, 170, 130, 162, 126, 80, 1, 29, 2, 128, 10, 160, 128, 79, 139,
-            199, 50, 81, 101, 28, 166, 154, 176, 171, 0, 145, 221, 200, 73, 165, 38, 162, 31, 204,
-            187, 191, 116, 5, 15, 207, 55, 180, 37, 62, 60, 128, 99, 253, 250, 231, 77, 221, 174,
-            216, 149, 213, 239, 187, 40, 246, 163, 124, 99, 133, 62, 8, 129, 218, 226, 185, 21, 5,
-            146, 94, 17, 36, 119, 83, 128, 33, 202, 229, 16, 159, 250, 53, 191, 119, 230, 234, 70,
-            45, 198, 224, 146, 222, 29, 197, 98, 105, 181, 44, 85, 164, 113, 64, 243, 125, 222,
-            140, 216, 128, 245, 192, 212, 235, 190, 198, 60, 71, 180, 12, 243, 105, 105, 56, 73,
-            232, 11, 208, 155, 177, 130, 46, 119, 34, 123, 43, 25, 235, 121, 72, 98, 18, 161, 2,
-            128, 11, 66, 128, 68, 208, 153, 216, 120, 10, 47, 206, 208, 81, 223, 191, 140, 146, 54,
-            251, 104, 84, 47, 205, 247, 134, 173, 239, 63, 40, 89, 226, 246, 179, 162, 103, 128,
-            49, 122, 8, 197, 197, 134, 127, 105, 148, 80, 73, 148, 158, 131, 187, 254, 22, 73, 15,
-            13, 241, 217, 163, 150, 171, 195, 22, 177, 67, 214, 50, 152, 128, 156, 255, 104, 214,
-            72, 105, 154, 11, 59, 7, 216, 163, 203, 53, 77, 224, 209, 18, 230, 59, 47, 125, 14, 86,
-            167, 84, 206, 39, 88, 124, 30, 120, 128, 204, 133, 68, 233, 188, 243, 5, 243, 115, 83,
-            228, 44, 118, 130, 103, 17, 95, 255, 93, 232, 65, 226, 112, 180, 236, 220, 239, 167, 9,
-            179, 81, 208, 128, 160, 132, 21, 235, 7, 116, 147, 3, 80, 201, 40, 159, 35, 21, 246,
-            175, 71, 117, 38, 114, 171, 242, 7, 152, 73, 252, 230, 176, 163, 66, 10, 41, 21, 1,
-            128, 12, 0, 128, 102, 42, 182, 65, 39, 49, 198, 76, 210, 39, 207, 127, 21, 74, 108,
-            158, 106, 25, 11, 61, 17, 106, 121, 156, 205, 57, 30, 233, 28, 177, 6, 108, 128, 71,
-            207, 110, 153, 124, 15, 176, 125, 191, 124, 16, 253, 209, 54, 86, 58, 100, 91, 176, 77,
-            6, 82, 45, 162, 225, 61, 174, 144, 137, 58, 233, 103, 153, 1, 128, 12, 2, 128, 238,
-            137, 237, 115, 49, 164, 218, 12, 210, 155, 191, 14, 64, 100, 28, 89, 68, 22, 41, 5, 36,
-            244, 195, 253, 122, 3, 45, 20, 174, 230, 130, 168, 128, 67, 211, 156, 230, 184, 3, 172,
-            115, 156, 201, 25, 198, 245, 134, 141, 46, 176, 144, 168, 93, 204, 208, 84, 103, 232,
-            127, 128, 119, 85, 73, 242, 132, 128, 245, 172, 7, 230, 107, 246, 103, 139, 204, 44,
-            209, 95, 107, 145, 71, 96, 179, 166, 58, 114, 240, 232, 168, 7, 13, 226, 92, 2, 58, 9,
-            225, 1, 29, 2, 128, 12, 5, 128, 84, 200, 11, 192, 95, 88, 110, 51, 221, 222, 153, 60,
-            150, 144, 33, 16, 98, 160, 240, 216, 30, 170, 78, 242, 120, 165, 188, 76, 89, 25, 0,
-            32, 128, 151, 236, 76, 53, 60, 241, 156, 247, 50, 200, 52, 232, 95, 25, 22, 136, 197,
-            122, 140, 19, 107, 252, 193, 188, 181, 58, 178, 182, 63, 206, 223, 126, 128, 139, 27,
-            80, 18, 39, 196, 97, 248, 66, 228, 23, 170, 164, 213, 161, 98, 253, 1, 182, 43, 223,
-            163, 79, 81, 168, 196, 9, 227, 110, 45, 225, 80, 128, 18, 213, 212, 40, 110, 57, 222,
-            111, 198, 15, 108, 48, 89, 209, 85, 39, 14, 91, 165, 177, 241, 85, 8, 84, 62, 235, 199,
-            35, 163, 189, 26, 219, 153, 1, 128, 12, 32, 128, 84, 169, 41, 29, 162, 47, 54, 59, 94,
-            146, 73, 108, 112, 100, 119, 166, 243, 228, 188, 87, 50, 200, 51, 32, 32, 225, 174, 84,
-            10, 157, 150, 166, 128, 45, 61, 190, 158, 184, 115, 220, 195, 120, 154, 2, 111, 224,
-            72, 97, 154, 240, 39, 42, 164, 152, 229, 105, 30, 223, 37, 0, 171, 149, 9, 127, 182,
-            128, 192, 188, 101, 87, 202, 179, 212, 140, 200, 248, 191, 144, 181, 250, 188, 35, 233,
-            120, 124, 118, 130, 35, 45, 13, 174, 118, 13, 146, 122, 59, 145, 128, 21, 1, 128, 16,
-            4, 128, 15, 144, 166, 42, 5, 138, 144, 209, 170, 83, 95, 17, 45, 250, 214, 176, 58, 30,
-            64, 186, 147, 7, 40, 210, 214, 213, 154, 245, 170, 201, 67, 175, 128, 141, 15, 218, 26,
-            78, 55, 155, 251, 86, 130, 23, 85, 194, 135, 83, 5, 114, 193, 88, 210, 127, 152, 101,
-            177, 230, 116, 69, 150, 117, 91, 74, 35, 21, 1, 128, 16, 4, 128, 66, 77, 175, 112, 251,
-            211, 177, 238, 155, 24, 116, 53, 175, 53, 131, 220, 120, 158, 248, 8, 168, 125, 15, 42,
-            1, 80, 110, 155, 127, 51, 122, 85, 128, 146, 245, 126, 22, 223, 10, 148, 207, 144, 18,
-            229, 15, 133, 120, 72, 212, 238, 47, 45, 31, 83, 73, 79, 10, 91, 210, 0, 56, 146, 205,
-            37, 227, 21, 1, 128, 16, 4, 128, 133, 64, 65, 54, 62, 70, 245, 155, 97, 197, 88, 238,
-            136, 63, 45, 3, 134, 182, 185, 161, 65, 114, 13, 85, 122, 244, 81, 131, 182, 224, 58,
-            30, 128, 169, 82, 38, 159, 170, 53, 105, 119, 137, 115, 113, 85, 139, 7, 94, 193, 141,
-            45, 25, 207, 41, 244, 51, 86, 184, 185, 133, 56, 214, 39, 103, 74, 21, 1, 128, 16, 4,
-            128, 205, 110, 50, 252, 200, 21, 126, 83, 3, 224, 242, 123, 13, 96, 20, 73, 208, 207,
-            101, 65, 101, 227, 133, 152, 75, 154, 27, 13, 186, 68, 135, 170, 128, 165, 83, 156,
-            245, 87, 130, 128, 119, 25, 140, 125, 156, 161, 82, 127, 9, 76, 9, 22, 122, 232, 41,
-            176, 12, 58, 108, 129, 15, 221, 63, 243, 104, 21, 1, 128, 16, 8, 128, 157, 138, 224,
-            106, 211, 220, 100, 217, 64, 87, 198, 192, 12, 162, 7, 157, 143, 189, 49, 109, 9, 111,
-            187, 179, 119, 10, 254, 62, 126, 107, 49, 195, 128, 103, 60, 176, 147, 117, 30, 74,
-            228, 217, 27, 2, 49, 1, 56, 108, 247, 190, 76, 82, 244, 216, 77, 158, 118, 199, 151,
-            199, 213, 203, 40, 138, 0, 21, 1, 128, 16, 16, 128, 89, 44, 185, 184, 156, 130, 98, 35,
-            79, 112, 112, 113, 251, 255, 151, 166, 158, 68, 212, 228, 116, 76, 59, 133, 253, 51,
-            10, 46, 99, 61, 194, 24, 128, 211, 110, 218, 22, 198, 45, 120, 66, 172, 53, 185, 35,
-            74, 227, 61, 48, 60, 22, 121, 97, 83, 115, 16, 216, 143, 220, 96, 121, 39, 42, 49, 188,
-            21, 1, 128, 16, 16, 128, 94, 127, 179, 50, 229, 176, 183, 170, 191, 74, 58, 38, 16,
-            120, 86, 221, 67, 2, 74, 98, 90, 190, 104, 223, 94, 46, 114, 123, 32, 170, 62, 196,
-            128, 72, 44, 226, 94, 234, 23, 28, 117, 14, 116, 19, 220, 19, 147, 208, 43, 222, 61,
-            141, 58, 185, 22, 232, 35, 105, 71, 197, 109, 215, 120, 93, 212, 153, 1, 128, 16, 24,
-            128, 160, 122, 156, 155, 164, 27, 253, 182, 105, 98, 68, 142, 40, 134, 117, 137, 108,
-            94, 116, 38, 85, 4, 75, 63, 140, 1, 185, 191, 206, 116, 145, 71, 128, 102, 106, 102,
-            163, 122, 93, 133, 204, 235, 189, 233, 182, 23, 100, 20, 26, 183, 64, 102, 223, 120,
-            100, 33, 64, 141, 53, 76, 50, 152, 186, 245, 174, 128, 234, 126, 53, 17, 5, 47, 80,
-            178, 219, 88, 236, 169, 216, 59, 155, 140, 58, 19, 252, 181, 179, 205, 177, 96, 174,
-            168, 32, 250, 84, 212, 112, 162, 21, 1, 128, 16, 32, 128, 21, 20, 220, 145, 208, 120,
-            7, 210, 103, 176, 117, 63, 121, 4, 19, 37, 106, 240, 74, 91, 206, 147, 107, 115, 171,
-            206, 198, 195, 50, 50, 99, 99, 128, 56, 180, 68, 96, 159, 234, 83, 177, 6, 235, 198,
-            16, 85, 54, 46, 225, 212, 226, 121, 204, 90, 238, 161, 194, 229, 202, 195, 217, 171,
-            42, 156, 140, 21, 1, 128, 16, 32, 128, 209, 124, 186, 243, 148, 29, 142, 132, 27, 109,
-            89, 77, 68, 254, 122, 4, 68, 245, 96, 46, 222, 96, 59, 13, 61, 90, 218, 41, 64, 99, 68,
-            85, 128, 104, 215, 18, 97, 98, 158, 9, 143, 143, 161, 24, 133, 89, 124, 35, 234, 106,
-            82, 58, 72, 139, 212, 123, 234, 163, 9, 232, 16, 95, 189, 139, 175, 153, 1, 128, 16,
-            36, 128, 94, 142, 71, 99, 255, 254, 249, 13, 215, 45, 105, 167, 13, 209, 171, 32, 199,
-            249, 218, 182, 72, 144, 74, 86, 156, 145, 4, 209, 155, 29, 209, 135, 128, 192, 0, 62,
-            110, 71, 122, 33, 138, 90, 46, 178, 125, 197, 66, 203, 15, 26, 239, 101, 58, 101, 172,
-            144, 51, 93, 81, 204, 171, 179, 224, 113, 10, 128, 249, 238, 161, 30, 32, 186, 195,
-            145, 212, 95, 127, 23, 147, 40, 185, 146, 175, 155, 120, 108, 49, 111, 24, 84, 246,
-            111, 164, 240, 152, 25, 121, 208, 161, 2, 128, 16, 90, 128, 179, 21, 155, 7, 87, 148,
-            17, 189, 38, 236, 224, 239, 67, 97, 28, 251, 85, 97, 32, 105, 20, 240, 35, 248, 199,
-            237, 3, 140, 224, 234, 225, 128, 128, 26, 124, 25, 199, 68, 40, 4, 48, 95, 173, 208,
-            45, 171, 112, 9, 242, 81, 121, 18, 237, 238, 167, 179, 30, 95, 55, 227, 22, 61, 191,
-            55, 46, 128, 127, 145, 197, 201, 186, 71, 215, 210, 223, 235, 23, 253, 159, 0, 120,
-            233, 188, 63, 209, 161, 249, 154, 103, 59, 106, 206, 252, 213, 100, 41, 39, 18, 128,
-            30, 155, 41, 205, 39, 68, 216, 10, 152, 80, 32, 70, 69, 254, 209, 161, 241, 130, 206,
-            128, 220, 214, 140, 139, 74, 158, 2, 211, 230, 164, 196, 156, 128, 57, 51, 17, 71, 50,
-            135, 231, 91, 131, 225, 236, 164, 168, 95, 218, 119, 81, 110, 170, 103, 176, 221, 41,
-            206, 30, 54, 80, 150, 23, 8, 171, 151, 21, 1, 128, 16, 128, 128, 103, 157, 166, 42, 91,
-            83, 172, 237, 138, 205, 47, 223, 39, 207, 85, 122, 73, 52, 145, 250, 229, 208, 129, 7,
-            37, 72, 115, 62, 187, 99, 33, 90, 128, 191, 134, 206, 238, 236, 106, 237, 200, 46, 86,
-            166, 96, 85, 102, 237, 32, 46, 92, 18, 167, 8, 92, 127, 144, 87, 63, 190, 138, 111, 88,
-            227, 121, 21, 1, 128, 17, 0, 128, 27, 245, 25, 187, 146, 44, 141, 168, 233, 24, 160,
-            205, 169, 135, 250, 129, 184, 49, 225, 77, 144, 71, 136, 94, 248, 56, 179, 237, 174,
-            120, 23, 217, 128, 88, 213, 98, 110, 116, 217, 180, 87, 126, 140, 169, 204, 50, 210,
-            238, 152, 97, 107, 212, 177, 176, 170, 253, 96, 149, 130, 131, 180, 174, 36, 219, 20,
-            29, 2, 128, 17, 3, 128, 65, 80, 156, 114, 24, 181, 252, 147, 40, 221, 145, 20, 245, 71,
-            4, 109, 30, 54, 204, 234, 115, 236, 188, 39, 75, 29, 42, 10, 166, 37, 174, 176, 128,
-            25, 15, 234, 120, 52, 94, 129, 196, 82, 128, 238, 44, 185, 184, 11, 252, 5, 8, 1, 199,
-            2, 136, 122, 137, 122, 223, 173, 117, 53, 172, 172, 30, 128, 208, 169, 49, 67, 77, 103,
-            37, 139, 237, 250, 104, 55, 246, 95, 40, 195, 116, 87, 237, 24, 50, 147, 38, 175, 238,
-            21, 45, 129, 32, 38, 116, 254, 128, 130, 168, 146, 189, 167, 223, 67, 200, 79, 62, 58,
-            30, 131, 28, 18, 28, 22, 194, 160, 5, 250, 85, 15, 123, 86, 59, 59, 88, 200, 130, 174,
-            69, 153, 1, 128, 17, 32, 128, 133, 158, 172, 201, 1, 133, 92, 92, 72, 123, 79, 18, 236,
-            10, 80, 88, 168, 71, 104, 36, 91, 249, 208, 124, 48, 78, 160, 232, 230, 226, 188, 92,
-            128, 60, 180, 65, 111, 126, 158, 180, 197, 76, 146, 39, 181, 133, 161, 205, 157, 162,
-            215, 155, 75, 27, 24, 72, 225, 148, 29, 90, 201, 30, 45, 17, 71, 128, 193, 64, 135, 72,
-            0, 139, 247, 86, 20, 87, 188, 169, 52, 17, 105, 118, 4, 154, 49, 31, 84, 73, 193, 150,
-            101, 208, 204, 224, 222, 14, 229, 92, 37, 3, 128, 17, 226, 128, 81, 178, 105, 9, 244,
-            212, 140, 228, 86, 192, 38, 225, 254, 77, 205, 185, 207, 179, 199, 76, 27, 38, 36, 108,
-            170, 50, 160, 252, 76, 214, 68, 184, 128, 183, 18, 165, 5, 105, 176, 118, 244, 204,
-            153, 124, 102, 67, 254, 112, 139, 169, 67, 246, 30, 161, 79, 111, 117, 150, 205, 185,
-            254, 188, 252, 197, 172, 128, 43, 28, 120, 184, 188, 134, 78, 142, 106, 7, 123, 148,
-            108, 13, 191, 100, 56, 171, 218, 218, 153, 123, 83, 54, 243, 23, 168, 142, 172, 90,
-            120, 88, 128, 159, 242, 105, 14, 70, 161, 58, 51, 216, 19, 219, 67, 32, 63, 132, 92,
-            178, 109, 222, 122, 187, 206, 206, 121, 210, 113, 168, 108, 52, 61, 183, 8, 128, 243,
-            217, 93, 243, 21, 184, 191, 185, 212, 137, 46, 175, 29, 49, 230, 112, 21, 46, 98, 95,
-            81, 47, 121, 207, 201, 105, 93, 4, 10, 156, 40, 229, 128, 96, 151, 140, 68, 249, 194,
-            13, 36, 168, 165, 176, 54, 10, 136, 231, 20, 231, 220, 40, 236, 242, 63, 215, 78, 2,
-            252, 241, 182, 213, 101, 89, 41, 21, 1, 128, 18, 0, 128, 94, 158, 118, 126, 14, 8, 53,
-            253, 139, 136, 93, 55, 7, 72, 182, 151, 30, 6, 133, 176, 31, 149, 132, 217, 249, 249,
-            196, 220, 99, 202, 187, 122, 128, 214, 69, 158, 94, 245, 86, 203, 139, 244, 178, 120,
-            53, 192, 222, 241, 73, 122, 107, 174, 75, 90, 215, 169, 222, 47, 212, 120, 160, 171,
-            127, 90, 72, 21, 1, 128, 18, 0, 128, 154, 82, 100, 75, 100, 166, 245, 236, 185, 241,
-            28, 2, 118, 2, 148, 192, 91, 79, 50, 220, 156, 32, 154, 115, 112, 82, 85, 189, 195, 99,
-            207, 1, 128, 158, 15, 24, 44, 6, 133, 134, 157, 61, 37, 100, 174, 226, 56, 184, 166,
-            140, 212, 144, 64, 84, 139, 17, 172, 95, 23, 10, 25, 154, 188, 134, 159, 153, 1, 128,
-            18, 4, 128, 244, 123, 248, 108, 73, 116, 42, 160, 248, 183, 10, 56, 182, 193, 46, 63,
-            242, 86, 30, 20, 38, 139, 135, 234, 150, 76, 67, 38, 7, 2, 152, 105, 128, 79, 151, 160,
-            220, 25, 91, 248, 197, 210, 90, 185, 101, 200, 23, 82, 228, 155, 101, 80, 80, 150, 227,
-            203, 56, 95, 184, 173, 94, 130, 4, 246, 138, 128, 15, 234, 147, 28, 12, 152, 145, 130,
-            204, 71, 233, 26, 0, 34, 69, 72, 119, 161, 20, 35, 11, 149, 167, 184, 172, 77, 174,
-            238, 234, 56, 165, 13, 161, 2, 128, 18, 26, 128, 140, 29, 10, 195, 174, 248, 16, 230,
-            61, 126, 168, 67, 163, 152, 125, 128, 174, 4, 99, 185, 153, 59, 27, 96, 125, 28, 8,
-            135, 117, 21, 155, 230, 128, 127, 64, 246, 106, 192, 156, 233, 168, 86, 30, 195, 181,
-            193, 80, 28, 34, 131, 33, 187, 247, 231, 103, 53, 15, 5, 254, 233, 75, 97, 8, 105, 61,
-            128, 222, 156, 253, 223, 128, 105, 169, 227, 224, 43, 149, 231, 151, 229, 1, 184, 110,
-            77, 123, 158, 17, 178, 129, 48, 24, 34, 37, 189, 20, 27, 88, 90, 128, 169, 39, 34, 230,
-            141, 3, 154, 118, 53, 181, 88, 112, 170, 45, 193, 36, 80, 168, 248, 166, 8, 81, 144,
-            22, 156, 183, 73, 222, 144, 162, 122, 216, 128, 192, 68, 126, 3, 87, 18, 69, 143, 122,
-            174, 49, 122, 96, 87, 64, 61, 152, 231, 42, 50, 168, 127, 228, 143, 82, 59, 60, 115,
-            92, 142, 105, 221, 153, 1, 128, 20, 2, 128, 56, 42, 69, 209, 94, 61, 53, 184, 74, 118,
-            181, 75, 160, 227, 177, 83, 132, 4, 107, 11, 78, 120, 26, 6, 116, 38, 34, 6, 38, 0,
-            192, 249, 128, 41, 23, 119, 57, 212, 188, 64, 252, 29, 182, 93, 92, 117, 59, 102, 242,
-            71, 68, 218, 109, 24, 4, 173, 29, 40, 63, 169, 238, 53, 27, 2, 228, 128, 153, 94, 101,
-            138, 143, 243, 68, 3, 215, 162, 208, 225, 143, 20, 100, 58, 132, 102, 6, 29, 61, 81,
-            230, 78, 184, 153, 88, 186, 62, 107, 26, 14, 37, 3, 128, 20, 46, 128, 92, 242, 65, 167,
-            30, 93, 113, 31, 25, 6, 158, 94, 144, 14, 195, 58, 58, 53, 235, 192, 27, 16, 79, 35,
-            166, 159, 69, 66, 215, 195, 190, 219, 128, 171, 177, 93, 217, 133, 44, 2, 75, 105, 116,
-            192, 140, 224, 136, 91, 58, 247, 207, 23, 47, 51, 36, 232, 130, 4, 214, 192, 42, 209,
-            117, 197, 181, 128, 197, 152, 248, 92, 245, 140, 1, 246, 93, 136, 22, 28, 114, 134, 7,
-            229, 168, 29, 230, 177, 54, 164, 96, 163, 7, 208, 31, 118, 238, 236, 47, 138, 128, 231,
-            141, 255, 213, 96, 250, 138, 150, 205, 68, 33, 51, 170, 171, 157, 191, 46, 146, 9, 33,
-            37, 234, 124, 126, 183, 151, 186, 63, 144, 120, 32, 69, 128, 207, 40, 26, 121, 185, 89,
-            35, 37, 60, 187, 250, 189, 179, 111, 189, 202, 112, 41, 219, 26, 106, 78, 215, 70, 165,
-            96, 108, 165, 18, 72, 248, 202, 128, 242, 175, 174, 178, 154, 237, 217, 222, 177, 225,
-            241, 183, 174, 42, 126, 42, 111, 58, 179, 122, 12, 23, 186, 88, 182, 42, 17, 81, 120,
-            232, 62, 38, 29, 2, 128, 20, 72, 128, 134, 168, 112, 246, 147, 124, 19, 51, 157, 186,
-            86, 222, 138, 39, 164, 3, 215, 245, 69, 245, 243, 78, 38, 88, 85, 151, 174, 178, 123,
-            158, 228, 121, 128, 189, 80, 0, 134, 178, 36, 91, 184, 110, 54, 159, 11, 194, 90, 136,
-            113, 161, 171, 237, 240, 198, 21, 241, 86, 196, 203, 84, 123, 95, 235, 97, 184, 128,
-            94, 5, 139, 41, 28, 125, 131, 77, 142, 248, 107, 68, 125, 98, 15, 109, 144, 195, 191,
-            249, 80, 57, 248, 210, 63, 24, 161, 59, 181, 128, 184, 72, 128, 3, 211, 75, 208, 48,
-            76, 11, 82, 254, 209, 16, 69, 55, 128, 243, 255, 134, 232, 166, 172, 144, 7, 0, 38,
-            119, 50, 201, 150, 3, 167, 76, 48, 29, 2, 128, 20, 129, 128, 150, 255, 72, 226, 241,
-            59, 74, 231, 31, 124, 254, 198, 42, 131, 205, 137, 83, 152, 17, 130, 161, 44, 172, 47,
-            206, 130, 46, 227, 82, 87, 43, 107, 128, 21, 4, 91, 180, 164, 143, 198, 152, 232, 67,
-            162, 72, 5, 203, 116, 101, 34, 62, 126, 29, 129, 153, 178, 23, 106, 41, 202, 43, 193,
-            79, 36, 61, 128, 249, 23, 152, 16, 223, 41, 88, 14, 128, 32, 236, 184, 102, 214, 228,
-            172, 145, 96, 244, 190, 64, 10, 211, 199, 136, 230, 79, 120, 120, 146, 50, 163, 128,
-            220, 227, 112, 67, 111, 110, 158, 87, 37, 213, 100, 117, 219, 62, 196, 123, 186, 17,
-            39, 71, 83, 24, 190, 237, 102, 206, 56, 202, 189, 63, 182, 216, 21, 1, 128, 24, 0, 128,
-            13, 197, 150, 232, 188, 35, 180, 94, 128, 169, 80, 7, 89, 135, 20, 112, 129, 72, 63,
-            231, 231, 146, 9, 197, 225, 141, 160, 109, 53, 67, 160, 221, 128, 222, 82, 62, 142,
-            112, 205, 31, 10, 185, 15, 185, 145, 130, 13, 54, 52, 226, 30, 207, 220, 10, 100, 29,
-            97, 235, 243, 142, 193, 161, 155, 5, 10, 21, 1, 128, 24, 0, 128, 149, 14, 30, 212, 28,
-            157, 55, 151, 8, 133, 170, 51, 64, 234, 212, 235, 112, 62, 223, 248, 173, 158, 28, 243,
-            184, 109, 128, 60, 71, 51, 212, 199, 128, 215, 152, 143, 191, 41, 166, 163, 65, 144,
-            54, 79, 71, 160, 159, 215, 196, 215, 41, 95, 79, 132, 252, 87, 20, 34, 245, 245, 177,
-            8, 72, 204, 27, 153, 1, 128, 24, 4, 128, 191, 251, 4, 202, 204, 205, 142, 22, 237, 93,
-            242, 15, 154, 1, 96, 120, 214, 12, 223, 87, 112, 251, 129, 106, 134, 117, 219, 215, 26,
-            70, 240, 49, 128, 53, 210, 10, 196, 5, 16, 105, 77, 226, 162, 211, 69, 90, 38, 206,
-            116, 203, 88, 221, 182, 141, 157, 5, 237, 246, 250, 21, 35, 133, 218, 15, 154, 128,
-            154, 212, 69, 85, 24, 162, 194, 163, 228, 112, 102, 80, 144, 148, 18, 142, 146, 187,
-            161, 248, 173, 8, 123, 224, 212, 192, 2, 98, 163, 157, 127, 100, 161, 2, 128, 24, 21,
-            128, 128, 254, 7, 142, 15, 142, 234, 159, 98, 171, 119, 5, 218, 140, 17, 206, 84, 172,
-            69, 50, 12, 208, 4, 149, 244, 233, 238, 30, 216, 8, 197, 230, 128, 144, 101, 239, 210,
-            10, 14, 138, 40, 238, 221, 161, 134, 27, 176, 152, 6, 223, 124, 153, 144, 241, 146,
-            140, 60, 87, 227, 192, 189, 22, 26, 28, 189, 128, 42, 90, 18, 243, 219, 156, 140, 156,
-            49, 181, 181, 68, 224, 20, 96, 250, 11, 33, 87, 206, 115, 37, 73, 10, 250, 237, 89,
-            252, 251, 203, 177, 241, 128, 147, 211, 132, 15, 235, 91, 61, 211, 118, 142, 51, 95,
-            93, 249, 220, 202, 111, 66, 29, 63, 84, 29, 59, 200, 168, 169, 191, 153, 76, 27, 38,
-            125, 128, 113, 193, 219, 182, 55, 211, 135, 116, 59, 199, 10, 229, 113, 250, 30, 52,
-            250, 240, 89, 103, 202, 209, 141, 219, 7, 206, 39, 0, 9, 33, 93, 46, 29, 2, 128, 24,
-            129, 128, 234, 179, 133, 182, 63, 96, 44, 103, 224, 4, 163, 1, 163, 246, 169, 43, 62,
-            200, 127, 186, 133, 61, 127, 90, 2, 133, 109, 249, 228, 105, 241, 91, 128, 210, 56,
-            237, 113, 78, 200, 136, 18, 50, 105, 221, 34, 101, 103, 114, 148, 143, 38, 237, 103,
-            131, 73, 175, 101, 220, 40, 107, 230, 50, 121, 203, 178, 128, 0, 127, 123, 174, 251,
-            83, 119, 195, 96, 152, 60, 181, 42, 26, 202, 28, 104, 134, 130, 165, 35, 177, 232, 242,
-            139, 26, 2, 44, 14, 110, 6, 128, 128, 118, 63, 113, 242, 124, 231, 212, 8, 82, 183, 24,
-            24, 130, 47, 242, 244, 239, 77, 79, 143, 77, 92, 138, 40, 193, 232, 240, 172, 212, 219,
-            251, 253, 153, 1, 128, 25, 0, 128, 131, 216, 237, 91, 73, 32, 179, 149, 147, 229, 128,
-            243, 150, 34, 77, 196, 2, 220, 114, 110, 222, 73, 237, 95, 79, 2, 153, 120, 123, 68,
-            40, 41, 128, 202, 189, 221, 119, 170, 102, 177, 23, 130, 60, 67, 68, 166, 211, 40, 227,
-            60, 236, 175, 67, 23, 197, 225, 205, 155, 225, 249, 77, 165, 180, 156, 72, 128, 25,
-            141, 203, 151, 66, 148, 197, 11, 2, 4, 154, 70, 86, 72, 181, 199, 241, 162, 17, 63,
-            102, 61, 167, 140, 47, 71, 148, 144, 130, 222, 95, 120, 161, 2, 128, 25, 144, 128, 124,
-            64, 196, 229, 80, 168, 165, 68, 83, 189, 233, 254, 77, 200, 221, 245, 37, 108, 71, 238,
-            214, 44, 231, 120, 123, 141, 25, 208, 126, 64, 39, 137, 128, 136, 83, 118, 141, 236,
-            159, 214, 79, 137, 164, 200, 141, 31, 182, 228, 245, 254, 107, 34, 121, 230, 28, 56,
-            103, 156, 212, 3, 236, 238, 247, 236, 54, 128, 248, 213, 148, 133, 76, 109, 186, 82,
-            73, 89, 243, 37, 46, 148, 151, 48, 199, 169, 171, 14, 165, 238, 9, 124, 59, 91, 63,
-            175, 118, 35, 194, 65, 128, 148, 64, 239, 131, 49, 37, 24, 198, 172, 226, 23, 213, 168,
-            19, 131, 79, 146, 25, 204, 0, 172, 128, 91, 60, 247, 60, 137, 21, 149, 3, 63, 5, 128,
-            235, 18, 177, 170, 150, 70, 142, 125, 245, 174, 97, 223, 109, 245, 163, 5, 244, 5, 168,
-            38, 66, 87, 156, 210, 164, 50, 51, 180, 99, 2, 184, 177, 29, 2, 128, 28, 1, 128, 1, 14,
-            241, 194, 133, 160, 107, 146, 140, 26, 34, 109, 174, 111, 254, 122, 224, 7, 205, 107,
-            177, 239, 70, 149, 185, 55, 129, 104, 114, 214, 167, 174, 128, 234, 243, 200, 140, 99,
-            91, 181, 198, 217, 191, 177, 206, 224, 52, 225, 194, 175, 53, 80, 84, 161, 206, 92, 34,
-            121, 70, 199, 75, 243, 17, 248, 4, 128, 86, 185, 253, 101, 119, 109, 238, 159, 46, 1,
-            99, 153, 103, 66, 36, 108, 224, 213, 27, 22, 98, 142, 213, 177, 196, 128, 215, 181,
-            233, 161, 77, 235, 128, 69, 241, 17, 180, 174, 22, 128, 154, 210, 189, 76, 35, 147,
-            165, 241, 163, 188, 41, 167, 232, 106, 146, 91, 25, 56, 98, 138, 43, 31, 241, 2, 119,
-            21, 1, 128, 32, 1, 128, 30, 218, 141, 47, 177, 18, 79, 115, 62, 144, 253, 130, 80, 216,
-            167, 189, 95, 225, 70, 209, 232, 145, 131, 192, 182, 122, 32, 213, 103, 101, 8, 104,
-            128, 22, 139, 97, 191, 41, 92, 230, 194, 29, 115, 128, 0, 62, 76, 107, 171, 218, 177,
-            11, 44, 71, 217, 186, 22, 22, 151, 197, 80, 243, 251, 81, 170, 21, 1, 128, 32, 1, 128,
-            40, 220, 230, 147, 210, 205, 205, 120, 160, 226, 19, 146, 71, 188, 7, 221, 128, 19,
-            198, 88, 6, 15, 114, 227, 245, 41, 14, 110, 10, 23, 147, 55, 128, 48, 227, 163, 227,
-            72, 147, 26, 78, 64, 235, 9, 100, 178, 69, 220, 253, 0, 14, 45, 40, 241, 210, 181, 158,
-            142, 65, 227, 9, 85, 55, 43, 11, 21, 1, 128, 32, 8, 128, 135, 48, 156, 183, 214, 203,
-            8, 37, 30, 196, 225, 20, 176, 42, 193, 221, 233, 198, 252, 165, 6, 138, 1, 133, 155,
-            44, 232, 212, 63, 209, 199, 124, 128, 141, 29, 159, 39, 63, 36, 78, 152, 248, 37, 33,
-            128, 188, 135, 221, 44, 106, 25, 83, 97, 170, 78, 136, 239, 126, 234, 85, 29, 108, 247,
-            96, 125, 153, 1, 128, 32, 9, 128, 50, 18, 46, 85, 33, 214, 103, 120, 18, 225, 122, 132,
-            189, 80, 163, 249, 102, 171, 185, 125, 250, 182, 244, 28, 167, 126, 9, 97, 43, 6, 235,
-            82, 128, 15, 249, 23, 87, 236, 109, 72, 91, 91, 139, 172, 240, 77, 53, 16, 227, 114,
-            156, 55, 32, 108, 85, 210, 158, 114, 190, 110, 89, 23, 95, 7, 27, 128, 76, 86, 226, 80,
-            106, 113, 12, 109, 148, 2, 142, 227, 59, 99, 212, 155, 150, 209, 219, 49, 199, 44, 61,
-            87, 160, 105, 253, 74, 82, 139, 100, 56, 153, 1, 128, 32, 9, 128, 79, 207, 236, 205,
-            253, 184, 210, 119, 100, 147, 252, 242, 22, 100, 244, 124, 193, 183, 212, 219, 197,
-            179, 209, 54, 159, 247, 17, 121, 38, 13, 99, 251, 128, 244, 181, 22, 87, 215, 184, 153,
-            75, 220, 197, 84, 40, 111, 98, 49, 135, 204, 57, 47, 54, 52, 233, 35, 90, 168, 30, 253,
-            44, 234, 128, 36, 65, 128, 28, 221, 182, 188, 116, 62, 216, 177, 145, 80, 70, 104, 14,
-            247, 212, 212, 236, 183, 215, 108, 43, 142, 14, 194, 104, 13, 116, 110, 180, 159, 144,
-            46, 29, 2, 128, 32, 44, 128, 178, 210, 234, 27, 227, 206, 114, 134, 138, 16, 130, 240,
-            138, 23, 117, 58, 153, 229, 245, 31, 254, 73, 63, 55, 2, 59, 110, 196, 38, 206, 228, 1,
-            128, 164, 173, 93, 168, 35, 213, 82, 58, 205, 192, 133, 250, 221, 24, 247, 219, 211,
-            24, 70, 0, 160, 138, 118, 92, 147, 190, 255, 77, 222, 49, 183, 59, 128, 235, 190, 244,
-            56, 249, 152, 19, 184, 209, 7, 46, 171, 149, 41, 55, 203, 13, 247, 42, 122, 191, 97,
-            18, 223, 37, 219, 188, 45, 61, 131, 11, 227, 128, 122, 92, 235, 221, 230, 226, 114, 37,
-            36, 52, 84, 95, 96, 104, 63, 156, 254, 28, 228, 115, 84, 189, 184, 15, 43, 253, 82, 86,
-            191, 174, 100, 233, 21, 1, 128, 32, 64, 128, 129, 244, 70, 89, 109, 38, 74, 56, 143,
-            69, 201, 95, 2, 6, 210, 50, 32, 38, 0, 108, 132, 172, 17, 89, 121, 57, 49, 123, 97,
-            237, 8, 219, 128, 112, 225, 35, 224, 141, 6, 92, 12, 25, 147, 77, 22, 5, 170, 173, 65,
-            111, 105, 66, 183, 146, 222, 222, 22, 118, 182, 231, 78, 190, 50, 105, 165, 21, 1, 128,
-            32, 64, 128, 134, 96, 70, 69, 253, 170, 56, 93, 20, 35, 124, 69, 69, 224, 170, 101,
-            165, 251, 226, 81, 99, 14, 27, 126, 87, 56, 65, 33, 181, 20, 239, 211, 128, 15, 67,
-            246, 129, 23, 193, 1, 147, 161, 221, 183, 83, 236, 108, 134, 213, 100, 143, 177, 179,
-            36, 27, 246, 140, 212, 53, 85, 50, 141, 235, 99, 52, 21, 1, 128, 32, 64, 128, 152, 146,
-            187, 83, 177, 101, 61, 49, 70, 14, 189, 51, 71, 149, 126, 118, 245, 142, 141, 75, 145,
-            36, 65, 20, 93, 26, 252, 181, 59, 32, 44, 184, 128, 120, 239, 122, 49, 165, 174, 229,
-            116, 34, 136, 252, 74, 136, 87, 40, 243, 138, 21, 154, 110, 162, 191, 235, 216, 43, 67,
-            205, 213, 170, 4, 106, 92, 21, 1, 128, 32, 64, 128, 213, 21, 208, 135, 163, 102, 197,
-            155, 27, 127, 201, 146, 66, 229, 75, 92, 70, 224, 208, 139, 180, 112, 140, 150, 186,
-            175, 238, 190, 169, 146, 193, 247, 128, 3, 215, 253, 221, 138, 226, 134, 229, 65, 13,
-            247, 84, 168, 250, 109, 26, 148, 74, 32, 68, 21, 217, 251, 242, 56, 44, 217, 27, 251,
-            87, 121, 129, 153, 1, 128, 32, 160, 128, 168, 98, 185, 14, 127, 140, 169, 45, 140, 39,
-            62, 162, 181, 233, 138, 202, 246, 236, 19, 159, 118, 24, 249, 135, 125, 167, 213, 15,
-            121, 181, 165, 128, 128, 243, 246, 74, 130, 37, 112, 14, 147, 147, 91, 46, 244, 226,
-            238, 163, 204, 179, 174, 181, 8, 174, 75, 4, 163, 0, 5, 253, 56, 67, 219, 248, 168,
-            128, 111, 53, 182, 173, 109, 89, 222, 208, 184, 34, 33, 130, 251, 104, 242, 66, 24,
-            107, 180, 9, 155, 224, 227, 226, 213, 142, 66, 182, 173, 100, 67, 56, 21, 1, 128, 33,
-            0, 128, 31, 42, 133, 238, 243, 59, 240, 16, 222, 33, 219, 227, 249, 130, 128, 135, 38,
-            229, 213, 70, 118, 24, 158, 118, 200, 70, 34, 238, 181, 190, 173, 179, 128, 67, 138,
-            84, 31, 252, 132, 148, 206, 91, 38, 82, 194, 179, 143, 53, 74, 209, 192, 215, 229, 253,
-            43, 9, 168, 189, 68, 233, 218, 89, 240, 129, 8, 21, 1, 128, 33, 0, 128, 120, 47, 101,
-            80, 63, 15, 203, 61, 170, 188, 158, 232, 117, 95, 216, 153, 189, 248, 14, 111, 227, 6,
-            214, 125, 116, 51, 9, 250, 224, 47, 182, 29, 128, 188, 227, 217, 219, 132, 201, 188,
-            54, 14, 250, 146, 17, 110, 203, 30, 63, 120, 59, 48, 71, 63, 183, 43, 214, 246, 235,
-            92, 28, 37, 229, 229, 4, 161, 2, 128, 33, 21, 128, 115, 93, 252, 132, 119, 249, 35, 77,
-            32, 226, 220, 123, 22, 218, 160, 52, 163, 240, 111, 17, 163, 212, 163, 84, 206, 117,
-            142, 202, 152, 53, 134, 131, 128, 239, 158, 114, 146, 71, 228, 89, 47, 80, 68, 28, 83,
-            23, 19, 147, 114, 180, 3, 221, 74, 28, 138, 56, 17, 119, 44, 110, 203, 20, 137, 24, 3,
-            128, 236, 103, 44, 112, 160, 220, 208, 8, 46, 222, 200, 245, 168, 150, 83, 180, 184,
-            144, 183, 22, 181, 100, 14, 245, 173, 21, 137, 109, 230, 67, 77, 43, 128, 41, 20, 242,
-            111, 84, 45, 180, 25, 1, 125, 150, 182, 15, 79, 225, 24, 54, 60, 34, 23, 47, 83, 59,
-            238, 26, 117, 8, 58, 240, 86, 146, 253, 128, 237, 213, 64, 26, 54, 65, 248, 26, 133,
-            188, 101, 110, 242, 52, 180, 163, 41, 171, 241, 140, 149, 80, 191, 114, 95, 180, 40,
-            222, 192, 59, 147, 113, 29, 2, 128, 33, 144, 128, 174, 197, 70, 203, 116, 144, 71, 233,
-            40, 105, 28, 94, 192, 43, 50, 196, 14, 97, 237, 191, 207, 102, 15, 248, 3, 0, 209, 117,
-            109, 179, 94, 168, 128, 83, 92, 14, 188, 59, 5, 218, 255, 32, 71, 164, 99, 38, 225, 43,
-            167, 35, 34, 118, 171, 88, 110, 170, 99, 135, 103, 110, 174, 6, 193, 179, 218, 128,
-            108, 214, 166, 241, 29, 208, 140, 82, 222, 167, 47, 11, 1, 99, 61, 200, 131, 125, 249,
-            176, 173, 183, 42, 254, 71, 100, 254, 140, 117, 163, 200, 135, 128, 105, 18, 41, 255,
-            230, 214, 179, 127, 128, 55, 100, 158, 52, 232, 184, 66, 21, 158, 144, 120, 209, 31,
-            122, 30, 250, 88, 38, 4, 193, 96, 50, 235, 21, 1, 128, 34, 0, 128, 82, 16, 102, 143,
-            95, 31, 236, 195, 104, 39, 71, 132, 228, 54, 231, 43, 68, 34, 6, 213, 162, 166, 207,
-            107, 120, 169, 18, 223, 163, 76, 6, 131, 128, 3, 243, 14, 81, 26, 99, 213, 31, 165,
-            220, 158, 45, 118, 190, 228, 61, 195, 135, 50, 167, 32, 85, 46, 43, 10, 20, 74, 98,
-            253, 125, 158, 29, 21, 1, 128, 34, 0, 128, 87, 171, 94, 106, 6, 137, 139, 252, 192, 15,
-            68, 7, 132, 90, 34, 64, 23, 0, 28, 189, 56, 123, 30, 180, 116, 51, 122, 23, 50, 126,
-            178, 129, 128, 178, 10, 246, 12, 31, 15, 172, 22, 114, 158, 145, 39, 255, 240, 150,
-            213, 205, 224, 10, 72, 107, 49, 61, 104, 13, 0, 60, 251, 11, 169, 17, 198, 21, 1, 128,
-            34, 0, 128, 228, 234, 94, 106, 205, 181, 185, 183, 238, 118, 140, 254, 9, 73, 183, 14,
-            40, 141, 219, 255, 68, 250, 192, 108, 176, 211, 91, 239, 84, 46, 248, 131, 128, 71,
-            152, 216, 226, 7, 202, 240, 36, 84, 225, 188, 171, 166, 150, 142, 99, 100, 146, 234,
-            253, 162, 152, 36, 245, 156, 167, 44, 92, 141, 19, 111, 208, 153, 1, 128, 34, 4, 128,
-            101, 52, 22, 150, 51, 147, 244, 203, 230, 152, 184, 177, 30, 186, 145, 27, 107, 71,
-            177, 48, 99, 180, 237, 188, 43, 13, 92, 43, 141, 163, 189, 48, 128, 42, 173, 59, 69,
-            207, 36, 190, 93, 118, 61, 149, 3, 227, 72, 167, 70, 157, 34, 231, 255, 181, 198, 65,
-            218, 76, 239, 140, 120, 84, 11, 70, 213, 128, 111, 99, 25, 70, 226, 13, 215, 161, 231,
-            99, 61, 12, 134, 237, 106, 7, 21, 139, 180, 56, 66, 18, 150, 234, 129, 127, 181, 142,
-            134, 117, 208, 50, 37, 3, 128, 35, 67, 128, 173, 109, 185, 76, 139, 168, 234, 212, 53,
-            41, 239, 243, 132, 148, 129, 152, 54, 228, 56, 233, 100, 88, 9, 44, 166, 83, 33, 4,
-            215, 91, 140, 242, 128, 15, 108, 176, 93, 114, 58, 109, 136, 49, 113, 213, 225, 29, 58,
-            0, 250, 96, 197, 247, 5, 175, 95, 211, 129, 20, 83, 192, 19, 241, 16, 129, 243, 128,
-            146, 173, 244, 216, 171, 214, 126, 199, 244, 127, 156, 201, 177, 124, 57, 205, 227,
-            253, 80, 67, 189, 168, 89, 82, 105, 227, 56, 70, 155, 77, 84, 227, 128, 215, 14, 158,
-            78, 135, 14, 15, 46, 94, 114, 96, 211, 76, 227, 153, 209, 243, 115, 187, 7, 16, 122,
-            230, 237, 13, 168, 219, 72, 50, 254, 231, 253, 128, 182, 78, 125, 146, 180, 18, 17,
-            237, 66, 10, 102, 244, 117, 226, 88, 220, 206, 120, 170, 3, 163, 237, 194, 138, 91,
-            228, 245, 182, 69, 245, 255, 63, 128, 72, 200, 184, 80, 9, 117, 215, 207, 249, 233, 90,
-            55, 199, 178, 173, 186, 128, 67, 173, 244, 83, 60, 22, 39, 86, 204, 39, 51, 240, 228,
-            184, 229, 21, 1, 128, 36, 0, 128, 175, 74, 29, 188, 169, 32, 155, 144, 90, 51, 156, 42,
-            184, 189, 251, 108, 35, 135, 68, 114, 225, 192, 204, 97, 125, 2, 207, 49, 232, 92, 166,
-            56, 128, 223, 112, 1, 170, 248, 13, 39, 203, 193, 48, 188, 23, 182, 148, 204, 174, 76,
-            232, 170, 33, 89, 249, 45, 198, 59, 129, 51, 130, 186, 237, 149, 248, 153, 1, 128, 36,
-            128, 128, 196, 238, 151, 169, 167, 16, 107, 155, 97, 147, 106, 134, 188, 48, 88, 174,
-            161, 60, 147, 49, 166, 146, 180, 34, 21, 3, 226, 233, 133, 164, 44, 144, 128, 153, 6,
-            81, 135, 181, 229, 83, 139, 242, 167, 107, 62, 43, 15, 245, 135, 219, 14, 188, 203,
-            174, 10, 139, 163, 126, 242, 160, 227, 11, 25, 46, 236, 128, 118, 30, 167, 54, 238,
-            186, 60, 231, 139, 242, 200, 160, 238, 94, 178, 161, 227, 246, 108, 100, 244, 26, 147,
-            82, 237, 246, 24, 2, 167, 230, 142, 112, 21, 1, 128, 40, 0, 128, 92, 21, 62, 131, 29,
-            64, 180, 148, 19, 29, 21, 146, 96, 177, 125, 245, 180, 149, 250, 216, 197, 232, 168,
-            249, 235, 178, 77, 144, 222, 40, 204, 244, 128, 230, 36, 77, 11, 36, 58, 207, 217, 158,
-            196, 140, 236, 121, 142, 157, 67, 150, 11, 164, 207, 25, 204, 129, 147, 81, 97, 127,
-            210, 165, 37, 102, 140, 21, 1, 128, 40, 0, 128, 129, 53, 239, 107, 102, 141, 74, 57,
-            43, 10, 128, 71, 76, 142, 167, 157, 223, 73, 65, 153, 232, 90, 85, 24, 70, 86, 206, 11,
-            105, 151, 213, 97, 128, 254, 42, 127, 25, 238, 243, 48, 82, 136, 220, 23, 19, 158, 190,
-            192, 188, 234, 149, 186, 16, 125, 98, 177, 206, 192, 210, 179, 189, 251, 184, 217, 249,
-            153, 1, 128, 40, 4, 128, 106, 21, 52, 6, 14, 7, 244, 247, 184, 69, 134, 71, 177, 94, 0,
-            129, 216, 5, 87, 201, 250, 53, 42, 92, 147, 230, 130, 146, 199, 163, 62, 37, 128, 214,
-            189, 193, 211, 159, 105, 184, 102, 199, 84, 146, 152, 224, 163, 26, 145, 134, 53, 4,
-            89, 165, 86, 235, 14, 133, 199, 118, 13, 164, 29, 51, 28, 128, 169, 84, 224, 95, 123,
-            99, 89, 181, 107, 213, 157, 99, 65, 226, 171, 122, 204, 194, 208, 175, 94, 208, 161,
-            225, 12, 8, 181, 198, 246, 128, 243, 78, 29, 2, 128, 40, 5, 128, 173, 112, 234, 59, 17,
-            139, 28, 229, 46, 253, 178, 126, 173, 182, 75, 170, 114, 227, 179, 170, 20, 29, 30,
-            160, 71, 252, 86, 79, 122, 80, 180, 111, 128, 220, 49, 4, 22, 163, 128, 85, 192, 169,
-            211, 199, 108, 66, 75, 211, 72, 139, 153, 12, 119, 247, 249, 204, 180, 162, 36, 160,
-            96, 202, 137, 181, 194, 128, 136, 24, 63, 210, 214, 136, 36, 119, 116, 224, 188, 211,
-            87, 135, 139, 77, 254, 20, 20, 117, 220, 176, 173, 55, 96, 0, 167, 213, 154, 66, 203,
-            248, 128, 188, 3, 64, 97, 136, 90, 231, 148, 152, 212, 107, 19, 25, 145, 126, 63, 44,
-            106, 32, 41, 72, 241, 217, 25, 73, 27, 74, 58, 190, 33, 34, 227, 153, 1, 128, 40, 8,
-            128, 136, 5, 104, 46, 168, 35, 153, 168, 121, 173, 103, 154, 165, 229, 127, 242, 199,
-            214, 60, 100, 80, 48, 83, 0, 207, 150, 154, 124, 30, 192, 96, 47, 128, 27, 69, 10, 233,
-            216, 133, 29, 206, 206, 117, 188, 47, 188, 186, 25, 104, 165, 149, 94, 4, 132, 79, 58,
-            33, 69, 177, 196, 33, 213, 15, 76, 184, 128, 123, 175, 78, 24, 159, 242, 95, 228, 251,
-            33, 140, 250, 67, 91, 157, 233, 112, 185, 105, 76, 56, 135, 106, 15, 173, 167, 125, 70,
-            119, 12, 74, 231, 29, 2, 128, 40, 36, 128, 68, 13, 175, 73, 82, 163, 15, 59, 204, 0,
-            104, 19, 62, 86, 240, 212, 53, 36, 126, 106, 56, 154, 20, 205, 87, 197, 187, 214, 199,
-            199, 119, 41, 128, 3, 48, 245, 208, 26, 149, 243, 133, 84, 40, 79, 160, 147, 170, 45,
-            41, 119, 70, 118, 105, 0, 143, 168, 236, 231, 12, 203, 131, 224, 218, 222, 144, 128,
-            90, 137, 26, 133, 192, 216, 222, 235, 192, 201, 158, 237, 31, 56, 9, 179, 254, 218,
-            139, 99, 27, 18, 231, 164, 81, 32, 23, 230, 12, 182, 13, 193, 128, 156, 192, 43, 122,
-            26, 8, 120, 14, 234, 4, 86, 63, 109, 63, 1, 1, 156, 128, 132, 249, 244, 11, 230, 219,
-            195, 101, 174, 212, 84, 245, 215, 120, 161, 2, 128, 40, 37, 128, 216, 90, 214, 108, 84,
-            19, 74, 228, 40, 166, 192, 80, 213, 244, 134, 19, 190, 40, 28, 181, 222, 254, 130, 32,
-            139, 104, 198, 7, 6, 61, 45, 145, 128, 202, 49, 137, 194, 40, 200, 67, 226, 131, 26,
-            105, 147, 133, 64, 100, 186, 73, 31, 215, 96, 165, 66, 130, 154, 17, 55, 3, 58, 63, 53,
-            5, 206, 128, 214, 189, 116, 112, 111, 230, 123, 255, 26, 61, 194, 111, 252, 66, 164,
-            152, 32, 240, 197, 37, 222, 84, 46, 223, 211, 107, 140, 224, 160, 87, 97, 5, 128, 32,
-            19, 58, 231, 46, 106, 223, 115, 169, 164, 172, 218, 148, 202, 66, 120, 130, 198, 208,
-            128, 38, 164, 120, 127, 145, 163, 79, 113, 209, 77, 194, 250, 128, 11, 7, 103, 50, 56,
-            132, 43, 39, 127, 69, 209, 36, 99, 112, 55, 121, 176, 163, 250, 161, 42, 171, 177, 99,
-            46, 76, 6, 17, 175, 208, 129, 15, 161, 2, 128, 40, 69, 128, 79, 158, 127, 47, 190, 186,
-            147, 135, 200, 107, 207, 144, 164, 100, 38, 29, 29, 247, 85, 207, 150, 53, 230, 56,
-            126, 163, 252, 90, 30, 134, 80, 220, 128, 180, 104, 53, 29, 225, 208, 41, 173, 77, 142,
-            197, 39, 83, 104, 152, 111, 253, 173, 224, 231, 173, 215, 233, 57, 18, 95, 67, 96, 214,
-            160, 47, 192, 128, 168, 101, 87, 1, 104, 71, 108, 4, 26, 1, 186, 101, 247, 214, 160,
-            107, 42, 176, 202, 166, 185, 156, 111, 63, 207, 53, 124, 179, 134, 192, 16, 135, 128,
-            149, 196, 225, 43, 134, 140, 143, 85, 94, 132, 130, 252, 55, 214, 130, 173, 130, 4,
-            141, 29, 211, 105, 104, 70, 66, 0, 46, 189, 219, 79, 173, 255, 128, 95, 19, 121, 148,
-            242, 42, 90, 111, 71, 229, 48, 44, 202, 204, 186, 83, 164, 78, 227, 200, 99, 44, 72,
-            133, 94, 4, 31, 194, 152, 57, 137, 167, 161, 2, 128, 40, 70, 128, 200, 33, 99, 190, 18,
-            29, 40, 253, 204, 244, 157, 175, 144, 73, 213, 60, 101, 82, 190, 139, 224, 56, 41, 34,
-            88, 85, 75, 15, 142, 76, 232, 93, 128, 175, 240, 86, 127, 64, 156, 5, 23, 165, 98, 138,
-            14, 224, 198, 78, 5, 76, 128, 146, 41, 43, 45, 131, 51, 140, 61, 36, 201, 164, 235, 46,
-            183, 128, 51, 106, 124, 89, 151, 250, 30, 160, 239, 115, 218, 205, 95, 180, 42, 249,
-            25, 188, 137, 166, 208, 250, 18, 217, 81, 37, 179, 33, 138, 0, 246, 224, 128, 3, 26,
-            67, 30, 183, 92, 239, 153, 87, 81, 30, 32, 99, 87, 124, 232, 103, 255, 44, 41, 121,
-            156, 222, 141, 177, 190, 191, 11, 13, 89, 42, 38, 128, 221, 60, 39, 0, 200, 66, 174,
-            153, 145, 35, 164, 39, 248, 44, 229, 94, 12, 3, 111, 77, 174, 105, 158, 108, 56, 170,
-            18, 48, 32, 112, 238, 103, 153, 1, 128, 40, 128, 128, 169, 84, 49, 4, 245, 35, 153, 63,
-            26, 186, 175, 254, 154, 165, 124, 142, 228, 201, 47, 157, 39, 137, 29, 180, 66, 93,
-            166, 142, 149, 188, 153, 69, 128, 204, 216, 193, 220, 231, 214, 34, 48, 114, 124, 253,
-            67, 234, 76, 50, 126, 138, 254, 176, 87, 155, 97, 5, 47, 108, 198, 255, 77, 88, 255,
-            207, 167, 128, 208, 22, 113, 11, 156, 85, 57, 157, 74, 242, 122, 11, 246, 113, 137,
-            252, 180, 241, 39, 61, 196, 48, 199, 34, 221, 41, 124, 105, 156, 143, 74, 66, 161, 2,
-            128, 40, 200, 128, 164, 110, 94, 80, 218, 5, 224, 50, 92, 234, 116, 43, 233, 237, 219,
-            242, 107, 198, 241, 248, 36, 227, 82, 82, 17, 166, 79, 217, 224, 104, 40, 186, 128, 43,
-            214, 145, 176, 25, 3, 147, 159, 93, 219, 240, 156, 231, 14, 63, 144, 53, 128, 68, 93,
-            205, 218, 55, 150, 253, 123, 150, 202, 151, 218, 25, 167, 128, 69, 236, 230, 138, 92,
-            143, 199, 129, 48, 208, 195, 14, 135, 127, 114, 24, 173, 214, 142, 11, 92, 200, 54,
-            218, 105, 100, 131, 22, 198, 177, 113, 107, 128, 199, 106, 134, 1, 231, 227, 114, 179,
-            77, 233, 198, 180, 45, 206, 12, 162, 0, 173, 56, 219, 118, 144, 38, 88, 203, 224, 216,
-            254, 4, 21, 103, 243, 128, 41, 107, 163, 85, 162, 80, 3, 65, 107, 132, 44, 180, 105,
-            190, 250, 228, 63, 20, 53, 178, 67, 206, 97, 97, 86, 36, 57, 45, 237, 39, 239, 240,
-            153, 1, 128, 48, 4, 128, 77, 220, 50, 157, 94, 25, 57, 3, 235, 46, 255, 85, 137, 126,
-            145, 248, 148, 215, 29, 228, 2, 242, 67, 115, 30, 42, 208, 241, 85, 18, 120, 210, 128,
-            105, 194, 118, 178, 249, 158, 127, 225, 56, 163, 153, 29, 75, 94, 63, 72, 77, 52, 109,
-            252, 240, 38, 123, 142, 81, 227, 248, 221, 237, 26, 141, 81, 128, 1, 245, 156, 179,
-            132, 199, 252, 122, 147, 112, 54, 187, 33, 139, 60, 4, 113, 25, 226, 227, 188, 199,
-            145, 231, 209, 98, 104, 237, 151, 231, 54, 143, 29, 2, 128, 48, 24, 128, 22, 34, 216,
-            237, 119, 98, 167, 252, 23, 130, 158, 119, 2, 76, 56, 202, 79, 20, 128, 187, 215, 12,
-            36, 158, 160, 138, 161, 246, 40, 23, 28, 142, 128, 217, 79, 208, 243, 28, 223, 33, 139,
-            65, 211, 214, 118, 253, 88, 146, 150, 212, 89, 80, 154, 220, 52, 253, 114, 49, 118,
-            201, 51, 248, 144, 118, 33, 128, 228, 185, 238, 121, 144, 20, 8, 190, 3, 209, 46, 177,
-            206, 16, 7, 86, 226, 184, 185, 170, 231, 177, 39, 76, 28, 15, 172, 45, 237, 19, 168,
-            183, 128, 148, 63, 145, 242, 205, 122, 189, 4, 212, 196, 33, 209, 133, 71, 173, 7, 100,
-            31, 226, 37, 194, 54, 106, 190, 193, 93, 217, 186, 22, 121, 173, 244, 153, 1, 128, 48,
-            32, 128, 140, 235, 82, 153, 143, 180, 245, 147, 147, 205, 182, 42, 201, 145, 135, 18,
-            82, 153, 77, 187, 21, 171, 191, 140, 110, 21, 86, 234, 226, 169, 170, 119, 128, 117, 0,
-            180, 37, 226, 184, 132, 126, 4, 201, 22, 190, 249, 186, 103, 17, 17, 138, 22, 182, 76,
-            137, 155, 8, 213, 112, 33, 220, 183, 213, 214, 212, 128, 209, 14, 231, 101, 114, 156,
-            255, 225, 97, 171, 176, 113, 123, 211, 253, 245, 79, 238, 116, 196, 145, 179, 246, 175,
-            70, 122, 146, 115, 177, 59, 51, 246, 37, 3, 128, 48, 201, 128, 10, 99, 36, 23, 82, 61,
-            187, 81, 62, 118, 110, 200, 249, 33, 203, 107, 7, 57, 219, 229, 243, 123, 231, 246, 86,
-            119, 155, 30, 73, 59, 149, 169, 128, 45, 37, 73, 81, 154, 140, 32, 199, 242, 156, 182,
-            10, 83, 93, 58, 112, 75, 119, 35, 244, 234, 246, 77, 218, 12, 180, 0, 59, 166, 199,
-            128, 58, 128, 248, 86, 225, 202, 28, 29, 196, 100, 113, 93, 174, 159, 25, 183, 84, 223,
-            166, 153, 252, 161, 35, 155, 201, 210, 220, 76, 251, 21, 92, 242, 174, 5, 128, 106,
-            113, 219, 203, 192, 102, 230, 171, 150, 169, 83, 110, 64, 78, 239, 7, 159, 203, 88, 13,
-            199, 242, 219, 51, 226, 57, 68, 10, 219, 55, 144, 127, 128, 142, 33, 243, 167, 51, 32,
-            0, 69, 22, 151, 157, 128, 77, 158, 209, 224, 146, 61, 118, 27, 25, 240, 20, 148, 51,
-            117, 177, 96, 202, 105, 33, 239, 128, 225, 237, 205, 138, 131, 249, 204, 45, 32, 182,
-            44, 113, 211, 118, 198, 131, 118, 37, 253, 140, 118, 133, 229, 10, 236, 167, 68, 195,
-            107, 100, 190, 206, 153, 1, 128, 50, 0, 128, 247, 80, 250, 229, 218, 197, 33, 75, 21,
-            142, 233, 161, 220, 200, 51, 3, 5, 167, 221, 150, 154, 14, 96, 77, 92, 196, 129, 45,
-            97, 5, 32, 138, 128, 129, 11, 248, 163, 188, 2, 64, 197, 201, 45, 15, 86, 84, 159, 194,
-            39, 201, 77, 159, 213, 99, 75, 63, 107, 185, 235, 216, 133, 108, 141, 100, 178, 128,
-            21, 205, 99, 177, 109, 195, 76, 216, 73, 194, 238, 49, 43, 105, 217, 130, 217, 60, 112,
-            166, 48, 150, 140, 2, 62, 206, 161, 83, 181, 5, 239, 182, 29, 2, 128, 52, 32, 128, 17,
-            118, 218, 98, 238, 240, 71, 235, 208, 148, 212, 86, 179, 200, 88, 216, 202, 109, 6,
-            214, 104, 176, 30, 45, 146, 92, 53, 108, 161, 198, 76, 19, 128, 181, 159, 207, 143, 50,
-            110, 124, 234, 254, 178, 253, 235, 161, 158, 49, 194, 85, 43, 210, 215, 94, 15, 43,
-            135, 120, 183, 145, 75, 53, 68, 164, 135, 128, 29, 105, 129, 105, 155, 88, 2, 153, 188,
-            69, 144, 97, 11, 129, 248, 230, 181, 115, 197, 119, 64, 220, 127, 219, 66, 241, 207,
-            29, 31, 204, 209, 10, 128, 135, 172, 1, 144, 43, 86, 19, 113, 58, 212, 211, 110, 38,
-            58, 114, 64, 86, 15, 206, 200, 98, 130, 91, 194, 133, 65, 9, 30, 221, 216, 106, 248,
-            161, 2, 128, 54, 4, 128, 198, 103, 98, 149, 72, 209, 158, 203, 169, 62, 137, 202, 241,
-            184, 141, 161, 154, 247, 132, 113, 227, 23, 88, 165, 23, 103, 64, 7, 156, 198, 234,
-            138, 128, 16, 245, 139, 202, 188, 139, 113, 157, 38, 34, 72, 67, 249, 107, 251, 196,
-            86, 238, 87, 46, 182, 230, 83, 143, 186, 140, 86, 5, 49, 2, 85, 129, 128, 129, 62, 161,
-            3, 116, 9, 199, 55, 40, 172, 67, 159, 153, 102, 3, 240, 65, 162, 40, 111, 89, 136, 131,
-            156, 51, 83, 115, 149, 16, 190, 189, 150, 128, 188, 153, 105, 218, 57, 172, 43, 26,
-            248, 0, 70, 190, 91, 167, 32, 98, 177, 99, 170, 236, 177, 85, 42, 118, 198, 25, 236,
-            137, 112, 23, 197, 220, 128, 44, 234, 17, 69, 195, 36, 138, 80, 246, 228, 207, 239, 94,
-            172, 207, 197, 65, 171, 116, 234, 37, 147, 10, 198, 118, 252, 48, 71, 209, 229, 245,
-            155, 169, 3, 128, 54, 70, 128, 224, 47, 149, 61, 127, 141, 118, 137, 80, 123, 14, 162,
-            144, 43, 58, 156, 159, 170, 93, 214, 116, 226, 160, 152, 48, 35, 151, 43, 70, 88, 66,
-            160, 128, 239, 87, 30, 199, 21, 65, 183, 205, 54, 79, 144, 37, 100, 61, 133, 61, 210,
-            165, 225, 39, 149, 86, 49, 109, 246, 179, 40, 111, 200, 187, 207, 79, 128, 172, 215,
-            45, 175, 208, 78, 133, 75, 130, 103, 150, 37, 202, 159, 183, 51, 39, 41, 65, 123, 82,
-            107, 169, 153, 65, 119, 105, 131, 145, 109, 55, 106, 128, 199, 178, 80, 241, 203, 7,
-            230, 172, 170, 21, 129, 205, 247, 238, 64, 53, 56, 102, 223, 195, 196, 223, 85, 91, 2,
-            199, 52, 73, 107, 93, 253, 181, 128, 41, 158, 227, 139, 23, 20, 200, 95, 187, 3, 219,
-            129, 51, 231, 174, 123, 7, 46, 209, 1, 108, 203, 196, 116, 183, 182, 131, 105, 124,
-            186, 225, 188, 128, 51, 235, 5, 32, 113, 30, 196, 154, 70, 253, 13, 42, 238, 173, 239,
-            232, 45, 99, 107, 22, 94, 133, 217, 134, 48, 202, 69, 224, 208, 37, 75, 162, 128, 64,
-            124, 241, 227, 207, 255, 238, 124, 87, 121, 167, 0, 25, 191, 0, 42, 76, 228, 150, 17,
-            112, 43, 218, 104, 150, 47, 185, 229, 226, 225, 227, 224, 29, 2, 128, 56, 64, 128, 201,
-            205, 244, 177, 123, 149, 84, 103, 114, 223, 46, 92, 126, 84, 144, 239, 95, 80, 6, 100,
-            249, 192, 149, 109, 20, 208, 254, 240, 141, 13, 161, 31, 128, 127, 58, 101, 223, 196,
-            230, 210, 190, 141, 1, 92, 79, 55, 37, 109, 135, 108, 214, 13, 215, 135, 166, 122, 183,
-            76, 150, 166, 115, 136, 171, 238, 166, 128, 53, 70, 55, 18, 22, 245, 27, 77, 137, 148,
-            115, 254, 170, 219, 44, 215, 8, 106, 142, 217, 61, 159, 12, 200, 29, 152, 64, 28, 216,
-            206, 107, 158, 128, 139, 126, 235, 153, 77, 101, 60, 146, 110, 78, 64, 182, 238, 231,
-            96, 188, 126, 45, 40, 53, 127, 171, 253, 229, 70, 133, 93, 185, 74, 194, 245, 227, 37,
-            3, 128, 62, 4, 128, 36, 11, 29, 47, 223, 80, 137, 190, 187, 212, 36, 37, 58, 56, 66,
-            101, 180, 252, 152, 21, 58, 166, 241, 79, 189, 237, 231, 139, 131, 222, 185, 203, 128,
-            149, 211, 52, 164, 218, 56, 82, 207, 132, 88, 34, 112, 154, 40, 179, 158, 29, 78, 218,
-            16, 49, 45, 145, 21, 93, 23, 183, 200, 178, 35, 185, 84, 128, 176, 60, 170, 39, 81,
-            162, 211, 137, 40, 50, 167, 212, 153, 222, 224, 152, 88, 189, 17, 212, 163, 99, 205,
-            35, 255, 205, 81, 187, 248, 48, 30, 115, 128, 189, 188, 131, 61, 209, 19, 69, 45, 233,
-            239, 164, 231, 202, 206, 192, 128, 94, 221, 152, 172, 38, 140, 28, 93, 162, 71, 180,
-            182, 246, 76, 47, 89, 128, 40, 102, 115, 56, 45, 33, 88, 164, 213, 228, 40, 199, 85,
-            48, 144, 168, 65, 39, 124, 29, 30, 148, 219, 248, 71, 93, 198, 100, 67, 60, 158, 156,
-            128, 47, 246, 70, 62, 251, 25, 29, 196, 149, 12, 238, 78, 200, 225, 248, 106, 21, 55,
-            104, 250, 160, 135, 146, 192, 233, 108, 116, 122, 73, 17, 239, 19, 21, 1, 128, 64, 1,
-            128, 164, 160, 48, 127, 25, 6, 116, 182, 205, 171, 69, 135, 24, 14, 206, 8, 100, 111,
-            112, 204, 4, 202, 12, 122, 213, 84, 43, 252, 235, 95, 55, 85, 128, 23, 193, 234, 50,
-            17, 185, 30, 242, 164, 21, 162, 182, 112, 93, 241, 237, 64, 28, 134, 72, 97, 123, 55,
-            210, 219, 104, 183, 28, 19, 247, 27, 194, 21, 1, 128, 64, 2, 128, 144, 67, 117, 162,
-            108, 202, 133, 226, 18, 112, 192, 196, 73, 56, 114, 246, 122, 147, 38, 145, 169, 65,
-            123, 158, 24, 134, 9, 148, 113, 151, 68, 126, 128, 193, 211, 58, 110, 212, 170, 234,
-            166, 125, 88, 226, 204, 151, 122, 94, 221, 226, 133, 118, 246, 164, 6, 167, 229, 198,
-            213, 255, 236, 221, 152, 105, 219, 21, 1, 128, 64, 2, 128, 245, 244, 152, 150, 98, 194,
-            169, 100, 208, 46, 196, 56, 231, 195, 124, 242, 4, 144, 230, 230, 18, 20, 209, 140,
-            191, 208, 255, 133, 157, 126, 31, 230, 128, 116, 178, 225, 24, 11, 85, 103, 139, 212,
-            139, 137, 226, 107, 138, 144, 49, 42, 8, 205, 54, 254, 84, 220, 53, 99, 146, 24, 219,
-            143, 173, 202, 218, 21, 1, 128, 64, 4, 128, 32, 223, 84, 247, 212, 234, 16, 90, 120,
-            106, 151, 135, 93, 177, 114, 193, 232, 23, 23, 32, 123, 73, 230, 239, 242, 155, 1, 134,
-            71, 239, 238, 227, 128, 223, 58, 146, 142, 109, 182, 102, 99, 199, 120, 248, 67, 49,
-            121, 61, 122, 196, 37, 155, 19, 68, 238, 117, 185, 219, 222, 167, 117, 166, 140, 24,
-            126, 153, 1, 128, 64, 6, 128, 77, 57, 50, 111, 136, 148, 178, 41, 180, 235, 70, 58,
-            221, 134, 81, 89, 213, 0, 66, 5, 97, 59, 235, 4, 91, 203, 152, 12, 243, 251, 157, 205,
-            128, 6, 85, 54, 189, 43, 92, 42, 255, 91, 141, 130, 90, 199, 176, 127, 210, 169, 22,
-            88, 42, 180, 121, 22, 27, 3, 239, 141, 101, 201, 138, 26, 155, 128, 127, 144, 156, 250,
-            234, 74, 106, 152, 0, 38, 248, 54, 27, 54, 43, 254, 65, 244, 124, 146, 21, 187, 128,
-            140, 61, 130, 216, 54, 47, 111, 150, 82, 29, 2, 128, 64, 7, 128, 84, 190, 66, 200, 168,
-            30, 83, 68, 84, 114, 19, 72, 220, 254, 53, 208, 197, 132, 4, 110, 185, 237, 201, 176,
-            134, 20, 87, 253, 106, 47, 142, 52, 128, 247, 65, 137, 241, 231, 252, 85, 157, 158,
-            172, 241, 231, 53, 188, 36, 127, 157, 27, 75, 175, 95, 115, 137, 164, 179, 78, 219, 2,
-            98, 228, 182, 50, 128, 11, 119, 177, 200, 187, 249, 149, 80, 48, 228, 110, 125, 164,
-            187, 201, 142, 90, 133, 111, 20, 82, 56, 42, 74, 98, 206, 111, 108, 104, 212, 137, 106,
-            128, 238, 76, 255, 193, 14, 226, 95, 35, 26, 64, 235, 12, 5, 119, 196, 138, 42, 57, 48,
-            66, 139, 251, 231, 115, 114, 137, 132, 128, 21, 250, 226, 195, 21, 1, 128, 64, 8, 128,
-            77, 100, 78, 23, 97, 182, 216, 199, 131, 56, 177, 59, 20, 255, 142, 236, 171, 123, 79,
-            185, 75, 247, 161, 112, 183, 37, 193, 207, 87, 185, 222, 106, 128, 39, 209, 52, 158,
-            121, 59, 28, 7, 95, 111, 75, 88, 94, 242, 230, 233, 9, 201, 253, 227, 208, 23, 131,
-            247, 122, 134, 190, 251, 138, 16, 66, 244, 21, 1, 128, 64, 8, 128, 182, 94, 17, 252,
-            211, 130, 23, 174, 211, 141, 42, 224, 79, 159, 181, 255, 241, 91, 182, 13, 208, 171,
-            75, 105, 82, 62, 134, 242, 158, 45, 76, 105, 128, 74, 85, 169, 189, 86, 158, 131, 186,
-            251, 12, 41, 140, 224, 134, 193, 100, 143, 224, 224, 7, 184, 168, 107, 161, 112, 228,
-            140, 156, 157, 143, 168, 120, 29, 2, 128, 64, 11, 128, 253, 48, 220, 47, 114, 241, 178,
-            33, 15, 217, 63, 121, 22, 54, 97, 82, 115, 224, 247, 196, 101, 38, 213, 126, 89, 123,
-            43, 7, 54, 202, 187, 120, 128, 105, 107, 158, 48, 31, 105, 187, 157, 44, 89, 22, 83,
-            80, 66, 171, 80, 227, 38, 135, 231, 92, 218, 1, 132, 111, 102, 149, 5, 115, 139, 111,
-            221, 128, 214, 14, 112, 5, 36, 84, 12, 77, 145, 70, 115, 117, 162, 140, 101, 211, 158,
-            183, 178, 54, 144, 110, 44, 104, 123, 139, 34, 202, 44, 221, 63, 147, 128, 191, 224,
-            27, 12, 195, 185, 28, 245, 30, 166, 64, 0, 76, 100, 54, 72, 122, 126, 191, 161, 48,
-            178, 246, 67, 109, 131, 75, 136, 210, 120, 179, 232, 21, 1, 128, 64, 16, 128, 99, 149,
-            242, 232, 75, 125, 69, 60, 202, 221, 126, 53, 135, 90, 1, 167, 144, 3, 135, 153, 73,
-            243, 184, 148, 55, 47, 80, 141, 85, 244, 149, 219, 128, 37, 119, 167, 166, 14, 127,
-            251, 11, 135, 110, 250, 126, 216, 160, 15, 133, 152, 193, 76, 23, 126, 173, 222, 207,
-            62, 254, 136, 218, 121, 10, 124, 75, 153, 1, 128, 64, 33, 128, 142, 133, 154, 131, 95,
-            55, 120, 71, 115, 47, 209, 22, 134, 203, 94, 73, 66, 235, 110, 101, 186, 123, 202, 4,
-            224, 233, 186, 74, 109, 148, 102, 72, 128, 248, 0, 3, 184, 161, 79, 9, 107, 220, 44,
-            205, 166, 55, 236, 188, 2, 22, 38, 13, 69, 240, 65, 15, 191, 53, 40, 254, 51, 103, 51,
-            93, 132, 128, 104, 54, 127, 239, 128, 10, 241, 52, 255, 26, 138, 63, 131, 94, 225, 64,
-            208, 200, 200, 250, 249, 46, 29, 121, 191, 164, 210, 189, 200, 118, 57, 114, 21, 1,
-            128, 64, 64, 128, 127, 243, 53, 18, 48, 184, 60, 92, 163, 49, 67, 206, 211, 195, 11,
-            14, 217, 190, 96, 196, 188, 206, 56, 42, 9, 104, 121, 255, 217, 35, 1, 61, 128, 131,
-            113, 74, 112, 140, 65, 108, 88, 220, 59, 32, 21, 62, 126, 139, 17, 121, 120, 227, 189,
-            59, 97, 49, 112, 88, 40, 188, 19, 241, 243, 16, 89, 29, 2, 128, 64, 73, 128, 118, 233,
-            240, 87, 66, 188, 104, 191, 64, 72, 156, 81, 244, 32, 108, 84, 157, 89, 125, 9, 107,
-            149, 191, 160, 156, 167, 89, 94, 100, 227, 71, 116, 128, 83, 213, 9, 42, 191, 27, 146,
-            25, 243, 226, 171, 21, 120, 233, 143, 71, 24, 54, 20, 119, 168, 105, 26, 112, 107, 246,
-            174, 177, 100, 253, 66, 50, 128, 174, 182, 178, 44, 24, 138, 206, 25, 57, 104, 138, 33,
-            166, 87, 33, 149, 99, 236, 230, 160, 37, 166, 217, 145, 110, 11, 49, 178, 149, 44, 103,
-            121, 128, 63, 253, 131, 42, 186, 207, 80, 128, 98, 7, 254, 173, 155, 183, 105, 61, 153,
-            115, 223, 145, 67, 164, 66, 122, 249, 67, 62, 113, 227, 171, 16, 72, 161, 2, 128, 64,
-            149, 128, 137, 116, 180, 121, 17, 15, 168, 9, 247, 151, 187, 167, 141, 105, 171, 32,
-            31, 22, 160, 156, 156, 5, 157, 37, 221, 131, 57, 42, 33, 55, 189, 77, 128, 164, 232,
-            207, 131, 71, 2, 246, 209, 245, 245, 133, 56, 22, 85, 159, 184, 148, 109, 95, 102, 199,
-            228, 3, 92, 231, 206, 87, 195, 14, 19, 212, 217, 128, 77, 41, 72, 124, 154, 181, 220,
-            141, 140, 185, 5, 99, 103, 252, 77, 207, 252, 72, 13, 43, 88, 55, 64, 198, 176, 135,
-            38, 137, 199, 92, 90, 16, 128, 177, 178, 214, 86, 169, 3, 107, 1, 99, 224, 152, 108,
-            69, 166, 222, 2, 16, 187, 200, 12, 222, 145, 7, 37, 101, 195, 201, 243, 84, 59, 10,
-            100, 128, 208, 3, 203, 87, 84, 1, 192, 115, 180, 255, 31, 248, 210, 180, 48, 0, 155,
-            83, 253, 54, 145, 239, 171, 252, 184, 192, 185, 168, 198, 201, 118, 58, 21, 1, 128, 65,
-            0, 128, 120, 178, 227, 124, 11, 96, 199, 154, 190, 19, 183, 180, 246, 113, 63, 20, 104,
-            221, 197, 116, 209, 173, 231, 128, 212, 244, 33, 159, 125, 154, 238, 231, 128, 106, 77,
-            141, 30, 119, 77, 200, 215, 68, 172, 109, 215, 93, 136, 92, 72, 59, 221, 203, 189, 64,
-            77, 25, 2, 150, 21, 162, 34, 67, 229, 141, 88, 21, 1, 128, 65, 0, 128, 151, 238, 242,
-            39, 94, 156, 23, 65, 91, 239, 171, 68, 188, 20, 45, 223, 40, 17, 161, 225, 35, 153, 9,
-            245, 239, 58, 223, 186, 46, 13, 120, 186, 128, 38, 78, 69, 11, 202, 18, 109, 254, 229,
-            0, 87, 86, 122, 198, 145, 28, 85, 213, 167, 123, 183, 155, 20, 67, 139, 185, 177, 151,
-            100, 163, 100, 2, 29, 2, 128, 65, 17, 128, 251, 138, 91, 126, 148, 53, 88, 151, 109,
-            138, 239, 57, 254, 191, 28, 52, 247, 81, 102, 18, 207, 35, 121, 18, 54, 30, 114, 107,
-            27, 63, 83, 65, 128, 253, 74, 2, 155, 252, 198, 51, 226, 160, 67, 16, 169, 4, 64, 112,
-            25, 162, 166, 75, 138, 232, 112, 213, 173, 244, 186, 117, 3, 134, 160, 105, 46, 128,
-            236, 35, 106, 124, 110, 203, 113, 232, 134, 155, 157, 67, 5, 109, 37, 27, 206, 27, 13,
-            36, 66, 223, 243, 138, 97, 208, 3, 40, 121, 211, 221, 49, 128, 6, 85, 58, 60, 75, 131,
-            112, 255, 39, 134, 249, 52, 19, 228, 77, 87, 26, 183, 247, 239, 187, 228, 124, 200,
-            183, 180, 190, 192, 177, 147, 102, 220, 21, 1, 128, 66, 0, 128, 10, 42, 9, 72, 0, 254,
-            10, 117, 78, 11, 174, 32, 137, 3, 227, 152, 184, 136, 111, 50, 124, 52, 193, 149, 200,
-            191, 169, 49, 46, 59, 85, 117, 128, 128, 180, 150, 104, 14, 56, 127, 153, 72, 162, 85,
-            4, 110, 116, 23, 36, 222, 63, 44, 61, 179, 10, 251, 45, 208, 97, 179, 251, 105, 233,
-            65, 7, 21, 1, 128, 68, 0, 128, 45, 149, 77, 92, 252, 130, 214, 55, 92, 1, 8, 63, 106,
-            151, 222, 182, 55, 32, 193, 6, 169, 251, 206, 210, 117, 172, 161, 122, 242, 40, 104,
-            193, 128, 3, 184, 112, 187, 142, 41, 38, 181, 162, 102, 190, 236, 198, 125, 17, 160,
-            23, 20, 21, 131, 174, 220, 97, 67, 74, 21, 240, 58, 8, 152, 36, 2, 29, 2, 128, 68, 40,
-            128, 99, 252, 106, 144, 221, 238, 225, 7, 45, 183, 22, 125, 147, 84, 172, 254, 102,
-            192, 125, 73, 165, 138, 46, 142, 46, 174, 251, 196, 145, 141, 157, 161, 128, 111, 231,
-            160, 54, 93, 206, 12, 87, 106, 132, 180, 191, 233, 246, 213, 58, 32, 77, 125, 69, 12,
-            46, 41, 239, 198, 7, 126, 124, 69, 95, 83, 235, 128, 110, 87, 5, 52, 135, 77, 138, 144,
-            173, 17, 56, 243, 170, 8, 29, 80, 119, 208, 226, 125, 4, 57, 151, 236, 85, 202, 176,
-            68, 152, 103, 128, 31, 128, 23, 119, 104, 161, 101, 168, 79, 91, 126, 254, 195, 103,
-            156, 4, 9, 135, 202, 98, 153, 34, 0, 128, 62, 232, 28, 0, 131, 149, 77, 20, 235, 183,
-            37, 3, 128, 69, 138, 128, 21, 237, 146, 124, 6, 237, 193, 168, 109, 138, 160, 181, 135,
-            199, 125, 64, 35, 199, 136, 219, 14, 225, 240, 73, 89, 62, 82, 191, 174, 9, 14, 115,
-            128, 157, 239, 194, 239, 131, 84, 1, 216, 188, 149, 20, 251, 147, 123, 105, 147, 144,
-            192, 215, 227, 188, 35, 122, 186, 14, 196, 118, 128, 61, 215, 122, 14, 128, 152, 170,
-            70, 218, 38, 241, 185, 229, 105, 169, 133, 71, 217, 31, 120, 143, 153, 197, 76, 77, 20,
-            97, 128, 249, 222, 139, 211, 237, 195, 221, 101, 255, 128, 42, 56, 242, 58, 240, 194,
-            54, 9, 216, 18, 96, 212, 132, 145, 243, 198, 170, 137, 154, 199, 224, 88, 232, 144,
-            214, 7, 189, 165, 140, 111, 85, 58, 128, 164, 240, 241, 149, 45, 60, 126, 243, 135,
-            187, 165, 160, 152, 222, 134, 224, 241, 208, 208, 100, 208, 23, 133, 199, 235, 32, 225,
-            155, 249, 237, 26, 90, 128, 253, 119, 116, 133, 194, 157, 90, 52, 12, 104, 80, 2, 238,
-            233, 72, 165, 234, 93, 74, 96, 190, 140, 136, 26, 169, 21, 221, 78, 81, 30, 123, 216,
-            153, 1, 128, 70, 0, 128, 36, 179, 75, 10, 84, 142, 195, 162, 126, 246, 61, 132, 34, 61,
-            125, 215, 36, 232, 151, 254, 108, 53, 176, 21, 152, 153, 60, 229, 140, 247, 187, 95,
-            128, 220, 76, 135, 61, 210, 180, 135, 181, 25, 223, 86, 11, 17, 31, 28, 183, 88, 234,
-            152, 175, 180, 64, 43, 55, 11, 233, 210, 20, 56, 92, 254, 56, 128, 29, 167, 213, 132,
-            70, 107, 195, 205, 83, 71, 125, 150, 150, 143, 115, 97, 160, 254, 246, 231, 13, 141,
-            88, 196, 69, 177, 150, 232, 49, 178, 154, 28, 21, 1, 128, 72, 0, 128, 161, 45, 89, 216,
-            201, 44, 25, 233, 57, 104, 76, 73, 196, 38, 145, 211, 85, 122, 45, 79, 236, 165, 61,
-            46, 250, 151, 134, 90, 27, 250, 153, 227, 128, 50, 74, 208, 62, 31, 231, 0, 246, 90,
-            242, 84, 101, 177, 121, 95, 5, 82, 192, 169, 125, 140, 199, 31, 73, 86, 89, 188, 42,
-            23, 94, 231, 77, 21, 1, 128, 72, 0, 128, 197, 101, 124, 144, 61, 24, 111, 57, 242, 165,
-            192, 232, 2, 172, 3, 173, 100, 59, 225, 212, 239, 16, 102, 199, 249, 211, 18, 65, 177,
-            183, 139, 14, 128, 202, 254, 89, 181, 236, 91, 178, 159, 216, 23, 193, 199, 202, 112,
-            232, 59, 248, 108, 11, 224, 121, 244, 163, 64, 245, 193, 141, 92, 122, 221, 112, 239,
-            29, 2, 128, 72, 33, 128, 193, 124, 81, 146, 192, 248, 78, 86, 214, 108, 255, 81, 196,
-            137, 52, 195, 222, 50, 20, 145, 27, 30, 111, 196, 186, 165, 225, 31, 209, 160, 25, 106,
-            128, 217, 51, 253, 224, 111, 227, 122, 253, 40, 197, 40, 122, 125, 61, 16, 35, 220,
-            226, 112, 205, 24, 108, 155, 167, 157, 35, 116, 91, 11, 12, 36, 75, 128, 205, 183, 208,
-            96, 168, 190, 80, 223, 233, 84, 250, 27, 49, 190, 157, 112, 158, 116, 233, 104, 186,
-            175, 39, 188, 250, 141, 166, 95, 190, 57, 37, 159, 128, 0, 174, 224, 95, 31, 225, 63,
-            90, 214, 63, 220, 156, 119, 255, 20, 24, 116, 217, 90, 249, 114, 57, 230, 39, 19, 151,
-            223, 85, 113, 76, 71, 167, 29, 2, 128, 74, 4, 128, 13, 246, 159, 94, 146, 188, 191,
-            215, 193, 136, 42, 21, 92, 222, 123, 85, 52, 147, 75, 123, 122, 12, 109, 45, 111, 54,
-            127, 131, 186, 247, 15, 124, 128, 218, 213, 240, 74, 192, 213, 6, 126, 73, 13, 242,
-            216, 52, 26, 212, 191, 68, 201, 24, 67, 186, 147, 17, 105, 129, 106, 68, 152, 166, 197,
-            194, 251, 128, 190, 13, 223, 15, 36, 16, 241, 209, 83, 233, 2, 211, 44, 142, 207, 186,
-            222, 232, 0, 101, 179, 74, 210, 76, 25, 115, 226, 38, 226, 45, 239, 221, 128, 191, 226,
-            131, 155, 48, 119, 31, 71, 39, 17, 169, 64, 240, 88, 67, 214, 53, 208, 25, 19, 17, 147,
-            119, 244, 65, 34, 13, 64, 164, 227, 46, 64, 169, 3, 128, 75, 7, 128, 145, 110, 172, 5,
-            89, 221, 130, 69, 125, 25, 234, 15, 85, 197, 156, 128, 218, 115, 69, 3, 232, 104, 172,
-            175, 142, 53, 146, 54, 181, 19, 116, 213, 128, 103, 182, 169, 228, 112, 90, 85, 255,
-            178, 80, 34, 187, 5, 198, 192, 30, 213, 144, 26, 190, 232, 79, 235, 132, 70, 38, 171,
-            22, 195, 104, 71, 27, 128, 7, 121, 148, 102, 63, 139, 161, 237, 70, 110, 134, 179, 52,
-            15, 185, 167, 122, 244, 105, 213, 114, 85, 80, 164, 181, 96, 112, 97, 252, 10, 232, 20,
-            128, 104, 49, 107, 69, 172, 6, 243, 148, 42, 60, 217, 168, 232, 176, 114, 117, 54, 103,
-            11, 113, 211, 217, 81, 234, 22, 120, 47, 156, 0, 68, 181, 127, 128, 158, 96, 88, 99,
-            235, 203, 8, 3, 76, 96, 153, 126, 103, 148, 180, 65, 40, 255, 159, 37, 103, 36, 253,
-            239, 51, 46, 253, 220, 224, 5, 224, 89, 128, 230, 132, 84, 213, 242, 170, 185, 74, 49,
-            148, 95, 99, 204, 116, 187, 37, 86, 62, 19, 135, 89, 107, 19, 87, 217, 16, 26, 224, 40,
-            112, 15, 80, 128, 133, 44, 185, 159, 53, 212, 219, 105, 4, 209, 106, 73, 249, 141, 172,
-            187, 5, 209, 180, 161, 247, 194, 189, 42, 126, 119, 55, 202, 106, 19, 47, 224, 161, 2,
-            128, 76, 130, 128, 146, 216, 85, 172, 53, 95, 184, 44, 165, 48, 3, 165, 55, 153, 168,
-            220, 14, 54, 130, 172, 142, 144, 61, 110, 38, 141, 26, 167, 53, 148, 178, 164, 128, 29,
-            90, 113, 38, 192, 68, 23, 9, 53, 251, 180, 109, 77, 228, 61, 144, 99, 28, 87, 91, 91,
-            235, 175, 112, 122, 63, 140, 3, 192, 73, 218, 191, 128, 243, 160, 153, 34, 204, 102,
-            190, 222, 97, 60, 72, 129, 52, 220, 225, 177, 27, 8, 115, 1, 141, 68, 50, 180, 251, 79,
-            48, 189, 160, 119, 167, 181, 128, 86, 176, 169, 22, 117, 91, 22, 2, 162, 99, 190, 136,
-            83, 26, 27, 130, 123, 223, 255, 61, 109, 54, 129, 160, 14, 228, 84, 242, 192, 9, 12,
-            61, 128, 179, 85, 60, 129, 65, 78, 40, 12, 162, 56, 110, 136, 78, 120, 9, 42, 181, 80,
-            15, 211, 18, 223, 106, 120, 41, 162, 110, 238, 196, 70, 37, 163, 21, 1, 128, 80, 0,
-            128, 107, 88, 112, 25, 157, 32, 125, 144, 32, 67, 255, 69, 28, 220, 132, 204, 231, 183,
-            4, 34, 154, 221, 244, 215, 109, 255, 156, 242, 81, 200, 43, 129, 128, 228, 213, 237,
-            88, 101, 114, 217, 251, 86, 167, 137, 0, 169, 130, 6, 161, 135, 148, 86, 67, 25, 73,
-            80, 250, 131, 221, 99, 34, 188, 107, 145, 143, 21, 1, 128, 80, 0, 128, 118, 67, 27, 31,
-            229, 58, 60, 88, 39, 33, 181, 111, 131, 10, 19, 95, 19, 1, 235, 42, 213, 75, 251, 174,
-            102, 49, 104, 110, 56, 147, 150, 245, 128, 61, 91, 205, 128, 135, 221, 207, 179, 4,
-            246, 84, 11, 139, 170, 93, 170, 98, 43, 229, 115, 155, 131, 145, 248, 158, 125, 179,
-            180, 195, 230, 97, 32, 21, 1, 128, 80, 0, 128, 120, 211, 117, 222, 215, 128, 174, 255,
-            43, 34, 130, 2, 13, 75, 76, 30, 106, 110, 163, 6, 154, 189, 243, 94, 73, 65, 28, 122,
-            105, 63, 255, 168, 128, 134, 141, 52, 47, 121, 69, 151, 197, 62, 106, 164, 120, 99,
-            213, 105, 185, 47, 203, 47, 174, 161, 237, 39, 163, 62, 140, 173, 51, 25, 27, 233, 210,
-            29, 2, 128, 80, 48, 128, 182, 14, 111, 242, 214, 91, 30, 157, 227, 73, 92, 195, 119,
-            113, 233, 169, 10, 66, 164, 64, 243, 210, 87, 89, 145, 164, 97, 51, 20, 236, 196, 66,
-            128, 227, 10, 155, 115, 84, 165, 191, 98, 59, 174, 235, 24, 236, 47, 57, 192, 94, 94,
-            142, 48, 221, 163, 125, 203, 241, 223, 30, 70, 234, 26, 21, 15, 128, 223, 53, 9, 139,
-            250, 211, 40, 196, 227, 92, 114, 254, 97, 71, 40, 13, 183, 138, 12, 146, 121, 184, 223,
-            206, 247, 23, 43, 250, 183, 183, 30, 3, 128, 185, 64, 10, 91, 90, 110, 236, 238, 108,
-            84, 240, 23, 129, 35, 21, 231, 173, 49, 229, 222, 6, 158, 104, 135, 195, 42, 164, 8,
-            19, 249, 226, 188, 161, 2, 128, 80, 100, 128, 39, 63, 234, 110, 14, 74, 205, 56, 71,
-            30, 146, 173, 17, 255, 71, 194, 53, 2, 40, 246, 49, 3, 200, 87, 60, 12, 174, 3, 173,
-            232, 91, 75, 128, 106, 229, 188, 61, 215, 82, 61, 241, 83, 228, 209, 90, 90, 108, 97,
-            188, 193, 19, 51, 127, 22, 65, 217, 243, 130, 144, 99, 124, 11, 64, 50, 154, 128, 165,
-            225, 155, 46, 25, 48, 186, 139, 231, 131, 131, 104, 138, 213, 226, 12, 85, 196, 56,
-            188, 172, 219, 66, 227, 7, 184, 122, 206, 244, 217, 95, 203, 128, 226, 61, 190, 51, 27,
-            20, 129, 141, 145, 191, 146, 104, 112, 127, 14, 243, 219, 240, 92, 28, 240, 164, 140,
-            202, 177, 83, 242, 216, 184, 254, 187, 49, 128, 45, 103, 242, 215, 209, 38, 239, 115,
-            143, 125, 133, 0, 242, 245, 230, 151, 97, 127, 198, 197, 207, 68, 181, 189, 235, 243,
-            2, 119, 147, 29, 232, 236, 29, 2, 128, 88, 4, 128, 209, 166, 194, 156, 74, 63, 96, 67,
-            124, 17, 177, 209, 242, 253, 158, 94, 191, 33, 82, 28, 186, 39, 23, 90, 47, 128, 137,
-            29, 189, 80, 115, 154, 128, 144, 220, 234, 16, 35, 73, 16, 19, 39, 193, 127, 112, 222,
-            83, 124, 69, 15, 103, 134, 152, 56, 241, 93, 250, 110, 212, 148, 131, 168, 93, 87, 68,
-            128, 194, 214, 231, 38, 178, 228, 88, 96, 73, 3, 20, 163, 187, 185, 36, 64, 29, 94,
-            212, 175, 134, 26, 180, 189, 215, 80, 242, 201, 118, 223, 175, 104, 128, 147, 127, 179,
-            31, 215, 127, 43, 166, 124, 197, 176, 96, 121, 3, 30, 194, 29, 152, 42, 63, 132, 242,
-            56, 29, 177, 2, 59, 157, 106, 100, 59, 88, 161, 2, 128, 88, 36, 128, 127, 75, 172, 70,
-            230, 162, 15, 220, 166, 18, 251, 117, 175, 189, 33, 18, 88, 194, 133, 240, 56, 6, 24,
-            185, 69, 100, 49, 220, 246, 137, 122, 161, 128, 33, 182, 203, 76, 50, 195, 54, 201, 37,
-            15, 171, 91, 195, 125, 94, 45, 51, 122, 82, 195, 41, 61, 248, 7, 225, 173, 209, 153,
-            82, 138, 100, 215, 128, 176, 94, 194, 60, 186, 120, 95, 101, 113, 242, 194, 253, 120,
-            187, 6, 194, 28, 107, 69, 149, 19, 141, 111, 199, 191, 80, 110, 102, 221, 26, 96, 14,
-            128, 19, 96, 232, 153, 96, 228, 173, 151, 114, 115, 151, 162, 72, 137, 252, 115, 186,
-            39, 117, 166, 201, 187, 249, 84, 133, 20, 176, 103, 174, 135, 186, 26, 128, 220, 71,
-            250, 95, 82, 92, 121, 191, 47, 232, 57, 35, 66, 122, 245, 170, 83, 13, 132, 172, 11,
-            94, 36, 85, 74, 161, 24, 205, 99, 91, 206, 174, 21, 1, 128, 96, 0, 128, 159, 224, 152,
-            91, 85, 58, 120, 77, 126, 158, 78, 222, 12, 63, 23, 174, 19, 24, 132, 234, 254, 100,
-            168, 16, 119, 193, 81, 33, 84, 145, 253, 216, 128, 76, 106, 89, 245, 47, 41, 72, 11,
-            113, 19, 37, 199, 3, 80, 1, 38, 164, 254, 98, 203, 198, 2, 208, 61, 40, 163, 229, 190,
-            8, 226, 129, 201, 153, 1, 128, 96, 16, 128, 56, 218, 99, 176, 18, 235, 65, 126, 233,
-            235, 222, 45, 149, 159, 64, 234, 244, 255, 49, 136, 164, 47, 42, 124, 40, 153, 108, 36,
-            229, 95, 179, 183, 128, 38, 61, 43, 77, 133, 2, 144, 166, 102, 115, 46, 46, 194, 103,
-            196, 133, 223, 102, 165, 162, 148, 58, 228, 59, 40, 237, 253, 201, 3, 29, 192, 115,
-            128, 204, 244, 29, 1, 131, 82, 190, 157, 130, 204, 191, 47, 234, 243, 96, 63, 83, 214,
-            24, 202, 241, 204, 211, 229, 234, 68, 87, 49, 104, 226, 45, 151, 29, 2, 128, 96, 36,
-            128, 170, 19, 237, 40, 151, 134, 247, 214, 15, 157, 156, 46, 172, 109, 141, 125, 63,
-            58, 136, 66, 223, 196, 36, 196, 175, 34, 239, 217, 29, 115, 56, 72, 128, 185, 100, 226,
-            115, 142, 44, 10, 132, 15, 59, 230, 226, 19, 111, 66, 60, 196, 190, 70, 156, 227, 71,
-            97, 223, 245, 85, 205, 76, 33, 164, 22, 30, 128, 215, 148, 100, 192, 28, 203, 102, 174,
-            139, 174, 149, 16, 0, 249, 105, 247, 33, 122, 204, 158, 214, 21, 132, 197, 47, 132,
-            253, 128, 27, 242, 51, 102, 128, 137, 162, 236, 191, 232, 64, 249, 240, 59, 223, 128,
-            146, 22, 175, 88, 156, 251, 36, 151, 164, 104, 254, 0, 126, 188, 77, 161, 167, 129, 91,
-            119, 24, 29, 2, 128, 96, 130, 128, 63, 84, 138, 205, 182, 235, 245, 13, 27, 254, 60,
-            112, 15, 30, 127, 168, 126, 24, 75, 241, 44, 158, 208, 230, 96, 50, 78, 189, 183, 119,
-            81, 77, 128, 174, 90, 93, 254, 75, 238, 186, 25, 237, 176, 237, 130, 200, 73, 137, 149,
-            16, 88, 153, 224, 29, 226, 25, 159, 169, 225, 15, 251, 3, 77, 51, 22, 128, 233, 182,
-            149, 136, 47, 250, 77, 62, 117, 85, 241, 152, 208, 194, 239, 55, 83, 33, 141, 72, 181,
-            35, 222, 189, 14, 194, 192, 219, 105, 59, 243, 174, 128, 100, 77, 122, 64, 252, 58,
-            156, 218, 38, 73, 211, 226, 150, 159, 204, 211, 129, 144, 128, 174, 221, 25, 47, 24,
-            246, 189, 149, 225, 82, 193, 27, 37, 29, 2, 128, 96, 130, 128, 65, 236, 157, 146, 30,
-            46, 78, 18, 38, 217, 5, 201, 30, 25, 131, 172, 6, 27, 132, 10, 140, 203, 39, 119, 47,
-            170, 234, 218, 117, 196, 230, 182, 128, 132, 95, 199, 119, 24, 229, 151, 79, 229, 111,
-            139, 76, 162, 21, 121, 222, 35, 225, 249, 122, 7, 120, 147, 39, 224, 166, 122, 128,
-            234, 34, 238, 80, 128, 107, 223, 98, 172, 80, 39, 232, 188, 107, 50, 4, 103, 163, 181,
-            209, 122, 92, 137, 70, 31, 44, 41, 148, 91, 169, 117, 182, 41, 211, 89, 98, 91, 128,
-            228, 235, 89, 50, 120, 146, 61, 144, 192, 28, 171, 80, 105, 55, 25, 188, 150, 251, 250,
-            53, 158, 250, 8, 72, 132, 170, 242, 111, 136, 17, 129, 175, 161, 2, 128, 102, 16, 128,
-            195, 24, 127, 114, 196, 12, 212, 133, 156, 131, 68, 52, 186, 121, 245, 206, 206, 253,
-            93, 47, 243, 237, 7, 199, 122, 136, 203, 44, 208, 55, 57, 177, 128, 230, 18, 203, 148,
-            54, 243, 240, 178, 125, 170, 196, 123, 165, 32, 172, 45, 236, 201, 207, 215, 183, 37,
-            141, 254, 104, 233, 234, 0, 155, 37, 13, 169, 128, 200, 185, 135, 124, 237, 203, 8, 45,
-            45, 37, 118, 135, 163, 60, 194, 91, 75, 94, 167, 158, 142, 103, 98, 248, 155, 98, 74,
-            228, 193, 32, 221, 222, 128, 238, 185, 90, 178, 116, 31, 62, 118, 32, 76, 87, 75, 94,
-            114, 90, 248, 222, 176, 208, 121, 32, 209, 24, 215, 58, 179, 26, 249, 171, 71, 183,
-            165, 128, 81, 68, 255, 76, 195, 242, 230, 48, 55, 52, 67, 148, 57, 201, 16, 188, 239,
-            1, 44, 174, 46, 147, 53, 61, 113, 118, 171, 52, 190, 156, 254, 45, 37, 3, 128, 102, 66,
-            128, 132, 82, 100, 77, 235, 27, 39, 120, 123, 40, 201, 93, 123, 227, 62, 248, 136, 240,
-            219, 20, 63, 16, 104, 70, 130, 234, 50, 82, 86, 243, 88, 21, 128, 204, 212, 233, 173,
-            184, 104, 146, 165, 37, 213, 40, 222, 108, 233, 67, 76, 227, 154, 166, 201, 223, 135,
-            56, 218, 37, 87, 100, 38, 181, 231, 195, 45, 128, 212, 46, 152, 91, 217, 127, 246, 31,
-            122, 123, 99, 174, 5, 36, 201, 160, 140, 49, 188, 171, 173, 0, 10, 91, 86, 219, 132,
-            235, 151, 142, 102, 204, 128, 118, 189, 211, 100, 113, 55, 100, 231, 101, 191, 115, 15,
-            6, 14, 96, 62, 173, 203, 86, 234, 171, 90, 101, 205, 66, 200, 154, 87, 253, 46, 45, 90,
-            128, 106, 193, 144, 36, 121, 21, 180, 146, 57, 199, 127, 143, 105, 167, 163, 127, 121,
-            131, 231, 104, 46, 138, 233, 239, 54, 22, 141, 112, 157, 107, 231, 102, 128, 195, 123,
-            74, 111, 154, 33, 223, 73, 42, 218, 188, 4, 184, 253, 28, 231, 56, 247, 88, 157, 38,
-            98, 51, 51, 56, 229, 0, 246, 15, 222, 213, 126, 161, 2, 128, 104, 192, 128, 202, 155,
-            51, 157, 153, 27, 36, 132, 85, 87, 192, 105, 86, 104, 153, 154, 153, 17, 228, 6, 137,
-            88, 251, 2, 108, 48, 216, 253, 179, 189, 218, 159, 128, 116, 62, 160, 126, 231, 21,
-            233, 219, 168, 57, 155, 51, 226, 34, 141, 57, 216, 56, 159, 89, 157, 69, 100, 82, 187,
-            237, 47, 95, 233, 132, 73, 145, 128, 244, 20, 109, 23, 228, 245, 234, 61, 162, 244,
-            158, 15, 144, 56, 150, 76, 226, 115, 40, 74, 172, 103, 221, 84, 175, 94, 241, 93, 68,
-            92, 167, 175, 128, 155, 162, 118, 102, 232, 199, 53, 180, 102, 234, 12, 59, 5, 54, 118,
-            86, 206, 23, 51, 16, 102, 241, 57, 220, 97, 93, 84, 126, 40, 210, 152, 178, 128, 67,
-            137, 174, 4, 185, 81, 164, 48, 49, 190, 177, 2, 83, 176, 187, 172, 137, 87, 239, 145,
-            222, 236, 13, 129, 161, 168, 75, 180, 49, 209, 3, 206, 161, 2, 128, 106, 1, 128, 110,
-            165, 223, 156, 143, 213, 203, 224, 42, 244, 228, 19, 10, 114, 76, 45, 149, 168, 87, 47,
-            127, 240, 118, 170, 133, 42, 11, 151, 103, 178, 87, 145, 128, 35, 60, 203, 97, 0, 2,
-            74, 33, 42, 131, 152, 25, 104, 95, 163, 10, 219, 89, 227, 169, 39, 111, 223, 55, 167,
-            157, 168, 234, 119, 255, 172, 253, 128, 118, 228, 125, 133, 59, 107, 99, 89, 137, 56,
-            118, 185, 11, 71, 188, 233, 216, 202, 165, 69, 56, 86, 150, 15, 221, 100, 17, 114, 95,
-            36, 194, 254, 128, 222, 246, 236, 115, 201, 246, 46, 78, 17, 4, 224, 246, 219, 242,
-            153, 0, 90, 194, 206, 145, 17, 121, 123, 12, 202, 95, 77, 206, 122, 71, 113, 53, 128,
-            131, 171, 66, 236, 52, 183, 12, 251, 231, 12, 145, 173, 209, 229, 150, 9, 13, 92, 245,
-            214, 83, 128, 118, 31, 199, 44, 59, 235, 197, 159, 65, 239, 37, 3, 128, 106, 24, 128,
-            164, 15, 80, 80, 116, 195, 129, 192, 66, 215, 178, 238, 140, 166, 234, 84, 119, 116,
-            86, 218, 7, 108, 46, 230, 41, 152, 152, 196, 21, 203, 241, 194, 128, 71, 155, 58, 15,
-            6, 20, 206, 59, 220, 136, 11, 212, 160, 136, 29, 194, 105, 154, 198, 90, 72, 61, 221,
-            76, 50, 22, 34, 205, 118, 38, 27, 179, 128, 58, 116, 136, 151, 96, 35, 175, 223, 187,
-            192, 6, 93, 58, 64, 113, 232, 202, 155, 115, 154, 236, 36, 237, 238, 154, 65, 148, 23,
-            205, 208, 113, 89, 128, 216, 20, 108, 132, 135, 106, 210, 140, 169, 154, 204, 197, 84,
-            210, 195, 80, 118, 102, 82, 52, 188, 215, 225, 245, 33, 78, 85, 82, 34, 141, 164, 156,
-            128, 189, 14, 200, 17, 213, 142, 95, 135, 126, 25, 94, 74, 150, 84, 30, 177, 42, 80,
-            114, 223, 200, 148, 126, 188, 54, 203, 54, 70, 245, 107, 204, 207, 128, 13, 47, 141,
-            15, 23, 153, 183, 11, 166, 236, 126, 144, 247, 158, 139, 197, 236, 45, 35, 62, 222,
-            178, 90, 230, 243, 208, 225, 140, 14, 58, 82, 225, 29, 2, 128, 112, 8, 128, 89, 243,
-            233, 147, 3, 26, 120, 101, 149, 208, 162, 40, 17, 17, 157, 200, 95, 198, 247, 169, 13,
-            28, 77, 100, 237, 207, 40, 90, 231, 190, 24, 244, 128, 189, 211, 23, 134, 67, 0, 237,
-            115, 70, 224, 129, 36, 239, 170, 9, 59, 177, 252, 9, 245, 159, 254, 117, 82, 18, 37,
-            151, 20, 136, 229, 65, 104, 128, 113, 225, 194, 204, 123, 135, 77, 161, 185, 179, 148,
-            12, 19, 16, 255, 110, 100, 106, 135, 8, 154, 60, 207, 185, 105, 46, 209, 146, 180, 173,
-            187, 187, 128, 241, 136, 113, 168, 218, 191, 117, 178, 239, 227, 134, 71, 107, 108, 99,
-            11, 108, 59, 71, 45, 57, 150, 1, 223, 221, 217, 245, 247, 179, 107, 44, 65, 21, 1, 128,
-            128, 1, 128, 162, 99, 243, 142, 139, 138, 52, 219, 245, 233, 147, 205, 183, 113, 216,
-            219, 102, 217, 207, 63, 0, 241, 44, 67, 65, 165, 60, 202, 3, 25, 61, 168, 128, 226,
-            253, 235, 0, 63, 77, 81, 165, 219, 226, 98, 36, 11, 2, 95, 82, 63, 94, 248, 105, 224,
-            122, 103, 36, 213, 199, 194, 97, 19, 86, 148, 71, 21, 1, 128, 128, 4, 128, 106, 30,
-            175, 29, 234, 3, 224, 11, 238, 249, 213, 242, 149, 156, 241, 17, 12, 8, 111, 102, 113,
-            183, 189, 107, 117, 92, 243, 49, 119, 100, 157, 2, 128, 47, 31, 113, 138, 162, 38, 45,
-            121, 35, 66, 92, 66, 123, 14, 60, 7, 56, 7, 18, 251, 83, 132, 185, 133, 234, 142, 23,
-            134, 255, 226, 193, 226, 21, 1, 128, 128, 8, 128, 117, 248, 108, 107, 170, 240, 211,
-            12, 245, 183, 88, 117, 24, 17, 151, 163, 235, 30, 123, 75, 119, 36, 214, 101, 9, 31,
-            56, 136, 62, 169, 214, 201, 128, 241, 135, 188, 145, 214, 54, 145, 211, 134, 84, 78,
-            233, 163, 211, 126, 50, 229, 59, 108, 7, 192, 201, 120, 217, 244, 4, 171, 37, 112, 17,
-            213, 32, 21, 1, 128, 128, 32, 128, 20, 218, 107, 7, 225, 45, 180, 109, 68, 192, 44,
-            175, 13, 105, 4, 30, 69, 67, 154, 204, 40, 234, 58, 229, 166, 116, 153, 90, 96, 236,
-            83, 243, 128, 43, 137, 64, 68, 20, 83, 137, 172, 180, 234, 41, 81, 40, 183, 100, 105,
-            49, 173, 199, 255, 114, 245, 116, 44, 48, 133, 72, 6, 239, 244, 155, 13, 153, 1, 128,
-            128, 40, 128, 211, 237, 238, 187, 159, 98, 174, 107, 152, 87, 124, 58, 74, 72, 19, 3,
-            72, 68, 154, 175, 195, 170, 178, 255, 126, 122, 155, 17, 100, 188, 195, 184, 128, 80,
-            193, 104, 32, 118, 12, 123, 135, 92, 13, 104, 186, 8, 160, 145, 243, 97, 216, 39, 138,
-            193, 92, 180, 216, 180, 85, 7, 83, 195, 147, 188, 69, 128, 119, 241, 145, 174, 141,
-            253, 128, 131, 21, 142, 98, 30, 65, 77, 18, 136, 127, 195, 50, 85, 82, 180, 229, 185,
-            5, 241, 129, 66, 61, 245, 150, 14, 21, 1, 128, 128, 128, 128, 6, 168, 240, 47, 13, 145,
-            75, 182, 82, 13, 103, 242, 179, 218, 141, 235, 30, 39, 131, 213, 61, 67, 25, 10, 116,
-            240, 202, 160, 190, 25, 37, 23, 128, 179, 238, 43, 1, 119, 100, 133, 176, 191, 22, 236,
-            42, 161, 140, 88, 103, 180, 27, 118, 199, 223, 33, 16, 124, 203, 154, 201, 171, 133,
-            239, 8, 177, 21, 1, 128, 128, 128, 128, 92, 15, 205, 37, 69, 142, 125, 166, 70, 254,
-            120, 234, 224, 28, 204, 228, 11, 94, 204, 29, 51, 106, 43, 221, 253, 169, 25, 196, 196,
-            146, 220, 101, 128, 254, 131, 149, 21, 114, 84, 74, 110, 236, 41, 87, 16, 184, 117,
-            108, 235, 155, 129, 221, 8, 161, 29, 167, 11, 109, 53, 56, 35, 19, 194, 242, 38, 153,
-            1, 128, 128, 132, 128, 79, 124, 198, 204, 28, 39, 172, 232, 157, 57, 219, 58, 53, 1,
-            223, 177, 19, 158, 123, 149, 60, 207, 97, 87, 25, 21, 135, 66, 5, 40, 251, 131, 128,
-            107, 240, 84, 29, 114, 39, 127, 111, 235, 204, 128, 34, 231, 136, 195, 194, 31, 147,
-            84, 195, 234, 186, 185, 181, 215, 84, 9, 196, 253, 2, 102, 197, 128, 213, 51, 150, 141,
-            114, 57, 73, 39, 179, 229, 35, 125, 150, 118, 203, 98, 118, 212, 99, 239, 27, 187, 219,
-            155, 60, 243, 104, 63, 166, 75, 23, 176, 29, 2, 128, 128, 137, 128, 135, 108, 218, 121,
-            214, 100, 146, 113, 109, 138, 148, 84, 8, 99, 132, 150, 138, 215, 66, 174, 67, 108, 18,
-            143, 83, 53, 103, 47, 142, 79, 90, 114, 128, 58, 242, 189, 112, 214, 121, 145, 124, 17,
-            215, 84, 35, 45, 238, 218, 156, 148, 76, 253, 178, 249, 156, 48, 120, 27, 241, 110,
-            111, 227, 128, 100, 25, 128, 231, 15, 152, 12, 119, 145, 120, 170, 223, 45, 159, 7,
-            224, 255, 161, 14, 126, 4, 187, 154, 127, 81, 229, 57, 25, 111, 254, 188, 45, 141, 71,
-            226, 128, 92, 35, 71, 228, 144, 180, 211, 27, 31, 75, 118, 2, 222, 1, 189, 24, 118,
-            235, 79, 161, 171, 163, 148, 87, 112, 122, 60, 141, 151, 94, 94, 110, 161, 2, 128, 128,
-            197, 128, 68, 170, 34, 114, 233, 186, 96, 89, 12, 41, 65, 99, 203, 12, 166, 182, 113,
-            224, 158, 118, 45, 224, 199, 47, 222, 115, 129, 57, 74, 103, 109, 148, 128, 228, 5, 87,
-            70, 180, 113, 255, 19, 83, 242, 155, 145, 165, 39, 198, 17, 47, 15, 206, 91, 119, 121,
-            147, 91, 184, 199, 189, 52, 12, 89, 30, 141, 128, 2, 133, 57, 91, 19, 131, 13, 139, 89,
-            103, 128, 214, 94, 83, 60, 54, 166, 86, 83, 167, 104, 115, 166, 226, 238, 47, 114, 168,
-            19, 137, 209, 190, 128, 171, 111, 148, 206, 245, 10, 9, 30, 64, 47, 205, 179, 232, 110,
-            86, 245, 150, 148, 97, 254, 1, 175, 232, 236, 145, 234, 166, 112, 221, 13, 123, 211,
-            128, 20, 134, 200, 71, 105, 242, 216, 136, 33, 107, 129, 188, 203, 22, 63, 79, 43, 219,
-            148, 54, 213, 97, 76, 1, 70, 250, 169, 101, 93, 85, 236, 200, 21, 1, 128, 129, 0, 128,
-            50, 160, 105, 65, 9, 60, 163, 133, 232, 24, 48, 217, 64, 197, 231, 36, 22, 238, 4, 40,
-            113, 218, 48, 110, 107, 39, 48, 30, 155, 138, 108, 241, 128, 255, 50, 140, 29, 245, 41,
-            57, 254, 169, 28, 155, 70, 60, 48, 202, 226, 19, 84, 86, 231, 239, 156, 10, 99, 168,
-            97, 234, 227, 180, 169, 189, 226, 21, 1, 128, 129, 0, 128, 152, 89, 99, 18, 66, 175,
-            75, 13, 163, 140, 151, 70, 236, 61, 205, 137, 183, 222, 79, 210, 149, 115, 235, 220,
-            118, 86, 21, 137, 27, 177, 79, 98, 128, 25, 134, 98, 211, 142, 125, 26, 23, 104, 200,
-            112, 213, 67, 231, 48, 153, 68, 83, 72, 213, 247, 10, 233, 132, 107, 90, 5, 104, 110,
-            36, 183, 38, 21, 1, 128, 129, 0, 128, 179, 211, 136, 251, 136, 193, 230, 95, 69, 242,
-            155, 4, 1, 8, 238, 88, 79, 240, 183, 55, 158, 55, 149, 85, 201, 141, 150, 180, 227,
-            106, 128, 238, 128, 117, 90, 177, 82, 117, 229, 77, 56, 203, 54, 212, 48, 201, 95, 222,
-            67, 155, 33, 186, 66, 254, 11, 99, 196, 64, 7, 103, 210, 240, 165, 5, 166, 153, 1, 128,
-            129, 32, 128, 38, 10, 87, 159, 192, 34, 81, 171, 134, 249, 97, 51, 92, 240, 167, 48,
-            18, 228, 114, 153, 153, 84, 110, 217, 193, 235, 96, 242, 240, 122, 135, 88, 128, 18,
-            23, 137, 236, 58, 73, 221, 204, 242, 214, 175, 205, 238, 169, 178, 253, 108, 93, 51,
-            17, 82, 237, 126, 250, 105, 238, 209, 74, 57, 171, 74, 119, 128, 157, 54, 204, 242,
-            157, 246, 97, 3, 218, 99, 248, 138, 197, 252, 59, 250, 26, 3, 109, 42, 38, 198, 196,
-            127, 135, 217, 14, 243, 98, 152, 115, 68, 153, 1, 128, 129, 32, 128, 123, 100, 135,
-            187, 137, 113, 69, 126, 17, 131, 234, 238, 109, 111, 195, 108, 102, 68, 162, 51, 203,
-            170, 206, 162, 206, 21, 56, 80, 240, 118, 108, 166, 128, 44, 100, 71, 150, 100, 156,
-            207, 214, 40, 147, 60, 204, 245, 95, 28, 174, 150, 13, 5, 203, 248, 230, 79, 251, 129,
-            93, 100, 227, 34, 22, 96, 140, 128, 94, 150, 96, 17, 67, 178, 138, 45, 53, 131, 50, 34,
-            155, 33, 209, 53, 203, 54, 194, 252, 133, 176, 118, 184, 227, 146, 249, 145, 82, 123,
-            216, 134, 161, 2, 128, 129, 98, 128, 219, 234, 33, 218, 109, 77, 180, 37, 144, 233, 69,
-            144, 233, 242, 170, 141, 5, 177, 57, 203, 102, 151, 99, 64, 162, 209, 31, 161, 250, 27,
-            60, 25, 128, 150, 201, 83, 108, 172, 200, 149, 175, 152, 239, 68, 46, 70, 171, 161,
-            225, 213, 67, 134, 52, 20, 196, 112, 18, 17, 15, 153, 79, 229, 12, 116, 125, 128, 240,
-            166, 193, 252, 109, 246, 105, 196, 144, 75, 63, 183, 160, 251, 118, 10, 35, 208, 150,
-            210, 210, 76, 56, 240, 185, 208, 105, 202, 133, 184, 200, 224, 128, 37, 123, 205, 80,
-            214, 27, 8, 122, 131, 47, 98, 14, 100, 97, 128, 153, 35, 146, 233, 11, 8, 35, 91, 135,
-            99, 43, 178, 171, 39, 252, 255, 60, 128, 155, 121, 239, 202, 225, 89, 85, 106, 242,
-            110, 68, 156, 254, 69, 176, 142, 234, 163, 175, 133, 1, 11, 6, 111, 36, 186, 161, 149,
-            252, 236, 248, 85, 29, 2, 128, 130, 3, 128, 176, 187, 196, 23, 87, 249, 84, 214, 236,
-            132, 108, 249, 28, 128, 108, 136, 100, 240, 72, 145, 25, 138, 140, 16, 186, 154, 138,
-            180, 53, 24, 47, 109, 128, 186, 239, 101, 239, 85, 128, 120, 156, 207, 35, 89, 115, 77,
-            40, 152, 176, 12, 122, 235, 235, 169, 167, 40, 16, 254, 226, 89, 115, 111, 33, 8, 165,
-            128, 222, 28, 245, 168, 138, 140, 212, 113, 167, 118, 253, 96, 16, 123, 78, 64, 36,
-            167, 102, 200, 43, 220, 201, 230, 158, 19, 163, 214, 115, 72, 229, 64, 128, 5, 199,
-            109, 1, 36, 59, 152, 39, 186, 84, 227, 31, 181, 54, 0, 79, 143, 179, 34, 5, 117, 206,
-            38, 198, 200, 53, 22, 36, 174, 18, 88, 175, 153, 1, 128, 130, 4, 128, 195, 164, 72,
-            162, 124, 144, 196, 12, 227, 115, 166, 145, 92, 48, 107, 49, 45, 209, 46, 223, 129, 22,
-            239, 91, 60, 225, 63, 36, 15, 234, 232, 30, 128, 20, 210, 136, 31, 76, 46, 16, 105,
-            228, 105, 33, 215, 159, 19, 76, 145, 141, 177, 235, 219, 40, 216, 254, 28, 155, 108, 0,
-            57, 83, 45, 82, 147, 128, 128, 189, 204, 239, 128, 208, 64, 248, 16, 14, 204, 133, 63,
-            128, 1, 41, 75, 215, 249, 197, 67, 75, 10, 10, 106, 156, 99, 16, 124, 145, 72, 9, 29,
-            2, 128, 130, 65, 128, 163, 145, 128, 255, 22, 222, 252, 142, 76, 67, 177, 82, 244, 65,
-            238, 141, 159, 142, 125, 80, 185, 246, 144, 241, 101, 228, 224, 127, 24, 56, 207, 203,
-            128, 228, 7, 147, 212, 200, 72, 88, 64, 122, 71, 28, 223, 0, 224, 25, 148, 50, 17, 38,
-            103, 157, 23, 220, 96, 71, 200, 67, 103, 197, 41, 45, 219, 128, 48, 112, 207, 175, 132,
-            115, 195, 32, 43, 137, 140, 240, 76, 228, 183, 173, 153, 91, 192, 52, 43, 24, 114, 252,
-            88, 208, 134, 220, 98, 100, 28, 121, 128, 93, 184, 246, 178, 33, 95, 61, 160, 196, 58,
-            228, 246, 154, 237, 79, 104, 70, 115, 137, 145, 49, 59, 166, 65, 206, 87, 9, 141, 193,
-            58, 183, 167, 29, 2, 128, 130, 80, 128, 136, 6, 211, 176, 113, 135, 136, 124, 153, 25,
-            252, 187, 129, 19, 255, 187, 123, 187, 55, 138, 135, 221, 95, 164, 169, 147, 200, 3,
-            34, 173, 92, 215, 128, 22, 251, 200, 31, 185, 40, 63, 181, 202, 11, 16, 199, 231, 238,
-            150, 78, 10, 119, 249, 174, 121, 242, 126, 178, 163, 164, 225, 210, 44, 250, 195, 36,
-            128, 88, 140, 254, 161, 229, 126, 185, 1, 117, 190, 83, 5, 132, 184, 246, 32, 7, 124,
-            219, 254, 62, 122, 156, 25, 22, 205, 60, 141, 29, 145, 146, 48, 128, 17, 102, 153, 1,
-            238, 171, 94, 16, 209, 246, 112, 201, 70, 91, 70, 166, 112, 54, 182, 201, 234, 28, 138,
-            66, 79, 52, 232, 83, 177, 87, 253, 30, 21, 1, 128, 132, 0, 128, 119, 156, 55, 177, 239,
-            189, 26, 253, 96, 127, 108, 244, 126, 18, 155, 52, 148, 36, 34, 156, 254, 232, 74, 206,
-            52, 169, 109, 97, 29, 232, 103, 78, 128, 85, 98, 102, 231, 149, 52, 146, 122, 165, 216,
-            177, 103, 119, 221, 0, 162, 39, 125, 200, 204, 101, 65, 238, 72, 159, 50, 115, 175,
-            221, 150, 164, 234, 153, 1, 128, 132, 1, 128, 140, 12, 77, 69, 80, 210, 193, 235, 115,
-            127, 76, 127, 90, 127, 1, 106, 160, 145, 61, 13, 252, 101, 241, 186, 98, 123, 20, 249,
-            30, 33, 189, 206, 128, 119, 131, 216, 72, 128, 83, 20, 51, 132, 104, 35, 120, 135, 89,
-            254, 155, 34, 121, 11, 134, 221, 208, 211, 13, 137, 17, 126, 128, 201, 221, 156, 149,
-            128, 245, 118, 131, 69, 149, 84, 219, 92, 28, 114, 0, 55, 176, 192, 105, 102, 245, 79,
-            190, 13, 20, 178, 201, 159, 63, 131, 19, 196, 8, 252, 8, 122, 153, 1, 128, 132, 2, 128,
-            32, 197, 58, 44, 68, 67, 163, 199, 8, 65, 201, 230, 30, 23, 138, 247, 63, 33, 175, 27,
-            212, 201, 207, 40, 207, 247, 139, 215, 203, 232, 15, 61, 128, 52, 23, 39, 243, 83, 217,
-            19, 158, 141, 158, 211, 228, 49, 217, 113, 137, 171, 117, 244, 189, 164, 92, 205, 131,
-            44, 246, 11, 193, 192, 186, 34, 66, 128, 241, 131, 3, 173, 200, 94, 21, 39, 93, 223,
-            119, 126, 120, 228, 98, 28, 97, 206, 217, 180, 70, 199, 149, 8, 201, 137, 193, 185,
-            196, 94, 6, 5, 153, 1, 128, 132, 16, 128, 46, 239, 231, 131, 21, 170, 103, 147, 13, 24,
-            26, 99, 75, 230, 132, 251, 103, 201, 193, 144, 106, 253, 101, 192, 12, 141, 255, 118,
-            204, 156, 235, 228, 128, 168, 28, 77, 16, 108, 165, 21, 185, 9, 249, 134, 49, 137, 12,
-            109, 255, 194, 208, 45, 144, 9, 219, 142, 82, 92, 135, 245, 28, 245, 163, 39, 248, 128,
-            160, 148, 193, 68, 253, 77, 124, 212, 175, 38, 83, 17, 235, 215, 2, 80, 182, 15, 77,
-            247, 33, 40, 57, 154, 27, 144, 76, 44, 122, 146, 88, 156, 153, 1, 128, 134, 0, 128,
-            172, 93, 246, 87, 152, 19, 111, 66, 200, 187, 110, 23, 93, 61, 51, 20, 116, 28, 99, 65,
-            232, 151, 154, 19, 26, 195, 250, 123, 190, 235, 183, 152, 128, 126, 191, 116, 81, 156,
-            13, 171, 84, 179, 229, 214, 56, 171, 219, 74, 233, 55, 254, 10, 83, 255, 74, 87, 98,
-            42, 12, 250, 10, 50, 126, 197, 139, 128, 122, 130, 182, 225, 237, 48, 154, 238, 157,
-            116, 230, 205, 194, 14, 241, 239, 40, 214, 55, 210, 189, 154, 129, 25, 14, 220, 237,
-            62, 114, 56, 125, 71, 21, 1, 128, 136, 0, 128, 134, 39, 13, 176, 120, 64, 39, 172, 92,
-            6, 20, 121, 214, 117, 164, 19, 34, 53, 74, 116, 71, 230, 193, 148, 125, 76, 177, 94,
-            103, 81, 230, 232, 128, 210, 50, 102, 87, 29, 57, 178, 141, 188, 188, 203, 246, 3, 200,
-            197, 237, 189, 133, 188, 163, 202, 252, 69, 7, 12, 31, 106, 173, 47, 146, 93, 216, 21,
-            1, 128, 136, 0, 128, 144, 48, 232, 254, 218, 114, 107, 190, 107, 36, 217, 19, 8, 94,
-            202, 15, 139, 220, 148, 60, 87, 132, 145, 197, 169, 96, 180, 138, 222, 218, 121, 229,
-            128, 123, 196, 191, 197, 30, 36, 235, 30, 225, 250, 40, 151, 147, 227, 94, 4, 188, 164,
-            136, 185, 172, 134, 84, 155, 27, 160, 76, 79, 247, 50, 74, 47, 153, 1, 128, 136, 64,
-            128, 121, 128, 164, 64, 155, 210, 90, 233, 120, 19, 202, 53, 253, 164, 51, 229, 88,
-            214, 63, 119, 104, 66, 62, 72, 162, 20, 203, 205, 194, 141, 16, 170, 128, 216, 10, 170,
-            208, 104, 173, 34, 27, 226, 5, 190, 149, 190, 226, 119, 78, 133, 75, 71, 32, 233, 44,
-            226, 106, 224, 150, 7, 62, 182, 76, 160, 241, 128, 112, 63, 4, 244, 6, 38, 205, 11, 15,
-            255, 6, 171, 170, 119, 123, 0, 130, 16, 180, 245, 221, 241, 88, 85, 109, 49, 4, 0, 20,
-            78, 164, 153, 29, 2, 128, 136, 65, 128, 150, 50, 44, 11, 154, 242, 220, 90, 177, 244,
-            196, 15, 39, 193, 42, 147, 229, 107, 47, 52, 32, 41, 103, 46, 40, 218, 225, 254, 164,
-            198, 82, 219, 128, 147, 157, 21, 197, 28, 129, 163, 215, 133, 57, 177, 34, 174, 4, 250,
-            243, 66, 26, 114, 177, 18, 7, 28, 38, 24, 96, 180, 185, 241, 246, 172, 40, 128, 227,
-            199, 72, 45, 200, 36, 141, 61, 95, 244, 41, 72, 79, 254, 134, 27, 77, 36, 47, 134, 228,
-            25, 212, 142, 193, 71, 36, 116, 135, 54, 98, 124, 128, 112, 182, 43, 60, 67, 155, 213,
-            35, 40, 115, 195, 103, 67, 47, 11, 128, 135, 49, 11, 210, 97, 191, 245, 88, 91, 88,
-            189, 74, 144, 92, 161, 68, 153, 1, 128, 136, 128, 128, 228, 202, 209, 49, 182, 6, 236,
-            17, 103, 60, 206, 96, 89, 95, 12, 76, 227, 100, 50, 25, 119, 154, 20, 6, 195, 119, 145,
-            202, 236, 194, 0, 220, 128, 209, 236, 192, 224, 53, 233, 52, 156, 201, 148, 11, 59,
-            127, 172, 108, 212, 220, 133, 148, 81, 48, 241, 95, 193, 42, 216, 228, 156, 33, 6, 97,
-            73, 128, 120, 10, 162, 1, 225, 131, 188, 3, 141, 253, 175, 242, 138, 189, 134, 211, 4,
-            228, 184, 23, 223, 33, 21, 236, 238, 156, 121, 66, 192, 13, 244, 105, 161, 2, 128, 137,
-            96, 128, 28, 93, 176, 157, 10, 217, 221, 131, 69, 51, 187, 53, 215, 112, 159, 240, 88,
-            149, 2, 187, 172, 17, 149, 159, 10, 120, 29, 139, 139, 108, 233, 80, 128, 147, 245,
-            244, 215, 140, 157, 9, 154, 149, 109, 193, 141, 88, 85, 129, 83, 40, 111, 97, 227, 215,
-            114, 128, 142, 220, 220, 206, 60, 74, 138, 72, 250, 128, 212, 193, 142, 41, 173, 252,
-            206, 149, 239, 229, 135, 253, 130, 15, 81, 90, 57, 2, 41, 17, 16, 49, 84, 60, 92, 104,
-            161, 20, 151, 31, 49, 29, 128, 11, 135, 250, 116, 184, 7, 11, 233, 243, 119, 248, 204,
-            251, 184, 33, 200, 154, 151, 164, 100, 35, 99, 2, 121, 159, 85, 192, 116, 114, 1, 167,
-            108, 128, 157, 169, 85, 36, 254, 200, 128, 87, 101, 90, 127, 37, 121, 122, 195, 229,
-            153, 221, 73, 253, 150, 30, 126, 85, 52, 158, 225, 202, 172, 229, 2, 176, 161, 2, 128,
-            139, 64, 128, 44, 104, 77, 98, 68, 200, 76, 51, 242, 193, 132, 147, 238, 189, 77, 100,
-            40, 221, 144, 131, 85, 100, 230, 19, 124, 239, 34, 214, 45, 16, 93, 91, 128, 171, 229,
-            218, 116, 200, 192, 187, 97, 167, 223, 0, 217, 76, 28, 215, 2, 145, 70, 107, 99, 196,
-            238, 186, 153, 166, 171, 155, 149, 244, 230, 31, 144, 128, 85, 195, 253, 157, 61, 19,
-            153, 187, 109, 249, 15, 149, 188, 40, 118, 96, 238, 85, 70, 11, 191, 107, 139, 98, 1,
-            39, 120, 142, 228, 17, 204, 234, 128, 181, 123, 91, 143, 71, 213, 124, 186, 111, 181,
-            241, 152, 139, 39, 240, 69, 130, 121, 176, 53, 29, 251, 142, 45, 135, 63, 59, 69, 248,
-            120, 240, 154, 128, 154, 13, 77, 188, 24, 194, 32, 205, 121, 60, 130, 185, 217, 6, 173,
-            57, 130, 104, 6, 255, 155, 184, 191, 113, 45, 65, 222, 45, 84, 137, 250, 111, 153, 1,
-            128, 140, 0, 128, 101, 102, 146, 193, 195, 229, 132, 255, 81, 54, 93, 207, 95, 81, 15,
-            105, 47, 35, 59, 124, 88, 40, 244, 92, 207, 75, 23, 199, 50, 102, 135, 131, 128, 235,
-            211, 188, 34, 198, 130, 16, 37, 251, 63, 26, 17, 68, 110, 115, 56, 97, 76, 220, 169,
-            163, 93, 216, 135, 235, 235, 218, 161, 198, 72, 30, 6, 128, 231, 103, 182, 59, 191, 53,
-            86, 193, 138, 6, 114, 186, 183, 61, 125, 66, 168, 69, 184, 210, 161, 18, 215, 96, 192,
-            201, 239, 48, 124, 113, 237, 224, 21, 1, 128, 144, 0, 128, 140, 10, 85, 56, 162, 116,
-            183, 66, 186, 40, 142, 106, 78, 95, 45, 56, 171, 235, 112, 140, 164, 46, 203, 165, 41,
-            155, 199, 87, 154, 146, 175, 245, 128, 187, 93, 169, 99, 245, 127, 58, 38, 254, 39, 17,
-            48, 81, 75, 176, 161, 4, 46, 93, 167, 239, 22, 129, 203, 119, 213, 54, 40, 201, 181,
-            127, 115, 153, 1, 128, 144, 2, 128, 165, 79, 149, 254, 171, 14, 165, 127, 106, 44, 174,
-            5, 174, 254, 243, 82, 198, 148, 91, 110, 162, 227, 94, 87, 235, 201, 205, 222, 90, 147,
-            101, 239, 128, 191, 74, 66, 38, 65, 127, 178, 52, 242, 14, 146, 45, 224, 19, 188, 26,
-            194, 47, 1, 124, 145, 73, 166, 58, 125, 236, 25, 173, 88, 2, 231, 248, 128, 143, 173,
-            100, 143, 37, 123, 217, 249, 72, 200, 184, 216, 188, 159, 210, 174, 19, 45, 239, 70,
-            230, 69, 172, 122, 251, 168, 200, 45, 155, 185, 91, 157, 29, 2, 128, 144, 34, 128, 227,
-            6, 134, 107, 111, 144, 213, 153, 192, 194, 55, 142, 211, 119, 197, 134, 84, 156, 149,
-            251, 131, 216, 225, 5, 99, 182, 38, 250, 12, 54, 236, 201, 128, 88, 72, 208, 132, 151,
-            184, 77, 228, 64, 12, 196, 223, 15, 221, 30, 160, 203, 209, 137, 1, 221, 182, 196, 112,
-            32, 28, 71, 194, 55, 43, 134, 249, 128, 152, 209, 75, 22, 182, 57, 164, 242, 97, 56,
-            32, 125, 124, 47, 204, 67, 160, 104, 165, 25, 82, 229, 27, 14, 157, 71, 81, 63, 251,
-            177, 4, 164, 128, 97, 122, 28, 110, 215, 233, 8, 85, 82, 19, 221, 183, 134, 20, 101,
-            144, 199, 22, 158, 33, 173, 184, 7, 96, 225, 34, 154, 128, 165, 22, 219, 99, 161, 2,
-            128, 144, 52, 128, 184, 99, 235, 215, 28, 112, 10, 90, 105, 221, 204, 247, 12, 103, 28,
-            136, 154, 172, 187, 170, 93, 74, 247, 148, 178, 34, 52, 177, 251, 44, 46, 54, 128, 165,
-            198, 231, 149, 240, 73, 164, 175, 19, 5, 18, 111, 77, 144, 61, 22, 169, 188, 117, 224,
-            207, 41, 201, 237, 114, 138, 158, 174, 85, 23, 204, 60, 128, 28, 168, 187, 40, 128, 55,
-            146, 116, 98, 213, 235, 127, 4, 130, 45, 35, 63, 40, 68, 190, 238, 36, 42, 53, 254,
-            229, 14, 84, 152, 227, 3, 94, 128, 121, 238, 97, 54, 86, 19, 60, 143, 121, 54, 59, 240,
-            75, 220, 161, 177, 124, 193, 7, 79, 91, 9, 170, 76, 103, 228, 206, 92, 247, 139, 156,
-            108, 128, 85, 85, 183, 52, 67, 246, 57, 130, 205, 86, 57, 5, 106, 79, 81, 56, 239, 40,
-            87, 63, 71, 154, 6, 159, 233, 33, 174, 243, 160, 221, 10, 137, 37, 3, 128, 147, 65,
-            128, 185, 117, 8, 214, 189, 154, 45, 30, 170, 51, 69, 246, 223, 48, 225, 216, 64, 190,
-            212, 245, 80, 219, 154, 7, 144, 204, 42, 219, 90, 81, 213, 149, 128, 50, 124, 107, 2,
-            179, 99, 253, 57, 93, 54, 7, 216, 250, 4, 27, 133, 12, 184, 144, 34, 171, 107, 179,
-            151, 154, 174, 171, 133, 90, 32, 211, 64, 128, 179, 108, 43, 116, 5, 103, 226, 192,
-            122, 145, 28, 17, 74, 249, 210, 87, 95, 217, 75, 156, 14, 232, 34, 108, 51, 126, 225,
-            244, 183, 83, 110, 112, 128, 68, 218, 25, 166, 56, 232, 42, 245, 113, 82, 157, 224, 39,
-            203, 19, 9, 64, 143, 174, 144, 196, 186, 240, 170, 144, 53, 133, 177, 166, 139, 87,
-            176, 128, 240, 29, 236, 130, 218, 239, 34, 144, 55, 215, 133, 193, 125, 228, 25, 242,
-            128, 55, 179, 111, 221, 99, 91, 108, 193, 245, 27, 143, 249, 114, 124, 197, 128, 19,
-            119, 161, 85, 194, 104, 76, 19, 129, 96, 191, 167, 253, 129, 238, 68, 123, 222, 65, 3,
-            160, 48, 156, 80, 84, 239, 57, 69, 30, 204, 88, 20, 161, 2, 128, 149, 64, 128, 175, 82,
-            155, 186, 254, 164, 97, 208, 49, 35, 33, 250, 58, 143, 107, 188, 135, 91, 252, 195,
-            209, 209, 113, 49, 249, 15, 232, 139, 56, 72, 15, 56, 128, 191, 83, 56, 146, 90, 19,
-            194, 122, 85, 2, 135, 196, 209, 137, 221, 0, 242, 61, 20, 88, 111, 119, 121, 95, 239,
-            46, 138, 3, 140, 254, 117, 96, 128, 126, 174, 99, 49, 14, 30, 101, 6, 36, 162, 80, 16,
-            164, 40, 71, 72, 131, 253, 217, 27, 97, 104, 128, 248, 63, 115, 180, 177, 130, 180,
-            229, 129, 128, 204, 34, 38, 233, 252, 18, 209, 0, 97, 40, 142, 51, 103, 248, 61, 207,
-            210, 221, 65, 240, 7, 37, 164, 180, 195, 88, 32, 76, 154, 1, 7, 206, 128, 83, 60, 112,
-            67, 112, 118, 152, 149, 73, 224, 201, 70, 0, 7, 92, 185, 85, 252, 166, 93, 165, 46,
-            150, 184, 39, 151, 190, 200, 80, 49, 10, 219, 29, 2, 128, 150, 0, 128, 218, 77, 152,
-            86, 147, 47, 61, 233, 42, 203, 156, 234, 106, 106, 27, 71, 74, 249, 139, 138, 201, 33,
-            142, 47, 203, 59, 206, 107, 221, 214, 1, 60, 128, 211, 140, 144, 183, 230, 114, 102,
-            64, 57, 7, 95, 85, 57, 154, 35, 37, 84, 167, 100, 200, 8, 148, 235, 2, 108, 108, 205,
-            137, 27, 167, 142, 40, 128, 159, 0, 51, 108, 247, 99, 43, 248, 208, 184, 52, 34, 133,
-            133, 168, 128, 70, 199, 201, 92, 136, 219, 173, 157, 110, 21, 47, 2, 29, 48, 231, 21,
-            128, 165, 87, 61, 23, 192, 51, 225, 219, 183, 165, 43, 219, 137, 209, 112, 74, 254,
-            223, 27, 207, 15, 104, 253, 64, 244, 92, 24, 8, 57, 121, 24, 127, 169, 3, 128, 156,
-            112, 128, 14, 140, 202, 119, 209, 245, 174, 128, 240, 85, 235, 240, 241, 206, 74, 44,
-            42, 71, 144, 1, 247, 60, 161, 206, 50, 222, 161, 247, 10, 91, 40, 0, 128, 186, 237, 33,
-            59, 29, 218, 43, 18, 117, 127, 187, 6, 142, 204, 177, 231, 175, 254, 36, 109, 110, 120,
-            71, 231, 157, 35, 44, 142, 250, 22, 159, 235, 128, 138, 111, 67, 172, 226, 163, 169,
-            247, 13, 168, 150, 235, 105, 217, 118, 1, 109, 91, 45, 27, 106, 69, 247, 36, 188, 62,
-            106, 168, 134, 44, 232, 172, 128, 196, 120, 208, 59, 46, 137, 16, 79, 29, 124, 62, 216,
-            224, 23, 178, 220, 231, 169, 35, 85, 121, 181, 100, 147, 61, 1, 236, 135, 181, 72, 145,
-            56, 128, 23, 33, 158, 2, 6, 241, 153, 107, 251, 222, 161, 192, 154, 154, 219, 76, 147,
-            216, 69, 4, 253, 178, 231, 202, 48, 148, 163, 129, 57, 43, 166, 145, 128, 204, 158, 55,
-            76, 234, 150, 219, 213, 8, 83, 193, 111, 45, 245, 167, 6, 78, 167, 228, 212, 250, 237,
-            13, 13, 178, 230, 136, 110, 245, 65, 105, 156, 128, 82, 123, 1, 143, 84, 152, 200, 17,
-            94, 138, 95, 146, 202, 116, 112, 140, 77, 19, 186, 240, 131, 116, 213, 115, 253, 78,
-            135, 217, 193, 41, 194, 251, 153, 1, 128, 160, 1, 128, 130, 206, 3, 16, 243, 250, 134,
-            217, 111, 2, 105, 107, 245, 196, 148, 120, 101, 194, 145, 154, 153, 230, 165, 145, 113,
-            86, 157, 232, 253, 184, 25, 153, 128, 172, 95, 209, 236, 96, 128, 58, 255, 205, 92,
-            113, 213, 68, 96, 167, 34, 150, 157, 226, 37, 128, 239, 1, 169, 107, 245, 208, 40, 145,
-            155, 61, 28, 128, 185, 145, 173, 124, 89, 35, 31, 227, 8, 205, 124, 191, 196, 18, 51,
-            132, 243, 119, 242, 232, 60, 197, 49, 4, 184, 116, 229, 88, 240, 159, 145, 107, 29, 2,
-            128, 160, 24, 128, 109, 198, 50, 181, 54, 207, 86, 32, 238, 194, 243, 9, 215, 55, 191,
-            100, 214, 28, 131, 23, 71, 148, 65, 201, 159, 220, 138, 71, 229, 186, 51, 217, 128,
-            211, 25, 247, 95, 56, 33, 251, 45, 120, 194, 189, 164, 191, 43, 19, 235, 50, 83, 154,
-            79, 55, 240, 3, 3, 35, 233, 221, 5, 19, 105, 140, 116, 128, 120, 225, 20, 8, 175, 33,
-            158, 17, 136, 45, 55, 178, 40, 228, 139, 249, 89, 72, 197, 8, 50, 114, 237, 243, 31,
-            158, 127, 204, 166, 52, 45, 8, 128, 84, 240, 74, 173, 246, 159, 166, 31, 178, 144, 145,
-            130, 98, 144, 89, 43, 9, 248, 111, 29, 111, 184, 88, 216, 119, 189, 169, 84, 80, 28,
-            253, 153, 37, 3, 128, 161, 112, 128, 140, 137, 169, 32, 124, 226, 21, 6, 4, 155, 0, 83,
-            253, 225, 112, 80, 155, 80, 85, 171, 37, 88, 224, 98, 127, 46, 108, 213, 160, 246, 38,
-            232, 128, 52, 144, 143, 68, 24, 97, 156, 76, 183, 26, 179, 124, 26, 52, 1, 63, 170,
-            190, 77, 140, 119, 161, 211, 94, 29, 180, 232, 132, 255, 207, 192, 215, 128, 153, 219,
-            103, 128, 177, 121, 67, 128, 108, 182, 69, 136, 227, 4, 192, 169, 84, 97, 114, 50, 145,
-            53, 49, 5, 33, 168, 247, 39, 36, 56, 234, 60, 128, 213, 84, 180, 229, 106, 90, 0, 176,
-            64, 214, 106, 255, 21, 29, 237, 42, 118, 91, 8, 229, 218, 16, 207, 253, 116, 248, 240,
-            109, 102, 22, 188, 17, 128, 74, 145, 58, 222, 44, 118, 71, 22, 248, 170, 58, 156, 30,
-            149, 253, 204, 57, 102, 49, 184, 16, 103, 137, 65, 161, 116, 207, 48, 193, 223, 67, 10,
-            128, 47, 122, 167, 250, 181, 77, 3, 236, 148, 11, 99, 74, 186, 250, 1, 243, 228, 85,
-            196, 227, 200, 163, 43, 124, 251, 13, 109, 229, 127, 200, 115, 211, 153, 1, 128, 162,
-            0, 128, 202, 141, 85, 133, 131, 160, 34, 64, 191, 48, 123, 89, 71, 173, 1, 75, 217, 32,
-            20, 206, 65, 5, 223, 116, 239, 250, 175, 32, 113, 71, 201, 79, 128, 205, 232, 27, 47,
-            26, 118, 55, 59, 107, 57, 166, 238, 133, 56, 232, 148, 18, 25, 16, 6, 203, 75, 31, 232,
-            182, 198, 133, 200, 87, 54, 32, 225, 128, 216, 29, 104, 252, 100, 191, 177, 175, 248,
-            88, 173, 103, 30, 211, 132, 139, 201, 136, 6, 226, 194, 98, 100, 154, 202, 145, 182,
-            63, 190, 150, 37, 218, 161, 2, 128, 162, 6, 128, 28, 101, 250, 154, 82, 36, 39, 8, 236,
-            187, 22, 20, 156, 248, 93, 195, 154, 44, 129, 187, 96, 239, 4, 225, 201, 62, 148, 95,
-            43, 120, 66, 122, 128, 10, 148, 252, 223, 26, 191, 184, 235, 220, 115, 208, 234, 136,
-            44, 198, 17, 2, 60, 220, 158, 244, 170, 125, 61, 118, 112, 117, 98, 138, 155, 55, 18,
-            128, 70, 48, 240, 18, 232, 71, 187, 0, 144, 76, 166, 68, 162, 28, 180, 66, 34, 76, 7,
-            160, 138, 79, 34, 93, 69, 74, 18, 99, 197, 2, 5, 146, 128, 177, 172, 94, 31, 119, 0,
-            68, 147, 161, 248, 3, 33, 37, 101, 198, 177, 193, 191, 175, 25, 107, 251, 99, 165, 239,
-            81, 154, 39, 46, 16, 81, 95, 128, 50, 226, 56, 174, 240, 246, 92, 64, 65, 235, 213,
-            227, 128, 172, 129, 27, 206, 150, 52, 188, 55, 11, 129, 70, 154, 192, 8, 36, 154, 176,
-            244, 185, 29, 2, 128, 164, 1, 128, 161, 138, 80, 140, 83, 90, 208, 88, 81, 80, 249, 64,
-            199, 86, 62, 66, 137, 93, 151, 3, 43, 122, 161, 150, 248, 149, 249, 176, 8, 223, 90,
-            90, 128, 163, 192, 125, 241, 242, 244, 27, 112, 33, 98, 87, 80, 162, 39, 152, 207, 49,
-            149, 104, 216, 68, 118, 213, 30, 0, 62, 158, 152, 236, 98, 93, 135, 128, 114, 193, 253,
-            2, 140, 142, 71, 240, 121, 110, 255, 207, 197, 5, 155, 208, 23, 202, 138, 141, 152,
-            184, 100, 77, 217, 37, 51, 24, 109, 200, 190, 44, 128, 56, 215, 95, 95, 111, 253, 178,
-            14, 182, 235, 199, 84, 199, 54, 68, 250, 131, 150, 70, 205, 196, 78, 156, 6, 189, 60,
-            67, 233, 182, 39, 117, 24, 153, 1, 128, 168, 0, 128, 240, 48, 243, 251, 208, 247, 236,
-            17, 185, 255, 58, 78, 247, 222, 31, 217, 222, 18, 94, 184, 182, 220, 27, 214, 33, 85,
-            245, 191, 28, 135, 90, 72, 128, 11, 129, 41, 233, 114, 234, 254, 188, 99, 107, 7, 234,
-            225, 71, 192, 237, 10, 76, 132, 34, 143, 106, 66, 237, 124, 89, 243, 113, 249, 48, 49,
-            98, 128, 170, 217, 33, 154, 27, 152, 98, 144, 14, 55, 223, 159, 72, 230, 7, 79, 173, 0,
-            207, 173, 243, 48, 1, 191, 128, 235, 146, 101, 200, 107, 24, 224, 161, 2, 128, 168, 24,
-            128, 40, 111, 242, 142, 54, 175, 246, 8, 116, 101, 96, 107, 154, 60, 151, 230, 217,
-            189, 238, 61, 32, 46, 185, 56, 24, 244, 166, 230, 156, 188, 148, 202, 128, 37, 72, 73,
-            117, 50, 53, 103, 57, 146, 88, 243, 148, 231, 172, 101, 197, 154, 200, 72, 5, 144, 7,
-            244, 33, 122, 243, 55, 203, 4, 18, 187, 211, 128, 112, 9, 110, 60, 163, 45, 172, 146,
-            171, 9, 227, 212, 100, 219, 86, 173, 80, 85, 69, 158, 204, 153, 107, 211, 131, 1, 245,
-            106, 254, 247, 164, 247, 128, 171, 135, 117, 81, 213, 127, 86, 37, 148, 54, 68, 184,
-            248, 143, 215, 184, 22, 146, 194, 100, 171, 89, 231, 249, 210, 163, 209, 95, 186, 233,
-            161, 248, 128, 130, 63, 255, 126, 12, 120, 8, 166, 176, 191, 206, 147, 167, 171, 210,
-            98, 59, 21, 36, 80, 224, 75, 81, 139, 249, 93, 216, 177, 57, 161, 129, 181, 169, 3,
-            128, 169, 193, 128, 249, 104, 120, 127, 129, 211, 196, 249, 21, 122, 248, 193, 111, 21,
-            192, 10, 68, 132, 249, 206, 197, 207, 19, 0, 38, 50, 125, 105, 124, 113, 241, 211, 128,
-            224, 16, 171, 27, 224, 172, 166, 89, 145, 104, 36, 203, 11, 220, 68, 138, 95, 13, 24,
-            121, 58, 4, 205, 56, 252, 199, 62, 13, 157, 42, 72, 99, 128, 102, 170, 111, 106, 100,
-            115, 124, 28, 108, 202, 80, 27, 228, 252, 127, 210, 209, 179, 9, 102, 113, 190, 24, 87,
-            155, 132, 164, 173, 212, 67, 248, 94, 128, 171, 212, 159, 36, 40, 202, 127, 62, 159,
-            112, 173, 131, 162, 78, 90, 25, 125, 246, 178, 119, 3, 38, 180, 35, 20, 119, 3, 16,
-            206, 68, 205, 236, 128, 60, 238, 106, 82, 128, 146, 205, 140, 246, 76, 74, 211, 234,
-            173, 62, 206, 172, 242, 118, 248, 51, 149, 130, 116, 171, 77, 7, 74, 197, 244, 71, 67,
-            128, 140, 141, 124, 113, 23, 251, 136, 237, 42, 128, 1, 109, 92, 40, 133, 224, 30, 98,
-            172, 153, 115, 66, 217, 254, 6, 26, 213, 202, 200, 227, 119, 190, 128, 152, 75, 151,
-            61, 50, 172, 116, 103, 238, 34, 0, 156, 6, 67, 88, 151, 203, 185, 220, 134, 90, 118,
-            186, 62, 251, 189, 171, 115, 167, 25, 215, 42, 37, 3, 128, 170, 65, 128, 139, 244, 129,
-            3, 156, 81, 134, 167, 245, 241, 97, 219, 85, 169, 136, 149, 58, 40, 126, 1, 211, 214,
-            65, 218, 144, 237, 116, 42, 51, 49, 140, 232, 128, 193, 156, 240, 161, 83, 138, 218,
-            57, 235, 154, 215, 181, 171, 60, 23, 231, 52, 177, 179, 90, 135, 198, 194, 85, 63, 221,
-            193, 66, 97, 96, 215, 194, 128, 171, 144, 60, 235, 162, 53, 150, 176, 7, 83, 142, 26,
-            51, 244, 230, 142, 183, 122, 164, 138, 37, 119, 202, 111, 240, 70, 188, 132, 214, 48,
-            50, 32, 128, 9, 34, 212, 151, 176, 105, 27, 18, 49, 226, 250, 134, 157, 15, 186, 151,
-            65, 143, 76, 130, 17, 189, 57, 86, 51, 219, 106, 189, 251, 5, 38, 49, 128, 117, 237,
-            39, 39, 16, 66, 3, 174, 143, 251, 241, 6, 108, 183, 110, 13, 174, 62, 175, 54, 45, 98,
-            119, 90, 37, 151, 106, 160, 3, 187, 71, 112, 128, 48, 145, 143, 92, 86, 56, 100, 221,
-            33, 65, 77, 162, 168, 114, 204, 95, 148, 106, 20, 209, 65, 96, 237, 174, 174, 213, 3,
-            170, 216, 202, 163, 105, 37, 3, 128, 180, 36, 128, 197, 69, 226, 239, 160, 231, 234,
-            218, 90, 103, 53, 140, 101, 52, 133, 54, 135, 78, 14, 247, 197, 184, 200, 82, 192, 217,
-            10, 255, 157, 1, 255, 195, 128, 38, 4, 51, 59, 8, 243, 205, 122, 137, 63, 52, 201, 35,
-            43, 250, 207, 129, 202, 4, 162, 188, 19, 30, 51, 24, 144, 165, 79, 103, 1, 222, 149,
-            128, 4, 217, 112, 215, 84, 135, 209, 164, 38, 242, 20, 198, 17, 179, 130, 26, 177, 159,
-            144, 198, 70, 179, 93, 173, 118, 107, 51, 208, 162, 89, 56, 63, 128, 235, 77, 192, 119,
-            32, 206, 161, 110, 134, 108, 231, 107, 64, 21, 242, 70, 183, 107, 0, 188, 214, 239,
-            226, 213, 47, 193, 210, 248, 131, 76, 75, 174, 128, 76, 93, 236, 159, 69, 145, 130,
-            192, 112, 29, 78, 116, 85, 181, 60, 33, 246, 161, 80, 184, 2, 244, 101, 6, 170, 239,
-            233, 200, 150, 151, 152, 125, 128, 43, 54, 66, 214, 82, 23, 237, 174, 62, 196, 88, 201,
-            50, 42, 250, 189, 198, 75, 241, 147, 143, 215, 150, 87, 48, 145, 46, 158, 224, 108, 46,
-            130, 37, 3, 128, 180, 144, 128, 183, 198, 239, 208, 158, 81, 182, 252, 162, 31, 125,
-            138, 100, 121, 156, 95, 129, 200, 211, 61, 131, 213, 21, 44, 61, 32, 99, 119, 112, 80,
-            170, 163, 128, 189, 99, 96, 5, 95, 174, 35, 141, 129, 252, 113, 154, 74, 208, 4, 94,
-            46, 13, 146, 152, 36, 82, 98, 63, 126, 228, 77, 167, 253, 206, 106, 63, 128, 179, 111,
-            191, 252, 166, 78, 94, 80, 128, 33, 44, 74, 66, 133, 211, 121, 70, 6, 123, 161, 61, 37,
-            200, 93, 171, 18, 96, 9, 178, 64, 121, 35, 128, 213, 234, 38, 67, 7, 177, 55, 4, 158,
-            174, 207, 134, 40, 220, 99, 114, 247, 241, 120, 140, 59, 54, 171, 131, 48, 26, 255,
-            255, 162, 218, 240, 132, 128, 12, 100, 249, 121, 128, 147, 52, 235, 27, 74, 223, 246,
-            54, 124, 1, 236, 132, 13, 211, 21, 238, 159, 216, 156, 11, 110, 189, 10, 70, 141, 152,
-            124, 128, 54, 211, 44, 215, 222, 246, 214, 49, 122, 64, 17, 50, 94, 57, 202, 102, 60,
-            208, 122, 252, 233, 247, 112, 123, 60, 211, 25, 229, 252, 104, 125, 30, 21, 1, 128,
-            192, 0, 128, 227, 107, 23, 229, 172, 188, 36, 77, 0, 133, 71, 104, 195, 203, 222, 80,
-            200, 253, 140, 47, 231, 126, 218, 81, 125, 152, 253, 242, 254, 120, 42, 218, 128, 75,
-            165, 166, 125, 51, 203, 206, 137, 134, 22, 203, 94, 238, 78, 223, 213, 86, 88, 34, 179,
-            35, 70, 42, 221, 82, 241, 223, 233, 224, 64, 56, 168, 153, 1, 128, 192, 2, 128, 124,
-            72, 194, 76, 147, 214, 250, 123, 197, 87, 1, 209, 82, 169, 125, 220, 152, 121, 81, 232,
-            57, 227, 32, 101, 223, 21, 65, 122, 212, 136, 229, 28, 128, 184, 178, 47, 94, 211, 20,
-            196, 250, 174, 111, 166, 3, 30, 213, 126, 146, 3, 201, 207, 227, 179, 179, 159, 165,
-            130, 86, 51, 80, 253, 251, 197, 79, 128, 35, 70, 39, 41, 223, 75, 217, 13, 89, 185,
-            126, 29, 60, 122, 163, 119, 123, 126, 163, 205, 128, 62, 254, 160, 104, 124, 130, 206,
-            197, 161, 142, 173, 153, 1, 128, 192, 32, 128, 251, 102, 131, 35, 68, 144, 149, 5, 81,
-            210, 157, 254, 179, 208, 231, 55, 72, 251, 244, 144, 54, 246, 45, 175, 26, 93, 21, 186,
-            77, 42, 48, 19, 128, 69, 25, 218, 74, 19, 146, 154, 84, 83, 90, 253, 224, 239, 154,
-            109, 171, 149, 125, 253, 215, 150, 215, 49, 163, 82, 59, 163, 240, 99, 200, 134, 86,
-            128, 88, 145, 185, 64, 217, 182, 203, 122, 141, 43, 178, 95, 33, 116, 230, 56, 124,
-            187, 61, 166, 135, 156, 17, 154, 117, 224, 119, 62, 133, 168, 104, 136, 153, 1, 128,
-            192, 64, 128, 233, 237, 70, 138, 83, 44, 191, 250, 74, 46, 86, 243, 35, 230, 247, 169,
-            109, 152, 58, 187, 167, 196, 80, 214, 85, 20, 197, 233, 86, 225, 64, 82, 128, 73, 228,
-            41, 218, 194, 182, 129, 5, 23, 179, 89, 92, 235, 155, 180, 255, 34, 123, 67, 180, 210,
-            115, 198, 106, 36, 86, 11, 2, 131, 48, 221, 88, 128, 108, 198, 93, 231, 100, 62, 118,
-            251, 168, 165, 119, 69, 210, 78, 193, 106, 63, 72, 182, 145, 40, 204, 126, 54, 46, 59,
-            52, 168, 76, 92, 102, 163, 153, 1, 128, 193, 0, 128, 224, 95, 51, 210, 23, 178, 104,
-            17, 216, 72, 199, 174, 41, 252, 4, 109, 166, 158, 15, 40, 51, 225, 120, 119, 239, 149,
-            177, 188, 139, 202, 203, 45, 128, 169, 220, 229, 110, 23, 117, 31, 79, 216, 7, 185, 20,
-            191, 121, 220, 142, 45, 197, 140, 253, 45, 179, 203, 109, 150, 149, 244, 73, 175, 145,
-            197, 206, 128, 238, 116, 177, 151, 37, 48, 16, 182, 34, 41, 40, 90, 27, 215, 11, 176,
-            186, 215, 211, 168, 105, 74, 158, 202, 39, 229, 218, 121, 126, 248, 137, 57, 161, 2,
-            128, 193, 9, 128, 203, 20, 139, 146, 23, 176, 175, 38, 72, 44, 141, 186, 94, 22, 158,
-            18, 105, 63, 53, 164, 146, 46, 223, 36, 210, 166, 220, 9, 18, 102, 6, 142, 128, 194,
-            82, 130, 214, 197, 216, 208, 149, 155, 181, 133, 72, 62, 113, 150, 219, 254, 253, 135,
-            120, 55, 101, 21, 122, 221, 36, 92, 88, 191, 31, 100, 21, 128, 241, 55, 179, 9, 103,
-            186, 171, 243, 198, 192, 249, 165, 181, 124, 173, 187, 191, 162, 86, 73, 216, 91, 128,
-            111, 60, 10, 205, 128, 20, 34, 228, 208, 128, 201, 49, 252, 67, 78, 14, 211, 108, 53,
-            225, 157, 95, 105, 224, 32, 119, 156, 188, 109, 51, 244, 172, 238, 182, 29, 168, 17,
-            190, 131, 155, 220, 152, 128, 239, 225, 63, 180, 34, 236, 200, 189, 167, 69, 111, 223,
-            215, 65, 202, 212, 185, 83, 158, 182, 5, 209, 75, 28, 206, 173, 214, 232, 107, 107, 55,
-            9, 153, 1, 128, 200, 0, 128, 49, 44, 161, 25, 66, 190, 118, 121, 231, 28, 221, 170,
-            171, 153, 116, 144, 175, 23, 242, 66, 222, 210, 28, 49, 140, 19, 45, 9, 150, 252, 128,
-            201, 128, 251, 253, 83, 109, 137, 177, 179, 227, 224, 222, 175, 58, 4, 11, 216, 194,
-            200, 26, 128, 46, 15, 188, 129, 60, 24, 6, 228, 218, 199, 31, 66, 54, 128, 10, 41, 188,
-            87, 119, 254, 197, 4, 204, 159, 109, 58, 184, 190, 250, 20, 40, 53, 212, 45, 62, 181,
-            73, 88, 28, 95, 95, 203, 84, 196, 61, 111, 169, 3, 128, 220, 68, 128, 11, 3, 128, 223,
-            141, 239, 186, 202, 200, 134, 248, 86, 242, 64, 171, 70, 122, 26, 255, 204, 234, 156,
-            30, 61, 127, 110, 105, 3, 9, 82, 231, 227, 128, 45, 138, 198, 173, 246, 227, 7, 60, 12,
-            17, 59, 69, 37, 17, 214, 244, 202, 123, 237, 74, 170, 253, 169, 131, 228, 206, 126,
-            192, 137, 61, 89, 113, 128, 33, 242, 59, 126, 80, 98, 73, 125, 236, 52, 247, 115, 89,
-            121, 195, 170, 171, 107, 252, 32, 89, 123, 210, 244, 51, 71, 232, 238, 94, 239, 6, 232,
-            128, 94, 31, 50, 39, 98, 199, 217, 235, 230, 37, 117, 32, 219, 24, 186, 192, 117, 23,
-            210, 2, 177, 98, 90, 26, 2, 61, 201, 112, 149, 140, 233, 134, 128, 31, 109, 84, 248,
-            13, 200, 152, 80, 5, 153, 20, 209, 97, 74, 104, 4, 85, 91, 203, 113, 65, 25, 241, 12,
-            25, 248, 249, 108, 90, 173, 148, 134, 128, 21, 55, 253, 47, 191, 186, 176, 109, 71,
-            249, 237, 104, 93, 184, 79, 207, 79, 31, 197, 175, 34, 164, 148, 193, 74, 74, 149, 52,
-            119, 94, 162, 11, 128, 50, 103, 100, 24, 131, 75, 236, 127, 11, 61, 157, 78, 216, 103,
-            114, 20, 156, 6, 247, 160, 235, 43, 176, 58, 107, 81, 84, 66, 107, 28, 160, 239, 201,
-            7, 128, 255, 191, 128, 158, 63, 149, 1, 58, 90, 199, 8, 180, 42, 100, 95, 68, 184, 64,
-            112, 145, 78, 130, 76, 221, 11, 40, 239, 41, 58, 200, 68, 13, 72, 188, 165, 128, 204,
-            220, 74, 251, 110, 231, 249, 175, 205, 130, 132, 250, 22, 40, 138, 163, 127, 121, 154,
-            73, 233, 16, 94, 82, 45, 183, 72, 11, 43, 119, 87, 234, 128, 88, 86, 140, 228, 43, 157,
-            244, 179, 181, 121, 251, 212, 56, 35, 194, 12, 228, 254, 104, 65, 27, 165, 158, 216,
-            188, 139, 168, 225, 61, 132, 202, 76, 128, 201, 23, 138, 207, 176, 44, 214, 85, 204,
-            70, 100, 4, 116, 82, 71, 228, 241, 90, 108, 43, 26, 68, 144, 18, 122, 64, 161, 70, 98,
-            251, 10, 252, 128, 110, 47, 111, 14, 41, 34, 78, 147, 203, 192, 168, 4, 197, 79, 134,
-            68, 109, 231, 253, 158, 107, 203, 91, 130, 151, 98, 180, 213, 9, 189, 68, 80, 128, 4,
-            201, 186, 96, 21, 36, 18, 155, 170, 90, 58, 226, 214, 67, 127, 237, 196, 222, 226, 174,
-            36, 51, 157, 46, 240, 74, 122, 183, 135, 39, 48, 186, 128, 170, 114, 149, 137, 242,
-            192, 204, 114, 121, 187, 191, 250, 8, 227, 127, 93, 220, 108, 194, 11, 178, 203, 61,
-            123, 11, 240, 189, 208, 206, 163, 200, 140, 128, 248, 91, 155, 199, 230, 136, 20, 51,
-            123, 8, 11, 178, 38, 69, 109, 117, 201, 28, 130, 75, 142, 190, 181, 184, 152, 190, 68,
-            115, 200, 51, 207, 108, 128, 209, 155, 60, 4, 152, 91, 147, 234, 238, 184, 1, 28, 148,
-            0, 120, 154, 38, 123, 111, 197, 112, 199, 2, 246, 233, 50, 71, 25, 87, 61, 100, 229,
-            128, 239, 53, 195, 76, 247, 20, 70, 41, 115, 0, 224, 30, 90, 189, 247, 57, 116, 102,
-            92, 3, 138, 59, 45, 103, 186, 65, 237, 78, 15, 134, 29, 177, 128, 220, 55, 8, 25, 116,
-            102, 167, 225, 3, 235, 164, 6, 67, 84, 93, 44, 192, 179, 134, 137, 114, 64, 202, 131,
-            226, 175, 96, 99, 103, 199, 178, 109, 128, 100, 45, 137, 234, 36, 180, 77, 29, 187,
-            234, 88, 127, 142, 106, 2, 148, 5, 11, 91, 223, 10, 213, 40, 60, 178, 56, 121, 179, 79,
-            196, 48, 49, 128, 229, 200, 177, 66, 203, 20, 230, 123, 193, 84, 65, 201, 133, 24, 68,
-            116, 225, 116, 176, 21, 130, 113, 115, 14, 112, 131, 6, 183, 93, 119, 144, 20, 128, 56,
-            87, 4, 116, 11, 175, 92, 158, 44, 17, 84, 84, 85, 175, 230, 255, 86, 111, 189, 17, 10,
-            242, 108, 19, 96, 55, 130, 150, 66, 30, 80, 122, 128, 99, 209, 106, 138, 72, 129, 164,
-            121, 105, 105, 236, 38, 195, 244, 250, 153, 205, 101, 159, 223, 245, 83, 115, 131, 230,
-            213, 201, 110, 78, 17, 82, 225, 201, 7, 128, 255, 254, 128, 208, 158, 3, 190, 40, 216,
-            177, 220, 46, 85, 232, 253, 123, 170, 53, 34, 76, 254, 150, 49, 92, 15, 76, 10, 166,
-            209, 43, 211, 223, 249, 176, 21, 128, 219, 204, 84, 174, 161, 163, 190, 254, 117, 195,
-            29, 7, 217, 23, 86, 245, 46, 71, 60, 203, 129, 217, 72, 123, 16, 220, 42, 200, 244, 54,
-            55, 50, 128, 215, 170, 165, 0, 121, 88, 226, 241, 89, 20, 184, 131, 70, 164, 242, 225,
-            11, 201, 96, 65, 23, 183, 32, 101, 9, 91, 191, 193, 240, 147, 90, 83, 128, 155, 146,
-            150, 77, 205, 13, 254, 237, 2, 217, 113, 89, 34, 87, 59, 162, 204, 246, 188, 218, 172,
-            221, 125, 223, 74, 198, 152, 6, 237, 249, 73, 114, 128, 117, 34, 190, 142, 140, 232,
-            56, 6, 243, 125, 122, 209, 114, 15, 29, 177, 82, 154, 33, 204, 129, 188, 72, 165, 102,
-            5, 238, 174, 87, 60, 231, 27, 128, 204, 83, 179, 23, 92, 175, 137, 194, 112, 119, 109,
-            117, 95, 176, 38, 183, 59, 179, 231, 142, 26, 164, 251, 72, 147, 40, 45, 28, 130, 88,
-            70, 76, 128, 118, 78, 9, 107, 124, 99, 15, 237, 148, 97, 66, 12, 196, 117, 177, 216,
-            180, 229, 24, 126, 95, 182, 75, 250, 54, 28, 34, 122, 132, 90, 21, 199, 128, 104, 216,
-            101, 53, 136, 32, 38, 150, 106, 89, 151, 102, 119, 159, 172, 121, 179, 251, 63, 218,
-            73, 27, 84, 71, 231, 233, 129, 165, 181, 87, 152, 28, 128, 97, 60, 168, 151, 117, 208,
-            149, 36, 153, 18, 159, 83, 4, 237, 135, 16, 3, 193, 13, 32, 215, 87, 3, 48, 254, 192,
-            221, 112, 223, 53, 77, 198, 128, 54, 63, 114, 225, 243, 241, 166, 252, 248, 189, 209,
-            228, 214, 120, 245, 224, 182, 166, 118, 14, 16, 83, 231, 209, 79, 4, 149, 145, 108, 15,
-            62, 108, 128, 158, 133, 78, 141, 28, 208, 225, 65, 226, 172, 19, 242, 66, 244, 222, 59,
-            231, 68, 69, 248, 111, 2, 162, 135, 191, 44, 203, 10, 194, 164, 231, 216, 128, 173,
-            122, 140, 218, 205, 222, 128, 215, 191, 40, 216, 214, 77, 27, 90, 144, 253, 77, 208,
-            214, 73, 167, 101, 40, 33, 2, 19, 2, 78, 125, 126, 229, 128, 25, 249, 143, 91, 234,
-            229, 99, 135, 101, 225, 221, 126, 43, 39, 116, 209, 166, 211, 150, 71, 204, 86, 227,
-            60, 52, 130, 224, 117, 243, 57, 157, 133, 128, 94, 84, 87, 142, 53, 210, 91, 172, 239,
-            170, 106, 177, 233, 85, 248, 15, 170, 36, 125, 161, 234, 254, 205, 255, 121, 105, 53,
-            86, 149, 83, 11, 37, 128, 91, 33, 242, 120, 86, 85, 201, 95, 166, 117, 7, 166, 199,
-            189, 199, 190, 198, 224, 142, 226, 153, 99, 71, 72, 174, 83, 235, 245, 119, 185, 65,
-            205, 77, 8, 128, 255, 255, 128, 6, 32, 133, 84, 101, 18, 126, 217, 191, 6, 177, 132,
-            244, 120, 216, 103, 150, 164, 175, 134, 187, 115, 179, 22, 186, 234, 226, 161, 121, 26,
-            222, 55, 128, 46, 76, 12, 88, 33, 101, 141, 239, 249, 204, 50, 40, 146, 72, 53, 196,
-            237, 137, 201, 156, 162, 175, 126, 235, 75, 129, 36, 170, 173, 227, 151, 84, 128, 150,
-            97, 175, 74, 255, 77, 186, 203, 201, 206, 163, 28, 53, 137, 246, 23, 243, 50, 33, 254,
-            102, 73, 205, 72, 95, 66, 237, 244, 178, 220, 95, 243, 128, 248, 133, 191, 217, 234,
-            55, 50, 83, 196, 84, 222, 191, 3, 25, 251, 225, 206, 154, 97, 250, 230, 7, 18, 148, 82,
-            158, 159, 24, 229, 187, 78, 248, 128, 53, 90, 65, 180, 241, 62, 98, 76, 77, 130, 116,
-            124, 57, 131, 192, 159, 129, 74, 73, 156, 182, 254, 24, 66, 120, 29, 111, 104, 113,
-            116, 87, 177, 128, 196, 149, 246, 233, 244, 233, 39, 94, 22, 147, 84, 71, 207, 212,
-            192, 32, 144, 53, 0, 121, 159, 67, 225, 200, 168, 120, 90, 201, 19, 105, 76, 107, 128,
-            16, 175, 11, 70, 56, 176, 152, 223, 221, 147, 33, 254, 212, 24, 192, 57, 46, 100, 39,
-            187, 73, 8, 172, 4, 82, 249, 0, 159, 110, 17, 65, 123, 128, 246, 155, 84, 144, 169,
-            142, 185, 181, 234, 141, 158, 150, 32, 54, 105, 130, 210, 235, 115, 71, 136, 249, 50,
-            98, 125, 33, 20, 63, 7, 21, 164, 206, 128, 235, 123, 178, 124, 30, 33, 51, 40, 195,
-            132, 173, 39, 202, 95, 110, 0, 162, 23, 137, 201, 55, 15, 57, 106, 235, 188, 156, 61,
-            114, 10, 174, 41, 128, 42, 252, 113, 214, 77, 16, 238, 32, 236, 70, 139, 69, 68, 26,
-            121, 55, 233, 83, 116, 152, 48, 185, 130, 225, 142, 192, 112, 218, 253, 171, 126, 230,
-            128, 74, 251, 28, 15, 44, 86, 24, 172, 214, 56, 125, 22, 226, 30, 144, 222, 170, 183,
-            86, 162, 193, 59, 163, 11, 245, 67, 117, 43, 41, 211, 246, 146, 128, 163, 87, 215, 73,
-            240, 35, 44, 127, 73, 101, 212, 114, 170, 201, 100, 246, 17, 253, 119, 168, 232, 187,
-            51, 39, 139, 234, 2, 152, 243, 120, 44, 228, 128, 132, 220, 6, 255, 222, 136, 239, 82,
-            249, 233, 142, 5, 148, 32, 136, 57, 224, 142, 86, 126, 17, 138, 137, 68, 89, 197, 42,
-            134, 72, 54, 105, 250, 128, 92, 187, 114, 111, 209, 220, 174, 223, 213, 39, 229, 13,
-            50, 234, 154, 124, 62, 120, 168, 20, 137, 91, 147, 151, 6, 46, 70, 148, 199, 180, 0,
-            128, 128, 253, 216, 104, 15, 195, 83, 31, 85, 6, 177, 192, 180, 67, 107, 77, 9, 12, 55,
-            182, 235, 115, 200, 71, 68, 190, 10, 25, 215, 53, 44, 255, 156, 128, 60, 106, 75, 142,
-            183, 243, 52, 207, 38, 138, 150, 93, 200, 67, 157, 246, 180, 22, 137, 12, 131, 69, 201,
-            138, 5, 64, 84, 62, 64, 78, 179, 199, 77, 8, 128, 255, 255, 128, 21, 95, 36, 122, 109,
-            82, 214, 86, 36, 233, 147, 9, 162, 15, 102, 163, 18, 54, 145, 232, 242, 92, 13, 57,
-            165, 177, 124, 159, 134, 148, 230, 191, 128, 17, 180, 212, 187, 199, 116, 184, 60, 247,
-            67, 45, 186, 99, 105, 214, 152, 151, 73, 184, 35, 176, 77, 137, 22, 86, 133, 113, 242,
-            131, 0, 114, 248, 128, 225, 64, 116, 89, 219, 148, 156, 23, 45, 108, 174, 179, 142, 4,
-            217, 70, 205, 136, 140, 181, 132, 177, 59, 99, 206, 108, 35, 84, 201, 222, 39, 26, 128,
-            52, 63, 111, 5, 37, 101, 158, 213, 159, 237, 129, 146, 15, 212, 168, 102, 128, 134,
-            142, 108, 250, 94, 0, 180, 92, 11, 78, 6, 31, 236, 117, 18, 128, 6, 234, 209, 47, 60,
-            70, 239, 171, 95, 104, 208, 48, 160, 199, 230, 154, 11, 9, 142, 177, 62, 180, 79, 232,
-            211, 82, 242, 194, 201, 131, 55, 216, 128, 26, 178, 61, 58, 248, 177, 214, 141, 149,
-            119, 43, 226, 196, 36, 145, 205, 209, 220, 22, 67, 145, 235, 28, 21, 68, 78, 17, 120,
-            10, 136, 203, 24, 128, 182, 219, 79, 27, 116, 254, 54, 219, 149, 90, 99, 158, 133, 31,
-            236, 70, 251, 155, 212, 117, 127, 167, 65, 153, 216, 53, 45, 196, 83, 37, 232, 179,
-            128, 19, 182, 172, 52, 190, 147, 215, 30, 0, 60, 93, 205, 2, 201, 109, 82, 110, 164, 3,
-            7, 164, 214, 154, 147, 23, 39, 241, 169, 64, 83, 146, 0, 128, 127, 74, 176, 32, 37,
-            115, 76, 139, 211, 14, 236, 66, 70, 48, 218, 49, 75, 65, 242, 41, 224, 8, 173, 179, 96,
-            55, 245, 179, 172, 240, 182, 25, 128, 55, 115, 145, 131, 180, 160, 117, 172, 130, 14,
-            156, 99, 139, 15, 7, 92, 3, 101, 20, 195, 126, 192, 251, 240, 207, 63, 247, 21, 6, 254,
-            158, 11, 128, 38, 117, 118, 233, 8, 173, 43, 112, 52, 222, 176, 191, 214, 37, 140, 221,
-            108, 2, 5, 177, 124, 224, 37, 131, 226, 44, 139, 76, 119, 193, 106, 228, 128, 60, 66,
-            232, 6, 65, 57, 164, 242, 246, 193, 0, 186, 127, 11, 93, 189, 9, 5, 247, 228, 206, 41,
-            25, 126, 99, 255, 190, 56, 250, 18, 237, 163, 128, 180, 239, 24, 135, 163, 86, 144, 19,
-            95, 194, 3, 154, 158, 101, 108, 146, 217, 137, 206, 117, 162, 93, 253, 169, 85, 204,
-            228, 147, 230, 236, 78, 0, 128, 127, 165, 31, 245, 177, 222, 92, 217, 26, 171, 244,
-            149, 96, 40, 164, 152, 42, 34, 154, 179, 57, 108, 74, 212, 136, 80, 214, 0, 216, 39,
-            244, 98, 128, 233, 240, 108, 189, 251, 219, 73, 68, 43, 151, 120, 194, 137, 251, 91,
-            11, 244, 59, 6, 184, 236, 190, 61, 39, 87, 87, 222, 170, 186, 8, 25, 8, 128, 204, 207,
-            180, 179, 96, 147, 201, 61, 238, 185, 48, 155, 251, 199, 208, 118, 234, 226, 157, 221,
-            210, 63, 166, 242, 24, 216, 230, 91, 208, 87, 9, 15, 77, 8, 128, 255, 255, 128, 32, 71,
-            114, 154, 208, 90, 0, 91, 213, 125, 214, 149, 124, 79, 23, 205, 52, 232, 217, 89, 34,
-            42, 160, 39, 122, 43, 229, 98, 243, 42, 206, 40, 128, 131, 102, 63, 30, 5, 91, 18, 216,
-            61, 183, 253, 15, 9, 26, 163, 2, 218, 1, 134, 95, 193, 154, 158, 13, 78, 98, 203, 226,
-            183, 67, 171, 125, 128, 157, 109, 179, 216, 230, 139, 210, 90, 41, 101, 53, 14, 53, 48,
-            207, 54, 65, 67, 137, 49, 73, 123, 138, 221, 32, 141, 114, 111, 219, 172, 85, 158, 128,
-            44, 1, 232, 124, 192, 192, 10, 7, 124, 59, 229, 244, 183, 26, 152, 13, 90, 74, 46, 88,
-            99, 20, 179, 33, 33, 14, 121, 131, 218, 93, 228, 18, 128, 194, 190, 49, 52, 173, 254,
-            128, 110, 138, 177, 97, 153, 252, 60, 138, 233, 134, 69, 106, 32, 184, 185, 13, 81,
-            162, 128, 227, 210, 219, 50, 32, 56, 128, 220, 163, 230, 155, 6, 111, 176, 235, 78,
-            131, 102, 186, 89, 228, 98, 9, 122, 63, 152, 75, 221, 230, 177, 197, 127, 42, 86, 36,
-            149, 100, 211, 10, 128, 93, 16, 106, 37, 26, 141, 150, 6, 81, 253, 146, 149, 21, 130,
-            37, 174, 124, 41, 1, 106, 230, 129, 65, 69, 180, 62, 9, 64, 15, 109, 119, 154, 128,
-            222, 83, 57, 102, 10, 56, 28, 143, 48, 68, 101, 86, 234, 53, 203, 83, 164, 112, 62,
-            220, 93, 180, 69, 149, 250, 235, 250, 124, 141, 91, 182, 134, 128, 197, 220, 184, 144,
-            48, 87, 179, 9, 12, 245, 165, 103, 193, 28, 253, 79, 70, 230, 195, 131, 5, 124, 112,
-            171, 160, 54, 72, 205, 74, 36, 252, 245, 128, 245, 22, 100, 52, 187, 161, 87, 79, 148,
-            130, 213, 215, 219, 143, 29, 139, 179, 140, 108, 71, 105, 9, 8, 103, 53, 88, 66, 77,
-            219, 88, 209, 174, 128, 212, 186, 208, 207, 165, 55, 183, 68, 170, 127, 146, 158, 202,
-            36, 119, 238, 109, 55, 243, 183, 112, 162, 134, 253, 86, 22, 237, 103, 242, 252, 208,
-            237, 128, 17, 99, 203, 152, 149, 177, 197, 203, 162, 120, 228, 91, 138, 154, 181, 225,
-            237, 175, 118, 130, 210, 223, 193, 13, 112, 221, 215, 17, 6, 226, 73, 28, 128, 4, 216,
-            2, 157, 168, 220, 49, 106, 247, 67, 24, 49, 155, 69, 122, 72, 239, 54, 246, 135, 222,
-            46, 194, 58, 113, 145, 235, 169, 125, 64, 211, 122, 128, 195, 66, 41, 72, 199, 187,
-            180, 153, 243, 223, 71, 98, 195, 151, 156, 184, 112, 50, 29, 246, 216, 59, 20, 165, 7,
-            240, 232, 102, 97, 80, 164, 206, 128, 145, 63, 250, 26, 195, 75, 39, 16, 83, 141, 48,
-            10, 106, 126, 174, 199, 201, 116, 94, 48, 35, 20, 96, 5, 64, 109, 69, 226, 8, 122, 20,
-            148, 128, 101, 188, 79, 138, 0, 82, 49, 142, 224, 148, 243, 97, 110, 49, 210, 178, 183,
-            118, 23, 19, 0, 26, 255, 9, 38, 239, 0, 59, 152, 8, 232, 65, 77, 8, 128, 255, 255, 128,
-            52, 152, 211, 190, 190, 158, 24, 14, 187, 114, 245, 129, 54, 61, 154, 0, 117, 139, 218,
-            240, 191, 56, 170, 150, 251, 101, 38, 180, 152, 139, 79, 38, 128, 251, 253, 5, 15, 246,
-            96, 81, 182, 123, 17, 154, 162, 226, 188, 243, 6, 139, 58, 157, 116, 190, 107, 108, 13,
-            89, 59, 173, 22, 237, 116, 122, 235, 128, 179, 28, 208, 45, 209, 100, 121, 155, 83, 66,
-            137, 54, 219, 53, 142, 32, 150, 7, 172, 148, 18, 122, 21, 36, 182, 93, 206, 2, 165,
-            195, 64, 44, 128, 8, 180, 57, 2, 38, 131, 151, 161, 248, 77, 111, 109, 180, 209, 200,
-            113, 88, 154, 143, 210, 153, 37, 193, 151, 17, 159, 76, 65, 90, 104, 54, 176, 128, 18,
-            52, 74, 173, 8, 114, 201, 4, 207, 101, 41, 158, 121, 41, 143, 233, 34, 3, 199, 241,
-            113, 152, 253, 255, 203, 131, 13, 102, 74, 25, 177, 183, 128, 202, 97, 201, 10, 67, 13,
-            160, 242, 224, 111, 202, 243, 55, 6, 9, 56, 208, 220, 27, 245, 152, 201, 250, 249, 228,
-            7, 139, 211, 173, 141, 5, 174, 128, 220, 10, 234, 6, 246, 9, 0, 85, 192, 41, 60, 21,
-            71, 124, 110, 49, 251, 223, 34, 177, 93, 219, 34, 146, 191, 71, 237, 3, 129, 158, 76,
-            193, 128, 192, 236, 45, 201, 236, 228, 208, 145, 220, 128, 181, 224, 168, 124, 9, 188,
-            11, 192, 67, 96, 172, 46, 68, 103, 55, 211, 214, 222, 12, 139, 101, 140, 128, 86, 218,
-            58, 172, 28, 45, 161, 2, 182, 166, 215, 163, 52, 76, 140, 207, 108, 135, 124, 120, 62,
-            158, 57, 193, 15, 143, 147, 86, 16, 141, 186, 29, 128, 111, 168, 178, 161, 106, 91,
-            217, 175, 166, 28, 60, 105, 190, 193, 46, 168, 61, 135, 62, 166, 95, 215, 142, 58, 13,
-            243, 77, 117, 49, 78, 151, 160, 128, 191, 212, 224, 98, 47, 88, 40, 255, 123, 79, 95,
-            176, 156, 243, 208, 110, 56, 211, 109, 85, 137, 179, 175, 25, 13, 241, 107, 209, 177,
-            135, 146, 163, 128, 194, 209, 192, 145, 251, 123, 139, 22, 187, 137, 235, 254, 6, 111,
-            31, 158, 192, 69, 78, 108, 156, 64, 187, 115, 82, 223, 9, 82, 61, 51, 119, 95, 128,
-            245, 163, 92, 184, 100, 208, 179, 172, 214, 16, 140, 71, 242, 205, 253, 56, 207, 97,
-            126, 100, 165, 248, 139, 109, 172, 208, 235, 249, 200, 80, 220, 254, 128, 158, 200,
-            116, 18, 168, 59, 54, 44, 90, 135, 190, 184, 13, 98, 181, 73, 85, 229, 180, 57, 54,
-            210, 160, 124, 167, 184, 99, 32, 99, 12, 75, 105, 128, 11, 18, 66, 155, 163, 28, 120,
-            167, 85, 154, 216, 121, 19, 215, 47, 51, 104, 158, 103, 55, 192, 211, 168, 253, 220,
-            78, 160, 45, 253, 45, 211, 192, 128, 255, 205, 138, 183, 70, 207, 10, 69, 205, 195, 48,
-            211, 73, 18, 184, 30, 90, 174, 196, 104, 34, 171, 249, 50, 206, 143, 203, 180, 165,
-            233, 162, 162, 77, 8, 128, 255, 255, 128, 58, 201, 166, 146, 29, 113, 160, 18, 70, 236,
-            115, 70, 19, 165, 72, 68, 38, 85, 231, 110, 214, 218, 156, 215, 151, 175, 220, 188,
-            182, 73, 74, 17, 128, 57, 37, 64, 205, 152, 86, 106, 143, 131, 35, 221, 109, 149, 45,
-            141, 167, 37, 79, 236, 129, 65, 7, 181, 110, 55, 11, 250, 240, 52, 117, 169, 158, 128,
-            204, 101, 91, 16, 8, 83, 117, 119, 139, 121, 194, 37, 123, 134, 194, 90, 40, 219, 78,
-            233, 131, 48, 203, 219, 106, 122, 71, 25, 40, 11, 225, 153, 128, 58, 54, 217, 135, 108,
-            131, 104, 19, 90, 4, 53, 235, 35, 168, 213, 219, 94, 202, 119, 183, 11, 157, 233, 29,
-            160, 191, 108, 188, 51, 12, 60, 141, 128, 160, 174, 75, 199, 199, 233, 169, 101, 168,
-            78, 26, 106, 176, 105, 38, 129, 28, 247, 14, 214, 218, 221, 13, 240, 9, 20, 63, 123,
-            141, 26, 190, 139, 128, 45, 224, 62, 80, 60, 203, 182, 72, 101, 127, 17, 216, 166, 187,
-            183, 16, 192, 61, 92, 109, 127, 2, 139, 56, 251, 157, 175, 125, 230, 247, 185, 101,
-            128, 33, 130, 72, 131, 94, 76, 68, 224, 132, 150, 251, 90, 1, 34, 7, 183, 173, 178,
-            240, 110, 250, 90, 137, 100, 116, 16, 195, 20, 129, 2, 12, 105, 128, 88, 2, 36, 134,
-            82, 225, 134, 78, 174, 11, 164, 130, 125, 128, 124, 207, 48, 63, 217, 247, 112, 230,
-            13, 150, 217, 139, 64, 60, 81, 63, 44, 190, 128, 248, 1, 253, 234, 97, 207, 65, 159,
-            63, 170, 168, 182, 82, 204, 227, 23, 134, 91, 81, 166, 28, 113, 220, 58, 95, 180, 123,
-            243, 6, 127, 139, 43, 128, 196, 0, 96, 165, 125, 95, 251, 75, 16, 200, 144, 62, 177,
-            94, 27, 145, 69, 51, 153, 206, 126, 148, 236, 188, 24, 58, 222, 98, 81, 47, 192, 198,
-            128, 251, 20, 123, 1, 82, 61, 114, 201, 104, 55, 28, 164, 11, 225, 82, 170, 17, 15,
-            255, 80, 199, 143, 187, 252, 119, 113, 83, 197, 12, 133, 199, 101, 128, 0, 93, 235,
-            202, 77, 21, 48, 18, 116, 37, 121, 157, 205, 37, 139, 102, 158, 231, 50, 87, 189, 72,
-            167, 17, 211, 116, 181, 1, 120, 27, 19, 249, 128, 87, 232, 34, 89, 226, 3, 137, 227,
-            28, 203, 181, 169, 215, 140, 10, 249, 197, 27, 218, 50, 128, 145, 14, 251, 156, 87,
-            140, 184, 184, 215, 8, 42, 128, 214, 186, 104, 28, 203, 26, 133, 97, 30, 82, 219, 208,
-            159, 189, 193, 198, 175, 244, 101, 39, 137, 114, 76, 22, 132, 158, 108, 244, 117, 184,
-            90, 85, 128, 14, 208, 6, 122, 78, 49, 9, 54, 65, 194, 183, 96, 215, 66, 8, 73, 20, 5,
-            110, 155, 203, 10, 199, 77, 90, 151, 44, 247, 242, 234, 143, 76, 128, 137, 223, 40,
-            196, 188, 73, 243, 240, 70, 195, 150, 112, 119, 234, 146, 188, 3, 143, 95, 228, 15,
-            193, 177, 235, 203, 165, 146, 65, 141, 18, 248, 235, 77, 8, 128, 255, 255, 128, 72,
-            227, 186, 208, 65, 38, 67, 240, 103, 92, 8, 31, 242, 47, 245, 124, 69, 128, 43, 61,
-            106, 233, 158, 65, 44, 123, 190, 30, 136, 221, 162, 110, 128, 20, 154, 240, 177, 127,
-            113, 180, 62, 122, 234, 248, 200, 205, 42, 62, 125, 112, 181, 95, 33, 195, 255, 255,
-            169, 168, 104, 31, 17, 9, 24, 157, 171, 128, 208, 215, 84, 115, 128, 144, 126, 59, 82,
-            130, 127, 14, 160, 184, 204, 246, 129, 118, 140, 182, 5, 115, 188, 129, 114, 59, 124,
-            74, 133, 195, 116, 82, 128, 245, 84, 141, 238, 0, 88, 3, 93, 78, 160, 149, 210, 209,
-            205, 188, 118, 155, 9, 41, 170, 77, 84, 141, 233, 167, 40, 235, 193, 187, 22, 235, 200,
-            128, 67, 167, 238, 15, 193, 10, 24, 162, 254, 231, 215, 199, 172, 254, 225, 135, 55,
-            189, 91, 55, 228, 121, 131, 223, 125, 40, 54, 153, 135, 202, 139, 121, 128, 40, 199,
-            178, 138, 237, 194, 159, 253, 110, 97, 131, 44, 90, 159, 151, 221, 200, 60, 141, 9, 61,
-            225, 19, 96, 144, 170, 177, 48, 242, 92, 158, 235, 128, 252, 114, 254, 107, 51, 251,
-            164, 43, 155, 188, 232, 54, 164, 242, 239, 19, 144, 44, 168, 19, 159, 30, 79, 200, 254,
-            66, 197, 216, 213, 160, 83, 224, 128, 152, 98, 71, 87, 176, 216, 190, 121, 251, 201,
-            190, 32, 15, 23, 229, 227, 64, 32, 180, 98, 168, 212, 246, 224, 140, 113, 76, 238, 1,
-            159, 197, 67, 128, 183, 68, 13, 132, 134, 132, 53, 250, 94, 25, 59, 107, 189, 188, 146,
-            126, 239, 148, 197, 234, 108, 254, 34, 173, 202, 44, 89, 168, 218, 147, 247, 187, 128,
-            203, 121, 148, 63, 84, 92, 146, 115, 80, 45, 207, 173, 216, 199, 47, 180, 75, 35, 145,
-            102, 158, 38, 123, 19, 85, 144, 176, 241, 59, 96, 81, 31, 128, 28, 122, 35, 15, 86,
-            120, 97, 7, 171, 19, 190, 105, 208, 148, 100, 236, 249, 120, 241, 229, 197, 41, 220,
-            148, 178, 32, 20, 138, 252, 160, 3, 181, 128, 107, 53, 208, 95, 35, 48, 191, 237, 246,
-            105, 67, 216, 186, 129, 234, 10, 245, 180, 72, 82, 209, 69, 228, 22, 204, 157, 177,
-            214, 7, 125, 130, 162, 128, 200, 182, 239, 18, 113, 83, 136, 129, 241, 86, 75, 2, 108,
-            211, 184, 233, 133, 125, 126, 229, 197, 18, 141, 74, 49, 209, 251, 162, 123, 132, 67,
-            241, 128, 98, 169, 128, 71, 226, 12, 74, 91, 45, 220, 144, 255, 5, 43, 4, 161, 5, 168,
-            254, 146, 163, 188, 181, 188, 245, 209, 229, 67, 252, 181, 99, 38, 128, 141, 190, 165,
-            156, 57, 149, 153, 127, 68, 145, 42, 198, 63, 244, 246, 245, 163, 214, 214, 230, 239,
-            171, 146, 69, 208, 69, 33, 128, 110, 194, 184, 150, 128, 87, 53, 244, 157, 86, 127,
-            165, 192, 182, 32, 74, 181, 129, 234, 159, 244, 180, 47, 218, 164, 250, 98, 146, 170,
-            222, 30, 50, 65, 128, 99, 114, 192, 77, 8, 128, 255, 255, 128, 98, 224, 118, 12, 76,
-            51, 177, 134, 55, 11, 240, 54, 16, 130, 71, 53, 233, 48, 237, 233, 145, 55, 196, 116,
-            75, 125, 25, 10, 222, 110, 145, 72, 128, 123, 97, 129, 68, 255, 146, 109, 130, 170,
-            164, 51, 127, 247, 61, 60, 39, 45, 247, 96, 65, 102, 249, 254, 240, 235, 150, 72, 191,
-            254, 139, 212, 143, 128, 10, 145, 115, 111, 253, 223, 196, 17, 64, 94, 25, 146, 103,
-            246, 48, 225, 201, 116, 96, 194, 171, 54, 38, 98, 86, 3, 3, 71, 129, 31, 6, 175, 128,
-            199, 195, 90, 57, 51, 127, 202, 28, 248, 27, 156, 174, 209, 38, 194, 27, 143, 96, 209,
-            92, 159, 32, 96, 206, 102, 91, 145, 17, 100, 34, 161, 225, 128, 111, 174, 226, 248,
-            116, 189, 185, 98, 48, 158, 21, 64, 84, 37, 132, 251, 159, 119, 145, 158, 160, 94, 50,
-            14, 78, 36, 7, 69, 225, 169, 118, 163, 128, 130, 131, 93, 107, 228, 124, 194, 73, 133,
-            43, 145, 205, 67, 139, 12, 122, 202, 182, 137, 8, 117, 46, 210, 14, 252, 151, 44, 230,
-            172, 18, 153, 208, 128, 90, 123, 171, 183, 168, 73, 23, 229, 112, 45, 203, 101, 146,
-            205, 166, 179, 139, 173, 186, 161, 12, 37, 201, 110, 4, 24, 59, 86, 176, 85, 19, 214,
-            128, 34, 137, 24, 131, 123, 49, 233, 144, 163, 244, 68, 122, 147, 162, 184, 83, 218,
-            111, 153, 130, 208, 164, 104, 189, 32, 120, 168, 115, 238, 172, 147, 241, 128, 253,
-            185, 119, 165, 240, 12, 10, 37, 37, 189, 229, 131, 173, 106, 160, 226, 95, 234, 200,
-            170, 142, 79, 97, 231, 226, 69, 40, 164, 129, 29, 119, 217, 128, 215, 202, 10, 223, 65,
-            233, 98, 218, 223, 110, 11, 98, 108, 232, 78, 52, 25, 27, 11, 140, 93, 219, 35, 105,
-            104, 192, 213, 107, 103, 160, 147, 131, 128, 213, 23, 236, 208, 56, 116, 59, 227, 151,
-            243, 244, 198, 4, 58, 214, 202, 73, 178, 35, 192, 153, 13, 96, 246, 250, 18, 143, 18,
-            70, 157, 58, 4, 128, 50, 104, 164, 63, 181, 151, 229, 156, 25, 254, 186, 145, 93, 195,
-            131, 202, 231, 191, 255, 114, 215, 176, 156, 119, 36, 246, 10, 30, 88, 250, 251, 240,
-            128, 103, 233, 178, 7, 124, 202, 44, 82, 24, 123, 141, 45, 162, 11, 34, 22, 42, 104,
-            22, 81, 73, 15, 223, 49, 220, 156, 80, 188, 243, 88, 152, 39, 128, 24, 57, 95, 182,
-            144, 171, 157, 147, 39, 2, 248, 223, 190, 4, 238, 26, 205, 51, 243, 21, 92, 81, 253,
-            196, 58, 117, 8, 176, 3, 193, 96, 167, 128, 151, 147, 172, 137, 135, 140, 127, 19, 211,
-            158, 45, 240, 247, 87, 188, 12, 50, 211, 176, 157, 204, 105, 116, 196, 132, 232, 58,
-            25, 94, 127, 118, 37, 128, 243, 24, 174, 152, 134, 125, 213, 8, 179, 123, 215, 74, 241,
-            38, 134, 46, 15, 246, 224, 164, 243, 101, 224, 238, 242, 220, 108, 49, 7, 49, 19, 171,
-            77, 8, 128, 255, 255, 128, 126, 26, 2, 115, 125, 60, 237, 15, 224, 8, 167, 168, 170,
-            64, 193, 115, 82, 79, 139, 126, 45, 146, 247, 235, 138, 57, 197, 193, 187, 235, 128,
-            255, 128, 25, 176, 10, 6, 193, 115, 82, 78, 202, 212, 37, 48, 143, 31, 79, 135, 198,
-            58, 65, 93, 240, 196, 114, 75, 89, 200, 211, 24, 49, 211, 43, 248, 128, 139, 88, 169,
-            62, 211, 4, 166, 73, 191, 142, 142, 178, 189, 92, 38, 216, 103, 217, 211, 148, 1, 115,
-            180, 191, 77, 82, 223, 207, 20, 125, 130, 6, 128, 209, 115, 203, 18, 175, 206, 244, 86,
-            105, 74, 157, 81, 221, 58, 102, 232, 144, 178, 104, 52, 9, 139, 91, 182, 234, 82, 83,
-            165, 63, 160, 7, 114, 128, 243, 117, 5, 152, 169, 216, 80, 167, 179, 87, 94, 251, 216,
-            140, 34, 48, 215, 29, 91, 152, 228, 185, 96, 65, 138, 128, 76, 168, 225, 197, 100, 250,
-            128, 73, 28, 242, 167, 201, 42, 255, 15, 244, 48, 253, 183, 243, 188, 29, 212, 143, 41,
-            81, 54, 199, 149, 4, 212, 255, 92, 150, 2, 85, 79, 165, 249, 128, 70, 150, 17, 211, 84,
-            245, 117, 100, 205, 53, 45, 9, 109, 239, 251, 118, 83, 123, 151, 163, 98, 7, 31, 40,
-            101, 127, 254, 39, 185, 235, 191, 126, 128, 172, 29, 177, 47, 180, 240, 225, 255, 229,
-            172, 48, 134, 20, 239, 15, 216, 82, 179, 108, 203, 153, 93, 70, 159, 209, 54, 236, 145,
-            124, 170, 81, 107, 128, 186, 239, 163, 235, 187, 188, 130, 158, 8, 16, 226, 8, 219, 34,
-            130, 174, 188, 108, 89, 154, 67, 90, 241, 11, 216, 9, 10, 128, 23, 129, 19, 221, 128,
-            186, 48, 62, 144, 230, 102, 102, 238, 208, 166, 83, 134, 87, 62, 187, 7, 25, 145, 177,
-            195, 61, 237, 224, 149, 223, 100, 143, 62, 16, 52, 146, 152, 128, 156, 234, 72, 98, 6,
-            27, 209, 167, 158, 64, 242, 192, 158, 62, 9, 86, 106, 165, 19, 16, 74, 190, 17, 113,
-            127, 135, 236, 235, 0, 127, 106, 39, 128, 40, 166, 91, 35, 4, 38, 216, 38, 58, 31, 180,
-            163, 228, 163, 221, 27, 13, 131, 28, 101, 92, 226, 34, 98, 131, 32, 29, 241, 155, 64,
-            143, 57, 128, 82, 245, 36, 234, 173, 169, 46, 196, 144, 134, 113, 200, 13, 87, 68, 4,
-            37, 101, 66, 251, 109, 83, 160, 85, 86, 69, 174, 215, 89, 230, 112, 188, 128, 221, 67,
-            169, 147, 247, 63, 47, 70, 136, 91, 226, 27, 120, 51, 140, 162, 62, 91, 88, 70, 44,
-            204, 72, 185, 113, 201, 160, 83, 159, 129, 97, 215, 128, 132, 55, 78, 3, 203, 239, 90,
-            240, 56, 255, 237, 43, 30, 91, 205, 38, 41, 33, 240, 10, 17, 141, 71, 173, 58, 21, 199,
-            163, 147, 192, 51, 251, 128, 180, 232, 114, 114, 129, 107, 156, 91, 142, 40, 134, 148,
-            85, 46, 182, 150, 215, 2, 32, 125, 172, 93, 123, 212, 118, 25, 55, 24, 116, 138, 17,
-            101, 77, 8, 128, 255, 255, 128, 128, 254, 250, 23, 70, 79, 240, 10, 70, 190, 77, 23,
-            122, 171, 19, 37, 218, 153, 112, 130, 94, 30, 206, 18, 150, 32, 162, 65, 73, 163, 16,
-            207, 128, 137, 190, 230, 69, 134, 11, 100, 164, 10, 55, 253, 247, 153, 197, 172, 120,
-            125, 235, 240, 22, 86, 199, 75, 131, 220, 63, 150, 25, 139, 71, 46, 106, 128, 73, 13,
-            115, 235, 64, 36, 245, 221, 252, 29, 178, 97, 173, 142, 37, 101, 82, 201, 121, 39, 123,
-            78, 17, 212, 132, 196, 101, 210, 70, 235, 119, 106, 128, 253, 222, 171, 127, 115, 14,
-            115, 223, 212, 163, 30, 77, 202, 78, 253, 70, 8, 102, 29, 54, 82, 179, 118, 125, 228,
-            121, 204, 89, 155, 222, 147, 83, 128, 227, 191, 35, 207, 4, 38, 245, 230, 87, 71, 56,
-            117, 73, 164, 79, 249, 251, 166, 168, 12, 182, 31, 114, 208, 98, 190, 35, 40, 34, 54,
-            149, 142, 128, 210, 191, 125, 82, 15, 167, 39, 230, 247, 255, 40, 150, 213, 65, 72, 78,
-            231, 253, 153, 154, 181, 54, 10, 85, 83, 168, 104, 19, 123, 238, 210, 157, 128, 188,
-            148, 108, 164, 57, 34, 79, 98, 107, 189, 224, 8, 59, 254, 177, 215, 116, 47, 35, 20,
-            70, 19, 107, 248, 229, 173, 205, 99, 127, 84, 140, 38, 128, 89, 233, 77, 43, 63, 224,
-            59, 236, 215, 221, 139, 212, 69, 171, 94, 165, 14, 206, 152, 89, 1, 53, 196, 34, 246,
-            82, 59, 225, 142, 199, 88, 243, 128, 117, 226, 17, 47, 123, 236, 238, 34, 252, 243, 63,
-            155, 180, 126, 70, 157, 186, 178, 213, 77, 110, 145, 185, 75, 124, 249, 105, 205, 153,
-            181, 43, 36, 128, 157, 170, 117, 166, 123, 135, 0, 223, 80, 39, 185, 37, 13, 197, 134,
-            103, 88, 139, 185, 94, 124, 140, 157, 193, 219, 105, 162, 48, 142, 116, 46, 248, 128,
-            160, 91, 89, 227, 55, 123, 4, 89, 135, 36, 197, 89, 65, 101, 147, 121, 121, 162, 65,
-            36, 178, 25, 40, 209, 173, 255, 147, 13, 45, 189, 157, 250, 128, 41, 153, 119, 56, 243,
-            143, 95, 161, 80, 46, 98, 74, 216, 137, 226, 201, 173, 30, 216, 212, 152, 61, 217, 17,
-            119, 35, 134, 112, 232, 65, 164, 120, 128, 165, 55, 127, 175, 186, 31, 23, 220, 207,
-            96, 33, 98, 108, 159, 8, 38, 117, 139, 15, 18, 75, 119, 213, 84, 118, 33, 191, 143, 40,
-            188, 193, 157, 128, 222, 213, 70, 76, 206, 239, 36, 101, 17, 253, 75, 245, 2, 241, 235,
-            87, 87, 222, 38, 84, 92, 92, 188, 240, 191, 129, 94, 229, 17, 75, 55, 228, 128, 162,
-            140, 154, 201, 203, 90, 176, 148, 127, 231, 168, 21, 129, 93, 169, 155, 69, 224, 117,
-            12, 52, 111, 190, 79, 218, 237, 3, 26, 139, 72, 33, 23, 128, 28, 63, 210, 252, 209, 52,
-            215, 165, 54, 173, 246, 45, 171, 12, 203, 82, 21, 25, 104, 150, 58, 235, 34, 234, 147,
-            220, 232, 209, 128, 233, 52, 165, 77, 8, 128, 255, 255, 128, 137, 99, 201, 150, 213,
-            190, 114, 244, 6, 179, 169, 97, 77, 105, 45, 53, 22, 156, 93, 12, 184, 184, 84, 163,
-            91, 104, 22, 72, 172, 99, 223, 187, 128, 197, 219, 148, 198, 173, 94, 34, 66, 48, 182,
-            111, 221, 140, 160, 148, 77, 231, 210, 200, 148, 207, 51, 95, 208, 62, 210, 245, 20,
-            68, 139, 200, 4, 128, 77, 144, 61, 88, 8, 197, 14, 144, 145, 125, 56, 136, 208, 232,
-            145, 155, 219, 207, 92, 164, 189, 153, 166, 199, 121, 225, 217, 127, 9, 134, 39, 186,
-            128, 196, 124, 80, 137, 76, 55, 85, 185, 142, 135, 241, 142, 186, 100, 253, 165, 77,
-            160, 85, 32, 134, 41, 247, 184, 65, 131, 59, 132, 169, 9, 30, 166, 128, 18, 117, 140,
-            186, 228, 64, 135, 84, 30, 98, 181, 80, 230, 253, 70, 136, 95, 136, 171, 130, 46, 36,
-            28, 19, 143, 163, 105, 132, 15, 51, 193, 199, 128, 221, 162, 121, 197, 49, 117, 171,
-            105, 13, 99, 191, 44, 219, 54, 37, 241, 44, 45, 172, 149, 42, 252, 240, 231, 151, 32,
-            250, 229, 157, 108, 230, 123, 128, 85, 204, 145, 73, 4, 179, 123, 90, 28, 38, 46, 107,
-            205, 172, 132, 83, 73, 150, 32, 87, 203, 253, 11, 237, 250, 4, 168, 207, 139, 102, 15,
-            50, 128, 79, 6, 201, 246, 121, 70, 151, 141, 50, 106, 166, 207, 183, 186, 173, 93, 245,
-            23, 122, 225, 14, 110, 67, 117, 79, 88, 197, 78, 27, 243, 172, 211, 128, 26, 191, 13,
-            212, 124, 197, 72, 93, 188, 69, 32, 48, 26, 65, 16, 151, 10, 142, 204, 17, 255, 100,
-            85, 131, 92, 60, 48, 128, 164, 159, 216, 161, 128, 176, 133, 137, 219, 162, 236, 2, 48,
-            223, 221, 131, 212, 23, 43, 87, 248, 115, 231, 110, 26, 95, 225, 207, 107, 93, 164,
-            187, 72, 69, 86, 225, 188, 128, 50, 147, 36, 178, 48, 203, 172, 96, 168, 69, 36, 123,
-            104, 92, 74, 223, 197, 134, 151, 188, 138, 123, 247, 105, 170, 126, 38, 79, 175, 6,
-            110, 251, 128, 91, 14, 212, 160, 69, 125, 86, 21, 62, 176, 59, 10, 244, 109, 5, 126,
-            233, 245, 115, 236, 1, 216, 100, 233, 73, 229, 190, 213, 199, 186, 115, 57, 128, 122,
-            80, 179, 183, 66, 91, 86, 66, 44, 113, 18, 236, 200, 216, 160, 62, 130, 153, 124, 125,
-            218, 30, 160, 165, 232, 219, 187, 161, 206, 217, 238, 164, 128, 23, 137, 235, 127, 228,
-            222, 79, 161, 131, 192, 77, 101, 19, 4, 8, 144, 158, 213, 48, 93, 185, 40, 209, 229,
-            224, 60, 56, 207, 3, 27, 102, 215, 128, 149, 254, 169, 111, 198, 105, 94, 163, 208,
-            239, 170, 235, 31, 98, 253, 82, 71, 239, 23, 186, 176, 141, 237, 85, 39, 235, 244, 19,
-            248, 173, 73, 74, 128, 18, 246, 63, 139, 55, 101, 233, 177, 71, 116, 10, 149, 84, 98,
-            53, 181, 234, 20, 179, 187, 71, 94, 87, 226, 132, 165, 35, 69, 46, 19, 162, 231, 77, 8,
-            128, 255, 255, 128, 156, 1, 8, 84, 91, 129, 182, 188, 2, 83, 7, 165, 8, 120, 243, 87,
-            173, 180, 121, 91, 65, 154, 45, 21, 71, 174, 77, 241, 179, 136, 13, 72, 128, 230, 28,
-            144, 93, 130, 226, 188, 221, 170, 208, 100, 82, 172, 225, 62, 209, 46, 68, 196, 33, 67,
-            4, 255, 22, 37, 196, 36, 174, 251, 236, 118, 222, 128, 214, 118, 96, 46, 102, 89, 165,
-            41, 246, 190, 182, 43, 105, 90, 176, 76, 189, 195, 215, 36, 237, 235, 187, 37, 79, 1,
-            100, 87, 76, 41, 73, 144, 128, 54, 183, 21, 156, 176, 38, 153, 68, 182, 160, 1, 199,
-            236, 221, 5, 213, 176, 16, 142, 78, 216, 40, 162, 25, 141, 11, 188, 248, 30, 210, 31,
-            163, 128, 75, 85, 160, 58, 176, 187, 39, 87, 199, 87, 164, 214, 101, 23, 26, 161, 102,
-            141, 46, 78, 231, 201, 60, 193, 167, 203, 38, 224, 183, 212, 245, 200, 128, 195, 26,
-            85, 247, 19, 6, 184, 129, 146, 36, 245, 138, 228, 216, 21, 170, 212, 18, 69, 21, 148,
-            124, 116, 72, 53, 13, 208, 37, 217, 253, 6, 212, 128, 40, 219, 41, 78, 113, 46, 62, 53,
-            196, 90, 196, 164, 54, 253, 126, 163, 171, 242, 23, 98, 212, 184, 39, 40, 163, 161, 94,
-            91, 183, 100, 173, 194, 128, 134, 161, 195, 243, 2, 138, 198, 95, 202, 238, 164, 185,
-            49, 3, 130, 20, 17, 174, 158, 221, 122, 51, 240, 97, 103, 182, 227, 64, 20, 199, 20,
-            49, 128, 255, 72, 48, 114, 3, 35, 14, 17, 46, 168, 199, 166, 43, 153, 163, 166, 53,
-            162, 127, 82, 97, 138, 54, 53, 35, 12, 177, 237, 37, 53, 113, 72, 128, 46, 149, 49, 64,
-            22, 110, 232, 250, 216, 176, 185, 77, 36, 109, 242, 191, 137, 155, 42, 3, 180, 61, 5,
-            146, 70, 239, 219, 5, 155, 189, 189, 161, 128, 241, 246, 18, 164, 219, 201, 100, 248,
-            235, 228, 160, 102, 103, 123, 5, 69, 48, 159, 2, 44, 149, 207, 228, 250, 167, 169, 178,
-            164, 72, 221, 10, 171, 128, 165, 52, 248, 115, 98, 191, 225, 147, 225, 201, 200, 167,
-            240, 3, 242, 222, 73, 84, 112, 54, 52, 42, 159, 193, 123, 184, 193, 197, 167, 182, 220,
-            134, 128, 126, 186, 202, 232, 87, 89, 157, 125, 156, 148, 133, 242, 194, 73, 236, 186,
-            151, 239, 221, 24, 95, 9, 136, 87, 139, 73, 49, 113, 145, 93, 7, 62, 128, 172, 197,
-            129, 8, 203, 252, 76, 29, 110, 106, 58, 21, 178, 190, 175, 177, 137, 207, 54, 251, 73,
-            35, 232, 228, 250, 155, 129, 160, 134, 206, 127, 235, 128, 248, 191, 25, 102, 35, 45,
-            111, 123, 238, 86, 29, 254, 128, 68, 227, 175, 192, 93, 19, 217, 162, 138, 47, 252,
-            170, 21, 173, 80, 187, 35, 100, 208, 128, 50, 196, 199, 63, 198, 32, 22, 234, 199, 53,
-            49, 204, 66, 56, 241, 135, 31, 199, 250, 237, 131, 168, 77, 113, 91, 198, 114, 41, 43,
-            164, 199, 210, 77, 8, 128, 255, 255, 128, 161, 170, 188, 190, 218, 88, 84, 19, 91, 88,
-            194, 120, 165, 126, 95, 15, 221, 19, 203, 0, 88, 211, 220, 121, 20, 193, 150, 197, 57,
-            168, 204, 61, 128, 174, 73, 255, 182, 60, 253, 41, 184, 208, 16, 215, 111, 181, 47, 64,
-            67, 144, 183, 182, 191, 233, 211, 225, 217, 247, 13, 18, 235, 176, 230, 25, 21, 128,
-            245, 160, 188, 5, 44, 199, 63, 129, 18, 187, 84, 101, 202, 147, 10, 147, 49, 3, 218,
-            70, 121, 111, 233, 66, 32, 233, 248, 133, 165, 13, 28, 219, 128, 14, 131, 238, 132, 41,
-            5, 14, 36, 248, 102, 22, 239, 179, 79, 208, 144, 227, 144, 199, 3, 94, 206, 131, 69,
-            146, 217, 208, 123, 251, 157, 205, 225, 128, 201, 255, 73, 224, 110, 158, 8, 94, 36,
-            55, 212, 237, 246, 37, 55, 70, 188, 222, 152, 76, 249, 234, 109, 66, 106, 45, 72, 221,
-            238, 43, 110, 75, 128, 38, 150, 145, 120, 219, 99, 149, 1, 78, 49, 180, 66, 12, 123,
-            211, 181, 120, 66, 152, 13, 242, 211, 225, 0, 43, 152, 83, 59, 130, 55, 158, 228, 128,
-            100, 229, 120, 116, 34, 155, 226, 67, 141, 201, 28, 67, 9, 149, 245, 162, 250, 33, 233,
-            147, 156, 18, 139, 46, 109, 175, 110, 62, 250, 255, 210, 189, 128, 222, 185, 16, 105,
-            45, 31, 111, 154, 244, 95, 235, 90, 16, 147, 39, 115, 135, 248, 251, 150, 146, 57, 252,
-            46, 166, 223, 188, 49, 240, 234, 13, 158, 128, 33, 232, 62, 9, 240, 6, 208, 13, 100,
-            149, 241, 92, 217, 45, 171, 37, 171, 218, 162, 174, 16, 47, 220, 90, 75, 48, 68, 124,
-            218, 138, 55, 107, 128, 103, 57, 14, 58, 242, 151, 78, 13, 140, 17, 77, 20, 3, 138,
-            153, 157, 25, 43, 143, 232, 153, 195, 243, 132, 121, 2, 137, 118, 173, 42, 18, 130,
-            128, 220, 215, 139, 137, 220, 192, 81, 98, 186, 83, 66, 246, 89, 5, 28, 36, 157, 107,
-            51, 113, 149, 84, 177, 143, 36, 186, 87, 61, 170, 103, 132, 151, 128, 46, 137, 147,
-            218, 166, 199, 59, 11, 162, 119, 139, 113, 85, 21, 33, 126, 149, 93, 122, 75, 232, 194,
-            66, 202, 86, 21, 224, 108, 135, 52, 133, 99, 128, 136, 117, 227, 97, 91, 152, 206, 125,
-            157, 195, 110, 114, 146, 128, 133, 192, 53, 85, 62, 141, 210, 118, 103, 252, 54, 41,
-            18, 47, 156, 42, 197, 6, 128, 177, 173, 252, 1, 57, 120, 8, 117, 140, 223, 84, 189,
-            163, 99, 215, 140, 235, 62, 53, 235, 56, 186, 37, 242, 41, 101, 60, 179, 144, 127, 14,
-            167, 128, 108, 64, 169, 3, 47, 216, 115, 25, 60, 249, 194, 24, 40, 42, 40, 173, 243,
-            102, 103, 209, 105, 30, 255, 123, 74, 82, 84, 37, 14, 19, 27, 124, 128, 14, 185, 46,
-            220, 127, 28, 164, 140, 32, 139, 241, 98, 71, 73, 138, 142, 127, 110, 90, 3, 172, 238,
-            101, 44, 220, 132, 205, 186, 94, 173, 21, 95, 77, 8, 128, 255, 255, 128, 168, 223, 25,
-            11, 206, 236, 171, 241, 168, 7, 44, 229, 142, 32, 218, 112, 90, 156, 111, 64, 130, 88,
-            124, 95, 39, 103, 100, 229, 18, 248, 121, 81, 128, 173, 73, 101, 206, 33, 240, 110,
-            100, 50, 233, 29, 115, 6, 148, 121, 110, 108, 122, 117, 183, 147, 109, 246, 48, 112,
-            83, 111, 156, 15, 4, 60, 75, 128, 22, 191, 84, 38, 63, 203, 40, 96, 183, 176, 170, 253,
-            141, 66, 144, 99, 155, 239, 192, 137, 33, 5, 0, 155, 134, 161, 228, 213, 51, 241, 199,
-            5, 128, 255, 29, 100, 191, 213, 214, 208, 199, 139, 190, 129, 8, 130, 20, 155, 156,
-            192, 65, 253, 63, 170, 224, 44, 156, 85, 163, 220, 218, 84, 40, 90, 75, 128, 131, 103,
-            65, 192, 138, 102, 118, 137, 191, 238, 116, 89, 53, 132, 154, 108, 119, 7, 57, 224,
-            123, 106, 166, 147, 108, 163, 73, 159, 224, 89, 93, 100, 128, 142, 245, 75, 52, 182, 2,
-            50, 50, 55, 244, 125, 107, 123, 69, 137, 65, 158, 59, 98, 196, 253, 79, 62, 145, 255,
-            200, 27, 4, 38, 231, 230, 66, 128, 32, 76, 194, 99, 158, 73, 99, 190, 128, 213, 50,
-            172, 63, 224, 128, 120, 196, 33, 85, 82, 174, 161, 222, 124, 74, 169, 99, 56, 246, 239,
-            102, 251, 128, 225, 75, 1, 43, 138, 191, 52, 196, 247, 204, 144, 239, 61, 142, 125, 36,
-            7, 82, 37, 185, 161, 215, 97, 79, 249, 120, 241, 151, 162, 113, 193, 240, 128, 78, 100,
-            19, 32, 69, 26, 151, 245, 12, 12, 164, 216, 124, 219, 29, 59, 171, 50, 240, 123, 5,
-            189, 80, 155, 53, 169, 24, 60, 221, 190, 239, 138, 128, 37, 202, 46, 52, 121, 244, 43,
-            35, 82, 59, 162, 209, 187, 193, 150, 50, 18, 61, 162, 156, 172, 159, 5, 34, 120, 183,
-            237, 222, 15, 51, 98, 15, 128, 214, 215, 183, 54, 157, 86, 208, 53, 194, 194, 180, 166,
-            204, 122, 184, 39, 161, 169, 213, 53, 217, 45, 177, 222, 144, 217, 226, 250, 145, 159,
-            46, 66, 128, 84, 107, 60, 172, 40, 120, 176, 253, 9, 181, 23, 51, 211, 193, 124, 85,
-            37, 210, 8, 243, 129, 188, 254, 242, 197, 206, 56, 31, 126, 221, 246, 122, 128, 74,
-            176, 47, 192, 71, 227, 110, 159, 95, 177, 45, 87, 106, 251, 81, 229, 6, 121, 193, 191,
-            214, 62, 142, 135, 219, 38, 176, 129, 80, 46, 186, 86, 128, 57, 42, 237, 125, 44, 113,
-            246, 243, 236, 154, 250, 193, 180, 225, 81, 8, 228, 9, 128, 119, 167, 65, 255, 134,
-            195, 139, 195, 200, 211, 57, 148, 69, 128, 122, 25, 208, 150, 207, 187, 6, 193, 249,
-            234, 175, 181, 47, 148, 109, 209, 128, 129, 18, 214, 156, 2, 17, 122, 23, 114, 165, 66,
-            16, 87, 128, 78, 128, 133, 118, 210, 161, 195, 242, 152, 179, 136, 192, 161, 168, 232,
-            145, 119, 44, 206, 69, 150, 202, 131, 232, 153, 33, 94, 211, 167, 64, 0, 147, 18, 145,
-            77, 8, 128, 255, 255, 128, 235, 170, 176, 106, 31, 135, 91, 27, 168, 205, 4, 210, 244,
-            245, 153, 39, 146, 173, 71, 237, 34, 85, 25, 35, 65, 251, 16, 181, 194, 133, 69, 177,
-            128, 30, 105, 245, 56, 77, 120, 75, 118, 9, 103, 147, 15, 128, 37, 232, 103, 192, 56,
-            91, 149, 243, 47, 3, 44, 248, 217, 163, 21, 81, 78, 7, 44, 128, 219, 63, 87, 8, 229,
-            222, 132, 250, 1, 225, 51, 237, 254, 225, 29, 204, 131, 255, 36, 183, 178, 64, 127, 15,
-            70, 65, 169, 135, 234, 35, 190, 42, 128, 76, 160, 43, 46, 103, 108, 169, 140, 103, 27,
-            31, 189, 229, 148, 228, 166, 43, 33, 214, 75, 165, 53, 37, 39, 41, 130, 233, 94, 21,
-            229, 163, 118, 128, 120, 29, 226, 227, 235, 204, 91, 18, 175, 148, 50, 116, 126, 12,
-            234, 177, 74, 190, 46, 22, 132, 176, 114, 171, 146, 51, 253, 7, 0, 173, 40, 190, 128,
-            10, 193, 46, 129, 134, 245, 146, 190, 101, 121, 204, 152, 197, 80, 63, 220, 158, 200,
-            3, 88, 43, 44, 24, 165, 47, 181, 36, 193, 216, 241, 206, 153, 128, 90, 202, 167, 68,
-            255, 202, 210, 189, 211, 102, 71, 223, 102, 46, 128, 75, 170, 79, 177, 199, 67, 251,
-            183, 170, 185, 240, 181, 231, 43, 232, 225, 37, 128, 57, 84, 84, 151, 169, 159, 193,
-            167, 123, 121, 3, 115, 144, 171, 214, 20, 201, 43, 11, 242, 239, 31, 98, 31, 245, 174,
-            91, 190, 231, 85, 96, 239, 128, 35, 46, 229, 132, 40, 97, 158, 102, 223, 11, 72, 121,
-            149, 157, 232, 35, 79, 168, 102, 156, 86, 110, 1, 163, 100, 191, 166, 149, 195, 160,
-            170, 76, 128, 147, 191, 101, 119, 78, 36, 203, 198, 170, 164, 76, 173, 14, 57, 162,
-            118, 53, 78, 69, 161, 117, 97, 154, 32, 69, 17, 180, 12, 124, 129, 5, 14, 128, 238,
-            162, 232, 223, 55, 63, 249, 78, 244, 140, 123, 149, 7, 55, 164, 246, 80, 99, 65, 115,
-            207, 179, 161, 63, 52, 204, 24, 202, 215, 242, 56, 91, 128, 69, 153, 241, 208, 132,
-            246, 94, 133, 85, 90, 253, 147, 62, 108, 52, 231, 201, 200, 204, 100, 172, 217, 54, 40,
-            58, 143, 109, 54, 93, 201, 144, 103, 128, 238, 151, 217, 228, 185, 160, 7, 251, 223,
-            94, 194, 138, 47, 1, 36, 8, 197, 236, 30, 85, 222, 21, 46, 179, 128, 162, 136, 151, 90,
-            189, 253, 212, 128, 253, 255, 224, 120, 100, 7, 148, 55, 13, 206, 19, 157, 182, 204,
-            14, 230, 188, 48, 69, 14, 155, 189, 228, 115, 84, 223, 109, 202, 174, 60, 215, 10, 128,
-            71, 87, 138, 22, 28, 81, 197, 47, 104, 24, 131, 127, 84, 51, 252, 148, 54, 126, 65, 42,
-            48, 206, 113, 157, 82, 77, 29, 141, 183, 252, 55, 162, 128, 30, 161, 101, 93, 118, 108,
-            61, 92, 80, 96, 64, 174, 203, 66, 250, 140, 126, 84, 178, 13, 191, 111, 201, 6, 8, 253,
-            43, 80, 178, 215, 207, 63, 77, 8, 128, 255, 255, 128, 254, 30, 183, 151, 115, 142, 17,
-            117, 113, 99, 88, 46, 121, 61, 40, 246, 85, 123, 86, 225, 182, 110, 88, 7, 127, 236,
-            14, 247, 70, 244, 163, 95, 128, 25, 118, 253, 146, 237, 189, 40, 204, 170, 7, 92, 76,
-            35, 100, 223, 137, 144, 220, 46, 13, 101, 243, 110, 138, 17, 236, 143, 31, 34, 190,
-            216, 200, 128, 74, 137, 75, 14, 17, 249, 82, 101, 241, 44, 148, 157, 131, 227, 88, 236,
-            107, 178, 19, 144, 255, 18, 182, 150, 74, 201, 124, 242, 141, 123, 121, 194, 128, 250,
-            151, 110, 6, 119, 107, 220, 202, 108, 18, 127, 86, 201, 163, 98, 44, 240, 91, 227, 12,
-            214, 245, 104, 219, 186, 53, 19, 255, 8, 122, 188, 184, 128, 211, 146, 138, 20, 223,
-            29, 48, 26, 253, 202, 160, 72, 103, 101, 78, 222, 176, 49, 168, 126, 253, 186, 119,
-            249, 220, 62, 132, 135, 196, 129, 116, 158, 128, 30, 126, 214, 204, 31, 158, 45, 18,
-            145, 46, 133, 139, 72, 107, 230, 241, 68, 112, 169, 12, 13, 151, 23, 114, 0, 208, 118,
-            200, 40, 134, 94, 230, 128, 206, 213, 245, 105, 26, 106, 101, 23, 189, 76, 239, 199,
-            85, 40, 254, 229, 155, 194, 221, 190, 45, 96, 147, 175, 87, 138, 232, 181, 205, 150,
-            49, 80, 128, 141, 60, 110, 194, 237, 191, 197, 236, 116, 191, 135, 52, 79, 195, 187,
-            30, 254, 141, 40, 180, 204, 202, 137, 57, 245, 236, 209, 254, 232, 102, 172, 72, 128,
-            228, 113, 46, 103, 38, 244, 186, 42, 112, 88, 56, 48, 50, 241, 222, 58, 103, 225, 69,
-            239, 218, 99, 131, 101, 241, 105, 46, 126, 2, 246, 164, 71, 128, 74, 99, 84, 35, 53,
-            202, 101, 249, 124, 253, 188, 88, 201, 89, 107, 158, 190, 68, 36, 233, 21, 90, 130, 64,
-            9, 18, 59, 117, 41, 63, 74, 7, 128, 154, 164, 8, 129, 49, 88, 194, 88, 72, 14, 246,
-            132, 59, 151, 117, 243, 62, 10, 41, 34, 136, 118, 211, 40, 254, 207, 186, 250, 43, 27,
-            87, 33, 128, 89, 102, 96, 102, 172, 67, 126, 155, 245, 12, 166, 66, 237, 38, 24, 131,
-            52, 181, 1, 198, 204, 160, 195, 248, 144, 117, 252, 103, 13, 126, 103, 10, 128, 63,
-            129, 94, 79, 79, 8, 140, 151, 2, 191, 171, 207, 108, 8, 93, 93, 155, 179, 221, 143, 76,
-            165, 157, 232, 18, 160, 132, 170, 25, 238, 72, 7, 128, 119, 254, 39, 23, 181, 253, 242,
-            111, 177, 210, 15, 3, 56, 7, 104, 89, 7, 32, 59, 114, 109, 158, 109, 127, 21, 120, 95,
-            152, 43, 208, 228, 144, 128, 87, 85, 51, 133, 211, 92, 206, 71, 197, 21, 108, 152, 171,
-            3, 90, 151, 239, 201, 239, 189, 201, 85, 93, 55, 251, 66, 69, 103, 157, 210, 91, 233,
-            128, 186, 242, 201, 220, 156, 216, 1, 119, 120, 25, 180, 119, 197, 240, 220, 204, 81,
-            84, 145, 46, 3, 146, 76, 153, 132, 215, 36, 246, 57, 65, 242, 201, 25, 1, 129, 0, 1, 4,
-            128, 151, 240, 251, 168, 161, 213, 53, 147, 49, 115, 40, 24, 91, 138, 12, 10, 127, 180,
-            207, 145, 60, 236, 125, 178, 72, 131, 191, 240, 147, 216, 114, 80, 128, 99, 225, 30,
-            90, 6, 223, 163, 17, 116, 110, 66, 221, 95, 25, 157, 184, 185, 225, 169, 125, 177, 0,
-            197, 157, 193, 173, 187, 54, 214, 47, 174, 150, 25, 1, 129, 6, 0, 48, 128, 131, 25,
-            173, 168, 105, 73, 175, 136, 88, 162, 137, 4, 195, 98, 183, 150, 76, 188, 117, 194,
-            168, 216, 16, 113, 45, 63, 115, 227, 18, 202, 211, 151, 128, 198, 160, 241, 31, 89,
-            150, 183, 63, 127, 135, 242, 54, 97, 146, 132, 149, 179, 215, 135, 221, 39, 83, 47,
-            154, 31, 100, 214, 238, 170, 92, 77, 113, 25, 1, 129, 9, 0, 3, 128, 222, 9, 163, 161,
-            209, 171, 162, 251, 92, 193, 64, 233, 220, 234, 67, 42, 144, 204, 23, 124, 115, 42, 70,
-            22, 117, 255, 28, 3, 219, 60, 163, 140, 128, 75, 226, 216, 138, 193, 135, 86, 81, 122,
-            154, 128, 250, 2, 126, 141, 177, 8, 148, 209, 189, 79, 63, 221, 101, 210, 239, 242, 29,
-            46, 231, 227, 100, 25, 1, 129, 9, 128, 2, 128, 162, 89, 253, 127, 244, 163, 33, 35,
-            118, 232, 205, 99, 63, 229, 23, 82, 44, 44, 145, 169, 157, 142, 83, 61, 209, 191, 103,
-            206, 212, 252, 44, 67, 128, 201, 48, 61, 36, 212, 70, 232, 0, 64, 26, 187, 18, 168,
-            251, 92, 249, 56, 107, 140, 149, 213, 112, 111, 41, 161, 186, 55, 153, 222, 204, 120,
-            61, 141, 7, 157, 14, 73, 7, 247, 22, 172, 137, 182, 52, 125, 21, 236, 236, 237, 202,
-            255, 223, 88, 95, 11, 106, 69, 50, 30, 250, 233, 42, 234, 21, 224, 116, 14, 199, 175,
-            231, 16, 219, 3, 0, 0, 88, 95, 3, 142, 113, 97, 36, 145, 25, 45, 104, 222, 171, 126,
-            111, 86, 63, 225, 16, 41, 1, 0, 0, 128, 110, 200, 13, 122, 252, 137, 163, 204, 235,
-            225, 15, 205, 240, 76, 161, 101, 24, 255, 136, 214, 178, 148, 98, 236, 134, 27, 0, 211,
-            18, 57, 219, 53, 128, 24, 179, 250, 7, 252, 211, 24, 112, 36, 254, 110, 123, 88, 175,
-            104, 227, 26, 244, 141, 143, 123, 92, 93, 148, 246, 56, 254, 87, 54, 202, 227, 116,
-            128, 212, 186, 95, 7, 117, 214, 42, 252, 137, 90, 39, 204, 71, 219, 16, 172, 46, 91,
-            134, 227, 70, 144, 166, 190, 177, 236, 42, 57, 145, 233, 105, 61, 128, 14, 214, 94,
-            167, 86, 123, 217, 88, 156, 68, 3, 216, 251, 117, 130, 117, 173, 120, 238, 191, 150,
-            165, 214, 18, 109, 103, 131, 162, 149, 60, 190, 15, 128, 145, 79, 88, 158, 111, 117,
-            135, 211, 50, 230, 34, 131, 178, 91, 19, 230, 181, 115, 168, 181, 12, 173, 102, 237,
-            72, 30, 125, 22, 166, 190, 163, 226, 128, 150, 97, 48, 76, 74, 235, 167, 247, 69, 103,
-            4, 154, 130, 7, 3, 22, 72, 160, 82, 232, 18, 244, 176, 58, 42, 23, 7, 231, 12, 78, 180,
-            155, 128, 191, 24, 153, 195, 29, 109, 95, 250, 129, 242, 53, 146, 183, 178, 157, 20,
-            56, 109, 0, 24, 132, 32, 212, 75, 125, 0, 101, 184, 163, 124, 84, 180, 128, 223, 120,
-            85, 135, 67, 76, 242, 99, 220, 31, 86, 41, 97, 198, 169, 114, 181, 40, 200, 30, 33,
-            169, 7, 31, 17, 77, 67, 188, 58, 79, 49, 70, 128, 65, 72, 245, 149, 209, 123, 119, 9,
-            68, 98, 196, 190, 181, 249, 88, 108, 108, 19, 163, 131, 105, 250, 44, 15, 229, 23, 79,
-            70, 55, 48, 24, 207, 88, 95, 4, 154, 39, 56, 238, 179, 8, 150, 170, 203, 139, 63, 180,
-            100, 113, 189, 16, 4, 0, 0, 0, 128, 150, 77, 204, 23, 91, 96, 20, 177, 216, 5, 108,
-            247, 200, 200, 199, 101, 114, 110, 31, 183, 134, 248, 253, 142, 109, 152, 116, 233, 25,
-            185, 123, 153, 128, 5, 145, 11, 232, 224, 155, 242, 140, 49, 94, 170, 70, 75, 193, 225,
-            78, 35, 140, 92, 155, 200, 205, 85, 110, 178, 60, 35, 99, 30, 6, 200, 106, 128, 180,
-            147, 63, 242, 56, 9, 87, 70, 86, 7, 181, 212, 101, 128, 67, 39, 192, 87, 234, 252, 168,
-            145, 67, 182, 36, 28, 227, 4, 153, 70, 59, 250, 137, 8, 158, 220, 222, 147, 76, 101,
-            130, 39, 238, 29, 250, 252, 214, 225, 105, 3, 255, 255, 128, 212, 192, 107, 17, 26,
-            200, 122, 195, 120, 105, 34, 67, 13, 110, 222, 20, 168, 48, 115, 160, 212, 187, 246,
-            92, 253, 220, 93, 68, 165, 244, 128, 149, 128, 251, 50, 66, 146, 95, 208, 2, 95, 45,
-            114, 134, 72, 91, 66, 74, 130, 178, 173, 14, 21, 149, 16, 128, 230, 116, 61, 76, 168,
-            24, 113, 226, 218, 128, 130, 145, 33, 106, 121, 80, 96, 66, 164, 70, 191, 105, 83, 79,
-            54, 235, 249, 71, 225, 58, 5, 127, 130, 251, 200, 50, 19, 156, 250, 206, 78, 78, 128,
-            44, 192, 40, 14, 185, 110, 219, 179, 241, 90, 91, 52, 41, 102, 199, 113, 82, 132, 131,
-            96, 38, 20, 10, 30, 59, 82, 176, 198, 176, 87, 21, 87, 128, 195, 5, 208, 225, 21, 57,
-            155, 53, 89, 33, 130, 228, 169, 0, 76, 48, 24, 82, 5, 42, 137, 197, 162, 63, 21, 197,
-            141, 251, 212, 115, 106, 82, 128, 121, 48, 30, 3, 225, 115, 179, 164, 175, 222, 54, 84,
-            122, 135, 160, 236, 243, 69, 251, 160, 241, 3, 245, 102, 217, 137, 189, 2, 173, 64, 53,
-            101, 128, 153, 158, 145, 143, 88, 49, 185, 124, 0, 150, 98, 54, 245, 85, 94, 94, 215,
-            123, 89, 24, 10, 71, 85, 52, 250, 210, 20, 228, 16, 149, 211, 151, 128, 35, 126, 133,
-            35, 101, 156, 135, 154, 57, 170, 188, 113, 130, 140, 189, 101, 229, 126, 131, 120, 56,
-            160, 48, 107, 104, 186, 122, 224, 85, 129, 147, 46, 128, 105, 89, 155, 175, 165, 144,
-            141, 206, 81, 133, 45, 110, 19, 216, 203, 165, 255, 113, 78, 67, 243, 110, 156, 71,
-            109, 18, 18, 115, 92, 216, 173, 77, 128, 123, 141, 236, 214, 0, 104, 118, 31, 247, 52,
-            108, 8, 252, 87, 196, 216, 167, 133, 138, 61, 36, 211, 220, 61, 76, 245, 34, 188, 150,
-            163, 67, 176, 128, 145, 171, 89, 220, 120, 189, 81, 183, 187, 110, 153, 163, 54, 248,
-            154, 229, 38, 99, 248, 20, 255, 92, 198, 245, 44, 156, 75, 241, 238, 7, 6, 224, 128,
-            239, 194, 154, 97, 78, 80, 169, 43, 93, 31, 212, 140, 222, 208, 103, 168, 30, 51, 240,
-            20, 63, 224, 162, 202, 9, 214, 212, 169, 103, 167, 181, 110, 128, 97, 138, 136, 94, 17,
-            95, 133, 176, 236, 165, 222, 28, 208, 47, 223, 231, 215, 15, 27, 155, 26, 211, 56, 191,
-            215, 96, 178, 205, 221, 97, 26, 31, 128, 246, 79, 70, 71, 167, 94, 137, 199, 210, 5,
-            154, 192, 53, 113, 183, 23, 32, 135, 208, 76, 45, 4, 58, 15, 13, 182, 41, 24, 188, 104,
-            66, 111, 128, 68, 251, 235, 134, 154, 122, 195, 108, 116, 251, 35, 161, 59, 76, 88,
-            164, 75, 140, 124, 44, 177, 90, 195, 238, 131, 61, 234, 70, 60, 255, 30, 252, 128, 12,
-            221, 198, 252, 251, 59, 183, 181, 5, 106, 13, 14, 159, 159, 196, 193, 91, 10, 108, 175,
-            177, 207, 135, 14, 162, 164, 53, 52, 72, 212, 240, 144,
-        ],
-        &[
-            53, 5, 184, 127, 12, 7, 161, 230, 19, 242, 40, 216, 133, 173, 104, 138, 243, 253, 144,
-            113, 45, 188, 246, 165, 235, 132, 2, 41, 19, 155, 58, 27, 169, 185, 79, 135, 241, 248,
-            219, 58, 224, 53, 67, 20, 2, 40, 107, 238, 0, 184, 127, 12, 8, 20, 202, 209, 22, 60,
-            58, 34, 15, 36, 74, 245, 196, 12, 123, 0, 127, 164, 135, 19, 253, 174, 157, 6, 109, 78,
-            64, 33, 52, 171, 87, 64, 132, 140, 207, 254, 57, 49, 20, 2, 40, 107, 238, 0, 184, 127,
-            12, 10, 171, 57, 161, 87, 190, 4, 15, 77, 191, 233, 179, 51, 63, 127, 175, 147, 4, 191,
-            43, 169, 175, 11, 79, 133, 11, 171, 78, 113, 103, 89, 224, 235, 200, 196, 112, 60, 140,
-            20, 2, 14, 39, 7, 0, 184, 127, 12, 11, 85, 210, 35, 142, 75, 232, 106, 179, 164, 9,
-            232, 247, 181, 127, 174, 239, 197, 159, 90, 72, 120, 248, 29, 130, 27, 126, 41, 132,
-            163, 167, 89, 224, 67, 210, 164, 128, 90, 20, 2, 40, 107, 238, 0, 184, 127, 13, 5, 52,
-            51, 204, 25, 202, 138, 30, 196, 100, 121, 254, 195, 196, 62, 234, 56, 45, 99, 125, 232,
-            242, 149, 204, 178, 192, 182, 246, 253, 212, 197, 211, 74, 104, 119, 55, 166, 1, 20, 2,
-            208, 18, 19, 0, 184, 127, 13, 5, 81, 223, 209, 36, 185, 44, 42, 85, 181, 77, 186, 108,
-            147, 253, 160, 143, 48, 248, 253, 107, 224, 228, 123, 246, 111, 63, 211, 30, 46, 29,
-            114, 185, 112, 179, 255, 17, 84, 20, 2, 132, 215, 23, 0, 184, 127, 13, 7, 240, 58, 227,
-            109, 196, 166, 126, 12, 243, 250, 207, 246, 68, 63, 91, 70, 253, 199, 63, 142, 223,
-            170, 127, 14, 157, 172, 165, 230, 227, 90, 143, 94, 109, 166, 31, 153, 113, 20, 2, 40,
-            107, 238, 0, 184, 127, 13, 9, 103, 103, 155, 99, 18, 194, 53, 176, 226, 62, 231, 178,
-            188, 206, 182, 79, 208, 225, 38, 185, 101, 32, 79, 112, 105, 170, 59, 79, 203, 173,
-            184, 214, 88, 226, 236, 248, 107, 20, 2, 194, 235, 11, 0, 184, 127, 13, 12, 15, 146,
-            227, 116, 246, 134, 228, 160, 128, 90, 169, 205, 2, 191, 153, 207, 233, 107, 185, 184,
-            77, 23, 39, 148, 158, 202, 75, 116, 11, 202, 140, 23, 1, 174, 147, 20, 6, 20, 2, 132,
-            215, 23, 0, 184, 127, 13, 12, 157, 88, 46, 173, 173, 202, 12, 89, 168, 20, 17, 1, 168,
-            249, 201, 158, 63, 138, 133, 199, 123, 12, 203, 87, 202, 97, 33, 207, 158, 220, 67, 96,
-            146, 233, 187, 209, 124, 20, 2, 90, 98, 2, 0, 184, 127, 13, 12, 173, 184, 147, 173, 6,
-            76, 176, 247, 241, 121, 83, 229, 41, 184, 16, 108, 116, 63, 120, 210, 56, 227, 221,
-            106, 144, 244, 33, 211, 43, 201, 32, 206, 18, 6, 130, 200, 1, 20, 2, 194, 235, 11, 0,
-            184, 127, 13, 13, 101, 61, 230, 210, 128, 198, 17, 212, 101, 54, 79, 143, 188, 102,
-            142, 105, 11, 32, 37, 153, 137, 168, 23, 13, 222, 242, 81, 57, 2, 145, 220, 179, 113,
-            88, 110, 23, 40, 20, 2, 40, 107, 238, 0, 184, 127, 13, 16, 40, 99, 121, 229, 239, 170,
-            41, 214, 10, 183, 238, 184, 200, 173, 30, 125, 55, 248, 67, 139, 176, 44, 200, 108, 64,
-            194, 130, 24, 212, 241, 131, 241, 16, 6, 124, 211, 104, 20, 2, 14, 39, 7, 0, 184, 127,
-            13, 16, 59, 144, 239, 42, 183, 44, 42, 85, 181, 166, 9, 186, 255, 19, 137, 157, 75,
-            164, 186, 254, 193, 5, 3, 141, 102, 167, 22, 73, 73, 104, 250, 225, 168, 73, 210, 221,
-            90, 20, 2, 132, 215, 23, 0, 184, 127, 13, 20, 50, 192, 86, 112, 161, 34, 32, 19, 68,
-            105, 123, 144, 120, 42, 23, 252, 62, 124, 79, 111, 58, 223, 192, 141, 164, 17, 92, 125,
-            5, 150, 13, 170, 115, 102, 243, 196, 81, 20, 2, 132, 215, 23, 0, 184, 127, 13, 20, 120,
-            19, 238, 6, 102, 118, 194, 106, 31, 185, 172, 189, 213, 107, 224, 13, 76, 68, 144, 24,
-            86, 146, 155, 157, 42, 135, 156, 170, 214, 17, 154, 208, 65, 126, 153, 73, 73, 20, 2,
-            225, 245, 5, 0, 184, 127, 13, 23, 239, 252, 229, 67, 233, 28, 25, 117, 57, 27, 109, 84,
-            135, 39, 100, 3, 53, 195, 40, 81, 158, 216, 67, 72, 124, 94, 42, 158, 26, 95, 218, 9,
-            169, 69, 144, 188, 81, 20, 2, 40, 107, 238, 0, 184, 127, 13, 25, 119, 45, 79, 123, 58,
-            26, 233, 11, 232, 164, 120, 127, 151, 209, 204, 201, 85, 147, 85, 60, 164, 168, 106,
-            120, 111, 62, 0, 51, 187, 172, 171, 10, 214, 14, 35, 250, 87, 20, 2, 40, 107, 238, 0,
-            184, 127, 13, 28, 142, 172, 17, 218, 13, 202, 214, 111, 214, 51, 147, 124, 99, 21, 211,
-            37, 59, 53, 102, 156, 88, 255, 226, 200, 121, 118, 189, 33, 124, 140, 237, 208, 7, 132,
-            38, 123, 107, 20, 2, 40, 107, 238, 0, 184, 127, 13, 28, 175, 76, 166, 118, 134, 22,
-            185, 78, 45, 93, 18, 214, 12, 115, 20, 204, 163, 131, 191, 24, 93, 221, 168, 63, 65,
-            61, 167, 64, 161, 33, 96, 30, 50, 119, 211, 8, 62, 20, 2, 225, 245, 5, 0, 184, 127, 13,
-            29, 190, 226, 170, 139, 51, 146, 168, 81, 31, 86, 25, 34, 157, 78, 27, 239, 131, 68,
-            205, 154, 95, 133, 70, 142, 35, 143, 217, 95, 132, 61, 213, 85, 178, 4, 208, 240, 11,
-            20, 2, 14, 39, 7, 0, 184, 127, 13, 31, 156, 29, 217, 46, 188, 230, 217, 123, 248, 120,
-            177, 1, 41, 39, 174, 106, 251, 126, 9, 44, 84, 26, 90, 188, 57, 4, 101, 105, 129, 190,
-            174, 251, 158, 187, 120, 29, 28, 20, 2, 208, 18, 19, 0, 184, 127, 13, 32, 121, 115,
-            117, 86, 56, 186, 112, 190, 87, 160, 196, 196, 84, 189, 175, 206, 233, 99, 123, 119,
-            50, 194, 107, 62, 75, 112, 160, 103, 247, 69, 144, 78, 86, 32, 122, 120, 89, 20, 2,
-            194, 235, 11, 0, 184, 127, 13, 32, 235, 82, 209, 140, 79, 132, 76, 46, 234, 98, 86,
-            102, 96, 181, 69, 229, 203, 117, 196, 255, 162, 65, 43, 138, 147, 190, 161, 115, 65,
-            86, 168, 177, 2, 80, 206, 136, 81, 20, 2, 40, 107, 238, 0, 184, 127, 13, 33, 51, 109,
-            167, 206, 173, 244, 217, 93, 76, 92, 1, 49, 150, 145, 72, 211, 161, 107, 61, 149, 171,
-            61, 5, 23, 113, 217, 113, 161, 149, 93, 126, 116, 91, 10, 58, 79, 22, 20, 2, 194, 235,
-            11, 0, 184, 127, 13, 33, 238, 14, 77, 32, 124, 206, 216, 178, 253, 246, 89, 0, 148, 7,
-            129, 211, 192, 29, 220, 3, 228, 173, 187, 142, 246, 148, 29, 247, 182, 228, 191, 55,
-            11, 16, 246, 9, 68, 20, 2, 40, 107, 238, 0, 184, 127, 13, 36, 94, 8, 236, 14, 35, 252,
-            190, 210, 228, 77, 179, 253, 242, 118, 146, 137, 242, 217, 1, 21, 171, 159, 144, 199,
-            190, 190, 236, 18, 92, 177, 241, 189, 82, 132, 36, 67, 49, 20, 2, 132, 215, 23, 0, 184,
-            127, 13, 42, 239, 62, 174, 225, 104, 57, 161, 248, 157, 248, 151, 102, 230, 224, 119,
-            28, 28, 240, 71, 251, 113, 47, 218, 255, 24, 238, 169, 102, 38, 124, 1, 255, 116, 150,
-            152, 118, 246, 20, 2, 40, 107, 238, 0, 184, 127, 13, 43, 131, 202, 245, 172, 6, 216,
-            87, 252, 172, 123, 217, 187, 3, 85, 29, 112, 185, 116, 56, 149, 169, 139, 116, 176,
-            110, 84, 189, 195, 79, 27, 39, 171, 36, 3, 86, 133, 125, 20, 2, 194, 235, 11, 0, 184,
-            127, 13, 45, 64, 199, 82, 165, 255, 42, 156, 81, 28, 93, 92, 230, 108, 128, 155, 196,
-            151, 238, 224, 229, 218, 202, 173, 172, 55, 45, 86, 251, 18, 255, 131, 97, 184, 244,
-            44, 7, 96, 20, 2, 40, 107, 238, 0, 184, 127, 13, 47, 28, 61, 166, 144, 63, 36, 23, 42,
-            86, 57, 67, 41, 28, 151, 210, 82, 222, 247, 30, 23, 171, 244, 103, 161, 98, 107, 202,
-            53, 135, 40, 169, 10, 130, 179, 222, 49, 24, 20, 2, 14, 39, 7, 0, 184, 127, 13, 51,
-            225, 200, 138, 98, 92, 242, 120, 187, 232, 195, 63, 232, 254, 3, 198, 198, 132, 180,
-            77, 252, 38, 223, 181, 188, 239, 179, 239, 81, 31, 84, 70, 83, 10, 248, 89, 193, 18,
-            20, 2, 194, 235, 11, 0, 184, 127, 13, 53, 75, 129, 240, 244, 96, 218, 159, 127, 211,
-            217, 97, 42, 104, 210, 234, 214, 157, 222, 83, 41, 123, 23, 43, 125, 181, 20, 208, 210,
-            97, 231, 197, 190, 152, 125, 247, 243, 42, 20, 2, 90, 98, 2, 0, 184, 127, 13, 53, 84,
-            22, 21, 141, 207, 124, 78, 133, 48, 123, 160, 187, 41, 176, 152, 226, 244, 5, 198, 115,
-            243, 155, 118, 215, 127, 176, 18, 237, 95, 92, 97, 252, 104, 225, 203, 255, 128, 20, 2,
-            194, 235, 11, 0, 184, 127, 13, 54, 57, 234, 114, 67, 9, 44, 42, 85, 181, 228, 147, 85,
-            38, 214, 64, 13, 114, 156, 229, 47, 80, 101, 50, 123, 65, 74, 151, 28, 105, 252, 184,
-            94, 239, 61, 90, 148, 1, 20, 2, 132, 215, 23, 0, 184, 127, 13, 55, 120, 124, 87, 97,
-            30, 204, 35, 237, 51, 84, 158, 135, 74, 231, 199, 101, 63, 197, 217, 91, 50, 66, 220,
-            125, 245, 116, 38, 100, 180, 128, 158, 51, 122, 19, 18, 100, 51, 20, 2, 14, 39, 7, 0,
-            184, 127, 13, 58, 102, 232, 138, 238, 185, 226, 212, 6, 196, 23, 126, 137, 160, 186,
-            73, 181, 65, 225, 65, 143, 124, 188, 204, 11, 134, 29, 209, 124, 234, 167, 62, 247,
-            249, 136, 45, 149, 32, 20, 2, 40, 107, 238, 0, 184, 127, 13, 60, 120, 144, 31, 15, 197,
-            44, 42, 85, 181, 175, 109, 116, 40, 29, 224, 129, 58, 96, 190, 178, 168, 158, 243, 156,
-            107, 42, 16, 163, 84, 39, 198, 131, 214, 30, 152, 241, 112, 20, 2, 132, 215, 23, 0,
-            184, 127, 13, 62, 5, 53, 125, 126, 100, 214, 241, 231, 166, 170, 112, 107, 143, 108,
-            182, 76, 151, 138, 178, 55, 154, 201, 52, 120, 137, 229, 61, 170, 154, 213, 172, 63,
-            98, 203, 36, 228, 4, 20, 2, 40, 107, 238, 0, 184, 127, 13, 62, 143, 103, 40, 103, 246,
-            198, 93, 230, 0, 55, 9, 170, 90, 107, 129, 53, 76, 0, 251, 19, 226, 129, 172, 5, 232,
-            82, 203, 65, 148, 198, 159, 120, 86, 110, 138, 200, 40, 20, 2, 208, 18, 19, 0, 184,
-            127, 13, 63, 197, 7, 180, 206, 77, 44, 42, 85, 181, 243, 35, 176, 157, 61, 198, 192,
-            146, 237, 14, 152, 132, 99, 24, 134, 19, 35, 34, 53, 230, 214, 205, 208, 48, 133, 136,
-            85, 41, 20, 2, 132, 215, 23, 0, 184, 127, 13, 64, 92, 255, 66, 36, 17, 148, 51, 157,
-            184, 180, 4, 234, 33, 109, 96, 67, 63, 0, 237, 103, 176, 205, 205, 158, 41, 210, 19,
-            85, 97, 93, 150, 113, 97, 219, 12, 176, 76, 20, 2, 194, 235, 11, 0, 184, 127, 13, 64,
-            130, 34, 132, 163, 236, 18, 201, 191, 102, 53, 153, 2, 10, 184, 61, 172, 93, 94, 54,
-            18, 19, 176, 62, 158, 23, 170, 162, 154, 242, 144, 217, 100, 44, 201, 53, 124, 123, 20,
-            2, 40, 107, 238, 0, 184, 127, 13, 68, 224, 145, 128, 47, 112, 216, 198, 122, 87, 133,
-            155, 40, 67, 74, 18, 241, 160, 120, 226, 151, 157, 140, 29, 187, 36, 4, 192, 225, 95,
-            231, 92, 28, 148, 227, 155, 32, 97, 20, 2, 90, 98, 2, 0, 184, 127, 13, 72, 114, 102,
-            193, 41, 10, 44, 42, 85, 181, 146, 176, 202, 240, 240, 68, 11, 137, 170, 140, 29, 113,
-            59, 231, 179, 161, 109, 121, 179, 197, 110, 237, 25, 215, 172, 219, 110, 8, 20, 2, 132,
-            215, 23, 0, 184, 127, 13, 74, 49, 152, 207, 7, 190, 78, 63, 30, 111, 121, 179, 86, 10,
-            3, 150, 241, 9, 150, 64, 204, 162, 107, 184, 255, 164, 236, 147, 49, 181, 47, 52, 179,
-            76, 204, 239, 99, 16, 20, 2, 16, 94, 95, 0, 184, 127, 13, 74, 116, 116, 200, 248, 194,
-            44, 42, 85, 181, 2, 121, 176, 31, 195, 118, 161, 181, 228, 230, 103, 10, 140, 36, 127,
-            0, 202, 108, 21, 105, 169, 253, 146, 174, 24, 253, 16, 49, 20, 2, 132, 215, 23, 0, 184,
-            127, 13, 74, 121, 253, 182, 178, 206, 60, 238, 177, 236, 152, 45, 177, 139, 222, 114,
-            236, 247, 252, 155, 204, 89, 49, 140, 240, 109, 213, 8, 244, 249, 46, 197, 107, 76, 81,
-            47, 145, 62, 20, 2, 40, 107, 238, 0, 184, 127, 13, 75, 248, 149, 209, 250, 170, 52,
-            188, 111, 181, 186, 110, 33, 80, 8, 124, 150, 253, 72, 82, 236, 24, 138, 186, 116, 165,
-            163, 131, 162, 46, 246, 107, 18, 197, 136, 206, 160, 13, 20, 2, 194, 235, 11, 0, 184,
-            127, 13, 76, 237, 30, 108, 128, 178, 198, 134, 15, 112, 21, 200, 107, 112, 116, 167,
-            79, 235, 167, 232, 161, 157, 159, 200, 133, 82, 20, 152, 215, 102, 138, 18, 211, 126,
-            254, 101, 72, 9, 20, 2, 40, 107, 238, 0, 184, 127, 13, 76, 245, 253, 226, 21, 30, 26,
-            14, 72, 180, 69, 121, 140, 187, 104, 159, 134, 201, 193, 124, 213, 66, 140, 236, 59,
-            195, 112, 254, 133, 167, 60, 211, 241, 65, 12, 51, 93, 30, 20, 2, 16, 94, 95, 0, 184,
-            127, 13, 83, 182, 17, 60, 125, 251, 0, 11, 147, 215, 45, 204, 18, 189, 85, 119, 67,
-            140, 146, 161, 156, 71, 120, 225, 44, 251, 138, 218, 135, 26, 23, 105, 78, 90, 47, 134,
-            195, 116, 20, 2, 40, 107, 238, 0, 184, 127, 13, 84, 242, 242, 13, 4, 227, 100, 149,
-            130, 123, 254, 11, 7, 209, 108, 84, 158, 177, 13, 126, 69, 153, 126, 149, 120, 139,
-            228, 74, 63, 39, 122, 246, 190, 254, 201, 159, 230, 47, 20, 2, 14, 39, 7, 0, 184, 127,
-            13, 85, 204, 176, 30, 24, 27, 44, 42, 85, 181, 199, 225, 53, 174, 68, 60, 110, 217, 81,
-            51, 141, 75, 50, 190, 108, 246, 26, 197, 189, 0, 11, 122, 17, 194, 107, 48, 120, 32,
-            20, 2, 132, 215, 23, 0, 184, 127, 13, 88, 188, 0, 22, 93, 104, 2, 170, 82, 86, 216, 4,
-            179, 55, 23, 241, 211, 56, 238, 249, 144, 30, 137, 104, 43, 128, 200, 30, 59, 17, 56,
-            160, 32, 121, 224, 132, 138, 160, 20, 2, 90, 98, 2, 0, 184, 127, 13, 93, 157, 98, 181,
-            202, 130, 134, 160, 220, 43, 101, 115, 54, 198, 0, 250, 15, 26, 188, 183, 250, 10, 160,
-            22, 218, 203, 76, 43, 105, 98, 249, 239, 37, 8, 18, 235, 203, 36, 20, 2, 40, 107, 238,
-            0, 184, 127, 13, 96, 153, 252, 158, 108, 255, 60, 186, 60, 185, 97, 115, 194, 223, 255,
-            63, 43, 176, 207, 94, 60, 112, 120, 76, 255, 173, 191, 2, 177, 178, 190, 79, 205, 209,
-            247, 142, 67, 116, 20, 2, 14, 39, 7, 0, 184, 127, 13, 96, 215, 187, 215, 68, 153, 114,
-            139, 204, 233, 36, 135, 40, 60, 179, 74, 240, 153, 211, 110, 50, 131, 223, 35, 71, 113,
-            231, 93, 59, 142, 95, 75, 67, 172, 58, 176, 108, 114, 20, 2, 40, 107, 238, 0, 184, 127,
-            13, 97, 249, 50, 189, 205, 186, 252, 253, 88, 157, 141, 246, 218, 35, 246, 90, 81, 222,
-            134, 127, 172, 148, 144, 234, 211, 255, 187, 54, 206, 141, 25, 70, 206, 193, 120, 154,
-            154, 70, 20, 2, 194, 235, 11, 0, 184, 127, 13, 100, 109, 245, 176, 129, 53, 114, 37,
-            244, 170, 217, 224, 127, 133, 134, 221, 55, 253, 167, 8, 79, 139, 251, 95, 53, 23, 169,
-            164, 204, 75, 34, 247, 49, 220, 44, 99, 164, 55, 20, 2, 40, 107, 238, 0, 184, 127, 13,
-            100, 251, 139, 21, 65, 142, 238, 238, 48, 50, 5, 32, 243, 110, 168, 201, 53, 54, 65,
-            159, 44, 41, 112, 109, 46, 95, 42, 95, 232, 86, 81, 217, 74, 224, 247, 209, 169, 13,
-            20, 2, 14, 39, 7, 0, 184, 127, 13, 102, 59, 239, 127, 165, 119, 28, 213, 39, 100, 30,
-            112, 112, 82, 153, 55, 167, 140, 2, 251, 77, 226, 159, 29, 33, 9, 8, 173, 101, 76, 252,
-            50, 193, 186, 147, 12, 109, 1, 20, 2, 194, 235, 11, 0, 184, 127, 13, 102, 132, 92, 129,
-            202, 69, 48, 4, 204, 206, 127, 84, 246, 4, 63, 19, 160, 48, 177, 240, 114, 49, 216, 24,
-            38, 233, 159, 253, 53, 8, 253, 223, 219, 208, 229, 169, 88, 84, 20, 2, 132, 215, 23, 0,
-            184, 127, 13, 104, 242, 133, 251, 16, 88, 44, 42, 85, 180, 247, 175, 11, 133, 89, 186,
-            253, 147, 50, 254, 120, 254, 245, 91, 9, 121, 181, 50, 23, 179, 248, 53, 83, 130, 186,
-            152, 144, 49, 20, 2, 132, 215, 23, 0, 184, 127, 13, 105, 59, 14, 140, 220, 180, 44, 42,
-            85, 181, 88, 145, 118, 22, 9, 125, 254, 231, 201, 20, 36, 151, 198, 108, 119, 177, 148,
-            205, 145, 19, 208, 230, 90, 17, 186, 39, 102, 11, 20, 2, 132, 215, 23, 0, 184, 127, 13,
-            105, 135, 201, 194, 51, 96, 182, 151, 155, 120, 151, 160, 251, 244, 209, 148, 25, 9,
-            115, 166, 72, 114, 119, 182, 113, 147, 44, 104, 113, 44, 136, 97, 163, 192, 148, 90,
-            134, 30, 20, 2, 40, 107, 238, 0, 184, 127, 13, 107, 169, 200, 10, 61, 182, 56, 50, 180,
-            20, 180, 196, 60, 134, 140, 38, 62, 61, 235, 99, 212, 119, 31, 87, 160, 165, 172, 168,
-            29, 168, 69, 98, 138, 63, 206, 66, 171, 55, 20, 2, 14, 39, 7, 0, 184, 127, 13, 107,
-            239, 51, 67, 189, 117, 166, 31, 184, 224, 83, 125, 15, 108, 141, 79, 148, 208, 70, 104,
-            89, 33, 91, 119, 229, 229, 60, 68, 152, 18, 83, 152, 48, 98, 183, 164, 111, 114, 20, 2,
-            90, 98, 2, 0, 184, 127, 13, 108, 72, 77, 215, 250, 239, 94, 16, 252, 230, 234, 163, 85,
-            196, 116, 118, 223, 252, 225, 207, 17, 138, 31, 12, 40, 188, 66, 9, 200, 36, 45, 66,
-            113, 208, 123, 252, 44, 3, 20, 2, 40, 107, 238, 0, 184, 127, 13, 108, 102, 130, 113,
-            224, 70, 252, 226, 59, 253, 199, 163, 27, 235, 89, 209, 202, 221, 147, 5, 111, 204,
-            130, 219, 105, 26, 73, 248, 56, 118, 121, 3, 91, 229, 27, 18, 85, 120, 20, 2, 40, 107,
-            238, 0, 184, 127, 13, 109, 85, 163, 77, 180, 233, 30, 134, 56, 123, 79, 105, 117, 228,
-            204, 87, 64, 56, 202, 253, 220, 15, 177, 77, 9, 184, 70, 218, 60, 101, 126, 39, 89,
-            156, 242, 170, 252, 81, 20, 2, 40, 107, 238, 0, 184, 127, 13, 111, 247, 250, 39, 86,
-            176, 112, 151, 85, 13, 207, 254, 163, 245, 64, 166, 202, 133, 166, 199, 73, 105, 234,
-            249, 5, 241, 72, 152, 175, 242, 34, 16, 100, 94, 164, 107, 184, 38, 20, 2, 40, 107,
-            238, 0, 184, 127, 13, 112, 177, 197, 249, 28, 209, 228, 73, 161, 230, 203, 79, 252,
-            242, 181, 188, 180, 190, 62, 60, 170, 135, 231, 73, 204, 175, 203, 252, 52, 221, 151,
-            162, 31, 24, 194, 247, 239, 55, 20, 2, 40, 107, 238, 0, 184, 127, 13, 117, 88, 8, 242,
-            170, 187, 200, 62, 72, 158, 217, 79, 190, 29, 27, 106, 113, 232, 57, 61, 89, 88, 57,
-            16, 70, 61, 201, 91, 253, 0, 169, 60, 228, 124, 177, 70, 89, 40, 20, 2, 90, 98, 2, 0,
-            184, 127, 13, 118, 71, 14, 118, 94, 219, 8, 161, 224, 41, 42, 108, 30, 109, 12, 79, 10,
-            45, 196, 210, 195, 249, 176, 231, 197, 216, 33, 60, 233, 141, 1, 184, 228, 91, 233,
-            237, 241, 88, 20, 2, 40, 107, 238, 0, 184, 127, 13, 120, 59, 125, 101, 231, 137, 176,
-            6, 166, 105, 102, 110, 228, 84, 132, 198, 138, 179, 73, 152, 118, 12, 248, 239, 42, 41,
-            147, 11, 39, 187, 61, 96, 223, 159, 6, 33, 238, 98, 20, 2, 194, 235, 11, 0, 184, 127,
-            13, 120, 98, 183, 212, 155, 230, 140, 210, 201, 180, 110, 7, 194, 15, 34, 99, 84, 11,
-            153, 60, 43, 37, 179, 203, 153, 90, 184, 115, 49, 74, 193, 14, 141, 95, 31, 80, 165,
-            94, 20, 2, 90, 98, 2, 0, 184, 127, 13, 121, 81, 249, 126, 31, 200, 118, 0, 192, 233, 1,
-            180, 52, 18, 3, 242, 31, 65, 15, 61, 160, 26, 228, 164, 93, 25, 79, 210, 192, 105, 60,
-            110, 14, 197, 152, 15, 76, 33, 20, 2, 104, 137, 9, 0, 184, 127, 13, 122, 50, 221, 167,
-            14, 156, 111, 235, 118, 76, 245, 38, 93, 143, 73, 31, 146, 29, 81, 194, 3, 24, 135, 15,
-            251, 102, 155, 5, 156, 122, 91, 149, 29, 146, 145, 99, 107, 138, 20, 2, 194, 235, 11,
-            0, 184, 127, 13, 122, 86, 216, 248, 31, 19, 54, 76, 41, 191, 188, 159, 6, 164, 43, 92,
-            243, 127, 253, 131, 30, 145, 200, 67, 204, 37, 216, 185, 0, 113, 84, 104, 16, 236, 242,
-            121, 228, 88, 20, 2, 208, 18, 19, 0, 184, 127, 13, 122, 170, 118, 121, 42, 103, 4, 88,
-            125, 66, 243, 119, 9, 199, 52, 47, 62, 145, 91, 90, 28, 111, 100, 202, 145, 107, 159,
-            168, 39, 148, 128, 200, 96, 44, 96, 8, 137, 189, 20, 2, 194, 235, 11, 0, 172, 127, 13,
-            123, 76, 5, 39, 209, 84, 220, 58, 238, 18, 81, 156, 25, 190, 2, 98, 139, 15, 128, 139,
-            221, 218, 154, 237, 86, 64, 39, 173, 128, 156, 211, 146, 193, 62, 155, 146, 75, 101, 8,
-            4, 0, 172, 127, 13, 124, 72, 120, 180, 138, 130, 214, 105, 203, 169, 213, 230, 37, 158,
-            181, 178, 33, 3, 18, 148, 224, 194, 9, 164, 140, 220, 139, 101, 2, 161, 255, 212, 64,
-            47, 70, 29, 252, 8, 8, 4, 0, 184, 127, 13, 125, 161, 82, 18, 225, 101, 204, 191, 64,
-            204, 83, 166, 127, 237, 175, 246, 17, 31, 252, 244, 214, 24, 175, 30, 248, 37, 133, 96,
-            96, 144, 130, 152, 159, 230, 105, 17, 35, 60, 20, 2, 194, 235, 11, 0, 184, 127, 13,
-            126, 118, 202, 44, 46, 25, 102, 206, 216, 155, 154, 118, 222, 77, 61, 195, 132, 180,
-            95, 223, 11, 210, 241, 98, 159, 21, 221, 62, 68, 234, 20, 195, 27, 161, 97, 129, 162,
-            85, 20, 2, 194, 235, 11, 0, 184, 127, 13, 127, 49, 69, 139, 129, 212, 44, 42, 85, 181,
-            16, 101, 29, 2, 106, 56, 206, 69, 104, 171, 124, 232, 6, 187, 41, 131, 209, 132, 97,
-            145, 207, 210, 155, 107, 6, 225, 9, 70, 20, 2, 132, 215, 23, 0, 184, 127, 13, 127, 64,
-            196, 139, 221, 165, 226, 248, 174, 240, 209, 188, 32, 114, 76, 40, 68, 13, 64, 226,
-            205, 49, 232, 56, 5, 161, 246, 65, 251, 183, 60, 193, 197, 183, 119, 52, 96, 118, 20,
-            2, 40, 107, 238, 0, 184, 127, 13, 127, 134, 34, 156, 99, 67, 44, 42, 85, 181, 42, 173,
-            92, 60, 251, 139, 244, 199, 196, 120, 170, 43, 33, 184, 253, 149, 151, 254, 39, 6, 161,
-            20, 87, 210, 62, 16, 50, 62, 20, 2, 132, 215, 23, 0, 184, 127, 13, 129, 34, 145, 28,
-            208, 128, 34, 160, 22, 15, 15, 182, 64, 59, 212, 29, 183, 134, 47, 214, 80, 171, 93,
-            66, 95, 166, 59, 11, 192, 148, 103, 240, 101, 145, 141, 224, 233, 112, 20, 2, 40, 107,
-            238, 0, 184, 127, 13, 129, 35, 171, 84, 180, 153, 198, 71, 123, 253, 87, 193, 37, 135,
-            177, 7, 90, 128, 217, 68, 199, 130, 151, 132, 238, 214, 26, 92, 139, 130, 85, 129, 126,
-            29, 98, 209, 7, 14, 20, 2, 14, 39, 7, 0, 184, 127, 13, 133, 92, 203, 127, 94, 116, 44,
-            42, 85, 181, 166, 65, 56, 148, 225, 56, 54, 251, 1, 101, 230, 173, 206, 125, 119, 192,
-            109, 204, 244, 43, 59, 40, 131, 151, 162, 125, 221, 59, 20, 2, 132, 215, 23, 0, 184,
-            127, 13, 134, 155, 126, 254, 214, 143, 36, 151, 52, 87, 177, 46, 157, 8, 231, 220, 11,
-            15, 37, 90, 181, 8, 225, 130, 156, 102, 246, 210, 230, 196, 203, 148, 172, 218, 90, 0,
-            171, 92, 20, 2, 194, 235, 11, 0, 184, 127, 13, 134, 179, 148, 180, 226, 214, 202, 188,
-            184, 79, 93, 210, 188, 95, 5, 122, 156, 210, 34, 129, 72, 189, 40, 183, 164, 128, 63,
-            212, 218, 38, 228, 89, 89, 70, 199, 68, 104, 103, 20, 2, 40, 107, 238, 0, 184, 127, 13,
-            136, 42, 211, 208, 114, 89, 6, 86, 174, 153, 219, 101, 189, 107, 143, 205, 166, 191,
-            218, 211, 67, 127, 74, 21, 212, 96, 36, 149, 158, 110, 180, 19, 196, 191, 187, 16, 85,
-            104, 20, 2, 40, 107, 238, 0, 172, 127, 13, 142, 173, 93, 10, 22, 140, 146, 40, 23, 126,
-            61, 158, 27, 251, 209, 121, 117, 179, 24, 92, 201, 157, 198, 178, 53, 162, 191, 212,
-            38, 9, 171, 49, 243, 243, 5, 157, 110, 107, 8, 4, 0, 184, 127, 13, 144, 17, 112, 203,
-            203, 103, 44, 42, 85, 181, 3, 2, 8, 0, 129, 226, 10, 51, 107, 64, 218, 201, 181, 174,
-            42, 232, 6, 146, 182, 83, 194, 179, 129, 49, 4, 125, 121, 122, 20, 2, 132, 215, 23, 0,
-            184, 127, 13, 144, 20, 195, 173, 184, 155, 202, 56, 96, 98, 169, 188, 165, 242, 60, 73,
-            183, 26, 226, 75, 64, 5, 195, 59, 221, 213, 74, 172, 1, 21, 81, 119, 40, 34, 61, 68,
-            26, 24, 20, 2, 40, 107, 238, 0, 184, 127, 13, 144, 70, 228, 133, 230, 213, 70, 108,
-            167, 142, 100, 247, 223, 136, 71, 216, 193, 146, 243, 218, 221, 243, 36, 38, 247, 42,
-            59, 170, 76, 62, 50, 0, 130, 175, 219, 136, 65, 52, 20, 2, 14, 39, 7, 0, 184, 127, 13,
-            145, 245, 158, 219, 172, 34, 44, 42, 85, 181, 69, 27, 177, 213, 115, 237, 228, 139,
-            207, 126, 191, 118, 149, 10, 235, 120, 1, 32, 236, 223, 61, 50, 132, 103, 214, 47, 217,
-            41, 20, 2, 132, 215, 23, 0, 184, 127, 13, 149, 40, 58, 48, 49, 234, 78, 148, 88, 109,
-            228, 18, 223, 81, 129, 88, 82, 138, 159, 205, 79, 149, 43, 99, 101, 207, 140, 120, 118,
-            141, 77, 103, 176, 239, 5, 241, 71, 77, 20, 2, 40, 107, 238, 0, 184, 127, 13, 149, 101,
-            58, 76, 245, 119, 218, 119, 250, 13, 130, 210, 217, 46, 224, 53, 49, 173, 54, 243, 134,
-            123, 164, 254, 122, 216, 187, 247, 233, 188, 145, 124, 122, 118, 124, 100, 202, 52, 20,
-            2, 40, 107, 238, 0, 184, 127, 13, 150, 37, 170, 252, 33, 195, 90, 199, 246, 175, 90,
-            235, 83, 100, 24, 136, 64, 208, 47, 14, 116, 232, 19, 230, 217, 204, 3, 152, 214, 153,
-            75, 102, 114, 118, 88, 164, 251, 48, 20, 2, 225, 245, 5, 0, 184, 127, 13, 151, 99, 221,
-            34, 43, 191, 178, 71, 206, 4, 206, 202, 250, 17, 119, 54, 62, 204, 149, 191, 122, 20,
-            241, 159, 232, 237, 73, 227, 155, 20, 44, 188, 126, 227, 37, 149, 147, 85, 20, 2, 40,
-            107, 238, 0, 184, 127, 13, 151, 187, 69, 29, 48, 143, 254, 169, 221, 181, 88, 97, 2,
-            141, 97, 137, 116, 210, 61, 17, 120, 238, 196, 212, 156, 153, 68, 188, 145, 85, 77,
-            117, 219, 188, 238, 129, 129, 89, 20, 2, 40, 107, 238, 0, 184, 127, 13, 156, 12, 90,
-            121, 232, 206, 247, 59, 170, 102, 212, 116, 110, 132, 71, 135, 127, 224, 81, 214, 223,
-            250, 133, 129, 29, 205, 20, 198, 220, 238, 178, 158, 1, 27, 21, 20, 242, 62, 20, 2, 14,
-            39, 7, 0, 184, 127, 13, 162, 70, 154, 2, 214, 161, 101, 88, 230, 43, 179, 233, 231,
-            218, 23, 138, 19, 246, 87, 201, 160, 232, 96, 68, 51, 90, 119, 166, 114, 249, 245, 113,
-            72, 159, 67, 174, 7, 182, 20, 2, 40, 107, 238, 0, 184, 127, 13, 164, 230, 78, 8, 154,
-            185, 216, 159, 210, 157, 90, 71, 12, 129, 64, 33, 197, 138, 250, 82, 253, 221, 180, 66,
-            164, 14, 167, 170, 101, 206, 211, 114, 45, 46, 166, 0, 253, 54, 20, 2, 194, 235, 11, 0,
-            184, 127, 13, 166, 52, 54, 112, 35, 179, 190, 45, 175, 132, 112, 93, 227, 76, 25, 48,
-            55, 15, 83, 86, 101, 36, 176, 129, 69, 180, 209, 146, 214, 205, 213, 163, 92, 199, 25,
-            48, 226, 64, 20, 2, 208, 18, 19, 0, 184, 127, 13, 167, 252, 56, 113, 87, 20, 24, 42,
-            237, 153, 100, 23, 7, 43, 83, 238, 72, 205, 38, 210, 159, 215, 217, 134, 222, 69, 142,
-            20, 39, 233, 250, 159, 33, 252, 178, 97, 80, 91, 20, 2, 40, 107, 238, 0, 184, 127, 13,
-            171, 247, 27, 254, 179, 193, 198, 37, 77, 61, 154, 140, 194, 252, 166, 8, 139, 31, 17,
-            197, 190, 185, 20, 108, 121, 126, 135, 73, 180, 26, 176, 117, 202, 8, 171, 81, 112, 96,
-            20, 2, 14, 39, 7, 0, 184, 127, 13, 174, 251, 175, 71, 6, 7, 226, 29, 48, 212, 4, 95,
-            198, 115, 112, 100, 235, 176, 226, 123, 94, 182, 16, 179, 116, 57, 121, 119, 223, 250,
-            26, 1, 59, 229, 157, 178, 37, 107, 20, 2, 208, 18, 19, 0, 184, 127, 13, 175, 165, 226,
-            101, 242, 0, 148, 236, 241, 20, 194, 146, 242, 162, 79, 246, 219, 195, 219, 248, 49,
-            27, 227, 126, 245, 63, 126, 120, 201, 209, 165, 157, 12, 87, 98, 202, 198, 114, 20, 2,
-            194, 235, 11, 0, 184, 127, 13, 176, 141, 100, 11, 238, 20, 56, 86, 217, 4, 98, 3, 11,
-            39, 149, 124, 66, 91, 51, 191, 143, 143, 102, 154, 183, 21, 88, 14, 127, 100, 243, 105,
-            153, 167, 76, 234, 57, 54, 20, 2, 225, 245, 5, 0, 184, 127, 13, 178, 131, 96, 111, 62,
-            75, 194, 46, 255, 99, 249, 24, 48, 212, 241, 184, 130, 186, 211, 10, 184, 118, 96, 175,
-            185, 61, 186, 140, 2, 121, 51, 112, 106, 149, 208, 99, 176, 102, 20, 2, 40, 107, 238,
-            0, 184, 127, 13, 179, 31, 233, 126, 112, 177, 154, 113, 247, 83, 248, 87, 104, 52, 183,
-            180, 63, 156, 51, 182, 28, 23, 116, 128, 227, 28, 75, 85, 48, 130, 151, 254, 62, 125,
-            252, 224, 111, 123, 20, 2, 40, 107, 238, 0, 184, 127, 13, 179, 208, 97, 52, 178, 218,
-            124, 39, 84, 241, 31, 224, 213, 230, 179, 194, 23, 78, 172, 209, 254, 54, 210, 115,
-            139, 251, 239, 181, 65, 251, 79, 86, 235, 41, 141, 193, 73, 26, 20, 2, 194, 235, 11, 0,
-            184, 127, 13, 183, 169, 84, 28, 0, 190, 214, 130, 226, 191, 252, 161, 158, 146, 118,
-            167, 154, 176, 191, 13, 118, 251, 181, 172, 98, 204, 121, 56, 180, 195, 236, 116, 99,
-            84, 53, 57, 200, 112, 20, 2, 40, 107, 238, 0, 184, 127, 13, 185, 44, 117, 47, 232, 58,
-            106, 19, 167, 125, 220, 155, 56, 193, 5, 134, 201, 76, 41, 40, 250, 26, 243, 119, 117,
-            127, 135, 121, 132, 238, 118, 197, 17, 198, 7, 155, 47, 105, 20, 2, 40, 107, 238, 0,
-            184, 127, 13, 187, 191, 104, 15, 235, 199, 174, 231, 40, 33, 202, 0, 230, 35, 4, 228,
-            240, 216, 88, 18, 42, 101, 184, 124, 141, 244, 240, 234, 226, 36, 174, 6, 75, 149, 29,
-            57, 246, 16, 20, 2, 14, 39, 7, 0, 184, 127, 13, 190, 75, 81, 148, 106, 176, 180, 90,
-            214, 165, 62, 10, 104, 135, 82, 243, 164, 15, 73, 207, 126, 102, 97, 105, 199, 135,
-            248, 59, 241, 194, 255, 138, 160, 38, 217, 154, 193, 119, 20, 2, 14, 39, 7, 0, 184,
-            127, 13, 191, 177, 30, 209, 39, 1, 166, 90, 63, 232, 146, 41, 89, 96, 102, 113, 64,
-            235, 57, 163, 80, 173, 136, 22, 82, 90, 144, 170, 230, 48, 253, 161, 148, 1, 78, 49,
-            52, 55, 20, 2, 194, 235, 11, 0, 184, 127, 13, 196, 134, 173, 22, 110, 178, 14, 149,
-            145, 55, 224, 206, 19, 114, 40, 4, 70, 14, 18, 28, 68, 162, 233, 12, 188, 6, 76, 148,
-            38, 226, 130, 27, 209, 235, 135, 241, 47, 69, 20, 2, 90, 98, 2, 0, 184, 127, 13, 196,
-            167, 182, 165, 65, 198, 70, 175, 92, 73, 123, 128, 63, 235, 149, 37, 223, 108, 20, 95,
-            247, 50, 170, 44, 54, 28, 40, 67, 205, 249, 241, 255, 228, 109, 44, 19, 133, 22, 20, 2,
-            90, 98, 2, 0, 184, 127, 13, 198, 205, 110, 128, 129, 251, 186, 27, 150, 80, 176, 144,
-            85, 234, 159, 190, 206, 123, 183, 238, 244, 114, 20, 27, 163, 19, 99, 122, 173, 3, 163,
-            175, 184, 64, 84, 49, 98, 93, 20, 2, 148, 53, 119, 0, 184, 127, 13, 201, 169, 217, 172,
-            198, 85, 56, 139, 240, 252, 1, 16, 193, 177, 141, 202, 180, 113, 114, 80, 131, 188,
-            107, 11, 82, 237, 171, 234, 176, 195, 231, 62, 80, 106, 84, 249, 224, 77, 20, 2, 194,
-            235, 11, 0, 184, 127, 13, 205, 245, 68, 205, 6, 49, 48, 92, 81, 5, 147, 92, 246, 47,
-            176, 201, 64, 142, 145, 157, 72, 85, 183, 198, 198, 151, 36, 145, 248, 130, 97, 131,
-            92, 196, 170, 246, 2, 69, 20, 2, 40, 107, 238, 0, 184, 127, 13, 210, 222, 214, 9, 52,
-            30, 66, 1, 145, 50, 195, 204, 173, 59, 150, 42, 118, 148, 32, 153, 191, 33, 134, 73,
-            219, 63, 11, 214, 181, 51, 109, 148, 213, 200, 171, 223, 177, 98, 20, 2, 40, 107, 238,
-            0, 184, 127, 13, 211, 245, 163, 39, 83, 143, 54, 148, 86, 107, 254, 188, 253, 114, 199,
-            129, 106, 135, 242, 204, 242, 150, 60, 12, 121, 71, 55, 5, 94, 173, 32, 181, 2, 168,
-            148, 50, 185, 0, 20, 2, 40, 107, 238, 0, 184, 127, 13, 212, 84, 186, 57, 81, 87, 28,
-            207, 200, 117, 189, 94, 157, 81, 131, 41, 170, 25, 217, 133, 194, 214, 37, 144, 67, 58,
-            25, 27, 78, 78, 80, 219, 113, 23, 168, 230, 181, 80, 20, 2, 40, 107, 238, 0, 184, 127,
-            13, 213, 47, 186, 226, 243, 33, 44, 42, 85, 181, 54, 203, 102, 200, 191, 40, 148, 175,
-            150, 138, 218, 18, 198, 231, 219, 75, 176, 40, 165, 123, 34, 71, 51, 52, 64, 69, 132,
-            105, 20, 2, 132, 215, 23, 0, 184, 127, 13, 214, 232, 25, 57, 238, 139, 44, 42, 85, 181,
-            43, 129, 38, 46, 16, 60, 6, 87, 4, 22, 96, 172, 159, 80, 10, 239, 167, 87, 162, 200,
-            52, 51, 38, 208, 165, 37, 245, 18, 20, 2, 132, 215, 23, 0, 184, 127, 13, 215, 219, 128,
-            25, 106, 1, 236, 221, 84, 140, 131, 69, 122, 180, 60, 175, 120, 103, 226, 190, 249, 30,
-            247, 131, 2, 93, 185, 101, 154, 253, 137, 121, 78, 193, 34, 10, 207, 41, 20, 2, 90, 98,
-            2, 0, 184, 127, 13, 217, 125, 155, 25, 62, 240, 140, 3, 132, 3, 254, 72, 238, 0, 104,
-            166, 82, 207, 226, 89, 61, 48, 213, 112, 31, 80, 142, 56, 239, 103, 111, 57, 47, 220,
-            133, 248, 6, 88, 20, 2, 194, 235, 11, 0, 184, 127, 13, 227, 127, 241, 158, 58, 138,
-            118, 23, 185, 198, 71, 95, 136, 123, 168, 1, 206, 228, 155, 50, 42, 77, 136, 130, 36,
-            200, 208, 121, 27, 176, 213, 201, 153, 182, 96, 94, 37, 26, 20, 2, 40, 107, 238, 0,
-            184, 127, 13, 228, 43, 136, 17, 101, 115, 148, 235, 184, 85, 153, 59, 240, 86, 139,
-            113, 251, 244, 25, 127, 222, 228, 203, 68, 163, 155, 212, 111, 165, 150, 155, 197, 195,
-            114, 174, 16, 19, 103, 20, 2, 14, 39, 7, 0, 184, 127, 13, 229, 133, 164, 157, 237, 232,
-            44, 42, 85, 180, 248, 83, 175, 103, 221, 114, 23, 152, 59, 137, 40, 147, 75, 237, 213,
-            127, 42, 166, 113, 123, 69, 112, 255, 156, 248, 197, 93, 51, 20, 2, 132, 215, 23, 0,
-            184, 127, 13, 230, 139, 6, 62, 158, 63, 238, 4, 97, 21, 21, 105, 51, 129, 12, 247, 98,
-            252, 181, 146, 142, 90, 117, 11, 37, 235, 156, 215, 147, 47, 66, 89, 114, 185, 79, 23,
-            150, 10, 20, 2, 90, 98, 2, 0, 184, 127, 13, 233, 36, 75, 18, 59, 111, 146, 203, 110,
-            245, 130, 139, 184, 47, 189, 253, 249, 8, 92, 80, 15, 209, 61, 230, 67, 61, 132, 147,
-            169, 68, 204, 81, 226, 29, 129, 236, 111, 108, 20, 2, 40, 107, 238, 0, 184, 127, 13,
-            235, 49, 105, 130, 212, 181, 50, 174, 226, 37, 242, 113, 76, 87, 62, 236, 150, 90, 157,
-            209, 225, 202, 57, 150, 54, 217, 21, 140, 224, 104, 132, 47, 5, 88, 243, 96, 164, 53,
-            20, 2, 225, 245, 5, 0, 184, 127, 13, 236, 89, 1, 147, 83, 255, 133, 149, 219, 246, 70,
-            36, 239, 128, 218, 122, 145, 111, 19, 155, 96, 127, 232, 172, 25, 174, 210, 25, 218,
-            124, 127, 153, 144, 190, 44, 33, 77, 30, 20, 2, 90, 98, 2, 0, 184, 127, 13, 238, 82,
-            183, 131, 161, 82, 190, 212, 151, 71, 10, 4, 202, 76, 19, 202, 204, 214, 156, 120, 39,
-            227, 221, 198, 68, 115, 253, 45, 124, 93, 73, 108, 113, 6, 31, 69, 43, 5, 20, 2, 194,
-            235, 11, 0, 184, 127, 13, 240, 16, 121, 170, 246, 93, 42, 199, 60, 36, 187, 116, 3,
-            118, 165, 176, 244, 72, 20, 232, 206, 139, 52, 242, 59, 224, 101, 14, 153, 183, 172,
-            129, 225, 21, 159, 156, 49, 81, 20, 2, 14, 39, 7, 0, 184, 127, 13, 240, 116, 130, 91,
-            103, 252, 20, 229, 178, 29, 46, 238, 8, 101, 173, 251, 135, 131, 172, 144, 5, 64, 214,
-            127, 10, 137, 235, 104, 129, 231, 125, 218, 145, 213, 9, 57, 136, 9, 20, 2, 194, 235,
-            11, 0, 184, 127, 13, 241, 137, 108, 127, 202, 157, 148, 33, 28, 70, 215, 187, 7, 198,
-            124, 43, 200, 14, 125, 91, 164, 98, 63, 142, 240, 213, 101, 210, 102, 114, 62, 198, 4,
-            151, 240, 55, 91, 59, 20, 2, 194, 235, 11, 0, 184, 127, 13, 241, 173, 127, 24, 53, 236,
-            226, 180, 1, 44, 62, 229, 88, 94, 159, 50, 107, 157, 70, 208, 90, 62, 37, 219, 229,
-            224, 136, 151, 209, 11, 38, 116, 28, 12, 224, 213, 5, 57, 20, 2, 40, 107, 238, 0, 184,
-            127, 13, 242, 250, 180, 126, 35, 203, 116, 201, 45, 21, 1, 177, 84, 183, 41, 15, 74,
-            195, 110, 69, 23, 109, 76, 107, 194, 84, 140, 173, 91, 37, 77, 239, 114, 195, 194, 119,
-            62, 90, 20, 2, 40, 107, 238, 0, 172, 127, 13, 243, 220, 116, 225, 92, 255, 128, 146,
-            253, 94, 34, 186, 188, 31, 243, 13, 148, 84, 67, 248, 78, 66, 238, 176, 171, 203, 108,
-            164, 162, 230, 112, 217, 67, 203, 83, 85, 112, 60, 8, 4, 0, 184, 127, 13, 245, 24, 233,
-            13, 13, 44, 190, 28, 136, 49, 208, 24, 254, 213, 130, 104, 139, 94, 117, 190, 60, 153,
-            131, 172, 23, 97, 251, 36, 169, 22, 69, 28, 105, 153, 192, 198, 111, 94, 20, 2, 14, 39,
-            7, 0, 184, 127, 13, 247, 64, 90, 76, 191, 61, 34, 171, 201, 38, 183, 177, 165, 160,
-            162, 2, 24, 57, 25, 222, 16, 137, 208, 73, 57, 224, 152, 51, 202, 117, 54, 181, 44,
-            206, 159, 169, 191, 53, 20, 2, 40, 107, 238, 0, 172, 127, 13, 247, 117, 119, 39, 193,
-            252, 96, 15, 240, 97, 177, 217, 127, 40, 218, 145, 173, 91, 62, 183, 204, 93, 237, 197,
-            67, 27, 41, 53, 160, 68, 12, 2, 166, 168, 42, 61, 212, 155, 8, 4, 0, 184, 127, 13, 247,
-            155, 251, 245, 93, 25, 84, 133, 142, 102, 22, 10, 49, 39, 226, 173, 246, 250, 170, 170,
-            194, 14, 65, 225, 131, 105, 69, 227, 133, 97, 249, 67, 161, 34, 161, 186, 85, 43, 20,
-            2, 40, 107, 238, 0, 184, 127, 13, 252, 37, 180, 66, 10, 251, 44, 193, 109, 169, 209,
-            247, 39, 20, 117, 7, 90, 168, 235, 92, 102, 103, 113, 68, 38, 184, 196, 29, 190, 207,
-            146, 189, 237, 250, 70, 43, 113, 99, 20, 2, 225, 245, 5, 0, 184, 127, 13, 253, 20, 132,
-            15, 108, 2, 224, 209, 137, 86, 6, 126, 192, 153, 131, 36, 218, 119, 220, 139, 179, 127,
-            70, 53, 213, 236, 6, 24, 63, 252, 101, 63, 159, 36, 20, 16, 182, 52, 20, 2, 40, 107,
-            238, 0, 21, 1, 128, 0, 3, 128, 13, 9, 45, 153, 24, 19, 57, 120, 123, 67, 38, 20, 175,
-            95, 225, 82, 181, 251, 197, 27, 181, 80, 241, 226, 96, 169, 200, 115, 5, 238, 50, 158,
-            128, 231, 29, 122, 59, 0, 180, 184, 185, 185, 191, 138, 82, 158, 241, 78, 110, 34, 43,
-            155, 17, 45, 179, 156, 85, 217, 25, 233, 235, 169, 157, 160, 112, 21, 1, 128, 0, 3,
-            128, 78, 56, 14, 34, 187, 0, 41, 77, 203, 208, 33, 249, 164, 253, 169, 152, 229, 208,
-            7, 147, 6, 63, 92, 124, 51, 113, 199, 4, 246, 254, 70, 224, 128, 48, 24, 41, 153, 85,
-            140, 114, 146, 80, 242, 33, 24, 127, 51, 12, 162, 23, 16, 124, 189, 221, 48, 222, 228,
-            208, 204, 76, 136, 109, 182, 192, 132, 21, 1, 128, 0, 5, 128, 60, 122, 209, 162, 66,
-            95, 4, 47, 159, 209, 247, 162, 180, 40, 31, 191, 106, 227, 177, 52, 160, 27, 92, 115,
-            193, 167, 65, 115, 63, 36, 236, 189, 128, 10, 195, 194, 145, 88, 144, 33, 205, 201,
-            229, 250, 88, 213, 128, 176, 14, 86, 209, 151, 174, 217, 20, 176, 243, 208, 236, 45,
-            197, 108, 14, 199, 173, 21, 1, 128, 0, 5, 128, 112, 8, 87, 126, 164, 203, 234, 7, 181,
-            167, 77, 15, 85, 161, 122, 148, 185, 224, 183, 60, 255, 247, 208, 118, 184, 104, 214,
-            227, 77, 41, 132, 139, 128, 121, 118, 175, 193, 241, 219, 97, 80, 61, 57, 55, 196, 20,
-            69, 29, 87, 103, 202, 72, 92, 250, 71, 102, 12, 49, 30, 48, 99, 105, 107, 186, 154, 21,
-            1, 128, 0, 6, 128, 144, 224, 22, 213, 209, 190, 75, 206, 219, 156, 186, 192, 177, 212,
-            108, 176, 111, 181, 206, 4, 31, 24, 67, 223, 66, 41, 62, 123, 246, 208, 94, 248, 128,
-            58, 87, 236, 183, 205, 127, 88, 182, 103, 58, 123, 226, 196, 106, 61, 16, 142, 25, 6,
-            249, 115, 163, 101, 89, 230, 167, 106, 166, 161, 34, 197, 215, 21, 1, 128, 0, 10, 128,
-            151, 166, 57, 120, 179, 75, 196, 69, 227, 110, 199, 86, 31, 194, 137, 57, 88, 239, 242,
-            65, 184, 230, 171, 25, 45, 188, 194, 175, 11, 92, 53, 177, 128, 58, 112, 80, 71, 117,
-            104, 20, 70, 26, 51, 185, 148, 54, 219, 0, 116, 210, 70, 116, 111, 146, 29, 199, 72,
-            151, 207, 141, 186, 122, 134, 209, 195, 21, 1, 128, 0, 10, 128, 243, 187, 87, 241, 55,
-            51, 35, 119, 150, 73, 231, 208, 113, 62, 170, 177, 24, 27, 183, 71, 160, 207, 75, 216,
-            174, 135, 219, 102, 103, 226, 241, 253, 128, 62, 46, 249, 76, 115, 73, 0, 37, 9, 26,
-            21, 189, 67, 170, 143, 247, 45, 52, 113, 73, 108, 171, 38, 72, 125, 53, 169, 162, 105,
-            253, 173, 161, 21, 1, 128, 0, 17, 128, 203, 203, 140, 101, 119, 223, 129, 66, 44, 197,
-            230, 80, 29, 196, 100, 29, 53, 203, 53, 162, 159, 254, 86, 45, 3, 214, 138, 130, 149,
-            94, 161, 221, 128, 48, 186, 210, 47, 123, 78, 251, 66, 39, 93, 209, 91, 50, 176, 149,
-            69, 214, 152, 53, 248, 62, 6, 248, 34, 224, 136, 235, 146, 158, 117, 249, 126, 21, 1,
-            128, 0, 18, 128, 199, 243, 93, 71, 133, 243, 28, 192, 248, 136, 133, 84, 40, 175, 160,
-            190, 144, 220, 62, 47, 93, 42, 87, 73, 176, 220, 23, 138, 53, 86, 88, 123, 128, 105, 5,
-            171, 36, 100, 161, 173, 161, 223, 209, 166, 146, 68, 76, 108, 235, 242, 144, 20, 189,
-            131, 55, 202, 64, 168, 237, 227, 148, 128, 43, 43, 245, 21, 1, 128, 0, 33, 128, 194,
-            162, 118, 194, 42, 147, 176, 229, 225, 99, 210, 146, 182, 131, 119, 73, 74, 76, 119,
-            195, 197, 176, 188, 164, 141, 179, 121, 160, 214, 203, 62, 27, 128, 37, 59, 55, 74,
-            158, 61, 110, 126, 20, 112, 30, 50, 202, 162, 250, 8, 109, 160, 161, 130, 50, 105, 72,
-            152, 240, 152, 26, 96, 37, 211, 8, 131, 21, 1, 128, 0, 36, 128, 189, 238, 72, 186, 179,
-            16, 208, 48, 168, 59, 124, 101, 253, 84, 60, 255, 56, 167, 255, 81, 40, 65, 59, 67,
-            106, 166, 146, 233, 201, 157, 232, 76, 128, 198, 81, 213, 112, 244, 121, 254, 196, 198,
-            32, 148, 29, 32, 8, 239, 11, 49, 219, 225, 89, 152, 177, 30, 234, 42, 134, 22, 171,
-            117, 38, 252, 89, 21, 1, 128, 0, 40, 128, 200, 152, 243, 174, 14, 229, 40, 203, 246,
-            20, 48, 114, 248, 246, 146, 113, 106, 133, 242, 183, 248, 28, 228, 62, 8, 254, 166, 74,
-            254, 105, 95, 45, 128, 200, 181, 178, 116, 92, 168, 179, 5, 134, 76, 5, 132, 149, 110,
-            235, 122, 38, 123, 82, 20, 146, 202, 50, 0, 41, 48, 178, 226, 207, 96, 244, 202, 21, 1,
-            128, 0, 48, 128, 9, 19, 154, 13, 14, 175, 246, 154, 31, 108, 1, 174, 120, 53, 97, 186,
-            214, 102, 164, 66, 94, 137, 111, 175, 191, 179, 51, 133, 105, 224, 126, 200, 128, 253,
-            121, 113, 239, 229, 75, 169, 173, 250, 233, 223, 15, 85, 148, 17, 200, 98, 12, 145,
-            240, 141, 18, 55, 127, 9, 68, 185, 122, 225, 97, 66, 241, 21, 1, 128, 0, 66, 128, 5,
-            237, 231, 244, 186, 82, 13, 167, 140, 236, 212, 31, 126, 33, 88, 231, 168, 129, 230,
-            118, 211, 110, 178, 170, 87, 79, 53, 127, 132, 66, 76, 76, 128, 47, 87, 224, 4, 232,
-            47, 174, 79, 131, 221, 80, 150, 118, 207, 82, 33, 207, 202, 133, 151, 213, 212, 85, 97,
-            193, 247, 100, 195, 212, 31, 43, 43, 21, 1, 128, 0, 72, 128, 109, 45, 76, 205, 96, 154,
-            211, 59, 158, 39, 138, 168, 200, 55, 166, 47, 20, 210, 71, 232, 120, 246, 24, 20, 106,
-            3, 190, 191, 64, 151, 52, 141, 128, 0, 248, 202, 97, 235, 202, 108, 164, 15, 117, 32,
-            221, 200, 121, 44, 197, 202, 14, 245, 108, 190, 214, 47, 14, 56, 159, 160, 211, 56,
-            142, 246, 232, 21, 1, 128, 0, 96, 128, 197, 225, 8, 75, 11, 193, 154, 186, 98, 167,
-            112, 163, 86, 166, 132, 8, 180, 211, 229, 124, 193, 166, 101, 89, 222, 8, 28, 64, 71,
-            9, 144, 221, 128, 12, 99, 108, 248, 34, 184, 254, 243, 80, 173, 110, 242, 93, 69, 91,
-            180, 247, 111, 233, 51, 158, 174, 77, 169, 145, 90, 138, 173, 191, 156, 108, 172, 21,
-            1, 128, 0, 132, 128, 30, 233, 216, 81, 138, 58, 243, 226, 22, 185, 101, 65, 245, 133,
-            149, 155, 7, 57, 109, 26, 202, 143, 55, 145, 127, 128, 161, 85, 232, 216, 84, 242, 128,
-            114, 139, 159, 52, 143, 141, 196, 238, 7, 37, 144, 51, 89, 11, 75, 229, 234, 23, 31,
-            48, 150, 79, 235, 250, 30, 8, 205, 41, 254, 136, 86, 253, 153, 1, 128, 0, 133, 128,
-            220, 8, 21, 117, 25, 61, 211, 17, 15, 241, 125, 50, 251, 121, 185, 101, 131, 108, 148,
-            110, 32, 54, 31, 203, 109, 70, 157, 239, 67, 139, 63, 163, 128, 27, 229, 247, 69, 144,
-            123, 15, 195, 10, 136, 242, 204, 30, 189, 213, 222, 60, 50, 171, 3, 192, 89, 178, 34,
-            99, 218, 78, 185, 240, 230, 62, 48, 128, 185, 187, 1, 169, 46, 116, 207, 110, 200, 171,
-            190, 197, 196, 162, 139, 123, 113, 213, 227, 22, 181, 141, 47, 138, 106, 92, 212, 22,
-            43, 138, 30, 21, 21, 1, 128, 0, 136, 128, 91, 136, 156, 126, 169, 45, 2, 248, 182, 91,
-            133, 61, 111, 77, 167, 105, 219, 203, 46, 156, 13, 196, 206, 196, 84, 38, 91, 158, 60,
-            92, 152, 247, 128, 99, 177, 162, 155, 184, 3, 68, 113, 9, 165, 174, 182, 107, 77, 17,
-            223, 121, 140, 224, 39, 164, 88, 107, 65, 234, 103, 98, 44, 221, 83, 100, 148, 21, 1,
-            128, 0, 144, 128, 68, 139, 74, 217, 3, 89, 216, 203, 235, 187, 166, 222, 195, 24, 206,
-            96, 67, 132, 61, 219, 214, 6, 75, 45, 94, 153, 107, 109, 210, 248, 157, 175, 128, 235,
-            240, 211, 254, 162, 115, 123, 242, 225, 141, 104, 46, 79, 82, 88, 95, 190, 248, 65,
-            177, 23, 247, 201, 219, 0, 28, 225, 84, 48, 1, 115, 74, 21, 1, 128, 0, 160, 128, 182,
-            32, 11, 28, 38, 242, 152, 197, 156, 118, 215, 128, 180, 24, 245, 107, 126, 33, 4, 68,
-            229, 146, 77, 111, 138, 158, 238, 251, 187, 95, 76, 187, 128, 66, 189, 109, 70, 139,
-            144, 84, 127, 159, 241, 99, 151, 114, 100, 157, 54, 249, 88, 246, 138, 33, 24, 196, 82,
-            234, 110, 104, 215, 96, 23, 214, 247, 29, 2, 128, 0, 177, 128, 199, 50, 201, 57, 175,
-            2, 55, 217, 58, 32, 234, 223, 238, 32, 112, 236, 219, 36, 102, 113, 31, 243, 123, 106,
-            206, 138, 78, 143, 65, 95, 64, 176, 128, 65, 255, 101, 134, 146, 204, 253, 34, 139, 32,
-            244, 235, 96, 48, 198, 94, 101, 134, 62, 99, 236, 36, 42, 146, 116, 238, 10, 175, 100,
-            189, 196, 185, 128, 225, 47, 191, 31, 181, 226, 227, 124, 242, 35, 4, 136, 217, 229,
-            205, 112, 47, 3, 102, 112, 68, 148, 204, 243, 133, 170, 158, 209, 180, 222, 40, 70,
-            128, 198, 122, 38, 81, 132, 119, 187, 167, 111, 31, 91, 202, 253, 207, 46, 104, 233,
-            141, 114, 109, 42, 137, 139, 63, 196, 97, 121, 148, 216, 149, 230, 221, 153, 1, 128, 0,
-            194, 128, 160, 100, 233, 91, 3, 183, 14, 46, 38, 91, 232, 130, 92, 197, 149, 174, 201,
-            100, 16, 253, 83, 81, 116, 25, 183, 147, 35, 216, 216, 118, 99, 122, 128, 179, 44, 124,
-            227, 90, 178, 230, 69, 120, 240, 134, 195, 239, 158, 174, 55, 184, 252, 171, 186, 143,
-            225, 250, 234, 45, 202, 218, 206, 117, 218, 23, 232, 128, 220, 17, 208, 182, 33, 98,
-            216, 24, 6, 184, 186, 104, 207, 11, 44, 216, 77, 151, 178, 228, 45, 48, 62, 32, 65, 82,
-            85, 57, 254, 69, 79, 117, 153, 1, 128, 0, 224, 128, 197, 236, 227, 105, 205, 222, 253,
-            5, 57, 175, 252, 136, 94, 138, 33, 152, 154, 161, 174, 155, 145, 167, 2, 33, 216, 105,
-            175, 246, 138, 54, 164, 219, 128, 246, 221, 126, 19, 113, 128, 152, 54, 57, 71, 94, 66,
-            195, 210, 232, 191, 62, 192, 119, 151, 205, 160, 206, 67, 224, 218, 71, 55, 215, 21,
-            171, 150, 128, 54, 213, 140, 201, 119, 178, 46, 128, 249, 141, 41, 194, 163, 208, 52,
-            160, 78, 3, 41, 39, 22, 45, 154, 152, 246, 132, 146, 91, 137, 124, 83, 10, 21, 1, 128,
-            1, 1, 128, 62, 151, 247, 122, 48, 155, 254, 155, 53, 173, 206, 45, 154, 21, 86, 2, 196,
-            110, 212, 63, 19, 1, 182, 58, 226, 169, 155, 27, 188, 249, 218, 118, 128, 106, 232, 74,
-            5, 153, 1, 117, 178, 181, 147, 95, 83, 15, 137, 138, 52, 172, 20, 137, 226, 174, 221,
-            37, 188, 130, 23, 115, 235, 194, 175, 37, 134, 21, 1, 128, 1, 4, 128, 255, 150, 72,
-            251, 4, 71, 48, 167, 124, 71, 230, 55, 174, 221, 2, 248, 192, 42, 37, 106, 215, 94, 81,
-            151, 209, 60, 14, 90, 53, 169, 93, 151, 128, 60, 140, 203, 47, 66, 139, 177, 59, 53,
-            132, 124, 7, 98, 66, 243, 98, 117, 214, 161, 229, 81, 160, 184, 147, 140, 160, 70, 78,
-            212, 51, 13, 32, 153, 1, 128, 1, 9, 128, 182, 176, 86, 103, 130, 112, 152, 70, 130,
-            123, 97, 130, 253, 202, 191, 114, 214, 5, 255, 18, 214, 188, 64, 45, 189, 70, 119, 130,
-            247, 143, 146, 25, 128, 222, 84, 191, 139, 38, 192, 231, 202, 43, 181, 111, 182, 5,
-            135, 40, 28, 47, 210, 52, 7, 8, 92, 121, 239, 243, 65, 5, 1, 245, 93, 26, 21, 128, 184,
-            185, 201, 70, 229, 20, 169, 131, 43, 58, 19, 47, 56, 193, 99, 137, 165, 239, 91, 197,
-            213, 64, 238, 202, 57, 22, 108, 109, 197, 119, 57, 10, 153, 1, 128, 1, 36, 128, 14,
-            170, 0, 109, 214, 215, 167, 163, 13, 219, 206, 10, 30, 107, 113, 29, 39, 91, 244, 58,
-            153, 2, 161, 102, 3, 140, 218, 20, 131, 32, 242, 8, 128, 133, 177, 246, 221, 107, 71,
-            189, 138, 186, 82, 98, 163, 60, 242, 43, 255, 173, 108, 48, 50, 166, 70, 71, 161, 223,
-            160, 240, 54, 68, 45, 114, 163, 128, 198, 226, 248, 195, 76, 125, 159, 27, 6, 223, 128,
-            132, 230, 130, 49, 135, 214, 81, 143, 190, 25, 228, 87, 39, 162, 36, 73, 203, 148, 225,
-            105, 75, 29, 2, 128, 1, 84, 128, 17, 23, 230, 230, 143, 163, 217, 25, 138, 100, 192,
-            43, 48, 140, 161, 78, 41, 251, 147, 63, 163, 86, 41, 222, 2, 20, 130, 83, 57, 191, 79,
-            126, 128, 61, 0, 118, 239, 107, 7, 10, 93, 74, 175, 172, 212, 108, 108, 249, 147, 224,
-            18, 66, 27, 58, 51, 9, 110, 237, 136, 169, 110, 118, 125, 190, 14, 128, 137, 192, 197,
-            116, 146, 233, 79, 154, 244, 27, 190, 228, 87, 205, 203, 22, 50, 160, 164, 195, 200,
-            109, 39, 86, 1, 226, 172, 107, 3, 147, 15, 177, 128, 30, 32, 47, 195, 1, 44, 229, 234,
-            208, 204, 202, 120, 125, 92, 146, 60, 190, 189, 5, 50, 192, 122, 48, 55, 97, 92, 12,
-            61, 136, 228, 207, 35, 21, 1, 128, 1, 128, 128, 101, 88, 102, 24, 69, 170, 173, 13,
-            212, 132, 64, 235, 198, 189, 112, 254, 22, 254, 27, 108, 202, 165, 127, 2, 169, 246,
-            133, 225, 196, 20, 188, 247, 128, 32, 71, 227, 60, 193, 112, 104, 173, 19, 63, 15, 50,
-            210, 42, 26, 154, 58, 152, 60, 91, 251, 19, 55, 1, 142, 46, 230, 121, 141, 181, 209,
-            105, 153, 1, 128, 1, 129, 128, 14, 79, 66, 100, 131, 147, 185, 54, 15, 141, 3, 226,
-            200, 237, 1, 31, 66, 198, 70, 255, 175, 138, 251, 103, 32, 82, 16, 147, 4, 74, 194, 85,
-            128, 230, 108, 3, 23, 130, 145, 205, 119, 136, 28, 69, 177, 82, 52, 206, 253, 162, 54,
-            146, 73, 51, 183, 199, 175, 32, 213, 154, 211, 128, 143, 174, 131, 128, 42, 161, 119,
-            243, 58, 128, 5, 252, 145, 90, 168, 133, 25, 14, 107, 167, 244, 36, 118, 17, 172, 89,
-            40, 178, 50, 179, 152, 145, 136, 254, 187, 79, 153, 1, 128, 1, 144, 128, 21, 128, 171,
-            91, 28, 68, 227, 29, 171, 149, 8, 252, 76, 113, 13, 128, 97, 167, 49, 177, 152, 172,
-            155, 64, 212, 178, 180, 93, 244, 51, 6, 39, 128, 126, 107, 34, 59, 69, 6, 67, 70, 79,
-            86, 249, 72, 161, 122, 212, 192, 93, 174, 248, 25, 226, 34, 184, 244, 144, 164, 6, 171,
-            173, 118, 195, 159, 128, 241, 254, 244, 155, 200, 49, 12, 118, 133, 129, 7, 120, 80,
-            24, 65, 237, 125, 34, 62, 9, 239, 48, 29, 43, 163, 152, 36, 39, 242, 113, 119, 154, 37,
-            3, 128, 1, 199, 128, 229, 52, 140, 251, 89, 18, 229, 223, 37, 116, 177, 95, 130, 85,
-            209, 100, 159, 224, 58, 178, 86, 52, 224, 208, 224, 105, 41, 125, 21, 39, 200, 153,
-            128, 56, 122, 161, 184, 180, 37, 225, 132, 219, 219, 210, 76, 124, 50, 54, 199, 218,
-            12, 48, 7, 188, 164, 189, 120, 68, 121, 198, 107, 120, 134, 75, 245, 128, 128, 194,
-            189, 61, 153, 136, 242, 95, 74, 24, 139, 74, 158, 136, 241, 247, 112, 85, 39, 202, 235,
-            190, 234, 111, 149, 128, 253, 109, 127, 107, 221, 33, 128, 158, 232, 136, 238, 212, 88,
-            112, 177, 200, 61, 65, 82, 11, 102, 89, 39, 220, 163, 123, 229, 152, 171, 30, 124, 40,
-            19, 231, 99, 135, 125, 142, 236, 128, 132, 101, 157, 170, 113, 35, 232, 185, 96, 247,
-            218, 132, 16, 134, 225, 25, 172, 6, 79, 216, 229, 133, 53, 253, 210, 183, 95, 225, 116,
-            85, 36, 126, 128, 200, 169, 163, 87, 47, 206, 153, 182, 169, 113, 79, 197, 99, 160,
-            124, 17, 47, 143, 21, 1, 230, 214, 198, 87, 114, 191, 20, 120, 251, 21, 130, 170, 21,
-            1, 128, 2, 1, 128, 157, 101, 55, 214, 161, 76, 215, 250, 122, 137, 171, 5, 225, 88, 48,
-            8, 189, 160, 18, 0, 209, 114, 66, 117, 18, 239, 153, 108, 36, 55, 240, 229, 128, 65,
-            118, 166, 45, 36, 126, 148, 138, 114, 29, 55, 208, 231, 160, 206, 167, 27, 182, 25,
-            122, 121, 61, 40, 219, 20, 129, 243, 200, 164, 75, 223, 25, 21, 1, 128, 2, 1, 128, 182,
-            124, 62, 39, 159, 24, 140, 204, 46, 154, 68, 61, 252, 134, 77, 96, 249, 83, 33, 17, 55,
-            233, 75, 174, 195, 57, 236, 149, 89, 44, 20, 129, 128, 244, 2, 150, 76, 157, 45, 142,
-            117, 104, 71, 253, 33, 114, 107, 189, 199, 147, 9, 219, 6, 222, 146, 222, 169, 126,
-            175, 5, 129, 17, 186, 203, 36, 21, 1, 128, 2, 2, 128, 145, 164, 48, 145, 174, 151, 23,
-            165, 212, 19, 9, 172, 230, 175, 92, 26, 24, 160, 18, 220, 217, 250, 187, 65, 230, 0,
-            250, 57, 3, 196, 134, 183, 128, 121, 44, 158, 95, 85, 171, 141, 171, 8, 61, 76, 205,
-            77, 32, 8, 195, 91, 87, 132, 247, 81, 79, 198, 27, 9, 174, 154, 140, 39, 120, 103, 119,
-            21, 1, 128, 2, 8, 128, 36, 254, 90, 102, 69, 2, 91, 191, 241, 84, 79, 118, 92, 141,
-            104, 135, 59, 244, 218, 43, 17, 46, 107, 148, 207, 35, 198, 76, 9, 248, 38, 98, 128,
-            231, 74, 188, 180, 2, 64, 229, 5, 198, 205, 126, 180, 80, 50, 21, 6, 178, 212, 179, 56,
-            52, 243, 145, 155, 132, 122, 209, 214, 250, 206, 110, 12, 21, 1, 128, 2, 8, 128, 139,
-            57, 240, 50, 220, 148, 216, 253, 194, 78, 125, 129, 214, 146, 103, 182, 0, 172, 1, 80,
-            26, 27, 101, 109, 161, 104, 129, 191, 208, 156, 88, 148, 128, 3, 5, 76, 99, 71, 3, 51,
-            138, 188, 23, 78, 61, 249, 14, 237, 158, 224, 174, 23, 157, 78, 106, 165, 12, 145, 40,
-            178, 177, 39, 227, 210, 122, 21, 1, 128, 2, 32, 128, 174, 236, 138, 170, 190, 40, 99,
-            252, 175, 239, 127, 31, 237, 206, 178, 175, 103, 70, 1, 6, 131, 162, 69, 170, 48, 72,
-            211, 237, 177, 217, 140, 179, 128, 240, 9, 237, 231, 166, 153, 186, 153, 163, 250, 44,
-            33, 31, 103, 155, 177, 155, 147, 115, 85, 105, 74, 148, 132, 237, 215, 76, 107, 53,
-            203, 115, 68, 21, 1, 128, 2, 32, 128, 245, 23, 17, 209, 83, 248, 96, 237, 45, 182, 34,
-            99, 154, 216, 216, 90, 202, 78, 60, 185, 156, 215, 94, 44, 3, 115, 235, 57, 57, 75,
-            244, 36, 128, 188, 172, 71, 218, 164, 41, 163, 143, 246, 173, 211, 233, 220, 28, 18,
-            108, 127, 83, 165, 155, 240, 99, 150, 145, 176, 36, 240, 69, 77, 209, 117, 234, 21, 1,
-            128, 2, 64, 128, 235, 102, 63, 161, 178, 133, 237, 119, 157, 200, 245, 214, 231, 223,
-            87, 114, 82, 87, 248, 51, 141, 157, 195, 216, 27, 247, 196, 106, 15, 229, 7, 35, 128,
-            95, 65, 153, 9, 68, 151, 146, 77, 147, 194, 172, 221, 143, 255, 191, 252, 126, 199, 87,
-            104, 78, 164, 236, 10, 208, 127, 237, 95, 5, 159, 199, 157, 153, 1, 128, 2, 65, 128,
-            99, 210, 154, 21, 178, 193, 125, 85, 206, 249, 58, 101, 156, 18, 63, 145, 182, 34, 237,
-            231, 73, 47, 226, 4, 86, 141, 92, 193, 43, 181, 28, 163, 128, 23, 129, 30, 191, 96,
-            191, 89, 80, 194, 41, 17, 11, 91, 104, 94, 6, 104, 77, 70, 127, 167, 28, 147, 147, 113,
-            43, 249, 251, 162, 184, 245, 189, 128, 251, 96, 197, 142, 226, 159, 95, 72, 144, 68,
-            183, 104, 187, 122, 61, 47, 241, 149, 122, 87, 35, 217, 102, 184, 119, 109, 172, 81,
-            212, 36, 93, 223, 21, 1, 128, 2, 128, 128, 39, 43, 181, 44, 99, 235, 129, 58, 178, 238,
-            135, 119, 123, 213, 233, 213, 221, 190, 24, 208, 102, 32, 41, 146, 247, 102, 251, 55,
-            228, 191, 112, 132, 128, 198, 97, 115, 128, 126, 175, 232, 202, 255, 189, 21, 84, 228,
-            246, 221, 67, 147, 231, 171, 70, 137, 116, 51, 210, 98, 93, 4, 8, 111, 1, 215, 134,
-            153, 1, 128, 2, 136, 128, 245, 186, 30, 234, 46, 209, 152, 84, 69, 42, 94, 14, 62, 154,
-            92, 170, 55, 38, 224, 91, 117, 175, 112, 206, 94, 50, 235, 190, 158, 223, 48, 122, 128,
-            145, 158, 73, 145, 122, 74, 4, 222, 83, 240, 208, 57, 26, 103, 51, 60, 72, 207, 208,
-            76, 23, 238, 52, 134, 110, 84, 101, 191, 65, 186, 138, 16, 128, 133, 4, 233, 24, 79,
-            228, 255, 81, 201, 201, 217, 145, 56, 243, 223, 227, 221, 32, 153, 144, 98, 1, 118, 27,
-            38, 18, 0, 77, 217, 229, 167, 219, 29, 2, 128, 2, 148, 128, 120, 1, 229, 133, 127, 25,
-            181, 209, 230, 92, 49, 229, 247, 31, 24, 105, 136, 25, 137, 19, 83, 134, 122, 196, 1,
-            70, 155, 112, 204, 141, 146, 61, 128, 90, 209, 77, 30, 0, 34, 229, 94, 44, 81, 106, 16,
-            64, 132, 0, 95, 29, 132, 49, 8, 153, 142, 15, 162, 129, 202, 59, 5, 207, 143, 162, 50,
-            128, 188, 235, 241, 241, 171, 214, 187, 162, 83, 116, 178, 167, 145, 20, 178, 145, 246,
-            23, 198, 164, 162, 133, 240, 212, 144, 101, 91, 25, 67, 49, 206, 61, 128, 0, 86, 202,
-            132, 154, 216, 154, 30, 105, 210, 195, 219, 251, 129, 118, 210, 189, 140, 144, 211, 81,
-            177, 47, 218, 121, 84, 173, 35, 58, 241, 251, 162, 21, 1, 128, 3, 0, 128, 121, 49, 22,
-            126, 47, 59, 139, 160, 154, 90, 135, 3, 220, 51, 167, 123, 171, 122, 97, 186, 255, 255,
-            84, 124, 25, 80, 221, 13, 143, 181, 61, 3, 128, 236, 94, 135, 1, 122, 117, 175, 238,
-            159, 61, 84, 32, 62, 56, 106, 132, 73, 156, 178, 100, 22, 164, 181, 25, 252, 79, 139,
-            151, 87, 167, 169, 105, 21, 1, 128, 4, 1, 128, 77, 95, 110, 84, 68, 162, 31, 192, 217,
-            93, 187, 71, 134, 176, 197, 66, 59, 47, 56, 58, 36, 122, 123, 123, 214, 5, 93, 10, 242,
-            207, 162, 188, 128, 78, 66, 70, 45, 171, 140, 108, 233, 242, 60, 189, 28, 119, 93, 163,
-            77, 26, 42, 177, 204, 25, 135, 7, 19, 50, 74, 26, 166, 219, 42, 248, 62, 21, 1, 128, 4,
-            1, 128, 184, 244, 202, 100, 182, 8, 21, 20, 125, 75, 62, 61, 215, 87, 85, 14, 42, 67,
-            116, 83, 156, 165, 32, 3, 68, 253, 224, 70, 53, 249, 133, 210, 128, 133, 55, 229, 76,
-            92, 21, 107, 187, 90, 198, 245, 77, 86, 83, 91, 141, 32, 210, 232, 192, 152, 192, 212,
-            252, 182, 214, 200, 195, 189, 15, 183, 184, 21, 1, 128, 4, 1, 128, 249, 109, 122, 234,
-            91, 148, 137, 50, 62, 114, 226, 42, 10, 203, 69, 113, 37, 15, 85, 48, 8, 42, 197, 197,
-            122, 181, 74, 173, 130, 240, 163, 87, 128, 122, 103, 245, 83, 96, 202, 235, 8, 42, 189,
-            114, 119, 45, 139, 234, 40, 73, 210, 46, 66, 79, 126, 23, 201, 49, 82, 52, 57, 184, 58,
-            179, 184, 21, 1, 128, 4, 16, 128, 15, 224, 158, 217, 187, 168, 239, 139, 16, 56, 102,
-            95, 164, 202, 53, 212, 46, 106, 112, 180, 161, 88, 114, 112, 157, 237, 179, 94, 34,
-            184, 67, 214, 128, 55, 31, 167, 135, 149, 168, 155, 36, 248, 37, 44, 142, 68, 61, 135,
-            45, 201, 205, 211, 147, 184, 50, 140, 77, 89, 213, 48, 120, 70, 237, 197, 234, 21, 1,
-            128, 4, 32, 128, 13, 54, 149, 21, 219, 3, 119, 196, 111, 37, 1, 63, 243, 183, 137, 62,
-            88, 151, 16, 94, 163, 187, 8, 135, 147, 138, 159, 128, 151, 11, 19, 12, 128, 242, 160,
-            48, 172, 182, 126, 33, 120, 225, 148, 224, 38, 118, 5, 62, 82, 12, 117, 90, 32, 80, 77,
-            98, 162, 150, 244, 161, 219, 193, 126, 204, 107, 153, 1, 128, 4, 33, 128, 209, 170,
-            213, 100, 195, 186, 56, 45, 113, 62, 12, 218, 215, 54, 101, 11, 144, 224, 200, 169, 77,
-            37, 45, 119, 8, 150, 227, 87, 38, 214, 135, 215, 128, 5, 44, 105, 45, 254, 142, 215,
-            199, 70, 4, 91, 143, 228, 251, 185, 13, 132, 58, 51, 94, 133, 94, 184, 62, 121, 29, 64,
-            179, 131, 226, 52, 20, 128, 25, 6, 250, 231, 47, 248, 136, 92, 44, 66, 67, 138, 49,
-            104, 255, 67, 200, 197, 98, 58, 241, 51, 188, 230, 246, 248, 77, 241, 233, 212, 76,
-            138, 29, 2, 128, 4, 100, 128, 225, 236, 90, 149, 228, 161, 167, 219, 244, 209, 23, 190,
-            230, 74, 114, 206, 248, 204, 105, 217, 222, 35, 186, 21, 215, 92, 205, 240, 145, 192,
-            60, 108, 128, 141, 93, 26, 245, 195, 157, 132, 179, 83, 209, 25, 196, 93, 64, 137, 188,
-            23, 181, 100, 52, 98, 56, 177, 195, 73, 62, 213, 190, 247, 83, 129, 67, 128, 167, 162,
-            53, 166, 14, 252, 176, 21, 148, 155, 229, 195, 224, 197, 255, 9, 146, 27, 207, 62, 12,
-            249, 212, 132, 217, 57, 70, 198, 19, 93, 150, 45, 128, 105, 122, 113, 6, 170, 14, 215,
-            77, 192, 229, 73, 71, 86, 230, 230, 44, 32, 79, 168, 51, 211, 219, 14, 165, 152, 11,
-            73, 17, 57, 227, 198, 106, 21, 1, 128, 4, 128, 128, 153, 56, 155, 61, 8, 145, 20, 76,
-            73, 160, 186, 125, 27, 0, 202, 166, 50, 62, 40, 198, 122, 66, 34, 64, 111, 80, 92, 22,
-            141, 43, 207, 63, 128, 37, 43, 205, 144, 232, 106, 73, 233, 221, 152, 31, 209, 193, 99,
-            197, 60, 189, 102, 223, 229, 214, 63, 172, 146, 119, 132, 253, 59, 68, 124, 61, 66, 21,
-            1, 128, 4, 128, 128, 154, 168, 69, 168, 28, 241, 89, 156, 255, 74, 142, 56, 93, 241,
-            119, 5, 179, 221, 10, 101, 60, 22, 28, 151, 233, 178, 186, 141, 214, 212, 87, 17, 128,
-            136, 103, 87, 66, 14, 4, 86, 32, 189, 253, 89, 232, 200, 3, 58, 149, 92, 9, 159, 29,
-            15, 184, 13, 42, 22, 39, 93, 24, 121, 42, 176, 115, 29, 2, 128, 4, 164, 128, 87, 52,
-            136, 49, 1, 133, 172, 25, 73, 69, 111, 193, 250, 82, 10, 40, 13, 75, 163, 46, 40, 32,
-            101, 84, 78, 50, 74, 237, 46, 65, 72, 196, 128, 83, 105, 201, 103, 139, 146, 66, 67,
-            123, 5, 238, 21, 126, 231, 131, 236, 233, 106, 173, 92, 177, 166, 248, 170, 14, 116,
-            17, 28, 14, 238, 204, 221, 128, 160, 197, 125, 252, 222, 87, 237, 50, 167, 85, 48, 206,
-            37, 202, 53, 133, 213, 10, 179, 106, 68, 173, 170, 236, 42, 213, 253, 28, 61, 124, 87,
-            90, 128, 98, 150, 43, 222, 173, 83, 243, 167, 227, 167, 164, 23, 135, 47, 10, 111, 221,
-            11, 45, 21, 34, 136, 67, 52, 230, 19, 69, 1, 49, 224, 95, 94, 21, 1, 128, 5, 0, 128,
-            87, 15, 178, 119, 209, 246, 139, 93, 186, 44, 142, 158, 105, 23, 163, 199, 4, 20, 246,
-            123, 207, 73, 150, 98, 76, 16, 179, 253, 136, 197, 74, 44, 128, 123, 58, 148, 113, 33,
-            114, 17, 78, 239, 74, 78, 228, 66, 211, 54, 188, 105, 234, 253, 171, 184, 23, 254, 73,
-            7, 211, 217, 87, 249, 154, 172, 197, 29, 2, 128, 5, 24, 128, 130, 65, 83, 186, 188,
-            140, 42, 248, 141, 195, 125, 127, 40, 109, 66, 78, 147, 63, 224, 219, 177, 206, 229,
-            84, 21, 45, 19, 132, 47, 139, 188, 25, 128, 129, 185, 144, 198, 225, 214, 218, 192,
-            190, 28, 202, 191, 41, 77, 167, 161, 171, 201, 23, 170, 251, 66, 211, 244, 157, 204,
-            174, 202, 114, 81, 179, 6, 128, 85, 4, 147, 6, 155, 253, 217, 221, 166, 220, 210, 29,
-            218, 245, 162, 36, 39, 83, 93, 116, 76, 218, 20, 207, 219, 225, 101, 111, 90, 99, 162,
-            15, 128, 168, 172, 197, 46, 122, 12, 186, 110, 116, 175, 2, 238, 217, 136, 146, 86,
-            117, 9, 120, 185, 188, 96, 188, 102, 191, 115, 132, 13, 109, 25, 42, 42, 21, 1, 128, 8,
-            1, 128, 123, 224, 17, 161, 146, 233, 241, 15, 146, 185, 28, 148, 71, 91, 145, 169, 235,
-            35, 73, 16, 208, 156, 236, 139, 200, 12, 203, 102, 133, 13, 255, 225, 128, 183, 150,
-            31, 232, 83, 182, 30, 79, 224, 183, 100, 136, 38, 125, 89, 222, 25, 62, 52, 16, 251,
-            11, 122, 125, 197, 63, 133, 242, 164, 32, 201, 200, 21, 1, 128, 8, 1, 128, 211, 179,
-            22, 239, 85, 202, 141, 38, 195, 11, 124, 79, 68, 184, 24, 113, 174, 130, 93, 148, 253,
-            200, 105, 32, 138, 32, 116, 213, 155, 137, 12, 1, 128, 103, 7, 89, 172, 66, 32, 167,
-            199, 61, 65, 59, 119, 98, 197, 91, 253, 46, 7, 249, 117, 66, 7, 92, 123, 97, 254, 106,
-            145, 42, 157, 142, 18, 21, 1, 128, 8, 4, 128, 73, 61, 180, 155, 69, 98, 242, 228, 142,
-            89, 167, 47, 241, 167, 246, 116, 250, 135, 17, 113, 165, 19, 201, 204, 13, 232, 190, 7,
-            210, 247, 14, 97, 128, 212, 77, 219, 124, 93, 168, 23, 247, 202, 187, 47, 141, 116, 66,
-            177, 6, 40, 55, 220, 91, 22, 62, 247, 198, 38, 1, 240, 105, 77, 190, 150, 130, 21, 1,
-            128, 8, 8, 128, 38, 208, 238, 212, 73, 211, 96, 63, 30, 38, 220, 41, 141, 114, 159,
-            158, 93, 128, 48, 155, 19, 143, 46, 25, 92, 53, 240, 51, 193, 98, 29, 124, 128, 38,
-            251, 103, 25, 165, 63, 88, 244, 76, 148, 77, 17, 43, 58, 235, 177, 190, 174, 146, 107,
-            199, 6, 133, 98, 28, 200, 31, 117, 100, 16, 8, 220, 21, 1, 128, 8, 8, 128, 152, 6, 119,
-            224, 149, 249, 110, 59, 103, 230, 162, 75, 41, 85, 157, 103, 237, 223, 42, 184, 88,
-            207, 110, 153, 19, 50, 141, 34, 205, 155, 236, 6, 128, 11, 99, 183, 82, 52, 33, 203,
-            27, 240, 179, 208, 179, 44, 222, 156, 19, 193, 147, 52, 2, 88, 21, 205, 40, 230, 254,
-            237, 40, 136, 22, 96, 236, 21, 1, 128, 8, 16, 128, 149, 65, 179, 70, 24, 61, 92, 33,
-            17, 102, 240, 131, 5, 35, 64, 32, 60, 89, 228, 255, 196, 184, 138, 52, 169, 102, 224,
-            164, 110, 205, 226, 146, 128, 30, 81, 172, 54, 71, 42, 219, 76, 62, 8, 121, 143, 130,
-            152, 170, 194, 50, 155, 170, 52, 220, 243, 206, 201, 139, 119, 125, 228, 180, 102, 112,
-            6, 21, 1, 128, 8, 16, 128, 166, 132, 119, 164, 242, 182, 214, 115, 227, 96, 46, 46, 25,
-            176, 239, 175, 183, 23, 45, 26, 227, 99, 8, 21, 143, 250, 80, 199, 16, 162, 236, 91,
-            128, 210, 157, 212, 221, 78, 39, 208, 191, 77, 41, 249, 135, 131, 80, 44, 113, 6, 134,
-            86, 217, 97, 86, 107, 105, 141, 17, 67, 11, 213, 141, 174, 201, 21, 1, 128, 8, 32, 128,
-            106, 213, 108, 221, 235, 169, 210, 224, 45, 158, 174, 49, 151, 192, 5, 169, 121, 216,
-            60, 185, 163, 124, 146, 193, 185, 50, 20, 224, 33, 254, 180, 30, 128, 54, 103, 254,
-            236, 254, 60, 140, 166, 9, 11, 121, 120, 126, 52, 214, 104, 47, 217, 236, 101, 6, 63,
-            54, 250, 49, 243, 36, 189, 142, 191, 50, 77, 21, 1, 128, 8, 128, 128, 61, 134, 77, 72,
-            175, 71, 130, 78, 38, 143, 86, 70, 12, 158, 178, 6, 58, 192, 83, 51, 243, 106, 24, 215,
-            230, 31, 225, 35, 80, 121, 122, 99, 128, 98, 75, 24, 173, 58, 140, 184, 149, 251, 58,
-            100, 70, 84, 150, 99, 159, 157, 94, 129, 87, 103, 81, 183, 179, 21, 226, 152, 166, 224,
-            122, 164, 0, 153, 1, 128, 8, 136, 128, 118, 61, 155, 200, 23, 166, 124, 184, 81, 77,
-            110, 50, 89, 185, 75, 14, 50, 16, 22, 249, 17, 246, 60, 170, 8, 96, 77, 141, 116, 215,
-            27, 81, 128, 33, 43, 230, 91, 108, 168, 18, 169, 144, 75, 112, 146, 147, 205, 116, 151,
-            252, 22, 180, 96, 31, 120, 18, 76, 203, 129, 140, 207, 243, 52, 249, 195, 128, 65, 198,
-            65, 64, 222, 63, 132, 177, 210, 60, 13, 29, 250, 3, 85, 144, 81, 164, 231, 195, 36, 15,
-            142, 223, 199, 138, 249, 185, 86, 189, 187, 246, 21, 1, 128, 9, 0, 128, 40, 71, 240,
-            20, 152, 158, 92, 130, 134, 246, 69, 4, 108, 143, 184, 113, 195, 74, 205, 88, 97, 206,
-            175, 65, 171, 220, 127, 225, 44, 226, 17, 192, 128, 152, 173, 56, 234, 248, 113, 248,
-            197, 27, 230, 174, 225, 68, 36, 30, 105, 212, 134, 128, 6, 151, 191, 77, 65, 166, 30,
-            135, 117, 67, 218, 198, 31, 21, 1, 128, 9, 0, 128, 196, 207, 11, 28, 182, 148, 126, 68,
-            115, 137, 171, 0, 63, 229, 49, 20, 255, 147, 57, 18, 222, 17, 211, 37, 54, 205, 176,
-            224, 120, 50, 140, 84, 128, 118, 60, 139, 203, 89, 246, 240, 147, 241, 237, 220, 106,
-            94, 218, 94, 213, 192, 130, 50, 98, 191, 76, 50, 198, 242, 71, 95, 148, 75, 111, 2,
-            146, 21, 1, 128, 9, 0, 128, 251, 146, 140, 240, 112, 66, 64, 60, 33, 53, 175, 248, 137,
-            193, 55, 207, 129, 212, 10, 28, 77, 0, 218, 149, 131, 103, 106, 197, 99, 17, 151, 7,
-            128, 36, 237, 198, 105, 60, 172, 84, 166, 51, 70, 61, 165, 51, 146, 43, 187, 56, 77,
-            226, 181, 227, 11, 250, 233, 41, 74, 92, 57, 239, 75, 106, 43, 29, 2, 128, 9, 80, 128,
-            82, 248, 188, 114, 113, 220, 134, 251, 58, 126, 74, 147, 170, 153, 245, 245, 241, 175,
-            175, 159, 242, 27, 142, 102, 225, 125, 202, 206, 34, 77, 45, 21, 128, 203, 245, 70,
-            149, 205, 148, 20, 105, 203, 61, 234, 56, 97, 130, 160, 21, 130, 49, 24, 18, 168, 218,
-            65, 54, 182, 188, 172, 231, 4, 124, 179, 78, 128, 72, 55, 239, 103, 21, 70, 29, 43, 92,
-            8, 79, 52, 108, 182, 156, 195, 126, 192, 200, 101, 87, 161, 91, 106, 65, 146, 132, 19,
-            15, 206, 67, 26, 128, 175, 67, 76, 20, 148, 130, 76, 243, 100, 125, 132, 32, 137, 246,
-            169, 16, 183, 131, 51, 6, 131, 118, 149, 148, 91, 42, 60, 124, 166, 162, 159, 246, 29,
-            2, 128, 10, 160, 128, 79, 139, 199, 50, 81, 101, 28, 166, 154, 176, 171, 0, 145, 221,
-            200, 73, 165, 38, 162, 31, 204, 187, 191, 116, 5, 15, 207, 55, 180, 37, 62, 60, 128,
-            99, 253, 250, 231, 77, 221, 174, 216, 149, 213, 239, 187, 40, 246, 163, 124, 99, 133,
-            62, 8, 129, 218, 226, 185, 21, 5, 146, 94, 17, 36, 119, 83, 128, 33, 202, 229, 16, 159,
-            250, 53, 191, 119, 230, 234, 70, 45, 198, 224, 146, 222, 29, 197, 98, 105, 181, 44, 85,
-            164, 113, 64, 243, 125, 222, 140, 216, 128, 245, 192, 212, 235, 190, 198, 60, 71, 180,
-            12, 243, 105, 105, 56, 73, 232, 11, 208, 155, 177, 130, 46, 119, 34, 123, 43, 25, 235,
-            121, 72, 98, 18, 161, 2, 128, 11, 66, 128, 68, 208, 153, 216, 120, 10, 47, 206, 208,
-            81, 223, 191, 140, 146, 54, 251, 104, 84, 47, 205, 247, 134, 173, 239, 63, 40, 89, 226,
-            246, 179, 162, 103, 128, 49, 122, 8, 197, 197, 134, 127, 105, 148, 80, 73, 148, 158,
-            131, 187, 254, 22, 73, 15, 13, 241, 217, 163, 150, 171, 195, 22, 177, 67, 214, 50, 152,
-            128, 156, 255, 104, 214, 72, 105, 154, 11, 59, 7, 216, 163, 203, 53, 77, 224, 209, 18,
-            230, 59, 47, 125, 14, 86, 167, 84, 206, 39, 88, 124, 30, 120, 128, 204, 133, 68, 233,
-            188, 243, 5, 243, 115, 83, 228, 44, 118, 130, 103, 17, 95, 255, 93, 232, 65, 226, 112,
-            180, 236, 220, 239, 167, 9, 179, 81, 208, 128, 160, 132, 21, 235, 7, 116, 147, 3, 80,
-            201, 40, 159, 35, 21, 246, 175, 71, 117, 38, 114, 171, 242, 7, 152, 73, 252, 230, 176,
-            163, 66, 10, 41, 21, 1, 128, 12, 0, 128, 102, 42, 182, 65, 39, 49, 198, 76, 210, 39,
-            207, 127, 21, 74, 108, 158, 106, 25, 11, 61, 17, 106, 121, 156, 205, 57, 30, 233, 28,
-            177, 6, 108, 128, 71, 207, 110, 153, 124, 15, 176, 125, 191, 124, 16, 253, 209, 54, 86,
-            58, 100, 91, 176, 77, 6, 82, 45, 162, 225, 61, 174, 144, 137, 58, 233, 103, 153, 1,
-            128, 12, 2, 128, 238, 137, 237, 115, 49, 164, 218, 12, 210, 155, 191, 14, 64, 100, 28,
-            89, 68, 22, 41, 5, 36, 244, 195, 253, 122, 3, 45, 20, 174, 230, 130, 168, 128, 67, 211,
-            156, 230, 184, 3, 172, 115, 156, 201, 25, 198, 245, 134, 141, 46, 176, 144, 168, 93,
-            204, 208, 84, 103, 232, 127, 128, 119, 85, 73, 242, 132, 128, 245, 172, 7, 230, 107,
-            246, 103, 139, 204, 44, 209, 95, 107, 145, 71, 96, 179, 166, 58, 114, 240, 232, 168, 7,
-            13, 226, 92, 2, 58, 9, 225, 1, 29, 2, 128, 12, 5, 128, 84, 200, 11, 192, 95, 88, 110,
-            51, 221, 222, 153, 60, 150, 144, 33, 16, 98, 160, 240, 216, 30, 170, 78, 242, 120, 165,
-            188, 76, 89, 25, 0, 32, 128, 151, 236, 76, 53, 60, 241, 156, 247, 50, 200, 52, 232, 95,
-            25, 22, 136, 197, 122, 140, 19, 107, 252, 193, 188, 181, 58, 178, 182, 63, 206, 223,
-            126, 128, 139, 27, 80, 18, 39, 196, 97, 248, 66, 228, 23, 170, 164, 213, 161, 98, 253,
-            1, 182, 43, 223, 163, 79, 81, 168, 196, 9, 227, 110, 45, 225, 80, 128, 18, 213, 212,
-            40, 110, 57, 222, 111, 198, 15, 108, 48, 89, 209, 85, 39, 14, 91, 165, 177, 241, 85, 8,
-            84, 62, 235, 199, 35, 163, 189, 26, 219, 21, 1, 128, 16, 4, 128, 15, 144, 166, 42, 5,
-            138, 144, 209, 170, 83, 95, 17, 45, 250, 214, 176, 58, 30, 64, 186, 147, 7, 40, 210,
-            214, 213, 154, 245, 170, 201, 67, 175, 128, 141, 15, 218, 26, 78, 55, 155, 251, 86,
-            130, 23, 85, 194, 135, 83, 5, 114, 193, 88, 210, 127, 152, 101, 177, 230, 116, 69, 150,
-            117, 91, 74, 35, 21, 1, 128, 16, 4, 128, 66, 77, 175, 112, 251, 211, 177, 238, 155, 24,
-            116, 53, 175, 53, 131, 220, 120, 158, 248, 8, 168, 125, 15, 42, 1, 80, 110, 155, 127,
-            51, 122, 85, 128, 146, 245, 126, 22, 223, 10, 148, 207, 144, 18, 229, 15, 133, 120, 72,
-            212, 238, 47, 45, 31, 83, 73, 79, 10, 91, 210, 0, 56, 146, 205, 37, 227, 21, 1, 128,
-            16, 4, 128, 133, 64, 65, 54, 62, 70, 245, 155, 97, 197, 88, 238, 136, 63, 45, 3, 134,
-            182, 185, 161, 65, 114, 13, 85, 122, 244, 81, 131, 182, 224, 58, 30, 128, 169, 82, 38,
-            159, 170, 53, 105, 119, 137, 115, 113, 85, 139, 7, 94, 193, 141, 45, 25, 207, 41, 244,
-            51, 86, 184, 185, 133, 56, 214, 39, 103, 74, 21, 1, 128, 16, 4, 128, 205, 110, 50, 252,
-            200, 21, 126, 83, 3, 224, 242, 123, 13, 96, 20, 73, 208, 207, 101, 65, 101, 227, 133,
-            152, 75, 154, 27, 13, 186, 68, 135, 170, 128, 165, 83, 156, 245, 87, 130, 128, 119, 25,
-            140, 125, 156, 161, 82, 127, 9, 76, 9, 22, 122, 232, 41, 176, 12, 58, 108, 129, 15,
-            221, 63, 243, 104, 21, 1, 128, 16, 8, 128, 157, 138, 224, 106, 211, 220, 100, 217, 64,
-            87, 198, 192, 12, 162, 7, 157, 143, 189, 49, 109, 9, 111, 187, 179, 119, 10, 254, 62,
-            126, 107, 49, 195, 128, 103, 60, 176, 147, 117, 30, 74, 228, 217, 27, 2, 49, 1, 56,
-            108, 247, 190, 76, 82, 244, 216, 77, 158, 118, 199, 151, 199, 213, 203, 40, 138, 0, 21,
-            1, 128, 16, 16, 128, 94, 127, 179, 50, 229, 176, 183, 170, 191, 74, 58, 38, 16, 120,
-            86, 221, 67, 2, 74, 98, 90, 190, 104, 223, 94, 46, 114, 123, 32, 170, 62, 196, 128, 72,
-            44, 226, 94, 234, 23, 28, 117, 14, 116, 19, 220, 19, 147, 208, 43, 222, 61, 141, 58,
-            185, 22, 232, 35, 105, 71, 197, 109, 215, 120, 93, 212, 21, 1, 128, 16, 32, 128, 209,
-            124, 186, 243, 148, 29, 142, 132, 27, 109, 89, 77, 68, 254, 122, 4, 68, 245, 96, 46,
-            222, 96, 59, 13, 61, 90, 218, 41, 64, 99, 68, 85, 128, 104, 215, 18, 97, 98, 158, 9,
-            143, 143, 161, 24, 133, 89, 124, 35, 234, 106, 82, 58, 72, 139, 212, 123, 234, 163, 9,
-            232, 16, 95, 189, 139, 175, 21, 1, 128, 16, 128, 128, 103, 157, 166, 42, 91, 83, 172,
-            237, 138, 205, 47, 223, 39, 207, 85, 122, 73, 52, 145, 250, 229, 208, 129, 7, 37, 72,
-            115, 62, 187, 99, 33, 90, 128, 191, 134, 206, 238, 236, 106, 237, 200, 46, 86, 166, 96,
-            85, 102, 237, 32, 46, 92, 18, 167, 8, 92, 127, 144, 87, 63, 190, 138, 111, 88, 227,
-            121, 21, 1, 128, 17, 0, 128, 27, 245, 25, 187, 146, 44, 141, 168, 233, 24, 160, 205,
-            169, 135, 250, 129, 184, 49, 225, 77, 144, 71, 136, 94, 248, 56, 179, 237, 174, 120,
-            23, 217, 128, 88, 213, 98, 110, 116, 217, 180, 87, 126, 140, 169, 204, 50, 210, 238,
-            152, 97, 107, 212, 177, 176, 170, 253, 96, 149, 130, 131, 180, 174, 36, 219, 20, 153,
-            1, 128, 17, 32, 128, 133, 158, 172, 201, 1, 133, 92, 92, 72, 123, 79, 18, 236, 10, 80,
-            88, 168, 71, 104, 36, 91, 249, 208, 124, 48, 78, 160, 232, 230, 226, 188, 92, 128, 60,
-            180, 65, 111, 126, 158, 180, 197, 76, 146, 39, 181, 133, 161, 205, 157, 162, 215, 155,
-            75, 27, 24, 72, 225, 148, 29, 90, 201, 30, 45, 17, 71, 128, 193, 64, 135, 72, 0, 139,
-            247, 86, 20, 87, 188, 169, 52, 17, 105, 118, 4, 154, 49, 31, 84, 73, 193, 150, 101,
-            208, 204, 224, 222, 14, 229, 92, 21, 1, 128, 18, 0, 128, 94, 158, 118, 126, 14, 8, 53,
-            253, 139, 136, 93, 55, 7, 72, 182, 151, 30, 6, 133, 176, 31, 149, 132, 217, 249, 249,
-            196, 220, 99, 202, 187, 122, 128, 214, 69, 158, 94, 245, 86, 203, 139, 244, 178, 120,
-            53, 192, 222, 241, 73, 122, 107, 174, 75, 90, 215, 169, 222, 47, 212, 120, 160, 171,
-            127, 90, 72, 21, 1, 128, 18, 0, 128, 154, 82, 100, 75, 100, 166, 245, 236, 185, 241,
-            28, 2, 118, 2, 148, 192, 91, 79, 50, 220, 156, 32, 154, 115, 112, 82, 85, 189, 195, 99,
-            207, 1, 128, 158, 15, 24, 44, 6, 133, 134, 157, 61, 37, 100, 174, 226, 56, 184, 166,
-            140, 212, 144, 64, 84, 139, 17, 172, 95, 23, 10, 25, 154, 188, 134, 159, 21, 1, 128,
-            24, 0, 128, 13, 197, 150, 232, 188, 35, 180, 94, 128, 169, 80, 7, 89, 135, 20, 112,
-            129, 72, 63, 231, 231, 146, 9, 197, 225, 141, 160, 109, 53, 67, 160, 221, 128, 222, 82,
-            62, 142, 112, 205, 31, 10, 185, 15, 185, 145, 130, 13, 54, 52, 226, 30, 207, 220, 10,
-            100, 29, 97, 235, 243, 142, 193, 161, 155, 5, 10, 161, 2, 128, 24, 21, 128, 128, 254,
-            7, 142, 15, 142, 234, 159, 98, 171, 119, 5, 218, 140, 17, 206, 84, 172, 69, 50, 12,
-            208, 4, 149, 244, 233, 238, 30, 216, 8, 197, 230, 128, 144, 101, 239, 210, 10, 14, 138,
-            40, 238, 221, 161, 134, 27, 176, 152, 6, 223, 124, 153, 144, 241, 146, 140, 60, 87,
-            227, 192, 189, 22, 26, 28, 189, 128, 42, 90, 18, 243, 219, 156, 140, 156, 49, 181, 181,
-            68, 224, 20, 96, 250, 11, 33, 87, 206, 115, 37, 73, 10, 250, 237, 89, 252, 251, 203,
-            177, 241, 128, 147, 211, 132, 15, 235, 91, 61, 211, 118, 142, 51, 95, 93, 249, 220,
-            202, 111, 66, 29, 63, 84, 29, 59, 200, 168, 169, 191, 153, 76, 27, 38, 125, 128, 113,
-            193, 219, 182, 55, 211, 135, 116, 59, 199, 10, 229, 113, 250, 30, 52, 250, 240, 89,
-            103, 202, 209, 141, 219, 7, 206, 39, 0, 9, 33, 93, 46, 29, 2, 128, 24, 129, 128, 234,
-            179, 133, 182, 63, 96, 44, 103, 224, 4, 163, 1, 163, 246, 169, 43, 62, 200, 127, 186,
-            133, 61, 127, 90, 2, 133, 109, 249, 228, 105, 241, 91, 128, 210, 56, 237, 113, 78, 200,
-            136, 18, 50, 105, 221, 34, 101, 103, 114, 148, 143, 38, 237, 103, 131, 73, 175, 101,
-            220, 40, 107, 230, 50, 121, 203, 178, 128, 0, 127, 123, 174, 251, 83, 119, 195, 96,
-            152, 60, 181, 42, 26, 202, 28, 104, 134, 130, 165, 35, 177, 232, 242, 139, 26, 2, 44,
-            14, 110, 6, 128, 128, 118, 63, 113, 242, 124, 231, 212, 8, 82, 183, 24, 24, 130, 47,
-            242, 244, 239, 77, 79, 143, 77, 92, 138, 40, 193, 232, 240, 172, 212, 219, 251, 253,
-            21, 1, 128, 32, 1, 128, 30, 218, 141, 47, 177, 18, 79, 115, 62, 144, 253, 130, 80, 216,
-            167, 189, 95, 225, 70, 209, 232, 145, 131, 192, 182, 122, 32, 213, 103, 101, 8, 104,
-            128, 22, 139, 97, 191, 41, 92, 230, 194, 29, 115, 128, 0, 62, 76, 107, 171, 218, 177,
-            11, 44, 71, 217, 186, 22, 22, 151, 197, 80, 243, 251, 81, 170, 21, 1, 128, 32, 8, 128,
-            135, 48, 156, 183, 214, 203, 8, 37, 30, 196, 225, 20, 176, 42, 193, 221, 233, 198, 252,
-            165, 6, 138, 1, 133, 155, 44, 232, 212, 63, 209, 199, 124, 128, 141, 29, 159, 39, 63,
-            36, 78, 152, 248, 37, 33, 128, 188, 135, 221, 44, 106, 25, 83, 97, 170, 78, 136, 239,
-            126, 234, 85, 29, 108, 247, 96, 125, 153, 1, 128, 32, 9, 128, 79, 207, 236, 205, 253,
-            184, 210, 119, 100, 147, 252, 242, 22, 100, 244, 124, 193, 183, 212, 219, 197, 179,
-            209, 54, 159, 247, 17, 121, 38, 13, 99, 251, 128, 244, 181, 22, 87, 215, 184, 153, 75,
-            220, 197, 84, 40, 111, 98, 49, 135, 204, 57, 47, 54, 52, 233, 35, 90, 168, 30, 253, 44,
-            234, 128, 36, 65, 128, 28, 221, 182, 188, 116, 62, 216, 177, 145, 80, 70, 104, 14, 247,
-            212, 212, 236, 183, 215, 108, 43, 142, 14, 194, 104, 13, 116, 110, 180, 159, 144, 46,
-            21, 1, 128, 32, 64, 128, 129, 244, 70, 89, 109, 38, 74, 56, 143, 69, 201, 95, 2, 6,
-            210, 50, 32, 38, 0, 108, 132, 172, 17, 89, 121, 57, 49, 123, 97, 237, 8, 219, 128, 112,
-            225, 35, 224, 141, 6, 92, 12, 25, 147, 77, 22, 5, 170, 173, 65, 111, 105, 66, 183, 146,
-            222, 222, 22, 118, 182, 231, 78, 190, 50, 105, 165, 21, 1, 128, 32, 64, 128, 152, 146,
-            187, 83, 177, 101, 61, 49, 70, 14, 189, 51, 71, 149, 126, 118, 245, 142, 141, 75, 145,
-            36, 65, 20, 93, 26, 252, 181, 59, 32, 44, 184, 128, 120, 239, 122, 49, 165, 174, 229,
-            116, 34, 136, 252, 74, 136, 87, 40, 243, 138, 21, 154, 110, 162, 191, 235, 216, 43, 67,
-            205, 213, 170, 4, 106, 92, 21, 1, 128, 32, 64, 128, 213, 21, 208, 135, 163, 102, 197,
-            155, 27, 127, 201, 146, 66, 229, 75, 92, 70, 224, 208, 139, 180, 112, 140, 150, 186,
-            175, 238, 190, 169, 146, 193, 247, 128, 3, 215, 253, 221, 138, 226, 134, 229, 65, 13,
-            247, 84, 168, 250, 109, 26, 148, 74, 32, 68, 21, 217, 251, 242, 56, 44, 217, 27, 251,
-            87, 121, 129, 21, 1, 128, 33, 0, 128, 120, 47, 101, 80, 63, 15, 203, 61, 170, 188, 158,
-            232, 117, 95, 216, 153, 189, 248, 14, 111, 227, 6, 214, 125, 116, 51, 9, 250, 224, 47,
-            182, 29, 128, 188, 227, 217, 219, 132, 201, 188, 54, 14, 250, 146, 17, 110, 203, 30,
-            63, 120, 59, 48, 71, 63, 183, 43, 214, 246, 235, 92, 28, 37, 229, 229, 4, 161, 2, 128,
-            33, 21, 128, 115, 93, 252, 132, 119, 249, 35, 77, 32, 226, 220, 123, 22, 218, 160, 52,
-            163, 240, 111, 17, 163, 212, 163, 84, 206, 117, 142, 202, 152, 53, 134, 131, 128, 239,
-            158, 114, 146, 71, 228, 89, 47, 80, 68, 28, 83, 23, 19, 147, 114, 180, 3, 221, 74, 28,
-            138, 56, 17, 119, 44, 110, 203, 20, 137, 24, 3, 128, 236, 103, 44, 112, 160, 220, 208,
-            8, 46, 222, 200, 245, 168, 150, 83, 180, 184, 144, 183, 22, 181, 100, 14, 245, 173, 21,
-            137, 109, 230, 67, 77, 43, 128, 41, 20, 242, 111, 84, 45, 180, 25, 1, 125, 150, 182,
-            15, 79, 225, 24, 54, 60, 34, 23, 47, 83, 59, 238, 26, 117, 8, 58, 240, 86, 146, 253,
-            128, 237, 213, 64, 26, 54, 65, 248, 26, 133, 188, 101, 110, 242, 52, 180, 163, 41, 171,
-            241, 140, 149, 80, 191, 114, 95, 180, 40, 222, 192, 59, 147, 113, 29, 2, 128, 33, 144,
-            128, 174, 197, 70, 203, 116, 144, 71, 233, 40, 105, 28, 94, 192, 43, 50, 196, 14, 97,
-            237, 191, 207, 102, 15, 248, 3, 0, 209, 117, 109, 179, 94, 168, 128, 83, 92, 14, 188,
-            59, 5, 218, 255, 32, 71, 164, 99, 38, 225, 43, 167, 35, 34, 118, 171, 88, 110, 170, 99,
-            135, 103, 110, 174, 6, 193, 179, 218, 128, 108, 214, 166, 241, 29, 208, 140, 82, 222,
-            167, 47, 11, 1, 99, 61, 200, 131, 125, 249, 176, 173, 183, 42, 254, 71, 100, 254, 140,
-            117, 163, 200, 135, 128, 105, 18, 41, 255, 230, 214, 179, 127, 128, 55, 100, 158, 52,
-            232, 184, 66, 21, 158, 144, 120, 209, 31, 122, 30, 250, 88, 38, 4, 193, 96, 50, 235,
-            21, 1, 128, 34, 0, 128, 228, 234, 94, 106, 205, 181, 185, 183, 238, 118, 140, 254, 9,
-            73, 183, 14, 40, 141, 219, 255, 68, 250, 192, 108, 176, 211, 91, 239, 84, 46, 248, 131,
-            128, 71, 152, 216, 226, 7, 202, 240, 36, 84, 225, 188, 171, 166, 150, 142, 99, 100,
-            146, 234, 253, 162, 152, 36, 245, 156, 167, 44, 92, 141, 19, 111, 208, 37, 3, 128, 35,
-            67, 128, 173, 109, 185, 76, 139, 168, 234, 212, 53, 41, 239, 243, 132, 148, 129, 152,
-            54, 228, 56, 233, 100, 88, 9, 44, 166, 83, 33, 4, 215, 91, 140, 242, 128, 15, 108, 176,
-            93, 114, 58, 109, 136, 49, 113, 213, 225, 29, 58, 0, 250, 96, 197, 247, 5, 175, 95,
-            211, 129, 20, 83, 192, 19, 241, 16, 129, 243, 128, 146, 173, 244, 216, 171, 214, 126,
-            199, 244, 127, 156, 201, 177, 124, 57, 205, 227, 253, 80, 67, 189, 168, 89, 82, 105,
-            227, 56, 70, 155, 77, 84, 227, 128, 215, 14, 158, 78, 135, 14, 15, 46, 94, 114, 96,
-            211, 76, 227, 153, 209, 243, 115, 187, 7, 16, 122, 230, 237, 13, 168, 219, 72, 50, 254,
-            231, 253, 128, 182, 78, 125, 146, 180, 18, 17, 237, 66, 10, 102, 244, 117, 226, 88,
-            220, 206, 120, 170, 3, 163, 237, 194, 138, 91, 228, 245, 182, 69, 245, 255, 63, 128,
-            72, 200, 184, 80, 9, 117, 215, 207, 249, 233, 90, 55, 199, 178, 173, 186, 128, 67, 173,
-            244, 83, 60, 22, 39, 86, 204, 39, 51, 240, 228, 184, 229, 21, 1, 128, 36, 0, 128, 175,
-            74, 29, 188, 169, 32, 155, 144, 90, 51, 156, 42, 184, 189, 251, 108, 35, 135, 68, 114,
-            225, 192, 204, 97, 125, 2, 207, 49, 232, 92, 166, 56, 128, 223, 112, 1, 170, 248, 13,
-            39, 203, 193, 48, 188, 23, 182, 148, 204, 174, 76, 232, 170, 33, 89, 249, 45, 198, 59,
-            129, 51, 130, 186, 237, 149, 248, 29, 2, 128, 40, 36, 128, 68, 13, 175, 73, 82, 163,
-            15, 59, 204, 0, 104, 19, 62, 86, 240, 212, 53, 36, 126, 106, 56, 154, 20, 205, 87, 197,
-            187, 214, 199, 199, 119, 41, 128, 3, 48, 245, 208, 26, 149, 243, 133, 84, 40, 79, 160,
-            147, 170, 45, 41, 119, 70, 118, 105, 0, 143, 168, 236, 231, 12, 203, 131, 224, 218,
-            222, 144, 128, 90, 137, 26, 133, 192, 216, 222, 235, 192, 201, 158, 237, 31, 56, 9,
-            179, 254, 218, 139, 99, 27, 18, 231, 164, 81, 32, 23, 230, 12, 182, 13, 193, 128, 156,
-            192, 43, 122, 26, 8, 120, 14, 234, 4, 86, 63, 109, 63, 1, 1, 156, 128, 132, 249, 244,
-            11, 230, 219, 195, 101, 174, 212, 84, 245, 215, 120, 161, 2, 128, 40, 37, 128, 216, 90,
-            214, 108, 84, 19, 74, 228, 40, 166, 192, 80, 213, 244, 134, 19, 190, 40, 28, 181, 222,
-            254, 130, 32, 139, 104, 198, 7, 6, 61, 45, 145, 128, 202, 49, 137, 194, 40, 200, 67,
-            226, 131, 26, 105, 147, 133, 64, 100, 186, 73, 31, 215, 96, 165, 66, 130, 154, 17, 55,
-            3, 58, 63, 53, 5, 206, 128, 214, 189, 116, 112, 111, 230, 123, 255, 26, 61, 194, 111,
-            252, 66, 164, 152, 32, 240, 197, 37, 222, 84, 46, 223, 211, 107, 140, 224, 160, 87, 97,
-            5, 128, 32, 19, 58, 231, 46, 106, 223, 115, 169, 164, 172, 218, 148, 202, 66, 120, 130,
-            198, 208, 128, 38, 164, 120, 127, 145, 163, 79, 113, 209, 77, 194, 250, 128, 11, 7,
-            103, 50, 56, 132, 43, 39, 127, 69, 209, 36, 99, 112, 55, 121, 176, 163, 250, 161, 42,
-            171, 177, 99, 46, 76, 6, 17, 175, 208, 129, 15, 153, 1, 128, 40, 128, 128, 169, 84, 49,
-            4, 245, 35, 153, 63, 26, 186, 175, 254, 154, 165, 124, 142, 228, 201, 47, 157, 39, 137,
-            29, 180, 66, 93, 166, 142, 149, 188, 153, 69, 128, 204, 216, 193, 220, 231, 214, 34,
-            48, 114, 124, 253, 67, 234, 76, 50, 126, 138, 254, 176, 87, 155, 97, 5, 47, 108, 198,
-            255, 77, 88, 255, 207, 167, 128, 208, 22, 113, 11, 156, 85, 57, 157, 74, 242, 122, 11,
-            246, 113, 137, 252, 180, 241, 39, 61, 196, 48, 199, 34, 221, 41, 124, 105, 156, 143,
-            74, 66, 161, 2, 128, 40, 200, 128, 164, 110, 94, 80, 218, 5, 224, 50, 92, 234, 116, 43,
-            233, 237, 219, 242, 107, 198, 241, 248, 36, 227, 82, 82, 17, 166, 79, 217, 224, 104,
-            40, 186, 128, 43, 214, 145, 176, 25, 3, 147, 159, 93, 219, 240, 156, 231, 14, 63, 144,
-            53, 128, 68, 93, 205, 218, 55, 150, 253, 123, 150, 202, 151, 218, 25, 167, 128, 69,
-            236, 230, 138, 92, 143, 199, 129, 48, 208, 195, 14, 135, 127, 114, 24, 173, 214, 142,
-            11, 92, 200, 54, 218, 105, 100, 131, 22, 198, 177, 113, 107, 128, 199, 106, 134, 1,
-            231, 227, 114, 179, 77, 233, 198, 180, 45, 206, 12, 162, 0, 173, 56, 219, 118, 144, 38,
-            88, 203, 224, 216, 254, 4, 21, 103, 243, 128, 41, 107, 163, 85, 162, 80, 3, 65, 107,
-            132, 44, 180, 105, 190, 250, 228, 63, 20, 53, 178, 67, 206, 97, 97, 86, 36, 57, 45,
-            237, 39, 239, 240, 161, 2, 128, 54, 4, 128, 198, 103, 98, 149, 72, 209, 158, 203, 169,
-            62, 137, 202, 241, 184, 141, 161, 154, 247, 132, 113, 227, 23, 88, 165, 23, 103, 64, 7,
-            156, 198, 234, 138, 128, 16, 245, 139, 202, 188, 139, 113, 157, 38, 34, 72, 67, 249,
-            107, 251, 196, 86, 238, 87, 46, 182, 230, 83, 143, 186, 140, 86, 5, 49, 2, 85, 129,
-            128, 129, 62, 161, 3, 116, 9, 199, 55, 40, 172, 67, 159, 153, 102, 3, 240, 65, 162, 40,
-            111, 89, 136, 131, 156, 51, 83, 115, 149, 16, 190, 189, 150, 128, 188, 153, 105, 218,
-            57, 172, 43, 26, 248, 0, 70, 190, 91, 167, 32, 98, 177, 99, 170, 236, 177, 85, 42, 118,
-            198, 25, 236, 137, 112, 23, 197, 220, 128, 44, 234, 17, 69, 195, 36, 138, 80, 246, 228,
-            207, 239, 94, 172, 207, 197, 65, 171, 116, 234, 37, 147, 10, 198, 118, 252, 48, 71,
-            209, 229, 245, 155, 37, 3, 128, 62, 4, 128, 36, 11, 29, 47, 223, 80, 137, 190, 187,
-            212, 36, 37, 58, 56, 66, 101, 180, 252, 152, 21, 58, 166, 241, 79, 189, 237, 231, 139,
-            131, 222, 185, 203, 128, 149, 211, 52, 164, 218, 56, 82, 207, 132, 88, 34, 112, 154,
-            40, 179, 158, 29, 78, 218, 16, 49, 45, 145, 21, 93, 23, 183, 200, 178, 35, 185, 84,
-            128, 176, 60, 170, 39, 81, 162, 211, 137, 40, 50, 167, 212, 153, 222, 224, 152, 88,
-            189, 17, 212, 163, 99, 205, 35, 255, 205, 81, 187, 248, 48, 30, 115, 128, 189, 188,
-            131, 61, 209, 19, 69, 45, 233, 239, 164, 231, 202, 206, 192, 128, 94, 221, 152, 172,
-            38, 140, 28, 93, 162, 71, 180, 182, 246, 76, 47, 89, 128, 40, 102, 115, 56, 45, 33, 88,
-            164, 213, 228, 40, 199, 85, 48, 144, 168, 65, 39, 124, 29, 30, 148, 219, 248, 71, 93,
-            198, 100, 67, 60, 158, 156, 128, 47, 246, 70, 62, 251, 25, 29, 196, 149, 12, 238, 78,
-            200, 225, 248, 106, 21, 55, 104, 250, 160, 135, 146, 192, 233, 108, 116, 122, 73, 17,
-            239, 19, 21, 1, 128, 64, 1, 128, 164, 160, 48, 127, 25, 6, 116, 182, 205, 171, 69, 135,
-            24, 14, 206, 8, 100, 111, 112, 204, 4, 202, 12, 122, 213, 84, 43, 252, 235, 95, 55, 85,
-            128, 23, 193, 234, 50, 17, 185, 30, 242, 164, 21, 162, 182, 112, 93, 241, 237, 64, 28,
-            134, 72, 97, 123, 55, 210, 219, 104, 183, 28, 19, 247, 27, 194, 21, 1, 128, 64, 2, 128,
-            144, 67, 117, 162, 108, 202, 133, 226, 18, 112, 192, 196, 73, 56, 114, 246, 122, 147,
-            38, 145, 169, 65, 123, 158, 24, 134, 9, 148, 113, 151, 68, 126, 128, 193, 211, 58, 110,
-            212, 170, 234, 166, 125, 88, 226, 204, 151, 122, 94, 221, 226, 133, 118, 246, 164, 6,
-            167, 229, 198, 213, 255, 236, 221, 152, 105, 219, 29, 2, 128, 64, 7, 128, 84, 190, 66,
-            200, 168, 30, 83, 68, 84, 114, 19, 72, 220, 254, 53, 208, 197, 132, 4, 110, 185, 237,
-            201, 176, 134, 20, 87, 253, 106, 47, 142, 52, 128, 247, 65, 137, 241, 231, 252, 85,
-            157, 158, 172, 241, 231, 53, 188, 36, 127, 157, 27, 75, 175, 95, 115, 137, 164, 179,
-            78, 219, 2, 98, 228, 182, 50, 128, 11, 119, 177, 200, 187, 249, 149, 80, 48, 228, 110,
-            125, 164, 187, 201, 142, 90, 133, 111, 20, 82, 56, 42, 74, 98, 206, 111, 108, 104, 212,
-            137, 106, 128, 238, 76, 255, 193, 14, 226, 95, 35, 26, 64, 235, 12, 5, 119, 196, 138,
-            42, 57, 48, 66, 139, 251, 231, 115, 114, 137, 132, 128, 21, 250, 226, 195, 153, 1, 128,
-            64, 33, 128, 142, 133, 154, 131, 95, 55, 120, 71, 115, 47, 209, 22, 134, 203, 94, 73,
-            66, 235, 110, 101, 186, 123, 202, 4, 224, 233, 186, 74, 109, 148, 102, 72, 128, 248, 0,
-            3, 184, 161, 79, 9, 107, 220, 44, 205, 166, 55, 236, 188, 2, 22, 38, 13, 69, 240, 65,
-            15, 191, 53, 40, 254, 51, 103, 51, 93, 132, 128, 104, 54, 127, 239, 128, 10, 241, 52,
-            255, 26, 138, 63, 131, 94, 225, 64, 208, 200, 200, 250, 249, 46, 29, 121, 191, 164,
-            210, 189, 200, 118, 57, 114, 21, 1, 128, 64, 64, 128, 127, 243, 53, 18, 48, 184, 60,
-            92, 163, 49, 67, 206, 211, 195, 11, 14, 217, 190, 96, 196, 188, 206, 56, 42, 9, 104,
-            121, 255, 217, 35, 1, 61, 128, 131, 113, 74, 112, 140, 65, 108, 88, 220, 59, 32, 21,
-            62, 126, 139, 17, 121, 120, 227, 189, 59, 97, 49, 112, 88, 40, 188, 19, 241, 243, 16,
-            89, 29, 2, 128, 64, 73, 128, 118, 233, 240, 87, 66, 188, 104, 191, 64, 72, 156, 81,
-            244, 32, 108, 84, 157, 89, 125, 9, 107, 149, 191, 160, 156, 167, 89, 94, 100, 227, 71,
-            116, 128, 83, 213, 9, 42, 191, 27, 146, 25, 243, 226, 171, 21, 120, 233, 143, 71, 24,
-            54, 20, 119, 168, 105, 26, 112, 107, 246, 174, 177, 100, 253, 66, 50, 128, 174, 182,
-            178, 44, 24, 138, 206, 25, 57, 104, 138, 33, 166, 87, 33, 149, 99, 236, 230, 160, 37,
-            166, 217, 145, 110, 11, 49, 178, 149, 44, 103, 121, 128, 63, 253, 131, 42, 186, 207,
-            80, 128, 98, 7, 254, 173, 155, 183, 105, 61, 153, 115, 223, 145, 67, 164, 66, 122, 249,
-            67, 62, 113, 227, 171, 16, 72, 161, 2, 128, 64, 149, 128, 137, 116, 180, 121, 17, 15,
-            168, 9, 247, 151, 187, 167, 141, 105, 171, 32, 31, 22, 160, 156, 156, 5, 157, 37, 221,
-            131, 57, 42, 33, 55, 189, 77, 128, 164, 232, 207, 131, 71, 2, 246, 209, 245, 245, 133,
-            56, 22, 85, 159, 184, 148, 109, 95, 102, 199, 228, 3, 92, 231, 206, 87, 195, 14, 19,
-            212, 217, 128, 77, 41, 72, 124, 154, 181, 220, 141, 140, 185, 5, 99, 103, 252, 77, 207,
-            252, 72, 13, 43, 88, 55, 64, 198, 176, 135, 38, 137, 199, 92, 90, 16, 128, 177, 178,
-            214, 86, 169, 3, 107, 1, 99, 224, 152, 108, 69, 166, 222, 2, 16, 187, 200, 12, 222,
-            145, 7, 37, 101, 195, 201, 243, 84, 59, 10, 100, 128, 208, 3, 203, 87, 84, 1, 192, 115,
-            180, 255, 31, 248, 210, 180, 48, 0, 155, 83, 253, 54, 145, 239, 171, 252, 184, 192,
-            185, 168, 198, 201, 118, 58, 21, 1, 128, 65, 0, 128, 120, 178, 227, 124, 11, 96, 199,
-            154, 190, 19, 183, 180, 246, 113, 63, 20, 104, 221, 197, 116, 209, 173, 231, 128, 212,
-            244, 33, 159, 125, 154, 238, 231, 128, 106, 77, 141, 30, 119, 77, 200, 215, 68, 172,
-            109, 215, 93, 136, 92, 72, 59, 221, 203, 189, 64, 77, 25, 2, 150, 21, 162, 34, 67, 229,
-            141, 88, 21, 1, 128, 66, 0, 128, 10, 42, 9, 72, 0, 254, 10, 117, 78, 11, 174, 32, 137,
-            3, 227, 152, 184, 136, 111, 50, 124, 52, 193, 149, 200, 191, 169, 49, 46, 59, 85, 117,
-            128, 128, 180, 150, 104, 14, 56, 127, 153, 72, 162, 85, 4, 110, 116, 23, 36, 222, 63,
-            44, 61, 179, 10, 251, 45, 208, 97, 179, 251, 105, 233, 65, 7, 21, 1, 128, 68, 0, 128,
-            45, 149, 77, 92, 252, 130, 214, 55, 92, 1, 8, 63, 106, 151, 222, 182, 55, 32, 193, 6,
-            169, 251, 206, 210, 117, 172, 161, 122, 242, 40, 104, 193, 128, 3, 184, 112, 187, 142,
-            41, 38, 181, 162, 102, 190, 236, 198, 125, 17, 160, 23, 20, 21, 131, 174, 220, 97, 67,
-            74, 21, 240, 58, 8, 152, 36, 2, 21, 1, 128, 72, 0, 128, 161, 45, 89, 216, 201, 44, 25,
-            233, 57, 104, 76, 73, 196, 38, 145, 211, 85, 122, 45, 79, 236, 165, 61, 46, 250, 151,
-            134, 90, 27, 250, 153, 227, 128, 50, 74, 208, 62, 31, 231, 0, 246, 90, 242, 84, 101,
-            177, 121, 95, 5, 82, 192, 169, 125, 140, 199, 31, 73, 86, 89, 188, 42, 23, 94, 231, 77,
-            21, 1, 128, 72, 0, 128, 197, 101, 124, 144, 61, 24, 111, 57, 242, 165, 192, 232, 2,
-            172, 3, 173, 100, 59, 225, 212, 239, 16, 102, 199, 249, 211, 18, 65, 177, 183, 139, 14,
-            128, 202, 254, 89, 181, 236, 91, 178, 159, 216, 23, 193, 199, 202, 112, 232, 59, 248,
-            108, 11, 224, 121, 244, 163, 64, 245, 193, 141, 92, 122, 221, 112, 239, 29, 2, 128, 74,
-            4, 128, 13, 246, 159, 94, 146, 188, 191, 215, 193, 136, 42, 21, 92, 222, 123, 85, 52,
-            147, 75, 123, 122, 12, 109, 45, 111, 54, 127, 131, 186, 247, 15, 124, 128, 218, 213,
-            240, 74, 192, 213, 6, 126, 73, 13, 242, 216, 52, 26, 212, 191, 68, 201, 24, 67, 186,
-            147, 17, 105, 129, 106, 68, 152, 166, 197, 194, 251, 128, 190, 13, 223, 15, 36, 16,
-            241, 209, 83, 233, 2, 211, 44, 142, 207, 186, 222, 232, 0, 101, 179, 74, 210, 76, 25,
-            115, 226, 38, 226, 45, 239, 221, 128, 191, 226, 131, 155, 48, 119, 31, 71, 39, 17, 169,
-            64, 240, 88, 67, 214, 53, 208, 25, 19, 17, 147, 119, 244, 65, 34, 13, 64, 164, 227, 46,
-            64, 169, 3, 128, 75, 7, 128, 145, 110, 172, 5, 89, 221, 130, 69, 125, 25, 234, 15, 85,
-            197, 156, 128, 218, 115, 69, 3, 232, 104, 172, 175, 142, 53, 146, 54, 181, 19, 116,
-            213, 128, 103, 182, 169, 228, 112, 90, 85, 255, 178, 80, 34, 187, 5, 198, 192, 30, 213,
-            144, 26, 190, 232, 79, 235, 132, 70, 38, 171, 22, 195, 104, 71, 27, 128, 7, 121, 148,
-            102, 63, 139, 161, 237, 70, 110, 134, 179, 52, 15, 185, 167, 122, 244, 105, 213, 114,
-            85, 80, 164, 181, 96, 112, 97, 252, 10, 232, 20, 128, 104, 49, 107, 69, 172, 6, 243,
-            148, 42, 60, 217, 168, 232, 176, 114, 117, 54, 103, 11, 113, 211, 217, 81, 234, 22,
-            120, 47, 156, 0, 68, 181, 127, 128, 158, 96, 88, 99, 235, 203, 8, 3, 76, 96, 153, 126,
-            103, 148, 180, 65, 40, 255, 159, 37, 103, 36, 253, 239, 51, 46, 253, 220, 224, 5, 224,
-            89, 128, 230, 132, 84, 213, 242, 170, 185, 74, 49, 148, 95, 99, 204, 116, 187, 37, 86,
-            62, 19, 135, 89, 107, 19, 87, 217, 16, 26, 224, 40, 112, 15, 80, 128, 133, 44, 185,
-            159, 53, 212, 219, 105, 4, 209, 106, 73, 249, 141, 172, 187, 5, 209, 180, 161, 247,
-            194, 189, 42, 126, 119, 55, 202, 106, 19, 47, 224, 161, 2, 128, 76, 130, 128, 146, 216,
-            85, 172, 53, 95, 184, 44, 165, 48, 3, 165, 55, 153, 168, 220, 14, 54, 130, 172, 142,
-            144, 61, 110, 38, 141, 26, 167, 53, 148, 178, 164, 128, 29, 90, 113, 38, 192, 68, 23,
-            9, 53, 251, 180, 109, 77, 228, 61, 144, 99, 28, 87, 91, 91, 235, 175, 112, 122, 63,
-            140, 3, 192, 73, 218, 191, 128, 243, 160, 153, 34, 204, 102, 190, 222, 97, 60, 72, 129,
-            52, 220, 225, 177, 27, 8, 115, 1, 141, 68, 50, 180, 251, 79, 48, 189, 160, 119, 167,
-            181, 128, 86, 176, 169, 22, 117, 91, 22, 2, 162, 99, 190, 136, 83, 26, 27, 130, 123,
-            223, 255, 61, 109, 54, 129, 160, 14, 228, 84, 242, 192, 9, 12, 61, 128, 179, 85, 60,
-            129, 65, 78, 40, 12, 162, 56, 110, 136, 78, 120, 9, 42, 181, 80, 15, 211, 18, 223, 106,
-            120, 41, 162, 110, 238, 196, 70, 37, 163, 21, 1, 128, 80, 0, 128, 118, 67, 27, 31, 229,
-            58, 60, 88, 39, 33, 181, 111, 131, 10, 19, 95, 19, 1, 235, 42, 213, 75, 251, 174, 102,
-            49, 104, 110, 56, 147, 150, 245, 128, 61, 91, 205, 128, 135, 221, 207, 179, 4, 246, 84,
-            11, 139, 170, 93, 170, 98, 43, 229, 115, 155, 131, 145, 248, 158, 125, 179, 180, 195,
-            230, 97, 32, 161, 2, 128, 80, 100, 128, 39, 63, 234, 110, 14, 74, 205, 56, 71, 30, 146,
-            173, 17, 255, 71, 194, 53, 2, 40, 246, 49, 3, 200, 87, 60, 12, 174, 3, 173, 232, 91,
-            75, 128, 106, 229, 188, 61, 215, 82, 61, 241, 83, 228, 209, 90, 90, 108, 97, 188, 193,
-            19, 51, 127, 22, 65, 217, 243, 130, 144, 99, 124, 11, 64, 50, 154, 128, 165, 225, 155,
-            46, 25, 48, 186, 139, 231, 131, 131, 104, 138, 213, 226, 12, 85, 196, 56, 188, 172,
-            219, 66, 227, 7, 184, 122, 206, 244, 217, 95, 203, 128, 226, 61, 190, 51, 27, 20, 129,
-            141, 145, 191, 146, 104, 112, 127, 14, 243, 219, 240, 92, 28, 240, 164, 140, 202, 177,
-            83, 242, 216, 184, 254, 187, 49, 128, 45, 103, 242, 215, 209, 38, 239, 115, 143, 125,
-            133, 0, 242, 245, 230, 151, 97, 127, 198, 197, 207, 68, 181, 189, 235, 243, 2, 119,
-            147, 29, 232, 236, 29, 2, 128, 88, 4, 128, 209, 166, 194, 156, 74, 63, 96, 67, 124, 17,
-            177, 209, 242, 253, 158, 94, 191, 33, 82, 28, 186, 39, 23, 90, 47, 128, 137, 29, 189,
-            80, 115, 154, 128, 144, 220, 234, 16, 35, 73, 16, 19, 39, 193, 127, 112, 222, 83, 124,
-            69, 15, 103, 134, 152, 56, 241, 93, 250, 110, 212, 148, 131, 168, 93, 87, 68, 128, 194,
-            214, 231, 38, 178, 228, 88, 96, 73, 3, 20, 163, 187, 185, 36, 64, 29, 94, 212, 175,
-            134, 26, 180, 189, 215, 80, 242, 201, 118, 223, 175, 104, 128, 147, 127, 179, 31, 215,
-            127, 43, 166, 124, 197, 176, 96, 121, 3, 30, 194, 29, 152, 42, 63, 132, 242, 56, 29,
-            177, 2, 59, 157, 106, 100, 59, 88, 161, 2, 128, 88, 36, 128, 127, 75, 172, 70, 230,
-            162, 15, 220, 166, 18, 251, 117, 175, 189, 33, 18, 88, 194, 133, 240, 56, 6, 24, 185,
-            69, 100, 49, 220, 246, 137, 122, 161, 128, 33, 182, 203, 76, 50, 195, 54, 201, 37, 15,
-            171, 91, 195, 125, 94, 45, 51, 122, 82, 195, 41, 61, 248, 7, 225, 173, 209, 153, 82,
-            138, 100, 215, 128, 176, 94, 194, 60, 186, 120, 95, 101, 113, 242, 194, 253, 120, 187,
-            6, 194, 28, 107, 69, 149, 19, 141, 111, 199, 191, 80, 110, 102, 221, 26, 96, 14, 128,
-            19, 96, 232, 153, 96, 228, 173, 151, 114, 115, 151, 162, 72, 137, 252, 115, 186, 39,
-            117, 166, 201, 187, 249, 84, 133, 20, 176, 103, 174, 135, 186, 26, 128, 220, 71, 250,
-            95, 82, 92, 121, 191, 47, 232, 57, 35, 66, 122, 245, 170, 83, 13, 132, 172, 11, 94, 36,
-            85, 74, 161, 24, 205, 99, 91, 206, 174, 21, 1, 128, 96, 0, 128, 159, 224, 152, 91, 85,
-            58, 120, 77, 126, 158, 78, 222, 12, 63, 23, 174, 19, 24, 132, 234, 254, 100, 168, 16,
-            119, 193, 81, 33, 84, 145, 253, 216, 128, 76, 106, 89, 245, 47, 41, 72, 11, 113, 19,
-            37, 199, 3, 80, 1, 38, 164, 254, 98, 203, 198, 2, 208, 61, 40, 163, 229, 190, 8, 226,
-            129, 201, 153, 1, 128, 96, 16, 128, 56, 218, 99, 176, 18, 235, 65, 126, 233, 235, 222,
-            45, 149, 159, 64, 234, 244, 255, 49, 136, 164, 47, 42, 124, 40, 153, 108, 36, 229, 95,
-            179, 183, 128, 38, 61, 43, 77, 133, 2, 144, 166, 102, 115, 46, 46, 194, 103, 196, 133,
-            223, 102, 165, 162, 148, 58, 228, 59, 40, 237, 253, 201, 3, 29, 192, 115, 128, 204,
-            244, 29, 1, 131, 82, 190, 157, 130, 204, 191, 47, 234, 243, 96, 63, 83, 214, 24, 202,
-            241, 204, 211, 229, 234, 68, 87, 49, 104, 226, 45, 151, 29, 2, 128, 96, 36, 128, 170,
-            19, 237, 40, 151, 134, 247, 214, 15, 157, 156, 46, 172, 109, 141, 125, 63, 58, 136, 66,
-            223, 196, 36, 196, 175, 34, 239, 217, 29, 115, 56, 72, 128, 185, 100, 226, 115, 142,
-            44, 10, 132, 15, 59, 230, 226, 19, 111, 66, 60, 196, 190, 70, 156, 227, 71, 97, 223,
-            245, 85, 205, 76, 33, 164, 22, 30, 128, 215, 148, 100, 192, 28, 203, 102, 174, 139,
-            174, 149, 16, 0, 249, 105, 247, 33, 122, 204, 158, 214, 21, 132, 197, 47, 132, 253,
-            128, 27, 242, 51, 102, 128, 137, 162, 236, 191, 232, 64, 249, 240, 59, 223, 128, 146,
-            22, 175, 88, 156, 251, 36, 151, 164, 104, 254, 0, 126, 188, 77, 161, 167, 129, 91, 119,
-            24, 161, 2, 128, 102, 16, 128, 195, 24, 127, 114, 196, 12, 212, 133, 156, 131, 68, 52,
-            186, 121, 245, 206, 206, 253, 93, 47, 243, 237, 7, 199, 122, 136, 203, 44, 208, 55, 57,
-            177, 128, 230, 18, 203, 148, 54, 243, 240, 178, 125, 170, 196, 123, 165, 32, 172, 45,
-            236, 201, 207, 215, 183, 37, 141, 254, 104, 233, 234, 0, 155, 37, 13, 169, 128, 200,
-            185, 135, 124, 237, 203, 8, 45, 45, 37, 118, 135, 163, 60, 194, 91, 75, 94, 167, 158,
-            142, 103, 98, 248, 155, 98, 74, 228, 193, 32, 221, 222, 128, 238, 185, 90, 178, 116,
-            31, 62, 118, 32, 76, 87, 75, 94, 114, 90, 248, 222, 176, 208, 121, 32, 209, 24, 215,
-            58, 179, 26, 249, 171, 71, 183, 165, 128, 81, 68, 255, 76, 195, 242, 230, 48, 55, 52,
-            67, 148, 57, 201, 16, 188, 239, 1, 44, 174, 46, 147, 53, 61, 113, 118, 171, 52, 190,
-            156, 254, 45, 37, 3, 128, 102, 66, 128, 132, 82, 100, 77, 235, 27, 39, 120, 123, 40,
-            201, 93, 123, 227, 62, 248, 136, 240, 219, 20, 63, 16, 104, 70, 130, 234, 50, 82, 86,
-            243, 88, 21, 128, 204, 212, 233, 173, 184, 104, 146, 165, 37, 213, 40, 222, 108, 233,
-            67, 76, 227, 154, 166, 201, 223, 135, 56, 218, 37, 87, 100, 38, 181, 231, 195, 45, 128,
-            212, 46, 152, 91, 217, 127, 246, 31, 122, 123, 99, 174, 5, 36, 201, 160, 140, 49, 188,
-            171, 173, 0, 10, 91, 86, 219, 132, 235, 151, 142, 102, 204, 128, 118, 189, 211, 100,
-            113, 55, 100, 231, 101, 191, 115, 15, 6, 14, 96, 62, 173, 203, 86, 234, 171, 90, 101,
-            205, 66, 200, 154, 87, 253, 46, 45, 90, 128, 106, 193, 144, 36, 121, 21, 180, 146, 57,
-            199, 127, 143, 105, 167, 163, 127, 121, 131, 231, 104, 46, 138, 233, 239, 54, 22, 141,
-            112, 157, 107, 231, 102, 128, 195, 123, 74, 111, 154, 33, 223, 73, 42, 218, 188, 4,
-            184, 253, 28, 231, 56, 247, 88, 157, 38, 98, 51, 51, 56, 229, 0, 246, 15, 222, 213,
-            126, 161, 2, 128, 104, 192, 128, 202, 155, 51, 157, 153, 27, 36, 132, 85, 87, 192, 105,
-            86, 104, 153, 154, 153, 17, 228, 6, 137, 88, 251, 2, 108, 48, 216, 253, 179, 189, 218,
-            159, 128, 116, 62, 160, 126, 231, 21, 233, 219, 168, 57, 155, 51, 226, 34, 141, 57,
-            216, 56, 159, 89, 157, 69, 100, 82, 187, 237, 47, 95, 233, 132, 73, 145, 128, 244, 20,
-            109, 23, 228, 245, 234, 61, 162, 244, 158, 15, 144, 56, 150, 76, 226, 115, 40, 74, 172,
-            103, 221, 84, 175, 94, 241, 93, 68, 92, 167, 175, 128, 155, 162, 118, 102, 232, 199,
-            53, 180, 102, 234, 12, 59, 5, 54, 118, 86, 206, 23, 51, 16, 102, 241, 57, 220, 97, 93,
-            84, 126, 40, 210, 152, 178, 128, 67, 137, 174, 4, 185, 81, 164, 48, 49, 190, 177, 2,
-            83, 176, 187, 172, 137, 87, 239, 145, 222, 236, 13, 129, 161, 168, 75, 180, 49, 209, 3,
-            206, 21, 1, 128, 128, 1, 128, 162, 99, 243, 142, 139, 138, 52, 219, 245, 233, 147, 205,
-            183, 113, 216, 219, 102, 217, 207, 63, 0, 241, 44, 67, 65, 165, 60, 202, 3, 25, 61,
-            168, 128, 226, 253, 235, 0, 63, 77, 81, 165, 219, 226, 98, 36, 11, 2, 95, 82, 63, 94,
-            248, 105, 224, 122, 103, 36, 213, 199, 194, 97, 19, 86, 148, 71, 21, 1, 128, 128, 8,
-            128, 117, 248, 108, 107, 170, 240, 211, 12, 245, 183, 88, 117, 24, 17, 151, 163, 235,
-            30, 123, 75, 119, 36, 214, 101, 9, 31, 56, 136, 62, 169, 214, 201, 128, 241, 135, 188,
-            145, 214, 54, 145, 211, 134, 84, 78, 233, 163, 211, 126, 50, 229, 59, 108, 7, 192, 201,
-            120, 217, 244, 4, 171, 37, 112, 17, 213, 32, 21, 1, 128, 128, 32, 128, 20, 218, 107, 7,
-            225, 45, 180, 109, 68, 192, 44, 175, 13, 105, 4, 30, 69, 67, 154, 204, 40, 234, 58,
-            229, 166, 116, 153, 90, 96, 236, 83, 243, 128, 43, 137, 64, 68, 20, 83, 137, 172, 180,
-            234, 41, 81, 40, 183, 100, 105, 49, 173, 199, 255, 114, 245, 116, 44, 48, 133, 72, 6,
-            239, 244, 155, 13, 153, 1, 128, 128, 40, 128, 211, 237, 238, 187, 159, 98, 174, 107,
-            152, 87, 124, 58, 74, 72, 19, 3, 72, 68, 154, 175, 195, 170, 178, 255, 126, 122, 155,
-            17, 100, 188, 195, 184, 128, 80, 193, 104, 32, 118, 12, 123, 135, 92, 13, 104, 186, 8,
-            160, 145, 243, 97, 216, 39, 138, 193, 92, 180, 216, 180, 85, 7, 83, 195, 147, 188, 69,
-            128, 119, 241, 145, 174, 141, 253, 128, 131, 21, 142, 98, 30, 65, 77, 18, 136, 127,
-            195, 50, 85, 82, 180, 229, 185, 5, 241, 129, 66, 61, 245, 150, 14, 21, 1, 128, 128,
-            128, 128, 6, 168, 240, 47, 13, 145, 75, 182, 82, 13, 103, 242, 179, 218, 141, 235, 30,
-            39, 131, 213, 61, 67, 25, 10, 116, 240, 202, 160, 190, 25, 37, 23, 128, 179, 238, 43,
-            1, 119, 100, 133, 176, 191, 22, 236, 42, 161, 140, 88, 103, 180, 27, 118, 199, 223, 33,
-            16, 124, 203, 154, 201, 171, 133, 239, 8, 177, 21, 1, 128, 128, 128, 128, 92, 15, 205,
-            37, 69, 142, 125, 166, 70, 254, 120, 234, 224, 28, 204, 228, 11, 94, 204, 29, 51, 106,
-            43, 221, 253, 169, 25, 196, 196, 146, 220, 101, 128, 254, 131, 149, 21, 114, 84, 74,
-            110, 236, 41, 87, 16, 184, 117, 108, 235, 155, 129, 221, 8, 161, 29, 167, 11, 109, 53,
-            56, 35, 19, 194, 242, 38, 29, 2, 128, 128, 137, 128, 135, 108, 218, 121, 214, 100, 146,
-            113, 109, 138, 148, 84, 8, 99, 132, 150, 138, 215, 66, 174, 67, 108, 18, 143, 83, 53,
-            103, 47, 142, 79, 90, 114, 128, 58, 242, 189, 112, 214, 121, 145, 124, 17, 215, 84, 35,
-            45, 238, 218, 156, 148, 76, 253, 178, 249, 156, 48, 120, 27, 241, 110, 111, 227, 128,
-            100, 25, 128, 231, 15, 152, 12, 119, 145, 120, 170, 223, 45, 159, 7, 224, 255, 161, 14,
-            126, 4, 187, 154, 127, 81, 229, 57, 25, 111, 254, 188, 45, 141, 71, 226, 128, 92, 35,
-            71, 228, 144, 180, 211, 27, 31, 75, 118, 2, 222, 1, 189, 24, 118, 235, 79, 161, 171,
-            163, 148, 87, 112, 122, 60, 141, 151, 94, 94, 110, 21, 1, 128, 129, 0, 128, 50, 160,
-            105, 65, 9, 60, 163, 133, 232, 24, 48, 217, 64, 197, 231, 36, 22, 238, 4, 40, 113, 218,
-            48, 110, 107, 39, 48, 30, 155, 138, 108, 241, 128, 255, 50, 140, 29, 245, 41, 57, 254,
-            169, 28, 155, 70, 60, 48, 202, 226, 19, 84, 86, 231, 239, 156, 10, 99, 168, 97, 234,
-            227, 180, 169, 189, 226, 21, 1, 128, 129, 0, 128, 60, 4, 68, 35, 110, 13, 93, 174, 76,
-            72, 123, 100, 213, 71, 85, 179, 33, 73, 61, 95, 45, 179, 218, 29, 233, 235, 223, 147,
-            15, 31, 254, 120, 128, 25, 104, 122, 81, 176, 24, 168, 68, 201, 234, 148, 209, 56, 23,
-            46, 10, 16, 59, 173, 96, 212, 181, 46, 66, 97, 187, 65, 101, 174, 56, 88, 126, 21, 1,
-            128, 129, 0, 128, 179, 211, 136, 251, 136, 193, 230, 95, 69, 242, 155, 4, 1, 8, 238,
-            88, 79, 240, 183, 55, 158, 55, 149, 85, 201, 141, 150, 180, 227, 106, 128, 238, 128,
-            117, 90, 177, 82, 117, 229, 77, 56, 203, 54, 212, 48, 201, 95, 222, 67, 155, 33, 186,
-            66, 254, 11, 99, 196, 64, 7, 103, 210, 240, 165, 5, 166, 153, 1, 128, 130, 4, 128, 195,
-            164, 72, 162, 124, 144, 196, 12, 227, 115, 166, 145, 92, 48, 107, 49, 45, 209, 46, 223,
-            129, 22, 239, 91, 60, 225, 63, 36, 15, 234, 232, 30, 128, 20, 210, 136, 31, 76, 46, 16,
-            105, 228, 105, 33, 215, 159, 19, 76, 145, 141, 177, 235, 219, 40, 216, 254, 28, 155,
-            108, 0, 57, 83, 45, 82, 147, 128, 128, 189, 204, 239, 128, 208, 64, 248, 16, 14, 204,
-            133, 63, 128, 1, 41, 75, 215, 249, 197, 67, 75, 10, 10, 106, 156, 99, 16, 124, 145, 72,
-            9, 29, 2, 128, 130, 80, 128, 136, 6, 211, 176, 113, 135, 136, 124, 153, 25, 252, 187,
-            129, 19, 255, 187, 123, 187, 55, 138, 135, 221, 95, 164, 169, 147, 200, 3, 34, 173, 92,
-            215, 128, 22, 251, 200, 31, 185, 40, 63, 181, 202, 11, 16, 199, 231, 238, 150, 78, 10,
-            119, 249, 174, 121, 242, 126, 178, 163, 164, 225, 210, 44, 250, 195, 36, 128, 88, 140,
-            254, 161, 229, 126, 185, 1, 117, 190, 83, 5, 132, 184, 246, 32, 7, 124, 219, 254, 62,
-            122, 156, 25, 22, 205, 60, 141, 29, 145, 146, 48, 128, 17, 102, 153, 1, 238, 171, 94,
-            16, 209, 246, 112, 201, 70, 91, 70, 166, 112, 54, 182, 201, 234, 28, 138, 66, 79, 52,
-            232, 83, 177, 87, 253, 30, 153, 1, 128, 134, 0, 128, 172, 93, 246, 87, 152, 19, 111,
-            66, 200, 187, 110, 23, 93, 61, 51, 20, 116, 28, 99, 65, 232, 151, 154, 19, 26, 195,
-            250, 123, 190, 235, 183, 152, 128, 126, 191, 116, 81, 156, 13, 171, 84, 179, 229, 214,
-            56, 171, 219, 74, 233, 55, 254, 10, 83, 255, 74, 87, 98, 42, 12, 250, 10, 50, 126, 197,
-            139, 128, 122, 130, 182, 225, 237, 48, 154, 238, 157, 116, 230, 205, 194, 14, 241, 239,
-            40, 214, 55, 210, 189, 154, 129, 25, 14, 220, 237, 62, 114, 56, 125, 71, 161, 2, 128,
-            139, 64, 128, 44, 104, 77, 98, 68, 200, 76, 51, 242, 193, 132, 147, 238, 189, 77, 100,
-            40, 221, 144, 131, 85, 100, 230, 19, 124, 239, 34, 214, 45, 16, 93, 91, 128, 171, 229,
-            218, 116, 200, 192, 187, 97, 167, 223, 0, 217, 76, 28, 215, 2, 145, 70, 107, 99, 196,
-            238, 186, 153, 166, 171, 155, 149, 244, 230, 31, 144, 128, 85, 195, 253, 157, 61, 19,
-            153, 187, 109, 249, 15, 149, 188, 40, 118, 96, 238, 85, 70, 11, 191, 107, 139, 98, 1,
-            39, 120, 142, 228, 17, 204, 234, 128, 181, 123, 91, 143, 71, 213, 124, 186, 111, 181,
-            241, 152, 139, 39, 240, 69, 130, 121, 176, 53, 29, 251, 142, 45, 135, 63, 59, 69, 248,
-            120, 240, 154, 128, 154, 13, 77, 188, 24, 194, 32, 205, 121, 60, 130, 185, 217, 6, 173,
-            57, 130, 104, 6, 255, 155, 184, 191, 113, 45, 65, 222, 45, 84, 137, 250, 111, 153, 1,
-            128, 140, 0, 128, 101, 102, 146, 193, 195, 229, 132, 255, 81, 54, 93, 207, 95, 81, 15,
-            105, 47, 35, 59, 124, 88, 40, 244, 92, 207, 75, 23, 199, 50, 102, 135, 131, 128, 235,
-            211, 188, 34, 198, 130, 16, 37, 251, 63, 26, 17, 68, 110, 115, 56, 97, 76, 220, 169,
-            163, 93, 216, 135, 235, 235, 218, 161, 198, 72, 30, 6, 128, 231, 103, 182, 59, 191, 53,
-            86, 193, 138, 6, 114, 186, 183, 61, 125, 66, 168, 69, 184, 210, 161, 18, 215, 96, 192,
-            201, 239, 48, 124, 113, 237, 224, 21, 1, 128, 144, 0, 128, 140, 10, 85, 56, 162, 116,
-            183, 66, 186, 40, 142, 106, 78, 95, 45, 56, 171, 235, 112, 140, 164, 46, 203, 165, 41,
-            155, 199, 87, 154, 146, 175, 245, 128, 187, 93, 169, 99, 245, 127, 58, 38, 254, 39, 17,
-            48, 81, 75, 176, 161, 4, 46, 93, 167, 239, 22, 129, 203, 119, 213, 54, 40, 201, 181,
-            127, 115, 29, 2, 128, 144, 34, 128, 227, 6, 134, 107, 111, 144, 213, 153, 192, 194, 55,
-            142, 211, 119, 197, 134, 84, 156, 149, 251, 131, 216, 225, 5, 99, 182, 38, 250, 12, 54,
-            236, 201, 128, 88, 72, 208, 132, 151, 184, 77, 228, 64, 12, 196, 223, 15, 221, 30, 160,
-            203, 209, 137, 1, 221, 182, 196, 112, 32, 28, 71, 194, 55, 43, 134, 249, 128, 152, 209,
-            75, 22, 182, 57, 164, 242, 97, 56, 32, 125, 124, 47, 204, 67, 160, 104, 165, 25, 82,
-            229, 27, 14, 157, 71, 81, 63, 251, 177, 4, 164, 128, 97, 122, 28, 110, 215, 233, 8, 85,
-            82, 19, 221, 183, 134, 20, 101, 144, 199, 22, 158, 33, 173, 184, 7, 96, 225, 34, 154,
-            128, 165, 22, 219, 99, 29, 2, 128, 150, 0, 128, 218, 77, 152, 86, 147, 47, 61, 233, 42,
-            203, 156, 234, 106, 106, 27, 71, 74, 249, 139, 138, 201, 33, 142, 47, 203, 59, 206,
-            107, 221, 214, 1, 60, 128, 211, 140, 144, 183, 230, 114, 102, 64, 57, 7, 95, 85, 57,
-            154, 35, 37, 84, 167, 100, 200, 8, 148, 235, 2, 108, 108, 205, 137, 27, 167, 142, 40,
-            128, 159, 0, 51, 108, 247, 99, 43, 248, 208, 184, 52, 34, 133, 133, 168, 128, 70, 199,
-            201, 92, 136, 219, 173, 157, 110, 21, 47, 2, 29, 48, 231, 21, 128, 165, 87, 61, 23,
-            192, 51, 225, 219, 183, 165, 43, 219, 137, 209, 112, 74, 254, 223, 27, 207, 15, 104,
-            253, 64, 244, 92, 24, 8, 57, 121, 24, 127, 169, 3, 128, 156, 112, 128, 14, 140, 202,
-            119, 209, 245, 174, 128, 240, 85, 235, 240, 241, 206, 74, 44, 42, 71, 144, 1, 247, 60,
-            161, 206, 50, 222, 161, 247, 10, 91, 40, 0, 128, 186, 237, 33, 59, 29, 218, 43, 18,
-            117, 127, 187, 6, 142, 204, 177, 231, 175, 254, 36, 109, 110, 120, 71, 231, 157, 35,
-            44, 142, 250, 22, 159, 235, 128, 138, 111, 67, 172, 226, 163, 169, 247, 13, 168, 150,
-            235, 105, 217, 118, 1, 109, 91, 45, 27, 106, 69, 247, 36, 188, 62, 106, 168, 134, 44,
-            232, 172, 128, 196, 120, 208, 59, 46, 137, 16, 79, 29, 124, 62, 216, 224, 23, 178, 220,
-            231, 169, 35, 85, 121, 181, 100, 147, 61, 1, 236, 135, 181, 72, 145, 56, 128, 23, 33,
-            158, 2, 6, 241, 153, 107, 251, 222, 161, 192, 154, 154, 219, 76, 147, 216, 69, 4, 253,
-            178, 231, 202, 48, 148, 163, 129, 57, 43, 166, 145, 128, 204, 158, 55, 76, 234, 150,
-            219, 213, 8, 83, 193, 111, 45, 245, 167, 6, 78, 167, 228, 212, 250, 237, 13, 13, 178,
-            230, 136, 110, 245, 65, 105, 156, 128, 82, 123, 1, 143, 84, 152, 200, 17, 94, 138, 95,
-            146, 202, 116, 112, 140, 77, 19, 186, 240, 131, 116, 213, 115, 253, 78, 135, 217, 193,
-            41, 194, 251, 161, 2, 128, 168, 24, 128, 40, 111, 242, 142, 54, 175, 246, 8, 116, 101,
-            96, 107, 154, 60, 151, 230, 217, 189, 238, 61, 32, 46, 185, 56, 24, 244, 166, 230, 156,
-            188, 148, 202, 128, 37, 72, 73, 117, 50, 53, 103, 57, 146, 88, 243, 148, 231, 172, 101,
-            197, 154, 200, 72, 5, 144, 7, 244, 33, 122, 243, 55, 203, 4, 18, 187, 211, 128, 112, 9,
-            110, 60, 163, 45, 172, 146, 171, 9, 227, 212, 100, 219, 86, 173, 80, 85, 69, 158, 204,
-            153, 107, 211, 131, 1, 245, 106, 254, 247, 164, 247, 128, 171, 135, 117, 81, 213, 127,
-            86, 37, 148, 54, 68, 184, 248, 143, 215, 184, 22, 146, 194, 100, 171, 89, 231, 249,
-            210, 163, 209, 95, 186, 233, 161, 248, 128, 130, 63, 255, 126, 12, 120, 8, 166, 176,
-            191, 206, 147, 167, 171, 210, 98, 59, 21, 36, 80, 224, 75, 81, 139, 249, 93, 216, 177,
-            57, 161, 129, 181, 37, 3, 128, 180, 36, 128, 197, 69, 226, 239, 160, 231, 234, 218, 90,
-            103, 53, 140, 101, 52, 133, 54, 135, 78, 14, 247, 197, 184, 200, 82, 192, 217, 10, 255,
-            157, 1, 255, 195, 128, 38, 4, 51, 59, 8, 243, 205, 122, 137, 63, 52, 201, 35, 43, 250,
-            207, 129, 202, 4, 162, 188, 19, 30, 51, 24, 144, 165, 79, 103, 1, 222, 149, 128, 4,
-            217, 112, 215, 84, 135, 209, 164, 38, 242, 20, 198, 17, 179, 130, 26, 177, 159, 144,
-            198, 70, 179, 93, 173, 118, 107, 51, 208, 162, 89, 56, 63, 128, 235, 77, 192, 119, 32,
-            206, 161, 110, 134, 108, 231, 107, 64, 21, 242, 70, 183, 107, 0, 188, 214, 239, 226,
-            213, 47, 193, 210, 248, 131, 76, 75, 174, 128, 76, 93, 236, 159, 69, 145, 130, 192,
-            112, 29, 78, 116, 85, 181, 60, 33, 246, 161, 80, 184, 2, 244, 101, 6, 170, 239, 233,
-            200, 150, 151, 152, 125, 128, 43, 54, 66, 214, 82, 23, 237, 174, 62, 196, 88, 201, 50,
-            42, 250, 189, 198, 75, 241, 147, 143, 215, 150, 87, 48, 145, 46, 158, 224, 108, 46,
-            130, 37, 3, 128, 180, 144, 128, 183, 198, 239, 208, 158, 81, 182, 252, 162, 31, 125,
-            138, 100, 121, 156, 95, 129, 200, 211, 61, 131, 213, 21, 44, 61, 32, 99, 119, 112, 80,
-            170, 163, 128, 189, 99, 96, 5, 95, 174, 35, 141, 129, 252, 113, 154, 74, 208, 4, 94,
-            46, 13, 146, 152, 36, 82, 98, 63, 126, 228, 77, 167, 253, 206, 106, 63, 128, 179, 111,
-            191, 252, 166, 78, 94, 80, 128, 33, 44, 74, 66, 133, 211, 121, 70, 6, 123, 161, 61, 37,
-            200, 93, 171, 18, 96, 9, 178, 64, 121, 35, 128, 213, 234, 38, 67, 7, 177, 55, 4, 158,
-            174, 207, 134, 40, 220, 99, 114, 247, 241, 120, 140, 59, 54, 171, 131, 48, 26, 255,
-            255, 162, 218, 240, 132, 128, 12, 100, 249, 121, 128, 147, 52, 235, 27, 74, 223, 246,
-            54, 124, 1, 236, 132, 13, 211, 21, 238, 159, 216, 156, 11, 110, 189, 10, 70, 141, 152,
-            124, 128, 54, 211, 44, 215, 222, 246, 214, 49, 122, 64, 17, 50, 94, 57, 202, 102, 60,
-            208, 122, 252, 233, 247, 112, 123, 60, 211, 25, 229, 252, 104, 125, 30, 153, 1, 128,
-            193, 0, 128, 224, 95, 51, 210, 23, 178, 104, 17, 216, 72, 199, 174, 41, 252, 4, 109,
-            166, 158, 15, 40, 51, 225, 120, 119, 239, 149, 177, 188, 139, 202, 203, 45, 128, 169,
-            220, 229, 110, 23, 117, 31, 79, 216, 7, 185, 20, 191, 121, 220, 142, 45, 197, 140, 253,
-            45, 179, 203, 109, 150, 149, 244, 73, 175, 145, 197, 206, 128, 238, 116, 177, 151, 37,
-            48, 16, 182, 34, 41, 40, 90, 27, 215, 11, 176, 186, 215, 211, 168, 105, 74, 158, 202,
-            39, 229, 218, 121, 126, 248, 137, 57, 153, 1, 128, 200, 0, 128, 49, 44, 161, 25, 66,
-            190, 118, 121, 231, 28, 221, 170, 171, 153, 116, 144, 175, 23, 242, 66, 222, 210, 28,
-            49, 140, 19, 45, 9, 150, 252, 128, 201, 128, 251, 253, 83, 109, 137, 177, 179, 227,
-            224, 222, 175, 58, 4, 11, 216, 194, 200, 26, 128, 46, 15, 188, 129, 60, 24, 6, 228,
-            218, 199, 31, 66, 54, 128, 10, 41, 188, 87, 119, 254, 197, 4, 204, 159, 109, 58, 184,
-            190, 250, 20, 40, 53, 212, 45, 62, 181, 73, 88, 28, 95, 95, 203, 84, 196, 61, 111, 169,
-            3, 128, 220, 68, 128, 11, 3, 128, 223, 141, 239, 186, 202, 200, 134, 248, 86, 242, 64,
-            171, 70, 122, 26, 255, 204, 234, 156, 30, 61, 127, 110, 105, 3, 9, 82, 231, 227, 128,
-            45, 138, 198, 173, 246, 227, 7, 60, 12, 17, 59, 69, 37, 17, 214, 244, 202, 123, 237,
-            74, 170, 253, 169, 131, 228, 206, 126, 192, 137, 61, 89, 113, 128, 33, 242, 59, 126,
-            80, 98, 73, 125, 236, 52, 247, 115, 89, 121, 195, 170, 171, 107, 252, 32, 89, 123, 210,
-            244, 51, 71, 232, 238, 94, 239, 6, 232, 128, 94, 31, 50, 39, 98, 199, 217, 235, 230,
-            37, 117, 32, 219, 24, 186, 192, 117, 23, 210, 2, 177, 98, 90, 26, 2, 61, 201, 112, 149,
-            140, 233, 134, 128, 31, 109, 84, 248, 13, 200, 152, 80, 5, 153, 20, 209, 97, 74, 104,
-            4, 85, 91, 203, 113, 65, 25, 241, 12, 25, 248, 249, 108, 90, 173, 148, 134, 128, 21,
-            55, 253, 47, 191, 186, 176, 109, 71, 249, 237, 104, 93, 184, 79, 207, 79, 31, 197, 175,
-            34, 164, 148, 193, 74, 74, 149, 52, 119, 94, 162, 11, 128, 50, 103, 100, 24, 131, 75,
-            236, 127, 11, 61, 157, 78, 216, 103, 114, 20, 156, 6, 247, 160, 235, 43, 176, 58, 107,
-            81, 84, 66, 107, 28, 160, 239, 201, 7, 128, 255, 191, 128, 158, 63, 149, 1, 58, 90,
-            199, 8, 180, 42, 100, 95, 68, 184, 64, 112, 145, 78, 130, 76, 221, 11, 40, 239, 41, 58,
-            200, 68, 13, 72, 188, 165, 128, 204, 220, 74, 251, 110, 231, 249, 175, 205, 130, 132,
-            250, 22, 40, 138, 163, 127, 121, 154, 73, 233, 16, 94, 82, 45, 183, 72, 11, 43, 119,
-            87, 234, 128, 88, 86, 140, 228, 43, 157, 244, 179, 181, 121, 251, 212, 56, 35, 194, 12,
-            228, 254, 104, 65, 27, 165, 158, 216, 188, 139, 168, 225, 61, 132, 202, 76, 128, 201,
-            23, 138, 207, 176, 44, 214, 85, 204, 70, 100, 4, 116, 82, 71, 228, 241, 90, 108, 43,
-            26, 68, 144, 18, 122, 64, 161, 70, 98, 251, 10, 252, 128, 110, 47, 111, 14, 41, 34, 78,
-            147, 203, 192, 168, 4, 197, 79, 134, 68, 109, 231, 253, 158, 107, 203, 91, 130, 151,
-            98, 180, 213, 9, 189, 68, 80, 128, 4, 201, 186, 96, 21, 36, 18, 155, 170, 90, 58, 226,
-            214, 67, 127, 237, 196, 222, 226, 174, 36, 51, 157, 46, 240, 74, 122, 183, 135, 39, 48,
-            186, 128, 170, 114, 149, 137, 242, 192, 204, 114, 121, 187, 191, 250, 8, 227, 127, 93,
-            220, 108, 194, 11, 178, 203, 61, 123, 11, 240, 189, 208, 206, 163, 200, 140, 128, 248,
-            91, 155, 199, 230, 136, 20, 51, 123, 8, 11, 178, 38, 69, 109, 117, 201, 28, 130, 75,
-            142, 190, 181, 184, 152, 190, 68, 115, 200, 51, 207, 108, 128, 209, 155, 60, 4, 152,
-            91, 147, 234, 238, 184, 1, 28, 148, 0, 120, 154, 38, 123, 111, 197, 112, 199, 2, 246,
-            233, 50, 71, 25, 87, 61, 100, 229, 128, 239, 53, 195, 76, 247, 20, 70, 41, 115, 0, 224,
-            30, 90, 189, 247, 57, 116, 102, 92, 3, 138, 59, 45, 103, 186, 65, 237, 78, 15, 134, 29,
-            177, 128, 220, 55, 8, 25, 116, 102, 167, 225, 3, 235, 164, 6, 67, 84, 93, 44, 192, 179,
-            134, 137, 114, 64, 202, 131, 226, 175, 96, 99, 103, 199, 178, 109, 128, 100, 45, 137,
-            234, 36, 180, 77, 29, 187, 234, 88, 127, 142, 106, 2, 148, 5, 11, 91, 223, 10, 213, 40,
-            60, 178, 56, 121, 179, 79, 196, 48, 49, 128, 229, 200, 177, 66, 203, 20, 230, 123, 193,
-            84, 65, 201, 133, 24, 68, 116, 225, 116, 176, 21, 130, 113, 115, 14, 112, 131, 6, 183,
-            93, 119, 144, 20, 128, 56, 87, 4, 116, 11, 175, 92, 158, 44, 17, 84, 84, 85, 175, 230,
-            255, 86, 111, 189, 17, 10, 242, 108, 19, 96, 55, 130, 150, 66, 30, 80, 122, 128, 99,
-            209, 106, 138, 72, 129, 164, 121, 105, 105, 236, 38, 195, 244, 250, 153, 205, 101, 159,
-            223, 245, 83, 115, 131, 230, 213, 201, 110, 78, 17, 82, 225, 201, 7, 128, 255, 254,
-            128, 208, 158, 3, 190, 40, 216, 177, 220, 46, 85, 232, 253, 123, 170, 53, 34, 76, 254,
-            150, 49, 92, 15, 76, 10, 166, 209, 43, 211, 223, 249, 176, 21, 128, 219, 204, 84, 174,
-            161, 163, 190, 254, 117, 195, 29, 7, 217, 23, 86, 245, 46, 71, 60, 203, 129, 217, 72,
-            123, 16, 220, 42, 200, 244, 54, 55, 50, 128, 215, 170, 165, 0, 121, 88, 226, 241, 89,
-            20, 184, 131, 70, 164, 242, 225, 11, 201, 96, 65, 23, 183, 32, 101, 9, 91, 191, 193,
-            240, 147, 90, 83, 128, 155, 146, 150, 77, 205, 13, 254, 237, 2, 217, 113, 89, 34, 87,
-            59, 162, 204, 246, 188, 218, 172, 221, 125, 223, 74, 198, 152, 6, 237, 249, 73, 114,
-            128, 117, 34, 190, 142, 140, 232, 56, 6, 243, 125, 122, 209, 114, 15, 29, 177, 82, 154,
-            33, 204, 129, 188, 72, 165, 102, 5, 238, 174, 87, 60, 231, 27, 128, 204, 83, 179, 23,
-            92, 175, 137, 194, 112, 119, 109, 117, 95, 176, 38, 183, 59, 179, 231, 142, 26, 164,
-            251, 72, 147, 40, 45, 28, 130, 88, 70, 76, 128, 118, 78, 9, 107, 124, 99, 15, 237, 148,
-            97, 66, 12, 196, 117, 177, 216, 180, 229, 24, 126, 95, 182, 75, 250, 54, 28, 34, 122,
-            132, 90, 21, 199, 128, 104, 216, 101, 53, 136, 32, 38, 150, 106, 89, 151, 102, 119,
-            159, 172, 121, 179, 251, 63, 218, 73, 27, 84, 71, 231, 233, 129, 165, 181, 87, 152, 28,
-            128, 97, 60, 168, 151, 117, 208, 149, 36, 153, 18, 159, 83, 4, 237, 135, 16, 3, 193,
-            13, 32, 215, 87, 3, 48, 254, 192, 221, 112, 223, 53, 77, 198, 128, 54, 63, 114, 225,
-            243, 241, 166, 252, 248, 189, 209, 228, 214, 120, 245, 224, 182, 166, 118, 14, 16, 83,
-            231, 209, 79, 4, 149, 145, 108, 15, 62, 108, 128, 158, 133, 78, 141, 28, 208, 225, 65,
-            226, 172, 19, 242, 66, 244, 222, 59, 231, 68, 69, 248, 111, 2, 162, 135, 191, 44, 203,
-            10, 194, 164, 231, 216, 128, 173, 122, 140, 218, 205, 222, 128, 215, 191, 40, 216, 214,
-            77, 27, 90, 144, 253, 77, 208, 214, 73, 167, 101, 40, 33, 2, 19, 2, 78, 125, 126, 229,
-            128, 25, 249, 143, 91, 234, 229, 99, 135, 101, 225, 221, 126, 43, 39, 116, 209, 166,
-            211, 150, 71, 204, 86, 227, 60, 52, 130, 224, 117, 243, 57, 157, 133, 128, 94, 84, 87,
-            142, 53, 210, 91, 172, 239, 170, 106, 177, 233, 85, 248, 15, 170, 36, 125, 161, 234,
-            254, 205, 255, 121, 105, 53, 86, 149, 83, 11, 37, 128, 91, 33, 242, 120, 86, 85, 201,
-            95, 166, 117, 7, 166, 199, 189, 199, 190, 198, 224, 142, 226, 153, 99, 71, 72, 174, 83,
-            235, 245, 119, 185, 65, 205, 77, 8, 128, 255, 255, 128, 6, 32, 133, 84, 101, 18, 126,
-            217, 191, 6, 177, 132, 244, 120, 216, 103, 150, 164, 175, 134, 187, 115, 179, 22, 186,
-            234, 226, 161, 121, 26, 222, 55, 128, 46, 76, 12, 88, 33, 101, 141, 239, 249, 204, 50,
-            40, 146, 72, 53, 196, 237, 137, 201, 156, 162, 175, 126, 235, 75, 129, 36, 170, 173,
-            227, 151, 84, 128, 150, 97, 175, 74, 255, 77, 186, 203, 201, 206, 163, 28, 53, 137,
-            246, 23, 243, 50, 33, 254, 102, 73, 205, 72, 95, 66, 237, 244, 178, 220, 95, 243, 128,
-            248, 133, 191, 217, 234, 55, 50, 83, 196, 84, 222, 191, 3, 25, 251, 225, 206, 154, 97,
-            250, 230, 7, 18, 148, 82, 158, 159, 24, 229, 187, 78, 248, 128, 53, 90, 65, 180, 241,
-            62, 98, 76, 77, 130, 116, 124, 57, 131, 192, 159, 129, 74, 73, 156, 182, 254, 24, 66,
-            120, 29, 111, 104, 113, 116, 87, 177, 128, 196, 149, 246, 233, 244, 233, 39, 94, 22,
-            147, 84, 71, 207, 212, 192, 32, 144, 53, 0, 121, 159, 67, 225, 200, 168, 120, 90, 201,
-            19, 105, 76, 107, 128, 16, 175, 11, 70, 56, 176, 152, 223, 221, 147, 33, 254, 212, 24,
-            192, 57, 46, 100, 39, 187, 73, 8, 172, 4, 82, 249, 0, 159, 110, 17, 65, 123, 128, 246,
-            155, 84, 144, 169, 142, 185, 181, 234, 141, 158, 150, 32, 54, 105, 130, 210, 235, 115,
-            71, 136, 249, 50, 98, 125, 33, 20, 63, 7, 21, 164, 206, 128, 235, 123, 178, 124, 30,
-            33, 51, 40, 195, 132, 173, 39, 202, 95, 110, 0, 162, 23, 137, 201, 55, 15, 57, 106,
-            235, 188, 156, 61, 114, 10, 174, 41, 128, 42, 252, 113, 214, 77, 16, 238, 32, 236, 70,
-            139, 69, 68, 26, 121, 55, 233, 83, 116, 152, 48, 185, 130, 225, 142, 192, 112, 218,
-            253, 171, 126, 230, 128, 74, 251, 28, 15, 44, 86, 24, 172, 214, 56, 125, 22, 226, 30,
-            144, 222, 170, 183, 86, 162, 193, 59, 163, 11, 245, 67, 117, 43, 41, 211, 246, 146,
-            128, 163, 87, 215, 73, 240, 35, 44, 127, 73, 101, 212, 114, 170, 201, 100, 246, 17,
-            253, 119, 168, 232, 187, 51, 39, 139, 234, 2, 152, 243, 120, 44, 228, 128, 132, 220, 6,
-            255, 222, 136, 239, 82, 249, 233, 142, 5, 148, 32, 136, 57, 224, 142, 86, 126, 17, 138,
-            137, 68, 89, 197, 42, 134, 72, 54, 105, 250, 128, 92, 187, 114, 111, 209, 220, 174,
-            223, 213, 39, 229, 13, 50, 234, 154, 124, 62, 120, 168, 20, 137, 91, 147, 151, 6, 46,
-            70, 148, 199, 180, 0, 128, 128, 253, 216, 104, 15, 195, 83, 31, 85, 6, 177, 192, 180,
-            67, 107, 77, 9, 12, 55, 182, 235, 115, 200, 71, 68, 190, 10, 25, 215, 53, 44, 255, 156,
-            128, 60, 106, 75, 142, 183, 243, 52, 207, 38, 138, 150, 93, 200, 67, 157, 246, 180, 22,
-            137, 12, 131, 69, 201, 138, 5, 64, 84, 62, 64, 78, 179, 199, 77, 8, 128, 255, 255, 128,
-            21, 95, 36, 122, 109, 82, 214, 86, 36, 233, 147, 9, 162, 15, 102, 163, 18, 54, 145,
-            232, 242, 92, 13, 57, 165, 177, 124, 159, 134, 148, 230, 191, 128, 17, 180, 212, 187,
-            199, 116, 184, 60, 247, 67, 45, 186, 99, 105, 214, 152, 151, 73, 184, 35, 176, 77, 137,
-            22, 86, 133, 113, 242, 131, 0, 114, 248, 128, 225, 64, 116, 89, 219, 148, 156, 23, 45,
-            108, 174, 179, 142, 4, 217, 70, 205, 136, 140, 181, 132, 177, 59, 99, 206, 108, 35, 84,
-            201, 222, 39, 26, 128, 52, 63, 111, 5, 37, 101, 158, 213, 159, 237, 129, 146, 15, 212,
-            168, 102, 128, 134, 142, 108, 250, 94, 0, 180, 92, 11, 78, 6, 31, 236, 117, 18, 128, 6,
-            234, 209, 47, 60, 70, 239, 171, 95, 104, 208, 48, 160, 199, 230, 154, 11, 9, 142, 177,
-            62, 180, 79, 232, 211, 82, 242, 194, 201, 131, 55, 216, 128, 26, 178, 61, 58, 248, 177,
-            214, 141, 149, 119, 43, 226, 196, 36, 145, 205, 209, 220, 22, 67, 145, 235, 28, 21, 68,
-            78, 17, 120, 10, 136, 203, 24, 128, 182, 219, 79, 27, 116, 254, 54, 219, 149, 90, 99,
-            158, 133, 31, 236, 70, 251, 155, 212, 117, 127, 167, 65, 153, 216, 53, 45, 196, 83, 37,
-            232, 179, 128, 19, 182, 172, 52, 190, 147, 215, 30, 0, 60, 93, 205, 2, 201, 109, 82,
-            110, 164, 3, 7, 164, 214, 154, 147, 23, 39, 241, 169, 64, 83, 146, 0, 128, 127, 74,
-            176, 32, 37, 115, 76, 139, 211, 14, 236, 66, 70, 48, 218, 49, 75, 65, 242, 41, 224, 8,
-            173, 179, 96, 55, 245, 179, 172, 240, 182, 25, 128, 55, 115, 145, 131, 180, 160, 117,
-            172, 130, 14, 156, 99, 139, 15, 7, 92, 3, 101, 20, 195, 126, 192, 251, 240, 207, 63,
-            247, 21, 6, 254, 158, 11, 128, 38, 117, 118, 233, 8, 173, 43, 112, 52, 222, 176, 191,
-            214, 37, 140, 221, 108, 2, 5, 177, 124, 224, 37, 131, 226, 44, 139, 76, 119, 193, 106,
-            228, 128, 60, 66, 232, 6, 65, 57, 164, 242, 246, 193, 0, 186, 127, 11, 93, 189, 9, 5,
-            247, 228, 206, 41, 25, 126, 99, 255, 190, 56, 250, 18, 237, 163, 128, 180, 239, 24,
-            135, 163, 86, 144, 19, 95, 194, 3, 154, 158, 101, 108, 146, 217, 137, 206, 117, 162,
-            93, 253, 169, 85, 204, 228, 147, 230, 236, 78, 0, 128, 127, 165, 31, 245, 177, 222, 92,
-            217, 26, 171, 244, 149, 96, 40, 164, 152, 42, 34, 154, 179, 57, 108, 74, 212, 136, 80,
-            214, 0, 216, 39, 244, 98, 128, 233, 240, 108, 189, 251, 219, 73, 68, 43, 151, 120, 194,
-            137, 251, 91, 11, 244, 59, 6, 184, 236, 190, 61, 39, 87, 87, 222, 170, 186, 8, 25, 8,
-            128, 204, 207, 180, 179, 96, 147, 201, 61, 238, 185, 48, 155, 251, 199, 208, 118, 234,
-            226, 157, 221, 210, 63, 166, 242, 24, 216, 230, 91, 208, 87, 9, 15, 77, 8, 128, 255,
-            255, 128, 32, 71, 114, 154, 208, 90, 0, 91, 213, 125, 214, 149, 124, 79, 23, 205, 52,
-            232, 217, 89, 34, 42, 160, 39, 122, 43, 229, 98, 243, 42, 206, 40, 128, 131, 102, 63,
-            30, 5, 91, 18, 216, 61, 183, 253, 15, 9, 26, 163, 2, 218, 1, 134, 95, 193, 154, 158,
-            13, 78, 98, 203, 226, 183, 67, 171, 125, 128, 157, 109, 179, 216, 230, 139, 210, 90,
-            41, 101, 53, 14, 53, 48, 207, 54, 65, 67, 137, 49, 73, 123, 138, 221, 32, 141, 114,
-            111, 219, 172, 85, 158, 128, 44, 1, 232, 124, 192, 192, 10, 7, 124, 59, 229, 244, 183,
-            26, 152, 13, 90, 74, 46, 88, 99, 20, 179, 33, 33, 14, 121, 131, 218, 93, 228, 18, 128,
-            194, 190, 49, 52, 173, 254, 128, 110, 138, 177, 97, 153, 252, 60, 138, 233, 134, 69,
-            106, 32, 184, 185, 13, 81, 162, 128, 227, 210, 219, 50, 32, 56, 128, 220, 163, 230,
-            155, 6, 111, 176, 235, 78, 131, 102, 186, 89, 228, 98, 9, 122, 63, 152, 75, 221, 230,
-            177, 197, 127, 42, 86, 36, 149, 100, 211, 10, 128, 93, 16, 106, 37, 26, 141, 150, 6,
-            81, 253, 146, 149, 21, 130, 37, 174, 124, 41, 1, 106, 230, 129, 65, 69, 180, 62, 9, 64,
-            15, 109, 119, 154, 128, 222, 83, 57, 102, 10, 56, 28, 143, 48, 68, 101, 86, 234, 53,
-            203, 83, 164, 112, 62, 220, 93, 180, 69, 149, 250, 235, 250, 124, 141, 91, 182, 134,
-            128, 197, 220, 184, 144, 48, 87, 179, 9, 12, 245, 165, 103, 193, 28, 253, 79, 70, 230,
-            195, 131, 5, 124, 112, 171, 160, 54, 72, 205, 74, 36, 252, 245, 128, 245, 22, 100, 52,
-            187, 161, 87, 79, 148, 130, 213, 215, 219, 143, 29, 139, 179, 140, 108, 71, 105, 9, 8,
-            103, 53, 88, 66, 77, 219, 88, 209, 174, 128, 212, 186, 208, 207, 165, 55, 183, 68, 170,
-            127, 146, 158, 202, 36, 119, 238, 109, 55, 243, 183, 112, 162, 134, 253, 86, 22, 237,
-            103, 242, 252, 208, 237, 128, 17, 99, 203, 152, 149, 177, 197, 203, 162, 120, 228, 91,
-            138, 154, 181, 225, 237, 175, 118, 130, 210, 223, 193, 13, 112, 221, 215, 17, 6, 226,
-            73, 28, 128, 4, 216, 2, 157, 168, 220, 49, 106, 247, 67, 24, 49, 155, 69, 122, 72, 239,
-            54, 246, 135, 222, 46, 194, 58, 113, 145, 235, 169, 125, 64, 211, 122, 128, 195, 66,
-            41, 72, 199, 187, 180, 153, 243, 223, 71, 98, 195, 151, 156, 184, 112, 50, 29, 246,
-            216, 59, 20, 165, 7, 240, 232, 102, 97, 80, 164, 206, 128, 145, 63, 250, 26, 195, 75,
-            39, 16, 83, 141, 48, 10, 106, 126, 174, 199, 201, 116, 94, 48, 35, 20, 96, 5, 64, 109,
-            69, 226, 8, 122, 20, 148, 128, 101, 188, 79, 138, 0, 82, 49, 142, 224, 148, 243, 97,
-            110, 49, 210, 178, 183, 118, 23, 19, 0, 26, 255, 9, 38, 239, 0, 59, 152, 8, 232, 65,
-            77, 8, 128, 255, 255, 128, 52, 152, 211, 190, 190, 158, 24, 14, 187, 114, 245, 129, 54,
-            61, 154, 0, 117, 139, 218, 240, 191, 56, 170, 150, 251, 101, 38, 180, 152, 139, 79, 38,
-            128, 251, 253, 5, 15, 246, 96, 81, 182, 123, 17, 154, 162, 226, 188, 243, 6, 139, 58,
-            157, 116, 190, 107, 108, 13, 89, 59, 173, 22, 237, 116, 122, 235, 128, 179, 28, 208,
-            45, 209, 100, 121, 155, 83, 66, 137, 54, 219, 53, 142, 32, 150, 7, 172, 148, 18, 122,
-            21, 36, 182, 93, 206, 2, 165, 195, 64, 44, 128, 8, 180, 57, 2, 38, 131, 151, 161, 248,
-            77, 111, 109, 180, 209, 200, 113, 88, 154, 143, 210, 153, 37, 193, 151, 17, 159, 76,
-            65, 90, 104, 54, 176, 128, 18, 52, 74, 173, 8, 114, 201, 4, 207, 101, 41, 158, 121, 41,
-            143, 233, 34, 3, 199, 241, 113, 152, 253, 255, 203, 131, 13, 102, 74, 25, 177, 183,
-            128, 202, 97, 201, 10, 67, 13, 160, 242, 224, 111, 202, 243, 55, 6, 9, 56, 208, 220,
-            27, 245, 152, 201, 250, 249, 228, 7, 139, 211, 173, 141, 5, 174, 128, 220, 10, 234, 6,
-            246, 9, 0, 85, 192, 41, 60, 21, 71, 124, 110, 49, 251, 223, 34, 177, 93, 219, 34, 146,
-            191, 71, 237, 3, 129, 158, 76, 193, 128, 192, 236, 45, 201, 236, 228, 208, 145, 220,
-            128, 181, 224, 168, 124, 9, 188, 11, 192, 67, 96, 172, 46, 68, 103, 55, 211, 214, 222,
-            12, 139, 101, 140, 128, 86, 218, 58, 172, 28, 45, 161, 2, 182, 166, 215, 163, 52, 76,
-            140, 207, 108, 135, 124, 120, 62, 158, 57, 193, 15, 143, 147, 86, 16, 141, 186, 29,
-            128, 111, 168, 178, 161, 106, 91, 217, 175, 166, 28, 60, 105, 190, 193, 46, 168, 61,
-            135, 62, 166, 95, 215, 142, 58, 13, 243, 77, 117, 49, 78, 151, 160, 128, 191, 212, 224,
-            98, 47, 88, 40, 255, 123, 79, 95, 176, 156, 243, 208, 110, 56, 211, 109, 85, 137, 179,
-            175, 25, 13, 241, 107, 209, 177, 135, 146, 163, 128, 194, 209, 192, 145, 251, 123, 139,
-            22, 187, 137, 235, 254, 6, 111, 31, 158, 192, 69, 78, 108, 156, 64, 187, 115, 82, 223,
-            9, 82, 61, 51, 119, 95, 128, 245, 163, 92, 184, 100, 208, 179, 172, 214, 16, 140, 71,
-            242, 205, 253, 56, 207, 97, 126, 100, 165, 248, 139, 109, 172, 208, 235, 249, 200, 80,
-            220, 254, 128, 158, 200, 116, 18, 168, 59, 54, 44, 90, 135, 190, 184, 13, 98, 181, 73,
-            85, 229, 180, 57, 54, 210, 160, 124, 167, 184, 99, 32, 99, 12, 75, 105, 128, 11, 18,
-            66, 155, 163, 28, 120, 167, 85, 154, 216, 121, 19, 215, 47, 51, 104, 158, 103, 55, 192,
-            211, 168, 253, 220, 78, 160, 45, 253, 45, 211, 192, 128, 255, 205, 138, 183, 70, 207,
-            10, 69, 205, 195, 48, 211, 73, 18, 184, 30, 90, 174, 196, 104, 34, 171, 249, 50, 206,
-            143, 203, 180, 165, 233, 162, 162, 77, 8, 128, 255, 255, 128, 58, 201, 166, 146, 29,
-            113, 160, 18, 70, 236, 115, 70, 19, 165, 72, 68, 38, 85, 231, 110, 214, 218, 156, 215,
-            151, 175, 220, 188, 182, 73, 74, 17, 128, 57, 37, 64, 205, 152, 86, 106, 143, 131, 35,
-            221, 109, 149, 45, 141, 167, 37, 79, 236, 129, 65, 7, 181, 110, 55, 11, 250, 240, 52,
-            117, 169, 158, 128, 204, 101, 91, 16, 8, 83, 117, 119, 139, 121, 194, 37, 123, 134,
-            194, 90, 40, 219, 78, 233, 131, 48, 203, 219, 106, 122, 71, 25, 40, 11, 225, 153, 128,
-            58, 54, 217, 135, 108, 131, 104, 19, 90, 4, 53, 235, 35, 168, 213, 219, 94, 202, 119,
-            183, 11, 157, 233, 29, 160, 191, 108, 188, 51, 12, 60, 141, 128, 160, 174, 75, 199,
-            199, 233, 169, 101, 168, 78, 26, 106, 176, 105, 38, 129, 28, 247, 14, 214, 218, 221,
-            13, 240, 9, 20, 63, 123, 141, 26, 190, 139, 128, 45, 224, 62, 80, 60, 203, 182, 72,
-            101, 127, 17, 216, 166, 187, 183, 16, 192, 61, 92, 109, 127, 2, 139, 56, 251, 157, 175,
-            125, 230, 247, 185, 101, 128, 33, 130, 72, 131, 94, 76, 68, 224, 132, 150, 251, 90, 1,
-            34, 7, 183, 173, 178, 240, 110, 250, 90, 137, 100, 116, 16, 195, 20, 129, 2, 12, 105,
-            128, 88, 2, 36, 134, 82, 225, 134, 78, 174, 11, 164, 130, 125, 128, 124, 207, 48, 63,
-            217, 247, 112, 230, 13, 150, 217, 139, 64, 60, 81, 63, 44, 190, 128, 248, 1, 253, 234,
-            97, 207, 65, 159, 63, 170, 168, 182, 82, 204, 227, 23, 134, 91, 81, 166, 28, 113, 220,
-            58, 95, 180, 123, 243, 6, 127, 139, 43, 128, 196, 0, 96, 165, 125, 95, 251, 75, 16,
-            200, 144, 62, 177, 94, 27, 145, 69, 51, 153, 206, 126, 148, 236, 188, 24, 58, 222, 98,
-            81, 47, 192, 198, 128, 251, 20, 123, 1, 82, 61, 114, 201, 104, 55, 28, 164, 11, 225,
-            82, 170, 17, 15, 255, 80, 199, 143, 187, 252, 119, 113, 83, 197, 12, 133, 199, 101,
-            128, 0, 93, 235, 202, 77, 21, 48, 18, 116, 37, 121, 157, 205, 37, 139, 102, 158, 231,
-            50, 87, 189, 72, 167, 17, 211, 116, 181, 1, 120, 27, 19, 249, 128, 87, 232, 34, 89,
-            226, 3, 137, 227, 28, 203, 181, 169, 215, 140, 10, 249, 197, 27, 218, 50, 128, 145, 14,
-            251, 156, 87, 140, 184, 184, 215, 8, 42, 128, 214, 186, 104, 28, 203, 26, 133, 97, 30,
-            82, 219, 208, 159, 189, 193, 198, 175, 244, 101, 39, 137, 114, 76, 22, 132, 158, 108,
-            244, 117, 184, 90, 85, 128, 14, 208, 6, 122, 78, 49, 9, 54, 65, 194, 183, 96, 215, 66,
-            8, 73, 20, 5, 110, 155, 203, 10, 199, 77, 90, 151, 44, 247, 242, 234, 143, 76, 128,
-            137, 223, 40, 196, 188, 73, 243, 240, 70, 195, 150, 112, 119, 234, 146, 188, 3, 143,
-            95, 228, 15, 193, 177, 235, 203, 165, 146, 65, 141, 18, 248, 235, 77, 8, 128, 255, 255,
-            128, 72, 227, 186, 208, 65, 38, 67, 240, 103, 92, 8, 31, 242, 47, 245, 124, 69, 128,
-            43, 61, 106, 233, 158, 65, 44, 123, 190, 30, 136, 221, 162, 110, 128, 20, 154, 240,
-            177, 127, 113, 180, 62, 122, 234, 248, 200, 205, 42, 62, 125, 112, 181, 95, 33, 195,
-            255, 255, 169, 168, 104, 31, 17, 9, 24, 157, 171, 128, 208, 215, 84, 115, 128, 144,
-            126, 59, 82, 130, 127, 14, 160, 184, 204, 246, 129, 118, 140, 182, 5, 115, 188, 129,
-            114, 59, 124, 74, 133, 195, 116, 82, 128, 245, 84, 141, 238, 0, 88, 3, 93, 78, 160,
-            149, 210, 209, 205, 188, 118, 155, 9, 41, 170, 77, 84, 141, 233, 167, 40, 235, 193,
-            187, 22, 235, 200, 128, 67, 167, 238, 15, 193, 10, 24, 162, 254, 231, 215, 199, 172,
-            254, 225, 135, 55, 189, 91, 55, 228, 121, 131, 223, 125, 40, 54, 153, 135, 202, 139,
-            121, 128, 40, 199, 178, 138, 237, 194, 159, 253, 110, 97, 131, 44, 90, 159, 151, 221,
-            200, 60, 141, 9, 61, 225, 19, 96, 144, 170, 177, 48, 242, 92, 158, 235, 128, 252, 114,
-            254, 107, 51, 251, 164, 43, 155, 188, 232, 54, 164, 242, 239, 19, 144, 44, 168, 19,
-            159, 30, 79, 200, 254, 66, 197, 216, 213, 160, 83, 224, 128, 152, 98, 71, 87, 176, 216,
-            190, 121, 251, 201, 190, 32, 15, 23, 229, 227, 64, 32, 180, 98, 168, 212, 246, 224,
-            140, 113, 76, 238, 1, 159, 197, 67, 128, 183, 68, 13, 132, 134, 132, 53, 250, 94, 25,
-            59, 107, 189, 188, 146, 126, 239, 148, 197, 234, 108, 254, 34, 173, 202, 44, 89, 168,
-            218, 147, 247, 187, 128, 203, 121, 148, 63, 84, 92, 146, 115, 80, 45, 207, 173, 216,
-            199, 47, 180, 75, 35, 145, 102, 158, 38, 123, 19, 85, 144, 176, 241, 59, 96, 81, 31,
-            128, 28, 122, 35, 15, 86, 120, 97, 7, 171, 19, 190, 105, 208, 148, 100, 236, 249, 120,
-            241, 229, 197, 41, 220, 148, 178, 32, 20, 138, 252, 160, 3, 181, 128, 107, 53, 208, 95,
-            35, 48, 191, 237, 246, 105, 67, 216, 186, 129, 234, 10, 245, 180, 72, 82, 209, 69, 228,
-            22, 204, 157, 177, 214, 7, 125, 130, 162, 128, 200, 182, 239, 18, 113, 83, 136, 129,
-            241, 86, 75, 2, 108, 211, 184, 233, 133, 125, 126, 229, 197, 18, 141, 74, 49, 209, 251,
-            162, 123, 132, 67, 241, 128, 98, 169, 128, 71, 226, 12, 74, 91, 45, 220, 144, 255, 5,
-            43, 4, 161, 5, 168, 254, 146, 163, 188, 181, 188, 245, 209, 229, 67, 252, 181, 99, 38,
-            128, 141, 190, 165, 156, 57, 149, 153, 127, 68, 145, 42, 198, 63, 244, 246, 245, 163,
-            214, 214, 230, 239, 171, 146, 69, 208, 69, 33, 128, 110, 194, 184, 150, 128, 87, 53,
-            244, 157, 86, 127, 165, 192, 182, 32, 74, 181, 129, 234, 159, 244, 180, 47, 218, 164,
-            250, 98, 146, 170, 222, 30, 50, 65, 128, 99, 114, 192, 77, 8, 128, 255, 255, 128, 98,
-            224, 118, 12, 76, 51, 177, 134, 55, 11, 240, 54, 16, 130, 71, 53, 233, 48, 237, 233,
-            145, 55, 196, 116, 75, 125, 25, 10, 222, 110, 145, 72, 128, 123, 97, 129, 68, 255, 146,
-            109, 130, 170, 164, 51, 127, 247, 61, 60, 39, 45, 247, 96, 65, 102, 249, 254, 240, 235,
-            150, 72, 191, 254, 139, 212, 143, 128, 10, 145, 115, 111, 253, 223, 196, 17, 64, 94,
-            25, 146, 103, 246, 48, 225, 201, 116, 96, 194, 171, 54, 38, 98, 86, 3, 3, 71, 129, 31,
-            6, 175, 128, 199, 195, 90, 57, 51, 127, 202, 28, 248, 27, 156, 174, 209, 38, 194, 27,
-            143, 96, 209, 92, 159, 32, 96, 206, 102, 91, 145, 17, 100, 34, 161, 225, 128, 111, 174,
-            226, 248, 116, 189, 185, 98, 48, 158, 21, 64, 84, 37, 132, 251, 159, 119, 145, 158,
-            160, 94, 50, 14, 78, 36, 7, 69, 225, 169, 118, 163, 128, 130, 131, 93, 107, 228, 124,
-            194, 73, 133, 43, 145, 205, 67, 139, 12, 122, 202, 182, 137, 8, 117, 46, 210, 14, 252,
-            151, 44, 230, 172, 18, 153, 208, 128, 90, 123, 171, 183, 168, 73, 23, 229, 112, 45,
-            203, 101, 146, 205, 166, 179, 139, 173, 186, 161, 12, 37, 201, 110, 4, 24, 59, 86, 176,
-            85, 19, 214, 128, 34, 137, 24, 131, 123, 49, 233, 144, 163, 244, 68, 122, 147, 162,
-            184, 83, 218, 111, 153, 130, 208, 164, 104, 189, 32, 120, 168, 115, 238, 172, 147, 241,
-            128, 253, 185, 119, 165, 240, 12, 10, 37, 37, 189, 229, 131, 173, 106, 160, 226, 95,
-            234, 200, 170, 142, 79, 97, 231, 226, 69, 40, 164, 129, 29, 119, 217, 128, 215, 202,
-            10, 223, 65, 233, 98, 218, 223, 110, 11, 98, 108, 232, 78, 52, 25, 27, 11, 140, 93,
-            219, 35, 105, 104, 192, 213, 107, 103, 160, 147, 131, 128, 213, 23, 236, 208, 56, 116,
-            59, 227, 151, 243, 244, 198, 4, 58, 214, 202, 73, 178, 35, 192, 153, 13, 96, 246, 250,
-            18, 143, 18, 70, 157, 58, 4, 128, 50, 104, 164, 63, 181, 151, 229, 156, 25, 254, 186,
-            145, 93, 195, 131, 202, 231, 191, 255, 114, 215, 176, 156, 119, 36, 246, 10, 30, 88,
-            250, 251, 240, 128, 103, 233, 178, 7, 124, 202, 44, 82, 24, 123, 141, 45, 162, 11, 34,
-            22, 42, 104, 22, 81, 73, 15, 223, 49, 220, 156, 80, 188, 243, 88, 152, 39, 128, 24, 57,
-            95, 182, 144, 171, 157, 147, 39, 2, 248, 223, 190, 4, 238, 26, 205, 51, 243, 21, 92,
-            81, 253, 196, 58, 117, 8, 176, 3, 193, 96, 167, 128, 151, 147, 172, 137, 135, 140, 127,
-            19, 211, 158, 45, 240, 247, 87, 188, 12, 50, 211, 176, 157, 204, 105, 116, 196, 132,
-            232, 58, 25, 94, 127, 118, 37, 128, 243, 24, 174, 152, 134, 125, 213, 8, 179, 123, 215,
-            74, 241, 38, 134, 46, 15, 246, 224, 164, 243, 101, 224, 238, 242, 220, 108, 49, 7, 49,
-            19, 171, 77, 8, 128, 255, 255, 128, 126, 26, 2, 115, 125, 60, 237, 15, 224, 8, 167,
-            168, 170, 64, 193, 115, 82, 79, 139, 126, 45, 146, 247, 235, 138, 57, 197, 193, 187,
-            235, 128, 255, 128, 25, 176, 10, 6, 193, 115, 82, 78, 202, 212, 37, 48, 143, 31, 79,
-            135, 198, 58, 65, 93, 240, 196, 114, 75, 89, 200, 211, 24, 49, 211, 43, 248, 128, 139,
-            88, 169, 62, 211, 4, 166, 73, 191, 142, 142, 178, 189, 92, 38, 216, 103, 217, 211, 148,
-            1, 115, 180, 191, 77, 82, 223, 207, 20, 125, 130, 6, 128, 209, 115, 203, 18, 175, 206,
-            244, 86, 105, 74, 157, 81, 221, 58, 102, 232, 144, 178, 104, 52, 9, 139, 91, 182, 234,
-            82, 83, 165, 63, 160, 7, 114, 128, 243, 117, 5, 152, 169, 216, 80, 167, 179, 87, 94,
-            251, 216, 140, 34, 48, 215, 29, 91, 152, 228, 185, 96, 65, 138, 128, 76, 168, 225, 197,
-            100, 250, 128, 73, 28, 242, 167, 201, 42, 255, 15, 244, 48, 253, 183, 243, 188, 29,
-            212, 143, 41, 81, 54, 199, 149, 4, 212, 255, 92, 150, 2, 85, 79, 165, 249, 128, 70,
-            150, 17, 211, 84, 245, 117, 100, 205, 53, 45, 9, 109, 239, 251, 118, 83, 123, 151, 163,
-            98, 7, 31, 40, 101, 127, 254, 39, 185, 235, 191, 126, 128, 172, 29, 177, 47, 180, 240,
-            225, 255, 229, 172, 48, 134, 20, 239, 15, 216, 82, 179, 108, 203, 153, 93, 70, 159,
-            209, 54, 236, 145, 124, 170, 81, 107, 128, 186, 239, 163, 235, 187, 188, 130, 158, 8,
-            16, 226, 8, 219, 34, 130, 174, 188, 108, 89, 154, 67, 90, 241, 11, 216, 9, 10, 128, 23,
-            129, 19, 221, 128, 186, 48, 62, 144, 230, 102, 102, 238, 208, 166, 83, 134, 87, 62,
-            187, 7, 25, 145, 177, 195, 61, 237, 224, 149, 223, 100, 143, 62, 16, 52, 146, 152, 128,
-            156, 234, 72, 98, 6, 27, 209, 167, 158, 64, 242, 192, 158, 62, 9, 86, 106, 165, 19, 16,
-            74, 190, 17, 113, 127, 135, 236, 235, 0, 127, 106, 39, 128, 40, 166, 91, 35, 4, 38,
-            216, 38, 58, 31, 180, 163, 228, 163, 221, 27, 13, 131, 28, 101, 92, 226, 34, 98, 131,
-            32, 29, 241, 155, 64, 143, 57, 128, 82, 245, 36, 234, 173, 169, 46, 196, 144, 134, 113,
-            200, 13, 87, 68, 4, 37, 101, 66, 251, 109, 83, 160, 85, 86, 69, 174, 215, 89, 230, 112,
-            188, 128, 221, 67, 169, 147, 247, 63, 47, 70, 136, 91, 226, 27, 120, 51, 140, 162, 62,
-            91, 88, 70, 44, 204, 72, 185, 113, 201, 160, 83, 159, 129, 97, 215, 128, 132, 55, 78,
-            3, 203, 239, 90, 240, 56, 255, 237, 43, 30, 91, 205, 38, 41, 33, 240, 10, 17, 141, 71,
-            173, 58, 21, 199, 163, 147, 192, 51, 251, 128, 180, 232, 114, 114, 129, 107, 156, 91,
-            142, 40, 134, 148, 85, 46, 182, 150, 215, 2, 32, 125, 172, 93, 123, 212, 118, 25, 55,
-            24, 116, 138, 17, 101, 77, 8, 128, 255, 255, 128, 128, 254, 250, 23, 70, 79, 240, 10,
-            70, 190, 77, 23, 122, 171, 19, 37, 218, 153, 112, 130, 94, 30, 206, 18, 150, 32, 162,
-            65, 73, 163, 16, 207, 128, 137, 190, 230, 69, 134, 11, 100, 164, 10, 55, 253, 247, 153,
-            197, 172, 120, 125, 235, 240, 22, 86, 199, 75, 131, 220, 63, 150, 25, 139, 71, 46, 106,
-            128, 73, 13, 115, 235, 64, 36, 245, 221, 252, 29, 178, 97, 173, 142, 37, 101, 82, 201,
-            121, 39, 123, 78, 17, 212, 132, 196, 101, 210, 70, 235, 119, 106, 128, 253, 222, 171,
-            127, 115, 14, 115, 223, 212, 163, 30, 77, 202, 78, 253, 70, 8, 102, 29, 54, 82, 179,
-            118, 125, 228, 121, 204, 89, 155, 222, 147, 83, 128, 227, 191, 35, 207, 4, 38, 245,
-            230, 87, 71, 56, 117, 73, 164, 79, 249, 251, 166, 168, 12, 182, 31, 114, 208, 98, 190,
-            35, 40, 34, 54, 149, 142, 128, 210, 191, 125, 82, 15, 167, 39, 230, 247, 255, 40, 150,
-            213, 65, 72, 78, 231, 253, 153, 154, 181, 54, 10, 85, 83, 168, 104, 19, 123, 238, 210,
-            157, 128, 188, 148, 108, 164, 57, 34, 79, 98, 107, 189, 224, 8, 59, 254, 177, 215, 116,
-            47, 35, 20, 70, 19, 107, 248, 229, 173, 205, 99, 127, 84, 140, 38, 128, 89, 233, 77,
-            43, 63, 224, 59, 236, 215, 221, 139, 212, 69, 171, 94, 165, 14, 206, 152, 89, 1, 53,
-            196, 34, 246, 82, 59, 225, 142, 199, 88, 243, 128, 117, 226, 17, 47, 123, 236, 238, 34,
-            252, 243, 63, 155, 180, 126, 70, 157, 186, 178, 213, 77, 110, 145, 185, 75, 124, 249,
-            105, 205, 153, 181, 43, 36, 128, 157, 170, 117, 166, 123, 135, 0, 223, 80, 39, 185, 37,
-            13, 197, 134, 103, 88, 139, 185, 94, 124, 140, 157, 193, 219, 105, 162, 48, 142, 116,
-            46, 248, 128, 160, 91, 89, 227, 55, 123, 4, 89, 135, 36, 197, 89, 65, 101, 147, 121,
-            121, 162, 65, 36, 178, 25, 40, 209, 173, 255, 147, 13, 45, 189, 157, 250, 128, 41, 153,
-            119, 56, 243, 143, 95, 161, 80, 46, 98, 74, 216, 137, 226, 201, 173, 30, 216, 212, 152,
-            61, 217, 17, 119, 35, 134, 112, 232, 65, 164, 120, 128, 165, 55, 127, 175, 186, 31, 23,
-            220, 207, 96, 33, 98, 108, 159, 8, 38, 117, 139, 15, 18, 75, 119, 213, 84, 118, 33,
-            191, 143, 40, 188, 193, 157, 128, 222, 213, 70, 76, 206, 239, 36, 101, 17, 253, 75,
-            245, 2, 241, 235, 87, 87, 222, 38, 84, 92, 92, 188, 240, 191, 129, 94, 229, 17, 75, 55,
-            228, 128, 162, 140, 154, 201, 203, 90, 176, 148, 127, 231, 168, 21, 129, 93, 169, 155,
-            69, 224, 117, 12, 52, 111, 190, 79, 218, 237, 3, 26, 139, 72, 33, 23, 128, 28, 63, 210,
-            252, 209, 52, 215, 165, 54, 173, 246, 45, 171, 12, 203, 82, 21, 25, 104, 150, 58, 235,
-            34, 234, 147, 220, 232, 209, 128, 233, 52, 165, 77, 8, 128, 255, 255, 128, 156, 1, 8,
-            84, 91, 129, 182, 188, 2, 83, 7, 165, 8, 120, 243, 87, 173, 180, 121, 91, 65, 154, 45,
-            21, 71, 174, 77, 241, 179, 136, 13, 72, 128, 230, 28, 144, 93, 130, 226, 188, 221, 170,
-            208, 100, 82, 172, 225, 62, 209, 46, 68, 196, 33, 67, 4, 255, 22, 37, 196, 36, 174,
-            251, 236, 118, 222, 128, 214, 118, 96, 46, 102, 89, 165, 41, 246, 190, 182, 43, 105,
-            90, 176, 76, 189, 195, 215, 36, 237, 235, 187, 37, 79, 1, 100, 87, 76, 41, 73, 144,
-            128, 54, 183, 21, 156, 176, 38, 153, 68, 182, 160, 1, 199, 236, 221, 5, 213, 176, 16,
-            142, 78, 216, 40, 162, 25, 141, 11, 188, 248, 30, 210, 31, 163, 128, 75, 85, 160, 58,
-            176, 187, 39, 87, 199, 87, 164, 214, 101, 23, 26, 161, 102, 141, 46, 78, 231, 201, 60,
-            193, 167, 203, 38, 224, 183, 212, 245, 200, 128, 195, 26, 85, 247, 19, 6, 184, 129,
-            146, 36, 245, 138, 228, 216, 21, 170, 212, 18, 69, 21, 148, 124, 116, 72, 53, 13, 208,
-            37, 217, 253, 6, 212, 128, 40, 219, 41, 78, 113, 46, 62, 53, 196, 90, 196, 164, 54,
-            253, 126, 163, 171, 242, 23, 98, 212, 184, 39, 40, 163, 161, 94, 91, 183, 100, 173,
-            194, 128, 134, 161, 195, 243, 2, 138, 198, 95, 202, 238, 164, 185, 49, 3, 130, 20, 17,
-            174, 158, 221, 122, 51, 240, 97, 103, 182, 227, 64, 20, 199, 20, 49, 128, 255, 72, 48,
-            114, 3, 35, 14, 17, 46, 168, 199, 166, 43, 153, 163, 166, 53, 162, 127, 82, 97, 138,
-            54, 53, 35, 12, 177, 237, 37, 53, 113, 72, 128, 46, 149, 49, 64, 22, 110, 232, 250,
-            216, 176, 185, 77, 36, 109, 242, 191, 137, 155, 42, 3, 180, 61, 5, 146, 70, 239, 219,
-            5, 155, 189, 189, 161, 128, 241, 246, 18, 164, 219, 201, 100, 248, 235, 228, 160, 102,
-            103, 123, 5, 69, 48, 159, 2, 44, 149, 207, 228, 250, 167, 169, 178, 164, 72, 221, 10,
-            171, 128, 165, 52, 248, 115, 98, 191, 225, 147, 225, 201, 200, 167, 240, 3, 242, 222,
-            73, 84, 112, 54, 52, 42, 159, 193, 123, 184, 193, 197, 167, 182, 220, 134, 128, 126,
-            186, 202, 232, 87, 89, 157, 125, 156, 148, 133, 242, 194, 73, 236, 186, 151, 239, 221,
-            24, 95, 9, 136, 87, 139, 73, 49, 113, 145, 93, 7, 62, 128, 172, 197, 129, 8, 203, 252,
-            76, 29, 110, 106, 58, 21, 178, 190, 175, 177, 137, 207, 54, 251, 73, 35, 232, 228, 250,
-            155, 129, 160, 134, 206, 127, 235, 128, 248, 191, 25, 102, 35, 45, 111, 123, 238, 86,
-            29, 254, 128, 68, 227, 175, 192, 93, 19, 217, 162, 138, 47, 252, 170, 21, 173, 80, 187,
-            35, 100, 208, 128, 50, 196, 199, 63, 198, 32, 22, 234, 199, 53, 49, 204, 66, 56, 241,
-            135, 31, 199, 250, 237, 131, 168, 77, 113, 91, 198, 114, 41, 43, 164, 199, 210, 77, 8,
-            128, 255, 255, 128, 161, 170, 188, 190, 218, 88, 84, 19, 91, 88, 194, 120, 165, 126,
-            95, 15, 221, 19, 203, 0, 88, 211, 220, 121, 20, 193, 150, 197, 57, 168, 204, 61, 128,
-            174, 73, 255, 182, 60, 253, 41, 184, 208, 16, 215, 111, 181, 47, 64, 67, 144, 183, 182,
-            191, 233, 211, 225, 217, 247, 13, 18, 235, 176, 230, 25, 21, 128, 245, 160, 188, 5, 44,
-            199, 63, 129, 18, 187, 84, 101, 202, 147, 10, 147, 49, 3, 218, 70, 121, 111, 233, 66,
-            32, 233, 248, 133, 165, 13, 28, 219, 128, 14, 131, 238, 132, 41, 5, 14, 36, 248, 102,
-            22, 239, 179, 79, 208, 144, 227, 144, 199, 3, 94, 206, 131, 69, 146, 217, 208, 123,
-            251, 157, 205, 225, 128, 201, 255, 73, 224, 110, 158, 8, 94, 36, 55, 212, 237, 246, 37,
-            55, 70, 188, 222, 152, 76, 249, 234, 109, 66, 106, 45, 72, 221, 238, 43, 110, 75, 128,
-            38, 150, 145, 120, 219, 99, 149, 1, 78, 49, 180, 66, 12, 123, 211, 181, 120, 66, 152,
-            13, 242, 211, 225, 0, 43, 152, 83, 59, 130, 55, 158, 228, 128, 100, 229, 120, 116, 34,
-            155, 226, 67, 141, 201, 28, 67, 9, 149, 245, 162, 250, 33, 233, 147, 156, 18, 139, 46,
-            109, 175, 110, 62, 250, 255, 210, 189, 128, 222, 185, 16, 105, 45, 31, 111, 154, 244,
-            95, 235, 90, 16, 147, 39, 115, 135, 248, 251, 150, 146, 57, 252, 46, 166, 223, 188, 49,
-            240, 234, 13, 158, 128, 33, 232, 62, 9, 240, 6, 208, 13, 100, 149, 241, 92, 217, 45,
-            171, 37, 171, 218, 162, 174, 16, 47, 220, 90, 75, 48, 68, 124, 218, 138, 55, 107, 128,
-            103, 57, 14, 58, 242, 151, 78, 13, 140, 17, 77, 20, 3, 138, 153, 157, 25, 43, 143, 232,
-            153, 195, 243, 132, 121, 2, 137, 118, 173, 42, 18, 130, 128, 220, 215, 139, 137, 220,
-            192, 81, 98, 186, 83, 66, 246, 89, 5, 28, 36, 157, 107, 51, 113, 149, 84, 177, 143, 36,
-            186, 87, 61, 170, 103, 132, 151, 128, 46, 137, 147, 218, 166, 199, 59, 11, 162, 119,
-            139, 113, 85, 21, 33, 126, 149, 93, 122, 75, 232, 194, 66, 202, 86, 21, 224, 108, 135,
-            52, 133, 99, 128, 136, 117, 227, 97, 91, 152, 206, 125, 157, 195, 110, 114, 146, 128,
-            133, 192, 53, 85, 62, 141, 210, 118, 103, 252, 54, 41, 18, 47, 156, 42, 197, 6, 128,
-            177, 173, 252, 1, 57, 120, 8, 117, 140, 223, 84, 189, 163, 99, 215, 140, 235, 62, 53,
-            235, 56, 186, 37, 242, 41, 101, 60, 179, 144, 127, 14, 167, 128, 108, 64, 169, 3, 47,
-            216, 115, 25, 60, 249, 194, 24, 40, 42, 40, 173, 243, 102, 103, 209, 105, 30, 255, 123,
-            74, 82, 84, 37, 14, 19, 27, 124, 128, 14, 185, 46, 220, 127, 28, 164, 140, 32, 139,
-            241, 98, 71, 73, 138, 142, 127, 110, 90, 3, 172, 238, 101, 44, 220, 132, 205, 186, 94,
-            173, 21, 95, 77, 8, 128, 255, 255, 128, 168, 223, 25, 11, 206, 236, 171, 241, 168, 7,
-            44, 229, 142, 32, 218, 112, 90, 156, 111, 64, 130, 88, 124, 95, 39, 103, 100, 229, 18,
-            248, 121, 81, 128, 173, 73, 101, 206, 33, 240, 110, 100, 50, 233, 29, 115, 6, 148, 121,
-            110, 108, 122, 117, 183, 147, 109, 246, 48, 112, 83, 111, 156, 15, 4, 60, 75, 128, 22,
-            191, 84, 38, 63, 203, 40, 96, 183, 176, 170, 253, 141, 66, 144, 99, 155, 239, 192, 137,
-            33, 5, 0, 155, 134, 161, 228, 213, 51, 241, 199, 5, 128, 255, 29, 100, 191, 213, 214,
-            208, 199, 139, 190, 129, 8, 130, 20, 155, 156, 192, 65, 253, 63, 170, 224, 44, 156, 85,
-            163, 220, 218, 84, 40, 90, 75, 128, 131, 103, 65, 192, 138, 102, 118, 137, 191, 238,
-            116, 89, 53, 132, 154, 108, 119, 7, 57, 224, 123, 106, 166, 147, 108, 163, 73, 159,
-            224, 89, 93, 100, 128, 142, 245, 75, 52, 182, 2, 50, 50, 55, 244, 125, 107, 123, 69,
-            137, 65, 158, 59, 98, 196, 253, 79, 62, 145, 255, 200, 27, 4, 38, 231, 230, 66, 128,
-            32, 76, 194, 99, 158, 73, 99, 190, 128, 213, 50, 172, 63, 224, 128, 120, 196, 33, 85,
-            82, 174, 161, 222, 124, 74, 169, 99, 56, 246, 239, 102, 251, 128, 225, 75, 1, 43, 138,
-            191, 52, 196, 247, 204, 144, 239, 61, 142, 125, 36, 7, 82, 37, 185, 161, 215, 97, 79,
-            249, 120, 241, 151, 162, 113, 193, 240, 128, 78, 100, 19, 32, 69, 26, 151, 245, 12, 12,
-            164, 216, 124, 219, 29, 59, 171, 50, 240, 123, 5, 189, 80, 155, 53, 169, 24, 60, 221,
-            190, 239, 138, 128, 37, 202, 46, 52, 121, 244, 43, 35, 82, 59, 162, 209, 187, 193, 150,
-            50, 18, 61, 162, 156, 172, 159, 5, 34, 120, 183, 237, 222, 15, 51, 98, 15, 128, 214,
-            215, 183, 54, 157, 86, 208, 53, 194, 194, 180, 166, 204, 122, 184, 39, 161, 169, 213,
-            53, 217, 45, 177, 222, 144, 217, 226, 250, 145, 159, 46, 66, 128, 84, 107, 60, 172, 40,
-            120, 176, 253, 9, 181, 23, 51, 211, 193, 124, 85, 37, 210, 8, 243, 129, 188, 254, 242,
-            197, 206, 56, 31, 126, 221, 246, 122, 128, 74, 176, 47, 192, 71, 227, 110, 159, 95,
-            177, 45, 87, 106, 251, 81, 229, 6, 121, 193, 191, 214, 62, 142, 135, 219, 38, 176, 129,
-            80, 46, 186, 86, 128, 57, 42, 237, 125, 44, 113, 246, 243, 236, 154, 250, 193, 180,
-            225, 81, 8, 228, 9, 128, 119, 167, 65, 255, 134, 195, 139, 195, 200, 211, 57, 148, 69,
-            128, 122, 25, 208, 150, 207, 187, 6, 193, 249, 234, 175, 181, 47, 148, 109, 209, 128,
-            129, 18, 214, 156, 2, 17, 122, 23, 114, 165, 66, 16, 87, 128, 78, 128, 133, 118, 210,
-            161, 195, 242, 152, 179, 136, 192, 161, 168, 232, 145, 119, 44, 206, 69, 150, 202, 131,
-            232, 153, 33, 94, 211, 167, 64, 0, 147, 18, 145, 77, 8, 128, 255, 255, 128, 235, 170,
-            176, 106, 31, 135, 91, 27, 168, 205, 4, 210, 244, 245, 153, 39, 146, 173, 71, 237, 34,
-            85, 25, 35, 65, 251, 16, 181, 194, 133, 69, 177, 128, 30, 105, 245, 56, 77, 120, 75,
-            118, 9, 103, 147, 15, 128, 37, 232, 103, 192, 56, 91, 149, 243, 47, 3, 44, 248, 217,
-            163, 21, 81, 78, 7, 44, 128, 219, 63, 87, 8, 229, 222, 132, 250, 1, 225, 51, 237, 254,
-            225, 29, 204, 131, 255, 36, 183, 178, 64, 127, 15, 70, 65, 169, 135, 234, 35, 190, 42,
-            128, 76, 160, 43, 46, 103, 108, 169, 140, 103, 27, 31, 189, 229, 148, 228, 166, 43, 33,
-            214, 75, 165, 53, 37, 39, 41, 130, 233, 94, 21, 229, 163, 118, 128, 120, 29, 226, 227,
-            235, 204, 91, 18, 175, 148, 50, 116, 126, 12, 234, 177, 74, 190, 46, 22, 132, 176, 114,
-            171, 146, 51, 253, 7, 0, 173, 40, 190, 128, 10, 193, 46, 129, 134, 245, 146, 190, 101,
-            121, 204, 152, 197, 80, 63, 220, 158, 200, 3, 88, 43, 44, 24, 165, 47, 181, 36, 193,
-            216, 241, 206, 153, 128, 90, 202, 167, 68, 255, 202, 210, 189, 211, 102, 71, 223, 102,
-            46, 128, 75, 170, 79, 177, 199, 67, 251, 183, 170, 185, 240, 181, 231, 43, 232, 225,
-            37, 128, 57, 84, 84, 151, 169, 159, 193, 167, 123, 121, 3, 115, 144, 171, 214, 20, 201,
-            43, 11, 242, 239, 31, 98, 31, 245, 174, 91, 190, 231, 85, 96, 239, 128, 35, 46, 229,
-            132, 40, 97, 158, 102, 223, 11, 72, 121, 149, 157, 232, 35, 79, 168, 102, 156, 86, 110,
-            1, 163, 100, 191, 166, 149, 195, 160, 170, 76, 128, 147, 191, 101, 119, 78, 36, 203,
-            198, 170, 164, 76, 173, 14, 57, 162, 118, 53, 78, 69, 161, 117, 97, 154, 32, 69, 17,
-            180, 12, 124, 129, 5, 14, 128, 238, 162, 232, 223, 55, 63, 249, 78, 244, 140, 123, 149,
-            7, 55, 164, 246, 80, 99, 65, 115, 207, 179, 161, 63, 52, 204, 24, 202, 215, 242, 56,
-            91, 128, 69, 153, 241, 208, 132, 246, 94, 133, 85, 90, 253, 147, 62, 108, 52, 231, 201,
-            200, 204, 100, 172, 217, 54, 40, 58, 143, 109, 54, 93, 201, 144, 103, 128, 238, 151,
-            217, 228, 185, 160, 7, 251, 223, 94, 194, 138, 47, 1, 36, 8, 197, 236, 30, 85, 222, 21,
-            46, 179, 128, 162, 136, 151, 90, 189, 253, 212, 128, 253, 255, 224, 120, 100, 7, 148,
-            55, 13, 206, 19, 157, 182, 204, 14, 230, 188, 48, 69, 14, 155, 189, 228, 115, 84, 223,
-            109, 202, 174, 60, 215, 10, 128, 71, 87, 138, 22, 28, 81, 197, 47, 104, 24, 131, 127,
-            84, 51, 252, 148, 54, 126, 65, 42, 48, 206, 113, 157, 82, 77, 29, 141, 183, 252, 55,
-            162, 128, 30, 161, 101, 93, 118, 108, 61, 92, 80, 96, 64, 174, 203, 66, 250, 140, 126,
-            84, 178, 13, 191, 111, 201, 6, 8, 253, 43, 80, 178, 215, 207, 63, 77, 8, 128, 255, 255,
-            128, 254, 30, 183, 151, 115, 142, 17, 117, 113, 99, 88, 46, 121, 61, 40, 246, 85, 123,
-            86, 225, 182, 110, 88, 7, 127, 236, 14, 247, 70, 244, 163, 95, 128, 25, 118, 253, 146,
-            237, 189, 40, 204, 170, 7, 92, 76, 35, 100, 223, 137, 144, 220, 46, 13, 101, 243, 110,
-            138, 17, 236, 143, 31, 34, 190, 216, 200, 128, 74, 137, 75, 14, 17, 249, 82, 101, 241,
-            44, 148, 157, 131, 227, 88, 236, 107, 178, 19, 144, 255, 18, 182, 150, 74, 201, 124,
-            242, 141, 123, 121, 194, 128, 250, 151, 110, 6, 119, 107, 220, 202, 108, 18, 127, 86,
-            201, 163, 98, 44, 240, 91, 227, 12, 214, 245, 104, 219, 186, 53, 19, 255, 8, 122, 188,
-            184, 128, 211, 146, 138, 20, 223, 29, 48, 26, 253, 202, 160, 72, 103, 101, 78, 222,
-            176, 49, 168, 126, 253, 186, 119, 249, 220, 62, 132, 135, 196, 129, 116, 158, 128, 30,
-            126, 214, 204, 31, 158, 45, 18, 145, 46, 133, 139, 72, 107, 230, 241, 68, 112, 169, 12,
-            13, 151, 23, 114, 0, 208, 118, 200, 40, 134, 94, 230, 128, 206, 213, 245, 105, 26, 106,
-            101, 23, 189, 76, 239, 199, 85, 40, 254, 229, 155, 194, 221, 190, 45, 96, 147, 175, 87,
-            138, 232, 181, 205, 150, 49, 80, 128, 141, 60, 110, 194, 237, 191, 197, 236, 116, 191,
-            135, 52, 79, 195, 187, 30, 254, 141, 40, 180, 204, 202, 137, 57, 245, 236, 209, 254,
-            232, 102, 172, 72, 128, 228, 113, 46, 103, 38, 244, 186, 42, 112, 88, 56, 48, 50, 241,
-            222, 58, 103, 225, 69, 239, 218, 99, 131, 101, 241, 105, 46, 126, 2, 246, 164, 71, 128,
-            74, 99, 84, 35, 53, 202, 101, 249, 124, 253, 188, 88, 201, 89, 107, 158, 190, 68, 36,
-            233, 21, 90, 130, 64, 9, 18, 59, 117, 41, 63, 74, 7, 128, 154, 164, 8, 129, 49, 88,
-            194, 88, 72, 14, 246, 132, 59, 151, 117, 243, 62, 10, 41, 34, 136, 118, 211, 40, 254,
-            207, 186, 250, 43, 27, 87, 33, 128, 89, 102, 96, 102, 172, 67, 126, 155, 245, 12, 166,
-            66, 237, 38, 24, 131, 52, 181, 1, 198, 204, 160, 195, 248, 144, 117, 252, 103, 13, 126,
-            103, 10, 128, 63, 129, 94, 79, 79, 8, 140, 151, 2, 191, 171, 207, 108, 8, 93, 93, 155,
-            179, 221, 143, 76, 165, 157, 232, 18, 160, 132, 170, 25, 238, 72, 7, 128, 119, 254, 39,
-            23, 181, 253, 242, 111, 177, 210, 15, 3, 56, 7, 104, 89, 7, 32, 59, 114, 109, 158, 109,
-            127, 21, 120, 95, 152, 43, 208, 228, 144, 128, 87, 85, 51, 133, 211, 92, 206, 71, 197,
-            21, 108, 152, 171, 3, 90, 151, 239, 201, 239, 189, 201, 85, 93, 55, 251, 66, 69, 103,
-            157, 210, 91, 233, 128, 186, 242, 201, 220, 156, 216, 1, 119, 120, 25, 180, 119, 197,
-            240, 220, 204, 81, 84, 145, 46, 3, 146, 76, 153, 132, 215, 36, 246, 57, 65, 242, 201,
-            25, 1, 129, 6, 0, 48, 128, 131, 25, 173, 168, 105, 73, 175, 136, 88, 162, 137, 4, 195,
-            98, 183, 150, 76, 188, 117, 194, 168, 216, 16, 113, 45, 63, 115, 227, 18, 202, 211,
-            151, 128, 198, 160, 241, 31, 89, 150, 183, 63, 127, 135, 242, 54, 97, 146, 132, 149,
-            179, 215, 135, 221, 39, 83, 47, 154, 31, 100, 214, 238, 170, 92, 77, 113, 25, 1, 129,
-            9, 0, 3, 128, 222, 9, 163, 161, 209, 171, 162, 251, 92, 193, 64, 233, 220, 234, 67, 42,
-            144, 204, 23, 124, 115, 42, 70, 22, 117, 255, 28, 3, 219, 60, 163, 140, 128, 75, 226,
-            216, 138, 193, 135, 86, 81, 122, 154, 128, 250, 2, 126, 141, 177, 8, 148, 209, 189, 79,
-            63, 221, 101, 210, 239, 242, 29, 46, 231, 227, 100, 141, 7, 157, 14, 73, 7, 247, 22,
-            172, 137, 182, 52, 125, 21, 236, 236, 237, 202, 255, 223, 88, 95, 11, 106, 69, 50, 30,
-            250, 233, 42, 234, 21, 224, 116, 14, 199, 175, 231, 16, 219, 3, 0, 0, 88, 95, 3, 142,
-            113, 97, 36, 145, 25, 45, 104, 222, 171, 126, 111, 86, 63, 225, 16, 41, 1, 0, 0, 128,
-            110, 200, 13, 122, 252, 137, 163, 204, 235, 225, 15, 205, 240, 76, 161, 101, 24, 255,
-            136, 214, 178, 148, 98, 236, 134, 27, 0, 211, 18, 57, 219, 53, 128, 24, 179, 250, 7,
-            252, 211, 24, 112, 36, 254, 110, 123, 88, 175, 104, 227, 26, 244, 141, 143, 123, 92,
-            93, 148, 246, 56, 254, 87, 54, 202, 227, 116, 128, 212, 186, 95, 7, 117, 214, 42, 252,
-            137, 90, 39, 204, 71, 219, 16, 172, 46, 91, 134, 227, 70, 144, 166, 190, 177, 236, 42,
-            57, 145, 233, 105, 61, 128, 14, 214, 94, 167, 86, 123, 217, 88, 156, 68, 3, 216, 251,
-            117, 130, 117, 173, 120, 238, 191, 150, 165, 214, 18, 109, 103, 131, 162, 149, 60, 190,
-            15, 128, 145, 79, 88, 158, 111, 117, 135, 211, 50, 230, 34, 131, 178, 91, 19, 230, 181,
-            115, 168, 181, 12, 173, 102, 237, 72, 30, 125, 22, 166, 190, 163, 226, 128, 150, 97,
-            48, 76, 74, 235, 167, 247, 69, 103, 4, 154, 130, 7, 3, 22, 72, 160, 82, 232, 18, 244,
-            176, 58, 42, 23, 7, 231, 12, 78, 180, 155, 128, 191, 24, 153, 195, 29, 109, 95, 250,
-            129, 242, 53, 146, 183, 178, 157, 20, 56, 109, 0, 24, 132, 32, 212, 75, 125, 0, 101,
-            184, 163, 124, 84, 180, 128, 223, 120, 85, 135, 67, 76, 242, 99, 220, 31, 86, 41, 97,
-            198, 169, 114, 181, 40, 200, 30, 33, 169, 7, 31, 17, 77, 67, 188, 58, 79, 49, 70, 128,
-            65, 72, 245, 149, 209, 123, 119, 9, 68, 98, 196, 190, 181, 249, 88, 108, 108, 19, 163,
-            131, 105, 250, 44, 15, 229, 23, 79, 70, 55, 48, 24, 207, 88, 95, 4, 154, 39, 56, 238,
-            179, 8, 150, 170, 203, 139, 63, 180, 100, 113, 189, 16, 4, 0, 0, 0, 128, 150, 77, 204,
-            23, 91, 96, 20, 177, 216, 5, 108, 247, 200, 200, 199, 101, 114, 110, 31, 183, 134, 248,
-            253, 142, 109, 152, 116, 233, 25, 185, 123, 153, 128, 5, 145, 11, 232, 224, 155, 242,
-            140, 49, 94, 170, 70, 75, 193, 225, 78, 35, 140, 92, 155, 200, 205, 85, 110, 178, 60,
-            35, 99, 30, 6, 200, 106, 128, 180, 147, 63, 242, 56, 9, 87, 70, 86, 7, 181, 212, 101,
-            128, 67, 39, 192, 87, 234, 252, 168, 145, 67, 182, 36, 28, 227, 4, 153, 70, 59, 250,
-            137, 8, 158, 220, 222, 147, 76, 101, 130, 39, 238, 29, 250, 252, 214, 225, 105, 3, 255,
-            255, 128, 212, 192, 107, 17, 26, 200, 122, 195, 120, 105, 34, 67, 13, 110, 222, 20,
-            168, 48, 115, 160, 212, 187, 246, 92, 253, 220, 93, 68, 165, 244, 128, 149, 128, 251,
-            50, 66, 146, 95, 208, 2, 95, 45, 114, 134, 72, 91, 66, 74, 130, 178, 173, 14, 21, 149,
-            16, 128, 230, 116, 61, 76, 168, 24, 113, 226, 218, 128, 130, 145, 33, 106, 121, 80, 96,
-            66, 164, 70, 191, 105, 83, 79, 54, 235, 249, 71, 225, 58, 5, 127, 130, 251, 200, 50,
-            19, 156, 250, 206, 78, 78, 128, 44, 192, 40, 14, 185, 110, 219, 179, 241, 90, 91, 52,
-            41, 102, 199, 113, 82, 132, 131, 96, 38, 20, 10, 30, 59, 82, 176, 198, 176, 87, 21, 87,
-            128, 195, 5, 208, 225, 21, 57, 155, 53, 89, 33, 130, 228, 169, 0, 76, 48, 24, 82, 5,
-            42, 137, 197, 162, 63, 21, 197, 141, 251, 212, 115, 106, 82, 128, 121, 48, 30, 3, 225,
-            115, 179, 164, 175, 222, 54, 84, 122, 135, 160, 236, 243, 69, 251, 160, 241, 3, 245,
-            102, 217, 137, 189, 2, 173, 64, 53, 101, 128, 153, 158, 145, 143, 88, 49, 185, 124, 0,
-            150, 98, 54, 245, 85, 94, 94, 215, 123, 89, 24, 10, 71, 85, 52, 250, 210, 20, 228, 16,
-            149, 211, 151, 128, 35, 126, 133, 35, 101, 156, 135, 154, 57, 170, 188, 113, 130, 140,
-            189, 101, 229, 126, 131, 120, 56, 160, 48, 107, 104, 186, 122, 224, 85, 129, 147, 46,
-            128, 105, 89, 155, 175, 165, 144, 141, 206, 81, 133, 45, 110, 19, 216, 203, 165, 255,
-            113, 78, 67, 243, 110, 156, 71, 109, 18, 18, 115, 92, 216, 173, 77, 128, 123, 141, 236,
-            214, 0, 104, 118, 31, 247, 52, 108, 8, 252, 87, 196, 216, 167, 133, 138, 61, 36, 211,
-            220, 61, 76, 245, 34, 188, 150, 163, 67, 176, 128, 145, 171, 89, 220, 120, 189, 81,
-            183, 187, 110, 153, 163, 54, 248, 154, 229, 38, 99, 248, 20, 255, 92, 198, 245, 44,
-            156, 75, 241, 238, 7, 6, 224, 128, 239, 194, 154, 97, 78, 80, 169, 43, 93, 31, 212,
-            140, 222, 208, 103, 168, 30, 51, 240, 20, 63, 224, 162, 202, 9, 214, 212, 169, 103,
-            167, 181, 110, 128, 97, 138, 136, 94, 17, 95, 133, 176, 236, 165, 222, 28, 208, 47,
-            223, 231, 215, 15, 27, 155, 26, 211, 56, 191, 215, 96, 178, 205, 221, 97, 26, 31, 128,
-            246, 79, 70, 71, 167, 94, 137, 199, 210, 5, 154, 192, 53, 113, 183, 23, 32, 135, 208,
-            76, 45, 4, 58, 15, 13, 182, 41, 24, 188, 104, 66, 111, 128, 68, 251, 235, 134, 154,
-            122, 195, 108, 116, 251, 35, 161, 59, 76, 88, 164, 75, 140, 124, 44, 177, 90, 195, 238,
-            131, 61, 234, 70, 60, 255, 30, 252, 128, 12, 221, 198, 252, 251, 59, 183, 181, 5, 106,
-            13, 14, 159, 159, 196, 193, 91, 10, 108, 175, 177, 207, 135, 14, 162, 164, 53, 52, 72,
-            212, 240, 144,
-        ],
-        &[
-            96, 184, 127, 12, 1, 132, 144, 247, 197, 89, 186, 156, 242, 51, 236, 248, 166, 61, 229,
-            136, 188, 27, 69, 36, 5, 183, 151, 55, 208, 139, 55, 159, 79, 207, 54, 44, 141, 123,
-            11, 84, 19, 56, 20, 2, 40, 107, 238, 0, 184, 127, 12, 1, 177, 138, 23, 143, 132, 106,
-            173, 175, 195, 42, 37, 219, 38, 152, 64, 119, 131, 211, 46, 119, 65, 98, 53, 4, 111,
-            156, 18, 224, 94, 47, 198, 84, 104, 44, 240, 76, 114, 20, 2, 40, 107, 238, 0, 172, 127,
-            12, 5, 40, 37, 249, 157, 191, 12, 67, 132, 246, 190, 52, 186, 161, 32, 28, 146, 148,
-            212, 159, 59, 173, 183, 175, 207, 124, 139, 108, 126, 243, 65, 126, 242, 108, 147, 160,
-            77, 80, 8, 4, 0, 184, 127, 12, 7, 136, 35, 250, 98, 249, 10, 92, 163, 224, 125, 105,
-            222, 109, 115, 29, 130, 78, 182, 9, 91, 246, 249, 35, 220, 47, 94, 146, 211, 102, 80,
-            33, 161, 210, 28, 102, 189, 92, 20, 2, 194, 235, 11, 0, 184, 127, 12, 11, 144, 175, 31,
-            105, 130, 140, 35, 50, 75, 12, 178, 158, 79, 209, 166, 140, 176, 143, 235, 229, 139,
-            80, 227, 157, 138, 253, 181, 247, 82, 214, 194, 108, 139, 165, 47, 192, 2, 20, 2, 225,
-            245, 5, 0, 184, 127, 12, 12, 6, 131, 27, 166, 169, 102, 12, 127, 25, 37, 51, 14, 9, 55,
-            3, 73, 214, 188, 64, 158, 53, 198, 40, 158, 160, 111, 230, 128, 200, 236, 186, 17, 147,
-            227, 234, 19, 106, 20, 2, 40, 107, 238, 0, 21, 1, 128, 0, 10, 128, 151, 166, 57, 120,
-            179, 75, 196, 69, 227, 110, 199, 86, 31, 194, 137, 57, 88, 239, 242, 65, 184, 230, 171,
-            25, 45, 188, 194, 175, 11, 92, 53, 177, 128, 58, 112, 80, 71, 117, 104, 20, 70, 26, 51,
-            185, 148, 54, 219, 0, 116, 210, 70, 116, 111, 146, 29, 199, 72, 151, 207, 141, 186,
-            122, 134, 209, 195, 21, 1, 128, 0, 10, 128, 243, 187, 87, 241, 55, 51, 35, 119, 150,
-            73, 231, 208, 113, 62, 170, 177, 24, 27, 183, 71, 160, 207, 75, 216, 174, 135, 219,
-            102, 103, 226, 241, 253, 128, 62, 46, 249, 76, 115, 73, 0, 37, 9, 26, 21, 189, 67, 170,
-            143, 247, 45, 52, 113, 73, 108, 171, 38, 72, 125, 53, 169, 162, 105, 253, 173, 161,
-            153, 1, 128, 1, 9, 128, 182, 176, 86, 103, 130, 112, 152, 70, 130, 123, 97, 130, 253,
-            202, 191, 114, 214, 5, 255, 18, 214, 188, 64, 45, 189, 70, 119, 130, 247, 143, 146, 25,
-            128, 222, 84, 191, 139, 38, 192, 231, 202, 43, 181, 111, 182, 5, 135, 40, 28, 47, 210,
-            52, 7, 8, 92, 121, 239, 243, 65, 5, 1, 245, 93, 26, 21, 128, 184, 185, 201, 70, 229,
-            20, 169, 131, 43, 58, 19, 47, 56, 193, 99, 137, 165, 239, 91, 197, 213, 64, 238, 202,
-            57, 22, 108, 109, 197, 119, 57, 10, 153, 1, 128, 1, 144, 128, 21, 128, 171, 91, 28, 68,
-            227, 29, 171, 149, 8, 252, 76, 113, 13, 128, 97, 167, 49, 177, 152, 172, 155, 64, 212,
-            178, 180, 93, 244, 51, 6, 39, 128, 126, 107, 34, 59, 69, 6, 67, 70, 79, 86, 249, 72,
-            161, 122, 212, 192, 93, 174, 248, 25, 226, 34, 184, 244, 144, 164, 6, 171, 173, 118,
-            195, 159, 128, 241, 254, 244, 155, 200, 49, 12, 118, 133, 129, 7, 120, 80, 24, 65, 237,
-            125, 34, 62, 9, 239, 48, 29, 43, 163, 152, 36, 39, 242, 113, 119, 154, 21, 1, 128, 2,
-            2, 128, 145, 164, 48, 145, 174, 151, 23, 165, 212, 19, 9, 172, 230, 175, 92, 26, 24,
-            160, 18, 220, 217, 250, 187, 65, 230, 0, 250, 57, 3, 196, 134, 183, 128, 121, 44, 158,
-            95, 85, 171, 141, 171, 8, 61, 76, 205, 77, 32, 8, 195, 91, 87, 132, 247, 81, 79, 198,
-            27, 9, 174, 154, 140, 39, 120, 103, 119, 153, 1, 128, 4, 33, 128, 209, 170, 213, 100,
-            195, 186, 56, 45, 113, 62, 12, 218, 215, 54, 101, 11, 144, 224, 200, 169, 77, 37, 45,
-            119, 8, 150, 227, 87, 38, 214, 135, 215, 128, 5, 44, 105, 45, 254, 142, 215, 199, 70,
-            4, 91, 143, 228, 251, 185, 13, 132, 58, 51, 94, 133, 94, 184, 62, 121, 29, 64, 179,
-            131, 226, 52, 20, 128, 25, 6, 250, 231, 47, 248, 136, 92, 44, 66, 67, 138, 49, 104,
-            255, 67, 200, 197, 98, 58, 241, 51, 188, 230, 246, 248, 77, 241, 233, 212, 76, 138,
-            153, 1, 128, 12, 2, 128, 238, 137, 237, 115, 49, 164, 218, 12, 210, 155, 191, 14, 64,
-            100, 28, 89, 68, 22, 41, 5, 36, 244, 195, 253, 122, 3, 45, 20, 174, 230, 130, 168, 128,
-            67, 211, 156, 230, 184, 3, 172, 115, 156, 201, 25, 198, 245, 134, 141, 46, 176, 144,
-            168, 93, 204, 208, 84, 103, 232, 127, 128, 119, 85, 73, 242, 132, 128, 245, 172, 7,
-            230, 107, 246, 103, 139, 204, 44, 209, 95, 107, 145, 71, 96, 179, 166, 58, 114, 240,
-            232, 168, 7, 13, 226, 92, 2, 58, 9, 225, 1, 21, 1, 128, 32, 64, 128, 152, 146, 187, 83,
-            177, 101, 61, 49, 70, 14, 189, 51, 71, 149, 126, 118, 245, 142, 141, 75, 145, 36, 65,
-            20, 93, 26, 252, 181, 59, 32, 44, 184, 128, 120, 239, 122, 49, 165, 174, 229, 116, 34,
-            136, 252, 74, 136, 87, 40, 243, 138, 21, 154, 110, 162, 191, 235, 216, 43, 67, 205,
-            213, 170, 4, 106, 92, 161, 2, 128, 80, 100, 128, 39, 63, 234, 110, 14, 74, 205, 56, 71,
-            30, 146, 173, 17, 255, 71, 194, 53, 2, 40, 246, 49, 3, 200, 87, 60, 12, 174, 3, 173,
-            232, 91, 75, 128, 106, 229, 188, 61, 215, 82, 61, 241, 83, 228, 209, 90, 90, 108, 97,
-            188, 193, 19, 51, 127, 22, 65, 217, 243, 130, 144, 99, 124, 11, 64, 50, 154, 128, 165,
-            225, 155, 46, 25, 48, 186, 139, 231, 131, 131, 104, 138, 213, 226, 12, 85, 196, 56,
-            188, 172, 219, 66, 227, 7, 184, 122, 206, 244, 217, 95, 203, 128, 226, 61, 190, 51, 27,
-            20, 129, 141, 145, 191, 146, 104, 112, 127, 14, 243, 219, 240, 92, 28, 240, 164, 140,
-            202, 177, 83, 242, 216, 184, 254, 187, 49, 128, 45, 103, 242, 215, 209, 38, 239, 115,
-            143, 125, 133, 0, 242, 245, 230, 151, 97, 127, 198, 197, 207, 68, 181, 189, 235, 243,
-            2, 119, 147, 29, 232, 236, 21, 1, 128, 96, 0, 128, 159, 224, 152, 91, 85, 58, 120, 77,
-            126, 158, 78, 222, 12, 63, 23, 174, 19, 24, 132, 234, 254, 100, 168, 16, 119, 193, 81,
-            33, 84, 145, 253, 216, 128, 76, 106, 89, 245, 47, 41, 72, 11, 113, 19, 37, 199, 3, 80,
-            1, 38, 164, 254, 98, 203, 198, 2, 208, 61, 40, 163, 229, 190, 8, 226, 129, 201, 21, 1,
-            128, 129, 0, 128, 60, 4, 68, 35, 110, 13, 93, 174, 76, 72, 123, 100, 213, 71, 85, 179,
-            33, 73, 61, 95, 45, 179, 218, 29, 233, 235, 223, 147, 15, 31, 254, 120, 128, 25, 104,
-            122, 81, 176, 24, 168, 68, 201, 234, 148, 209, 56, 23, 46, 10, 16, 59, 173, 96, 212,
-            181, 46, 66, 97, 187, 65, 101, 174, 56, 88, 126, 29, 2, 128, 150, 0, 128, 218, 77, 152,
-            86, 147, 47, 61, 233, 42, 203, 156, 234, 106, 106, 27, 71, 74, 249, 139, 138, 201, 33,
-            142, 47, 203, 59, 206, 107, 221, 214, 1, 60, 128, 211, 140, 144, 183, 230, 114, 102,
-            64, 57, 7, 95, 85, 57, 154, 35, 37, 84, 167, 100, 200, 8, 148, 235, 2, 108, 108, 205,
-            137, 27, 167, 142, 40, 128, 159, 0, 51, 108, 247, 99, 43, 248, 208, 184, 52, 34, 133,
-            133, 168, 128, 70, 199, 201, 92, 136, 219, 173, 157, 110, 21, 47, 2, 29, 48, 231, 21,
-            128, 165, 87, 61, 23, 192, 51, 225, 219, 183, 165, 43, 219, 137, 209, 112, 74, 254,
-            223, 27, 207, 15, 104, 253, 64, 244, 92, 24, 8, 57, 121, 24, 127, 201, 7, 128, 255,
-            191, 128, 158, 63, 149, 1, 58, 90, 199, 8, 180, 42, 100, 95, 68, 184, 64, 112, 145, 78,
-            130, 76, 221, 11, 40, 239, 41, 58, 200, 68, 13, 72, 188, 165, 128, 204, 220, 74, 251,
-            110, 231, 249, 175, 205, 130, 132, 250, 22, 40, 138, 163, 127, 121, 154, 73, 233, 16,
-            94, 82, 45, 183, 72, 11, 43, 119, 87, 234, 128, 88, 86, 140, 228, 43, 157, 244, 179,
-            181, 121, 251, 212, 56, 35, 194, 12, 228, 254, 104, 65, 27, 165, 158, 216, 188, 139,
-            168, 225, 61, 132, 202, 76, 128, 201, 23, 138, 207, 176, 44, 214, 85, 204, 70, 100, 4,
-            116, 82, 71, 228, 241, 90, 108, 43, 26, 68, 144, 18, 122, 64, 161, 70, 98, 251, 10,
-            252, 128, 110, 47, 111, 14, 41, 34, 78, 147, 203, 192, 168, 4, 197, 79, 134, 68, 109,
-            231, 253, 158, 107, 203, 91, 130, 151, 98, 180, 213, 9, 189, 68, 80, 128, 4, 201, 186,
-            96, 21, 36, 18, 155, 170, 90, 58, 226, 214, 67, 127, 237, 196, 222, 226, 174, 36, 51,
-            157, 46, 240, 74, 122, 183, 135, 39, 48, 186, 128, 170, 114, 149, 137, 242, 192, 204,
-            114, 121, 187, 191, 250, 8, 227, 127, 93, 220, 108, 194, 11, 178, 203, 61, 123, 11,
-            240, 189, 208, 206, 163, 200, 140, 128, 248, 91, 155, 199, 230, 136, 20, 51, 123, 8,
-            11, 178, 38, 69, 109, 117, 201, 28, 130, 75, 142, 190, 181, 184, 152, 190, 68, 115,
-            200, 51, 207, 108, 128, 209, 155, 60, 4, 152, 91, 147, 234, 238, 184, 1, 28, 148, 0,
-            120, 154, 38, 123, 111, 197, 112, 199, 2, 246, 233, 50, 71, 25, 87, 61, 100, 229, 128,
-            239, 53, 195, 76, 247, 20, 70, 41, 115, 0, 224, 30, 90, 189, 247, 57, 116, 102, 92, 3,
-            138, 59, 45, 103, 186, 65, 237, 78, 15, 134, 29, 177, 128, 220, 55, 8, 25, 116, 102,
-            167, 225, 3, 235, 164, 6, 67, 84, 93, 44, 192, 179, 134, 137, 114, 64, 202, 131, 226,
-            175, 96, 99, 103, 199, 178, 109, 128, 100, 45, 137, 234, 36, 180, 77, 29, 187, 234, 88,
-            127, 142, 106, 2, 148, 5, 11, 91, 223, 10, 213, 40, 60, 178, 56, 121, 179, 79, 196, 48,
-            49, 128, 229, 200, 177, 66, 203, 20, 230, 123, 193, 84, 65, 201, 133, 24, 68, 116, 225,
-            116, 176, 21, 130, 113, 115, 14, 112, 131, 6, 183, 93, 119, 144, 20, 128, 56, 87, 4,
-            116, 11, 175, 92, 158, 44, 17, 84, 84, 85, 175, 230, 255, 86, 111, 189, 17, 10, 242,
-            108, 19, 96, 55, 130, 150, 66, 30, 80, 122, 128, 99, 209, 106, 138, 72, 129, 164, 121,
-            105, 105, 236, 38, 195, 244, 250, 153, 205, 101, 159, 223, 245, 83, 115, 131, 230, 213,
-            201, 110, 78, 17, 82, 225, 77, 8, 128, 255, 255, 128, 6, 32, 133, 84, 101, 18, 126,
-            217, 191, 6, 177, 132, 244, 120, 216, 103, 150, 164, 175, 134, 187, 115, 179, 22, 186,
-            234, 226, 161, 121, 26, 222, 55, 128, 46, 76, 12, 88, 33, 101, 141, 239, 249, 204, 50,
-            40, 146, 72, 53, 196, 237, 137, 201, 156, 162, 175, 126, 235, 75, 129, 36, 170, 173,
-            227, 151, 84, 128, 150, 97, 175, 74, 255, 77, 186, 203, 201, 206, 163, 28, 53, 137,
-            246, 23, 243, 50, 33, 254, 102, 73, 205, 72, 95, 66, 237, 244, 178, 220, 95, 243, 128,
-            248, 133, 191, 217, 234, 55, 50, 83, 196, 84, 222, 191, 3, 25, 251, 225, 206, 154, 97,
-            250, 230, 7, 18, 148, 82, 158, 159, 24, 229, 187, 78, 248, 128, 53, 90, 65, 180, 241,
-            62, 98, 76, 77, 130, 116, 124, 57, 131, 192, 159, 129, 74, 73, 156, 182, 254, 24, 66,
-            120, 29, 111, 104, 113, 116, 87, 177, 128, 196, 149, 246, 233, 244, 233, 39, 94, 22,
-            147, 84, 71, 207, 212, 192, 32, 144, 53, 0, 121, 159, 67, 225, 200, 168, 120, 90, 201,
-            19, 105, 76, 107, 128, 16, 175, 11, 70, 56, 176, 152, 223, 221, 147, 33, 254, 212, 24,
-            192, 57, 46, 100, 39, 187, 73, 8, 172, 4, 82, 249, 0, 159, 110, 17, 65, 123, 128, 246,
-            155, 84, 144, 169, 142, 185, 181, 234, 141, 158, 150, 32, 54, 105, 130, 210, 235, 115,
-            71, 136, 249, 50, 98, 125, 33, 20, 63, 7, 21, 164, 206, 128, 235, 123, 178, 124, 30,
-            33, 51, 40, 195, 132, 173, 39, 202, 95, 110, 0, 162, 23, 137, 201, 55, 15, 57, 106,
-            235, 188, 156, 61, 114, 10, 174, 41, 128, 42, 252, 113, 214, 77, 16, 238, 32, 236, 70,
-            139, 69, 68, 26, 121, 55, 233, 83, 116, 152, 48, 185, 130, 225, 142, 192, 112, 218,
-            253, 171, 126, 230, 128, 74, 251, 28, 15, 44, 86, 24, 172, 214, 56, 125, 22, 226, 30,
-            144, 222, 170, 183, 86, 162, 193, 59, 163, 11, 245, 67, 117, 43, 41, 211, 246, 146,
-            128, 163, 87, 215, 73, 240, 35, 44, 127, 73, 101, 212, 114, 170, 201, 100, 246, 17,
-            253, 119, 168, 232, 187, 51, 39, 139, 234, 2, 152, 243, 120, 44, 228, 128, 132, 220, 6,
-            255, 222, 136, 239, 82, 249, 233, 142, 5, 148, 32, 136, 57, 224, 142, 86, 126, 17, 138,
-            137, 68, 89, 197, 42, 134, 72, 54, 105, 250, 128, 92, 187, 114, 111, 209, 220, 174,
-            223, 213, 39, 229, 13, 50, 234, 154, 124, 62, 120, 168, 20, 137, 91, 147, 151, 6, 46,
-            70, 148, 199, 180, 0, 128, 128, 253, 216, 104, 15, 195, 83, 31, 85, 6, 177, 192, 180,
-            67, 107, 77, 9, 12, 55, 182, 235, 115, 200, 71, 68, 190, 10, 25, 215, 53, 44, 255, 156,
-            128, 60, 106, 75, 142, 183, 243, 52, 207, 38, 138, 150, 93, 200, 67, 157, 246, 180, 22,
-            137, 12, 131, 69, 201, 138, 5, 64, 84, 62, 64, 78, 179, 199, 77, 8, 128, 255, 255, 128,
-            98, 224, 118, 12, 76, 51, 177, 134, 55, 11, 240, 54, 16, 130, 71, 53, 233, 48, 237,
-            233, 145, 55, 196, 116, 75, 125, 25, 10, 222, 110, 145, 72, 128, 123, 97, 129, 68, 255,
-            146, 109, 130, 170, 164, 51, 127, 247, 61, 60, 39, 45, 247, 96, 65, 102, 249, 254, 240,
-            235, 150, 72, 191, 254, 139, 212, 143, 128, 10, 145, 115, 111, 253, 223, 196, 17, 64,
-            94, 25, 146, 103, 246, 48, 225, 201, 116, 96, 194, 171, 54, 38, 98, 86, 3, 3, 71, 129,
-            31, 6, 175, 128, 199, 195, 90, 57, 51, 127, 202, 28, 248, 27, 156, 174, 209, 38, 194,
-            27, 143, 96, 209, 92, 159, 32, 96, 206, 102, 91, 145, 17, 100, 34, 161, 225, 128, 111,
-            174, 226, 248, 116, 189, 185, 98, 48, 158, 21, 64, 84, 37, 132, 251, 159, 119, 145,
-            158, 160, 94, 50, 14, 78, 36, 7, 69, 225, 169, 118, 163, 128, 130, 131, 93, 107, 228,
-            124, 194, 73, 133, 43, 145, 205, 67, 139, 12, 122, 202, 182, 137, 8, 117, 46, 210, 14,
-            252, 151, 44, 230, 172, 18, 153, 208, 128, 90, 123, 171, 183, 168, 73, 23, 229, 112,
-            45, 203, 101, 146, 205, 166, 179, 139, 173, 186, 161, 12, 37, 201, 110, 4, 24, 59, 86,
-            176, 85, 19, 214, 128, 34, 137, 24, 131, 123, 49, 233, 144, 163, 244, 68, 122, 147,
-            162, 184, 83, 218, 111, 153, 130, 208, 164, 104, 189, 32, 120, 168, 115, 238, 172, 147,
-            241, 128, 253, 185, 119, 165, 240, 12, 10, 37, 37, 189, 229, 131, 173, 106, 160, 226,
-            95, 234, 200, 170, 142, 79, 97, 231, 226, 69, 40, 164, 129, 29, 119, 217, 128, 215,
-            202, 10, 223, 65, 233, 98, 218, 223, 110, 11, 98, 108, 232, 78, 52, 25, 27, 11, 140,
-            93, 219, 35, 105, 104, 192, 213, 107, 103, 160, 147, 131, 128, 213, 23, 236, 208, 56,
-            116, 59, 227, 151, 243, 244, 198, 4, 58, 214, 202, 73, 178, 35, 192, 153, 13, 96, 246,
-            250, 18, 143, 18, 70, 157, 58, 4, 128, 50, 104, 164, 63, 181, 151, 229, 156, 25, 254,
-            186, 145, 93, 195, 131, 202, 231, 191, 255, 114, 215, 176, 156, 119, 36, 246, 10, 30,
-            88, 250, 251, 240, 128, 103, 233, 178, 7, 124, 202, 44, 82, 24, 123, 141, 45, 162, 11,
-            34, 22, 42, 104, 22, 81, 73, 15, 223, 49, 220, 156, 80, 188, 243, 88, 152, 39, 128, 24,
-            57, 95, 182, 144, 171, 157, 147, 39, 2, 248, 223, 190, 4, 238, 26, 205, 51, 243, 21,
-            92, 81, 253, 196, 58, 117, 8, 176, 3, 193, 96, 167, 128, 151, 147, 172, 137, 135, 140,
-            127, 19, 211, 158, 45, 240, 247, 87, 188, 12, 50, 211, 176, 157, 204, 105, 116, 196,
-            132, 232, 58, 25, 94, 127, 118, 37, 128, 243, 24, 174, 152, 134, 125, 213, 8, 179, 123,
-            215, 74, 241, 38, 134, 46, 15, 246, 224, 164, 243, 101, 224, 238, 242, 220, 108, 49, 7,
-            49, 19, 171, 77, 8, 128, 255, 255, 128, 254, 30, 183, 151, 115, 142, 17, 117, 113, 99,
-            88, 46, 121, 61, 40, 246, 85, 123, 86, 225, 182, 110, 88, 7, 127, 236, 14, 247, 70,
-            244, 163, 95, 128, 25, 118, 253, 146, 237, 189, 40, 204, 170, 7, 92, 76, 35, 100, 223,
-            137, 144, 220, 46, 13, 101, 243, 110, 138, 17, 236, 143, 31, 34, 190, 216, 200, 128,
-            74, 137, 75, 14, 17, 249, 82, 101, 241, 44, 148, 157, 131, 227, 88, 236, 107, 178, 19,
-            144, 255, 18, 182, 150, 74, 201, 124, 242, 141, 123, 121, 194, 128, 250, 151, 110, 6,
-            119, 107, 220, 202, 108, 18, 127, 86, 201, 163, 98, 44, 240, 91, 227, 12, 214, 245,
-            104, 219, 186, 53, 19, 255, 8, 122, 188, 184, 128, 211, 146, 138, 20, 223, 29, 48, 26,
-            253, 202, 160, 72, 103, 101, 78, 222, 176, 49, 168, 126, 253, 186, 119, 249, 220, 62,
-            132, 135, 196, 129, 116, 158, 128, 30, 126, 214, 204, 31, 158, 45, 18, 145, 46, 133,
-            139, 72, 107, 230, 241, 68, 112, 169, 12, 13, 151, 23, 114, 0, 208, 118, 200, 40, 134,
-            94, 230, 128, 206, 213, 245, 105, 26, 106, 101, 23, 189, 76, 239, 199, 85, 40, 254,
-            229, 155, 194, 221, 190, 45, 96, 147, 175, 87, 138, 232, 181, 205, 150, 49, 80, 128,
-            141, 60, 110, 194, 237, 191, 197, 236, 116, 191, 135, 52, 79, 195, 187, 30, 254, 141,
-            40, 180, 204, 202, 137, 57, 245, 236, 209, 254, 232, 102, 172, 72, 128, 228, 113, 46,
-            103, 38, 244, 186, 42, 112, 88, 56, 48, 50, 241, 222, 58, 103, 225, 69, 239, 218, 99,
-            131, 101, 241, 105, 46, 126, 2, 246, 164, 71, 128, 74, 99, 84, 35, 53, 202, 101, 249,
-            124, 253, 188, 88, 201, 89, 107, 158, 190, 68, 36, 233, 21, 90, 130, 64, 9, 18, 59,
-            117, 41, 63, 74, 7, 128, 154, 164, 8, 129, 49, 88, 194, 88, 72, 14, 246, 132, 59, 151,
-            117, 243, 62, 10, 41, 34, 136, 118, 211, 40, 254, 207, 186, 250, 43, 27, 87, 33, 128,
-            89, 102, 96, 102, 172, 67, 126, 155, 245, 12, 166, 66, 237, 38, 24, 131, 52, 181, 1,
-            198, 204, 160, 195, 248, 144, 117, 252, 103, 13, 126, 103, 10, 128, 63, 129, 94, 79,
-            79, 8, 140, 151, 2, 191, 171, 207, 108, 8, 93, 93, 155, 179, 221, 143, 76, 165, 157,
-            232, 18, 160, 132, 170, 25, 238, 72, 7, 128, 119, 254, 39, 23, 181, 253, 242, 111, 177,
-            210, 15, 3, 56, 7, 104, 89, 7, 32, 59, 114, 109, 158, 109, 127, 21, 120, 95, 152, 43,
-            208, 228, 144, 128, 87, 85, 51, 133, 211, 92, 206, 71, 197, 21, 108, 152, 171, 3, 90,
-            151, 239, 201, 239, 189, 201, 85, 93, 55, 251, 66, 69, 103, 157, 210, 91, 233, 128,
-            186, 242, 201, 220, 156, 216, 1, 119, 120, 25, 180, 119, 197, 240, 220, 204, 81, 84,
-            145, 46, 3, 146, 76, 153, 132, 215, 36, 246, 57, 65, 242, 201, 141, 7, 157, 14, 73, 7,
-            247, 22, 172, 137, 182, 52, 125, 21, 236, 236, 237, 202, 255, 223, 88, 95, 11, 106, 69,
-            50, 30, 250, 233, 42, 234, 21, 224, 116, 14, 199, 175, 231, 16, 219, 3, 0, 0, 88, 95,
-            3, 142, 113, 97, 36, 145, 25, 45, 104, 222, 171, 126, 111, 86, 63, 225, 16, 41, 1, 0,
-            0, 128, 110, 200, 13, 122, 252, 137, 163, 204, 235, 225, 15, 205, 240, 76, 161, 101,
-            24, 255, 136, 214, 178, 148, 98, 236, 134, 27, 0, 211, 18, 57, 219, 53, 128, 24, 179,
-            250, 7, 252, 211, 24, 112, 36, 254, 110, 123, 88, 175, 104, 227, 26, 244, 141, 143,
-            123, 92, 93, 148, 246, 56, 254, 87, 54, 202, 227, 116, 128, 212, 186, 95, 7, 117, 214,
-            42, 252, 137, 90, 39, 204, 71, 219, 16, 172, 46, 91, 134, 227, 70, 144, 166, 190, 177,
-            236, 42, 57, 145, 233, 105, 61, 128, 14, 214, 94, 167, 86, 123, 217, 88, 156, 68, 3,
-            216, 251, 117, 130, 117, 173, 120, 238, 191, 150, 165, 214, 18, 109, 103, 131, 162,
-            149, 60, 190, 15, 128, 145, 79, 88, 158, 111, 117, 135, 211, 50, 230, 34, 131, 178, 91,
-            19, 230, 181, 115, 168, 181, 12, 173, 102, 237, 72, 30, 125, 22, 166, 190, 163, 226,
-            128, 150, 97, 48, 76, 74, 235, 167, 247, 69, 103, 4, 154, 130, 7, 3, 22, 72, 160, 82,
-            232, 18, 244, 176, 58, 42, 23, 7, 231, 12, 78, 180, 155, 128, 191, 24, 153, 195, 29,
-            109, 95, 250, 129, 242, 53, 146, 183, 178, 157, 20, 56, 109, 0, 24, 132, 32, 212, 75,
-            125, 0, 101, 184, 163, 124, 84, 180, 128, 223, 120, 85, 135, 67, 76, 242, 99, 220, 31,
-            86, 41, 97, 198, 169, 114, 181, 40, 200, 30, 33, 169, 7, 31, 17, 77, 67, 188, 58, 79,
-            49, 70, 128, 65, 72, 245, 149, 209, 123, 119, 9, 68, 98, 196, 190, 181, 249, 88, 108,
-            108, 19, 163, 131, 105, 250, 44, 15, 229, 23, 79, 70, 55, 48, 24, 207, 88, 95, 4, 154,
-            39, 56, 238, 179, 8, 150, 170, 203, 139, 63, 180, 100, 113, 189, 16, 4, 0, 0, 0, 128,
-            150, 77, 204, 23, 91, 96, 20, 177, 216, 5, 108, 247, 200, 200, 199, 101, 114, 110, 31,
-            183, 134, 248, 253, 142, 109, 152, 116, 233, 25, 185, 123, 153, 128, 5, 145, 11, 232,
-            224, 155, 242, 140, 49, 94, 170, 70, 75, 193, 225, 78, 35, 140, 92, 155, 200, 205, 85,
-            110, 178, 60, 35, 99, 30, 6, 200, 106, 128, 180, 147, 63, 242, 56, 9, 87, 70, 86, 7,
-            181, 212, 101, 128, 67, 39, 192, 87, 234, 252, 168, 145, 67, 182, 36, 28, 227, 4, 153,
-            70, 59, 250, 137, 8, 158, 220, 222, 147, 76, 101, 130, 39, 238, 29, 250, 252, 214, 225,
-            105, 3, 255, 255, 128, 212, 192, 107, 17, 26, 200, 122, 195, 120, 105, 34, 67, 13, 110,
-            222, 20, 168, 48, 115, 160, 212, 187, 246, 92, 253, 220, 93, 68, 165, 244, 128, 149,
-            128, 251, 50, 66, 146, 95, 208, 2, 95, 45, 114, 134, 72, 91, 66, 74, 130, 178, 173, 14,
-            21, 149, 16, 128, 230, 116, 61, 76, 168, 24, 113, 226, 218, 128, 130, 145, 33, 106,
-            121, 80, 96, 66, 164, 70, 191, 105, 83, 79, 54, 235, 249, 71, 225, 58, 5, 127, 130,
-            251, 200, 50, 19, 156, 250, 206, 78, 78, 128, 44, 192, 40, 14, 185, 110, 219, 179, 241,
-            90, 91, 52, 41, 102, 199, 113, 82, 132, 131, 96, 38, 20, 10, 30, 59, 82, 176, 198, 176,
-            87, 21, 87, 128, 195, 5, 208, 225, 21, 57, 155, 53, 89, 33, 130, 228, 169, 0, 76, 48,
-            24, 82, 5, 42, 137, 197, 162, 63, 21, 197, 141, 251, 212, 115, 106, 82, 128, 121, 48,
-            30, 3, 225, 115, 179, 164, 175, 222, 54, 84, 122, 135, 160, 236, 243, 69, 251, 160,
-            241, 3, 245, 102, 217, 137, 189, 2, 173, 64, 53, 101, 128, 153, 158, 145, 143, 88, 49,
-            185, 124, 0, 150, 98, 54, 245, 85, 94, 94, 215, 123, 89, 24, 10, 71, 85, 52, 250, 210,
-            20, 228, 16, 149, 211, 151, 128, 35, 126, 133, 35, 101, 156, 135, 154, 57, 170, 188,
-            113, 130, 140, 189, 101, 229, 126, 131, 120, 56, 160, 48, 107, 104, 186, 122, 224, 85,
-            129, 147, 46, 128, 105, 89, 155, 175, 165, 144, 141, 206, 81, 133, 45, 110, 19, 216,
-            203, 165, 255, 113, 78, 67, 243, 110, 156, 71, 109, 18, 18, 115, 92, 216, 173, 77, 128,
-            123, 141, 236, 214, 0, 104, 118, 31, 247, 52, 108, 8, 252, 87, 196, 216, 167, 133, 138,
-            61, 36, 211, 220, 61, 76, 245, 34, 188, 150, 163, 67, 176, 128, 145, 171, 89, 220, 120,
-            189, 81, 183, 187, 110, 153, 163, 54, 248, 154, 229, 38, 99, 248, 20, 255, 92, 198,
-            245, 44, 156, 75, 241, 238, 7, 6, 224, 128, 239, 194, 154, 97, 78, 80, 169, 43, 93, 31,
-            212, 140, 222, 208, 103, 168, 30, 51, 240, 20, 63, 224, 162, 202, 9, 214, 212, 169,
-            103, 167, 181, 110, 128, 97, 138, 136, 94, 17, 95, 133, 176, 236, 165, 222, 28, 208,
-            47, 223, 231, 215, 15, 27, 155, 26, 211, 56, 191, 215, 96, 178, 205, 221, 97, 26, 31,
-            128, 246, 79, 70, 71, 167, 94, 137, 199, 210, 5, 154, 192, 53, 113, 183, 23, 32, 135,
-            208, 76, 45, 4, 58, 15, 13, 182, 41, 24, 188, 104, 66, 111, 128, 68, 251, 235, 134,
-            154, 122, 195, 108, 116, 251, 35, 161, 59, 76, 88, 164, 75, 140, 124, 44, 177, 90, 195,
-            238, 131, 61, 234, 70, 60, 255, 30, 252, 128, 12, 221, 198, 252, 251, 59, 183, 181, 5,
-            106, 13, 14, 159, 159, 196, 193, 91, 10, 108, 175, 177, 207, 135, 14, 162, 164, 53, 52,
-            72, 212, 240, 144,
-        ],
-    ];
-
     const EXPECTED: &[&[u8]] = &[
         &[
             95, 62, 73, 7, 247, 22, 172, 137, 182, 52, 125, 21, 236, 236, 237, 202, 136, 220, 222,
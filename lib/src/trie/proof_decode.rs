@@ -38,7 +38,7 @@
 //! Once decoded, one can examine the content of the proof, in other words the list of storage
 //! items and values.
 
-use super::{nibble, trie_node, TrieEntryVersion};
+use super::{nibble, trie_node, TrieEntryVersion, CHILD_STORAGE_PREFIX};
 
 use alloc::{collections::BTreeMap, vec, vec::Vec};
 use core::{fmt, iter, mem, ops};
@@ -804,6 +804,57 @@ impl<T: AsRef<[u8]>> DecodedTrieProof<T> {
         }
     }
 
+    /// Queries from the proof the Merkle value of the root node of the given child trie.
+    ///
+    /// The root of a child trie is stored in the main trie, as the storage value at the key
+    /// `:child_storage:default:` followed with the identifier of the child trie. The proof must
+    /// contain this main trie entry, and the entries of the child trie must then be looked up
+    /// by passing the returned value as `trie_root_merkle_value` to the other functions of
+    /// [`DecodedTrieProof`].
+    ///
+    /// Returns `Ok(None)` if the child trie is known to not exist.
+    pub fn child_trie_root_hash(
+        &'_ self,
+        main_trie_root_merkle_value: &[u8; 32],
+        child_trie: &[u8],
+    ) -> Result<Option<&'_ [u8; 32]>, ChildTrieError> {
+        // TODO: allocation here, but probably not problematic
+        let mut key = Vec::with_capacity(CHILD_STORAGE_PREFIX.len() + child_trie.len());
+        key.extend_from_slice(CHILD_STORAGE_PREFIX);
+        key.extend_from_slice(child_trie);
+
+        match self.storage_value(main_trie_root_merkle_value, &key) {
+            Err(err) => Err(ChildTrieError::IncompleteProof(err)),
+            Ok(None) => Ok(None),
+            Ok(Some((value, _))) => <&[u8; 32]>::try_from(value)
+                .map(Some)
+                .map_err(|_| ChildTrieError::InvalidChildTrieRootHash),
+        }
+    }
+
+    /// Queries from the proof the storage value at the given key of the given child trie.
+    ///
+    /// Returns an error if the storage value couldn't be determined from the proof. Returns
+    /// `Ok(None)` if the storage value is known to have no value, including if the child trie
+    /// is known to not exist.
+    ///
+    /// > **Note**: This function is a convenient wrapper around
+    /// >           [`DecodedTrieProof::child_trie_root_hash`] and
+    /// >           [`DecodedTrieProof::storage_value`].
+    pub fn child_trie_storage_value(
+        &'_ self,
+        main_trie_root_merkle_value: &[u8; 32],
+        child_trie: &[u8],
+        key: &[u8],
+    ) -> Result<Option<(&'_ [u8], TrieEntryVersion)>, ChildTrieError> {
+        let Some(child_trie_root_hash) =
+            self.child_trie_root_hash(main_trie_root_merkle_value, child_trie)?
+            else { return Ok(None) };
+
+        self.storage_value(child_trie_root_hash, key)
+            .map_err(ChildTrieError::IncompleteProof)
+    }
+
     /// Find in the proof the trie node that follows `key_before` in lexicographic order.
     ///
     /// If `or_equal` is `true`, then `key_before` is returned if it is equal to a node in the
@@ -1046,6 +1097,18 @@ impl<T: AsRef<[u8]>> DecodedTrieProof<T> {
 #[derive(Debug, Clone, derive_more::Display)]
 pub struct IncompleteProofError();
 
+/// Error potentially returned by [`DecodedTrieProof::child_trie_root_hash`] and
+/// [`DecodedTrieProof::child_trie_storage_value`].
+#[derive(Debug, Clone, derive_more::Display)]
+pub enum ChildTrieError {
+    /// Proof doesn't contain enough information to answer the request.
+    #[display(fmt = "{_0}")]
+    IncompleteProof(IncompleteProofError),
+    /// The storage value of the main trie that is supposed to contain the Merkle value of the
+    /// root of the child trie isn't 32 bytes long.
+    InvalidChildTrieRootHash,
+}
+
 /// Storage value of the node.
 #[derive(Copy, Clone)]
 pub enum StorageValue<'a> {
@@ -15,9 +15,9 @@
 // You should have received a copy of the GNU General Public License
 // along with this program.  If not, see <http://www.gnu.org/licenses/>.
 
-use super::{nibble, trie_node, trie_structure};
+use super::{nibble, trie_node, trie_structure, CHILD_STORAGE_PREFIX};
 
-use alloc::{borrow::ToOwned as _, vec::Vec};
+use alloc::{borrow::ToOwned as _, collections::BTreeMap, vec::Vec};
 use core::{array, iter};
 
 pub use super::nibble::Nibble;
@@ -36,6 +36,10 @@ pub struct ProofBuilder {
 
     /// List of keys of the nodes in [`ProofBuilder::trie_structure`] whose user data is `None`.
     missing_node_values: hashbrown::HashSet<Vec<Nibble>, fnv::FnvBuildHasher>,
+
+    /// Nodes of the child tries that are part of the proof, indexed by child trie identifier.
+    /// The [`ProofBuilder`]s in this list never have child tries themselves.
+    child_tries: BTreeMap<Vec<u8>, ProofBuilder>,
 }
 
 #[derive(Debug, Clone)]
@@ -65,6 +69,7 @@ impl ProofBuilder {
                 capacity,
                 Default::default(),
             ),
+            child_tries: BTreeMap::new(),
         }
     }
 
@@ -168,29 +173,69 @@ impl ProofBuilder {
         );
         if key.len() != partial_key_len {
             let parent_key = &key[..(key.len() - partial_key_len - 1)];
-            match self.trie_structure.node(parent_key.iter().copied()) {
-                trie_structure::Entry::Occupied(_) => {
-                    // The parent is already in the structure. Nothing to do.
-                }
-                trie_structure::Entry::Vacant(entry) => match entry.insert_storage_value() {
-                    trie_structure::PrepareInsert::One(insert) => {
-                        let _was_inserted = self.missing_node_values.insert(parent_key.to_owned());
-                        debug_assert!(_was_inserted);
-                        insert.insert(None);
-                    }
-                    trie_structure::PrepareInsert::Two(insert) => {
-                        let _was_inserted = self.missing_node_values.insert(parent_key.to_owned());
-                        debug_assert!(_was_inserted);
+            self.insert_node_if_missing(parent_key);
+        }
+    }
 
-                        let _was_inserted = self
-                            .missing_node_values
-                            .insert(insert.branch_node_key().collect());
-                        debug_assert!(_was_inserted);
+    /// Inserts the node value of a given trie node of the given child trie into the builder.
+    ///
+    /// This function is similar to [`ProofBuilder::set_node_value`], except that the node
+    /// belongs to a child trie. The node of the main trie whose storage value is the Merkle
+    /// value of the root of this child trie, in other words the node whose key is
+    /// `:child_storage:default:` followed with `child_trie`, is also required to be part of the
+    /// proof. Its node value must be provided through [`ProofBuilder::set_node_value`] as well.
+    ///
+    /// # Panic
+    ///
+    /// See [`ProofBuilder::set_node_value`].
+    ///
+    pub fn set_child_trie_node_value(
+        &mut self,
+        child_trie: &[u8],
+        key: &[Nibble],
+        node_value: &[u8],
+        unhashed_storage_value: Option<&[u8]>,
+    ) {
+        self.child_tries
+            .entry(child_trie.to_owned())
+            .or_default()
+            .set_node_value(key, node_value, unhashed_storage_value);
+
+        let child_trie_key = nibble::bytes_to_nibbles(
+            CHILD_STORAGE_PREFIX
+                .iter()
+                .copied()
+                .chain(child_trie.iter().copied()),
+        )
+        .collect::<Vec<_>>();
+        self.insert_node_if_missing(&child_trie_key);
+    }
 
-                        insert.insert(None, None);
-                    }
-                },
+    /// Makes sure that a node exists at the given key in [`ProofBuilder::trie_structure`]. If
+    /// not, inserts it and marks it as missing.
+    fn insert_node_if_missing(&mut self, key: &[Nibble]) {
+        match self.trie_structure.node(key.iter().copied()) {
+            trie_structure::Entry::Occupied(_) => {
+                // The node is already in the structure. Nothing to do.
             }
+            trie_structure::Entry::Vacant(entry) => match entry.insert_storage_value() {
+                trie_structure::PrepareInsert::One(insert) => {
+                    let _was_inserted = self.missing_node_values.insert(key.to_owned());
+                    debug_assert!(_was_inserted);
+                    insert.insert(None);
+                }
+                trie_structure::PrepareInsert::Two(insert) => {
+                    let _was_inserted = self.missing_node_values.insert(key.to_owned());
+                    debug_assert!(_was_inserted);
+
+                    let _was_inserted = self
+                        .missing_node_values
+                        .insert(insert.branch_node_key().collect());
+                    debug_assert!(_was_inserted);
+
+                    insert.insert(None, None);
+                }
+            },
         }
     }
 
@@ -204,6 +249,19 @@ impl ProofBuilder {
         self.missing_node_values.iter().map(|v| &v[..])
     }
 
+    /// Returns a list of child trie identifiers and keys for which the node value must be known
+    /// in order to be able to build the proof.
+    ///
+    /// For each entry returned by this iterator, [`ProofBuilder::set_child_trie_node_value`]
+    /// must be called.
+    pub fn missing_child_trie_node_values(&self) -> impl Iterator<Item = (&[u8], &[Nibble])> {
+        self.child_tries.iter().flat_map(|(child_trie, builder)| {
+            builder
+                .missing_node_values()
+                .map(move |key| (&child_trie[..], key))
+        })
+    }
+
     /// Returns the hash of the trie root node.
     ///
     /// This function returns `None` if the proof is empty or if the trie root node is missing
@@ -218,6 +276,15 @@ impl ProofBuilder {
         Some(blake2_hash(node_value))
     }
 
+    /// Returns the hash of the root node of the given child trie.
+    ///
+    /// This function returns `None` if no node of this child trie has been added, or if the root
+    /// node of this child trie is missing from the proof, in which case
+    /// [`ProofBuilder::missing_child_trie_node_values`] will return it.
+    pub fn child_trie_root_hash(&self, child_trie: &[u8]) -> Option<[u8; 32]> {
+        self.child_tries.get(child_trie)?.trie_root_hash()
+    }
+
     /// Modifies the node values that have been inserted in the proof builder in order to make the
     /// proof coherent, if necessary.
     ///
@@ -244,7 +311,43 @@ impl ProofBuilder {
     /// This function works even if [`ProofBuilder::missing_node_values`] returns a non-empty
     /// iterator, but will not be able to update everything. You are encouraged to call this
     /// function only after having added all missing node values;
+    ///
+    /// The child tries are made coherent as well. Afterwards, the storage values of the nodes
+    /// of the main trie that contain the root of a child trie are updated to match the hash of
+    /// the root node of the child trie, if both are known. This is the only situation where a
+    /// storage value is modified.
     pub fn make_coherent(&mut self) {
+        for (child_trie, child_trie_builder) in &mut self.child_tries {
+            child_trie_builder.make_coherent();
+            let Some(child_trie_root_hash) = child_trie_builder.trie_root_hash()
+                else { continue };
+
+            let child_trie_key = nibble::bytes_to_nibbles(
+                CHILD_STORAGE_PREFIX
+                    .iter()
+                    .copied()
+                    .chain(child_trie.iter().copied()),
+            );
+            let trie_structure::Entry::Occupied(mut entry) =
+                self.trie_structure.node(child_trie_key)
+                else { continue };
+            let Some(node_info) = entry.user_data().as_mut()
+                else { continue };
+
+            // The root hash of a child trie is 32 bytes long, and is thus always inlined in
+            // the node value.
+            let mut decoded_node_value = trie_node::decode(&node_info.node_value).unwrap();
+            decoded_node_value.storage_value =
+                trie_node::StorageValue::Unhashed(&child_trie_root_hash);
+            node_info.node_value = trie_node::encode_to_vec(decoded_node_value).unwrap();
+            node_info.storage_value_node = None;
+        }
+
+        self.make_trie_coherent();
+    }
+
+    /// Same as [`ProofBuilder::make_coherent`], but only for [`ProofBuilder::trie_structure`].
+    fn make_trie_coherent(&mut self) {
         // The implementation of this function iterates over the nodes of the trie in a specific
         // order: we start with the deepest child possible of the root node, then we jump from each
         // node to the deepest child possible of its next sibling. If a node is the last sibling,
@@ -372,14 +475,42 @@ impl ProofBuilder {
     ///
     /// This function will succeed even if [`ProofBuilder::missing_node_values`] returns a
     /// non-zero number of elements. However, the proof produced will then be invalid.
+    ///
+    /// The nodes of the main trie and of all the child tries are put together in the same
+    /// proof.
     pub fn build(mut self) -> impl Iterator<Item = impl AsRef<[u8]> + Clone> {
+        let child_tries = core::mem::take(&mut self.child_tries);
+
+        // Collect the entries in the proof into a `HashSet` in order to de-duplicate them.
+        let entries = self
+            .into_entries()
+            .chain(
+                child_tries
+                    .into_values()
+                    .flat_map(|child_trie| child_trie.into_entries()),
+            )
+            .collect::<hashbrown::HashSet<_, fnv::FnvBuildHasher>>();
+
+        // The first bytes of the proof contain the number of entries in the proof.
+        let num_entries_encoded = crate::util::encode_scale_compact_usize(entries.len());
+
+        // Add the size of each entry before each entry.
+        let entries = entries.into_iter().flat_map(|entry| {
+            let len = crate::util::encode_scale_compact_usize(entry.len());
+            [either::Left(len), either::Right(entry)].into_iter()
+        });
+
+        iter::once(either::Left(num_entries_encoded)).chain(entries.into_iter().map(either::Right))
+    }
+
+    /// Returns the list of entries of the proof that concern [`ProofBuilder::trie_structure`].
+    /// The list might contain duplicates.
+    fn into_entries(mut self) -> impl Iterator<Item = Vec<u8>> {
         // Index of the root node in the trie, if any.
         let root_node_index = self.trie_structure.root_node().map(|n| n.node_index());
 
-        // Collect the entries in the proof into a `HashSet` in order to de-duplicate them.
         // TODO: we need to collect the indices into a Vec due to the API of trie_structure not allowing non-mutable access to nodes
-        let entries = self
-            .trie_structure
+        self.trie_structure
             .iter_unordered()
             .collect::<Vec<_>>()
             .into_iter()
@@ -408,18 +539,6 @@ impl ProofBuilder {
                         .chain(trie_structure_value.storage_value_node.into_iter()),
                 )
             })
-            .collect::<hashbrown::HashSet<_, fnv::FnvBuildHasher>>();
-
-        // The first bytes of the proof contain the number of entries in the proof.
-        let num_entries_encoded = crate::util::encode_scale_compact_usize(entries.len());
-
-        // Add the size of each entry before each entry.
-        let entries = entries.into_iter().flat_map(|entry| {
-            let len = crate::util::encode_scale_compact_usize(entry.len());
-            [either::Left(len), either::Right(entry)].into_iter()
-        });
-
-        iter::once(either::Left(num_entries_encoded)).chain(entries.into_iter().map(either::Right))
     }
 
     /// Similar to [`ProofBuilder::build`], but returns a `Vec`.
@@ -611,6 +730,95 @@ mod tests {
         }
     }
 
+    #[test]
+    fn child_trie_requires_main_trie_node() {
+        let mut proof_builder = super::ProofBuilder::new();
+
+        let child_trie_node_value = trie_node::encode_to_vec(trie_node::Decoded {
+            children: [None::<&[u8]>; 16],
+            partial_key: nibble::bytes_to_nibbles(b"abc".iter().copied()),
+            storage_value: trie_node::StorageValue::Unhashed(b"hello"),
+        })
+        .unwrap();
+        proof_builder.set_child_trie_node_value(
+            b"foo",
+            &nibble::bytes_to_nibbles(b"abc".iter().copied()).collect::<Vec<_>>(),
+            &child_trie_node_value,
+            None,
+        );
+
+        assert_eq!(proof_builder.missing_child_trie_node_values().count(), 0);
+        assert_eq!(
+            proof_builder.missing_node_values().collect::<Vec<_>>(),
+            vec![
+                &nibble::bytes_to_nibbles(b":child_storage:default:foo".iter().copied())
+                    .collect::<Vec<_>>()[..]
+            ]
+        );
+    }
+
+    #[test]
+    fn child_trie_proof_decodes() {
+        let mut proof_builder = super::ProofBuilder::new();
+
+        // The main trie contains a single node whose storage value is the root of the child
+        // trie. The storage value is initially wrong and fixed by `make_coherent`.
+        let main_trie_key = b":child_storage:default:foo";
+        let main_trie_node_value = trie_node::encode_to_vec(trie_node::Decoded {
+            children: [None::<&[u8]>; 16],
+            partial_key: nibble::bytes_to_nibbles(main_trie_key.iter().copied()),
+            storage_value: trie_node::StorageValue::Unhashed(&[0; 32]),
+        })
+        .unwrap();
+        proof_builder.set_node_value(
+            &nibble::bytes_to_nibbles(main_trie_key.iter().copied()).collect::<Vec<_>>(),
+            &main_trie_node_value,
+            None,
+        );
+
+        let child_trie_node_value = trie_node::encode_to_vec(trie_node::Decoded {
+            children: [None::<&[u8]>; 16],
+            partial_key: nibble::bytes_to_nibbles(b"abc".iter().copied()),
+            storage_value: trie_node::StorageValue::Unhashed(b"hello"),
+        })
+        .unwrap();
+        proof_builder.set_child_trie_node_value(
+            b"foo",
+            &nibble::bytes_to_nibbles(b"abc".iter().copied()).collect::<Vec<_>>(),
+            &child_trie_node_value,
+            None,
+        );
+
+        assert_eq!(proof_builder.missing_node_values().count(), 0);
+        assert_eq!(proof_builder.missing_child_trie_node_values().count(), 0);
+        proof_builder.make_coherent();
+
+        let main_trie_root_hash = proof_builder.trie_root_hash().unwrap();
+        let child_trie_root_hash = proof_builder.child_trie_root_hash(b"foo").unwrap();
+        let proof = proof_decode::decode_and_verify_proof(proof_decode::Config {
+            proof: proof_builder.build_to_vec(),
+        })
+        .unwrap();
+
+        assert_eq!(
+            proof
+                .child_trie_root_hash(&main_trie_root_hash, b"foo")
+                .unwrap(),
+            Some(&child_trie_root_hash)
+        );
+        assert_eq!(
+            proof
+                .child_trie_storage_value(&main_trie_root_hash, b"foo", b"abc")
+                .unwrap()
+                .map(|(value, _)| value),
+            Some(&b"hello"[..])
+        );
+        assert!(proof
+            .child_trie_storage_value(&main_trie_root_hash, b"bar", b"abc")
+            .unwrap()
+            .is_none());
+    }
+
     #[test]
     fn identical_nodes_deduplicated() {
         let mut proof_builder = super::ProofBuilder::new();
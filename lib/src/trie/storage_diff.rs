@@ -0,0 +1,544 @@
+// Smoldot
+// Copyright (C) 2023  Pierre Krieger
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Calculates the list of storage items that differ between two tries.
+//!
+//! Given the hashes of the root nodes of two tries, this module determines the keys whose
+//! storage value has been inserted, modified, or deleted when going from the old trie to the new
+//! trie.
+//!
+//! The algorithm walks down both tries simultaneously. Whenever two nodes at the same position
+//! in both tries have the same Merkle value, they are guaranteed to be identical, and so is
+//! their entire subtree. These subtrees are skipped, meaning that the number of node values that
+//! need to be accessed is proportional to the number of differences rather than to the size of
+//! the tries.
+//!
+//! Child tries are also compared. Whenever the value of a key of the main trie that starts with
+//! `:child_storage:default:` differs between the two tries, this storage value is the hash of the
+//! root node of a child trie, and the old and new versions of this child trie are compared as
+//! well. Storage values that aren't 32 bytes long aren't considered as child trie roots.
+//!
+//! # Usage
+//!
+//! Call [`storage_diff`] in order to start the calculation. The API user must then provide the
+//! node values requested through [`StorageDiff::NodeValue`]. These node values can be obtained
+//! for example from a database or from a set of proofs.
+
+use super::{nibble, trie_node};
+
+use alloc::{boxed::Box, collections::BTreeMap, vec::Vec};
+
+mod tests;
+
+/// Prefix of the keys of the main trie that refer to a child trie.
+const CHILD_STORAGE_PREFIX: &[u8] = b":child_storage:default:";
+
+/// Configuration for [`storage_diff`].
+#[derive(Debug, Clone)]
+pub struct Config {
+    /// Hash of the root node of the trie to compare from.
+    pub old_trie_root_hash: [u8; 32],
+    /// Hash of the root node of the trie to compare to.
+    pub new_trie_root_hash: [u8; 32],
+}
+
+/// Starts the calculation of the difference between two tries.
+pub fn storage_diff(config: Config) -> StorageDiff {
+    Inner {
+        stack: Vec::from([Comparison {
+            child_trie: None,
+            old: Some(Subtree::Unresolved {
+                key_prefix: Vec::new(),
+                merkle_value: config.old_trie_root_hash.to_vec(),
+            }),
+            new: Some(Subtree::Unresolved {
+                key_prefix: Vec::new(),
+                merkle_value: config.new_trie_root_hash.to_vec(),
+            }),
+        }]),
+        diff: BTreeMap::new(),
+        child_tries: Vec::new(),
+    }
+    .run()
+}
+
+/// Current state of the calculation.
+#[must_use]
+pub enum StorageDiff {
+    /// In order to continue, the API user must provide a node value.
+    NodeValue(NodeValue),
+    /// Calculation is finished.
+    Finished {
+        /// List of keys whose storage value differs between the two tries, and how it differs.
+        ///
+        /// Keys that consist in an uneven number of nibbles aren't included. See the
+        /// documentation of
+        /// [`DecodedTrieProof::iter_runtime_context_ordered`](super::proof_decode::DecodedTrieProof::iter_runtime_context_ordered)
+        /// for an explanation.
+        diff: BTreeMap<Vec<u8>, DiffKind>,
+        /// Same as [`StorageDiff::Finished::diff`], but for each child trie that differs between
+        /// the two tries. Keys of this map are the identifiers of the child tries, in other words
+        /// the keys of the main trie without the `:child_storage:default:` prefix.
+        ///
+        /// The keys of the main trie corresponding to these child tries are also found in
+        /// [`StorageDiff::Finished::diff`].
+        child_tries_diff: BTreeMap<Vec<u8>, BTreeMap<Vec<u8>, DiffKind>>,
+    },
+}
+
+/// How the storage value of a key differs between the two tries.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum DiffKind {
+    /// The key has a storage value in the new trie but not in the old trie.
+    Inserted,
+    /// The key has a storage value in both tries, and these storage values are different.
+    Modified,
+    /// The key has a storage value in the old trie but not in the new trie.
+    Deleted,
+}
+
+/// In order to continue, the API user must provide the node value whose hash is
+/// [`NodeValue::hash`].
+#[must_use]
+pub struct NodeValue {
+    inner: Inner,
+    /// Value returned by [`NodeValue::hash`].
+    hash: [u8; 32],
+}
+
+impl NodeValue {
+    /// Returns the hash of the node value that must be provided.
+    pub fn hash(&self) -> &[u8; 32] {
+        &self.hash
+    }
+
+    /// Injects the node value whose hash is [`NodeValue::hash`].
+    ///
+    /// On error, the [`NodeValue`] is returned back, and the node value can be injected again,
+    /// for example after having obtained it from a different source.
+    pub fn inject(mut self, node_value: &[u8]) -> Result<StorageDiff, (NodeValue, Error)> {
+        if blake2_rfc::blake2b::blake2b(32, &[], node_value).as_bytes() != self.hash {
+            return Err((self, Error::HashMismatch));
+        }
+
+        // The node requested is always one of the two sides of the comparison at the top of
+        // the stack.
+        let comparison = self.inner.stack.last_mut().unwrap();
+        let side = [&mut comparison.old, &mut comparison.new]
+            .into_iter()
+            .find(|side| {
+                matches!(side, Some(Subtree::Unresolved { merkle_value, .. }) if *merkle_value == self.hash)
+            })
+            .unwrap();
+        let Some(Subtree::Unresolved { key_prefix, .. }) = side
+            else { unreachable!() };
+
+        let node = match Node::decode(key_prefix, node_value) {
+            Ok(node) => node,
+            Err(err) => return Err((self, Error::InvalidNodeValue(err))),
+        };
+
+        *side = Some(Subtree::Resolved(Box::new(node)));
+        Ok(self.inner.run())
+    }
+}
+
+/// Error potentially returned by [`NodeValue::inject`].
+#[derive(Debug, Clone, derive_more::Display)]
+pub enum Error {
+    /// The hash of the node value doesn't match [`NodeValue::hash`].
+    HashMismatch,
+    /// The node value, or the node value of one of its inline children, has an invalid format.
+    #[display(fmt = "Invalid node value: {_0}")]
+    InvalidNodeValue(trie_node::Error),
+}
+
+struct Inner {
+    /// List of subtrees that remain to be compared.
+    stack: Vec<Comparison>,
+    /// Differences found so far in the main trie.
+    diff: BTreeMap<Vec<u8>, DiffKind>,
+    /// Child tries found so far whose root differs between the two tries. Indexed by
+    /// [`Comparison::child_trie`].
+    child_tries: Vec<ChildTrie>,
+}
+
+/// Child trie whose root differs between the two tries.
+struct ChildTrie {
+    /// Identifier of the child trie, without the `:child_storage:default:` prefix.
+    id: Vec<u8>,
+    /// Differences found so far in this child trie.
+    diff: BTreeMap<Vec<u8>, DiffKind>,
+}
+
+/// Two subtrees to compare.
+struct Comparison {
+    /// Index within [`Inner::child_tries`] of the child trie the subtrees belong to, or `None`
+    /// for the main trie.
+    child_trie: Option<usize>,
+    /// Subtree in the old trie. `None` if the new subtree has no equivalent in the old trie.
+    old: Option<Subtree>,
+    /// Subtree in the new trie. `None` if the old subtree has no equivalent in the new trie.
+    new: Option<Subtree>,
+}
+
+enum Subtree {
+    /// Only the Merkle value of the root node of the subtree is known.
+    Unresolved {
+        /// Key of the parent of the node, plus the nibble of the child.
+        key_prefix: Vec<nibble::Nibble>,
+        /// Merkle value of the node. Either a hash, or the node value itself if it is inlined.
+        merkle_value: Vec<u8>,
+    },
+    /// The root node of the subtree has been decoded.
+    Resolved(Box<Node>),
+}
+
+/// Decoded trie node.
+struct Node {
+    /// Full key of the node.
+    key: Vec<nibble::Nibble>,
+    /// Length of the key of the parent of the node, plus one for the nibble of the child.
+    key_prefix_len: usize,
+    /// Merkle value of the node.
+    merkle_value: Vec<u8>,
+    /// Storage value of the node, if any.
+    storage_value: Option<StorageValue>,
+    /// Merkle values of the children of the node.
+    children: [Option<Vec<u8>>; 16],
+}
+
+/// See [`Node::storage_value`].
+#[derive(PartialEq, Eq)]
+enum StorageValue {
+    Unhashed(Vec<u8>),
+    Hashed([u8; 32]),
+}
+
+impl Node {
+    /// Decodes the given node value. Also verifies that the inline children of the node, if any,
+    /// can be decoded.
+    fn decode(key_prefix: &[nibble::Nibble], node_value: &[u8]) -> Result<Node, trie_node::Error> {
+        fn verify_inline_children(
+            decoded: &trie_node::Decoded<trie_node::DecodedPartialKey, &[u8]>,
+        ) -> Result<(), trie_node::Error> {
+            for child in decoded.children.iter().flatten() {
+                if child.len() < 32 {
+                    verify_inline_children(&trie_node::decode(child)?)?;
+                }
+            }
+            Ok(())
+        }
+
+        let decoded = trie_node::decode(node_value)?;
+        verify_inline_children(&decoded)?;
+
+        Ok(Node {
+            key: key_prefix
+                .iter()
+                .copied()
+                .chain(decoded.partial_key)
+                .collect(),
+            key_prefix_len: key_prefix.len(),
+            merkle_value: if node_value.len() < 32 && !key_prefix.is_empty() {
+                node_value.to_vec()
+            } else {
+                blake2_rfc::blake2b::blake2b(32, &[], node_value)
+                    .as_bytes()
+                    .to_vec()
+            },
+            storage_value: match decoded.storage_value {
+                trie_node::StorageValue::None => None,
+                trie_node::StorageValue::Unhashed(value) => {
+                    Some(StorageValue::Unhashed(value.to_vec()))
+                }
+                trie_node::StorageValue::Hashed(hash) => Some(StorageValue::Hashed(*hash)),
+            },
+            children: decoded
+                .children
+                .map(|child| child.map(|child| child.to_vec())),
+        })
+    }
+
+    /// Returns the subtrees of the children of this node.
+    fn into_children(self) -> impl Iterator<Item = (nibble::Nibble, Option<Subtree>)> {
+        let key = self.key;
+        nibble::all_nibbles()
+            .zip(self.children)
+            .map(move |(nibble, merkle_value)| {
+                let subtree = merkle_value.map(|merkle_value| Subtree::Unresolved {
+                    key_prefix: key
+                        .iter()
+                        .copied()
+                        .chain(core::iter::once(nibble))
+                        .collect(),
+                    merkle_value,
+                });
+                (nibble, subtree)
+            })
+    }
+}
+
+impl StorageValue {
+    /// Returns `true` if both storage values are identical, no matter whether they are hashed.
+    fn equivalent(&self, other: &StorageValue) -> bool {
+        match (self, other) {
+            (StorageValue::Unhashed(a), StorageValue::Hashed(b))
+            | (StorageValue::Hashed(b), StorageValue::Unhashed(a)) => {
+                blake2_rfc::blake2b::blake2b(32, &[], a).as_bytes() == b
+            }
+            (a, b) => a == b,
+        }
+    }
+}
+
+impl Subtree {
+    fn key_prefix(&self) -> &[nibble::Nibble] {
+        match self {
+            Subtree::Unresolved { key_prefix, .. } => key_prefix,
+            Subtree::Resolved(node) => &node.key[..node.key_prefix_len],
+        }
+    }
+
+    fn merkle_value(&self) -> &[u8] {
+        match self {
+            Subtree::Unresolved { merkle_value, .. } => merkle_value,
+            Subtree::Resolved(node) => &node.merkle_value,
+        }
+    }
+}
+
+impl Inner {
+    fn run(mut self) -> StorageDiff {
+        loop {
+            let Some(comparison) = self.stack.last_mut()
+                else {
+                    return StorageDiff::Finished {
+                        diff: self.diff,
+                        child_tries_diff: self
+                            .child_tries
+                            .into_iter()
+                            .filter(|child_trie| !child_trie.diff.is_empty())
+                            .map(|child_trie| (child_trie.id, child_trie.diff))
+                            .collect(),
+                    }
+                };
+
+            // If both subtrees are at the same position and have the same Merkle value, then
+            // they are identical and can be skipped.
+            if let (Some(old), Some(new)) = (&comparison.old, &comparison.new) {
+                if old.key_prefix() == new.key_prefix() && old.merkle_value() == new.merkle_value()
+                {
+                    self.stack.pop();
+                    continue;
+                }
+            }
+
+            // Decode the root nodes of both subtrees. Inline node values have already been
+            // verified when decoding their parent.
+            for side in [&mut comparison.old, &mut comparison.new] {
+                let Some(Subtree::Unresolved {
+                    key_prefix,
+                    merkle_value,
+                }) = side
+                else { continue };
+                if merkle_value.len() < 32 {
+                    let node = Node::decode(key_prefix, merkle_value).unwrap();
+                    *side = Some(Subtree::Resolved(Box::new(node)));
+                }
+            }
+
+            // Request the node values that aren't inline from the API user.
+            let hash_to_request = [&comparison.old, &comparison.new]
+                .into_iter()
+                .find_map(|side| match side {
+                    Some(Subtree::Unresolved { merkle_value, .. }) => {
+                        Some(<[u8; 32]>::try_from(&merkle_value[..]).unwrap())
+                    }
+                    _ => None,
+                });
+            if let Some(hash) = hash_to_request {
+                return StorageDiff::NodeValue(NodeValue { inner: self, hash });
+            }
+
+            let comparison = self.stack.pop().unwrap();
+            let child_trie = comparison.child_trie;
+            let old = comparison.old.map(|subtree| match subtree {
+                Subtree::Resolved(node) => *node,
+                Subtree::Unresolved { .. } => unreachable!(),
+            });
+            let new = comparison.new.map(|subtree| match subtree {
+                Subtree::Resolved(node) => *node,
+                Subtree::Unresolved { .. } => unreachable!(),
+            });
+
+            match (old, new) {
+                (None, None) => {}
+                (Some(old), None) => {
+                    self.insert_diff(child_trie, &old.key, old.storage_value.as_ref(), None);
+                    for (_, child) in old.into_children() {
+                        self.stack.push(Comparison {
+                            child_trie,
+                            old: child,
+                            new: None,
+                        });
+                    }
+                }
+                (None, Some(new)) => {
+                    self.insert_diff(child_trie, &new.key, None, new.storage_value.as_ref());
+                    for (_, child) in new.into_children() {
+                        self.stack.push(Comparison {
+                            child_trie,
+                            old: None,
+                            new: child,
+                        });
+                    }
+                }
+                (Some(old), Some(new)) if old.key == new.key => {
+                    self.insert_diff(
+                        child_trie,
+                        &old.key,
+                        old.storage_value.as_ref(),
+                        new.storage_value.as_ref(),
+                    );
+
+                    for ((_, old_child), (_, new_child)) in
+                        old.into_children().zip(new.into_children())
+                    {
+                        self.stack.push(Comparison {
+                            child_trie,
+                            old: old_child,
+                            new: new_child,
+                        });
+                    }
+                }
+                (Some(old), Some(new)) if new.key.starts_with(&old.key) => {
+                    // The old node is an ancestor of the new node. The new trie has no node at
+                    // the key of the old node.
+                    self.insert_diff(child_trie, &old.key, old.storage_value.as_ref(), None);
+
+                    let new_nibble = new.key[old.key.len()];
+                    let mut new = Some(new);
+                    for (nibble, old_child) in old.into_children() {
+                        self.stack.push(Comparison {
+                            child_trie,
+                            old: old_child,
+                            new: if nibble == new_nibble {
+                                new.take().map(|new| Subtree::Resolved(Box::new(new)))
+                            } else {
+                                None
+                            },
+                        });
+                    }
+                }
+                (Some(old), Some(new)) if old.key.starts_with(&new.key) => {
+                    // The new node is an ancestor of the old node. The old trie has no node at
+                    // the key of the new node.
+                    self.insert_diff(child_trie, &new.key, None, new.storage_value.as_ref());
+
+                    let old_nibble = old.key[new.key.len()];
+                    let mut old = Some(old);
+                    for (nibble, new_child) in new.into_children() {
+                        self.stack.push(Comparison {
+                            child_trie,
+                            old: if nibble == old_nibble {
+                                old.take().map(|old| Subtree::Resolved(Box::new(old)))
+                            } else {
+                                None
+                            },
+                            new: new_child,
+                        });
+                    }
+                }
+                (Some(old), Some(new)) => {
+                    // The keys of the nodes diverge. Everything in the old subtree has been
+                    // deleted, and everything in the new subtree has been inserted.
+                    self.stack.push(Comparison {
+                        child_trie,
+                        old: Some(Subtree::Resolved(Box::new(old))),
+                        new: None,
+                    });
+                    self.stack.push(Comparison {
+                        child_trie,
+                        old: None,
+                        new: Some(Subtree::Resolved(Box::new(new))),
+                    });
+                }
+            }
+        }
+    }
+
+    /// Records the difference, if any, between the old and new storage values of the given key.
+    ///
+    /// If the key belongs to the main trie and refers to a child trie, the old and new versions
+    /// of this child trie are queued for comparison.
+    fn insert_diff(
+        &mut self,
+        child_trie: Option<usize>,
+        key: &[nibble::Nibble],
+        old_value: Option<&StorageValue>,
+        new_value: Option<&StorageValue>,
+    ) {
+        let kind = match (old_value, new_value) {
+            (None, None) => return,
+            (Some(_), None) => DiffKind::Deleted,
+            (None, Some(_)) => DiffKind::Inserted,
+            (Some(old_value), Some(new_value)) if old_value.equivalent(new_value) => return,
+            (Some(_), Some(_)) => DiffKind::Modified,
+        };
+
+        if key.len() % 2 == 1 {
+            return;
+        }
+
+        let key = nibble::nibbles_to_bytes_suffix_extend(key.iter().copied()).collect::<Vec<_>>();
+
+        let Some(child_trie) = child_trie else {
+            if let Some(child_trie) = key.strip_prefix(CHILD_STORAGE_PREFIX) {
+                // The storage value of a key that refers to a child trie is the hash of the root
+                // node of the child trie.
+                let as_child_trie_root = |value: Option<&StorageValue>| match value {
+                    Some(StorageValue::Unhashed(value)) if value.len() == 32 => {
+                        Some(Subtree::Unresolved {
+                            key_prefix: Vec::new(),
+                            merkle_value: value.clone(),
+                        })
+                    }
+                    _ => None,
+                };
+                let old = as_child_trie_root(old_value);
+                let new = as_child_trie_root(new_value);
+                if old.is_some() || new.is_some() {
+                    self.stack.push(Comparison {
+                        child_trie: Some(self.child_tries.len()),
+                        old,
+                        new,
+                    });
+                    self.child_tries.push(ChildTrie {
+                        id: child_trie.to_vec(),
+                        diff: BTreeMap::new(),
+                    });
+                }
+            }
+
+            self.diff.insert(key, kind);
+            return;
+        };
+
+        self.child_tries[child_trie].diff.insert(key, kind);
+    }
+}
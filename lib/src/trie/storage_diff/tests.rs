@@ -0,0 +1,316 @@
+// Smoldot
+// Copyright (C) 2023  Pierre Krieger
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <http://www.gnu.org/licenses/>.
+
+#![cfg(test)]
+
+use super::{storage_diff, Config, DiffKind, Error, StorageDiff};
+use crate::trie::{nibble, trie_node, trie_structure};
+
+use alloc::{collections::BTreeMap, vec, vec::Vec};
+use rand::distributions::{Distribution as _, Uniform};
+use std::collections::HashMap;
+
+/// Storage items that differ between two tries, indexed by key.
+type Diff = BTreeMap<Vec<u8>, DiffKind>;
+
+/// Builds the trie containing the given entries. Returns the hash of its root node and all its
+/// hashed node values, indexed by hash.
+fn build_trie(entries: &BTreeMap<Vec<u8>, Vec<u8>>) -> ([u8; 32], HashMap<[u8; 32], Vec<u8>>) {
+    fn node_merkle_value(
+        trie: &mut trie_structure::TrieStructure<Option<Vec<u8>>>,
+        node_index: trie_structure::NodeIndex,
+        node_values: &mut HashMap<[u8; 32], Vec<u8>>,
+    ) -> Vec<u8> {
+        let mut children: [Option<Vec<u8>>; 16] = Default::default();
+        for (nibble, child) in nibble::all_nibbles().zip(children.iter_mut()) {
+            let child_index = trie
+                .node_by_index(node_index)
+                .unwrap()
+                .child(nibble)
+                .map(|child| child.node_index());
+            *child = child_index.map(|child| node_merkle_value(trie, child, node_values));
+        }
+
+        let mut node = trie.node_by_index(node_index).unwrap();
+        let partial_key = node.partial_key().collect::<Vec<_>>();
+        let is_root_node = node.is_root_node();
+        let storage_value = node.user_data().clone();
+
+        let node_value = trie_node::encode_to_vec(trie_node::Decoded {
+            partial_key: partial_key.into_iter(),
+            children,
+            storage_value: match &storage_value {
+                Some(value) => trie_node::StorageValue::Unhashed(value),
+                None => trie_node::StorageValue::None,
+            },
+        })
+        .unwrap();
+
+        if node_value.len() < 32 && !is_root_node {
+            return node_value;
+        }
+
+        let hash =
+            *<&[u8; 32]>::try_from(blake2_rfc::blake2b::blake2b(32, &[], &node_value).as_bytes())
+                .unwrap();
+        node_values.insert(hash, node_value);
+        hash.to_vec()
+    }
+
+    let mut trie = trie_structure::TrieStructure::new();
+    for (key, value) in entries {
+        trie.node(nibble::bytes_to_nibbles(key.iter().copied()))
+            .into_vacant()
+            .unwrap()
+            .insert_storage_value()
+            .insert(Some(value.clone()), None);
+    }
+
+    let mut node_values = HashMap::new();
+    let root_hash = match trie.root_node().map(|node| node.node_index()) {
+        Some(root_index) => node_merkle_value(&mut trie, root_index, &mut node_values),
+        None => {
+            let hash = blake2_rfc::blake2b::blake2b(32, &[], &[0]);
+            node_values.insert(
+                <[u8; 32]>::try_from(hash.as_bytes()).unwrap(),
+                Vec::from([0]),
+            );
+            hash.as_bytes().to_vec()
+        }
+    };
+
+    (<[u8; 32]>::try_from(&root_hash[..]).unwrap(), node_values)
+}
+
+/// Runs the diff calculation to completion. Returns the diff of the main trie and of the child
+/// tries, and the number of node values that have been requested.
+fn run_diff(
+    old_trie_root_hash: [u8; 32],
+    new_trie_root_hash: [u8; 32],
+    node_values: &HashMap<[u8; 32], Vec<u8>>,
+) -> (Diff, BTreeMap<Vec<u8>, Diff>, usize) {
+    let mut diff = storage_diff(Config {
+        old_trie_root_hash,
+        new_trie_root_hash,
+    });
+
+    let mut num_requests = 0;
+    loop {
+        match diff {
+            StorageDiff::Finished {
+                diff,
+                child_tries_diff,
+            } => return (diff, child_tries_diff, num_requests),
+            StorageDiff::NodeValue(req) => {
+                num_requests += 1;
+                let node_value = node_values.get(req.hash()).unwrap();
+                diff = req.inject(node_value).unwrap_or_else(|_| panic!());
+            }
+        }
+    }
+}
+
+/// Calculates the diff between two lists of entries by comparing them directly.
+fn expected_diff(
+    old: &BTreeMap<Vec<u8>, Vec<u8>>,
+    new: &BTreeMap<Vec<u8>, Vec<u8>>,
+) -> BTreeMap<Vec<u8>, DiffKind> {
+    let mut diff = BTreeMap::new();
+    for (key, old_value) in old {
+        match new.get(key) {
+            None => {
+                diff.insert(key.clone(), DiffKind::Deleted);
+            }
+            Some(new_value) if new_value != old_value => {
+                diff.insert(key.clone(), DiffKind::Modified);
+            }
+            Some(_) => {}
+        }
+    }
+    for key in new.keys() {
+        if !old.contains_key(key) {
+            diff.insert(key.clone(), DiffKind::Inserted);
+        }
+    }
+    diff
+}
+
+#[test]
+fn identical_tries() {
+    match storage_diff(Config {
+        old_trie_root_hash: [1; 32],
+        new_trie_root_hash: [1; 32],
+    }) {
+        StorageDiff::Finished {
+            diff,
+            child_tries_diff,
+        } => {
+            assert!(diff.is_empty());
+            assert!(child_tries_diff.is_empty());
+        }
+        StorageDiff::NodeValue(_) => panic!(),
+    }
+}
+
+#[test]
+fn random_tries() {
+    fn random_entries() -> BTreeMap<Vec<u8>, Vec<u8>> {
+        let num_entries = Uniform::new_inclusive(0, 32).sample(&mut rand::thread_rng());
+        (0..num_entries)
+            .map(|_| {
+                // Keys are short and use few distinct bytes in order to share prefixes.
+                let key_len = Uniform::new_inclusive(0, 3).sample(&mut rand::thread_rng());
+                let key = (0..key_len)
+                    .map(|_| Uniform::new_inclusive(0, 3).sample(&mut rand::thread_rng()) * 0x11)
+                    .collect::<Vec<u8>>();
+                // Storage values are sometimes large enough for node values to be hashed.
+                let value_len = Uniform::new_inclusive(1, 40).sample(&mut rand::thread_rng());
+                let value = Uniform::new_inclusive(0u8, 1).sample(&mut rand::thread_rng());
+                (key, vec![value; value_len])
+            })
+            .collect()
+    }
+
+    for _ in 0..1000 {
+        let old = random_entries();
+        let new = random_entries();
+
+        let (old_root, mut node_values) = build_trie(&old);
+        let (new_root, new_node_values) = build_trie(&new);
+        node_values.extend(new_node_values);
+
+        let (diff, child_tries_diff, _) = run_diff(old_root, new_root, &node_values);
+        assert_eq!(diff, expected_diff(&old, &new));
+        assert!(child_tries_diff.is_empty());
+    }
+}
+
+#[test]
+fn identical_subtrees_skipped() {
+    let old = (0..2000u32)
+        .map(|n| {
+            let key = blake2_rfc::blake2b::blake2b(32, &[], &n.to_le_bytes());
+            (key.as_bytes().to_vec(), n.to_le_bytes().to_vec())
+        })
+        .collect::<BTreeMap<_, _>>();
+
+    let mut new = old.clone();
+    let modified_key = new.keys().nth(1000).unwrap().clone();
+    new.insert(modified_key.clone(), b"foo".to_vec());
+
+    let (old_root, mut node_values) = build_trie(&old);
+    let (new_root, new_node_values) = build_trie(&new);
+    let total_num_nodes = node_values.len();
+    node_values.extend(new_node_values);
+
+    let (diff, _, num_requests) = run_diff(old_root, new_root, &node_values);
+    assert_eq!(diff, BTreeMap::from([(modified_key, DiffKind::Modified)]));
+
+    // Only the nodes between the root and the modified node are requested, for both tries.
+    assert!(num_requests <= 2 * 8);
+    assert!(num_requests < total_num_nodes);
+}
+
+#[test]
+fn child_tries() {
+    let child_entries = |value: &[u8]| {
+        BTreeMap::from([
+            (b"unchanged".to_vec(), b"foo".to_vec()),
+            (b"changed".to_vec(), value.to_vec()),
+        ])
+    };
+
+    let (unchanged_root, mut node_values) = build_trie(&child_entries(b"a"));
+    let (modified_old_root, modified_old_node_values) = build_trie(&child_entries(b"b"));
+    let (modified_new_root, modified_new_node_values) = build_trie(&child_entries(b"c"));
+    let (deleted_root, deleted_node_values) = build_trie(&child_entries(b"d"));
+    let (inserted_root, inserted_node_values) = build_trie(&child_entries(b"e"));
+    node_values.extend(modified_old_node_values);
+    node_values.extend(modified_new_node_values);
+    node_values.extend(deleted_node_values);
+    node_values.extend(inserted_node_values);
+
+    let old = BTreeMap::from([
+        (
+            b":child_storage:default:unchanged".to_vec(),
+            unchanged_root.to_vec(),
+        ),
+        (
+            b":child_storage:default:modified".to_vec(),
+            modified_old_root.to_vec(),
+        ),
+        (
+            b":child_storage:default:deleted".to_vec(),
+            deleted_root.to_vec(),
+        ),
+    ]);
+    let new = BTreeMap::from([
+        (
+            b":child_storage:default:unchanged".to_vec(),
+            unchanged_root.to_vec(),
+        ),
+        (
+            b":child_storage:default:modified".to_vec(),
+            modified_new_root.to_vec(),
+        ),
+        (
+            b":child_storage:default:inserted".to_vec(),
+            inserted_root.to_vec(),
+        ),
+    ]);
+
+    let (old_root, old_node_values) = build_trie(&old);
+    let (new_root, new_node_values) = build_trie(&new);
+    node_values.extend(old_node_values);
+    node_values.extend(new_node_values);
+
+    let (diff, child_tries_diff, _) = run_diff(old_root, new_root, &node_values);
+    assert_eq!(diff, expected_diff(&old, &new));
+
+    let all_inserted = BTreeMap::from([
+        (b"unchanged".to_vec(), DiffKind::Inserted),
+        (b"changed".to_vec(), DiffKind::Inserted),
+    ]);
+    let all_deleted = BTreeMap::from([
+        (b"unchanged".to_vec(), DiffKind::Deleted),
+        (b"changed".to_vec(), DiffKind::Deleted),
+    ]);
+    assert_eq!(
+        child_tries_diff,
+        BTreeMap::from([
+            (
+                b"modified".to_vec(),
+                BTreeMap::from([(b"changed".to_vec(), DiffKind::Modified)])
+            ),
+            (b"deleted".to_vec(), all_deleted),
+            (b"inserted".to_vec(), all_inserted),
+        ])
+    );
+}
+
+#[test]
+fn hash_mismatch() {
+    let (old_root, _) = build_trie(&BTreeMap::from([(b"foo".to_vec(), b"bar".to_vec())]));
+    let (new_root, _) = build_trie(&BTreeMap::new());
+
+    let StorageDiff::NodeValue(req) = storage_diff(Config {
+        old_trie_root_hash: old_root,
+        new_trie_root_hash: new_root,
+    }) else { panic!() };
+
+    assert!(matches!(req.inject(&[0]), Err((_, Error::HashMismatch))));
+}
@@ -15,8 +15,10 @@
 // You should have received a copy of the GNU General Public License
 // along with this program.  If not, see <http://www.gnu.org/licenses/>.
 
-//! Internal module. Contains functions that aren't Substrate/Polkadot-specific and should ideally
-//! be found in third party libraries, but that aren't worth a third-party library.
+//! Contains functions that aren't Substrate/Polkadot-specific and should ideally be found in
+//! third party libraries, but that aren't worth a third-party library.
+//!
+//! Most of the content of this module is internal to this crate.
 
 use core::{cmp, iter, marker, str};
 
@@ -277,6 +279,23 @@ macro_rules! decode_scale_compact {
 
 decode_scale_compact!(nom_scale_compact_usize, usize);
 decode_scale_compact!(nom_scale_compact_u64, u64);
+decode_scale_compact!(nom_scale_compact_u128, u128);
+
+/// Decodes a SCALE-compact-encoded `usize` at the start of `bytes`. Returns the number and the
+/// bytes that follow it, or `None` if `bytes` doesn't start with a valid encoding.
+pub fn decode_scale_compact_usize(bytes: &[u8]) -> Option<(usize, &[u8])> {
+    nom_scale_compact_usize::<nom::error::Error<&[u8]>>(bytes)
+        .ok()
+        .map(|(rest, value)| (value, rest))
+}
+
+/// Decodes a SCALE-compact-encoded `u64` at the start of `bytes`. Returns the number and the
+/// bytes that follow it, or `None` if `bytes` doesn't start with a valid encoding.
+pub fn decode_scale_compact_u64(bytes: &[u8]) -> Option<(u64, &[u8])> {
+    nom_scale_compact_u64::<nom::error::Error<&[u8]>>(bytes)
+        .ok()
+        .map(|(rest, value)| (value, rest))
+}
 
 macro_rules! encode_scale_compact {
     ($fn_name:ident, $num_ty:ty) => {
@@ -311,3 +330,45 @@ macro_rules! encode_scale_compact {
 
 encode_scale_compact!(encode_scale_compact_u64, u64);
 encode_scale_compact!(encode_scale_compact_usize, usize);
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn decode_scale_compact_u64_basic() {
+        assert_eq!(
+            super::decode_scale_compact_u64(&[0b0000_0100, 0xff]),
+            Some((1, &[0xff][..]))
+        );
+        assert_eq!(
+            super::decode_scale_compact_u64(&[0b0000_0001, 0b0000_0100]),
+            Some((256, &[][..]))
+        );
+        assert_eq!(
+            super::decode_scale_compact_u64(&[0b0000_0010, 0, 0, 0b0000_0001]),
+            Some((1 << 22, &[][..]))
+        );
+        assert_eq!(
+            super::decode_scale_compact_u64(&[0b0001_0011, 1, 2, 3, 4, 5, 6, 7, 8]),
+            Some((0x0807060504030201, &[][..]))
+        );
+    }
+
+    #[test]
+    fn decode_scale_compact_u64_invalid() {
+        // Empty input.
+        assert_eq!(super::decode_scale_compact_u64(&[]), None);
+        // Truncated two-bytes and four-bytes modes.
+        assert_eq!(super::decode_scale_compact_u64(&[0b0000_0001]), None);
+        assert_eq!(super::decode_scale_compact_u64(&[0b0000_0010, 0, 0]), None);
+        // Big-integer mode with too few bytes.
+        assert_eq!(
+            super::decode_scale_compact_u64(&[0b0000_0011, 1, 2, 3]),
+            None
+        );
+        // Big-integer mode with more than 8 bytes.
+        assert_eq!(
+            super::decode_scale_compact_u64(&[0b0001_0111, 1, 2, 3, 4, 5, 6, 7, 8, 9]),
+            None
+        );
+    }
+}
@@ -374,6 +374,9 @@ pub fn verify(
             storage_main_trie_changes: Default::default(),
             offchain_storage_changes: Default::default(),
             max_log_level: config.max_log_level,
+            trace: false,
+            fuel_budget: None,
+            max_pending_changes_size: None,
         });
 
         match vm {
@@ -469,6 +472,9 @@ impl VerifyInner {
                                 .into_main_trie_diff(),
                             offchain_storage_changes: success.offchain_storage_changes,
                             max_log_level: 0,
+                            trace: false,
+                            fuel_budget: None,
+                            max_pending_changes_size: None,
                         });
 
                         match vm {
@@ -592,6 +598,23 @@ impl VerifyInner {
                     self.inner = sig.verify_and_resume();
                     self.phase = phase;
                 }
+                // No keystore is available when verifying a block.
+                (runtime_host::RuntimeHostVm::KeystorePublicKeys(req), phase) => {
+                    self.inner = req.resume(iter::empty::<&[u8]>());
+                    self.phase = phase;
+                }
+                (runtime_host::RuntimeHostVm::KeystoreGenerate(req), phase) => {
+                    self.inner = req.resume(None);
+                    self.phase = phase;
+                }
+                (runtime_host::RuntimeHostVm::SignRequest(req), phase) => {
+                    self.inner = req.resume(None);
+                    self.phase = phase;
+                }
+                (runtime_host::RuntimeHostVm::Offchain(ctx), phase) => {
+                    self.inner = ctx.reject();
+                    self.phase = phase;
+                }
             }
         }
     }
@@ -781,7 +804,10 @@ pub struct RuntimeCompilation {
 
 impl RuntimeCompilation {
     /// Performs the runtime compilation.
-    pub fn build(self) -> Verify {
+    ///
+    /// If `compilation_cache` is `Some`, the compiled runtime is loaded from and stored in it.
+    /// See [`vm::Config::compilation_cache`].
+    pub fn build(self, compilation_cache: Option<&dyn vm::CompilationCache>) -> Verify {
         // A `RuntimeCompilation` object is built only if `:code` is available.
         let code = self
             .storage_changes
@@ -794,6 +820,7 @@ impl RuntimeCompilation {
             module: code,
             heap_pages: self.heap_pages,
             exec_hint: vm::ExecHint::CompileAheadOfTime,
+            compilation_cache,
             allow_unresolved_imports: false,
         }) {
             Ok(vm) => vm,
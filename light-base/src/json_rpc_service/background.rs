@@ -51,6 +51,22 @@ mod getters;
 mod state_chain;
 mod transactions;
 
+/// Maximum number of WebAssembly instructions that a runtime call performed on behalf of a
+/// JSON-RPC client is allowed to execute.
+///
+/// The runtime code and the parameters of the call are under the control of the JSON-RPC client
+/// and of the chain, meaning that a call could be crafted to never finish. This limit guarantees
+/// that such a call can't freeze the light client forever. The value is chosen to be
+/// comfortably above the cost of any legitimate runtime call.
+const RUNTIME_CALL_FUEL_BUDGET: u64 = 5_000_000_000;
+
+/// Maximum number of bytes that the storage changes and logs of a runtime call performed on
+/// behalf of a JSON-RPC client are allowed to occupy.
+///
+/// Similar to [`RUNTIME_CALL_FUEL_BUDGET`], this limit guarantees that a call can't make the
+/// memory usage of the light client explode, which matters in particular in browsers.
+const RUNTIME_CALL_MAX_PENDING_CHANGES_SIZE: usize = 64 * 1024 * 1024;
+
 /// Fields used to process JSON-RPC requests in the background.
 struct Background<TPlat: PlatformRef> {
     /// Target to use for all the logs.
@@ -489,7 +505,11 @@ impl<TPlat: PlatformRef> Background<TPlat> {
             | methods::MethodCall::transaction_unstable_unwatch { .. }
             | methods::MethodCall::network_unstable_subscribeEvents { .. }
             | methods::MethodCall::network_unstable_unsubscribeEvents { .. }
-            | methods::MethodCall::chainHead_unstable_finalizedDatabase { .. } => {}
+            | methods::MethodCall::chainHead_unstable_finalizedDatabase { .. }
+            | methods::MethodCall::state_traceBlock { .. }
+            | methods::MethodCall::chain_unstable_extrinsicLocations { .. }
+            | methods::MethodCall::chain_unstable_subscribeEvents { .. }
+            | methods::MethodCall::chain_unstable_unsubscribeEvents { .. } => {}
         }
 
         // Each call is handled in a separate method.
@@ -626,7 +646,9 @@ impl<TPlat: PlatformRef> Background<TPlat> {
             | methods::MethodCall::system_addReservedPeer { .. }
             | methods::MethodCall::system_dryRun { .. }
             | methods::MethodCall::system_networkState { .. }
-            | methods::MethodCall::system_removeReservedPeer { .. }) => {
+            | methods::MethodCall::system_removeReservedPeer { .. }
+            | methods::MethodCall::state_traceBlock { .. }
+            | methods::MethodCall::chain_unstable_extrinsicLocations { .. }) => {
                 // TODO: implement the ones that make sense to implement ^
                 log::error!(target: &self.log_target, "JSON-RPC call not supported yet: {:?}", _method);
                 request.fail(json_rpc::parse::ErrorResponse::ServerError(
@@ -753,7 +775,11 @@ impl<TPlat: PlatformRef> Background<TPlat> {
             | methods::MethodCall::transaction_unstable_unwatch { .. }
             | methods::MethodCall::network_unstable_subscribeEvents { .. }
             | methods::MethodCall::network_unstable_unsubscribeEvents { .. }
-            | methods::MethodCall::chainHead_unstable_finalizedDatabase { .. } => {}
+            | methods::MethodCall::chainHead_unstable_finalizedDatabase { .. }
+            | methods::MethodCall::state_traceBlock { .. }
+            | methods::MethodCall::chain_unstable_extrinsicLocations { .. }
+            | methods::MethodCall::chain_unstable_subscribeEvents { .. }
+            | methods::MethodCall::chain_unstable_unsubscribeEvents { .. } => {}
         }
 
         // Each call is handled in a separate method.
@@ -776,6 +802,9 @@ impl<TPlat: PlatformRef> Background<TPlat> {
             methods::MethodCall::state_subscribeStorage { .. } => {
                 self.state_subscribe_storage(request).await;
             }
+            methods::MethodCall::chain_unstable_subscribeEvents { .. } => {
+                self.chain_unstable_subscribe_events(request).await;
+            }
 
             methods::MethodCall::chainHead_unstable_body { .. } => {
                 self.chain_head_unstable_body(request).await;
@@ -1086,7 +1115,7 @@ impl<TPlat: PlatformRef> Background<TPlat> {
             // try to find any similar runtime it might have, and if not will compile it.
             let pinned_runtime_id = self
                 .runtime_service
-                .compile_and_pin_runtime(storage_code, storage_heap_pages)
+                .compile_and_pin_runtime(block_number, storage_code, storage_heap_pages)
                 .await;
 
             let precall = self
@@ -1222,6 +1251,9 @@ impl<TPlat: PlatformRef> Background<TPlat> {
             storage_main_trie_changes: Default::default(),
             offchain_storage_changes: Default::default(),
             max_log_level: 0,
+            trace: false,
+            fuel_budget: Some(RUNTIME_CALL_FUEL_BUDGET),
+            max_pending_changes_size: Some(RUNTIME_CALL_MAX_PENDING_CHANGES_SIZE),
         }) {
             Ok(vm) => vm,
             Err((err, prototype)) => {
@@ -1305,6 +1337,20 @@ impl<TPlat: PlatformRef> Background<TPlat> {
                 runtime_host::RuntimeHostVm::SignatureVerification(sig) => {
                     runtime_call = sig.verify_and_resume();
                 }
+                // The light client doesn't have any keystore.
+                runtime_host::RuntimeHostVm::KeystorePublicKeys(req) => {
+                    runtime_call = req.resume(iter::empty::<&[u8]>());
+                }
+                runtime_host::RuntimeHostVm::KeystoreGenerate(req) => {
+                    runtime_call = req.resume(None);
+                }
+                runtime_host::RuntimeHostVm::SignRequest(req) => {
+                    runtime_call = req.resume(None);
+                }
+                // Runtime calls made through the JSON-RPC API are never off-chain workers.
+                runtime_host::RuntimeHostVm::Offchain(ctx) => {
+                    runtime_call = ctx.reject();
+                }
             }
         }
     }
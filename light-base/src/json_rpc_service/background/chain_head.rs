@@ -1154,6 +1154,9 @@ impl<TPlat: PlatformRef> ChainHeadFollowTask<TPlat> {
                             offchain_storage_changes: Default::default(),
                             storage_main_trie_changes: Default::default(),
                             max_log_level: 0,
+                            trace: false,
+                            fuel_budget: Some(super::RUNTIME_CALL_FUEL_BUDGET),
+                            max_pending_changes_size: Some(super::RUNTIME_CALL_MAX_PENDING_CHANGES_SIZE),
                         }) {
                             Err((error, prototype)) => {
                                 runtime_call_lock.unlock(prototype);
@@ -1281,6 +1284,21 @@ impl<TPlat: PlatformRef> ChainHeadFollowTask<TPlat> {
                                         runtime_host::RuntimeHostVm::SignatureVerification(sig) => {
                                             runtime_call = sig.verify_and_resume();
                                         }
+                                        // The light client doesn't have any keystore.
+                                        runtime_host::RuntimeHostVm::KeystorePublicKeys(req) => {
+                                            runtime_call = req.resume(iter::empty::<&[u8]>());
+                                        }
+                                        runtime_host::RuntimeHostVm::KeystoreGenerate(req) => {
+                                            runtime_call = req.resume(None);
+                                        }
+                                        runtime_host::RuntimeHostVm::SignRequest(req) => {
+                                            runtime_call = req.resume(None);
+                                        }
+                                        // Runtime calls made through the JSON-RPC API are never
+                                        // off-chain workers.
+                                        runtime_host::RuntimeHostVm::Offchain(ctx) => {
+                                            runtime_call = ctx.reject();
+                                        }
                                     }
                                 }
                             }
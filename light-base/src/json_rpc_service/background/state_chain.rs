@@ -31,9 +31,10 @@ use core::{
 };
 use futures_util::{future, stream, FutureExt as _, StreamExt as _};
 use smoldot::{
-    header,
+    executor, header,
     informant::HashDisplay,
     json_rpc::{self, methods, service},
+    metadata,
     network::protocol,
 };
 
@@ -1269,4 +1270,282 @@ impl<TPlat: PlatformRef> Background<TPlat> {
             },
         )
     }
+
+    /// Handles a call to [`methods::MethodCall::chain_unstable_subscribeEvents`].
+    pub(super) async fn chain_unstable_subscribe_events(
+        self: &Arc<Self>,
+        request: service::SubscriptionStartProcess,
+    ) {
+        let methods::MethodCall::chain_unstable_subscribeEvents { best_block } = request.request()
+            else { unreachable!() };
+
+        let mut blocks_list = {
+            let (current_block_header, blocks_subscription) = if best_block.unwrap_or(false) {
+                sub_utils::subscribe_best(&self.runtime_service).await
+            } else {
+                sub_utils::subscribe_finalized(&self.runtime_service).await
+            };
+            stream::once(future::ready(current_block_header)).chain(blocks_subscription)
+        };
+
+        self.platform
+            .spawn_task(format!("{}-subscribe-events", self.log_target).into(), {
+                let me = self.clone();
+
+                async move {
+                    let mut subscription = request.accept();
+                    let subscription_id = subscription.subscription_id().to_owned();
+
+                    // Specification and metadata of the runtime of the latest block whose events
+                    // have been decoded. The metadata is only downloaded again when the runtime
+                    // changes.
+                    let mut metadata_cache = None;
+
+                    loop {
+                        let event = {
+                            let unsubscribed = pin::pin!(subscription.wait_until_stale());
+                            match future::select(blocks_list.next(), unsubscribed).await {
+                                future::Either::Left((ev, _)) => either::Left(ev),
+                                future::Either::Right((ev, _)) => either::Right(ev),
+                            }
+                        };
+
+                        match event {
+                            either::Left(None) => {
+                                // Streams returned by `subscribe_best` and `subscribe_finalized`
+                                // are always unlimited.
+                                unreachable!()
+                            }
+                            either::Left(Some(header)) => {
+                                let block_hash = header::hash_from_scale_encoded_header(&header);
+                                let block_number = match header::decode(
+                                    &header,
+                                    me.sync_service.block_number_bytes(),
+                                ) {
+                                    Ok(h) => h.number,
+                                    Err(error) => {
+                                        log::warn!(
+                                            target: &me.log_target,
+                                            "`chain_unstable_subscribeEvents` subscription has \
+                                            skipped block due to undecodable header. Hash: {}. \
+                                            Error: {}",
+                                            HashDisplay(&block_hash),
+                                            error,
+                                        );
+                                        continue;
+                                    }
+                                };
+
+                                let events =
+                                    match me.runtime_events(&block_hash, &mut metadata_cache).await
+                                    {
+                                        Ok(events) => events,
+                                        Err(error) => {
+                                            log::warn!(
+                                                target: &me.log_target,
+                                                "`chain_unstable_subscribeEvents` subscription has \
+                                                skipped block due to failure to obtain its events. \
+                                                Hash: {}. Error: {}",
+                                                HashDisplay(&block_hash),
+                                                error,
+                                            );
+                                            continue;
+                                        }
+                                    };
+
+                                subscription
+                                    .send_notification(
+                                        methods::ServerToClient::chain_unstable_events {
+                                            subscription: (&subscription_id).into(),
+                                            result: methods::RuntimeEvents {
+                                                block_hash: methods::HashHexString(block_hash),
+                                                block_number,
+                                                events,
+                                            },
+                                        },
+                                    )
+                                    .await;
+                            }
+                            either::Right(()) => {
+                                break;
+                            }
+                        }
+                    }
+                }
+            });
+    }
+
+    /// Downloads and decodes the events emitted by the runtime during the given block.
+    ///
+    /// `metadata_cache` contains the specification and the metadata of the runtime used during
+    /// the previous call, and is updated if the runtime of the block is different.
+    async fn runtime_events(
+        self: &Arc<Self>,
+        block_hash: &[u8; 32],
+        metadata_cache: &mut Option<(executor::CoreVersion, Vec<u8>)>,
+    ) -> Result<Vec<methods::RuntimeEvent<'static>>, RuntimeEventsError> {
+        let specification = self
+            .runtime_access(block_hash)
+            .await
+            .map_err(RuntimeEventsError::RuntimeCall)?
+            .specification()
+            .map_err(RuntimeEventsError::InvalidRuntime)?;
+
+        if !matches!(metadata_cache, Some((cached, _)) if *cached == specification) {
+            let output = self
+                .runtime_call(
+                    block_hash,
+                    "Metadata",
+                    1..=2,
+                    metadata::METADATA_FUNCTION_NAME,
+                    iter::empty::<Vec<u8>>(),
+                    3,
+                    Duration::from_secs(8),
+                    NonZeroU32::new(1).unwrap(),
+                )
+                .await
+                .map_err(RuntimeEventsError::RuntimeCall)?;
+            let metadata = metadata::decode_metadata_output(&output.return_value)
+                .map_err(RuntimeEventsError::Metadata)?
+                .to_vec();
+            *metadata_cache = Some((specification, metadata));
+        }
+
+        let metadata = metadata::decode(&metadata_cache.as_ref().unwrap().1)
+            .map_err(RuntimeEventsError::Metadata)?;
+
+        let events = self
+            .storage_query(
+                iter::once(&metadata::events::SYSTEM_EVENTS_KEY[..]),
+                block_hash,
+                3,
+                Duration::from_secs(12),
+                NonZeroU32::new(1).unwrap(),
+            )
+            .await
+            .map_err(RuntimeEventsError::StorageQuery)?
+            .pop()
+            .unwrap();
+        let Some(events) = events else {
+            return Ok(Vec::new());
+        };
+
+        let events = metadata::events::decode_events(&metadata, &events)
+            .map_err(RuntimeEventsError::Decode)?;
+
+        Ok(events
+            .into_iter()
+            .map(|event| methods::RuntimeEvent {
+                phase: match event.phase {
+                    metadata::events::Phase::ApplyExtrinsic(extrinsic_index) => {
+                        methods::RuntimeEventPhase::ApplyExtrinsic { extrinsic_index }
+                    }
+                    metadata::events::Phase::Finalization => {
+                        methods::RuntimeEventPhase::Finalization
+                    }
+                    metadata::events::Phase::Initialization => {
+                        methods::RuntimeEventPhase::Initialization
+                    }
+                },
+                pallet: event.pallet_name.to_owned().into(),
+                pallet_index: event.pallet_index,
+                variant: event.variant_name.to_owned().into(),
+                variant_index: event.variant_index,
+                fields: fields_to_json(&event.fields),
+                topics: event
+                    .topics
+                    .iter()
+                    .map(|topic| methods::HexString(topic.to_vec()))
+                    .collect(),
+            })
+            .collect())
+    }
+}
+
+/// Error potentially returned by [`Background::runtime_events`].
+#[derive(Debug, derive_more::Display)]
+enum RuntimeEventsError {
+    /// Error while calling the runtime in order to obtain the metadata.
+    #[display(fmt = "{_0}")]
+    RuntimeCall(super::RuntimeCallError),
+    /// The runtime of the block is invalid.
+    #[display(fmt = "{_0}")]
+    InvalidRuntime(runtime_service::RuntimeError),
+    /// Failed to decode the metadata returned by the runtime.
+    #[display(fmt = "Failed to decode metadata: {_0}")]
+    Metadata(metadata::DecodeError),
+    /// Error while downloading the events from the network.
+    #[display(fmt = "{_0}")]
+    StorageQuery(super::StorageQueryError),
+    /// Failed to decode the events using the metadata.
+    #[display(fmt = "Failed to decode events: {_0}")]
+    Decode(metadata::events::DecodeEventsError),
+}
+
+/// Converts the fields of a structure or of a variant into JSON. Returns an object if all the
+/// fields have a name, and an array otherwise.
+fn fields_to_json(fields: &[(Option<&str>, metadata::value::Value)]) -> serde_json::Value {
+    if !fields.is_empty() && fields.iter().all(|(name, _)| name.is_some()) {
+        serde_json::Value::Object(
+            fields
+                .iter()
+                .map(|(name, value)| (name.unwrap().to_owned(), value_to_json(value)))
+                .collect(),
+        )
+    } else {
+        serde_json::Value::Array(
+            fields
+                .iter()
+                .map(|(_, value)| value_to_json(value))
+                .collect(),
+        )
+    }
+}
+
+/// Converts a value decoded using the metadata into JSON.
+fn value_to_json(value: &metadata::value::Value) -> serde_json::Value {
+    // Integers above this value can't be represented by a JavaScript number without losing
+    // precision, and are instead represented as strings.
+    const MAX_SAFE_INTEGER: u128 = (1 << 53) - 1;
+
+    match value.unwrap_newtype() {
+        metadata::value::Value::Bool(value) => serde_json::Value::Bool(*value),
+        metadata::value::Value::Char(value) => serde_json::Value::String(value.to_string()),
+        metadata::value::Value::Str(value) => serde_json::Value::String((*value).to_owned()),
+        metadata::value::Value::Unsigned(value) if *value <= MAX_SAFE_INTEGER => {
+            serde_json::Value::from(u64::try_from(*value).unwrap())
+        }
+        metadata::value::Value::Signed(value) if value.unsigned_abs() <= MAX_SAFE_INTEGER => {
+            serde_json::Value::from(i64::try_from(*value).unwrap())
+        }
+        metadata::value::Value::Unsigned(value) => serde_json::Value::String(value.to_string()),
+        metadata::value::Value::Signed(value) => serde_json::Value::String(value.to_string()),
+        metadata::value::Value::U256(value) | metadata::value::Value::I256(value) => {
+            // Encoded as a big endian hexadecimal number.
+            let mut value = **value;
+            value.reverse();
+            serde_json::Value::String(format!("0x{}", hex::encode(value)))
+        }
+        metadata::value::Value::Bytes(value)
+        | metadata::value::Value::BitSequence { data: value, .. } => {
+            serde_json::Value::String(format!("0x{}", hex::encode(value)))
+        }
+        metadata::value::Value::Sequence(items) => {
+            serde_json::Value::Array(items.iter().map(value_to_json).collect())
+        }
+        metadata::value::Value::Composite(fields) => fields_to_json(fields),
+        metadata::value::Value::Variant { name, fields, .. } => match &fields[..] {
+            [] => serde_json::Value::String((*name).to_owned()),
+            [(None, value)] => {
+                let mut object = serde_json::Map::new();
+                object.insert((*name).to_owned(), value_to_json(value));
+                serde_json::Value::Object(object)
+            }
+            _ => {
+                let mut object = serde_json::Map::new();
+                object.insert((*name).to_owned(), fields_to_json(fields));
+                serde_json::Value::Object(object)
+            }
+        },
+    }
 }
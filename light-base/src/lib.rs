@@ -1132,6 +1132,10 @@ async fn start_services<TPlat: platform::PlatformRef>(
                 platform: platform.clone(),
                 sync_service: sync_service.clone(),
                 genesis_block_scale_encoded_header,
+                code_substitutes: chain_spec
+                    .code_substitutes()
+                    .map(|(block_number, code)| (block_number, code.to_vec()))
+                    .collect(),
             })
             .await,
         );
@@ -1164,6 +1168,10 @@ async fn start_services<TPlat: platform::PlatformRef>(
                 platform: platform.clone(),
                 sync_service: sync_service.clone(),
                 genesis_block_scale_encoded_header,
+                code_substitutes: chain_spec
+                    .code_substitutes()
+                    .map(|(block_number, code)| (block_number, code.to_vec()))
+                    .collect(),
             })
             .await,
         );
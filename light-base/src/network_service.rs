@@ -236,6 +236,7 @@ impl<TPlat: PlatformRef> NetworkService<TPlat> {
                 genesis_hash: chain.genesis_block_hash,
                 role: protocol::Role::Light,
                 allow_inbound_block_requests: false,
+                allow_inbound_grandpa_warp_sync_requests: false,
             });
 
             log_chain_names.push(chain.log_name);
@@ -1269,6 +1270,7 @@ async fn update_round<TPlat: PlatformRef>(
                         .respond_identify(request_id, &shared.identify_agent_version);
                 }
                 service::Event::BlocksRequestIn { .. } => unreachable!(),
+                service::Event::GrandpaWarpSyncRequestIn { .. } => unreachable!(),
                 service::Event::RequestInCancel { .. } => {
                     // All incoming requests are immediately answered.
                     unreachable!()
@@ -67,7 +67,7 @@ use alloc::{
 };
 use async_lock::{Mutex, MutexGuard};
 use core::{
-    iter, mem,
+    cmp, iter, mem,
     num::{NonZeroU32, NonZeroUsize},
     pin::Pin,
     time::Duration,
@@ -83,6 +83,8 @@ use smoldot::{
     trie::{self, proof_decode, TrieEntryVersion},
 };
 
+mod tests;
+
 /// Configuration for a runtime service.
 pub struct Config<TPlat: PlatformRef> {
     /// Name of the chain, for logging purposes.
@@ -99,6 +101,11 @@ pub struct Config<TPlat: PlatformRef> {
 
     /// Header of the genesis block of the chain, in SCALE encoding.
     pub genesis_block_scale_encoded_header: Vec<u8>,
+
+    /// List of runtime codes that replace the on-chain runtime code, alongside with the block
+    /// number they are registered at.
+    /// See [`smoldot::chain_spec::ChainSpec::code_substitutes`].
+    pub code_substitutes: Vec<(u64, Vec<u8>)>,
 }
 
 /// Identifies a runtime currently pinned within a [`RuntimeService`].
@@ -107,6 +114,9 @@ pub struct PinnedRuntimeId(Arc<Runtime>);
 
 /// See [the module-level documentation](..).
 pub struct RuntimeService<TPlat: PlatformRef> {
+    /// Target to use for the logs.
+    log_target: String,
+
     /// See [`Config::platform`].
     platform: TPlat,
 
@@ -156,11 +166,26 @@ impl<TPlat: PlatformRef> RuntimeService<TPlat> {
             }
         };
 
+        let code_substitutes = {
+            let mut list = config
+                .code_substitutes
+                .into_iter()
+                .map(|(block_number, code)| CodeSubstitute {
+                    block_number,
+                    code,
+                    spec_version: None,
+                })
+                .collect::<Vec<_>>();
+            list.sort_unstable_by_key(|s| cmp::Reverse(s.block_number));
+            list
+        };
+
         let guarded = Arc::new(Mutex::new(Guarded {
             next_subscription_id: 0,
             best_near_head_of_chain,
             tree,
             runtimes: slab::Slab::with_capacity(2),
+            code_substitutes,
         }));
 
         // Spawns a task that runs in the background and updates the content of the mutex.
@@ -169,6 +194,7 @@ impl<TPlat: PlatformRef> RuntimeService<TPlat> {
             let sync_service = config.sync_service.clone();
             let guarded = guarded.clone();
             let platform = config.platform.clone();
+            let log_target = log_target.clone();
             let (abortable, abort) = future::abortable(async move {
                 run_background(log_target, platform, sync_service, guarded).await;
             });
@@ -177,6 +203,7 @@ impl<TPlat: PlatformRef> RuntimeService<TPlat> {
         });
 
         RuntimeService {
+            log_target,
             platform: config.platform,
             sync_service: config.sync_service,
             guarded,
@@ -513,39 +540,25 @@ impl<TPlat: PlatformRef> RuntimeService<TPlat> {
     /// heap pages. If none is found, compiles the runtime and stores it within the
     /// [`RuntimeService`]. In both cases, it is kept pinned until it is unpinned with
     /// [`RuntimeService::unpin_runtime`].
+    ///
+    /// The number of the block the storage code and heap pages have been read from is necessary
+    /// in order to apply the code substitutes. See [`Config::code_substitutes`].
     pub async fn compile_and_pin_runtime(
         &self,
+        block_number: u64,
         storage_code: Option<Vec<u8>>,
         storage_heap_pages: Option<Vec<u8>>,
     ) -> PinnedRuntimeId {
         let mut guarded = self.guarded.lock().await;
-
-        // Try to find an existing identical runtime.
-        let existing_runtime = guarded
-            .runtimes
-            .iter()
-            .filter_map(|(_, rt)| rt.upgrade())
-            .find(|rt| rt.runtime_code == storage_code && rt.heap_pages == storage_heap_pages);
-
-        let runtime = if let Some(existing_runtime) = existing_runtime {
-            existing_runtime
-        } else {
-            // No identical runtime was found. Try compiling the new runtime.
-            let runtime = SuccessfulRuntime::from_storage::<TPlat>(
-                &self.platform,
-                &storage_code,
-                &storage_heap_pages,
-            )
-            .await;
-            let runtime = Arc::new(Runtime {
-                heap_pages: storage_heap_pages,
-                runtime_code: storage_code,
-                runtime,
-            });
-            guarded.runtimes.insert(Arc::downgrade(&runtime));
-            runtime
-        };
-
+        let runtime = find_or_compile_runtime(
+            &self.platform,
+            &self.log_target,
+            &mut guarded,
+            block_number,
+            storage_code,
+            storage_heap_pages,
+        )
+        .await;
         PinnedRuntimeId(runtime)
     }
 
@@ -973,19 +986,14 @@ impl<'a> RuntimeCall<'a> {
         main_trie_root: &[u8; 32],
         child_trie: &[u8],
     ) -> Result<Option<[u8; 32]>, RuntimeCallError> {
-        // TODO: allocation here, but probably not problematic
-        const PREFIX: &[u8] = b":child_storage:default:";
-        let mut key = Vec::with_capacity(PREFIX.len() + child_trie.as_ref().len());
-        key.extend_from_slice(PREFIX);
-        key.extend_from_slice(child_trie.as_ref());
-
-        match proof.storage_value(main_trie_root, &key) {
-            Err(err) => Err(RuntimeCallError::MissingProofEntry(err)),
-            Ok(None) => Ok(None),
-            Ok(Some((value, _))) => match <[u8; 32]>::try_from(value) {
-                Ok(hash) => Ok(Some(hash)),
-                Err(_) => Err(RuntimeCallError::InvalidChildTrieRoot),
-            },
+        match proof.child_trie_root_hash(main_trie_root, child_trie) {
+            Ok(hash) => Ok(hash.copied()),
+            Err(proof_decode::ChildTrieError::IncompleteProof(err)) => {
+                Err(RuntimeCallError::MissingProofEntry(err))
+            }
+            Err(proof_decode::ChildTrieError::InvalidChildTrieRootHash) => {
+                Err(RuntimeCallError::InvalidChildTrieRoot)
+            }
         }
     }
 }
@@ -1073,11 +1081,28 @@ struct Guarded<TPlat: PlatformRef> {
     /// the elements.
     runtimes: slab::Slab<Weak<Runtime>>,
 
+    /// See [`Config::code_substitutes`]. Ordered by decreasing block number.
+    code_substitutes: Vec<CodeSubstitute>,
+
     /// Tree of blocks received from the sync service. Keeps track of which block has been
     /// reported to the outer API.
     tree: GuardedInner<TPlat>,
 }
 
+/// Entry in [`Guarded::code_substitutes`].
+struct CodeSubstitute {
+    /// Number of the block starting from which the substitute applies.
+    block_number: u64,
+
+    /// Runtime code of the substitute, in the same format as the `:code` storage item.
+    code: Vec<u8>,
+
+    /// `spec_version` of the substitute. Calculated the first time it is needed, as this
+    /// requires compiling the code with the `:heappages` of the block being checked. Contains
+    /// `Some(None)` if the compilation has failed, in which case the substitute is never used.
+    spec_version: Option<Option<u32>>,
+}
+
 enum GuardedInner<TPlat: PlatformRef> {
     FinalizedBlockRuntimeKnown {
         /// Tree of blocks. Holds the state of the download of everything. Always `Some` when the
@@ -1219,6 +1244,14 @@ async fn run_background<TPlat: PlatformRef>(
                 let runtime = Arc::new(Runtime {
                     runtime_code: finalized_block_runtime.storage_code,
                     heap_pages: finalized_block_runtime.storage_heap_pages,
+                    code_substitute_block_number: None,
+                    on_chain_spec_version: Some(
+                        finalized_block_runtime
+                            .virtual_machine
+                            .runtime_version()
+                            .decode()
+                            .spec_version,
+                    ),
                     runtime: Ok(SuccessfulRuntime {
                         runtime_spec: finalized_block_runtime
                             .virtual_machine
@@ -1228,6 +1261,25 @@ async fn run_background<TPlat: PlatformRef>(
                     }),
                 });
 
+                // The runtime provided by the sync service is the on-chain runtime, and might
+                // have to be replaced with a code substitute.
+                let runtime = match header::decode(
+                    &subscription.finalized_block_scale_encoded_header,
+                    sync_service.block_number_bytes(),
+                ) {
+                    Ok(decoded) => {
+                        apply_code_substitutes(
+                            &platform,
+                            &log_target,
+                            lock,
+                            decoded.number,
+                            runtime,
+                        )
+                        .await
+                    }
+                    Err(_) => runtime,
+                };
+
                 match &runtime.runtime {
                     Ok(runtime) => {
                         log::info!(
@@ -1291,6 +1343,7 @@ async fn run_background<TPlat: PlatformRef>(
                             let same_runtime_as_parent = same_runtime_as_parent(
                                 &block.scale_encoded_header,
                                 sync_service.block_number_bytes(),
+                                &lock.code_substitutes,
                             );
                             let _ = tree.input_insert_block(
                                 Block {
@@ -1345,6 +1398,7 @@ async fn run_background<TPlat: PlatformRef>(
                             let same_runtime_as_parent = same_runtime_as_parent(
                                 &block.scale_encoded_header,
                                 sync_service.block_number_bytes(),
+                                &lock.code_substitutes,
                             );
                             let _ = tree.input_insert_block(
                                 Block {
@@ -1405,7 +1459,7 @@ async fn run_background<TPlat: PlatformRef>(
                                 guarded.best_near_head_of_chain = near_head_of_chain;
                             }
 
-                            let same_runtime_as_parent = same_runtime_as_parent(&new_block.scale_encoded_header, sync_service.block_number_bytes());
+                            let same_runtime_as_parent = same_runtime_as_parent(&new_block.scale_encoded_header, sync_service.block_number_bytes(), &guarded.code_substitutes);
 
                             match &mut guarded.tree {
                                 GuardedInner::FinalizedBlockRuntimeKnown {
@@ -1493,7 +1547,7 @@ async fn run_background<TPlat: PlatformRef>(
                     }.format_with(", ", |block, fmt| fmt(&HashDisplay(&block.hash))).to_string();
 
                     match download_result {
-                        Ok((storage_code, storage_heap_pages)) => {
+                        Ok((block_number, storage_code, storage_heap_pages)) => {
                             log::debug!(
                                 target: &log_target,
                                 "Worker <= SuccessfulDownload(blocks=[{}])",
@@ -1504,7 +1558,7 @@ async fn run_background<TPlat: PlatformRef>(
                             guarded.best_near_head_of_chain = true;
                             drop(guarded);
 
-                            background.runtime_download_finished(async_op_id, storage_code, storage_heap_pages).await;
+                            background.runtime_download_finished(async_op_id, block_number, storage_code, storage_heap_pages).await;
                         }
                         Err(error) => {
                             log::debug!(
@@ -1577,14 +1631,14 @@ struct Background<TPlat: PlatformRef> {
     blocks_stream: Pin<Box<dyn Stream<Item = sync_service::Notification> + Send>>,
 
     /// List of runtimes currently being downloaded from the network.
-    /// For each item, the download id, storage value of `:code`, and storage value of
-    /// `:heappages`.
+    /// For each item, the download id, number of the block whose storage is downloaded, storage
+    /// value of `:code`, and storage value of `:heappages`.
     runtime_downloads: stream::FuturesUnordered<
         future::BoxFuture<
             'static,
             (
                 async_tree::AsyncOpId,
-                Result<(Option<Vec<u8>>, Option<Vec<u8>>), RuntimeDownloadError>,
+                Result<(u64, Option<Vec<u8>>, Option<Vec<u8>>), RuntimeDownloadError>,
             ),
         >,
     >,
@@ -1598,59 +1652,21 @@ impl<TPlat: PlatformRef> Background<TPlat> {
     async fn runtime_download_finished(
         &mut self,
         async_op_id: async_tree::AsyncOpId,
+        block_number: u64,
         storage_code: Option<Vec<u8>>,
         storage_heap_pages: Option<Vec<u8>>,
     ) {
         let mut guarded = self.guarded.lock().await;
 
-        // Try to find an existing runtime identical to the one that has just been downloaded.
-        // This loop is `O(n)`, but given that we expect this list to very small (at most 1 or
-        // 2 elements), this is not a problem.
-        let existing_runtime = guarded
-            .runtimes
-            .iter()
-            .filter_map(|(_, rt)| rt.upgrade())
-            .find(|rt| rt.runtime_code == storage_code && rt.heap_pages == storage_heap_pages);
-
-        // If no identical runtime was found, try compiling the runtime.
-        let runtime = if let Some(existing_runtime) = existing_runtime {
-            existing_runtime
-        } else {
-            let runtime = SuccessfulRuntime::from_storage::<TPlat>(
-                &self.platform,
-                &storage_code,
-                &storage_heap_pages,
-            )
-            .await;
-            match &runtime {
-                Ok(runtime) => {
-                    log::info!(
-                        target: &self.log_target,
-                        "Successfully compiled runtime. Spec version: {}. Size of `:code`: {}.",
-                        runtime.runtime_spec.decode().spec_version,
-                        BytesDisplay(u64::try_from(storage_code.as_ref().map_or(0, |v| v.len())).unwrap())
-                    );
-                }
-                Err(error) => {
-                    log::warn!(
-                        target: &self.log_target,
-                        "Failed to compile runtime. Size of `:code`: {}.\nError: {}\n\
-                        This indicates an incompatibility between smoldot and the chain.",
-                        BytesDisplay(u64::try_from(storage_code.as_ref().map_or(0, |v| v.len())).unwrap()),
-                        error
-                    );
-                }
-            }
-
-            let runtime = Arc::new(Runtime {
-                heap_pages: storage_heap_pages,
-                runtime_code: storage_code,
-                runtime,
-            });
-
-            guarded.runtimes.insert(Arc::downgrade(&runtime));
-            runtime
-        };
+        let runtime = find_or_compile_runtime(
+            &self.platform,
+            &self.log_target,
+            &mut guarded,
+            block_number,
+            storage_code,
+            storage_heap_pages,
+        )
+        .await;
 
         // Insert the runtime into the tree.
         match &mut guarded.tree {
@@ -2020,7 +2036,7 @@ impl<TPlat: PlatformRef> Background<TPlat> {
                                             _ => None,
                                         })
                                         .unwrap();
-                                    Ok((code, heap_pages))
+                                    Ok((block_number, code, heap_pages))
                                 }
                                 Err(error) => Err(RuntimeDownloadError::StorageQuery(error)),
                             };
@@ -2110,6 +2126,16 @@ struct Runtime {
     /// build.
     // TODO: consider storing hash instead
     heap_pages: Option<Vec<u8>>,
+
+    /// If `Some`, [`Runtime::runtime`] has been built from the code substitute registered at the
+    /// given block number rather than from [`Runtime::runtime_code`].
+    /// See [`Config::code_substitutes`].
+    code_substitute_block_number: Option<u64>,
+
+    /// `spec_version` of the runtime built from [`Runtime::runtime_code`], ignoring the code
+    /// substitutes. `None` if this runtime has failed to build. Used in order to determine which
+    /// code substitute applies.
+    on_chain_spec_version: Option<u32>,
 }
 
 struct SuccessfulRuntime {
@@ -2125,17 +2151,19 @@ struct SuccessfulRuntime {
 impl SuccessfulRuntime {
     async fn from_storage<TPlat: PlatformRef>(
         platform: &TPlat,
-        code: &Option<Vec<u8>>,
-        heap_pages: &Option<Vec<u8>>,
+        code: Option<&[u8]>,
+        heap_pages: Option<&[u8]>,
     ) -> Result<Self, RuntimeError> {
         // Since compiling the runtime is a CPU-intensive operation, we yield once before.
         platform.yield_after_cpu_intensive().await;
 
         // Parameters for `HostVmPrototype::new`.
-        let module = code.as_ref().ok_or(RuntimeError::CodeNotFound)?;
-        let heap_pages = executor::storage_heap_pages_to_value(heap_pages.as_deref())
+        let module = code.ok_or(RuntimeError::CodeNotFound)?;
+        let heap_pages = executor::storage_heap_pages_to_value(heap_pages)
             .map_err(RuntimeError::InvalidHeapPages)?;
-        let exec_hint = executor::vm::ExecHint::CompileAheadOfTime;
+        // The runtime calls performed on behalf of JSON-RPC clients are given a fuel budget, which
+        // requires the `Untrusted` hint.
+        let exec_hint = executor::vm::ExecHint::Untrusted;
 
         // We try once with `allow_unresolved_imports: false`. If this fails due to unresolved
         // import, we try again but with `allowed_unresolved_imports: true`.
@@ -2146,6 +2174,7 @@ impl SuccessfulRuntime {
             module,
             heap_pages,
             exec_hint,
+            compilation_cache: None,
             allow_unresolved_imports: false,
         }) {
             Ok(vm) => {
@@ -2164,6 +2193,7 @@ impl SuccessfulRuntime {
                     module,
                     heap_pages,
                     exec_hint,
+                    compilation_cache: None,
                     allow_unresolved_imports: true,
                 }) {
                     Ok(vm) => {
@@ -2195,10 +2225,235 @@ impl SuccessfulRuntime {
     }
 }
 
+/// Finds within [`Guarded::runtimes`] the runtime corresponding to the given storage values of
+/// `:code` and `:heappages` of the block whose number is `block_number`, or compiles it if none
+/// is found. The code substitutes found in [`Guarded::code_substitutes`] are taken into account.
+async fn find_or_compile_runtime<TPlat: PlatformRef>(
+    platform: &TPlat,
+    log_target: &str,
+    guarded: &mut Guarded<TPlat>,
+    block_number: u64,
+    storage_code: Option<Vec<u8>>,
+    storage_heap_pages: Option<Vec<u8>>,
+) -> Arc<Runtime> {
+    // Try to find an existing runtime with the same storage values.
+    // This loop is `O(n)`, but given that we expect this list to very small (at most 1 or
+    // 2 elements), this is not a problem.
+    // The runtime found might have been built from a code substitute, or not, depending on the
+    // block it was built for. This is corrected below.
+    let existing_runtime = guarded
+        .runtimes
+        .iter()
+        .filter_map(|(_, rt)| rt.upgrade())
+        .find(|rt| rt.runtime_code == storage_code && rt.heap_pages == storage_heap_pages);
+
+    let runtime = if let Some(existing_runtime) = existing_runtime {
+        existing_runtime
+    } else {
+        let runtime =
+            compile_runtime(platform, log_target, storage_code, storage_heap_pages, None).await;
+        guarded.runtimes.insert(Arc::downgrade(&runtime));
+        runtime
+    };
+
+    apply_code_substitutes(platform, log_target, guarded, block_number, runtime).await
+}
+
+/// Returns the runtime to use for the block whose number is `block_number`, given a runtime
+/// built from the storage values of `:code` and `:heappages` of this block.
+///
+/// If a code substitute applies to this block, the returned runtime is built from this
+/// substitute. Otherwise, the returned runtime is built from the on-chain code. Runtimes are
+/// searched for in [`Guarded::runtimes`] and compiled if necessary.
+async fn apply_code_substitutes<TPlat: PlatformRef>(
+    platform: &TPlat,
+    log_target: &str,
+    guarded: &mut Guarded<TPlat>,
+    block_number: u64,
+    runtime: Arc<Runtime>,
+) -> Arc<Runtime> {
+    // `runtime` might itself have been built from a code substitute. The `spec_version` of the
+    // on-chain runtime is what determines whether a substitute applies.
+    let substitute_index = match runtime.on_chain_spec_version {
+        Some(on_chain_spec_version) => {
+            // The `spec_version` of the substitutes that might apply is calculated if it isn't
+            // known yet, using the `:heappages` of the block.
+            for substitute in guarded.code_substitutes.iter_mut() {
+                if substitute.block_number > block_number || substitute.spec_version.is_some() {
+                    continue;
+                }
+
+                let built = SuccessfulRuntime::from_storage(
+                    platform,
+                    Some(&substitute.code),
+                    runtime.heap_pages.as_deref(),
+                )
+                .await;
+                if let Err(error) = &built {
+                    log::warn!(
+                        target: log_target,
+                        "Failed to compile code substitute registered at block #{}. This \
+                        code substitute will be ignored.\nError: {}",
+                        substitute.block_number,
+                        error
+                    );
+                }
+                substitute.spec_version =
+                    Some(built.ok().map(|rt| rt.runtime_spec.decode().spec_version));
+            }
+
+            select_code_substitute(
+                &guarded.code_substitutes,
+                block_number,
+                on_chain_spec_version,
+            )
+        }
+        None => None,
+    };
+
+    let substitute_block_number =
+        substitute_index.map(|idx| guarded.code_substitutes[idx].block_number);
+    if runtime.code_substitute_block_number == substitute_block_number {
+        return runtime;
+    }
+
+    // Try to find an existing runtime corresponding to what is needed.
+    let existing_runtime = guarded
+        .runtimes
+        .iter()
+        .filter_map(|(_, rt)| rt.upgrade())
+        .find(|rt| {
+            rt.runtime_code == runtime.runtime_code
+                && rt.heap_pages == runtime.heap_pages
+                && rt.code_substitute_block_number == substitute_block_number
+        });
+    if let Some(existing_runtime) = existing_runtime {
+        return existing_runtime;
+    }
+
+    let new_runtime = compile_runtime(
+        platform,
+        log_target,
+        runtime.runtime_code.clone(),
+        runtime.heap_pages.clone(),
+        substitute_index.map(|idx| {
+            (
+                &guarded.code_substitutes[idx],
+                runtime.on_chain_spec_version,
+            )
+        }),
+    )
+    .await;
+    guarded.runtimes.insert(Arc::downgrade(&new_runtime));
+    new_runtime
+}
+
+/// Compiles a runtime and prints the outcome in the logs.
+///
+/// The runtime is built from `code_substitute` if it is `Some`, or from `storage_code`
+/// otherwise. If `code_substitute` is `Some`, it must also contain the `spec_version` of the
+/// runtime built from `storage_code`.
+async fn compile_runtime<TPlat: PlatformRef>(
+    platform: &TPlat,
+    log_target: &str,
+    storage_code: Option<Vec<u8>>,
+    storage_heap_pages: Option<Vec<u8>>,
+    code_substitute: Option<(&CodeSubstitute, Option<u32>)>,
+) -> Arc<Runtime> {
+    let code = match code_substitute {
+        Some((substitute, _)) => Some(&substitute.code[..]),
+        None => storage_code.as_deref(),
+    };
+
+    let runtime =
+        SuccessfulRuntime::from_storage(platform, code, storage_heap_pages.as_deref()).await;
+
+    match (&runtime, code_substitute) {
+        (Ok(runtime), None) => {
+            log::info!(
+                target: log_target,
+                "Successfully compiled runtime. Spec version: {}. Size of `:code`: {}.",
+                runtime.runtime_spec.decode().spec_version,
+                BytesDisplay(u64::try_from(code.map_or(0, |v| v.len())).unwrap())
+            );
+        }
+        (Ok(runtime), Some((substitute, _))) => {
+            log::info!(
+                target: log_target,
+                "Successfully compiled code substitute registered at block #{}. Spec version: {}. \
+                Size of code: {}.",
+                substitute.block_number,
+                runtime.runtime_spec.decode().spec_version,
+                BytesDisplay(u64::try_from(substitute.code.len()).unwrap())
+            );
+        }
+        (Err(error), _) => {
+            log::warn!(
+                target: log_target,
+                "Failed to compile runtime. Size of `:code`: {}.\nError: {}\n\
+                This indicates an incompatibility between smoldot and the chain.",
+                BytesDisplay(u64::try_from(code.map_or(0, |v| v.len())).unwrap()),
+                error
+            );
+        }
+    }
+
+    Arc::new(Runtime {
+        heap_pages: storage_heap_pages,
+        runtime_code: storage_code,
+        code_substitute_block_number: code_substitute
+            .map(|(substitute, _)| substitute.block_number),
+        on_chain_spec_version: match code_substitute {
+            Some((_, on_chain_spec_version)) => on_chain_spec_version,
+            None => runtime
+                .as_ref()
+                .ok()
+                .map(|rt| rt.runtime_spec.decode().spec_version),
+        },
+        runtime,
+    })
+}
+
+/// Returns the index within `code_substitutes` of the code substitute that applies to the block
+/// whose number is `block_number` and whose on-chain runtime has the given `spec_version`, or
+/// `None` if no substitute applies.
+///
+/// A code substitute applies starting from the block it is registered at, and as long as the
+/// on-chain runtime keeps the same `spec_version` as the substitute. If multiple substitutes
+/// apply, the one registered the most recently is picked.
+///
+/// The substitutes whose `spec_version` isn't known yet are ignored.
+fn select_code_substitute(
+    code_substitutes: &[CodeSubstitute],
+    block_number: u64,
+    on_chain_spec_version: u32,
+) -> Option<usize> {
+    code_substitutes
+        .iter()
+        .enumerate()
+        .filter(|(_, substitute)| {
+            substitute.block_number <= block_number
+                && substitute.spec_version == Some(Some(on_chain_spec_version))
+        })
+        .max_by_key(|(_, substitute)| substitute.block_number)
+        .map(|(index, _)| index)
+}
+
 /// Returns `true` if the block can be assumed to have the same runtime as its parent.
-fn same_runtime_as_parent(header: &[u8], block_number_bytes: usize) -> bool {
+fn same_runtime_as_parent(
+    header: &[u8],
+    block_number_bytes: usize,
+    code_substitutes: &[CodeSubstitute],
+) -> bool {
     match header::decode(header, block_number_bytes) {
-        Ok(h) => !h.digest.has_runtime_environment_updated(),
+        // A code substitute starts applying at the block it is registered at, even if the
+        // runtime hasn't been modified on chain.
+        Ok(h) => {
+            !h.digest.has_runtime_environment_updated()
+                && !code_substitutes
+                    .iter()
+                    .any(|substitute| substitute.block_number == h.number)
+        }
         Err(_) => false,
     }
 }
@@ -0,0 +1,81 @@
+// Smoldot
+// Copyright (C) 2023  Pierre Krieger
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <http://www.gnu.org/licenses/>.
+
+#![cfg(test)]
+
+use super::{select_code_substitute, CodeSubstitute};
+
+use alloc::vec::Vec;
+
+fn substitute(block_number: u64, spec_version: Option<Option<u32>>) -> CodeSubstitute {
+    CodeSubstitute {
+        block_number,
+        code: Vec::new(),
+        spec_version,
+    }
+}
+
+#[test]
+fn applies_starting_from_activation_height() {
+    let substitutes = [substitute(100, Some(Some(5)))];
+    assert_eq!(select_code_substitute(&substitutes, 99, 5), None);
+    assert_eq!(select_code_substitute(&substitutes, 100, 5), Some(0));
+    assert_eq!(select_code_substitute(&substitutes, 101, 5), Some(0));
+}
+
+#[test]
+fn spec_version_bump_ends_substitute() {
+    let substitutes = [substitute(100, Some(Some(5)))];
+    assert_eq!(select_code_substitute(&substitutes, 150, 5), Some(0));
+    // The on-chain runtime has been upgraded.
+    assert_eq!(select_code_substitute(&substitutes, 151, 6), None);
+}
+
+#[test]
+fn fork_below_activation_height() {
+    let substitutes = [substitute(100, Some(Some(5)))];
+
+    // A fork that branches off at block #90 keeps the same on-chain runtime. Its blocks below
+    // the activation height don't use the substitute, while the ones above do.
+    assert_eq!(select_code_substitute(&substitutes, 95, 5), None);
+    assert_eq!(select_code_substitute(&substitutes, 100, 5), Some(0));
+
+    // Another fork that branches off at block #90 upgrades its runtime at block #95. The
+    // substitute doesn't apply to it, even at the activation height.
+    assert_eq!(select_code_substitute(&substitutes, 100, 6), None);
+    assert_eq!(select_code_substitute(&substitutes, 105, 6), None);
+}
+
+#[test]
+fn most_recent_substitute_picked() {
+    // Substitutes are ordered by decreasing block number.
+    let substitutes = [
+        substitute(200, Some(Some(5))),
+        substitute(150, Some(Some(6))),
+        substitute(100, Some(Some(5))),
+    ];
+    assert_eq!(select_code_substitute(&substitutes, 120, 5), Some(2));
+    assert_eq!(select_code_substitute(&substitutes, 160, 6), Some(1));
+    assert_eq!(select_code_substitute(&substitutes, 160, 5), Some(2));
+    assert_eq!(select_code_substitute(&substitutes, 200, 5), Some(0));
+}
+
+#[test]
+fn failed_or_unknown_substitutes_ignored() {
+    let substitutes = [substitute(150, Some(None)), substitute(100, None)];
+    assert_eq!(select_code_substitute(&substitutes, 200, 5), None);
+}
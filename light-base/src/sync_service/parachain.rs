@@ -1151,6 +1151,8 @@ async fn parahead<TPlat: PlatformRef>(
             para::OccupiedCoreAssumption::TimedOut,
         ),
         max_log_level: 0,
+        trace: false,
+        fuel_budget: None,
     }) {
         Ok(vm) => vm,
         Err((err, prototype)) => {
@@ -1178,12 +1180,19 @@ async fn parahead<TPlat: PlatformRef>(
                 };
                 let storage_value = match storage_value {
                     Ok(v) => v.map(|(v, _)| v),
-                    Err(err) => {
-                        runtime_call_lock.unlock(
-                            read_only_runtime_host::RuntimeHostVm::StorageGet(get).into_prototype(),
-                        );
-                        return Err(ParaheadError::Call(err));
-                    }
+                    Err(err) => match get.inject_unavailable() {
+                        Ok(next) => {
+                            runtime_call = next;
+                            continue;
+                        }
+                        Err(get) => {
+                            runtime_call_lock.unlock(
+                                read_only_runtime_host::RuntimeHostVm::StorageGet(*get)
+                                    .into_prototype(),
+                            );
+                            return Err(ParaheadError::Call(err));
+                        }
+                    },
                 };
                 runtime_call = get.inject_value(storage_value.map(iter::once));
             }